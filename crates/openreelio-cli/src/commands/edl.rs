@@ -0,0 +1,187 @@
+//! CMX 3600 EDL interchange: `edl import`.
+//!
+//! Colorists and online houses hand cuts back as EDLs. `edl import` reads one
+//! into a **new sequence** created at the EDL's frame rate, matching each
+//! event's reel to a project asset by `* SOURCE FILE`, `* FROM CLIP NAME` or
+//! reel name. Events that match nothing are listed in `unresolved` with the
+//! reason; everything the format carries that OpenReelio does not import is
+//! named in `warnings` and `unsupported`.
+//!
+//! The plan, dry run and report are shared with the other importers; see
+//! [`super::interchange_import`].
+
+use clap::Subcommand;
+
+use openreelio_core::interchange::edl::{self, ParsedEdl};
+use openreelio_core::interchange::edl_import::{edl_to_plan_steps, EdlImportContext};
+use openreelio_core::interchange::models::is_drop_frame_rate;
+use openreelio_core::Ratio;
+
+use super::interchange_import::{self, ImportArgs, PlannedImport};
+
+#[derive(Subcommand)]
+pub enum EdlAction {
+    /// Import a CMX 3600 EDL into a new sequence
+    Import {
+        #[command(flatten)]
+        args: ImportArgs,
+
+        /// Frame rate the EDL's timecode counts at: 23.976, 24, 25, 29.97,
+        /// 30, 59.94, 60, or a ratio such as 30000/1001
+        ///
+        /// Defaults to the active sequence's rate, or 29.97 when the EDL
+        /// declares DROP FRAME and the active sequence is not a drop-frame rate.
+        #[arg(long)]
+        fps: Option<String>,
+    },
+}
+
+pub fn execute(action: EdlAction) -> anyhow::Result<()> {
+    match action {
+        EdlAction::Import { args, fps } => interchange_import::exit_with(run_import(&args, fps)),
+    }
+}
+
+/// An EDL is a cut list; a sane one is well under this.
+const MAX_EDL_BYTES: u64 = 16 * 1024 * 1024;
+
+fn run_import(args: &ImportArgs, fps: Option<String>) -> anyhow::Result<i32> {
+    let document = super::otio::read_capped(&args.file, MAX_EDL_BYTES, "EDL")?;
+    let parsed = edl::parse_edl(&document).map_err(|error| anyhow::anyhow!(error))?;
+    let explicit_fps = fps
+        .as_deref()
+        .map(|text| {
+            parse_fps(text).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "'{text}' is not a frame rate; use e.g. 23.976, 25, 29.97 or 30000/1001"
+                )
+            })
+        })
+        .transpose()?;
+    let sequence_name = interchange_import::sequence_name(
+        args.name.clone(),
+        parsed.title.clone(),
+        &args.file,
+        "EDL",
+    );
+
+    interchange_import::run(args, &args.file, "EDL", &sequence_name, |target| {
+        let fps = import_fps(explicit_fps, &parsed, target.state);
+        let import = edl_to_plan_steps(
+            &parsed,
+            &EdlImportContext {
+                sequence_name: &sequence_name,
+                sequence_fps: fps.clone(),
+                assets: &target.state.assets,
+                project_root: target.project_root,
+                allow_external_media: args.allow_external_media,
+            },
+        )
+        .map_err(|error| anyhow::anyhow!(error))?;
+
+        Ok(PlannedImport {
+            fps,
+            steps: import.steps,
+            asset_imports: import.asset_imports,
+            warnings: import.warnings,
+            unsupported: import.unsupported,
+            report: serde_json::json!({
+                "recordStart": import.record_start,
+                "eventCount": parsed.events.len(),
+                "placedEvents": import.placed_events,
+                "unresolved": import.unresolved,
+            }),
+        })
+    })
+}
+
+/// The rate the EDL is read at when `--fps` is not given.
+///
+/// An EDL does not state its rate. The active sequence's is the best guess —
+/// the cut usually went out of this project — unless the EDL declares drop
+/// frame and that rate has none, in which case it is 29.97.
+fn import_fps(
+    explicit: Option<Ratio>,
+    parsed: &ParsedEdl,
+    state: &openreelio_core::project::ProjectState,
+) -> Ratio {
+    if let Some(fps) = explicit {
+        return fps;
+    }
+
+    match interchange_import::active_sequence_fps(state) {
+        Some(fps) if !parsed.drop_frame || is_drop_frame_rate(&fps) => fps,
+        _ if parsed.drop_frame => Ratio::new(30000, 1001),
+        _ => Ratio::new(24, 1),
+    }
+}
+
+/// Parses a frame rate as a ratio (`30000/1001`) or a decimal (`29.97`).
+///
+/// The NTSC decimals map to their exact 1001 ratios, because `29.97` is never
+/// really 2997/100 and drop-frame timecode only exists at 30000/1001.
+fn parse_fps(text: &str) -> Option<Ratio> {
+    let text = text.trim();
+    if let Some((num, den)) = text.split_once('/') {
+        let num = num.trim().parse::<i32>().ok()?;
+        let den = den.trim().parse::<i32>().ok()?;
+        return (num > 0 && den > 0).then(|| Ratio::new(num, den));
+    }
+
+    let value = text.parse::<f64>().ok()?;
+    if !value.is_finite() || value <= 0.0 || value > 1000.0 {
+        return None;
+    }
+    for (ntsc, num) in [
+        (23.976, 24000),
+        (29.97, 30000),
+        (47.952, 48000),
+        (59.94, 60000),
+    ] {
+        if (value - ntsc).abs() < 0.01 {
+            return Some(Ratio::new(num, 1001));
+        }
+    }
+    if (value - value.round()).abs() < 1e-9 {
+        return Some(Ratio::new(value.round() as i32, 1));
+    }
+    Some(Ratio::new((value * 1000.0).round() as i32, 1000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fps_maps_ntsc_decimals_to_exact_ratios() {
+        assert_eq!(parse_fps("29.97"), Some(Ratio::new(30000, 1001)));
+        assert_eq!(parse_fps("23.976"), Some(Ratio::new(24000, 1001)));
+        assert_eq!(parse_fps("25"), Some(Ratio::new(25, 1)));
+        assert_eq!(parse_fps("30000/1001"), Some(Ratio::new(30000, 1001)));
+        assert_eq!(parse_fps("0"), None);
+        assert_eq!(parse_fps("24/0"), None);
+        assert_eq!(parse_fps("fast"), None);
+    }
+
+    #[test]
+    fn a_drop_frame_edl_defaults_to_29_97_when_the_project_has_no_drop_frame_rate() {
+        let state = openreelio_core::project::ProjectState::new_empty("Test");
+        let drop_frame = ParsedEdl {
+            drop_frame: true,
+            ..ParsedEdl::default()
+        };
+
+        assert_eq!(
+            import_fps(None, &drop_frame, &state),
+            Ratio::new(30000, 1001)
+        );
+        assert_eq!(
+            import_fps(None, &ParsedEdl::default(), &state),
+            Ratio::new(24, 1)
+        );
+        assert_eq!(
+            import_fps(Some(Ratio::new(25, 1)), &drop_frame, &state),
+            Ratio::new(25, 1)
+        );
+    }
+}
@@ -0,0 +1,91 @@
+//! Final Cut Pro XML interchange: `fcpxml import`.
+//!
+//! `fcpxml import` reads the first project of an FCPXML document — from Final
+//! Cut Pro, DaVinci Resolve, or OpenReelio's own FCPXML export — into a **new
+//! sequence** created at the project's frame rate and frame size. Each lane
+//! becomes a track; media is relinked to project assets by path, then by file
+//! name, and imported only when neither matches. Everything the document
+//! carries that OpenReelio does not import is named in `warnings` and
+//! `unsupported`.
+//!
+//! The plan, dry run and report are shared with the other importers; see
+//! [`super::interchange_import`].
+
+use clap::Subcommand;
+use std::path::{Path, PathBuf};
+
+use openreelio_core::interchange::fcpxml_import::{
+    fcpxml_to_plan_steps, parse_fcpxml, FcpxmlImportContext,
+};
+
+use super::interchange_import::{self, ImportArgs, PlannedImport};
+
+#[derive(Subcommand)]
+pub enum FcpxmlAction {
+    /// Import an FCPXML project into a new sequence
+    ///
+    /// `--file` is an .fcpxml document or an .fcpxmld bundle.
+    Import {
+        #[command(flatten)]
+        args: ImportArgs,
+    },
+}
+
+pub fn execute(action: FcpxmlAction) -> anyhow::Result<()> {
+    match action {
+        FcpxmlAction::Import { args } => interchange_import::exit_with(run_import(&args)),
+    }
+}
+
+/// Same ceiling as an OTIO document: a cut list, not media.
+const MAX_FCPXML_BYTES: u64 = 64 * 1024 * 1024;
+
+fn run_import(args: &ImportArgs) -> anyhow::Result<i32> {
+    let file = document_path(&args.file);
+    let document = super::otio::read_capped(&file, MAX_FCPXML_BYTES, "FCPXML")?;
+    let parsed = parse_fcpxml(&document).map_err(|error| anyhow::anyhow!(error))?;
+    let sequence_name = interchange_import::sequence_name(
+        args.name.clone(),
+        parsed.project_name.clone(),
+        &file,
+        "FCPXML",
+    );
+
+    interchange_import::run(args, &file, "FCPXML", &sequence_name, |target| {
+        let fps = interchange_import::sequence_fps(parsed.fps.clone(), target.state);
+        let import = fcpxml_to_plan_steps(
+            &parsed,
+            &FcpxmlImportContext {
+                sequence_name: &sequence_name,
+                sequence_fps: fps.clone(),
+                assets: &target.state.assets,
+                project_root: target.project_root,
+                allow_external_media: args.allow_external_media,
+            },
+        )
+        .map_err(|error| anyhow::anyhow!(error))?;
+
+        Ok(PlannedImport {
+            fps,
+            steps: import.steps,
+            asset_imports: import.asset_imports,
+            warnings: import.warnings,
+            unsupported: import.unsupported,
+            report: serde_json::json!({
+                "canvas": { "width": import.canvas.0, "height": import.canvas.1 },
+                "clipCount": parsed.clips.len(),
+                "placedClips": import.placed_clips,
+            }),
+        })
+    })
+}
+
+/// Final Cut exports a bundle directory (`.fcpxmld`) whose document is
+/// `Info.fcpxml`; accept the bundle as well as the document.
+fn document_path(file: &Path) -> PathBuf {
+    if file.is_dir() {
+        file.join("Info.fcpxml")
+    } else {
+        file.to_path_buf()
+    }
+}
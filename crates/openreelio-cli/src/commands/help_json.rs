@@ -74,6 +74,17 @@ pub(crate) fn build_schema() -> serde_json::Value {
                 },
                 "example": "openreelio-cli project info --path ./project"
             },
+            "project.update": {
+                "description": "Update project settings; renders embed them as title/artist/description/copyright tags. At least one field is required. Prints the resulting settings.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "name": { "type": "string", "required": false, "desc": "New project name (exported as the title)" },
+                    "description": { "type": "string", "required": false, "desc": "Project description" },
+                    "author": { "type": "string", "required": false, "desc": "Author name (exported as the artist)" },
+                    "copyright": { "type": "string", "required": false, "desc": "Copyright notice" }
+                },
+                "example": "openreelio-cli project update --path ./project --author \"Jane Doe\" --copyright \"(c) 2026 Jane Doe\""
+            },
             "project.save": {
                 "description": "Save the project state (snapshot + metadata)",
                 "params": {
@@ -373,6 +384,40 @@ pub(crate) fn build_schema() -> serde_json::Value {
                 },
                 "example": "openreelio-cli otio import --path ./project --file cut.otio --dry-run"
             },
+            "edl.import": {
+                "description": "Import a CMX 3600 EDL (.edl) into a NEW sequence created at the EDL's frame rate with one track per channel assignment (V, A, A2, AA...). Like 'otio import' the file becomes an edit plan run through the 'plan execute' machinery: one atomic, undoable unit with the same 0/1/2 exit codes. An EDL names media only by reel, so each event is matched to an asset by '* SOURCE FILE' path, then '* FROM CLIP NAME', then reel name (file stem or the 8-character reel 'edl export' writes); a '* SOURCE FILE' that matches nothing is imported first and reported in 'assetImports'. Events that match no asset, match several, or read past the end of their media are listed in 'unresolved' with the reason instead of being placed. Dissolves and wipes become an AddEffect on the outgoing clip, M2 rates become SetClipSpeed (negative rates reverse). Record timecode is measured from the whole hour at or before the first event ('recordStart'), and drop-frame timecode is honoured. Reported but not fatal: key events (imported as cuts), freeze frames, fades to or from black, SMPTE wipe patterns other than 001/002, overlapping events on one track, and source/record duration mismatches.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "file": { "type": "string", "required": true, "desc": "EDL file to read (max 16 MiB)" },
+                    "name": { "type": "string", "required": false, "desc": "Name of the new sequence (defaults to the EDL's TITLE, then the file name)" },
+                    "fps": { "type": "string", "required": false, "desc": "Frame rate the EDL's timecode counts at: 23.976, 24, 25, 29.97, 30, 59.94, 60 or a ratio like 30000/1001. Defaults to the active sequence's rate, or 29.97 when the EDL declares DROP FRAME and that rate is not a drop-frame rate" },
+                    "dry-run": { "type": "boolean", "required": false, "desc": "Print the plan, unresolved events and warnings, and stop without touching the project" },
+                    "allow-external-media": { "type": "boolean", "required": false, "desc": "Import '* SOURCE FILE' media from outside the project directory. Off by default: an EDL chooses its own media paths" }
+                },
+                "example": "openreelio-cli edl import --path ./project --file conform.edl --fps 23.976 --dry-run"
+            },
+            "fcpxml.import": {
+                "description": "Import the first project of an FCPXML document (Final Cut Pro, DaVinci Resolve, or OpenReelio's own FCPXML export) into a NEW sequence created at the project's frame rate and frame size (1920x1080, 3840x2160 or 1080x1920; other sizes fall back to 1920x1080 and are reported). Like 'otio import' the document becomes an edit plan run through the 'plan execute' machinery: one atomic, undoable unit with the same 0/1/2 exit codes. The spine, connected clips on lanes, clips connected to gaps, secondary storylines and the active pick of an audition are resolved to sequence time through each element's offset/start; each lane becomes a track (V1.. bottom-up, A1.. for audio-only lanes). Media is relinked by path, then by file name, and otherwise imported subject to the same project-directory scoping as OTIO import. Transitions become an AddEffect on the outgoing clip, marker/chapter-marker become sequence markers (chapter and to-do types kept), and a static adjust-transform becomes SetClipTransform. Reported but not fatal: compound, synchronized and multicam clips, titles, generators, filters, retimes, other adjustments, keyframed transforms, disabled clips, overlapping clips on one lane, and unmatched media.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "file": { "type": "string", "required": true, "desc": "FCPXML file to read (max 64 MiB); an .fcpxmld bundle directory reads its Info.fcpxml" },
+                    "name": { "type": "string", "required": false, "desc": "Name of the new sequence (defaults to the FCPXML project's name, then the file name)" },
+                    "dry-run": { "type": "boolean", "required": false, "desc": "Print the plan, warnings and asset imports, and stop without touching the project" },
+                    "allow-external-media": { "type": "boolean", "required": false, "desc": "Import media the document references from outside the project directory. Off by default: an FCPXML chooses its own media paths" }
+                },
+                "example": "openreelio-cli fcpxml import --path ./project --file cut.fcpxml --dry-run"
+            },
+            "xmeml.import": {
+                "description": "Import the first sequence of a Final Cut Pro 7 XML (xmeml) document — what Premiere Pro and DaVinci Resolve export as 'Final Cut Pro XML', or OpenReelio's own XMEML export — into a NEW sequence created at the document's frame rate and frame size (1920x1080, 3840x2160 or 1080x1920; other sizes fall back to 1920x1080 and are reported). Like 'otio import' the document becomes an edit plan run through the 'plan execute' machinery: one atomic, undoable unit with the same 0/1/2 exit codes. Every video and audio track becomes a track of its kind (video tracks keep their stacking). Clip items keep their source in/out, timeline start/end and enabled state; a constant-speed Time Remap becomes SetClipSpeed, Opacity and Audio Levels filters become the clip's opacity (an opacity effect when keyframed) and volume (audio keyframes when keyframed), and sequence and clip markers become sequence markers. A video clip item whose media has sound is imported muted, because its sound arrives through the linked audio clip item. Media is relinked by path, then by file name, and otherwise imported subject to the same project-directory scoping as OTIO import. Transitions become an AddEffect on the outgoing clip. Reported but not fatal: nested sequences, generators, other filters, variable speed, fades to or from black, disabled tracks, overlapping clips on one track, and unmatched media.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "file": { "type": "string", "required": true, "desc": "XMEML file to read (max 64 MiB)" },
+                    "name": { "type": "string", "required": false, "desc": "Name of the new sequence (defaults to the XMEML sequence's name, then the file name)" },
+                    "dry-run": { "type": "boolean", "required": false, "desc": "Print the plan, warnings and asset imports, and stop without touching the project" },
+                    "allow-external-media": { "type": "boolean", "required": false, "desc": "Import media the document references from outside the project directory. Off by default: an XMEML chooses its own media paths" }
+                },
+                "example": "openreelio-cli xmeml import --path ./project --file premiere_cut.xml --dry-run"
+            },
             "packs.list": {
                 "description": "List curated caption style packs, transition recipes, text presets, and pacing profiles. Packs are the quality floor: name one instead of assembling typography, a transition duration, or a cutting rhythm by hand. Every listed id is accepted by caption --style-pack, by stylePack on CreateCaption/UpdateCaption/ImportGeneratedCaptions, by recipe on AddEffect, by text add --preset / preset on AddTextClip, and by plan from-profile --profile",
                 "params": {
@@ -712,9 +757,101 @@ pub(crate) fn build_schema() -> serde_json::Value {
                     "sequence": { "type": "string", "required": false, "desc": "Sequence ID" },
                     "start": { "type": "number", "required": false, "desc": "Start of the rendered range in timeline seconds (default: 0)" },
                     "end": { "type": "number", "required": false, "desc": "End of the rendered range in timeline seconds (default: sequence duration); must be greater than --start" },
-                    "progress": { "type": "boolean", "required": false, "desc": "Stream NDJSON encode progress to stderr as {\"type\":\"progress\",\"percent\":..,\"frame\":..,\"totalFrames\":..,\"fps\":..,\"etaSeconds\":..}" }
+                    "progress": { "type": "boolean", "required": false, "desc": "Stream NDJSON encode progress to stderr as {\"type\":\"progress\",\"percent\":..,\"frame\":..,\"totalFrames\":..,\"fps\":..,\"etaSeconds\":..}" },
+                    "workers": { "type": "number", "required": false, "desc": "Render the range as segments encoded by this many local FFmpeg processes (max 32), then stitch them. Segments are cached per export profile under .openreelio/cache/renders, so re-exports only encode changed segments. The result gains 'segments': {count, cached, remote}. Not available for MXF or stream-copy exports." },
+                    "remote-worker": { "type": "array", "required": false, "desc": "host:port of a render.worker sharing the segments; repeatable. The worker must see the project at the same path. Local workers default to one per core when only remote workers are given." },
+                    "segment-duration": { "type": "number", "required": false, "desc": "Length of one distributed segment in seconds, at least 2 (default: 10)" },
+                    "cover": { "type": "string", "required": false, "desc": "PNG or JPEG image embedded as cover art; MP4, MOV and MKV only" },
+                    "no-metadata": { "type": "boolean", "required": false, "desc": "Skip the project tags and chapters. By default the project name, author, description and copyright are written as title/artist/description/copyright tags, chapter markers become chapters (MP4/MOV/MKV/WebM), and the result gains 'chapters' and 'chapterListPath', a YouTube-style '0:00 Title' list written beside the output. Warnings flag lists YouTube would reject (fewer than 3 chapters, or chapters under 10s)." },
+                    "loudness": { "type": "string", "required": false, "desc": "Normalize the mix to a delivery spec: streaming (-14 LUFS, -1 dBTP), ebu_r128 (-23 LUFS, -1 dBTP) or atsc_a85 (-24 LKFS, -2 dBTP). The rendered range is measured with a first loudnorm pass and corrected by one linear gain in the encode; the result gains 'loudness': {targetLufs, truePeakCeilingDbtp, measuredLufs, measuredTruePeakDbtp, measuredLraLu, outputLufs, outputTruePeakDbtp, outputLraLu, normalizationType}. normalizationType 'dynamic' means the ceiling could not be met linearly. Fails on a silent sequence or with copied audio." },
+                    "target-lufs": { "type": "number", "required": false, "desc": "Integrated loudness target in LUFS (-70 to -5), replacing the spec's; requires --loudness" },
+                    "true-peak": { "type": "number", "required": false, "desc": "True-peak ceiling in dBTP (-9 to 0), replacing the spec's; requires --loudness" }
+                },
+                "example": "openreelio-cli render start --path ./project --workers 8 --progress --output final.mp4"
+            },
+            "render.worker": {
+                "description": "Serve segments for distributed renders (requires FFmpeg). Each connection carries one JSON-line segment request and gets one JSON-line reply; segments are rendered one at a time, only into the project's render cache, and cancelled when the coordinator disconnects. Logs {\"type\":\"listening\"} and {\"type\":\"segment\"} events to stderr; Ctrl-C stops it and prints {segmentsCompleted, segmentsFailed}.",
+                "params": {
+                    "listen": { "type": "string", "required": false, "desc": "Address to listen on (default: 127.0.0.1:7600); keep it on loopback unless the network is trusted" }
+                },
+                "example": "openreelio-cli render worker --listen 127.0.0.1:7601"
+            },
+            "render.queue.add": {
+                "description": "Append a render to the project's durable render queue (stored in .openreelio/render_queue.json). The preset, range and sequence are checked now; the output path is stored absolute. Prints the new item, including its 'id'.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "output": { "type": "string", "required": true, "desc": "Output file path; a relative path is resolved against the current directory" },
+                    "preset": { "type": "string", "required": false, "desc": "Render preset name (default: mp4_h264_1080p). Use render.presets for the supported list." },
+                    "sequence": { "type": "string", "required": false, "desc": "Sequence ID (defaults to active)" },
+                    "start": { "type": "number", "required": false, "desc": "Start of the rendered range in timeline seconds" },
+                    "end": { "type": "number", "required": false, "desc": "End of the rendered range in timeline seconds; must be greater than --start" },
+                    "max-attempts": { "type": "number", "required": false, "desc": "Attempts before a failure is final, 1-10 (default: 3)" }
+                },
+                "example": "openreelio-cli render queue add --path ./project --preset prores_422 --output master.mov"
+            },
+            "render.queue.list": {
+                "description": "List the render queue as {paused, items}; each item carries status (pending, paused, rendering, completed, failed, cancelled), attempts, maxAttempts and lastError",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" }
+                },
+                "example": "openreelio-cli render queue list --path ./project"
+            },
+            "render.queue.run": {
+                "description": "Render pending queue items in order until none are left (requires FFmpeg). Items a crashed run left 'rendering' are recovered first and listed under 'recovered'. A failed attempt is retried until the item's maxAttempts is spent. Cancels and pauses written by other processes are honoured between items, and a cancel also stops the running encode. Ctrl-C stops the run without spending an attempt and exits non-zero with status 'interrupted'; run again to resume.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "progress": { "type": "boolean", "required": false, "desc": "Stream NDJSON encode progress to stderr, as render.start does" }
+                },
+                "example": "openreelio-cli render queue run --path ./project --progress"
+            },
+            "render.queue.move": {
+                "description": "Move a queue item to a new position; prints the updated queue",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "id": { "type": "string", "required": true, "desc": "Queue item ID" },
+                    "position": { "type": "number", "required": true, "desc": "Zero-based target position, clamped to the end of the queue" }
+                },
+                "example": "openreelio-cli render queue move --path ./project --id 01J... --position 0"
+            },
+            "render.queue.pause": {
+                "description": "Pause one pending item, or the whole queue when --id is omitted. A paused queue makes render.queue.run stop after the current item.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "id": { "type": "string", "required": false, "desc": "Queue item ID" }
+                },
+                "example": "openreelio-cli render queue pause --path ./project"
+            },
+            "render.queue.resume": {
+                "description": "Resume one paused item, or the whole queue when --id is omitted",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "id": { "type": "string", "required": false, "desc": "Queue item ID" }
+                },
+                "example": "openreelio-cli render queue resume --path ./project"
+            },
+            "render.queue.cancel": {
+                "description": "Cancel a queue item that has not finished. A running render.queue.run stops the item's encode within about a second and removes the partial output.",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "id": { "type": "string", "required": true, "desc": "Queue item ID" }
+                },
+                "example": "openreelio-cli render queue cancel --path ./project --id 01J..."
+            },
+            "render.queue.retry": {
+                "description": "Return a failed or cancelled item to pending with its attempt count reset",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "id": { "type": "string", "required": true, "desc": "Queue item ID" }
+                },
+                "example": "openreelio-cli render queue retry --path ./project --id 01J..."
+            },
+            "render.queue.remove": {
+                "description": "Remove a queue item; a rendering item must be cancelled first",
+                "params": {
+                    "path": { "type": "string", "required": true, "desc": "Project directory path" },
+                    "id": { "type": "string", "required": true, "desc": "Queue item ID" }
                 },
-                "example": "openreelio-cli render start --path ./project --proxy --start 0 --end 5 --progress --output proxy.mp4"
+                "example": "openreelio-cli render queue remove --path ./project --id 01J..."
             },
             "ffmpeg.info": {
                 "description": "Resolve the FFmpeg/FFprobe binaries this CLI will use and report their version and source (explicit, env, bundled, managed, dev, or system)",
@@ -751,13 +888,19 @@ pub(crate) fn build_schema() -> serde_json::Value {
                     "sequence": { "type": "string", "required": false, "desc": "Sequence ID (defaults to active)" },
                     "file": { "type": "string", "required": false, "desc": "Rendered file to measure (black/freeze/silence detection, EBU R128 loudness, peaks). Without it only structural checks run and FFmpeg is never invoked. Measured times are file-relative and are compared against timeline times, so pass a full-sequence render rather than a partial one." },
                     "structural-only": { "type": "boolean", "required": false, "desc": "Run structural checks only and never touch FFmpeg; conflicts with --file" },
-                    "checks": { "type": "string", "required": false, "desc": "Comma-separated check IDs to run exclusively (asset.license and sequence.duration are opt-in and only run when named here): sequence.empty, timeline.gap, clip.orphan, clip.missing_asset, audio.silent_clip, caption.overlap, caption.reading_rate, caption.out_of_bounds, caption.safe_area, shot.length_stats, shot.cut_rhythm, clip.aspect_ratio, asset.license, sequence.duration, render.duration_mismatch, render.missing_video, render.resolution_mismatch, render.black_frames, render.frozen, audio.peak, audio.clipping, audio.loudness" },
+                    "checks": { "type": "string", "required": false, "desc": "Comma-separated check IDs to run exclusively (asset.license and sequence.duration are opt-in and only run when named here): sequence.empty, timeline.gap, clip.orphan, clip.missing_asset, audio.silent_clip, caption.overlap, caption.reading_rate, caption.out_of_bounds, caption.safe_area, shot.length_stats, shot.cut_rhythm, clip.aspect_ratio, asset.license, sequence.duration, render.duration_mismatch, render.missing_video, render.resolution_mismatch, render.av_sync, render.frame_timing, render.black_frames, render.frozen, render.flashing, render.broadcast_legal, audio.peak, audio.clipping, audio.loudness" },
                     "skip": { "type": "string", "required": false, "desc": "Comma-separated check IDs to disable" },
+                    "profile": { "type": "string", "required": false, "desc": "Delivery profile to apply: a built-in (broadcast, streaming, youtube, social_vertical), the name of a .toml/.json file in <project>/.openreelio/qc-profiles or the user config dir's openreelio/qc-profiles (searched in that order, before the built-ins), or a path to such a file. A profile enables or disables checks, overrides severities and sets thresholds; --checks, --skip and the threshold flags still override it. An invalid profile is a tool failure (exit 2) naming every problem." },
                     "target-lufs": { "type": "number", "required": false, "desc": "Integrated loudness target in LUFS (default -14). Negative values need the '=' form: --target-lufs=-14. Deviation over 1 LU warns, over 3 LU errors." },
                     "max-true-peak": { "type": "number", "required": false, "desc": "Maximum acceptable true peak in dBTP (default -1). Negative values need the '=' form: --max-true-peak=-1. Sample peak is used when the encoder reports no true peak." },
                     "duration-tolerance-sec": { "type": "number", "required": false, "desc": "Divergence tolerated between the rendered file and the sequence, in seconds (default: 0.5s, or two frames when that is longer). Honoured exactly, so a tighter value really is tighter." },
+                    "max-av-drift-sec": { "type": "number", "required": false, "desc": "Start offset or duration drift tolerated between the rendered audio and video streams for render.av_sync, in seconds (default: 0.04s, or one frame when that is longer). Honoured exactly." },
+                    "levels": { "type": "string", "required": false, "desc": "Legal video levels for render.broadcast_legal: nominal (default; luma 16-235, chroma 16-240) or ebu_r103 (luma 5-246, chroma 5-251, ignoring excursions under 1% of the picture)" },
                     "fail-on": { "type": "string", "required": false, "desc": "Lowest severity that exits 1: info, warning, error (default), critical" },
                     "timeout-sec": { "type": "number", "required": false, "desc": "Timeout for the rendered-file measurement pass in seconds (default: 600)" },
+                    "junit": { "type": "string", "required": false, "desc": "Also write the report as JUnit XML to this path: one testcase per check; findings at or above --fail-on are a <failure>, a check that errored is an <error>, a skipped check is <skipped>, and findings below the threshold go to <system-out>" },
+                    "html": { "type": "string", "required": false, "desc": "Also write a self-contained HTML report to this path, with a frame from the rendered file embedded for each finding's time range (up to 60; none without --file)" },
+                    "csv": { "type": "string", "required": false, "desc": "Also write the findings as CSV to this path: one row per finding (check_id, category, status, severity, start/end seconds and timecodes, message, entities, auto_fixable), plus one row per check that found nothing" },
                     "json-pretty": { "type": "boolean", "required": false, "desc": "Pretty-print the JSON output" }
                 },
                 "example": "openreelio-cli verify --path ./project --file proxy.mp4 --target-lufs=-14 --fail-on error"
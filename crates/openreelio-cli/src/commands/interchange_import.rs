@@ -0,0 +1,285 @@
+//! Scaffolding shared by `edl import`, `fcpxml import` and `xmeml import`.
+//!
+//! Each reads a file into a **new sequence**. Like `otio import`, the import is
+//! a plan: one atomic, undoable unit that rolls back on failure and reports
+//! through the `0` / `1` / `2` exit codes of `plan execute`. `--dry-run` prints
+//! the plan and stops.
+//!
+//! A format module only parses its file and turns it into plan steps; the
+//! arguments, project loading, plan execution and report live here.
+
+use clap::Args;
+use std::path::{Path, PathBuf};
+
+use openreelio_core::interchange::otio_import::OtioAssetImport;
+use openreelio_core::project::ProjectState;
+use openreelio_core::Ratio;
+
+use super::plan::{EditPlan, PlanStep, EXIT_TOOL_FAILURE};
+use crate::output;
+
+/// Arguments every interchange import takes.
+#[derive(Args)]
+pub struct ImportArgs {
+    /// Project directory path
+    #[arg(long)]
+    pub path: PathBuf,
+
+    /// File to read
+    #[arg(long)]
+    pub file: PathBuf,
+
+    /// Name of the new sequence (defaults to the name the file gives it, else
+    /// the file name)
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Print the plan the file proposes and stop without applying it
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Import media the file references from outside the project directory
+    #[arg(long)]
+    pub allow_external_media: bool,
+}
+
+/// The project an import plans against.
+pub(super) struct ImportTarget<'a> {
+    pub state: &'a ProjectState,
+    pub project_root: &'a Path,
+}
+
+/// What a format made of its file.
+pub(super) struct PlannedImport {
+    /// Rate the new sequence is created at.
+    pub fps: Ratio,
+    pub steps: Vec<serde_json::Value>,
+    pub asset_imports: Vec<OtioAssetImport>,
+    pub warnings: Vec<String>,
+    pub unsupported: Vec<String>,
+    /// Format-specific fields merged into the report: an object of counts and
+    /// the like.
+    pub report: serde_json::Value,
+}
+
+/// Turns an import's exit code into the process's.
+pub(super) fn exit_with(result: anyhow::Result<i32>) -> anyhow::Result<()> {
+    match result {
+        Ok(0) => Ok(()),
+        Ok(exit_code) => {
+            super::plan::flush_stdout();
+            std::process::exit(exit_code)
+        }
+        Err(error) => {
+            super::plan::flush_stdout();
+            eprintln!("error: {error}");
+            std::process::exit(EXIT_TOOL_FAILURE)
+        }
+    }
+}
+
+/// Plans an import against the project and, unless `--dry-run`, applies it.
+///
+/// `format` names the document kind in errors and the plan id ("EDL").
+pub(super) fn run(
+    args: &ImportArgs,
+    file: &Path,
+    format: &str,
+    sequence_name: &str,
+    plan: impl FnOnce(ImportTarget<'_>) -> anyhow::Result<PlannedImport>,
+) -> anyhow::Result<i32> {
+    if args.dry_run {
+        // As with `otio import --dry-run`, reading must not open an editing
+        // session, because opening one writes.
+        let (project_root, state) = super::load_project_state_read_only(&args.path)?;
+        let import = plan(ImportTarget {
+            state: &state,
+            project_root: &project_root,
+        })?;
+        let edit_plan = edit_plan(file, format, &import.steps)?;
+
+        let mut report = serde_json::json!({
+            "status": "ok",
+            "message": "Dry run: no changes were applied",
+            "dryRun": true,
+            "stepCount": edit_plan.steps.len(),
+            "plan": edit_plan,
+        });
+        if let Some(map) = report.as_object_mut() {
+            insert_report(map, sequence_name, import);
+        }
+        output::print_json(&report)?;
+        return Ok(0);
+    }
+
+    let mut project = super::load_project(&args.path)?;
+    let import = plan(ImportTarget {
+        state: &project.state,
+        project_root: &project.path,
+    })?;
+    let edit_plan = edit_plan(file, format, &import.steps)?;
+
+    let (mut result, exit_code) = super::plan::execute_plan_on_project(&mut project, &edit_plan)?;
+
+    let sequence_id = created_sequence_id(&result);
+    if let Some(map) = result.as_object_mut() {
+        map.insert("sequenceId".to_string(), serde_json::json!(sequence_id));
+        insert_report(map, sequence_name, import);
+    }
+
+    output::print_json(&result)?;
+    Ok(exit_code)
+}
+
+fn insert_report(
+    map: &mut serde_json::Map<String, serde_json::Value>,
+    sequence_name: &str,
+    import: PlannedImport,
+) {
+    let asset_imports: Vec<serde_json::Value> = import
+        .asset_imports
+        .iter()
+        .map(|asset| serde_json::json!({ "name": asset.name, "uri": asset.uri }))
+        .collect();
+
+    map.insert("sequenceName".to_string(), serde_json::json!(sequence_name));
+    map.insert("fps".to_string(), serde_json::json!(import.fps));
+    map.insert("assetImports".to_string(), serde_json::json!(asset_imports));
+    map.insert("warnings".to_string(), serde_json::json!(import.warnings));
+    map.insert(
+        "unsupported".to_string(),
+        serde_json::json!(import.unsupported),
+    );
+    if let serde_json::Value::Object(fields) = import.report {
+        map.extend(fields);
+    }
+}
+
+fn edit_plan(file: &Path, format: &str, steps: &[serde_json::Value]) -> anyhow::Result<EditPlan> {
+    let steps: Vec<PlanStep> = steps
+        .iter()
+        .map(|step| serde_json::from_value(step.clone()))
+        .collect::<Result<_, _>>()
+        .map_err(|error| {
+            anyhow::anyhow!("{format} import produced an unreadable step: {}", error)
+        })?;
+
+    Ok(EditPlan {
+        id: plan_id(file, format),
+        steps,
+    })
+}
+
+/// The id the plan's `CreateSequence` step produced, if it ran.
+fn created_sequence_id(result: &serde_json::Value) -> Option<String> {
+    result["stepResults"]
+        .as_array()?
+        .iter()
+        .find(|step| step["stepId"] == "sequence" && step["status"] == "ok")
+        .and_then(|step| step["createdIds"][0].as_str())
+        .map(str::to_string)
+}
+
+/// `--name`, else the name the document gives, else the file name.
+pub(super) fn sequence_name(
+    explicit: Option<String>,
+    document_name: Option<String>,
+    file: &Path,
+    format: &str,
+) -> String {
+    explicit
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+        .or(document_name)
+        .or_else(|| file_stem(file))
+        .unwrap_or_else(|| format!("{format} Import"))
+}
+
+/// The document's frame rate, else the active sequence's, else 24.
+pub(super) fn sequence_fps(document_fps: Option<Ratio>, state: &ProjectState) -> Ratio {
+    document_fps
+        .or_else(|| active_sequence_fps(state))
+        .unwrap_or_else(|| Ratio::new(24, 1))
+}
+
+pub(super) fn active_sequence_fps(state: &ProjectState) -> Option<Ratio> {
+    state
+        .active_sequence_id
+        .as_ref()
+        .and_then(|id| state.sequences.get(id))
+        .map(|sequence| sequence.format.fps.clone())
+}
+
+/// Derives a stable plan id from the file being imported.
+fn plan_id(file: &Path, format: &str) -> String {
+    let format = format.to_lowercase();
+    let stem = file_stem(file).unwrap_or_else(|| format.clone());
+    format!("{format}_import_{stem}")
+}
+
+fn file_stem(file: &Path) -> Option<String> {
+    file.file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .filter(|stem| !stem.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_name_prefers_the_flag_then_the_document_then_the_file() {
+        let file = Path::new("/tmp/export.xml");
+        let named = || Some("Rough Cut".to_string());
+
+        assert_eq!(
+            sequence_name(Some("Mine".to_string()), named(), file, "XMEML"),
+            "Mine"
+        );
+        assert_eq!(
+            sequence_name(Some("  ".to_string()), named(), file, "XMEML"),
+            "Rough Cut"
+        );
+        assert_eq!(sequence_name(None, None, file, "XMEML"), "export");
+        assert_eq!(
+            sequence_name(None, None, Path::new("/"), "XMEML"),
+            "XMEML Import"
+        );
+    }
+
+    #[test]
+    fn sequence_fps_prefers_the_documents_rate() {
+        let state = ProjectState::new_empty("Test");
+
+        assert_eq!(
+            sequence_fps(Some(Ratio::new(25, 1)), &state),
+            Ratio::new(25, 1)
+        );
+        assert_eq!(sequence_fps(None, &state), Ratio::new(24, 1));
+    }
+
+    #[test]
+    fn plan_id_names_the_format_and_the_file() {
+        assert_eq!(
+            plan_id(Path::new("/tmp/cut_v3.edl"), "EDL"),
+            "edl_import_cut_v3"
+        );
+        assert_eq!(plan_id(Path::new("/"), "FCPXML"), "fcpxml_import_fcpxml");
+    }
+
+    #[test]
+    fn created_sequence_id_reads_the_sequence_steps_result() {
+        let result = serde_json::json!({
+            "stepResults": [
+                { "stepId": "asset_0", "status": "ok", "createdIds": ["asset_a"] },
+                { "stepId": "sequence", "status": "ok", "createdIds": ["seq_1"] },
+            ]
+        });
+        let failed = serde_json::json!({
+            "stepResults": [{ "stepId": "sequence", "status": "failed" }]
+        });
+
+        assert_eq!(created_sequence_id(&result).as_deref(), Some("seq_1"));
+        assert_eq!(created_sequence_id(&failed), None);
+    }
+}
@@ -2052,12 +2052,18 @@ fn run_verify_tool(state: &McpServerState, arguments: Value) -> Result<Value, To
         structural_only,
         checks: optional_string_array_argument(&arguments, "checks")?,
         skip: optional_string_array_argument(&arguments, "skip")?,
+        profile: None,
         target_lufs: None,
         max_true_peak: None,
         duration_tolerance_sec: None,
+        max_av_drift_sec: None,
+        levels: None,
         fail_on: optional_string_argument(&arguments, "failOn")?
             .unwrap_or_else(|| DEFAULT_VERIFY_FAIL_ON.to_string()),
         timeout_sec: VERIFY_MEASURE_TIMEOUT_SEC,
+        junit: None,
+        html: None,
+        csv: None,
         json_pretty: false,
     };
 
@@ -11,9 +11,12 @@ mod analysis;
 mod asset;
 mod caption;
 mod command;
+mod edl;
+mod fcpxml;
 mod ffmpeg;
 mod frame;
 mod help_json;
+mod interchange_import;
 mod mcp;
 mod otio;
 mod packs;
@@ -21,11 +24,15 @@ mod perception;
 mod plan;
 mod project;
 mod render;
+mod render_queue;
+mod render_worker;
 mod state;
 mod text;
 mod timeline;
 mod transcription;
 mod verify;
+mod verify_report;
+mod xmeml;
 
 use clap::{Parser, Subcommand};
 
@@ -84,6 +91,24 @@ pub enum Commands {
         action: otio::OtioAction,
     },
 
+    /// CMX 3600 EDL interchange (import)
+    Edl {
+        #[command(subcommand)]
+        action: edl::EdlAction,
+    },
+
+    /// Final Cut Pro XML interchange (import)
+    Fcpxml {
+        #[command(subcommand)]
+        action: fcpxml::FcpxmlAction,
+    },
+
+    /// Final Cut Pro 7 XML (XMEML) interchange, as Premiere and Resolve write it (import)
+    Xmeml {
+        #[command(subcommand)]
+        action: xmeml::XmemlAction,
+    },
+
     /// Curated caption style packs, transition recipes, and text presets
     Packs {
         #[command(subcommand)]
@@ -157,6 +182,9 @@ pub fn execute(cli: Cli) -> anyhow::Result<()> {
         Commands::Timeline { action } => timeline::execute(action),
         Commands::Caption { action } => caption::execute(action),
         Commands::Otio { action } => otio::execute(action),
+        Commands::Edl { action } => edl::execute(action),
+        Commands::Fcpxml { action } => fcpxml::execute(action),
+        Commands::Xmeml { action } => xmeml::execute(action),
         Commands::Packs { action } => packs::execute(action),
         Commands::Transcription { action } => transcription::execute(action),
         Commands::Text { action } => text::execute(action),
@@ -275,7 +275,7 @@ const MAX_OTIO_BYTES: u64 = 64 * 1024 * 1024;
 
 /// Reads an OTIO document, refusing one larger than [`MAX_OTIO_BYTES`].
 fn read_otio_document(file: &std::path::Path) -> anyhow::Result<String> {
-    read_capped(file, MAX_OTIO_BYTES)
+    read_capped(file, MAX_OTIO_BYTES, "OTIO")
 }
 
 /// Reads a whole file, refusing one larger than `max_bytes`.
@@ -285,11 +285,21 @@ fn read_otio_document(file: &std::path::Path) -> anyhow::Result<String> {
 /// pipe or a device node, so a `metadata` guard in front of `read_to_string`
 /// leaves the unbounded read reachable — which is the only part that matters,
 /// because it is the one that allocates.
-fn read_capped(file: &std::path::Path, max_bytes: u64) -> anyhow::Result<String> {
+///
+/// `format` names the document kind in errors ("OTIO", "EDL").
+pub(super) fn read_capped(
+    file: &std::path::Path,
+    max_bytes: u64,
+    format: &str,
+) -> anyhow::Result<String> {
     use std::io::Read;
 
     let handle = std::fs::File::open(file).map_err(|error| {
-        anyhow::anyhow!("Failed to read OTIO file '{}': {}", file.display(), error)
+        anyhow::anyhow!(
+            "Failed to read {format} file '{}': {}",
+            file.display(),
+            error
+        )
     })?;
 
     let mut buffer = Vec::new();
@@ -297,12 +307,16 @@ fn read_capped(file: &std::path::Path, max_bytes: u64) -> anyhow::Result<String>
         .take(max_bytes.saturating_add(1))
         .read_to_end(&mut buffer)
         .map_err(|error| {
-            anyhow::anyhow!("Failed to read OTIO file '{}': {}", file.display(), error)
+            anyhow::anyhow!(
+                "Failed to read {format} file '{}': {}",
+                file.display(),
+                error
+            )
         })?;
 
     if buffer.len() as u64 > max_bytes {
         anyhow::bail!(
-            "OTIO file '{}' is larger than the {} MiB limit",
+            "{format} file '{}' is larger than the {} MiB limit",
             file.display(),
             max_bytes / (1024 * 1024)
         );
@@ -310,7 +324,7 @@ fn read_capped(file: &std::path::Path, max_bytes: u64) -> anyhow::Result<String>
 
     String::from_utf8(buffer).map_err(|_| {
         anyhow::anyhow!(
-            "OTIO file '{}' is not valid UTF-8; OTIO documents are JSON text",
+            "{format} file '{}' is not valid UTF-8; {format} documents are text",
             file.display()
         )
     })
@@ -358,7 +372,7 @@ mod tests {
         // — the part that allocates — reachable.
         let (_dir, file) = write_temp("big.otio", &[b'x'; 64]);
 
-        let error = read_capped(&file, 16).expect_err("an oversized file must be refused");
+        let error = read_capped(&file, 16, "OTIO").expect_err("an oversized file must be refused");
 
         assert!(
             error.to_string().contains("larger than"),
@@ -370,7 +384,7 @@ mod tests {
     fn should_read_a_document_that_exactly_fills_the_cap() {
         let (_dir, file) = write_temp("exact.otio", b"0123456789abcdef");
 
-        let document = read_capped(&file, 16).expect("a file at the cap should read");
+        let document = read_capped(&file, 16, "OTIO").expect("a file at the cap should read");
 
         assert_eq!(document, "0123456789abcdef");
     }
@@ -379,7 +393,7 @@ mod tests {
     fn should_refuse_a_document_that_is_not_utf8() {
         let (_dir, file) = write_temp("binary.otio", &[0xff, 0xfe, 0x00]);
 
-        let error = read_capped(&file, 16).expect_err("binary is not an OTIO document");
+        let error = read_capped(&file, 16, "OTIO").expect_err("binary is not an OTIO document");
 
         assert!(error.to_string().contains("UTF-8"), "got: {error}");
     }
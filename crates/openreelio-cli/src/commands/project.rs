@@ -1,7 +1,8 @@
-//! Project lifecycle commands: create, open, info, save.
+//! Project lifecycle commands: create, open, info, update, save.
 
 use crate::output;
 use clap::Subcommand;
+use openreelio_core::commands::UpdateProjectSettingsCommand;
 use openreelio_core::ActiveProject;
 use std::path::PathBuf;
 
@@ -32,6 +33,29 @@ pub enum ProjectAction {
         path: PathBuf,
     },
 
+    /// Update project settings; exports embed them as metadata tags
+    Update {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// New project name (exported as the title)
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Project description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Author name (exported as the artist)
+        #[arg(long)]
+        author: Option<String>,
+
+        /// Copyright notice
+        #[arg(long)]
+        copyright: Option<String>,
+    },
+
     /// Save the current project state
     Save {
         /// Project directory path
@@ -110,6 +134,36 @@ pub fn execute(action: ProjectAction) -> anyhow::Result<()> {
             }))
         }
 
+        ProjectAction::Update {
+            path,
+            name,
+            description,
+            author,
+            copyright,
+        } => {
+            let mut project = super::load_project(&path)?;
+            let mut cmd = UpdateProjectSettingsCommand::new();
+            cmd.name = name;
+            cmd.description = description;
+            cmd.author = author;
+            cmd.copyright = copyright;
+            let result = project
+                .executor
+                .execute(Box::new(cmd), &mut project.state)
+                .map_err(|e| anyhow::anyhow!("Update failed: {}", e))?;
+            super::save_project(&mut project)?;
+
+            let meta = &project.state.meta;
+            output::print_json(&serde_json::json!({
+                "status": "ok",
+                "opId": result.op_id,
+                "name": meta.name,
+                "description": meta.description,
+                "author": meta.author,
+                "copyright": meta.copyright,
+            }))
+        }
+
         ProjectAction::Save { path } => {
             let mut project = super::load_project(&path)?;
             super::save_project(&mut project)?;
@@ -4,12 +4,21 @@ use crate::ffmpeg_env::ensure_ffmpeg;
 use crate::output;
 use crate::validate;
 use clap::Subcommand;
-use openreelio_core::ffmpeg::FFmpegRunner;
+use openreelio_core::assets::Asset;
+use openreelio_core::effects::Effect;
+use openreelio_core::ffmpeg::{FFmpegInfo, FFmpegRunner};
+use openreelio_core::project::ProjectState;
+use openreelio_core::render::distributed::check_distributable;
+use openreelio_core::render::metadata::chapter_list_path;
 use openreelio_core::render::{
-    build_render_graph, build_render_plan, validate_export_settings, AudioCodec, ExportEngine,
-    ExportPreset, ExportProgress, ExportSettings, HdrMode, VideoCodec,
+    build_render_graph, build_render_plan, export_chapter_spans, sequence_cache_dir,
+    validate_export_settings, AudioCodec, DistributedRenderSettings, ExportEngine, ExportError,
+    ExportMetadata, ExportPreset, ExportProgress, ExportResult, ExportSettings, HdrMode,
+    LoudnessNormalization, LoudnessStandard, RenderGraph, RenderPlan, VideoCodec,
+    DEFAULT_DISTRIBUTED_SEGMENT_DURATION_SEC,
 };
-use openreelio_core::timeline::Canvas;
+use openreelio_core::timeline::{Canvas, Sequence};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 /// Canonical list of render presets. Single source of truth for both
@@ -21,12 +30,17 @@ const RENDER_PRESETS: &[(&str, &str, &str)] = &[
     ("mp4_draft", "MP4 H.264 720p Draft", "mp4"),
     ("proxy_480p", "Proxy 480p (fast, agent inspection)", "mp4"),
     ("webm_vp9_1080p", "WebM VP9 1080p", "webm"),
-    ("prores_422", "ProRes 422", "mov"),
+    ("webm_av1_1080p", "WebM AV1 1080p", "webm"),
+    ("prores_422", "ProRes 422 HQ", "mov"),
+    ("dnxhr_hqx", "DNxHR HQX (MXF)", "mxf"),
 ];
 
 /// Preset identifier selected by the `--proxy` shorthand.
 const PROXY_PRESET_ID: &str = "proxy_480p";
 
+/// How often a running render checks whether it has been asked to stop.
+const STOP_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
 /// Progress channel depth. Bounded so a slow stderr consumer applies
 /// backpressure to the progress reader instead of growing without limit.
 const PROGRESS_CHANNEL_CAPACITY: usize = 32;
@@ -80,6 +94,58 @@ pub enum RenderAction {
         /// Stream NDJSON encode progress to stderr
         #[arg(long)]
         progress: bool,
+
+        /// Split the render into segments encoded by this many local FFmpeg
+        /// processes (defaults to one per core when --remote-worker is given)
+        #[arg(long)]
+        workers: Option<usize>,
+
+        /// Address (host:port) of a `render worker` sharing the segments; repeatable
+        #[arg(long = "remote-worker")]
+        remote_workers: Vec<String>,
+
+        /// Length of one distributed segment in seconds
+        #[arg(long, default_value_t = DEFAULT_DISTRIBUTED_SEGMENT_DURATION_SEC)]
+        segment_duration: f64,
+
+        /// PNG or JPEG image embedded as cover art (MP4, MOV and MKV)
+        #[arg(long)]
+        cover: Option<PathBuf>,
+
+        /// Write no project tags, chapters or chapter list
+        #[arg(long, conflicts_with = "cover")]
+        no_metadata: bool,
+
+        /// Normalize the mix to a delivery loudness spec in two passes:
+        /// streaming (-14 LUFS), ebu_r128 (-23 LUFS) or atsc_a85 (-24 LKFS)
+        #[arg(long)]
+        loudness: Option<String>,
+
+        /// Integrated loudness target in LUFS, replacing the spec's
+        #[arg(long, requires = "loudness", allow_hyphen_values = true)]
+        target_lufs: Option<f64>,
+
+        /// True-peak ceiling in dBTP, replacing the spec's
+        #[arg(long, requires = "loudness", allow_hyphen_values = true)]
+        true_peak: Option<f64>,
+    },
+
+    /// Serve segments of distributed renders started elsewhere (requires FFmpeg)
+    Worker {
+        /// Address to listen on; keep it on loopback unless the network is trusted
+        #[arg(long, default_value = "127.0.0.1:7600")]
+        listen: String,
+
+        /// Project directory this worker renders segments of; repeatable.
+        /// Requests naming any other project are refused
+        #[arg(long = "project", required = true)]
+        projects: Vec<PathBuf>,
+    },
+
+    /// Manage the project's durable render queue
+    Queue {
+        #[command(subcommand)]
+        action: super::render_queue::QueueAction,
     },
 }
 
@@ -113,6 +179,14 @@ pub fn execute(action: RenderAction) -> anyhow::Result<()> {
             start,
             end,
             progress,
+            workers,
+            remote_workers,
+            segment_duration,
+            cover,
+            no_metadata,
+            loudness,
+            target_lufs,
+            true_peak,
         } => start_render(StartArgs {
             path,
             output_path,
@@ -122,7 +196,21 @@ pub fn execute(action: RenderAction) -> anyhow::Result<()> {
             start,
             end,
             progress,
+            workers,
+            remote_workers,
+            segment_duration,
+            cover,
+            no_metadata,
+            loudness,
+            target_lufs,
+            true_peak,
         }),
+
+        RenderAction::Worker { listen, projects } => {
+            super::render_worker::serve(&listen, &projects)
+        }
+
+        RenderAction::Queue { action } => super::render_queue::execute(action),
     }
 }
 
@@ -139,6 +227,14 @@ struct StartArgs {
     start: Option<f64>,
     end: Option<f64>,
     progress: bool,
+    workers: Option<usize>,
+    remote_workers: Vec<String>,
+    segment_duration: f64,
+    cover: Option<PathBuf>,
+    no_metadata: bool,
+    loudness: Option<String>,
+    target_lufs: Option<f64>,
+    true_peak: Option<f64>,
 }
 
 fn start_render(args: StartArgs) -> anyhow::Result<()> {
@@ -151,6 +247,14 @@ fn start_render(args: StartArgs) -> anyhow::Result<()> {
         start,
         end,
         progress,
+        workers,
+        remote_workers,
+        segment_duration,
+        cover,
+        no_metadata,
+        loudness,
+        target_lufs,
+        true_peak,
     } = args;
 
     validate_render_range(start, end)?;
@@ -168,17 +272,128 @@ fn start_render(args: StartArgs) -> anyhow::Result<()> {
 
     let project = super::load_project(&path)?;
     let seq_id = super::resolve_sequence_id(&project, sequence)?;
-    let sequence = project
+    let canvas = project
         .state
         .sequences
         .get(&seq_id)
         .ok_or_else(|| anyhow::anyhow!("Sequence '{}' not found", seq_id))?
+        .format
+        .canvas
+        .clone();
+    let mut settings = build_export_settings(&preset_id, output_path, &canvas, start, end)?;
+    if !no_metadata {
+        let mut metadata = ExportMetadata::from_project(&project.state.meta);
+        if let Some(cover) = cover {
+            metadata =
+                metadata.with_cover_image(std::fs::canonicalize(&cover).map_err(|error| {
+                    anyhow::anyhow!("Cover image '{}' not found: {}", cover.display(), error)
+                })?);
+        }
+        settings.metadata = Some(metadata);
+    }
+    if let Some(loudness) = loudness {
+        let standard = LoudnessStandard::parse(&loudness).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Unknown loudness spec '{}'. Supported: streaming, ebu_r128, atsc_a85",
+                loudness
+            )
+        })?;
+        let mut target = LoudnessNormalization::for_standard(standard);
+        if let Some(target_lufs) = target_lufs {
+            target = target.with_integrated_lufs(target_lufs);
+        }
+        if let Some(true_peak) = true_peak {
+            target = target.with_true_peak(true_peak);
+        }
+        settings.loudness = Some(target);
+    }
+    let distributed = if workers.is_some() || !remote_workers.is_empty() {
+        let mut distributed = DistributedRenderSettings::new(
+            workers.unwrap_or_else(DistributedRenderSettings::default_local_workers),
+        );
+        distributed.remote_workers = remote_workers;
+        distributed.project_path = Some(project.path.clone());
+        distributed.segment_duration_sec = segment_duration;
+        distributed.cache_dir = Some(
+            sequence_cache_dir(&project.path, &seq_id).map_err(|error| anyhow::anyhow!(error))?,
+        );
+        distributed
+            .validate()
+            .and_then(|()| check_distributable(&settings))
+            .map_err(|error| anyhow::anyhow!(error))?;
+        Some(distributed)
+    } else {
+        None
+    };
+    let prepared = prepare_render(&project.state, &seq_id, settings)?;
+    let plan_hash = prepared.render_plan.plan_hash.clone();
+    let warnings = prepared.warnings.clone();
+    let chapters = export_chapter_spans(&prepared.sequence, &prepared.settings);
+
+    let outcome = match distributed {
+        Some(distributed) => run_distributed_render(prepared, progress, distributed)?,
+        None => run_render(prepared, progress, None)?,
+    };
+    let segments = outcome.segments;
+    let result = outcome.result.map_err(|error| match error {
+        ExportError::Cancelled => {
+            anyhow::anyhow!("Render cancelled; the partial output file was removed")
+        }
+        other => anyhow::anyhow!(other),
+    })?;
+
+    let mut response = serde_json::json!({
+        "status": "ok",
+        "sequenceId": seq_id,
+        "preset": preset_id,
+        "outputPath": result.output_path.display().to_string(),
+        "durationSec": result.duration_sec,
+        "fileSize": result.file_size,
+        "encodingTimeSec": result.encoding_time_sec,
+        "planHash": plan_hash,
+        "warnings": warnings,
+    });
+    if let Some(segments) = segments {
+        response["segments"] = serde_json::to_value(segments)?;
+    }
+    if let Some(loudness) = &result.loudness {
+        response["loudness"] = serde_json::to_value(loudness)?;
+    }
+    if !chapters.is_empty() {
+        response["chapters"] = serde_json::to_value(&chapters)?;
+        response["chapterListPath"] =
+            serde_json::json!(chapter_list_path(&result.output_path).display().to_string());
+    }
+    output::print_json_pretty(&response)
+}
+
+/// A sequence render that passed validation and is ready for FFmpeg.
+pub(super) struct PreparedRender {
+    sequence: Sequence,
+    assets: HashMap<String, Asset>,
+    effects: HashMap<String, Effect>,
+    settings: ExportSettings,
+    graph: RenderGraph,
+    pub(super) render_plan: RenderPlan,
+    /// Non-fatal validation findings
+    pub(super) warnings: Vec<String>,
+    ffmpeg_info: FFmpegInfo,
+}
+
+/// Validates `settings` against a sequence and builds its render plan.
+pub(super) fn prepare_render(
+    state: &ProjectState,
+    seq_id: &str,
+    settings: ExportSettings,
+) -> anyhow::Result<PreparedRender> {
+    let sequence = state
+        .sequences
+        .get(seq_id)
+        .ok_or_else(|| anyhow::anyhow!("Sequence '{}' not found", seq_id))?
         .clone();
-    let assets = project.state.assets.clone();
-    let effects = project.state.effects.clone();
-    let settings =
-        build_export_settings(&preset_id, output_path, &sequence.format.canvas, start, end)?;
-    let graph = build_render_graph(&project.state, &seq_id)
+    let assets = state.assets.clone();
+    let effects = state.effects.clone();
+    let graph = build_render_graph(state, seq_id)
         .map_err(|error| anyhow::anyhow!("Failed to build render graph: {}", error))?;
 
     // Validation measures transformed clips with FFprobe, so the resolved
@@ -199,20 +414,95 @@ fn start_render(args: StartArgs) -> anyhow::Result<()> {
             render_plan.validation.errors.join("; ")
         ));
     }
-    let plan_hash = render_plan.plan_hash.clone();
+
+    Ok(PreparedRender {
+        sequence,
+        assets,
+        effects,
+        settings,
+        graph,
+        render_plan,
+        warnings: validation.warnings,
+        ffmpeg_info,
+    })
+}
+
+/// How a render started by [`run_render`] ended.
+pub(super) struct RenderOutcome {
+    pub(super) result: Result<ExportResult, ExportError>,
+    /// Whether Ctrl-C stopped the render, as opposed to `stop_requested`
+    pub(super) interrupted: bool,
+    /// Segment counts of a distributed render
+    pub(super) segments: Option<SegmentStats>,
+}
+
+/// How the segments of a distributed render were produced.
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct SegmentStats {
+    count: usize,
+    cached: usize,
+    remote: usize,
+}
+
+/// Encodes a prepared render, stopping early on Ctrl-C or once
+/// `stop_requested` (polled every [`STOP_POLL_INTERVAL`]) returns true.
+pub(super) fn run_render(
+    prepared: PreparedRender,
+    progress: bool,
+    stop_requested: Option<Box<dyn Fn() -> bool + Send>>,
+) -> anyhow::Result<RenderOutcome> {
+    drive_render(prepared, progress, stop_requested, None)
+}
+
+/// Encodes a prepared render as segments spread over `distributed` workers
+/// and stitches them, stopping early on Ctrl-C.
+fn run_distributed_render(
+    prepared: PreparedRender,
+    progress: bool,
+    distributed: DistributedRenderSettings,
+) -> anyhow::Result<RenderOutcome> {
+    drive_render(prepared, progress, None, Some(distributed))
+}
+
+fn drive_render(
+    prepared: PreparedRender,
+    progress: bool,
+    stop_requested: Option<Box<dyn Fn() -> bool + Send>>,
+    distributed: Option<DistributedRenderSettings>,
+) -> anyhow::Result<RenderOutcome> {
+    let PreparedRender {
+        sequence,
+        assets,
+        effects,
+        settings,
+        graph,
+        render_plan,
+        ffmpeg_info,
+        ..
+    } = prepared;
 
     let runtime = tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()
         .map_err(|error| anyhow::anyhow!("Failed to create Tokio runtime: {error}"))?;
-    let result = runtime.block_on(async move {
+    Ok(runtime.block_on(async move {
         let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel::<()>();
         // Ctrl-C must reach FFmpeg: without this the CLI would exit while the
         // child keeps encoding into a half-written output file.
         let signal_task = tokio::spawn(async move {
-            if tokio::signal::ctrl_c().await.is_ok() {
-                let _ = cancel_tx.send(());
-            }
+            let interrupted = match stop_requested {
+                Some(stop_requested) => tokio::select! {
+                    () = ctrl_c_pressed() => true,
+                    () = poll_until(stop_requested) => false,
+                },
+                None => {
+                    ctrl_c_pressed().await;
+                    true
+                }
+            };
+            let _ = cancel_tx.send(());
+            interrupted
         });
 
         let (progress_tx, progress_task) = if progress {
@@ -229,46 +519,78 @@ fn start_render(args: StartArgs) -> anyhow::Result<()> {
         };
 
         let engine = ExportEngine::new(FFmpegRunner::new(ffmpeg_info));
-        let export = engine
-            .export_sequence_with_effects_for_plan(
-                &sequence,
-                &assets,
-                &effects,
-                &settings,
-                &render_plan,
-                progress_tx,
-                Some(cancel_rx),
-            )
-            .await;
+        let (result, segments) = match distributed {
+            Some(distributed) => {
+                let result = engine
+                    .export_sequence_distributed(
+                        &sequence,
+                        &assets,
+                        &effects,
+                        &graph,
+                        &settings,
+                        &distributed,
+                        progress_tx,
+                        Some(cancel_rx),
+                    )
+                    .await;
+                match result {
+                    Ok(done) => (
+                        Ok(done.export),
+                        Some(SegmentStats {
+                            count: done.segment_count,
+                            cached: done.cached_segments,
+                            remote: done.remote_segments,
+                        }),
+                    ),
+                    Err(error) => (Err(error), None),
+                }
+            }
+            None => {
+                let result = engine
+                    .export_sequence_with_effects_for_plan(
+                        &sequence,
+                        &assets,
+                        &effects,
+                        &settings,
+                        &render_plan,
+                        progress_tx,
+                        Some(cancel_rx),
+                    )
+                    .await;
+                (result, None)
+            }
+        };
 
         signal_task.abort();
+        let interrupted = matches!(signal_task.await, Ok(true));
         if let Some(task) = progress_task {
             let _ = task.await;
         }
 
-        export
-    });
-
-    let result = result.map_err(|error| match error {
-        openreelio_core::render::ExportError::Cancelled => {
-            anyhow::anyhow!("Render cancelled; the partial output file was removed")
+        RenderOutcome {
+            result,
+            interrupted,
+            segments,
         }
-        other => anyhow::anyhow!(other),
-    })?;
-
-    output::print_json_pretty(&serde_json::json!({
-        "status": "ok",
-        "sequenceId": seq_id,
-        "preset": preset_id,
-        "outputPath": result.output_path.display().to_string(),
-        "durationSec": result.duration_sec,
-        "fileSize": result.file_size,
-        "encodingTimeSec": result.encoding_time_sec,
-        "planHash": plan_hash,
-        "warnings": validation.warnings,
     }))
 }
 
+/// Resolves on Ctrl-C; never resolves where the signal cannot be listened for.
+async fn ctrl_c_pressed() {
+    if tokio::signal::ctrl_c().await.is_err() {
+        std::future::pending::<()>().await;
+    }
+}
+
+async fn poll_until(check: Box<dyn Fn() -> bool + Send>) {
+    loop {
+        tokio::time::sleep(STOP_POLL_INTERVAL).await;
+        if check() {
+            return;
+        }
+    }
+}
+
 /// Write one NDJSON progress record to stderr.
 ///
 /// stdout stays reserved for the single result object, so progress goes to
@@ -287,7 +609,7 @@ fn emit_progress_line(update: &ExportProgress) {
 }
 
 /// Validates the optional `--start` / `--end` render range.
-fn validate_render_range(start: Option<f64>, end: Option<f64>) -> anyhow::Result<()> {
+pub(super) fn validate_render_range(start: Option<f64>, end: Option<f64>) -> anyhow::Result<()> {
     if let Some(start) = start {
         validate::time_non_negative(start, "start")?;
     }
@@ -312,7 +634,7 @@ fn default_extension(path: PathBuf, extension: &str) -> PathBuf {
 ///
 /// `canvas` is the sequence canvas: the proxy preset fits its frame to it so a
 /// vertical or square edit is not pillarboxed into a 16:9 proxy.
-fn build_export_settings(
+pub(super) fn build_export_settings(
     preset: &str,
     output_path: PathBuf,
     canvas: &Canvas,
@@ -349,6 +671,12 @@ fn build_export_settings(
             hardware_accel: Default::default(),
             resolved_encoder_name: None,
             encoder_speed: None,
+            audio_channel_layout: Default::default(),
+            audio_downmix: Default::default(),
+            prores_profile: Default::default(),
+            dnxhr_profile: Default::default(),
+            metadata: None,
+            loudness: None,
         },
         "mp4_draft" | "mp4_h264_720p" | "draft" => {
             ExportSettings::from_preset(ExportPreset::Mp4Draft, output_path)
@@ -357,7 +685,11 @@ fn build_export_settings(
         "webm_vp9_1080p" | "webm_vp9" | "webm" => {
             ExportSettings::from_preset(ExportPreset::WebmVp9, output_path)
         }
+        "webm_av1_1080p" | "webm_av1" | "av1" => {
+            ExportSettings::from_preset(ExportPreset::WebmAv1, output_path)
+        }
         "prores_422" | "prores" => ExportSettings::from_preset(ExportPreset::ProRes, output_path),
+        "dnxhr_hqx" | "dnxhr" => ExportSettings::from_preset(ExportPreset::DnxhrMxf, output_path),
         "prores_4444" => {
             return Err(anyhow::anyhow!(
                 "Preset 'prores_4444' is not currently supported in CLI mode. Use 'prores_422' instead."
@@ -0,0 +1,435 @@
+//! `render queue` — the project's durable render queue.
+//!
+//! Items live in the project (see `openreelio_core::render::queue`), so a
+//! queue filled from the CLI or the app survives either of them dying. `run`
+//! claims the queue, recovers items a dead runner left rendering, then works
+//! the queue in order until nothing is pending; a pause or cancel from another
+//! process is picked up between items and while an item encodes. A second
+//! `run` on the same project fails instead of rendering alongside the first.
+
+use super::render::{build_export_settings, prepare_render, run_render, validate_render_range};
+use crate::output;
+use clap::Subcommand;
+use openreelio_core::render::{
+    ExportError, ExportMetadata, ExportResult, ExportSettings, RenderJobStatus, RenderQueue,
+    RenderQueueItem, DEFAULT_RENDER_QUEUE_MAX_ATTEMPTS, MAX_RENDER_QUEUE_ATTEMPTS,
+};
+use openreelio_core::CoreError;
+use std::path::{Path, PathBuf};
+
+#[derive(Subcommand)]
+pub enum QueueAction {
+    /// Add a render to the end of the queue
+    Add {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Output file path
+        #[arg(long)]
+        output: PathBuf,
+
+        /// Render preset name
+        #[arg(long, default_value = "mp4_h264_1080p")]
+        preset: String,
+
+        /// Sequence ID (defaults to active)
+        #[arg(long)]
+        sequence: Option<String>,
+
+        /// Start of the rendered range in timeline seconds
+        #[arg(long)]
+        start: Option<f64>,
+
+        /// End of the rendered range in timeline seconds
+        #[arg(long)]
+        end: Option<f64>,
+
+        /// Attempts before a failure is final
+        #[arg(long, default_value_t = DEFAULT_RENDER_QUEUE_MAX_ATTEMPTS)]
+        max_attempts: u32,
+    },
+
+    /// List queued renders and their status
+    List {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+    },
+
+    /// Render every pending item, resuming any a previous run left unfinished (requires FFmpeg)
+    Run {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Stream NDJSON encode progress to stderr
+        #[arg(long)]
+        progress: bool,
+    },
+
+    /// Move an item to a new position in the queue
+    Move {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Queue item ID
+        #[arg(long)]
+        id: String,
+
+        /// Zero-based target position (clamped to the end of the queue)
+        #[arg(long)]
+        position: usize,
+    },
+
+    /// Pause one pending item, or the whole queue when no ID is given
+    Pause {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Queue item ID
+        #[arg(long)]
+        id: Option<String>,
+    },
+
+    /// Resume one paused item, or the whole queue when no ID is given
+    Resume {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Queue item ID
+        #[arg(long)]
+        id: Option<String>,
+    },
+
+    /// Cancel an unfinished item; a running encode stops within a second
+    Cancel {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Queue item ID
+        #[arg(long)]
+        id: String,
+    },
+
+    /// Put a failed or cancelled item back in line with a fresh retry budget
+    Retry {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Queue item ID
+        #[arg(long)]
+        id: String,
+    },
+
+    /// Remove an item that is not rendering
+    Remove {
+        /// Project directory path
+        #[arg(long)]
+        path: PathBuf,
+
+        /// Queue item ID
+        #[arg(long)]
+        id: String,
+    },
+}
+
+pub fn execute(action: QueueAction) -> anyhow::Result<()> {
+    match action {
+        QueueAction::Add {
+            path,
+            output: output_path,
+            preset,
+            sequence,
+            start,
+            end,
+            max_attempts,
+        } => add_item(AddArgs {
+            path,
+            output_path,
+            preset,
+            sequence,
+            start,
+            end,
+            max_attempts,
+        }),
+
+        QueueAction::List { path } => {
+            let project_dir = project_dir(&path)?;
+            print_queue(&load_queue(&project_dir)?)
+        }
+
+        QueueAction::Run { path, progress } => run_queue(&path, progress),
+
+        QueueAction::Move { path, id, position } => {
+            update_queue(&path, |queue| queue.move_item(&id, position))
+        }
+
+        QueueAction::Pause { path, id } => update_queue(&path, |queue| match &id {
+            Some(id) => queue.pause_item(id),
+            None => {
+                queue.paused = true;
+                Ok(())
+            }
+        }),
+
+        QueueAction::Resume { path, id } => update_queue(&path, |queue| match &id {
+            Some(id) => queue.resume_item(id),
+            None => {
+                queue.paused = false;
+                Ok(())
+            }
+        }),
+
+        QueueAction::Cancel { path, id } => update_queue(&path, |queue| queue.cancel(&id)),
+
+        QueueAction::Retry { path, id } => update_queue(&path, |queue| queue.retry(&id)),
+
+        QueueAction::Remove { path, id } => {
+            update_queue(&path, |queue| queue.remove(&id).map(|_| ()))
+        }
+    }
+}
+
+/// Parsed `render queue add` inputs; see `StartArgs` for why this is a struct.
+struct AddArgs {
+    path: PathBuf,
+    output_path: PathBuf,
+    preset: String,
+    sequence: Option<String>,
+    start: Option<f64>,
+    end: Option<f64>,
+    max_attempts: u32,
+}
+
+fn add_item(args: AddArgs) -> anyhow::Result<()> {
+    let AddArgs {
+        path,
+        output_path,
+        preset,
+        sequence,
+        start,
+        end,
+        max_attempts,
+    } = args;
+
+    validate_render_range(start, end)?;
+    if max_attempts == 0 || max_attempts > MAX_RENDER_QUEUE_ATTEMPTS {
+        return Err(anyhow::anyhow!(
+            "--max-attempts must be between 1 and {}",
+            MAX_RENDER_QUEUE_ATTEMPTS
+        ));
+    }
+
+    let (project_dir, state) = super::load_project_state_read_only(&path)?;
+    let seq_id = super::resolve_sequence_id_in_state(&state, sequence)?;
+    let sequence = state
+        .sequences
+        .get(&seq_id)
+        .ok_or_else(|| anyhow::anyhow!("Sequence '{}' not found", seq_id))?;
+
+    // The runner may start from any working directory, so the queue only
+    // ever holds absolute output paths.
+    let output_path = if output_path.is_absolute() {
+        output_path
+    } else {
+        std::env::current_dir()?.join(output_path)
+    };
+    // Rejects an unknown preset now rather than when the queue runs.
+    build_export_settings(
+        &preset,
+        output_path.clone(),
+        &sequence.format.canvas,
+        start,
+        end,
+    )?;
+
+    let item = RenderQueueItem::new(&seq_id, preset.trim(), &output_path.to_string_lossy())
+        .with_range(start, end)
+        .with_max_attempts(max_attempts);
+    RenderQueue::update(&project_dir, |queue| {
+        queue.push(item.clone());
+        Ok(())
+    })
+    .map_err(|error| anyhow::anyhow!("Failed to save render queue: {}", error))?;
+
+    output::print_json_pretty(&serde_json::json!({
+        "status": "ok",
+        "item": item,
+    }))
+}
+
+/// How one queue item's attempt ended.
+enum ItemOutcome {
+    Completed(ExportResult),
+    /// Ctrl-C stopped the encode; the attempt does not count
+    Interrupted,
+}
+
+fn run_queue(path: &PathBuf, progress: bool) -> anyhow::Result<()> {
+    let project_dir = project_dir(path)?;
+    // Held until the run ends. Only the holder may treat `Rendering` items as
+    // abandoned, because only it knows none of them is being encoded.
+    let _runner = RenderQueue::lock_runner(&project_dir)
+        .map_err(|error| anyhow::anyhow!("Cannot run render queue: {}", error))?;
+    let recovered = RenderQueue::update(&project_dir, |queue| Ok(queue.recover_interrupted()))
+        .map_err(|error| anyhow::anyhow!("Failed to update render queue: {}", error))?;
+
+    let mut processed = Vec::new();
+    let mut interrupted = false;
+    // The queue is reloaded before every item so that reordering, pausing
+    // or adding from another process takes effect on the next pick.
+    while let Some(item) = load_queue(&project_dir)?.next_pending().cloned() {
+        RenderQueue::update(&project_dir, |queue| queue.mark_rendering(&item.id))
+            .map_err(|error| anyhow::anyhow!("Failed to update render queue: {}", error))?;
+
+        match render_item(path, &project_dir, &item, progress) {
+            Ok(ItemOutcome::Completed(result)) => {
+                let status =
+                    RenderQueue::update(&project_dir, |queue| queue.mark_completed(&item.id))
+                        .map_err(|error| {
+                            anyhow::anyhow!("Failed to update render queue: {}", error)
+                        })?;
+                processed.push(serde_json::json!({
+                    "id": item.id,
+                    "status": status,
+                    "outputPath": result.output_path.display().to_string(),
+                    "durationSec": result.duration_sec,
+                    "fileSize": result.file_size,
+                    "encodingTimeSec": result.encoding_time_sec,
+                }));
+            }
+            Ok(ItemOutcome::Interrupted) => {
+                RenderQueue::update(&project_dir, |queue| queue.mark_interrupted(&item.id))
+                    .map_err(|error| anyhow::anyhow!("Failed to update render queue: {}", error))?;
+                interrupted = true;
+                break;
+            }
+            Err(error) => {
+                let message = error.to_string();
+                let status = RenderQueue::update(&project_dir, |queue| {
+                    queue.mark_failed(&item.id, &message)
+                })
+                .map_err(|error| anyhow::anyhow!("Failed to update render queue: {}", error))?;
+                processed.push(serde_json::json!({
+                    "id": item.id,
+                    "status": status,
+                    "error": message,
+                }));
+            }
+        }
+    }
+
+    let queue = load_queue(&project_dir)?;
+    let count = |status: RenderJobStatus| {
+        queue
+            .items
+            .iter()
+            .filter(|item| item.status == status)
+            .count()
+    };
+    let failed = count(RenderJobStatus::Failed);
+    output::print_json_pretty(&serde_json::json!({
+        "status": if interrupted { "interrupted" } else { "ok" },
+        "recovered": recovered,
+        "processed": processed,
+        "queuePaused": queue.paused,
+        "pending": count(RenderJobStatus::Pending),
+        "paused": count(RenderJobStatus::Paused),
+        "failed": failed,
+    }))?;
+
+    if interrupted {
+        return Err(anyhow::anyhow!(
+            "Render queue interrupted; run it again to resume"
+        ));
+    }
+    Ok(())
+}
+
+fn render_item(
+    path: &PathBuf,
+    project_dir: &Path,
+    item: &RenderQueueItem,
+    progress: bool,
+) -> anyhow::Result<ItemOutcome> {
+    // Read fresh for every item: the project may have been edited since the
+    // item was queued, and the queue renders what is saved now.
+    let (_, state) = super::load_project_state_read_only(path)?;
+    let sequence = state
+        .sequences
+        .get(&item.sequence_id)
+        .ok_or_else(|| anyhow::anyhow!("Sequence '{}' not found", item.sequence_id))?;
+    let output_path = PathBuf::from(&item.output_path);
+    let mut settings = match &item.settings {
+        Some(request) => {
+            ExportSettings::from_video_request(request, output_path, item.in_point, item.out_point)?
+        }
+        None => build_export_settings(
+            &item.preset,
+            output_path,
+            &sequence.format.canvas,
+            item.in_point,
+            item.out_point,
+        )?,
+    };
+    settings.metadata = Some(ExportMetadata::from_project(&state.meta));
+    let prepared = prepare_render(&state, &item.sequence_id, settings)?;
+
+    let queue_dir = project_dir.to_path_buf();
+    let item_id = item.id.clone();
+    // A cancel or removal written by another process stops the encode. A
+    // queue file that cannot be read right now is not a reason to stop.
+    let stop_requested = Box::new(move || match RenderQueue::load(&queue_dir) {
+        Ok(queue) => queue
+            .get(&item_id)
+            .is_none_or(|current| current.status == RenderJobStatus::Cancelled),
+        Err(_) => false,
+    });
+
+    let outcome = run_render(prepared, progress, Some(stop_requested))?;
+    match outcome.result {
+        Ok(result) => Ok(ItemOutcome::Completed(result)),
+        Err(ExportError::Cancelled) if outcome.interrupted => Ok(ItemOutcome::Interrupted),
+        Err(ExportError::Cancelled) => Err(anyhow::anyhow!(
+            "Cancelled from the render queue; the partial output file was removed"
+        )),
+        Err(error) => Err(anyhow::anyhow!(error)),
+    }
+}
+
+fn project_dir(path: &PathBuf) -> anyhow::Result<PathBuf> {
+    std::fs::canonicalize(path)
+        .map_err(|e| anyhow::anyhow!("Project path '{}' not found: {}", path.display(), e))
+}
+
+fn load_queue(project_dir: &Path) -> anyhow::Result<RenderQueue> {
+    RenderQueue::load(project_dir)
+        .map_err(|error| anyhow::anyhow!("Failed to load render queue: {}", error))
+}
+
+/// Applies one edit to the queue and prints the result.
+fn update_queue(
+    path: &PathBuf,
+    change: impl FnOnce(&mut RenderQueue) -> Result<(), CoreError>,
+) -> anyhow::Result<()> {
+    let project_dir = project_dir(path)?;
+    let queue = RenderQueue::update(&project_dir, |queue| {
+        change(queue)?;
+        Ok(queue.clone())
+    })
+    .map_err(|error| anyhow::anyhow!("{}", error))?;
+    print_queue(&queue)
+}
+
+fn print_queue(queue: &RenderQueue) -> anyhow::Result<()> {
+    output::print_json_pretty(&serde_json::json!({
+        "paused": queue.paused,
+        "items": queue.items,
+    }))
+}
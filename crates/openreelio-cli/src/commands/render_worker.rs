@@ -0,0 +1,240 @@
+//! `render worker` — serves segments of distributed renders.
+//!
+//! A coordinator (`render start --remote-worker`) connects once per segment
+//! and sends a [`WorkerSegmentRequest`]; the worker loads the project from the
+//! shared filesystem, encodes the segment straight into the project's render
+//! cache and answers with a [`WorkerSegmentResponse`]. Segments are served one
+//! at a time — run one worker per core to share more of a machine. A
+//! coordinator that drops the connection cancels its segment.
+//!
+//! The project a request names comes from whoever reached the socket, so it
+//! is only served when it is one of the `--project` directories the worker
+//! was started with.
+
+use crate::ffmpeg_env::ensure_ffmpeg;
+use openreelio_core::ffmpeg::FFmpegRunner;
+use openreelio_core::render::distributed::{
+    picture_only_sequence, read_json_line, write_json_line,
+};
+use openreelio_core::render::{
+    build_render_graph, build_render_plan, render_cache_dir, ExportEngine, ExportError,
+    ExportResult, WorkerSegmentRequest, WorkerSegmentResponse,
+};
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncReadExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+
+/// Listens on `listen` and serves segments of `projects` until Ctrl-C.
+pub(super) fn serve(listen: &str, projects: &[PathBuf]) -> anyhow::Result<()> {
+    let projects = projects
+        .iter()
+        .map(|project| {
+            std::fs::canonicalize(project).map_err(|error| {
+                anyhow::anyhow!("Project path '{}' not found: {}", project.display(), error)
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let ffmpeg_info = ensure_ffmpeg()?;
+    let engine = ExportEngine::new(FFmpegRunner::new(ffmpeg_info));
+
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .map_err(|error| anyhow::anyhow!("Failed to create Tokio runtime: {error}"))?;
+    let (completed, failed) = runtime.block_on(async {
+        let listener = TcpListener::bind(listen)
+            .await
+            .map_err(|error| anyhow::anyhow!("Failed to listen on {listen}: {error}"))?;
+        let address = listener.local_addr()?;
+        // stdout stays reserved for the final summary; events go to stderr
+        // like render progress does.
+        eprintln!(
+            "{}",
+            serde_json::json!({ "type": "listening", "address": address.to_string() })
+        );
+
+        let (mut completed, mut failed) = (0usize, 0usize);
+        loop {
+            let stream = tokio::select! {
+                _ = tokio::signal::ctrl_c() => break,
+                accepted = listener.accept() => match accepted {
+                    Ok((stream, _)) => stream,
+                    Err(error) => {
+                        tracing::warn!("Render worker failed to accept a connection: {error}");
+                        continue;
+                    }
+                },
+            };
+            match serve_connection(stream, &engine, &projects).await {
+                Ok(Some(true)) => completed += 1,
+                Ok(Some(false)) => failed += 1,
+                Ok(None) => {}
+                Err(error) => {
+                    tracing::warn!("Render worker connection failed: {error}");
+                    failed += 1;
+                }
+            }
+        }
+        Ok::<_, anyhow::Error>((completed, failed))
+    })?;
+
+    crate::output::print_json_pretty(&serde_json::json!({
+        "status": "ok",
+        "segmentsCompleted": completed,
+        "segmentsFailed": failed,
+    }))
+}
+
+/// Serves one request; `Some(true)` when the segment rendered, `None` when
+/// the peer hung up before asking for anything.
+async fn serve_connection(
+    stream: TcpStream,
+    engine: &ExportEngine,
+    projects: &[PathBuf],
+) -> Result<Option<bool>, ExportError> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+    let Some(request) = read_json_line::<_, WorkerSegmentRequest>(&mut reader).await? else {
+        return Ok(None);
+    };
+
+    // Nothing follows the request, so the read side only ends when the
+    // coordinator drops the connection.
+    let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel::<()>();
+    let hangup = tokio::spawn(async move {
+        let mut buffer = [0u8; 64];
+        while matches!(reader.read(&mut buffer).await, Ok(read) if read > 0) {}
+        let _ = cancel_tx.send(());
+    });
+
+    let result = render_segment(engine, projects, &request, cancel_rx).await;
+    hangup.abort();
+    eprintln!(
+        "{}",
+        serde_json::json!({
+            "type": "segment",
+            "sequenceId": request.sequence_id,
+            "outputPath": request.settings.output_path.display().to_string(),
+            "ok": result.is_ok(),
+        })
+    );
+
+    let rendered = result.is_ok();
+    let response = match result {
+        Ok(result) => WorkerSegmentResponse::Completed { result },
+        Err(error) => WorkerSegmentResponse::Failed {
+            message: error.to_string(),
+        },
+    };
+    write_json_line(&mut writer, &response).await?;
+    Ok(Some(rendered))
+}
+
+async fn render_segment(
+    engine: &ExportEngine,
+    projects: &[PathBuf],
+    request: &WorkerSegmentRequest,
+    cancel_rx: tokio::sync::oneshot::Receiver<()>,
+) -> Result<ExportResult, ExportError> {
+    let project_path = served_project(projects, &request.project_path)?;
+    let (project_path, state) = super::load_project_state_read_only(&project_path)
+        .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+    check_output_in_cache(&project_path, &request.settings.output_path)?;
+
+    let sequence = state.sequences.get(&request.sequence_id).ok_or_else(|| {
+        ExportError::InvalidSettings(format!("Sequence '{}' not found", request.sequence_id))
+    })?;
+    let graph = build_render_graph(&state, &request.sequence_id)
+        .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+    let plan = build_render_plan(&graph, &state.assets, &state.effects, &request.settings);
+    if !plan.validation.is_valid {
+        return Err(ExportError::InvalidSettings(
+            plan.validation.errors.join("; "),
+        ));
+    }
+
+    engine
+        .export_sequence_with_effects_for_plan(
+            &picture_only_sequence(sequence),
+            &state.assets,
+            &state.effects,
+            &request.settings,
+            &plan,
+            None,
+            Some(cancel_rx),
+        )
+        .await
+}
+
+/// The served project `requested` names, or an error when it is none of
+/// `projects` (which are canonical).
+fn served_project(projects: &[PathBuf], requested: &Path) -> Result<PathBuf, ExportError> {
+    std::fs::canonicalize(requested)
+        .ok()
+        .filter(|requested| projects.contains(requested))
+        .ok_or_else(|| {
+            ExportError::InvalidSettings(format!(
+                "Project '{}' is not served by this worker",
+                requested.display()
+            ))
+        })
+}
+
+/// Refuses outputs outside the project's render cache, so anyone able to
+/// reach the socket cannot make the worker overwrite arbitrary files.
+fn check_output_in_cache(project_path: &Path, output_path: &Path) -> Result<(), ExportError> {
+    let outside = || {
+        ExportError::InvalidSettings(format!(
+            "Segment output '{}' is outside the project's render cache",
+            output_path.display()
+        ))
+    };
+    let cache_dir = std::fs::canonicalize(render_cache_dir(project_path)).map_err(|_| outside())?;
+    let parent = output_path
+        .parent()
+        .and_then(|parent| std::fs::canonicalize(parent).ok())
+        .ok_or_else(outside)?;
+    if parent.starts_with(&cache_dir) && output_path.file_name().is_some() {
+        Ok(())
+    } else {
+        Err(outside())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outputs_must_stay_inside_the_render_cache() {
+        let project = tempfile::tempdir().unwrap();
+        let segments = render_cache_dir(project.path())
+            .join("seq_1")
+            .join("final_ab");
+        std::fs::create_dir_all(&segments).unwrap();
+
+        assert!(check_output_in_cache(project.path(), &segments.join("segment.mp4")).is_ok());
+        assert!(check_output_in_cache(project.path(), &project.path().join("out.mp4")).is_err());
+        assert!(check_output_in_cache(
+            project.path(),
+            &segments.join("..").join("..").join("..").join("escape.mp4")
+        )
+        .is_err());
+        assert!(check_output_in_cache(project.path(), Path::new("/etc/passwd")).is_err());
+    }
+
+    #[test]
+    fn only_the_projects_the_worker_was_started_with_are_served() {
+        let served = tempfile::tempdir().unwrap();
+        let other = tempfile::tempdir().unwrap();
+        let projects = vec![std::fs::canonicalize(served.path()).unwrap()];
+
+        assert_eq!(
+            served_project(&projects, &served.path().join(".")).unwrap(),
+            projects[0]
+        );
+        assert!(served_project(&projects, other.path()).is_err());
+        assert!(served_project(&projects, &served.path().join("nested")).is_err());
+        assert!(served_project(&projects, &served.path().join("..")).is_err());
+    }
+}
@@ -26,14 +26,15 @@
 //! still measures correctly but its timestamps no longer line up with the
 //! timeline.
 
+use super::verify_report;
 use crate::ffmpeg_env::ensure_ffmpeg;
 use crate::output;
 use clap::Args;
 use openreelio_core::ffmpeg::FFmpegRunner;
 use openreelio_core::qc::{
-    crossref_black_ranges_with_gaps, measure_rendered_file_detailed, MeasureOptions,
-    MeasurementReport, QCContext, QCEngine, QCEngineConfig, QCReport, QCSeverityFilter, RuleStatus,
-    Severity,
+    crossref_black_ranges_with_gaps, measure_rendered_file_detailed, resolve_profile,
+    MeasureOptions, MeasurementReport, QCContext, QCEngine, QCEngineConfig, QCReport,
+    QCSeverityFilter, RuleStatus, Severity,
 };
 use openreelio_core::timeline::Sequence;
 use serde::Serialize;
@@ -64,6 +65,12 @@ const PEAK_CHECK_ID: &str = "audio.peak";
 /// Check ID of the render-length rule, wired to `--duration-tolerance-sec`.
 const DURATION_CHECK_ID: &str = "render.duration_mismatch";
 
+/// Check ID of the legal-levels rule, wired to `--levels`.
+const LEGAL_LEVELS_CHECK_ID: &str = "render.broadcast_legal";
+
+/// Check ID of the stream-sync rule, wired to `--max-av-drift-sec`.
+const AV_SYNC_CHECK_ID: &str = "render.av_sync";
+
 /// Arguments for `verify`.
 #[derive(Args)]
 pub struct VerifyArgs {
@@ -91,6 +98,12 @@ pub struct VerifyArgs {
     #[arg(long, value_delimiter = ',')]
     pub skip: Option<Vec<String>>,
 
+    /// Delivery profile to apply: a built-in (broadcast, streaming, youtube,
+    /// social_vertical), a profile in the project's or user's qc-profiles
+    /// directory, or a path to a .toml/.json profile file
+    #[arg(long)]
+    pub profile: Option<String>,
+
     /// Integrated loudness target in LUFS
     #[arg(long)]
     pub target_lufs: Option<f64>,
@@ -104,6 +117,15 @@ pub struct VerifyArgs {
     #[arg(long)]
     pub duration_tolerance_sec: Option<f64>,
 
+    /// Offset or drift tolerated between the rendered audio and video
+    /// streams, in seconds; honoured exactly
+    #[arg(long)]
+    pub max_av_drift_sec: Option<f64>,
+
+    /// Legal video levels to check against: nominal (16-235) or ebu_r103
+    #[arg(long, value_parser = ["nominal", "ebu_r103"])]
+    pub levels: Option<String>,
+
     /// Lowest severity that fails the run: info, warning, error, critical
     #[arg(long, default_value = "error")]
     pub fail_on: String,
@@ -112,6 +134,19 @@ pub struct VerifyArgs {
     #[arg(long, default_value_t = 600)]
     pub timeout_sec: u64,
 
+    /// Also write the report as JUnit XML, one testcase per check
+    #[arg(long)]
+    pub junit: Option<PathBuf>,
+
+    /// Also write a self-contained HTML report, with a frame from the rendered
+    /// file for each finding's time range
+    #[arg(long)]
+    pub html: Option<PathBuf>,
+
+    /// Also write the findings as CSV, one row per finding
+    #[arg(long)]
+    pub csv: Option<PathBuf>,
+
     /// Pretty-print the JSON output
     #[arg(long)]
     pub json_pretty: bool,
@@ -164,6 +199,11 @@ pub(crate) fn run_verify(args: VerifyArgs) -> anyhow::Result<(Value, i32)> {
             "Invalid value for --timeout-sec: must be >= 1"
         ));
     }
+    // The measurement pass can take minutes; a report path that cannot be
+    // written should fail before it, not after.
+    for (flag, path) in report_file_targets(&args) {
+        validate_report_path(flag, path)?;
+    }
 
     let project = super::load_project(&args.path)?;
     let sequence_id = super::resolve_sequence_id(&project, args.sequence.clone())?;
@@ -186,6 +226,7 @@ pub(crate) fn run_verify(args: VerifyArgs) -> anyhow::Result<(Value, i32)> {
     let mut warnings: Vec<String> = Vec::new();
     let mut errors: Vec<String> = Vec::new();
     let mut measurement: Option<MeasurementReport> = None;
+    let mut runner: Option<FFmpegRunner> = None;
 
     if let Some(file) = args.file.as_ref() {
         if !file.exists() {
@@ -198,13 +239,13 @@ pub(crate) fn run_verify(args: VerifyArgs) -> anyhow::Result<(Value, i32)> {
         // FFmpeg is only required once a rendered file is in play; structural
         // runs must work on machines without it.
         let ffmpeg_info = ensure_ffmpeg()?;
-        let runner = FFmpegRunner::new(ffmpeg_info);
+        let file_runner = runner.insert(FFmpegRunner::new(ffmpeg_info));
         let options = MeasureOptions {
             timeout: Duration::from_secs(args.timeout_sec),
             ..Default::default()
         };
 
-        match runtime.block_on(measure_rendered_file_detailed(&runner, file, &options)) {
+        match runtime.block_on(measure_rendered_file_detailed(file_runner, file, &options)) {
             Ok(report) => {
                 warnings.extend(report.notes.iter().cloned());
                 measurement = Some(report);
@@ -248,7 +289,7 @@ pub(crate) fn run_verify(args: VerifyArgs) -> anyhow::Result<(Value, i32)> {
         ));
     }
 
-    let output_value = build_output(
+    let mut output_value = build_output(
         OutputInputs {
             sequence,
             sequence_id: &sequence_id,
@@ -257,6 +298,7 @@ pub(crate) fn run_verify(args: VerifyArgs) -> anyhow::Result<(Value, i32)> {
             selected_ids: &selected_ids,
             measurement: measurement.as_ref(),
             rendered_file: args.file.as_deref(),
+            profile: args.profile.as_deref(),
             structural_only: args.structural_only,
         },
         warnings,
@@ -276,9 +318,87 @@ pub(crate) fn run_verify(args: VerifyArgs) -> anyhow::Result<(Value, i32)> {
         0
     };
 
+    write_report_files(&args, &mut output_value, fail_on, &runtime, runner.as_ref())?;
+
     Ok((output_value, exit_code))
 }
 
+// ── Report files ────────────────────────────────────────────────────────
+
+/// The report files requested, paired with the flag that named each.
+fn report_file_targets(args: &VerifyArgs) -> Vec<(&'static str, &Path)> {
+    [
+        ("--junit", args.junit.as_deref()),
+        ("--html", args.html.as_deref()),
+        ("--csv", args.csv.as_deref()),
+    ]
+    .into_iter()
+    .filter_map(|(flag, path)| path.map(|path| (flag, path)))
+    .collect()
+}
+
+/// Rejects a report path whose directory does not exist.
+fn validate_report_path(flag: &str, path: &Path) -> anyhow::Result<()> {
+    if path.is_dir() {
+        return Err(anyhow::anyhow!(
+            "Invalid value for {flag}: '{}' is a directory",
+            path.display()
+        ));
+    }
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() && !parent.is_dir() => Err(anyhow::anyhow!(
+            "Invalid value for {flag}: directory '{}' does not exist",
+            parent.display()
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Writes the report files the caller asked for.
+///
+/// A thumbnail that cannot be taken is a warning on the report, since the page
+/// is still useful without it. A report file that cannot be written is a tool
+/// failure, because CI would otherwise read the one left by the previous run.
+fn write_report_files(
+    args: &VerifyArgs,
+    report: &mut Value,
+    fail_on: Severity,
+    runtime: &tokio::runtime::Runtime,
+    runner: Option<&FFmpegRunner>,
+) -> anyhow::Result<()> {
+    if let Some(path) = args.html.as_deref() {
+        // Frames come from the rendered file, so a structural run or a file
+        // without a picture yields a page of time ranges alone.
+        let has_picture = !report["measurements"]["videoStream"].is_null();
+        let (thumbnails, notes) = match (runner, args.file.as_deref()) {
+            (Some(runner), Some(file)) if has_picture => {
+                runtime.block_on(verify_report::collect_thumbnails(runner, file, report))
+            }
+            _ => (verify_report::Thumbnails::new(), Vec::new()),
+        };
+        if let Some(warnings) = report["warnings"].as_array_mut() {
+            warnings.extend(notes.into_iter().map(Value::String));
+        }
+        write_report_file("--html", path, &verify_report::html(report, &thumbnails))?;
+    }
+    if let Some(path) = args.junit.as_deref() {
+        write_report_file("--junit", path, &verify_report::junit_xml(report, fail_on))?;
+    }
+    if let Some(path) = args.csv.as_deref() {
+        write_report_file("--csv", path, &verify_report::csv(report))?;
+    }
+    Ok(())
+}
+
+fn write_report_file(flag: &str, path: &Path, contents: &str) -> anyhow::Result<()> {
+    std::fs::write(path, contents).map_err(|error| {
+        anyhow::anyhow!(
+            "Failed to write the {flag} report to '{}': {error}",
+            path.display()
+        )
+    })
+}
+
 // ── Configuration ───────────────────────────────────────────────────────
 
 /// Parses a severity threshold name.
@@ -310,6 +430,24 @@ fn build_engine_config(engine: &QCEngine, args: &VerifyArgs) -> anyhow::Result<Q
         .map(|rule| rule.check_id().to_string())
         .collect();
 
+    if args.checks.is_none() {
+        for rule in engine.rules() {
+            if OPT_IN_CHECK_IDS.contains(&rule.check_id()) {
+                config.disable_rule(rule.name());
+            }
+        }
+    }
+
+    // The profile sits between the defaults and the explicit flags: it may
+    // switch opt-in checks on, and every flag below still overrides it.
+    if let Some(name) = args.profile.as_deref() {
+        let profile = resolve_profile(name, Some(&args.path))
+            .map_err(|error| anyhow::anyhow!("Invalid value for --profile: {error}"))?;
+        profile
+            .apply(engine, &mut config)
+            .map_err(|error| anyhow::anyhow!("Invalid value for --profile: {error}"))?;
+    }
+
     if let Some(requested) = args.checks.as_ref() {
         let requested = normalize_ids(requested);
         // An empty selection would disable every rule and report a clean run
@@ -325,13 +463,9 @@ fn build_engine_config(engine: &QCEngine, args: &VerifyArgs) -> anyhow::Result<Q
             }
         }
         for rule in engine.rules() {
-            if !requested.iter().any(|id| id == rule.check_id()) {
-                config.disable_rule(rule.name());
-            }
-        }
-    } else {
-        for rule in engine.rules() {
-            if OPT_IN_CHECK_IDS.contains(&rule.check_id()) {
+            if requested.iter().any(|id| id == rule.check_id()) {
+                config.enable_rule(rule.name());
+            } else {
                 config.disable_rule(rule.name());
             }
         }
@@ -387,6 +521,25 @@ fn build_engine_config(engine: &QCEngine, args: &VerifyArgs) -> anyhow::Result<Q
         );
     }
 
+    if let Some(max_drift_sec) = args.max_av_drift_sec {
+        if !max_drift_sec.is_finite() || max_drift_sec < 0.0 {
+            return Err(anyhow::anyhow!(
+                "Invalid value for --max-av-drift-sec: must be a finite, non-negative number"
+            ));
+        }
+        set_param(
+            engine,
+            &mut config,
+            AV_SYNC_CHECK_ID,
+            "max_drift_sec",
+            max_drift_sec,
+        );
+    }
+
+    if let Some(levels) = args.levels.as_deref() {
+        set_param(engine, &mut config, LEGAL_LEVELS_CHECK_ID, "levels", levels);
+    }
+
     Ok(config)
 }
 
@@ -396,7 +549,7 @@ fn set_param(
     config: &mut QCEngineConfig,
     check_id: &str,
     key: &str,
-    value: f64,
+    value: impl serde::Serialize,
 ) {
     let Some(rule) = engine.get_rule_by_check_id(check_id) else {
         return;
@@ -560,6 +713,7 @@ struct OutputInputs<'a> {
     selected_ids: &'a [String],
     measurement: Option<&'a MeasurementReport>,
     rendered_file: Option<&'a Path>,
+    profile: Option<&'a str>,
     structural_only: bool,
 }
 
@@ -601,6 +755,7 @@ fn build_output(inputs: OutputInputs<'_>, mut warnings: Vec<String>, errors: Vec
             "sequenceId": inputs.sequence_id,
             "sequenceName": inputs.sequence.name,
             "renderedFile": inputs.rendered_file.map(|path| path.display().to_string()),
+            "profile": inputs.profile,
             "measured": inputs.measurement.is_some(),
             "selectedChecks": inputs.selected_ids,
         },
@@ -748,6 +903,22 @@ fn build_measurements(measurement: Option<&MeasurementReport>, file: Option<&Pat
         })),
         "blackRanges": spans_json(&measurements.black_ranges),
         "freezeRanges": spans_json(&measurements.freeze_ranges),
+        "levelExcursions": measurements.level_excursions,
+        "streamTiming": measurements.stream_timing,
+        // Totals only; each break in the cadence is reported by its check.
+        "frameTiming": measurements.frame_timing.as_ref().map(|timing| serde_json::json!({
+            "frameCount": timing.frame_count,
+            "measuredFps": timing.measured_fps(),
+            "nominalIntervalSec": timing.nominal_interval_sec,
+            "minIntervalSec": timing.min_interval_sec,
+            "maxIntervalSec": timing.max_interval_sec,
+            "irregularIntervals": timing.irregular_intervals,
+            "droppedFrames": timing.dropped_frames,
+            "duplicatedFrames": timing.duplicated_frames,
+        })),
+        // The raw list runs to one entry per flashing frame; the check reports
+        // the ranges that matter, so only the count is summarised here.
+        "flashTransitionCount": measurements.flash_transitions.as_ref().map(Vec::len),
         "silenceRanges": spans_json(&measurements.silence_ranges),
         "integratedLufs": measurements.integrated_lufs,
         "loudnessRangeLu": measurements.loudness_range_lu,
@@ -862,11 +1033,17 @@ mod tests {
             structural_only: true,
             checks: None,
             skip: None,
+            profile: None,
             target_lufs: None,
             max_true_peak: None,
             duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: None,
             fail_on: "error".to_string(),
             timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
             json_pretty: false,
         };
 
@@ -893,11 +1070,17 @@ mod tests {
             structural_only: true,
             checks: Some(vec!["timeline.gap".to_string()]),
             skip: None,
+            profile: None,
             target_lufs: None,
             max_true_peak: None,
             duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: None,
             fail_on: "error".to_string(),
             timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
             json_pretty: false,
         };
 
@@ -918,11 +1101,17 @@ mod tests {
             structural_only: true,
             checks: None,
             skip: Some(vec!["timeline.gap".to_string()]),
+            profile: None,
             target_lufs: None,
             max_true_peak: None,
             duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: None,
             fail_on: "error".to_string(),
             timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
             json_pretty: false,
         };
 
@@ -941,11 +1130,17 @@ mod tests {
             structural_only: false,
             checks: None,
             skip: None,
+            profile: None,
             target_lufs: Some(-16.0),
             max_true_peak: Some(-2.0),
             duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: None,
             fail_on: "error".to_string(),
             timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
             json_pretty: false,
         };
 
@@ -984,11 +1179,17 @@ mod tests {
             structural_only: false,
             checks: None,
             skip: None,
+            profile: None,
             target_lufs: None,
             max_true_peak: None,
             duration_tolerance_sec: Some(0.04),
+            max_av_drift_sec: None,
+            levels: None,
             fail_on: "error".to_string(),
             timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
             json_pretty: false,
         };
 
@@ -1009,6 +1210,207 @@ mod tests {
         assert!(build_engine_config(&engine, &args).is_err());
     }
 
+    /// Feature: A/V sync threshold
+    /// Scenario: should hand the drift threshold to the sync rule
+    #[test]
+    fn test_max_av_drift_should_reach_the_rule_configuration() {
+        let engine = QCEngine::new();
+        let mut args = VerifyArgs {
+            path: PathBuf::from("."),
+            sequence: None,
+            file: None,
+            structural_only: false,
+            checks: None,
+            skip: None,
+            profile: None,
+            target_lufs: None,
+            max_true_peak: None,
+            duration_tolerance_sec: None,
+            max_av_drift_sec: Some(0.02),
+            levels: None,
+            fail_on: "error".to_string(),
+            timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
+            json_pretty: false,
+        };
+
+        let config = build_engine_config(&engine, &args).expect("config builds");
+        let rule = engine
+            .get_rule_by_check_id(AV_SYNC_CHECK_ID)
+            .expect("sync rule registered");
+
+        assert_eq!(
+            config
+                .get_rule_config(rule.name())
+                .get_param::<f64>("max_drift_sec"),
+            Some(0.02)
+        );
+
+        args.max_av_drift_sec = Some(f64::NAN);
+        assert!(build_engine_config(&engine, &args).is_err());
+    }
+
+    /// Feature: Delivery profiles
+    /// Scenario: should apply a project profile underneath the explicit flags
+    #[test]
+    fn test_profile_should_configure_rules_and_yield_to_explicit_flags() {
+        let project = tempfile::tempdir().expect("temp dir");
+        let profile_dir = project.path().join(".openreelio").join("qc-profiles");
+        std::fs::create_dir_all(&profile_dir).expect("profile dir");
+        std::fs::write(
+            profile_dir.join("festival.toml"),
+            r#"
+                [checks."audio.loudness"]
+                severity = "error"
+                params = { target_lufs = -24.0 }
+
+                [checks."asset.license"]
+                enabled = true
+
+                [checks."timeline.gap"]
+                enabled = false
+            "#,
+        )
+        .expect("profile written");
+
+        let engine = QCEngine::new();
+        let mut args = VerifyArgs {
+            path: project.path().to_path_buf(),
+            sequence: None,
+            file: None,
+            structural_only: false,
+            checks: None,
+            skip: None,
+            profile: Some("festival".to_string()),
+            target_lufs: None,
+            max_true_peak: None,
+            duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: None,
+            fail_on: "error".to_string(),
+            timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
+            json_pretty: false,
+        };
+
+        let config = build_engine_config(&engine, &args).expect("config builds");
+        let enabled = enabled_check_ids(&engine, &config);
+        assert!(enabled.contains(&"asset.license".to_string()));
+        assert!(!enabled.contains(&"timeline.gap".to_string()));
+        let loudness = config.get_rule_config("AudioLoudnessRule");
+        assert_eq!(loudness.get_param::<f64>("target_lufs"), Some(-24.0));
+        assert_eq!(loudness.severity_override, Some(Severity::Error));
+
+        args.target_lufs = Some(-16.0);
+        args.checks = Some(vec!["timeline.gap".to_string()]);
+        let config = build_engine_config(&engine, &args).expect("config builds");
+        assert_eq!(
+            config
+                .get_rule_config("AudioLoudnessRule")
+                .get_param::<f64>("target_lufs"),
+            Some(-16.0),
+            "an explicit flag must win over the profile"
+        );
+        assert_eq!(
+            enabled_check_ids(&engine, &config),
+            vec!["timeline.gap".to_string()],
+            "--checks must narrow the run to what was named, profile or not"
+        );
+    }
+
+    /// Feature: Delivery profiles
+    /// Scenario: should refuse a profile that does not fit the checks
+    #[test]
+    fn test_profile_should_reject_invalid_and_unknown_profiles() {
+        let project = tempfile::tempdir().expect("temp dir");
+        let profile_path = project.path().join("typo.json");
+        std::fs::write(
+            &profile_path,
+            r#"{ "checks": { "audio.peak": { "params": { "peak": -2 } } } }"#,
+        )
+        .expect("profile written");
+
+        let engine = QCEngine::new();
+        let mut args = VerifyArgs {
+            path: project.path().to_path_buf(),
+            sequence: None,
+            file: None,
+            structural_only: false,
+            checks: None,
+            skip: None,
+            profile: Some(profile_path.display().to_string()),
+            target_lufs: None,
+            max_true_peak: None,
+            duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: None,
+            fail_on: "error".to_string(),
+            timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
+            json_pretty: false,
+        };
+
+        let error = build_engine_config(&engine, &args)
+            .expect_err("a misspelt parameter must not be ignored")
+            .to_string();
+        assert!(error.starts_with("Invalid value for --profile"), "{error}");
+        assert!(error.contains("params.peak"), "{error}");
+        assert!(error.contains("peak_db"), "{error}");
+
+        args.profile = Some("no_such_profile".to_string());
+        let error = build_engine_config(&engine, &args)
+            .expect_err("an unknown profile must fail")
+            .to_string();
+        assert!(error.contains("no_such_profile"), "{error}");
+        assert!(error.contains("broadcast"), "{error}");
+    }
+
+    /// Feature: Legal levels preset
+    /// Scenario: should hand the --levels preset to the legal-levels rule
+    #[test]
+    fn test_levels_should_reach_the_rule_configuration() {
+        let engine = QCEngine::new();
+        let args = VerifyArgs {
+            path: PathBuf::from("."),
+            sequence: None,
+            file: None,
+            structural_only: false,
+            checks: None,
+            skip: None,
+            profile: None,
+            target_lufs: None,
+            max_true_peak: None,
+            duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: Some("ebu_r103".to_string()),
+            fail_on: "error".to_string(),
+            timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
+            json_pretty: false,
+        };
+
+        let config = build_engine_config(&engine, &args).expect("config builds");
+        let rule = engine
+            .get_rule_by_check_id(LEGAL_LEVELS_CHECK_ID)
+            .expect("legal-levels rule registered");
+
+        assert_eq!(
+            config
+                .get_rule_config(rule.name())
+                .get_param::<String>("levels")
+                .as_deref(),
+            Some("ebu_r103")
+        );
+    }
+
     /// Feature: Check status reporting
     /// Scenario: should never call a check with findings "passed"
     #[test]
@@ -1127,11 +1529,17 @@ mod tests {
             structural_only: true,
             checks: Some(vec![String::new(), "  ".to_string()]),
             skip: None,
+            profile: None,
             target_lufs: None,
             max_true_peak: None,
             duration_tolerance_sec: None,
+            max_av_drift_sec: None,
+            levels: None,
             fail_on: "error".to_string(),
             timeout_sec: 600,
+            junit: None,
+            html: None,
+            csv: None,
             json_pretty: false,
         };
 
@@ -0,0 +1,722 @@
+//! File exports of the `verify` report: JUnit XML, HTML and CSV.
+//!
+//! Every format is rendered from the JSON document `verify` prints rather than
+//! from the engine's report, so a file can never say something stdout did not.
+//!
+//! - **JUnit XML** has one `<testcase>` per check. A check whose findings reach
+//!   `--fail-on` is a `<failure>`, a check that could not run is an `<error>`,
+//!   and a skipped check is `<skipped>`. Findings below the threshold go to
+//!   `<system-out>`. Without that split the informational shot statistics alone
+//!   would turn every nightly run red.
+//! - **HTML** is one self-contained page. Thumbnails are embedded as data URIs,
+//!   one for each violating time range, taken from the rendered file.
+//! - **CSV** has one row per finding, plus one row for each check that found
+//!   nothing, so the sheet also shows what was checked.
+
+use base64::Engine as _;
+use openreelio_core::ffmpeg::{FFmpegRunner, FrameExtractOptions};
+use openreelio_core::qc::Severity;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::path::Path;
+
+/// Most thumbnails extracted for one HTML report.
+///
+/// Each costs an FFmpeg spawn and about 20 KB of page, so a render with
+/// hundreds of findings gets pictures for the first ones and times for the rest.
+pub(crate) const MAX_REPORT_THUMBNAILS: usize = 60;
+
+/// Thumbnail width in pixels; enough to recognise the shot, small enough to embed.
+const THUMBNAIL_WIDTH_PX: u32 = 320;
+
+/// Embedded thumbnails, keyed by violation ID, as `data:` URIs.
+pub(crate) type Thumbnails = HashMap<String, String>;
+
+// ── JUnit XML ──────────────────────────────────────────────────────────
+
+/// Renders the report as a JUnit XML document.
+pub(crate) fn junit_xml(report: &Value, fail_on: Severity) -> String {
+    let checks = checks(report);
+    let suite_name = format!(
+        "openreelio.verify.{}",
+        str_field(&report["target"], "sequenceName")
+            .or_else(|| str_field(&report["target"], "sequenceId"))
+            .unwrap_or("sequence")
+    );
+    let seconds = report["durationMs"].as_f64().unwrap_or(0.0) / 1000.0;
+    let timestamp = str_field(report, "checkedAt").unwrap_or_default();
+
+    let mut failures = 0;
+    let mut errors = 0;
+    let mut skipped = 0;
+    let mut cases = String::new();
+
+    for check in checks {
+        let id = str_field(check, "id").unwrap_or_default();
+        let category = str_field(check, "category").unwrap_or_default();
+        let status = str_field(check, "status").unwrap_or_default();
+        let _ = write!(
+            cases,
+            "    <testcase classname=\"verify.{}\" name=\"{}\" time=\"0\"",
+            xml_escape(category),
+            xml_escape(id)
+        );
+
+        let findings = violations(check);
+        let (failing, below): (Vec<&Value>, Vec<&Value>) = findings.iter().partition(|violation| {
+            severity_of(violation).is_some_and(|s| s.meets_threshold(fail_on))
+        });
+
+        match status {
+            "skipped" => {
+                skipped += 1;
+                let _ = writeln!(
+                    cases,
+                    ">\n      <skipped message=\"{}\"/>\n    </testcase>",
+                    xml_escape(str_field(check, "skipReason").unwrap_or("skipped"))
+                );
+                continue;
+            }
+            "errored" => {
+                errors += 1;
+                let _ = write!(
+                    cases,
+                    ">\n      <error type=\"errored\" message=\"{}\"/>\n",
+                    xml_escape(str_field(check, "message").unwrap_or_default())
+                );
+            }
+            _ if !failing.is_empty() => {
+                failures += 1;
+                let worst = failing
+                    .iter()
+                    .filter_map(|violation| str_field(violation, "severity"))
+                    .max_by_key(|severity| severity_rank(severity))
+                    .unwrap_or("error");
+                let _ = write!(
+                    cases,
+                    ">\n      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    xml_escape(worst),
+                    xml_escape(str_field(check, "message").unwrap_or_default()),
+                    xml_escape(&finding_lines(&failing))
+                );
+            }
+            _ if below.is_empty() => {
+                cases.push_str("/>\n");
+                continue;
+            }
+            _ => cases.push_str(">\n"),
+        }
+
+        if !below.is_empty() {
+            let _ = writeln!(
+                cases,
+                "      <system-out>{}</system-out>",
+                xml_escape(&finding_lines(&below))
+            );
+        }
+        cases.push_str("    </testcase>\n");
+    }
+
+    let mut properties = String::new();
+    for (name, value) in [
+        ("sequenceId", &report["target"]["sequenceId"]),
+        ("renderedFile", &report["target"]["renderedFile"]),
+        ("profile", &report["target"]["profile"]),
+        ("status", &report["status"]),
+        ("failOn", &Value::String(severity_name(fail_on).to_string())),
+    ] {
+        if let Some(value) = value.as_str() {
+            let _ = writeln!(
+                properties,
+                "      <property name=\"{name}\" value=\"{}\"/>",
+                xml_escape(value)
+            );
+        }
+    }
+
+    let counts = format!(
+        "tests=\"{}\" failures=\"{failures}\" errors=\"{errors}\" skipped=\"{skipped}\" time=\"{seconds:.3}\"",
+        checks.len()
+    );
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <testsuites name=\"openreelio verify\" {counts}>\n  \
+         <testsuite name=\"{}\" {counts} timestamp=\"{}\">\n    \
+         <properties>\n{properties}    </properties>\n{cases}  \
+         </testsuite>\n</testsuites>\n",
+        xml_escape(&suite_name),
+        xml_escape(timestamp),
+    )
+}
+
+// ── CSV ────────────────────────────────────────────────────────────────
+
+/// Renders the report as CSV: one row per finding, one per clean check.
+pub(crate) fn csv(report: &Value) -> String {
+    let mut out = String::from(
+        "check_id,category,status,severity,start_sec,end_sec,start_timecode,end_timecode,\
+         message,entities,auto_fixable\n",
+    );
+
+    for check in checks(report) {
+        let id = str_field(check, "id").unwrap_or_default();
+        let category = str_field(check, "category").unwrap_or_default();
+        let status = str_field(check, "status").unwrap_or_default();
+        let findings = violations(check);
+
+        if findings.is_empty() {
+            let message = str_field(check, "skipReason")
+                .or_else(|| str_field(check, "message"))
+                .unwrap_or_default();
+            csv_row(
+                &mut out,
+                &[
+                    id, category, status, "", "", "", "", "", message, "", "false",
+                ],
+            );
+            continue;
+        }
+
+        for violation in findings {
+            let range = &violation["timeRange"];
+            let start = range["startSec"].as_f64();
+            let end = range["endSec"].as_f64();
+            let entities = violation["entities"]
+                .as_array()
+                .map(|entities| {
+                    entities
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .unwrap_or_default();
+            let seconds = |value: Option<f64>| value.map(|v| format!("{v:.3}")).unwrap_or_default();
+            let timecode = |value: Option<f64>| value.map(format_timecode).unwrap_or_default();
+
+            csv_row(
+                &mut out,
+                &[
+                    id,
+                    category,
+                    status,
+                    str_field(violation, "severity").unwrap_or_default(),
+                    &seconds(start),
+                    &seconds(end),
+                    &timecode(start),
+                    &timecode(end),
+                    str_field(violation, "message").unwrap_or_default(),
+                    &entities,
+                    if violation["autoFixable"].as_bool() == Some(true) {
+                        "true"
+                    } else {
+                        "false"
+                    },
+                ],
+            );
+        }
+    }
+
+    out
+}
+
+fn csv_row(out: &mut String, fields: &[&str]) {
+    let row: Vec<String> = fields.iter().map(|field| csv_escape(field)).collect();
+    out.push_str(&row.join(","));
+    out.push('\n');
+}
+
+/// Quotes a field when it holds a separator, a quote or a line break.
+///
+/// A leading `=`, `+`, `-` or `@` also gets quoted with a `'` prefix, except
+/// for a plain number. Spreadsheets would otherwise run a message that starts
+/// that way as a formula.
+fn csv_escape(field: &str) -> String {
+    let formula_like = field.starts_with(['=', '+', '-', '@']) && field.parse::<f64>().is_err();
+    let field = if formula_like {
+        format!("'{field}")
+    } else {
+        field.to_string()
+    };
+
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field
+    }
+}
+
+// ── HTML ───────────────────────────────────────────────────────────────
+
+/// Renders the report as a single self-contained HTML page.
+pub(crate) fn html(report: &Value, thumbnails: &Thumbnails) -> String {
+    let target = &report["target"];
+    let summary = &report["summary"];
+    let status = str_field(report, "status").unwrap_or("unknown");
+    let title = format!(
+        "QC report: {}",
+        str_field(target, "sequenceName")
+            .or_else(|| str_field(target, "sequenceId"))
+            .unwrap_or("sequence")
+    );
+
+    let mut body = String::new();
+    let _ = writeln!(body, "<h1>{}</h1>", html_escape(&title));
+    let _ = writeln!(
+        body,
+        "<p class=\"verdict {status_class}\">{}</p>",
+        html_escape(status),
+        status_class = css_class(status)
+    );
+
+    body.push_str("<table class=\"facts\">\n");
+    for (label, value) in [
+        ("Sequence", &target["sequenceId"]),
+        ("Rendered file", &target["renderedFile"]),
+        ("Profile", &target["profile"]),
+        ("Checked at", &report["checkedAt"]),
+    ] {
+        if let Some(value) = value.as_str() {
+            let _ = writeln!(
+                body,
+                "<tr><th>{label}</th><td>{}</td></tr>",
+                html_escape(value)
+            );
+        }
+    }
+    let _ = writeln!(
+        body,
+        "<tr><th>Findings</th><td>{} critical, {} error, {} warning, {} info; {} skipped</td></tr>",
+        summary["critical"],
+        summary["error"],
+        summary["warning"],
+        summary["info"],
+        summary["skipped"]
+    );
+    body.push_str("</table>\n");
+
+    for (heading, key) in [("Errors", "errors"), ("Warnings", "warnings")] {
+        let messages: Vec<&str> = report[key]
+            .as_array()
+            .map(|messages| messages.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        if messages.is_empty() {
+            continue;
+        }
+        let _ = writeln!(body, "<h2>{heading}</h2>\n<ul>");
+        for message in messages {
+            let _ = writeln!(body, "<li>{}</li>", html_escape(message));
+        }
+        body.push_str("</ul>\n");
+    }
+
+    body.push_str("<h2>Checks</h2>\n<table class=\"checks\">\n");
+    body.push_str("<tr><th>Check</th><th>Status</th><th>Result</th></tr>\n");
+    for check in checks(report) {
+        let status = str_field(check, "status").unwrap_or_default();
+        let message = str_field(check, "skipReason")
+            .or_else(|| str_field(check, "message"))
+            .unwrap_or_default();
+        let _ = writeln!(
+            body,
+            "<tr><td><code>{}</code></td><td><span class=\"badge {}\">{}</span></td><td>{}</td></tr>",
+            html_escape(str_field(check, "id").unwrap_or_default()),
+            css_class(status),
+            html_escape(status),
+            html_escape(message)
+        );
+    }
+    body.push_str("</table>\n");
+
+    let with_findings: Vec<&Value> = checks(report)
+        .iter()
+        .filter(|check| !violations(check).is_empty())
+        .collect();
+    if !with_findings.is_empty() {
+        body.push_str("<h2>Findings</h2>\n");
+    }
+    for check in with_findings {
+        let _ = writeln!(
+            body,
+            "<h3><code>{}</code></h3>\n<div class=\"findings\">",
+            html_escape(str_field(check, "id").unwrap_or_default())
+        );
+        for violation in violations(check) {
+            let severity = str_field(violation, "severity").unwrap_or_default();
+            let range = &violation["timeRange"];
+            let when = match (range["startSec"].as_f64(), range["endSec"].as_f64()) {
+                (Some(start), Some(end)) => {
+                    format!("{} – {}", format_timecode(start), format_timecode(end))
+                }
+                _ => "whole sequence".to_string(),
+            };
+            let picture = str_field(violation, "id")
+                .and_then(|id| thumbnails.get(id))
+                .map(|uri| {
+                    format!(
+                        "<img src=\"{uri}\" alt=\"Frame at {}\">",
+                        html_escape(&when)
+                    )
+                })
+                .unwrap_or_else(|| "<div class=\"no-frame\">no frame</div>".to_string());
+            let _ = writeln!(
+                body,
+                "<figure class=\"finding {}\">{picture}<figcaption><span class=\"badge {}\">{}</span> \
+                 <span class=\"when\">{}</span><br>{}</figcaption></figure>",
+                css_class(severity),
+                css_class(severity),
+                html_escape(severity),
+                html_escape(&when),
+                html_escape(str_field(violation, "message").unwrap_or_default())
+            );
+        }
+        body.push_str("</div>\n");
+    }
+
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<style>{HTML_STYLE}</style>\n</head>\n<body>\n{body}</body>\n</html>\n",
+        html_escape(&title)
+    )
+}
+
+const HTML_STYLE: &str = "\
+body{font-family:system-ui,sans-serif;margin:2rem;color:#1d1d1f;background:#fafafa}\
+table{border-collapse:collapse;margin:1rem 0}\
+th,td{text-align:left;padding:.35rem .75rem;border-bottom:1px solid #ddd;vertical-align:top}\
+.verdict{font-size:1.25rem;font-weight:600;text-transform:uppercase}\
+.badge{display:inline-block;padding:.1rem .5rem;border-radius:.75rem;font-size:.8rem;background:#e5e5ea}\
+.passed,.ok,.info{background:#d1f2d9}.warned,.warning{background:#ffe8b3}\
+.failed,.error,.critical,.errored{background:#ffd0cc}.skipped{background:#e5e5ea}\
+p.verdict{display:inline-block;padding:.25rem .75rem;border-radius:.5rem}\
+.findings{display:flex;flex-wrap:wrap;gap:1rem}\
+figure{margin:0;width:320px;background:#fff;border:1px solid #ddd;border-radius:.5rem;overflow:hidden}\
+figure img,.no-frame{display:block;width:320px;min-height:60px}\
+.no-frame{display:flex;align-items:center;justify-content:center;color:#888;background:#eee;height:180px}\
+figcaption{padding:.5rem;font-size:.9rem}.when{font-family:ui-monospace,monospace}";
+
+/// Extracts one frame per violating time range from the rendered file.
+///
+/// Frames are taken at the middle of each range, clamped inside the file.
+/// A frame that cannot be extracted is left out and reported in the notes;
+/// the report is still written, with the time range and no picture.
+pub(crate) async fn collect_thumbnails(
+    runner: &FFmpegRunner,
+    rendered_file: &Path,
+    report: &Value,
+) -> (Thumbnails, Vec<String>) {
+    let mut thumbnails = Thumbnails::new();
+    let mut notes = Vec::new();
+
+    let duration_sec = report["measurements"]["durationSec"].as_f64();
+    let targets: Vec<(String, f64)> = checks(report)
+        .iter()
+        .flat_map(violations)
+        .filter_map(|violation| {
+            let id = str_field(violation, "id")?;
+            let start = violation["timeRange"]["startSec"].as_f64()?;
+            let end = violation["timeRange"]["endSec"].as_f64()?;
+            let mut time_sec = (start + end.max(start)) / 2.0;
+            if let Some(duration) = duration_sec {
+                // Seeking to the very end lands past the last decodable frame.
+                time_sec = time_sec.min((duration - 0.1).max(0.0));
+            }
+            Some((id.to_string(), time_sec.max(0.0)))
+        })
+        .collect();
+
+    if targets.len() > MAX_REPORT_THUMBNAILS {
+        notes.push(format!(
+            "HTML report: thumbnails limited to the first {MAX_REPORT_THUMBNAILS} of {} findings",
+            targets.len()
+        ));
+    }
+
+    let scratch = match tempfile::tempdir() {
+        Ok(dir) => dir,
+        Err(error) => {
+            notes.push(format!("HTML report: no thumbnails ({error})"));
+            return (thumbnails, notes);
+        }
+    };
+    let options = FrameExtractOptions {
+        overwrite: true,
+        max_width: Some(THUMBNAIL_WIDTH_PX),
+        quality: Some(5),
+    };
+
+    let mut failed = 0;
+    for (index, (id, time_sec)) in targets.iter().take(MAX_REPORT_THUMBNAILS).enumerate() {
+        let frame = scratch.path().join(format!("finding_{index}.jpg"));
+        let bytes = match runner
+            .extract_frame_with_options(rendered_file, *time_sec, &frame, &options)
+            .await
+        {
+            Ok(()) => std::fs::read(&frame).ok().filter(|bytes| !bytes.is_empty()),
+            Err(_) => None,
+        };
+        match bytes {
+            Some(bytes) => {
+                let encoded = base64::engine::general_purpose::STANDARD.encode(bytes);
+                thumbnails.insert(id.clone(), format!("data:image/jpeg;base64,{encoded}"));
+            }
+            None => failed += 1,
+        }
+    }
+    if failed > 0 {
+        notes.push(format!(
+            "HTML report: {failed} thumbnail(s) could not be extracted from the rendered file"
+        ));
+    }
+
+    (thumbnails, notes)
+}
+
+// ── Shared helpers ─────────────────────────────────────────────────────
+
+fn checks(report: &Value) -> &[Value] {
+    report["checks"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+fn violations(check: &Value) -> &[Value] {
+    check["violations"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> Option<&'a str> {
+    value.get(key).and_then(Value::as_str)
+}
+
+fn severity_of(violation: &Value) -> Option<Severity> {
+    match str_field(violation, "severity")? {
+        "info" => Some(Severity::Info),
+        "warning" => Some(Severity::Warning),
+        "error" => Some(Severity::Error),
+        "critical" => Some(Severity::Critical),
+        _ => None,
+    }
+}
+
+fn severity_rank(severity: &str) -> u8 {
+    match severity {
+        "critical" => 3,
+        "error" => 2,
+        "warning" => 1,
+        _ => 0,
+    }
+}
+
+fn severity_name(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Info => "info",
+        Severity::Warning => "warning",
+        Severity::Error => "error",
+        Severity::Critical => "critical",
+    }
+}
+
+/// One line per finding: `[severity] start-end: message`.
+fn finding_lines(findings: &[&Value]) -> String {
+    findings
+        .iter()
+        .map(|violation| {
+            let range = &violation["timeRange"];
+            let when = match (range["startSec"].as_f64(), range["endSec"].as_f64()) {
+                (Some(start), Some(end)) => {
+                    format!(" {}-{}", format_timecode(start), format_timecode(end))
+                }
+                _ => String::new(),
+            };
+            format!(
+                "[{}]{}: {}",
+                str_field(violation, "severity").unwrap_or_default(),
+                when,
+                str_field(violation, "message").unwrap_or_default()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `HH:MM:SS.mmm`, the form producers paste into an edit decision list.
+fn format_timecode(time_sec: f64) -> String {
+    let millis = (time_sec.max(0.0) * 1000.0).round() as u64;
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+/// Escapes text for XML attributes and content, dropping characters XML 1.0
+/// cannot carry at all.
+fn xml_escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\n' | '\t' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+/// Status and severity names double as CSS classes; anything else gets none.
+fn css_class(name: &str) -> &'static str {
+    match name {
+        "ok" => "ok",
+        "passed" => "passed",
+        "warned" => "warned",
+        "warning" => "warning",
+        "failed" => "failed",
+        "errored" => "errored",
+        "skipped" => "skipped",
+        "info" => "info",
+        "error" => "error",
+        "critical" => "critical",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> Value {
+        serde_json::json!({
+            "status": "failed",
+            "checkedAt": "2026-01-01T00:00:00Z",
+            "durationMs": 1500,
+            "target": {
+                "sequenceId": "seq_1",
+                "sequenceName": "Episode <1>",
+                "renderedFile": "/renders/ep1.mp4",
+                "profile": "broadcast",
+            },
+            "summary": { "critical": 0, "error": 1, "warning": 0, "info": 1, "skipped": 1 },
+            "checks": [
+                {
+                    "id": "timeline.gap", "category": "structural", "status": "failed",
+                    "message": "Gap of 1.0s", "violations": [{
+                        "id": "v_gap", "severity": "error", "message": "Gap of 1.0s at \"10s\"",
+                        "timeRange": { "startSec": 10.0, "endSec": 11.0 },
+                        "entities": ["track_1"], "autoFixable": true,
+                    }],
+                },
+                {
+                    "id": "shot.length_stats", "category": "structural", "status": "warned",
+                    "message": "1 shot", "violations": [{
+                        "id": "v_stats", "severity": "info", "message": "=1 shot, median 10s",
+                        "entities": [], "autoFixable": false,
+                    }],
+                },
+                { "id": "clip.orphan", "category": "structural", "status": "passed", "message": "ok" },
+                {
+                    "id": "render.frozen", "category": "rendered", "status": "skipped",
+                    "skipReason": "no rendered measurements available", "message": "Skipped",
+                },
+            ],
+            "measurements": { "measured": false },
+            "warnings": ["1 rendered check(s) were skipped"],
+            "errors": [],
+        })
+    }
+
+    #[test]
+    fn test_junit_fails_only_checks_that_reach_the_threshold() {
+        let xml = junit_xml(&sample_report(), Severity::Error);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+        assert!(xml.contains(
+            "<testsuites name=\"openreelio verify\" tests=\"4\" failures=\"1\" errors=\"0\" skipped=\"1\" time=\"1.500\">"
+        ));
+        assert!(xml.contains("name=\"openreelio.verify.Episode &lt;1&gt;\""));
+        assert!(xml.contains(
+            "<testcase classname=\"verify.structural\" name=\"timeline.gap\" time=\"0\">"
+        ));
+        assert!(xml.contains("<failure type=\"error\" message=\"Gap of 1.0s\">"));
+        assert!(xml.contains("[error] 00:00:10.000-00:00:11.000: Gap of 1.0s at &quot;10s&quot;"));
+        assert!(xml.contains("<system-out>[info]: =1 shot, median 10s</system-out>"));
+        assert!(xml.contains(
+            "<testcase classname=\"verify.structural\" name=\"clip.orphan\" time=\"0\"/>"
+        ));
+        assert!(xml.contains("<skipped message=\"no rendered measurements available\"/>"));
+        assert!(xml.contains("<property name=\"profile\" value=\"broadcast\"/>"));
+
+        let strict = junit_xml(&sample_report(), Severity::Info);
+        assert!(strict.contains("failures=\"2\""));
+    }
+
+    #[test]
+    fn test_csv_has_a_row_per_finding_and_per_clean_check() {
+        let csv = csv(&sample_report());
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines.len(), 5, "{csv}");
+        assert!(lines[0].starts_with("check_id,category,status,severity,start_sec"));
+        assert_eq!(
+            lines[1],
+            "timeline.gap,structural,failed,error,10.000,11.000,00:00:10.000,00:00:11.000,\
+             \"Gap of 1.0s at \"\"10s\"\"\",track_1,true"
+        );
+        // A message a spreadsheet would evaluate stays text.
+        assert_eq!(
+            lines[2],
+            "shot.length_stats,structural,warned,info,,,,,\"'=1 shot, median 10s\",,false"
+        );
+        assert_eq!(lines[3], "clip.orphan,structural,passed,,,,,,ok,,false");
+        assert!(lines[4].starts_with("render.frozen,rendered,skipped,"));
+    }
+
+    #[test]
+    fn test_html_is_self_contained_and_escaped() {
+        let mut thumbnails = Thumbnails::new();
+        thumbnails.insert(
+            "v_gap".to_string(),
+            "data:image/jpeg;base64,AAAA".to_string(),
+        );
+
+        let html = html(&sample_report(), &thumbnails);
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>QC report: Episode &lt;1&gt;</title>"));
+        assert!(html.contains("<img src=\"data:image/jpeg;base64,AAAA\""));
+        assert!(html.contains("00:00:10.000 – 00:00:11.000"));
+        assert!(html.contains("<div class=\"no-frame\">no frame</div>"));
+        assert!(html.contains("1 rendered check(s) were skipped"));
+        assert!(!html.contains("<script"), "the page must not need scripts");
+        assert!(
+            !html.contains("http://") && !html.contains("https://"),
+            "nothing may be fetched"
+        );
+    }
+
+    #[test]
+    fn test_format_timecode_carries_milliseconds() {
+        assert_eq!(format_timecode(0.0), "00:00:00.000");
+        assert_eq!(format_timecode(3723.4567), "01:02:03.457");
+        assert_eq!(format_timecode(-1.0), "00:00:00.000");
+    }
+}
@@ -0,0 +1,77 @@
+//! Final Cut Pro 7 XML interchange: `xmeml import`.
+//!
+//! `xmeml import` reads the first sequence of an XMEML document — what
+//! Premiere Pro and DaVinci Resolve export as "Final Cut Pro XML", or
+//! OpenReelio's own XMEML export — into a **new sequence** created at the
+//! document's frame rate and frame size. Each track becomes a track of its
+//! kind; media is relinked to project assets by path, then by file name, and
+//! imported only when neither matches. Everything the document carries that
+//! OpenReelio does not import is named in `warnings` and `unsupported`.
+//!
+//! The plan, dry run and report are shared with the other importers; see
+//! [`super::interchange_import`].
+
+use clap::Subcommand;
+
+use openreelio_core::interchange::xmeml_import::{
+    parse_xmeml, xmeml_to_plan_steps, XmemlImportContext,
+};
+
+use super::interchange_import::{self, ImportArgs, PlannedImport};
+
+#[derive(Subcommand)]
+pub enum XmemlAction {
+    /// Import an XMEML sequence (.xml) into a new sequence
+    Import {
+        #[command(flatten)]
+        args: ImportArgs,
+    },
+}
+
+pub fn execute(action: XmemlAction) -> anyhow::Result<()> {
+    match action {
+        XmemlAction::Import { args } => interchange_import::exit_with(run_import(&args)),
+    }
+}
+
+/// Same ceiling as an OTIO document: a cut list, not media.
+const MAX_XMEML_BYTES: u64 = 64 * 1024 * 1024;
+
+fn run_import(args: &ImportArgs) -> anyhow::Result<i32> {
+    let document = super::otio::read_capped(&args.file, MAX_XMEML_BYTES, "XMEML")?;
+    let parsed = parse_xmeml(&document).map_err(|error| anyhow::anyhow!(error))?;
+    let sequence_name = interchange_import::sequence_name(
+        args.name.clone(),
+        parsed.sequence_name.clone(),
+        &args.file,
+        "XMEML",
+    );
+
+    interchange_import::run(args, &args.file, "XMEML", &sequence_name, |target| {
+        let fps = interchange_import::sequence_fps(parsed.fps.clone(), target.state);
+        let import = xmeml_to_plan_steps(
+            &parsed,
+            &XmemlImportContext {
+                sequence_name: &sequence_name,
+                sequence_fps: fps.clone(),
+                assets: &target.state.assets,
+                project_root: target.project_root,
+                allow_external_media: args.allow_external_media,
+            },
+        )
+        .map_err(|error| anyhow::anyhow!(error))?;
+
+        Ok(PlannedImport {
+            fps,
+            steps: import.steps,
+            asset_imports: import.asset_imports,
+            warnings: import.warnings,
+            unsupported: import.unsupported,
+            report: serde_json::json!({
+                "canvas": { "width": import.canvas.0, "height": import.canvas.1 },
+                "clipCount": parsed.clips.len(),
+                "placedClips": import.placed_clips,
+            }),
+        })
+    })
+}
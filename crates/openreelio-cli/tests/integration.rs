@@ -1003,6 +1003,38 @@ fn test_project_info() {
     assert!(result["assets"].is_array());
 }
 
+#[test]
+fn test_project_update_sets_export_metadata() {
+    let dir = create_temp_project("update_test");
+    let path = project_path(&dir, "update_test");
+    let result = run_cli_ok(&[
+        "project",
+        "update",
+        "--path",
+        &path,
+        "--author",
+        "Jane Doe",
+        "--copyright",
+        "(c) 2026 Jane Doe",
+    ]);
+    assert_eq!(result["status"], "ok");
+    assert_eq!(result["name"], "update_test");
+    assert_eq!(result["author"], "Jane Doe");
+    assert_eq!(result["copyright"], "(c) 2026 Jane Doe");
+
+    // The settings survive a reload
+    let reopened = run_cli_ok(&[
+        "project",
+        "update",
+        "--path",
+        &path,
+        "--description",
+        "Launch film",
+    ]);
+    assert_eq!(reopened["copyright"], "(c) 2026 Jane Doe");
+    assert_eq!(reopened["description"], "Launch film");
+}
+
 #[test]
 fn test_project_save() {
     let dir = create_temp_project("save_test");
@@ -1590,7 +1622,7 @@ fn test_validation_caption_inverted_range() {
 fn test_render_presets() {
     let result = run_cli_ok(&["render", "presets"]);
     let presets = result["presets"].as_array().unwrap();
-    assert_eq!(presets.len(), 7);
+    assert_eq!(presets.len(), 9);
     // Verify first preset structure
     assert_eq!(presets[0]["id"], "mp4_h264_1080p");
     let ids: Vec<&str> = presets
@@ -1601,6 +1633,10 @@ fn test_render_presets() {
         ids.contains(&"proxy_480p") && ids.contains(&"mp4_draft"),
         "expected proxy and draft presets, got: {ids:?}"
     );
+    assert!(
+        ids.contains(&"dnxhr_hqx"),
+        "expected the DNxHR mastering preset, got: {ids:?}"
+    );
 }
 
 #[test]
@@ -1644,6 +1680,137 @@ fn test_render_start_invalid_preset() {
     );
 }
 
+#[test]
+fn test_render_queue_add_reorder_pause_cancel_retry_remove() {
+    let dir = create_temp_project("render_queue");
+    let path = project_path(&dir, "render_queue");
+    let output_a = dir.path().join("a.mp4").to_string_lossy().to_string();
+    let output_b = dir.path().join("b.mov").to_string_lossy().to_string();
+
+    let first = run_cli_ok(&[
+        "render", "queue", "add", "--path", &path, "--output", &output_a,
+    ]);
+    assert_eq!(first["item"]["status"], "pending");
+    assert_eq!(first["item"]["maxAttempts"], 3);
+    let first_id = first["item"]["id"].as_str().unwrap().to_string();
+    let second = run_cli_ok(&[
+        "render",
+        "queue",
+        "add",
+        "--path",
+        &path,
+        "--output",
+        &output_b,
+        "--preset",
+        "prores_422",
+        "--max-attempts",
+        "1",
+    ]);
+    let second_id = second["item"]["id"].as_str().unwrap().to_string();
+
+    let (_stdout, stderr) = run_cli_err(&[
+        "render", "queue", "add", "--path", &path, "--output", &output_a, "--preset", "nope",
+    ]);
+    assert!(stderr.contains("Unknown preset"), "got: {stderr}");
+
+    let moved = run_cli_ok(&[
+        "render",
+        "queue",
+        "move",
+        "--path",
+        &path,
+        "--id",
+        &second_id,
+        "--position",
+        "0",
+    ]);
+    assert_eq!(moved["items"][0]["id"], second_id.as_str());
+    assert_eq!(moved["items"][1]["id"], first_id.as_str());
+
+    let paused = run_cli_ok(&[
+        "render", "queue", "pause", "--path", &path, "--id", &first_id,
+    ]);
+    assert_eq!(paused["items"][1]["status"], "paused");
+    let cancelled = run_cli_ok(&[
+        "render", "queue", "cancel", "--path", &path, "--id", &second_id,
+    ]);
+    assert_eq!(cancelled["items"][0]["status"], "cancelled");
+    let retried = run_cli_ok(&[
+        "render", "queue", "retry", "--path", &path, "--id", &second_id,
+    ]);
+    assert_eq!(retried["items"][0]["status"], "pending");
+
+    run_cli_ok(&[
+        "render", "queue", "remove", "--path", &path, "--id", &second_id,
+    ]);
+    let listed = run_cli_ok(&["render", "queue", "list", "--path", &path]);
+    assert_eq!(listed["items"].as_array().unwrap().len(), 1);
+    assert_eq!(listed["items"][0]["id"], first_id.as_str());
+    assert_eq!(listed["paused"], false);
+}
+
+#[test]
+fn test_render_queue_run_recovers_items_left_rendering() {
+    let dir = create_temp_project("render_queue_recover");
+    let path = project_path(&dir, "render_queue_recover");
+    let output = dir.path().join("out.mp4").to_string_lossy().to_string();
+    let added = run_cli_ok(&[
+        "render", "queue", "add", "--path", &path, "--output", &output,
+    ]);
+    let id = added["item"]["id"].as_str().unwrap().to_string();
+
+    // Simulate a runner that died mid-encode, then hold the queue so the
+    // run only recovers and never needs FFmpeg.
+    let queue_file = std::path::Path::new(&path).join(".openreelio/render_queue.json");
+    let mut queue: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&queue_file).unwrap()).unwrap();
+    queue["items"][0]["status"] = "rendering".into();
+    queue["items"][0]["attempts"] = 1.into();
+    std::fs::write(&queue_file, serde_json::to_string(&queue).unwrap()).unwrap();
+    run_cli_ok(&["render", "queue", "pause", "--path", &path]);
+
+    let result = run_cli_ok(&["render", "queue", "run", "--path", &path]);
+
+    assert_eq!(result["status"], "ok");
+    assert_eq!(result["recovered"][0], id.as_str());
+    assert_eq!(result["processed"].as_array().unwrap().len(), 0);
+    assert_eq!(result["queuePaused"], true);
+    assert_eq!(result["pending"], 1);
+}
+
+#[test]
+fn test_render_queue_run_refuses_a_queue_another_runner_holds() {
+    let dir = create_temp_project("render_queue_held");
+    let path = project_path(&dir, "render_queue_held");
+    let output = dir.path().join("out.mp4").to_string_lossy().to_string();
+    run_cli_ok(&[
+        "render", "queue", "add", "--path", &path, "--output", &output,
+    ]);
+
+    // A live runner is mid-encode on the item.
+    let queue_file = std::path::Path::new(&path).join(".openreelio/render_queue.json");
+    let mut queue: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&queue_file).unwrap()).unwrap();
+    queue["items"][0]["status"] = "rendering".into();
+    queue["items"][0]["attempts"] = 1.into();
+    std::fs::write(&queue_file, serde_json::to_string(&queue).unwrap()).unwrap();
+    let _runner =
+        openreelio_core::render::RenderQueue::lock_runner(std::path::Path::new(&path)).unwrap();
+
+    let (_stdout, stderr, code) = run_cli_exit(&["render", "queue", "run", "--path", &path]);
+
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("Another runner"),
+        "expected the held queue to be refused, got: {stderr}"
+    );
+    let listed = run_cli_ok(&["render", "queue", "list", "--path", &path]);
+    assert_eq!(
+        listed["items"][0]["status"], "rendering",
+        "the live runner's item must not be recovered: {listed}"
+    );
+}
+
 #[test]
 fn test_ffmpeg_info_reports_resolved_binaries() {
     if available_ffmpeg_path().is_none() {
@@ -2457,6 +2624,45 @@ fn test_render_start_rejects_proxy_combined_with_preset() {
     );
 }
 
+#[test]
+fn test_render_start_rejects_unknown_loudness_spec() {
+    let dir = create_temp_project("render_loudness_err");
+    let path = project_path(&dir, "render_loudness_err");
+    let (_stdout, stderr) = run_cli_err(&[
+        "render",
+        "start",
+        "--path",
+        &path,
+        "--output",
+        "out.mp4",
+        "--loudness",
+        "loud",
+        "--true-peak",
+        "-2",
+    ]);
+    assert!(
+        stderr.contains("Unknown loudness spec 'loud'"),
+        "Expected a loudness spec error, got: {}",
+        stderr
+    );
+
+    let (_stdout, stderr) = run_cli_err(&[
+        "render",
+        "start",
+        "--path",
+        &path,
+        "--output",
+        "out.mp4",
+        "--true-peak",
+        "-2",
+    ]);
+    assert!(
+        stderr.contains("--loudness"),
+        "Expected --true-peak to require --loudness, got: {}",
+        stderr
+    );
+}
+
 #[test]
 fn test_render_start_rejects_inverted_range() {
     let dir = create_temp_project("render_range_err");
@@ -6925,6 +7131,140 @@ fn test_verify_rejects_an_unknown_check_id_with_the_tool_failure_code() {
     );
 }
 
+/// Feature: Verify delivery profiles
+/// Scenario: should run the checks a profile switches on and name it in the report
+#[test]
+fn test_verify_applies_a_delivery_profile() {
+    let (_dir, path, _asset_id, _track_id) = create_project_with_placed_dummy("verify_profile");
+
+    let (stdout, stderr, _code) = run_cli_exit(&[
+        "verify",
+        "--path",
+        &path,
+        "--structural-only",
+        "--profile",
+        "social_vertical",
+    ]);
+    let report: serde_json::Value = serde_json::from_str(&stdout)
+        .unwrap_or_else(|error| panic!("{error}\n{stdout}\nstderr: {stderr}"));
+
+    assert_eq!(report["target"]["profile"], "social_vertical");
+    // The profile opts in to the platform length limit, which is off by default.
+    let duration = find_check(&report, "sequence.duration");
+    assert_ne!(duration["status"], "skipped", "{duration}");
+    assert!(report["target"]["selectedChecks"]
+        .as_array()
+        .unwrap()
+        .contains(&serde_json::json!("sequence.duration")));
+}
+
+/// Feature: Verify delivery profiles
+/// Scenario: should refuse a profile it cannot apply as written
+#[test]
+fn test_verify_rejects_an_invalid_profile_with_the_tool_failure_code() {
+    let (_dir, path, _asset_id, _track_id) = create_project_with_placed_dummy("verify_bad_profile");
+    let profile_dir = std::path::Path::new(&path).join(".openreelio/qc-profiles");
+    std::fs::create_dir_all(&profile_dir).unwrap();
+    std::fs::write(
+        profile_dir.join("house.toml"),
+        "[checks.\"audio.loudness\"]\nparams = { target_lufs = \"-23\" }\n",
+    )
+    .unwrap();
+
+    let (_stdout, stderr, code) = run_cli_exit(&[
+        "verify",
+        "--path",
+        &path,
+        "--structural-only",
+        "--profile",
+        "house",
+    ]);
+
+    assert_eq!(code, 2, "a broken profile is a tool failure, not a finding");
+    assert!(
+        stderr.contains("house.toml") && stderr.contains("params.target_lufs: expected a number"),
+        "expected the error to locate the problem, got: {stderr}"
+    );
+}
+
+/// Feature: Verify report files
+/// Scenario: should write JUnit, HTML and CSV files that agree with stdout
+#[test]
+fn test_verify_writes_junit_html_and_csv_reports() {
+    let (dir, path, asset_id, track_id) = create_project_with_placed_dummy("verify_report_files");
+    run_cli_ok(&[
+        "timeline", "insert", "--path", &path, "--asset", &asset_id, "--track", &track_id, "--at",
+        "11.0",
+    ]);
+
+    let junit = dir.path().join("qc.xml");
+    let html = dir.path().join("qc.html");
+    let csv = dir.path().join("qc.csv");
+    let (stdout, stderr, code) = run_cli_exit(&[
+        "verify",
+        "--path",
+        &path,
+        "--structural-only",
+        "--junit",
+        junit.to_str().unwrap(),
+        "--html",
+        html.to_str().unwrap(),
+        "--csv",
+        csv.to_str().unwrap(),
+    ]);
+    assert_eq!(
+        code, 1,
+        "the gap still breaches the threshold.\nstderr: {stderr}"
+    );
+    let report: serde_json::Value =
+        serde_json::from_str(&stdout).unwrap_or_else(|error| panic!("{error}\n{stdout}"));
+    let check_count = report["checks"].as_array().unwrap().len();
+
+    let junit = std::fs::read_to_string(&junit).expect("JUnit report written");
+    assert!(
+        junit.contains(&format!("tests=\"{check_count}\" failures=\"1\"")),
+        "{junit}"
+    );
+    assert!(junit.contains("name=\"timeline.gap\""), "{junit}");
+    assert!(junit.contains("<failure type=\"error\""), "{junit}");
+    assert!(junit.contains("<skipped message="), "{junit}");
+
+    let csv = std::fs::read_to_string(&csv).expect("CSV report written");
+    assert!(
+        csv.lines()
+            .any(|line| line.starts_with("timeline.gap,structural,failed,error,10.000,11.000,")),
+        "{csv}"
+    );
+
+    let html = std::fs::read_to_string(&html).expect("HTML report written");
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains("timeline.gap"));
+    assert!(html.contains("00:00:10.000"));
+}
+
+/// Feature: Verify report files
+/// Scenario: should refuse a report path it could not write before running anything
+#[test]
+fn test_verify_rejects_a_report_path_in_a_missing_directory() {
+    let (dir, path, _asset_id, _track_id) = create_project_with_placed_dummy("verify_report_path");
+    let missing = dir.path().join("no_such_dir").join("qc.xml");
+
+    let (_stdout, stderr, code) = run_cli_exit(&[
+        "verify",
+        "--path",
+        &path,
+        "--structural-only",
+        "--junit",
+        missing.to_str().unwrap(),
+    ]);
+
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains("Invalid value for --junit") && stderr.contains("does not exist"),
+        "{stderr}"
+    );
+}
+
 #[test]
 fn test_verify_measures_a_rendered_file() {
     let Some((dir, path, asset_id)) = create_project_with_media(
@@ -7465,7 +7805,14 @@ fn test_verify_fails_a_render_with_no_video_stream() {
 
     // The picture checks must say they had nothing to look at rather than
     // report the picture they never saw as clean.
-    for picture_check in ["render.black_frames", "render.frozen"] {
+    for picture_check in [
+        "render.black_frames",
+        "render.frozen",
+        "render.flashing",
+        "render.broadcast_legal",
+        "render.av_sync",
+        "render.frame_timing",
+    ] {
         let check = find_check(&report, picture_check);
         assert_eq!(
             check["status"], "skipped",
@@ -7584,6 +7931,43 @@ fn test_verify_fails_a_render_black_across_several_ranges() {
     }
 }
 
+/// Feature: Verify against a rendered file
+/// Scenario: should embed a frame from the render beside every finding
+#[test]
+fn test_verify_html_report_embeds_a_thumbnail_per_finding() {
+    let (dir, path, _asset_id, _track_id) = create_project_with_placed_dummy("verify_html_thumbs");
+
+    let render_path = dir.path().join("intermittent-black.mp4");
+    if !create_intermittently_black_render(&render_path) {
+        return;
+    }
+
+    let html = dir.path().join("qc.html");
+    let (stdout, stderr, code) = run_cli_exit(&[
+        "verify",
+        "--path",
+        &path,
+        "--file",
+        render_path.to_str().unwrap(),
+        "--html",
+        html.to_str().unwrap(),
+    ]);
+    assert_eq!(code, 1, "the fixture is a broken render: {stderr}");
+
+    let report: serde_json::Value = serde_json::from_str(&stdout).expect("verify prints JSON");
+    let findings = find_check(&report, "render.black_frames")["violations"]
+        .as_array()
+        .expect("black findings")
+        .len();
+    assert!(findings >= 2, "the fixture must produce several findings");
+
+    let html = std::fs::read_to_string(&html).unwrap_or_else(|error| panic!("{error}\n{stderr}"));
+    assert!(
+        html.matches("<img src=\"data:image/jpeg;base64,").count() >= findings,
+        "every black range needs its thumbnail"
+    );
+}
+
 #[test]
 fn test_verify_reports_a_missing_render_file_as_a_tool_failure() {
     let (_dir, path, _asset_id, _track_id) =
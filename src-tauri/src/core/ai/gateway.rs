@@ -201,6 +201,27 @@ const SYSTEM_PROMPT_BASE: &str = r#"You are an AI video editing assistant for Op
 ### MoveClip - Reposition clip
 { "commandType": "MoveClip", "params": { "sequenceId": "required", "trackId": "required", "clipId": "required", "newTimelineIn": number, "newTrackId"?: string }}
 
+### SlipClip - Shift source in/out, keep timeline position
+{ "commandType": "SlipClip", "params": { "sequenceId": "required", "trackId": "required", "clipId": "required", "deltaSec": number }}
+
+### SlideClip - Move clip between neighbours, trimming them to keep total length
+{ "commandType": "SlideClip", "params": { "sequenceId": "required", "trackId": "required", "clipId": "required", "deltaSec": number }}
+
+### RollEdit - Move the cut between two adjacent clips
+{ "commandType": "RollEdit", "params": { "sequenceId": "required", "trackId": "required", "outgoingClipId": "required", "incomingClipId": "required", "deltaSec": number }}
+
+### CreateMulticamClip - Group synced camera clips (one video track per angle) into a multicam clip
+{ "commandType": "CreateMulticamClip", "params": { "sequenceId": "required", "trackId": "required", "clipIds": ["required"], "name"?: string }}
+
+### SwitchMulticamAngle - Cut a multicam clip to another angle at a time (or the whole clip)
+{ "commandType": "SwitchMulticamAngle", "params": { "sequenceId": "required", "trackId": "required", "clipId": "required", "angleTrackId": "required", "atSec"?: number }}
+
+### FlattenMulticamClip - Replace a multicam clip with its active angle's media
+{ "commandType": "FlattenMulticamClip", "params": { "sequenceId": "required", "trackId": "required", "clipId": "required" }}
+
+### SyncClips - Place clips at audio-sync offsets (seconds of source time) relative to a reference clip
+{ "commandType": "SyncClips", "params": { "sequenceId": "required", "referenceTrackId": "required", "referenceClipId": "required", "clips": [{ "trackId": "required", "clipId": "required", "offsetSec": number }], "link"?: boolean }}
+
 ## Track Commands
 
 ### AddTrack
@@ -209,6 +230,20 @@ const SYSTEM_PROMPT_BASE: &str = r#"You are an AI video editing assistant for Op
 ### ToggleTrackMute
 { "commandType": "ToggleTrackMute", "params": { "sequenceId": "required", "trackId": "required", "muted": boolean }}
 
+## Audio Bus Commands
+
+### CreateAudioBus - Add a submix bus, feeding the master or another bus
+{ "commandType": "CreateAudioBus", "params": { "sequenceId": "required", "name": "required", "outputBusId"?: string }}
+
+### SetTrackOutputBus - Route a track to a bus (omit busId for the master)
+{ "commandType": "SetTrackOutputBus", "params": { "sequenceId": "required", "trackId": "required", "busId"?: string }}
+
+### UpdateAudioBus
+{ "commandType": "UpdateAudioBus", "params": { "sequenceId": "required", "busId": "required", "name"?: string, "volumeDb"?: number, "muted"?: boolean, "outputBusId"?: string, "routeToMaster"?: boolean }}
+
+### AddAudioBusEffect - Insert an audio effect (compressor, eq_band, limiter, ...) on a bus
+{ "commandType": "AddAudioBusEffect", "params": { "sequenceId": "required", "busId": "required", "effectType": string, "params": object }}
+
 ## Effect Commands
 
 ### AddEffect
@@ -842,6 +877,120 @@ Return JSON array of edit scripts."#;
                         },
                     }
                 }
+                "SlipClip" | "SlideClip" | "RollEdit" => {
+                    if cmd.params.get("sequenceId").is_none() {
+                        issues.push(format!(
+                            "{} command {} missing sequenceId",
+                            cmd.command_type, i
+                        ));
+                    }
+                    if cmd.params.get("trackId").is_none() {
+                        issues.push(format!(
+                            "{} command {} missing trackId",
+                            cmd.command_type, i
+                        ));
+                    }
+                    let clip_keys: &[&str] = if cmd.command_type == "RollEdit" {
+                        &["outgoingClipId", "incomingClipId"]
+                    } else {
+                        &["clipId"]
+                    };
+                    for key in clip_keys {
+                        if cmd.params.get(*key).is_none() {
+                            issues.push(format!(
+                                "{} command {} missing {}",
+                                cmd.command_type, i, key
+                            ));
+                        }
+                    }
+                    match cmd.params.get("deltaSec") {
+                        None => issues.push(format!(
+                            "{} command {} missing deltaSec",
+                            cmd.command_type, i
+                        )),
+                        Some(v) => match v.as_f64() {
+                            Some(t) if t.is_finite() => {}
+                            _ => issues.push(format!(
+                                "{} command {} invalid deltaSec (must be a finite number)",
+                                cmd.command_type, i
+                            )),
+                        },
+                    }
+                }
+                "SyncClips" => {
+                    for key in ["sequenceId", "referenceTrackId", "referenceClipId"] {
+                        if cmd.params.get(key).is_none() {
+                            issues.push(format!("SyncClips command {} missing {}", i, key));
+                        }
+                    }
+                    match cmd.params.get("clips").and_then(|v| v.as_array()) {
+                        Some(clips) if !clips.is_empty() => {
+                            for (j, entry) in clips.iter().enumerate() {
+                                let has_ids = entry.get("trackId").is_some_and(|v| v.is_string())
+                                    && entry.get("clipId").is_some_and(|v| v.is_string());
+                                let offset_ok = entry
+                                    .get("offsetSec")
+                                    .and_then(|v| v.as_f64())
+                                    .is_some_and(f64::is_finite);
+                                if !has_ids || !offset_ok {
+                                    issues.push(format!(
+                                        "SyncClips command {} clips[{}] needs trackId, clipId and a finite offsetSec",
+                                        i, j
+                                    ));
+                                }
+                            }
+                        }
+                        _ => issues.push(format!(
+                            "SyncClips command {} requires a non-empty clips array",
+                            i
+                        )),
+                    }
+                }
+                "CreateAudioBus"
+                | "RemoveAudioBus"
+                | "UpdateAudioBus"
+                | "SetTrackOutputBus"
+                | "AddAudioBusEffect"
+                | "RemoveAudioBusEffect" => {
+                    let required: &[&str] = match cmd.command_type.as_str() {
+                        "CreateAudioBus" => &["sequenceId", "name"],
+                        "SetTrackOutputBus" => &["sequenceId", "trackId"],
+                        "AddAudioBusEffect" => &["sequenceId", "busId", "effectType"],
+                        "RemoveAudioBusEffect" => &["sequenceId", "busId", "effectId"],
+                        _ => &["sequenceId", "busId"],
+                    };
+                    for key in required {
+                        if cmd.params.get(*key).is_none() {
+                            issues.push(format!(
+                                "{} command {} missing {}",
+                                cmd.command_type, i, key
+                            ));
+                        }
+                    }
+                }
+                "CreateMulticamClip" | "SwitchMulticamAngle" | "FlattenMulticamClip" => {
+                    let required: &[&str] = match cmd.command_type.as_str() {
+                        "CreateMulticamClip" => &["sequenceId", "trackId", "clipIds"],
+                        "SwitchMulticamAngle" => {
+                            &["sequenceId", "trackId", "clipId", "angleTrackId"]
+                        }
+                        _ => &["sequenceId", "trackId", "clipId"],
+                    };
+                    for key in required {
+                        if cmd.params.get(*key).is_none() {
+                            issues.push(format!(
+                                "{} command {} missing {}",
+                                cmd.command_type, i, key
+                            ));
+                        }
+                    }
+                    if let Some(v) = cmd.params.get("atSec").filter(|v| !v.is_null()) {
+                        if !v.as_f64().is_some_and(|t| t.is_finite() && t >= 0.0) {
+                            issues
+                                .push(format!("{} command {} invalid atSec", cmd.command_type, i));
+                        }
+                    }
+                }
                 "AddTrack" => {
                     if cmd.params.get("sequenceId").is_none() {
                         issues.push(format!("AddTrack command {} missing sequenceId", i));
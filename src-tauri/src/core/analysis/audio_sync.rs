@@ -0,0 +1,602 @@
+//! Audio Sync — aligning multi-source footage by its sound.
+//!
+//! Cameras and a separate recorder that heard the same room share the shape of
+//! their audio even when levels, mics and noise differ. This module finds how
+//! far apart the sources started by cross-correlating their waveform envelopes
+//! (the same peak data the timeline draws), and falls back to start timecode
+//! when the audio is inconclusive.
+//!
+//! Envelope extraction is done by FFmpeg in the IPC layer; this module only
+//! compares the results.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::ffmpeg::WaveformData;
+use crate::core::{CoreError, CoreResult};
+
+/// Envelope rate of the coarse search pass, in samples per second.
+const COARSE_RATE: u32 = 10;
+
+/// Envelope floor: quieter peaks are treated as silence (-60 dBFS).
+const ENVELOPE_FLOOR: f32 = 0.001;
+
+/// Coarse samples either side of the best lag ignored when looking for the
+/// runner-up peak, so the main peak's own shoulders do not count against it.
+const PEAK_EXCLUSION_SAMPLES: i64 = 5;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// Parameters for audio sync analysis.
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioSyncParams {
+    /// Largest start offset to search, in seconds. `None` searches every
+    /// offset that leaves `min_overlap_sec` of shared audio.
+    pub max_offset_sec: Option<f64>,
+    /// Shortest stretch of audio two sources must share to be compared. Default: 5.0
+    pub min_overlap_sec: f64,
+    /// Audio matches below this confidence fall back to timecode. Default: 0.25
+    pub min_confidence: f64,
+}
+
+impl Default for AudioSyncParams {
+    fn default() -> Self {
+        Self {
+            max_offset_sec: None,
+            min_overlap_sec: 5.0,
+            min_confidence: 0.25,
+        }
+    }
+}
+
+/// One source to sync: an asset's audio envelope and/or its start timecode.
+#[derive(Clone, Debug)]
+pub struct AudioSyncSource {
+    pub asset_id: String,
+    /// Waveform peaks of the asset's audio, if it has any
+    pub envelope: Option<WaveformData>,
+    /// Source timecode of the asset's first frame, in seconds
+    pub start_timecode_sec: Option<f64>,
+}
+
+/// How an offset was found.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum SyncMethod {
+    /// Cross-correlation of the audio envelopes
+    Audio,
+    /// Difference of the start timecodes
+    Timecode,
+}
+
+/// Where one asset starts relative to the reference asset.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetSyncOffset {
+    pub asset_id: String,
+    /// Reference-asset time at which this asset's first sample plays.
+    /// Negative when this asset started recording before the reference.
+    pub offset_sec: f64,
+    /// 0.0 (no evidence) to 1.0 (unambiguous match)
+    pub confidence: f64,
+    pub method: SyncMethod,
+}
+
+/// An asset that could not be placed relative to the reference.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsyncedAsset {
+    pub asset_id: String,
+    pub reason: String,
+}
+
+/// Result of syncing a set of assets to the first one.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioSyncResult {
+    pub reference_asset_id: String,
+    /// One entry per synced asset, the reference included (offset 0)
+    pub offsets: Vec<AssetSyncOffset>,
+    pub unsynced: Vec<UnsyncedAsset>,
+}
+
+impl AudioSyncResult {
+    /// Returns the offset found for an asset, if it was synced.
+    pub fn offset_for(&self, asset_id: &str) -> Option<&AssetSyncOffset> {
+        self.offsets
+            .iter()
+            .find(|offset| offset.asset_id == asset_id)
+    }
+}
+
+/// Best alignment found by [`correlate_envelopes`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EnvelopeMatch {
+    /// Reference time at which the other envelope starts, in seconds
+    pub offset_sec: f64,
+    pub confidence: f64,
+}
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/// Syncs every source to the first one.
+///
+/// Each source is matched against the reference by audio first; when either
+/// side has no envelope or the match is weaker than `min_confidence`, the
+/// difference of the start timecodes is used instead. Sources with neither
+/// are reported as unsynced.
+pub fn compute_sync_offsets(
+    sources: &[AudioSyncSource],
+    params: &AudioSyncParams,
+) -> CoreResult<AudioSyncResult> {
+    let reference = sources.first().ok_or_else(|| {
+        CoreError::ValidationError("Audio sync requires at least one source".to_string())
+    })?;
+    validate_params(params)?;
+
+    let mut result = AudioSyncResult {
+        reference_asset_id: reference.asset_id.clone(),
+        offsets: vec![AssetSyncOffset {
+            asset_id: reference.asset_id.clone(),
+            offset_sec: 0.0,
+            confidence: 1.0,
+            method: SyncMethod::Audio,
+        }],
+        unsynced: Vec::new(),
+    };
+
+    for source in &sources[1..] {
+        if result.offset_for(&source.asset_id).is_some() {
+            continue;
+        }
+
+        let audio_match = match (&reference.envelope, &source.envelope) {
+            (Some(reference_envelope), Some(envelope)) => {
+                correlate_envelopes(reference_envelope, envelope, params)?
+            }
+            _ => None,
+        };
+        let timecode_offset = reference
+            .start_timecode_sec
+            .zip(source.start_timecode_sec)
+            .map(|(reference_tc, tc)| tc - reference_tc);
+
+        match (audio_match, timecode_offset) {
+            (Some(found), _) if found.confidence >= params.min_confidence => {
+                result.offsets.push(AssetSyncOffset {
+                    asset_id: source.asset_id.clone(),
+                    offset_sec: found.offset_sec,
+                    confidence: found.confidence,
+                    method: SyncMethod::Audio,
+                });
+            }
+            (_, Some(offset_sec)) => {
+                result.offsets.push(AssetSyncOffset {
+                    asset_id: source.asset_id.clone(),
+                    offset_sec,
+                    confidence: 1.0,
+                    method: SyncMethod::Timecode,
+                });
+            }
+            (found, None) => {
+                let reason = match found {
+                    Some(found) => format!(
+                        "Audio match confidence {:.2} is below {:.2} and no timecode is available",
+                        found.confidence, params.min_confidence
+                    ),
+                    None if source.envelope.is_none() || reference.envelope.is_none() => {
+                        "No audio envelope or timecode to compare".to_string()
+                    }
+                    None => "Audio does not overlap the reference enough to compare".to_string(),
+                };
+                result.unsynced.push(UnsyncedAsset {
+                    asset_id: source.asset_id.clone(),
+                    reason,
+                });
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Finds where `other` starts on `reference`'s clock by cross-correlation.
+///
+/// Both envelopes are turned into onset curves (rises in log level), which
+/// keeps claps, plosives and cuts while ignoring gain and steady noise. A
+/// coarse pass at [`COARSE_RATE`] tries every allowed offset; a fine pass at
+/// the envelopes' own rate refines the winner to sub-sample precision.
+///
+/// Offsets are ranked by correlation weighted by overlap length. Confidence
+/// is the winning correlation scaled by how far it stands above the best
+/// unrelated offset, so repetitive audio that matches in several places, or
+/// audio that only matches by chance, scores low.
+///
+/// Returns `None` when no offset leaves enough overlap.
+pub fn correlate_envelopes(
+    reference: &WaveformData,
+    other: &WaveformData,
+    params: &AudioSyncParams,
+) -> CoreResult<Option<EnvelopeMatch>> {
+    if reference.samples_per_second == 0 || reference.samples_per_second != other.samples_per_second
+    {
+        return Err(CoreError::ValidationError(format!(
+            "Audio sync envelopes must share a non-zero sample rate (got {} and {})",
+            reference.samples_per_second, other.samples_per_second
+        )));
+    }
+    validate_params(params)?;
+
+    let rate = reference.samples_per_second;
+    let reference_onsets = onset_curve(&reference.peaks);
+    let other_onsets = onset_curve(&other.peaks);
+
+    // Coarse pass.
+    let factor = (rate / COARSE_RATE).max(1) as usize;
+    let coarse_rate = rate as f64 / factor as f64;
+    let coarse_reference = decimate(&reference_onsets, factor);
+    let coarse_other = decimate(&other_onsets, factor);
+    let Some(coarse_lags) = lag_range(
+        coarse_reference.len(),
+        coarse_other.len(),
+        coarse_rate,
+        params,
+    ) else {
+        return Ok(None);
+    };
+    let coarse_min_overlap = (params.min_overlap_sec * coarse_rate).ceil().max(1.0) as usize;
+    let coarse_scores: Vec<(i64, f64)> = coarse_lags
+        .filter_map(|lag| {
+            normalized_correlation(&coarse_reference, &coarse_other, lag, coarse_min_overlap)
+                .map(|(correlation, overlap)| (lag, significance(correlation, overlap)))
+        })
+        .collect();
+    let Some(&(best_coarse_lag, best_significance)) =
+        coarse_scores.iter().max_by(|a, b| a.1.total_cmp(&b.1))
+    else {
+        return Ok(None);
+    };
+    let runner_up = coarse_scores
+        .iter()
+        .filter(|(lag, _)| (lag - best_coarse_lag).abs() > PEAK_EXCLUSION_SAMPLES)
+        .map(|(_, score)| *score)
+        .fold(0.0_f64, f64::max);
+
+    // Fine pass around the coarse winner.
+    let min_overlap = (params.min_overlap_sec * rate as f64).ceil().max(1.0) as usize;
+    let centre = best_coarse_lag * factor as i64;
+    let span = factor as i64;
+    let fine_scores: Vec<(i64, f64)> = (centre - span..=centre + span)
+        .filter_map(|lag| {
+            normalized_correlation(&reference_onsets, &other_onsets, lag, min_overlap)
+                .map(|(correlation, _)| (lag, correlation))
+        })
+        .collect();
+    let Some(best_index) = fine_scores
+        .iter()
+        .enumerate()
+        .max_by(|a, b| a.1 .1.total_cmp(&b.1 .1))
+        .map(|(index, _)| index)
+    else {
+        return Ok(None);
+    };
+    let (best_lag, best_score) = fine_scores[best_index];
+    let refinement = match (
+        best_index.checked_sub(1).map(|i| fine_scores[i]),
+        fine_scores.get(best_index + 1),
+    ) {
+        (Some((before_lag, before)), Some(&(after_lag, after)))
+            if before_lag == best_lag - 1 && after_lag == best_lag + 1 =>
+        {
+            parabolic_peak_offset(before, best_score, after)
+        }
+        _ => 0.0,
+    };
+
+    let distinctness = if best_significance > 0.0 {
+        (1.0 - runner_up.max(0.0) / best_significance).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+
+    Ok(Some(EnvelopeMatch {
+        offset_sec: (best_lag as f64 + refinement) / rate as f64,
+        confidence: best_score.clamp(0.0, 1.0) * distinctness,
+    }))
+}
+
+// =============================================================================
+// Internals
+// =============================================================================
+
+fn validate_params(params: &AudioSyncParams) -> CoreResult<()> {
+    if !params.min_overlap_sec.is_finite() || params.min_overlap_sec <= 0.0 {
+        return Err(CoreError::ValidationError(
+            "minOverlapSec must be a positive number".to_string(),
+        ));
+    }
+    if !(0.0..=1.0).contains(&params.min_confidence) {
+        return Err(CoreError::ValidationError(
+            "minConfidence must be between 0 and 1".to_string(),
+        ));
+    }
+    if let Some(max_offset) = params.max_offset_sec {
+        if !max_offset.is_finite() || max_offset < 0.0 {
+            return Err(CoreError::ValidationError(
+                "maxOffsetSec must be a non-negative number".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Rises in log level between consecutive envelope samples.
+fn onset_curve(peaks: &[f32]) -> Vec<f64> {
+    let mut previous = None;
+    peaks
+        .iter()
+        .map(|peak| {
+            let level = (peak.max(ENVELOPE_FLOOR) as f64).ln();
+            let rise = previous.map_or(0.0, |previous: f64| (level - previous).max(0.0));
+            previous = Some(level);
+            rise
+        })
+        .collect()
+}
+
+/// Sums consecutive blocks of `factor` samples.
+fn decimate(values: &[f64], factor: usize) -> Vec<f64> {
+    values
+        .chunks(factor)
+        .map(|block| block.iter().sum())
+        .collect()
+}
+
+/// Lags (in samples, reference index minus other index) worth trying.
+fn lag_range(
+    reference_len: usize,
+    other_len: usize,
+    rate: f64,
+    params: &AudioSyncParams,
+) -> Option<std::ops::RangeInclusive<i64>> {
+    let min_overlap = (params.min_overlap_sec * rate).ceil().max(1.0) as i64;
+    let mut lowest = min_overlap - other_len as i64;
+    let mut highest = reference_len as i64 - min_overlap;
+    if let Some(max_offset) = params.max_offset_sec {
+        let max_lag = (max_offset * rate).ceil() as i64;
+        lowest = lowest.max(-max_lag);
+        highest = highest.min(max_lag);
+    }
+    (lowest <= highest).then_some(lowest..=highest)
+}
+
+/// Pearson correlation of `reference[i + lag]` against `other[i]` over their
+/// overlap, with the overlap length, or `None` when the overlap is too short
+/// or either side is flat.
+fn normalized_correlation(
+    reference: &[f64],
+    other: &[f64],
+    lag: i64,
+    min_overlap: usize,
+) -> Option<(f64, usize)> {
+    let start = (-lag).max(0) as usize;
+    let end = (reference.len() as i64 - lag).min(other.len() as i64);
+    if end <= start as i64 || ((end as usize) - start) < min_overlap {
+        return None;
+    }
+    let end = end as usize;
+    let count = (end - start) as f64;
+
+    let (mut sum_r, mut sum_o, mut sum_rr, mut sum_oo, mut sum_ro) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for i in start..end {
+        let r = reference[(i as i64 + lag) as usize];
+        let o = other[i];
+        sum_r += r;
+        sum_o += o;
+        sum_rr += r * r;
+        sum_oo += o * o;
+        sum_ro += r * o;
+    }
+    let covariance = sum_ro - sum_r * sum_o / count;
+    let variance_r = sum_rr - sum_r * sum_r / count;
+    let variance_o = sum_oo - sum_o * sum_o / count;
+    if variance_r <= f64::EPSILON || variance_o <= f64::EPSILON {
+        return None;
+    }
+    Some((covariance / (variance_r * variance_o).sqrt(), end - start))
+}
+
+/// Ranks a correlation by how unlikely it is to be chance: a short overlap
+/// matches noise far more easily than a long one.
+fn significance(correlation: f64, overlap: usize) -> f64 {
+    correlation * (overlap as f64).sqrt()
+}
+
+/// Vertex of the parabola through three equally spaced scores, relative to
+/// the middle one (in samples, within ±0.5).
+fn parabolic_peak_offset(before: f64, peak: f64, after: f64) -> f64 {
+    let curvature = before - 2.0 * peak + after;
+    if curvature.abs() <= f64::EPSILON {
+        return 0.0;
+    }
+    (0.5 * (before - after) / curvature).clamp(-0.5, 0.5)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 100;
+
+    /// A minute of "speech": bursts at irregular, deterministic times.
+    fn scene_peaks(duration_sec: f64) -> Vec<f32> {
+        let len = (duration_sec * RATE as f64) as usize;
+        let mut state: u32 = 0x2545_f491;
+        let mut peaks = vec![0.01_f32; len];
+        let mut i = 0;
+        while i < len {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let gap = 10 + (state % 90) as usize;
+            let burst = 5 + (state % 30) as usize;
+            let level = 0.2 + (state % 80) as f32 / 100.0;
+            for peak in peaks.iter_mut().skip(i).take(burst) {
+                *peak = level;
+            }
+            i += burst + gap;
+        }
+        peaks
+    }
+
+    fn envelope(peaks: Vec<f32>) -> WaveformData {
+        WaveformData {
+            samples_per_second: RATE,
+            duration_sec: peaks.len() as f64 / RATE as f64,
+            peaks,
+            channels: 1,
+        }
+    }
+
+    /// The part of the scene a recorder that started at `start_sec` heard,
+    /// at its own gain.
+    fn recording(scene: &[f32], start_sec: f64, duration_sec: f64, gain: f32) -> WaveformData {
+        let start = (start_sec * RATE as f64) as usize;
+        let len = (duration_sec * RATE as f64) as usize;
+        envelope(
+            scene[start..start + len]
+                .iter()
+                .map(|peak| peak * gain)
+                .collect(),
+        )
+    }
+
+    fn source(asset_id: &str, envelope: Option<WaveformData>, tc: Option<f64>) -> AudioSyncSource {
+        AudioSyncSource {
+            asset_id: asset_id.to_string(),
+            envelope,
+            start_timecode_sec: tc,
+        }
+    }
+
+    #[test]
+    fn should_find_offset_between_recordings_of_the_same_scene() {
+        let scene = scene_peaks(120.0);
+        let camera = recording(&scene, 10.0, 90.0, 1.0);
+        let lav = recording(&scene, 23.45, 80.0, 0.3);
+
+        let found = correlate_envelopes(&camera, &lav, &AudioSyncParams::default())
+            .unwrap()
+            .unwrap();
+
+        assert!((found.offset_sec - 13.45).abs() < 0.02, "{found:?}");
+        assert!(found.confidence > 0.5, "{found:?}");
+    }
+
+    #[test]
+    fn should_find_negative_offset_when_other_started_first() {
+        let scene = scene_peaks(120.0);
+        let camera = recording(&scene, 30.0, 60.0, 1.0);
+        let recorder = recording(&scene, 5.0, 100.0, 2.0);
+
+        let found = correlate_envelopes(&camera, &recorder, &AudioSyncParams::default())
+            .unwrap()
+            .unwrap();
+
+        assert!((found.offset_sec + 25.0).abs() < 0.02, "{found:?}");
+    }
+
+    #[test]
+    fn should_report_low_confidence_for_unrelated_audio() {
+        let scene = scene_peaks(120.0);
+        let other_scene: Vec<f32> = scene.iter().rev().copied().collect();
+        let camera = recording(&scene, 0.0, 60.0, 1.0);
+        let unrelated = recording(&other_scene, 0.0, 60.0, 1.0);
+
+        let found = correlate_envelopes(&camera, &unrelated, &AudioSyncParams::default())
+            .unwrap()
+            .unwrap();
+
+        assert!(found.confidence < 0.25, "{found:?}");
+    }
+
+    #[test]
+    fn should_respect_max_offset() {
+        let scene = scene_peaks(120.0);
+        let camera = recording(&scene, 0.0, 90.0, 1.0);
+        let lav = recording(&scene, 40.0, 60.0, 1.0);
+        let params = AudioSyncParams {
+            max_offset_sec: Some(10.0),
+            ..AudioSyncParams::default()
+        };
+
+        let found = correlate_envelopes(&camera, &lav, &params)
+            .unwrap()
+            .unwrap();
+
+        assert!(found.offset_sec.abs() <= 10.0 + 1e-9);
+        assert!(found.confidence < 0.25, "{found:?}");
+    }
+
+    #[test]
+    fn should_reject_mismatched_envelope_rates() {
+        let camera = envelope(vec![0.5; 100]);
+        let mut lav = envelope(vec![0.5; 100]);
+        lav.samples_per_second = 50;
+
+        assert!(correlate_envelopes(&camera, &lav, &AudioSyncParams::default()).is_err());
+    }
+
+    #[test]
+    fn should_fall_back_to_timecode_when_audio_is_missing_or_weak() {
+        let scene = scene_peaks(120.0);
+        let other_scene: Vec<f32> = scene.iter().rev().copied().collect();
+        let sources = vec![
+            source(
+                "cam_a",
+                Some(recording(&scene, 0.0, 60.0, 1.0)),
+                Some(3600.0),
+            ),
+            source("cam_b", Some(recording(&scene, 4.0, 50.0, 0.5)), None),
+            source("cam_c", None, Some(3612.5)),
+            source(
+                "broll",
+                Some(recording(&other_scene, 0.0, 60.0, 1.0)),
+                Some(3590.0),
+            ),
+            source("stills", None, None),
+        ];
+
+        let result = compute_sync_offsets(&sources, &AudioSyncParams::default()).unwrap();
+
+        assert_eq!(result.reference_asset_id, "cam_a");
+        let cam_b = result.offset_for("cam_b").unwrap();
+        assert_eq!(cam_b.method, SyncMethod::Audio);
+        assert!((cam_b.offset_sec - 4.0).abs() < 0.02);
+        let cam_c = result.offset_for("cam_c").unwrap();
+        assert_eq!(cam_c.method, SyncMethod::Timecode);
+        assert!((cam_c.offset_sec - 12.5).abs() < 1e-9);
+        let broll = result.offset_for("broll").unwrap();
+        assert_eq!(broll.method, SyncMethod::Timecode);
+        assert!((broll.offset_sec + 10.0).abs() < 1e-9);
+        assert_eq!(result.unsynced.len(), 1);
+        assert_eq!(result.unsynced[0].asset_id, "stills");
+    }
+
+    #[test]
+    fn should_reject_empty_sources_and_invalid_params() {
+        assert!(compute_sync_offsets(&[], &AudioSyncParams::default()).is_err());
+
+        let params = AudioSyncParams {
+            min_confidence: 2.0,
+            ..AudioSyncParams::default()
+        };
+        let sources = vec![source("cam_a", None, None)];
+        assert!(compute_sync_offsets(&sources, &params).is_err());
+    }
+}
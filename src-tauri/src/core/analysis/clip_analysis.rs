@@ -1082,6 +1082,8 @@ mod tests {
             workspace_managed: false,
             missing: false,
             quarantined_uri: None,
+            source: None,
+            image_sequence: None,
         }
     }
 
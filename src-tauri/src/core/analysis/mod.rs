@@ -23,6 +23,7 @@
 //! ```
 
 pub mod audio;
+pub mod audio_sync;
 pub mod cleanup;
 pub mod clip_analysis;
 pub mod clip_perception;
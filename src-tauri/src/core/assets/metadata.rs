@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 use std::path::Path;
 use std::process::Command;
 
-use crate::core::assets::{AudioInfo, VideoInfo};
+use crate::core::assets::{AudioInfo, SourceMetadata, VideoInfo};
 use crate::core::ffmpeg::resolved_ffprobe_path;
 use crate::core::process::configure_std_command;
 use crate::core::{CoreError, CoreResult, Ratio};
@@ -49,6 +49,12 @@ pub struct MediaMetadata {
     /// `height x width`. See [`crate::core::ffmpeg::display_dimensions`].
     #[serde(default)]
     pub rotation_deg: f64,
+    /// Source timecode, reel and camera tags.
+    ///
+    /// Read through [`crate::core::ffmpeg::source_metadata_from_probe`], the
+    /// same parser the async probe uses.
+    #[serde(default)]
+    pub source: SourceMetadata,
 }
 
 impl Default for MediaMetadata {
@@ -61,6 +67,7 @@ impl Default for MediaMetadata {
             audio: None,
             format: String::new(),
             rotation_deg: 0.0,
+            source: SourceMetadata::default(),
         }
     }
 }
@@ -89,6 +96,7 @@ struct FFprobeStream {
     nb_frames: Option<String>,
     sample_rate: Option<String>,
     channels: Option<u8>,
+    channel_layout: Option<String>,
     bit_rate: Option<String>,
     color_transfer: Option<String>,
     #[allow(dead_code)]
@@ -159,11 +167,17 @@ impl MetadataExtractor {
 
     /// Parse FFprobe JSON output into MediaMetadata
     fn parse_ffprobe_output(json: &str) -> CoreResult<MediaMetadata> {
-        let output: FFprobeOutput = serde_json::from_str(json).map_err(|e| {
+        let probe: serde_json::Value = serde_json::from_str(json).map_err(|e| {
+            CoreError::FFprobeError(format!("Failed to parse ffprobe output: {}", e))
+        })?;
+        let output: FFprobeOutput = serde_json::from_value(probe.clone()).map_err(|e| {
             CoreError::FFprobeError(format!("Failed to parse ffprobe output: {}", e))
         })?;
 
-        let mut metadata = MediaMetadata::default();
+        let mut metadata = MediaMetadata {
+            source: crate::core::ffmpeg::source_metadata_from_probe(&probe),
+            ..MediaMetadata::default()
+        };
 
         // Parse format info
         if let Some(format) = output.format {
@@ -317,6 +331,10 @@ impl MetadataExtractor {
                 .clone()
                 .unwrap_or_else(|| "unknown".to_string()),
             bitrate,
+            channel_layout: stream
+                .channel_layout
+                .clone()
+                .filter(|layout| !layout.is_empty() && layout != "unknown"),
         }
     }
 
@@ -586,6 +604,44 @@ mod tests {
         assert_eq!(video.bitrate, Some(5000000));
     }
 
+    #[test]
+    fn test_parse_source_timecode_from_timecode_track() {
+        let json = r#"{
+            "streams": [
+                {
+                    "codec_type": "video",
+                    "codec_name": "prores",
+                    "width": 3840,
+                    "height": 2160,
+                    "r_frame_rate": "25/1",
+                    "tags": { "reel_name": "B002" }
+                },
+                {
+                    "codec_type": "data",
+                    "codec_tag_string": "tmcd",
+                    "tags": { "timecode": "14:22:05:10" }
+                }
+            ],
+            "format": {
+                "duration": "30.0",
+                "size": "1000",
+                "format_name": "mov,mp4,m4a,3gp,3g2,mj2",
+                "tags": { "com.apple.quicktime.camera.lens_model": "Zeiss CP.3 35mm" }
+            }
+        }"#;
+
+        let metadata = MetadataExtractor::parse_ffprobe_output(json).unwrap();
+
+        assert_eq!(
+            metadata.source.start_timecode.as_deref(),
+            Some("14:22:05:10")
+        );
+        let expected = 14.0 * 3600.0 + 22.0 * 60.0 + 5.0 + 10.0 / 25.0;
+        assert!((metadata.source.start_sec.unwrap() - expected).abs() < 1e-9);
+        assert_eq!(metadata.source.reel_name.as_deref(), Some("B002"));
+        assert_eq!(metadata.source.lens.as_deref(), Some("Zeiss CP.3 35mm"));
+    }
+
     #[test]
     fn test_parse_audio_only_metadata() {
         let json = r#"{
@@ -616,6 +672,7 @@ mod tests {
         assert_eq!(audio.channels, 2);
         assert_eq!(audio.codec, "mp3");
         assert_eq!(audio.bitrate, Some(320000));
+        assert_eq!(audio.channel_layout, None);
     }
 
     #[test]
@@ -633,7 +690,8 @@ mod tests {
                     "codec_type": "audio",
                     "codec_name": "aac",
                     "sample_rate": "48000",
-                    "channels": 6
+                    "channels": 6,
+                    "channel_layout": "5.1(side)"
                 }
             ],
             "format": {
@@ -657,6 +715,7 @@ mod tests {
         assert_eq!(audio.sample_rate, 48000);
         assert_eq!(audio.channels, 6);
         assert_eq!(audio.codec, "aac");
+        assert_eq!(audio.channel_layout.as_deref(), Some("5.1(side)"));
     }
 
     #[test]
@@ -35,9 +35,9 @@ pub fn media_kind_from_extension(ext: &str) -> Option<AssetKind> {
         | "awb" | "caf" | "dts" | "eac3" | "ec3" | "flac" | "m4a" | "mka" | "mp3" | "mp4a"
         | "oga" | "ogg" | "opus" | "ra" | "snd" | "tta" | "wav" | "wave" | "weba" | "wma"
         | "wv" => Some(AssetKind::Audio),
-        "avif" | "bmp" | "dds" | "dib" | "exr" | "gif" | "hdr" | "heic" | "heif" | "ico"
-        | "jpe" | "jpeg" | "jpg" | "jxl" | "png" | "psd" | "svg" | "tga" | "tif" | "tiff"
-        | "webp" => Some(AssetKind::Image),
+        "avif" | "bmp" | "dds" | "dib" | "dpx" | "exr" | "gif" | "hdr" | "heic" | "heif"
+        | "ico" | "jpe" | "jpeg" | "jpg" | "jxl" | "png" | "psd" | "svg" | "tga" | "tif"
+        | "tiff" | "webp" => Some(AssetKind::Image),
         "srt" | "vtt" | "ass" | "ssa" | "sub" => Some(AssetKind::Subtitle),
         "ttf" | "otf" | "woff" | "woff2" => Some(AssetKind::Font),
         _ => None,
@@ -136,6 +136,10 @@ pub struct AudioInfo {
     /// Bitrate in bps (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bitrate: Option<u64>,
+    /// FFmpeg channel layout name (e.g., "stereo", "5.1(side)"); `None` when
+    /// the file does not label its channels, as many multitrack camera files do
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub channel_layout: Option<String>,
 }
 
 impl Default for AudioInfo {
@@ -145,6 +149,103 @@ impl Default for AudioInfo {
             channels: 2,
             codec: "aac".to_string(),
             bitrate: None,
+            channel_layout: None,
+        }
+    }
+}
+
+/// Recording metadata carried by the source file: where its timecode starts
+/// and which reel, camera and lens produced it.
+///
+/// Interchange exports read this so EDL, FCPXML and OTIO point at the
+/// timecode the footage was actually shot with instead of zero.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SourceMetadata {
+    /// Start timecode as recorded in the file (e.g., "01:00:00:00")
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_timecode: Option<String>,
+    /// Start timecode in seconds from midnight
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_sec: Option<f64>,
+    /// Reel or tape name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reel_name: Option<String>,
+    /// Camera make and model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub camera: Option<String>,
+    /// Lens model
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lens: Option<String>,
+}
+
+impl SourceMetadata {
+    /// Returns whether the file carried none of the recognised fields.
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+/// Frame rate given to an image sequence until the user sets one; stills
+/// carry no timing of their own and 24 fps is what render passes are shot at.
+pub const DEFAULT_IMAGE_SEQUENCE_FPS: i32 = 24;
+
+/// A numbered run of stills played back as one video asset.
+///
+/// The asset's `uri` is the first frame, so path validation, scoping and
+/// relinking treat the sequence like any file; `pattern` names its siblings.
+/// The frame rate lives on the asset's [`VideoInfo`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSequenceInfo {
+    /// `printf`-style file name of the frames (e.g. "shot_%04d.exr")
+    pub pattern: String,
+    /// Number of the first frame
+    pub start_number: u64,
+    /// Number of consecutive frames
+    pub frame_count: u64,
+}
+
+impl ImageSequenceInfo {
+    /// Rejects patterns that could name anything but a sibling of the first
+    /// frame, and empty sequences.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.pattern.contains(['/', '\\']) || self.pattern.starts_with('.') {
+            return Err(format!(
+                "Image sequence pattern must be a plain file name: {}",
+                self.pattern
+            ));
+        }
+        let well_formed = self.pattern.split_once('%').is_some_and(|(_, rest)| {
+            !rest.contains('%')
+                && rest
+                    .trim_start_matches(|c: char| c.is_ascii_digit())
+                    .starts_with('d')
+        });
+        if !well_formed {
+            return Err(format!(
+                "Image sequence pattern must hold one frame number: {}",
+                self.pattern
+            ));
+        }
+        if self.frame_count == 0 {
+            return Err("Image sequence has no frames".to_string());
+        }
+        Ok(())
+    }
+
+    /// Pattern path beside `first_frame`
+    pub fn pattern_path(&self, first_frame: &std::path::Path) -> std::path::PathBuf {
+        first_frame.with_file_name(&self.pattern)
+    }
+
+    /// How long the sequence plays at `fps`
+    pub fn duration_sec(&self, fps: &Ratio) -> f64 {
+        let fps = fps.as_f64();
+        if fps > 0.0 {
+            self.frame_count as f64 / fps
+        } else {
+            0.0
         }
     }
 }
@@ -278,6 +379,14 @@ pub struct Asset {
     /// this application.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub quarantined_uri: Option<String>,
+
+    /// Source timecode, reel and camera metadata probed from the file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<SourceMetadata>,
+
+    /// Set when the asset is a numbered still sequence rather than one file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_sequence: Option<ImageSequenceInfo>,
 }
 
 impl Asset {
@@ -326,6 +435,8 @@ impl Asset {
             workspace_managed: false,
             missing: false,
             quarantined_uri: None,
+            source: None,
+            image_sequence: None,
         }
     }
 
@@ -352,6 +463,8 @@ impl Asset {
             workspace_managed: false,
             missing: false,
             quarantined_uri: None,
+            source: None,
+            image_sequence: None,
         }
     }
 
@@ -396,9 +509,27 @@ impl Asset {
             workspace_managed: false,
             missing: false,
             quarantined_uri: None,
+            source: None,
+            image_sequence: None,
         }
     }
 
+    /// Creates a video asset playing a numbered still sequence from its
+    /// first frame at `video_info.fps`
+    pub fn new_image_sequence(
+        name: &str,
+        first_frame_uri: &str,
+        video_info: VideoInfo,
+        sequence: ImageSequenceInfo,
+    ) -> Self {
+        let mut asset = Self::new_video(name, first_frame_uri, video_info);
+        if let Some(video) = asset.video.as_ref() {
+            asset.duration_sec = Some(sequence.duration_sec(&video.fps));
+        }
+        asset.image_sequence = Some(sequence);
+        asset
+    }
+
     /// Sets the file hash
     pub fn with_hash(mut self, hash: &str) -> Self {
         self.hash = hash.to_string();
@@ -429,6 +560,30 @@ impl Asset {
         self
     }
 
+    /// Sets the source recording metadata, dropping it when empty
+    pub fn with_source(mut self, source: SourceMetadata) -> Self {
+        self.source = (!source.is_empty()).then_some(source);
+        self
+    }
+
+    /// Returns the source start timecode in seconds, or zero when the file
+    /// carried none.
+    pub fn source_start_sec(&self) -> f64 {
+        self.source
+            .as_ref()
+            .and_then(|source| source.start_sec)
+            .filter(|sec| sec.is_finite() && *sec >= 0.0)
+            .unwrap_or(0.0)
+    }
+
+    /// Returns the recorded reel or tape name, if any.
+    pub fn reel_name(&self) -> Option<&str> {
+        self.source
+            .as_ref()
+            .and_then(|source| source.reel_name.as_deref())
+            .filter(|reel| !reel.trim().is_empty())
+    }
+
     /// Sets the thumbnail URL
     pub fn with_thumbnail_url(mut self, url: &str) -> Self {
         self.thumbnail_url = Some(url.to_string());
@@ -458,9 +613,44 @@ impl Asset {
 
     /// Checks if this asset requires proxy generation
     ///
-    /// Returns true for video assets with height > 720p
+    /// Returns true for video assets with height > 720p, and for image
+    /// sequences at any size since the preview cannot play stills as video
     pub fn needs_proxy(&self) -> bool {
-        requires_proxy(&self.kind, self.video.as_ref())
+        self.image_sequence.is_some() || requires_proxy(&self.kind, self.video.as_ref())
+    }
+
+    /// The `image2` input for an image-sequence asset whose first frame has
+    /// been validated at `first_frame`, or `None` for any other asset.
+    pub fn image_sequence_input(
+        &self,
+        first_frame: &std::path::Path,
+    ) -> Option<crate::core::ffmpeg::ImageSequenceInput> {
+        let sequence = self
+            .image_sequence
+            .as_ref()
+            .filter(|sequence| sequence.validate().is_ok())?;
+        Some(crate::core::ffmpeg::ImageSequenceInput {
+            pattern_path: sequence.pattern_path(first_frame),
+            start_number: sequence.start_number,
+            frame_count: sequence.frame_count,
+            fps: self
+                .video
+                .as_ref()
+                .map(|video| video.fps.clone())
+                .unwrap_or_else(|| Ratio::new(DEFAULT_IMAGE_SEQUENCE_FPS, 1)),
+        })
+    }
+
+    /// FFmpeg arguments opening this asset as an input, `-i` included, given
+    /// the validated path of its file (an image sequence's first frame).
+    pub fn ffmpeg_input_args(&self, validated_path: &std::path::Path) -> Vec<String> {
+        match self.image_sequence_input(validated_path) {
+            Some(input) => input.input_args(),
+            None => vec![
+                "-i".to_string(),
+                validated_path.to_string_lossy().to_string(),
+            ],
+        }
     }
 
     /// Marks the asset as pending proxy generation
@@ -814,11 +1004,66 @@ mod tests {
         assert_eq!(media_kind_from_extension("png"), Some(AssetKind::Image));
         assert_eq!(media_kind_from_extension("tif"), Some(AssetKind::Image));
         assert_eq!(media_kind_from_extension("heic"), Some(AssetKind::Image));
+        assert_eq!(media_kind_from_extension("dpx"), Some(AssetKind::Image));
         assert_eq!(media_kind_from_extension("srt"), Some(AssetKind::Subtitle));
         assert_eq!(media_kind_from_extension("ttf"), Some(AssetKind::Font));
         assert_eq!(media_kind_from_extension("txt"), None);
     }
 
+    #[test]
+    fn test_image_sequence_asset_plays_frames_at_its_rate() {
+        let sequence = ImageSequenceInfo {
+            pattern: "shot_%04d.exr".to_string(),
+            start_number: 1001,
+            frame_count: 48,
+        };
+        let video_info = VideoInfo {
+            width: 1280,
+            height: 720,
+            fps: Ratio::new(24, 1),
+            ..Default::default()
+        };
+        let asset = Asset::new_image_sequence(
+            "shot_%04d.exr",
+            "/renders/shot_1001.exr",
+            video_info,
+            sequence,
+        );
+
+        assert_eq!(asset.kind, AssetKind::Video);
+        assert_eq!(asset.duration_sec, Some(2.0));
+        // Stills cannot be previewed as video at any size
+        assert!(asset.needs_proxy());
+        assert_eq!(
+            asset.ffmpeg_input_args(std::path::Path::new("/renders/shot_1001.exr")),
+            vec![
+                "-f",
+                "image2",
+                "-framerate",
+                "24/1",
+                "-start_number",
+                "1001",
+                "-i",
+                "/renders/shot_%04d.exr"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_image_sequence_pattern_must_stay_beside_the_first_frame() {
+        let sequence = |pattern: &str| ImageSequenceInfo {
+            pattern: pattern.to_string(),
+            start_number: 1,
+            frame_count: 2,
+        };
+
+        assert!(sequence("shot_%04d.exr").validate().is_ok());
+        assert!(sequence("../shot_%04d.exr").validate().is_err());
+        assert!(sequence("sub/shot_%04d.exr").validate().is_err());
+        assert!(sequence("shot_0001.exr").validate().is_err());
+        assert!(sequence("%s_%04d.exr").validate().is_err());
+    }
+
     #[test]
     fn test_unique_ids() {
         let asset1 = Asset::new_video("a.mp4", "/a.mp4", VideoInfo::default());
@@ -6,7 +6,7 @@
 use std::path::{Path, PathBuf};
 
 use super::models::AssetKind;
-use crate::core::ffmpeg::FFmpegRunner;
+use crate::core::ffmpeg::{FFmpegRunner, ImageSequenceInput};
 use crate::core::process::configure_tokio_command;
 
 /// Default thumbnail size
@@ -82,6 +82,26 @@ impl ThumbnailService {
         Ok(thumb_path)
     }
 
+    /// Generate a thumbnail for a numbered image sequence imported as video
+    pub async fn generate_for_image_sequence(
+        &self,
+        asset_id: &str,
+        sequence: &ImageSequenceInput,
+    ) -> Result<PathBuf, ThumbnailError> {
+        let thumb_path = self.thumbnail_path(asset_id);
+
+        self.ffmpeg
+            .generate_image_sequence_thumbnail(
+                sequence,
+                &thumb_path,
+                Some((self.width, self.height)),
+            )
+            .await
+            .map_err(|e| ThumbnailError::FFmpegError(e.to_string()))?;
+
+        Ok(thumb_path)
+    }
+
     /// Generate a thumbnail for an image asset
     pub async fn generate_for_image(
         &self,
@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::{
     assets::{
         media_kind_from_extension, Asset, AssetKind, AudioInfo, LicenseInfo, MetadataExtractor,
-        ProxyStatus, VideoInfo,
+        ProxyStatus, SourceMetadata, VideoInfo,
     },
     commands::{Command, CommandResult, StateChange},
     fs::{validate_asset_relative_path, validate_local_input_path},
@@ -152,6 +152,12 @@ impl ImportAssetCommand {
         self
     }
 
+    /// Sets the source timecode, reel and camera metadata
+    pub fn with_source(mut self, source: SourceMetadata) -> Self {
+        self.asset = self.asset.with_source(source);
+        self
+    }
+
     /// Sets the project root for resolving relative paths
     pub fn with_project_root(mut self, root: std::path::PathBuf) -> Self {
         self.project_root = Some(root);
@@ -336,6 +342,8 @@ pub struct UpdateAssetCommand {
     pub workspace_managed: Option<bool>,
     /// Whether the asset file is missing (optional)
     pub missing: Option<bool>,
+    /// New source recording metadata (optional). `Some(None)` clears it.
+    pub source: Option<Option<SourceMetadata>>,
     /// Original values (for undo)
     #[serde(skip)]
     original_name: Option<String>,
@@ -365,6 +373,8 @@ pub struct UpdateAssetCommand {
     original_workspace_managed: Option<bool>,
     #[serde(skip)]
     original_missing: Option<bool>,
+    #[serde(skip)]
+    original_source: Option<Option<SourceMetadata>>,
     /// Quarantined URI recorded at load time, captured so `undo` can put a
     /// still-unresolved quarantine back after a relink is rolled back.
     #[serde(skip)]
@@ -390,6 +400,7 @@ impl UpdateAssetCommand {
             relative_path: None,
             workspace_managed: None,
             missing: None,
+            source: None,
             original_name: None,
             original_tags: None,
             original_license: None,
@@ -404,6 +415,7 @@ impl UpdateAssetCommand {
             original_relative_path: None,
             original_workspace_managed: None,
             original_missing: None,
+            original_source: None,
             original_quarantined_uri: None,
         }
     }
@@ -491,6 +503,12 @@ impl UpdateAssetCommand {
         self.missing = Some(missing);
         self
     }
+
+    /// Sets source recording metadata. Use `None` to clear.
+    pub fn with_source(mut self, source: Option<SourceMetadata>) -> Self {
+        self.source = Some(source.filter(|source| !source.is_empty()));
+        self
+    }
 }
 
 impl Command for UpdateAssetCommand {
@@ -540,6 +558,7 @@ impl Command for UpdateAssetCommand {
         self.original_relative_path = Some(asset.relative_path.clone());
         self.original_workspace_managed = Some(asset.workspace_managed);
         self.original_missing = Some(asset.missing);
+        self.original_source = Some(asset.source.clone());
         self.original_quarantined_uri = Some(asset.quarantined_uri.clone());
 
         // Apply new values
@@ -592,6 +611,9 @@ impl Command for UpdateAssetCommand {
         if let Some(missing) = self.missing {
             asset.missing = missing;
         }
+        if let Some(source) = &self.source {
+            asset.source = source.clone();
+        }
 
         let op_id = ulid::Ulid::new().to_string();
 
@@ -646,6 +668,9 @@ impl Command for UpdateAssetCommand {
             if let Some(missing) = self.original_missing {
                 asset.missing = missing;
             }
+            if let Some(source) = &self.original_source {
+                asset.source = source.clone();
+            }
             if let Some(quarantined_uri) = &self.original_quarantined_uri {
                 asset.quarantined_uri = quarantined_uri.clone();
             }
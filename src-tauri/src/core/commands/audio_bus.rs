@@ -0,0 +1,821 @@
+//! Audio Bus Commands Module
+//!
+//! Implements commands that create, route and process submix buses.
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    commands::{Command, CommandResult, StateChange},
+    effects::{Effect, EffectType, ParamValue},
+    project::ProjectState,
+    timeline::{would_create_bus_cycle, AudioBus, Sequence, TrackKind},
+    BusId, CoreError, CoreResult, EffectId, SequenceId, TrackId,
+};
+
+const BUS_MIN_VOLUME_DB: f32 = -60.0;
+const BUS_MAX_VOLUME_DB: f32 = 6.0;
+
+/// Bus routing as it stood before an edit, restored on undo.
+#[derive(Clone, Debug)]
+struct RoutingSnapshot {
+    buses: Vec<AudioBus>,
+    track_routes: Vec<(TrackId, Option<BusId>)>,
+}
+
+impl RoutingSnapshot {
+    fn capture(sequence: &Sequence) -> Self {
+        Self {
+            buses: sequence.audio_buses.clone(),
+            track_routes: sequence
+                .tracks
+                .iter()
+                .map(|track| (track.id.clone(), track.output_bus_id.clone()))
+                .collect(),
+        }
+    }
+
+    fn restore(&self, sequence: &mut Sequence) {
+        sequence.audio_buses = self.buses.clone();
+        for (track_id, output_bus_id) in &self.track_routes {
+            if let Some(track) = sequence.get_track_mut(track_id) {
+                track.output_bus_id = output_bus_id.clone();
+            }
+        }
+    }
+}
+
+fn sequence_mut<'a>(
+    state: &'a mut ProjectState,
+    sequence_id: &str,
+) -> CoreResult<&'a mut Sequence> {
+    state
+        .sequences
+        .get_mut(sequence_id)
+        .ok_or_else(|| CoreError::SequenceNotFound(sequence_id.to_string()))
+}
+
+fn bus_not_found(bus_id: &str) -> CoreError {
+    CoreError::NotFound(format!("Audio bus not found: {}", bus_id))
+}
+
+fn validate_bus_name(name: &str) -> CoreResult<String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(CoreError::ValidationError(
+            "Audio bus name cannot be empty".to_string(),
+        ));
+    }
+    Ok(name.to_string())
+}
+
+/// Checks that `bus_id` may feed `output_bus_id`.
+fn validate_bus_output(sequence: &Sequence, bus_id: &str, output_bus_id: &str) -> CoreResult<()> {
+    if sequence.get_audio_bus(output_bus_id).is_none() {
+        return Err(bus_not_found(output_bus_id));
+    }
+    if would_create_bus_cycle(sequence, bus_id, output_bus_id) {
+        return Err(CoreError::ValidationError(format!(
+            "Routing audio bus '{}' into '{}' would feed it back into itself",
+            bus_id, output_bus_id
+        )));
+    }
+    Ok(())
+}
+
+fn routing_changed(sequence_id: &str) -> CommandResult {
+    let op_id = ulid::Ulid::new().to_string();
+    CommandResult::new(&op_id).with_change(StateChange::SequenceModified {
+        sequence_id: sequence_id.to_string(),
+    })
+}
+
+// =============================================================================
+// CreateAudioBusCommand
+// =============================================================================
+
+/// Command to add a submix bus to a sequence.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAudioBusCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Bus name
+    pub name: String,
+    /// Bus the new bus feeds (None = master)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_bus_id: Option<BusId>,
+    /// Created bus ID (stored after execution)
+    #[serde(skip)]
+    created_bus_id: Option<BusId>,
+}
+
+impl CreateAudioBusCommand {
+    /// Creates a command adding a bus that feeds the master.
+    pub fn new(sequence_id: &str, name: &str) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            name: name.to_string(),
+            output_bus_id: None,
+            created_bus_id: None,
+        }
+    }
+
+    /// Routes the new bus into another bus.
+    pub fn with_output(mut self, bus_id: &str) -> Self {
+        self.output_bus_id = Some(bus_id.to_string());
+        self
+    }
+}
+
+impl Command for CreateAudioBusCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let name = validate_bus_name(&self.name)?;
+        let sequence = sequence_mut(state, &self.sequence_id)?;
+        if let Some(output_bus_id) = self.output_bus_id.as_deref() {
+            if sequence.get_audio_bus(output_bus_id).is_none() {
+                return Err(bus_not_found(output_bus_id));
+            }
+        }
+
+        let mut bus = AudioBus::new(&name);
+        // Redo recreates the bus under the same ID, so later commands that
+        // route into it still find it.
+        if let Some(bus_id) = &self.created_bus_id {
+            bus.id = bus_id.clone();
+        }
+        bus.output_bus_id = self.output_bus_id.clone();
+        let bus_id = bus.id.clone();
+        self.created_bus_id = Some(bus_id.clone());
+        sequence.audio_buses.push(bus);
+
+        Ok(routing_changed(&self.sequence_id).with_created_id(&bus_id))
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        if let (Some(bus_id), Some(sequence)) = (
+            &self.created_bus_id,
+            state.sequences.get_mut(&self.sequence_id),
+        ) {
+            sequence.audio_buses.retain(|bus| &bus.id != bus_id);
+        }
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "CreateAudioBus"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// RemoveAudioBusCommand
+// =============================================================================
+
+/// Command to remove a submix bus.
+///
+/// Tracks and buses that fed the removed bus are rerouted to wherever it fed,
+/// so removing a bus never silences anything. Its effects are deleted with it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveAudioBusCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Bus to remove
+    pub bus_id: BusId,
+    #[serde(skip)]
+    previous_routing: Option<RoutingSnapshot>,
+    #[serde(skip)]
+    removed_effects: Vec<Effect>,
+}
+
+impl RemoveAudioBusCommand {
+    /// Creates a new remove audio bus command.
+    pub fn new(sequence_id: &str, bus_id: &str) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            bus_id: bus_id.to_string(),
+            previous_routing: None,
+            removed_effects: Vec::new(),
+        }
+    }
+}
+
+impl Command for RemoveAudioBusCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = sequence_mut(state, &self.sequence_id)?;
+        let position = sequence
+            .audio_buses
+            .iter()
+            .position(|bus| bus.id == self.bus_id)
+            .ok_or_else(|| bus_not_found(&self.bus_id))?;
+        let previous_routing = RoutingSnapshot::capture(sequence);
+
+        let removed = sequence.audio_buses.remove(position);
+        for track in &mut sequence.tracks {
+            if track.output_bus_id.as_deref() == Some(removed.id.as_str()) {
+                track.output_bus_id = removed.output_bus_id.clone();
+            }
+        }
+        for bus in &mut sequence.audio_buses {
+            if bus.output_bus_id.as_deref() == Some(removed.id.as_str()) {
+                bus.output_bus_id = removed.output_bus_id.clone();
+            }
+        }
+
+        self.previous_routing = Some(previous_routing);
+        self.removed_effects = removed
+            .effects
+            .iter()
+            .filter_map(|effect_id| state.effects.remove(effect_id))
+            .collect();
+
+        Ok(routing_changed(&self.sequence_id))
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(previous_routing) = &self.previous_routing else {
+            return Ok(());
+        };
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            previous_routing.restore(sequence);
+        }
+        for effect in &self.removed_effects {
+            state.effects.insert(effect.id.clone(), effect.clone());
+        }
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "RemoveAudioBus"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// UpdateAudioBusCommand
+// =============================================================================
+
+/// Command to rename, re-level, mute or reroute a submix bus.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateAudioBusCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Bus to update
+    pub bus_id: BusId,
+    /// New bus name
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// New bus gain in dB (clamped to -60.0 to +6.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume_db: Option<f32>,
+    /// New muted state
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub muted: Option<bool>,
+    /// New output: `Some(None)` feeds the master, `Some(Some(id))` another bus
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_bus_id: Option<Option<BusId>>,
+    #[serde(skip)]
+    previous_bus: Option<AudioBus>,
+}
+
+impl UpdateAudioBusCommand {
+    /// Creates a command that changes nothing until a setter is applied.
+    pub fn new(sequence_id: &str, bus_id: &str) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            bus_id: bus_id.to_string(),
+            name: None,
+            volume_db: None,
+            muted: None,
+            output_bus_id: None,
+            previous_bus: None,
+        }
+    }
+
+    /// Renames the bus.
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Sets the bus gain.
+    pub fn with_volume_db(mut self, volume_db: f32) -> Self {
+        self.volume_db = Some(volume_db);
+        self
+    }
+
+    /// Mutes or unmutes the bus.
+    pub fn with_muted(mut self, muted: bool) -> Self {
+        self.muted = Some(muted);
+        self
+    }
+
+    /// Routes the bus into another bus, or into the master with `None`.
+    pub fn with_output(mut self, output_bus_id: Option<BusId>) -> Self {
+        self.output_bus_id = Some(output_bus_id);
+        self
+    }
+}
+
+impl Command for UpdateAudioBusCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let name = self.name.as_deref().map(validate_bus_name).transpose()?;
+        if let Some(volume_db) = self.volume_db {
+            if !volume_db.is_finite() {
+                return Err(CoreError::ValidationError(
+                    "Audio bus volume must be a finite number".to_string(),
+                ));
+            }
+        }
+
+        let sequence = sequence_mut(state, &self.sequence_id)?;
+        if let Some(Some(output_bus_id)) = &self.output_bus_id {
+            validate_bus_output(sequence, &self.bus_id, output_bus_id)?;
+        }
+        let bus = sequence
+            .get_audio_bus_mut(&self.bus_id)
+            .ok_or_else(|| bus_not_found(&self.bus_id))?;
+
+        self.previous_bus = Some(bus.clone());
+        if let Some(name) = name {
+            bus.name = name;
+        }
+        if let Some(volume_db) = self.volume_db {
+            bus.volume_db = volume_db.clamp(BUS_MIN_VOLUME_DB, BUS_MAX_VOLUME_DB);
+        }
+        if let Some(muted) = self.muted {
+            bus.muted = muted;
+        }
+        if let Some(output_bus_id) = &self.output_bus_id {
+            bus.output_bus_id = output_bus_id.clone();
+        }
+
+        Ok(routing_changed(&self.sequence_id))
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(previous_bus) = &self.previous_bus else {
+            return Ok(());
+        };
+        if let Some(bus) = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .and_then(|sequence| sequence.get_audio_bus_mut(&self.bus_id))
+        {
+            *bus = previous_bus.clone();
+        }
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "UpdateAudioBus"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// SetTrackOutputBusCommand
+// =============================================================================
+
+/// Command to route a track's audio into a submix bus or back to the master.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTrackOutputBusCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Track to route
+    pub track_id: TrackId,
+    /// Bus the track feeds (None = master)
+    pub bus_id: Option<BusId>,
+    #[serde(skip)]
+    previous_bus_id: Option<Option<BusId>>,
+}
+
+impl SetTrackOutputBusCommand {
+    /// Creates a new set track output bus command.
+    pub fn new(sequence_id: &str, track_id: &str, bus_id: Option<&str>) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            bus_id: bus_id.map(str::to_string),
+            previous_bus_id: None,
+        }
+    }
+}
+
+impl Command for SetTrackOutputBusCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = sequence_mut(state, &self.sequence_id)?;
+        if let Some(bus_id) = self.bus_id.as_deref() {
+            if sequence.get_audio_bus(bus_id).is_none() {
+                return Err(bus_not_found(bus_id));
+            }
+        }
+        let track = sequence
+            .get_track_mut(&self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        if !matches!(track.kind, TrackKind::Video | TrackKind::Audio) {
+            return Err(CoreError::ValidationError(format!(
+                "Track '{}' carries no audio and cannot be routed to a bus",
+                self.track_id
+            )));
+        }
+
+        self.previous_bus_id = Some(track.output_bus_id.clone());
+        track.output_bus_id = self.bus_id.clone();
+
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::TrackModified {
+                track_id: self.track_id.clone(),
+            }),
+        )
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(previous_bus_id) = &self.previous_bus_id else {
+            return Ok(());
+        };
+        if let Some(track) = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .and_then(|sequence| sequence.get_track_mut(&self.track_id))
+        {
+            track.output_bus_id = previous_bus_id.clone();
+        }
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "SetTrackOutputBus"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// AddAudioBusEffectCommand
+// =============================================================================
+
+/// Command to add an audio effect (EQ, compressor, limiter, ...) to a bus.
+///
+/// The effect lives in the project's effect registry like a clip effect, so
+/// `UpdateEffect` edits its parameters.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddAudioBusEffectCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Bus to process
+    pub bus_id: BusId,
+    /// Effect type; must be an audio effect
+    pub effect_type: EffectType,
+    #[serde(default)]
+    pub params: std::collections::HashMap<String, ParamValue>,
+    /// Position in the bus effect chain (None = append at end)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<usize>,
+    #[serde(skip)]
+    created_effect_id: Option<EffectId>,
+}
+
+impl AddAudioBusEffectCommand {
+    /// Creates a command appending an effect with default parameters.
+    pub fn new(sequence_id: &str, bus_id: &str, effect_type: EffectType) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            bus_id: bus_id.to_string(),
+            effect_type,
+            params: std::collections::HashMap::new(),
+            position: None,
+            created_effect_id: None,
+        }
+    }
+
+    /// Add a parameter value to the effect
+    pub fn with_param(mut self, name: impl Into<String>, value: ParamValue) -> Self {
+        self.params.insert(name.into(), value);
+        self
+    }
+
+    /// Set the position where the effect should be inserted
+    pub fn at_position(mut self, position: usize) -> Self {
+        self.position = Some(position);
+        self
+    }
+}
+
+impl Command for AddAudioBusEffectCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        if !self.effect_type.is_audio() {
+            return Err(CoreError::ValidationError(format!(
+                "Only audio effects can be added to an audio bus, not {:?}",
+                self.effect_type
+            )));
+        }
+
+        let sequence = sequence_mut(state, &self.sequence_id)?;
+        let bus = sequence
+            .get_audio_bus_mut(&self.bus_id)
+            .ok_or_else(|| bus_not_found(&self.bus_id))?;
+
+        let mut effect = Effect::new(self.effect_type.clone());
+        if let Some(effect_id) = &self.created_effect_id {
+            effect.id = effect_id.clone();
+        }
+        for (key, value) in &self.params {
+            effect.set_param(key, value.clone());
+        }
+        let effect_id = effect.id.clone();
+        self.created_effect_id = Some(effect_id.clone());
+
+        match self.position {
+            Some(pos) if pos < bus.effects.len() => bus.effects.insert(pos, effect_id.clone()),
+            _ => bus.effects.push(effect_id.clone()),
+        }
+        state.effects.insert(effect_id.clone(), effect);
+
+        Ok(routing_changed(&self.sequence_id).with_created_id(&effect_id))
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(effect_id) = self.created_effect_id.as_deref() else {
+            return Ok(());
+        };
+        if let Some(bus) = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .and_then(|sequence| sequence.get_audio_bus_mut(&self.bus_id))
+        {
+            bus.effects.retain(|id| id != effect_id);
+        }
+        state.effects.remove(effect_id);
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "AddAudioBusEffect"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// RemoveAudioBusEffectCommand
+// =============================================================================
+
+/// Command to remove an effect from a bus.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoveAudioBusEffectCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Bus holding the effect
+    pub bus_id: BusId,
+    /// Effect to remove
+    pub effect_id: EffectId,
+    #[serde(skip)]
+    removed_effect: Option<(usize, Effect)>,
+}
+
+impl RemoveAudioBusEffectCommand {
+    /// Creates a new remove audio bus effect command.
+    pub fn new(sequence_id: &str, bus_id: &str, effect_id: &str) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            bus_id: bus_id.to_string(),
+            effect_id: effect_id.to_string(),
+            removed_effect: None,
+        }
+    }
+}
+
+impl Command for RemoveAudioBusEffectCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = sequence_mut(state, &self.sequence_id)?;
+        let bus = sequence
+            .get_audio_bus_mut(&self.bus_id)
+            .ok_or_else(|| bus_not_found(&self.bus_id))?;
+        let position = bus
+            .effects
+            .iter()
+            .position(|id| id == &self.effect_id)
+            .ok_or_else(|| {
+                CoreError::NotFound(format!(
+                    "Effect {} not found on audio bus {}",
+                    self.effect_id, self.bus_id
+                ))
+            })?;
+
+        bus.effects.remove(position);
+        self.removed_effect = state
+            .effects
+            .remove(&self.effect_id)
+            .map(|effect| (position, effect));
+
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::EffectRemoved {
+                effect_id: self.effect_id.clone(),
+            }),
+        )
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some((position, effect)) = &self.removed_effect else {
+            return Ok(());
+        };
+        if let Some(bus) = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .and_then(|sequence| sequence.get_audio_bus_mut(&self.bus_id))
+        {
+            let insert_pos = (*position).min(bus.effects.len());
+            bus.effects.insert(insert_pos, self.effect_id.clone());
+        }
+        state.effects.insert(self.effect_id.clone(), effect.clone());
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "RemoveAudioBusEffect"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::commands::CreateSequenceCommand;
+    use crate::core::timeline::Track;
+
+    fn setup() -> (ProjectState, SequenceId, TrackId) {
+        let mut state = ProjectState::new_empty("Test");
+        CreateSequenceCommand::new("Main", "1080p")
+            .execute(&mut state)
+            .unwrap();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        let track = Track::new_audio("DX");
+        let track_id = track.id.clone();
+        state.sequences.get_mut(&seq_id).unwrap().add_track(track);
+        (state, seq_id, track_id)
+    }
+
+    fn create_bus(state: &mut ProjectState, seq_id: &str, name: &str) -> BusId {
+        CreateAudioBusCommand::new(seq_id, name)
+            .execute(state)
+            .unwrap()
+            .created_ids[0]
+            .clone()
+    }
+
+    #[test]
+    fn routes_a_track_into_a_bus_and_undoes() {
+        let (mut state, seq_id, track_id) = setup();
+        let bus_id = create_bus(&mut state, &seq_id, "Dialog");
+
+        let mut route = SetTrackOutputBusCommand::new(&seq_id, &track_id, Some(&bus_id));
+        route.execute(&mut state).unwrap();
+        let track = state.sequences[&seq_id].get_track(&track_id).unwrap();
+        assert_eq!(track.output_bus_id.as_deref(), Some(bus_id.as_str()));
+
+        route.undo(&mut state).unwrap();
+        let track = state.sequences[&seq_id].get_track(&track_id).unwrap();
+        assert_eq!(track.output_bus_id, None);
+
+        let mut missing = SetTrackOutputBusCommand::new(&seq_id, &track_id, Some("nope"));
+        assert!(missing.execute(&mut state).is_err());
+    }
+
+    #[test]
+    fn refuses_to_route_a_bus_into_itself() {
+        let (mut state, seq_id, _) = setup();
+        let stems = create_bus(&mut state, &seq_id, "Stems");
+        let dialog = CreateAudioBusCommand::new(&seq_id, "Dialog")
+            .with_output(&stems)
+            .execute(&mut state)
+            .unwrap()
+            .created_ids[0]
+            .clone();
+
+        let mut loop_back =
+            UpdateAudioBusCommand::new(&seq_id, &stems).with_output(Some(dialog.clone()));
+        assert!(loop_back.execute(&mut state).is_err());
+
+        let mut update = UpdateAudioBusCommand::new(&seq_id, &dialog)
+            .with_name("  DX  ")
+            .with_volume_db(-120.0)
+            .with_muted(true)
+            .with_output(None);
+        update.execute(&mut state).unwrap();
+        let bus = state.sequences[&seq_id].get_audio_bus(&dialog).unwrap();
+        assert_eq!(bus.name, "DX");
+        assert_eq!(bus.volume_db, -60.0);
+        assert!(bus.muted);
+        assert_eq!(bus.output_bus_id, None);
+
+        update.undo(&mut state).unwrap();
+        let bus = state.sequences[&seq_id].get_audio_bus(&dialog).unwrap();
+        assert_eq!(bus.name, "Dialog");
+        assert_eq!(bus.output_bus_id.as_deref(), Some(stems.as_str()));
+    }
+
+    #[test]
+    fn removing_a_bus_reroutes_its_inputs_and_undo_restores_everything() {
+        let (mut state, seq_id, track_id) = setup();
+        let stems = create_bus(&mut state, &seq_id, "Stems");
+        let dialog = CreateAudioBusCommand::new(&seq_id, "Dialog")
+            .with_output(&stems)
+            .execute(&mut state)
+            .unwrap()
+            .created_ids[0]
+            .clone();
+        let sub = CreateAudioBusCommand::new(&seq_id, "Sub")
+            .with_output(&dialog)
+            .execute(&mut state)
+            .unwrap()
+            .created_ids[0]
+            .clone();
+        SetTrackOutputBusCommand::new(&seq_id, &track_id, Some(&dialog))
+            .execute(&mut state)
+            .unwrap();
+        let effect_id = AddAudioBusEffectCommand::new(&seq_id, &dialog, EffectType::Compressor)
+            .execute(&mut state)
+            .unwrap()
+            .created_ids[0]
+            .clone();
+
+        let mut remove = RemoveAudioBusCommand::new(&seq_id, &dialog);
+        remove.execute(&mut state).unwrap();
+        let sequence = &state.sequences[&seq_id];
+        assert!(sequence.get_audio_bus(&dialog).is_none());
+        assert_eq!(
+            sequence.get_track(&track_id).unwrap().output_bus_id,
+            Some(stems.clone())
+        );
+        assert_eq!(
+            sequence.get_audio_bus(&sub).unwrap().output_bus_id,
+            Some(stems.clone())
+        );
+        assert!(!state.effects.contains_key(&effect_id));
+
+        remove.undo(&mut state).unwrap();
+        let sequence = &state.sequences[&seq_id];
+        assert_eq!(
+            sequence.get_audio_bus(&dialog).unwrap().effects,
+            vec![effect_id.clone()]
+        );
+        assert_eq!(
+            sequence.get_track(&track_id).unwrap().output_bus_id,
+            Some(dialog.clone())
+        );
+        assert!(state.effects.contains_key(&effect_id));
+    }
+
+    #[test]
+    fn bus_effects_must_be_audio_effects() {
+        let (mut state, seq_id, _) = setup();
+        let bus_id = create_bus(&mut state, &seq_id, "Music");
+
+        let mut blur = AddAudioBusEffectCommand::new(&seq_id, &bus_id, EffectType::GaussianBlur);
+        assert!(blur.execute(&mut state).is_err());
+
+        let mut limiter = AddAudioBusEffectCommand::new(&seq_id, &bus_id, EffectType::Limiter)
+            .with_param("limit", ParamValue::Float(0.8));
+        let effect_id = limiter.execute(&mut state).unwrap().created_ids[0].clone();
+        assert_eq!(state.effects[&effect_id].get_float("limit"), Some(0.8));
+
+        let mut remove = RemoveAudioBusEffectCommand::new(&seq_id, &bus_id, &effect_id);
+        remove.execute(&mut state).unwrap();
+        assert!(state.sequences[&seq_id]
+            .get_audio_bus(&bus_id)
+            .unwrap()
+            .effects
+            .is_empty());
+        remove.undo(&mut state).unwrap();
+        assert_eq!(
+            state.sequences[&seq_id]
+                .get_audio_bus(&bus_id)
+                .unwrap()
+                .effects,
+            vec![effect_id]
+        );
+    }
+}
@@ -3,16 +3,16 @@
 //! Implements all clip-related editing commands.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::core::{
-    assets::AssetKind,
+    assets::{Asset, AssetKind},
     commands::{Command, CommandResult, StateChange},
     project::ProjectState,
     timeline::{
         AudioKeyframe, AudioSettings, BlendMode, Clip, ClipPlace, ClipRange, FadeType,
         KeyframeInterpolation, SlowMotionInterpolation, TimeRemapCurve, TimeRemapKeyframe, Track,
-        TrackKind, Transform, TransformKeyframe,
+        TrackKind, Transform, TransformKeyframe, CHANNEL_MAP_LENGTHS,
     },
     AssetId, ClipId, CoreError, CoreResult, SequenceId, TimeSec, TrackId,
 };
@@ -2921,594 +2921,721 @@ impl Command for TrimClipCommand {
 }
 
 // =============================================================================
-// SplitClipCommand
+// Slip / Slide / Roll shared helpers
 // =============================================================================
 
-/// Command to split a clip at a given time
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SplitClipCommand {
-    /// Target sequence ID
-    pub sequence_id: SequenceId,
-    /// Target track ID
-    pub track_id: TrackId,
-    /// Clip ID to split
-    pub clip_id: ClipId,
-    /// Time to split at (in timeline time)
-    pub split_at: TimeSec,
-    /// Created clip ID (second half)
-    #[serde(skip)]
-    created_clip_id: Option<ClipId>,
-    /// Original clip data (for undo)
-    #[serde(skip)]
-    original_clip: Option<Clip>,
+/// Tolerance used when deciding whether two clips share an edit point.
+const EDIT_POINT_EPSILON: TimeSec = 1e-6;
+
+/// Source range and timeline placement of one clip touched by a slip, slide
+/// or roll edit. Used both for the planned result and for the undo snapshot.
+#[derive(Clone, Debug)]
+struct ClipSpan {
+    track_id: TrackId,
+    clip_id: ClipId,
+    range: ClipRange,
+    place: ClipPlace,
 }
 
-impl SplitClipCommand {
-    /// Creates a new split clip command
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, split_at: TimeSec) -> Self {
+impl ClipSpan {
+    fn of(track_id: &str, clip: &Clip) -> Self {
         Self {
-            sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
-            clip_id: clip_id.to_string(),
-            split_at,
-            created_clip_id: None,
-            original_clip: None,
+            clip_id: clip.id.clone(),
+            range: clip.range.clone(),
+            place: clip.place.clone(),
+        }
+    }
+}
+
+fn validate_span_edit_clip(clip: &Clip, command_type: &str) -> CoreResult<()> {
+    if clip.freeze_frame || clip.has_time_remap() {
+        return Err(CoreError::ValidationError(format!(
+            "{command_type} does not yet support freeze-frame or time-remapped clips ('{}')",
+            clip.id
+        )));
+    }
+    if !clip.speed.is_finite() || clip.speed <= 0.0 {
+        return Err(CoreError::ValidationError(
+            "Clip speed must be finite and > 0".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects a source range that reaches outside the media it plays.
+///
+/// Stills and assets without a probed duration have unlimited handles.
+fn validate_source_handles(
+    assets: &HashMap<AssetId, Asset>,
+    clip: &Clip,
+    range: &ClipRange,
+) -> CoreResult<()> {
+    if !range.source_in_sec.is_finite() || range.source_in_sec < -EDIT_POINT_EPSILON {
+        return Err(CoreError::ValidationError(format!(
+            "Clip '{}' would start {:.3}s before the beginning of its source media",
+            clip.id, -range.source_in_sec
+        )));
+    }
+
+    let asset_duration = assets
+        .get(&clip.asset_id)
+        .filter(|asset| asset.kind != AssetKind::Image)
+        .and_then(|asset| asset.duration_sec);
+    if let Some(asset_duration) = asset_duration {
+        if !range.source_out_sec.is_finite()
+            || range.source_out_sec > asset_duration + EDIT_POINT_EPSILON
+        {
+            return Err(CoreError::ValidationError(format!(
+                "Clip '{}' needs source media up to {:.3}s but asset '{}' is {:.3}s long",
+                clip.id, range.source_out_sec, clip.asset_id, asset_duration
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Plans moving a clip's out point by `delta` timeline seconds.
+fn plan_out_point_move(
+    assets: &HashMap<AssetId, Asset>,
+    track_id: &str,
+    clip: &Clip,
+    delta: TimeSec,
+    command_type: &str,
+) -> CoreResult<ClipSpan> {
+    validate_span_edit_clip(clip, command_type)?;
+
+    let mut span = ClipSpan::of(track_id, clip);
+    span.place.duration_sec += delta;
+    span.range.source_out_sec += delta * clip.speed as f64;
+    if !span.place.duration_sec.is_finite() || span.place.duration_sec <= EDIT_POINT_EPSILON {
+        return Err(CoreError::ValidationError(format!(
+            "{command_type} would leave clip '{}' with no duration",
+            clip.id
+        )));
+    }
+    validate_source_handles(assets, clip, &span.range)?;
+
+    Ok(span)
+}
+
+/// Plans moving a clip's in point by `delta` timeline seconds, keeping its end.
+fn plan_in_point_move(
+    assets: &HashMap<AssetId, Asset>,
+    track_id: &str,
+    clip: &Clip,
+    delta: TimeSec,
+    command_type: &str,
+) -> CoreResult<ClipSpan> {
+    validate_span_edit_clip(clip, command_type)?;
+
+    let mut span = ClipSpan::of(track_id, clip);
+    span.place.timeline_in_sec += delta;
+    span.place.duration_sec -= delta;
+    span.range.source_in_sec += delta * clip.speed as f64;
+    if !span.place.duration_sec.is_finite() || span.place.duration_sec <= EDIT_POINT_EPSILON {
+        return Err(CoreError::ValidationError(format!(
+            "{command_type} would leave clip '{}' with no duration",
+            clip.id
+        )));
+    }
+    validate_source_handles(assets, clip, &span.range)?;
+
+    Ok(span)
+}
+
+fn find_track_for_span_edit<'a>(
+    sequence: &'a crate::core::timeline::Sequence,
+    track_id: &str,
+) -> CoreResult<&'a Track> {
+    let track = sequence
+        .tracks
+        .iter()
+        .find(|track| track.id == track_id)
+        .ok_or_else(|| CoreError::TrackNotFound(track_id.to_string()))?;
+    validate_track_unlocked(track)?;
+    Ok(track)
+}
+
+/// Clip ending exactly where `clip` starts on the same track.
+fn clip_ending_at<'a>(track: &'a Track, clip: &Clip) -> Option<&'a Clip> {
+    track.clips.iter().find(|other| {
+        other.id != clip.id
+            && (other.place.timeline_out_sec() - clip.place.timeline_in_sec).abs()
+                < EDIT_POINT_EPSILON
+    })
+}
+
+/// Clip starting exactly where `clip` ends on the same track.
+fn clip_starting_at<'a>(track: &'a Track, clip: &Clip) -> Option<&'a Clip> {
+    track.clips.iter().find(|other| {
+        other.id != clip.id
+            && (other.place.timeline_in_sec - clip.place.timeline_out_sec()).abs()
+                < EDIT_POINT_EPSILON
+    })
+}
+
+/// Linked companions of a clip that must follow it because their track is
+/// sync-locked. Locked tracks are never touched, matching ripple edits.
+fn sync_locked_link_partners<'a>(
+    sequence: &'a crate::core::timeline::Sequence,
+    track_id: &str,
+    clip: &Clip,
+) -> Vec<(&'a Track, &'a Clip)> {
+    let Some(link_group_id) = clip.link_group_id.as_deref() else {
+        return Vec::new();
+    };
+    if link_group_id.is_empty() {
+        return Vec::new();
+    }
+
+    sequence
+        .tracks
+        .iter()
+        .filter(|track| track.id != track_id && track.sync_lock && !track.locked)
+        .flat_map(|track| {
+            track
+                .clips
+                .iter()
+                .filter(|other| other.link_group_id.as_deref() == Some(link_group_id))
+                .map(move |other| (track, other))
+        })
+        .collect()
+}
+
+/// Applies planned spans, validating every touched track before any of them
+/// is written back. Returns the previous spans for undo.
+fn commit_clip_spans(
+    sequence: &mut crate::core::timeline::Sequence,
+    spans: &[ClipSpan],
+) -> CoreResult<Vec<ClipSpan>> {
+    let mut previous = Vec::with_capacity(spans.len());
+    let mut candidates: Vec<(usize, Track)> = Vec::new();
+
+    for span in spans {
+        let track_idx = sequence
+            .tracks
+            .iter()
+            .position(|track| track.id == span.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(span.track_id.clone()))?;
+        let slot = match candidates.iter().position(|(idx, _)| *idx == track_idx) {
+            Some(slot) => slot,
+            None => {
+                candidates.push((track_idx, sequence.tracks[track_idx].clone()));
+                candidates.len() - 1
+            }
+        };
+
+        let original = sequence.tracks[track_idx]
+            .clips
+            .iter()
+            .find(|clip| clip.id == span.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(span.clip_id.clone()))?;
+        previous.push(ClipSpan::of(&span.track_id, original));
+
+        let candidate = candidates[slot]
+            .1
+            .clips
+            .iter_mut()
+            .find(|clip| clip.id == span.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(span.clip_id.clone()))?;
+        candidate.range = span.range.clone();
+        candidate.place = span.place.clone();
+    }
+
+    for (_, track) in &mut candidates {
+        sort_track_clips(track);
+        for pair in track.clips.windows(2) {
+            if pair[0].place.overlaps(&pair[1].place) {
+                return Err(CoreError::ClipOverlap {
+                    track_id: track.id.clone(),
+                    existing_clip_id: pair[0].id.clone(),
+                    new_start: pair[1].place.timeline_in_sec,
+                    new_end: pair[1].place.timeline_out_sec(),
+                });
+            }
+        }
+    }
+
+    for (track_idx, track) in candidates {
+        sequence.tracks[track_idx] = track;
+    }
+
+    Ok(previous)
+}
+
+fn restore_clip_spans(sequence: &mut crate::core::timeline::Sequence, previous: &[ClipSpan]) {
+    // Restore in reverse so a clip touched twice ends at its earliest value.
+    for span in previous.iter().rev() {
+        if let Some(track) = sequence.get_track_mut(&span.track_id) {
+            if let Some(clip) = track.clips.iter_mut().find(|c| c.id == span.clip_id) {
+                clip.range = span.range.clone();
+                clip.place = span.place.clone();
+            }
+            sort_track_clips(track);
         }
     }
 }
 
+fn clip_spans_result(spans: &[ClipSpan]) -> CommandResult {
+    let op_id = ulid::Ulid::new().to_string();
+    let mut result = CommandResult::new(&op_id);
+    let refs: Vec<(TrackId, ClipId)> = spans
+        .iter()
+        .map(|span| (span.track_id.clone(), span.clip_id.clone()))
+        .collect();
+    append_clip_modified_changes(&mut result, &refs);
+    result
+}
+
+fn validate_edit_delta(delta: TimeSec, field: &str) -> CoreResult<()> {
+    if !delta.is_finite() {
+        return Err(CoreError::ValidationError(format!(
+            "{field} must be a finite number"
+        )));
+    }
+    Ok(())
+}
+
 // =============================================================================
-// SetClipMuteCommand
+// SlipClipCommand
 // =============================================================================
 
-/// Command to set clip-level audio mute state.
+/// Command to slip a clip: shifts which part of the source plays while the
+/// clip keeps its timeline position and length.
+///
+/// Linked clips on sync-locked tracks slip by the same amount so they stay
+/// in sync with the picture.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipMuteCommand {
+pub struct SlipClipCommand {
+    /// Target sequence ID
     pub sequence_id: SequenceId,
+    /// Track containing the clip
     pub track_id: TrackId,
+    /// Clip ID to slip
     pub clip_id: ClipId,
-    pub muted: bool,
+    /// Source offset in seconds (positive plays later media)
+    pub delta_sec: TimeSec,
+    /// Spans touched by the edit, for undo
     #[serde(skip)]
-    previous_muted: Option<bool>,
+    previous_spans: Vec<ClipSpan>,
 }
 
-impl SetClipMuteCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, muted: bool) -> Self {
+impl SlipClipCommand {
+    /// Creates a new slip clip command
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, delta_sec: TimeSec) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            muted,
-            previous_muted: None,
+            delta_sec,
+            previous_spans: Vec::new(),
         }
     }
-}
 
-impl Command for SetClipMuteCommand {
-    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+    fn plan(&self, state: &ProjectState) -> CoreResult<Vec<ClipSpan>> {
+        validate_edit_delta(self.delta_sec, "deltaSec")?;
+
         let sequence = state
             .sequences
-            .get_mut(&self.sequence_id)
+            .get(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
-        let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
+        let track = find_track_for_span_edit(sequence, &self.track_id)?;
         let clip = track
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
+            .get_clip(&self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_muted = Some(clip.audio.muted);
-        clip.audio.muted = self.muted;
-
-        let op_id = ulid::Ulid::new().to_string();
-        Ok(
-            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
-                clip_id: self.clip_id.clone(),
-            }),
-        )
+        let mut targets = vec![(track, clip)];
+        targets.extend(sync_locked_link_partners(sequence, &self.track_id, clip));
+
+        targets
+            .into_iter()
+            .map(|(track, clip)| {
+                validate_span_edit_clip(clip, "SlipClip")?;
+                let mut span = ClipSpan::of(&track.id, clip);
+                span.range.source_in_sec += self.delta_sec;
+                span.range.source_out_sec += self.delta_sec;
+                validate_source_handles(&state.assets, clip, &span.range)?;
+                Ok(span)
+            })
+            .collect()
     }
+}
 
-    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(previous_muted) = self.previous_muted else {
-            return Ok(());
-        };
+impl Command for SlipClipCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let spans = self.plan(state)?;
 
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        self.previous_spans = commit_clip_spans(sequence, &spans)?;
 
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
-            return Ok(());
-        };
+        Ok(clip_spans_result(&spans))
+    }
 
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.audio.muted = previous_muted;
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            restore_clip_spans(sequence, &self.previous_spans);
         }
-
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipMute"
+        "SlipClip"
     }
 
     fn to_json(&self) -> serde_json::Value {
-        serde_json::json!({
-            "sequenceId": self.sequence_id,
-            "trackId": self.track_id,
-            "clipId": self.clip_id,
-            "muted": self.muted,
-        })
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
     }
 }
 
 // =============================================================================
-// SetClipAudioCommand
+// SlideClipCommand
 // =============================================================================
 
-const MIN_CLIP_VOLUME_DB: f32 = -60.0;
-const MAX_CLIP_VOLUME_DB: f32 = 6.0;
-
-/// Command to set clip-level audio settings.
+/// Command to slide a clip along the timeline between its neighbours.
+///
+/// The clip keeps its source range; the clip ending at its start is
+/// lengthened or shortened and the clip starting at its end is trimmed to
+/// match, so the overall program length does not change. A gap on either
+/// side absorbs the slide instead. Linked clips on sync-locked tracks slide
+/// with it.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipAudioCommand {
+pub struct SlideClipCommand {
+    /// Target sequence ID
     pub sequence_id: SequenceId,
+    /// Track containing the clip
     pub track_id: TrackId,
+    /// Clip ID to slide
     pub clip_id: ClipId,
-    pub volume_db: Option<f32>,
-    pub pan: Option<f32>,
-    pub muted: Option<bool>,
-    pub fade_in_sec: Option<TimeSec>,
-    pub fade_out_sec: Option<TimeSec>,
-    pub audio_role: Option<String>,
-    pub audio_tags: Option<Vec<String>>,
+    /// Timeline offset in seconds (positive slides right)
+    pub delta_sec: TimeSec,
+    /// Spans touched by the edit, for undo
     #[serde(skip)]
-    previous_audio: Option<AudioSettings>,
+    previous_spans: Vec<ClipSpan>,
 }
 
-impl SetClipAudioCommand {
-    #[allow(clippy::too_many_arguments)]
-    pub fn new(
-        sequence_id: &str,
-        track_id: &str,
-        clip_id: &str,
-        volume_db: Option<f32>,
-        pan: Option<f32>,
-        muted: Option<bool>,
-        fade_in_sec: Option<TimeSec>,
-        fade_out_sec: Option<TimeSec>,
-    ) -> Self {
+impl SlideClipCommand {
+    /// Creates a new slide clip command
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, delta_sec: TimeSec) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            volume_db,
-            pan,
-            muted,
-            fade_in_sec,
-            fade_out_sec,
-            audio_role: None,
-            audio_tags: None,
-            previous_audio: None,
+            delta_sec,
+            previous_spans: Vec::new(),
         }
     }
 
-    pub fn with_audio_role(mut self, audio_role: String) -> Self {
-        self.audio_role = Some(audio_role);
-        self
-    }
-
-    pub fn with_audio_tags(mut self, audio_tags: Vec<String>) -> Self {
-        self.audio_tags = Some(audio_tags);
-        self
-    }
+    fn plan(&self, state: &ProjectState) -> CoreResult<Vec<ClipSpan>> {
+        validate_edit_delta(self.delta_sec, "deltaSec")?;
 
-    fn clamp_volume_db(value: f32) -> f32 {
-        value.clamp(MIN_CLIP_VOLUME_DB, MAX_CLIP_VOLUME_DB)
-    }
-
-    fn clamp_pan(value: f32) -> f32 {
-        value.clamp(-1.0, 1.0)
-    }
-
-    fn clamp_fade_duration(value: TimeSec, clip_duration: TimeSec) -> TimeSec {
-        value.clamp(0.0, clip_duration.max(0.0))
-    }
-
-    fn normalize_fade_pair(clip: &mut Clip, fade_in_updated: bool, fade_out_updated: bool) {
-        let clip_duration = clip.duration().max(0.0);
-
-        clip.audio.fade_in_sec = Self::clamp_fade_duration(clip.audio.fade_in_sec, clip_duration);
-        clip.audio.fade_out_sec = Self::clamp_fade_duration(clip.audio.fade_out_sec, clip_duration);
-
-        let total_fade = clip.audio.fade_in_sec + clip.audio.fade_out_sec;
-        if total_fade <= clip_duration {
-            return;
-        }
-
-        if fade_in_updated && !fade_out_updated {
-            clip.audio.fade_in_sec = (clip_duration - clip.audio.fade_out_sec).max(0.0);
-            return;
-        }
-
-        clip.audio.fade_out_sec = (clip_duration - clip.audio.fade_in_sec).max(0.0);
-    }
+        let sequence = state
+            .sequences
+            .get(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let track = find_track_for_span_edit(sequence, &self.track_id)?;
+        let clip = track
+            .get_clip(&self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-    fn normalize_audio_role(value: &str) -> CoreResult<Option<String>> {
-        let normalized = value.trim().to_ascii_lowercase();
-        if normalized.is_empty() || normalized == "none" {
-            return Ok(None);
-        }
+        let mut targets = vec![(track, clip)];
+        targets.extend(sync_locked_link_partners(sequence, &self.track_id, clip));
 
-        match normalized.as_str() {
-            "dialogue" | "music" | "sfx" | "ambience" | "voiceover" => Ok(Some(normalized)),
-            _ => Err(CoreError::InvalidCommand(format!(
-                "Unsupported audioRole '{}'",
-                value
-            ))),
-        }
-    }
+        let mut spans = Vec::new();
+        for (track, clip) in targets {
+            validate_span_edit_clip(clip, "SlideClip")?;
 
-    fn normalize_audio_tags(values: &[String]) -> Vec<String> {
-        let mut normalized = Vec::new();
-        for value in values {
-            let tag = value.trim().to_ascii_lowercase();
-            if tag.is_empty() || normalized.contains(&tag) {
-                continue;
+            let mut span = ClipSpan::of(&track.id, clip);
+            span.place.timeline_in_sec += self.delta_sec;
+            if !is_valid_time_sec(span.place.timeline_in_sec) {
+                return Err(CoreError::ValidationError(format!(
+                    "SlideClip would move clip '{}' before the start of the sequence",
+                    clip.id
+                )));
+            }
+            spans.push(span);
+
+            if let Some(previous) = clip_ending_at(track, clip) {
+                spans.push(plan_out_point_move(
+                    &state.assets,
+                    &track.id,
+                    previous,
+                    self.delta_sec,
+                    "SlideClip",
+                )?);
+            }
+            if let Some(next) = clip_starting_at(track, clip) {
+                spans.push(plan_in_point_move(
+                    &state.assets,
+                    &track.id,
+                    next,
+                    self.delta_sec,
+                    "SlideClip",
+                )?);
             }
-            normalized.push(tag);
         }
-        normalized
+
+        Ok(spans)
     }
 }
 
-impl Command for SetClipAudioCommand {
+impl Command for SlideClipCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if self.volume_db.is_none()
-            && self.pan.is_none()
-            && self.muted.is_none()
-            && self.fade_in_sec.is_none()
-            && self.fade_out_sec.is_none()
-            && self.audio_role.is_none()
-            && self.audio_tags.is_none()
-        {
-            return Err(CoreError::InvalidCommand(
-                "SetClipAudio requires at least one audio field".to_string(),
-            ));
-        }
+        let spans = self.plan(state)?;
 
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        self.previous_spans = commit_clip_spans(sequence, &spans)?;
 
-        let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        Ok(clip_spans_result(&spans))
+    }
 
-        let clip = track
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            restore_clip_spans(sequence, &self.previous_spans);
+        }
+        Ok(())
+    }
 
-        self.previous_audio = Some(clip.audio.clone());
+    fn type_name(&self) -> &'static str {
+        "SlideClip"
+    }
 
-        if let Some(volume_db) = self.volume_db {
-            if !volume_db.is_finite() {
-                return Err(CoreError::InvalidCommand(
-                    "volumeDb must be a finite number".to_string(),
-                ));
-            }
-            clip.audio.volume_db = Self::clamp_volume_db(volume_db);
-        }
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
 
-        if let Some(pan) = self.pan {
-            if !pan.is_finite() {
-                return Err(CoreError::InvalidCommand(
-                    "pan must be a finite number".to_string(),
-                ));
-            }
-            clip.audio.pan = Self::clamp_pan(pan);
-        }
+// =============================================================================
+// RollEditCommand
+// =============================================================================
 
-        if let Some(muted) = self.muted {
-            clip.audio.muted = muted;
-        }
+/// Command to roll the edit point between two adjacent clips.
+///
+/// The outgoing clip's out point and the incoming clip's in point move
+/// together, so nothing else on the track shifts. When both clips have linked
+/// companions that meet at a cut on a sync-locked track, that cut rolls too.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RollEditCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Track containing both clips
+    pub track_id: TrackId,
+    /// Clip ending at the edit point
+    pub outgoing_clip_id: ClipId,
+    /// Clip starting at the edit point
+    pub incoming_clip_id: ClipId,
+    /// Edit point offset in seconds (positive rolls right)
+    pub delta_sec: TimeSec,
+    /// Spans touched by the edit, for undo
+    #[serde(skip)]
+    previous_spans: Vec<ClipSpan>,
+}
 
-        if let Some(fade_in_sec) = self.fade_in_sec {
-            if !is_valid_time_sec(fade_in_sec) {
-                return Err(CoreError::InvalidCommand(
-                    "fadeInSec must be a finite, non-negative number".to_string(),
-                ));
-            }
-            clip.audio.fade_in_sec = fade_in_sec;
+impl RollEditCommand {
+    /// Creates a new roll edit command
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        outgoing_clip_id: &str,
+        incoming_clip_id: &str,
+        delta_sec: TimeSec,
+    ) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            outgoing_clip_id: outgoing_clip_id.to_string(),
+            incoming_clip_id: incoming_clip_id.to_string(),
+            delta_sec,
+            previous_spans: Vec::new(),
         }
+    }
 
-        if let Some(fade_out_sec) = self.fade_out_sec {
-            if !is_valid_time_sec(fade_out_sec) {
-                return Err(CoreError::InvalidCommand(
-                    "fadeOutSec must be a finite, non-negative number".to_string(),
-                ));
-            }
-            clip.audio.fade_out_sec = fade_out_sec;
-        }
+    fn plan(&self, state: &ProjectState) -> CoreResult<Vec<ClipSpan>> {
+        validate_edit_delta(self.delta_sec, "deltaSec")?;
 
-        if let Some(audio_role) = &self.audio_role {
-            clip.audio.audio_role = Self::normalize_audio_role(audio_role)?;
+        let sequence = state
+            .sequences
+            .get(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let track = find_track_for_span_edit(sequence, &self.track_id)?;
+        let outgoing = track
+            .get_clip(&self.outgoing_clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.outgoing_clip_id.clone()))?;
+        let incoming = track
+            .get_clip(&self.incoming_clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.incoming_clip_id.clone()))?;
+
+        if clip_starting_at(track, outgoing).map(|clip| &clip.id) != Some(&incoming.id) {
+            return Err(CoreError::ValidationError(format!(
+                "Clips '{}' and '{}' do not share an edit point",
+                outgoing.id, incoming.id
+            )));
         }
 
-        if let Some(audio_tags) = &self.audio_tags {
-            clip.audio.audio_tags = Self::normalize_audio_tags(audio_tags);
+        let mut cuts = vec![(track, outgoing, incoming)];
+        let incoming_group = incoming
+            .link_group_id
+            .as_deref()
+            .filter(|group| !group.is_empty());
+        for (partner_track, partner) in
+            sync_locked_link_partners(sequence, &self.track_id, outgoing)
+        {
+            let partner_next = clip_starting_at(partner_track, partner).filter(|next| {
+                incoming_group.is_some() && next.link_group_id.as_deref() == incoming_group
+            });
+            if let Some(partner_next) = partner_next {
+                cuts.push((partner_track, partner, partner_next));
+            }
         }
 
-        Self::normalize_fade_pair(
-            clip,
-            self.fade_in_sec.is_some(),
-            self.fade_out_sec.is_some(),
-        );
+        let mut spans = Vec::with_capacity(cuts.len() * 2);
+        for (track, outgoing, incoming) in cuts {
+            spans.push(plan_out_point_move(
+                &state.assets,
+                &track.id,
+                outgoing,
+                self.delta_sec,
+                "RollEdit",
+            )?);
+            spans.push(plan_in_point_move(
+                &state.assets,
+                &track.id,
+                incoming,
+                self.delta_sec,
+                "RollEdit",
+            )?);
+        }
 
-        let op_id = ulid::Ulid::new().to_string();
-        Ok(
-            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
-                clip_id: self.clip_id.clone(),
-            }),
-        )
+        Ok(spans)
     }
+}
 
-    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(previous_audio) = &self.previous_audio else {
-            return Ok(());
-        };
+impl Command for RollEditCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let spans = self.plan(state)?;
 
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        self.previous_spans = commit_clip_spans(sequence, &spans)?;
 
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
-            return Ok(());
-        };
+        Ok(clip_spans_result(&spans))
+    }
 
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.audio = previous_audio.clone();
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            restore_clip_spans(sequence, &self.previous_spans);
         }
-
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipAudio"
+        "RollEdit"
     }
 
     fn to_json(&self) -> serde_json::Value {
-        serde_json::json!({
-            "sequenceId": self.sequence_id,
-            "trackId": self.track_id,
-            "clipId": self.clip_id,
-            "volumeDb": self.volume_db,
-            "pan": self.pan,
-            "muted": self.muted,
-            "fadeInSec": self.fade_in_sec,
-            "fadeOutSec": self.fade_out_sec,
-            "audioRole": self.audio_role,
-            "audioTags": self.audio_tags,
-        })
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
     }
 }
 
 // =============================================================================
-// SetClipSpeedCommand
+// SplitClipCommand
 // =============================================================================
 
-/// Command to update a clip's playback speed.
-///
-/// Timeline duration is automatically recalculated from source range and speed:
-/// `duration = (sourceOut - sourceIn) / speed`.
+/// Command to split a clip at a given time
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipSpeedCommand {
+pub struct SplitClipCommand {
+    /// Target sequence ID
     pub sequence_id: SequenceId,
+    /// Target track ID
     pub track_id: TrackId,
+    /// Clip ID to split
     pub clip_id: ClipId,
-    pub speed: f32,
-    pub reverse: bool,
-    #[serde(skip)]
-    previous_speed: Option<f32>,
+    /// Time to split at (in timeline time)
+    pub split_at: TimeSec,
+    /// Created clip ID (second half)
     #[serde(skip)]
-    previous_reverse: Option<bool>,
+    created_clip_id: Option<ClipId>,
+    /// Original clip data (for undo)
     #[serde(skip)]
-    previous_duration_sec: Option<TimeSec>,
+    original_clip: Option<Clip>,
 }
 
-impl SetClipSpeedCommand {
-    pub fn new(
-        sequence_id: &str,
-        track_id: &str,
-        clip_id: &str,
-        speed: f32,
-        reverse: bool,
-    ) -> Self {
+impl SplitClipCommand {
+    /// Creates a new split clip command
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, split_at: TimeSec) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            speed,
-            reverse,
-            previous_speed: None,
-            previous_reverse: None,
-            previous_duration_sec: None,
+            split_at,
+            created_clip_id: None,
+            original_clip: None,
         }
     }
 }
 
-impl Command for SetClipSpeedCommand {
-    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.speed.is_finite() || self.speed <= 0.0 {
-            return Err(CoreError::InvalidCommand(
-                "speed must be a finite number > 0".to_string(),
-            ));
-        }
-
-        let sequence = state
-            .sequences
-            .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
-        let track_idx = sequence
-            .tracks
-            .iter()
-            .position(|track| track.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
-        validate_track_unlocked(&sequence.tracks[track_idx])?;
-
-        let clip_idx = sequence.tracks[track_idx]
-            .clips
-            .iter()
-            .position(|c| c.id == self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
-
-        {
-            let clip = &sequence.tracks[track_idx].clips[clip_idx];
-            self.previous_speed = Some(clip.speed);
-            self.previous_reverse = Some(clip.reverse);
-            self.previous_duration_sec = Some(clip.place.duration_sec);
-        }
-
-        let clip = &mut sequence.tracks[track_idx].clips[clip_idx];
-        clip.speed = self.speed;
-        clip.reverse = self.reverse;
-        clip.place.duration_sec = clip.range.duration() / self.speed as f64;
-
-        if !clip.place.duration_sec.is_finite() || clip.place.duration_sec <= 0.0 {
-            return Err(CoreError::ValidationError(
-                "Clip duration must be finite and > 0 after speed change".to_string(),
-            ));
-        }
-
-        {
-            let clip_ref = &sequence.tracks[track_idx].clips[clip_idx];
-            let track = &sequence.tracks[track_idx];
-            validate_no_overlap(track, &clip_ref.place, Some(&clip_ref.id))?;
-        }
-
-        let op_id = ulid::Ulid::new().to_string();
-        Ok(
-            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
-                clip_id: self.clip_id.clone(),
-            }),
-        )
-    }
-
-    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let (Some(previous_speed), Some(previous_reverse), Some(previous_duration_sec)) = (
-            self.previous_speed,
-            self.previous_reverse,
-            self.previous_duration_sec,
-        ) else {
-            return Ok(());
-        };
-
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
-
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
-            return Ok(());
-        };
-
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.speed = previous_speed;
-            clip.reverse = previous_reverse;
-            clip.place.duration_sec = previous_duration_sec;
-            sort_track_clips(track);
-        }
-
-        Ok(())
-    }
-
-    fn type_name(&self) -> &'static str {
-        "SetClipSpeed"
-    }
-
-    fn to_json(&self) -> serde_json::Value {
-        serde_json::json!({
-            "sequenceId": self.sequence_id,
-            "trackId": self.track_id,
-            "clipId": self.clip_id,
-            "speed": self.speed,
-            "reverse": self.reverse,
-        })
-    }
-}
-
 // =============================================================================
-// SetClipSlowMotionInterpolationCommand
+// SetClipMuteCommand
 // =============================================================================
 
-/// Command to update a clip's slow-motion interpolation mode.
+/// Command to set clip-level audio mute state.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipSlowMotionInterpolationCommand {
+pub struct SetClipMuteCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub interpolation: SlowMotionInterpolation,
+    pub muted: bool,
     #[serde(skip)]
-    previous_interpolation: Option<SlowMotionInterpolation>,
+    previous_muted: Option<bool>,
 }
 
-impl SetClipSlowMotionInterpolationCommand {
-    pub fn new(
-        sequence_id: &str,
-        track_id: &str,
-        clip_id: &str,
-        interpolation: SlowMotionInterpolation,
-    ) -> Self {
+impl SetClipMuteCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, muted: bool) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            interpolation,
-            previous_interpolation: None,
+            muted,
+            previous_muted: None,
         }
     }
 }
 
-impl Command for SetClipSlowMotionInterpolationCommand {
+impl Command for SetClipMuteCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        let track_idx = sequence
+        let track = sequence
             .tracks
-            .iter()
-            .position(|track| track.id == self.track_id)
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        validate_track_unlocked(&sequence.tracks[track_idx])?;
-
-        let clip = sequence.tracks[track_idx]
+        let clip = track
             .clips
             .iter_mut()
             .find(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_interpolation = Some(clip.slow_motion_interpolation.clone());
-        clip.slow_motion_interpolation = self.interpolation.clone();
+        self.previous_muted = Some(clip.audio.muted);
+        clip.audio.muted = self.muted;
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -3519,7 +3646,7 @@ impl Command for SetClipSlowMotionInterpolationCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(ref previous_interpolation) = self.previous_interpolation else {
+        let Some(previous_muted) = self.previous_muted else {
             return Ok(());
         };
 
@@ -3532,14 +3659,14 @@ impl Command for SetClipSlowMotionInterpolationCommand {
         };
 
         if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.slow_motion_interpolation = previous_interpolation.clone();
+            clip.audio.muted = previous_muted;
         }
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipSlowMotionInterpolation"
+        "SetClipMute"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -3547,139 +3674,194 @@ impl Command for SetClipSlowMotionInterpolationCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "interpolation": self.interpolation,
+            "muted": self.muted,
         })
     }
 }
 
 // =============================================================================
-// ReverseClipCommand
+// SetClipAudioCommand
 // =============================================================================
 
-/// Command to toggle a clip's reverse playback state.
-///
-/// When reversed, video and audio play backward. Reverse is applied in the
-/// FFmpeg render pipeline via `reverse` and `areverse` filters.
+const MIN_CLIP_VOLUME_DB: f32 = -60.0;
+const MAX_CLIP_VOLUME_DB: f32 = 6.0;
+
+/// Command to set clip-level audio settings.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ReverseClipCommand {
+pub struct SetClipAudioCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
+    pub volume_db: Option<f32>,
+    pub pan: Option<f32>,
+    pub muted: Option<bool>,
+    pub fade_in_sec: Option<TimeSec>,
+    pub fade_out_sec: Option<TimeSec>,
+    pub audio_role: Option<String>,
+    pub audio_tags: Option<Vec<String>>,
+    /// Source channels to play; an empty map plays the source as it is
+    #[serde(default)]
+    pub channel_map: Option<Vec<u8>>,
     #[serde(skip)]
-    previous_reverse: Option<bool>,
+    previous_audio: Option<AudioSettings>,
 }
 
-impl ReverseClipCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str) -> Self {
+impl SetClipAudioCommand {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        clip_id: &str,
+        volume_db: Option<f32>,
+        pan: Option<f32>,
+        muted: Option<bool>,
+        fade_in_sec: Option<TimeSec>,
+        fade_out_sec: Option<TimeSec>,
+    ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            previous_reverse: None,
+            volume_db,
+            pan,
+            muted,
+            fade_in_sec,
+            fade_out_sec,
+            audio_role: None,
+            audio_tags: None,
+            channel_map: None,
+            previous_audio: None,
         }
     }
-}
 
-impl Command for ReverseClipCommand {
-    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        let sequence = state
-            .sequences
-            .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+    pub fn with_audio_role(mut self, audio_role: String) -> Self {
+        self.audio_role = Some(audio_role);
+        self
+    }
 
-        let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+    pub fn with_audio_tags(mut self, audio_tags: Vec<String>) -> Self {
+        self.audio_tags = Some(audio_tags);
+        self
+    }
 
-        validate_track_unlocked(track)?;
+    pub fn with_channel_map(mut self, channel_map: Vec<u8>) -> Self {
+        self.channel_map = Some(channel_map);
+        self
+    }
 
-        let clip = track
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+    fn clamp_volume_db(value: f32) -> f32 {
+        value.clamp(MIN_CLIP_VOLUME_DB, MAX_CLIP_VOLUME_DB)
+    }
 
-        self.previous_reverse = Some(clip.reverse);
-        clip.reverse = !clip.reverse;
+    fn clamp_pan(value: f32) -> f32 {
+        value.clamp(-1.0, 1.0)
+    }
 
-        let op_id = ulid::Ulid::new().to_string();
-        Ok(
-            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
-                clip_id: self.clip_id.clone(),
-            }),
-        )
+    fn clamp_fade_duration(value: TimeSec, clip_duration: TimeSec) -> TimeSec {
+        value.clamp(0.0, clip_duration.max(0.0))
     }
 
-    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(previous_reverse) = self.previous_reverse else {
-            return Ok(());
-        };
+    fn normalize_fade_pair(clip: &mut Clip, fade_in_updated: bool, fade_out_updated: bool) {
+        let clip_duration = clip.duration().max(0.0);
 
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
+        clip.audio.fade_in_sec = Self::clamp_fade_duration(clip.audio.fade_in_sec, clip_duration);
+        clip.audio.fade_out_sec = Self::clamp_fade_duration(clip.audio.fade_out_sec, clip_duration);
 
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
-            return Ok(());
-        };
+        let total_fade = clip.audio.fade_in_sec + clip.audio.fade_out_sec;
+        if total_fade <= clip_duration {
+            return;
+        }
 
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.reverse = previous_reverse;
+        if fade_in_updated && !fade_out_updated {
+            clip.audio.fade_in_sec = (clip_duration - clip.audio.fade_out_sec).max(0.0);
+            return;
         }
 
-        Ok(())
+        clip.audio.fade_out_sec = (clip_duration - clip.audio.fade_in_sec).max(0.0);
     }
 
-    fn type_name(&self) -> &'static str {
-        "ReverseClip"
-    }
+    fn normalize_audio_role(value: &str) -> CoreResult<Option<String>> {
+        let normalized = value.trim().to_ascii_lowercase();
+        if normalized.is_empty() || normalized == "none" {
+            return Ok(None);
+        }
 
-    fn to_json(&self) -> serde_json::Value {
-        serde_json::json!({
-            "sequenceId": self.sequence_id,
-            "trackId": self.track_id,
-            "clipId": self.clip_id,
-        })
+        match normalized.as_str() {
+            "dialogue" | "music" | "sfx" | "ambience" | "voiceover" => Ok(Some(normalized)),
+            _ => Err(CoreError::InvalidCommand(format!(
+                "Unsupported audioRole '{}'",
+                value
+            ))),
+        }
     }
-}
 
-// =============================================================================
-// SetClipEnabledCommand
-// =============================================================================
+    /// Checks a channel map against the layouts it can produce and, when the
+    /// clip's asset reports its channel count, against the source channels.
+    fn validate_channel_map(&self, state: &ProjectState, channel_map: &[u8]) -> CoreResult<()> {
+        if channel_map.is_empty() {
+            return Ok(());
+        }
+        if !CHANNEL_MAP_LENGTHS.contains(&channel_map.len()) {
+            return Err(CoreError::InvalidCommand(format!(
+                "channelMap must pick 1, 2, 6 or 8 channels, got {}",
+                channel_map.len()
+            )));
+        }
 
-/// Command to enable or disable a clip.
-///
-/// Disabled clips are skipped during render/preview but remain on the timeline
-/// for non-destructive toggling. This is the clip-level equivalent of muting —
-/// the clip stays in place, preserving timing, but produces no output.
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct SetClipEnabledCommand {
-    pub sequence_id: SequenceId,
-    pub track_id: TrackId,
-    pub clip_id: ClipId,
-    pub enabled: bool,
-    #[serde(skip)]
-    previous_enabled: Option<bool>,
-}
+        let source_channels = state
+            .sequences
+            .get(&self.sequence_id)
+            .and_then(|sequence| sequence.get_track(&self.track_id))
+            .and_then(|track| track.clips.iter().find(|c| c.id == self.clip_id))
+            .and_then(|clip| state.assets.get(&clip.asset_id))
+            .and_then(|asset| asset.audio.as_ref())
+            .map(|audio| audio.channels);
+        if let Some(channels) = source_channels {
+            if let Some(&channel) = channel_map.iter().find(|&&c| c >= channels) {
+                return Err(CoreError::InvalidCommand(format!(
+                    "channelMap picks channel {} but the source has {} channels",
+                    channel, channels
+                )));
+            }
+        }
 
-impl SetClipEnabledCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, enabled: bool) -> Self {
-        Self {
-            sequence_id: sequence_id.to_string(),
-            track_id: track_id.to_string(),
-            clip_id: clip_id.to_string(),
-            enabled,
-            previous_enabled: None,
+        Ok(())
+    }
+
+    fn normalize_audio_tags(values: &[String]) -> Vec<String> {
+        let mut normalized = Vec::new();
+        for value in values {
+            let tag = value.trim().to_ascii_lowercase();
+            if tag.is_empty() || normalized.contains(&tag) {
+                continue;
+            }
+            normalized.push(tag);
         }
+        normalized
     }
 }
 
-impl Command for SetClipEnabledCommand {
+impl Command for SetClipAudioCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        if self.volume_db.is_none()
+            && self.pan.is_none()
+            && self.muted.is_none()
+            && self.fade_in_sec.is_none()
+            && self.fade_out_sec.is_none()
+            && self.audio_role.is_none()
+            && self.audio_tags.is_none()
+            && self.channel_map.is_none()
+        {
+            return Err(CoreError::InvalidCommand(
+                "SetClipAudio requires at least one audio field".to_string(),
+            ));
+        }
+
+        if let Some(channel_map) = &self.channel_map {
+            self.validate_channel_map(state, channel_map)?;
+        }
+
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
@@ -3691,16 +3873,71 @@ impl Command for SetClipEnabledCommand {
             .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        validate_track_unlocked(track)?;
-
         let clip = track
             .clips
             .iter_mut()
             .find(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_enabled = Some(clip.enabled);
-        clip.enabled = self.enabled;
+        self.previous_audio = Some(clip.audio.clone());
+
+        if let Some(volume_db) = self.volume_db {
+            if !volume_db.is_finite() {
+                return Err(CoreError::InvalidCommand(
+                    "volumeDb must be a finite number".to_string(),
+                ));
+            }
+            clip.audio.volume_db = Self::clamp_volume_db(volume_db);
+        }
+
+        if let Some(pan) = self.pan {
+            if !pan.is_finite() {
+                return Err(CoreError::InvalidCommand(
+                    "pan must be a finite number".to_string(),
+                ));
+            }
+            clip.audio.pan = Self::clamp_pan(pan);
+        }
+
+        if let Some(muted) = self.muted {
+            clip.audio.muted = muted;
+        }
+
+        if let Some(fade_in_sec) = self.fade_in_sec {
+            if !is_valid_time_sec(fade_in_sec) {
+                return Err(CoreError::InvalidCommand(
+                    "fadeInSec must be a finite, non-negative number".to_string(),
+                ));
+            }
+            clip.audio.fade_in_sec = fade_in_sec;
+        }
+
+        if let Some(fade_out_sec) = self.fade_out_sec {
+            if !is_valid_time_sec(fade_out_sec) {
+                return Err(CoreError::InvalidCommand(
+                    "fadeOutSec must be a finite, non-negative number".to_string(),
+                ));
+            }
+            clip.audio.fade_out_sec = fade_out_sec;
+        }
+
+        if let Some(audio_role) = &self.audio_role {
+            clip.audio.audio_role = Self::normalize_audio_role(audio_role)?;
+        }
+
+        if let Some(audio_tags) = &self.audio_tags {
+            clip.audio.audio_tags = Self::normalize_audio_tags(audio_tags);
+        }
+
+        if let Some(channel_map) = &self.channel_map {
+            clip.audio.channel_map = channel_map.clone();
+        }
+
+        Self::normalize_fade_pair(
+            clip,
+            self.fade_in_sec.is_some(),
+            self.fade_out_sec.is_some(),
+        );
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -3711,7 +3948,7 @@ impl Command for SetClipEnabledCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(previous_enabled) = self.previous_enabled else {
+        let Some(previous_audio) = &self.previous_audio else {
             return Ok(());
         };
 
@@ -3724,14 +3961,14 @@ impl Command for SetClipEnabledCommand {
         };
 
         if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.enabled = previous_enabled;
+            clip.audio = previous_audio.clone();
         }
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipEnabled"
+        "SetClipAudio"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -3739,245 +3976,131 @@ impl Command for SetClipEnabledCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "enabled": self.enabled,
+            "volumeDb": self.volume_db,
+            "pan": self.pan,
+            "muted": self.muted,
+            "fadeInSec": self.fade_in_sec,
+            "fadeOutSec": self.fade_out_sec,
+            "audioRole": self.audio_role,
+            "audioTags": self.audio_tags,
+            "channelMap": self.channel_map,
         })
     }
 }
 
 // =============================================================================
-// CreateFreezeFrameCommand
+// SetClipSpeedCommand
 // =============================================================================
 
-/// Command to create a freeze frame from a clip at the playhead position.
+/// Command to update a clip's playback speed.
 ///
-/// Extracts a single frame from the source clip at the given timeline position
-/// and inserts a new still clip of the specified duration. The freeze frame clip
-/// uses `freeze_frame = true` so the render pipeline loops the single frame.
+/// Timeline duration is automatically recalculated from source range and speed:
+/// `duration = (sourceOut - sourceIn) / speed`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateFreezeFrameCommand {
+pub struct SetClipSpeedCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub playhead_sec: f64,
-    #[serde(default = "default_freeze_frame_duration")]
-    pub duration_sec: f64,
+    pub speed: f32,
+    pub reverse: bool,
     #[serde(skip)]
-    created_clip_id: Option<ClipId>,
+    previous_speed: Option<f32>,
     #[serde(skip)]
-    shifted_clips: Vec<ShiftedClipRecord>,
+    previous_reverse: Option<bool>,
     #[serde(skip)]
-    split_clips: Vec<SplitClipRecord>,
-}
-
-pub const DEFAULT_FREEZE_FRAME_DURATION: f64 = 2.0;
-
-fn default_freeze_frame_duration() -> f64 {
-    DEFAULT_FREEZE_FRAME_DURATION
+    previous_duration_sec: Option<TimeSec>,
 }
 
-impl CreateFreezeFrameCommand {
+impl SetClipSpeedCommand {
     pub fn new(
         sequence_id: &str,
         track_id: &str,
         clip_id: &str,
-        playhead_sec: f64,
-        duration_sec: f64,
+        speed: f32,
+        reverse: bool,
     ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            playhead_sec,
-            duration_sec,
-            created_clip_id: None,
-            shifted_clips: Vec::new(),
-            split_clips: Vec::new(),
+            speed,
+            reverse,
+            previous_speed: None,
+            previous_reverse: None,
+            previous_duration_sec: None,
         }
     }
 }
 
-impl Command for CreateFreezeFrameCommand {
+impl Command for SetClipSpeedCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.playhead_sec.is_finite() || self.playhead_sec < 0.0 {
-            return Err(CoreError::InvalidCommand(
-                "playhead_sec must be a finite non-negative number".to_string(),
-            ));
-        }
-        if !self.duration_sec.is_finite() || self.duration_sec <= 0.0 {
+        if !self.speed.is_finite() || self.speed <= 0.0 {
             return Err(CoreError::InvalidCommand(
-                "duration_sec must be a finite number > 0".to_string(),
+                "speed must be a finite number > 0".to_string(),
             ));
         }
 
-        let (source_clip_template, freeze_range, shift_records, split_candidates) = {
-            let sequence = state
-                .sequences
-                .get(&self.sequence_id)
-                .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-            let track = sequence
-                .tracks
-                .iter()
-                .find(|t| t.id == self.track_id)
-                .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        let track_idx = sequence
+            .tracks
+            .iter()
+            .position(|track| track.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-            validate_track_unlocked(track)?;
+        validate_track_unlocked(&sequence.tracks[track_idx])?;
 
-            let source_clip = track
-                .clips
-                .iter()
-                .find(|c| c.id == self.clip_id)
-                .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+        let clip_idx = sequence.tracks[track_idx]
+            .clips
+            .iter()
+            .position(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-            let clip_start = source_clip.place.timeline_in_sec;
-            let clip_end = source_clip.place.timeline_out_sec();
-            if self.playhead_sec < clip_start || self.playhead_sec > clip_end {
-                return Err(CoreError::ValidationError(format!(
-                    "Playhead ({:.3}s) is outside clip range [{:.3}s, {:.3}s]",
-                    self.playhead_sec, clip_start, clip_end
-                )));
-            }
-
-            let fps = sequence.format.fps.as_f64();
-            let frame_window = if fps > 0.0 { 1.0 / fps } else { 1.0 / 25.0 };
-            let freeze_range =
-                freeze_frame_source_range(source_clip, self.playhead_sec, frame_window);
-            let mut shift_records = Vec::new();
-            let mut split_candidates = Vec::new();
-
-            for clip in &track.clips {
-                let clip_start = clip.place.timeline_in_sec;
-                let clip_end = clip.place.timeline_out_sec();
-
-                if clip_start < self.playhead_sec && clip_end > self.playhead_sec {
-                    split_candidates.push(clip.clone());
-                } else if clip_start >= self.playhead_sec {
-                    shift_records.push(ShiftedClipRecord {
-                        track_id: self.track_id.clone(),
-                        clip_id: clip.id.clone(),
-                        original_timeline_in: clip_start,
-                    });
-                }
-            }
-
-            (
-                source_clip.clone(),
-                freeze_range,
-                shift_records,
-                split_candidates,
-            )
-        };
-
-        let sequence = state
-            .sequences
-            .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
-        let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
-        let mut split_records = Vec::new();
-
-        for original_clip in split_candidates {
-            let relative_split = self.playhead_sec - original_clip.place.timeline_in_sec;
-            let right_duration = original_clip.place.timeline_out_sec() - self.playhead_sec;
-            if !relative_split.is_finite()
-                || !right_duration.is_finite()
-                || relative_split <= 0.0
-                || right_duration <= 0.0
-            {
-                return Err(CoreError::ValidationError(
-                    "Freeze frame split produced an invalid clip duration".to_string(),
-                ));
-            }
-
-            let ((first_source_in, first_source_out), (second_source_in, second_source_out)) =
-                split_clip_ranges_at(&original_clip, self.playhead_sec);
-            let fragment = clone_clip_fragment_with_rebased_time_remap(
-                &original_clip,
-                second_source_in,
-                second_source_out,
-                self.playhead_sec + self.duration_sec,
-                right_duration,
-                relative_split,
-            );
-            let fragment_id = fragment.id.clone();
-
-            let clip = track
-                .clips
-                .iter_mut()
-                .find(|c| c.id == original_clip.id)
-                .ok_or_else(|| CoreError::ClipNotFound(original_clip.id.clone()))?;
-            clip.range.source_in_sec = first_source_in;
-            clip.range.source_out_sec = first_source_out;
-            clip.place.duration_sec = relative_split;
-            rebase_clip_time_remap_for_fragment(clip, 0.0, relative_split);
-            insert_clip_sorted(track, fragment);
-
-            split_records.push(SplitClipRecord {
-                track_id: self.track_id.clone(),
-                original_clip: Box::new(original_clip),
-                fragment_clip_id: fragment_id,
-            });
-        }
-
-        for record in &shift_records {
-            if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
-                clip.place.timeline_in_sec += self.duration_sec;
-            }
+        {
+            let clip = &sequence.tracks[track_idx].clips[clip_idx];
+            self.previous_speed = Some(clip.speed);
+            self.previous_reverse = Some(clip.reverse);
+            self.previous_duration_sec = Some(clip.place.duration_sec);
         }
-        sort_track_clips(track);
-
-        let mut freeze_clip = source_clip_template;
-        freeze_clip.id = ulid::Ulid::new().to_string();
-        freeze_clip.range = freeze_range;
-        freeze_clip.place = ClipPlace {
-            timeline_in_sec: self.playhead_sec,
-            duration_sec: self.duration_sec,
-        };
-        freeze_clip.freeze_frame = true;
-        freeze_clip.speed = 1.0;
-        freeze_clip.reverse = false;
-        freeze_clip.time_remap = None;
-        freeze_clip.audio.muted = true;
-
-        let clip_id = freeze_clip.id.clone();
-        insert_clip_sorted(track, freeze_clip);
-
-        self.created_clip_id = Some(clip_id.clone());
-        self.shifted_clips = shift_records;
-        self.split_clips = split_records;
 
-        let op_id = ulid::Ulid::new().to_string();
-        let mut result = CommandResult::new(&op_id)
-            .with_change(StateChange::ClipCreated {
-                clip_id: clip_id.clone(),
-            })
-            .with_created_id(&clip_id);
+        let clip = &mut sequence.tracks[track_idx].clips[clip_idx];
+        clip.speed = self.speed;
+        clip.reverse = self.reverse;
+        clip.place.duration_sec = clip.range.duration() / self.speed as f64;
 
-        for record in &self.shifted_clips {
-            result = result.with_change(StateChange::ClipModified {
-                clip_id: record.clip_id.clone(),
-            });
+        if !clip.place.duration_sec.is_finite() || clip.place.duration_sec <= 0.0 {
+            return Err(CoreError::ValidationError(
+                "Clip duration must be finite and > 0 after speed change".to_string(),
+            ));
         }
 
-        for record in &self.split_clips {
-            result = result.with_change(StateChange::ClipModified {
-                clip_id: record.original_clip.id.clone(),
-            });
-            result = result.with_change(StateChange::ClipCreated {
-                clip_id: record.fragment_clip_id.clone(),
-            });
+        {
+            let clip_ref = &sequence.tracks[track_idx].clips[clip_idx];
+            let track = &sequence.tracks[track_idx];
+            validate_no_overlap(track, &clip_ref.place, Some(&clip_ref.id))?;
         }
 
-        Ok(result)
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
+                clip_id: self.clip_id.clone(),
+            }),
+        )
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let (Some(previous_speed), Some(previous_reverse), Some(previous_duration_sec)) = (
+            self.previous_speed,
+            self.previous_reverse,
+            self.previous_duration_sec,
+        ) else {
+            return Ok(());
+        };
+
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
@@ -3986,36 +4109,18 @@ impl Command for CreateFreezeFrameCommand {
             return Ok(());
         };
 
-        if let Some(ref created_id) = self.created_clip_id {
-            track.clips.retain(|c| c.id != *created_id);
-        }
-
-        for record in &self.split_clips {
-            track.clips.retain(|c| c.id != record.fragment_clip_id);
-
-            if let Some(clip) = track
-                .clips
-                .iter_mut()
-                .find(|c| c.id == record.original_clip.id)
-            {
-                *clip = (*record.original_clip).clone();
-            } else {
-                insert_clip_sorted(track, (*record.original_clip).clone());
-            }
-        }
-
-        for record in &self.shifted_clips {
-            if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
-                clip.place.timeline_in_sec = record.original_timeline_in;
-            }
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.speed = previous_speed;
+            clip.reverse = previous_reverse;
+            clip.place.duration_sec = previous_duration_sec;
+            sort_track_clips(track);
         }
-        sort_track_clips(track);
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "CreateFreezeFrame"
+        "SetClipSpeed"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4023,82 +4128,47 @@ impl Command for CreateFreezeFrameCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "playheadSec": self.playhead_sec,
-            "durationSec": self.duration_sec,
+            "speed": self.speed,
+            "reverse": self.reverse,
         })
     }
 }
 
 // =============================================================================
-// SetTimeRemapCommand
+// SetClipSlowMotionInterpolationCommand
 // =============================================================================
 
-/// Command to set a time remap curve on a clip for variable-speed playback.
-///
-/// When a valid time remap curve is active, it overrides the constant `speed`
-/// field. The clip's timeline duration is recalculated from the curve.
+/// Command to update a clip's slow-motion interpolation mode.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetTimeRemapCommand {
+pub struct SetClipSlowMotionInterpolationCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub time_remap: TimeRemapCurve,
-    #[serde(skip)]
-    previous_time_remap: Option<Option<TimeRemapCurve>>,
+    pub interpolation: SlowMotionInterpolation,
     #[serde(skip)]
-    previous_duration_sec: Option<TimeSec>,
+    previous_interpolation: Option<SlowMotionInterpolation>,
 }
 
-impl SetTimeRemapCommand {
+impl SetClipSlowMotionInterpolationCommand {
     pub fn new(
         sequence_id: &str,
         track_id: &str,
         clip_id: &str,
-        time_remap: TimeRemapCurve,
+        interpolation: SlowMotionInterpolation,
     ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            time_remap,
-            previous_time_remap: None,
-            previous_duration_sec: None,
+            interpolation,
+            previous_interpolation: None,
         }
     }
 }
 
-impl Command for SetTimeRemapCommand {
+impl Command for SetClipSlowMotionInterpolationCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.time_remap.is_valid() {
-            return Err(CoreError::InvalidCommand(
-                "Time remap curve must have at least 2 keyframes".to_string(),
-            ));
-        }
-
-        // Validate all keyframe values are finite and non-negative
-        for (i, kf) in self.time_remap.keyframes.iter().enumerate() {
-            if !kf.timeline_time.is_finite() || kf.timeline_time < 0.0 {
-                return Err(CoreError::InvalidCommand(format!(
-                    "Keyframe {} has invalid timeline_time: {}",
-                    i, kf.timeline_time
-                )));
-            }
-            if !kf.source_time.is_finite() || kf.source_time < 0.0 {
-                return Err(CoreError::InvalidCommand(format!(
-                    "Keyframe {} has invalid source_time: {}",
-                    i, kf.source_time
-                )));
-            }
-        }
-
-        let new_duration = self.time_remap.timeline_duration();
-        if !new_duration.is_finite() || new_duration <= 0.0 {
-            return Err(CoreError::ValidationError(
-                "Time remap curve must produce a positive timeline duration".to_string(),
-            ));
-        }
-
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
@@ -4112,27 +4182,14 @@ impl Command for SetTimeRemapCommand {
 
         validate_track_unlocked(&sequence.tracks[track_idx])?;
 
-        let clip_idx = sequence.tracks[track_idx]
+        let clip = sequence.tracks[track_idx]
             .clips
-            .iter()
-            .position(|c| c.id == self.clip_id)
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        {
-            let clip = &sequence.tracks[track_idx].clips[clip_idx];
-            self.previous_time_remap = Some(clip.time_remap.clone());
-            self.previous_duration_sec = Some(clip.place.duration_sec);
-        }
-
-        let clip = &mut sequence.tracks[track_idx].clips[clip_idx];
-        clip.time_remap = Some(self.time_remap.clone());
-        clip.place.duration_sec = new_duration;
-
-        {
-            let clip_ref = &sequence.tracks[track_idx].clips[clip_idx];
-            let track = &sequence.tracks[track_idx];
-            validate_no_overlap(track, &clip_ref.place, Some(&clip_ref.id))?;
-        }
+        self.previous_interpolation = Some(clip.slow_motion_interpolation.clone());
+        clip.slow_motion_interpolation = self.interpolation.clone();
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -4143,9 +4200,7 @@ impl Command for SetTimeRemapCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let (Some(ref previous_time_remap), Some(previous_duration_sec)) =
-            (&self.previous_time_remap, self.previous_duration_sec)
-        else {
+        let Some(ref previous_interpolation) = self.previous_interpolation else {
             return Ok(());
         };
 
@@ -4158,16 +4213,14 @@ impl Command for SetTimeRemapCommand {
         };
 
         if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.time_remap = previous_time_remap.clone();
-            clip.place.duration_sec = previous_duration_sec;
-            sort_track_clips(track);
+            clip.slow_motion_interpolation = previous_interpolation.clone();
         }
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetTimeRemap"
+        "SetClipSlowMotionInterpolation"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4175,80 +4228,63 @@ impl Command for SetTimeRemapCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "timeRemap": self.time_remap,
+            "interpolation": self.interpolation,
         })
     }
 }
 
 // =============================================================================
-// ClearTimeRemapCommand
+// ReverseClipCommand
 // =============================================================================
 
-/// Command to remove a time remap curve from a clip.
+/// Command to toggle a clip's reverse playback state.
 ///
-/// Restores the clip to constant-speed mode using the `speed` field.
-/// The clip's duration is recalculated from source range and constant speed.
+/// When reversed, video and audio play backward. Reverse is applied in the
+/// FFmpeg render pipeline via `reverse` and `areverse` filters.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ClearTimeRemapCommand {
+pub struct ReverseClipCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
     #[serde(skip)]
-    previous_time_remap: Option<Option<TimeRemapCurve>>,
-    #[serde(skip)]
-    previous_duration_sec: Option<TimeSec>,
+    previous_reverse: Option<bool>,
 }
 
-impl ClearTimeRemapCommand {
+impl ReverseClipCommand {
     pub fn new(sequence_id: &str, track_id: &str, clip_id: &str) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            previous_time_remap: None,
-            previous_duration_sec: None,
+            previous_reverse: None,
         }
     }
 }
 
-impl Command for ClearTimeRemapCommand {
+impl Command for ReverseClipCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        let track_idx = sequence
+        let track = sequence
             .tracks
-            .iter()
-            .position(|track| track.id == self.track_id)
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        validate_track_unlocked(&sequence.tracks[track_idx])?;
+        validate_track_unlocked(track)?;
 
-        let clip_idx = sequence.tracks[track_idx]
+        let clip = track
             .clips
-            .iter()
-            .position(|c| c.id == self.clip_id)
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        let original = &sequence.tracks[track_idx].clips[clip_idx];
-        self.previous_time_remap = Some(original.time_remap.clone());
-        self.previous_duration_sec = Some(original.place.duration_sec);
-
-        let clip = &mut sequence.tracks[track_idx].clips[clip_idx];
-        clip.time_remap = None;
-        // Restore duration from source range and constant speed
-        clip.place.duration_sec = clip.range.duration() / clip.safe_speed();
-
-        {
-            let clip_ref = &sequence.tracks[track_idx].clips[clip_idx];
-            let track = &sequence.tracks[track_idx];
-            validate_no_overlap(track, &clip_ref.place, Some(&clip_ref.id))?;
-        }
-
-        sort_track_clips(&mut sequence.tracks[track_idx]);
+        self.previous_reverse = Some(clip.reverse);
+        clip.reverse = !clip.reverse;
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -4259,9 +4295,7 @@ impl Command for ClearTimeRemapCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let (Some(ref previous_time_remap), Some(previous_duration_sec)) =
-            (&self.previous_time_remap, self.previous_duration_sec)
-        else {
+        let Some(previous_reverse) = self.previous_reverse else {
             return Ok(());
         };
 
@@ -4274,16 +4308,14 @@ impl Command for ClearTimeRemapCommand {
         };
 
         if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.time_remap = previous_time_remap.clone();
-            clip.place.duration_sec = previous_duration_sec;
-            sort_track_clips(track);
+            clip.reverse = previous_reverse;
         }
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "ClearTimeRemap"
+        "ReverseClip"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4296,71 +4328,60 @@ impl Command for ClearTimeRemapCommand {
 }
 
 // =============================================================================
-// SetAudioFadeInCommand
+// SetClipEnabledCommand
 // =============================================================================
 
-/// Command to set audio fade-in duration and curve type on a clip.
+/// Command to enable or disable a clip.
+///
+/// Disabled clips are skipped during render/preview but remain on the timeline
+/// for non-destructive toggling. This is the clip-level equivalent of muting —
+/// the clip stays in place, preserving timing, but produces no output.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetAudioFadeInCommand {
+pub struct SetClipEnabledCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub duration: f64,
-    pub fade_type: FadeType,
+    pub enabled: bool,
     #[serde(skip)]
-    previous_audio: Option<AudioSettings>,
+    previous_enabled: Option<bool>,
 }
 
-impl SetAudioFadeInCommand {
-    pub fn new(
-        sequence_id: &str,
-        track_id: &str,
-        clip_id: &str,
-        duration: f64,
-        fade_type: FadeType,
-    ) -> Self {
+impl SetClipEnabledCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, enabled: bool) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            duration,
-            fade_type,
-            previous_audio: None,
+            enabled,
+            previous_enabled: None,
         }
     }
 }
 
-impl Command for SetAudioFadeInCommand {
+impl Command for SetClipEnabledCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.duration.is_finite() || self.duration < 0.0 {
-            return Err(CoreError::InvalidCommand(
-                "Fade-in duration must be a non-negative finite number".to_string(),
-            ));
-        }
-
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
         let track = sequence
-            .get_track_mut(&self.track_id)
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        let clip = track
-            .get_clip_mut(&self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_audio = Some(clip.audio.clone());
+        validate_track_unlocked(track)?;
 
-        let clip_duration = clip.duration().max(0.0);
-        clip.audio.fade_in_sec = self.duration.min(clip_duration);
-        clip.audio.fade_in_type = self.fade_type.clone();
+        let clip = track
+            .clips
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        // Ensure fades don't exceed clip duration
-        let total = clip.audio.fade_in_sec + clip.audio.fade_out_sec;
-        if total > clip_duration {
-            clip.audio.fade_out_sec = (clip_duration - clip.audio.fade_in_sec).max(0.0);
-        }
+        self.previous_enabled = Some(clip.enabled);
+        clip.enabled = self.enabled;
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -4371,25 +4392,27 @@ impl Command for SetAudioFadeInCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(previous_audio) = &self.previous_audio else {
+        let Some(previous_enabled) = self.previous_enabled else {
             return Ok(());
         };
 
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-        let Some(track) = sequence.get_track_mut(&self.track_id) else {
+
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
             return Ok(());
         };
-        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
-            clip.audio = previous_audio.clone();
+
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.enabled = previous_enabled;
         }
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetAudioFadeIn"
+        "SetClipEnabled"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4397,107 +4420,283 @@ impl Command for SetAudioFadeInCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "duration": self.duration,
-            "fadeType": self.fade_type,
+            "enabled": self.enabled,
         })
     }
 }
 
 // =============================================================================
-// SetAudioFadeOutCommand
+// CreateFreezeFrameCommand
 // =============================================================================
 
-/// Command to set audio fade-out duration and curve type on a clip.
+/// Command to create a freeze frame from a clip at the playhead position.
+///
+/// Extracts a single frame from the source clip at the given timeline position
+/// and inserts a new still clip of the specified duration. The freeze frame clip
+/// uses `freeze_frame = true` so the render pipeline loops the single frame.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetAudioFadeOutCommand {
+pub struct CreateFreezeFrameCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub duration: f64,
-    pub fade_type: FadeType,
+    pub playhead_sec: f64,
+    #[serde(default = "default_freeze_frame_duration")]
+    pub duration_sec: f64,
     #[serde(skip)]
-    previous_audio: Option<AudioSettings>,
+    created_clip_id: Option<ClipId>,
+    #[serde(skip)]
+    shifted_clips: Vec<ShiftedClipRecord>,
+    #[serde(skip)]
+    split_clips: Vec<SplitClipRecord>,
 }
 
-impl SetAudioFadeOutCommand {
+pub const DEFAULT_FREEZE_FRAME_DURATION: f64 = 2.0;
+
+fn default_freeze_frame_duration() -> f64 {
+    DEFAULT_FREEZE_FRAME_DURATION
+}
+
+impl CreateFreezeFrameCommand {
     pub fn new(
         sequence_id: &str,
         track_id: &str,
         clip_id: &str,
-        duration: f64,
-        fade_type: FadeType,
+        playhead_sec: f64,
+        duration_sec: f64,
     ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            duration,
-            fade_type,
-            previous_audio: None,
+            playhead_sec,
+            duration_sec,
+            created_clip_id: None,
+            shifted_clips: Vec::new(),
+            split_clips: Vec::new(),
         }
     }
 }
 
-impl Command for SetAudioFadeOutCommand {
+impl Command for CreateFreezeFrameCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.duration.is_finite() || self.duration < 0.0 {
+        if !self.playhead_sec.is_finite() || self.playhead_sec < 0.0 {
             return Err(CoreError::InvalidCommand(
-                "Fade-out duration must be a non-negative finite number".to_string(),
+                "playhead_sec must be a finite non-negative number".to_string(),
+            ));
+        }
+        if !self.duration_sec.is_finite() || self.duration_sec <= 0.0 {
+            return Err(CoreError::InvalidCommand(
+                "duration_sec must be a finite number > 0".to_string(),
             ));
         }
 
-        let sequence = state
-            .sequences
-            .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-        let track = sequence
-            .get_track_mut(&self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        let clip = track
-            .get_clip_mut(&self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
-
-        self.previous_audio = Some(clip.audio.clone());
+        let (source_clip_template, freeze_range, shift_records, split_candidates) = {
+            let sequence = state
+                .sequences
+                .get(&self.sequence_id)
+                .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        let clip_duration = clip.duration().max(0.0);
-        clip.audio.fade_out_sec = self.duration.min(clip_duration);
-        clip.audio.fade_out_type = self.fade_type.clone();
+            let track = sequence
+                .tracks
+                .iter()
+                .find(|t| t.id == self.track_id)
+                .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        // Ensure fades don't exceed clip duration
-        let total = clip.audio.fade_in_sec + clip.audio.fade_out_sec;
-        if total > clip_duration {
-            clip.audio.fade_in_sec = (clip_duration - clip.audio.fade_out_sec).max(0.0);
-        }
+            validate_track_unlocked(track)?;
+
+            let source_clip = track
+                .clips
+                .iter()
+                .find(|c| c.id == self.clip_id)
+                .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+
+            let clip_start = source_clip.place.timeline_in_sec;
+            let clip_end = source_clip.place.timeline_out_sec();
+            if self.playhead_sec < clip_start || self.playhead_sec > clip_end {
+                return Err(CoreError::ValidationError(format!(
+                    "Playhead ({:.3}s) is outside clip range [{:.3}s, {:.3}s]",
+                    self.playhead_sec, clip_start, clip_end
+                )));
+            }
+
+            let fps = sequence.format.fps.as_f64();
+            let frame_window = if fps > 0.0 { 1.0 / fps } else { 1.0 / 25.0 };
+            let freeze_range =
+                freeze_frame_source_range(source_clip, self.playhead_sec, frame_window);
+            let mut shift_records = Vec::new();
+            let mut split_candidates = Vec::new();
+
+            for clip in &track.clips {
+                let clip_start = clip.place.timeline_in_sec;
+                let clip_end = clip.place.timeline_out_sec();
+
+                if clip_start < self.playhead_sec && clip_end > self.playhead_sec {
+                    split_candidates.push(clip.clone());
+                } else if clip_start >= self.playhead_sec {
+                    shift_records.push(ShiftedClipRecord {
+                        track_id: self.track_id.clone(),
+                        clip_id: clip.id.clone(),
+                        original_timeline_in: clip_start,
+                    });
+                }
+            }
+
+            (
+                source_clip.clone(),
+                freeze_range,
+                shift_records,
+                split_candidates,
+            )
+        };
+
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let track = sequence
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+
+        let mut split_records = Vec::new();
+
+        for original_clip in split_candidates {
+            let relative_split = self.playhead_sec - original_clip.place.timeline_in_sec;
+            let right_duration = original_clip.place.timeline_out_sec() - self.playhead_sec;
+            if !relative_split.is_finite()
+                || !right_duration.is_finite()
+                || relative_split <= 0.0
+                || right_duration <= 0.0
+            {
+                return Err(CoreError::ValidationError(
+                    "Freeze frame split produced an invalid clip duration".to_string(),
+                ));
+            }
+
+            let ((first_source_in, first_source_out), (second_source_in, second_source_out)) =
+                split_clip_ranges_at(&original_clip, self.playhead_sec);
+            let fragment = clone_clip_fragment_with_rebased_time_remap(
+                &original_clip,
+                second_source_in,
+                second_source_out,
+                self.playhead_sec + self.duration_sec,
+                right_duration,
+                relative_split,
+            );
+            let fragment_id = fragment.id.clone();
+
+            let clip = track
+                .clips
+                .iter_mut()
+                .find(|c| c.id == original_clip.id)
+                .ok_or_else(|| CoreError::ClipNotFound(original_clip.id.clone()))?;
+            clip.range.source_in_sec = first_source_in;
+            clip.range.source_out_sec = first_source_out;
+            clip.place.duration_sec = relative_split;
+            rebase_clip_time_remap_for_fragment(clip, 0.0, relative_split);
+            insert_clip_sorted(track, fragment);
+
+            split_records.push(SplitClipRecord {
+                track_id: self.track_id.clone(),
+                original_clip: Box::new(original_clip),
+                fragment_clip_id: fragment_id,
+            });
+        }
+
+        for record in &shift_records {
+            if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
+                clip.place.timeline_in_sec += self.duration_sec;
+            }
+        }
+        sort_track_clips(track);
+
+        let mut freeze_clip = source_clip_template;
+        freeze_clip.id = ulid::Ulid::new().to_string();
+        freeze_clip.range = freeze_range;
+        freeze_clip.place = ClipPlace {
+            timeline_in_sec: self.playhead_sec,
+            duration_sec: self.duration_sec,
+        };
+        freeze_clip.freeze_frame = true;
+        freeze_clip.speed = 1.0;
+        freeze_clip.reverse = false;
+        freeze_clip.time_remap = None;
+        freeze_clip.audio.muted = true;
+
+        let clip_id = freeze_clip.id.clone();
+        insert_clip_sorted(track, freeze_clip);
+
+        self.created_clip_id = Some(clip_id.clone());
+        self.shifted_clips = shift_records;
+        self.split_clips = split_records;
 
         let op_id = ulid::Ulid::new().to_string();
-        Ok(
-            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
-                clip_id: self.clip_id.clone(),
-            }),
-        )
+        let mut result = CommandResult::new(&op_id)
+            .with_change(StateChange::ClipCreated {
+                clip_id: clip_id.clone(),
+            })
+            .with_created_id(&clip_id);
+
+        for record in &self.shifted_clips {
+            result = result.with_change(StateChange::ClipModified {
+                clip_id: record.clip_id.clone(),
+            });
+        }
+
+        for record in &self.split_clips {
+            result = result.with_change(StateChange::ClipModified {
+                clip_id: record.original_clip.id.clone(),
+            });
+            result = result.with_change(StateChange::ClipCreated {
+                clip_id: record.fragment_clip_id.clone(),
+            });
+        }
+
+        Ok(result)
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(previous_audio) = &self.previous_audio else {
-            return Ok(());
-        };
-
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-        let Some(track) = sequence.get_track_mut(&self.track_id) else {
+
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
             return Ok(());
         };
-        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
-            clip.audio = previous_audio.clone();
+
+        if let Some(ref created_id) = self.created_clip_id {
+            track.clips.retain(|c| c.id != *created_id);
+        }
+
+        for record in &self.split_clips {
+            track.clips.retain(|c| c.id != record.fragment_clip_id);
+
+            if let Some(clip) = track
+                .clips
+                .iter_mut()
+                .find(|c| c.id == record.original_clip.id)
+            {
+                *clip = (*record.original_clip).clone();
+            } else {
+                insert_clip_sorted(track, (*record.original_clip).clone());
+            }
+        }
+
+        for record in &self.shifted_clips {
+            if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
+                clip.place.timeline_in_sec = record.original_timeline_in;
+            }
         }
+        sort_track_clips(track);
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetAudioFadeOut"
+        "CreateFreezeFrame"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4505,109 +4704,116 @@ impl Command for SetAudioFadeOutCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "duration": self.duration,
-            "fadeType": self.fade_type,
+            "playheadSec": self.playhead_sec,
+            "durationSec": self.duration_sec,
         })
     }
 }
 
 // =============================================================================
-// AddAudioKeyframeCommand
+// SetTimeRemapCommand
 // =============================================================================
 
-/// Command to add a volume automation keyframe to a clip's audio settings.
+/// Command to set a time remap curve on a clip for variable-speed playback.
+///
+/// When a valid time remap curve is active, it overrides the constant `speed`
+/// field. The clip's timeline duration is recalculated from the curve.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct AddAudioKeyframeCommand {
+pub struct SetTimeRemapCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    /// Time offset from clip start in seconds
-    pub time_offset: f64,
-    /// Volume value in dB
-    pub value_db: f64,
-    /// Interpolation method to the next keyframe
-    #[serde(default)]
-    pub interpolation: KeyframeInterpolation,
-    /// Index at which the keyframe was inserted (for undo)
+    pub time_remap: TimeRemapCurve,
     #[serde(skip)]
-    inserted_index: Option<usize>,
-    /// Actual clamped value stored for precise undo identification
+    previous_time_remap: Option<Option<TimeRemapCurve>>,
     #[serde(skip)]
-    clamped_value_db: Option<f64>,
+    previous_duration_sec: Option<TimeSec>,
 }
 
-impl AddAudioKeyframeCommand {
+impl SetTimeRemapCommand {
     pub fn new(
         sequence_id: &str,
         track_id: &str,
         clip_id: &str,
-        time_offset: f64,
-        value_db: f64,
-        interpolation: KeyframeInterpolation,
+        time_remap: TimeRemapCurve,
     ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            time_offset,
-            value_db,
-            interpolation,
-            inserted_index: None,
-            clamped_value_db: None,
+            time_remap,
+            previous_time_remap: None,
+            previous_duration_sec: None,
         }
     }
 }
 
-impl Command for AddAudioKeyframeCommand {
+impl Command for SetTimeRemapCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.time_offset.is_finite() || self.time_offset < 0.0 {
+        if !self.time_remap.is_valid() {
             return Err(CoreError::InvalidCommand(
-                "timeOffset must be a finite, non-negative number".to_string(),
+                "Time remap curve must have at least 2 keyframes".to_string(),
             ));
         }
-        if !self.value_db.is_finite() {
-            return Err(CoreError::InvalidCommand(
-                "valueDb must be a finite number".to_string(),
-            ));
+
+        // Validate all keyframe values are finite and non-negative
+        for (i, kf) in self.time_remap.keyframes.iter().enumerate() {
+            if !kf.timeline_time.is_finite() || kf.timeline_time < 0.0 {
+                return Err(CoreError::InvalidCommand(format!(
+                    "Keyframe {} has invalid timeline_time: {}",
+                    i, kf.timeline_time
+                )));
+            }
+            if !kf.source_time.is_finite() || kf.source_time < 0.0 {
+                return Err(CoreError::InvalidCommand(format!(
+                    "Keyframe {} has invalid source_time: {}",
+                    i, kf.source_time
+                )));
+            }
+        }
+
+        let new_duration = self.time_remap.timeline_duration();
+        if !new_duration.is_finite() || new_duration <= 0.0 {
+            return Err(CoreError::ValidationError(
+                "Time remap curve must produce a positive timeline duration".to_string(),
+            ));
         }
 
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-        let track = sequence
-            .get_track_mut(&self.track_id)
+
+        let track_idx = sequence
+            .tracks
+            .iter()
+            .position(|track| track.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        validate_track_unlocked(track)?;
-        let clip = track
-            .get_clip_mut(&self.clip_id)
+
+        validate_track_unlocked(&sequence.tracks[track_idx])?;
+
+        let clip_idx = sequence.tracks[track_idx]
+            .clips
+            .iter()
+            .position(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        // Validate time_offset is within clip duration
-        let clip_duration = clip.duration();
-        if self.time_offset > clip_duration {
-            return Err(CoreError::InvalidCommand(format!(
-                "timeOffset ({:.3}s) exceeds clip duration ({:.3}s)",
-                self.time_offset, clip_duration
-            )));
+        {
+            let clip = &sequence.tracks[track_idx].clips[clip_idx];
+            self.previous_time_remap = Some(clip.time_remap.clone());
+            self.previous_duration_sec = Some(clip.place.duration_sec);
         }
 
-        let clamped_db = self
-            .value_db
-            .clamp(MIN_CLIP_VOLUME_DB as f64, MAX_CLIP_VOLUME_DB as f64);
-        let keyframe = AudioKeyframe::new(self.time_offset, clamped_db, self.interpolation.clone());
-        self.clamped_value_db = Some(clamped_db);
-
-        clip.audio.volume_keyframes.push(keyframe);
-        AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
+        let clip = &mut sequence.tracks[track_idx].clips[clip_idx];
+        clip.time_remap = Some(self.time_remap.clone());
+        clip.place.duration_sec = new_duration;
 
-        // Find the inserted index for undo — match on both time AND value
-        // to avoid ambiguity when multiple keyframes share the same time.
-        self.inserted_index = clip.audio.volume_keyframes.iter().position(|kf| {
-            (kf.time_offset - self.time_offset).abs() < 1e-9
-                && (kf.value_db - clamped_db).abs() < 1e-9
-        });
+        {
+            let clip_ref = &sequence.tracks[track_idx].clips[clip_idx];
+            let track = &sequence.tracks[track_idx];
+            validate_no_overlap(track, &clip_ref.place, Some(&clip_ref.id))?;
+        }
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -4618,26 +4824,31 @@ impl Command for AddAudioKeyframeCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(index) = self.inserted_index else {
+        let (Some(ref previous_time_remap), Some(previous_duration_sec)) =
+            (&self.previous_time_remap, self.previous_duration_sec)
+        else {
             return Ok(());
         };
 
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-        let Some(track) = sequence.get_track_mut(&self.track_id) else {
+
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
             return Ok(());
         };
-        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
-            if index < clip.audio.volume_keyframes.len() {
-                clip.audio.volume_keyframes.remove(index);
-            }
+
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.time_remap = previous_time_remap.clone();
+            clip.place.duration_sec = previous_duration_sec;
+            sort_track_clips(track);
         }
+
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "AddAudioKeyframe"
+        "SetTimeRemap"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4645,66 +4856,80 @@ impl Command for AddAudioKeyframeCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "timeOffset": self.time_offset,
-            "valueDb": self.value_db,
-            "interpolation": self.interpolation,
+            "timeRemap": self.time_remap,
         })
     }
 }
 
 // =============================================================================
-// RemoveAudioKeyframeCommand
+// ClearTimeRemapCommand
 // =============================================================================
 
-/// Command to remove a volume automation keyframe by index.
+/// Command to remove a time remap curve from a clip.
+///
+/// Restores the clip to constant-speed mode using the `speed` field.
+/// The clip's duration is recalculated from source range and constant speed.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct RemoveAudioKeyframeCommand {
+pub struct ClearTimeRemapCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    /// Index of the keyframe to remove (sorted order)
-    pub keyframe_index: usize,
-    /// Removed keyframe stored for undo
     #[serde(skip)]
-    removed_keyframe: Option<AudioKeyframe>,
+    previous_time_remap: Option<Option<TimeRemapCurve>>,
+    #[serde(skip)]
+    previous_duration_sec: Option<TimeSec>,
 }
 
-impl RemoveAudioKeyframeCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, keyframe_index: usize) -> Self {
+impl ClearTimeRemapCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            keyframe_index,
-            removed_keyframe: None,
+            previous_time_remap: None,
+            previous_duration_sec: None,
         }
     }
 }
 
-impl Command for RemoveAudioKeyframeCommand {
+impl Command for ClearTimeRemapCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-        let track = sequence
-            .get_track_mut(&self.track_id)
+
+        let track_idx = sequence
+            .tracks
+            .iter()
+            .position(|track| track.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        validate_track_unlocked(track)?;
-        let clip = track
-            .get_clip_mut(&self.clip_id)
+
+        validate_track_unlocked(&sequence.tracks[track_idx])?;
+
+        let clip_idx = sequence.tracks[track_idx]
+            .clips
+            .iter()
+            .position(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        if self.keyframe_index >= clip.audio.volume_keyframes.len() {
-            return Err(CoreError::InvalidCommand(format!(
-                "keyframeIndex {} out of bounds (clip has {} keyframes)",
-                self.keyframe_index,
-                clip.audio.volume_keyframes.len()
-            )));
+        let original = &sequence.tracks[track_idx].clips[clip_idx];
+        self.previous_time_remap = Some(original.time_remap.clone());
+        self.previous_duration_sec = Some(original.place.duration_sec);
+
+        let clip = &mut sequence.tracks[track_idx].clips[clip_idx];
+        clip.time_remap = None;
+        // Restore duration from source range and constant speed
+        clip.place.duration_sec = clip.range.duration() / clip.safe_speed();
+
+        {
+            let clip_ref = &sequence.tracks[track_idx].clips[clip_idx];
+            let track = &sequence.tracks[track_idx];
+            validate_no_overlap(track, &clip_ref.place, Some(&clip_ref.id))?;
         }
 
-        self.removed_keyframe = Some(clip.audio.volume_keyframes.remove(self.keyframe_index));
+        sort_track_clips(&mut sequence.tracks[track_idx]);
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -4715,27 +4940,31 @@ impl Command for RemoveAudioKeyframeCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(ref removed) = self.removed_keyframe else {
+        let (Some(ref previous_time_remap), Some(previous_duration_sec)) =
+            (&self.previous_time_remap, self.previous_duration_sec)
+        else {
             return Ok(());
         };
 
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-        let Some(track) = sequence.get_track_mut(&self.track_id) else {
+
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
             return Ok(());
         };
-        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
-            let index = self.keyframe_index.min(clip.audio.volume_keyframes.len());
-            clip.audio.volume_keyframes.insert(index, removed.clone());
-            // Re-sort to guarantee time ordering after restore
-            AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
+
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.time_remap = previous_time_remap.clone();
+            clip.place.duration_sec = previous_duration_sec;
+            sort_track_clips(track);
         }
+
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "RemoveAudioKeyframe"
+        "ClearTimeRemap"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4743,63 +4972,51 @@ impl Command for RemoveAudioKeyframeCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "keyframeIndex": self.keyframe_index,
         })
     }
 }
 
 // =============================================================================
-// MoveAudioKeyframeCommand
+// SetAudioFadeInCommand
 // =============================================================================
 
-/// Command to move a volume automation keyframe to a new time offset.
+/// Command to set audio fade-in duration and curve type on a clip.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct MoveAudioKeyframeCommand {
+pub struct SetAudioFadeInCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    /// Index of the keyframe to move (sorted order, pre-move)
-    pub keyframe_index: usize,
-    /// New time offset from clip start in seconds
-    pub new_time_offset: f64,
-    /// Previous time offset stored for undo
-    #[serde(skip)]
-    previous_time_offset: Option<f64>,
-    /// Index of the keyframe after move + re-sort (for reliable undo)
-    #[serde(skip)]
-    moved_to_index: Option<usize>,
-    /// Value stored for disambiguation when multiple keyframes share the same time
+    pub duration: f64,
+    pub fade_type: FadeType,
     #[serde(skip)]
-    keyframe_value_db: Option<f64>,
+    previous_audio: Option<AudioSettings>,
 }
 
-impl MoveAudioKeyframeCommand {
+impl SetAudioFadeInCommand {
     pub fn new(
         sequence_id: &str,
         track_id: &str,
         clip_id: &str,
-        keyframe_index: usize,
-        new_time_offset: f64,
+        duration: f64,
+        fade_type: FadeType,
     ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            keyframe_index,
-            new_time_offset,
-            previous_time_offset: None,
-            moved_to_index: None,
-            keyframe_value_db: None,
+            duration,
+            fade_type,
+            previous_audio: None,
         }
     }
 }
 
-impl Command for MoveAudioKeyframeCommand {
+impl Command for SetAudioFadeInCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.new_time_offset.is_finite() || self.new_time_offset < 0.0 {
+        if !self.duration.is_finite() || self.duration < 0.0 {
             return Err(CoreError::InvalidCommand(
-                "newTimeOffset must be a finite, non-negative number".to_string(),
+                "Fade-in duration must be a non-negative finite number".to_string(),
             ));
         }
 
@@ -4810,42 +5027,21 @@ impl Command for MoveAudioKeyframeCommand {
         let track = sequence
             .get_track_mut(&self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        validate_track_unlocked(track)?;
         let clip = track
             .get_clip_mut(&self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        if self.keyframe_index >= clip.audio.volume_keyframes.len() {
-            return Err(CoreError::InvalidCommand(format!(
-                "keyframeIndex {} out of bounds (clip has {} keyframes)",
-                self.keyframe_index,
-                clip.audio.volume_keyframes.len()
-            )));
-        }
-
-        let clip_duration = clip.duration();
-        if self.new_time_offset > clip_duration {
-            return Err(CoreError::InvalidCommand(format!(
-                "newTimeOffset ({:.3}s) exceeds clip duration ({:.3}s)",
-                self.new_time_offset, clip_duration
-            )));
-        }
-
-        let kf = &clip.audio.volume_keyframes[self.keyframe_index];
-        self.previous_time_offset = Some(kf.time_offset);
-        self.keyframe_value_db = Some(kf.value_db);
-        clip.audio.volume_keyframes[self.keyframe_index].time_offset = self.new_time_offset;
+        self.previous_audio = Some(clip.audio.clone());
 
-        // Re-sort after move
-        AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
+        let clip_duration = clip.duration().max(0.0);
+        clip.audio.fade_in_sec = self.duration.min(clip_duration);
+        clip.audio.fade_in_type = self.fade_type.clone();
 
-        // Store post-move index for reliable undo (match on time + value)
-        self.moved_to_index = clip.audio.volume_keyframes.iter().position(|kf| {
-            (kf.time_offset - self.new_time_offset).abs() < 1e-9
-                && self
-                    .keyframe_value_db
-                    .is_none_or(|v| (kf.value_db - v).abs() < 1e-9)
-        });
+        // Ensure fades don't exceed clip duration
+        let total = clip.audio.fade_in_sec + clip.audio.fade_out_sec;
+        if total > clip_duration {
+            clip.audio.fade_out_sec = (clip_duration - clip.audio.fade_in_sec).max(0.0);
+        }
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -4856,7 +5052,7 @@ impl Command for MoveAudioKeyframeCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(prev_offset) = self.previous_time_offset else {
+        let Some(previous_audio) = &self.previous_audio else {
             return Ok(());
         };
 
@@ -4867,29 +5063,14 @@ impl Command for MoveAudioKeyframeCommand {
             return Ok(());
         };
         if let Some(clip) = track.get_clip_mut(&self.clip_id) {
-            // Use the stored post-move index for reliable undo
-            let target_idx = self.moved_to_index.or_else(|| {
-                // Fallback: search by time + value
-                clip.audio.volume_keyframes.iter().position(|kf| {
-                    (kf.time_offset - self.new_time_offset).abs() < 1e-9
-                        && self
-                            .keyframe_value_db
-                            .is_none_or(|v| (kf.value_db - v).abs() < 1e-9)
-                })
-            });
-
-            if let Some(idx) = target_idx {
-                if idx < clip.audio.volume_keyframes.len() {
-                    clip.audio.volume_keyframes[idx].time_offset = prev_offset;
-                    AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
-                }
-            }
+            clip.audio = previous_audio.clone();
         }
+
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "MoveAudioKeyframe"
+        "SetAudioFadeIn"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -4897,63 +5078,53 @@ impl Command for MoveAudioKeyframeCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "keyframeIndex": self.keyframe_index,
-            "newTimeOffset": self.new_time_offset,
+            "duration": self.duration,
+            "fadeType": self.fade_type,
         })
     }
 }
 
 // =============================================================================
-// SetAudioKeyframeValueCommand
+// SetAudioFadeOutCommand
 // =============================================================================
 
-/// Command to update the volume value of an existing audio keyframe.
+/// Command to set audio fade-out duration and curve type on a clip.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetAudioKeyframeValueCommand {
+pub struct SetAudioFadeOutCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    /// Index of the keyframe to update (sorted order)
-    pub keyframe_index: usize,
-    /// New volume value in dB
-    pub value_db: f64,
-    /// Optional new interpolation method
-    pub interpolation: Option<KeyframeInterpolation>,
-    /// Previous value stored for undo
-    #[serde(skip)]
-    previous_value_db: Option<f64>,
+    pub duration: f64,
+    pub fade_type: FadeType,
     #[serde(skip)]
-    previous_interpolation: Option<KeyframeInterpolation>,
+    previous_audio: Option<AudioSettings>,
 }
 
-impl SetAudioKeyframeValueCommand {
+impl SetAudioFadeOutCommand {
     pub fn new(
         sequence_id: &str,
         track_id: &str,
         clip_id: &str,
-        keyframe_index: usize,
-        value_db: f64,
-        interpolation: Option<KeyframeInterpolation>,
+        duration: f64,
+        fade_type: FadeType,
     ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            keyframe_index,
-            value_db,
-            interpolation,
-            previous_value_db: None,
-            previous_interpolation: None,
+            duration,
+            fade_type,
+            previous_audio: None,
         }
     }
 }
 
-impl Command for SetAudioKeyframeValueCommand {
+impl Command for SetAudioFadeOutCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !self.value_db.is_finite() {
+        if !self.duration.is_finite() || self.duration < 0.0 {
             return Err(CoreError::InvalidCommand(
-                "valueDb must be a finite number".to_string(),
+                "Fade-out duration must be a non-negative finite number".to_string(),
             ));
         }
 
@@ -4964,29 +5135,20 @@ impl Command for SetAudioKeyframeValueCommand {
         let track = sequence
             .get_track_mut(&self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        validate_track_unlocked(track)?;
         let clip = track
             .get_clip_mut(&self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        if self.keyframe_index >= clip.audio.volume_keyframes.len() {
-            return Err(CoreError::InvalidCommand(format!(
-                "keyframeIndex {} out of bounds (clip has {} keyframes)",
-                self.keyframe_index,
-                clip.audio.volume_keyframes.len()
-            )));
-        }
-
-        let kf = &mut clip.audio.volume_keyframes[self.keyframe_index];
-        self.previous_value_db = Some(kf.value_db);
-        self.previous_interpolation = Some(kf.interpolation.clone());
+        self.previous_audio = Some(clip.audio.clone());
 
-        kf.value_db = self
-            .value_db
-            .clamp(MIN_CLIP_VOLUME_DB as f64, MAX_CLIP_VOLUME_DB as f64);
+        let clip_duration = clip.duration().max(0.0);
+        clip.audio.fade_out_sec = self.duration.min(clip_duration);
+        clip.audio.fade_out_type = self.fade_type.clone();
 
-        if let Some(ref interp) = self.interpolation {
-            kf.interpolation = interp.clone();
+        // Ensure fades don't exceed clip duration
+        let total = clip.audio.fade_in_sec + clip.audio.fade_out_sec;
+        if total > clip_duration {
+            clip.audio.fade_in_sec = (clip_duration - clip.audio.fade_out_sec).max(0.0);
         }
 
         let op_id = ulid::Ulid::new().to_string();
@@ -4998,9 +5160,7 @@ impl Command for SetAudioKeyframeValueCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let (Some(prev_value), Some(ref prev_interp)) =
-            (self.previous_value_db, &self.previous_interpolation)
-        else {
+        let Some(previous_audio) = &self.previous_audio else {
             return Ok(());
         };
 
@@ -5011,16 +5171,14 @@ impl Command for SetAudioKeyframeValueCommand {
             return Ok(());
         };
         if let Some(clip) = track.get_clip_mut(&self.clip_id) {
-            if let Some(kf) = clip.audio.volume_keyframes.get_mut(self.keyframe_index) {
-                kf.value_db = prev_value;
-                kf.interpolation = prev_interp.clone();
-            }
+            clip.audio = previous_audio.clone();
         }
+
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetAudioKeyframeValue"
+        "SetAudioFadeOut"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -5028,87 +5186,109 @@ impl Command for SetAudioKeyframeValueCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "keyframeIndex": self.keyframe_index,
-            "valueDb": self.value_db,
-            "interpolation": self.interpolation,
+            "duration": self.duration,
+            "fadeType": self.fade_type,
         })
     }
 }
 
 // =============================================================================
-// SetClipTransformCommand
+// AddAudioKeyframeCommand
 // =============================================================================
 
-/// Command to update a clip's transform (position/scale/rotation/anchor).
+/// Command to add a volume automation keyframe to a clip's audio settings.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipTransformCommand {
+pub struct AddAudioKeyframeCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub transform: Transform,
+    /// Time offset from clip start in seconds
+    pub time_offset: f64,
+    /// Volume value in dB
+    pub value_db: f64,
+    /// Interpolation method to the next keyframe
+    #[serde(default)]
+    pub interpolation: KeyframeInterpolation,
+    /// Index at which the keyframe was inserted (for undo)
     #[serde(skip)]
-    previous_transform: Option<Transform>,
+    inserted_index: Option<usize>,
+    /// Actual clamped value stored for precise undo identification
+    #[serde(skip)]
+    clamped_value_db: Option<f64>,
 }
 
-impl SetClipTransformCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, transform: Transform) -> Self {
+impl AddAudioKeyframeCommand {
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        clip_id: &str,
+        time_offset: f64,
+        value_db: f64,
+        interpolation: KeyframeInterpolation,
+    ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            transform,
-            previous_transform: None,
+            time_offset,
+            value_db,
+            interpolation,
+            inserted_index: None,
+            clamped_value_db: None,
         }
     }
+}
 
-    fn sanitize_transform(mut transform: Transform) -> Transform {
-        // Position/anchor are normalized.
-        transform.position.x = transform.position.x.clamp(0.0, 1.0);
-        transform.position.y = transform.position.y.clamp(0.0, 1.0);
-        transform.anchor.x = transform.anchor.x.clamp(0.0, 1.0);
-        transform.anchor.y = transform.anchor.y.clamp(0.0, 1.0);
-
-        // Scale must be finite and non-zero-ish. Clamp to a reasonable range.
-        if !transform.scale.x.is_finite() {
-            transform.scale.x = 1.0;
-        }
-        if !transform.scale.y.is_finite() {
-            transform.scale.y = 1.0;
+impl Command for AddAudioKeyframeCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        if !self.time_offset.is_finite() || self.time_offset < 0.0 {
+            return Err(CoreError::InvalidCommand(
+                "timeOffset must be a finite, non-negative number".to_string(),
+            ));
         }
-        transform.scale.x = transform.scale.x.clamp(0.01, 100.0);
-        transform.scale.y = transform.scale.y.clamp(0.01, 100.0);
-
-        // Rotation must be finite.
-        if !transform.rotation_deg.is_finite() {
-            transform.rotation_deg = 0.0;
+        if !self.value_db.is_finite() {
+            return Err(CoreError::InvalidCommand(
+                "valueDb must be a finite number".to_string(),
+            ));
         }
 
-        transform
-    }
-}
-
-impl Command for SetClipTransformCommand {
-    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
         let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
+            .get_track_mut(&self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
+        validate_track_unlocked(track)?;
         let clip = track
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
+            .get_clip_mut(&self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_transform = Some(clip.transform.clone());
-        clip.transform = Self::sanitize_transform(self.transform.clone());
+        // Validate time_offset is within clip duration
+        let clip_duration = clip.duration();
+        if self.time_offset > clip_duration {
+            return Err(CoreError::InvalidCommand(format!(
+                "timeOffset ({:.3}s) exceeds clip duration ({:.3}s)",
+                self.time_offset, clip_duration
+            )));
+        }
+
+        let clamped_db = self
+            .value_db
+            .clamp(MIN_CLIP_VOLUME_DB as f64, MAX_CLIP_VOLUME_DB as f64);
+        let keyframe = AudioKeyframe::new(self.time_offset, clamped_db, self.interpolation.clone());
+        self.clamped_value_db = Some(clamped_db);
+
+        clip.audio.volume_keyframes.push(keyframe);
+        AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
+
+        // Find the inserted index for undo — match on both time AND value
+        // to avoid ambiguity when multiple keyframes share the same time.
+        self.inserted_index = clip.audio.volume_keyframes.iter().position(|kf| {
+            (kf.time_offset - self.time_offset).abs() < 1e-9
+                && (kf.value_db - clamped_db).abs() < 1e-9
+        });
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -5119,27 +5299,26 @@ impl Command for SetClipTransformCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(prev) = &self.previous_transform else {
+        let Some(index) = self.inserted_index else {
             return Ok(());
         };
 
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+        let Some(track) = sequence.get_track_mut(&self.track_id) else {
             return Ok(());
         };
-
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.transform = prev.clone();
+        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
+            if index < clip.audio.volume_keyframes.len() {
+                clip.audio.volume_keyframes.remove(index);
+            }
         }
-
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipTransform"
+        "AddAudioKeyframe"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -5147,77 +5326,66 @@ impl Command for SetClipTransformCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "transform": self.transform,
+            "timeOffset": self.time_offset,
+            "valueDb": self.value_db,
+            "interpolation": self.interpolation,
         })
     }
 }
 
 // =============================================================================
-// SetClipMotionKeyframesCommand
+// RemoveAudioKeyframeCommand
 // =============================================================================
 
-/// Command to replace a clip's transform animation keyframes.
+/// Command to remove a volume automation keyframe by index.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipMotionKeyframesCommand {
+pub struct RemoveAudioKeyframeCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub keyframes: Vec<TransformKeyframe>,
+    /// Index of the keyframe to remove (sorted order)
+    pub keyframe_index: usize,
+    /// Removed keyframe stored for undo
     #[serde(skip)]
-    previous_keyframes: Option<Vec<TransformKeyframe>>,
+    removed_keyframe: Option<AudioKeyframe>,
 }
 
-impl SetClipMotionKeyframesCommand {
-    pub fn new(
-        sequence_id: &str,
-        track_id: &str,
-        clip_id: &str,
-        keyframes: Vec<TransformKeyframe>,
-    ) -> Self {
+impl RemoveAudioKeyframeCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, keyframe_index: usize) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            keyframes,
-            previous_keyframes: None,
+            keyframe_index,
+            removed_keyframe: None,
         }
     }
+}
 
-    fn sanitize_keyframes(mut keyframes: Vec<TransformKeyframe>) -> Vec<TransformKeyframe> {
-        keyframes
-            .retain(|keyframe| keyframe.time_offset.is_finite() && keyframe.time_offset >= 0.0);
-        for keyframe in &mut keyframes {
-            keyframe.time_offset = keyframe.time_offset.max(0.0);
-            keyframe.transform =
-                SetClipTransformCommand::sanitize_transform(keyframe.transform.clone());
-        }
-        keyframes.sort_by(|a, b| a.time_offset.total_cmp(&b.time_offset));
-        keyframes
-    }
-}
-
-impl Command for SetClipMotionKeyframesCommand {
+impl Command for RemoveAudioKeyframeCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
         let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
+            .get_track_mut(&self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
+        validate_track_unlocked(track)?;
         let clip = track
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
+            .get_clip_mut(&self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_keyframes = Some(clip.motion_keyframes.clone());
-        clip.motion_keyframes = Self::sanitize_keyframes(self.keyframes.clone());
+        if self.keyframe_index >= clip.audio.volume_keyframes.len() {
+            return Err(CoreError::InvalidCommand(format!(
+                "keyframeIndex {} out of bounds (clip has {} keyframes)",
+                self.keyframe_index,
+                clip.audio.volume_keyframes.len()
+            )));
+        }
+
+        self.removed_keyframe = Some(clip.audio.volume_keyframes.remove(self.keyframe_index));
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -5228,27 +5396,27 @@ impl Command for SetClipMotionKeyframesCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(prev) = &self.previous_keyframes else {
+        let Some(ref removed) = self.removed_keyframe else {
             return Ok(());
         };
 
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+        let Some(track) = sequence.get_track_mut(&self.track_id) else {
             return Ok(());
         };
-
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.motion_keyframes = prev.clone();
+        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
+            let index = self.keyframe_index.min(clip.audio.volume_keyframes.len());
+            clip.audio.volume_keyframes.insert(index, removed.clone());
+            // Re-sort to guarantee time ordering after restore
+            AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
         }
-
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipMotionKeyframes"
+        "RemoveAudioKeyframe"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -5256,68 +5424,109 @@ impl Command for SetClipMotionKeyframesCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "keyframes": self.keyframes,
+            "keyframeIndex": self.keyframe_index,
         })
     }
 }
 
 // =============================================================================
-// SetClipOpacityCommand
+// MoveAudioKeyframeCommand
 // =============================================================================
 
-/// Command to set a clip's opacity.
+/// Command to move a volume automation keyframe to a new time offset.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipOpacityCommand {
+pub struct MoveAudioKeyframeCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub opacity: f32,
+    /// Index of the keyframe to move (sorted order, pre-move)
+    pub keyframe_index: usize,
+    /// New time offset from clip start in seconds
+    pub new_time_offset: f64,
+    /// Previous time offset stored for undo
     #[serde(skip)]
-    previous_opacity: Option<f32>,
+    previous_time_offset: Option<f64>,
+    /// Index of the keyframe after move + re-sort (for reliable undo)
+    #[serde(skip)]
+    moved_to_index: Option<usize>,
+    /// Value stored for disambiguation when multiple keyframes share the same time
+    #[serde(skip)]
+    keyframe_value_db: Option<f64>,
 }
 
-impl SetClipOpacityCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, opacity: f32) -> Self {
+impl MoveAudioKeyframeCommand {
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        clip_id: &str,
+        keyframe_index: usize,
+        new_time_offset: f64,
+    ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            opacity,
-            previous_opacity: None,
-        }
-    }
-
-    fn sanitize_opacity(opacity: f32) -> f32 {
-        if opacity.is_finite() {
-            opacity.clamp(0.0, 1.0)
-        } else {
-            1.0
+            keyframe_index,
+            new_time_offset,
+            previous_time_offset: None,
+            moved_to_index: None,
+            keyframe_value_db: None,
         }
     }
 }
 
-impl Command for SetClipOpacityCommand {
+impl Command for MoveAudioKeyframeCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        if !self.new_time_offset.is_finite() || self.new_time_offset < 0.0 {
+            return Err(CoreError::InvalidCommand(
+                "newTimeOffset must be a finite, non-negative number".to_string(),
+            ));
+        }
+
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
         let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
+            .get_track_mut(&self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
+        validate_track_unlocked(track)?;
         let clip = track
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
+            .get_clip_mut(&self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_opacity = Some(clip.opacity);
-        clip.opacity = Self::sanitize_opacity(self.opacity);
+        if self.keyframe_index >= clip.audio.volume_keyframes.len() {
+            return Err(CoreError::InvalidCommand(format!(
+                "keyframeIndex {} out of bounds (clip has {} keyframes)",
+                self.keyframe_index,
+                clip.audio.volume_keyframes.len()
+            )));
+        }
+
+        let clip_duration = clip.duration();
+        if self.new_time_offset > clip_duration {
+            return Err(CoreError::InvalidCommand(format!(
+                "newTimeOffset ({:.3}s) exceeds clip duration ({:.3}s)",
+                self.new_time_offset, clip_duration
+            )));
+        }
+
+        let kf = &clip.audio.volume_keyframes[self.keyframe_index];
+        self.previous_time_offset = Some(kf.time_offset);
+        self.keyframe_value_db = Some(kf.value_db);
+        clip.audio.volume_keyframes[self.keyframe_index].time_offset = self.new_time_offset;
+
+        // Re-sort after move
+        AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
+
+        // Store post-move index for reliable undo (match on time + value)
+        self.moved_to_index = clip.audio.volume_keyframes.iter().position(|kf| {
+            (kf.time_offset - self.new_time_offset).abs() < 1e-9
+                && self
+                    .keyframe_value_db
+                    .is_none_or(|v| (kf.value_db - v).abs() < 1e-9)
+        });
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(
@@ -5328,27 +5537,40 @@ impl Command for SetClipOpacityCommand {
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(prev) = self.previous_opacity else {
+        let Some(prev_offset) = self.previous_time_offset else {
             return Ok(());
         };
 
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+        let Some(track) = sequence.get_track_mut(&self.track_id) else {
             return Ok(());
         };
+        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
+            // Use the stored post-move index for reliable undo
+            let target_idx = self.moved_to_index.or_else(|| {
+                // Fallback: search by time + value
+                clip.audio.volume_keyframes.iter().position(|kf| {
+                    (kf.time_offset - self.new_time_offset).abs() < 1e-9
+                        && self
+                            .keyframe_value_db
+                            .is_none_or(|v| (kf.value_db - v).abs() < 1e-9)
+                })
+            });
 
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.opacity = prev;
+            if let Some(idx) = target_idx {
+                if idx < clip.audio.volume_keyframes.len() {
+                    clip.audio.volume_keyframes[idx].time_offset = prev_offset;
+                    AudioKeyframe::sort_by_time(&mut clip.audio.volume_keyframes);
+                }
+            }
         }
-
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipOpacity"
+        "MoveAudioKeyframe"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -5356,100 +5578,130 @@ impl Command for SetClipOpacityCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "opacity": self.opacity,
+            "keyframeIndex": self.keyframe_index,
+            "newTimeOffset": self.new_time_offset,
         })
     }
 }
 
 // =============================================================================
-// SetClipBlendModeCommand
+// SetAudioKeyframeValueCommand
 // =============================================================================
 
-/// Command to set a clip's blend mode.
-///
-/// Blend modes control how a clip composites with clips below it.
-/// Only supported on video/overlay tracks.
+/// Command to update the volume value of an existing audio keyframe.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct SetClipBlendModeCommand {
+pub struct SetAudioKeyframeValueCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    pub blend_mode: BlendMode,
+    /// Index of the keyframe to update (sorted order)
+    pub keyframe_index: usize,
+    /// New volume value in dB
+    pub value_db: f64,
+    /// Optional new interpolation method
+    pub interpolation: Option<KeyframeInterpolation>,
+    /// Previous value stored for undo
     #[serde(skip)]
-    previous_blend_mode: Option<BlendMode>,
+    previous_value_db: Option<f64>,
+    #[serde(skip)]
+    previous_interpolation: Option<KeyframeInterpolation>,
 }
 
-impl SetClipBlendModeCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, blend_mode: BlendMode) -> Self {
+impl SetAudioKeyframeValueCommand {
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        clip_id: &str,
+        keyframe_index: usize,
+        value_db: f64,
+        interpolation: Option<KeyframeInterpolation>,
+    ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            blend_mode,
-            previous_blend_mode: None,
+            keyframe_index,
+            value_db,
+            interpolation,
+            previous_value_db: None,
+            previous_interpolation: None,
         }
     }
 }
 
-impl Command for SetClipBlendModeCommand {
+impl Command for SetAudioKeyframeValueCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        if !self.value_db.is_finite() {
+            return Err(CoreError::InvalidCommand(
+                "valueDb must be a finite number".to_string(),
+            ));
+        }
+
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
         let track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
+            .get_track_mut(&self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
-        if !track.is_video() {
-            return Err(CoreError::NotSupported(
-                "Blend mode is only supported for video clips".to_string(),
-            ));
-        }
-
+        validate_track_unlocked(track)?;
         let clip = track
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
+            .get_clip_mut(&self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        self.previous_blend_mode = Some(clip.blend_mode.clone());
-        clip.blend_mode = self.blend_mode.clone();
+        if self.keyframe_index >= clip.audio.volume_keyframes.len() {
+            return Err(CoreError::InvalidCommand(format!(
+                "keyframeIndex {} out of bounds (clip has {} keyframes)",
+                self.keyframe_index,
+                clip.audio.volume_keyframes.len()
+            )));
+        }
 
-        let op_id = ulid::Ulid::new().to_string();
-        Ok(
-            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
+        let kf = &mut clip.audio.volume_keyframes[self.keyframe_index];
+        self.previous_value_db = Some(kf.value_db);
+        self.previous_interpolation = Some(kf.interpolation.clone());
+
+        kf.value_db = self
+            .value_db
+            .clamp(MIN_CLIP_VOLUME_DB as f64, MAX_CLIP_VOLUME_DB as f64);
+
+        if let Some(ref interp) = self.interpolation {
+            kf.interpolation = interp.clone();
+        }
+
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
                 clip_id: self.clip_id.clone(),
             }),
         )
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(prev) = &self.previous_blend_mode else {
+        let (Some(prev_value), Some(ref prev_interp)) =
+            (self.previous_value_db, &self.previous_interpolation)
+        else {
             return Ok(());
         };
 
         let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
             return Ok(());
         };
-
-        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+        let Some(track) = sequence.get_track_mut(&self.track_id) else {
             return Ok(());
         };
-
-        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-            clip.blend_mode = prev.clone();
+        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
+            if let Some(kf) = clip.audio.volume_keyframes.get_mut(self.keyframe_index) {
+                kf.value_db = prev_value;
+                kf.interpolation = prev_interp.clone();
+            }
         }
-
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SetClipBlendMode"
+        "SetAudioKeyframeValue"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -5457,12 +5709,67 @@ impl Command for SetClipBlendModeCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "blendMode": self.blend_mode,
+            "keyframeIndex": self.keyframe_index,
+            "valueDb": self.value_db,
+            "interpolation": self.interpolation,
         })
     }
 }
 
-impl Command for SplitClipCommand {
+// =============================================================================
+// SetClipTransformCommand
+// =============================================================================
+
+/// Command to update a clip's transform (position/scale/rotation/anchor).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetClipTransformCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    pub transform: Transform,
+    #[serde(skip)]
+    previous_transform: Option<Transform>,
+}
+
+impl SetClipTransformCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, transform: Transform) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            clip_id: clip_id.to_string(),
+            transform,
+            previous_transform: None,
+        }
+    }
+
+    fn sanitize_transform(mut transform: Transform) -> Transform {
+        // Position/anchor are normalized.
+        transform.position.x = transform.position.x.clamp(0.0, 1.0);
+        transform.position.y = transform.position.y.clamp(0.0, 1.0);
+        transform.anchor.x = transform.anchor.x.clamp(0.0, 1.0);
+        transform.anchor.y = transform.anchor.y.clamp(0.0, 1.0);
+
+        // Scale must be finite and non-zero-ish. Clamp to a reasonable range.
+        if !transform.scale.x.is_finite() {
+            transform.scale.x = 1.0;
+        }
+        if !transform.scale.y.is_finite() {
+            transform.scale.y = 1.0;
+        }
+        transform.scale.x = transform.scale.x.clamp(0.01, 100.0);
+        transform.scale.y = transform.scale.y.clamp(0.01, 100.0);
+
+        // Rotation must be finite.
+        if !transform.rotation_deg.is_finite() {
+            transform.rotation_deg = 0.0;
+        }
+
+        transform
+    }
+}
+
+impl Command for SetClipTransformCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
@@ -5475,929 +5782,1843 @@ impl Command for SplitClipCommand {
             .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        let clip_idx = track
+        let clip = track
             .clips
-            .iter()
-            .position(|c| c.id == self.clip_id)
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        // Store original clip for undo BEFORE any modification
-        self.original_clip = Some(track.clips[clip_idx].clone());
-
-        let original = track.clips[clip_idx].clone();
-
-        // Validate split point is within clip bounds
-        let clip_start = original.place.timeline_in_sec;
-        let clip_end = clip_start + original.duration();
-
-        if self.split_at <= clip_start || self.split_at >= clip_end {
-            return Err(CoreError::InvalidSplitPoint(self.split_at));
-        }
-
-        let relative_split = self.split_at - clip_start;
-        let ((first_source_in, first_source_out), (second_source_in, second_source_out)) =
-            split_clip_ranges_at(&original, self.split_at);
-        let second_timeline_duration = clip_end - self.split_at;
-
-        let second_clip = clone_clip_fragment_with_rebased_time_remap(
-            &original,
-            second_source_in,
-            second_source_out,
-            self.split_at,
-            second_timeline_duration,
-            relative_split,
-        );
-
-        let second_clip_id = second_clip.id.clone();
-
-        // Store created clip ID for undo
-        self.created_clip_id = Some(second_clip_id.clone());
-
-        // Prepare modified first clip without mutating track until validations pass.
-        let mut first_clip = original.clone();
-        first_clip.range.source_in_sec = first_source_in;
-        first_clip.range.source_out_sec = first_source_out;
-        first_clip.place.duration_sec = relative_split;
-        rebase_clip_time_remap_for_fragment(&mut first_clip, 0.0, relative_split);
-
-        if !first_clip.place.duration_sec.is_finite() || first_clip.place.duration_sec <= 0.0 {
-            return Err(CoreError::ValidationError(
-                "Split produced an invalid first clip duration".to_string(),
-            ));
-        }
-        if !second_clip.place.duration_sec.is_finite() || second_clip.place.duration_sec <= 0.0 {
-            return Err(CoreError::ValidationError(
-                "Split produced an invalid second clip duration".to_string(),
-            ));
-        }
-
-        // Validate overlap with the rest of the track BEFORE mutating.
-        validate_no_overlap(track, &first_clip.place, Some(&first_clip.id))?;
-        validate_no_overlap(track, &second_clip.place, Some(&first_clip.id))?;
-
-        // Apply: replace original clip and insert the new clip in timeline order.
-        track.clips[clip_idx] = first_clip;
-        insert_clip_sorted(track, second_clip);
+        self.previous_transform = Some(clip.transform.clone());
+        clip.transform = Self::sanitize_transform(self.transform.clone());
 
         let op_id = ulid::Ulid::new().to_string();
-
-        Ok(CommandResult::new(&op_id)
-            .with_change(StateChange::ClipModified {
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
                 clip_id: self.clip_id.clone(),
-            })
-            .with_change(StateChange::ClipCreated {
-                clip_id: second_clip_id.clone(),
-            })
-            .with_created_id(&second_clip_id))
+            }),
+        )
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        if let (Some(original), Some(created_id)) = (&self.original_clip, &self.created_clip_id) {
-            if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
-                if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) {
-                    // Remove the created clip
-                    track.clips.retain(|c| &c.id != created_id);
+        let Some(prev) = &self.previous_transform else {
+            return Ok(());
+        };
 
-                    // Restore original clip
-                    if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
-                        *clip = original.clone();
-                    }
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
 
-                    sort_track_clips(track);
-                }
-            }
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+            return Ok(());
+        };
+
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.transform = prev.clone();
         }
+
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "SplitClip"
+        "SetClipTransform"
     }
 
     fn to_json(&self) -> serde_json::Value {
-        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "trackId": self.track_id,
+            "clipId": self.clip_id,
+            "transform": self.transform,
+        })
     }
 }
 
 // =============================================================================
-// GapInfo — data returned by find_gaps
+// SetClipMotionKeyframesCommand
 // =============================================================================
 
-/// Describes a gap (empty region) between clips on a track.
-#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, specta::Type)]
+/// Command to replace a clip's transform animation keyframes.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct GapInfo {
-    /// Start time of the gap (end of preceding clip).
-    pub start: TimeSec,
-    /// End time of the gap (start of next clip).
-    pub end: TimeSec,
-    /// Duration of the gap.
-    pub duration: TimeSec,
+pub struct SetClipMotionKeyframesCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    pub keyframes: Vec<TransformKeyframe>,
+    #[serde(skip)]
+    previous_keyframes: Option<Vec<TransformKeyframe>>,
 }
 
-/// Scans a track for gaps between clips.
-///
-/// Returns a sorted list of gaps. Adjacent/overlapping clips produce no gap.
-/// Only gaps with positive duration (> 1 microsecond tolerance) are included.
-pub fn find_gaps(track: &Track) -> Vec<GapInfo> {
-    if track.clips.is_empty() {
-        return Vec::new();
+impl SetClipMotionKeyframesCommand {
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        clip_id: &str,
+        keyframes: Vec<TransformKeyframe>,
+    ) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            clip_id: clip_id.to_string(),
+            keyframes,
+            previous_keyframes: None,
+        }
     }
 
-    // Clips should already be sorted, but ensure deterministic ordering.
-    let mut sorted_clips: Vec<&Clip> = track.clips.iter().collect();
-    sorted_clips.sort_by(|a, b| {
-        a.place
-            .timeline_in_sec
-            .total_cmp(&b.place.timeline_in_sec)
-            .then_with(|| a.id.cmp(&b.id))
-    });
-
-    let mut gaps = Vec::new();
-    const EPSILON: f64 = 1e-6;
-
-    for window in sorted_clips.windows(2) {
-        let prev_end = window[0].place.timeline_out_sec();
-        let next_start = window[1].place.timeline_in_sec;
-        let gap_duration = next_start - prev_end;
-
-        if gap_duration > EPSILON {
-            gaps.push(GapInfo {
-                start: prev_end,
-                end: next_start,
-                duration: gap_duration,
-            });
-        }
-    }
-
-    gaps
-}
-
-// =============================================================================
-// CloseGapCommand
-// =============================================================================
-
-/// Command to close a specific gap by shifting all downstream clips left.
-///
-/// All clips starting at or after `gap_end` on the target track are shifted
-/// left by `gap_duration`. Sync-locked tracks shift their clips in tandem.
-/// Locked tracks are never modified.
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct CloseGapCommand {
-    pub sequence_id: SequenceId,
-    pub track_id: TrackId,
-    /// Start of the gap to close.
-    pub gap_start: TimeSec,
-    /// End of the gap to close.
-    pub gap_end: TimeSec,
-
-    // --- Undo state ---
-    #[serde(skip)]
-    shifted_clips: Vec<ShiftedClipRecord>,
-    #[serde(skip)]
-    gap_duration: TimeSec,
-}
-
-impl CloseGapCommand {
-    pub fn new(sequence_id: &str, track_id: &str, gap_start: TimeSec, gap_end: TimeSec) -> Self {
-        Self {
-            sequence_id: sequence_id.to_string(),
-            track_id: track_id.to_string(),
-            gap_start,
-            gap_end,
-            shifted_clips: Vec::new(),
-            gap_duration: 0.0,
+    fn sanitize_keyframes(mut keyframes: Vec<TransformKeyframe>) -> Vec<TransformKeyframe> {
+        keyframes
+            .retain(|keyframe| keyframe.time_offset.is_finite() && keyframe.time_offset >= 0.0);
+        for keyframe in &mut keyframes {
+            keyframe.time_offset = keyframe.time_offset.max(0.0);
+            keyframe.transform =
+                SetClipTransformCommand::sanitize_transform(keyframe.transform.clone());
         }
+        keyframes.sort_by(|a, b| a.time_offset.total_cmp(&b.time_offset));
+        keyframes
     }
 }
 
-impl Command for CloseGapCommand {
+impl Command for SetClipMotionKeyframesCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if !is_valid_time_sec(self.gap_start) || !is_valid_time_sec(self.gap_end) {
-            return Err(CoreError::ValidationError(
-                "Invalid gap time range".to_string(),
-            ));
-        }
-        if self.gap_end <= self.gap_start {
-            return Err(CoreError::ValidationError(
-                "gap_end must be greater than gap_start".to_string(),
-            ));
-        }
-
-        let gap_duration = self.gap_end - self.gap_start;
-
         let sequence = state
             .sequences
-            .get(&self.sequence_id)
+            .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
         let track = sequence
             .tracks
-            .iter()
+            .iter_mut()
             .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        if track.locked {
-            return Err(CoreError::ValidationError(format!(
-                "Track '{}' is locked",
-                self.track_id
-            )));
-        }
-
-        // Verify there is actually a gap at the specified location:
-        // No clip should occupy the [gap_start, gap_end) region.
-        let has_clip_in_gap = track.clips.iter().any(|c| {
-            let clip_start = c.place.timeline_in_sec;
-            let clip_end = c.place.timeline_out_sec();
-            // Clip overlaps gap if clip_start < gap_end AND clip_end > gap_start
-            clip_start < self.gap_end && clip_end > self.gap_start
-        });
-        if has_clip_in_gap {
-            return Err(CoreError::ValidationError(
-                "No gap exists at the specified location — a clip occupies that region".to_string(),
-            ));
-        }
-
-        // Collect shift records for target track: all clips starting at or after gap_end
-        let mut shift_records: Vec<ShiftedClipRecord> = Vec::new();
-
-        for clip in &track.clips {
-            if clip.place.timeline_in_sec >= self.gap_end - 1e-6 {
-                shift_records.push(ShiftedClipRecord {
-                    track_id: self.track_id.clone(),
-                    clip_id: clip.id.clone(),
-                    original_timeline_in: clip.place.timeline_in_sec,
-                });
-            }
-        }
-
-        // Sync-locked tracks: shift clips at or after gap_start
-        for other_track in &sequence.tracks {
-            if other_track.id == self.track_id || other_track.locked || !other_track.sync_lock {
-                continue;
-            }
-            for clip in &other_track.clips {
-                if clip.place.timeline_in_sec >= self.gap_end - 1e-6 {
-                    shift_records.push(ShiftedClipRecord {
-                        track_id: other_track.id.clone(),
-                        clip_id: clip.id.clone(),
-                        original_timeline_in: clip.place.timeline_in_sec,
-                    });
-                }
-            }
-        }
-
-        // --- Mutate state ---
-        let sequence = state
-            .sequences
-            .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
-        for record in &shift_records {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
-                if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
-                    clip.place.timeline_in_sec -= gap_duration;
-                }
-                sort_track_clips(track);
-            }
-        }
+        let clip = track
+            .clips
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        // Store undo state
-        self.shifted_clips = shift_records;
-        self.gap_duration = gap_duration;
+        self.previous_keyframes = Some(clip.motion_keyframes.clone());
+        clip.motion_keyframes = Self::sanitize_keyframes(self.keyframes.clone());
 
-        // Build result
         let op_id = ulid::Ulid::new().to_string();
-        let mut result = CommandResult::new(&op_id);
-        for record in &self.shifted_clips {
-            result = result.with_change(StateChange::ClipModified {
-                clip_id: record.clip_id.clone(),
-            });
-        }
-
-        Ok(result)
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
+                clip_id: self.clip_id.clone(),
+            }),
+        )
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let sequence = state
-            .sequences
-            .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let Some(prev) = &self.previous_keyframes else {
+            return Ok(());
+        };
 
-        for record in self.shifted_clips.iter().rev() {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
-                if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
-                    clip.place.timeline_in_sec = record.original_timeline_in;
-                }
-                sort_track_clips(track);
-            }
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
+
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+            return Ok(());
+        };
+
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.motion_keyframes = prev.clone();
         }
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "CloseGap"
+        "SetClipMotionKeyframes"
     }
 
     fn to_json(&self) -> serde_json::Value {
-        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "trackId": self.track_id,
+            "clipId": self.clip_id,
+            "keyframes": self.keyframes,
+        })
     }
 }
 
 // =============================================================================
-// CloseAllGapsCommand
+// SetClipOpacityCommand
 // =============================================================================
 
-/// Command to remove all gaps on a track by ripple-shifting every clip leftward.
-///
-/// Each clip is repositioned so that `clip[i].timeline_in = clip[i-1].timeline_out`.
-/// The first clip shifts to time 0 if there is a leading gap.
-/// Sync-locked tracks shift their clips in tandem.
-/// Locked tracks are never modified.
+/// Command to set a clip's opacity.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CloseAllGapsCommand {
+pub struct SetClipOpacityCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
-
-    // --- Undo state ---
-    #[serde(skip)]
-    shifted_clips: Vec<ShiftedClipRecord>,
-    /// Total shift applied to sync-locked tracks (cumulative gap duration).
+    pub clip_id: ClipId,
+    pub opacity: f32,
     #[serde(skip)]
-    sync_shift_amount: TimeSec,
+    previous_opacity: Option<f32>,
 }
 
-impl CloseAllGapsCommand {
-    pub fn new(sequence_id: &str, track_id: &str) -> Self {
+impl SetClipOpacityCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, opacity: f32) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
-            shifted_clips: Vec::new(),
-            sync_shift_amount: 0.0,
+            clip_id: clip_id.to_string(),
+            opacity,
+            previous_opacity: None,
+        }
+    }
+
+    fn sanitize_opacity(opacity: f32) -> f32 {
+        if opacity.is_finite() {
+            opacity.clamp(0.0, 1.0)
+        } else {
+            1.0
         }
     }
 }
 
-impl Command for CloseAllGapsCommand {
+impl Command for SetClipOpacityCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
-            .get(&self.sequence_id)
+            .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
         let track = sequence
             .tracks
-            .iter()
+            .iter_mut()
             .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        if track.locked {
-            return Err(CoreError::ValidationError(format!(
-                "Track '{}' is locked",
-                self.track_id
-            )));
-        }
+        let clip = track
+            .clips
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        if track.clips.is_empty() {
-            let op_id = ulid::Ulid::new().to_string();
-            return Ok(CommandResult::new(&op_id));
+        self.previous_opacity = Some(clip.opacity);
+        clip.opacity = Self::sanitize_opacity(self.opacity);
+
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
+                clip_id: self.clip_id.clone(),
+            }),
+        )
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(prev) = self.previous_opacity else {
+            return Ok(());
+        };
+
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
+
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+            return Ok(());
+        };
+
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.opacity = prev;
         }
 
-        // Work on a sorted copy to compute new positions
-        let mut sorted_clips: Vec<&Clip> = track.clips.iter().collect();
-        sorted_clips.sort_by(|a, b| {
-            a.place
-                .timeline_in_sec
-                .total_cmp(&b.place.timeline_in_sec)
-                .then_with(|| a.id.cmp(&b.id))
-        });
+        Ok(())
+    }
 
-        // Calculate new positions: pack clips left-to-right with no gaps
-        let mut shift_records: Vec<ShiftedClipRecord> = Vec::new();
-        let mut next_start: TimeSec = 0.0;
-        let mut new_positions: Vec<(ClipId, TimeSec)> = Vec::new();
+    fn type_name(&self) -> &'static str {
+        "SetClipOpacity"
+    }
 
-        for clip in &sorted_clips {
-            let original_start = clip.place.timeline_in_sec;
-            if (original_start - next_start).abs() > 1e-6 {
-                shift_records.push(ShiftedClipRecord {
-                    track_id: self.track_id.clone(),
-                    clip_id: clip.id.clone(),
-                    original_timeline_in: original_start,
-                });
-                new_positions.push((clip.id.clone(), next_start));
-            }
-            next_start += clip.place.duration_sec;
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "trackId": self.track_id,
+            "clipId": self.clip_id,
+            "opacity": self.opacity,
+        })
+    }
+}
+
+// =============================================================================
+// SetClipBlendModeCommand
+// =============================================================================
+
+/// Command to set a clip's blend mode.
+///
+/// Blend modes control how a clip composites with clips below it.
+/// Only supported on video/overlay tracks.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetClipBlendModeCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    pub blend_mode: BlendMode,
+    #[serde(skip)]
+    previous_blend_mode: Option<BlendMode>,
+}
+
+impl SetClipBlendModeCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, blend_mode: BlendMode) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            clip_id: clip_id.to_string(),
+            blend_mode,
+            previous_blend_mode: None,
         }
+    }
+}
 
-        // Compute total gap removed for sync-locked tracks:
-        // = original last clip end - new last clip end
-        let original_end = sorted_clips
-            .last()
-            .map(|c| c.place.timeline_out_sec())
-            .unwrap_or(0.0);
-        let new_end = next_start;
-        let total_gap_removed = original_end - new_end;
+impl Command for SetClipBlendModeCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        // Determine the earliest affected position for sync-lock filtering
-        let earliest_shift_pos = shift_records
-            .first()
-            .map(|r| r.original_timeline_in)
-            .unwrap_or(0.0);
+        let track = sequence
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        // Sync-locked tracks: shift downstream clips by total_gap_removed
-        if total_gap_removed > 1e-6 {
-            for other_track in &sequence.tracks {
-                if other_track.id == self.track_id || other_track.locked || !other_track.sync_lock {
-                    continue;
-                }
-                for clip in &other_track.clips {
-                    if clip.place.timeline_in_sec >= earliest_shift_pos {
-                        shift_records.push(ShiftedClipRecord {
-                            track_id: other_track.id.clone(),
-                            clip_id: clip.id.clone(),
-                            original_timeline_in: clip.place.timeline_in_sec,
-                        });
-                    }
-                }
-            }
+        if !track.is_video() {
+            return Err(CoreError::NotSupported(
+                "Blend mode is only supported for video clips".to_string(),
+            ));
         }
 
-        // --- Mutate state ---
+        let clip = track
+            .clips
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+
+        self.previous_blend_mode = Some(clip.blend_mode.clone());
+        clip.blend_mode = self.blend_mode.clone();
+
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
+                clip_id: self.clip_id.clone(),
+            }),
+        )
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(prev) = &self.previous_blend_mode else {
+            return Ok(());
+        };
+
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
+
+        let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) else {
+            return Ok(());
+        };
+
+        if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            clip.blend_mode = prev.clone();
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "SetClipBlendMode"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "trackId": self.track_id,
+            "clipId": self.clip_id,
+            "blendMode": self.blend_mode,
+        })
+    }
+}
+
+impl Command for SplitClipCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
         let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        // Apply new positions to target track
-        let target_track = sequence
+        let track = sequence
             .tracks
             .iter_mut()
             .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        for (clip_id, new_pos) in &new_positions {
-            if let Some(clip) = target_track.clips.iter_mut().find(|c| &c.id == clip_id) {
-                clip.place.timeline_in_sec = *new_pos;
-            }
+        let clip_idx = track
+            .clips
+            .iter()
+            .position(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+
+        // Store original clip for undo BEFORE any modification
+        self.original_clip = Some(track.clips[clip_idx].clone());
+
+        let original = track.clips[clip_idx].clone();
+
+        // Validate split point is within clip bounds
+        let clip_start = original.place.timeline_in_sec;
+        let clip_end = clip_start + original.duration();
+
+        if self.split_at <= clip_start || self.split_at >= clip_end {
+            return Err(CoreError::InvalidSplitPoint(self.split_at));
+        }
+
+        let relative_split = self.split_at - clip_start;
+        let ((first_source_in, first_source_out), (second_source_in, second_source_out)) =
+            split_clip_ranges_at(&original, self.split_at);
+        let second_timeline_duration = clip_end - self.split_at;
+
+        let second_clip = clone_clip_fragment_with_rebased_time_remap(
+            &original,
+            second_source_in,
+            second_source_out,
+            self.split_at,
+            second_timeline_duration,
+            relative_split,
+        );
+
+        let second_clip_id = second_clip.id.clone();
+
+        // Store created clip ID for undo
+        self.created_clip_id = Some(second_clip_id.clone());
+
+        // Prepare modified first clip without mutating track until validations pass.
+        let mut first_clip = original.clone();
+        first_clip.range.source_in_sec = first_source_in;
+        first_clip.range.source_out_sec = first_source_out;
+        first_clip.place.duration_sec = relative_split;
+        rebase_clip_time_remap_for_fragment(&mut first_clip, 0.0, relative_split);
+
+        if !first_clip.place.duration_sec.is_finite() || first_clip.place.duration_sec <= 0.0 {
+            return Err(CoreError::ValidationError(
+                "Split produced an invalid first clip duration".to_string(),
+            ));
+        }
+        if !second_clip.place.duration_sec.is_finite() || second_clip.place.duration_sec <= 0.0 {
+            return Err(CoreError::ValidationError(
+                "Split produced an invalid second clip duration".to_string(),
+            ));
+        }
+
+        // Validate overlap with the rest of the track BEFORE mutating.
+        validate_no_overlap(track, &first_clip.place, Some(&first_clip.id))?;
+        validate_no_overlap(track, &second_clip.place, Some(&first_clip.id))?;
+
+        // Apply: replace original clip and insert the new clip in timeline order.
+        track.clips[clip_idx] = first_clip;
+        insert_clip_sorted(track, second_clip);
+
+        let op_id = ulid::Ulid::new().to_string();
+
+        Ok(CommandResult::new(&op_id)
+            .with_change(StateChange::ClipModified {
+                clip_id: self.clip_id.clone(),
+            })
+            .with_change(StateChange::ClipCreated {
+                clip_id: second_clip_id.clone(),
+            })
+            .with_created_id(&second_clip_id))
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        if let (Some(original), Some(created_id)) = (&self.original_clip, &self.created_clip_id) {
+            if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+                if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) {
+                    // Remove the created clip
+                    track.clips.retain(|c| &c.id != created_id);
+
+                    // Restore original clip
+                    if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+                        *clip = original.clone();
+                    }
+
+                    sort_track_clips(track);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "SplitClip"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// GapInfo — data returned by find_gaps
+// =============================================================================
+
+/// Describes a gap (empty region) between clips on a track.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GapInfo {
+    /// Start time of the gap (end of preceding clip).
+    pub start: TimeSec,
+    /// End time of the gap (start of next clip).
+    pub end: TimeSec,
+    /// Duration of the gap.
+    pub duration: TimeSec,
+}
+
+/// Scans a track for gaps between clips.
+///
+/// Returns a sorted list of gaps. Adjacent/overlapping clips produce no gap.
+/// Only gaps with positive duration (> 1 microsecond tolerance) are included.
+pub fn find_gaps(track: &Track) -> Vec<GapInfo> {
+    if track.clips.is_empty() {
+        return Vec::new();
+    }
+
+    // Clips should already be sorted, but ensure deterministic ordering.
+    let mut sorted_clips: Vec<&Clip> = track.clips.iter().collect();
+    sorted_clips.sort_by(|a, b| {
+        a.place
+            .timeline_in_sec
+            .total_cmp(&b.place.timeline_in_sec)
+            .then_with(|| a.id.cmp(&b.id))
+    });
+
+    let mut gaps = Vec::new();
+    const EPSILON: f64 = 1e-6;
+
+    for window in sorted_clips.windows(2) {
+        let prev_end = window[0].place.timeline_out_sec();
+        let next_start = window[1].place.timeline_in_sec;
+        let gap_duration = next_start - prev_end;
+
+        if gap_duration > EPSILON {
+            gaps.push(GapInfo {
+                start: prev_end,
+                end: next_start,
+                duration: gap_duration,
+            });
+        }
+    }
+
+    gaps
+}
+
+// =============================================================================
+// CloseGapCommand
+// =============================================================================
+
+/// Command to close a specific gap by shifting all downstream clips left.
+///
+/// All clips starting at or after `gap_end` on the target track are shifted
+/// left by `gap_duration`. Sync-locked tracks shift their clips in tandem.
+/// Locked tracks are never modified.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseGapCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    /// Start of the gap to close.
+    pub gap_start: TimeSec,
+    /// End of the gap to close.
+    pub gap_end: TimeSec,
+
+    // --- Undo state ---
+    #[serde(skip)]
+    shifted_clips: Vec<ShiftedClipRecord>,
+    #[serde(skip)]
+    gap_duration: TimeSec,
+}
+
+impl CloseGapCommand {
+    pub fn new(sequence_id: &str, track_id: &str, gap_start: TimeSec, gap_end: TimeSec) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            gap_start,
+            gap_end,
+            shifted_clips: Vec::new(),
+            gap_duration: 0.0,
+        }
+    }
+}
+
+impl Command for CloseGapCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        if !is_valid_time_sec(self.gap_start) || !is_valid_time_sec(self.gap_end) {
+            return Err(CoreError::ValidationError(
+                "Invalid gap time range".to_string(),
+            ));
+        }
+        if self.gap_end <= self.gap_start {
+            return Err(CoreError::ValidationError(
+                "gap_end must be greater than gap_start".to_string(),
+            ));
+        }
+
+        let gap_duration = self.gap_end - self.gap_start;
+
+        let sequence = state
+            .sequences
+            .get(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let track = sequence
+            .tracks
+            .iter()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+
+        if track.locked {
+            return Err(CoreError::ValidationError(format!(
+                "Track '{}' is locked",
+                self.track_id
+            )));
+        }
+
+        // Verify there is actually a gap at the specified location:
+        // No clip should occupy the [gap_start, gap_end) region.
+        let has_clip_in_gap = track.clips.iter().any(|c| {
+            let clip_start = c.place.timeline_in_sec;
+            let clip_end = c.place.timeline_out_sec();
+            // Clip overlaps gap if clip_start < gap_end AND clip_end > gap_start
+            clip_start < self.gap_end && clip_end > self.gap_start
+        });
+        if has_clip_in_gap {
+            return Err(CoreError::ValidationError(
+                "No gap exists at the specified location — a clip occupies that region".to_string(),
+            ));
+        }
+
+        // Collect shift records for target track: all clips starting at or after gap_end
+        let mut shift_records: Vec<ShiftedClipRecord> = Vec::new();
+
+        for clip in &track.clips {
+            if clip.place.timeline_in_sec >= self.gap_end - 1e-6 {
+                shift_records.push(ShiftedClipRecord {
+                    track_id: self.track_id.clone(),
+                    clip_id: clip.id.clone(),
+                    original_timeline_in: clip.place.timeline_in_sec,
+                });
+            }
+        }
+
+        // Sync-locked tracks: shift clips at or after gap_start
+        for other_track in &sequence.tracks {
+            if other_track.id == self.track_id || other_track.locked || !other_track.sync_lock {
+                continue;
+            }
+            for clip in &other_track.clips {
+                if clip.place.timeline_in_sec >= self.gap_end - 1e-6 {
+                    shift_records.push(ShiftedClipRecord {
+                        track_id: other_track.id.clone(),
+                        clip_id: clip.id.clone(),
+                        original_timeline_in: clip.place.timeline_in_sec,
+                    });
+                }
+            }
+        }
+
+        // --- Mutate state ---
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        for record in &shift_records {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
+                if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
+                    clip.place.timeline_in_sec -= gap_duration;
+                }
+                sort_track_clips(track);
+            }
+        }
+
+        // Store undo state
+        self.shifted_clips = shift_records;
+        self.gap_duration = gap_duration;
+
+        // Build result
+        let op_id = ulid::Ulid::new().to_string();
+        let mut result = CommandResult::new(&op_id);
+        for record in &self.shifted_clips {
+            result = result.with_change(StateChange::ClipModified {
+                clip_id: record.clip_id.clone(),
+            });
+        }
+
+        Ok(result)
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        for record in self.shifted_clips.iter().rev() {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
+                if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
+                    clip.place.timeline_in_sec = record.original_timeline_in;
+                }
+                sort_track_clips(track);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "CloseGap"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// CloseAllGapsCommand
+// =============================================================================
+
+/// Command to remove all gaps on a track by ripple-shifting every clip leftward.
+///
+/// Each clip is repositioned so that `clip[i].timeline_in = clip[i-1].timeline_out`.
+/// The first clip shifts to time 0 if there is a leading gap.
+/// Sync-locked tracks shift their clips in tandem.
+/// Locked tracks are never modified.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CloseAllGapsCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+
+    // --- Undo state ---
+    #[serde(skip)]
+    shifted_clips: Vec<ShiftedClipRecord>,
+    /// Total shift applied to sync-locked tracks (cumulative gap duration).
+    #[serde(skip)]
+    sync_shift_amount: TimeSec,
+}
+
+impl CloseAllGapsCommand {
+    pub fn new(sequence_id: &str, track_id: &str) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            shifted_clips: Vec::new(),
+            sync_shift_amount: 0.0,
+        }
+    }
+}
+
+impl Command for CloseAllGapsCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = state
+            .sequences
+            .get(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let track = sequence
+            .tracks
+            .iter()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+
+        if track.locked {
+            return Err(CoreError::ValidationError(format!(
+                "Track '{}' is locked",
+                self.track_id
+            )));
+        }
+
+        if track.clips.is_empty() {
+            let op_id = ulid::Ulid::new().to_string();
+            return Ok(CommandResult::new(&op_id));
+        }
+
+        // Work on a sorted copy to compute new positions
+        let mut sorted_clips: Vec<&Clip> = track.clips.iter().collect();
+        sorted_clips.sort_by(|a, b| {
+            a.place
+                .timeline_in_sec
+                .total_cmp(&b.place.timeline_in_sec)
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        // Calculate new positions: pack clips left-to-right with no gaps
+        let mut shift_records: Vec<ShiftedClipRecord> = Vec::new();
+        let mut next_start: TimeSec = 0.0;
+        let mut new_positions: Vec<(ClipId, TimeSec)> = Vec::new();
+
+        for clip in &sorted_clips {
+            let original_start = clip.place.timeline_in_sec;
+            if (original_start - next_start).abs() > 1e-6 {
+                shift_records.push(ShiftedClipRecord {
+                    track_id: self.track_id.clone(),
+                    clip_id: clip.id.clone(),
+                    original_timeline_in: original_start,
+                });
+                new_positions.push((clip.id.clone(), next_start));
+            }
+            next_start += clip.place.duration_sec;
+        }
+
+        // Compute total gap removed for sync-locked tracks:
+        // = original last clip end - new last clip end
+        let original_end = sorted_clips
+            .last()
+            .map(|c| c.place.timeline_out_sec())
+            .unwrap_or(0.0);
+        let new_end = next_start;
+        let total_gap_removed = original_end - new_end;
+
+        // Determine the earliest affected position for sync-lock filtering
+        let earliest_shift_pos = shift_records
+            .first()
+            .map(|r| r.original_timeline_in)
+            .unwrap_or(0.0);
+
+        // Sync-locked tracks: shift downstream clips by total_gap_removed
+        if total_gap_removed > 1e-6 {
+            for other_track in &sequence.tracks {
+                if other_track.id == self.track_id || other_track.locked || !other_track.sync_lock {
+                    continue;
+                }
+                for clip in &other_track.clips {
+                    if clip.place.timeline_in_sec >= earliest_shift_pos {
+                        shift_records.push(ShiftedClipRecord {
+                            track_id: other_track.id.clone(),
+                            clip_id: clip.id.clone(),
+                            original_timeline_in: clip.place.timeline_in_sec,
+                        });
+                    }
+                }
+            }
+        }
+
+        // --- Mutate state ---
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        // Apply new positions to target track
+        let target_track = sequence
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+
+        for (clip_id, new_pos) in &new_positions {
+            if let Some(clip) = target_track.clips.iter_mut().find(|c| &c.id == clip_id) {
+                clip.place.timeline_in_sec = *new_pos;
+            }
+        }
+        sort_track_clips(target_track);
+
+        // Shift sync-locked tracks
+        if total_gap_removed > 1e-6 {
+            for record in &shift_records {
+                if record.track_id == self.track_id {
+                    continue;
+                }
+                if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
+                    if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
+                        clip.place.timeline_in_sec -= total_gap_removed;
+                    }
+                    sort_track_clips(track);
+                }
+            }
+        }
+
+        // Store undo state
+        self.shifted_clips = shift_records;
+        self.sync_shift_amount = total_gap_removed;
+
+        // Build result
+        let op_id = ulid::Ulid::new().to_string();
+        let mut result = CommandResult::new(&op_id);
+        for record in &self.shifted_clips {
+            result = result.with_change(StateChange::ClipModified {
+                clip_id: record.clip_id.clone(),
+            });
+        }
+
+        Ok(result)
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        // Restore all shifted clips to original positions (reverse order)
+        for record in self.shifted_clips.iter().rev() {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
+                if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
+                    clip.place.timeline_in_sec = record.original_timeline_in;
+                }
+                sort_track_clips(track);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "CloseAllGaps"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
+// =============================================================================
+// LinkClipsCommand
+// =============================================================================
+
+// Empty string marks an explicit "not linked" state. This suppresses the
+// frontend's legacy implicit A/V matching without surfacing a visible link badge.
+const EXPLICIT_UNLINK_SENTINEL: &str = "";
+
+/// Command to link multiple clips together for synchronized editing.
+/// Linked clips share a `link_group_id` and are selected/moved together.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkClipsCommand {
+    pub sequence_id: SequenceId,
+    /// Pairs of (track_id, clip_id) to link together
+    pub clip_refs: Vec<(TrackId, ClipId)>,
+    pub(crate) link_group_id: String,
+
+    #[serde(skip)]
+    previous_link_group_ids: Vec<(TrackId, ClipId, Option<String>)>,
+}
+
+impl LinkClipsCommand {
+    pub fn new(sequence_id: &str, clip_refs: Vec<(String, String)>) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            clip_refs,
+            link_group_id: ulid::Ulid::new().to_string(),
+            previous_link_group_ids: Vec::new(),
+        }
+    }
+}
+
+impl Command for LinkClipsCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        // Deduplicate clip refs to prevent single-clip link groups
+        let mut unique_refs = self.clip_refs.clone();
+        unique_refs.sort();
+        unique_refs.dedup();
+        if unique_refs.len() < 2 {
+            return Err(CoreError::ValidationError(
+                "LinkClips requires at least 2 distinct clips".to_string(),
+            ));
+        }
+        self.clip_refs = unique_refs;
+
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let affected_refs = collect_affected_link_refs(sequence, &self.clip_refs)?;
+        validate_clip_refs_unlocked(sequence, &affected_refs)?;
+        self.previous_link_group_ids = capture_link_group_state(sequence, &affected_refs)?;
+
+        // Apply link group ID to all clips (validated above, but propagate errors defensively)
+        for (track_id, clip_id) in &self.clip_refs {
+            let track = sequence
+                .tracks
+                .iter_mut()
+                .find(|t| &t.id == track_id)
+                .ok_or_else(|| CoreError::TrackNotFound(track_id.clone()))?;
+            let clip = track
+                .clips
+                .iter_mut()
+                .find(|c| &c.id == clip_id)
+                .ok_or_else(|| CoreError::ClipNotFound(clip_id.clone()))?;
+            clip.link_group_id = Some(self.link_group_id.clone());
+        }
+
+        let normalized_refs = normalize_degenerate_link_groups(sequence, &affected_refs)?;
+
+        let op_id = ulid::Ulid::new().to_string();
+        let mut result = CommandResult::new(&op_id);
+        append_clip_modified_changes(&mut result, &self.clip_refs);
+        append_clip_modified_changes(&mut result, &normalized_refs);
+        state.is_dirty = true;
+        Ok(result)
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
+
+        for (track_id, clip_id, prev_link) in &self.previous_link_group_ids {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| &t.id == track_id) {
+                if let Some(clip) = track.clips.iter_mut().find(|c| &c.id == clip_id) {
+                    clip.link_group_id = prev_link.clone();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "LinkClips"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "clipRefs": self.clip_refs.iter().map(|(t, c)| serde_json::json!({"trackId": t, "clipId": c})).collect::<Vec<_>>(),
+            "linkGroupId": self.link_group_id,
+        })
+    }
+}
+
+// =============================================================================
+// UnlinkClipsCommand
+// =============================================================================
+
+/// Command to unlink clips by removing their link_group_id.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlinkClipsCommand {
+    pub sequence_id: SequenceId,
+    /// Pairs of (track_id, clip_id) to unlink
+    pub clip_refs: Vec<(TrackId, ClipId)>,
+
+    #[serde(skip)]
+    previous_link_group_ids: Vec<(TrackId, ClipId, Option<String>)>,
+}
+
+impl UnlinkClipsCommand {
+    pub fn new(sequence_id: &str, clip_refs: Vec<(String, String)>) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            clip_refs,
+            previous_link_group_ids: Vec::new(),
+        }
+    }
+}
+
+impl Command for UnlinkClipsCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let affected_refs = collect_affected_link_refs(sequence, &self.clip_refs)?;
+        validate_clip_refs_unlocked(sequence, &affected_refs)?;
+        self.previous_link_group_ids = capture_link_group_state(sequence, &affected_refs)?;
+
+        // Mark clips as explicitly unlinked so legacy implicit pairing does not
+        // immediately re-link them on the frontend.
+        for (track_id, clip_id) in &self.clip_refs {
+            let track = sequence
+                .tracks
+                .iter_mut()
+                .find(|t| &t.id == track_id)
+                .ok_or_else(|| CoreError::TrackNotFound(track_id.clone()))?;
+            let clip = track
+                .clips
+                .iter_mut()
+                .find(|c| &c.id == clip_id)
+                .ok_or_else(|| CoreError::ClipNotFound(clip_id.clone()))?;
+            clip.link_group_id = Some(EXPLICIT_UNLINK_SENTINEL.to_string());
+        }
+
+        let normalized_refs = normalize_degenerate_link_groups(sequence, &affected_refs)?;
+
+        let op_id = ulid::Ulid::new().to_string();
+        let mut result = CommandResult::new(&op_id);
+        append_clip_modified_changes(&mut result, &self.clip_refs);
+        append_clip_modified_changes(&mut result, &normalized_refs);
+        state.is_dirty = true;
+        Ok(result)
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
+
+        for (track_id, clip_id, prev_link) in &self.previous_link_group_ids {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| &t.id == track_id) {
+                if let Some(clip) = track.clips.iter_mut().find(|c| &c.id == clip_id) {
+                    clip.link_group_id = prev_link.clone();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "UnlinkClips"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "clipRefs": self.clip_refs.iter().map(|(t, c)| serde_json::json!({"trackId": t, "clipId": c})).collect::<Vec<_>>(),
+        })
+    }
+}
+
+// =============================================================================
+// DetachAudioCommand
+// =============================================================================
+
+/// Command to detach audio from a video clip, creating a separate audio clip
+/// on a designated audio track. Both clips remain independent (unlinked).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DetachAudioCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    /// Optional target audio track ID. If None, uses first available audio track or creates one.
+    pub target_audio_track_id: Option<TrackId>,
+
+    #[serde(skip)]
+    created_audio_clip_id: Option<ClipId>,
+    #[serde(skip)]
+    created_audio_track_id: Option<TrackId>,
+    #[serde(skip)]
+    previous_link_group_ids: Vec<(TrackId, ClipId, Option<String>)>,
+}
+
+impl DetachAudioCommand {
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        clip_id: &str,
+        target_audio_track_id: Option<String>,
+    ) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            clip_id: clip_id.to_string(),
+            target_audio_track_id,
+            created_audio_clip_id: None,
+            created_audio_track_id: None,
+            previous_link_group_ids: Vec::new(),
+        }
+    }
+}
+
+impl Command for DetachAudioCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        use crate::core::timeline::TrackKind;
+
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        // Validate source track is video/overlay (has visual content)
+        let source_track = sequence
+            .tracks
+            .iter()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        validate_track_unlocked(source_track)?;
+
+        if !source_track.is_video() {
+            return Err(CoreError::ValidationError(
+                "Detach Audio is only available for clips on video/overlay tracks".to_string(),
+            ));
+        }
+
+        // Find the source clip and create the audio clip
+        let source_clip = source_track
+            .clips
+            .iter()
+            .find(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+
+        let affected_refs =
+            collect_affected_link_refs(sequence, &[(self.track_id.clone(), self.clip_id.clone())])?;
+        validate_clip_refs_unlocked(sequence, &affected_refs)?;
+        self.previous_link_group_ids = capture_link_group_state(sequence, &affected_refs)?;
+
+        // Create audio clip with same timing, range, speed, time remap, and audio settings
+        let mut audio_clip = Clip::new(&source_clip.asset_id);
+        audio_clip.range = source_clip.range.clone();
+        audio_clip.place = source_clip.place.clone();
+        audio_clip.speed = source_clip.speed;
+        audio_clip.reverse = source_clip.reverse;
+        audio_clip.audio = source_clip.audio.clone();
+        audio_clip.time_remap = source_clip.time_remap.clone();
+        audio_clip.link_group_id = Some(EXPLICIT_UNLINK_SENTINEL.to_string());
+        let audio_clip_id = audio_clip.id.clone();
+
+        // Determine target audio track
+        let target_track_id = if let Some(ref given_id) = self.target_audio_track_id {
+            // Validate the given track is audio
+            let target = sequence
+                .tracks
+                .iter()
+                .find(|t| &t.id == given_id)
+                .ok_or_else(|| CoreError::TrackNotFound(given_id.clone()))?;
+            if !target.is_audio() {
+                return Err(CoreError::ValidationError(
+                    "Target track must be an audio track".to_string(),
+                ));
+            }
+            validate_track_unlocked(target)?;
+            given_id.clone()
+        } else {
+            // Find the first unlocked audio track that can accept the clip,
+            // or create one if every existing lane would overlap.
+            if let Some(audio_track) = sequence.tracks.iter().find(|t| {
+                t.kind == TrackKind::Audio
+                    && !t.locked
+                    && validate_no_overlap(t, &audio_clip.place, None).is_ok()
+            }) {
+                audio_track.id.clone()
+            } else {
+                // Create a new audio track
+                let new_track = Track::new_audio("Audio (Detached)");
+                let new_track_id = new_track.id.clone();
+                sequence.tracks.push(new_track);
+                self.created_audio_track_id = Some(new_track_id.clone());
+                new_track_id
+            }
+        };
+
+        // Mark the source clip as explicitly unlinked so it stays detached from
+        // any legacy implicit A/V pairing.
+        let source_track_mut = sequence
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        let source_clip_mut = source_track_mut
+            .clips
+            .iter_mut()
+            .find(|c| c.id == self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+        source_clip_mut.link_group_id = Some(EXPLICIT_UNLINK_SENTINEL.to_string());
+
+        // Validate no overlap on target track before inserting
+        let target_track = sequence
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == target_track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(target_track_id.clone()))?;
+        validate_no_overlap(target_track, &audio_clip.place, None)?;
+        target_track.clips.push(audio_clip);
+        sort_track_clips(target_track);
+
+        self.created_audio_clip_id = Some(audio_clip_id.clone());
+        let normalized_refs = normalize_degenerate_link_groups(sequence, &affected_refs)?;
+
+        let op_id = ulid::Ulid::new().to_string();
+        let mut result = CommandResult::new(&op_id)
+            .with_change(StateChange::ClipCreated {
+                clip_id: audio_clip_id.clone(),
+            })
+            .with_created_id(&audio_clip_id);
+        append_clip_modified_changes(
+            &mut result,
+            &[(self.track_id.clone(), self.clip_id.clone())],
+        );
+        append_clip_modified_changes(&mut result, &normalized_refs);
+        Ok(result)
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
+
+        // Remove the created audio clip
+        if let Some(ref audio_clip_id) = self.created_audio_clip_id {
+            for track in &mut sequence.tracks {
+                track.clips.retain(|c| &c.id != audio_clip_id);
+            }
+        }
+
+        // Remove created audio track if we created one
+        if let Some(ref created_track_id) = self.created_audio_track_id {
+            sequence.tracks.retain(|t| &t.id != created_track_id);
+        }
+
+        for (track_id, clip_id, prev_link) in &self.previous_link_group_ids {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| &t.id == track_id) {
+                if let Some(clip) = track.clips.iter_mut().find(|c| &c.id == clip_id) {
+                    clip.link_group_id = prev_link.clone();
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "DetachAudio"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "trackId": self.track_id,
+            "clipId": self.clip_id,
+            "targetAudioTrackId": self.target_audio_track_id,
+        })
+    }
+}
+
+// =============================================================================
+// ApplyAudioDuckingCommand
+// =============================================================================
+
+/// Atomically sets pre-computed duck keyframes on a music clip.
+///
+/// The IPC layer performs the speech analysis (FFmpeg silencedetect) and
+/// keyframe generation (via `ducking::generate_duck_keyframes`).  This
+/// command only handles the state mutation so that undo/redo works as a
+/// single operation.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyAudioDuckingCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    /// Pre-computed ducking keyframes to apply
+    pub keyframes: Vec<AudioKeyframe>,
+    /// Stored for undo — previous volume keyframes on the clip
+    #[serde(skip)]
+    previous_keyframes: Option<Vec<AudioKeyframe>>,
+}
+
+impl ApplyAudioDuckingCommand {
+    pub fn new(
+        sequence_id: &str,
+        track_id: &str,
+        clip_id: &str,
+        keyframes: Vec<AudioKeyframe>,
+    ) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            clip_id: clip_id.to_string(),
+            keyframes,
+            previous_keyframes: None,
+        }
+    }
+}
+
+impl Command for ApplyAudioDuckingCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let track = sequence
+            .get_track_mut(&self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+
+        if track.locked {
+            return Err(CoreError::InvalidCommand(
+                "Cannot apply ducking to a locked track".to_string(),
+            ));
         }
-        sort_track_clips(target_track);
 
-        // Shift sync-locked tracks
-        if total_gap_removed > 1e-6 {
-            for record in &shift_records {
-                if record.track_id == self.track_id {
-                    continue;
-                }
-                if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
-                    if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
-                        clip.place.timeline_in_sec -= total_gap_removed;
-                    }
-                    sort_track_clips(track);
-                }
-            }
-        }
+        let clip = track
+            .get_clip_mut(&self.clip_id)
+            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        // Store undo state
-        self.shifted_clips = shift_records;
-        self.sync_shift_amount = total_gap_removed;
+        // Store previous keyframes for undo
+        self.previous_keyframes = Some(clip.audio.volume_keyframes.clone());
 
-        // Build result
-        let op_id = ulid::Ulid::new().to_string();
-        let mut result = CommandResult::new(&op_id);
-        for record in &self.shifted_clips {
-            result = result.with_change(StateChange::ClipModified {
-                clip_id: record.clip_id.clone(),
-            });
-        }
+        // Apply the pre-computed duck keyframes
+        clip.audio.volume_keyframes = self.keyframes.clone();
 
-        Ok(result)
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
+                clip_id: self.clip_id.clone(),
+            }),
+        )
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let sequence = state
-            .sequences
-            .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let Some(previous) = &self.previous_keyframes else {
+            return Ok(());
+        };
 
-        // Restore all shifted clips to original positions (reverse order)
-        for record in self.shifted_clips.iter().rev() {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == record.track_id) {
-                if let Some(clip) = track.clips.iter_mut().find(|c| c.id == record.clip_id) {
-                    clip.place.timeline_in_sec = record.original_timeline_in;
-                }
-                sort_track_clips(track);
-            }
+        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
+            return Ok(());
+        };
+        let Some(track) = sequence.get_track_mut(&self.track_id) else {
+            return Ok(());
+        };
+        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
+            clip.audio.volume_keyframes = previous.clone();
         }
 
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "CloseAllGaps"
+        "ApplyAudioDucking"
     }
 
     fn to_json(&self) -> serde_json::Value {
-        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "trackId": self.track_id,
+            "clipId": self.clip_id,
+            "keyframeCount": self.keyframes.len(),
+        })
     }
 }
 
 // =============================================================================
-// LinkClipsCommand
+// Compound Clip Commands
 // =============================================================================
 
-// Empty string marks an explicit "not linked" state. This suppresses the
-// frontend's legacy implicit A/V matching without surfacing a visible link badge.
-const EXPLICIT_UNLINK_SENTINEL: &str = "";
-
-/// Command to link multiple clips together for synchronized editing.
-/// Linked clips share a `link_group_id` and are selected/moved together.
+/// Command to create a compound clip by nesting selected clips into a new sequence.
+///
+/// Selected clips are moved into a new inner sequence and replaced with a single
+/// compound clip that references the inner sequence.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct LinkClipsCommand {
+pub struct CreateCompoundClipCommand {
     pub sequence_id: SequenceId,
-    /// Pairs of (track_id, clip_id) to link together
-    pub clip_refs: Vec<(TrackId, ClipId)>,
-    pub(crate) link_group_id: String,
+    pub track_id: TrackId,
+    pub clip_ids: Vec<ClipId>,
+    pub name: Option<String>,
 
     #[serde(skip)]
-    previous_link_group_ids: Vec<(TrackId, ClipId, Option<String>)>,
+    created_sequence_id: Option<SequenceId>,
+    #[serde(skip)]
+    created_compound_clip_id: Option<ClipId>,
+    #[serde(skip)]
+    removed_clips: Vec<Clip>,
 }
 
-impl LinkClipsCommand {
-    pub fn new(sequence_id: &str, clip_refs: Vec<(String, String)>) -> Self {
+impl CreateCompoundClipCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_ids: Vec<String>) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
-            clip_refs,
-            link_group_id: ulid::Ulid::new().to_string(),
-            previous_link_group_ids: Vec::new(),
+            track_id: track_id.to_string(),
+            clip_ids,
+            name: None,
+            created_sequence_id: None,
+            created_compound_clip_id: None,
+            removed_clips: Vec::new(),
         }
     }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
 }
 
-impl Command for LinkClipsCommand {
+impl Command for CreateCompoundClipCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        // Deduplicate clip refs to prevent single-clip link groups
-        let mut unique_refs = self.clip_refs.clone();
-        unique_refs.sort();
-        unique_refs.dedup();
-        if unique_refs.len() < 2 {
+        // 1. Deduplicate and validate inputs
+        let mut unique_ids: Vec<ClipId> = self.clip_ids.clone();
+        unique_ids.sort();
+        unique_ids.dedup();
+        self.clip_ids = unique_ids;
+
+        if self.clip_ids.is_empty() {
             return Err(CoreError::ValidationError(
-                "LinkClips requires at least 2 distinct clips".to_string(),
+                "CreateCompoundClip requires at least 1 clip".to_string(),
             ));
         }
-        self.clip_refs = unique_refs;
 
+        // 2. Get source sequence and validate track
         let sequence = state
             .sequences
-            .get_mut(&self.sequence_id)
+            .get(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        let affected_refs = collect_affected_link_refs(sequence, &self.clip_refs)?;
-        validate_clip_refs_unlocked(sequence, &affected_refs)?;
-        self.previous_link_group_ids = capture_link_group_state(sequence, &affected_refs)?;
+        let track = sequence
+            .tracks
+            .iter()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        // Apply link group ID to all clips (validated above, but propagate errors defensively)
-        for (track_id, clip_id) in &self.clip_refs {
-            let track = sequence
-                .tracks
-                .iter_mut()
-                .find(|t| &t.id == track_id)
-                .ok_or_else(|| CoreError::TrackNotFound(track_id.clone()))?;
+        validate_track_unlocked(track)?;
+
+        // 3. Collect selected clips and validate they all exist
+        let mut selected_clips: Vec<Clip> = Vec::new();
+        for clip_id in &self.clip_ids {
             let clip = track
                 .clips
-                .iter_mut()
-                .find(|c| &c.id == clip_id)
+                .iter()
+                .find(|c| c.id == *clip_id)
                 .ok_or_else(|| CoreError::ClipNotFound(clip_id.clone()))?;
-            clip.link_group_id = Some(self.link_group_id.clone());
+            selected_clips.push(clip.clone());
         }
 
-        let normalized_refs = normalize_degenerate_link_groups(sequence, &affected_refs)?;
-
-        let op_id = ulid::Ulid::new().to_string();
-        let mut result = CommandResult::new(&op_id);
-        append_clip_modified_changes(&mut result, &self.clip_refs);
-        append_clip_modified_changes(&mut result, &normalized_refs);
-        state.is_dirty = true;
-        Ok(result)
-    }
+        // 4. Compute the time span of selected clips
+        let span_start = selected_clips
+            .iter()
+            .map(|c| c.place.timeline_in_sec)
+            .fold(f64::INFINITY, f64::min);
+        let span_end = selected_clips
+            .iter()
+            .map(|c| c.place.timeline_out_sec())
+            .fold(f64::NEG_INFINITY, f64::max);
+        let compound_duration = span_end - span_start;
 
-    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
+        if !compound_duration.is_finite() || compound_duration <= 0.0 {
+            return Err(CoreError::ValidationError(
+                "Selected clips have zero, negative, or invalid total duration".to_string(),
+            ));
+        }
 
-        for (track_id, clip_id, prev_link) in &self.previous_link_group_ids {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| &t.id == track_id) {
-                if let Some(clip) = track.clips.iter_mut().find(|c| &c.id == clip_id) {
-                    clip.link_group_id = prev_link.clone();
-                }
-            }
+        let selected_clip_id_set: std::collections::HashSet<&str> = self
+            .clip_ids
+            .iter()
+            .map(|clip_id| clip_id.as_str())
+            .collect();
+        if let Some(conflicting_clip) = track.clips.iter().find(|clip| {
+            !selected_clip_id_set.contains(clip.id.as_str())
+                && clip.place.timeline_in_sec < span_end
+                && clip.place.timeline_out_sec() > span_start
+        }) {
+            return Err(CoreError::ValidationError(format!(
+                "CreateCompoundClip requires selecting all clips within the nested span; clip '{}' overlaps the selection window",
+                conflicting_clip.id
+            )));
         }
 
-        Ok(())
-    }
+        // 5. Create inner sequence with same format
+        let seq_format = sequence.format.clone();
+        let compound_name = self
+            .name
+            .clone()
+            .unwrap_or_else(|| "Compound Clip".to_string());
+        let mut inner_sequence = crate::core::timeline::Sequence::new(&compound_name, seq_format);
 
-    fn type_name(&self) -> &'static str {
-        "LinkClips"
-    }
+        // 6. Create a track in the inner sequence matching the source track kind
+        let inner_track = crate::core::timeline::Track::new(&track.name, track.kind.clone());
+        let inner_track_id = inner_track.id.clone();
+        inner_sequence.add_track(inner_track);
 
-    fn to_json(&self) -> serde_json::Value {
-        serde_json::json!({
-            "sequenceId": self.sequence_id,
-            "clipRefs": self.clip_refs.iter().map(|(t, c)| serde_json::json!({"trackId": t, "clipId": c})).collect::<Vec<_>>(),
-            "linkGroupId": self.link_group_id,
-        })
-    }
-}
+        // 7. Move clips to inner sequence (adjust positions relative to span_start)
+        let inner_track_mut = inner_sequence
+            .get_track_mut(&inner_track_id)
+            .ok_or_else(|| {
+                CoreError::Internal(format!(
+                    "Inner track '{}' not found after creation",
+                    inner_track_id
+                ))
+            })?;
+        for clip in &selected_clips {
+            let mut inner_clip = clip.clone();
+            inner_clip.place.timeline_in_sec -= span_start;
+            insert_clip_sorted(inner_track_mut, inner_clip);
+        }
 
-// =============================================================================
-// UnlinkClipsCommand
-// =============================================================================
+        let inner_sequence_id = inner_sequence.id.clone();
 
-/// Command to unlink clips by removing their link_group_id.
-#[derive(Clone, Debug, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-pub struct UnlinkClipsCommand {
-    pub sequence_id: SequenceId,
-    /// Pairs of (track_id, clip_id) to unlink
-    pub clip_refs: Vec<(TrackId, ClipId)>,
+        // 8. Create compound clip at the original span position
+        let compound_clip =
+            Clip::compound(&inner_sequence_id, compound_duration).place_at(span_start);
+        let compound_clip_id = compound_clip.id.clone();
 
-    #[serde(skip)]
-    previous_link_group_ids: Vec<(TrackId, ClipId, Option<String>)>,
-}
+        // 9. Store undo state before modifying
+        self.removed_clips = selected_clips;
+        self.created_sequence_id = Some(inner_sequence_id.clone());
+        self.created_compound_clip_id = Some(compound_clip_id.clone());
 
-impl UnlinkClipsCommand {
-    pub fn new(sequence_id: &str, clip_refs: Vec<(String, String)>) -> Self {
-        Self {
-            sequence_id: sequence_id.to_string(),
-            clip_refs,
-            previous_link_group_ids: Vec::new(),
-        }
-    }
-}
+        // 10. Mutate state: add inner sequence
+        state
+            .sequences
+            .insert(inner_sequence_id.clone(), inner_sequence);
 
-impl Command for UnlinkClipsCommand {
-    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        let sequence = state
+        // 11. Mutate state: remove original clips and add compound clip
+        let sequence_mut = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        let affected_refs = collect_affected_link_refs(sequence, &self.clip_refs)?;
-        validate_clip_refs_unlocked(sequence, &affected_refs)?;
-        self.previous_link_group_ids = capture_link_group_state(sequence, &affected_refs)?;
+        let track_mut = sequence_mut
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
 
-        // Mark clips as explicitly unlinked so legacy implicit pairing does not
-        // immediately re-link them on the frontend.
-        for (track_id, clip_id) in &self.clip_refs {
-            let track = sequence
-                .tracks
-                .iter_mut()
-                .find(|t| &t.id == track_id)
-                .ok_or_else(|| CoreError::TrackNotFound(track_id.clone()))?;
-            let clip = track
-                .clips
-                .iter_mut()
-                .find(|c| &c.id == clip_id)
-                .ok_or_else(|| CoreError::ClipNotFound(clip_id.clone()))?;
-            clip.link_group_id = Some(EXPLICIT_UNLINK_SENTINEL.to_string());
-        }
+        // Remove the original clips
+        track_mut
+            .clips
+            .retain(|c| !selected_clip_id_set.contains(c.id.as_str()));
 
-        let normalized_refs = normalize_degenerate_link_groups(sequence, &affected_refs)?;
+        // Insert compound clip
+        insert_clip_sorted(track_mut, compound_clip);
 
-        let op_id = ulid::Ulid::new().to_string();
-        let mut result = CommandResult::new(&op_id);
-        append_clip_modified_changes(&mut result, &self.clip_refs);
-        append_clip_modified_changes(&mut result, &normalized_refs);
         state.is_dirty = true;
+
+        // 12. Build result
+        let op_id = ulid::Ulid::new().to_string();
+        let mut result = CommandResult::new(&op_id)
+            .with_change(StateChange::SequenceCreated {
+                sequence_id: inner_sequence_id,
+            })
+            .with_change(StateChange::ClipCreated {
+                clip_id: compound_clip_id.clone(),
+            })
+            .with_created_id(&compound_clip_id);
+
+        for clip_id in &self.clip_ids {
+            result = result.with_change(StateChange::ClipDeleted {
+                clip_id: clip_id.clone(),
+            });
+            result = result.with_deleted_id(clip_id);
+        }
+
         Ok(result)
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
+        // 1. Remove the inner sequence
+        if let Some(ref inner_seq_id) = self.created_sequence_id {
+            state.sequences.remove(inner_seq_id);
+        }
 
-        for (track_id, clip_id, prev_link) in &self.previous_link_group_ids {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| &t.id == track_id) {
-                if let Some(clip) = track.clips.iter_mut().find(|c| &c.id == clip_id) {
-                    clip.link_group_id = prev_link.clone();
+        // 2. Remove compound clip and restore original clips
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) {
+                // Remove compound clip
+                if let Some(ref compound_id) = self.created_compound_clip_id {
+                    track.clips.retain(|c| &c.id != compound_id);
+                }
+
+                // Restore original clips
+                for clip in &self.removed_clips {
+                    insert_clip_sorted(track, clip.clone());
                 }
             }
         }
 
+        state.is_dirty = true;
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "UnlinkClips"
+        "CreateCompoundClip"
     }
 
     fn to_json(&self) -> serde_json::Value {
         serde_json::json!({
             "sequenceId": self.sequence_id,
-            "clipRefs": self.clip_refs.iter().map(|(t, c)| serde_json::json!({"trackId": t, "clipId": c})).collect::<Vec<_>>(),
+            "trackId": self.track_id,
+            "clipIds": self.clip_ids,
+            "name": self.name,
         })
     }
 }
 
-// =============================================================================
-// DetachAudioCommand
-// =============================================================================
-
-/// Command to detach audio from a video clip, creating a separate audio clip
-/// on a designated audio track. Both clips remain independent (unlinked).
+/// Command to unnest a compound clip, restoring its inner clips to the parent timeline.
+///
+/// The compound clip is replaced with the clips from its inner sequence,
+/// repositioned to the compound clip's timeline position.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct DetachAudioCommand {
+pub struct UnnestCompoundClipCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
     pub clip_id: ClipId,
-    /// Optional target audio track ID. If None, uses first available audio track or creates one.
-    pub target_audio_track_id: Option<TrackId>,
 
     #[serde(skip)]
-    created_audio_clip_id: Option<ClipId>,
+    removed_compound_clip: Option<Clip>,
     #[serde(skip)]
-    created_audio_track_id: Option<TrackId>,
+    removed_inner_sequence: Option<crate::core::timeline::Sequence>,
     #[serde(skip)]
-    previous_link_group_ids: Vec<(TrackId, ClipId, Option<String>)>,
+    restored_clip_ids: Vec<ClipId>,
 }
 
-impl DetachAudioCommand {
-    pub fn new(
-        sequence_id: &str,
-        track_id: &str,
-        clip_id: &str,
-        target_audio_track_id: Option<String>,
-    ) -> Self {
+impl UnnestCompoundClipCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
             clip_id: clip_id.to_string(),
-            target_audio_track_id,
-            created_audio_clip_id: None,
-            created_audio_track_id: None,
-            previous_link_group_ids: Vec::new(),
+            removed_compound_clip: None,
+            removed_inner_sequence: None,
+            restored_clip_ids: Vec::new(),
         }
     }
 }
 
-impl Command for DetachAudioCommand {
+impl Command for UnnestCompoundClipCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        use crate::core::timeline::TrackKind;
-
+        // 1. Validate and get compound clip
         let sequence = state
             .sequences
-            .get_mut(&self.sequence_id)
+            .get(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        // Validate source track is video/overlay (has visual content)
-        let source_track = sequence
+        let track = sequence
             .tracks
             .iter()
             .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        validate_track_unlocked(source_track)?;
 
-        if !source_track.is_video() {
-            return Err(CoreError::ValidationError(
-                "Detach Audio is only available for clips on video/overlay tracks".to_string(),
-            ));
-        }
+        validate_track_unlocked(track)?;
 
-        // Find the source clip and create the audio clip
-        let source_clip = source_track
+        let compound_clip = track
             .clips
             .iter()
             .find(|c| c.id == self.clip_id)
             .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
 
-        let affected_refs =
-            collect_affected_link_refs(sequence, &[(self.track_id.clone(), self.clip_id.clone())])?;
-        validate_clip_refs_unlocked(sequence, &affected_refs)?;
-        self.previous_link_group_ids = capture_link_group_state(sequence, &affected_refs)?;
+        // 2. Validate it is a compound clip
+        let inner_seq_id = compound_clip
+            .compound_sequence_id
+            .as_ref()
+            .ok_or_else(|| {
+                CoreError::ValidationError(format!(
+                    "Clip '{}' is not a compound clip",
+                    self.clip_id
+                ))
+            })?
+            .clone();
 
-        // Create audio clip with same timing, range, speed, time remap, and audio settings
-        let mut audio_clip = Clip::new(&source_clip.asset_id);
-        audio_clip.range = source_clip.range.clone();
-        audio_clip.place = source_clip.place.clone();
-        audio_clip.speed = source_clip.speed;
-        audio_clip.reverse = source_clip.reverse;
-        audio_clip.audio = source_clip.audio.clone();
-        audio_clip.time_remap = source_clip.time_remap.clone();
-        audio_clip.link_group_id = Some(EXPLICIT_UNLINK_SENTINEL.to_string());
-        let audio_clip_id = audio_clip.id.clone();
+        let compound_start = compound_clip.place.timeline_in_sec;
 
-        // Determine target audio track
-        let target_track_id = if let Some(ref given_id) = self.target_audio_track_id {
-            // Validate the given track is audio
-            let target = sequence
-                .tracks
+        // 3. Get inner sequence clips
+        let inner_sequence = state
+            .sequences
+            .get(&inner_seq_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(inner_seq_id.clone()))?;
+
+        let populated_inner_tracks: Vec<&crate::core::timeline::Track> = inner_sequence
+            .tracks
+            .iter()
+            .filter(|inner_track| !inner_track.clips.is_empty())
+            .collect();
+        if populated_inner_tracks.len() > 1
+            || populated_inner_tracks
                 .iter()
-                .find(|t| &t.id == given_id)
-                .ok_or_else(|| CoreError::TrackNotFound(given_id.clone()))?;
-            if !target.is_audio() {
-                return Err(CoreError::ValidationError(
-                    "Target track must be an audio track".to_string(),
-                ));
-            }
-            validate_track_unlocked(target)?;
-            given_id.clone()
-        } else {
-            // Find the first unlocked audio track that can accept the clip,
-            // or create one if every existing lane would overlap.
-            if let Some(audio_track) = sequence.tracks.iter().find(|t| {
-                t.kind == TrackKind::Audio
-                    && !t.locked
-                    && validate_no_overlap(t, &audio_clip.place, None).is_ok()
-            }) {
-                audio_track.id.clone()
-            } else {
-                // Create a new audio track
-                let new_track = Track::new_audio("Audio (Detached)");
-                let new_track_id = new_track.id.clone();
-                sequence.tracks.push(new_track);
-                self.created_audio_track_id = Some(new_track_id.clone());
-                new_track_id
+                .any(|inner_track| inner_track.kind != track.kind)
+        {
+            return Err(CoreError::ValidationError(
+                "UnnestCompoundClip currently supports only a single populated inner track matching the parent track kind".to_string(),
+            ));
+        }
+
+        // Collect all clips from all tracks in the inner sequence
+        let mut inner_clips: Vec<Clip> = Vec::new();
+        for inner_track in &inner_sequence.tracks {
+            for clip in &inner_track.clips {
+                let mut restored_clip = clip.clone();
+                // Adjust positions back to parent timeline
+                restored_clip.place.timeline_in_sec += compound_start;
+                inner_clips.push(restored_clip);
             }
-        };
+        }
 
-        // Mark the source clip as explicitly unlinked so it stays detached from
-        // any legacy implicit A/V pairing.
-        let source_track_mut = sequence
+        let restored_ids: Vec<ClipId> = inner_clips.iter().map(|c| c.id.clone()).collect();
+
+        // 4. Store undo state
+        self.removed_compound_clip = Some(compound_clip.clone());
+        self.removed_inner_sequence = Some(inner_sequence.clone());
+        self.restored_clip_ids = restored_ids.clone();
+
+        // 5. Mutate state: remove inner sequence
+        state.sequences.remove(&inner_seq_id);
+
+        // 6. Mutate state: replace compound clip with inner clips
+        let sequence_mut = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let track_mut = sequence_mut
             .tracks
             .iter_mut()
             .find(|t| t.id == self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-        let source_clip_mut = source_track_mut
-            .clips
-            .iter_mut()
-            .find(|c| c.id == self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
-        source_clip_mut.link_group_id = Some(EXPLICIT_UNLINK_SENTINEL.to_string());
 
-        // Validate no overlap on target track before inserting
-        let target_track = sequence
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == target_track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(target_track_id.clone()))?;
-        validate_no_overlap(target_track, &audio_clip.place, None)?;
-        target_track.clips.push(audio_clip);
-        sort_track_clips(target_track);
+        // Remove compound clip
+        track_mut.clips.retain(|c| c.id != self.clip_id);
 
-        self.created_audio_clip_id = Some(audio_clip_id.clone());
-        let normalized_refs = normalize_degenerate_link_groups(sequence, &affected_refs)?;
+        // Insert restored clips
+        for clip in inner_clips {
+            insert_clip_sorted(track_mut, clip);
+        }
+
+        state.is_dirty = true;
 
+        // 7. Build result
         let op_id = ulid::Ulid::new().to_string();
-        let mut result = CommandResult::new(&op_id)
-            .with_change(StateChange::ClipCreated {
-                clip_id: audio_clip_id.clone(),
-            })
-            .with_created_id(&audio_clip_id);
-        append_clip_modified_changes(
-            &mut result,
-            &[(self.track_id.clone(), self.clip_id.clone())],
-        );
-        append_clip_modified_changes(&mut result, &normalized_refs);
+        let mut result = CommandResult::new(&op_id).with_change(StateChange::ClipDeleted {
+            clip_id: self.clip_id.clone(),
+        });
+
+        for clip_id in &restored_ids {
+            result = result
+                .with_change(StateChange::ClipCreated {
+                    clip_id: clip_id.clone(),
+                })
+                .with_created_id(clip_id);
+        }
+
         Ok(result)
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
-
-        // Remove the created audio clip
-        if let Some(ref audio_clip_id) = self.created_audio_clip_id {
-            for track in &mut sequence.tracks {
-                track.clips.retain(|c| &c.id != audio_clip_id);
-            }
+        // 1. Restore inner sequence
+        if let Some(ref inner_seq) = self.removed_inner_sequence {
+            state
+                .sequences
+                .insert(inner_seq.id.clone(), inner_seq.clone());
         }
 
-        // Remove created audio track if we created one
-        if let Some(ref created_track_id) = self.created_audio_track_id {
-            sequence.tracks.retain(|t| &t.id != created_track_id);
-        }
+        // 2. Remove restored clips and re-add compound clip
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) {
+                // Remove the restored clips
+                let restored_set: std::collections::HashSet<&str> =
+                    self.restored_clip_ids.iter().map(|s| s.as_str()).collect();
+                track
+                    .clips
+                    .retain(|c| !restored_set.contains(c.id.as_str()));
 
-        for (track_id, clip_id, prev_link) in &self.previous_link_group_ids {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| &t.id == track_id) {
-                if let Some(clip) = track.clips.iter_mut().find(|c| &c.id == clip_id) {
-                    clip.link_group_id = prev_link.clone();
+                // Restore compound clip
+                if let Some(ref compound_clip) = self.removed_compound_clip {
+                    insert_clip_sorted(track, compound_clip.clone());
                 }
             }
         }
 
+        state.is_dirty = true;
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "DetachAudio"
+        "UnnestCompoundClip"
     }
 
     fn to_json(&self) -> serde_json::Value {
@@ -6405,338 +7626,549 @@ impl Command for DetachAudioCommand {
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
             "clipId": self.clip_id,
-            "targetAudioTrackId": self.target_audio_track_id,
         })
     }
 }
 
 // =============================================================================
-// ApplyAudioDuckingCommand
+// Multicam Clip Commands
 // =============================================================================
 
-/// Atomically sets pre-computed duck keyframes on a music clip.
+/// Finds a multicam clip and its inner sequence of angles.
+fn find_multicam_clip<'a>(
+    state: &'a ProjectState,
+    sequence_id: &str,
+    track_id: &str,
+    clip_id: &str,
+) -> CoreResult<(&'a Track, &'a Clip, &'a crate::core::timeline::Sequence)> {
+    let sequence = state
+        .sequences
+        .get(sequence_id)
+        .ok_or_else(|| CoreError::SequenceNotFound(sequence_id.to_string()))?;
+    let track = sequence
+        .get_track(track_id)
+        .ok_or_else(|| CoreError::TrackNotFound(track_id.to_string()))?;
+    validate_track_unlocked(track)?;
+    let clip = track
+        .get_clip(clip_id)
+        .ok_or_else(|| CoreError::ClipNotFound(clip_id.to_string()))?;
+    let inner_sequence_id = clip
+        .compound_sequence_id
+        .as_deref()
+        .filter(|_| clip.is_multicam())
+        .ok_or_else(|| {
+            CoreError::ValidationError(format!("Clip '{}' is not a multicam clip", clip_id))
+        })?;
+    let inner = state
+        .sequences
+        .get(inner_sequence_id)
+        .ok_or_else(|| CoreError::SequenceNotFound(inner_sequence_id.to_string()))?;
+    Ok((track, clip, inner))
+}
+
+/// Command to group synced angle clips into a multicam clip.
 ///
-/// The IPC layer performs the speech analysis (FFmpeg silencedetect) and
-/// keyframe generation (via `ducking::generate_duck_keyframes`).  This
-/// command only handles the state mutation so that undo/redo works as a
-/// single operation.
+/// Each video track holding selected clips becomes one angle: a video track in
+/// a new inner sequence, keeping the clips' sync. The selected clips are
+/// replaced by a single multicam clip on `track_id`, showing the angle that
+/// came from that track (or the first angle). Audio such as a lav stays on the
+/// parent timeline.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct ApplyAudioDuckingCommand {
+pub struct CreateMulticamClipCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
-    pub clip_id: ClipId,
-    /// Pre-computed ducking keyframes to apply
-    pub keyframes: Vec<AudioKeyframe>,
-    /// Stored for undo — previous volume keyframes on the clip
+    pub clip_ids: Vec<ClipId>,
+    pub name: Option<String>,
+
     #[serde(skip)]
-    previous_keyframes: Option<Vec<AudioKeyframe>>,
+    created_sequence_id: Option<SequenceId>,
+    #[serde(skip)]
+    created_multicam_clip_id: Option<ClipId>,
+    #[serde(skip)]
+    removed_clips: Vec<(TrackId, Clip)>,
 }
 
-impl ApplyAudioDuckingCommand {
-    pub fn new(
-        sequence_id: &str,
-        track_id: &str,
-        clip_id: &str,
-        keyframes: Vec<AudioKeyframe>,
-    ) -> Self {
+impl CreateMulticamClipCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_ids: Vec<String>) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
-            clip_id: clip_id.to_string(),
-            keyframes,
-            previous_keyframes: None,
+            clip_ids,
+            name: None,
+            created_sequence_id: None,
+            created_multicam_clip_id: None,
+            removed_clips: Vec::new(),
         }
     }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
 }
 
-impl Command for ApplyAudioDuckingCommand {
+impl Command for CreateMulticamClipCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let mut unique_ids: Vec<ClipId> = self.clip_ids.clone();
+        unique_ids.sort();
+        unique_ids.dedup();
+        self.clip_ids = unique_ids;
+
         let sequence = state
             .sequences
-            .get_mut(&self.sequence_id)
+            .get(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-        let track = sequence
-            .get_track_mut(&self.track_id)
+        let target_track = sequence
+            .get_track(&self.track_id)
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        validate_track_unlocked(target_track)?;
+        if target_track.kind != TrackKind::Video {
+            return Err(CoreError::ValidationError(format!(
+                "CreateMulticamClip must place the multicam clip on a video track; '{}' is {}",
+                target_track.id,
+                track_kind_label(&target_track.kind)
+            )));
+        }
 
-        if track.locked {
-            return Err(CoreError::InvalidCommand(
-                "Cannot apply ducking to a locked track".to_string(),
+        // 1. Group the selection by source track, in track order: one angle each.
+        let selected: HashSet<&str> = self.clip_ids.iter().map(String::as_str).collect();
+        let mut angles: Vec<(&Track, Vec<Clip>)> = Vec::new();
+        for track in &sequence.tracks {
+            let clips: Vec<Clip> = track
+                .clips
+                .iter()
+                .filter(|clip| selected.contains(clip.id.as_str()))
+                .cloned()
+                .collect();
+            if clips.is_empty() {
+                continue;
+            }
+            if track.kind != TrackKind::Video {
+                return Err(CoreError::ValidationError(format!(
+                    "Multicam angles must be video clips; clip '{}' is on {} track '{}'",
+                    clips[0].id,
+                    track_kind_label(&track.kind),
+                    track.name
+                )));
+            }
+            validate_track_unlocked(track)?;
+            if let Some(retimed) = clips
+                .iter()
+                .find(|clip| !crate::core::timeline::plays_at_normal_speed(clip))
+            {
+                return Err(CoreError::ValidationError(format!(
+                    "Angle clip '{}' is retimed; multicam angles must play at normal speed",
+                    retimed.id
+                )));
+            }
+            angles.push((track, clips));
+        }
+
+        let found: usize = angles.iter().map(|(_, clips)| clips.len()).sum();
+        if found != self.clip_ids.len() {
+            let missing = self
+                .clip_ids
+                .iter()
+                .find(|clip_id| {
+                    !angles
+                        .iter()
+                        .any(|(_, clips)| clips.iter().any(|clip| &clip.id == *clip_id))
+                })
+                .cloned()
+                .unwrap_or_default();
+            return Err(CoreError::ClipNotFound(missing));
+        }
+        if angles.len() < 2 {
+            return Err(CoreError::ValidationError(
+                "CreateMulticamClip requires clips on at least 2 video tracks, one per angle"
+                    .to_string(),
             ));
         }
 
-        let clip = track
-            .get_clip_mut(&self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
+        // 2. The multicam clip spans every angle.
+        let all_clips = || angles.iter().flat_map(|(_, clips)| clips.iter());
+        let span_start = all_clips()
+            .map(|clip| clip.place.timeline_in_sec)
+            .fold(f64::INFINITY, f64::min);
+        let span_end = all_clips()
+            .map(|clip| clip.timeline_end())
+            .fold(f64::NEG_INFINITY, f64::max);
+        let duration = span_end - span_start;
+        if !duration.is_finite() || duration <= 0.0 {
+            return Err(CoreError::ValidationError(
+                "Selected clips have zero, negative, or invalid total duration".to_string(),
+            ));
+        }
+        if let Some(conflict) = target_track.clips.iter().find(|clip| {
+            !selected.contains(clip.id.as_str())
+                && clip.place.timeline_in_sec < span_end
+                && clip.timeline_end() > span_start
+        }) {
+            return Err(CoreError::ClipOverlap {
+                track_id: target_track.id.clone(),
+                existing_clip_id: conflict.id.clone(),
+                new_start: span_start,
+                new_end: span_end,
+            });
+        }
 
-        // Store previous keyframes for undo
-        self.previous_keyframes = Some(clip.audio.volume_keyframes.clone());
+        // 3. Build the inner sequence, one video track per angle.
+        let name = self
+            .name
+            .clone()
+            .unwrap_or_else(|| "Multicam Clip".to_string());
+        let mut inner_sequence =
+            crate::core::timeline::Sequence::new(&name, sequence.format.clone());
+        let mut active_angle = None;
+        let mut removed_clips = Vec::new();
+        for (source_track, clips) in &angles {
+            let mut angle_track = Track::new_video(&source_track.name);
+            if source_track.id == self.track_id || active_angle.is_none() {
+                active_angle = Some(angle_track.id.clone());
+            }
+            for clip in clips {
+                let mut inner_clip = clip.clone();
+                inner_clip.place.timeline_in_sec -= span_start;
+                insert_clip_sorted(&mut angle_track, inner_clip);
+                removed_clips.push((source_track.id.clone(), clip.clone()));
+            }
+            inner_sequence.add_track(angle_track);
+        }
+        let active_angle = active_angle
+            .ok_or_else(|| CoreError::Internal("CreateMulticamClip built no angles".to_string()))?;
 
-        // Apply the pre-computed duck keyframes
-        clip.audio.volume_keyframes = self.keyframes.clone();
+        let inner_sequence_id = inner_sequence.id.clone();
+        let multicam_clip =
+            Clip::multicam(&inner_sequence_id, duration, &active_angle).place_at(span_start);
+        let multicam_clip_id = multicam_clip.id.clone();
+
+        // 4. Apply.
+        state
+            .sequences
+            .insert(inner_sequence_id.clone(), inner_sequence);
+        let sequence_mut = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        for track in &mut sequence_mut.tracks {
+            track
+                .clips
+                .retain(|clip| !selected.contains(clip.id.as_str()));
+        }
+        let target_mut = sequence_mut
+            .get_track_mut(&self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        insert_clip_sorted(target_mut, multicam_clip);
+        state.is_dirty = true;
+
+        self.created_sequence_id = Some(inner_sequence_id.clone());
+        self.created_multicam_clip_id = Some(multicam_clip_id.clone());
+        self.removed_clips = removed_clips;
 
         let op_id = ulid::Ulid::new().to_string();
-        Ok(
-            CommandResult::new(&op_id).with_change(StateChange::ClipModified {
-                clip_id: self.clip_id.clone(),
-            }),
-        )
+        let mut result = CommandResult::new(&op_id)
+            .with_change(StateChange::SequenceCreated {
+                sequence_id: inner_sequence_id,
+            })
+            .with_change(StateChange::ClipCreated {
+                clip_id: multicam_clip_id.clone(),
+            })
+            .with_created_id(&multicam_clip_id);
+        for (_, clip) in &self.removed_clips {
+            result = result
+                .with_change(StateChange::ClipDeleted {
+                    clip_id: clip.id.clone(),
+                })
+                .with_deleted_id(&clip.id);
+        }
+
+        Ok(result)
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        let Some(previous) = &self.previous_keyframes else {
-            return Ok(());
-        };
+        if let Some(ref inner_sequence_id) = self.created_sequence_id {
+            state.sequences.remove(inner_sequence_id);
+        }
 
-        let Some(sequence) = state.sequences.get_mut(&self.sequence_id) else {
-            return Ok(());
-        };
-        let Some(track) = sequence.get_track_mut(&self.track_id) else {
-            return Ok(());
-        };
-        if let Some(clip) = track.get_clip_mut(&self.clip_id) {
-            clip.audio.volume_keyframes = previous.clone();
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            if let Some(ref multicam_id) = self.created_multicam_clip_id {
+                if let Some(track) = sequence.get_track_mut(&self.track_id) {
+                    track.clips.retain(|clip| &clip.id != multicam_id);
+                }
+            }
+            for (track_id, clip) in &self.removed_clips {
+                if let Some(track) = sequence.get_track_mut(track_id) {
+                    insert_clip_sorted(track, clip.clone());
+                }
+            }
         }
 
+        state.is_dirty = true;
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "ApplyAudioDucking"
+        "CreateMulticamClip"
     }
 
     fn to_json(&self) -> serde_json::Value {
         serde_json::json!({
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
-            "clipId": self.clip_id,
-            "keyframeCount": self.keyframes.len(),
+            "clipIds": self.clip_ids,
+            "name": self.name,
         })
     }
 }
 
-// =============================================================================
-// Compound Clip Commands
-// =============================================================================
-
-/// Command to create a compound clip by nesting selected clips into a new sequence.
+/// Command to cut a multicam clip to another angle.
 ///
-/// Selected clips are moved into a new inner sequence and replaced with a single
-/// compound clip that references the inner sequence.
+/// With `at_sec` inside the clip, the clip is split there and the later part
+/// shows the new angle, like a live switch. Without it (or at the clip's
+/// start) the whole clip changes angle.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateCompoundClipCommand {
+pub struct SwitchMulticamAngleCommand {
     pub sequence_id: SequenceId,
     pub track_id: TrackId,
-    pub clip_ids: Vec<ClipId>,
-    pub name: Option<String>,
+    pub clip_id: ClipId,
+    /// Inner video track of the angle to show
+    pub angle_track_id: TrackId,
+    /// Timeline time of the cut; `None` switches the whole clip
+    pub at_sec: Option<TimeSec>,
 
     #[serde(skip)]
-    created_sequence_id: Option<SequenceId>,
-    #[serde(skip)]
-    created_compound_clip_id: Option<ClipId>,
+    original_clip: Option<Clip>,
     #[serde(skip)]
-    removed_clips: Vec<Clip>,
+    created_clip_id: Option<ClipId>,
 }
 
-impl CreateCompoundClipCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_ids: Vec<String>) -> Self {
+impl SwitchMulticamAngleCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str, angle_track_id: &str) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
             track_id: track_id.to_string(),
-            clip_ids,
-            name: None,
-            created_sequence_id: None,
-            created_compound_clip_id: None,
-            removed_clips: Vec::new(),
+            clip_id: clip_id.to_string(),
+            angle_track_id: angle_track_id.to_string(),
+            at_sec: None,
+            original_clip: None,
+            created_clip_id: None,
         }
     }
 
-    pub fn with_name(mut self, name: &str) -> Self {
-        self.name = Some(name.to_string());
+    pub fn at(mut self, at_sec: TimeSec) -> Self {
+        self.at_sec = Some(at_sec);
         self
     }
 }
 
-impl Command for CreateCompoundClipCommand {
+impl Command for SwitchMulticamAngleCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        // 1. Deduplicate and validate inputs
-        let mut unique_ids: Vec<ClipId> = self.clip_ids.clone();
-        unique_ids.sort();
-        unique_ids.dedup();
-        self.clip_ids = unique_ids;
-
-        if self.clip_ids.is_empty() {
-            return Err(CoreError::ValidationError(
-                "CreateCompoundClip requires at least 1 clip".to_string(),
-            ));
+        let (track, original, inner) =
+            find_multicam_clip(state, &self.sequence_id, &self.track_id, &self.clip_id)?;
+        if !crate::core::timeline::multicam_angles(inner)
+            .any(|angle| angle.id == self.angle_track_id)
+        {
+            return Err(CoreError::ValidationError(format!(
+                "Angle '{}' is not a video track of multicam clip '{}'",
+                self.angle_track_id, self.clip_id
+            )));
+        }
+        if original.multicam_angle.as_deref() == Some(self.angle_track_id.as_str()) {
+            return Err(CoreError::ValidationError(format!(
+                "Angle '{}' is already showing on multicam clip '{}'",
+                self.angle_track_id, self.clip_id
+            )));
         }
 
-        // 2. Get source sequence and validate track
-        let sequence = state
-            .sequences
-            .get(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let clip_start = original.place.timeline_in_sec;
+        let clip_end = original.timeline_end();
+        let split_at = match self.at_sec {
+            Some(at) if !is_valid_time_sec(at) => return Err(CoreError::InvalidSplitPoint(at)),
+            Some(at) if (at - clip_start).abs() <= EDIT_POINT_EPSILON => None,
+            Some(at) if at <= clip_start || at >= clip_end - EDIT_POINT_EPSILON => {
+                return Err(CoreError::InvalidSplitPoint(at))
+            }
+            at => at,
+        };
 
-        let track = sequence
-            .tracks
-            .iter()
-            .find(|t| t.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        let mut first = original.clone();
+        let second = match split_at {
+            None => {
+                first.multicam_angle = Some(self.angle_track_id.clone());
+                None
+            }
+            Some(at) => {
+                let relative_split = at - clip_start;
+                let ((first_in, first_out), (second_in, second_out)) =
+                    split_clip_ranges_at(original, at);
+                let mut second = clone_clip_fragment_with_rebased_time_remap(
+                    original,
+                    second_in,
+                    second_out,
+                    at,
+                    clip_end - at,
+                    relative_split,
+                );
+                second.multicam_angle = Some(self.angle_track_id.clone());
+                first.range.source_in_sec = first_in;
+                first.range.source_out_sec = first_out;
+                first.place.duration_sec = relative_split;
+                rebase_clip_time_remap_for_fragment(&mut first, 0.0, relative_split);
+                validate_no_overlap(track, &second.place, Some(&first.id))?;
+                Some(second)
+            }
+        };
 
-        validate_track_unlocked(track)?;
+        self.original_clip = Some(original.clone());
+        self.created_clip_id = second.as_ref().map(|clip| clip.id.clone());
 
-        // 3. Collect selected clips and validate they all exist
-        let mut selected_clips: Vec<Clip> = Vec::new();
-        for clip_id in &self.clip_ids {
-            let clip = track
-                .clips
-                .iter()
-                .find(|c| c.id == *clip_id)
-                .ok_or_else(|| CoreError::ClipNotFound(clip_id.clone()))?;
-            selected_clips.push(clip.clone());
+        let track_mut = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .and_then(|sequence| sequence.get_track_mut(&self.track_id))
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+        if let Some(clip) = track_mut.clips.iter_mut().find(|c| c.id == self.clip_id) {
+            *clip = first;
         }
+        let op_id = ulid::Ulid::new().to_string();
+        let mut result = CommandResult::new(&op_id).with_change(StateChange::ClipModified {
+            clip_id: self.clip_id.clone(),
+        });
+        if let Some(second) = second {
+            let second_id = second.id.clone();
+            insert_clip_sorted(track_mut, second);
+            result = result
+                .with_change(StateChange::ClipCreated {
+                    clip_id: second_id.clone(),
+                })
+                .with_created_id(&second_id);
+        }
+        state.is_dirty = true;
 
-        // 4. Compute the time span of selected clips
-        let span_start = selected_clips
-            .iter()
-            .map(|c| c.place.timeline_in_sec)
-            .fold(f64::INFINITY, f64::min);
-        let span_end = selected_clips
-            .iter()
-            .map(|c| c.place.timeline_out_sec())
-            .fold(f64::NEG_INFINITY, f64::max);
-        let compound_duration = span_end - span_start;
+        Ok(result)
+    }
 
-        if !compound_duration.is_finite() || compound_duration <= 0.0 {
-            return Err(CoreError::ValidationError(
-                "Selected clips have zero, negative, or invalid total duration".to_string(),
-            ));
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(original) = &self.original_clip else {
+            return Ok(());
+        };
+        if let Some(track) = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .and_then(|sequence| sequence.get_track_mut(&self.track_id))
+        {
+            if let Some(created_id) = &self.created_clip_id {
+                track.clips.retain(|clip| &clip.id != created_id);
+            }
+            if let Some(clip) = track.clips.iter_mut().find(|c| c.id == self.clip_id) {
+                *clip = original.clone();
+            }
+            sort_track_clips(track);
         }
 
-        let selected_clip_id_set: std::collections::HashSet<&str> = self
-            .clip_ids
-            .iter()
-            .map(|clip_id| clip_id.as_str())
-            .collect();
-        if let Some(conflicting_clip) = track.clips.iter().find(|clip| {
-            !selected_clip_id_set.contains(clip.id.as_str())
-                && clip.place.timeline_in_sec < span_end
-                && clip.place.timeline_out_sec() > span_start
-        }) {
-            return Err(CoreError::ValidationError(format!(
-                "CreateCompoundClip requires selecting all clips within the nested span; clip '{}' overlaps the selection window",
-                conflicting_clip.id
-            )));
-        }
+        state.is_dirty = true;
+        Ok(())
+    }
 
-        // 5. Create inner sequence with same format
-        let seq_format = sequence.format.clone();
-        let compound_name = self
-            .name
-            .clone()
-            .unwrap_or_else(|| "Compound Clip".to_string());
-        let mut inner_sequence = crate::core::timeline::Sequence::new(&compound_name, seq_format);
+    fn type_name(&self) -> &'static str {
+        "SwitchMulticamAngle"
+    }
 
-        // 6. Create a track in the inner sequence matching the source track kind
-        let inner_track = crate::core::timeline::Track::new(&track.name, track.kind.clone());
-        let inner_track_id = inner_track.id.clone();
-        inner_sequence.add_track(inner_track);
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "trackId": self.track_id,
+            "clipId": self.clip_id,
+            "angleTrackId": self.angle_track_id,
+            "atSec": self.at_sec,
+        })
+    }
+}
 
-        // 7. Move clips to inner sequence (adjust positions relative to span_start)
-        let inner_track_mut = inner_sequence
-            .get_track_mut(&inner_track_id)
-            .ok_or_else(|| {
-                CoreError::Internal(format!(
-                    "Inner track '{}' not found after creation",
-                    inner_track_id
-                ))
-            })?;
-        for clip in &selected_clips {
-            let mut inner_clip = clip.clone();
-            inner_clip.place.timeline_in_sec -= span_start;
-            insert_clip_sorted(inner_track_mut, inner_clip);
-        }
+/// Command to flatten a multicam clip into the clips of its active angle.
+///
+/// The multicam clip is replaced by the angle's media, cut to the clip's
+/// window (see [`crate::core::timeline::resolve_multicam_angle`]). The inner
+/// sequence is kept, since other cuts of the same multicam clip still use it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlattenMulticamClipCommand {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
 
-        let inner_sequence_id = inner_sequence.id.clone();
+    #[serde(skip)]
+    removed_multicam_clip: Option<Clip>,
+    #[serde(skip)]
+    created_clip_ids: Vec<ClipId>,
+}
 
-        // 8. Create compound clip at the original span position
-        let compound_clip =
-            Clip::compound(&inner_sequence_id, compound_duration).place_at(span_start);
-        let compound_clip_id = compound_clip.id.clone();
+impl FlattenMulticamClipCommand {
+    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            clip_id: clip_id.to_string(),
+            removed_multicam_clip: None,
+            created_clip_ids: Vec::new(),
+        }
+    }
+}
 
-        // 9. Store undo state before modifying
-        self.removed_clips = selected_clips;
-        self.created_sequence_id = Some(inner_sequence_id.clone());
-        self.created_compound_clip_id = Some(compound_clip_id.clone());
+impl Command for FlattenMulticamClipCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let (_, multicam_clip, inner) =
+            find_multicam_clip(state, &self.sequence_id, &self.track_id, &self.clip_id)?;
+        let mut flattened = crate::core::timeline::resolve_multicam_angle(multicam_clip, inner)?;
+        for clip in &mut flattened {
+            clip.id = ulid::Ulid::new().to_string();
+        }
 
-        // 10. Mutate state: add inner sequence
-        state
-            .sequences
-            .insert(inner_sequence_id.clone(), inner_sequence);
+        self.removed_multicam_clip = Some(multicam_clip.clone());
+        self.created_clip_ids = flattened.iter().map(|clip| clip.id.clone()).collect();
 
-        // 11. Mutate state: remove original clips and add compound clip
-        let sequence_mut = state
+        let track_mut = state
             .sequences
             .get_mut(&self.sequence_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
-
-        let track_mut = sequence_mut
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
+            .and_then(|sequence| sequence.get_track_mut(&self.track_id))
             .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
-        // Remove the original clips
-        track_mut
-            .clips
-            .retain(|c| !selected_clip_id_set.contains(c.id.as_str()));
-
-        // Insert compound clip
-        insert_clip_sorted(track_mut, compound_clip);
-
+        track_mut.clips.retain(|clip| clip.id != self.clip_id);
+        for clip in flattened {
+            insert_clip_sorted(track_mut, clip);
+        }
         state.is_dirty = true;
 
-        // 12. Build result
         let op_id = ulid::Ulid::new().to_string();
         let mut result = CommandResult::new(&op_id)
-            .with_change(StateChange::SequenceCreated {
-                sequence_id: inner_sequence_id,
-            })
-            .with_change(StateChange::ClipCreated {
-                clip_id: compound_clip_id.clone(),
-            })
-            .with_created_id(&compound_clip_id);
-
-        for clip_id in &self.clip_ids {
-            result = result.with_change(StateChange::ClipDeleted {
-                clip_id: clip_id.clone(),
-            });
-            result = result.with_deleted_id(clip_id);
+            .with_change(StateChange::ClipDeleted {
+                clip_id: self.clip_id.clone(),
+            })
+            .with_deleted_id(&self.clip_id);
+        for clip_id in &self.created_clip_ids {
+            result = result
+                .with_change(StateChange::ClipCreated {
+                    clip_id: clip_id.clone(),
+                })
+                .with_created_id(clip_id);
         }
 
         Ok(result)
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        // 1. Remove the inner sequence
-        if let Some(ref inner_seq_id) = self.created_sequence_id {
-            state.sequences.remove(inner_seq_id);
-        }
-
-        // 2. Remove compound clip and restore original clips
-        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) {
-                // Remove compound clip
-                if let Some(ref compound_id) = self.created_compound_clip_id {
-                    track.clips.retain(|c| &c.id != compound_id);
-                }
-
-                // Restore original clips
-                for clip in &self.removed_clips {
-                    insert_clip_sorted(track, clip.clone());
-                }
+        if let Some(track) = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .and_then(|sequence| sequence.get_track_mut(&self.track_id))
+        {
+            let created: HashSet<&str> = self.created_clip_ids.iter().map(String::as_str).collect();
+            track
+                .clips
+                .retain(|clip| !created.contains(clip.id.as_str()));
+            if let Some(multicam_clip) = &self.removed_multicam_clip {
+                insert_clip_sorted(track, multicam_clip.clone());
             }
         }
 
@@ -6745,207 +8177,253 @@ impl Command for CreateCompoundClipCommand {
     }
 
     fn type_name(&self) -> &'static str {
-        "CreateCompoundClip"
+        "FlattenMulticamClip"
     }
 
     fn to_json(&self) -> serde_json::Value {
         serde_json::json!({
             "sequenceId": self.sequence_id,
             "trackId": self.track_id,
-            "clipIds": self.clip_ids,
-            "name": self.name,
+            "clipId": self.clip_id,
         })
     }
 }
 
-/// Command to unnest a compound clip, restoring its inner clips to the parent timeline.
-///
-/// The compound clip is replaced with the clips from its inner sequence,
-/// repositioned to the compound clip's timeline position.
+// =============================================================================
+// SyncClipsCommand
+// =============================================================================
+
+/// A clip to line up with the reference clip, and where its media starts on
+/// the reference media's clock (see
+/// [`crate::core::analysis::audio_sync::AssetSyncOffset`]).
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct UnnestCompoundClipCommand {
-    pub sequence_id: SequenceId,
+pub struct SyncClipOffset {
     pub track_id: TrackId,
     pub clip_id: ClipId,
+    /// Reference-media time at which this clip's media starts
+    pub offset_sec: TimeSec,
+}
+
+/// Command to move clips so their media plays in sync with a reference clip.
+///
+/// Each clip keeps its source range and moves to where that range happened
+/// on the reference's clock; clips linked to it (such as a camera's own
+/// audio) move by the same amount. With `link`, the reference, the synced
+/// clips and their linked clips end up in one link group.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncClipsCommand {
+    pub sequence_id: SequenceId,
+    pub reference_track_id: TrackId,
+    pub reference_clip_id: ClipId,
+    pub clips: Vec<SyncClipOffset>,
+    /// Link the synced clips to the reference
+    pub link: bool,
+    pub(crate) link_group_id: String,
 
     #[serde(skip)]
-    removed_compound_clip: Option<Clip>,
-    #[serde(skip)]
-    removed_inner_sequence: Option<crate::core::timeline::Sequence>,
+    previous_spans: Vec<ClipSpan>,
     #[serde(skip)]
-    restored_clip_ids: Vec<ClipId>,
+    previous_link_group_ids: Vec<(TrackId, ClipId, Option<String>)>,
 }
 
-impl UnnestCompoundClipCommand {
-    pub fn new(sequence_id: &str, track_id: &str, clip_id: &str) -> Self {
+impl SyncClipsCommand {
+    pub fn new(
+        sequence_id: &str,
+        reference_track_id: &str,
+        reference_clip_id: &str,
+        clips: Vec<SyncClipOffset>,
+    ) -> Self {
         Self {
             sequence_id: sequence_id.to_string(),
-            track_id: track_id.to_string(),
-            clip_id: clip_id.to_string(),
-            removed_compound_clip: None,
-            removed_inner_sequence: None,
-            restored_clip_ids: Vec::new(),
+            reference_track_id: reference_track_id.to_string(),
+            reference_clip_id: reference_clip_id.to_string(),
+            clips,
+            link: false,
+            link_group_id: ulid::Ulid::new().to_string(),
+            previous_spans: Vec::new(),
+            previous_link_group_ids: Vec::new(),
         }
     }
-}
 
-impl Command for UnnestCompoundClipCommand {
-    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        // 1. Validate and get compound clip
+    pub fn with_link(mut self, link: bool) -> Self {
+        self.link = link;
+        self
+    }
+
+    fn plan(&self, state: &ProjectState) -> CoreResult<Vec<ClipSpan>> {
+        if self.clips.is_empty() {
+            return Err(CoreError::ValidationError(
+                "SyncClips requires at least one clip to sync".to_string(),
+            ));
+        }
+
         let sequence = state
             .sequences
             .get(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+        let (_, reference) =
+            find_clip_ref(sequence, &self.reference_track_id, &self.reference_clip_id)?;
+        validate_plays_in_sync(reference)?;
+        // Timeline time at which the reference media's clock reads zero.
+        let reference_zero = reference.place.timeline_in_sec - reference.range.source_in_sec;
+
+        let mut spans: Vec<ClipSpan> = Vec::new();
+        for target in &self.clips {
+            if !target.offset_sec.is_finite() {
+                return Err(CoreError::ValidationError(format!(
+                    "offsetSec for clip '{}' must be a finite number",
+                    target.clip_id
+                )));
+            }
+            if target.clip_id == self.reference_clip_id {
+                return Err(CoreError::ValidationError(format!(
+                    "Clip '{}' is the sync reference and cannot be synced to itself",
+                    target.clip_id
+                )));
+            }
+            if spans.iter().any(|span| span.clip_id == target.clip_id) {
+                continue;
+            }
 
-        let track = sequence
-            .tracks
-            .iter()
-            .find(|t| t.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
-        validate_track_unlocked(track)?;
-
-        let compound_clip = track
-            .clips
-            .iter()
-            .find(|c| c.id == self.clip_id)
-            .ok_or_else(|| CoreError::ClipNotFound(self.clip_id.clone()))?;
-
-        // 2. Validate it is a compound clip
-        let inner_seq_id = compound_clip
-            .compound_sequence_id
-            .as_ref()
-            .ok_or_else(|| {
-                CoreError::ValidationError(format!(
-                    "Clip '{}' is not a compound clip",
-                    self.clip_id
-                ))
-            })?
-            .clone();
+            let (track, clip) = find_clip_ref(sequence, &target.track_id, &target.clip_id)?;
+            validate_plays_in_sync(clip)?;
+            let synced_in = reference_zero + target.offset_sec + clip.range.source_in_sec;
+            if synced_in < -EDIT_POINT_EPSILON {
+                return Err(CoreError::ValidationError(format!(
+                    "Syncing clip '{}' would place it {:.3}s before the sequence start; move the reference clip later first",
+                    clip.id, -synced_in
+                )));
+            }
+            let delta = synced_in.max(0.0) - clip.place.timeline_in_sec;
 
-        let compound_start = compound_clip.place.timeline_in_sec;
+            let mut span = ClipSpan::of(&track.id, clip);
+            span.place.timeline_in_sec += delta;
+            spans.push(span);
 
-        // 3. Get inner sequence clips
-        let inner_sequence = state
-            .sequences
-            .get(&inner_seq_id)
-            .ok_or_else(|| CoreError::SequenceNotFound(inner_seq_id.clone()))?;
+            let partners = clip
+                .link_group_id
+                .as_deref()
+                .filter(|link_group_id| !link_group_id.is_empty())
+                .map(|link_group_id| collect_clip_refs_for_link_group(sequence, link_group_id))
+                .unwrap_or_default();
+            for (partner_track_id, partner_clip_id) in partners {
+                if partner_clip_id == self.reference_clip_id
+                    || self
+                        .clips
+                        .iter()
+                        .any(|other| other.clip_id == partner_clip_id)
+                    || spans.iter().any(|span| span.clip_id == partner_clip_id)
+                {
+                    continue;
+                }
+                let (partner_track, partner) =
+                    find_clip_ref(sequence, &partner_track_id, &partner_clip_id)?;
+                let mut span = ClipSpan::of(&partner_track.id, partner);
+                span.place.timeline_in_sec += delta;
+                if span.place.timeline_in_sec < -EDIT_POINT_EPSILON {
+                    return Err(CoreError::ValidationError(format!(
+                        "Syncing clip '{}' would move its linked clip '{}' before the sequence start",
+                        clip.id, partner.id
+                    )));
+                }
+                span.place.timeline_in_sec = span.place.timeline_in_sec.max(0.0);
+                spans.push(span);
+            }
+        }
 
-        let populated_inner_tracks: Vec<&crate::core::timeline::Track> = inner_sequence
-            .tracks
+        let refs: Vec<(TrackId, ClipId)> = spans
             .iter()
-            .filter(|inner_track| !inner_track.clips.is_empty())
+            .map(|span| (span.track_id.clone(), span.clip_id.clone()))
             .collect();
-        if populated_inner_tracks.len() > 1
-            || populated_inner_tracks
-                .iter()
-                .any(|inner_track| inner_track.kind != track.kind)
-        {
-            return Err(CoreError::ValidationError(
-                "UnnestCompoundClip currently supports only a single populated inner track matching the parent track kind".to_string(),
-            ));
-        }
-
-        // Collect all clips from all tracks in the inner sequence
-        let mut inner_clips: Vec<Clip> = Vec::new();
-        for inner_track in &inner_sequence.tracks {
-            for clip in &inner_track.clips {
-                let mut restored_clip = clip.clone();
-                // Adjust positions back to parent timeline
-                restored_clip.place.timeline_in_sec += compound_start;
-                inner_clips.push(restored_clip);
-            }
-        }
+        validate_clip_refs_unlocked(sequence, &refs)?;
 
-        let restored_ids: Vec<ClipId> = inner_clips.iter().map(|c| c.id.clone()).collect();
+        Ok(spans)
+    }
+}
 
-        // 4. Store undo state
-        self.removed_compound_clip = Some(compound_clip.clone());
-        self.removed_inner_sequence = Some(inner_sequence.clone());
-        self.restored_clip_ids = restored_ids.clone();
+/// Sync placement maps source time to timeline time one to one.
+fn validate_plays_in_sync(clip: &Clip) -> CoreResult<()> {
+    if crate::core::timeline::plays_at_normal_speed(clip) {
+        Ok(())
+    } else {
+        Err(CoreError::ValidationError(format!(
+            "Clip '{}' is retimed; only normal-speed clips can be synced",
+            clip.id
+        )))
+    }
+}
 
-        // 5. Mutate state: remove inner sequence
-        state.sequences.remove(&inner_seq_id);
+impl Command for SyncClipsCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let spans = self.plan(state)?;
 
-        // 6. Mutate state: replace compound clip with inner clips
-        let sequence_mut = state
+        let sequence = state
             .sequences
             .get_mut(&self.sequence_id)
             .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
 
-        let track_mut = sequence_mut
-            .tracks
-            .iter_mut()
-            .find(|t| t.id == self.track_id)
-            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
-
-        // Remove compound clip
-        track_mut.clips.retain(|c| c.id != self.clip_id);
-
-        // Insert restored clips
-        for clip in inner_clips {
-            insert_clip_sorted(track_mut, clip);
+        let mut link_refs = Vec::new();
+        if self.link {
+            let mut seen = HashSet::new();
+            push_unique_clip_ref(
+                &mut seen,
+                &mut link_refs,
+                &self.reference_track_id,
+                &self.reference_clip_id,
+            );
+            for span in &spans {
+                push_unique_clip_ref(&mut seen, &mut link_refs, &span.track_id, &span.clip_id);
+            }
+            link_refs = collect_affected_link_refs(sequence, &link_refs)?;
+            validate_clip_refs_unlocked(sequence, &link_refs)?;
         }
 
-        state.is_dirty = true;
-
-        // 7. Build result
-        let op_id = ulid::Ulid::new().to_string();
-        let mut result = CommandResult::new(&op_id).with_change(StateChange::ClipDeleted {
-            clip_id: self.clip_id.clone(),
-        });
-
-        for clip_id in &restored_ids {
-            result = result
-                .with_change(StateChange::ClipCreated {
-                    clip_id: clip_id.clone(),
-                })
-                .with_created_id(clip_id);
+        self.previous_spans = commit_clip_spans(sequence, &spans)?;
+        self.previous_link_group_ids = capture_link_group_state(sequence, &link_refs)?;
+        for (track_id, clip_id) in &link_refs {
+            if let Some(clip) = sequence
+                .get_track_mut(track_id)
+                .and_then(|track| track.get_clip_mut(clip_id))
+            {
+                clip.link_group_id = Some(self.link_group_id.clone());
+            }
         }
+        state.is_dirty = true;
 
+        let mut changed_refs: Vec<(TrackId, ClipId)> = spans
+            .iter()
+            .map(|span| (span.track_id.clone(), span.clip_id.clone()))
+            .collect();
+        changed_refs.extend(link_refs);
+        let mut result = CommandResult::new(&ulid::Ulid::new().to_string());
+        append_clip_modified_changes(&mut result, &changed_refs);
         Ok(result)
     }
 
     fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
-        // 1. Restore inner sequence
-        if let Some(ref inner_seq) = self.removed_inner_sequence {
-            state
-                .sequences
-                .insert(inner_seq.id.clone(), inner_seq.clone());
-        }
-
-        // 2. Remove restored clips and re-add compound clip
         if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
-            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) {
-                // Remove the restored clips
-                let restored_set: std::collections::HashSet<&str> =
-                    self.restored_clip_ids.iter().map(|s| s.as_str()).collect();
-                track
-                    .clips
-                    .retain(|c| !restored_set.contains(c.id.as_str()));
-
-                // Restore compound clip
-                if let Some(ref compound_clip) = self.removed_compound_clip {
-                    insert_clip_sorted(track, compound_clip.clone());
+            for (track_id, clip_id, previous) in &self.previous_link_group_ids {
+                if let Some(clip) = sequence
+                    .get_track_mut(track_id)
+                    .and_then(|track| track.get_clip_mut(clip_id))
+                {
+                    clip.link_group_id = previous.clone();
                 }
             }
+            restore_clip_spans(sequence, &self.previous_spans);
         }
-
         state.is_dirty = true;
         Ok(())
     }
 
     fn type_name(&self) -> &'static str {
-        "UnnestCompoundClip"
+        "SyncClips"
     }
 
     fn to_json(&self) -> serde_json::Value {
-        serde_json::json!({
-            "sequenceId": self.sequence_id,
-            "trackId": self.track_id,
-            "clipId": self.clip_id,
-        })
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
     }
 }
 
@@ -8351,16 +9829,61 @@ mod tests {
             vec!["interview".to_string(), "lav".to_string()]
         );
 
-        audio_cmd.undo(&mut state).unwrap();
+        audio_cmd.undo(&mut state).unwrap();
+
+        let restored = &state.sequences[&seq_id].tracks[0].clips[0].audio;
+        assert_eq!(restored.volume_db, 0.0);
+        assert_eq!(restored.pan, 0.0);
+        assert!(!restored.muted);
+        assert_eq!(restored.fade_in_sec, 0.0);
+        assert_eq!(restored.fade_out_sec, 0.0);
+        assert_eq!(restored.audio_role, None);
+        assert!(restored.audio_tags.is_empty());
+    }
+
+    #[test]
+    fn test_set_clip_audio_channel_map_checks_source_channels() {
+        let mut state = create_test_state();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        let track_id = state.sequences[&seq_id].tracks[0].id.clone();
+        let asset_id = state.assets.keys().next().unwrap().clone();
+        state.assets.get_mut(&asset_id).unwrap().audio = Some(AudioInfo {
+            channels: 4,
+            ..AudioInfo::default()
+        });
+
+        let mut insert_cmd =
+            InsertClipCommand::new(&seq_id, &track_id, &asset_id, 0.0).with_source_range(0.0, 10.0);
+        insert_cmd.execute(&mut state).unwrap();
+        let clip_id = state.sequences[&seq_id].tracks[0].clips[0].id.clone();
+        let set_map = |channel_map: Vec<u8>| {
+            SetClipAudioCommand::new(&seq_id, &track_id, &clip_id, None, None, None, None, None)
+                .with_channel_map(channel_map)
+        };
+
+        let mut mono = set_map(vec![1]);
+        mono.execute(&mut state).unwrap();
+        assert_eq!(
+            state.sequences[&seq_id].tracks[0].clips[0]
+                .audio
+                .channel_map,
+            vec![1]
+        );
+
+        assert!(set_map(vec![4]).execute(&mut state).is_err());
+        assert!(set_map(vec![0, 1, 2]).execute(&mut state).is_err());
 
-        let restored = &state.sequences[&seq_id].tracks[0].clips[0].audio;
-        assert_eq!(restored.volume_db, 0.0);
-        assert_eq!(restored.pan, 0.0);
-        assert!(!restored.muted);
-        assert_eq!(restored.fade_in_sec, 0.0);
-        assert_eq!(restored.fade_out_sec, 0.0);
-        assert_eq!(restored.audio_role, None);
-        assert!(restored.audio_tags.is_empty());
+        set_map(Vec::new()).execute(&mut state).unwrap();
+        assert!(state.sequences[&seq_id].tracks[0].clips[0]
+            .audio
+            .channel_map
+            .is_empty());
+
+        mono.undo(&mut state).unwrap();
+        assert!(state.sequences[&seq_id].tracks[0].clips[0]
+            .audio
+            .channel_map
+            .is_empty());
     }
 
     // =============================================================================
@@ -8682,106 +10205,475 @@ mod tests {
         let mut state = create_test_state();
         let (seq_id, _track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
 
-        // When: Slip B's source forward by 3s (no timeline movement)
-        let mut trim_cmd = TrimClipCommand::new_simple(&seq_id, &clips[1])
-            .with_source_in(3.0)
-            .with_source_out(13.0);
-        trim_cmd.execute(&mut state).unwrap();
+        // When: Slip B's source forward by 3s (no timeline movement)
+        let mut trim_cmd = TrimClipCommand::new_simple(&seq_id, &clips[1])
+            .with_source_in(3.0)
+            .with_source_out(13.0);
+        trim_cmd.execute(&mut state).unwrap();
+
+        // Then: B's timeline position unchanged, source shifted
+        let track = &state.sequences[&seq_id].tracks[0];
+
+        // B: timeline still 10-20, source now 3-13
+        assert_eq!(track.clips[1].place.timeline_in_sec, 10.0);
+        assert_eq!(track.clips[1].place.duration_sec, 10.0);
+        assert_eq!(track.clips[1].range.source_in_sec, 3.0);
+        assert_eq!(track.clips[1].range.source_out_sec, 13.0);
+
+        // Neighbors unchanged
+        assert_eq!(track.clips[0].place.timeline_in_sec, 0.0);
+        assert_eq!(track.clips[0].range.source_in_sec, 0.0);
+        assert_eq!(track.clips[2].place.timeline_in_sec, 20.0);
+        assert_eq!(track.clips[2].range.source_in_sec, 0.0);
+    }
+
+    // -- Slide Edit Scenarios --
+
+    #[test]
+    fn test_slide_edit_moves_clip_adjusts_neighbors() {
+        // Given: 3 contiguous clips [A(0-10), B(10-20), C(20-30)]
+        let mut state = create_test_state();
+        let (seq_id, _track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+
+        // When: Slide B right by 3s
+        // Positive slide → shrink next first, then move, then extend prev.
+
+        // Step 1: Trim C (shrink from start): sourceIn=3, timelineIn=23
+        let mut trim_c = TrimClipCommand::new_simple(&seq_id, &clips[2])
+            .with_source_in(3.0)
+            .with_timeline_in(23.0);
+        trim_c.execute(&mut state).unwrap();
+
+        // Step 2: Move B to timeline 13
+        let mut move_b = MoveClipCommand::new_simple(&seq_id, &clips[1], 13.0);
+        move_b.execute(&mut state).unwrap();
+
+        // Step 3: Trim A (extend): sourceOut=13
+        let mut trim_a = TrimClipCommand::new_simple(&seq_id, &clips[0]).with_source_out(13.0);
+        trim_a.execute(&mut state).unwrap();
+
+        // Then: Clips still contiguous, B shifted right
+        let track = &state.sequences[&seq_id].tracks[0];
+
+        // A: source 0-13, timeline 0-13
+        assert_eq!(track.clips[0].range.source_out_sec, 13.0);
+        assert_eq!(track.clips[0].place.timeline_in_sec, 0.0);
+        assert_eq!(track.clips[0].place.duration_sec, 13.0);
+
+        // B: source 0-10, timeline 13-23
+        assert_eq!(track.clips[1].place.timeline_in_sec, 13.0);
+        assert_eq!(track.clips[1].place.duration_sec, 10.0);
+
+        // C: source 3-10, timeline 23-30
+        assert_eq!(track.clips[2].range.source_in_sec, 3.0);
+        assert_eq!(track.clips[2].place.timeline_in_sec, 23.0);
+        assert_eq!(track.clips[2].place.duration_sec, 7.0);
+
+        // Total timeline length preserved: 0-30
+        let last = &track.clips[2];
+        let total_end = last.place.timeline_in_sec + last.place.duration_sec;
+        assert_eq!(total_end, 30.0);
+    }
+
+    #[test]
+    fn test_slide_edit_undo_restores_all_positions() {
+        // Given: 3 clips with slide edit applied
+        let mut state = create_test_state();
+        let (seq_id, _track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+
+        let mut trim_c = TrimClipCommand::new_simple(&seq_id, &clips[2])
+            .with_source_in(3.0)
+            .with_timeline_in(23.0);
+        trim_c.execute(&mut state).unwrap();
+
+        let mut move_b = MoveClipCommand::new_simple(&seq_id, &clips[1], 13.0);
+        move_b.execute(&mut state).unwrap();
+
+        let mut trim_a = TrimClipCommand::new_simple(&seq_id, &clips[0]).with_source_out(13.0);
+        trim_a.execute(&mut state).unwrap();
+
+        // When: Undo in reverse order
+        trim_a.undo(&mut state).unwrap();
+        move_b.undo(&mut state).unwrap();
+        trim_c.undo(&mut state).unwrap();
+
+        // Then: All clips restored to original contiguous positions
+        let track = &state.sequences[&seq_id].tracks[0];
+        for (i, clip) in track.clips.iter().enumerate() {
+            assert_eq!(clip.place.timeline_in_sec, (i * 10) as f64);
+            assert_eq!(clip.place.duration_sec, 10.0);
+            assert_eq!(clip.range.source_in_sec, 0.0);
+            assert_eq!(clip.range.source_out_sec, 10.0);
+        }
+    }
+
+    // -- Dedicated Slip / Slide / Roll Commands --
+
+    /// Adds a sync-locked audio track mirroring the first track's clips, with
+    /// each audio clip linked to the video clip at the same index.
+    fn add_linked_audio_track(
+        state: &mut ProjectState,
+        seq_id: &str,
+        video_clips: &[ClipId],
+        sync_lock: bool,
+    ) -> (TrackId, Vec<ClipId>) {
+        let asset_id = state.assets.keys().next().unwrap().clone();
+        let mut audio_track = Track::new("Audio 1", TrackKind::Audio);
+        audio_track.sync_lock = sync_lock;
+        let audio_track_id = audio_track.id.clone();
+        state
+            .sequences
+            .get_mut(seq_id)
+            .unwrap()
+            .tracks
+            .push(audio_track);
+
+        let mut audio_clips = Vec::new();
+        for (i, video_clip_id) in video_clips.iter().enumerate() {
+            let mut cmd =
+                InsertClipCommand::new(seq_id, &audio_track_id, &asset_id, (i * 10) as f64)
+                    .with_source_range(0.0, 10.0);
+            let audio_clip_id = cmd.execute(state).unwrap().created_ids[0].clone();
+
+            let group = format!("link-{i}");
+            let sequence = state.sequences.get_mut(seq_id).unwrap();
+            for track in &mut sequence.tracks {
+                for clip in &mut track.clips {
+                    if clip.id == *video_clip_id || clip.id == audio_clip_id {
+                        clip.link_group_id = Some(group.clone());
+                    }
+                }
+            }
+            audio_clips.push(audio_clip_id);
+        }
+
+        (audio_track_id, audio_clips)
+    }
+
+    #[test]
+    fn test_slip_clip_command_shifts_source_and_undoes() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+
+        let mut cmd = SlipClipCommand::new(&seq_id, &track_id, &clips[1], 3.0);
+        let result = cmd.execute(&mut state).unwrap();
+        assert_eq!(result.changes.len(), 1);
+
+        let clip = &state.sequences[&seq_id].tracks[0].clips[1];
+        assert_eq!(clip.range.source_in_sec, 3.0);
+        assert_eq!(clip.range.source_out_sec, 13.0);
+        assert_eq!(clip.place.timeline_in_sec, 10.0);
+        assert_eq!(clip.place.duration_sec, 10.0);
+
+        cmd.undo(&mut state).unwrap();
+        let clip = &state.sequences[&seq_id].tracks[0].clips[1];
+        assert_eq!(clip.range.source_in_sec, 0.0);
+        assert_eq!(clip.range.source_out_sec, 10.0);
+    }
+
+    #[test]
+    fn test_slip_clip_command_rejects_slip_past_source_handles() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 1);
+
+        // No media before source 0.
+        let mut before_start = SlipClipCommand::new(&seq_id, &track_id, &clips[0], -1.0);
+        assert!(matches!(
+            before_start.execute(&mut state),
+            Err(CoreError::ValidationError(_))
+        ));
+
+        // The test asset is 60s long; source 51-61 runs past its end.
+        let mut past_end = SlipClipCommand::new(&seq_id, &track_id, &clips[0], 51.0);
+        let error = past_end.execute(&mut state).unwrap_err();
+        assert!(error.to_string().contains("60.000s long"));
+
+        let clip = &state.sequences[&seq_id].tracks[0].clips[0];
+        assert_eq!(clip.range.source_in_sec, 0.0);
+        assert_eq!(clip.range.source_out_sec, 10.0);
+    }
+
+    #[test]
+    fn test_slide_clip_command_trims_neighbors_in_one_step() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+
+        let mut cmd = SlideClipCommand::new(&seq_id, &track_id, &clips[1], 3.0);
+        let result = cmd.execute(&mut state).unwrap();
+        assert_eq!(result.changes.len(), 3);
+
+        let track = &state.sequences[&seq_id].tracks[0];
+        assert_eq!(track.clips[0].range.source_out_sec, 13.0);
+        assert_eq!(track.clips[0].place.duration_sec, 13.0);
+        assert_eq!(track.clips[1].place.timeline_in_sec, 13.0);
+        assert_eq!(track.clips[1].range.source_in_sec, 0.0);
+        assert_eq!(track.clips[1].place.duration_sec, 10.0);
+        assert_eq!(track.clips[2].range.source_in_sec, 3.0);
+        assert_eq!(track.clips[2].place.timeline_in_sec, 23.0);
+        assert_eq!(track.clips[2].place.timeline_out_sec(), 30.0);
+
+        cmd.undo(&mut state).unwrap();
+        let track = &state.sequences[&seq_id].tracks[0];
+        for (i, clip) in track.clips.iter().enumerate() {
+            assert_eq!(clip.id, clips[i]);
+            assert_eq!(clip.place.timeline_in_sec, (i * 10) as f64);
+            assert_eq!(clip.place.duration_sec, 10.0);
+            assert_eq!(clip.range.source_in_sec, 0.0);
+            assert_eq!(clip.range.source_out_sec, 10.0);
+        }
+    }
+
+    #[test]
+    fn test_slide_clip_command_rejects_consuming_a_neighbor() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+
+        let mut cmd = SlideClipCommand::new(&seq_id, &track_id, &clips[1], 10.0);
+        assert!(matches!(
+            cmd.execute(&mut state),
+            Err(CoreError::ValidationError(_))
+        ));
+
+        // Sliding left would extend C's head before source 0.
+        let mut left = SlideClipCommand::new(&seq_id, &track_id, &clips[1], -2.0);
+        assert!(matches!(
+            left.execute(&mut state),
+            Err(CoreError::ValidationError(_))
+        ));
+
+        let track = &state.sequences[&seq_id].tracks[0];
+        assert_eq!(track.clips[1].place.timeline_in_sec, 10.0);
+        assert_eq!(track.clips[0].place.duration_sec, 10.0);
+        assert_eq!(track.clips[2].place.timeline_in_sec, 20.0);
+    }
+
+    #[test]
+    fn test_slide_clip_command_moves_into_adjacent_gap() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 2);
+        let mut remove_first = RemoveClipCommand::new(&seq_id, &track_id, &clips[0]);
+        remove_first.execute(&mut state).unwrap();
+
+        let mut cmd = SlideClipCommand::new(&seq_id, &track_id, &clips[1], -4.0);
+        cmd.execute(&mut state).unwrap();
+
+        let track = &state.sequences[&seq_id].tracks[0];
+        assert_eq!(track.clips.len(), 1);
+        assert_eq!(track.clips[0].place.timeline_in_sec, 6.0);
+        assert_eq!(track.clips[0].place.duration_sec, 10.0);
+    }
+
+    #[test]
+    fn test_roll_edit_command_moves_cut_and_undoes() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 2);
+        {
+            // Give B head room so the cut can roll left.
+            let clip_b = &mut state.sequences.get_mut(&seq_id).unwrap().tracks[0].clips[1];
+            clip_b.range.source_in_sec = 5.0;
+            clip_b.range.source_out_sec = 15.0;
+        }
+
+        let mut cmd = RollEditCommand::new(&seq_id, &track_id, &clips[0], &clips[1], -3.0);
+        cmd.execute(&mut state).unwrap();
+
+        let track = &state.sequences[&seq_id].tracks[0];
+        assert_eq!(track.clips[0].place.duration_sec, 7.0);
+        assert_eq!(track.clips[0].range.source_out_sec, 7.0);
+        assert_eq!(track.clips[1].place.timeline_in_sec, 7.0);
+        assert_eq!(track.clips[1].place.duration_sec, 13.0);
+        assert_eq!(track.clips[1].range.source_in_sec, 2.0);
+        assert_eq!(track.clips[1].range.source_out_sec, 15.0);
+
+        cmd.undo(&mut state).unwrap();
+        let track = &state.sequences[&seq_id].tracks[0];
+        assert_eq!(track.clips[0].place.duration_sec, 10.0);
+        assert_eq!(track.clips[1].place.timeline_in_sec, 10.0);
+        assert_eq!(track.clips[1].range.source_in_sec, 5.0);
+    }
+
+    #[test]
+    fn test_roll_edit_command_requires_a_shared_edit_point() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+
+        let mut cmd = RollEditCommand::new(&seq_id, &track_id, &clips[0], &clips[2], 1.0);
+        let error = cmd.execute(&mut state).unwrap_err();
+        assert!(error.to_string().contains("do not share an edit point"));
+    }
+
+    #[test]
+    fn test_roll_edit_command_respects_asset_duration() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 2);
+        {
+            // A already plays the last 10s of the 60s asset.
+            let clip_a = &mut state.sequences.get_mut(&seq_id).unwrap().tracks[0].clips[0];
+            clip_a.range.source_in_sec = 50.0;
+            clip_a.range.source_out_sec = 60.0;
+        }
+
+        let mut cmd = RollEditCommand::new(&seq_id, &track_id, &clips[0], &clips[1], 1.0);
+        assert!(matches!(
+            cmd.execute(&mut state),
+            Err(CoreError::ValidationError(_))
+        ));
+        assert_eq!(
+            state.sequences[&seq_id].tracks[0].clips[1]
+                .place
+                .timeline_in_sec,
+            10.0
+        );
+    }
+
+    #[test]
+    fn test_span_edit_commands_reject_locked_track() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+        state.sequences.get_mut(&seq_id).unwrap().tracks[0].locked = true;
+
+        let mut slip = SlipClipCommand::new(&seq_id, &track_id, &clips[1], 1.0);
+        assert!(slip.execute(&mut state).is_err());
+        let mut slide = SlideClipCommand::new(&seq_id, &track_id, &clips[1], 1.0);
+        assert!(slide.execute(&mut state).is_err());
+        let mut roll = RollEditCommand::new(&seq_id, &track_id, &clips[0], &clips[1], 1.0);
+        assert!(roll.execute(&mut state).is_err());
+    }
+
+    #[test]
+    fn test_slip_clip_command_carries_linked_clip_on_sync_locked_track() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 2);
+        let (audio_track_id, audio_clips) =
+            add_linked_audio_track(&mut state, &seq_id, &clips, true);
 
-        // Then: B's timeline position unchanged, source shifted
-        let track = &state.sequences[&seq_id].tracks[0];
+        let mut cmd = SlipClipCommand::new(&seq_id, &track_id, &clips[1], 2.0);
+        let result = cmd.execute(&mut state).unwrap();
+        assert_eq!(result.changes.len(), 2);
 
-        // B: timeline still 10-20, source now 3-13
-        assert_eq!(track.clips[1].place.timeline_in_sec, 10.0);
-        assert_eq!(track.clips[1].place.duration_sec, 10.0);
-        assert_eq!(track.clips[1].range.source_in_sec, 3.0);
-        assert_eq!(track.clips[1].range.source_out_sec, 13.0);
+        let sequence = &state.sequences[&seq_id];
+        let audio = sequence
+            .get_track(&audio_track_id)
+            .unwrap()
+            .get_clip(&audio_clips[1])
+            .unwrap();
+        assert_eq!(audio.range.source_in_sec, 2.0);
+        assert_eq!(audio.range.source_out_sec, 12.0);
+        let untouched = sequence
+            .get_track(&audio_track_id)
+            .unwrap()
+            .get_clip(&audio_clips[0])
+            .unwrap();
+        assert_eq!(untouched.range.source_in_sec, 0.0);
 
-        // Neighbors unchanged
-        assert_eq!(track.clips[0].place.timeline_in_sec, 0.0);
-        assert_eq!(track.clips[0].range.source_in_sec, 0.0);
-        assert_eq!(track.clips[2].place.timeline_in_sec, 20.0);
-        assert_eq!(track.clips[2].range.source_in_sec, 0.0);
+        cmd.undo(&mut state).unwrap();
+        let audio = state.sequences[&seq_id]
+            .get_track(&audio_track_id)
+            .unwrap()
+            .get_clip(&audio_clips[1])
+            .unwrap();
+        assert_eq!(audio.range.source_in_sec, 0.0);
     }
 
-    // -- Slide Edit Scenarios --
-
     #[test]
-    fn test_slide_edit_moves_clip_adjusts_neighbors() {
-        // Given: 3 contiguous clips [A(0-10), B(10-20), C(20-30)]
+    fn test_span_edit_commands_leave_linked_clip_without_sync_lock() {
         let mut state = create_test_state();
-        let (seq_id, _track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+        let (audio_track_id, audio_clips) =
+            add_linked_audio_track(&mut state, &seq_id, &clips, false);
 
-        // When: Slide B right by 3s
-        // Positive slide → shrink next first, then move, then extend prev.
-
-        // Step 1: Trim C (shrink from start): sourceIn=3, timelineIn=23
-        let mut trim_c = TrimClipCommand::new_simple(&seq_id, &clips[2])
-            .with_source_in(3.0)
-            .with_timeline_in(23.0);
-        trim_c.execute(&mut state).unwrap();
+        let mut slide = SlideClipCommand::new(&seq_id, &track_id, &clips[1], 2.0);
+        slide.execute(&mut state).unwrap();
 
-        // Step 2: Move B to timeline 13
-        let mut move_b = MoveClipCommand::new_simple(&seq_id, &clips[1], 13.0);
-        move_b.execute(&mut state).unwrap();
+        let audio = state.sequences[&seq_id]
+            .get_track(&audio_track_id)
+            .unwrap()
+            .get_clip(&audio_clips[1])
+            .unwrap();
+        assert_eq!(audio.place.timeline_in_sec, 10.0);
+    }
 
-        // Step 3: Trim A (extend): sourceOut=13
-        let mut trim_a = TrimClipCommand::new_simple(&seq_id, &clips[0]).with_source_out(13.0);
-        trim_a.execute(&mut state).unwrap();
+    #[test]
+    fn test_slide_clip_command_carries_linked_clip_and_its_neighbors() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+        let (audio_track_id, audio_clips) =
+            add_linked_audio_track(&mut state, &seq_id, &clips, true);
 
-        // Then: Clips still contiguous, B shifted right
-        let track = &state.sequences[&seq_id].tracks[0];
+        let mut cmd = SlideClipCommand::new(&seq_id, &track_id, &clips[1], 3.0);
+        let result = cmd.execute(&mut state).unwrap();
+        assert_eq!(result.changes.len(), 6);
 
-        // A: source 0-13, timeline 0-13
-        assert_eq!(track.clips[0].range.source_out_sec, 13.0);
-        assert_eq!(track.clips[0].place.timeline_in_sec, 0.0);
-        assert_eq!(track.clips[0].place.duration_sec, 13.0);
+        let audio_track = state.sequences[&seq_id].get_track(&audio_track_id).unwrap();
+        assert_eq!(audio_track.clips[0].place.duration_sec, 13.0);
+        assert_eq!(audio_track.clips[1].id, audio_clips[1]);
+        assert_eq!(audio_track.clips[1].place.timeline_in_sec, 13.0);
+        assert_eq!(audio_track.clips[2].place.timeline_in_sec, 23.0);
+        assert_eq!(audio_track.clips[2].range.source_in_sec, 3.0);
+    }
 
-        // B: source 0-10, timeline 13-23
-        assert_eq!(track.clips[1].place.timeline_in_sec, 13.0);
-        assert_eq!(track.clips[1].place.duration_sec, 10.0);
+    #[test]
+    fn test_slide_clip_command_is_atomic_when_a_linked_partner_fails() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+        let (audio_track_id, audio_clips) =
+            add_linked_audio_track(&mut state, &seq_id, &clips, true);
+        {
+            // The audio neighbour after the linked clip has no tail room to give.
+            let sequence = state.sequences.get_mut(&seq_id).unwrap();
+            let audio_track = sequence.get_track_mut(&audio_track_id).unwrap();
+            let next = audio_track.get_clip_mut(&audio_clips[2]).unwrap();
+            next.place.duration_sec = 2.0;
+            next.range.source_out_sec = 2.0;
+        }
 
-        // C: source 3-10, timeline 23-30
-        assert_eq!(track.clips[2].range.source_in_sec, 3.0);
-        assert_eq!(track.clips[2].place.timeline_in_sec, 23.0);
-        assert_eq!(track.clips[2].place.duration_sec, 7.0);
+        let mut cmd = SlideClipCommand::new(&seq_id, &track_id, &clips[1], 3.0);
+        assert!(cmd.execute(&mut state).is_err());
 
-        // Total timeline length preserved: 0-30
-        let last = &track.clips[2];
-        let total_end = last.place.timeline_in_sec + last.place.duration_sec;
-        assert_eq!(total_end, 30.0);
+        let video_track = &state.sequences[&seq_id].tracks[0];
+        assert_eq!(video_track.clips[1].place.timeline_in_sec, 10.0);
+        assert_eq!(video_track.clips[0].place.duration_sec, 10.0);
     }
 
     #[test]
-    fn test_slide_edit_undo_restores_all_positions() {
-        // Given: 3 clips with slide edit applied
+    fn test_roll_edit_command_rolls_linked_cut_on_sync_locked_track() {
         let mut state = create_test_state();
-        let (seq_id, _track_id, clips) = setup_multi_clip_timeline(&mut state, 3);
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 2);
+        let (audio_track_id, audio_clips) =
+            add_linked_audio_track(&mut state, &seq_id, &clips, true);
 
-        let mut trim_c = TrimClipCommand::new_simple(&seq_id, &clips[2])
-            .with_source_in(3.0)
-            .with_timeline_in(23.0);
-        trim_c.execute(&mut state).unwrap();
+        let mut cmd = RollEditCommand::new(&seq_id, &track_id, &clips[0], &clips[1], 2.0);
+        let result = cmd.execute(&mut state).unwrap();
+        assert_eq!(result.changes.len(), 4);
 
-        let mut move_b = MoveClipCommand::new_simple(&seq_id, &clips[1], 13.0);
-        move_b.execute(&mut state).unwrap();
+        let audio_track = state.sequences[&seq_id].get_track(&audio_track_id).unwrap();
+        let outgoing = audio_track.get_clip(&audio_clips[0]).unwrap();
+        let incoming = audio_track.get_clip(&audio_clips[1]).unwrap();
+        assert_eq!(outgoing.place.duration_sec, 12.0);
+        assert_eq!(incoming.place.timeline_in_sec, 12.0);
+        assert_eq!(incoming.range.source_in_sec, 2.0);
 
-        let mut trim_a = TrimClipCommand::new_simple(&seq_id, &clips[0]).with_source_out(13.0);
-        trim_a.execute(&mut state).unwrap();
+        cmd.undo(&mut state).unwrap();
+        let audio_track = state.sequences[&seq_id].get_track(&audio_track_id).unwrap();
+        assert_eq!(
+            audio_track
+                .get_clip(&audio_clips[1])
+                .unwrap()
+                .place
+                .timeline_in_sec,
+            10.0
+        );
+    }
 
-        // When: Undo in reverse order
-        trim_a.undo(&mut state).unwrap();
-        move_b.undo(&mut state).unwrap();
-        trim_c.undo(&mut state).unwrap();
+    #[test]
+    fn test_span_edit_commands_reject_freeze_frame_clips() {
+        let mut state = create_test_state();
+        let (seq_id, track_id, clips) = setup_multi_clip_timeline(&mut state, 1);
+        state.sequences.get_mut(&seq_id).unwrap().tracks[0].clips[0].freeze_frame = true;
 
-        // Then: All clips restored to original contiguous positions
-        let track = &state.sequences[&seq_id].tracks[0];
-        for (i, clip) in track.clips.iter().enumerate() {
-            assert_eq!(clip.place.timeline_in_sec, (i * 10) as f64);
-            assert_eq!(clip.place.duration_sec, 10.0);
-            assert_eq!(clip.range.source_in_sec, 0.0);
-            assert_eq!(clip.range.source_out_sec, 10.0);
-        }
+        let mut cmd = SlipClipCommand::new(&seq_id, &track_id, &clips[0], 1.0);
+        let error = cmd.execute(&mut state).unwrap_err();
+        assert!(error.to_string().contains("freeze-frame"));
     }
 
     // =========================================================================
@@ -12438,6 +14330,379 @@ mod tests {
             .contains("single populated inner track"));
     }
 
+    // =========================================================================
+    // Multicam Clip Tests (BDD)
+    // =========================================================================
+
+    /// Helper: two synced camera angles on V1/V2, plus a lav on A1.
+    fn create_multicam_test_state() -> (ProjectState, String, Vec<String>, Vec<String>) {
+        let mut state = create_test_state();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        let asset_id = state.assets.keys().next().unwrap().clone();
+
+        let sequence = state.sequences.get_mut(&seq_id).unwrap();
+        sequence
+            .tracks
+            .push(Track::new("Video 2", TrackKind::Video));
+        sequence
+            .tracks
+            .push(Track::new("Audio 1", TrackKind::Audio));
+        let track_ids: Vec<String> = sequence.tracks.iter().map(|t| t.id.clone()).collect();
+
+        // Cam A covers [0-20], cam B starts 2s later and covers [2-20], lav [0-20].
+        let cam_a = Clip::new(&asset_id).with_source_range(5.0, 25.0);
+        let cam_b = Clip::new(&asset_id)
+            .with_source_range(0.0, 18.0)
+            .place_at(2.0);
+        let lav = Clip::new(&asset_id).with_source_range(5.0, 25.0);
+        let clip_ids = vec![cam_a.id.clone(), cam_b.id.clone(), lav.id.clone()];
+        sequence.tracks[0].add_clip(cam_a);
+        sequence.tracks[1].add_clip(cam_b);
+        sequence.tracks[2].add_clip(lav);
+
+        (state, seq_id, track_ids, clip_ids)
+    }
+
+    fn create_multicam(
+        state: &mut ProjectState,
+        seq_id: &str,
+        track_ids: &[String],
+        clip_ids: &[String],
+    ) -> Clip {
+        let mut cmd = CreateMulticamClipCommand::new(
+            seq_id,
+            &track_ids[0],
+            vec![clip_ids[0].clone(), clip_ids[1].clone()],
+        );
+        cmd.execute(state).unwrap();
+        state.sequences[seq_id].tracks[0].clips[0].clone()
+    }
+
+    #[test]
+    fn test_create_multicam_clip_should_group_angles_into_one_clip() {
+        // Given two synced camera angles and a lav
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+
+        // When creating a multicam clip from the two camera clips on V1
+        let mut cmd = CreateMulticamClipCommand::new(
+            &seq_id,
+            &track_ids[0],
+            vec![clip_ids[0].clone(), clip_ids[1].clone()],
+        )
+        .with_name("Interview");
+        let result = cmd.execute(&mut state).unwrap();
+
+        // Then V1 holds one multicam clip spanning both angles and V2 is empty
+        let sequence = &state.sequences[&seq_id];
+        assert_eq!(sequence.tracks[0].clips.len(), 1);
+        assert!(sequence.tracks[1].clips.is_empty());
+        let multicam = &sequence.tracks[0].clips[0];
+        assert!(multicam.is_multicam());
+        assert_eq!(multicam.place.timeline_in_sec, 0.0);
+        assert_eq!(multicam.place.duration_sec, 20.0);
+
+        // And the lav stays on the parent timeline
+        assert_eq!(sequence.tracks[2].clips.len(), 1);
+
+        // And the inner sequence has one video track per angle, keeping sync
+        let inner = &state.sequences[multicam.compound_sequence_id.as_ref().unwrap()];
+        assert_eq!(inner.name, "Interview");
+        assert_eq!(inner.tracks.len(), 2);
+        assert_eq!(inner.tracks[1].clips[0].place.timeline_in_sec, 2.0);
+
+        // And the angle from the target track is on screen
+        assert_eq!(multicam.multicam_angle.as_ref(), Some(&inner.tracks[0].id));
+        assert_eq!(result.created_ids, vec![multicam.id.clone()]);
+        assert_eq!(result.deleted_ids.len(), 2);
+    }
+
+    #[test]
+    fn test_create_multicam_clip_should_undo_atomically() {
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+        let mut cmd = CreateMulticamClipCommand::new(
+            &seq_id,
+            &track_ids[0],
+            vec![clip_ids[0].clone(), clip_ids[1].clone()],
+        );
+        cmd.execute(&mut state).unwrap();
+        let sequence_count = state.sequences.len();
+
+        cmd.undo(&mut state).unwrap();
+
+        let sequence = &state.sequences[&seq_id];
+        assert_eq!(sequence.tracks[0].clips[0].id, clip_ids[0]);
+        assert_eq!(sequence.tracks[1].clips[0].id, clip_ids[1]);
+        assert_eq!(state.sequences.len(), sequence_count - 1);
+    }
+
+    #[test]
+    fn test_create_multicam_clip_should_reject_single_angle_and_audio_clips() {
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+
+        let mut single =
+            CreateMulticamClipCommand::new(&seq_id, &track_ids[0], vec![clip_ids[0].clone()]);
+        let err = single.execute(&mut state).unwrap_err();
+        assert!(err.to_string().contains("at least 2 video tracks"));
+
+        let mut with_lav = CreateMulticamClipCommand::new(&seq_id, &track_ids[0], clip_ids.clone());
+        let err = with_lav.execute(&mut state).unwrap_err();
+        assert!(err.to_string().contains("must be video clips"));
+
+        // Nothing changed
+        assert!(state.sequences[&seq_id].tracks[0]
+            .clips
+            .iter()
+            .all(|c| !c.is_multicam()));
+    }
+
+    #[test]
+    fn test_switch_multicam_angle_should_cut_to_new_angle_at_time() {
+        // Given a multicam clip showing cam A
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+        let multicam = create_multicam(&mut state, &seq_id, &track_ids, &clip_ids);
+        let inner = &state.sequences[multicam.compound_sequence_id.as_ref().unwrap()];
+        let cam_b = inner.tracks[1].id.clone();
+
+        // When switching to cam B at 8s
+        let mut cmd =
+            SwitchMulticamAngleCommand::new(&seq_id, &track_ids[0], &multicam.id, &cam_b).at(8.0);
+        let result = cmd.execute(&mut state).unwrap();
+
+        // Then the clip is cut at 8s and the later part shows cam B
+        let clips = &state.sequences[&seq_id].tracks[0].clips;
+        assert_eq!(clips.len(), 2);
+        assert_eq!(clips[0].multicam_angle, multicam.multicam_angle);
+        assert_eq!(clips[0].place.duration_sec, 8.0);
+        assert_eq!(clips[1].multicam_angle.as_deref(), Some(cam_b.as_str()));
+        assert_eq!(clips[1].place.timeline_in_sec, 8.0);
+        assert_eq!(clips[1].range.source_in_sec, 8.0);
+        assert_eq!(result.created_ids, vec![clips[1].id.clone()]);
+
+        // And undo restores the single clip
+        cmd.undo(&mut state).unwrap();
+        let clips = &state.sequences[&seq_id].tracks[0].clips;
+        assert_eq!(clips.len(), 1);
+        assert_eq!(clips[0].multicam_angle, multicam.multicam_angle);
+        assert_eq!(clips[0].place.duration_sec, 20.0);
+    }
+
+    #[test]
+    fn test_switch_multicam_angle_without_time_should_switch_whole_clip() {
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+        let multicam = create_multicam(&mut state, &seq_id, &track_ids, &clip_ids);
+        let inner = &state.sequences[multicam.compound_sequence_id.as_ref().unwrap()];
+        let cam_a = inner.tracks[0].id.clone();
+        let cam_b = inner.tracks[1].id.clone();
+
+        let mut cmd = SwitchMulticamAngleCommand::new(&seq_id, &track_ids[0], &multicam.id, &cam_b);
+        let result = cmd.execute(&mut state).unwrap();
+
+        let clips = &state.sequences[&seq_id].tracks[0].clips;
+        assert_eq!(clips.len(), 1);
+        assert_eq!(clips[0].multicam_angle.as_deref(), Some(cam_b.as_str()));
+        assert!(result.created_ids.is_empty());
+
+        // Switching to the angle already showing, or cutting outside the clip, is rejected
+        let mut same =
+            SwitchMulticamAngleCommand::new(&seq_id, &track_ids[0], &multicam.id, &cam_b);
+        assert!(same.execute(&mut state).is_err());
+        let mut outside =
+            SwitchMulticamAngleCommand::new(&seq_id, &track_ids[0], &multicam.id, &cam_a).at(25.0);
+        assert!(matches!(
+            outside.execute(&mut state),
+            Err(CoreError::InvalidSplitPoint(_))
+        ));
+    }
+
+    #[test]
+    fn test_flatten_multicam_clip_should_replace_it_with_active_angle_media() {
+        // Given a multicam clip cut to cam B at 8s
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+        let multicam = create_multicam(&mut state, &seq_id, &track_ids, &clip_ids);
+        let inner_id = multicam.compound_sequence_id.clone().unwrap();
+        let cam_b = state.sequences[&inner_id].tracks[1].id.clone();
+        let mut switch =
+            SwitchMulticamAngleCommand::new(&seq_id, &track_ids[0], &multicam.id, &cam_b).at(8.0);
+        switch.execute(&mut state).unwrap();
+        let second_id = switch.created_clip_id.clone().unwrap();
+
+        // When flattening the cam B part
+        let mut cmd = FlattenMulticamClipCommand::new(&seq_id, &track_ids[0], &second_id);
+        let result = cmd.execute(&mut state).unwrap();
+
+        // Then it becomes a plain cam B clip over [8-20], trimmed to match
+        let clips = &state.sequences[&seq_id].tracks[0].clips;
+        assert_eq!(clips.len(), 2);
+        let flat = &clips[1];
+        assert!(!flat.is_compound());
+        assert_eq!(
+            flat.asset_id,
+            state.sequences[&inner_id].tracks[1].clips[0].asset_id
+        );
+        assert!((flat.place.timeline_in_sec - 8.0).abs() < 1e-9);
+        assert!((flat.place.duration_sec - 12.0).abs() < 1e-9);
+        assert!((flat.range.source_in_sec - 6.0).abs() < 1e-9);
+        assert_eq!(result.deleted_ids, vec![second_id.clone()]);
+
+        // And the inner sequence is kept for the remaining multicam part
+        assert!(state.sequences.contains_key(&inner_id));
+
+        // And undo brings the multicam part back
+        cmd.undo(&mut state).unwrap();
+        let clips = &state.sequences[&seq_id].tracks[0].clips;
+        assert_eq!(clips.len(), 2);
+        assert_eq!(clips[1].id, second_id);
+        assert!(clips[1].is_multicam());
+    }
+
+    #[test]
+    fn test_flatten_should_reject_non_multicam_clip() {
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+
+        let mut cmd = FlattenMulticamClipCommand::new(&seq_id, &track_ids[0], &clip_ids[0]);
+        let result = cmd.execute(&mut state);
+
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("not a multicam clip"));
+    }
+
+    // =========================================================================
+    // SyncClipsCommand Tests (BDD)
+    // =========================================================================
+
+    fn sync_offset(track_id: &str, clip_id: &str, offset_sec: f64) -> SyncClipOffset {
+        SyncClipOffset {
+            track_id: track_id.to_string(),
+            clip_id: clip_id.to_string(),
+            offset_sec,
+        }
+    }
+
+    #[test]
+    fn test_sync_clips_should_place_clips_on_reference_clock() {
+        // Given cam A on V1 showing source 5s at 0s, cam B on V2 and a lav on A1
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+
+        // When cam B started recording 8s and the lav 6s after cam A
+        let mut cmd = SyncClipsCommand::new(
+            &seq_id,
+            &track_ids[0],
+            &clip_ids[0],
+            vec![
+                sync_offset(&track_ids[1], &clip_ids[1], 8.0),
+                sync_offset(&track_ids[2], &clip_ids[2], 6.0),
+            ],
+        );
+        let result = cmd.execute(&mut state).unwrap();
+
+        // Then each clip plays its source in step with cam A's clock
+        let sequence = &state.sequences[&seq_id];
+        assert!((sequence.tracks[1].clips[0].place.timeline_in_sec - 3.0).abs() < 1e-9);
+        assert!((sequence.tracks[2].clips[0].place.timeline_in_sec - 6.0).abs() < 1e-9);
+        assert_eq!(sequence.tracks[0].clips[0].place.timeline_in_sec, 0.0);
+        assert!(sequence.tracks[1].clips[0].link_group_id.is_none());
+        assert_eq!(result.changes.len(), 2);
+
+        // And undo puts them back
+        cmd.undo(&mut state).unwrap();
+        let sequence = &state.sequences[&seq_id];
+        assert_eq!(sequence.tracks[1].clips[0].place.timeline_in_sec, 2.0);
+        assert_eq!(sequence.tracks[2].clips[0].place.timeline_in_sec, 0.0);
+    }
+
+    #[test]
+    fn test_sync_clips_with_link_should_link_synced_clips_to_reference() {
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+
+        let mut cmd = SyncClipsCommand::new(
+            &seq_id,
+            &track_ids[0],
+            &clip_ids[0],
+            vec![sync_offset(&track_ids[2], &clip_ids[2], 5.0)],
+        )
+        .with_link(true);
+        cmd.execute(&mut state).unwrap();
+
+        let sequence = &state.sequences[&seq_id];
+        let reference_group = sequence.tracks[0].clips[0].link_group_id.clone();
+        assert!(reference_group.is_some());
+        assert_eq!(sequence.tracks[2].clips[0].link_group_id, reference_group);
+        assert!(sequence.tracks[1].clips[0].link_group_id.is_none());
+
+        cmd.undo(&mut state).unwrap();
+        let sequence = &state.sequences[&seq_id];
+        assert!(sequence.tracks[0].clips[0].link_group_id.is_none());
+        assert!(sequence.tracks[2].clips[0].link_group_id.is_none());
+    }
+
+    #[test]
+    fn test_sync_clips_should_carry_linked_partners() {
+        // Given cam B linked to the lav
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+        {
+            let sequence = state.sequences.get_mut(&seq_id).unwrap();
+            sequence.tracks[1].clips[0].link_group_id = Some("cam_b_pair".to_string());
+            sequence.tracks[2].clips[0].link_group_id = Some("cam_b_pair".to_string());
+        }
+
+        // When syncing only cam B, one second later than where it sits
+        let mut cmd = SyncClipsCommand::new(
+            &seq_id,
+            &track_ids[0],
+            &clip_ids[0],
+            vec![sync_offset(&track_ids[1], &clip_ids[1], 8.0)],
+        );
+        cmd.execute(&mut state).unwrap();
+
+        // Then the lav moves with it
+        let sequence = &state.sequences[&seq_id];
+        assert!((sequence.tracks[1].clips[0].place.timeline_in_sec - 3.0).abs() < 1e-9);
+        assert!((sequence.tracks[2].clips[0].place.timeline_in_sec - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sync_clips_should_reject_placement_before_sequence_start() {
+        let (mut state, seq_id, track_ids, clip_ids) = create_multicam_test_state();
+
+        // Cam B starting 3s after cam A would begin at -2s on the timeline
+        let mut early = SyncClipsCommand::new(
+            &seq_id,
+            &track_ids[0],
+            &clip_ids[0],
+            vec![sync_offset(&track_ids[1], &clip_ids[1], 3.0)],
+        );
+        let err = early.execute(&mut state).unwrap_err();
+        assert!(err.to_string().contains("before the sequence start"));
+
+        let mut itself = SyncClipsCommand::new(
+            &seq_id,
+            &track_ids[0],
+            &clip_ids[0],
+            vec![sync_offset(&track_ids[0], &clip_ids[0], 0.0)],
+        );
+        assert!(itself.execute(&mut state).is_err());
+
+        let mut retimed_state = state.clone();
+        retimed_state.sequences.get_mut(&seq_id).unwrap().tracks[2].clips[0].speed = 2.0;
+        let mut retimed = SyncClipsCommand::new(
+            &seq_id,
+            &track_ids[0],
+            &clip_ids[0],
+            vec![sync_offset(&track_ids[2], &clip_ids[2], 6.0)],
+        );
+        assert!(retimed.execute(&mut retimed_state).is_err());
+
+        // Nothing moved
+        assert_eq!(
+            state.sequences[&seq_id].tracks[1].clips[0]
+                .place
+                .timeline_in_sec,
+            2.0
+        );
+    }
+
     // =========================================================================
     // CreateAdjustmentLayerCommand Tests
     // =========================================================================
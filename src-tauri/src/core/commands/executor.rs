@@ -1239,6 +1239,7 @@ impl CommandExecutor {
                     "relativePath": asset.relative_path.clone(),
                     "workspaceManaged": asset.workspace_managed,
                     "missing": asset.missing,
+                    "source": asset.source.clone(),
                 }))
             }
 
@@ -1282,6 +1283,10 @@ impl CommandExecutor {
                         ))
                     })?,
                 );
+                payload.insert(
+                    "startTimecode".to_string(),
+                    serde_json::json!(sequence.start_timecode),
+                );
 
                 Ok(serde_json::Value::Object(payload))
             }
@@ -1370,7 +1375,7 @@ impl CommandExecutor {
                     .cloned()
                     .unwrap_or(serde_json::Value::Null);
 
-                Ok(serde_json::json!({
+                let mut payload = serde_json::json!({
                     "sequenceId": seq_id,
                     "trackId": track_id,
                     "name": name,
@@ -1379,6 +1384,58 @@ impl CommandExecutor {
                     "locked": locked,
                     "visible": visible,
                     "volume": volume,
+                });
+
+                // Surround pan can be cleared, so Null cannot mean "unchanged" for
+                // it; the key is only present when this edit set it.
+                if type_name == "SetTrackSurroundPan" {
+                    let sequence = state.sequences.get(seq_id).ok_or_else(|| {
+                        CoreError::Internal(format!(
+                            "TrackUpdate could not find sequence: {seq_id}"
+                        ))
+                    })?;
+                    let track = sequence.get_track(track_id).ok_or_else(|| {
+                        CoreError::Internal(format!("TrackUpdate could not find track: {track_id}"))
+                    })?;
+                    payload["surroundPan"] = to_value(&track.surround_pan)?;
+                }
+
+                Ok(payload)
+            }
+
+            OpKind::AudioBusCreate
+            | OpKind::AudioBusRemove
+            | OpKind::AudioBusUpdate
+            | OpKind::AudioBusRoute
+            | OpKind::AudioBusEffectAdd
+            | OpKind::AudioBusEffectRemove => {
+                let seq_id = get_str(&command_json, "sequenceId").ok_or_else(|| {
+                    CoreError::Internal(format!("{type_name} payload missing sequenceId"))
+                })?;
+                let sequence = state.sequences.get(seq_id).ok_or_else(|| {
+                    CoreError::Internal(format!("{type_name} could not find sequence: {seq_id}"))
+                })?;
+
+                // The whole routing, every track's output and the bus effects, so
+                // replay restores the mix without knowing which edit made it.
+                let track_output_buses: serde_json::Map<String, serde_json::Value> = sequence
+                    .tracks
+                    .iter()
+                    .map(|track| (track.id.clone(), serde_json::json!(track.output_bus_id)))
+                    .collect();
+                let effects = sequence
+                    .audio_buses
+                    .iter()
+                    .flat_map(|bus| bus.effects.iter())
+                    .filter_map(|effect_id| state.effects.get(effect_id))
+                    .map(to_value)
+                    .collect::<CoreResult<Vec<_>>>()?;
+
+                Ok(serde_json::json!({
+                    "sequenceId": seq_id,
+                    "audioBuses": to_value(&sequence.audio_buses)?,
+                    "trackOutputBuses": track_output_buses,
+                    "effects": effects,
                 }))
             }
 
@@ -1564,6 +1621,45 @@ impl CommandExecutor {
                 }))
             }
 
+            OpKind::ClipSlip | OpKind::ClipSlide | OpKind::ClipRoll => {
+                let seq_id = get_str(&command_json, "sequenceId").ok_or_else(|| {
+                    CoreError::Internal(format!("{type_name} payload missing sequenceId"))
+                })?;
+                let sequence = state.sequences.get(seq_id).ok_or_else(|| {
+                    CoreError::Internal(format!("{type_name} could not find sequence: {seq_id}"))
+                })?;
+
+                // One entry per touched clip, including sync-locked partners and
+                // trimmed neighbours, so replay needs no knowledge of the edit.
+                let mut clips = Vec::new();
+                for change in &result.changes {
+                    let StateChange::ClipModified { clip_id } = change else {
+                        continue;
+                    };
+                    let clip = sequence
+                        .tracks
+                        .iter()
+                        .find_map(|t| t.get_clip(clip_id))
+                        .ok_or_else(|| {
+                            CoreError::Internal(format!(
+                                "{type_name} could not find clip: {clip_id}"
+                            ))
+                        })?;
+                    clips.push(serde_json::json!({
+                        "clipId": clip_id,
+                        "sourceIn": clip.range.source_in_sec,
+                        "sourceOut": clip.range.source_out_sec,
+                        "timelineIn": clip.place.timeline_in_sec,
+                        "duration": clip.place.duration_sec,
+                    }));
+                }
+
+                Ok(serde_json::json!({
+                    "sequenceId": seq_id,
+                    "clips": clips,
+                }))
+            }
+
             OpKind::ClipSplit => {
                 let seq_id = get_str(&command_json, "sequenceId").ok_or_else(|| {
                     CoreError::Internal("ClipSplit payload missing sequenceId".to_string())
@@ -1977,6 +2073,54 @@ impl CommandExecutor {
                 }))
             }
 
+            OpKind::MulticamClipCreate
+            | OpKind::MulticamAngleSwitch
+            | OpKind::MulticamClipFlatten
+            | OpKind::ClipSync => {
+                let seq_id = get_str(&command_json, "sequenceId").ok_or_else(|| {
+                    CoreError::Internal(format!("{type_name} payload missing sequenceId"))
+                })?;
+                let sequence = state.sequences.get(seq_id).ok_or_else(|| {
+                    CoreError::Internal(format!("{type_name} could not find sequence: {seq_id}"))
+                })?;
+
+                // Snapshots of every clip the edit wrote, plus any inner sequence it
+                // created, so replay needs neither the angles, the offsets nor fresh IDs.
+                let (created_clip_ids, modified_clip_ids, deleted_clip_ids) =
+                    Self::collect_clip_change_ids(result);
+                let mut clips = Vec::new();
+                for clip_id in modified_clip_ids.iter().chain(&created_clip_ids) {
+                    let (track_id, clip) = Self::find_clip_in_sequence(sequence, clip_id)
+                        .ok_or_else(|| {
+                            CoreError::Internal(format!(
+                                "{type_name} could not find clip: {clip_id}"
+                            ))
+                        })?;
+                    clips.push(serde_json::json!({
+                        "trackId": track_id,
+                        "clip": to_value(clip)?,
+                    }));
+                }
+                let inner_sequence = result
+                    .changes
+                    .iter()
+                    .find_map(|change| match change {
+                        StateChange::SequenceCreated { sequence_id } => {
+                            state.sequences.get(sequence_id)
+                        }
+                        _ => None,
+                    })
+                    .map(to_value)
+                    .transpose()?
+                    .unwrap_or(serde_json::Value::Null);
+
+                let mut payload = command_json;
+                payload["removedClipIds"] = serde_json::json!(deleted_clip_ids);
+                payload["clips"] = serde_json::Value::Array(clips);
+                payload["innerSequence"] = inner_sequence;
+                Ok(payload)
+            }
+
             OpKind::CompoundClipUnnest
             | OpKind::ClipGroup
             | OpKind::ClipUngroup
@@ -2101,6 +2245,9 @@ impl CommandExecutor {
             "RemoveClip" | "DeleteClip" => OpKind::ClipRemove,
             "MoveClip" => OpKind::ClipMove,
             "TrimClip" => OpKind::ClipTrim,
+            "SlipClip" => OpKind::ClipSlip,
+            "SlideClip" => OpKind::ClipSlide,
+            "RollEdit" => OpKind::ClipRoll,
             "SetClipMute"
             | "SetClipTransform"
             | "SetClipMotionKeyframes"
@@ -2120,6 +2267,10 @@ impl CommandExecutor {
             "SplitClip" => OpKind::ClipSplit,
             "CreateCompoundClip" => OpKind::CompoundClipCreate,
             "UnnestCompoundClip" => OpKind::CompoundClipUnnest,
+            "CreateMulticamClip" => OpKind::MulticamClipCreate,
+            "SwitchMulticamAngle" => OpKind::MulticamAngleSwitch,
+            "FlattenMulticamClip" => OpKind::MulticamClipFlatten,
+            "SyncClips" => OpKind::ClipSync,
             "GroupClips" => OpKind::ClipGroup,
             "UngroupClips" => OpKind::ClipUngroup,
             "LinkClips" => OpKind::ClipLink,
@@ -2132,10 +2283,17 @@ impl CommandExecutor {
             | "SetCaptionTrackLanguage"
             | "SetTrackBlendMode"
             | "SetTrackVolume"
+            | "SetTrackSurroundPan"
             | "ToggleTrackMute"
             | "ToggleTrackLock"
             | "ToggleTrackVisibility"
             | "UpdateTrack" => OpKind::TrackUpdate,
+            "CreateAudioBus" => OpKind::AudioBusCreate,
+            "RemoveAudioBus" => OpKind::AudioBusRemove,
+            "UpdateAudioBus" => OpKind::AudioBusUpdate,
+            "SetTrackOutputBus" => OpKind::AudioBusRoute,
+            "AddAudioBusEffect" => OpKind::AudioBusEffectAdd,
+            "RemoveAudioBusEffect" => OpKind::AudioBusEffectRemove,
             "ImportAsset" | "AddAsset" => OpKind::AssetImport,
             "RemoveAsset" | "DeleteAsset" => OpKind::AssetRemove,
             "UpdateAsset" => OpKind::AssetUpdate,
@@ -2154,9 +2312,10 @@ impl CommandExecutor {
             "CreateCaption" => OpKind::CaptionAdd,
             "DeleteCaption" => OpKind::CaptionRemove,
             "CreateSequence" => OpKind::SequenceCreate,
-            "UpdateSequence" | "SetMasterVolume" | "UpdateSequenceHdrSettings" => {
-                OpKind::SequenceUpdate
-            }
+            "UpdateSequence"
+            | "SetMasterVolume"
+            | "UpdateSequenceHdrSettings"
+            | "SetSequenceStartTimecode" => OpKind::SequenceUpdate,
             "RemoveSequence" | "DeleteSequence" => OpKind::SequenceRemove,
             "CreateProject" => OpKind::ProjectCreate,
             "UpdateProjectSettings" => OpKind::ProjectSettings,
@@ -2306,24 +2465,27 @@ mod tests {
     use super::*;
     use crate::core::assets::{Asset, VideoInfo};
     use crate::core::commands::{
-        AddAudioKeyframeCommand, AddEffectCommand, AddMarkerCommand, AddMaskCommand,
-        AddTextClipCommand, CloseAllGapsCommand, CloseGapCommand, CreateAdjustmentLayerCommand,
-        CreateCompoundClipCommand, CreateSequenceCommand, ExtractEditCommand,
-        GeneratedCaptionSegment, GroupClipsCommand, ImportAssetCommand,
+        AddAudioBusEffectCommand, AddAudioKeyframeCommand, AddEffectCommand, AddMarkerCommand,
+        AddMaskCommand, AddTextClipCommand, AddTrackCommand, CloseAllGapsCommand, CloseGapCommand,
+        CreateAdjustmentLayerCommand, CreateAudioBusCommand, CreateCompoundClipCommand,
+        CreateMulticamClipCommand, CreateSequenceCommand, ExtractEditCommand,
+        FlattenMulticamClipCommand, GeneratedCaptionSegment, GroupClipsCommand, ImportAssetCommand,
         ImportGeneratedCaptionsCommand, InsertClipCommand, InsertEditCommand, LiftCommand,
-        LinkClipsCommand, MoveClipCommand, OverwriteEditCommand, ReverseClipCommand,
-        RippleDeleteCommand, SetAudioFadeInCommand, SetAudioFadeOutCommand,
-        SetClipBlendModeCommand, SetClipEnabledCommand, SetClipMotionKeyframesCommand,
-        SetClipOpacityCommand, SetClipSpeedCommand, SetMasterVolumeCommand,
-        SetTrackBlendModeCommand, SplitClipCommand, StateChange, TrimClipCommand,
-        UngroupClipsCommand, UnlinkClipsCommand, UnnestCompoundClipCommand,
+        LinkClipsCommand, MoveClipCommand, OverwriteEditCommand, RemoveAudioBusCommand,
+        ReverseClipCommand, RippleDeleteCommand, RollEditCommand, SetAudioFadeInCommand,
+        SetAudioFadeOutCommand, SetClipBlendModeCommand, SetClipEnabledCommand,
+        SetClipMotionKeyframesCommand, SetClipOpacityCommand, SetClipSpeedCommand,
+        SetMasterVolumeCommand, SetTrackBlendModeCommand, SetTrackOutputBusCommand,
+        SetTrackSurroundPanCommand, SlideClipCommand, SlipClipCommand, SplitClipCommand,
+        StateChange, SwitchMulticamAngleCommand, SyncClipOffset, SyncClipsCommand, TrimClipCommand,
+        UngroupClipsCommand, UnlinkClipsCommand, UnnestCompoundClipCommand, UpdateAudioBusCommand,
     };
     use crate::core::effects::{EffectType, ParamValue};
     use crate::core::masks::{MaskShape, RectMask};
     use crate::core::project::{OpKind, Operation, OpsLog, ProjectMeta, ProjectState};
     use crate::core::timeline::{
-        BlendMode, Clip, FadeType, KeyframeInterpolation, Sequence, SequenceFormat, Track,
-        TrackKind, Transform, TransformKeyframe,
+        BlendMode, Clip, FadeType, KeyframeInterpolation, Sequence, SequenceFormat, SurroundPan,
+        Track, TrackKind, Transform, TransformKeyframe,
     };
     use tempfile::TempDir;
 
@@ -3880,13 +4042,28 @@ mod tests {
         assert_kind("SetAudioFadeIn", OpKind::ClipUpdate);
         assert_kind("SetClipMotionKeyframes", OpKind::ClipUpdate);
         assert_kind("SetMasterVolume", OpKind::SequenceUpdate);
+        assert_kind("SetSequenceStartTimecode", OpKind::SequenceUpdate);
         assert_kind("CreateCompoundClip", OpKind::CompoundClipCreate);
         assert_kind("UnnestCompoundClip", OpKind::CompoundClipUnnest);
+        assert_kind("CreateMulticamClip", OpKind::MulticamClipCreate);
+        assert_kind("SwitchMulticamAngle", OpKind::MulticamAngleSwitch);
+        assert_kind("FlattenMulticamClip", OpKind::MulticamClipFlatten);
+        assert_kind("SyncClips", OpKind::ClipSync);
+        assert_kind("CreateAudioBus", OpKind::AudioBusCreate);
+        assert_kind("RemoveAudioBus", OpKind::AudioBusRemove);
+        assert_kind("UpdateAudioBus", OpKind::AudioBusUpdate);
+        assert_kind("SetTrackOutputBus", OpKind::AudioBusRoute);
+        assert_kind("SetTrackSurroundPan", OpKind::TrackUpdate);
+        assert_kind("AddAudioBusEffect", OpKind::AudioBusEffectAdd);
+        assert_kind("RemoveAudioBusEffect", OpKind::AudioBusEffectRemove);
         assert_kind("GroupClips", OpKind::ClipGroup);
         assert_kind("UngroupClips", OpKind::ClipUngroup);
         assert_kind("LinkClips", OpKind::ClipLink);
         assert_kind("UnlinkClips", OpKind::ClipUnlink);
         assert_kind("CreateAdjustmentLayer", OpKind::ClipAdd);
+        assert_kind("SlipClip", OpKind::ClipSlip);
+        assert_kind("SlideClip", OpKind::ClipSlide);
+        assert_kind("RollEdit", OpKind::ClipRoll);
 
         let error = CommandExecutor::type_name_to_op_kind("UnknownCommand").unwrap_err();
         assert_eq!(
@@ -4796,6 +4973,522 @@ mod tests {
         assert_eq!(ordered, vec![clip_id, new_clip_id]);
     }
 
+    #[test]
+    fn test_executor_logs_slip_slide_and_roll_as_single_replayable_ops() {
+        let temp_dir = TempDir::new().unwrap();
+        let ops_path = temp_dir.path().join("ops.jsonl");
+
+        let mut executor = CommandExecutor::with_ops_log(OpsLog::new(&ops_path));
+        let mut state = ProjectState::new_empty("Test");
+
+        executor
+            .execute(
+                Box::new(CreateSequenceCommand::new("Main", "1080p")),
+                &mut state,
+            )
+            .unwrap();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        let track_id = state.sequences[&seq_id].tracks[0].id.clone();
+
+        let asset_path = temp_dir.path().join("test.mp4");
+        std::fs::write(&asset_path, b"test").unwrap();
+        let import_cmd =
+            ImportAssetCommand::new("test.mp4", &asset_path.to_string_lossy()).with_duration(30.0);
+        executor
+            .execute(Box::new(import_cmd.clone()), &mut state)
+            .unwrap();
+        let asset_id = import_cmd.asset_id().to_string();
+
+        // Three contiguous clips [A(0-10), B(10-20), C(20-30)], each playing source 5-15.
+        let mut clip_ids = Vec::new();
+        for i in 0..3 {
+            let insert = InsertClipCommand::new(&seq_id, &track_id, &asset_id, (i * 10) as f64)
+                .with_source_range(5.0, 15.0);
+            let result = executor.execute(Box::new(insert), &mut state).unwrap();
+            clip_ids.push(result.created_ids[0].clone());
+        }
+        let undo_count_before = executor.undo_count();
+
+        executor
+            .execute(
+                Box::new(SlipClipCommand::new(&seq_id, &track_id, &clip_ids[1], 2.0)),
+                &mut state,
+            )
+            .unwrap();
+        executor
+            .execute(
+                Box::new(SlideClipCommand::new(&seq_id, &track_id, &clip_ids[1], 3.0)),
+                &mut state,
+            )
+            .unwrap();
+        executor
+            .execute(
+                Box::new(RollEditCommand::new(
+                    &seq_id,
+                    &track_id,
+                    &clip_ids[0],
+                    &clip_ids[1],
+                    -1.0,
+                )),
+                &mut state,
+            )
+            .unwrap();
+
+        assert_eq!(executor.undo_count(), undo_count_before + 3);
+
+        let ops = OpsLog::new(&ops_path).read_all().unwrap().operations;
+        let kinds: Vec<_> = ops[ops.len() - 3..]
+            .iter()
+            .map(|op| op.kind.clone())
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![OpKind::ClipSlip, OpKind::ClipSlide, OpKind::ClipRoll]
+        );
+        assert_eq!(
+            ops[ops.len() - 2].payload["clips"]
+                .as_array()
+                .unwrap()
+                .len(),
+            3
+        );
+
+        let replayed =
+            ProjectState::from_ops_log(&OpsLog::new(&ops_path), ProjectMeta::new("Test")).unwrap();
+        let live_track = state.sequences[&seq_id].get_track(&track_id).unwrap();
+        let replayed_track = replayed.sequences[&seq_id].get_track(&track_id).unwrap();
+        for clip_id in &clip_ids {
+            let live = live_track.get_clip(clip_id).unwrap();
+            let replayed = replayed_track.get_clip(clip_id).unwrap();
+            assert_eq!(replayed.range, live.range);
+            assert_eq!(replayed.place, live.place);
+        }
+
+        // A: extended by the slide, then rolled back by one second.
+        let a = live_track.get_clip(&clip_ids[0]).unwrap();
+        assert_eq!(a.place.duration_sec, 12.0);
+        assert_eq!(a.range.source_out_sec, 17.0);
+        // B: slipped to source 7-17, slid to 13, then its head extended to 12.
+        let b = live_track.get_clip(&clip_ids[1]).unwrap();
+        assert_eq!(b.place.timeline_in_sec, 12.0);
+        assert_eq!(b.place.duration_sec, 11.0);
+        assert_eq!(b.range.source_in_sec, 6.0);
+        assert_eq!(b.range.source_out_sec, 17.0);
+
+        // One undo reverts the whole roll.
+        executor.undo(&mut state).unwrap();
+        let a = state.sequences[&seq_id]
+            .get_track(&track_id)
+            .unwrap()
+            .get_clip(&clip_ids[0])
+            .unwrap();
+        assert_eq!(a.place.duration_sec, 13.0);
+    }
+
+    #[test]
+    fn test_executor_multicam_edits_replay_from_ops_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let ops_path = temp_dir.path().join("ops.jsonl");
+
+        let mut executor = CommandExecutor::with_ops_log(OpsLog::new(&ops_path));
+        let mut state = ProjectState::new_empty("Test");
+
+        executor
+            .execute(
+                Box::new(CreateSequenceCommand::new("Main", "1080p")),
+                &mut state,
+            )
+            .unwrap();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        executor
+            .execute(
+                Box::new(AddTrackCommand::new(&seq_id, "Video 2", TrackKind::Video)),
+                &mut state,
+            )
+            .unwrap();
+        let video_track_ids: Vec<String> = state.sequences[&seq_id]
+            .tracks
+            .iter()
+            .filter(|track| track.kind == TrackKind::Video)
+            .map(|track| track.id.clone())
+            .collect();
+
+        let asset_path = temp_dir.path().join("test.mp4");
+        std::fs::write(&asset_path, b"test").unwrap();
+        let import_cmd =
+            ImportAssetCommand::new("test.mp4", &asset_path.to_string_lossy()).with_duration(30.0);
+        executor
+            .execute(Box::new(import_cmd.clone()), &mut state)
+            .unwrap();
+        let asset_id = import_cmd.asset_id().to_string();
+
+        // One angle per video track, both covering [0-20].
+        let mut angle_clip_ids = Vec::new();
+        for track_id in &video_track_ids {
+            let insert = InsertClipCommand::new(&seq_id, track_id, &asset_id, 0.0)
+                .with_source_range(0.0, 20.0);
+            let result = executor.execute(Box::new(insert), &mut state).unwrap();
+            angle_clip_ids.push(result.created_ids[0].clone());
+        }
+
+        let target_track_id = video_track_ids[0].clone();
+        let created = executor
+            .execute(
+                Box::new(CreateMulticamClipCommand::new(
+                    &seq_id,
+                    &target_track_id,
+                    angle_clip_ids,
+                )),
+                &mut state,
+            )
+            .unwrap();
+        let multicam_id = created.created_ids[0].clone();
+        let inner_id = state.sequences[&seq_id]
+            .get_track(&target_track_id)
+            .unwrap()
+            .get_clip(&multicam_id)
+            .unwrap()
+            .compound_sequence_id
+            .clone()
+            .unwrap();
+        let second_angle = state.sequences[&inner_id].tracks[1].id.clone();
+
+        let switched = executor
+            .execute(
+                Box::new(
+                    SwitchMulticamAngleCommand::new(
+                        &seq_id,
+                        &target_track_id,
+                        &multicam_id,
+                        &second_angle,
+                    )
+                    .at(5.0),
+                ),
+                &mut state,
+            )
+            .unwrap();
+        executor
+            .execute(
+                Box::new(FlattenMulticamClipCommand::new(
+                    &seq_id,
+                    &target_track_id,
+                    &switched.created_ids[0],
+                )),
+                &mut state,
+            )
+            .unwrap();
+
+        let ops = OpsLog::new(&ops_path).read_all().unwrap().operations;
+        let kinds: Vec<_> = ops[ops.len() - 3..]
+            .iter()
+            .map(|op| op.kind.clone())
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                OpKind::MulticamClipCreate,
+                OpKind::MulticamAngleSwitch,
+                OpKind::MulticamClipFlatten
+            ]
+        );
+
+        let replayed =
+            ProjectState::from_ops_log(&OpsLog::new(&ops_path), ProjectMeta::new("Test")).unwrap();
+        assert!(replayed.sequences.contains_key(&inner_id));
+        for track_id in &video_track_ids {
+            let live = &state.sequences[&seq_id].get_track(track_id).unwrap().clips;
+            let replayed = &replayed.sequences[&seq_id]
+                .get_track(track_id)
+                .unwrap()
+                .clips;
+            assert_eq!(replayed.len(), live.len());
+            for (replayed, live) in replayed.iter().zip(live) {
+                assert_eq!(replayed.id, live.id);
+                assert_eq!(replayed.place, live.place);
+                assert_eq!(replayed.range, live.range);
+                assert_eq!(replayed.multicam_angle, live.multicam_angle);
+            }
+        }
+    }
+
+    #[test]
+    fn test_executor_audio_bus_routing_replays_from_ops_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let ops_path = temp_dir.path().join("ops.jsonl");
+
+        let mut executor = CommandExecutor::with_ops_log(OpsLog::new(&ops_path));
+        let mut state = ProjectState::new_empty("Test");
+
+        executor
+            .execute(
+                Box::new(CreateSequenceCommand::new("Main", "1080p")),
+                &mut state,
+            )
+            .unwrap();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        executor
+            .execute(
+                Box::new(AddTrackCommand::new(&seq_id, "DX", TrackKind::Audio)),
+                &mut state,
+            )
+            .unwrap();
+        let track_id = state.sequences[&seq_id]
+            .tracks
+            .iter()
+            .find(|track| track.name == "DX")
+            .unwrap()
+            .id
+            .clone();
+
+        let stems = executor
+            .execute(
+                Box::new(CreateAudioBusCommand::new(&seq_id, "Stems")),
+                &mut state,
+            )
+            .unwrap()
+            .created_ids[0]
+            .clone();
+        let dialog = executor
+            .execute(
+                Box::new(CreateAudioBusCommand::new(&seq_id, "Dialog").with_output(&stems)),
+                &mut state,
+            )
+            .unwrap()
+            .created_ids[0]
+            .clone();
+        executor
+            .execute(
+                Box::new(SetTrackOutputBusCommand::new(
+                    &seq_id,
+                    &track_id,
+                    Some(&dialog),
+                )),
+                &mut state,
+            )
+            .unwrap();
+        let compressor = executor
+            .execute(
+                Box::new(AddAudioBusEffectCommand::new(
+                    &seq_id,
+                    &dialog,
+                    EffectType::Compressor,
+                )),
+                &mut state,
+            )
+            .unwrap()
+            .created_ids[0]
+            .clone();
+        executor
+            .execute(
+                Box::new(UpdateAudioBusCommand::new(&seq_id, &dialog).with_volume_db(-4.0)),
+                &mut state,
+            )
+            .unwrap();
+        executor
+            .execute(
+                Box::new(RemoveAudioBusCommand::new(&seq_id, &stems)),
+                &mut state,
+            )
+            .unwrap();
+
+        let ops = OpsLog::new(&ops_path).read_all().unwrap().operations;
+        assert_eq!(ops.last().unwrap().kind, OpKind::AudioBusRemove);
+
+        let replayed =
+            ProjectState::from_ops_log(&OpsLog::new(&ops_path), ProjectMeta::new("Test")).unwrap();
+        let live = &state.sequences[&seq_id];
+        let restored = &replayed.sequences[&seq_id];
+        assert_eq!(restored.audio_buses, live.audio_buses);
+        assert_eq!(restored.audio_buses.len(), 1);
+        assert_eq!(restored.audio_buses[0].output_bus_id, None);
+        assert_eq!(restored.audio_buses[0].volume_db, -4.0);
+        assert_eq!(
+            restored.get_track(&track_id).unwrap().output_bus_id,
+            Some(dialog)
+        );
+        assert_eq!(
+            replayed.effects[&compressor].effect_type,
+            EffectType::Compressor
+        );
+    }
+
+    #[test]
+    fn test_executor_track_surround_pan_replays_from_ops_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let ops_path = temp_dir.path().join("ops.jsonl");
+
+        let mut executor = CommandExecutor::with_ops_log(OpsLog::new(&ops_path));
+        let mut state = ProjectState::new_empty("Test");
+
+        executor
+            .execute(
+                Box::new(CreateSequenceCommand::new("Main", "1080p")),
+                &mut state,
+            )
+            .unwrap();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        let track_id = executor
+            .execute(
+                Box::new(AddTrackCommand::new(&seq_id, "FX", TrackKind::Audio)),
+                &mut state,
+            )
+            .unwrap()
+            .created_ids[0]
+            .clone();
+
+        let pan = SurroundPan {
+            x: 0.5,
+            y: -1.0,
+            lfe: 0.5,
+        };
+        executor
+            .execute(
+                Box::new(SetTrackSurroundPanCommand::new(
+                    &seq_id,
+                    &track_id,
+                    Some(pan),
+                )),
+                &mut state,
+            )
+            .unwrap();
+        let replayed =
+            ProjectState::from_ops_log(&OpsLog::new(&ops_path), ProjectMeta::new("Test")).unwrap();
+        assert_eq!(
+            replayed.sequences[&seq_id]
+                .get_track(&track_id)
+                .unwrap()
+                .surround_pan,
+            Some(pan)
+        );
+
+        executor
+            .execute(
+                Box::new(SetTrackSurroundPanCommand::new(&seq_id, &track_id, None)),
+                &mut state,
+            )
+            .unwrap();
+        let replayed =
+            ProjectState::from_ops_log(&OpsLog::new(&ops_path), ProjectMeta::new("Test")).unwrap();
+        assert_eq!(
+            replayed.sequences[&seq_id]
+                .get_track(&track_id)
+                .unwrap()
+                .surround_pan,
+            None
+        );
+    }
+
+    #[test]
+    fn test_executor_sync_clips_replays_from_ops_log() {
+        let temp_dir = TempDir::new().unwrap();
+        let ops_path = temp_dir.path().join("ops.jsonl");
+
+        let mut executor = CommandExecutor::with_ops_log(OpsLog::new(&ops_path));
+        let mut state = ProjectState::new_empty("Test");
+
+        executor
+            .execute(
+                Box::new(CreateSequenceCommand::new("Main", "1080p")),
+                &mut state,
+            )
+            .unwrap();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+        executor
+            .execute(
+                Box::new(AddTrackCommand::new(&seq_id, "Recorder", TrackKind::Audio)),
+                &mut state,
+            )
+            .unwrap();
+        let video_track_id = state.sequences[&seq_id].tracks[0].id.clone();
+        let audio_track_id = state.sequences[&seq_id]
+            .tracks
+            .iter()
+            .find(|track| track.name == "Recorder")
+            .unwrap()
+            .id
+            .clone();
+
+        let asset_path = temp_dir.path().join("test.mp4");
+        std::fs::write(&asset_path, b"test").unwrap();
+        let import_cmd =
+            ImportAssetCommand::new("test.mp4", &asset_path.to_string_lossy()).with_duration(30.0);
+        executor
+            .execute(Box::new(import_cmd.clone()), &mut state)
+            .unwrap();
+        let asset_id = import_cmd.asset_id().to_string();
+
+        let camera = executor
+            .execute(
+                Box::new(
+                    InsertClipCommand::new(&seq_id, &video_track_id, &asset_id, 2.0)
+                        .with_source_range(0.0, 20.0),
+                ),
+                &mut state,
+            )
+            .unwrap()
+            .created_ids[0]
+            .clone();
+        let recorder = executor
+            .execute(
+                Box::new(
+                    InsertClipCommand::new(&seq_id, &audio_track_id, &asset_id, 0.0)
+                        .with_source_range(0.0, 20.0),
+                ),
+                &mut state,
+            )
+            .unwrap()
+            .created_ids[0]
+            .clone();
+
+        executor
+            .execute(
+                Box::new(
+                    SyncClipsCommand::new(
+                        &seq_id,
+                        &video_track_id,
+                        &camera,
+                        vec![SyncClipOffset {
+                            track_id: audio_track_id.clone(),
+                            clip_id: recorder.clone(),
+                            offset_sec: 1.5,
+                        }],
+                    )
+                    .with_link(true),
+                ),
+                &mut state,
+            )
+            .unwrap();
+
+        let ops = OpsLog::new(&ops_path).read_all().unwrap().operations;
+        assert_eq!(ops.last().unwrap().kind, OpKind::ClipSync);
+
+        let replayed =
+            ProjectState::from_ops_log(&OpsLog::new(&ops_path), ProjectMeta::new("Test")).unwrap();
+        let live_clip = state.sequences[&seq_id]
+            .get_track(&audio_track_id)
+            .unwrap()
+            .get_clip(&recorder)
+            .unwrap();
+        let replayed_clip = replayed.sequences[&seq_id]
+            .get_track(&audio_track_id)
+            .unwrap()
+            .get_clip(&recorder)
+            .unwrap();
+        assert!((live_clip.place.timeline_in_sec - 3.5).abs() < 1e-9);
+        assert_eq!(replayed_clip.place, live_clip.place);
+        assert!(live_clip.link_group_id.is_some());
+        assert_eq!(replayed_clip.link_group_id, live_clip.link_group_id);
+        assert_eq!(
+            replayed.sequences[&seq_id]
+                .get_track(&video_track_id)
+                .unwrap()
+                .get_clip(&camera)
+                .unwrap()
+                .link_group_id,
+            live_clip.link_group_id
+        );
+    }
+
     #[test]
     fn test_executor_ops_log_replay_roundtrip_for_audio_automation_and_master_volume() {
         let temp_dir = TempDir::new().unwrap();
@@ -4,6 +4,7 @@
 //! All editing operations are performed through Commands in this module.
 
 mod asset;
+mod audio_bus;
 mod caption;
 mod clip;
 mod effect;
@@ -19,6 +20,7 @@ mod track;
 mod traits;
 
 pub use asset::*;
+pub use audio_bus::*;
 pub use caption::*;
 pub use clip::*;
 pub use effect::*;
@@ -15,7 +15,7 @@ use crate::core::{
 // UpdateProjectSettingsCommand
 // =============================================================================
 
-/// Updates project metadata (name/description/author).
+/// Updates project metadata (name/description/author/copyright).
 ///
 /// Note: This only supports setting values (not clearing Option fields) because
 /// the replay handler (`apply_project_settings`) only applies `as_str()` fields.
@@ -31,6 +31,9 @@ pub struct UpdateProjectSettingsCommand {
     /// New author name (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
+    /// New copyright notice (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
 
     /// Previous name (for undo)
     #[serde(skip)]
@@ -41,6 +44,9 @@ pub struct UpdateProjectSettingsCommand {
     /// Previous author (for undo)
     #[serde(skip)]
     previous_author: Option<Option<String>>,
+    /// Previous copyright notice (for undo)
+    #[serde(skip)]
+    previous_copyright: Option<Option<String>>,
 }
 
 impl UpdateProjectSettingsCommand {
@@ -52,9 +58,11 @@ impl UpdateProjectSettingsCommand {
             name: None,
             description: None,
             author: None,
+            copyright: None,
             previous_name: None,
             previous_description: None,
             previous_author: None,
+            previous_copyright: None,
         }
     }
 
@@ -75,6 +83,12 @@ impl UpdateProjectSettingsCommand {
         self.author = Some(author.to_string());
         self
     }
+
+    /// Sets a new copyright notice.
+    pub fn with_copyright(mut self, copyright: &str) -> Self {
+        self.copyright = Some(copyright.to_string());
+        self
+    }
 }
 
 impl Default for UpdateProjectSettingsCommand {
@@ -85,7 +99,11 @@ impl Default for UpdateProjectSettingsCommand {
 
 impl Command for UpdateProjectSettingsCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        if self.name.is_none() && self.description.is_none() && self.author.is_none() {
+        if self.name.is_none()
+            && self.description.is_none()
+            && self.author.is_none()
+            && self.copyright.is_none()
+        {
             return Err(CoreError::InvalidCommand(
                 "UpdateProjectSettings requires at least one field".to_string(),
             ));
@@ -104,6 +122,7 @@ impl Command for UpdateProjectSettingsCommand {
         self.previous_name = Some(state.meta.name.clone());
         self.previous_description = Some(state.meta.description.clone());
         self.previous_author = Some(state.meta.author.clone());
+        self.previous_copyright = Some(state.meta.copyright.clone());
 
         if let Some(name) = &self.name {
             state.meta.name = name.trim().to_string();
@@ -114,6 +133,9 @@ impl Command for UpdateProjectSettingsCommand {
         if let Some(author) = &self.author {
             state.meta.author = Some(author.to_string());
         }
+        if let Some(copyright) = &self.copyright {
+            state.meta.copyright = Some(copyright.to_string());
+        }
 
         let op_id = ulid::Ulid::new().to_string();
         Ok(CommandResult::new(&op_id))
@@ -129,6 +151,9 @@ impl Command for UpdateProjectSettingsCommand {
         if let Some(previous_author) = &self.previous_author {
             state.meta.author = previous_author.clone();
         }
+        if let Some(previous_copyright) = &self.previous_copyright {
+            state.meta.copyright = previous_copyright.clone();
+        }
         Ok(())
     }
 
@@ -162,13 +187,15 @@ mod tests {
         let cmd = UpdateProjectSettingsCommand::new()
             .with_name("Updated")
             .with_description("Desc")
-            .with_author("Author");
+            .with_author("Author")
+            .with_copyright("(c) 2026 Studio");
 
         executor.execute(Box::new(cmd), &mut state).unwrap();
 
         assert_eq!(state.meta.name, "Updated");
         assert_eq!(state.meta.description.as_deref(), Some("Desc"));
         assert_eq!(state.meta.author.as_deref(), Some("Author"));
+        assert_eq!(state.meta.copyright.as_deref(), Some("(c) 2026 Studio"));
         assert!(state.is_dirty);
     }
 
@@ -6,9 +6,10 @@ use serde::{Deserialize, Serialize};
 
 use crate::core::{
     commands::{Command, CommandResult, StateChange},
+    interchange::models::{is_drop_frame_rate, Timecode},
     project::ProjectState,
     timeline::{Sequence, SequenceFormat, SequenceHdrSettings, Track, TrackKind},
-    CoreError, CoreResult, SequenceId,
+    CoreError, CoreResult, Ratio, SequenceId,
 };
 
 // =============================================================================
@@ -25,6 +26,10 @@ pub struct CreateSequenceCommand {
     pub format: String,
     /// Whether to add default tracks
     pub add_default_tracks: bool,
+    /// Frame rate overriding the preset's, for a sequence that has to match
+    /// footage or an imported edit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fps: Option<Ratio>,
     /// Created sequence ID (stored after execution for undo)
     #[serde(skip)]
     created_sequence_id: Option<SequenceId>,
@@ -40,6 +45,7 @@ impl CreateSequenceCommand {
             name: name.to_string(),
             format: format.to_string(),
             add_default_tracks: true,
+            fps: None,
             created_sequence_id: None,
             previous_active_sequence_id: None,
         }
@@ -51,6 +57,12 @@ impl CreateSequenceCommand {
         self
     }
 
+    /// Overrides the preset's frame rate
+    pub fn with_fps(mut self, fps: Ratio) -> Self {
+        self.fps = Some(fps);
+        self
+    }
+
     fn resolve_sequence_format(format: &str) -> SequenceFormat {
         match format.trim().to_ascii_lowercase().as_str() {
             "1080p" | "youtube_1080" | "youtube_1080p" | "landscape_1080" | "1920x1080" => {
@@ -68,7 +80,16 @@ impl CreateSequenceCommand {
 
 impl Command for CreateSequenceCommand {
     fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
-        let seq_format = Self::resolve_sequence_format(&self.format);
+        let mut seq_format = Self::resolve_sequence_format(&self.format);
+        if let Some(fps) = &self.fps {
+            if fps.num <= 0 || fps.den <= 0 {
+                return Err(CoreError::InvalidCommand(format!(
+                    "Invalid sequence frame rate {}/{}",
+                    fps.num, fps.den
+                )));
+            }
+            seq_format.fps = fps.clone();
+        }
 
         // Create sequence
         let mut sequence = Sequence::new(&self.name, seq_format);
@@ -370,6 +391,107 @@ impl Command for UpdateSequenceHdrSettingsCommand {
     }
 }
 
+// =============================================================================
+// SetSequenceStartTimecodeCommand
+// =============================================================================
+
+/// Command to set (or clear) the timecode of a sequence's first frame.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetSequenceStartTimecodeCommand {
+    pub sequence_id: SequenceId,
+    pub start_timecode: Option<String>,
+    #[serde(skip)]
+    previous_start_timecode: Option<Option<String>>,
+}
+
+impl SetSequenceStartTimecodeCommand {
+    pub fn new(sequence_id: &str, start_timecode: Option<String>) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            start_timecode,
+            previous_start_timecode: None,
+        }
+    }
+}
+
+/// Checks a start timecode against the sequence rate and returns it in
+/// canonical `HH:MM:SS:FF` (`;` for drop-frame) form.
+fn normalize_start_timecode(text: &str, fps: &Ratio) -> CoreResult<String> {
+    let timecode = Timecode::parse(text).ok_or_else(|| {
+        CoreError::InvalidCommand(format!(
+            "Invalid start timecode '{text}' (expected HH:MM:SS:FF)"
+        ))
+    })?;
+
+    let nominal_fps = fps.as_f64().round() as u32;
+    if timecode.frames >= nominal_fps.max(1) {
+        return Err(CoreError::InvalidCommand(format!(
+            "Start timecode '{text}' has a frame count beyond the sequence rate"
+        )));
+    }
+    if timecode.drop_frame && !is_drop_frame_rate(fps) {
+        return Err(CoreError::InvalidCommand(format!(
+            "Drop-frame start timecode '{text}' needs a 29.97 or 59.94 fps sequence"
+        )));
+    }
+
+    Ok(timecode.to_string())
+}
+
+impl Command for SetSequenceStartTimecodeCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let next = match self.start_timecode.as_deref() {
+            Some(text) if !text.trim().is_empty() => {
+                Some(normalize_start_timecode(text, &sequence.format.fps)?)
+            }
+            _ => None,
+        };
+
+        self.start_timecode = next.clone();
+        self.previous_start_timecode = Some(sequence.start_timecode.clone());
+        sequence.start_timecode = next;
+        sequence.modified_at = chrono::Utc::now().to_rfc3339();
+        state.is_dirty = true;
+
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::SequenceModified {
+                sequence_id: self.sequence_id.clone(),
+            }),
+        )
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(previous) = &self.previous_start_timecode else {
+            return Ok(());
+        };
+
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            sequence.start_timecode = previous.clone();
+            state.is_dirty = true;
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "SetSequenceStartTimecode"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "sequenceId": self.sequence_id,
+            "startTimecode": self.start_timecode,
+        })
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -400,6 +522,31 @@ mod tests {
         assert_eq!(seq.tracks.len(), 2); // Video + Audio default tracks
     }
 
+    #[test]
+    fn test_create_sequence_with_fps_and_no_default_tracks() {
+        let mut state = create_test_state();
+
+        let mut cmd = CreateSequenceCommand::new("Conform", "1080p")
+            .with_fps(Ratio::new(24000, 1001))
+            .with_default_tracks(false);
+        cmd.execute(&mut state).unwrap();
+
+        let seq = state.sequences.values().next().unwrap();
+        assert_eq!(seq.format.fps, Ratio::new(24000, 1001));
+        assert_eq!(seq.format.canvas.width, 1920);
+        assert!(seq.tracks.is_empty());
+    }
+
+    #[test]
+    fn test_create_sequence_rejects_invalid_fps() {
+        let mut state = create_test_state();
+
+        let mut cmd = CreateSequenceCommand::new("Broken", "1080p").with_fps(Ratio::new(0, 1));
+
+        assert!(cmd.execute(&mut state).is_err());
+        assert!(state.sequences.is_empty());
+    }
+
     #[test]
     fn test_create_sequence_sets_active() {
         let mut state = create_test_state();
@@ -637,4 +784,43 @@ mod tests {
         assert_eq!(settings.max_cll, Some(500));
         assert_eq!(settings.max_fall, Some(500));
     }
+    #[test]
+    fn test_set_sequence_start_timecode_normalizes_and_undoes() {
+        let (mut state, seq_id) = create_test_state_with_sequence();
+
+        let mut cmd = SetSequenceStartTimecodeCommand::new(&seq_id, Some("1:00:00.00".to_string()));
+        cmd.execute(&mut state).unwrap();
+        assert_eq!(
+            state.sequences[&seq_id].start_timecode.as_deref(),
+            Some("01:00:00:00")
+        );
+        assert_eq!(cmd.to_json()["startTimecode"], "01:00:00:00");
+
+        cmd.undo(&mut state).unwrap();
+        assert_eq!(state.sequences[&seq_id].start_timecode, None);
+    }
+
+    #[test]
+    fn test_set_sequence_start_timecode_rejects_timecode_the_rate_cannot_show() {
+        let (mut state, seq_id) = create_test_state_with_sequence();
+        state.sequences.get_mut(&seq_id).unwrap().format.fps = Ratio::new(25, 1);
+
+        for text in ["01:00:00:25", "01:00:00;00", "ten o'clock"] {
+            let mut cmd = SetSequenceStartTimecodeCommand::new(&seq_id, Some(text.to_string()));
+            assert!(
+                cmd.execute(&mut state).is_err(),
+                "{text} should be rejected"
+            );
+        }
+        assert_eq!(state.sequences[&seq_id].start_timecode, None);
+
+        state.sequences.get_mut(&seq_id).unwrap().format.fps = Ratio::new(30000, 1001);
+        let mut cmd =
+            SetSequenceStartTimecodeCommand::new(&seq_id, Some("00:59:59;29".to_string()));
+        cmd.execute(&mut state).unwrap();
+        assert_eq!(
+            state.sequences[&seq_id].start_timecode.as_deref(),
+            Some("00:59:59;29")
+        );
+    }
 }
@@ -233,6 +233,7 @@ impl Command for AddTextClipCommand {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
             group_id: None,
         };
@@ -9,7 +9,7 @@ use serde::{Deserialize, Serialize};
 use crate::core::{
     commands::{Command, CommandResult, StateChange},
     project::ProjectState,
-    timeline::{BlendMode, Track, TrackKind},
+    timeline::{BlendMode, SurroundPan, Track, TrackKind},
     CoreError, CoreResult, SequenceId, TrackId,
 };
 
@@ -743,6 +743,98 @@ impl Command for SetTrackVolumeCommand {
     }
 }
 
+// =============================================================================
+// SetTrackSurroundPanCommand
+// =============================================================================
+
+/// Command to place an audio track in the surround field, or to clear its
+/// position so its clips keep their own channels.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetTrackSurroundPanCommand {
+    /// Target sequence ID
+    pub sequence_id: SequenceId,
+    /// Target track ID
+    pub track_id: TrackId,
+    /// Desired position (`None` clears it)
+    pub surround_pan: Option<SurroundPan>,
+    /// Original position (for undo)
+    #[serde(skip)]
+    original_surround_pan: Option<Option<SurroundPan>>,
+}
+
+impl SetTrackSurroundPanCommand {
+    /// Creates a new set track surround pan command.
+    pub fn new(sequence_id: &str, track_id: &str, surround_pan: Option<SurroundPan>) -> Self {
+        Self {
+            sequence_id: sequence_id.to_string(),
+            track_id: track_id.to_string(),
+            surround_pan,
+            original_surround_pan: None,
+        }
+    }
+}
+
+impl Command for SetTrackSurroundPanCommand {
+    fn execute(&mut self, state: &mut ProjectState) -> CoreResult<CommandResult> {
+        if self.surround_pan.is_some_and(|pan| !pan.is_finite()) {
+            return Err(CoreError::ValidationError(
+                "Surround pan must be finite".to_string(),
+            ));
+        }
+
+        let sequence = state
+            .sequences
+            .get_mut(&self.sequence_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(self.sequence_id.clone()))?;
+
+        let track = sequence
+            .tracks
+            .iter_mut()
+            .find(|t| t.id == self.track_id)
+            .ok_or_else(|| CoreError::TrackNotFound(self.track_id.clone()))?;
+
+        if !track.is_audio() {
+            return Err(CoreError::ValidationError(format!(
+                "Surround pan is only supported for audio tracks: {}",
+                self.track_id
+            )));
+        }
+
+        self.original_surround_pan = Some(track.surround_pan);
+        track.surround_pan = self.surround_pan.map(SurroundPan::clamped);
+
+        let op_id = ulid::Ulid::new().to_string();
+        Ok(
+            CommandResult::new(&op_id).with_change(StateChange::TrackModified {
+                track_id: self.track_id.clone(),
+            }),
+        )
+    }
+
+    fn undo(&self, state: &mut ProjectState) -> CoreResult<()> {
+        let Some(original_surround_pan) = self.original_surround_pan else {
+            return Ok(());
+        };
+
+        if let Some(sequence) = state.sequences.get_mut(&self.sequence_id) {
+            if let Some(track) = sequence.tracks.iter_mut().find(|t| t.id == self.track_id) {
+                track.surround_pan = original_surround_pan;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_name(&self) -> &'static str {
+        "SetTrackSurroundPan"
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or(serde_json::json!({}))
+    }
+}
+
 // =============================================================================
 // ToggleTrackMuteCommand
 // =============================================================================
@@ -1232,6 +1324,48 @@ mod tests {
         assert_eq!(state.sequences[&seq_id].tracks[0].volume, 1.0);
     }
 
+    #[test]
+    fn test_set_track_surround_pan_clamps_clears_and_undoes() {
+        let mut state = create_test_state();
+        let seq_id = state.active_sequence_id.clone().unwrap();
+
+        let mut add_cmd = AddTrackCommand::new(&seq_id, "Audio 1", TrackKind::Audio);
+        let result = add_cmd.execute(&mut state).unwrap();
+        let track_id = result.created_ids[0].clone();
+
+        let pan = SurroundPan {
+            x: -3.0,
+            y: 0.5,
+            lfe: 0.25,
+        };
+        let mut pan_cmd = SetTrackSurroundPanCommand::new(&seq_id, &track_id, Some(pan));
+        pan_cmd.execute(&mut state).unwrap();
+        assert_eq!(
+            state.sequences[&seq_id].tracks[0].surround_pan,
+            Some(SurroundPan {
+                x: -1.0,
+                y: 0.5,
+                lfe: 0.25
+            })
+        );
+
+        let mut clear_cmd = SetTrackSurroundPanCommand::new(&seq_id, &track_id, None);
+        clear_cmd.execute(&mut state).unwrap();
+        assert_eq!(state.sequences[&seq_id].tracks[0].surround_pan, None);
+
+        clear_cmd.undo(&mut state).unwrap();
+        pan_cmd.undo(&mut state).unwrap();
+        assert_eq!(state.sequences[&seq_id].tracks[0].surround_pan, None);
+
+        let mut video_cmd = AddTrackCommand::new(&seq_id, "Video 1", TrackKind::Video);
+        let video_id = video_cmd.execute(&mut state).unwrap().created_ids[0].clone();
+        assert!(
+            SetTrackSurroundPanCommand::new(&seq_id, &video_id, Some(pan))
+                .execute(&mut state)
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_remove_nonexistent_track() {
         let mut state = create_test_state();
@@ -108,6 +108,7 @@ pub fn effect_capability(effect_type: &EffectType) -> EffectCapability {
         EffectType::Curves => EffectCapability::export_supported("curves"),
         EffectType::TemperatureTint => EffectCapability::export_supported("colorbalance"),
         EffectType::Lut => EffectCapability::export_supported("lut3d"),
+        EffectType::BroadcastLegalizer => EffectCapability::export_supported("lutyuv"),
 
         // Transform effects
         EffectType::Crop => EffectCapability::export_supported("crop"),
@@ -269,6 +270,7 @@ fn all_known_effect_types() -> Vec<EffectType> {
         EffectType::Curves,
         EffectType::TemperatureTint,
         EffectType::Lut,
+        EffectType::BroadcastLegalizer,
         EffectType::Crop,
         EffectType::Flip,
         EffectType::Mirror,
@@ -328,6 +330,7 @@ fn effect_type_key(effect_type: &EffectType) -> String {
         EffectType::Curves => "curves",
         EffectType::TemperatureTint => "temperature_tint",
         EffectType::Lut => "lut",
+        EffectType::BroadcastLegalizer => "broadcast_legalizer",
         EffectType::Crop => "crop",
         EffectType::Flip => "flip",
         EffectType::Mirror => "mirror",
@@ -390,6 +393,7 @@ pub fn effect_type_label(effect_type: &EffectType) -> String {
         EffectType::Curves => "Curves",
         EffectType::TemperatureTint => "Temperature / Tint",
         EffectType::Lut => "LUT",
+        EffectType::BroadcastLegalizer => "Broadcast Legalizer",
         EffectType::Crop => "Crop",
         EffectType::Flip => "Flip",
         EffectType::Mirror => "Mirror",
@@ -322,6 +322,7 @@ impl IntoFFmpegFilter for Effect {
             EffectType::Curves => "curves",
             EffectType::TemperatureTint => "colorbalance",
             EffectType::Lut => "lut3d",
+            EffectType::BroadcastLegalizer => "lutyuv",
 
             // Transform effects
             EffectType::Crop => "crop",
@@ -431,6 +432,7 @@ impl Effect {
             EffectType::TemperatureTint => self.build_temperature_tint_filter(),
             EffectType::Curves => self.build_curves_filter(),
             EffectType::Gamma => self.build_gamma_filter(),
+            EffectType::BroadcastLegalizer => self.build_broadcast_legalizer_filter(),
 
             // Transform effects
             EffectType::Flip => "vflip".to_string(),
@@ -531,6 +533,31 @@ impl Effect {
         format!("eq=gamma={:.4}", value)
     }
 
+    /// Builds the `lutyuv` clamp that holds luma and chroma inside legal levels.
+    ///
+    /// Limits are 8-bit code values. `lutyuv` evaluates `maxval` as the
+    /// component's legal maximum at the stream's own bit depth (235 for luma
+    /// and 240 for chroma, shifted up), so scaling by it keeps the same limits
+    /// on a 10- or 12-bit pipeline.
+    fn build_broadcast_legalizer_filter(&self) -> String {
+        let luma_min = self.get_float("luma_min").unwrap_or(16.0).clamp(0.0, 255.0);
+        let luma_max = self
+            .get_float("luma_max")
+            .unwrap_or(235.0)
+            .clamp(luma_min, 255.0);
+        let chroma_min = self
+            .get_float("chroma_min")
+            .unwrap_or(16.0)
+            .clamp(0.0, 255.0);
+        let chroma_max = self
+            .get_float("chroma_max")
+            .unwrap_or(240.0)
+            .clamp(chroma_min, 255.0);
+
+        let chroma = format!("clip(val,{chroma_min}*maxval/240,{chroma_max}*maxval/240)");
+        format!("lutyuv=y='clip(val,{luma_min}*maxval/235,{luma_max}*maxval/235)':u='{chroma}':v='{chroma}'")
+    }
+
     /// Builds FFmpeg colorbalance filter for 3-way color correction.
     ///
     /// Color Wheels (Lift/Gamma/Gain) maps to FFmpeg's colorbalance filter:
@@ -3689,6 +3716,46 @@ mod tests {
         );
     }
 
+    // =========================================================================
+    // Broadcast Legalizer Tests
+    // =========================================================================
+
+    #[test]
+    fn test_broadcast_legalizer_filter_clamps_to_nominal_levels_by_default() {
+        let effect = Effect::new(EffectType::BroadcastLegalizer);
+
+        let filter = effect.to_filter_string("in", "out");
+        assert!(
+            filter.contains(
+                "lutyuv=y='clip(val,16*maxval/235,235*maxval/235)':\
+                 u='clip(val,16*maxval/240,240*maxval/240)':\
+                 v='clip(val,16*maxval/240,240*maxval/240)'"
+            ),
+            "Expected nominal-range clamp, got: {}",
+            filter
+        );
+    }
+
+    #[test]
+    fn test_broadcast_legalizer_filter_keeps_limits_ordered() {
+        let mut effect = Effect::new(EffectType::BroadcastLegalizer);
+        effect.set_param("luma_min", ParamValue::Float(5.0));
+        effect.set_param("luma_max", ParamValue::Float(2.0));
+        effect.set_param("chroma_max", ParamValue::Float(300.0));
+
+        let filter = effect.to_filter_string("in", "out");
+        assert!(
+            filter.contains("y='clip(val,5*maxval/235,5*maxval/235)'"),
+            "Expected luma max raised to luma min, got: {}",
+            filter
+        );
+        assert!(
+            filter.contains("u='clip(val,16*maxval/240,255*maxval/240)'"),
+            "Expected chroma max capped at 255, got: {}",
+            filter
+        );
+    }
+
     // =========================================================================
     // Zoom output-frame and canvas tests
     // =========================================================================
@@ -207,6 +207,7 @@ pub enum EffectType {
     Curves,
     TemperatureTint, // White balance (temperature + tint)
     Lut,
+    BroadcastLegalizer, // Clamps luma/chroma into broadcast-legal code values
 
     // Transform effects
     Crop,
@@ -293,7 +294,8 @@ impl EffectType {
             | Self::Levels
             | Self::Curves
             | Self::TemperatureTint
-            | Self::Lut => EffectCategory::Color,
+            | Self::Lut
+            | Self::BroadcastLegalizer => EffectCategory::Color,
 
             Self::Crop | Self::Flip | Self::Mirror | Self::Rotate | Self::Stabilize => {
                 EffectCategory::Transform
@@ -756,6 +758,13 @@ impl Effect {
                 );
                 params.insert("intensity".to_string(), ParamValue::Float(1.0));
             }
+            EffectType::BroadcastLegalizer => {
+                // 8-bit code values; scaled to the pipeline's bit depth on export
+                params.insert("luma_min".to_string(), ParamValue::Float(16.0));
+                params.insert("luma_max".to_string(), ParamValue::Float(235.0));
+                params.insert("chroma_min".to_string(), ParamValue::Float(16.0));
+                params.insert("chroma_max".to_string(), ParamValue::Float(240.0));
+            }
             EffectType::Curves => {
                 // RGB Color Curves — each channel stored as JSON-serialized Vec<CurvePoint>
                 let identity = curve_points_to_json(&default_identity_curve());
@@ -969,6 +978,12 @@ impl Effect {
                 ParamDef::string("interp", "Interpolation", "tetrahedral"),
                 ParamDef::float("intensity", "Intensity", 1.0, 0.0, 1.0),
             ],
+            EffectType::BroadcastLegalizer => vec![
+                ParamDef::float("luma_min", "Luma Min", 16.0, 0.0, 255.0),
+                ParamDef::float("luma_max", "Luma Max", 235.0, 0.0, 255.0),
+                ParamDef::float("chroma_min", "Chroma Min", 16.0, 0.0, 255.0),
+                ParamDef::float("chroma_max", "Chroma Max", 240.0, 0.0, 255.0),
+            ],
             EffectType::Curves => {
                 let identity_json = curve_points_to_json(&default_identity_curve());
                 let flat_json = curve_points_to_json(&default_flat_curve());
@@ -1255,6 +1270,7 @@ mod tests {
                 | EffectType::Curves
                 | EffectType::TemperatureTint
                 | EffectType::Lut
+                | EffectType::BroadcastLegalizer
                 | EffectType::Crop
                 | EffectType::Flip
                 | EffectType::Mirror
@@ -0,0 +1,80 @@
+//! Numbered still sequences read as one video stream.
+//!
+//! FFmpeg's `image2` demuxer turns `shot_%04d.exr` into a stream of frames at
+//! a rate the caller picks; stills carry no timing of their own. Everything
+//! that opens an image-sequence asset — probing, proxies, thumbnails and the
+//! export graph — builds its input through [`ImageSequenceInput::input_args`]
+//! so the sequence plays back identically everywhere.
+
+use std::path::PathBuf;
+
+use crate::core::Ratio;
+
+/// A numbered still sequence opened through the `image2` demuxer
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageSequenceInput {
+    /// `printf`-style path of the frames (e.g. "/renders/shot_%04d.exr")
+    pub pattern_path: PathBuf,
+    /// Number of the first frame
+    pub start_number: u64,
+    /// Number of consecutive frames
+    pub frame_count: u64,
+    /// Playback rate
+    pub fps: Ratio,
+}
+
+impl ImageSequenceInput {
+    /// Demuxer options followed by `-i`, ready to stand in for `-i <file>`
+    pub fn input_args(&self) -> Vec<String> {
+        vec![
+            "-f".to_string(),
+            "image2".to_string(),
+            "-framerate".to_string(),
+            format!("{}/{}", self.fps.num, self.fps.den),
+            "-start_number".to_string(),
+            self.start_number.to_string(),
+            "-i".to_string(),
+            self.pattern_path.to_string_lossy().to_string(),
+        ]
+    }
+
+    /// How long the sequence plays
+    pub fn duration_sec(&self) -> f64 {
+        let fps = self.fps.as_f64();
+        if fps > 0.0 {
+            self.frame_count as f64 / fps
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn opens_the_pattern_through_image2_at_the_given_rate() {
+        let input = ImageSequenceInput {
+            pattern_path: PathBuf::from("/renders/shot_%04d.exr"),
+            start_number: 1001,
+            frame_count: 48,
+            fps: Ratio::new(24000, 1001),
+        };
+
+        assert_eq!(
+            input.input_args(),
+            vec![
+                "-f",
+                "image2",
+                "-framerate",
+                "24000/1001",
+                "-start_number",
+                "1001",
+                "-i",
+                "/renders/shot_%04d.exr"
+            ]
+        );
+        assert!((input.duration_sec() - 2.002).abs() < 1e-9);
+    }
+}
@@ -21,10 +21,12 @@ pub mod bundler;
 #[cfg(all(not(test), feature = "gui"))]
 mod commands;
 mod detection;
+mod image_sequence;
 pub mod installer;
 mod resolver;
 pub mod rotation;
 mod runner;
+pub mod source_tags;
 mod state;
 
 pub use bundler::{
@@ -33,6 +35,7 @@ pub use bundler::{
 #[cfg(all(not(test), feature = "gui"))]
 pub use commands::*;
 pub use detection::*;
+pub use image_sequence::ImageSequenceInput;
 pub use resolver::{
     resolve_and_register, resolve_ffmpeg, resolved_ffmpeg_path, resolved_ffprobe_path,
     set_resolved_paths, FFmpegResolveOptions, ResolvedFFmpeg, FFMPEG_PATH_ENV, FFPROBE_PATH_ENV,
@@ -42,6 +45,7 @@ pub use runner::{
     capture_filter_stderr, AudioStreamInfo, FFmpegProgress, FFmpegRunner, FilterCapture,
     FilterMode, FrameExtractOptions, MediaInfo, RenderSettings, VideoStreamInfo, WaveformData,
 };
+pub use source_tags::source_metadata_from_probe;
 pub use state::{create_ffmpeg_state, FFmpegState, SharedFFmpegState};
 #[cfg(all(not(test), feature = "gui"))]
 pub use state::{detect_ffmpeg, initialize_shared_ffmpeg};
@@ -10,7 +10,8 @@ use std::sync::Arc;
 use specta::Type;
 use tokio::sync::mpsc;
 
-use super::{FFmpegError, FFmpegInfo, FFmpegResult};
+use super::{FFmpegError, FFmpegInfo, FFmpegResult, ImageSequenceInput};
+use crate::core::assets::SourceMetadata;
 use crate::core::process::configure_tokio_command;
 
 fn is_nonempty_file(path: &Path) -> bool {
@@ -213,6 +214,9 @@ pub struct MediaInfo {
     pub format: String,
     /// File size in bytes
     pub size_bytes: u64,
+    /// Source timecode, reel and camera tags
+    #[serde(default)]
+    pub source: SourceMetadata,
 }
 
 /// Video stream information.
@@ -258,6 +262,9 @@ pub struct AudioStreamInfo {
     pub codec: String,
     /// Bitrate in bits/s (if available)
     pub bitrate: Option<u64>,
+    /// Channel layout name (e.g., "5.1(side)"), if the stream labels one
+    #[serde(default)]
+    pub channel_layout: Option<String>,
 }
 
 /// Render/export settings
@@ -783,6 +790,42 @@ impl FFmpegRunner {
             return Ok(());
         }
 
+        // Get video duration to determine thumbnail position
+        let media_info = self.probe(input).await?;
+        self.write_thumbnail(
+            vec!["-i".to_string(), input.to_string_lossy().to_string()],
+            media_info.duration_sec,
+            output,
+            size,
+        )
+        .await
+    }
+
+    /// Generate a thumbnail for a numbered still sequence, picked the same
+    /// way [`FFmpegRunner::generate_thumbnail`] picks one from a video.
+    pub async fn generate_image_sequence_thumbnail(
+        &self,
+        input: &ImageSequenceInput,
+        output: &Path,
+        size: Option<(u32, u32)>,
+    ) -> FFmpegResult<()> {
+        if is_nonempty_file(output) {
+            return Ok(());
+        }
+
+        self.write_thumbnail(input.input_args(), input.duration_sec(), output, size)
+            .await
+    }
+
+    /// Extracts one frame of the input opened by `input_args` as a JPEG: at
+    /// 1 second, or 10% in for inputs shorter than 10 seconds.
+    async fn write_thumbnail(
+        &self,
+        input_args: Vec<String>,
+        duration_sec: f64,
+        output: &Path,
+        size: Option<(u32, u32)>,
+    ) -> FFmpegResult<()> {
         // Create output directory if needed
         if let Some(parent) = output.parent() {
             tokio::fs::create_dir_all(parent).await.map_err(|e| {
@@ -790,23 +833,16 @@ impl FFmpegRunner {
             })?;
         }
 
-        // Get video duration to determine thumbnail position
-        let media_info = self.probe(input).await?;
-        let thumb_time = if media_info.duration_sec > 10.0 {
+        let thumb_time = if duration_sec > 10.0 {
             1.0 // Use 1 second for longer videos
         } else {
-            media_info.duration_sec * 0.1 // Use 10% for short videos
+            duration_sec * 0.1 // Use 10% for short videos
         };
 
         // Build FFmpeg command with optional scaling
-        let mut args = vec![
-            "-ss".to_string(),
-            format!("{:.3}", thumb_time),
-            "-i".to_string(),
-            input.to_string_lossy().to_string(),
-            "-frames:v".to_string(),
-            "1".to_string(),
-        ];
+        let mut args = vec!["-ss".to_string(), format!("{:.3}", thumb_time)];
+        args.extend(input_args);
+        args.extend(["-frames:v".to_string(), "1".to_string()]);
 
         // Add scaling filter if size specified
         if let Some((width, height)) = size {
@@ -865,8 +901,6 @@ impl FFmpegRunner {
             })?;
         }
 
-        let settings = RenderSettings::proxy_720p();
-
         // Get total frames for progress calculation
         let media_info = self.probe(input).await?;
         let total_frames = if let Some(video) = &media_info.video {
@@ -875,18 +909,59 @@ impl FFmpegRunner {
             None
         };
 
+        self.encode_proxy(
+            vec!["-i".to_string(), input.to_string_lossy().to_string()],
+            media_info.duration_sec,
+            total_frames,
+            output,
+            progress_tx,
+        )
+        .await
+    }
+
+    /// Generate a proxy video for a numbered still sequence
+    pub async fn generate_image_sequence_proxy(
+        &self,
+        input: &ImageSequenceInput,
+        output: &Path,
+        progress_tx: Option<mpsc::Sender<FFmpegProgress>>,
+    ) -> FFmpegResult<()> {
+        // Create output directory if needed
+        if let Some(parent) = output.parent() {
+            tokio::fs::create_dir_all(parent).await.map_err(|e| {
+                FFmpegError::OutputError(format!("Failed to create output directory: {}", e))
+            })?;
+        }
+
+        self.encode_proxy(
+            input.input_args(),
+            input.duration_sec(),
+            Some(input.frame_count),
+            output,
+            progress_tx,
+        )
+        .await
+    }
+
+    /// Encodes the input opened by `input_args` as a 720p preview proxy.
+    async fn encode_proxy(
+        &self,
+        input_args: Vec<String>,
+        duration_sec: f64,
+        total_frames: Option<u64>,
+        output: &Path,
+        progress_tx: Option<mpsc::Sender<FFmpegProgress>>,
+    ) -> FFmpegResult<()> {
+        let settings = RenderSettings::proxy_720p();
+
         // Build FFmpeg command.
         // Important: only enable `-progress pipe:1` when we are actually draining stdout,
         // otherwise the child can deadlock once the stdout pipe fills.
         let mut cmd = tokio::process::Command::new(&self.info.ffmpeg_path);
         configure_tokio_command(&mut cmd);
+        cmd.args(["-hide_banner", "-loglevel", "error", "-nostdin"]);
+        cmd.args(&input_args);
         cmd.args([
-            "-hide_banner",
-            "-loglevel",
-            "error",
-            "-nostdin",
-            "-i",
-            &input.to_string_lossy(),
             "-vf",
             // Scale to 720p height while preserving aspect ratio
             // -2 ensures width is divisible by 2 (H.264 codec requirement)
@@ -934,7 +1009,7 @@ impl FFmpegRunner {
         if let Some(tx) = progress_tx {
             if let Some(stdout) = child.stdout.take() {
                 let total = total_frames;
-                let duration = media_info.duration_sec;
+                let duration = duration_sec;
 
                 tokio::spawn(async move {
                     use tokio::io::{AsyncBufReadExt, BufReader};
@@ -1019,6 +1094,37 @@ impl FFmpegRunner {
         parse_probe_output(&json_str)
     }
 
+    /// Probe a numbered still sequence through the `image2` demuxer.
+    ///
+    /// The duration is the sequence's own frame count at `input.fps`; what
+    /// the demuxer reports for a pattern depends on how far it scanned.
+    pub async fn probe_image_sequence(
+        &self,
+        input: &ImageSequenceInput,
+    ) -> FFmpegResult<MediaInfo> {
+        let framerate = format!("{}/{}", input.fps.num, input.fps.den);
+        let start_number = input.start_number.to_string();
+        let json_str = self
+            .run_ffprobe_json_unchecked(
+                &input.pattern_path,
+                &[
+                    "-f",
+                    "image2",
+                    "-framerate",
+                    &framerate,
+                    "-start_number",
+                    &start_number,
+                    "-show_format",
+                    "-show_streams",
+                ],
+            )
+            .await?;
+        let mut media_info = parse_probe_output(&json_str)?;
+        media_info.duration_sec = input.duration_sec();
+        media_info.video_duration_sec = Some(input.duration_sec());
+        Ok(media_info)
+    }
+
     /// Duration of the primary video stream, when the file reports one.
     ///
     /// [`FFmpegRunner::probe`] reports the *container* duration, which is the
@@ -1056,6 +1162,16 @@ impl FFmpegRunner {
             )));
         }
 
+        self.run_ffprobe_json_unchecked(input, args).await
+    }
+
+    /// [`FFmpegRunner::run_ffprobe_json`] without the existence check, for
+    /// inputs that are patterns rather than files.
+    async fn run_ffprobe_json_unchecked(
+        &self,
+        input: &Path,
+        args: &[&str],
+    ) -> FFmpegResult<String> {
         // Run ffprobe with JSON output
         let mut cmd = tokio::process::Command::new(&self.info.ffprobe_path);
         configure_tokio_command(&mut cmd);
@@ -2016,6 +2132,7 @@ fn parse_probe_output(json_str: &str) -> FFmpegResult<MediaInfo> {
         audio: audio_info,
         format: format_name,
         size_bytes,
+        source: super::source_metadata_from_probe(&json),
     })
 }
 
@@ -2161,11 +2278,18 @@ fn parse_audio_stream(stream: &serde_json::Value) -> FFmpegResult<AudioStreamInf
         .and_then(|b| b.as_str())
         .and_then(|s| s.parse::<u64>().ok());
 
+    let channel_layout = stream
+        .get("channel_layout")
+        .and_then(|l| l.as_str())
+        .filter(|l| !l.is_empty() && *l != "unknown")
+        .map(str::to_string);
+
     Ok(AudioStreamInfo {
         sample_rate,
         channels,
         codec,
         bitrate,
+        channel_layout,
     })
 }
 
@@ -0,0 +1,271 @@
+//! Source timecode, reel and camera tags carried by a container.
+//!
+//! Cameras stamp the timecode they were running when recording started, and
+//! the reel or card it went onto, into container tags. Where those land depends
+//! on the writer: QuickTime keeps the timecode on a `tmcd` data track (FFmpeg
+//! usually mirrors it onto the video stream), MXF puts it on the container, and
+//! Broadcast WAV carries a `time_reference` sample count instead of a timecode.
+//!
+//! Both probe paths read the same FFprobe JSON through this module, so an asset
+//! imported through either one reports the same source timecode.
+
+use serde_json::Value;
+
+use crate::core::assets::SourceMetadata;
+use crate::core::interchange::models::Timecode;
+use crate::core::Ratio;
+
+/// Tags holding the start timecode.
+const TIMECODE_KEYS: &[&str] = &["timecode"];
+
+/// Tags holding the reel, tape or camera-card name.
+const REEL_KEYS: &[&str] = &[
+    "reel_name",
+    "reel",
+    "tape_name",
+    "tape",
+    "com.apple.proapps.reel",
+];
+
+/// Tags holding the camera manufacturer.
+const CAMERA_MAKE_KEYS: &[&str] = &[
+    "com.apple.quicktime.make",
+    "camera_make",
+    "make",
+    "com.android.manufacturer",
+];
+
+/// Tags holding the camera model.
+const CAMERA_MODEL_KEYS: &[&str] = &[
+    "com.apple.quicktime.model",
+    "camera_model",
+    "model",
+    "com.android.model",
+];
+
+/// Tags holding the lens model.
+const LENS_KEYS: &[&str] = &[
+    "com.apple.quicktime.camera.lens_model",
+    "lens_model",
+    "lens",
+];
+
+/// Broadcast WAV start, in samples since midnight.
+const TIME_REFERENCE_KEY: &str = "time_reference";
+
+/// Reads source timecode, reel, camera and lens tags off an FFprobe
+/// `-show_format -show_streams` document.
+///
+/// Tags are looked up on the primary video stream first, then on timecode
+/// tracks, then on the container, then on every other stream, so the value the
+/// picture was recorded with wins over one a remuxer copied elsewhere. Keys are
+/// matched without regard to case because writers disagree on it.
+///
+/// `start_sec` comes from the timecode at the video frame rate, or from a
+/// Broadcast WAV `time_reference` when the file carries no timecode.
+pub fn source_metadata_from_probe(probe: &Value) -> SourceMetadata {
+    let streams = probe
+        .get("streams")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let video = streams
+        .iter()
+        .find(|stream| codec_type(stream) == Some("video"));
+    let audio = streams
+        .iter()
+        .find(|stream| codec_type(stream) == Some("audio"));
+    let timecode_track = streams.iter().find(|stream| is_timecode_track(stream));
+
+    let mut tag_sets: Vec<&Value> = Vec::new();
+    tag_sets.extend(video.and_then(tags_of));
+    tag_sets.extend(
+        streams
+            .iter()
+            .filter(|stream| is_timecode_track(stream))
+            .filter_map(tags_of),
+    );
+    tag_sets.extend(probe.get("format").and_then(tags_of));
+    tag_sets.extend(streams.iter().filter_map(tags_of));
+    let lookup = |keys: &[&str]| tag_sets.iter().find_map(|tags| tag_value(tags, keys));
+
+    let start_timecode = lookup(TIMECODE_KEYS);
+    let frame_rate = video
+        .and_then(stream_frame_rate)
+        .or_else(|| timecode_track.and_then(stream_frame_rate));
+    let start_sec = start_timecode
+        .as_deref()
+        .and_then(Timecode::parse)
+        .zip(frame_rate)
+        .map(|(timecode, fps)| timecode.to_seconds(&fps))
+        .or_else(|| broadcast_wav_start_sec(&tag_sets, audio));
+
+    SourceMetadata {
+        start_timecode,
+        start_sec,
+        reel_name: lookup(REEL_KEYS),
+        camera: camera_name(lookup(CAMERA_MAKE_KEYS), lookup(CAMERA_MODEL_KEYS)),
+        lens: lookup(LENS_KEYS),
+    }
+}
+
+fn codec_type(stream: &Value) -> Option<&str> {
+    stream.get("codec_type").and_then(Value::as_str)
+}
+
+/// Whether a stream is a QuickTime/MP4 timecode track.
+fn is_timecode_track(stream: &Value) -> bool {
+    stream.get("codec_tag_string").and_then(Value::as_str) == Some("tmcd")
+        || codec_type(stream) == Some("data")
+}
+
+fn tags_of(object: &Value) -> Option<&Value> {
+    object.get("tags").filter(|tags| tags.is_object())
+}
+
+/// Returns the first non-blank value stored under any of `keys`.
+fn tag_value(tags: &Value, keys: &[&str]) -> Option<String> {
+    let tags = tags.as_object()?;
+    keys.iter().find_map(|key| {
+        tags.iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .and_then(|(_, value)| value.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::to_string)
+    })
+}
+
+/// Parses a stream's `r_frame_rate`, refusing the `0/0` data streams report.
+fn stream_frame_rate(stream: &Value) -> Option<Ratio> {
+    let raw = stream.get("r_frame_rate").and_then(Value::as_str)?;
+    let (num, den) = raw.split_once('/')?;
+    let num: i32 = num.trim().parse().ok()?;
+    let den: i32 = den.trim().parse().ok()?;
+    (num > 0 && den > 0).then(|| Ratio::new(num, den))
+}
+
+/// Converts a Broadcast WAV `time_reference` into seconds since midnight.
+fn broadcast_wav_start_sec(tag_sets: &[&Value], audio: Option<&Value>) -> Option<f64> {
+    let samples: u64 = tag_sets
+        .iter()
+        .find_map(|tags| tag_value(tags, &[TIME_REFERENCE_KEY]))?
+        .parse()
+        .ok()?;
+    let sample_rate: f64 = audio?
+        .get("sample_rate")
+        .and_then(Value::as_str)?
+        .parse()
+        .ok()
+        .filter(|rate: &f64| *rate > 0.0)?;
+    Some(samples as f64 / sample_rate)
+}
+
+/// Joins make and model, without repeating a make the model already names.
+fn camera_name(make: Option<String>, model: Option<String>) -> Option<String> {
+    match (make, model) {
+        (Some(make), Some(model)) if model.to_lowercase().starts_with(&make.to_lowercase()) => {
+            Some(model)
+        }
+        (Some(make), Some(model)) => Some(format!("{} {}", make, model)),
+        (make, model) => model.or(make),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reads_timecode_from_a_tmcd_track() {
+        let probe = json!({
+            "streams": [
+                { "codec_type": "video", "r_frame_rate": "24/1" },
+                { "codec_type": "audio", "sample_rate": "48000" },
+                {
+                    "codec_type": "data",
+                    "codec_tag_string": "tmcd",
+                    "r_frame_rate": "0/0",
+                    "tags": { "timecode": "01:00:10:12", "reel_name": "A001C003" }
+                }
+            ],
+            "format": { "tags": { "com.apple.quicktime.make": "Apple" } }
+        });
+
+        let source = source_metadata_from_probe(&probe);
+
+        assert_eq!(source.start_timecode.as_deref(), Some("01:00:10:12"));
+        assert!((source.start_sec.unwrap() - 3610.5).abs() < 1e-9);
+        assert_eq!(source.reel_name.as_deref(), Some("A001C003"));
+        assert_eq!(source.camera.as_deref(), Some("Apple"));
+    }
+
+    #[test]
+    fn prefers_the_video_stream_timecode_over_the_container() {
+        let probe = json!({
+            "streams": [{
+                "codec_type": "video",
+                "r_frame_rate": "30000/1001",
+                "tags": { "timecode": "00:59:58;00" }
+            }],
+            "format": { "tags": { "TIMECODE": "10:00:00:00", "Tape_Name": "TAPE01" } }
+        });
+
+        let source = source_metadata_from_probe(&probe);
+
+        assert_eq!(source.start_timecode.as_deref(), Some("00:59:58;00"));
+        let expected = Timecode::parse("00:59:58;00")
+            .unwrap()
+            .to_seconds(&Ratio::new(30000, 1001));
+        assert!((source.start_sec.unwrap() - expected).abs() < 1e-9);
+        assert_eq!(source.reel_name.as_deref(), Some("TAPE01"));
+    }
+
+    #[test]
+    fn reads_broadcast_wav_time_reference() {
+        let probe = json!({
+            "streams": [{ "codec_type": "audio", "sample_rate": "48000" }],
+            "format": { "tags": { "time_reference": "172800000" } }
+        });
+
+        let source = source_metadata_from_probe(&probe);
+
+        assert_eq!(source.start_timecode, None);
+        assert!((source.start_sec.unwrap() - 3600.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn joins_camera_make_and_model_and_reads_lens() {
+        let probe = json!({
+            "streams": [{ "codec_type": "video", "r_frame_rate": "25/1" }],
+            "format": { "tags": {
+                "com.apple.quicktime.make": "Apple",
+                "com.apple.quicktime.model": "iPhone 15 Pro",
+                "com.apple.quicktime.camera.lens_model": "iPhone 15 Pro back camera 6.765mm f/1.78"
+            } }
+        });
+
+        let source = source_metadata_from_probe(&probe);
+
+        assert_eq!(source.camera.as_deref(), Some("Apple iPhone 15 Pro"));
+        assert_eq!(
+            source.lens.as_deref(),
+            Some("iPhone 15 Pro back camera 6.765mm f/1.78")
+        );
+        assert_eq!(
+            camera_name(Some("Canon".into()), Some("Canon EOS R5".into())).as_deref(),
+            Some("Canon EOS R5")
+        );
+    }
+
+    #[test]
+    fn reports_nothing_for_an_untagged_file() {
+        let probe = json!({
+            "streams": [{ "codec_type": "video", "r_frame_rate": "30/1" }],
+            "format": {}
+        });
+
+        assert!(source_metadata_from_probe(&probe).is_empty());
+    }
+}
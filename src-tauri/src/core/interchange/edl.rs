@@ -1,7 +1,8 @@
-//! CMX 3600 EDL Export
+//! CMX 3600 EDL Export and Parsing
 //!
 //! Generates Edit Decision Lists in the CMX 3600 format, the industry standard
-//! for exchanging timeline data between NLEs.
+//! for exchanging timeline data between NLEs, and reads them back into
+//! [`ParsedEdl`] for [`super::edl_import`] to turn into plan steps.
 //!
 //! ## Format Reference
 //!
@@ -21,8 +22,19 @@
 //! - EDL is a flat format — only one video track is exported per EDL.
 //!   Multiple video tracks require multiple EDL files.
 //! - Effects and keyframes are not representable in EDL.
-//! - Reel names are truncated to 8 characters (CMX 3600 limitation).
+//! - Reel names are truncated to 8 characters (CMX 3600 limitation). The
+//!   recorded reel name is used when the asset carries one, the asset name
+//!   otherwise, and source timecodes start at the asset's recorded timecode.
 //! - Audio-only clips are exported as separate audio events.
+//!
+//! ## Parsing
+//!
+//! [`parse_edl`] reads what colorists and online houses actually send back:
+//! `V`/`A`/`B`/`AA/V` channels, `C`/`D`/`Wnnn` edits with their frame counts,
+//! the two-line form a dissolve or wipe takes, `M2` speed lines and the
+//! `* FROM CLIP NAME` / `* TO CLIP NAME` / `* SOURCE FILE` comments. Key edits,
+//! `AUD` and `SPLIT` lines and anything else the parser does not understand are
+//! named in [`ParsedEdl::warnings`] rather than dropped.
 
 use std::collections::HashMap;
 use std::fmt::Write;
@@ -36,6 +48,10 @@ use super::models::{
     InterchangeExportResult, InterchangeFormat, Timecode,
 };
 
+/// Event numbers shared by consecutive lines, as in the two lines of a
+/// dissolve, belong to one event; this is the largest number CMX 3600 allows.
+const MAX_EVENT_NUMBER: u32 = 9999;
+
 // =============================================================================
 // Public API
 // =============================================================================
@@ -191,14 +207,15 @@ fn build_track_events(
     for clip in clips {
         let asset = assets.get(&clip.asset_id);
 
-        // Derive reel name from asset (or use placeholder)
+        // Reel name as recorded, falling back to the asset name (or placeholder)
         let reel_name = asset
-            .map(|a| truncate_reel_name(&a.name, 8))
+            .map(|a| truncate_reel_name(a.reel_name().unwrap_or(&a.name), 8))
             .unwrap_or_else(|| "AX".to_string());
 
-        // Source timecodes (position within the original media)
-        let source_in = Timecode::from_seconds(clip.range.source_in_sec, fps);
-        let source_out = Timecode::from_seconds(clip.range.source_out_sec, fps);
+        // Source timecodes (position within the original media, on its own clock)
+        let source_start = asset.map(Asset::source_start_sec).unwrap_or(0.0);
+        let source_in = Timecode::from_seconds(source_start + clip.range.source_in_sec, fps);
+        let source_out = Timecode::from_seconds(source_start + clip.range.source_out_sec, fps);
 
         // Record timecodes (position on the timeline)
         let record_in = Timecode::from_seconds(clip.place.timeline_in_sec, fps);
@@ -277,6 +294,370 @@ fn write_event(output: &mut String, event: &EdlEvent, fps: &Ratio) {
     let _ = writeln!(output);
 }
 
+// =============================================================================
+// Parsing
+// =============================================================================
+
+/// An EDL read back from text.
+#[derive(Clone, Debug, Default)]
+pub struct ParsedEdl {
+    /// The `TITLE:` line, if the file had one.
+    pub title: Option<String>,
+    /// Whether the first `FCM:` line declared drop-frame timecode.
+    pub drop_frame: bool,
+    /// Events in file order.
+    pub events: Vec<ParsedEdlEvent>,
+    /// Lines the parser skipped or could only read approximately.
+    pub warnings: Vec<String>,
+}
+
+/// One event parsed out of an EDL.
+#[derive(Clone, Debug)]
+pub struct ParsedEdlEvent {
+    /// The event as its line describes it.
+    ///
+    /// `speed` is always `None` here: an `M2` line states a rate in frames per
+    /// second, which only becomes a multiplier against the frame rate the
+    /// importer decides on. It is kept in [`Self::speed_fps`] instead.
+    pub event: EdlEvent,
+    /// Playback rate from the event's `M2` line. Negative plays in reverse.
+    pub speed_fps: Option<f64>,
+    /// For a dissolve or wipe, the outgoing reel named on the event's first line.
+    pub from_reel: Option<String>,
+    /// For a dissolve or wipe, the outgoing clip's `* FROM CLIP NAME`.
+    pub from_clip_name: Option<String>,
+    /// 1-based line the event started on, for reports.
+    pub line: usize,
+}
+
+/// Parses a CMX 3600 EDL.
+///
+/// # Errors
+///
+/// Refuses text that has neither a `TITLE:` line nor a single event line:
+/// nothing else distinguishes an EDL from an arbitrary text file, and importing
+/// one as an empty timeline would report success for the wrong file.
+pub fn parse_edl(content: &str) -> Result<ParsedEdl, String> {
+    let mut parsed = ParsedEdl::default();
+    let mut saw_fcm = false;
+    let mut current_drop_frame = false;
+    // Lines of the event being read, which may span two event lines.
+    let mut pending: Vec<ParsedEdlEvent> = Vec::new();
+
+    for (index, raw_line) in content.lines().enumerate() {
+        let line_number = index + 1;
+        let line = raw_line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(title) = line.strip_prefix("TITLE:") {
+            parsed.title = Some(title.trim().to_string()).filter(|title| !title.is_empty());
+            continue;
+        }
+
+        if let Some(fcm) = line.strip_prefix("FCM:") {
+            current_drop_frame = fcm.trim().eq_ignore_ascii_case("DROP FRAME");
+            if !saw_fcm {
+                parsed.drop_frame = current_drop_frame;
+                saw_fcm = true;
+            }
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('*') {
+            apply_comment(&mut pending, comment.trim());
+            continue;
+        }
+
+        if line.starts_with("M2") {
+            apply_speed_line(&mut pending, line, line_number, &mut parsed.warnings);
+            continue;
+        }
+
+        let Some(event_number) = leading_event_number(line) else {
+            parsed.warnings.push(format!(
+                "line {line_number} (\"{}\") is not an event, comment or speed line and was skipped",
+                truncate_for_report(line)
+            ));
+            continue;
+        };
+
+        match parse_event_line(line, event_number, current_drop_frame) {
+            Ok((event, note)) => {
+                if let Some(note) = note {
+                    parsed
+                        .warnings
+                        .push(format!("line {line_number}: event {event_number} {note}"));
+                }
+                let record = ParsedEdlEvent {
+                    event,
+                    speed_fps: None,
+                    from_reel: None,
+                    from_clip_name: None,
+                    line: line_number,
+                };
+                push_event_line(&mut parsed.events, &mut pending, record);
+            }
+            Err(reason) => parsed.warnings.push(format!(
+                "line {line_number}: event {event_number} was skipped: {reason}"
+            )),
+        }
+    }
+
+    parsed.events.append(&mut pending);
+
+    if parsed.title.is_none() && parsed.events.is_empty() {
+        return Err(
+            "this is not a CMX 3600 EDL: it has no TITLE line and no event lines".to_string(),
+        );
+    }
+
+    Ok(parsed)
+}
+
+/// Adds an event line to the event being read, or starts a new one.
+///
+/// A dissolve or wipe is written as two lines sharing one event number: the
+/// outgoing source on a zero-length cut, then the incoming source carrying the
+/// transition. The first line describes no media of its own, so it is folded
+/// into the second as its "from" side rather than kept as an event.
+fn push_event_line(
+    events: &mut Vec<ParsedEdlEvent>,
+    pending: &mut Vec<ParsedEdlEvent>,
+    mut record: ParsedEdlEvent,
+) {
+    let same_event = pending
+        .last()
+        .is_some_and(|last| last.event.event_number == record.event.event_number);
+
+    if !same_event {
+        events.append(pending);
+        pending.push(record);
+        return;
+    }
+
+    let is_transition = !matches!(record.event.edit_type, EditType::Cut);
+    if is_transition {
+        if let Some(position) = pending.iter().rposition(|from| {
+            matches!(from.event.edit_type, EditType::Cut)
+                && from.event.channel == record.event.channel
+                && from.event.record_in == from.event.record_out
+        }) {
+            let from = pending.remove(position);
+            record.from_reel = Some(from.event.reel_name);
+            record.from_clip_name = from.event.clip_name;
+        }
+    }
+    pending.push(record);
+}
+
+/// Applies a `*` comment to the event it follows.
+fn apply_comment(pending: &mut [ParsedEdlEvent], comment: &str) {
+    let Some(last) = pending.last_mut() else {
+        return;
+    };
+
+    if let Some(name) = comment_value(comment, "FROM CLIP NAME") {
+        // On a transition the FROM name belongs to the outgoing side; the
+        // incoming side is named by the TO line that follows.
+        if last.from_reel.is_some() {
+            last.from_clip_name = Some(name);
+        } else {
+            last.event.clip_name = Some(name);
+        }
+    } else if let Some(name) = comment_value(comment, "TO CLIP NAME") {
+        last.event.clip_name = Some(name);
+    } else if let Some(path) = comment_value(comment, "SOURCE FILE") {
+        last.event.source_file = Some(path);
+    }
+}
+
+/// The value of a `KEY: value` comment, if the comment is that key.
+fn comment_value(comment: &str, key: &str) -> Option<String> {
+    let rest = comment.strip_prefix(key)?.trim_start();
+    let value = rest.strip_prefix(':')?.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Applies an `M2   REEL   048.0   00:00:05:00` line to the event it follows.
+fn apply_speed_line(
+    pending: &mut [ParsedEdlEvent],
+    line: &str,
+    line_number: usize,
+    warnings: &mut Vec<String>,
+) {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let (Some(reel), Some(rate)) = (tokens.get(1), tokens.get(2)) else {
+        warnings.push(format!(
+            "line {line_number}: the M2 line names no reel and rate and was skipped"
+        ));
+        return;
+    };
+    let Some(speed_fps) = rate.parse::<f64>().ok().filter(|rate| rate.is_finite()) else {
+        warnings.push(format!(
+            "line {line_number}: the M2 rate \"{rate}\" is not a number and was skipped"
+        ));
+        return;
+    };
+
+    match pending
+        .iter_mut()
+        .rev()
+        .find(|record| record.event.reel_name.eq_ignore_ascii_case(reel))
+    {
+        Some(record) => record.speed_fps = Some(speed_fps),
+        None => warnings.push(format!(
+            "line {line_number}: the M2 line names reel {reel}, which the event above it does \
+             not use, and was skipped"
+        )),
+    }
+}
+
+/// The event number a line starts with, if it starts with one.
+fn leading_event_number(line: &str) -> Option<u32> {
+    let first = line.split_whitespace().next()?;
+    if !first.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    first
+        .parse::<u32>()
+        .ok()
+        .filter(|number| *number <= MAX_EVENT_NUMBER)
+}
+
+/// Parses `NNN  REEL  CHANNEL  EDIT [DURATION]  SRC_IN SRC_OUT REC_IN REC_OUT`.
+///
+/// Returns the event and, when the line could only be read approximately, a
+/// note saying how.
+fn parse_event_line(
+    line: &str,
+    event_number: u32,
+    drop_frame: bool,
+) -> Result<(EdlEvent, Option<String>), String> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    if tokens.len() < 8 {
+        return Err(format!(
+            "an event line needs a reel, channel, edit type and four timecodes, found {} fields",
+            tokens.len()
+        ));
+    }
+
+    let timecodes = &tokens[tokens.len() - 4..];
+    let mut parsed_tc = Vec::with_capacity(4);
+    for text in timecodes {
+        let mut tc =
+            Timecode::parse(text).ok_or_else(|| format!("\"{text}\" is not a timecode"))?;
+        tc.drop_frame |= drop_frame;
+        parsed_tc.push(tc);
+    }
+
+    let reel_name = tokens[1].to_string();
+    let channel = parse_channel(tokens[2])
+        .ok_or_else(|| format!("\"{}\" is not a channel this parser knows", tokens[2]))?;
+    let (edit_type, note) = parse_edit_type(&tokens[3..tokens.len() - 4])?;
+
+    let mut parsed_tc = parsed_tc.into_iter();
+    let mut next_tc = || {
+        parsed_tc
+            .next()
+            .unwrap_or_else(|| Timecode::zero(drop_frame))
+    };
+    let event = EdlEvent {
+        event_number,
+        reel_name,
+        channel,
+        edit_type,
+        source_in: next_tc(),
+        source_out: next_tc(),
+        record_in: next_tc(),
+        record_out: next_tc(),
+        clip_name: None,
+        source_file: None,
+        speed: None,
+    };
+
+    Ok((event, note))
+}
+
+/// Parses a channel field: `V`, `A`, `A2`, `A12`, `AA`, `B`, `AA/V`, `A2/V`
+/// or `NONE`.
+fn parse_channel(field: &str) -> Option<EdlChannel> {
+    let field = field.to_ascii_uppercase();
+    match field.as_str() {
+        "V" => return Some(EdlChannel::Video),
+        "B" => return Some(EdlChannel::Both(vec![1])),
+        "NONE" => return Some(EdlChannel::None),
+        _ => {}
+    }
+
+    if let Some(audio) = field.strip_suffix("/V") {
+        return parse_audio_channels(audio).map(EdlChannel::Both);
+    }
+    if let Some(audio) = field.strip_prefix("B") {
+        return parse_audio_channels(&format!("A{audio}")).map(EdlChannel::Both);
+    }
+    parse_audio_channels(&field).map(EdlChannel::Audio)
+}
+
+/// Parses `A`, `AA`, `A2` or `A12` into the channel numbers it names.
+fn parse_audio_channels(field: &str) -> Option<Vec<u8>> {
+    match field {
+        "A" => Some(vec![1]),
+        "AA" => Some(vec![1, 2]),
+        _ => {
+            let digits = field.strip_prefix('A')?;
+            if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+                return None;
+            }
+            let channels: Vec<u8> = digits.bytes().map(|byte| byte - b'0').collect();
+            (!channels.contains(&0)).then_some(channels)
+        }
+    }
+}
+
+/// Parses the edit-type field and its transition duration, if any.
+fn parse_edit_type(tokens: &[&str]) -> Result<(EditType, Option<String>), String> {
+    let Some(kind) = tokens.first() else {
+        return Err("the line has no edit type".to_string());
+    };
+    let kind = kind.to_ascii_uppercase();
+    let duration = || -> Result<u32, String> {
+        tokens
+            .get(1)
+            .and_then(|value| value.parse::<u32>().ok())
+            .ok_or_else(|| format!("the {kind} edit has no frame count"))
+    };
+
+    match kind.as_str() {
+        "C" => Ok((EditType::Cut, None)),
+        "D" => Ok((EditType::Dissolve(duration()?), None)),
+        _ if kind.starts_with('W') => {
+            let code = kind[1..]
+                .parse::<u32>()
+                .map_err(|_| format!("\"{kind}\" is not a wipe code"))?;
+            Ok((EditType::Wipe(code, duration()?), None))
+        }
+        _ if kind.starts_with('K') => Ok((
+            EditType::Cut,
+            Some("is a key edit, which has no OpenReelio equivalent; it was read as a cut".into()),
+        )),
+        _ => Err(format!("\"{kind}\" is not an edit type this parser knows")),
+    }
+}
+
+/// Shortens a foreign line for a report so one enormous line cannot flood it.
+fn truncate_for_report(line: &str) -> String {
+    const MAX_CHARS: usize = 60;
+    if line.chars().count() <= MAX_CHARS {
+        line.to_string()
+    } else {
+        let mut shortened: String = line.chars().take(MAX_CHARS).collect();
+        shortened.push('…');
+        shortened
+    }
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -284,7 +665,7 @@ fn write_event(output: &mut String, event: &EdlEvent, fps: &Ratio) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::assets::{Asset, AssetKind, LicenseInfo, ProxyStatus};
+    use crate::core::assets::{Asset, AssetKind, LicenseInfo, ProxyStatus, SourceMetadata};
     use crate::core::timeline::{
         AudioSettings, BlendMode, Clip, ClipPlace, ClipRange, Sequence, SequenceFormat, Track,
         Transform,
@@ -313,6 +694,8 @@ mod tests {
             workspace_managed: false,
             missing: false,
             quarantined_uri: None,
+            source: None,
+            image_sequence: None,
         }
     }
 
@@ -348,6 +731,7 @@ mod tests {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
             group_id: None,
         }
@@ -417,6 +801,30 @@ mod tests {
         assert!(edl.contains("* SOURCE FILE: /media/interview.mp4"));
     }
 
+    #[test]
+    fn should_use_recorded_reel_and_source_timecode() {
+        // Given: a camera original recorded at 01:00:00:00 onto reel A001C003
+        let mut seq = make_sequence("Camera Original", 24, 1);
+        let mut track = Track::new_video("V1");
+        track.add_clip(make_clip("c1", "a1", 5.0, 10.0, 0.0, 5.0));
+        seq.add_track(track);
+
+        let asset =
+            make_asset("a1", "interview.mov", "/media/interview.mov").with_source(SourceMetadata {
+                start_timecode: Some("01:00:00:00".to_string()),
+                start_sec: Some(3600.0),
+                reel_name: Some("A001C003".to_string()),
+                ..SourceMetadata::default()
+            });
+        let assets = HashMap::from([("a1".to_string(), asset)]);
+
+        // When: exporting to EDL
+        let (edl, _, _) = export_edl(&seq, &assets).expect("EDL export should succeed");
+
+        // Then: the event names the recorded reel and its source timecode
+        assert!(edl.contains("001  A001C003 V C 01:00:05:00 01:00:10:00 00:00:00:00 00:00:05:00"));
+    }
+
     #[test]
     fn should_export_multiple_clips_in_timeline_order() {
         // Given: a sequence with three clips out of order in the track
@@ -610,4 +1018,161 @@ mod tests {
             export_edl(&seq, &assets).expect_err("EDL export should reject multiple video tracks");
         assert!(error.contains("only one video track"));
     }
+
+    // =========================================================================
+    // Parsing
+    // =========================================================================
+
+    #[test]
+    fn should_parse_the_header_and_a_cut_with_its_comments() {
+        let edl = "TITLE: Colour Return\nFCM: NON-DROP FRAME\n\n\
+            001  A001C003 V     C        14:23:11:05 14:23:16:05 01:00:00:00 01:00:05:00\n\
+            * FROM CLIP NAME: A001C003_220101.mov\n\
+            * SOURCE FILE: /media/A001C003_220101.mov\n";
+
+        let parsed = parse_edl(edl).expect("a valid EDL");
+
+        assert_eq!(parsed.title.as_deref(), Some("Colour Return"));
+        assert!(!parsed.drop_frame);
+        assert_eq!(parsed.events.len(), 1);
+        let event = &parsed.events[0].event;
+        assert_eq!(event.event_number, 1);
+        assert_eq!(event.reel_name, "A001C003");
+        assert_eq!(event.channel, EdlChannel::Video);
+        assert_eq!(event.edit_type, EditType::Cut);
+        assert_eq!(event.source_in.to_string(), "14:23:11:05");
+        assert_eq!(event.record_out.to_string(), "01:00:05:00");
+        assert_eq!(event.clip_name.as_deref(), Some("A001C003_220101.mov"));
+        assert_eq!(
+            event.source_file.as_deref(),
+            Some("/media/A001C003_220101.mov")
+        );
+        assert!(parsed.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_fold_the_two_lines_of_a_dissolve_into_one_event() {
+        let edl = "TITLE: Dissolve\n\
+            001  REELA    V     C        00:00:00:00 00:00:05:00 01:00:00:00 01:00:05:00\n\
+            002  REELA    V     C        00:00:05:00 00:00:05:00 01:00:05:00 01:00:05:00\n\
+            002  REELB    V     D    030 00:00:10:00 00:00:15:00 01:00:05:00 01:00:10:00\n\
+            * FROM CLIP NAME: a.mov\n\
+            * TO CLIP NAME: b.mov\n";
+
+        let parsed = parse_edl(edl).expect("a valid EDL");
+
+        assert_eq!(parsed.events.len(), 2);
+        let dissolve = &parsed.events[1];
+        assert_eq!(dissolve.event.edit_type, EditType::Dissolve(30));
+        assert_eq!(dissolve.event.reel_name, "REELB");
+        assert_eq!(dissolve.from_reel.as_deref(), Some("REELA"));
+        assert_eq!(dissolve.from_clip_name.as_deref(), Some("a.mov"));
+        assert_eq!(dissolve.event.clip_name.as_deref(), Some("b.mov"));
+    }
+
+    #[test]
+    fn should_parse_wipes_and_audio_channel_spellings() {
+        let edl = "TITLE: Channels\n\
+            001  R1 A     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            002  R1 A2    C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            003  R1 AA    C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            004  R1 AA/V  C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            005  R1 B     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            006  R1 V     W001 015 00:00:00:00 00:00:01:00 01:00:01:00 01:00:02:00\n";
+
+        let parsed = parse_edl(edl).expect("a valid EDL");
+
+        let channels: Vec<EdlChannel> = parsed
+            .events
+            .iter()
+            .map(|record| record.event.channel.clone())
+            .collect();
+        assert_eq!(
+            channels,
+            vec![
+                EdlChannel::Audio(vec![1]),
+                EdlChannel::Audio(vec![2]),
+                EdlChannel::Audio(vec![1, 2]),
+                EdlChannel::Both(vec![1, 2]),
+                EdlChannel::Both(vec![1]),
+                EdlChannel::Video,
+            ]
+        );
+        assert_eq!(parsed.events[5].event.edit_type, EditType::Wipe(1, 15));
+    }
+
+    #[test]
+    fn should_attach_an_m2_rate_to_the_event_that_uses_its_reel() {
+        let edl = "TITLE: Speed\n\
+            001  FAST     V     C        00:00:00:00 00:00:10:00 01:00:00:00 01:00:05:00\n\
+            M2   FAST       048.0                00:00:00:00\n";
+
+        let parsed = parse_edl(edl).expect("a valid EDL");
+
+        assert_eq!(parsed.events[0].speed_fps, Some(48.0));
+        assert_eq!(parsed.events[0].event.speed, None);
+    }
+
+    #[test]
+    fn should_mark_every_timecode_drop_frame_under_a_drop_frame_fcm() {
+        let edl = "TITLE: DF\nFCM: DROP FRAME\n\
+            001  R1 V     C        00:01:00:02 00:01:01:00 01:00:00:00 01:00:00:28\n";
+
+        let parsed = parse_edl(edl).expect("a valid EDL");
+
+        assert!(parsed.drop_frame);
+        assert!(parsed.events[0].event.source_in.drop_frame);
+        assert!(parsed.events[0].event.record_out.drop_frame);
+    }
+
+    #[test]
+    fn should_read_our_own_export_back() {
+        let mut seq = make_sequence("Round Trip", 24, 1);
+        let mut track = Track::new_video("V1");
+        track.add_clip(make_clip("c1", "a1", 5.0, 10.0, 0.0, 5.0));
+        let mut fast = make_clip("c2", "a1", 10.0, 20.0, 5.0, 5.0);
+        fast.speed = 2.0;
+        track.add_clip(fast);
+        seq.add_track(track);
+        let mut assets = HashMap::new();
+        assets.insert(
+            "a1".to_string(),
+            make_asset("a1", "interview.mp4", "/media/interview.mp4"),
+        );
+        let (edl, _, _) = export_edl(&seq, &assets).expect("EDL export should succeed");
+
+        let parsed = parse_edl(&edl).expect("our own EDL should parse");
+
+        assert_eq!(parsed.title.as_deref(), Some("Round Trip"));
+        assert_eq!(parsed.events.len(), 2);
+        assert_eq!(
+            parsed.events[0].event.source_file.as_deref(),
+            Some("/media/interview.mp4")
+        );
+        assert_eq!(parsed.events[1].speed_fps, Some(48.0));
+        assert!(parsed.warnings.is_empty(), "{:?}", parsed.warnings);
+    }
+
+    #[test]
+    fn should_report_lines_it_cannot_read_rather_than_dropping_them() {
+        let edl = "TITLE: Odd\n\
+            001  R1 V     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            AUD  3    4\n\
+            002  R1 V     K    000 00:00:00:00 00:00:01:00 01:00:01:00 01:00:02:00\n\
+            003  R1 V     C        00:00:00:00 00:00:01:00 01:00:02:00\n";
+
+        let parsed = parse_edl(edl).expect("a valid EDL");
+
+        assert_eq!(parsed.events.len(), 2);
+        assert_eq!(parsed.warnings.len(), 3, "{:?}", parsed.warnings);
+        assert!(parsed.warnings.iter().any(|w| w.contains("AUD")));
+        assert!(parsed.warnings.iter().any(|w| w.contains("key edit")));
+        assert!(parsed.warnings.iter().any(|w| w.contains("event 3")));
+    }
+
+    #[test]
+    fn should_refuse_text_that_is_not_an_edl() {
+        let error = parse_edl("hello\nworld\n").expect_err("not an EDL");
+        assert!(error.contains("not a CMX 3600 EDL"));
+    }
 }
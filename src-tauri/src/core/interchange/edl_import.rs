@@ -0,0 +1,1107 @@
+//! CMX 3600 EDL Import
+//!
+//! Turns a [`ParsedEdl`] into the plan steps that rebuild it as a new
+//! OpenReelio sequence. Like [`super::otio_import`], nothing here mutates state:
+//! the steps come back as `EditPlan.steps`-shaped JSON and the caller runs them
+//! through the plan machinery, so the whole import — the sequence included — is
+//! one atomic, undoable unit.
+//!
+//! ## What an import can carry
+//!
+//! Video and audio events, dissolves and wipes with their frame counts, `M2`
+//! speed changes and reverse playback. An EDL names its media only by reel, so
+//! every event is matched to an asset — by `* SOURCE FILE`, then by
+//! `* FROM CLIP NAME`, then by reel name — and an event that matches nothing is
+//! listed in [`EdlImportPlan::unresolved`] with the reason, rather than placed
+//! against the wrong media or dropped.
+//!
+//! ## Time
+//!
+//! An EDL carries no frame rate, only timecode, so the caller states the rate
+//! ([`EdlImportContext::sequence_fps`]) and the new sequence is created at it.
+//! Record timecode conventionally starts at `01:00:00:00`, so the sequence's
+//! zero is the whole hour at or before the first event; an edit that starts at
+//! `01:00:05:00` keeps its five-second lead-in.
+//!
+//! Source timecode is read as a position from the start of the media. An asset
+//! whose media is shorter than the timecode an event asks for is reported as
+//! unresolved rather than placed past its end.
+//!
+//! ## Trust
+//!
+//! A `* SOURCE FILE` path is chosen by whoever wrote the EDL, so importing new
+//! media from one goes through the same project-directory scoping as an OTIO
+//! import ([`EdlImportContext::allow_external_media`]).
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::{json, Value as JsonValue};
+
+use crate::core::ai::MAX_PLAN_STEPS;
+use crate::core::assets::Asset;
+use crate::core::timeline::TimelineClock;
+use crate::core::Ratio;
+
+use super::edl::{ParsedEdl, ParsedEdlEvent};
+use super::import_plan::{
+    base_name, step_reference, ImportPlanWriter, ResolvedMedia, SEQUENCE_STEP,
+};
+use super::models::{truncate_reel_name, EditType, EdlChannel, Timecode};
+use super::otio_import::OtioAssetImport;
+
+/// Reel names EDLs use for black, which is a gap rather than media.
+const BLACK_REELS: &[&str] = &["BL", "BLK", "BLACK"];
+
+/// Speed multipliers within this of 1.0 are treated as normal speed: an `M2`
+/// rate is written to one decimal place.
+const UNITY_SPEED_TOLERANCE: f64 = 0.001;
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/// An event the import could not place, and why.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdlUnresolvedEvent {
+    /// The event's number in the EDL.
+    pub event_number: u32,
+    /// Reel the event names.
+    pub reel_name: String,
+    /// The event's `* FROM CLIP NAME`, if it had one.
+    pub clip_name: Option<String>,
+    /// The event's `* SOURCE FILE`, if it had one.
+    pub source_file: Option<String>,
+    /// Channel field as written (`V`, `A12`, `B`...).
+    pub channel: String,
+    /// Record in, as written.
+    pub record_in: String,
+    /// Record out, as written.
+    pub record_out: String,
+    /// Why the event was not placed.
+    pub reason: String,
+}
+
+/// The plan an EDL proposes.
+#[derive(Clone, Debug, Default)]
+pub struct EdlImportPlan {
+    /// `EditPlan.steps`-shaped JSON, in dependency order. The first step
+    /// creates the sequence.
+    pub steps: Vec<JsonValue>,
+    /// Structural notes: parser warnings, overlapping events, transitions that
+    /// could not be attached.
+    pub warnings: Vec<String>,
+    /// Editorial detail the file carried that OpenReelio does not import.
+    pub unsupported: Vec<String>,
+    /// Media the plan imports before it can place clips, in step order.
+    pub asset_imports: Vec<OtioAssetImport>,
+    /// Events that matched no asset.
+    pub unresolved: Vec<EdlUnresolvedEvent>,
+    /// Number of events placed as clips.
+    pub placed_events: usize,
+    /// The record timecode the sequence's zero corresponds to.
+    pub record_start: String,
+}
+
+/// The project an EDL is being imported into.
+pub struct EdlImportContext<'a> {
+    /// Name of the sequence the plan creates.
+    pub sequence_name: &'a str,
+    /// Frame rate the EDL's timecode counts at, and that the sequence is
+    /// created with.
+    pub sequence_fps: Ratio,
+    /// Assets already in the project, keyed by id.
+    pub assets: &'a HashMap<String, Asset>,
+    /// The project directory. Media outside it is refused unless
+    /// [`Self::allow_external_media`] is set.
+    pub project_root: &'a Path,
+    /// Lets a `* SOURCE FILE` comment name media outside the project directory.
+    pub allow_external_media: bool,
+}
+
+/// Converts a parsed EDL into plan steps that build it as a new sequence.
+///
+/// # Errors
+///
+/// Refuses when the plan would exceed [`MAX_PLAN_STEPS`], for the same reason
+/// the OTIO import does: a chunked import is no longer atomic.
+pub fn edl_to_plan_steps(
+    edl: &ParsedEdl,
+    context: &EdlImportContext<'_>,
+) -> Result<EdlImportPlan, String> {
+    let mut builder = PlanBuilder::new(context);
+    builder.out.warnings.extend(edl.warnings.iter().cloned());
+
+    let fps_value = context.sequence_fps.as_f64();
+    if !fps_value.is_finite() || fps_value <= 0.0 {
+        return Err(format!(
+            "{}/{} is not a frame rate an EDL can be read at",
+            context.sequence_fps.num, context.sequence_fps.den
+        ));
+    }
+
+    builder.create_sequence();
+    builder.set_record_start(edl);
+    builder.create_tracks(edl);
+
+    let mut events: Vec<&ParsedEdlEvent> = edl.events.iter().collect();
+    // Stable, so two events at one record time keep their file order.
+    events.sort_by(|a, b| {
+        builder
+            .record_seconds(&a.event.record_in)
+            .total_cmp(&builder.record_seconds(&b.event.record_in))
+    });
+    for record in events {
+        builder.add_event(record);
+    }
+
+    let plan = builder.finish();
+
+    if plan.steps.len() > MAX_PLAN_STEPS {
+        return Err(format!(
+            "This EDL needs {} plan steps, which exceeds the maximum of {MAX_PLAN_STEPS} a single \
+             plan may carry. Splitting it into several plans would give up atomicity, so import a \
+             shorter EDL instead.",
+            plan.steps.len()
+        ));
+    }
+
+    Ok(plan)
+}
+
+// =============================================================================
+// Internal: plan building
+// =============================================================================
+
+/// A track the plan creates, keyed by what it carries.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+enum TrackKey {
+    Video,
+    Audio(Vec<u8>),
+}
+
+impl TrackKey {
+    fn name(&self) -> String {
+        match self {
+            TrackKey::Video => "V1".to_string(),
+            TrackKey::Audio(channels) => channels
+                .iter()
+                .map(|channel| format!("A{channel}"))
+                .collect::<Vec<_>>()
+                .join("+"),
+        }
+    }
+
+    fn step_id(&self) -> String {
+        match self {
+            TrackKey::Video => "track_v".to_string(),
+            TrackKey::Audio(channels) => format!(
+                "track_a{}",
+                channels
+                    .iter()
+                    .map(u8::to_string)
+                    .collect::<Vec<_>>()
+                    .join("_")
+            ),
+        }
+    }
+}
+
+/// The tracks an event's channel field puts it on.
+fn track_keys(channel: &EdlChannel) -> Vec<TrackKey> {
+    match channel {
+        EdlChannel::Video => vec![TrackKey::Video],
+        EdlChannel::Audio(channels) => vec![TrackKey::Audio(channels.clone())],
+        EdlChannel::Both(channels) => vec![TrackKey::Video, TrackKey::Audio(channels.clone())],
+        EdlChannel::None => Vec::new(),
+    }
+}
+
+/// A clip already emitted on a track.
+struct PlacedClip {
+    event_number: u32,
+    /// Id of the step that created the clip, for a transition to reference.
+    clip_step: String,
+    /// Id of the last step that touched the clip, for the next clip on the
+    /// track to order itself behind.
+    last_step: String,
+    timeline_end_sec: f64,
+}
+
+struct PlanBuilder<'a> {
+    context: &'a EdlImportContext<'a>,
+    clock: TimelineClock,
+    plan: EdlImportPlan,
+    out: ImportPlanWriter<'a>,
+    record_start_sec: f64,
+    /// Tracks the plan created, with the clips placed on each so far.
+    tracks: HashMap<TrackKey, Vec<PlacedClip>>,
+}
+
+impl<'a> PlanBuilder<'a> {
+    fn new(context: &'a EdlImportContext<'a>) -> Self {
+        Self {
+            context,
+            clock: TimelineClock::new(context.sequence_fps.clone()),
+            plan: EdlImportPlan::default(),
+            out: ImportPlanWriter::new(
+                "EDL",
+                context.assets,
+                context.project_root,
+                context.allow_external_media,
+            ),
+            record_start_sec: 0.0,
+            tracks: HashMap::new(),
+        }
+    }
+
+    fn finish(self) -> EdlImportPlan {
+        let ImportPlanWriter {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+            ..
+        } = self.out;
+        EdlImportPlan {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+            ..self.plan
+        }
+    }
+
+    fn fps(&self) -> &Ratio {
+        &self.context.sequence_fps
+    }
+
+    fn record_seconds(&self, timecode: &Timecode) -> f64 {
+        timecode.to_seconds(self.fps())
+    }
+
+    // -------------------------------------------------------------------------
+    // Sequence and tracks
+    // -------------------------------------------------------------------------
+
+    fn create_sequence(&mut self) {
+        let fps = self.fps().clone();
+        self.out
+            .create_sequence(self.context.sequence_name, &fps, None);
+    }
+
+    /// Puts the sequence's zero on the whole hour at or before the first event.
+    fn set_record_start(&mut self, edl: &ParsedEdl) {
+        // The hour comes from the timecode itself: at drop-frame rates
+        // `01:00:00;00` is a few milliseconds short of 3600 real seconds.
+        let earliest = edl
+            .events
+            .iter()
+            .map(|record| &record.event.record_in)
+            .min_by(|a, b| self.record_seconds(a).total_cmp(&self.record_seconds(b)));
+
+        if let Some(earliest) = earliest {
+            let start = Timecode {
+                hours: earliest.hours,
+                minutes: 0,
+                seconds: 0,
+                frames: 0,
+                drop_frame: edl.drop_frame,
+            };
+            self.record_start_sec = self.record_seconds(&start);
+            self.plan.record_start = start.to_string();
+        } else {
+            self.plan.record_start = Timecode::zero(edl.drop_frame).to_string();
+        }
+    }
+
+    /// Creates one track per distinct channel assignment, video first.
+    fn create_tracks(&mut self, edl: &ParsedEdl) {
+        let mut keys: Vec<TrackKey> = edl
+            .events
+            .iter()
+            .flat_map(|record| track_keys(&record.event.channel))
+            .collect();
+        keys.sort();
+        keys.dedup();
+
+        for key in keys {
+            let kind = match key {
+                TrackKey::Video => "video",
+                TrackKey::Audio(_) => "audio",
+            };
+            self.out.push_step(
+                &key.step_id(),
+                "CreateTrack",
+                json!({
+                    "sequenceId": step_reference(SEQUENCE_STEP),
+                    "kind": kind,
+                    "name": key.name(),
+                }),
+                &[SEQUENCE_STEP],
+            );
+            self.tracks.insert(key, Vec::new());
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Events
+    // -------------------------------------------------------------------------
+
+    fn add_event(&mut self, record: &ParsedEdlEvent) {
+        let event = &record.event;
+        let keys = track_keys(&event.channel);
+        if keys.is_empty() {
+            self.out.warnings.push(format!(
+                "event {} has no channel assignment and was not imported",
+                event.event_number
+            ));
+            return;
+        }
+
+        if is_black(&event.reel_name) {
+            // Black is a hole in our model; nothing is placed. A transition
+            // *into* black is a fade, which the outgoing clip would carry.
+            if !matches!(event.edit_type, EditType::Cut) {
+                self.out.unsupported.push(format!(
+                    "event {} transitions to black; OpenReelio has no fade-to-black transition, \
+                     so it was imported as a cut",
+                    event.event_number
+                ));
+            }
+            return;
+        }
+
+        let record_in = self.record_seconds(&event.record_in) - self.record_start_sec;
+        let record_out = self.record_seconds(&event.record_out) - self.record_start_sec;
+        let start_frame = self.clock.seconds_to_nearest_frame(record_in);
+        let end_frame = self.clock.seconds_to_nearest_frame(record_out);
+        if end_frame <= start_frame {
+            self.out.warnings.push(format!(
+                "event {} has no record duration and was not imported",
+                event.event_number
+            ));
+            return;
+        }
+        let timeline_start = self.clock.frame_to_seconds(start_frame);
+        let timeline_end = self.clock.frame_to_seconds(end_frame);
+        let record_duration = timeline_end - timeline_start;
+
+        let speed = self.event_speed(record);
+        let source_in = self.record_seconds(&event.source_in);
+        let source_out = source_in + record_duration * speed.map_or(1.0, |(rate, _)| rate);
+        self.check_source_length(record, source_out, speed.is_some());
+
+        let resolved = match self.resolve_media(record, source_in, source_out) {
+            Ok(resolved) => resolved,
+            Err(reason) => {
+                self.unresolved(record, reason);
+                return;
+            }
+        };
+        let media_start = match &resolved {
+            ResolvedMedia::Existing(asset_id) => self.media_start_sec(asset_id, source_in),
+            ResolvedMedia::Imported(_) => 0.0,
+        };
+        let source_in = source_in - media_start;
+        let source_out = source_out - media_start;
+
+        let mut placed_any = false;
+        for key in keys {
+            let overlapping = self.tracks.get(&key).and_then(|placed| {
+                placed
+                    .iter()
+                    .find(|clip| clip.timeline_end_sec > timeline_start + 1e-9)
+                    .map(|clip| clip.event_number)
+            });
+            if let Some(other) = overlapping {
+                self.out.warnings.push(format!(
+                    "event {} overlaps event {other} on track {} and was not imported there",
+                    event.event_number,
+                    key.name()
+                ));
+                continue;
+            }
+
+            let clip_step = format!("clip_{}_{}", key.step_id(), record.line);
+            self.place_clip(
+                &key,
+                &clip_step,
+                &resolved,
+                timeline_start,
+                source_in,
+                source_out,
+            );
+            let mut last_step = clip_step.clone();
+
+            if let Some((rate, reverse)) = speed {
+                let speed_step = format!("speed_{}_{}", key.step_id(), record.line);
+                self.out.push_step(
+                    &speed_step,
+                    "SetClipSpeed",
+                    json!({
+                        "sequenceId": step_reference(SEQUENCE_STEP),
+                        "trackId": step_reference(&key.step_id()),
+                        "clipId": step_reference(&clip_step),
+                        "speed": rate,
+                        "reverse": reverse,
+                    }),
+                    &[&clip_step],
+                );
+                last_step = speed_step;
+            }
+
+            if !matches!(event.edit_type, EditType::Cut) {
+                self.add_transition(record, &key, timeline_start, &clip_step);
+            }
+
+            if let Some(placed) = self.tracks.get_mut(&key) {
+                placed.push(PlacedClip {
+                    event_number: event.event_number,
+                    clip_step,
+                    last_step,
+                    timeline_end_sec: timeline_end,
+                });
+            }
+            placed_any = true;
+        }
+        // A `B` event placed on video and audio is still one event.
+        if placed_any {
+            self.plan.placed_events += 1;
+        }
+    }
+
+    /// Emits the `InsertClip` for an event on one track.
+    ///
+    /// Each clip depends on the clip before it on the track. The plan's
+    /// dependency sort is otherwise free to insert a later clip first, and a
+    /// speed-changed clip is inserted at its full source length before
+    /// `SetClipSpeed` shortens it — so it must land before its neighbour does.
+    fn place_clip(
+        &mut self,
+        key: &TrackKey,
+        clip_step: &str,
+        resolved: &ResolvedMedia,
+        timeline_start: f64,
+        source_in: f64,
+        source_out: f64,
+    ) {
+        let track_step = key.step_id();
+        let mut depends_on = vec![track_step.clone()];
+        if let Some(previous) = self.tracks.get(key).and_then(|placed| placed.last()) {
+            depends_on.push(previous.last_step.clone());
+        }
+        let asset_value = resolved.asset_value(&mut depends_on);
+        let depends_refs: Vec<&str> = depends_on.iter().map(String::as_str).collect();
+
+        self.out.push_step(
+            clip_step,
+            "InsertClip",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "trackId": step_reference(&track_step),
+                "assetId": asset_value,
+                "timelineStart": timeline_start,
+                "sourceIn": source_in,
+                "sourceOut": source_out,
+            }),
+            &depends_refs,
+        );
+    }
+
+    /// The speed multiplier and reverse flag an event's `M2` line asks for, or
+    /// `None` for normal speed.
+    fn event_speed(&mut self, record: &ParsedEdlEvent) -> Option<(f64, bool)> {
+        let speed_fps = record.speed_fps?;
+        let multiplier = speed_fps / self.fps().as_f64();
+
+        if multiplier.abs() < UNITY_SPEED_TOLERANCE {
+            self.out.unsupported.push(format!(
+                "event {} is a freeze frame (M2 rate 0); OpenReelio cannot set a clip to zero \
+                 speed from an EDL, so it was imported at normal speed",
+                record.event.event_number
+            ));
+            return None;
+        }
+        if (multiplier - 1.0).abs() < UNITY_SPEED_TOLERANCE {
+            return None;
+        }
+
+        Some((multiplier.abs(), multiplier < 0.0))
+    }
+
+    /// Warns when the source side of a normal-speed event disagrees with its
+    /// record side. The record side decides the clip's length, because it is
+    /// what the timeline the EDL describes actually shows.
+    fn check_source_length(&mut self, record: &ParsedEdlEvent, source_out: f64, has_speed: bool) {
+        if has_speed {
+            return;
+        }
+        let stated_out = self.record_seconds(&record.event.source_out);
+        let frame = 1.0 / self.fps().as_f64();
+        if (stated_out - source_out).abs() > frame / 2.0 {
+            self.out.warnings.push(format!(
+                "event {}'s source duration does not match its record duration; the clip was \
+                 placed at its record length, ending its source at {}",
+                record.event.event_number,
+                Timecode::from_seconds(source_out, self.fps())
+            ));
+        }
+    }
+
+    fn unresolved(&mut self, record: &ParsedEdlEvent, reason: String) {
+        let event = &record.event;
+        self.plan.unresolved.push(EdlUnresolvedEvent {
+            event_number: event.event_number,
+            reel_name: event.reel_name.clone(),
+            clip_name: event.clip_name.clone(),
+            source_file: event.source_file.clone(),
+            channel: event.channel.to_string(),
+            record_in: event.record_in.to_string(),
+            record_out: event.record_out.to_string(),
+            reason,
+        });
+    }
+
+    // -------------------------------------------------------------------------
+    // Reel matching
+    // -------------------------------------------------------------------------
+
+    /// Finds the asset an event names, importing it from `* SOURCE FILE` when
+    /// the project does not have it, or says why there is none.
+    fn resolve_media(
+        &mut self,
+        record: &ParsedEdlEvent,
+        source_in: f64,
+        source_out: f64,
+    ) -> Result<ResolvedMedia, String> {
+        let event = &record.event;
+
+        if let Some(path) = event.source_file.as_deref() {
+            if let Some(asset_id) = self.out.find_asset_by_path(path) {
+                return self.check_asset_length(asset_id, source_in, source_out);
+            }
+        }
+
+        if let Some(asset_id) = event
+            .clip_name
+            .as_deref()
+            .and_then(|name| self.find_asset_by_name(name))
+        {
+            return self.check_asset_length(asset_id, source_in, source_out);
+        }
+
+        if let Some(path) = event.source_file.as_deref() {
+            if let Some(asset_id) = self.find_asset_by_name(&base_name(path)) {
+                return self.check_asset_length(asset_id, source_in, source_out);
+            }
+        }
+
+        match self.find_assets_by_reel(&event.reel_name).as_slice() {
+            [asset_id] => return self.check_asset_length(asset_id.clone(), source_in, source_out),
+            [] => {}
+            several => {
+                return Err(format!(
+                    "reel {} matches {} assets ({}); add a * FROM CLIP NAME or * SOURCE FILE \
+                     comment to pick one",
+                    event.reel_name,
+                    several.len(),
+                    several.join(", ")
+                ))
+            }
+        }
+
+        let Some(path) = event.source_file.as_deref() else {
+            return Err(format!(
+                "no asset matches reel {}{}",
+                event.reel_name,
+                event
+                    .clip_name
+                    .as_deref()
+                    .map(|name| format!(" or clip name '{name}'"))
+                    .unwrap_or_default()
+            ));
+        };
+
+        let clip_label = event.clip_name.as_deref().unwrap_or(&event.reel_name);
+        self.out.import_media(clip_label, path)
+    }
+
+    /// Refuses an asset whose media ends before the event's source out.
+    fn check_asset_length(
+        &self,
+        asset_id: String,
+        source_in: f64,
+        source_out: f64,
+    ) -> Result<ResolvedMedia, String> {
+        let Some(asset) = self.context.assets.get(&asset_id) else {
+            return Ok(ResolvedMedia::Existing(asset_id));
+        };
+        let source_out = source_out - self.media_start_sec(&asset_id, source_in);
+        match asset.duration_sec.filter(|duration| duration.is_finite()) {
+            Some(duration) if source_out > duration + 1e-6 => Err(format!(
+                "the event reads asset '{}' up to {}, but its media is only {:.3}s long; the EDL \
+                 likely uses camera source timecode",
+                asset.name,
+                Timecode::from_seconds(source_out, self.fps()),
+                duration
+            )),
+            _ => Ok(ResolvedMedia::Existing(asset_id)),
+        }
+    }
+
+    /// Where an asset's own timecode starts, as far as an event reading it
+    /// from `source_in` is concerned.
+    ///
+    /// An EDL written against camera timecode reads a file recorded from
+    /// 01:00:00:00 at 01:00:05:00. One written by a tool that ignores source
+    /// timecode reads the same frame at 00:00:05:00, so an in-point before the
+    /// recorded start is taken as counted from the first frame instead.
+    fn media_start_sec(&self, asset_id: &str, source_in: f64) -> f64 {
+        let start = self
+            .context
+            .assets
+            .get(asset_id)
+            .map(Asset::source_start_sec)
+            .unwrap_or(0.0);
+        if source_in + 1e-6 >= start {
+            start
+        } else {
+            0.0
+        }
+    }
+
+    /// Matches a clip name against asset names, with or without the extension.
+    fn find_asset_by_name(&self, name: &str) -> Option<String> {
+        if let Some(asset_id) = self.out.find_asset_by_name(name) {
+            return Some(asset_id);
+        }
+        let wanted = name.trim().to_lowercase();
+        let wanted_stem = file_stem(&wanted);
+        self.out
+            .sorted_assets()
+            .into_iter()
+            .find(|asset| file_stem(&asset.name.to_lowercase()) == wanted_stem)
+            .map(|asset| asset.id.clone())
+    }
+
+    /// Every asset a reel name could mean: one recorded onto that reel, one
+    /// whose name stem is the reel, or one whose reel or name truncates to it
+    /// the way [`super::edl::export_edl`] does.
+    fn find_assets_by_reel(&self, reel: &str) -> Vec<String> {
+        let wanted = reel.to_uppercase();
+        let mut matches: Vec<String> = self
+            .context
+            .assets
+            .values()
+            .filter(|asset| match asset.reel_name() {
+                Some(recorded) => {
+                    recorded.to_uppercase() == wanted || truncate_reel_name(recorded, 8) == wanted
+                }
+                None => {
+                    file_stem(&asset.name).to_uppercase() == wanted
+                        || truncate_reel_name(&asset.name, 8) == wanted
+                }
+            })
+            .map(|asset| asset.id.clone())
+            .collect();
+        matches.sort();
+        matches
+    }
+
+    // -------------------------------------------------------------------------
+    // Transitions
+    // -------------------------------------------------------------------------
+
+    /// Attaches an event's dissolve or wipe to the clip it cuts away from.
+    ///
+    /// A CMX transition starts at the cut and runs into the incoming clip, with
+    /// the outgoing clip's tail underneath. OpenReelio stores one duration
+    /// centred on the cut, so the blend is attached to the outgoing clip and its
+    /// timing is reported as shifted.
+    fn add_transition(
+        &mut self,
+        record: &ParsedEdlEvent,
+        key: &TrackKey,
+        cut_sec: f64,
+        incoming_step: &str,
+    ) {
+        let event = &record.event;
+        let (effect_type, frames, direction) = match event.edit_type {
+            EditType::Dissolve(frames) => ("cross_dissolve", frames, None),
+            EditType::Wipe(code, frames) => {
+                let direction = match code {
+                    1 => None,
+                    2 => Some("down"),
+                    _ => {
+                        self.out.unsupported.push(format!(
+                            "event {} uses SMPTE wipe pattern {code:03}; it was imported as a \
+                             plain horizontal wipe",
+                            event.event_number
+                        ));
+                        None
+                    }
+                };
+                ("wipe", frames, direction)
+            }
+            EditType::Cut => return,
+        };
+
+        if record.from_reel.as_deref().is_some_and(is_black) {
+            self.out.unsupported.push(format!(
+                "event {} fades up from black; OpenReelio has no fade-from-black transition, so it \
+                 was imported as a cut",
+                event.event_number
+            ));
+            return;
+        }
+
+        let half_frame = 0.5 / self.fps().as_f64();
+        let Some(outgoing_step) = self.tracks.get(key).and_then(|placed| {
+            placed
+                .last()
+                .filter(|clip| (clip.timeline_end_sec - cut_sec).abs() < half_frame)
+                .map(|clip| clip.clip_step.clone())
+        }) else {
+            self.out.warnings.push(format!(
+                "the transition on event {} was not imported: no imported clip on track {} ends \
+                 where it starts",
+                event.event_number,
+                key.name()
+            ));
+            return;
+        };
+
+        if frames == 0 {
+            return;
+        }
+        let duration_sec = frames as f64 / self.fps().as_f64();
+
+        self.out.unsupported.push(format!(
+            "the transition on event {} runs {frames} frames after its cut in the EDL; OpenReelio \
+             centres a transition on the cut, so it starts {:.3}s earlier",
+            event.event_number,
+            duration_sec / 2.0
+        ));
+
+        let mut params = json!({ "duration": duration_sec });
+        if let (Some(map), Some(direction)) = (params.as_object_mut(), direction) {
+            map.insert("direction".to_string(), json!(direction));
+        }
+
+        let step_id = format!("transition_{}_{}", key.step_id(), record.line);
+        self.out.push_step(
+            &step_id,
+            "AddEffect",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "trackId": step_reference(&key.step_id()),
+                "clipId": step_reference(&outgoing_step),
+                "effectType": effect_type,
+                "params": params,
+            }),
+            &[&outgoing_step, incoming_step],
+        );
+    }
+}
+
+// =============================================================================
+// Internal: helpers
+// =============================================================================
+
+fn is_black(reel: &str) -> bool {
+    BLACK_REELS
+        .iter()
+        .any(|black| reel.eq_ignore_ascii_case(black))
+}
+
+fn file_stem(name: &str) -> &str {
+    match name.rfind('.') {
+        Some(dot) if dot > 0 => &name[..dot],
+        _ => name,
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::assets::SourceMetadata;
+    use crate::core::interchange::edl::parse_edl;
+    use crate::core::interchange::import_plan::test_support::{assets_with, steps_of};
+
+    fn plan_for(edl: &str, assets: &HashMap<String, Asset>) -> EdlImportPlan {
+        plan_at(edl, assets, Ratio::new(24, 1))
+    }
+
+    fn plan_at(edl: &str, assets: &HashMap<String, Asset>, fps: Ratio) -> EdlImportPlan {
+        let parsed = parse_edl(edl).expect("fixture should parse");
+        edl_to_plan_steps(
+            &parsed,
+            &EdlImportContext {
+                sequence_name: "Conform",
+                sequence_fps: fps,
+                assets,
+                project_root: Path::new("/media"),
+                allow_external_media: false,
+            },
+        )
+        .expect("plan should build")
+    }
+
+    #[test]
+    fn should_create_the_sequence_at_the_given_rate_without_default_tracks() {
+        let plan = plan_for("TITLE: Empty\n", &HashMap::new());
+
+        assert_eq!(plan.steps[0]["commandType"], "CreateSequence");
+        assert_eq!(plan.steps[0]["payload"]["name"], "Conform");
+        assert_eq!(plan.steps[0]["payload"]["fps"]["num"], 24);
+        assert_eq!(plan.steps[0]["payload"]["addDefaultTracks"], false);
+    }
+
+    #[test]
+    fn should_place_events_relative_to_the_first_record_hour() {
+        let edl = "TITLE: Cut\n\
+            001  INTERVIE V     C        00:00:05:00 00:00:10:00 01:00:02:00 01:00:07:00\n\
+            002  INTERVIE V     C        00:00:20:00 00:00:22:00 01:00:07:00 01:00:09:00\n";
+        let assets = assets_with(&[("a1", "interview.mp4", "/media/interview.mp4", Some(60.0))]);
+
+        let plan = plan_for(edl, &assets);
+
+        let clips = steps_of(&plan.steps, "InsertClip");
+        assert_eq!(clips.len(), 2);
+        assert_eq!(clips[0]["payload"]["assetId"], "a1");
+        assert_eq!(clips[0]["payload"]["timelineStart"], 2.0);
+        assert_eq!(clips[0]["payload"]["sourceIn"], 5.0);
+        assert_eq!(clips[0]["payload"]["sourceOut"], 10.0);
+        assert_eq!(clips[1]["payload"]["timelineStart"], 7.0);
+        assert_eq!(plan.record_start, "01:00:00:00");
+        assert_eq!(plan.placed_events, 2);
+        // The second clip is ordered behind the first.
+        assert!(clips[1]["dependsOn"].as_array().is_some_and(|deps| deps
+            .iter()
+            .any(|dep| dep == clips[0]["id"].as_str().unwrap())));
+    }
+
+    #[test]
+    fn should_match_reels_by_source_file_then_clip_name_then_reel() {
+        let edl = "TITLE: Match\n\
+            001  AX       V     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            * SOURCE FILE: /media/by_path.mov\n\
+            002  AX       V     C        00:00:00:00 00:00:01:00 01:00:01:00 01:00:02:00\n\
+            * FROM CLIP NAME: By Name.mov\n\
+            003  A001C003 V     C        00:00:00:00 00:00:01:00 01:00:02:00 01:00:03:00\n";
+        let assets = assets_with(&[
+            ("p", "by_path.mov", "/media/by_path.mov", Some(10.0)),
+            ("n", "By Name.MOV", "/elsewhere/x.mov", Some(10.0)),
+            ("r", "A001C003.mov", "/media/A001C003.mov", Some(10.0)),
+        ]);
+
+        let plan = plan_for(edl, &assets);
+
+        let ids: Vec<&JsonValue> = steps_of(&plan.steps, "InsertClip")
+            .iter()
+            .map(|step| &step["payload"]["assetId"])
+            .collect();
+        assert_eq!(ids, vec!["p", "n", "r"]);
+        assert!(plan.unresolved.is_empty());
+    }
+
+    #[test]
+    fn should_list_an_event_that_matches_no_asset_as_unresolved() {
+        let edl = "TITLE: Missing\n\
+            001  TAPE42   V     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            * FROM CLIP NAME: lost.mov\n";
+
+        let plan = plan_for(edl, &HashMap::new());
+
+        assert!(steps_of(&plan.steps, "InsertClip").is_empty());
+        assert_eq!(plan.unresolved.len(), 1);
+        let unresolved = &plan.unresolved[0];
+        assert_eq!(unresolved.event_number, 1);
+        assert_eq!(unresolved.reel_name, "TAPE42");
+        assert_eq!(unresolved.record_in, "01:00:00:00");
+        assert!(unresolved.reason.contains("TAPE42"));
+    }
+
+    #[test]
+    fn should_report_an_ambiguous_reel_instead_of_guessing() {
+        let edl = "TITLE: Ambiguous\n\
+            001  CLIP     V     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n";
+        let assets = assets_with(&[
+            ("a", "clip.mov", "/media/a/clip.mov", Some(10.0)),
+            ("b", "clip.mp4", "/media/b/clip.mp4", Some(10.0)),
+        ]);
+
+        let plan = plan_for(edl, &assets);
+
+        assert_eq!(plan.unresolved.len(), 1);
+        assert!(plan.unresolved[0].reason.contains("matches 2 assets"));
+    }
+
+    #[test]
+    fn should_report_source_timecode_beyond_the_media_as_unresolved() {
+        let edl = "TITLE: Camera TC\n\
+            001  A001     V     C        14:00:00:00 14:00:01:00 01:00:00:00 01:00:01:00\n";
+        let assets = assets_with(&[("a", "A001.mov", "/media/A001.mov", Some(30.0))]);
+
+        let plan = plan_for(edl, &assets);
+
+        assert_eq!(plan.unresolved.len(), 1);
+        assert!(plan.unresolved[0].reason.contains("camera source timecode"));
+    }
+
+    #[test]
+    fn should_read_camera_source_timecode_against_the_recorded_start_and_reel() {
+        let edl = "TITLE: Camera TC\n\
+            001  A001C007 V     C        14:00:02:00 14:00:04:00 01:00:00:00 01:00:02:00\n";
+        let mut assets = assets_with(&[("a", "clip.mov", "/media/clip.mov", Some(30.0))]);
+        let recorded = assets.remove("a").unwrap().with_source(SourceMetadata {
+            start_timecode: Some("14:00:00:00".to_string()),
+            start_sec: Some(14.0 * 3600.0),
+            reel_name: Some("A001C007".to_string()),
+            ..SourceMetadata::default()
+        });
+        assets.insert("a".to_string(), recorded);
+
+        let plan = plan_for(edl, &assets);
+
+        assert!(plan.unresolved.is_empty(), "{:?}", plan.unresolved);
+        let clips = steps_of(&plan.steps, "InsertClip");
+        assert_eq!(clips[0]["payload"]["assetId"], "a");
+        assert_eq!(clips[0]["payload"]["sourceIn"], 2.0);
+        assert_eq!(clips[0]["payload"]["sourceOut"], 4.0);
+    }
+
+    #[test]
+    fn should_import_a_source_file_inside_the_project_and_refuse_one_outside() {
+        let edl = "TITLE: Relink\n\
+            001  AX       V     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            * SOURCE FILE: /media/new.mov\n\
+            002  AX       V     C        00:00:00:00 00:00:01:00 01:00:01:00 01:00:02:00\n\
+            * SOURCE FILE: /etc/elsewhere.mov\n";
+
+        let plan = plan_for(edl, &HashMap::new());
+
+        assert_eq!(steps_of(&plan.steps, "ImportAsset").len(), 1);
+        assert_eq!(plan.asset_imports[0].uri, "/media/new.mov");
+        let clips = steps_of(&plan.steps, "InsertClip");
+        assert_eq!(clips[0]["payload"]["assetId"]["$fromStep"], "import_0");
+        assert_eq!(plan.unresolved.len(), 1);
+        assert!(plan.unresolved[0].reason.contains("outside the project"));
+    }
+
+    #[test]
+    fn should_put_audio_channels_on_their_own_tracks_and_both_on_video_and_audio() {
+        let edl = "TITLE: AV\n\
+            001  R1       B     C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n\
+            002  R1       A2    C        00:00:00:00 00:00:01:00 01:00:00:00 01:00:01:00\n";
+        let assets = assets_with(&[("r", "R1.mov", "/media/R1.mov", Some(10.0))]);
+
+        let plan = plan_for(edl, &assets);
+
+        let track_names: Vec<&JsonValue> = steps_of(&plan.steps, "CreateTrack")
+            .iter()
+            .map(|step| &step["payload"]["name"])
+            .collect();
+        assert_eq!(track_names, vec!["V1", "A1", "A2"]);
+        assert_eq!(steps_of(&plan.steps, "InsertClip").len(), 3);
+        assert_eq!(plan.placed_events, 2);
+    }
+
+    #[test]
+    fn should_apply_an_m2_speed_as_a_multiplier_of_the_sequence_rate() {
+        let edl = "TITLE: Speed\n\
+            001  FAST     V     C        00:00:00:00 00:00:05:00 01:00:00:00 01:00:05:00\n\
+            M2   FAST       048.0                00:00:00:00\n\
+            002  FAST     V     C        00:00:30:00 00:00:32:00 01:00:05:00 01:00:07:00\n\
+            M2   FAST       -024.0               00:00:30:00\n";
+        let assets = assets_with(&[("f", "FAST.mov", "/media/FAST.mov", Some(60.0))]);
+
+        let plan = plan_for(edl, &assets);
+
+        let clips = steps_of(&plan.steps, "InsertClip");
+        // 5s of record at 2x reads 10s of source.
+        assert_eq!(clips[0]["payload"]["sourceOut"], 10.0);
+        let speeds = steps_of(&plan.steps, "SetClipSpeed");
+        assert_eq!(speeds.len(), 2);
+        assert_eq!(speeds[0]["payload"]["speed"], 2.0);
+        assert_eq!(speeds[0]["payload"]["reverse"], false);
+        assert_eq!(speeds[1]["payload"]["speed"], 1.0);
+        assert_eq!(speeds[1]["payload"]["reverse"], true);
+        // The next clip waits for the speed change that makes room for it.
+        assert!(clips[1]["dependsOn"].as_array().is_some_and(|deps| deps
+            .iter()
+            .any(|dep| dep == speeds[0]["id"].as_str().unwrap())));
+    }
+
+    #[test]
+    fn should_attach_a_dissolve_to_the_outgoing_clip_with_its_frame_count() {
+        let edl = "TITLE: Dissolve\n\
+            001  REELA    V     C        00:00:00:00 00:00:05:00 01:00:00:00 01:00:05:00\n\
+            002  REELA    V     C        00:00:05:00 00:00:05:00 01:00:05:00 01:00:05:00\n\
+            002  REELB    V     D    024 00:00:10:00 00:00:15:00 01:00:05:00 01:00:10:00\n";
+        let assets = assets_with(&[
+            ("a", "REELA.mov", "/media/REELA.mov", Some(60.0)),
+            ("b", "REELB.mov", "/media/REELB.mov", Some(60.0)),
+        ]);
+
+        let plan = plan_for(edl, &assets);
+
+        let effects = steps_of(&plan.steps, "AddEffect");
+        assert_eq!(effects.len(), 1);
+        let first_clip = steps_of(&plan.steps, "InsertClip")[0]["id"].clone();
+        assert_eq!(effects[0]["payload"]["effectType"], "cross_dissolve");
+        assert_eq!(effects[0]["payload"]["clipId"]["$fromStep"], first_clip);
+        assert_eq!(effects[0]["payload"]["params"]["duration"], 1.0);
+        assert!(plan.unsupported.iter().any(|u| u.contains("centres")));
+    }
+
+    #[test]
+    fn should_report_a_fade_from_black_rather_than_inventing_a_clip() {
+        let edl = "TITLE: Fade\n\
+            001  BL       V     C        00:00:00:00 00:00:00:00 01:00:00:00 01:00:00:00\n\
+            001  REELA    V     D    024 00:00:00:00 00:00:05:00 01:00:00:00 01:00:05:00\n";
+        let assets = assets_with(&[("a", "REELA.mov", "/media/REELA.mov", Some(60.0))]);
+
+        let plan = plan_for(edl, &assets);
+
+        assert_eq!(steps_of(&plan.steps, "InsertClip").len(), 1);
+        assert!(steps_of(&plan.steps, "AddEffect").is_empty());
+        assert!(plan.unsupported.iter().any(|u| u.contains("from black")));
+    }
+
+    #[test]
+    fn should_read_drop_frame_record_timecode_at_29_97() {
+        let edl = "TITLE: DF\nFCM: DROP FRAME\n\
+            001  R1       V     C        00:00:00;00 00:01:00;02 01:00:00;00 01:01:00;02\n";
+        let assets = assets_with(&[("r", "R1.mov", "/media/R1.mov", Some(120.0))]);
+
+        let plan = plan_at(edl, &assets, Ratio::new(30000, 1001));
+
+        let clip = &steps_of(&plan.steps, "InsertClip")[0]["payload"];
+        // 00:01:00;02 is frame 1800 in drop-frame counting.
+        let expected = 1800.0 * 1001.0 / 30000.0;
+        let source_out = clip["sourceOut"].as_f64().expect("a number");
+        assert!((source_out - expected).abs() < 1e-6, "{source_out}");
+        assert_eq!(plan.record_start, "01:00:00;00");
+    }
+
+    #[test]
+    fn should_skip_an_overlapping_event_on_the_same_track() {
+        let edl = "TITLE: Overlap\n\
+            001  R1       V     C        00:00:00:00 00:00:05:00 01:00:00:00 01:00:05:00\n\
+            002  R1       V     C        00:00:00:00 00:00:05:00 01:00:03:00 01:00:08:00\n";
+        let assets = assets_with(&[("r", "R1.mov", "/media/R1.mov", Some(60.0))]);
+
+        let plan = plan_for(edl, &assets);
+
+        assert_eq!(steps_of(&plan.steps, "InsertClip").len(), 1);
+        assert!(plan.warnings.iter().any(|w| w.contains("overlaps event 1")));
+    }
+}
@@ -0,0 +1,1541 @@
+//! FCPXML Import
+//!
+//! Reads a Final Cut Pro XML document — what [`super::xml::export_fcpxml`]
+//! writes, and what Final Cut Pro and DaVinci Resolve export — and turns its
+//! first project into the plan steps that rebuild it as a new OpenReelio
+//! sequence. Like [`super::otio_import`], nothing here mutates state: the steps
+//! come back as `EditPlan.steps`-shaped JSON and the caller runs them through
+//! the plan machinery, so the whole import — the sequence included — is one
+//! atomic, undoable unit.
+//!
+//! ## What an import can carry
+//!
+//! - `format` and `asset` resources: the sequence's frame rate and frame size,
+//!   and the media each clip reads.
+//! - The spine and everything connected to it: `asset-clip`, `clip` (with its
+//!   `video` / `audio` content), `video` and `audio` on any lane, `gap`s and
+//!   the clips connected to them, secondary storylines, and the active pick of
+//!   an `audition`. Each lane becomes a track.
+//! - `transition`s, as a blend on the outgoing clip.
+//! - `marker` and `chapter-marker`, as sequence markers.
+//! - A static `adjust-transform`, as the clip's transform.
+//!
+//! Compound, synchronized and multicam clips, titles, generators, filters,
+//! retiming and keyframed adjustments are named in
+//! [`FcpxmlImportPlan::unsupported`] rather than approximated.
+//!
+//! ## Time
+//!
+//! Every time in an FCPXML document is a rational number of seconds, and each
+//! element's `offset` is measured in its *parent's* local timeline — the
+//! sequence's for a spine item, the clip's own `start`-based timeline for a
+//! clip connected to it. [`parse_fcpxml`] resolves all of that into sequence
+//! seconds, counted from the sequence's `tcStart`; the plan then snaps every
+//! position to the new sequence's frame grid.
+//!
+//! ## Relinking
+//!
+//! Media is matched to project assets by the relinking every interchange
+//! import shares: by path, then by file name, and only then imported — through
+//! the same project-directory scoping
+//! ([`FcpxmlImportContext::allow_external_media`]).
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde_json::{json, Value as JsonValue};
+
+use crate::core::ai::MAX_PLAN_STEPS;
+use crate::core::assets::Asset;
+use crate::core::timeline::{MarkerType, TimelineClock, Transform};
+use crate::core::{Point2D, Ratio};
+
+use super::import_plan::{
+    step_reference, ImportPlanWriter, ResolvedMedia, DEFAULT_CANVAS, SEQUENCE_STEP,
+};
+use super::models::file_url_to_path;
+use super::otio_import::OtioAssetImport;
+use super::xml_tree::{parse_xml, XmlElement};
+
+/// Clip children that are adjustments or effects the import does not carry,
+/// with the name they are reported under.
+const UNSUPPORTED_ADJUSTMENTS: &[(&str, &str)] = &[
+    ("adjust-crop", "a crop"),
+    ("adjust-corners", "a corner pin"),
+    ("adjust-blend", "a blend mode or opacity"),
+    ("adjust-volume", "a volume adjustment"),
+    ("adjust-panner", "a pan adjustment"),
+    ("adjust-stabilization", "stabilization"),
+    ("adjust-rollingShutter", "rolling-shutter correction"),
+    ("adjust-conform", "a conform adjustment"),
+    ("adjust-360-transform", "a 360° transform"),
+    ("timeMap", "a retime"),
+];
+
+// =============================================================================
+// Public API: parsing
+// =============================================================================
+
+/// A media `asset` declared in the document's resources.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FcpxmlAsset {
+    /// Resource id clips reference it by (`r2`).
+    pub id: String,
+    /// Display name.
+    pub name: String,
+    /// Media URL: the `src` attribute, or the original `media-rep`'s.
+    pub src: Option<String>,
+    /// The media's own start time. Clip `start`s are measured on this clock.
+    pub start_sec: f64,
+    /// Length of the media, if stated.
+    pub duration_sec: Option<f64>,
+    pub has_video: bool,
+    pub has_audio: bool,
+}
+
+/// A marker on the sequence's timeline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FcpxmlMarker {
+    pub label: String,
+    /// Position in sequence seconds.
+    pub time_sec: f64,
+    /// `chapter` for a `chapter-marker`, `todo` for a to-do marker.
+    pub marker_type: MarkerType,
+}
+
+/// A clip resolved to sequence time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FcpxmlClip {
+    /// Position among the document's clips, for stable step ids.
+    pub index: usize,
+    pub name: String,
+    /// Id of the `asset` resource the clip reads.
+    pub asset_ref: String,
+    /// Lane the clip sits on: `0` is the primary storyline, positive lanes are
+    /// above it and negative below.
+    pub lane: i32,
+    /// Whether the clip carries only sound.
+    pub audio_only: bool,
+    /// Position in sequence seconds.
+    pub timeline_start_sec: f64,
+    pub duration_sec: f64,
+    /// Source in-point, in seconds from the start of the media.
+    pub source_in_sec: f64,
+    /// A static `adjust-transform`, already in OpenReelio's normalized space.
+    pub transform: Option<Transform>,
+    /// Markers the clip carries, in sequence time.
+    pub markers: Vec<FcpxmlMarker>,
+}
+
+/// A transition resolved to sequence time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FcpxmlTransition {
+    pub name: String,
+    pub lane: i32,
+    /// Start of the overlap, in sequence seconds.
+    pub timeline_start_sec: f64,
+    pub duration_sec: f64,
+}
+
+/// An FCPXML document's first project, read into sequence time.
+#[derive(Clone, Debug, Default)]
+pub struct ParsedFcpxml {
+    /// The document's `version` attribute.
+    pub version: Option<String>,
+    /// Name of the project the sequence came from.
+    pub project_name: Option<String>,
+    /// Frame rate of the sequence's format, if it states a frame duration.
+    pub fps: Option<Ratio>,
+    /// Frame size of the sequence's format, if stated.
+    pub canvas: Option<(u32, u32)>,
+    /// Whether the sequence counts drop-frame timecode.
+    pub drop_frame: bool,
+    /// Media assets, keyed by resource id.
+    pub assets: HashMap<String, FcpxmlAsset>,
+    /// Clips in document order.
+    pub clips: Vec<FcpxmlClip>,
+    /// Transitions in document order.
+    pub transitions: Vec<FcpxmlTransition>,
+    /// Structural notes from reading the document.
+    pub warnings: Vec<String>,
+    /// Detail the document carried that the import does not restore.
+    pub unsupported: Vec<String>,
+}
+
+/// Parses an FCPXML document and resolves its first project to sequence time.
+///
+/// # Errors
+///
+/// Refuses a document that is not XML, whose root is not `<fcpxml>`, or that
+/// carries no project with a spine.
+pub fn parse_fcpxml(content: &str) -> Result<ParsedFcpxml, String> {
+    let root = parse_xml(content).map_err(|error| format!("this is not FCPXML: {error}"))?;
+    if root.name != "fcpxml" {
+        return Err(format!(
+            "this is not an FCPXML document: its root element is <{}>, not <fcpxml>",
+            root.name
+        ));
+    }
+
+    let mut parsed = ParsedFcpxml {
+        version: root.attr("version").map(str::to_string),
+        ..ParsedFcpxml::default()
+    };
+
+    let resources = Resources::read(root.child("resources"), &mut parsed);
+
+    let mut projects = Vec::new();
+    root.descendants("project", &mut projects);
+    let Some(project) = projects.first() else {
+        return Err(
+            "this FCPXML carries no project; export a project (a timeline) from the editor, not \
+             only clips or an event"
+                .to_string(),
+        );
+    };
+    if projects.len() > 1 {
+        parsed.warnings.push(format!(
+            "the document holds {} projects; only the first, '{}', was imported",
+            projects.len(),
+            project.attr("name").unwrap_or_default()
+        ));
+    }
+    parsed.project_name = project
+        .attr("name")
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string);
+
+    let sequence = project
+        .child("sequence")
+        .ok_or_else(|| "the project has no <sequence>".to_string())?;
+    let spine = sequence
+        .child("spine")
+        .ok_or_else(|| "the project's sequence has no <spine>".to_string())?;
+
+    if let Some(format) = sequence
+        .attr("format")
+        .and_then(|id| resources.formats.get(id))
+    {
+        parsed.fps = format.fps.clone();
+        parsed.canvas = format.canvas;
+    }
+    parsed.drop_frame = sequence.attr("tcFormat") == Some("DF");
+    let tc_start = sequence.attr("tcStart").and_then(parse_time).unwrap_or(0.0);
+
+    let mut walker = Walker {
+        resources: &resources,
+        canvas: parsed.canvas.unwrap_or(DEFAULT_CANVAS),
+        parsed: &mut parsed,
+    };
+    walker.walk_container(
+        &spine.children,
+        TimeMap {
+            sequence_origin: 0.0,
+            local_origin: tc_start,
+        },
+        0,
+    );
+
+    Ok(parsed)
+}
+
+// =============================================================================
+// Public API: plan building
+// =============================================================================
+
+/// The plan an FCPXML document proposes.
+#[derive(Clone, Debug, Default)]
+pub struct FcpxmlImportPlan {
+    /// `EditPlan.steps`-shaped JSON, in dependency order. The first step
+    /// creates the sequence.
+    pub steps: Vec<JsonValue>,
+    /// Structural notes: unresolved media, overlapping clips, transitions that
+    /// could not be attached.
+    pub warnings: Vec<String>,
+    /// Editorial detail the file carried that OpenReelio does not import.
+    pub unsupported: Vec<String>,
+    /// Media the plan imports before it can place clips, in step order.
+    pub asset_imports: Vec<OtioAssetImport>,
+    /// Number of clips placed.
+    pub placed_clips: usize,
+    /// Frame size the sequence is created at.
+    pub canvas: (u32, u32),
+}
+
+/// The project an FCPXML document is being imported into.
+pub struct FcpxmlImportContext<'a> {
+    /// Name of the sequence the plan creates.
+    pub sequence_name: &'a str,
+    /// Frame rate the sequence is created with; every position is snapped to
+    /// its grid.
+    pub sequence_fps: Ratio,
+    /// Assets already in the project, keyed by id.
+    pub assets: &'a HashMap<String, Asset>,
+    /// The project directory. Media outside it is refused unless
+    /// [`Self::allow_external_media`] is set.
+    pub project_root: &'a Path,
+    /// Lets the document name media outside the project directory.
+    pub allow_external_media: bool,
+}
+
+/// Converts a parsed FCPXML project into plan steps that build it as a new
+/// sequence.
+///
+/// # Errors
+///
+/// Refuses an unusable frame rate, and a plan that would exceed
+/// [`MAX_PLAN_STEPS`] — a chunked import is no longer atomic.
+pub fn fcpxml_to_plan_steps(
+    fcpxml: &ParsedFcpxml,
+    context: &FcpxmlImportContext<'_>,
+) -> Result<FcpxmlImportPlan, String> {
+    let fps_value = context.sequence_fps.as_f64();
+    if !fps_value.is_finite() || fps_value <= 0.0 {
+        return Err(format!(
+            "{}/{} is not a frame rate a sequence can be created at",
+            context.sequence_fps.num, context.sequence_fps.den
+        ));
+    }
+
+    let mut builder = PlanBuilder::new(fcpxml, context);
+    builder.out.warnings.extend(fcpxml.warnings.iter().cloned());
+    builder
+        .out
+        .unsupported
+        .extend(fcpxml.unsupported.iter().cloned());
+
+    builder.create_sequence();
+    builder.create_tracks();
+
+    let mut clips: Vec<&FcpxmlClip> = fcpxml.clips.iter().collect();
+    clips.sort_by(|a, b| {
+        a.timeline_start_sec
+            .total_cmp(&b.timeline_start_sec)
+            .then(a.index.cmp(&b.index))
+    });
+    for clip in clips {
+        builder.add_clip(clip);
+    }
+    for (index, transition) in fcpxml.transitions.iter().enumerate() {
+        builder.add_transition(index, transition);
+    }
+
+    let plan = builder.finish();
+    if plan.steps.len() > MAX_PLAN_STEPS {
+        return Err(format!(
+            "This FCPXML needs {} plan steps, which exceeds the maximum of {MAX_PLAN_STEPS} a \
+             single plan may carry. Splitting it into several plans would give up atomicity, so \
+             import a shorter project instead.",
+            plan.steps.len()
+        ));
+    }
+
+    Ok(plan)
+}
+
+// =============================================================================
+// Internal: resources
+// =============================================================================
+
+struct FcpxmlFormat {
+    fps: Option<Ratio>,
+    canvas: Option<(u32, u32)>,
+}
+
+struct Resources {
+    formats: HashMap<String, FcpxmlFormat>,
+    /// `effect` resources: generators and titles reference these.
+    effects: HashMap<String, String>,
+    /// `media` resources: compound and multicam clips reference these.
+    media: HashMap<String, String>,
+}
+
+impl Resources {
+    /// Reads the resources section, storing assets on `parsed` directly.
+    fn read(resources: Option<&XmlElement>, parsed: &mut ParsedFcpxml) -> Self {
+        let mut formats = HashMap::new();
+        let mut effects = HashMap::new();
+        let mut media = HashMap::new();
+
+        for resource in resources.map(|r| r.children.as_slice()).unwrap_or_default() {
+            let Some(id) = resource.attr("id") else {
+                continue;
+            };
+            let name = resource.attr("name").unwrap_or(id).to_string();
+            match resource.name.as_str() {
+                "format" => {
+                    let fps = resource
+                        .attr("frameDuration")
+                        .and_then(parse_rational)
+                        .filter(|(num, den)| *num > 0 && *den > 0)
+                        .and_then(|(num, den)| {
+                            // A frame lasting num/den seconds is den/num fps.
+                            let divisor = gcd(num, den);
+                            Some(Ratio::new(
+                                i32::try_from(den / divisor).ok()?,
+                                i32::try_from(num / divisor).ok()?,
+                            ))
+                        });
+                    let canvas = resource
+                        .attr("width")
+                        .and_then(|w| w.parse::<u32>().ok())
+                        .zip(resource.attr("height").and_then(|h| h.parse::<u32>().ok()))
+                        .filter(|(w, h)| *w > 0 && *h > 0);
+                    formats.insert(id.to_string(), FcpxmlFormat { fps, canvas });
+                }
+                "asset" => {
+                    let src = resource.attr("src").map(str::to_string).or_else(|| {
+                        let reps: Vec<&XmlElement> = resource.children_named("media-rep").collect();
+                        reps.iter()
+                            .find(|rep| rep.attr("kind") == Some("original-media"))
+                            .or(reps.first())
+                            .and_then(|rep| rep.attr("src"))
+                            .map(str::to_string)
+                    });
+                    parsed.assets.insert(
+                        id.to_string(),
+                        FcpxmlAsset {
+                            id: id.to_string(),
+                            name,
+                            src,
+                            start_sec: resource.attr("start").and_then(parse_time).unwrap_or(0.0),
+                            duration_sec: resource.attr("duration").and_then(parse_time),
+                            has_video: resource.attr("hasVideo") == Some("1"),
+                            has_audio: resource.attr("hasAudio") == Some("1"),
+                        },
+                    );
+                }
+                "effect" => {
+                    effects.insert(id.to_string(), name);
+                }
+                "media" => {
+                    media.insert(id.to_string(), name);
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            formats,
+            effects,
+            media,
+        }
+    }
+}
+
+// =============================================================================
+// Internal: walking the spine
+// =============================================================================
+
+/// Maps a container's local time onto sequence time: local `local_origin`
+/// falls at sequence `sequence_origin`.
+#[derive(Clone, Copy)]
+struct TimeMap {
+    sequence_origin: f64,
+    local_origin: f64,
+}
+
+impl TimeMap {
+    fn to_sequence(self, local: f64) -> f64 {
+        self.sequence_origin + (local - self.local_origin)
+    }
+}
+
+struct Walker<'a> {
+    resources: &'a Resources,
+    /// Frame size `adjust-transform` positions are measured against.
+    canvas: (u32, u32),
+    parsed: &'a mut ParsedFcpxml,
+}
+
+impl Walker<'_> {
+    /// Walks a storyline's items. Items without an `offset` follow the one
+    /// before them, which is how a storyline is laid out.
+    fn walk_container(&mut self, children: &[XmlElement], map: TimeMap, lane: i32) {
+        let mut cursor = map.sequence_origin;
+        for element in children {
+            if let Some(end) = self.walk_item(element, map, lane, cursor) {
+                cursor = end;
+            }
+        }
+    }
+
+    /// Walks the clips, storylines and transitions connected to an item: its
+    /// children that sit on a lane of their own.
+    fn walk_connected(&mut self, parent: &XmlElement, map: TimeMap, lane: i32) {
+        for element in &parent.children {
+            if element.attr("lane").is_some() {
+                self.walk_item(element, map, lane, map.sequence_origin);
+            }
+        }
+    }
+
+    /// Walks one item and returns where it ends in sequence time, if it is an
+    /// item that occupies time.
+    fn walk_item(
+        &mut self,
+        element: &XmlElement,
+        map: TimeMap,
+        parent_lane: i32,
+        cursor: f64,
+    ) -> Option<f64> {
+        let lane = parent_lane
+            + element
+                .attr("lane")
+                .and_then(|lane| lane.parse::<i32>().ok())
+                .unwrap_or(0);
+        let start = element
+            .attr("offset")
+            .and_then(parse_time)
+            .map(|offset| map.to_sequence(offset))
+            .unwrap_or(cursor);
+        let duration = element.attr("duration").and_then(parse_time);
+        let name = element.attr("name").unwrap_or_default().to_string();
+
+        match element.name.as_str() {
+            "asset-clip" | "clip" | "video" | "audio" => {
+                let duration = self.require_duration(element, duration)?;
+                self.add_clip(element, start, duration, lane);
+                Some(start + duration)
+            }
+            "gap" => {
+                let duration = self.require_duration(element, duration)?;
+                let local_start = element.attr("start").and_then(parse_time).unwrap_or(0.0);
+                self.walk_connected(element, clip_map(start, local_start), lane);
+                Some(start + duration)
+            }
+            "transition" => {
+                let duration = self.require_duration(element, duration)?;
+                self.parsed.transitions.push(FcpxmlTransition {
+                    name,
+                    lane,
+                    timeline_start_sec: start,
+                    duration_sec: duration,
+                });
+                // A transition overlaps its neighbours; it does not advance
+                // the storyline.
+                None
+            }
+            "spine" => {
+                // A secondary storyline. Its items are laid out from its own
+                // first offset, which lands where the storyline is connected.
+                let first_offset = element
+                    .children
+                    .first()
+                    .and_then(|first| first.attr("offset"))
+                    .and_then(parse_time)
+                    .unwrap_or(0.0);
+                self.walk_container(&element.children, clip_map(start, first_offset), lane);
+                None
+            }
+            "audition" => {
+                // The first child is the active pick; the alternates are not
+                // part of the edit.
+                let pick = element.children.first()?;
+                if element.children.len() > 1 {
+                    self.parsed.unsupported.push(format!(
+                        "audition '{}' holds {} alternates; only its active pick was imported",
+                        pick.attr("name").unwrap_or_default(),
+                        element.children.len() - 1
+                    ));
+                }
+                self.walk_item(pick, map, parent_lane, cursor)
+            }
+            other => {
+                let what = match other {
+                    "ref-clip" => "compound clip",
+                    "sync-clip" => "synchronized clip",
+                    "mc-clip" => "multicam clip",
+                    "title" => "title",
+                    "mc-angle" => "multicam angle",
+                    _ => "",
+                };
+                if what.is_empty() {
+                    self.parsed.unsupported.push(format!(
+                        "a <{other}> element was not imported: OpenReelio has no equivalent"
+                    ));
+                } else {
+                    self.parsed.unsupported.push(format!(
+                        "{what} '{name}' was not imported: OpenReelio imports a flat cut of media \
+                         clips"
+                    ));
+                }
+                duration.map(|duration| start + duration)
+            }
+        }
+    }
+
+    fn require_duration(&mut self, element: &XmlElement, duration: Option<f64>) -> Option<f64> {
+        match duration {
+            Some(duration) if duration > 0.0 => Some(duration),
+            _ => {
+                self.parsed.warnings.push(format!(
+                    "<{}> '{}' has no usable duration and was not imported",
+                    element.name,
+                    element.attr("name").unwrap_or_default()
+                ));
+                None
+            }
+        }
+    }
+
+    fn add_clip(&mut self, element: &XmlElement, start: f64, duration: f64, lane: i32) {
+        let name = element.attr("name").unwrap_or_default().to_string();
+
+        // An `asset-clip`, a bare `video` / `audio`, or our own export's
+        // `clip` names its media directly. A Final Cut `clip` is a container
+        // whose `video` or `audio` child does.
+        let media = if element.attr("ref").is_some() {
+            Some(element)
+        } else {
+            element.children.iter().find(|child| {
+                matches!(child.name.as_str(), "video" | "audio") && child.attr("ref").is_some()
+            })
+        };
+        let local_start = element.attr("start").and_then(parse_time);
+
+        let Some(media) = media else {
+            self.parsed.warnings.push(format!(
+                "clip '{name}' references no media and was not imported"
+            ));
+            return;
+        };
+        let asset_ref = media.attr("ref").unwrap_or_default();
+
+        let Some(asset) = self.parsed.assets.get(asset_ref).cloned() else {
+            let what = if let Some(effect) = self.resources.effects.get(asset_ref) {
+                format!("the generator '{effect}'")
+            } else if let Some(media) = self.resources.media.get(asset_ref) {
+                format!("the compound or multicam media '{media}'")
+            } else {
+                format!("the undeclared resource '{asset_ref}'")
+            };
+            self.parsed.unsupported.push(format!(
+                "clip '{name}' plays {what}, which was not imported"
+            ));
+            let local_start = local_start.unwrap_or(0.0);
+            self.walk_connected(element, clip_map(start, local_start), lane);
+            return;
+        };
+        let name = if name.is_empty() {
+            asset.name.clone()
+        } else {
+            name
+        };
+
+        if element.attr("enabled") == Some("0") {
+            self.parsed.warnings.push(format!(
+                "clip '{name}' is disabled in the file and was not imported"
+            ));
+            return;
+        }
+
+        let local_start = local_start.unwrap_or(asset.start_sec);
+        let media_time = if std::ptr::eq(media, element) {
+            local_start
+        } else {
+            // The content's `offset` is in the container clip's time and its
+            // `start` in the media's.
+            let inner_offset = media
+                .attr("offset")
+                .and_then(parse_time)
+                .unwrap_or(local_start);
+            let inner_start = media
+                .attr("start")
+                .and_then(parse_time)
+                .unwrap_or(asset.start_sec);
+            inner_start + (local_start - inner_offset)
+        };
+        let source_in = media_time - asset.start_sec;
+        if source_in < -1e-6 {
+            self.parsed.warnings.push(format!(
+                "clip '{name}' reads from before the start of its media and was not imported"
+            ));
+            return;
+        }
+
+        let audio_only = element.name == "audio"
+            || media.name == "audio"
+            || element.attr("srcEnable") == Some("audio")
+            || (asset.has_audio && !asset.has_video);
+
+        let map = clip_map(start, local_start);
+        let transform = self.read_adjustments(element, &name);
+        let markers = self.read_markers(element, map);
+
+        let index = self.parsed.clips.len();
+        self.parsed.clips.push(FcpxmlClip {
+            index,
+            name,
+            asset_ref: asset_ref.to_string(),
+            lane,
+            audio_only,
+            timeline_start_sec: start,
+            duration_sec: duration,
+            source_in_sec: source_in.max(0.0),
+            transform,
+            markers,
+        });
+
+        self.walk_connected(element, map, lane);
+    }
+
+    /// Reads the clip's static transform and reports every other adjustment,
+    /// filter and retime it carries.
+    fn read_adjustments(&mut self, element: &XmlElement, name: &str) -> Option<Transform> {
+        let mut lost: Vec<String> = Vec::new();
+        let mut transform = None;
+
+        for child in &element.children {
+            match child.name.as_str() {
+                "adjust-transform" => {
+                    if child.child("param").is_some() {
+                        lost.push("a keyframed transform (its static values were kept)".into());
+                    }
+                    transform = Some(self.transform_of(child));
+                }
+                "filter-video" | "filter-audio" => lost.push(format!(
+                    "the {} '{}'",
+                    if child.name == "filter-video" {
+                        "video effect"
+                    } else {
+                        "audio effect"
+                    },
+                    child
+                        .attr("name")
+                        .or_else(|| {
+                            child
+                                .attr("ref")
+                                .and_then(|r| self.resources.effects.get(r))
+                                .map(String::as_str)
+                        })
+                        .unwrap_or("unnamed")
+                )),
+                other => {
+                    if let Some((_, label)) = UNSUPPORTED_ADJUSTMENTS
+                        .iter()
+                        .find(|(element_name, _)| *element_name == other)
+                    {
+                        lost.push((*label).to_string());
+                    }
+                }
+            }
+        }
+
+        if !lost.is_empty() {
+            self.parsed.unsupported.push(format!(
+                "clip '{name}' carries {} that FCPXML import does not restore",
+                lost.join(", ")
+            ));
+        }
+        transform
+    }
+
+    /// Converts an `adjust-transform` into OpenReelio's normalized transform.
+    ///
+    /// FCPXML measures `position` and `anchor` from the frame's centre in
+    /// percent of the frame *height* on both axes, with y pointing up, and
+    /// `rotation` in degrees counter-clockwise. OpenReelio normalizes each axis
+    /// to its own dimension from the top-left, with y pointing down, and
+    /// rotates clockwise.
+    fn transform_of(&self, adjust: &XmlElement) -> Transform {
+        let (width, height) = self.canvas;
+        let aspect = f64::from(height) / f64::from(width);
+        let to_normalized =
+            |(x, y): (f64, f64)| Point2D::new(0.5 + x / 100.0 * aspect, 0.5 - y / 100.0);
+
+        let defaults = Transform::default();
+        Transform {
+            position: adjust
+                .attr("position")
+                .and_then(parse_pair)
+                .map(to_normalized)
+                .unwrap_or(defaults.position),
+            scale: adjust
+                .attr("scale")
+                .and_then(parse_pair)
+                .map(|(x, y)| Point2D::new(x, y))
+                .unwrap_or(defaults.scale),
+            rotation_deg: adjust
+                .attr("rotation")
+                .and_then(|r| r.trim().parse::<f64>().ok())
+                .filter(|r| r.is_finite())
+                .map(|r| -r)
+                .unwrap_or(defaults.rotation_deg),
+            anchor: adjust
+                .attr("anchor")
+                .and_then(parse_pair)
+                .map(to_normalized)
+                .unwrap_or(defaults.anchor),
+        }
+    }
+
+    fn read_markers(&mut self, element: &XmlElement, map: TimeMap) -> Vec<FcpxmlMarker> {
+        let mut markers = Vec::new();
+        for child in &element.children {
+            let marker_type = match child.name.as_str() {
+                "marker" if child.attr("completed").is_some() => MarkerType::Todo,
+                "marker" => MarkerType::Generic,
+                "chapter-marker" => MarkerType::Chapter,
+                _ => continue,
+            };
+            let label = child.attr("value").unwrap_or_default().to_string();
+            let Some(local) = child.attr("start").and_then(parse_time) else {
+                self.parsed.warnings.push(format!(
+                    "marker '{label}' has no readable start and was not imported"
+                ));
+                continue;
+            };
+            markers.push(FcpxmlMarker {
+                label,
+                time_sec: map.to_sequence(local),
+                marker_type,
+            });
+        }
+        markers
+    }
+}
+
+/// The time map of an item's own timeline: its `start` falls where it sits.
+fn clip_map(sequence_start: f64, local_start: f64) -> TimeMap {
+    TimeMap {
+        sequence_origin: sequence_start,
+        local_origin: local_start,
+    }
+}
+
+// =============================================================================
+// Internal: plan building
+// =============================================================================
+
+/// A track the plan creates: a lane, split by what it carries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct TrackKey {
+    audio: bool,
+    lane: i32,
+}
+
+/// A clip already emitted on a track.
+struct PlacedClip {
+    step_id: String,
+    timeline_start_sec: f64,
+    timeline_end_sec: f64,
+}
+
+struct PlanBuilder<'a> {
+    fcpxml: &'a ParsedFcpxml,
+    context: &'a FcpxmlImportContext<'a>,
+    clock: TimelineClock,
+    plan: FcpxmlImportPlan,
+    out: ImportPlanWriter<'a>,
+    /// Step id of each track the plan created.
+    track_steps: HashMap<TrackKey, String>,
+    /// Clips placed on each track so far, in timeline order.
+    placed: HashMap<TrackKey, Vec<PlacedClip>>,
+    /// Resolution of each FCPXML asset, so each is relinked — and reported —
+    /// once. `None` when it could not be.
+    resolved: HashMap<String, Option<ResolvedMedia>>,
+}
+
+impl<'a> PlanBuilder<'a> {
+    fn new(fcpxml: &'a ParsedFcpxml, context: &'a FcpxmlImportContext<'a>) -> Self {
+        Self {
+            fcpxml,
+            context,
+            clock: TimelineClock::new(context.sequence_fps.clone()),
+            plan: FcpxmlImportPlan::default(),
+            out: ImportPlanWriter::new(
+                "FCPXML",
+                context.assets,
+                context.project_root,
+                context.allow_external_media,
+            ),
+            track_steps: HashMap::new(),
+            placed: HashMap::new(),
+            resolved: HashMap::new(),
+        }
+    }
+
+    fn finish(self) -> FcpxmlImportPlan {
+        let ImportPlanWriter {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+            ..
+        } = self.out;
+        FcpxmlImportPlan {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+            ..self.plan
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Sequence and tracks
+    // -------------------------------------------------------------------------
+
+    fn create_sequence(&mut self) {
+        let canvas = self.out.preset_canvas(self.fcpxml.canvas);
+        self.plan.canvas = canvas;
+        self.out.create_sequence(
+            self.context.sequence_name,
+            &self.context.sequence_fps,
+            Some(canvas),
+        );
+    }
+
+    /// Creates one track per lane and kind: video lanes top-down, then audio
+    /// lanes outward from the primary storyline.
+    ///
+    /// Track 0 is the topmost track and is composited in front of the rest, so
+    /// the highest lane is created first. Video tracks are still numbered from
+    /// the bottom: the primary storyline's track is `V1` when nothing sits
+    /// below it.
+    fn create_tracks(&mut self) {
+        let mut keys: Vec<TrackKey> = self
+            .fcpxml
+            .clips
+            .iter()
+            .map(|clip| TrackKey {
+                audio: clip.audio_only,
+                lane: clip.lane,
+            })
+            .collect();
+        keys.sort_by_key(|key| {
+            if key.audio {
+                (1, key.lane.abs(), key.lane > 0)
+            } else {
+                (0, -key.lane, false)
+            }
+        });
+        keys.dedup();
+
+        let mut video_left = keys.iter().filter(|key| !key.audio).count();
+        let mut audio_count = 0;
+        for key in keys {
+            let (kind, name) = if key.audio {
+                audio_count += 1;
+                ("audio", format!("A{audio_count}"))
+            } else {
+                let name = format!("V{video_left}");
+                video_left -= 1;
+                ("video", name)
+            };
+            let step_id = format!("track_{}", self.track_steps.len());
+            self.out.push_step(
+                &step_id,
+                "CreateTrack",
+                json!({
+                    "sequenceId": step_reference(SEQUENCE_STEP),
+                    "kind": kind,
+                    "name": name,
+                }),
+                &[SEQUENCE_STEP],
+            );
+            self.track_steps.insert(key, step_id);
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Clips
+    // -------------------------------------------------------------------------
+
+    fn add_clip(&mut self, clip: &FcpxmlClip) {
+        let key = TrackKey {
+            audio: clip.audio_only,
+            lane: clip.lane,
+        };
+        let Some(track_step) = self.track_steps.get(&key).cloned() else {
+            return;
+        };
+
+        let start_frame = self.clock.seconds_to_nearest_frame(clip.timeline_start_sec);
+        let end_frame = self
+            .clock
+            .seconds_to_nearest_frame(clip.timeline_start_sec + clip.duration_sec);
+        if end_frame <= start_frame {
+            self.out.warnings.push(format!(
+                "clip '{}' is shorter than one frame at the sequence's rate and was not imported",
+                clip.name
+            ));
+            return;
+        }
+        let timeline_start = self.clock.frame_to_seconds(start_frame);
+        let timeline_end = self.clock.frame_to_seconds(end_frame);
+
+        if let Some(previous) = self.placed.get(&key).and_then(|placed| {
+            placed
+                .iter()
+                .find(|other| other.timeline_end_sec > timeline_start + 1e-9)
+        }) {
+            self.out.warnings.push(format!(
+                "clip '{}' overlaps an earlier clip on its lane (which ends at {:.3}s) and was \
+                 not imported",
+                clip.name, previous.timeline_end_sec
+            ));
+            return;
+        }
+
+        let Some(resolved) = self.resolve_media(&clip.asset_ref, &clip.name) else {
+            return;
+        };
+
+        let step_id = format!("clip_{}", clip.index);
+        let mut depends_on = vec![track_step.clone()];
+        let asset_value = resolved.asset_value(&mut depends_on);
+        let depends_refs: Vec<&str> = depends_on.iter().map(String::as_str).collect();
+
+        self.out.push_step(
+            &step_id,
+            "InsertClip",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "trackId": step_reference(&track_step),
+                "assetId": asset_value,
+                "timelineStart": timeline_start,
+                "sourceIn": clip.source_in_sec,
+                "sourceOut": clip.source_in_sec + (timeline_end - timeline_start),
+            }),
+            &depends_refs,
+        );
+
+        if let Some(transform) = &clip.transform {
+            if *transform != Transform::default() {
+                self.out.push_step(
+                    &format!("transform_{}", clip.index),
+                    "SetClipTransform",
+                    json!({
+                        "sequenceId": step_reference(SEQUENCE_STEP),
+                        "trackId": step_reference(&track_step),
+                        "clipId": step_reference(&step_id),
+                        "transform": transform,
+                    }),
+                    &[&step_id],
+                );
+            }
+        }
+
+        for (marker_index, marker) in clip.markers.iter().enumerate() {
+            self.add_marker(clip, marker_index, marker);
+        }
+
+        self.placed.entry(key).or_default().push(PlacedClip {
+            step_id,
+            timeline_start_sec: timeline_start,
+            timeline_end_sec: timeline_end,
+        });
+        self.plan.placed_clips += 1;
+    }
+
+    fn add_marker(&mut self, clip: &FcpxmlClip, marker_index: usize, marker: &FcpxmlMarker) {
+        if marker.time_sec < 0.0 {
+            self.out.warnings.push(format!(
+                "marker '{}' falls before the start of the sequence and was not imported",
+                marker.label
+            ));
+            return;
+        }
+        self.out.push_step(
+            &format!("marker_{}_{marker_index}", clip.index),
+            "AddMarker",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "timeSec": self.clock.snap_seconds_to_frame(marker.time_sec),
+                "label": marker.label,
+                "markerType": marker.marker_type,
+            }),
+            &[SEQUENCE_STEP],
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Relinking
+    // -------------------------------------------------------------------------
+
+    /// Finds the project asset an FCPXML asset names, importing it if needed.
+    fn resolve_media(&mut self, asset_ref: &str, clip_name: &str) -> Option<ResolvedMedia> {
+        if let Some(resolved) = self.resolved.get(asset_ref) {
+            return resolved.clone();
+        }
+        let resolved = self.relink(asset_ref, clip_name);
+        self.resolved
+            .insert(asset_ref.to_string(), resolved.clone());
+        resolved
+    }
+
+    fn relink(&mut self, asset_ref: &str, clip_name: &str) -> Option<ResolvedMedia> {
+        let asset = self.fcpxml.assets.get(asset_ref)?;
+
+        let Some(src) = asset.src.as_deref() else {
+            if let Some(asset_id) = self.out.find_asset_by_name(&asset.name) {
+                return Some(ResolvedMedia::Existing(asset_id));
+            }
+            self.out.warnings.push(format!(
+                "asset '{}' has no media path and matches no project asset by name; its clips \
+                 were not imported",
+                asset.name
+            ));
+            return None;
+        };
+
+        let Some(path) = file_url_to_path(src) else {
+            self.out.warnings.push(format!(
+                "asset '{}' references '{src}', which is not a local file URL; its clips were not \
+                 imported",
+                asset.name
+            ));
+            return None;
+        };
+
+        self.out.relink(clip_name, &path)
+    }
+
+    // -------------------------------------------------------------------------
+    // Transitions
+    // -------------------------------------------------------------------------
+
+    /// Attaches a transition to the outgoing clip of the cut it covers.
+    ///
+    /// An FCPXML transition overlaps the end of one clip and the start of the
+    /// next; OpenReelio keeps one duration centred on the cut, stored on the
+    /// outgoing clip. The transition is attached to the lane's video track, or
+    /// to its audio track when the lane carries only sound.
+    fn add_transition(&mut self, index: usize, transition: &FcpxmlTransition) {
+        let span_start = transition.timeline_start_sec;
+        let span_end = span_start + transition.duration_sec;
+        let half_frame = 0.5 / self.clock.frames_per_second();
+
+        let keys = [
+            TrackKey {
+                audio: false,
+                lane: transition.lane,
+            },
+            TrackKey {
+                audio: true,
+                lane: transition.lane,
+            },
+        ];
+        let found = keys.iter().find_map(|key| {
+            let placed = self.placed.get(key)?;
+            let outgoing = placed.iter().rev().find(|clip| {
+                clip.timeline_end_sec >= span_start - half_frame
+                    && clip.timeline_end_sec <= span_end + half_frame
+            })?;
+            let incoming = placed.iter().find(|clip| {
+                (clip.timeline_start_sec - outgoing.timeline_end_sec).abs() < half_frame
+            })?;
+            Some((*key, outgoing.step_id.clone(), incoming.step_id.clone()))
+        });
+
+        let Some((key, outgoing, incoming)) = found else {
+            self.out.warnings.push(format!(
+                "transition '{}' at {:.3}s was not imported: no two imported clips meet inside it",
+                transition.name, span_start
+            ));
+            return;
+        };
+        let Some(track_step) = self.track_steps.get(&key).cloned() else {
+            return;
+        };
+
+        let effect_type = transition_effect_type(&transition.name);
+        if effect_type.is_none() {
+            self.out.unsupported.push(format!(
+                "transition '{}' has no OpenReelio equivalent and was imported as a cross dissolve",
+                transition.name
+            ));
+        }
+
+        self.out.push_step(
+            &format!("transition_{index}"),
+            "AddEffect",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "trackId": step_reference(&track_step),
+                "clipId": step_reference(&outgoing),
+                "effectType": effect_type.unwrap_or("cross_dissolve"),
+                "params": { "duration": transition.duration_sec },
+            }),
+            &[&outgoing, &incoming],
+        );
+    }
+}
+
+// =============================================================================
+// Internal: helpers
+// =============================================================================
+
+/// The OpenReelio transition an FCPXML transition's name describes, if any.
+pub(super) fn transition_effect_type(name: &str) -> Option<&'static str> {
+    let name = name.to_lowercase();
+    if name.contains("dissolve") || name.contains("fade") {
+        Some("cross_dissolve")
+    } else if name.contains("wipe") {
+        Some("wipe")
+    } else if name.contains("slide") || name.contains("push") {
+        Some("slide")
+    } else {
+        None
+    }
+}
+
+/// Parses an FCPXML time (`1001/30000s`, `10s`, `0s`) into seconds.
+pub(super) fn parse_time(text: &str) -> Option<f64> {
+    let body = text.trim().strip_suffix('s')?;
+    let seconds = match body.split_once('/') {
+        Some((num, den)) => {
+            let num = num.trim().parse::<f64>().ok()?;
+            let den = den.trim().parse::<f64>().ok()?;
+            if den == 0.0 {
+                return None;
+            }
+            num / den
+        }
+        None => body.trim().parse::<f64>().ok()?,
+    };
+    seconds.is_finite().then_some(seconds)
+}
+
+/// Parses an integer FCPXML time into its numerator and denominator, for a
+/// frame duration that must stay exact.
+fn parse_rational(text: &str) -> Option<(i64, i64)> {
+    let body = text.trim().strip_suffix('s')?;
+    match body.split_once('/') {
+        Some((num, den)) => Some((num.trim().parse().ok()?, den.trim().parse().ok()?)),
+        None => Some((body.trim().parse().ok()?, 1)),
+    }
+}
+
+/// Parses a space-separated pair of numbers (`"12.5 -3"`).
+fn parse_pair(text: &str) -> Option<(f64, f64)> {
+    let mut parts = text.split_whitespace().map(str::parse::<f64>);
+    let x = parts.next()?.ok()?;
+    let y = parts.next()?.ok()?;
+    (x.is_finite() && y.is_finite()).then_some((x, y))
+}
+
+fn gcd(mut a: i64, mut b: i64) -> i64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs().max(1)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::assets::AssetKind;
+    use crate::core::interchange::import_plan::test_support::{assets_with, steps_of};
+    use crate::core::interchange::xml::export_fcpxml;
+    use crate::core::timeline::{
+        Clip, ClipPlace, ClipRange, Sequence, SequenceFormat, Track, TrackKind,
+    };
+
+    fn plan_for(document: &str, assets: &HashMap<String, Asset>) -> FcpxmlImportPlan {
+        let parsed = parse_fcpxml(document).expect("fixture should parse");
+        fcpxml_to_plan_steps(
+            &parsed,
+            &FcpxmlImportContext {
+                sequence_name: "Imported",
+                sequence_fps: parsed.fps.clone().unwrap_or(Ratio::new(24, 1)),
+                assets,
+                project_root: Path::new("/media"),
+                allow_external_media: false,
+            },
+        )
+        .expect("plan should build")
+    }
+
+    /// A Final Cut-style document: 25fps, one asset starting at 10s (its
+    /// timecode origin), a clip connected above the spine, and a transition.
+    const FINAL_CUT_DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE fcpxml>
+<fcpxml version="1.10">
+  <resources>
+    <format id="r1" name="FFVideoFormat1080p25" frameDuration="1/25s" width="1920" height="1080"/>
+    <asset id="r2" name="Interview" start="10s" duration="120s" hasVideo="1" hasAudio="1" format="r1">
+      <media-rep kind="original-media" src="file:///media/Interview.mov"/>
+    </asset>
+    <asset id="r3" name="Broll" start="0s" duration="30s" hasVideo="1" format="r1" src="file:///media/Broll.mov"/>
+    <asset id="r4" name="Music" start="0s" duration="200s" hasAudio="1" src="file:///media/Music.wav"/>
+  </resources>
+  <library>
+    <event name="Day 1">
+      <project name="Rough Cut">
+        <sequence format="r1" duration="20s" tcStart="3600s" tcFormat="NDF">
+          <spine>
+            <asset-clip name="Interview" ref="r2" offset="3600s" start="15s" duration="8s">
+              <marker start="17s" duration="1/25s" value="Good line"/>
+              <chapter-marker start="15s" duration="1/25s" value="Intro"/>
+              <asset-clip name="Broll" ref="r3" lane="1" offset="18s" start="2s" duration="3s">
+                <adjust-transform position="10 -20" scale="0.5 0.5" rotation="90"/>
+              </asset-clip>
+              <asset-clip name="Music" ref="r4" lane="-1" offset="15s" start="0s" duration="8s"/>
+            </asset-clip>
+            <transition name="Cross Dissolve" offset="3607s" duration="2s"/>
+            <asset-clip name="Interview" ref="r2" offset="3608s" start="40s" duration="6s"/>
+          </spine>
+        </sequence>
+      </project>
+    </event>
+  </library>
+</fcpxml>"#;
+
+    #[test]
+    fn should_resolve_nested_offsets_to_sequence_time() {
+        let parsed = parse_fcpxml(FINAL_CUT_DOCUMENT).expect("valid FCPXML");
+
+        assert_eq!(parsed.fps, Some(Ratio::new(25, 1)));
+        assert_eq!(parsed.project_name.as_deref(), Some("Rough Cut"));
+        let by_name = |name: &str| {
+            parsed
+                .clips
+                .iter()
+                .find(|clip| clip.name == name)
+                .expect("clip present")
+        };
+
+        let interview = by_name("Interview");
+        assert_eq!(interview.timeline_start_sec, 0.0);
+        // start="15s" on media whose clock starts at 10s is 5s into the file.
+        assert!((interview.source_in_sec - 5.0).abs() < 1e-9);
+
+        let broll = by_name("Broll");
+        assert_eq!(broll.lane, 1);
+        // Connected at the parent's local 18s, which is 3s after its start.
+        assert!((broll.timeline_start_sec - 3.0).abs() < 1e-9);
+        assert!((broll.source_in_sec - 2.0).abs() < 1e-9);
+
+        let music = by_name("Music");
+        assert!(music.audio_only);
+        assert_eq!(music.lane, -1);
+    }
+
+    #[test]
+    fn should_read_markers_and_chapter_markers_in_sequence_time() {
+        let parsed = parse_fcpxml(FINAL_CUT_DOCUMENT).expect("valid FCPXML");
+        let markers = &parsed.clips[0].markers;
+
+        assert_eq!(markers.len(), 2);
+        assert!((markers[0].time_sec - 2.0).abs() < 1e-9);
+        assert_eq!(markers[0].marker_type, MarkerType::Generic);
+        assert_eq!(markers[1].marker_type, MarkerType::Chapter);
+        assert_eq!(markers[1].time_sec, 0.0);
+    }
+
+    #[test]
+    fn should_convert_adjust_transform_into_normalized_space() {
+        let parsed = parse_fcpxml(FINAL_CUT_DOCUMENT).expect("valid FCPXML");
+        let transform = parsed
+            .clips
+            .iter()
+            .find_map(|clip| clip.transform.clone())
+            .expect("a transform");
+
+        // 10% of the frame height to the right, 20% down.
+        assert!((transform.position.x - (0.5 + 0.1 * 1080.0 / 1920.0)).abs() < 1e-9);
+        assert!((transform.position.y - 0.7).abs() < 1e-9);
+        assert_eq!(transform.scale, Point2D::new(0.5, 0.5));
+        assert_eq!(transform.rotation_deg, -90.0);
+    }
+
+    #[test]
+    fn should_build_a_sequence_with_one_track_per_lane_and_kind() {
+        let assets = assets_with(&[
+            ("a1", "Interview.mov", "/media/Interview.mov", Some(60.0)),
+            ("a2", "Broll.mov", "/media/Broll.mov", Some(60.0)),
+            ("a3", "Music.wav", "/media/Music.wav", Some(60.0)),
+        ]);
+        let plan = plan_for(FINAL_CUT_DOCUMENT, &assets);
+
+        let sequence = &plan.steps[0];
+        assert_eq!(sequence["commandType"], "CreateSequence");
+        assert_eq!(sequence["payload"]["fps"]["num"], 25);
+        assert_eq!(sequence["payload"]["format"], "1920x1080");
+
+        let tracks: Vec<_> = steps_of(&plan.steps, "CreateTrack")
+            .iter()
+            .map(|step| {
+                format!(
+                    "{}:{}",
+                    step["payload"]["kind"].as_str().unwrap_or_default(),
+                    step["payload"]["name"].as_str().unwrap_or_default()
+                )
+            })
+            .collect();
+        assert_eq!(tracks, vec!["video:V2", "video:V1", "audio:A1"]);
+        assert_eq!(steps_of(&plan.steps, "InsertClip").len(), 4);
+        assert_eq!(plan.placed_clips, 4);
+        assert!(steps_of(&plan.steps, "ImportAsset").is_empty());
+        assert_eq!(steps_of(&plan.steps, "SetClipTransform").len(), 1);
+        assert_eq!(steps_of(&plan.steps, "AddMarker").len(), 2);
+    }
+
+    #[test]
+    fn should_attach_a_transition_to_the_outgoing_clip() {
+        let assets = assets_with(&[
+            ("a1", "Interview.mov", "/media/Interview.mov", Some(60.0)),
+            ("a2", "Broll.mov", "/media/Broll.mov", Some(60.0)),
+            ("a3", "Music.wav", "/media/Music.wav", Some(60.0)),
+        ]);
+        let plan = plan_for(FINAL_CUT_DOCUMENT, &assets);
+
+        let effects = steps_of(&plan.steps, "AddEffect");
+        assert_eq!(effects.len(), 1);
+        assert_eq!(effects[0]["payload"]["clipId"]["$fromStep"], "clip_0");
+        assert_eq!(effects[0]["payload"]["effectType"], "cross_dissolve");
+        assert_eq!(effects[0]["payload"]["params"]["duration"], 2.0);
+    }
+
+    #[test]
+    fn should_relink_by_file_name_and_import_unknown_media_inside_the_project() {
+        let assets = assets_with(&[(
+            "a1",
+            "Interview.mov",
+            "/elsewhere/Interview.mov",
+            Some(60.0),
+        )]);
+        let plan = plan_for(FINAL_CUT_DOCUMENT, &assets);
+
+        assert!(plan
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("by file name")));
+        let imports: Vec<_> = plan.asset_imports.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(imports, vec!["Music.wav", "Broll.mov"]);
+    }
+
+    #[test]
+    fn should_refuse_media_outside_the_project_directory() {
+        let document =
+            FINAL_CUT_DOCUMENT.replace("file:///media/Broll.mov", "file:///etc/Broll.mov");
+        let assets = assets_with(&[
+            ("a1", "Interview.mov", "/media/Interview.mov", Some(60.0)),
+            ("a3", "Music.wav", "/media/Music.wav", Some(60.0)),
+        ]);
+        let plan = plan_for(&document, &assets);
+
+        assert!(plan
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("outside the project directory")));
+        assert_eq!(plan.placed_clips, 3);
+    }
+
+    #[test]
+    fn should_read_a_final_cut_clip_container_through_its_video_child() {
+        let document = r#"<fcpxml version="1.11">
+  <resources>
+    <format id="r1" frameDuration="1001/30000s" width="1920" height="1080"/>
+    <asset id="r2" name="A" start="0s" duration="60s" hasVideo="1" src="file:///media/A.mov"/>
+  </resources>
+  <project name="P"><sequence format="r1" tcStart="0s"><spine>
+    <clip name="Wrapped" offset="0s" start="10s" duration="5s">
+      <video ref="r2" offset="10s" start="20s" duration="5s"/>
+    </clip>
+  </spine></sequence></project>
+</fcpxml>"#;
+        let parsed = parse_fcpxml(document).expect("valid FCPXML");
+
+        assert_eq!(parsed.fps, Some(Ratio::new(30000, 1001)));
+        assert_eq!(parsed.clips.len(), 1);
+        assert!((parsed.clips[0].source_in_sec - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_report_compound_clips_and_filters_as_unsupported() {
+        let document = r#"<fcpxml version="1.11">
+  <resources>
+    <format id="r1" frameDuration="1/24s"/>
+    <asset id="r2" name="A" duration="60s" hasVideo="1" src="file:///media/A.mov"/>
+    <media id="r3" name="Compound 1"/>
+  </resources>
+  <project name="P"><sequence format="r1"><spine>
+    <ref-clip name="Compound 1" ref="r3" offset="0s" duration="2s"/>
+    <asset-clip name="A" ref="r2" offset="2s" duration="2s">
+      <filter-video name="Gaussian Blur"/>
+      <timeMap/>
+    </asset-clip>
+  </spine></sequence></project>
+</fcpxml>"#;
+        let parsed = parse_fcpxml(document).expect("valid FCPXML");
+
+        assert_eq!(parsed.clips.len(), 1);
+        assert!(parsed
+            .unsupported
+            .iter()
+            .any(|note| note.contains("compound clip 'Compound 1'")));
+        assert!(parsed
+            .unsupported
+            .iter()
+            .any(|note| note.contains("Gaussian Blur") && note.contains("a retime")));
+    }
+
+    #[test]
+    fn should_refuse_a_document_that_is_not_fcpxml_or_has_no_project() {
+        assert!(parse_fcpxml("<xmeml version=\"4\"/>")
+            .expect_err("wrong root")
+            .contains("not an FCPXML document"));
+        assert!(
+            parse_fcpxml("<fcpxml version=\"1.11\"><resources/></fcpxml>")
+                .expect_err("no project")
+                .contains("no project")
+        );
+        assert!(parse_fcpxml("not xml <").is_err());
+    }
+
+    #[test]
+    fn should_round_trip_our_own_fcpxml_export() {
+        let mut assets = assets_with(&[
+            ("v1", "shot.mp4", "/media/shot.mp4", Some(60.0)),
+            ("a1", "music.wav", "/media/music.wav", Some(60.0)),
+        ]);
+        if let Some(music) = assets.get_mut("a1") {
+            music.kind = AssetKind::Audio;
+        }
+
+        let mut sequence = Sequence::new("Cut", SequenceFormat::youtube_1080());
+        let mut video = Track::new("Video 1", TrackKind::Video);
+        let mut audio = Track::new("Audio 1", TrackKind::Audio);
+        let clip = |id: &str, asset: &str, src_in: f64, tl_in: f64| {
+            let mut clip = Clip::new(asset);
+            clip.id = id.to_string();
+            clip.range = ClipRange::new(src_in, src_in + 4.0);
+            clip.place = ClipPlace::new(tl_in, 4.0);
+            clip
+        };
+        video.clips.push(clip("c1", "v1", 2.0, 0.0));
+        video.clips.push(clip("c2", "v1", 10.0, 4.0));
+        audio.clips.push(clip("c3", "a1", 0.0, 0.0));
+        sequence.tracks.push(video);
+        sequence.tracks.push(audio);
+
+        let (document, _, _) = export_fcpxml(&sequence, &assets).expect("export");
+        let plan = plan_for(&document, &assets);
+
+        let inserts = steps_of(&plan.steps, "InsertClip");
+        assert_eq!(inserts.len(), 3);
+        let placements: Vec<(f64, f64)> = inserts
+            .iter()
+            .map(|step| {
+                (
+                    step["payload"]["timelineStart"].as_f64().unwrap_or(-1.0),
+                    step["payload"]["sourceIn"].as_f64().unwrap_or(-1.0),
+                )
+            })
+            .collect();
+        assert!(placements.contains(&(0.0, 2.0)));
+        assert!(placements.contains(&(4.0, 10.0)));
+        assert!(placements.contains(&(0.0, 0.0)));
+        assert!(steps_of(&plan.steps, "ImportAsset").is_empty());
+        assert!(plan.warnings.is_empty(), "{:?}", plan.warnings);
+    }
+}
@@ -0,0 +1,506 @@
+//! Import Plan Building
+//!
+//! What the interchange importers — OTIO, EDL, FCPXML and XMEML — share once
+//! a document is parsed: the step list an import writes, the `CreateSequence`
+//! step, and relinking the document's media to project assets.
+//!
+//! Relinking lives here so it behaves, and reports, the same whichever format
+//! named the media: a path is matched against the project's assets, then its
+//! file name is (with a warning, since a name can collide), and only then is
+//! the media imported — through the project-directory scoping
+//! [`scoped_media_path`] enforces.
+
+use std::collections::HashMap;
+use std::path::{Component, Path, PathBuf};
+
+use serde_json::{json, Value as JsonValue};
+
+use crate::core::assets::Asset;
+// `file_url_to_path` turns `file://host/share/x` into `//host/share/x`, and a
+// hand-written document may carry `\\host\share\x`, `/\host\share\x` or the
+// percent-encoded `%5C%5Chost`; the shared check recognises all of them.
+use crate::core::fs::is_network_path;
+use crate::core::Ratio;
+
+use super::models::strip_verbatim_prefix;
+use super::otio_import::OtioAssetImport;
+
+/// Id of the step that creates the sequence every other step builds into.
+pub(super) const SEQUENCE_STEP: &str = "sequence";
+
+/// Frame sizes `CreateSequence` has a preset for.
+const PRESET_CANVASES: &[(u32, u32)] = &[(1920, 1080), (3840, 2160), (1080, 1920)];
+
+/// Frame size assumed when a document does not state one, or states one no
+/// preset matches.
+pub(super) const DEFAULT_CANVAS: (u32, u32) = (1920, 1080);
+
+/// Where a clip's media came from.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum ResolvedMedia {
+    /// An asset already in the project.
+    Existing(String),
+    /// An `ImportAsset` step this plan emits; the id is only known at run time.
+    Imported(String),
+}
+
+impl ResolvedMedia {
+    /// The `assetId` a step payload carries, registering the import step it
+    /// waits on in `depends_on`.
+    pub(super) fn asset_value(&self, depends_on: &mut Vec<String>) -> JsonValue {
+        match self {
+            ResolvedMedia::Existing(asset_id) => json!(asset_id),
+            ResolvedMedia::Imported(import_step) => {
+                depends_on.push(import_step.clone());
+                step_reference(import_step)
+            }
+        }
+    }
+}
+
+/// The steps and notes an import accumulates, and the project it relinks
+/// media against.
+///
+/// Each format's plan carries these four lists under the same names; the
+/// format's builder moves them across when it finishes.
+pub(super) struct ImportPlanWriter<'a> {
+    /// `EditPlan.steps`-shaped JSON, in the order it was pushed.
+    pub steps: Vec<JsonValue>,
+    pub warnings: Vec<String>,
+    pub unsupported: Vec<String>,
+    pub asset_imports: Vec<OtioAssetImport>,
+    /// Names the document kind in refusals ("OTIO", "EDL").
+    format: &'static str,
+    assets: &'a HashMap<String, Asset>,
+    project_root: &'a Path,
+    allow_external_media: bool,
+    /// Import steps already emitted, keyed by the media path, so two clips off
+    /// the same file import it once.
+    imported: HashMap<String, String>,
+}
+
+impl<'a> ImportPlanWriter<'a> {
+    pub(super) fn new(
+        format: &'static str,
+        assets: &'a HashMap<String, Asset>,
+        project_root: &'a Path,
+        allow_external_media: bool,
+    ) -> Self {
+        Self {
+            steps: Vec::new(),
+            warnings: Vec::new(),
+            unsupported: Vec::new(),
+            asset_imports: Vec::new(),
+            format,
+            assets,
+            project_root,
+            allow_external_media,
+            imported: HashMap::new(),
+        }
+    }
+
+    pub(super) fn push_step(
+        &mut self,
+        id: &str,
+        command_type: &str,
+        payload: JsonValue,
+        depends_on: &[&str],
+    ) {
+        self.steps.push(json!({
+            "id": id,
+            "commandType": command_type,
+            "payload": payload,
+            "dependsOn": depends_on,
+        }));
+    }
+
+    // -------------------------------------------------------------------------
+    // Sequence
+    // -------------------------------------------------------------------------
+
+    /// The preset frame size a document's frame size is created at, reporting
+    /// one no preset matches.
+    pub(super) fn preset_canvas(&mut self, canvas: Option<(u32, u32)>) -> (u32, u32) {
+        let canvas = canvas.unwrap_or(DEFAULT_CANVAS);
+        if PRESET_CANVASES.contains(&canvas) {
+            return canvas;
+        }
+        self.unsupported.push(format!(
+            "the document's {}x{} frame size has no OpenReelio sequence preset; the sequence was \
+             created at {}x{}",
+            canvas.0, canvas.1, DEFAULT_CANVAS.0, DEFAULT_CANVAS.1
+        ));
+        DEFAULT_CANVAS
+    }
+
+    /// Emits the step that creates the sequence, without default tracks: the
+    /// plan creates the tracks the document has. With no `canvas` the
+    /// sequence takes `CreateSequence`'s default format.
+    pub(super) fn create_sequence(&mut self, name: &str, fps: &Ratio, canvas: Option<(u32, u32)>) {
+        let mut payload = json!({
+            "name": name,
+            "fps": { "num": fps.num, "den": fps.den },
+            "addDefaultTracks": false,
+        });
+        if let Some((width, height)) = canvas {
+            payload["format"] = json!(format!("{width}x{height}"));
+        }
+        self.push_step(SEQUENCE_STEP, "CreateSequence", payload, &[]);
+    }
+
+    // -------------------------------------------------------------------------
+    // Relinking
+    // -------------------------------------------------------------------------
+
+    /// Finds the project asset the media at `path` is, importing it if the
+    /// project has none. `None`, with the reason recorded, when it may not be
+    /// imported.
+    ///
+    /// Matching an asset the project already holds reads nothing off the
+    /// foreign path, so both matches run before the scoping guard: the guard
+    /// exists to keep `ImportAsset` away from a path the document chose, not to
+    /// stop a clip from finding media the user imported themselves.
+    pub(super) fn relink(&mut self, clip_name: &str, path: &str) -> Option<ResolvedMedia> {
+        if let Some(asset_id) = self.find_asset_by_path(path) {
+            return Some(ResolvedMedia::Existing(asset_id));
+        }
+
+        if let Some(asset_id) = self.find_asset_by_name(&base_name(path)) {
+            self.warnings.push(format!(
+                "'{path}' was matched to project asset '{asset_id}' by file name because it is \
+                 not the path that asset was imported from; check it is the intended media"
+            ));
+            return Some(ResolvedMedia::Existing(asset_id));
+        }
+
+        match self.import_media(clip_name, path) {
+            Ok(resolved) => Some(resolved),
+            Err(warning) => {
+                self.warnings.push(warning);
+                None
+            }
+        }
+    }
+
+    /// Emits the `ImportAsset` step for media the project does not have, once
+    /// per file, or says why the path may not be imported.
+    pub(super) fn import_media(
+        &mut self,
+        clip_name: &str,
+        path: &str,
+    ) -> Result<ResolvedMedia, String> {
+        let uri = scoped_media_path(
+            self.project_root,
+            self.allow_external_media,
+            self.format,
+            clip_name,
+            path,
+        )?;
+
+        if let Some(existing_step) = self.imported.get(&uri) {
+            return Ok(ResolvedMedia::Imported(existing_step.clone()));
+        }
+
+        let name = base_name(&uri);
+        let step_id = format!("import_{}", self.imported.len());
+        self.push_step(
+            &step_id,
+            "ImportAsset",
+            json!({ "name": name, "uri": uri }),
+            &[],
+        );
+        self.imported.insert(uri.clone(), step_id.clone());
+        self.asset_imports.push(OtioAssetImport { name, uri });
+
+        Ok(ResolvedMedia::Imported(step_id))
+    }
+
+    pub(super) fn find_asset_by_path(&self, path: &str) -> Option<String> {
+        let wanted = normalize_path(path);
+        self.sorted_assets()
+            .into_iter()
+            .find(|asset| normalize_path(&asset.uri) == wanted)
+            .map(|asset| asset.id.clone())
+    }
+
+    /// Matches a name against asset names, case-insensitively.
+    pub(super) fn find_asset_by_name(&self, name: &str) -> Option<String> {
+        let wanted = name.trim().to_lowercase();
+        self.sorted_assets()
+            .into_iter()
+            .find(|asset| asset.name.to_lowercase() == wanted)
+            .map(|asset| asset.id.clone())
+    }
+
+    /// The project's assets in id order, so a name two assets share resolves
+    /// the same way on every run.
+    pub(super) fn sorted_assets(&self) -> Vec<&'a Asset> {
+        let mut assets: Vec<&Asset> = self.assets.values().collect();
+        assets.sort_by(|a, b| a.id.cmp(&b.id));
+        assets
+    }
+}
+
+// =============================================================================
+// Helpers
+// =============================================================================
+
+/// Turns a media path a foreign file chose into one an import may actually
+/// read, or the reason it may not.
+///
+/// This is the whole of an import's trust boundary, and it is deliberately
+/// one decision rather than a list of banned spellings. A path that does not
+/// match an asset the project already has is about to be handed to
+/// `ImportAsset`, which stats it and may ffprobe it, so the question is not
+/// "is this a UNC path" — `\\host\share`, `/\host\share`, `//host/share` and
+/// `%5C%5Chost` are all the same request — but "is this inside the project".
+/// Answering that refuses the SMB reflection and the filesystem existence
+/// oracle together, and keeps refusing spellings nobody has thought of yet.
+/// `format` names the file kind in the refusal ("OTIO", "EDL").
+pub(super) fn scoped_media_path(
+    project_root: &Path,
+    allow_external_media: bool,
+    format: &str,
+    clip_name: &str,
+    path: &str,
+) -> Result<String, String> {
+    // Kept as its own check so a network path is named as one in the report
+    // even when external media is allowed: an outbound SMB connection is a
+    // different hazard from reading a local file the operator asked for.
+    if is_network_path(path) {
+        return Err(format!(
+            "clip '{clip_name}' references the network path '{path}', which OpenReelio will \
+             not import from an {format} file; relink it to local media instead"
+        ));
+    }
+
+    if path.contains("://") {
+        return Err(format!(
+            "clip '{clip_name}' references '{path}', which is not a local file, and was not \
+             imported"
+        ));
+    }
+
+    let candidate = if is_absolute_media_path(path) {
+        PathBuf::from(path)
+    } else {
+        // A relative reference is ours: an asset stored inside the project
+        // keeps a project-relative URI. It resolves against the project root,
+        // which also makes it in-scope by construction — once `..` is out.
+        project_root.join(path)
+    };
+
+    if candidate
+        .components()
+        .any(|component| matches!(component, Component::ParentDir))
+    {
+        return Err(format!(
+            "clip '{clip_name}' references '{path}', which walks out of the directory it \
+             starts in, and was not imported"
+        ));
+    }
+
+    if !allow_external_media && !is_inside_project(project_root, &candidate) {
+        return Err(format!(
+            "clip '{clip_name}' references '{path}', which is outside the project directory, \
+             and was not imported; an {format} file chooses its own media paths, so import only \
+             reads media from inside the project unless external media is explicitly allowed \
+             (`--allow-external-media`)"
+        ));
+    }
+
+    // The project root arrives canonicalised, which on Windows means
+    // `\\?\C:\…`. Left in, joining a relative reference onto it and swapping
+    // separators yields `//?/C:/…` — a path that reads as a network
+    // authority, that ImportAsset cannot open, and that no longer matches
+    // the same file named absolutely.
+    Ok(strip_verbatim_prefix(&candidate.to_string_lossy()).replace('\\', "/"))
+}
+
+/// Builds a `$fromStep` reference to the first id a step creates.
+pub(super) fn step_reference(step_id: &str) -> JsonValue {
+    json!({ "$fromStep": step_id, "$path": "createdIds.0" })
+}
+
+/// Normalises a path for comparison: no Windows verbatim prefix, forward
+/// slashes, case-folded.
+///
+/// The prefix matters here as much as in the URL: an imported asset's stored
+/// URI reads `\\?\C:\…` while the path decoded out of a file URL reads
+/// `C:\…`, and without stripping it the two never compare equal, so every clip
+/// would re-import media the project already has.
+///
+/// Case folding is not correct on a case-sensitive filesystem, but a false
+/// match here reuses an asset the user already has rather than importing a
+/// duplicate, and the alternative — two assets for one file — is the worse
+/// failure.
+pub(super) fn normalize_path(path: &str) -> String {
+    strip_verbatim_prefix(path)
+        .replace('\\', "/")
+        .to_lowercase()
+}
+
+pub(super) fn base_name(path: &str) -> String {
+    path.replace('\\', "/")
+        .rsplit('/')
+        .next()
+        .filter(|name| !name.is_empty())
+        .unwrap_or(path)
+        .to_string()
+}
+
+/// Whether a decoded media path names an absolute location.
+///
+/// Deliberately answered from the string rather than `Path::is_absolute`: a
+/// document written on Windows is routinely read on Linux and the reverse, and
+/// `Path::is_absolute` answers for the *host*, so `C:/Windows/win.ini` reads as a
+/// relative path on Linux and would be joined onto the project root — landing
+/// inside the scope it was supposed to be measured against.
+fn is_absolute_media_path(path: &str) -> bool {
+    let bytes = path.as_bytes();
+    matches!(bytes.first(), Some(b'/') | Some(b'\\'))
+        || matches!(bytes, [drive, b':', ..] if drive.is_ascii_alphabetic())
+}
+
+/// Whether a media path resolves inside the project directory.
+///
+/// A `..` component is refused outright rather than resolved: the containment
+/// test below falls back to a textual comparison for a path that does not exist,
+/// and `<project>/../../etc/passwd` passes a textual comparison.
+fn is_inside_project(project_root: &Path, candidate: &Path) -> bool {
+    if candidate
+        .components()
+        .any(|component| matches!(component, Component::ParentDir))
+    {
+        return false;
+    }
+    crate::core::workspace::path_resolver::is_inside_project(project_root, candidate)
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+/// Fixtures the importers' tests share.
+#[cfg(test)]
+pub(super) mod test_support {
+    use super::*;
+    use crate::core::assets::{AssetKind, LicenseInfo, ProxyStatus};
+
+    pub fn make_asset(id: &str, name: &str, uri: &str, duration: Option<f64>) -> Asset {
+        Asset {
+            id: id.to_string(),
+            kind: AssetKind::Video,
+            name: name.to_string(),
+            uri: uri.to_string(),
+            hash: "abc123".to_string(),
+            duration_sec: duration,
+            file_size: 1024,
+            imported_at: "2026-01-01T00:00:00Z".to_string(),
+            video: None,
+            audio: None,
+            license: LicenseInfo::default(),
+            tags: vec![],
+            thumbnail_url: None,
+            proxy_status: ProxyStatus::NotNeeded,
+            proxy_url: None,
+            bin_id: None,
+            relative_path: None,
+            workspace_managed: false,
+            missing: false,
+            quarantined_uri: None,
+            source: None,
+            image_sequence: None,
+        }
+    }
+
+    pub fn assets_with(entries: &[(&str, &str, &str, Option<f64>)]) -> HashMap<String, Asset> {
+        entries
+            .iter()
+            .map(|(id, name, uri, duration)| (id.to_string(), make_asset(id, name, uri, *duration)))
+            .collect()
+    }
+
+    pub fn steps_of<'p>(steps: &'p [JsonValue], command_type: &str) -> Vec<&'p JsonValue> {
+        steps
+            .iter()
+            .filter(|step| step["commandType"] == command_type)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::test_support::assets_with;
+    use super::*;
+
+    fn writer(assets: &HashMap<String, Asset>) -> ImportPlanWriter<'_> {
+        ImportPlanWriter::new("OTIO", assets, Path::new("/media"), false)
+    }
+
+    #[test]
+    fn should_prefer_a_path_match_over_a_file_name_match() {
+        let assets = assets_with(&[
+            ("a1", "clip.mov", "/archive/clip.mov", None),
+            ("a2", "other.mov", "/media/day1/clip.mov", None),
+        ]);
+        let mut writer = writer(&assets);
+
+        let resolved = writer.relink("Clip", "/media/day1/clip.mov");
+
+        assert_eq!(resolved, Some(ResolvedMedia::Existing("a2".to_string())));
+        assert!(writer.warnings.is_empty());
+    }
+
+    #[test]
+    fn should_match_by_file_name_in_id_order_and_say_so() {
+        let assets = assets_with(&[
+            ("b", "Clip.mov", "/archive/b/clip.mov", None),
+            ("a", "clip.mov", "/archive/a/clip.mov", None),
+        ]);
+        let mut writer = writer(&assets);
+
+        let resolved = writer.relink("Clip", "/media/clip.mov");
+
+        assert_eq!(resolved, Some(ResolvedMedia::Existing("a".to_string())));
+        assert_eq!(writer.warnings.len(), 1);
+        assert!(writer.warnings[0].contains("by file name"));
+    }
+
+    #[test]
+    fn should_import_unmatched_media_once() {
+        let assets = HashMap::new();
+        let mut writer = writer(&assets);
+
+        let first = writer.relink("A", "/media/new.mov");
+        let second = writer.relink("B", "/media/new.mov");
+
+        assert_eq!(first, Some(ResolvedMedia::Imported("import_0".to_string())));
+        assert_eq!(second, first);
+        assert_eq!(writer.steps.len(), 1);
+        assert_eq!(writer.steps[0]["payload"]["name"], "new.mov");
+        assert_eq!(writer.asset_imports.len(), 1);
+    }
+
+    #[test]
+    fn should_record_why_media_outside_the_project_was_not_imported() {
+        let assets = HashMap::new();
+        let mut writer = writer(&assets);
+
+        assert_eq!(writer.relink("A", "/elsewhere/new.mov"), None);
+        assert!(writer.steps.is_empty());
+        assert!(writer.warnings[0].contains("outside the project directory"));
+    }
+
+    #[test]
+    fn should_fall_back_to_the_default_canvas_and_report_it() {
+        let assets = HashMap::new();
+        let mut writer = writer(&assets);
+
+        assert_eq!(writer.preset_canvas(Some((3840, 2160))), (3840, 2160));
+        assert_eq!(writer.preset_canvas(None), DEFAULT_CANVAS);
+        assert!(writer.unsupported.is_empty());
+        assert_eq!(writer.preset_canvas(Some((1280, 720))), DEFAULT_CANVAS);
+        assert_eq!(writer.unsupported.len(), 1);
+    }
+}
@@ -1,13 +1,20 @@
 //! Interchange Module
 //!
 //! Handles export/import of timeline data to/from standard NLE interchange formats:
-//! - **EDL**: CMX 3600 Edit Decision List
-//! - **FCPXML**: Final Cut Pro XML (v1.11)
+//! - **EDL**: CMX 3600 Edit Decision List (export and import)
+//! - **FCPXML**: Final Cut Pro XML (v1.11 export, import)
 //! - **OTIO**: OpenTimelineIO cut interchange (export and import)
+//! - **XMEML**: Final Cut Pro 7 XML, as Premiere and Resolve exchange it (export and import)
 
 pub mod edl;
+pub mod edl_import;
+pub mod fcpxml_import;
+mod import_plan;
 pub mod models;
 pub mod otio;
 pub mod otio_import;
 pub mod otio_schema;
+pub mod xmeml;
+pub mod xmeml_import;
 pub mod xml;
+mod xml_tree;
@@ -24,6 +24,8 @@ pub enum InterchangeFormat {
     Fcpxml,
     /// OpenTimelineIO cut interchange
     Otio,
+    /// Final Cut Pro 7 XML (`xmeml`), as Premiere Pro and DaVinci Resolve read it
+    Xmeml,
 }
 
 impl fmt::Display for InterchangeFormat {
@@ -32,6 +34,7 @@ impl fmt::Display for InterchangeFormat {
             InterchangeFormat::Edl => write!(f, "EDL"),
             InterchangeFormat::Fcpxml => write!(f, "FCPXML"),
             InterchangeFormat::Otio => write!(f, "OTIO"),
+            InterchangeFormat::Xmeml => write!(f, "XMEML"),
         }
     }
 }
@@ -152,6 +155,39 @@ impl Timecode {
         }
     }
 
+    /// Parses an `HH:MM:SS:FF` timecode.
+    ///
+    /// A `;` (or `,`, which some tools write) before the frame field marks the
+    /// timecode as drop-frame; `:` and `.` mark it as non-drop. Returns `None`
+    /// for anything that is not four numeric fields with minutes and seconds
+    /// under 60.
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim();
+        let separator_index = text.rfind([':', ';', '.', ','])?;
+        let drop_frame = matches!(text.as_bytes()[separator_index], b';' | b',');
+
+        let mut fields = text[..separator_index].split(':');
+        let hours = fields.next()?.parse::<u32>().ok()?;
+        let minutes = fields.next()?.parse::<u32>().ok()?;
+        let seconds = fields.next()?.parse::<u32>().ok()?;
+        if fields.next().is_some() {
+            return None;
+        }
+        let frames = text[separator_index + 1..].parse::<u32>().ok()?;
+
+        if minutes >= 60 || seconds >= 60 {
+            return None;
+        }
+
+        Some(Self {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            drop_frame,
+        })
+    }
+
     /// Converts timecode to total seconds at the given frame rate
     pub fn to_seconds(&self, fps: &Ratio) -> f64 {
         let fps_float = fps.as_f64();
@@ -402,6 +438,10 @@ pub fn asset_src_url(uri: &str) -> String {
 /// the encoded spelling as the POSIX path `/C:/Media/a.mp4`, which matches no
 /// Windows asset and imports a second copy of media the project already has.
 ///
+/// `file://localhost/x` is `file:///x` (RFC 8089): Final Cut Pro 7 and Premiere
+/// write every `pathurl` that way, and reading the host as a UNC server would
+/// send every relink looking for `//localhost/…`.
+///
 /// A relative reference (`media/a.mp4`) is returned as a relative path: an asset
 /// stored inside the project keeps a project-relative URI, and the caller
 /// resolves it against the project root. Callers must not treat the result as
@@ -409,6 +449,10 @@ pub fn asset_src_url(uri: &str) -> String {
 /// [`super::otio_import`].
 pub fn file_url_to_path(url: &str) -> Option<String> {
     if let Some(rest) = url.strip_prefix("file://") {
+        let rest = match rest.get(..10) {
+            Some(host) if host.eq_ignore_ascii_case("localhost/") => &rest[9..],
+            _ => rest,
+        };
         let decoded = percent_decode(rest);
         return match decoded.as_bytes() {
             // `file:///C:/x` — a Windows drive letter follows the slash.
@@ -570,6 +614,43 @@ mod tests {
         assert!((original_seconds - recovered).abs() < 0.05);
     }
 
+    #[test]
+    fn should_parse_non_drop_and_drop_frame_timecodes() {
+        let ndf = Timecode::parse("01:02:03:04").expect("a valid timecode");
+        assert_eq!(
+            (ndf.hours, ndf.minutes, ndf.seconds, ndf.frames),
+            (1, 2, 3, 4)
+        );
+        assert!(!ndf.drop_frame);
+
+        let df = Timecode::parse("00:01:00;02").expect("a valid drop-frame timecode");
+        assert!(df.drop_frame);
+        assert_eq!(df.to_string(), "00:01:00;02");
+    }
+
+    #[test]
+    fn should_refuse_a_malformed_timecode() {
+        for text in [
+            "",
+            "01:02:03",
+            "01:02:03:04:05",
+            "01:60:00:00",
+            "aa:bb:cc:dd",
+        ] {
+            assert!(Timecode::parse(text).is_none(), "{text} should not parse");
+        }
+    }
+
+    #[test]
+    fn should_roundtrip_a_parsed_drop_frame_timecode_through_seconds() {
+        // 00:10:00;00 at 29.97 is exactly 17982 frames.
+        let fps = Ratio::new(30000, 1001);
+        let tc = Timecode::parse("00:10:00;00").expect("a valid timecode");
+        let seconds = tc.to_seconds(&fps);
+        assert!((seconds - 17982.0 * 1001.0 / 30000.0).abs() < 1e-9);
+        assert_eq!(Timecode::from_seconds(seconds, &fps), tc);
+    }
+
     // =========================================================================
     // Reel Name Tests
     // =========================================================================
@@ -668,6 +749,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_read_a_localhost_file_url_as_a_local_path() {
+        assert_eq!(
+            file_url_to_path("file://localhost/Volumes/Media/clip%201.mov").as_deref(),
+            Some("/Volumes/Media/clip 1.mov")
+        );
+        assert_eq!(
+            file_url_to_path("file://localhost/C%3a/Media/clip.mov").as_deref(),
+            Some("C:/Media/clip.mov")
+        );
+    }
+
     #[test]
     fn should_not_decode_a_non_file_url() {
         assert_eq!(file_url_to_path("https://example.com/clip.mp4"), None);
@@ -208,19 +208,19 @@ pub fn build_export_result(
 /// are aggregated by category, because a timeline where every clip is graded
 /// would otherwise bury the structural warnings under a thousand lines.
 #[derive(Default)]
-struct LossReport {
-    warnings: Vec<String>,
-    unsupported: Vec<String>,
-    aggregates: Aggregates,
+pub(super) struct LossReport {
+    pub warnings: Vec<String>,
+    pub unsupported: Vec<String>,
+    pub aggregates: Aggregates,
 }
 
 #[derive(Default)]
-struct Aggregates {
+pub(super) struct Aggregates {
     entries: Vec<(&'static str, &'static str, Vec<String>)>,
 }
 
 impl Aggregates {
-    fn record(&mut self, category: &'static str, description: &'static str, item: &str) {
+    pub fn record(&mut self, category: &'static str, description: &'static str, item: &str) {
         if let Some(existing) = self
             .entries
             .iter_mut()
@@ -233,7 +233,7 @@ impl Aggregates {
             .push((category, description, vec![item.to_string()]));
     }
 
-    fn into_entries(self) -> Vec<String> {
+    pub fn into_entries(self) -> Vec<String> {
         self.entries
             .into_iter()
             .map(|(_, description, items)| {
@@ -444,7 +444,7 @@ fn build_track(
 }
 
 /// Clips in timeline order, tie-broken by id so the output is deterministic.
-fn sorted_clips(track: &Track) -> Vec<&Clip> {
+pub(super) fn sorted_clips(track: &Track) -> Vec<&Clip> {
     let mut clips: Vec<&Clip> = track.clips.iter().collect();
     clips.sort_by(|a, b| {
         a.place
@@ -571,6 +571,10 @@ fn build_clip(
 ) -> OtioClip {
     let source_start = clock.seconds_to_nearest_frame(clip.range.source_in_sec);
     let asset = assets.get(&clip.asset_id);
+    // OTIO ranges are in the media's own time, so a file recorded from
+    // 01:00:00:00 offers media from that timecode on. The offset rides on the
+    // available range, so it is only applied when that range can be written.
+    let mut media_start: Frame = 0;
 
     let media_reference = match asset {
         Some(asset) => {
@@ -578,7 +582,12 @@ fn build_clip(
                 .duration_sec
                 .filter(|duration| duration.is_finite() && *duration > 0.0)
                 .map(|duration| {
-                    TimeRange::from_frames(0, clock.seconds_to_nearest_frame(duration), rate)
+                    media_start = clock.seconds_to_nearest_frame(asset.source_start_sec());
+                    TimeRange::from_frames(
+                        media_start,
+                        clock.seconds_to_nearest_frame(duration),
+                        rate,
+                    )
                 });
 
             if let Some(available) = available_range.as_ref() {
@@ -625,6 +634,11 @@ fn build_clip(
             map.insert("timeRemap".to_string(), json!(true));
         }
     }
+    let mut metadata = json!({ OPENREELIO_METADATA_KEY: openreelio });
+    if let (Some(reel), Some(map)) = (asset.and_then(Asset::reel_name), metadata.as_object_mut()) {
+        // The key the OTIO CMX 3600 adapter reads a reel name from.
+        map.insert("cmx_3600".to_string(), json!({ "reel": reel }));
+    }
 
     let name = clip
         .label
@@ -635,10 +649,10 @@ fn build_clip(
     OtioClip {
         otio_schema: schema_clip(),
         name,
-        source_range: TimeRange::from_frames(source_start, duration_frames, rate),
+        source_range: TimeRange::from_frames(media_start + source_start, duration_frames, rate),
         media_reference,
         markers: Vec::new(),
-        metadata: json!({ OPENREELIO_METADATA_KEY: openreelio }),
+        metadata,
     }
 }
 
@@ -824,7 +838,7 @@ fn parse_value(json: &str) -> serde_json::Value {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::assets::{AssetKind, LicenseInfo, ProxyStatus};
+    use crate::core::assets::{AssetKind, LicenseInfo, ProxyStatus, SourceMetadata};
     use crate::core::effects::{EffectType, ParamValue};
     use crate::core::timeline::{
         AudioSettings, BlendMode, ClipPlace, ClipRange, SequenceFormat, SlowMotionInterpolation,
@@ -852,6 +866,8 @@ mod tests {
             workspace_managed: false,
             missing: false,
             quarantined_uri: None,
+            source: None,
+            image_sequence: None,
         }
     }
 
@@ -879,6 +895,7 @@ mod tests {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
             group_id: None,
         }
@@ -1050,6 +1067,45 @@ mod tests {
         assert!(reference.get("available_range").is_none());
     }
 
+    #[test]
+    fn should_place_ranges_on_the_recorded_source_timecode_and_read_them_back() {
+        // Given: a clip 2s into a file recorded from 01:00:00:00 on reel A001
+        let mut sequence = make_sequence("Source TC");
+        let mut track = Track::new_video("V1");
+        track.add_clip(make_clip("c1", "a1", 2.0, 0.0, 3.0));
+        sequence.add_track(track);
+        let mut assets = assets_with(&[("a1", "a.mov", "/media/a.mov", Some(10.0))]);
+        let asset = assets.remove("a1").unwrap().with_source(SourceMetadata {
+            start_timecode: Some("01:00:00:00".to_string()),
+            start_sec: Some(3600.0),
+            reel_name: Some("A001".to_string()),
+            ..SourceMetadata::default()
+        });
+        assets.insert("a1".to_string(), asset);
+
+        // When: exported
+        let export = export_otio(&sequence, &assets, &HashMap::new()).expect("export should work");
+        let clip = children_of(&parse_value(&export.json), 0)[0].clone();
+
+        // Then: both ranges are on the file's clock and the reel is named
+        assert_eq!(
+            clip["media_reference"]["available_range"]["start_time"]["value"],
+            86_400.0
+        );
+        assert_eq!(clip["source_range"]["start_time"]["value"], 86_448.0);
+        assert_eq!(clip["metadata"]["cmx_3600"]["reel"], "A001");
+
+        // And: importing it back lands on the same source in-point
+        let parsed = parse_otio(&export.json).expect("our own output must parse");
+        let plan = import_plan(&parsed, "target-seq", &assets);
+        let insert = plan
+            .steps
+            .iter()
+            .find(|step| step["commandType"] == "InsertClip")
+            .expect("the clip should import");
+        assert_eq!(insert["payload"]["sourceIn"], 2.0);
+    }
+
     #[test]
     fn should_export_a_missing_asset_as_a_missing_reference_and_warn() {
         let mut sequence = make_sequence("Offline");
@@ -40,7 +40,7 @@
 //! would otherwise get over the whole filesystem.
 
 use std::collections::HashMap;
-use std::path::{Component, Path, PathBuf};
+use std::path::Path;
 
 use serde_json::{json, Value as JsonValue};
 
@@ -49,16 +49,13 @@ use crate::core::assets::Asset;
 use crate::core::timeline::TimelineClock;
 use crate::core::Ratio;
 
-use super::models::{file_url_to_path, strip_verbatim_prefix};
-// `file_url_to_path` turns `file://host/share/x` into `//host/share/x`, and a
-// hand-written `.otio` may carry `\\host\share\x`, `/\host\share\x` or the
-// percent-encoded `%5C%5Chost`; the shared check recognises all of them.
+use super::import_plan::{step_reference, ImportPlanWriter, ResolvedMedia};
+use super::models::file_url_to_path;
 use super::otio_schema::{
-    openreelio_meta_bool, openreelio_meta_f64, openreelio_meta_str, OtioClip, OtioComposable,
-    OtioMarker, OtioMediaRef, OtioTimeline, OtioTrack, OtioTrackOrItem, OtioTransition,
-    RationalTime,
+    openreelio_meta_bool, openreelio_meta_f64, openreelio_meta_str, ExternalReference, OtioClip,
+    OtioComposable, OtioMarker, OtioMediaRef, OtioTimeline, OtioTrack, OtioTrackOrItem,
+    OtioTransition, RationalTime,
 };
-use crate::core::fs::is_network_path;
 
 /// Extra handle, in frames, a transition is required to have beyond its own
 /// length. Mirrors the render engine's slack so a plan this importer accepts
@@ -152,7 +149,7 @@ pub fn otio_to_plan_steps(
     for (index, child) in timeline.tracks.children.iter().enumerate() {
         match child {
             OtioTrackOrItem::Track(track) => builder.add_track(index, track)?,
-            OtioTrackOrItem::Stack(stack) => builder.plan.unsupported.push(format!(
+            OtioTrackOrItem::Stack(stack) => builder.out.unsupported.push(format!(
                 "the nested stack '{}' was not imported: OpenReelio imports a flat cut, not a \
                  nested composition",
                 stack.name
@@ -203,10 +200,7 @@ struct PlanBuilder<'a> {
     context: &'a OtioImportContext<'a>,
     /// Frame grid of the target sequence.
     clock: TimelineClock,
-    plan: OtioImportPlan,
-    /// Import steps already emitted, keyed by the media path, so two clips off
-    /// the same file import it once.
-    imported: HashMap<String, String>,
+    out: ImportPlanWriter<'a>,
     /// Distinct node rates the file used, so a rate that is not the sequence's
     /// is reported once rather than once per clip.
     foreign_rates: Vec<f64>,
@@ -217,15 +211,31 @@ impl<'a> PlanBuilder<'a> {
         Self {
             context,
             clock: TimelineClock::new(context.sequence_fps.clone()),
-            plan: OtioImportPlan::default(),
-            imported: HashMap::new(),
+            out: ImportPlanWriter::new(
+                "OTIO",
+                context.assets,
+                context.project_root,
+                context.allow_external_media,
+            ),
             foreign_rates: Vec::new(),
         }
     }
 
     fn finish(mut self) -> OtioImportPlan {
         self.report_rate_mismatch();
-        self.plan
+        let ImportPlanWriter {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+            ..
+        } = self.out;
+        OtioImportPlan {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+        }
     }
 
     fn sequence_id(&self) -> &str {
@@ -264,7 +274,7 @@ impl<'a> PlanBuilder<'a> {
             return;
         }
         foreign.dedup();
-        self.plan.unsupported.push(format!(
+        self.out.unsupported.push(format!(
             "the file expresses its timing at {} fps and the sequence runs at {sequence_rate} fps; \
              every position was snapped to the sequence's frame grid, so a cut may move by up to \
              half a frame",
@@ -272,22 +282,13 @@ impl<'a> PlanBuilder<'a> {
         ));
     }
 
-    fn push_step(&mut self, id: &str, command_type: &str, payload: JsonValue, depends_on: &[&str]) {
-        self.plan.steps.push(json!({
-            "id": id,
-            "commandType": command_type,
-            "payload": payload,
-            "dependsOn": depends_on,
-        }));
-    }
-
     // -------------------------------------------------------------------------
     // Tracks
     // -------------------------------------------------------------------------
 
     fn add_track(&mut self, index: usize, track: &OtioTrack) -> Result<(), String> {
         let Some(kind) = track_kind(track) else {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "track '{}' has kind \"{}\", which OpenReelio does not import; only Video and \
                  Audio tracks are editorial",
                 track.name, track.kind
@@ -297,7 +298,7 @@ impl<'a> PlanBuilder<'a> {
 
         let track_step = format!("track_{index}");
         let sequence_id = self.sequence_id().to_string();
-        self.push_step(
+        self.out.push_step(
             &track_step,
             "CreateTrack",
             json!({
@@ -320,7 +321,7 @@ impl<'a> PlanBuilder<'a> {
                     // gap synthesis the exporter does.
                     match self.seconds_of(&gap.source_range.duration) {
                         Some(duration_sec) if duration_sec >= 0.0 => cursor_sec += duration_sec,
-                        _ => self.plan.warnings.push(format!(
+                        _ => self.out.warnings.push(format!(
                             "a gap on track '{}' has a length this build cannot read and was \
                              ignored, so everything after it moved earlier",
                             track.name
@@ -329,7 +330,7 @@ impl<'a> PlanBuilder<'a> {
                 }
                 OtioComposable::Clip(clip) => {
                     let Some(duration_sec) = self.seconds_of(&clip.source_range.duration) else {
-                        self.plan.warnings.push(format!(
+                        self.out.warnings.push(format!(
                             "clip '{}' has a duration this build cannot read ({:e} at rate {:e}) \
                              and was not imported",
                             clip.name,
@@ -339,7 +340,7 @@ impl<'a> PlanBuilder<'a> {
                         continue;
                     };
                     if duration_sec <= 0.0 {
-                        self.plan.warnings.push(format!(
+                        self.out.warnings.push(format!(
                             "clip '{}' has a duration of {duration_sec}s and was not imported",
                             clip.name
                         ));
@@ -410,15 +411,25 @@ impl<'a> PlanBuilder<'a> {
         duration_sec: f64,
     ) -> Result<Option<PlacedClip>, String> {
         let Some(source_in_sec) = self.seconds_of(&clip.source_range.start_time) else {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "clip '{}' has a source in-point this build cannot read ({:e} at rate {:e}) and \
                  was not imported",
                 clip.name, clip.source_range.start_time.value, clip.source_range.start_time.rate
             ));
             return Ok(None);
         };
+        // Source ranges are in the media's own time; an available range that
+        // starts at the file's timecode puts the first frame there, not at zero.
+        let media_start_sec = match &clip.media_reference {
+            OtioMediaRef::External(ExternalReference {
+                available_range: Some(available),
+                ..
+            }) => self.seconds_of(&available.start_time).unwrap_or(0.0),
+            _ => 0.0,
+        };
+        let source_in_sec = source_in_sec - media_start_sec;
         if source_in_sec < 0.0 {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "clip '{}' reads from {source_in_sec}s of its source, which is before the media \
                  starts, and was not imported",
                 clip.name
@@ -437,7 +448,7 @@ impl<'a> PlanBuilder<'a> {
         let timeline_start = self.clock.frame_to_seconds(start_frame);
         let snapped_duration = self.clock.frame_to_seconds(end_frame) - timeline_start;
         if snapped_duration <= 0.0 {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "clip '{}' is shorter than one frame at the sequence's rate and was not imported",
                 clip.name
             ));
@@ -453,18 +464,12 @@ impl<'a> PlanBuilder<'a> {
 
         let step_id = format!("clip_{track_index}_{child_index}");
         let mut depends_on = vec![track_step.to_string()];
-        let asset_value = match &resolved {
-            ResolvedMedia::Existing(asset_id) => json!(asset_id),
-            ResolvedMedia::Imported(import_step) => {
-                depends_on.push(import_step.clone());
-                step_reference(import_step)
-            }
-        };
+        let asset_value = resolved.asset_value(&mut depends_on);
         let depends_refs: Vec<&str> = depends_on.iter().map(String::as_str).collect();
         let source_out_sec = source_in_sec + snapped_duration;
         let sequence_id = self.sequence_id().to_string();
 
-        self.push_step(
+        self.out.push_step(
             &step_id,
             "InsertClip",
             json!({
@@ -514,7 +519,7 @@ impl<'a> PlanBuilder<'a> {
         }
 
         if !lost.is_empty() {
-            self.plan.unsupported.push(format!(
+            self.out.unsupported.push(format!(
                 "clip '{}' carried {} that OTIO import does not restore; it was placed at \
                  unmodified speed and plays its slot straight through",
                 clip.name,
@@ -523,7 +528,7 @@ impl<'a> PlanBuilder<'a> {
         }
 
         if !clip.markers.is_empty() {
-            self.plan.unsupported.push(format!(
+            self.out.unsupported.push(format!(
                 "clip '{}' carries {} marker(s) that were not imported: OpenReelio holds markers \
                  on the sequence, not on a clip",
                 clip.name,
@@ -548,7 +553,7 @@ impl<'a> PlanBuilder<'a> {
                 ))
             }
             OtioMediaRef::Missing(_) => {
-                self.plan.warnings.push(format!(
+                self.out.warnings.push(format!(
                     "clip '{}' carries a {} and was not imported: there is no media to place",
                     clip.name,
                     clip.media_reference.kind_label()
@@ -566,140 +571,14 @@ impl<'a> PlanBuilder<'a> {
         }
 
         let Some(path) = file_url_to_path(&reference.target_url) else {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "clip '{}' references '{}', which is not a local file URL, and was not imported",
                 clip.name, reference.target_url
             ));
             return Ok(None);
         };
 
-        // Matching an asset the project already holds reads nothing off the
-        // foreign path, so both matches run before the scoping guard: the guard
-        // exists to keep `ImportAsset` away from a path the file chose, not to
-        // stop a clip from finding media the user imported themselves.
-        if let Some(asset_id) = self.find_asset_by_path(&path) {
-            return Ok(Some(ResolvedMedia::Existing(asset_id)));
-        }
-
-        let base_name = base_name(&path);
-        if let Some(asset_id) = self.find_asset_by_name(&base_name) {
-            self.plan.warnings.push(format!(
-                "clip '{}' was matched to asset '{}' by file name because '{}' is not the path \
-                 that asset was imported from; check it is the intended media",
-                clip.name, asset_id, path
-            ));
-            return Ok(Some(ResolvedMedia::Existing(asset_id)));
-        }
-
-        let Some(uri) = self.scoped_import_path(&clip.name, &path) else {
-            return Ok(None);
-        };
-
-        if let Some(existing_step) = self.imported.get(&uri) {
-            return Ok(Some(ResolvedMedia::Imported(existing_step.clone())));
-        }
-
-        let step_id = format!("import_{}", self.imported.len());
-        self.push_step(
-            &step_id,
-            "ImportAsset",
-            json!({ "name": base_name, "uri": uri }),
-            &[],
-        );
-        self.imported.insert(uri.clone(), step_id.clone());
-        self.plan.asset_imports.push(OtioAssetImport {
-            name: base_name,
-            uri,
-        });
-
-        Ok(Some(ResolvedMedia::Imported(step_id)))
-    }
-
-    /// Turns a media path the file chose into one this import may actually read,
-    /// or `None` — with the reason recorded — when it may not.
-    ///
-    /// This is the whole of the import's trust boundary, and it is deliberately
-    /// one decision rather than a list of banned spellings. A path that does not
-    /// match an asset the project already has is about to be handed to
-    /// `ImportAsset`, which stats it and may ffprobe it, so the question is not
-    /// "is this a UNC path" — `\\host\share`, `/\host\share`, `//host/share` and
-    /// `%5C%5Chost` are all the same request — but "is this inside the project".
-    /// Answering that refuses the SMB reflection and the filesystem existence
-    /// oracle together, and keeps refusing spellings nobody has thought of yet.
-    fn scoped_import_path(&mut self, clip_name: &str, path: &str) -> Option<String> {
-        // Kept as its own check so a network path is named as one in the report
-        // even when external media is allowed: an outbound SMB connection is a
-        // different hazard from reading a local file the operator asked for.
-        if is_network_path(path) {
-            self.plan.warnings.push(format!(
-                "clip '{clip_name}' references the network path '{path}', which OpenReelio will \
-                 not import from an OTIO file; relink it to local media instead"
-            ));
-            return None;
-        }
-
-        if path.contains("://") {
-            self.plan.warnings.push(format!(
-                "clip '{clip_name}' references '{path}', which is not a local file, and was not \
-                 imported"
-            ));
-            return None;
-        }
-
-        let candidate = if is_absolute_media_path(path) {
-            PathBuf::from(path)
-        } else {
-            // A relative reference is ours: an asset stored inside the project
-            // keeps a project-relative URI. It resolves against the project root,
-            // which also makes it in-scope by construction — once `..` is out.
-            self.context.project_root.join(path)
-        };
-
-        if candidate
-            .components()
-            .any(|component| matches!(component, Component::ParentDir))
-        {
-            self.plan.warnings.push(format!(
-                "clip '{clip_name}' references '{path}', which walks out of the directory it \
-                 starts in, and was not imported"
-            ));
-            return None;
-        }
-
-        if !self.context.allow_external_media
-            && !is_inside_project(self.context.project_root, &candidate)
-        {
-            self.plan.warnings.push(format!(
-                "clip '{clip_name}' references '{path}', which is outside the project directory, \
-                 and was not imported; an OTIO file chooses its own media paths, so import only \
-                 reads media from inside the project unless external media is explicitly allowed \
-                 (`--allow-external-media`)"
-            ));
-            return None;
-        }
-
-        // The project root arrives canonicalised, which on Windows means
-        // `\\?\C:\…`. Left in, joining a relative reference onto it and swapping
-        // separators yields `//?/C:/…` — a path that reads as a network
-        // authority, that ImportAsset cannot open, and that no longer matches
-        // the same file named absolutely.
-        Some(strip_verbatim_prefix(&candidate.to_string_lossy()).replace('\\', "/"))
-    }
-
-    fn find_asset_by_path(&self, path: &str) -> Option<String> {
-        let wanted = normalize_path(path);
-        self.assets()
-            .values()
-            .find(|asset| normalize_path(&asset.uri) == wanted)
-            .map(|asset| asset.id.clone())
-    }
-
-    fn find_asset_by_name(&self, name: &str) -> Option<String> {
-        let wanted = name.to_lowercase();
-        self.assets()
-            .values()
-            .find(|asset| asset.name.to_lowercase() == wanted)
-            .map(|asset| asset.id.clone())
+        Ok(self.out.relink(&clip.name, &path))
     }
 
     // -------------------------------------------------------------------------
@@ -730,7 +609,7 @@ impl<'a> PlanBuilder<'a> {
                 .iter()
                 .find(|clip| clip.child_index == child_index + 1),
         ) else {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "a transition on track '{track_name}' was not imported: it does not sit between \
                  two imported clips, because it is at the end of the track or because an adjacent \
                  clip was skipped"
@@ -742,7 +621,7 @@ impl<'a> PlanBuilder<'a> {
             self.seconds_of(&transition.in_offset),
             self.seconds_of(&transition.out_offset),
         ) else {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "a transition on track '{track_name}' has offsets this build cannot read and was \
                  not imported"
             ));
@@ -751,14 +630,14 @@ impl<'a> PlanBuilder<'a> {
         let duration_sec = in_sec + out_sec;
 
         if duration_sec <= 0.0 {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "a transition on track '{track_name}' has no length and was not imported"
             ));
             return;
         }
 
         if (in_sec - out_sec).abs() > f64::EPSILON {
-            self.plan.unsupported.push(format!(
+            self.out.unsupported.push(format!(
                 "the transition on track '{track_name}' is asymmetric ({in_sec:.3}s before the \
                  cut, {out_sec:.3}s after); OpenReelio stores a single duration, so it was \
                  imported as {duration_sec:.3}s centred on the cut"
@@ -782,7 +661,7 @@ impl<'a> PlanBuilder<'a> {
         // track cannot collide however many clips between them were skipped.
         let step_id = format!("transition_{track_index}_{child_index}");
         let sequence_id = self.sequence_id().to_string();
-        self.push_step(
+        self.out.push_step(
             &step_id,
             "AddEffect",
             json!({
@@ -813,7 +692,7 @@ impl<'a> PlanBuilder<'a> {
             if IMPORTABLE_TRANSITIONS.contains(&effect_type) {
                 return Some(effect_type.to_string());
             }
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "a transition on track '{track_name}' names the type \"{effect_type}\", which is \
                  not one of the two-input transitions an OTIO import may place ({}); it was not \
                  imported",
@@ -823,7 +702,7 @@ impl<'a> PlanBuilder<'a> {
         }
 
         if transition.transition_type != "SMPTE_Dissolve" {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "the \"{}\" transition on track '{track_name}' has no OpenReelio equivalent and \
                  was imported as a cross dissolve",
                 transition.transition_type
@@ -869,7 +748,7 @@ impl<'a> PlanBuilder<'a> {
         {
             Some(available) => {
                 if outgoing.source_out_sec + outgoing_needed > available {
-                    self.plan.warnings.push(format!(
+                    self.out.warnings.push(format!(
                         "the transition on track '{track_name}' needs {outgoing_needed:.3}s of \
                          unused media after the outgoing clip's out point but only \
                          {:.3}s is available; it will render as a cut",
@@ -877,14 +756,14 @@ impl<'a> PlanBuilder<'a> {
                     ));
                 }
             }
-            None => self.plan.warnings.push(format!(
+            None => self.out.warnings.push(format!(
                 "the length of the outgoing clip's source on track '{track_name}' is unknown, so \
                  its transition handles cannot be verified; the blend may render as a cut"
             )),
         }
 
         if incoming.source_in_sec - incoming_needed < 0.0 {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "the transition on track '{track_name}' needs {incoming_needed:.3}s of unused \
                  media before the incoming clip's in point but it starts {:.3}s into its source; \
                  it will render as a cut",
@@ -910,7 +789,7 @@ impl<'a> PlanBuilder<'a> {
             None => "the sequence".to_string(),
         };
         let Some(time_sec) = self.seconds_of(&marker.marked_range.start_time) else {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "the marker '{}' on {origin} has a position this build cannot read and was not \
                  imported",
                 marker.name
@@ -918,7 +797,7 @@ impl<'a> PlanBuilder<'a> {
             return;
         };
         if time_sec < 0.0 {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "the marker '{}' on {origin} is at {time_sec}s, before the start of the sequence, \
                  and was not imported",
                 marker.name
@@ -947,7 +826,7 @@ impl<'a> PlanBuilder<'a> {
         }
 
         if marker.marked_range.duration.to_seconds().unwrap_or(0.0) > 0.0 {
-            self.plan.unsupported.push(format!(
+            self.out.unsupported.push(format!(
                 "the marker '{}' spans a range; OpenReelio markers are points, so only its start \
                  was imported",
                 marker.name
@@ -955,34 +834,21 @@ impl<'a> PlanBuilder<'a> {
         }
 
         if track.is_some() {
-            self.plan.warnings.push(format!(
+            self.out.warnings.push(format!(
                 "the marker '{}' on {origin} was imported onto the sequence: OpenReelio holds \
                  markers on the sequence, not per track",
                 marker.name
             ));
         }
 
-        self.push_step(step_id, "AddMarker", payload, &[]);
+        self.out.push_step(step_id, "AddMarker", payload, &[]);
     }
 }
 
-/// Where a clip's media came from.
-enum ResolvedMedia {
-    /// An asset already in the project.
-    Existing(String),
-    /// An `ImportAsset` step this plan emits; the id is only known at run time.
-    Imported(String),
-}
-
 // =============================================================================
 // Internal: helpers
 // =============================================================================
 
-/// Builds a `$fromStep` reference to the first id a step creates.
-fn step_reference(step_id: &str) -> JsonValue {
-    json!({ "$fromStep": step_id, "$path": "createdIds.0" })
-}
-
 /// Maps an OTIO track kind onto ours. `None` for anything non-editorial.
 fn track_kind(track: &OtioTrack) -> Option<&'static str> {
     match track.kind.as_str() {
@@ -1001,33 +867,6 @@ fn track_name(track: &OtioTrack, index: usize) -> String {
     }
 }
 
-/// Normalises a path for comparison: no Windows verbatim prefix, forward
-/// slashes, case-folded.
-///
-/// The prefix matters here as much as in the URL: an imported asset's stored
-/// URI reads `\\?\C:\…` while the path decoded out of a `target_url` reads
-/// `C:\…`, and without stripping it the two never compare equal, so every clip
-/// would re-import media the project already has.
-///
-/// Case folding is not correct on a case-sensitive filesystem, but a false
-/// match here reuses an asset the user already has rather than importing a
-/// duplicate, and the alternative — two assets for one file — is the worse
-/// failure.
-fn normalize_path(path: &str) -> String {
-    strip_verbatim_prefix(path)
-        .replace('\\', "/")
-        .to_lowercase()
-}
-
-fn base_name(path: &str) -> String {
-    path.replace('\\', "/")
-        .rsplit('/')
-        .next()
-        .filter(|name| !name.is_empty())
-        .unwrap_or(path)
-        .to_string()
-}
-
 /// The wipe / slide direction a transition asks for, if it asks for a valid one.
 ///
 /// Only wipes and slides have a direction, and the value comes out of a foreign
@@ -1044,34 +883,6 @@ fn transition_direction(transition: &OtioTransition, effect_type: &str) -> Optio
         .then(|| direction.to_string())
 }
 
-/// Whether a decoded media path names an absolute location.
-///
-/// Deliberately answered from the string rather than `Path::is_absolute`: an
-/// `.otio` written on Windows is routinely read on Linux and the reverse, and
-/// `Path::is_absolute` answers for the *host*, so `C:/Windows/win.ini` reads as a
-/// relative path on Linux and would be joined onto the project root — landing
-/// inside the scope it was supposed to be measured against.
-fn is_absolute_media_path(path: &str) -> bool {
-    let bytes = path.as_bytes();
-    matches!(bytes.first(), Some(b'/') | Some(b'\\'))
-        || matches!(bytes, [drive, b':', ..] if drive.is_ascii_alphabetic())
-}
-
-/// Whether a media path resolves inside the project directory.
-///
-/// A `..` component is refused outright rather than resolved: the containment
-/// test below falls back to a textual comparison for a path that does not exist,
-/// and `<project>/../../etc/passwd` passes a textual comparison.
-fn is_inside_project(project_root: &Path, candidate: &Path) -> bool {
-    if candidate
-        .components()
-        .any(|component| matches!(component, Component::ParentDir))
-    {
-        return false;
-    }
-    crate::core::workspace::path_resolver::is_inside_project(project_root, candidate)
-}
-
 // =============================================================================
 // Tests
 // =============================================================================
@@ -1079,34 +890,10 @@ fn is_inside_project(project_root: &Path, candidate: &Path) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::assets::{AssetKind, LicenseInfo, ProxyStatus};
+    use crate::core::fs::is_network_path;
+    use crate::core::interchange::import_plan::test_support::assets_with;
     use crate::core::interchange::otio::parse_otio;
 
-    fn make_asset(id: &str, name: &str, uri: &str, duration: Option<f64>) -> Asset {
-        Asset {
-            id: id.to_string(),
-            kind: AssetKind::Video,
-            name: name.to_string(),
-            uri: uri.to_string(),
-            hash: "abc123".to_string(),
-            duration_sec: duration,
-            file_size: 1024,
-            imported_at: "2026-01-01T00:00:00Z".to_string(),
-            video: None,
-            audio: None,
-            license: LicenseInfo::default(),
-            tags: vec![],
-            thumbnail_url: None,
-            proxy_status: ProxyStatus::NotNeeded,
-            proxy_url: None,
-            bin_id: None,
-            relative_path: None,
-            workspace_managed: false,
-            missing: false,
-            quarantined_uri: None,
-        }
-    }
-
     fn rational(value: f64, rate: f64) -> JsonValue {
         json!({ "OTIO_SCHEMA": "RationalTime.1", "value": value, "rate": rate })
     }
@@ -1154,13 +941,6 @@ mod tests {
         })
     }
 
-    fn assets_with(entries: &[(&str, &str, &str, Option<f64>)]) -> HashMap<String, Asset> {
-        entries
-            .iter()
-            .map(|(id, name, uri, duration)| (id.to_string(), make_asset(id, name, uri, *duration)))
-            .collect()
-    }
-
     /// Where every fixture keeps its media, and the project root the fixtures
     /// import into: the importer only reads media from inside the project, so a
     /// test that is not about scoping should satisfy scoping by construction.
@@ -0,0 +1,1232 @@
+//! XMEML Export
+//!
+//! Writes a [`Sequence`] as Final Cut Pro 7 XML (`<xmeml version="4">`). The
+//! format is long retired in Final Cut itself, but it is still what Premiere
+//! Pro and DaVinci Resolve exchange most reliably — Premiere reads it without a
+//! plug-in and round-trips it far better than FCPXML — which makes it the
+//! "finish in Premiere" path.
+//!
+//! ## What survives
+//!
+//! - video and audio tracks, bottom-up as XMEML lists them, with their
+//!   enabled and locked state,
+//! - clips, with their media, source in/out and timeline start/end, and
+//!   disabled clips as disabled clip items,
+//! - constant speed and reverse, as a *Time Remap* filter,
+//! - clip opacity and an opacity effect's keyframes, as an *Opacity* filter,
+//! - clip volume and volume keyframes, as an *Audio Levels* filter,
+//! - sequence markers.
+//!
+//! Sound lives only on audio tracks in XMEML: a clip item on a video track is
+//! silent. A video clip whose media carries sound is therefore written twice —
+//! as its picture on the video track and, linked to it, as its sound on an
+//! audio track added for that purpose — so the cut plays the same.
+//!
+//! What does **not** survive is reported in [`XmemlExport::unsupported`]:
+//! effects other than opacity, transitions, transforms, blend modes, pan and
+//! fades, freeze frames and time-remap curves, and caption tracks.
+//!
+//! ## Time
+//!
+//! Every time is an integer frame count at the sequence's `<rate>`: an integer
+//! `timebase` and an `ntsc` flag for the 1000/1001 pull-down rates. Keyframe
+//! `<when>` values are counted, as Final Cut Pro 7 counts them, in source
+//! frames from the start of the media, so a keyframe stays on its frame when
+//! the clip is trimmed.
+
+use std::collections::HashMap;
+
+use crate::core::assets::{Asset, AssetKind};
+use crate::core::commands::is_text_clip;
+use crate::core::effects::{Effect, EffectType};
+use crate::core::timeline::{
+    AudioSettings, BlendMode, Clip, Marker, Sequence, TimelineClock, Track, TrackKind, Transform,
+};
+use crate::core::{Frame, Ratio};
+
+use super::models::{
+    asset_src_url, is_drop_frame_rate, InterchangeExportResult, InterchangeFormat, Timecode,
+};
+use super::otio::{sorted_clips, LossReport};
+
+/// Largest gain an *Audio Levels* filter accepts: +12 dB.
+const MAX_LEVEL_GAIN: f64 = 3.98109;
+
+// =============================================================================
+// Public API
+// =============================================================================
+
+/// Result of an XMEML export.
+#[derive(Clone, Debug)]
+pub struct XmemlExport {
+    /// The complete XMEML document.
+    pub xml: String,
+    /// Tracks written to the document, the added sound tracks included.
+    pub track_count: u32,
+    /// Clips of the sequence written to the document. A video clip written
+    /// with its linked sound counts once.
+    pub clip_count: u32,
+    /// Structural notes: missing assets, overlapping clips, skipped clips.
+    pub warnings: Vec<String>,
+    /// Editorial detail XMEML cannot carry.
+    pub unsupported: Vec<String>,
+}
+
+/// Exports a sequence to a Final Cut Pro 7 XML document.
+///
+/// # Arguments
+/// * `sequence` - The sequence to export
+/// * `assets` - Asset map for resolving media references
+/// * `effects` - Effect map for resolving the clips' opacity effects
+///
+/// # Errors
+/// Returns `Err` only for a sequence whose frame rate is not a positive
+/// number; a sequence with nothing exportable produces an empty document plus
+/// warnings.
+pub fn export_xmeml(
+    sequence: &Sequence,
+    assets: &HashMap<String, Asset>,
+    effects: &HashMap<String, Effect>,
+) -> Result<XmemlExport, String> {
+    let fps = &sequence.format.fps;
+    if fps.num <= 0 || fps.den <= 0 {
+        return Err(format!(
+            "{}/{} is not a frame rate XMEML can express",
+            fps.num, fps.den
+        ));
+    }
+
+    let mut report = LossReport::default();
+    let rate = XmemlRate::from_ratio(fps).unwrap_or_else(|| {
+        let rounded = XmemlRate::nearest(fps);
+        report.unsupported.push(format!(
+            "the sequence runs at {:.3} fps, which XMEML cannot express; it was written at {} fps \
+             and every position was counted in those frames",
+            fps.as_f64(),
+            rounded.to_ratio().as_f64()
+        ));
+        rounded
+    });
+    let clock = TimelineClock::new(rate.to_ratio());
+
+    let mut video_tracks = Vec::new();
+    let mut audio_tracks = Vec::new();
+    // Track 0 is the topmost track; XMEML lists video tracks bottom-up.
+    for track in sequence.tracks.iter().rev() {
+        match track.kind {
+            TrackKind::Video | TrackKind::Overlay => {
+                video_tracks.push(collect_track(track, assets, &clock, &mut report));
+            }
+            TrackKind::Audio => {}
+            TrackKind::Caption => report.unsupported.push(format!(
+                "caption track '{}' was not exported: XMEML has no caption track",
+                track.name
+            )),
+        }
+    }
+    for track in sequence
+        .tracks
+        .iter()
+        .filter(|t| t.kind == TrackKind::Audio)
+    {
+        audio_tracks.push(collect_track(track, assets, &clock, &mut report));
+    }
+    let clip_count = video_tracks
+        .iter()
+        .chain(&audio_tracks)
+        .map(|track| track.items.len() as u32)
+        .sum();
+
+    link_embedded_sound(&mut video_tracks, &mut audio_tracks);
+    assign_ids(&mut video_tracks, &mut audio_tracks);
+
+    for track in video_tracks.iter().chain(&audio_tracks) {
+        for item in &track.items {
+            record_clip_losses(item, track.source, effects, &mut report);
+        }
+    }
+
+    let mut writer = XmemlWriter {
+        out: String::with_capacity(16 * 1024),
+        rate,
+        clock: &clock,
+        effects,
+        written_files: HashMap::new(),
+    };
+    writer.write_document(sequence, &video_tracks, &audio_tracks);
+
+    let LossReport {
+        warnings,
+        mut unsupported,
+        aggregates,
+    } = report;
+    unsupported.extend(aggregates.into_entries());
+
+    Ok(XmemlExport {
+        xml: writer.out,
+        track_count: (video_tracks.len() + audio_tracks.len()) as u32,
+        clip_count,
+        warnings,
+        unsupported,
+    })
+}
+
+/// Builds an `InterchangeExportResult` from the export output.
+pub fn build_export_result(
+    output_path: &str,
+    export: &XmemlExport,
+    duration_sec: f64,
+) -> InterchangeExportResult {
+    InterchangeExportResult {
+        output_path: output_path.to_string(),
+        format: InterchangeFormat::Xmeml,
+        event_count: export.clip_count,
+        track_count: export.track_count,
+        duration_sec,
+        warnings: export.warnings.clone(),
+        unsupported: export.unsupported.clone(),
+    }
+}
+
+// =============================================================================
+// Rates and levels
+// =============================================================================
+
+/// An XMEML `<rate>`: an integer timebase, pulled down by 1000/1001 when
+/// `ntsc` is set. 29.97 fps is timebase 30 with `ntsc`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) struct XmemlRate {
+    pub timebase: u32,
+    pub ntsc: bool,
+}
+
+impl XmemlRate {
+    /// The rate that expresses `fps` exactly, if there is one.
+    pub fn from_ratio(fps: &Ratio) -> Option<Self> {
+        if fps.num <= 0 || fps.den <= 0 {
+            return None;
+        }
+        if fps.den == 1001 && fps.num % 1000 == 0 {
+            return Some(Self {
+                timebase: (fps.num / 1000) as u32,
+                ntsc: true,
+            });
+        }
+        (fps.num % fps.den == 0).then(|| Self {
+            timebase: (fps.num / fps.den) as u32,
+            ntsc: false,
+        })
+    }
+
+    /// The closest integer rate, for a frame rate XMEML cannot express.
+    fn nearest(fps: &Ratio) -> Self {
+        Self {
+            timebase: (fps.as_f64().round() as u32).max(1),
+            ntsc: false,
+        }
+    }
+
+    pub fn to_ratio(self) -> Ratio {
+        if self.ntsc {
+            Ratio::new(self.timebase as i32 * 1000, 1001)
+        } else {
+            Ratio::new(self.timebase as i32, 1)
+        }
+    }
+}
+
+/// Converts decibels to the linear gain an *Audio Levels* filter stores.
+pub(super) fn db_to_gain(db: f64) -> f64 {
+    if db <= -60.0 {
+        0.0
+    } else {
+        10.0_f64.powf(db / 20.0).min(MAX_LEVEL_GAIN)
+    }
+}
+
+/// Converts an *Audio Levels* gain back to decibels, floored at -60 dB.
+pub(super) fn gain_to_db(gain: f64) -> f64 {
+    if gain <= 0.001 {
+        -60.0
+    } else {
+        (20.0 * gain.log10()).max(-60.0)
+    }
+}
+
+// =============================================================================
+// Internal: collecting clips
+// =============================================================================
+
+/// A clip as it is written: its span on the timeline and in the source, in
+/// frames.
+struct Item<'a> {
+    clip: &'a Clip,
+    asset: &'a Asset,
+    start: Frame,
+    end: Frame,
+    source_in: Frame,
+    source_out: Frame,
+    /// `clipitem-N`, assigned once every track is known.
+    id: String,
+    /// 1-based track and clip index, among the tracks of its kind.
+    position: (usize, usize),
+    /// The other half of a clip written as linked picture and sound.
+    link: Option<Link>,
+}
+
+/// Where the linked half of a clip item sits.
+#[derive(Clone)]
+struct Link {
+    audio: bool,
+    /// 1-based, among the tracks of its kind.
+    track_index: usize,
+    /// 1-based, among the items of its track.
+    clip_index: usize,
+    id: String,
+}
+
+struct ItemTrack<'a> {
+    /// The sequence track the items came from. For an added sound track,
+    /// the video track whose clips it carries the sound of.
+    source: &'a Track,
+    items: Vec<Item<'a>>,
+}
+
+fn collect_track<'a>(
+    track: &'a Track,
+    assets: &'a HashMap<String, Asset>,
+    clock: &TimelineClock,
+    report: &mut LossReport,
+) -> ItemTrack<'a> {
+    let mut items: Vec<Item<'a>> = Vec::new();
+    let mut cursor: Frame = 0;
+
+    for clip in sorted_clips(track) {
+        if let Some(reason) = unexportable_reason(clip) {
+            report.unsupported.push(format!(
+                "clip '{}' on track '{}' was not exported: {reason}",
+                clip.id, track.name
+            ));
+            continue;
+        }
+        let Some(asset) = assets.get(&clip.asset_id) else {
+            report.warnings.push(format!(
+                "clip '{}' on track '{}' references asset '{}', which is not in the project, and \
+                 was not exported",
+                clip.id, track.name, clip.asset_id
+            ));
+            continue;
+        };
+
+        let start = clock.seconds_to_nearest_frame(clip.place.timeline_in_sec);
+        let end = clock.seconds_to_nearest_frame(clip.place.timeline_out_sec());
+        if end <= start {
+            report.warnings.push(format!(
+                "clip '{}' on track '{}' is shorter than one frame and was not exported",
+                clip.id, track.name
+            ));
+            continue;
+        }
+        if start < cursor {
+            report.warnings.push(format!(
+                "clip '{}' on track '{}' overlaps the clip before it and was not exported: an \
+                 XMEML track cannot hold two clips at one time",
+                clip.id, track.name
+            ));
+            continue;
+        }
+
+        let source_in = clock.seconds_to_nearest_frame(clip.range.source_in_sec);
+        let source_out = clock
+            .seconds_to_nearest_frame(clip.range.source_out_sec)
+            .max(source_in + 1);
+        items.push(Item {
+            clip,
+            asset,
+            start,
+            end,
+            source_in,
+            source_out,
+            id: String::new(),
+            position: (0, 0),
+            link: None,
+        });
+        cursor = end;
+    }
+
+    ItemTrack {
+        source: track,
+        items,
+    }
+}
+
+/// Returns why a clip has no XMEML representation at all, if it has none.
+fn unexportable_reason(clip: &Clip) -> Option<&'static str> {
+    if clip.is_compound() || clip.asset_id.starts_with(Clip::COMPOUND_ASSET_PREFIX) {
+        return Some("it is a compound clip, and this export writes no nested sequences");
+    }
+    if clip.is_adjustment_layer() || clip.asset_id == Clip::ADJUSTMENT_LAYER_ASSET_ID {
+        return Some("it is an adjustment layer, which carries grading rather than media");
+    }
+    if is_text_clip(clip) {
+        return Some("it is a text clip, and XMEML has no portable text generator");
+    }
+    None
+}
+
+/// Whether a clip's media carries sound.
+fn has_sound(asset: &Asset) -> bool {
+    asset.audio.is_some() || asset.kind == AssetKind::Audio
+}
+
+/// Adds one audio track per video track whose clips carry sound, holding that
+/// sound as clip items linked to their pictures.
+fn link_embedded_sound<'a>(video: &mut [ItemTrack<'a>], audio: &mut Vec<ItemTrack<'a>>) {
+    for (video_index, track) in video.iter_mut().enumerate() {
+        let mut sound = Vec::new();
+        for (clip_index, item) in track.items.iter_mut().enumerate() {
+            if !has_sound(item.asset) || item.clip.audio.muted {
+                continue;
+            }
+            let sound_index = sound.len();
+            item.link = Some(Link {
+                audio: true,
+                track_index: audio.len() + 1,
+                clip_index: sound_index + 1,
+                id: String::new(),
+            });
+            sound.push(Item {
+                clip: item.clip,
+                asset: item.asset,
+                start: item.start,
+                end: item.end,
+                source_in: item.source_in,
+                source_out: item.source_out,
+                id: String::new(),
+                position: (0, 0),
+                link: Some(Link {
+                    audio: false,
+                    track_index: video_index + 1,
+                    clip_index: clip_index + 1,
+                    id: String::new(),
+                }),
+            });
+        }
+        if !sound.is_empty() {
+            audio.push(ItemTrack {
+                source: track.source,
+                items: sound,
+            });
+        }
+    }
+}
+
+/// Numbers every clip item and fills in the ids its links point at.
+fn assign_ids<'a>(video: &mut [ItemTrack<'a>], audio: &mut [ItemTrack<'a>]) {
+    let mut next = 1;
+    for tracks in [&mut *video, &mut *audio] {
+        for (track_index, track) in tracks.iter_mut().enumerate() {
+            for (clip_index, item) in track.items.iter_mut().enumerate() {
+                item.id = format!("clipitem-{next}");
+                item.position = (track_index + 1, clip_index + 1);
+                next += 1;
+            }
+        }
+    }
+
+    let id_of = |video: &[ItemTrack<'_>], audio: &[ItemTrack<'_>], link: &Link| {
+        let tracks = if link.audio { audio } else { video };
+        tracks[link.track_index - 1].items[link.clip_index - 1]
+            .id
+            .clone()
+    };
+    let mut resolved = Vec::new();
+    for (is_audio, tracks) in [(false, &*video), (true, &*audio)] {
+        for (track_index, track) in tracks.iter().enumerate() {
+            for (clip_index, item) in track.items.iter().enumerate() {
+                if let Some(link) = &item.link {
+                    resolved.push((is_audio, track_index, clip_index, id_of(video, audio, link)));
+                }
+            }
+        }
+    }
+    for (is_audio, track_index, clip_index, id) in resolved {
+        let tracks: &mut [ItemTrack<'a>] = if is_audio { audio } else { video };
+        if let Some(link) = &mut tracks[track_index].items[clip_index].link {
+            link.id = id;
+        }
+    }
+}
+
+/// Records the detail a clip carries that XMEML, as written here, cannot.
+fn record_clip_losses(
+    item: &Item<'_>,
+    track: &Track,
+    effects: &HashMap<String, Effect>,
+    report: &mut LossReport,
+) {
+    let clip = item.clip;
+
+    let mut timing: Vec<&str> = Vec::new();
+    if clip.freeze_frame {
+        timing.push("a freeze frame");
+    }
+    if clip.time_remap.is_some() {
+        timing.push("a time remap curve");
+    }
+    if !timing.is_empty() {
+        report.unsupported.push(format!(
+            "clip '{}' on track '{}' has {} that this export does not write; it was exported at \
+             its constant speed",
+            clip.id,
+            track.name,
+            timing.join(" and ")
+        ));
+    }
+
+    // The sound half of a linked clip carries the same clip; report it once.
+    if item.link.as_ref().is_some_and(|link| !link.audio) {
+        return;
+    }
+
+    if clip.effects.iter().any(|effect_id| {
+        !effects
+            .get(effect_id)
+            .is_some_and(|effect| effect.effect_type == EffectType::Opacity)
+    }) {
+        report.aggregates.record(
+            "effects",
+            "carry effects or transitions that were dropped",
+            &clip.id,
+        );
+    }
+    if clip.transform != Transform::default() || !clip.motion_keyframes.is_empty() {
+        report
+            .aggregates
+            .record("transform", "carry a transform that was dropped", &clip.id);
+    }
+    if clip.blend_mode != BlendMode::Normal {
+        report
+            .aggregates
+            .record("blendMode", "have a blend mode that was dropped", &clip.id);
+    }
+    let defaults = AudioSettings::default();
+    if clip.audio.pan != defaults.pan
+        || clip.audio.fade_in_sec > 0.0
+        || clip.audio.fade_out_sec > 0.0
+    {
+        report.aggregates.record(
+            "audio",
+            "have pan or fade settings that were dropped",
+            &clip.id,
+        );
+    }
+}
+
+// =============================================================================
+// Internal: writing
+// =============================================================================
+
+struct XmemlWriter<'a> {
+    out: String,
+    rate: XmemlRate,
+    clock: &'a TimelineClock,
+    effects: &'a HashMap<String, Effect>,
+    /// `file-N` id of each asset whose `<file>` has been written in full.
+    written_files: HashMap<String, String>,
+}
+
+impl XmemlWriter<'_> {
+    fn line(&mut self, depth: usize, text: &str) {
+        for _ in 0..depth {
+            self.out.push_str("  ");
+        }
+        self.out.push_str(text);
+        self.out.push('\n');
+    }
+
+    fn element(&mut self, depth: usize, name: &str, value: impl std::fmt::Display) {
+        let value = xml_escape(&value.to_string());
+        self.line(depth, &format!("<{name}>{value}</{name}>"));
+    }
+
+    fn rate(&mut self, depth: usize) {
+        self.line(depth, "<rate>");
+        self.element(depth + 1, "timebase", self.rate.timebase);
+        self.element(depth + 1, "ntsc", xml_bool(self.rate.ntsc));
+        self.line(depth, "</rate>");
+    }
+
+    fn write_document(
+        &mut self,
+        sequence: &Sequence,
+        video: &[ItemTrack<'_>],
+        audio: &[ItemTrack<'_>],
+    ) {
+        self.line(0, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        self.line(0, "<!DOCTYPE xmeml>");
+        self.line(0, r#"<xmeml version="4">"#);
+        self.line(1, r#"<sequence id="sequence-1">"#);
+        self.element(2, "name", &sequence.name);
+        let duration = self.clock.seconds_to_nearest_frame(sequence.duration());
+        self.element(2, "duration", duration);
+        self.rate(2);
+        self.line(2, "<timecode>");
+        self.rate(3);
+        self.element(3, "string", "00:00:00:00");
+        self.element(3, "frame", 0);
+        let drop_frame = self.rate.ntsc && is_drop_frame_rate(&self.rate.to_ratio());
+        self.element(3, "displayformat", if drop_frame { "DF" } else { "NDF" });
+        self.line(2, "</timecode>");
+
+        self.line(2, "<media>");
+        self.line(3, "<video>");
+        self.line(4, "<format>");
+        self.line(5, "<samplecharacteristics>");
+        self.rate(6);
+        self.element(6, "width", sequence.format.canvas.width);
+        self.element(6, "height", sequence.format.canvas.height);
+        self.element(6, "pixelaspectratio", "square");
+        self.line(5, "</samplecharacteristics>");
+        self.line(4, "</format>");
+        for track in video {
+            self.write_track(track, false);
+        }
+        self.line(3, "</video>");
+        self.line(3, "<audio>");
+        for track in audio {
+            self.write_track(track, true);
+        }
+        self.line(3, "</audio>");
+        self.line(2, "</media>");
+
+        for marker in &sequence.markers {
+            self.write_marker(marker);
+        }
+        self.line(1, "</sequence>");
+        self.line(0, "</xmeml>");
+    }
+
+    fn write_track(&mut self, track: &ItemTrack<'_>, audio: bool) {
+        let source = track.source;
+        // A hidden video track still contributes its sound, so only a muted
+        // track silences the sound it carries.
+        let enabled = if audio { !source.muted } else { source.visible };
+        self.line(4, "<track>");
+        self.element(5, "enabled", xml_bool(enabled));
+        self.element(5, "locked", xml_bool(source.locked));
+        for item in &track.items {
+            self.write_clip_item(item, audio);
+        }
+        self.line(4, "</track>");
+    }
+
+    fn write_clip_item(&mut self, item: &Item<'_>, audio: bool) {
+        let clip = item.clip;
+        let media_frames = item
+            .asset
+            .duration_sec
+            .map(|sec| self.clock.seconds_to_nearest_frame(sec))
+            .unwrap_or(item.source_out)
+            .max(item.source_out);
+        let name = clip
+            .label
+            .clone()
+            .unwrap_or_else(|| item.asset.name.clone());
+
+        self.line(5, &format!(r#"<clipitem id="{}">"#, item.id));
+        self.element(6, "name", &name);
+        // XMEML has no clip mute; a muted sound clip is a disabled one.
+        self.element(
+            6,
+            "enabled",
+            xml_bool(clip.enabled && !(audio && clip.audio.muted)),
+        );
+        self.element(6, "duration", media_frames);
+        self.rate(6);
+        self.element(6, "start", item.start);
+        self.element(6, "end", item.end);
+        self.element(6, "in", item.source_in);
+        self.element(6, "out", item.source_out);
+        self.write_file(item.asset, media_frames);
+        if audio {
+            self.line(6, "<sourcetrack>");
+            self.element(7, "mediatype", "audio");
+            self.element(7, "trackindex", 1);
+            self.line(6, "</sourcetrack>");
+        }
+
+        if (clip.speed - 1.0).abs() > f32::EPSILON || clip.reverse {
+            self.write_time_remap(clip, audio);
+        }
+        if audio {
+            self.write_audio_levels(item);
+        } else {
+            self.write_opacity(item);
+        }
+
+        // Both halves of a linked clip list the whole group, picture first.
+        if let Some(link) = &item.link {
+            let own = Link {
+                audio,
+                track_index: item.position.0,
+                clip_index: item.position.1,
+                id: item.id.clone(),
+            };
+            let (picture, sound) = if audio { (link, &own) } else { (&own, link) };
+            self.write_link(picture);
+            self.write_link(sound);
+        }
+        self.line(5, "</clipitem>");
+    }
+
+    fn write_link(&mut self, link: &Link) {
+        self.line(6, "<link>");
+        self.element(7, "linkclipref", &link.id);
+        self.element(7, "mediatype", if link.audio { "audio" } else { "video" });
+        self.element(7, "trackindex", link.track_index);
+        self.element(7, "clipindex", link.clip_index);
+        self.line(6, "</link>");
+    }
+
+    /// Writes an asset's `<file>` in full the first time, and as a reference
+    /// to that definition every time after.
+    fn write_file(&mut self, asset: &Asset, media_frames: Frame) {
+        if let Some(id) = self.written_files.get(&asset.id) {
+            let id = id.clone();
+            self.line(6, &format!(r#"<file id="{id}"/>"#));
+            return;
+        }
+        let id = format!("file-{}", self.written_files.len() + 1);
+        self.written_files.insert(asset.id.clone(), id.clone());
+
+        self.line(6, &format!(r#"<file id="{id}">"#));
+        self.element(7, "name", &asset.name);
+        self.element(7, "pathurl", pathurl(&asset.uri));
+        self.rate(7);
+        self.element(7, "duration", media_frames);
+        self.write_file_timecode(asset);
+        self.line(7, "<media>");
+        if let Some(video) = &asset.video {
+            self.line(8, "<video>");
+            self.line(9, "<samplecharacteristics>");
+            self.element(10, "width", video.width);
+            self.element(10, "height", video.height);
+            self.line(9, "</samplecharacteristics>");
+            self.line(8, "</video>");
+        } else if matches!(asset.kind, AssetKind::Video | AssetKind::Image) {
+            self.line(8, "<video/>");
+        }
+        if let Some(audio) = &asset.audio {
+            self.line(8, "<audio>");
+            self.element(9, "channelcount", audio.channels);
+            self.line(8, "</audio>");
+        } else if asset.kind == AssetKind::Audio {
+            self.line(8, "<audio/>");
+        }
+        self.line(7, "</media>");
+        self.line(6, "</file>");
+    }
+
+    /// Writes the source timecode and reel a camera original was recorded
+    /// with. Clip in and out points stay counted from the first frame of the
+    /// file; this only tells the receiving tool what timecode that frame has.
+    fn write_file_timecode(&mut self, asset: &Asset) {
+        let start_sec = asset.source_start_sec();
+        let reel = asset.reel_name();
+        if start_sec <= 0.0 && reel.is_none() {
+            return;
+        }
+        let ratio = self.rate.to_ratio();
+        let drop_frame = self.rate.ntsc && is_drop_frame_rate(&ratio);
+        self.line(7, "<timecode>");
+        self.rate(8);
+        self.element(8, "string", Timecode::from_seconds(start_sec, &ratio));
+        self.element(8, "frame", self.clock.seconds_to_nearest_frame(start_sec));
+        self.element(8, "displayformat", if drop_frame { "DF" } else { "NDF" });
+        if let Some(reel) = reel {
+            self.line(8, "<reel>");
+            self.element(9, "name", reel);
+            self.line(8, "</reel>");
+        }
+        self.line(7, "</timecode>");
+    }
+
+    fn write_time_remap(&mut self, clip: &Clip, audio: bool) {
+        self.line(6, "<filter>");
+        self.line(7, "<effect>");
+        self.element(8, "name", "Time Remap");
+        self.element(8, "effectid", "timeremap");
+        self.element(8, "effectcategory", "motion");
+        self.element(8, "effecttype", "motion");
+        self.element(8, "mediatype", if audio { "audio" } else { "video" });
+        self.parameter(8, "variablespeed", "variablespeed", Some((0.0, 1.0)), "0");
+        let speed = format_number(clip.speed as f64 * 100.0);
+        self.parameter(8, "speed", "speed", Some((-100000.0, 100000.0)), &speed);
+        self.parameter(8, "reverse", "reverse", None, xml_bool(clip.reverse));
+        self.parameter(8, "frameblending", "frameblending", None, "FALSE");
+        self.line(7, "</effect>");
+        self.line(6, "</filter>");
+    }
+
+    fn parameter(
+        &mut self,
+        depth: usize,
+        id: &str,
+        name: &str,
+        range: Option<(f64, f64)>,
+        value: &str,
+    ) {
+        self.line(depth, "<parameter>");
+        self.element(depth + 1, "parameterid", id);
+        self.element(depth + 1, "name", name);
+        if let Some((min, max)) = range {
+            self.element(depth + 1, "valuemin", format_number(min));
+            self.element(depth + 1, "valuemax", format_number(max));
+        }
+        self.element(depth + 1, "value", value);
+        self.line(depth, "</parameter>");
+    }
+
+    /// Writes the clip's opacity, and its opacity effect's keyframes, as one
+    /// *Opacity* filter in percent.
+    fn write_opacity(&mut self, item: &Item<'_>) {
+        let clip = item.clip;
+        let effect = clip
+            .effects
+            .iter()
+            .filter_map(|id| self.effects.get(id))
+            .find(|effect| effect.enabled && effect.effect_type == EffectType::Opacity);
+        let base = clip.opacity as f64;
+        let level = effect.and_then(|e| e.get_float("value")).unwrap_or(1.0);
+        let keyframes: Vec<(f64, f64)> = effect
+            .and_then(|e| e.keyframes.get("value"))
+            .map(|keyframes| {
+                keyframes
+                    .iter()
+                    .filter_map(|kf| Some((kf.time_offset, kf.value.as_float()? * base)))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let value = base * level;
+        if keyframes.is_empty() && (value - 1.0).abs() < 1e-6 {
+            return;
+        }
+        let keyframes: Vec<(Frame, f64)> = keyframes
+            .into_iter()
+            .map(|(offset, value)| (self.keyframe_when(item, offset), value * 100.0))
+            .collect();
+        self.keyframed_filter(
+            "Opacity",
+            "opacity",
+            "motion",
+            "video",
+            ("opacity", "Opacity", 0.0, 100.0),
+            value * 100.0,
+            &keyframes,
+        );
+    }
+
+    /// Writes the clip's volume, or its volume keyframes, as one *Audio Levels*
+    /// filter in linear gain.
+    fn write_audio_levels(&mut self, item: &Item<'_>) {
+        let settings = &item.clip.audio;
+        if settings.volume_keyframes.is_empty() && settings.volume_db.abs() < 1e-3 {
+            return;
+        }
+        let keyframes: Vec<(Frame, f64)> = settings
+            .volume_keyframes
+            .iter()
+            .map(|kf| {
+                (
+                    self.keyframe_when(item, kf.time_offset),
+                    db_to_gain(kf.value_db),
+                )
+            })
+            .collect();
+        self.keyframed_filter(
+            "Audio Levels",
+            "audiolevels",
+            "audiolevels",
+            "audio",
+            ("level", "Level", 0.0, MAX_LEVEL_GAIN),
+            db_to_gain(settings.volume_db as f64),
+            &keyframes,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn keyframed_filter(
+        &mut self,
+        name: &str,
+        effect_id: &str,
+        category: &str,
+        media_type: &str,
+        (parameter_id, parameter_name, min, max): (&str, &str, f64, f64),
+        value: f64,
+        keyframes: &[(Frame, f64)],
+    ) {
+        self.line(6, "<filter>");
+        self.line(7, "<effect>");
+        self.element(8, "name", name);
+        self.element(8, "effectid", effect_id);
+        self.element(8, "effectcategory", category);
+        self.element(8, "effecttype", category);
+        self.element(8, "mediatype", media_type);
+        self.line(8, "<parameter>");
+        self.element(9, "parameterid", parameter_id);
+        self.element(9, "name", parameter_name);
+        self.element(9, "valuemin", format_number(min));
+        self.element(9, "valuemax", format_number(max));
+        self.element(9, "value", format_number(value));
+        for (when, value) in keyframes {
+            self.line(9, "<keyframe>");
+            self.element(10, "when", when);
+            self.element(10, "value", format_number(*value));
+            self.line(9, "</keyframe>");
+        }
+        self.line(8, "</parameter>");
+        self.line(7, "</effect>");
+        self.line(6, "</filter>");
+    }
+
+    /// The source frame a keyframe `offset_sec` into the clip's timeline span
+    /// falls on.
+    fn keyframe_when(&self, item: &Item<'_>, offset_sec: f64) -> Frame {
+        let speed = (item.clip.speed as f64).abs().max(f64::MIN_POSITIVE);
+        let frames = self
+            .clock
+            .seconds_to_nearest_frame(offset_sec.max(0.0) * speed);
+        if item.clip.reverse {
+            item.source_out - frames
+        } else {
+            item.source_in + frames
+        }
+    }
+
+    fn write_marker(&mut self, marker: &Marker) {
+        self.line(2, "<marker>");
+        self.element(3, "name", &marker.label);
+        self.element(3, "comment", "");
+        self.element(
+            3,
+            "in",
+            self.clock.seconds_to_nearest_frame(marker.time_sec),
+        );
+        self.element(3, "out", -1);
+        self.line(2, "</marker>");
+    }
+}
+
+// =============================================================================
+// Internal: formatting
+// =============================================================================
+
+fn xml_bool(value: bool) -> &'static str {
+    if value {
+        "TRUE"
+    } else {
+        "FALSE"
+    }
+}
+
+/// Writes a number without a trailing `.0` and with at most five decimals.
+fn format_number(value: f64) -> String {
+    let rounded = (value * 100_000.0).round() / 100_000.0;
+    if rounded.fract() == 0.0 {
+        format!("{}", rounded as i64)
+    } else {
+        format!("{rounded}")
+    }
+}
+
+/// The `pathurl` Final Cut Pro 7 and Premiere write: a file URL whose
+/// authority is `localhost`.
+fn pathurl(uri: &str) -> String {
+    let url = asset_src_url(uri);
+    match url.strip_prefix("file:///") {
+        Some(path) => format!("file://localhost/{path}"),
+        None => url,
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::assets::{AudioInfo, LicenseInfo, ProxyStatus, SourceMetadata};
+    use crate::core::effects::{Keyframe, ParamValue};
+    use crate::core::timeline::{
+        AudioKeyframe, ClipPlace, ClipRange, KeyframeInterpolation, SequenceFormat,
+    };
+
+    fn make_asset(id: &str, name: &str, uri: &str, kind: AssetKind) -> Asset {
+        Asset {
+            id: id.to_string(),
+            kind,
+            name: name.to_string(),
+            uri: uri.to_string(),
+            hash: "abc123".to_string(),
+            duration_sec: Some(60.0),
+            file_size: 1024,
+            imported_at: "2026-01-01T00:00:00Z".to_string(),
+            video: None,
+            audio: None,
+            license: LicenseInfo::default(),
+            tags: vec![],
+            thumbnail_url: None,
+            proxy_status: ProxyStatus::NotNeeded,
+            proxy_url: None,
+            bin_id: None,
+            relative_path: None,
+            workspace_managed: false,
+            missing: false,
+            quarantined_uri: None,
+            source: None,
+            image_sequence: None,
+        }
+    }
+
+    fn make_clip(id: &str, asset_id: &str, src_in: f64, tl_in: f64, duration: f64) -> Clip {
+        let mut clip = Clip::new(asset_id);
+        clip.id = id.to_string();
+        clip.range = ClipRange::new(src_in, src_in + duration);
+        clip.place = ClipPlace::new(tl_in, duration);
+        clip
+    }
+
+    fn make_sequence(fps_num: i32, fps_den: i32) -> Sequence {
+        Sequence::new(
+            "Cut",
+            SequenceFormat::new(1920, 1080, fps_num, fps_den, 48000),
+        )
+    }
+
+    fn assets_of(list: Vec<Asset>) -> HashMap<String, Asset> {
+        list.into_iter()
+            .map(|asset| (asset.id.clone(), asset))
+            .collect()
+    }
+
+    #[test]
+    fn should_write_an_xmeml_v4_document_with_an_ntsc_rate() {
+        let sequence = make_sequence(30000, 1001);
+
+        let export = export_xmeml(&sequence, &HashMap::new(), &HashMap::new()).expect("export");
+
+        assert!(export.xml.contains("<!DOCTYPE xmeml>"));
+        assert!(export.xml.contains(r#"<xmeml version="4">"#));
+        assert!(export.xml.contains("<timebase>30</timebase>"));
+        assert!(export.xml.contains("<ntsc>TRUE</ntsc>"));
+        assert!(export.xml.contains("<displayformat>DF</displayformat>"));
+        assert!(export.unsupported.is_empty(), "{:?}", export.unsupported);
+    }
+
+    #[test]
+    fn should_round_an_inexpressible_rate_and_report_it() {
+        let sequence = make_sequence(47, 2);
+
+        let export = export_xmeml(&sequence, &HashMap::new(), &HashMap::new()).expect("export");
+
+        assert!(export.xml.contains("<timebase>24</timebase>"));
+        assert!(export
+            .unsupported
+            .iter()
+            .any(|note| note.contains("XMEML cannot express")));
+    }
+
+    #[test]
+    fn should_write_frame_spans_and_a_shared_file_definition() {
+        let assets = assets_of(vec![make_asset(
+            "v1",
+            "shot.mp4",
+            "/media/shot 1.mp4",
+            AssetKind::Video,
+        )]);
+        let mut sequence = make_sequence(24, 1);
+        let mut track = Track::new("Video 1", TrackKind::Video);
+        track.clips.push(make_clip("c1", "v1", 2.0, 0.0, 4.0));
+        track.clips.push(make_clip("c2", "v1", 10.0, 4.0, 2.0));
+        sequence.tracks.push(track);
+
+        let export = export_xmeml(&sequence, &assets, &HashMap::new()).expect("export");
+
+        assert_eq!(export.clip_count, 2);
+        assert_eq!(export.track_count, 1);
+        assert!(export.xml.contains("<start>0</start>"));
+        assert!(export.xml.contains("<end>96</end>"));
+        assert!(export.xml.contains("<in>48</in>"));
+        assert!(export.xml.contains("<out>144</out>"));
+        assert!(export.xml.contains("<in>240</in>"));
+        assert!(export
+            .xml
+            .contains("<pathurl>file://localhost/media/shot%201.mp4</pathurl>"));
+        assert_eq!(export.xml.matches(r#"<file id="file-1">"#).count(), 1);
+        assert_eq!(export.xml.matches(r#"<file id="file-1"/>"#).count(), 1);
+    }
+
+    #[test]
+    fn should_write_the_recorded_timecode_and_reel_on_the_file() {
+        let shot = make_asset("v1", "A001.mov", "/media/A001.mov", AssetKind::Video).with_source(
+            SourceMetadata {
+                start_timecode: Some("01:00:00:00".to_string()),
+                start_sec: Some(3600.0),
+                reel_name: Some("A001".to_string()),
+                ..SourceMetadata::default()
+            },
+        );
+        let assets = assets_of(vec![shot]);
+        let mut sequence = make_sequence(24, 1);
+        let mut track = Track::new("Video 1", TrackKind::Video);
+        track.clips.push(make_clip("c1", "v1", 2.0, 0.0, 4.0));
+        sequence.tracks.push(track);
+
+        let export = export_xmeml(&sequence, &assets, &HashMap::new()).expect("export");
+
+        assert!(export.xml.contains("<string>01:00:00:00</string>"));
+        assert!(export.xml.contains("<frame>86400</frame>"));
+        assert!(export.xml.contains("<name>A001</name>"));
+        // In points stay counted from the first frame of the file.
+        assert!(export.xml.contains("<in>48</in>"));
+    }
+
+    #[test]
+    fn should_write_the_sound_of_a_video_clip_as_a_linked_audio_item() {
+        let mut shot = make_asset("v1", "shot.mov", "/media/shot.mov", AssetKind::Video);
+        shot.audio = Some(AudioInfo::default());
+        let assets = assets_of(vec![shot]);
+        let mut sequence = make_sequence(25, 1);
+        let mut track = Track::new("Video 1", TrackKind::Video);
+        track.clips.push(make_clip("c1", "v1", 0.0, 0.0, 2.0));
+        sequence.tracks.push(track);
+
+        let export = export_xmeml(&sequence, &assets, &HashMap::new()).expect("export");
+
+        assert_eq!(export.clip_count, 1);
+        assert_eq!(export.track_count, 2);
+        assert!(export.xml.contains(r#"<clipitem id="clipitem-1">"#));
+        assert!(export.xml.contains(r#"<clipitem id="clipitem-2">"#));
+        assert_eq!(
+            export
+                .xml
+                .matches("<linkclipref>clipitem-2</linkclipref>")
+                .count(),
+            2
+        );
+        assert!(export.xml.contains("<mediatype>audio</mediatype>"));
+    }
+
+    #[test]
+    fn should_write_speed_opacity_and_volume_keyframes_as_filters() {
+        let mut music = make_asset("a1", "music.wav", "/media/music.wav", AssetKind::Audio);
+        music.audio = Some(AudioInfo::default());
+        let assets = assets_of(vec![
+            make_asset("v1", "shot.mp4", "/media/shot.mp4", AssetKind::Video),
+            music,
+        ]);
+
+        let mut opacity = Effect::new(EffectType::Opacity);
+        opacity.keyframes.insert(
+            "value".to_string(),
+            vec![
+                Keyframe::new(0.0, ParamValue::Float(0.0)),
+                Keyframe::new(1.0, ParamValue::Float(1.0)),
+            ],
+        );
+        let effects: HashMap<String, Effect> = [(opacity.id.clone(), opacity.clone())].into();
+
+        let mut sequence = make_sequence(24, 1);
+        let mut video = Track::new("Video 1", TrackKind::Video);
+        let mut picture = make_clip("c1", "v1", 1.0, 0.0, 2.0);
+        picture.speed = 2.0;
+        picture.range = ClipRange::new(1.0, 5.0);
+        picture.effects.push(opacity.id.clone());
+        video.clips.push(picture);
+        let mut audio = Track::new("Audio 1", TrackKind::Audio);
+        let mut sound = make_clip("c2", "a1", 0.0, 0.0, 4.0);
+        sound.audio.volume_db = -6.0;
+        sound.audio.volume_keyframes = vec![
+            AudioKeyframe::new(0.0, -60.0, KeyframeInterpolation::Linear),
+            AudioKeyframe::new(1.0, 0.0, KeyframeInterpolation::Linear),
+        ];
+        audio.clips.push(sound);
+        sequence.tracks.push(video);
+        sequence.tracks.push(audio);
+
+        let export = export_xmeml(&sequence, &assets, &effects).expect("export");
+
+        assert!(export.xml.contains("<effectid>timeremap</effectid>"));
+        assert!(export.xml.contains("<value>200</value>"));
+        assert!(export.xml.contains("<effectid>opacity</effectid>"));
+        // One second into a clip at 2x is two seconds into the source.
+        assert!(export.xml.contains("<when>24</when>"));
+        assert!(export.xml.contains("<when>72</when>"));
+        assert!(export.xml.contains("<effectid>audiolevels</effectid>"));
+        assert!(export.xml.contains("<when>0</when>"));
+        assert!(export.unsupported.is_empty(), "{:?}", export.unsupported);
+    }
+
+    #[test]
+    fn should_report_what_xmeml_cannot_carry() {
+        let assets = assets_of(vec![make_asset(
+            "v1",
+            "shot.mp4",
+            "/media/shot.mp4",
+            AssetKind::Video,
+        )]);
+        let blur = Effect::new(EffectType::GaussianBlur);
+        let effects: HashMap<String, Effect> = [(blur.id.clone(), blur.clone())].into();
+
+        let mut sequence = make_sequence(24, 1);
+        let mut video = Track::new("Video 1", TrackKind::Video);
+        let mut clip = make_clip("c1", "v1", 0.0, 0.0, 2.0);
+        clip.effects.push(blur.id.clone());
+        clip.freeze_frame = true;
+        video.clips.push(clip);
+        video.clips.push(make_clip("c2", "missing", 0.0, 2.0, 2.0));
+        sequence.tracks.push(video);
+        sequence
+            .tracks
+            .push(Track::new("Captions", TrackKind::Caption));
+
+        let export = export_xmeml(&sequence, &assets, &effects).expect("export");
+
+        assert_eq!(export.clip_count, 1);
+        assert!(export
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("'missing'")));
+        assert!(export
+            .unsupported
+            .iter()
+            .any(|note| note.contains("freeze frame")));
+        assert!(export
+            .unsupported
+            .iter()
+            .any(|note| note.contains("effects or transitions")));
+        assert!(export
+            .unsupported
+            .iter()
+            .any(|note| note.contains("caption track 'Captions'")));
+    }
+
+    #[test]
+    fn should_convert_between_decibels_and_level_gain() {
+        assert_eq!(db_to_gain(0.0), 1.0);
+        assert_eq!(db_to_gain(-80.0), 0.0);
+        assert_eq!(db_to_gain(20.0), MAX_LEVEL_GAIN);
+        assert!((gain_to_db(db_to_gain(-6.0)) + 6.0).abs() < 1e-9);
+        assert_eq!(gain_to_db(0.0), -60.0);
+    }
+}
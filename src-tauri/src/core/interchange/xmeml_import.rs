@@ -0,0 +1,1453 @@
+//! XMEML Import
+//!
+//! Reads a Final Cut Pro 7 XML document — what [`super::xmeml::export_xmeml`]
+//! writes, and what Premiere Pro and DaVinci Resolve export as "Final Cut Pro
+//! XML" — and turns its first sequence into the plan steps that rebuild it as
+//! a new OpenReelio sequence. Like [`super::fcpxml_import`], nothing here
+//! mutates state: the whole import, the sequence included, is one atomic,
+//! undoable plan.
+//!
+//! ## What an import can carry
+//!
+//! - The sequence's `<rate>` and frame size.
+//! - Every video and audio `<track>`, each becoming a track of its kind.
+//!   Video tracks keep their stacking: XMEML lists them bottom-up.
+//! - `clipitem`s, with their media, source in/out, timeline start/end and
+//!   enabled state.
+//! - A *Time Remap* filter at constant speed, as the clip's speed and reverse.
+//! - *Opacity* and *Audio Levels* filters, static or keyframed.
+//! - Sequence and clip `marker`s, as sequence markers.
+//! - A `transitionitem` between two clips, as a transition on the outgoing
+//!   clip.
+//!
+//! Sound lives only on audio tracks in XMEML, so a clip item on a video track
+//! is imported muted when its media has sound: the sound arrives through the
+//! audio clip items linked to it, and playing both would double it.
+//!
+//! Nested sequences, generators, other filters, variable speed and one-sided
+//! transitions are named in [`XmemlImportPlan::unsupported`].
+//!
+//! ## Time
+//!
+//! `start` and `end` count sequence frames; `in`, `out` and a keyframe's
+//! `when` count the clip's own frames, `when` from the start of the media. A
+//! clip at a transition has a `start` or `end` of `-1`, resolved from the
+//! transition's span and alignment.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde_json::{json, Value as JsonValue};
+
+use crate::core::ai::MAX_PLAN_STEPS;
+use crate::core::assets::Asset;
+use crate::core::timeline::TimelineClock;
+use crate::core::Ratio;
+
+use super::fcpxml_import::transition_effect_type;
+use super::import_plan::{step_reference, ImportPlanWriter, ResolvedMedia, SEQUENCE_STEP};
+use super::models::file_url_to_path;
+use super::otio_import::OtioAssetImport;
+use super::xmeml::{gain_to_db, XmemlRate};
+use super::xml_tree::{parse_xml, XmlElement};
+
+// =============================================================================
+// Public API: parsing
+// =============================================================================
+
+/// A media `<file>` the document defines.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmemlFile {
+    pub id: String,
+    pub name: String,
+    /// The `pathurl`, if the file states one.
+    pub pathurl: Option<String>,
+    /// Whether the file's `<media>` has sound; `None` when it does not say.
+    pub has_audio: Option<bool>,
+}
+
+/// A marker, in sequence time.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmemlMarker {
+    pub label: String,
+    pub time_sec: f64,
+}
+
+/// A keyframe, in seconds from the start of the clip on the timeline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmemlKeyframe {
+    pub offset_sec: f64,
+    /// Opacity from 0 to 1, or volume in dB.
+    pub value: f64,
+}
+
+/// A static level and the keyframes that animate it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmemlLevel {
+    /// Opacity from 0 to 1, or volume in dB.
+    pub value: f64,
+    pub keyframes: Vec<XmemlKeyframe>,
+}
+
+/// A clip item resolved to seconds.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmemlClip {
+    /// Position among the document's clips, for stable step ids.
+    pub index: usize,
+    pub name: String,
+    /// Id of the `<file>` the clip reads.
+    pub file_id: String,
+    /// Whether the clip sits on an audio track.
+    pub audio: bool,
+    /// The track's position among the tracks of its kind, in document order
+    /// (bottom-up for video).
+    pub track_index: usize,
+    pub enabled: bool,
+    pub timeline_start_sec: f64,
+    pub duration_sec: f64,
+    pub source_in_sec: f64,
+    /// Constant speed from a *Time Remap* filter; `1.0` when there is none.
+    pub speed: f64,
+    pub reverse: bool,
+    pub opacity: Option<XmemlLevel>,
+    pub volume: Option<XmemlLevel>,
+    /// Markers the clip carries, in sequence time.
+    pub markers: Vec<XmemlMarker>,
+}
+
+/// A transition between two clips on one track.
+#[derive(Clone, Debug, PartialEq)]
+pub struct XmemlTransition {
+    pub name: String,
+    pub audio: bool,
+    pub track_index: usize,
+    /// The cut the transition is centred on, in sequence seconds.
+    pub cut_sec: f64,
+    pub duration_sec: f64,
+}
+
+/// An XMEML document's first sequence, read into seconds.
+#[derive(Clone, Debug, Default)]
+pub struct ParsedXmeml {
+    /// The document's `version` attribute.
+    pub version: Option<String>,
+    pub sequence_name: Option<String>,
+    /// The sequence's frame rate.
+    pub fps: Option<Ratio>,
+    /// The sequence's frame size, if stated.
+    pub canvas: Option<(u32, u32)>,
+    /// Number of video and audio tracks.
+    pub video_tracks: usize,
+    pub audio_tracks: usize,
+    /// Media files, keyed by id.
+    pub files: HashMap<String, XmemlFile>,
+    /// Clips in document order.
+    pub clips: Vec<XmemlClip>,
+    pub transitions: Vec<XmemlTransition>,
+    /// Sequence markers.
+    pub markers: Vec<XmemlMarker>,
+    /// Structural notes from reading the document.
+    pub warnings: Vec<String>,
+    /// Detail the document carried that the import does not restore.
+    pub unsupported: Vec<String>,
+}
+
+/// Parses an XMEML document and resolves its first sequence to seconds.
+///
+/// # Errors
+///
+/// Refuses a document that is not XML, whose root is not `<xmeml>`, or that
+/// carries no sequence with a usable `<rate>`.
+pub fn parse_xmeml(content: &str) -> Result<ParsedXmeml, String> {
+    let root = parse_xml(content).map_err(|error| format!("this is not XMEML: {error}"))?;
+    if root.name != "xmeml" {
+        return Err(format!(
+            "this is not a Final Cut Pro 7 XML document: its root element is <{}>, not <xmeml>",
+            root.name
+        ));
+    }
+
+    let mut sequences = Vec::new();
+    root.descendants("sequence", &mut sequences);
+    let Some(sequence) = sequences.first() else {
+        return Err(
+            "this XMEML carries no sequence; export a sequence (a timeline) from the editor, not \
+             only clips or a bin"
+                .to_string(),
+        );
+    };
+
+    let mut parsed = ParsedXmeml {
+        version: root.attr("version").map(str::to_string),
+        sequence_name: child_text(sequence, "name")
+            .filter(|name| !name.is_empty())
+            .map(str::to_string),
+        ..ParsedXmeml::default()
+    };
+    if sequences.len() > 1 {
+        parsed.warnings.push(format!(
+            "the document holds {} sequences; only the first, '{}', was imported",
+            sequences.len(),
+            parsed.sequence_name.clone().unwrap_or_default()
+        ));
+    }
+
+    let rate = sequence
+        .child("rate")
+        .and_then(read_rate)
+        .ok_or_else(|| "the sequence has no usable <rate>".to_string())?;
+    parsed.fps = Some(rate.to_ratio());
+
+    let media = sequence.child("media");
+    let video = media.and_then(|media| media.child("video"));
+    let audio = media.and_then(|media| media.child("audio"));
+
+    parsed.canvas = video
+        .and_then(|video| video.child("format"))
+        .and_then(|format| format.child("samplecharacteristics"))
+        .and_then(|sample| {
+            let width = child_text(sample, "width")?.parse().ok()?;
+            let height = child_text(sample, "height")?.parse().ok()?;
+            Some((width, height))
+        });
+
+    let mut files = Vec::new();
+    sequence.descendants("file", &mut files);
+    for file in files {
+        read_file(file, &mut parsed);
+    }
+
+    let mut reader = TrackReader {
+        rate,
+        parsed: &mut parsed,
+    };
+    for (kind_is_audio, container) in [(false, video), (true, audio)] {
+        let Some(container) = container else {
+            continue;
+        };
+        for (track_index, track) in container.children_named("track").enumerate() {
+            reader.read_track(track, kind_is_audio, track_index);
+        }
+    }
+    parsed.video_tracks = video.map_or(0, |v| v.children_named("track").count());
+    parsed.audio_tracks = audio.map_or(0, |a| a.children_named("track").count());
+
+    let seconds_per_frame = 1.0 / rate.to_ratio().as_f64();
+    for marker in sequence.children_named("marker") {
+        if let Some(frame) = child_frames(marker, "in") {
+            parsed.markers.push(XmemlMarker {
+                label: marker_label(marker),
+                time_sec: frame as f64 * seconds_per_frame,
+            });
+        }
+    }
+
+    Ok(parsed)
+}
+
+// =============================================================================
+// Public API: plan building
+// =============================================================================
+
+/// The plan an XMEML document proposes.
+#[derive(Clone, Debug, Default)]
+pub struct XmemlImportPlan {
+    /// `EditPlan.steps`-shaped JSON, in dependency order. The first step
+    /// creates the sequence.
+    pub steps: Vec<JsonValue>,
+    /// Structural notes: unresolved media, overlapping clips, transitions that
+    /// could not be attached.
+    pub warnings: Vec<String>,
+    /// Editorial detail the file carried that OpenReelio does not import.
+    pub unsupported: Vec<String>,
+    /// Media the plan imports before it can place clips, in step order.
+    pub asset_imports: Vec<OtioAssetImport>,
+    /// Number of clips placed.
+    pub placed_clips: usize,
+    /// Frame size the sequence is created at.
+    pub canvas: (u32, u32),
+}
+
+/// The project an XMEML document is being imported into.
+pub struct XmemlImportContext<'a> {
+    /// Name of the sequence the plan creates.
+    pub sequence_name: &'a str,
+    /// Frame rate the sequence is created with; every position is snapped to
+    /// its grid.
+    pub sequence_fps: Ratio,
+    /// Assets already in the project, keyed by id.
+    pub assets: &'a HashMap<String, Asset>,
+    /// The project directory. Media outside it is refused unless
+    /// [`Self::allow_external_media`] is set.
+    pub project_root: &'a Path,
+    /// Lets the document name media outside the project directory.
+    pub allow_external_media: bool,
+}
+
+/// Converts a parsed XMEML sequence into plan steps that build it as a new
+/// sequence.
+///
+/// # Errors
+///
+/// Refuses an unusable frame rate, and a plan that would exceed
+/// [`MAX_PLAN_STEPS`] — a chunked import is no longer atomic.
+pub fn xmeml_to_plan_steps(
+    xmeml: &ParsedXmeml,
+    context: &XmemlImportContext<'_>,
+) -> Result<XmemlImportPlan, String> {
+    let fps_value = context.sequence_fps.as_f64();
+    if !fps_value.is_finite() || fps_value <= 0.0 {
+        return Err(format!(
+            "{}/{} is not a frame rate a sequence can be created at",
+            context.sequence_fps.num, context.sequence_fps.den
+        ));
+    }
+
+    let mut builder = PlanBuilder::new(xmeml, context);
+    builder.out.warnings.extend(xmeml.warnings.iter().cloned());
+    builder
+        .out
+        .unsupported
+        .extend(xmeml.unsupported.iter().cloned());
+
+    builder.create_sequence();
+    builder.create_tracks();
+
+    let mut clips: Vec<&XmemlClip> = xmeml.clips.iter().collect();
+    clips.sort_by(|a, b| {
+        a.timeline_start_sec
+            .total_cmp(&b.timeline_start_sec)
+            .then(a.index.cmp(&b.index))
+    });
+    for clip in clips {
+        builder.add_clip(clip);
+    }
+    for (index, transition) in xmeml.transitions.iter().enumerate() {
+        builder.add_transition(index, transition);
+    }
+    for (index, marker) in xmeml.markers.iter().enumerate() {
+        builder.add_marker(&format!("marker_{index}"), marker);
+    }
+
+    let plan = builder.finish();
+    if plan.steps.len() > MAX_PLAN_STEPS {
+        return Err(format!(
+            "This XMEML needs {} plan steps, which exceeds the maximum of {MAX_PLAN_STEPS} a \
+             single plan may carry. Splitting it into several plans would give up atomicity, so \
+             import a shorter sequence instead.",
+            plan.steps.len()
+        ));
+    }
+
+    Ok(plan)
+}
+
+// =============================================================================
+// Internal: reading
+// =============================================================================
+
+/// A track child in document order, before `-1` positions are resolved.
+enum TrackItem<'a> {
+    Clip(&'a XmlElement),
+    Transition {
+        name: String,
+        start: i64,
+        end: i64,
+        alignment: String,
+    },
+}
+
+struct TrackReader<'a> {
+    rate: XmemlRate,
+    parsed: &'a mut ParsedXmeml,
+}
+
+impl TrackReader<'_> {
+    fn read_track(&mut self, track: &XmlElement, audio: bool, track_index: usize) {
+        let label = format!("{}{}", if audio { "A" } else { "V" }, track_index + 1);
+        if child_text(track, "enabled") == Some("FALSE") {
+            self.parsed.unsupported.push(format!(
+                "track {label} is disabled in the file; it was imported enabled"
+            ));
+        }
+
+        let mut items = Vec::new();
+        for child in &track.children {
+            match child.name.as_str() {
+                "clipitem" => items.push(TrackItem::Clip(child)),
+                "transitionitem" => items.push(TrackItem::Transition {
+                    name: child
+                        .child("effect")
+                        .and_then(|effect| child_text(effect, "name"))
+                        .unwrap_or("Transition")
+                        .to_string(),
+                    start: child_frames(child, "start").unwrap_or(-1),
+                    end: child_frames(child, "end").unwrap_or(-1),
+                    alignment: child_text(child, "alignment")
+                        .unwrap_or("center")
+                        .to_lowercase(),
+                }),
+                "generatoritem" => self.parsed.unsupported.push(format!(
+                    "generator '{}' on track {label} has no OpenReelio equivalent and was not \
+                     imported",
+                    child_text(child, "name").unwrap_or_default()
+                )),
+                _ => {}
+            }
+        }
+
+        for (position, item) in items.iter().enumerate() {
+            match item {
+                TrackItem::Clip(element) => {
+                    let start = self.resolve_edge(&items, position, "start");
+                    let end = self.resolve_edge(&items, position, "end");
+                    self.read_clip(element, audio, track_index, &label, start, end);
+                }
+                TrackItem::Transition {
+                    name,
+                    start,
+                    end,
+                    alignment,
+                } => self.read_transition(name, *start, *end, alignment, audio, track_index),
+            }
+        }
+    }
+
+    /// A clip's `start` or `end`, or — when it is `-1` — the cut of the
+    /// transition beside it.
+    fn resolve_edge(&self, items: &[TrackItem<'_>], position: usize, edge: &str) -> Option<i64> {
+        let TrackItem::Clip(element) = items[position] else {
+            return None;
+        };
+        let frames = child_frames(element, edge)?;
+        if frames >= 0 {
+            return Some(frames);
+        }
+        let neighbour = if edge == "start" {
+            position.checked_sub(1).and_then(|p| items.get(p))
+        } else {
+            items.get(position + 1)
+        };
+        match neighbour {
+            Some(TrackItem::Transition {
+                start,
+                end,
+                alignment,
+                ..
+            }) if *start >= 0 && *end >= *start => Some(transition_cut(*start, *end, alignment)),
+            _ => None,
+        }
+    }
+
+    fn read_clip(
+        &mut self,
+        element: &XmlElement,
+        audio: bool,
+        track_index: usize,
+        label: &str,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) {
+        let name = child_text(element, "name").unwrap_or_default().to_string();
+        if element.child("sequence").is_some() {
+            self.parsed.unsupported.push(format!(
+                "clip '{name}' on track {label} is a nested sequence, which is not imported"
+            ));
+            return;
+        }
+        let Some(file_id) = element.child("file").and_then(|file| file.attr("id")) else {
+            self.parsed.warnings.push(format!(
+                "clip '{name}' on track {label} names no media file and was not imported"
+            ));
+            return;
+        };
+        let (Some(start), Some(end)) = (start, end) else {
+            self.parsed.warnings.push(format!(
+                "clip '{name}' on track {label} has no resolvable start or end and was not \
+                 imported"
+            ));
+            return;
+        };
+
+        // `in`, `out` and keyframes count the clip's frames, which may run at
+        // a rate of their own.
+        let sequence_fps = self.rate.to_ratio().as_f64();
+        let clip_fps = element
+            .child("rate")
+            .and_then(read_rate)
+            .unwrap_or(self.rate)
+            .to_ratio()
+            .as_f64();
+        let source_in = child_frames(element, "in").unwrap_or(0).max(0);
+        let source_out = child_frames(element, "out").unwrap_or(source_in);
+
+        let mut clip = XmemlClip {
+            index: self.parsed.clips.len(),
+            name: name.clone(),
+            file_id: file_id.to_string(),
+            audio,
+            track_index,
+            enabled: child_text(element, "enabled") != Some("FALSE"),
+            timeline_start_sec: start as f64 / sequence_fps,
+            duration_sec: (end - start) as f64 / sequence_fps,
+            source_in_sec: source_in as f64 / clip_fps,
+            speed: 1.0,
+            reverse: false,
+            opacity: None,
+            volume: None,
+            markers: Vec::new(),
+        };
+
+        for filter in element.children_named("filter") {
+            let Some(effect) = filter.child("effect") else {
+                continue;
+            };
+            let effect_id = child_text(effect, "effectid").unwrap_or_default();
+            match effect_id {
+                "timeremap" => {
+                    if parameter(effect, "variablespeed")
+                        .and_then(|p| child_text(p, "value"))
+                        .is_some_and(|value| value != "0" && value != "FALSE")
+                    {
+                        self.parsed.unsupported.push(format!(
+                            "clip '{name}' on track {label} has variable speed; it was imported \
+                             at its average speed"
+                        ));
+                    }
+                    clip.reverse = parameter(effect, "reverse")
+                        .and_then(|p| child_text(p, "value"))
+                        == Some("TRUE");
+                }
+                "opacity" => {
+                    clip.opacity = parameter(effect, "opacity")
+                        .and_then(|p| read_level(p, |value| (value / 100.0).clamp(0.0, 1.0)));
+                }
+                "audiolevels" => {
+                    clip.volume =
+                        parameter(effect, "level").and_then(|p| read_level(p, gain_to_db));
+                }
+                _ => {
+                    let filter_name = child_text(effect, "name").unwrap_or(effect_id);
+                    self.parsed.unsupported.push(format!(
+                        "clip '{name}' on track {label} carries the '{filter_name}' filter, which \
+                         is not imported"
+                    ));
+                }
+            }
+        }
+
+        // The speed is what the clip's spans say, which a *Time Remap* filter
+        // only restates; it holds for clips that carry no filter too.
+        if end > start && source_out > source_in {
+            let speed = ((source_out - source_in) as f64 / clip_fps) / clip.duration_sec;
+            if (speed - 1.0).abs() > 1e-3 {
+                clip.speed = speed;
+            }
+        }
+
+        // Keyframes count source frames; convert to seconds into the clip.
+        let (speed, reverse) = (clip.speed, clip.reverse);
+        let to_offset = |when: f64| {
+            let frames = if reverse {
+                source_out as f64 - when
+            } else {
+                when - source_in as f64
+            };
+            (frames / clip_fps / speed).max(0.0)
+        };
+        for level in [&mut clip.opacity, &mut clip.volume].into_iter().flatten() {
+            for keyframe in &mut level.keyframes {
+                keyframe.offset_sec = to_offset(keyframe.offset_sec);
+            }
+        }
+
+        for marker in element.children_named("marker") {
+            if let Some(frame) = child_frames(marker, "in") {
+                let into_clip = to_offset(frame as f64);
+                clip.markers.push(XmemlMarker {
+                    label: marker_label(marker),
+                    time_sec: clip.timeline_start_sec + into_clip,
+                });
+            }
+        }
+
+        self.parsed.clips.push(clip);
+    }
+
+    fn read_transition(
+        &mut self,
+        name: &str,
+        start: i64,
+        end: i64,
+        alignment: &str,
+        audio: bool,
+        track_index: usize,
+    ) {
+        if start < 0 || end <= start {
+            return;
+        }
+        if alignment.ends_with("black") {
+            self.parsed.unsupported.push(format!(
+                "transition '{name}' fades {} black, which is not imported",
+                if alignment.starts_with("start") {
+                    "from"
+                } else {
+                    "to"
+                }
+            ));
+            return;
+        }
+        let fps = self.rate.to_ratio().as_f64();
+        self.parsed.transitions.push(XmemlTransition {
+            name: name.to_string(),
+            audio,
+            track_index,
+            cut_sec: transition_cut(start, end, alignment) as f64 / fps,
+            duration_sec: (end - start) as f64 / fps,
+        });
+    }
+}
+
+/// Records a `<file>` the first time its full definition appears.
+fn read_file(file: &XmlElement, parsed: &mut ParsedXmeml) {
+    let Some(id) = file.attr("id") else {
+        return;
+    };
+    // Later occurrences are `<file id="…"/>` references to the definition.
+    if file.children.is_empty() || parsed.files.contains_key(id) {
+        return;
+    }
+    let media = file.child("media");
+    parsed.files.insert(
+        id.to_string(),
+        XmemlFile {
+            id: id.to_string(),
+            name: child_text(file, "name").unwrap_or(id).to_string(),
+            pathurl: child_text(file, "pathurl")
+                .filter(|url| !url.is_empty())
+                .map(str::to_string),
+            has_audio: media.map(|media| media.child("audio").is_some()),
+        },
+    );
+}
+
+/// A static level and its keyframes, each converted by `convert`. Keyframe
+/// offsets are still source frames here.
+fn read_level(parameter: &XmlElement, convert: impl Fn(f64) -> f64) -> Option<XmemlLevel> {
+    let keyframes: Vec<XmemlKeyframe> = parameter
+        .children_named("keyframe")
+        .filter_map(|keyframe| {
+            let when = child_text(keyframe, "when")?.parse::<f64>().ok()?;
+            let value = child_text(keyframe, "value")?.parse::<f64>().ok()?;
+            (when.is_finite() && value.is_finite()).then(|| XmemlKeyframe {
+                offset_sec: when,
+                value: convert(value),
+            })
+        })
+        .collect();
+    let value = child_text(parameter, "value")
+        .and_then(|value| value.parse::<f64>().ok())
+        .filter(|value| value.is_finite())
+        .map(&convert)
+        .or_else(|| keyframes.first().map(|keyframe| keyframe.value))?;
+    Some(XmemlLevel { value, keyframes })
+}
+
+fn read_rate(rate: &XmlElement) -> Option<XmemlRate> {
+    let timebase = child_text(rate, "timebase")?.parse::<u32>().ok()?;
+    (timebase > 0).then(|| XmemlRate {
+        timebase,
+        ntsc: child_text(rate, "ntsc") == Some("TRUE"),
+    })
+}
+
+/// The cut a transition from `start` to `end` frames is aligned on.
+fn transition_cut(start: i64, end: i64, alignment: &str) -> i64 {
+    match alignment {
+        "start" | "start-black" => start,
+        "end" | "end-black" => end,
+        _ => start + (end - start) / 2,
+    }
+}
+
+fn parameter<'a>(effect: &'a XmlElement, id: &str) -> Option<&'a XmlElement> {
+    effect
+        .children_named("parameter")
+        .find(|p| child_text(p, "parameterid") == Some(id))
+}
+
+fn child_text<'a>(element: &'a XmlElement, name: &str) -> Option<&'a str> {
+    element.child(name).map(|child| child.text.as_str())
+}
+
+fn child_frames(element: &XmlElement, name: &str) -> Option<i64> {
+    child_text(element, name)?.parse().ok()
+}
+
+/// A marker's name, else its comment.
+fn marker_label(marker: &XmlElement) -> String {
+    child_text(marker, "name")
+        .filter(|name| !name.is_empty())
+        .or_else(|| child_text(marker, "comment"))
+        .unwrap_or_default()
+        .to_string()
+}
+
+// =============================================================================
+// Internal: plan building
+// =============================================================================
+
+/// A track the plan creates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct TrackKey {
+    audio: bool,
+    index: usize,
+}
+
+/// A clip already emitted on a track.
+struct PlacedClip {
+    step_id: String,
+    timeline_start_sec: f64,
+    timeline_end_sec: f64,
+}
+
+struct PlanBuilder<'a> {
+    xmeml: &'a ParsedXmeml,
+    context: &'a XmemlImportContext<'a>,
+    clock: TimelineClock,
+    plan: XmemlImportPlan,
+    out: ImportPlanWriter<'a>,
+    /// Step id of each track the plan created.
+    track_steps: HashMap<TrackKey, String>,
+    /// Clips placed on each track so far, in timeline order.
+    placed: HashMap<TrackKey, Vec<PlacedClip>>,
+    /// Resolution of each file, so each is relinked — and reported — once.
+    resolved: HashMap<String, Option<ResolvedMedia>>,
+}
+
+impl<'a> PlanBuilder<'a> {
+    fn new(xmeml: &'a ParsedXmeml, context: &'a XmemlImportContext<'a>) -> Self {
+        Self {
+            xmeml,
+            context,
+            clock: TimelineClock::new(context.sequence_fps.clone()),
+            plan: XmemlImportPlan::default(),
+            out: ImportPlanWriter::new(
+                "XMEML",
+                context.assets,
+                context.project_root,
+                context.allow_external_media,
+            ),
+            track_steps: HashMap::new(),
+            placed: HashMap::new(),
+            resolved: HashMap::new(),
+        }
+    }
+
+    fn finish(self) -> XmemlImportPlan {
+        let ImportPlanWriter {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+            ..
+        } = self.out;
+        XmemlImportPlan {
+            steps,
+            warnings,
+            unsupported,
+            asset_imports,
+            ..self.plan
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Sequence and tracks
+    // -------------------------------------------------------------------------
+
+    fn create_sequence(&mut self) {
+        let canvas = self.out.preset_canvas(self.xmeml.canvas);
+        self.plan.canvas = canvas;
+        self.out.create_sequence(
+            self.context.sequence_name,
+            &self.context.sequence_fps,
+            Some(canvas),
+        );
+    }
+
+    /// Creates every track the document lists. Track 0 is the topmost track,
+    /// so video tracks are created from the top of XMEML's bottom-up list.
+    fn create_tracks(&mut self) {
+        let video = (0..self.xmeml.video_tracks).rev().map(|index| TrackKey {
+            audio: false,
+            index,
+        });
+        let audio = (0..self.xmeml.audio_tracks).map(|index| TrackKey { audio: true, index });
+        let keys: Vec<TrackKey> = video.chain(audio).collect();
+
+        for key in keys {
+            let (kind, name) = if key.audio {
+                ("audio", format!("A{}", key.index + 1))
+            } else {
+                ("video", format!("V{}", key.index + 1))
+            };
+            let step_id = format!("track_{}", self.track_steps.len());
+            self.out.push_step(
+                &step_id,
+                "CreateTrack",
+                json!({
+                    "sequenceId": step_reference(SEQUENCE_STEP),
+                    "kind": kind,
+                    "name": name,
+                }),
+                &[SEQUENCE_STEP],
+            );
+            self.track_steps.insert(key, step_id);
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // Clips
+    // -------------------------------------------------------------------------
+
+    fn add_clip(&mut self, clip: &XmemlClip) {
+        let key = TrackKey {
+            audio: clip.audio,
+            index: clip.track_index,
+        };
+        let Some(track_step) = self.track_steps.get(&key).cloned() else {
+            return;
+        };
+
+        let start_frame = self.clock.seconds_to_nearest_frame(clip.timeline_start_sec);
+        let end_frame = self
+            .clock
+            .seconds_to_nearest_frame(clip.timeline_start_sec + clip.duration_sec);
+        if end_frame <= start_frame {
+            self.out.warnings.push(format!(
+                "clip '{}' is shorter than one frame at the sequence's rate and was not imported",
+                clip.name
+            ));
+            return;
+        }
+        let timeline_start = self.clock.frame_to_seconds(start_frame);
+        let timeline_end = self.clock.frame_to_seconds(end_frame);
+
+        if let Some(previous) = self.placed.get(&key).and_then(|placed| {
+            placed
+                .iter()
+                .find(|other| other.timeline_end_sec > timeline_start + 1e-9)
+        }) {
+            self.out.warnings.push(format!(
+                "clip '{}' overlaps an earlier clip on its track (which ends at {:.3}s) and was \
+                 not imported",
+                clip.name, previous.timeline_end_sec
+            ));
+            return;
+        }
+
+        let Some(resolved) = self.resolve_media(&clip.file_id, &clip.name) else {
+            return;
+        };
+
+        let step_id = format!("clip_{}", clip.index);
+        let mut depends_on = vec![track_step.clone()];
+        let asset_value = resolved.asset_value(&mut depends_on);
+        let depends_refs: Vec<&str> = depends_on.iter().map(String::as_str).collect();
+
+        // The speed step derives the timeline span from the source span, so
+        // the source span is sized to land on the snapped slot.
+        let source_out = clip.source_in_sec + (timeline_end - timeline_start) * clip.speed;
+        self.out.push_step(
+            &step_id,
+            "InsertClip",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "trackId": step_reference(&track_step),
+                "assetId": asset_value,
+                "timelineStart": timeline_start,
+                "sourceIn": clip.source_in_sec,
+                "sourceOut": source_out,
+            }),
+            &depends_refs,
+        );
+
+        let clip_payload = |extra: JsonValue| {
+            let mut payload = json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "trackId": step_reference(&track_step),
+                "clipId": step_reference(&step_id),
+            });
+            if let (Some(map), JsonValue::Object(extra)) = (payload.as_object_mut(), extra) {
+                map.extend(extra);
+            }
+            payload
+        };
+
+        if (clip.speed - 1.0).abs() > 1e-3 || clip.reverse {
+            let payload = clip_payload(json!({ "speed": clip.speed, "reverse": clip.reverse }));
+            self.out.push_step(
+                &format!("speed_{}", clip.index),
+                "SetClipSpeed",
+                payload,
+                &[&step_id],
+            );
+        }
+
+        let file_has_audio = self
+            .xmeml
+            .files
+            .get(&clip.file_id)
+            .and_then(|file| file.has_audio);
+        if !clip.audio && file_has_audio != Some(false) {
+            let payload = clip_payload(json!({ "muted": true }));
+            self.out.push_step(
+                &format!("mute_{}", clip.index),
+                "SetClipMute",
+                payload,
+                &[&step_id],
+            );
+        }
+
+        if !clip.enabled {
+            let payload = clip_payload(json!({ "enabled": false }));
+            self.out.push_step(
+                &format!("disable_{}", clip.index),
+                "SetClipEnabled",
+                payload,
+                &[&step_id],
+            );
+        }
+
+        if let Some(opacity) = clip.opacity.as_ref().filter(|_| !clip.audio) {
+            if !opacity.keyframes.is_empty() {
+                let keyframes: Vec<JsonValue> = opacity
+                    .keyframes
+                    .iter()
+                    .map(|keyframe| {
+                        json!({
+                            "timeOffset": keyframe.offset_sec,
+                            "value": keyframe.value,
+                            "easing": "linear",
+                        })
+                    })
+                    .collect();
+                let payload = clip_payload(json!({
+                    "effectType": "opacity",
+                    "params": { "value": opacity.value },
+                    "keyframes": { "value": keyframes },
+                }));
+                self.out.push_step(
+                    &format!("opacity_{}", clip.index),
+                    "AddEffect",
+                    payload,
+                    &[&step_id],
+                );
+            } else if (opacity.value - 1.0).abs() > 1e-6 {
+                let payload = clip_payload(json!({ "opacity": opacity.value }));
+                self.out.push_step(
+                    &format!("opacity_{}", clip.index),
+                    "SetClipOpacity",
+                    payload,
+                    &[&step_id],
+                );
+            }
+        }
+
+        if let Some(volume) = clip.volume.as_ref().filter(|_| clip.audio) {
+            if volume.keyframes.is_empty() {
+                if volume.value.abs() > 1e-3 {
+                    let payload = clip_payload(json!({ "volumeDb": volume.value }));
+                    self.out.push_step(
+                        &format!("volume_{}", clip.index),
+                        "SetClipAudio",
+                        payload,
+                        &[&step_id],
+                    );
+                }
+            } else {
+                for (keyframe_index, keyframe) in volume.keyframes.iter().enumerate() {
+                    let payload = clip_payload(json!({
+                        "timeOffset": keyframe.offset_sec,
+                        "valueDb": keyframe.value,
+                    }));
+                    self.out.push_step(
+                        &format!("volume_{}_{keyframe_index}", clip.index),
+                        "AddAudioKeyframe",
+                        payload,
+                        &[&step_id],
+                    );
+                }
+            }
+        }
+
+        for (marker_index, marker) in clip.markers.iter().enumerate() {
+            self.add_marker(&format!("marker_{}_{marker_index}", clip.index), marker);
+        }
+
+        self.placed.entry(key).or_default().push(PlacedClip {
+            step_id,
+            timeline_start_sec: timeline_start,
+            timeline_end_sec: timeline_end,
+        });
+        self.plan.placed_clips += 1;
+    }
+
+    fn add_marker(&mut self, step_id: &str, marker: &XmemlMarker) {
+        if marker.time_sec < 0.0 {
+            self.out.warnings.push(format!(
+                "marker '{}' falls before the start of the sequence and was not imported",
+                marker.label
+            ));
+            return;
+        }
+        self.out.push_step(
+            step_id,
+            "AddMarker",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "timeSec": self.clock.snap_seconds_to_frame(marker.time_sec),
+                "label": marker.label,
+            }),
+            &[SEQUENCE_STEP],
+        );
+    }
+
+    // -------------------------------------------------------------------------
+    // Relinking
+    // -------------------------------------------------------------------------
+
+    /// Finds the project asset a file names, importing it if needed.
+    fn resolve_media(&mut self, file_id: &str, clip_name: &str) -> Option<ResolvedMedia> {
+        if let Some(resolved) = self.resolved.get(file_id) {
+            return resolved.clone();
+        }
+        let resolved = self.relink(file_id, clip_name);
+        self.resolved.insert(file_id.to_string(), resolved.clone());
+        resolved
+    }
+
+    fn relink(&mut self, file_id: &str, clip_name: &str) -> Option<ResolvedMedia> {
+        let Some(file) = self.xmeml.files.get(file_id) else {
+            self.out.warnings.push(format!(
+                "clip '{clip_name}' references file '{file_id}', which the document never \
+                 defines; it was not imported"
+            ));
+            return None;
+        };
+
+        let Some(url) = file.pathurl.as_deref() else {
+            if let Some(asset_id) = self.out.find_asset_by_name(&file.name) {
+                return Some(ResolvedMedia::Existing(asset_id));
+            }
+            self.out.warnings.push(format!(
+                "file '{}' has no path and matches no project asset by name; its clips were not \
+                 imported",
+                file.name
+            ));
+            return None;
+        };
+
+        let Some(path) = file_url_to_path(url) else {
+            self.out.warnings.push(format!(
+                "file '{}' references '{url}', which is not a local file URL; its clips were not \
+                 imported",
+                file.name
+            ));
+            return None;
+        };
+
+        self.out.relink(clip_name, &path)
+    }
+
+    // -------------------------------------------------------------------------
+    // Transitions
+    // -------------------------------------------------------------------------
+
+    /// Attaches a transition to the outgoing clip of the cut it sits on.
+    fn add_transition(&mut self, index: usize, transition: &XmemlTransition) {
+        let key = TrackKey {
+            audio: transition.audio,
+            index: transition.track_index,
+        };
+        let half_frame = 0.5 / self.clock.frames_per_second();
+        let cut = self.clock.snap_seconds_to_frame(transition.cut_sec);
+
+        let found = self.placed.get(&key).and_then(|placed| {
+            let outgoing = placed
+                .iter()
+                .find(|clip| (clip.timeline_end_sec - cut).abs() < half_frame)?;
+            let incoming = placed
+                .iter()
+                .find(|clip| (clip.timeline_start_sec - cut).abs() < half_frame)?;
+            Some((outgoing.step_id.clone(), incoming.step_id.clone()))
+        });
+        let (Some((outgoing, incoming)), Some(track_step)) =
+            (found, self.track_steps.get(&key).cloned())
+        else {
+            self.out.warnings.push(format!(
+                "transition '{}' at {:.3}s was not imported: no two imported clips meet at its cut",
+                transition.name, transition.cut_sec
+            ));
+            return;
+        };
+
+        let effect_type = transition_effect_type(&transition.name);
+        if effect_type.is_none() {
+            self.out.unsupported.push(format!(
+                "transition '{}' has no OpenReelio equivalent and was imported as a cross dissolve",
+                transition.name
+            ));
+        }
+
+        self.out.push_step(
+            &format!("transition_{index}"),
+            "AddEffect",
+            json!({
+                "sequenceId": step_reference(SEQUENCE_STEP),
+                "trackId": step_reference(&track_step),
+                "clipId": step_reference(&outgoing),
+                "effectType": effect_type.unwrap_or("cross_dissolve"),
+                "params": { "duration": transition.duration_sec },
+            }),
+            &[&outgoing, &incoming],
+        );
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::assets::{AssetKind, AudioInfo};
+    use crate::core::interchange::import_plan::test_support::{assets_with, steps_of};
+    use crate::core::interchange::xmeml::export_xmeml;
+    use crate::core::timeline::{
+        Clip, ClipPlace, ClipRange, Marker, Sequence, SequenceFormat, Track, TrackKind,
+    };
+
+    fn plan_for(document: &str, assets: &HashMap<String, Asset>) -> XmemlImportPlan {
+        let parsed = parse_xmeml(document).expect("fixture should parse");
+        xmeml_to_plan_steps(
+            &parsed,
+            &XmemlImportContext {
+                sequence_name: "Imported",
+                sequence_fps: parsed.fps.clone().unwrap_or(Ratio::new(24, 1)),
+                assets,
+                project_root: Path::new("/media"),
+                allow_external_media: false,
+            },
+        )
+        .expect("plan should build")
+    }
+
+    /// A Premiere-style document: 25fps, two video tracks, a clip at 50%
+    /// speed, keyframed opacity and audio levels, a dissolve and markers.
+    const PREMIERE_DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE xmeml>
+<xmeml version="4">
+  <sequence id="sequence-1">
+    <name>Online</name>
+    <rate><timebase>25</timebase><ntsc>FALSE</ntsc></rate>
+    <media>
+      <video>
+        <format><samplecharacteristics><width>1920</width><height>1080</height></samplecharacteristics></format>
+        <track>
+          <clipitem id="clipitem-1">
+            <name>Interview</name>
+            <rate><timebase>25</timebase><ntsc>FALSE</ntsc></rate>
+            <start>0</start><end>-1</end><in>50</in><out>175</out>
+            <file id="file-1">
+              <name>Interview.mov</name>
+              <pathurl>file://localhost/media/Interview.mov</pathurl>
+              <media><video/><audio><channelcount>2</channelcount></audio></media>
+            </file>
+            <marker><name>Good line</name><in>75</in><out>-1</out></marker>
+          </clipitem>
+          <transitionitem>
+            <start>100</start><end>150</end><alignment>center</alignment>
+            <effect><name>Cross Dissolve</name></effect>
+          </transitionitem>
+          <clipitem id="clipitem-2">
+            <name>Interview</name>
+            <start>-1</start><end>250</end><in>1000</in><out>1125</out>
+            <file id="file-1"/>
+          </clipitem>
+        </track>
+        <track>
+          <clipitem id="clipitem-3">
+            <name>Broll</name>
+            <start>25</start><end>75</end><in>0</in><out>25</out>
+            <file id="file-2">
+              <name>Broll.mov</name>
+              <pathurl>file://localhost/media/Broll.mov</pathurl>
+              <media><video/></media>
+            </file>
+            <filter><effect>
+              <name>Time Remap</name><effectid>timeremap</effectid>
+              <parameter><parameterid>variablespeed</parameterid><value>0</value></parameter>
+              <parameter><parameterid>speed</parameterid><value>50</value></parameter>
+              <parameter><parameterid>reverse</parameterid><value>FALSE</value></parameter>
+            </effect></filter>
+            <filter><effect>
+              <name>Opacity</name><effectid>opacity</effectid>
+              <parameter><parameterid>opacity</parameterid><value>100</value>
+                <keyframe><when>0</when><value>0</value></keyframe>
+                <keyframe><when>25</when><value>100</value></keyframe>
+              </parameter>
+            </effect></filter>
+            <filter><effect><name>Gaussian Blur</name><effectid>gaussianblur</effectid></effect></filter>
+          </clipitem>
+        </track>
+      </video>
+      <audio>
+        <track>
+          <clipitem id="clipitem-4">
+            <name>Music</name>
+            <start>0</start><end>250</end><in>0</in><out>250</out>
+            <file id="file-3">
+              <name>Music.wav</name>
+              <pathurl>file://localhost/media/Music.wav</pathurl>
+              <media><audio/></media>
+            </file>
+            <filter><effect>
+              <name>Audio Levels</name><effectid>audiolevels</effectid>
+              <parameter><parameterid>level</parameterid><value>0.5</value>
+                <keyframe><when>0</when><value>0</value></keyframe>
+                <keyframe><when>50</when><value>1</value></keyframe>
+              </parameter>
+            </effect></filter>
+          </clipitem>
+        </track>
+      </audio>
+    </media>
+    <marker><name>Act 2</name><in>200</in><out>-1</out></marker>
+  </sequence>
+</xmeml>"#;
+
+    fn full_assets() -> HashMap<String, Asset> {
+        assets_with(&[
+            ("a1", "Interview.mov", "/media/Interview.mov", Some(60.0)),
+            ("a2", "Broll.mov", "/media/Broll.mov", Some(60.0)),
+            ("a3", "Music.wav", "/media/Music.wav", Some(60.0)),
+        ])
+    }
+
+    #[test]
+    fn should_resolve_clip_edges_at_a_transition_to_its_cut() {
+        let parsed = parse_xmeml(PREMIERE_DOCUMENT).expect("valid XMEML");
+
+        assert_eq!(parsed.fps, Some(Ratio::new(25, 1)));
+        assert_eq!(parsed.sequence_name.as_deref(), Some("Online"));
+        assert_eq!((parsed.video_tracks, parsed.audio_tracks), (2, 1));
+
+        // The dissolve runs from frame 100 to 150, centred on frame 125.
+        let first = &parsed.clips[0];
+        assert_eq!(first.timeline_start_sec, 0.0);
+        assert!((first.duration_sec - 5.0).abs() < 1e-9);
+        assert!((first.source_in_sec - 2.0).abs() < 1e-9);
+        let second = &parsed.clips[1];
+        assert!((second.timeline_start_sec - 5.0).abs() < 1e-9);
+        assert_eq!(parsed.transitions.len(), 1);
+        assert!((parsed.transitions[0].cut_sec - 5.0).abs() < 1e-9);
+        assert!((parsed.transitions[0].duration_sec - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_read_speed_levels_and_markers() {
+        let parsed = parse_xmeml(PREMIERE_DOCUMENT).expect("valid XMEML");
+        let by_name = |name: &str| {
+            parsed
+                .clips
+                .iter()
+                .find(|clip| clip.name == name)
+                .expect("clip present")
+        };
+
+        let broll = by_name("Broll");
+        assert!((broll.speed - 0.5).abs() < 1e-9);
+        let opacity = broll.opacity.as_ref().expect("opacity");
+        assert_eq!(opacity.value, 1.0);
+        // Source frame 25 at half speed is two seconds into the clip.
+        assert_eq!(opacity.keyframes[1].offset_sec, 2.0);
+        assert_eq!(opacity.keyframes[0].value, 0.0);
+
+        let music = by_name("Music");
+        let volume = music.volume.as_ref().expect("volume");
+        assert!((volume.value - gain_to_db(0.5)).abs() < 1e-9);
+        assert_eq!(volume.keyframes[0].value, -60.0);
+        assert_eq!(volume.keyframes[1].offset_sec, 2.0);
+
+        assert!((parsed.clips[0].markers[0].time_sec - 1.0).abs() < 1e-9);
+        assert!((parsed.markers[0].time_sec - 8.0).abs() < 1e-9);
+        assert!(parsed
+            .unsupported
+            .iter()
+            .any(|note| note.contains("Gaussian Blur")));
+    }
+
+    #[test]
+    fn should_build_a_sequence_with_the_documents_tracks() {
+        let plan = plan_for(PREMIERE_DOCUMENT, &full_assets());
+
+        let sequence = &plan.steps[0];
+        assert_eq!(sequence["commandType"], "CreateSequence");
+        assert_eq!(sequence["payload"]["fps"]["num"], 25);
+        assert_eq!(sequence["payload"]["format"], "1920x1080");
+
+        let tracks: Vec<_> = steps_of(&plan.steps, "CreateTrack")
+            .iter()
+            .map(|step| {
+                format!(
+                    "{}:{}",
+                    step["payload"]["kind"].as_str().unwrap_or_default(),
+                    step["payload"]["name"].as_str().unwrap_or_default()
+                )
+            })
+            .collect();
+        assert_eq!(tracks, vec!["video:V2", "video:V1", "audio:A1"]);
+        assert_eq!(plan.placed_clips, 4);
+        assert!(steps_of(&plan.steps, "ImportAsset").is_empty());
+        assert_eq!(steps_of(&plan.steps, "SetClipSpeed").len(), 1);
+        assert_eq!(steps_of(&plan.steps, "AddAudioKeyframe").len(), 2);
+        assert_eq!(steps_of(&plan.steps, "AddMarker").len(), 2);
+        assert!(plan.warnings.is_empty(), "{:?}", plan.warnings);
+    }
+
+    #[test]
+    fn should_mute_video_clips_whose_sound_is_on_an_audio_track() {
+        let plan = plan_for(PREMIERE_DOCUMENT, &full_assets());
+
+        // Both Interview clips carry sound; Broll's file has none.
+        let muted: Vec<_> = steps_of(&plan.steps, "SetClipMute")
+            .iter()
+            .map(|step| step["payload"]["clipId"]["$fromStep"].clone())
+            .collect();
+        assert_eq!(muted, vec!["clip_0", "clip_1"]);
+    }
+
+    #[test]
+    fn should_import_keyframed_opacity_as_an_opacity_effect_and_the_dissolve() {
+        let plan = plan_for(PREMIERE_DOCUMENT, &full_assets());
+
+        let effects = steps_of(&plan.steps, "AddEffect");
+        assert_eq!(effects.len(), 2);
+        let opacity = effects
+            .iter()
+            .find(|step| step["payload"]["effectType"] == "opacity")
+            .expect("opacity effect");
+        assert_eq!(opacity["payload"]["keyframes"]["value"][1]["value"], 1.0);
+        let dissolve = effects
+            .iter()
+            .find(|step| step["payload"]["effectType"] == "cross_dissolve")
+            .expect("dissolve");
+        assert_eq!(dissolve["payload"]["clipId"]["$fromStep"], "clip_0");
+        assert_eq!(dissolve["payload"]["params"]["duration"], 2.0);
+    }
+
+    #[test]
+    fn should_relink_by_file_name_and_refuse_media_outside_the_project() {
+        let document = PREMIERE_DOCUMENT.replace(
+            "file://localhost/media/Music.wav",
+            "file://localhost/etc/Music.wav",
+        );
+        let assets = assets_with(&[(
+            "a1",
+            "Interview.mov",
+            "/elsewhere/Interview.mov",
+            Some(60.0),
+        )]);
+        let plan = plan_for(&document, &assets);
+
+        assert!(plan
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("by file name")));
+        assert!(plan
+            .warnings
+            .iter()
+            .any(|warning| warning.contains("outside the project directory")));
+        let imports: Vec<_> = plan.asset_imports.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(imports, vec!["Broll.mov"]);
+        assert_eq!(plan.placed_clips, 3);
+    }
+
+    #[test]
+    fn should_refuse_a_document_that_is_not_xmeml_or_has_no_sequence() {
+        assert!(parse_xmeml("<fcpxml version=\"1.11\"/>")
+            .expect_err("wrong root")
+            .contains("not a Final Cut Pro 7 XML document"));
+        assert!(parse_xmeml("<xmeml version=\"4\"><bin/></xmeml>")
+            .expect_err("no sequence")
+            .contains("no sequence"));
+        assert!(parse_xmeml("not xml <").is_err());
+    }
+
+    #[test]
+    fn should_round_trip_our_own_xmeml_export() {
+        let mut assets = assets_with(&[
+            ("v1", "shot.mp4", "/media/shot.mp4", Some(60.0)),
+            ("a1", "music.wav", "/media/music.wav", Some(60.0)),
+        ]);
+        if let Some(shot) = assets.get_mut("v1") {
+            shot.audio = Some(AudioInfo::default());
+        }
+        if let Some(music) = assets.get_mut("a1") {
+            music.kind = AssetKind::Audio;
+        }
+
+        let mut sequence = Sequence::new("Cut", SequenceFormat::youtube_1080());
+        let mut video = Track::new("Video 1", TrackKind::Video);
+        let mut audio = Track::new("Audio 1", TrackKind::Audio);
+        let clip = |id: &str, asset: &str, src_in: f64, tl_in: f64| {
+            let mut clip = Clip::new(asset);
+            clip.id = id.to_string();
+            clip.range = ClipRange::new(src_in, src_in + 4.0);
+            clip.place = ClipPlace::new(tl_in, 4.0);
+            clip
+        };
+        let mut slow = clip("c2", "v1", 10.0, 4.0);
+        slow.speed = 2.0;
+        slow.range = ClipRange::new(10.0, 18.0);
+        video.clips.push(clip("c1", "v1", 2.0, 0.0));
+        video.clips.push(slow);
+        let mut music = clip("c3", "a1", 0.0, 0.0);
+        music.audio.volume_db = -6.0;
+        audio.clips.push(music);
+        sequence.tracks.push(video);
+        sequence.tracks.push(audio);
+        sequence.markers.push(Marker::new(3.0, "Beat"));
+
+        let export = export_xmeml(&sequence, &assets, &HashMap::new()).expect("export");
+        let plan = plan_for(&export.xml, &assets);
+
+        let placements: Vec<(f64, f64)> = steps_of(&plan.steps, "InsertClip")
+            .iter()
+            .map(|step| {
+                (
+                    step["payload"]["timelineStart"].as_f64().unwrap_or(-1.0),
+                    step["payload"]["sourceIn"].as_f64().unwrap_or(-1.0),
+                )
+            })
+            .collect();
+        // Two pictures, their two linked sounds, and the music.
+        assert_eq!(placements.len(), 5);
+        assert!(placements.contains(&(0.0, 2.0)));
+        assert!(placements.contains(&(4.0, 10.0)));
+        assert!(placements.contains(&(0.0, 0.0)));
+
+        let speeds = steps_of(&plan.steps, "SetClipSpeed");
+        assert_eq!(speeds.len(), 2);
+        assert!((speeds[0]["payload"]["speed"].as_f64().unwrap_or(0.0) - 2.0).abs() < 1e-6);
+        let volume = steps_of(&plan.steps, "SetClipAudio");
+        assert_eq!(volume.len(), 1);
+        assert!((volume[0]["payload"]["volumeDb"].as_f64().unwrap_or(0.0) + 6.0).abs() < 1e-3);
+        assert_eq!(steps_of(&plan.steps, "SetClipMute").len(), 2);
+        assert_eq!(steps_of(&plan.steps, "AddMarker").len(), 1);
+        assert!(steps_of(&plan.steps, "ImportAsset").is_empty());
+        assert!(plan.warnings.is_empty(), "{:?}", plan.warnings);
+    }
+}
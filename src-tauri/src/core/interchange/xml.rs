@@ -211,10 +211,11 @@ fn write_resources(
 
             writeln!(
                 output,
-                r#"    <asset id="{rid}" name="{name}" src="{src}" start="0/1s" duration="{dur}" hasVideo="{hv}" hasAudio="{ha}" format="r1"/>"#,
+                r#"    <asset id="{rid}" name="{name}" src="{src}" start="{start}" duration="{dur}" hasVideo="{hv}" hasAudio="{ha}" format="r1"/>"#,
                 rid = resource_id,
                 name = xml_escape(&asset.name),
                 src = xml_escape(&asset_src_url(&asset.uri)),
+                start = rational_time(asset.source_start_sec(), fps),
                 dur = duration,
                 hv = if has_video { "1" } else { "0" },
                 ha = if has_audio { "1" } else { "0" },
@@ -265,16 +266,22 @@ fn write_library(
     )
     .map_err(|e| e.to_string())?;
 
-    // Build asset ID → resource ID mapping
-    let mut asset_resource_map: HashMap<&str, String> = HashMap::new();
+    // Build asset ID → resource mapping
+    let mut asset_resource_map: HashMap<&str, AssetResource> = HashMap::new();
     let mut resource_idx = 2usize;
     for track in &sequence.tracks {
         for clip in &track.clips {
-            if clip.enabled
-                && !asset_resource_map.contains_key(clip.asset_id.as_str())
-                && assets.contains_key(&clip.asset_id)
-            {
-                asset_resource_map.insert(&clip.asset_id, format!("r{}", resource_idx));
+            if asset_resource_map.contains_key(clip.asset_id.as_str()) || !clip.enabled {
+                continue;
+            }
+            if let Some(asset) = assets.get(&clip.asset_id) {
+                asset_resource_map.insert(
+                    &clip.asset_id,
+                    AssetResource {
+                        id: format!("r{}", resource_idx),
+                        start_sec: asset.source_start_sec(),
+                    },
+                );
                 resource_idx += 1;
             }
         }
@@ -302,7 +309,7 @@ fn write_library(
 fn write_spine(
     output: &mut String,
     sequence: &Sequence,
-    asset_resource_map: &HashMap<&str, String>,
+    asset_resource_map: &HashMap<&str, AssetResource>,
     fps: &Ratio,
 ) -> Result<(u32, u32), String> {
     writeln!(output, "          <spine>").map_err(|e| e.to_string())?;
@@ -414,13 +421,20 @@ fn write_spine(
     Ok((event_count, track_count))
 }
 
+/// An `<asset>` resource a clip can point at.
+struct AssetResource {
+    /// Resource ID (`r2`, `r3`, ...)
+    id: String,
+    /// Where the asset's own timecode starts, which every clip `start` is on
+    start_sec: f64,
+}
+
 fn resolve_asset_resource_ref<'a>(
     clip: &crate::core::timeline::Clip,
-    asset_resource_map: &'a HashMap<&str, String>,
-) -> Result<&'a str, String> {
+    asset_resource_map: &'a HashMap<&str, AssetResource>,
+) -> Result<&'a AssetResource, String> {
     asset_resource_map
         .get(clip.asset_id.as_str())
-        .map(|resource_id| resource_id.as_str())
         .ok_or_else(|| {
             format!(
                 "Clip '{}' references missing asset '{}'",
@@ -433,17 +447,17 @@ fn resolve_asset_resource_ref<'a>(
 fn write_clip_element(
     output: &mut String,
     clip: &crate::core::timeline::Clip,
-    asset_resource_map: &HashMap<&str, String>,
+    asset_resource_map: &HashMap<&str, AssetResource>,
     fps: &Ratio,
     lane: usize,
 ) -> Result<(), String> {
-    let resource_ref = resolve_asset_resource_ref(clip, asset_resource_map)?;
+    let resource = resolve_asset_resource_ref(clip, asset_resource_map)?;
 
     let name = clip.label.as_deref().unwrap_or(&clip.id);
 
     let offset = rational_time(clip.place.timeline_in_sec, fps);
     let duration = rational_time(clip.place.duration_sec, fps);
-    let start = rational_time(clip.range.source_in_sec, fps);
+    let start = rational_time(resource.start_sec + clip.range.source_in_sec, fps);
 
     let lane_attr = if lane > 0 {
         format!(r#" lane="{}""#, lane)
@@ -461,7 +475,7 @@ fn write_clip_element(
         output,
         r#"            <clip name="{name}" ref="{rref}" offset="{offset}" duration="{dur}" start="{start}" tcFormat="{tc_format}"{lane}/>"#,
         name = xml_escape(name),
-        rref = resource_ref,
+        rref = resource.id,
         offset = offset,
         dur = duration,
         start = start,
@@ -477,17 +491,17 @@ fn write_clip_element(
 fn write_audio_clip_element(
     output: &mut String,
     clip: &crate::core::timeline::Clip,
-    asset_resource_map: &HashMap<&str, String>,
+    asset_resource_map: &HashMap<&str, AssetResource>,
     fps: &Ratio,
     lane: usize,
 ) -> Result<(), String> {
-    let resource_ref = resolve_asset_resource_ref(clip, asset_resource_map)?;
+    let resource = resolve_asset_resource_ref(clip, asset_resource_map)?;
 
     let name = clip.label.as_deref().unwrap_or(&clip.id);
 
     let offset = rational_time(clip.place.timeline_in_sec, fps);
     let duration = rational_time(clip.place.duration_sec, fps);
-    let start = rational_time(clip.range.source_in_sec, fps);
+    let start = rational_time(resource.start_sec + clip.range.source_in_sec, fps);
 
     let tc_format = if super::models::is_drop_frame_rate(fps) {
         "DF"
@@ -499,7 +513,7 @@ fn write_audio_clip_element(
         output,
         r#"            <clip name="{name}" ref="{rref}" offset="{offset}" duration="{dur}" start="{start}" lane="{lane}" tcFormat="{tc_format}"/>"#,
         name = xml_escape(name),
-        rref = resource_ref,
+        rref = resource.id,
         offset = offset,
         dur = duration,
         start = start,
@@ -518,7 +532,7 @@ fn write_audio_clip_element(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::assets::{Asset, AssetKind, LicenseInfo, ProxyStatus};
+    use crate::core::assets::{Asset, AssetKind, LicenseInfo, ProxyStatus, SourceMetadata};
     use crate::core::timeline::{
         AudioSettings, BlendMode, Clip, ClipPlace, ClipRange, Sequence, SequenceFormat, Track,
         Transform,
@@ -547,6 +561,8 @@ mod tests {
             workspace_managed: false,
             missing: false,
             quarantined_uri: None,
+            source: None,
+            image_sequence: None,
         }
     }
 
@@ -582,6 +598,7 @@ mod tests {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
             group_id: None,
         }
@@ -685,6 +702,29 @@ mod tests {
         assert!(xml.contains("start="));
     }
 
+    #[test]
+    fn should_start_assets_and_clips_at_the_recorded_source_timecode() {
+        // Given: a camera original whose timecode starts at 01:00:00:00
+        let mut seq = make_sequence("Source TC", 24, 1);
+        let mut track = Track::new_video("V1");
+        track.add_clip(make_clip("c1", "a1", 1.0, 4.0, 0.0, 3.0));
+        seq.add_track(track);
+
+        let asset = make_asset("a1", "A001.mov", "/A001.mov").with_source(SourceMetadata {
+            start_timecode: Some("01:00:00:00".to_string()),
+            start_sec: Some(3600.0),
+            ..SourceMetadata::default()
+        });
+        let assets = HashMap::from([("a1".to_string(), asset)]);
+
+        // When: exporting to FCPXML
+        let (xml, _, _) = export_fcpxml(&seq, &assets).expect("FCPXML export should succeed");
+
+        // Then: the asset starts at its timecode and the clip start is on that clock
+        assert!(xml.contains(r#"src="file:///A001.mov" start="3600/1s""#));
+        assert!(xml.contains(r#"duration="3/1s" start="3601/1s""#));
+    }
+
     #[test]
     fn should_export_multiple_tracks_with_lane_offsets() {
         // Given: a sequence with two video tracks
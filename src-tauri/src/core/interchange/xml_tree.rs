@@ -0,0 +1,250 @@
+//! Minimal XML Element Tree
+//!
+//! The XML interchange importers walk documents that are small (a cut list, not
+//! a media file) and deeply cross-referenced (a clip names a resource declared
+//! elsewhere), so they read the whole document into a plain element tree first
+//! and then navigate it. This module is that tree and nothing more: no
+//! namespaces, no DTD processing, no entity expansion beyond the five XML
+//! predefined entities and character references.
+//!
+//! A DTD is skipped rather than honoured. FCPXML opens with `<!DOCTYPE fcpxml>`
+//! and XMEML with `<!DOCTYPE xmeml>`; neither defines entities, and a document
+//! that does define them is not one an importer should be expanding.
+
+use quick_xml::escape::resolve_predefined_entity;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+/// Nesting deeper than this is refused. Real interchange documents nest a few
+/// dozen levels at most; a document nesting thousands is an attack on the
+/// recursive walks that read it.
+const MAX_DEPTH: usize = 256;
+
+/// An element with its attributes, child elements and text content.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(super) struct XmlElement {
+    /// Local name, without any namespace prefix.
+    pub name: String,
+    /// Attributes in document order, values unescaped.
+    pub attributes: Vec<(String, String)>,
+    /// Child elements in document order.
+    pub children: Vec<XmlElement>,
+    /// Concatenated text directly inside this element, trimmed.
+    pub text: String,
+}
+
+impl XmlElement {
+    /// The value of an attribute, if present.
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// The first child element with the given name.
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    /// Every child element with the given name.
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        self.children.iter().filter(move |child| child.name == name)
+    }
+
+    /// Every element with the given name anywhere below this one, in document
+    /// order. Matching elements are not searched further.
+    pub fn descendants<'a>(&'a self, name: &str, out: &mut Vec<&'a XmlElement>) {
+        for child in &self.children {
+            if child.name == name {
+                out.push(child);
+            } else {
+                child.descendants(name, out);
+            }
+        }
+    }
+}
+
+/// Parses a document into its root element.
+///
+/// # Errors
+///
+/// Returns a message naming the byte position for malformed XML, mismatched
+/// tags, nesting past [`MAX_DEPTH`], or a document with no root element.
+pub(super) fn parse_xml(content: &str) -> Result<XmlElement, String> {
+    // Text is trimmed once the element closes, not per event: an entity
+    // reference splits `Shot &amp; 1` into three events, and trimming each
+    // would eat the spaces around the ampersand.
+    let mut reader = Reader::from_str(content);
+
+    let mut stack: Vec<XmlElement> = Vec::new();
+    let mut root: Option<XmlElement> = None;
+
+    loop {
+        let position = reader.buffer_position();
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("malformed XML at byte {position}: {e}"))?;
+
+        match event {
+            Event::Start(start) => {
+                if stack.len() >= MAX_DEPTH {
+                    return Err(format!(
+                        "XML nests deeper than {MAX_DEPTH} elements at byte {position}"
+                    ));
+                }
+                stack.push(element_from(&start, position)?);
+            }
+            Event::Empty(start) => {
+                let element = element_from(&start, position)?;
+                attach(&mut stack, &mut root, element, position)?;
+            }
+            Event::End(_) => {
+                let element = stack
+                    .pop()
+                    .ok_or_else(|| format!("unexpected closing tag at byte {position}"))?;
+                attach(&mut stack, &mut root, element, position)?;
+            }
+            Event::Text(text) => {
+                let decoded = text
+                    .decode()
+                    .map_err(|e| format!("unreadable text at byte {position}: {e}"))?;
+                append_text(&mut stack, &decoded);
+            }
+            Event::CData(data) => {
+                let decoded = data
+                    .decode()
+                    .map_err(|e| format!("unreadable CDATA at byte {position}: {e}"))?;
+                append_text(&mut stack, &decoded);
+            }
+            Event::GeneralRef(reference) => {
+                let resolved = match reference
+                    .resolve_char_ref()
+                    .map_err(|e| format!("bad character reference at byte {position}: {e}"))?
+                {
+                    Some(ch) => ch.to_string(),
+                    None => {
+                        let name = reference
+                            .decode()
+                            .map_err(|e| format!("unreadable entity at byte {position}: {e}"))?;
+                        resolve_predefined_entity(&name)
+                            .ok_or_else(|| {
+                                format!("undefined entity '&{name};' at byte {position}")
+                            })?
+                            .to_string()
+                    }
+                };
+                append_text(&mut stack, &resolved);
+            }
+            Event::Eof => break,
+            Event::Decl(_) | Event::DocType(_) | Event::Comment(_) | Event::PI(_) => {}
+        }
+    }
+
+    if let Some(open) = stack.last() {
+        return Err(format!("element <{}> is never closed", open.name));
+    }
+    root.ok_or_else(|| "the document has no root element".to_string())
+}
+
+fn element_from(start: &BytesStart<'_>, position: u64) -> Result<XmlElement, String> {
+    let name = String::from_utf8_lossy(start.local_name().as_ref()).to_string();
+    let mut attributes = Vec::new();
+    for attribute in start.attributes() {
+        let attribute =
+            attribute.map_err(|e| format!("malformed attribute at byte {position}: {e}"))?;
+        let key = String::from_utf8_lossy(attribute.key.local_name().as_ref()).to_string();
+        let value = attribute
+            .unescape_value()
+            .map_err(|e| format!("unreadable attribute '{key}' at byte {position}: {e}"))?;
+        attributes.push((key, value.to_string()));
+    }
+    Ok(XmlElement {
+        name,
+        attributes,
+        children: Vec::new(),
+        text: String::new(),
+    })
+}
+
+fn attach(
+    stack: &mut [XmlElement],
+    root: &mut Option<XmlElement>,
+    mut element: XmlElement,
+    position: u64,
+) -> Result<(), String> {
+    element.text = element.text.trim().to_string();
+    match stack.last_mut() {
+        Some(parent) => parent.children.push(element),
+        None if root.is_none() => *root = Some(element),
+        None => {
+            return Err(format!(
+                "a second root element <{}> starts at byte {position}",
+                element.name
+            ))
+        }
+    }
+    Ok(())
+}
+
+fn append_text(stack: &mut [XmlElement], text: &str) {
+    if let Some(current) = stack.last_mut() {
+        current.text.push_str(text);
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_read_elements_attributes_and_text() {
+        let root = parse_xml(
+            r#"<?xml version="1.0"?>
+<!DOCTYPE xmeml>
+<xmeml version="4"><clip id="a &amp; b"><name>Shot &lt;1&gt;</name><file/></clip></xmeml>"#,
+        )
+        .expect("valid XML");
+
+        assert_eq!(root.name, "xmeml");
+        let clip = root.child("clip").expect("a clip");
+        assert_eq!(clip.attr("id"), Some("a & b"));
+        assert_eq!(
+            clip.child("name").map(|name| name.text.as_str()),
+            Some("Shot <1>")
+        );
+        assert!(clip.child("file").is_some());
+    }
+
+    #[test]
+    fn should_find_descendants_in_document_order() {
+        let root = parse_xml("<a><b><c n=\"1\"/></b><c n=\"2\"/></a>").expect("valid XML");
+        let mut found = Vec::new();
+        root.descendants("c", &mut found);
+
+        let order: Vec<_> = found.iter().filter_map(|c| c.attr("n")).collect();
+        assert_eq!(order, vec!["1", "2"]);
+    }
+
+    #[test]
+    fn should_refuse_mismatched_and_unclosed_tags() {
+        assert!(parse_xml("<a><b></a>").is_err());
+        assert!(parse_xml("<a><b>").is_err());
+        assert!(parse_xml("").is_err());
+    }
+
+    #[test]
+    fn should_refuse_nesting_past_the_depth_limit() {
+        let deep = format!(
+            "{}{}",
+            "<a>".repeat(MAX_DEPTH + 1),
+            "</a>".repeat(MAX_DEPTH + 1)
+        );
+        let error = parse_xml(&deep).expect_err("too deep");
+        assert!(error.contains("nests deeper"), "{error}");
+    }
+}
@@ -9,8 +9,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::core::{
-    fs::validate_path_id_component, render::VideoExportRequest, AssetId, ClipId, SequenceId,
-    TimeSec, TrackId,
+    assets::ImageSequenceInfo, fs::validate_path_id_component, render::VideoExportRequest, AssetId,
+    ClipId, Ratio, SequenceId, TimeSec, TrackId,
 };
 
 const MAX_JOB_PAYLOAD_BYTES: usize = 256 * 1024; // 256KiB
@@ -58,6 +58,21 @@ fn validate_time_non_negative(label: &str, t: TimeSec) -> Result<(), String> {
     Ok(())
 }
 
+fn validate_image_sequence(
+    sequence: Option<&ImageSequenceInfo>,
+    frame_rate: Option<&Ratio>,
+) -> Result<(), String> {
+    if let Some(sequence) = sequence {
+        sequence.validate()?;
+    }
+    if let Some(rate) = frame_rate {
+        if rate.num <= 0 || rate.den <= 0 {
+            return Err("frameRate must be positive".to_string());
+        }
+    }
+    Ok(())
+}
+
 // =============================================================================
 // Payloads
 // =============================================================================
@@ -69,12 +84,18 @@ pub struct ThumbnailJobPayload {
     pub input_path: String,
     pub width: Option<u32>,
     pub height: Option<u32>,
+    /// Set when `input_path` is the first frame of an image sequence
+    #[serde(default)]
+    pub image_sequence: Option<ImageSequenceInfo>,
+    #[serde(default)]
+    pub frame_rate: Option<Ratio>,
 }
 
 impl ThumbnailJobPayload {
     pub fn validate(&self) -> Result<(), String> {
         validate_id("assetId", &self.asset_id)?;
         validate_string_len("inputPath", &self.input_path, 4096)?;
+        validate_image_sequence(self.image_sequence.as_ref(), self.frame_rate.as_ref())?;
         Ok(())
     }
 }
@@ -84,12 +105,18 @@ impl ThumbnailJobPayload {
 pub struct ProxyJobPayload {
     pub asset_id: AssetId,
     pub input_path: String,
+    /// Set when `input_path` is the first frame of an image sequence
+    #[serde(default)]
+    pub image_sequence: Option<ImageSequenceInfo>,
+    #[serde(default)]
+    pub frame_rate: Option<Ratio>,
 }
 
 impl ProxyJobPayload {
     pub fn validate(&self) -> Result<(), String> {
         validate_id("assetId", &self.asset_id)?;
         validate_string_len("inputPath", &self.input_path, 4096)?;
+        validate_image_sequence(self.image_sequence.as_ref(), self.frame_rate.as_ref())?;
         Ok(())
     }
 }
@@ -113,6 +113,42 @@ fn parse_optional_video_export_request(
     }
 }
 
+/// Reads the optional `imageSequence` / `frameRate` pair of a thumbnail or
+/// proxy payload into an `image2` input beside the validated first frame.
+#[cfg(any(feature = "gui", test))]
+fn parse_optional_image_sequence_input(
+    payload: &serde_json::Value,
+    first_frame: &std::path::Path,
+) -> Result<Option<crate::core::ffmpeg::ImageSequenceInput>, String> {
+    use crate::core::{
+        assets::{ImageSequenceInfo, DEFAULT_IMAGE_SEQUENCE_FPS},
+        Ratio,
+    };
+
+    let sequence: ImageSequenceInfo = match payload.get("imageSequence") {
+        None | Some(serde_json::Value::Null) => return Ok(None),
+        Some(value) => serde_json::from_value(value.clone())
+            .map_err(|e| format!("Invalid imageSequence payload: {e}"))?,
+    };
+    sequence.validate()?;
+
+    let fps: Ratio = match payload.get("frameRate") {
+        None | Some(serde_json::Value::Null) => Ratio::new(DEFAULT_IMAGE_SEQUENCE_FPS, 1),
+        Some(value) => serde_json::from_value(value.clone())
+            .map_err(|e| format!("Invalid frameRate payload: {e}"))?,
+    };
+    if fps.num <= 0 || fps.den <= 0 {
+        return Err("frameRate must be positive".to_string());
+    }
+
+    Ok(Some(crate::core::ffmpeg::ImageSequenceInput {
+        pattern_path: sequence.pattern_path(first_frame),
+        start_number: sequence.start_number,
+        frame_count: sequence.frame_count,
+        fps,
+    }))
+}
+
 // =============================================================================
 // Job Handle
 // =============================================================================
@@ -588,6 +624,7 @@ impl JobProcessor {
             .ok_or("Missing inputPath in payload")?;
 
         let input_path = validate_local_input_path_async(input_path, "inputPath").await?;
+        let image_sequence = parse_optional_image_sequence_input(&job.payload, &input_path)?;
 
         let width = job
             .payload
@@ -618,10 +655,19 @@ impl JobProcessor {
             _ => Some((320, 180)), // Default thumbnail size
         };
 
-        runner
-            .generate_thumbnail(&input_path, &output_path, size)
-            .await
-            .map_err(|e| format!("Thumbnail generation failed: {}", e))?;
+        let result = match &image_sequence {
+            Some(sequence) => {
+                runner
+                    .generate_image_sequence_thumbnail(sequence, &output_path, size)
+                    .await
+            }
+            None => {
+                runner
+                    .generate_thumbnail(&input_path, &output_path, size)
+                    .await
+            }
+        };
+        result.map_err(|e| format!("Thumbnail generation failed: {}", e))?;
 
         Ok(serde_json::json!({
             "assetId": asset_id,
@@ -651,6 +697,7 @@ impl JobProcessor {
             .ok_or("Missing inputPath in payload")?;
 
         let input_path = validate_local_input_path_async(input_path, "inputPath").await?;
+        let image_sequence = parse_optional_image_sequence_input(&job.payload, &input_path)?;
 
         // Emit generating event
         emit_or_warn(
@@ -696,9 +743,18 @@ impl JobProcessor {
         });
 
         // Generate proxy with progress
-        let result = runner
-            .generate_proxy(&input_path, &output_path, Some(progress_tx))
-            .await;
+        let result = match &image_sequence {
+            Some(sequence) => {
+                runner
+                    .generate_image_sequence_proxy(sequence, &output_path, Some(progress_tx))
+                    .await
+            }
+            None => {
+                runner
+                    .generate_proxy(&input_path, &output_path, Some(progress_tx))
+                    .await
+            }
+        };
 
         // Wait for progress reporter to finish
         let _ = progress_task.await;
@@ -1237,8 +1293,8 @@ impl JobProcessor {
     async fn process_preview_render(&self, job: &Job) -> Result<serde_json::Value, String> {
         #[cfg(not(test))]
         use crate::core::render::{
-            build_render_graph, build_render_plan, validate_export_settings, ExportEngine,
-            ExportSettings,
+            build_render_graph, build_render_plan, resolve_multicam_clips,
+            validate_export_settings, ExportEngine, ExportSettings,
         };
 
         // Validate required payload fields
@@ -1307,7 +1363,7 @@ impl JobProcessor {
                     .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?;
 
                 // Clone required data to release the lock
-                let sequence = sequence.clone();
+                let sequence = resolve_multicam_clips(sequence, &project.state.sequences);
                 let render_graph = build_render_graph(&project.state, sequence_id)
                     .map_err(|error| format!("Failed to build render graph: {}", error))?;
                 let assets = project.state.assets.clone();
@@ -1508,8 +1564,8 @@ impl JobProcessor {
         use crate::core::{
             fs::{export_allowed_roots, validate_scoped_output_path},
             render::{
-                build_render_graph, build_render_plan, validate_export_settings, ExportEngine,
-                ExportPreset, ExportSettings,
+                build_render_graph, build_render_plan, resolve_multicam_clips,
+                validate_export_settings, ExportEngine, ExportPreset, ExportSettings,
             },
         };
 
@@ -1554,12 +1610,14 @@ impl JobProcessor {
                 let guard = app_state.project.lock().await;
                 let project = guard.as_ref().ok_or("No project open")?;
 
-                let sequence = project
-                    .state
-                    .sequences
-                    .get(sequence_id)
-                    .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?
-                    .clone();
+                let sequence = resolve_multicam_clips(
+                    project
+                        .state
+                        .sequences
+                        .get(sequence_id)
+                        .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+                    &project.state.sequences,
+                );
                 let render_graph = build_render_graph(&project.state, sequence_id)
                     .map_err(|error| format!("Failed to build render graph: {}", error))?;
 
@@ -2373,6 +2431,46 @@ mod tests {
         assert_eq!(parsed.width, Some(1920));
     }
 
+    #[test]
+    fn parses_optional_image_sequence_input_beside_the_first_frame() {
+        let first_frame = std::path::Path::new("/renders/shot_1001.exr");
+        let payload = serde_json::json!({
+            "assetId": "asset_001",
+            "inputPath": "/renders/shot_1001.exr",
+            "imageSequence": {
+                "pattern": "shot_%04d.exr",
+                "startNumber": 1001,
+                "frameCount": 48
+            },
+            "frameRate": { "num": 25, "den": 1 }
+        });
+
+        let input = parse_optional_image_sequence_input(&payload, first_frame)
+            .unwrap()
+            .expect("image sequence should parse");
+
+        assert_eq!(
+            input.pattern_path,
+            std::path::PathBuf::from("/renders/shot_%04d.exr")
+        );
+        assert_eq!(input.start_number, 1001);
+        assert_eq!(input.fps, crate::core::Ratio::new(25, 1));
+
+        let plain = serde_json::json!({ "inputPath": "/media/clip.mp4" });
+        assert!(parse_optional_image_sequence_input(&plain, first_frame)
+            .unwrap()
+            .is_none());
+
+        let escaping = serde_json::json!({
+            "imageSequence": {
+                "pattern": "../shot_%04d.exr",
+                "startNumber": 1,
+                "frameCount": 2
+            }
+        });
+        assert!(parse_optional_image_sequence_input(&escaping, first_frame).is_err());
+    }
+
     #[test]
     fn test_preview_render_job_submission() {
         let pool = WorkerPool::with_defaults();
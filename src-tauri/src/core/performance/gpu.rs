@@ -749,6 +749,8 @@ fn vendor_from_encoder(mode: &HardwareAccelMode) -> Option<GpuVendor> {
         HardwareAccelMode::Qsv => Some(GpuVendor::Intel),
         HardwareAccelMode::Amf => Some(GpuVendor::Amd),
         HardwareAccelMode::VideoToolbox => Some(GpuVendor::Apple),
+        // VA-API can be any vendor on Linux
+        HardwareAccelMode::Vaapi => None,
         HardwareAccelMode::Auto | HardwareAccelMode::Cpu => None,
     }
 }
@@ -1203,8 +1205,10 @@ mod tests {
                 display_name: "NVIDIA NVENC".to_string(),
                 h264_encoder: "h264_nvenc".to_string(),
                 h265_encoder: "hevc_nvenc".to_string(),
+                av1_encoder: String::new(),
             }],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
         // When: building GPU devices
@@ -1244,15 +1248,18 @@ mod tests {
                     display_name: "NVIDIA NVENC".to_string(),
                     h264_encoder: "h264_nvenc".to_string(),
                     h265_encoder: "hevc_nvenc".to_string(),
+                    av1_encoder: String::new(),
                 },
                 crate::core::render::HardwareEncoderInfo {
                     backend: HardwareAccelMode::Qsv,
                     display_name: "Intel QSV".to_string(),
                     h264_encoder: "h264_qsv".to_string(),
                     h265_encoder: "hevc_qsv".to_string(),
+                    av1_encoder: String::new(),
                 },
             ],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
         // When: building GPU devices
@@ -1274,6 +1281,7 @@ mod tests {
         let encoders = AvailableEncoders {
             hardware: Vec::new(),
             has_hardware: false,
+            software_av1_encoder: None,
         };
 
         // When: building GPU devices
@@ -1300,8 +1308,10 @@ mod tests {
                 display_name: "NVIDIA NVENC".to_string(),
                 h264_encoder: "h264_nvenc".to_string(),
                 h265_encoder: "hevc_nvenc".to_string(),
+                av1_encoder: String::new(),
             }],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
         // When: building GPU devices
@@ -1328,6 +1338,7 @@ mod tests {
         let encoders = AvailableEncoders {
             hardware: Vec::new(),
             has_hardware: false,
+            software_av1_encoder: None,
         };
 
         // When: building GPU devices
@@ -1373,8 +1384,10 @@ mod tests {
                 display_name: "NVIDIA NVENC".to_string(),
                 h264_encoder: "h264_nvenc".to_string(),
                 h265_encoder: "hevc_nvenc".to_string(),
+                av1_encoder: String::new(),
             }],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
         let first = build_gpu_devices_from_probes(&decoders, &encoders);
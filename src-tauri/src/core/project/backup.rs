@@ -444,6 +444,7 @@ mod tests {
                 version: "0.1.0".to_string(),
                 description: None,
                 author: None,
+                copyright: None,
                 format_version: 2,
             },
             assets: HashMap::new(),
@@ -34,10 +34,17 @@ pub enum OpKind {
     ClipRemove,
     ClipMove,
     ClipTrim,
+    ClipSlip,
+    ClipSlide,
+    ClipRoll,
     ClipSplit,
     ClipUpdate,
     CompoundClipCreate,
     CompoundClipUnnest,
+    MulticamClipCreate,
+    MulticamAngleSwitch,
+    MulticamClipFlatten,
+    ClipSync,
     ClipGroup,
     ClipUngroup,
     ClipLink,
@@ -49,6 +56,14 @@ pub enum OpKind {
     TrackReorder,
     TrackUpdate,
 
+    // Audio bus operations
+    AudioBusCreate,
+    AudioBusRemove,
+    AudioBusUpdate,
+    AudioBusRoute,
+    AudioBusEffectAdd,
+    AudioBusEffectRemove,
+
     // Effect operations
     EffectAdd,
     EffectRemove,
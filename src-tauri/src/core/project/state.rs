@@ -18,7 +18,7 @@ use crate::core::{
     masks::MaskGroup,
     project::{OpKind, Operation, OpsLog},
     timeline::{
-        AudioSettings, BlendMode, Clip, Marker, Sequence, SequenceHdrSettings, Track,
+        AudioBus, AudioSettings, BlendMode, Clip, Marker, Sequence, SequenceHdrSettings, Track,
         TransformKeyframe,
     },
     AssetId, CoreError, CoreResult, EffectId, SequenceId,
@@ -99,6 +99,9 @@ pub struct ProjectMeta {
     /// Author name
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<String>,
+    /// Copyright notice embedded in exports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
     /// Format version: 1 = legacy (import-only), 2 = workspace-enabled, 3 = bins removed
     /// Defaults to 1 for backward compatibility with existing projects.
     #[serde(default = "default_format_version")]
@@ -121,6 +124,7 @@ impl ProjectMeta {
             modified_at: now,
             description: None,
             author: None,
+            copyright: None,
             format_version: 3,
         }
     }
@@ -286,16 +290,27 @@ impl ProjectState {
             OpKind::TrackRemove => self.apply_track_remove(op)?,
             OpKind::TrackReorder => self.apply_track_reorder(op)?,
             OpKind::TrackUpdate => self.apply_track_update(op)?,
+            OpKind::AudioBusCreate
+            | OpKind::AudioBusRemove
+            | OpKind::AudioBusUpdate
+            | OpKind::AudioBusRoute
+            | OpKind::AudioBusEffectAdd
+            | OpKind::AudioBusEffectRemove => self.apply_audio_routing(op)?,
 
             // Clip operations
             OpKind::ClipAdd => self.apply_clip_add(op)?,
             OpKind::ClipRemove => self.apply_clip_remove(op)?,
             OpKind::ClipMove => self.apply_clip_move(op)?,
             OpKind::ClipTrim => self.apply_clip_trim(op)?,
+            OpKind::ClipSlip | OpKind::ClipSlide | OpKind::ClipRoll => self.apply_clip_spans(op)?,
             OpKind::ClipSplit => self.apply_clip_split(op)?,
             OpKind::ClipUpdate => self.apply_clip_update(op)?,
             OpKind::CompoundClipCreate => self.apply_compound_clip_create(op)?,
             OpKind::CompoundClipUnnest => self.apply_compound_clip_unnest(op)?,
+            OpKind::MulticamClipCreate
+            | OpKind::MulticamAngleSwitch
+            | OpKind::MulticamClipFlatten
+            | OpKind::ClipSync => self.apply_clip_snapshots(op)?,
             OpKind::ClipGroup => self.apply_clip_group(op)?,
             OpKind::ClipUngroup => self.apply_clip_ungroup(op)?,
             OpKind::ClipLink => self.apply_clip_link(op)?,
@@ -452,6 +467,10 @@ impl ProjectState {
             if let Some(audio_value) = op.payload.get("audio") {
                 asset.audio = serde_json::from_value(audio_value.clone()).ok();
             }
+
+            if let Some(source_value) = op.payload.get("source") {
+                asset.source = serde_json::from_value(source_value.clone()).ok();
+            }
         }
         Ok(())
     }
@@ -736,6 +755,16 @@ impl ProjectState {
             } else {
                 None
             };
+            let next_start_timecode = match op.payload.get("startTimecode") {
+                Some(serde_json::Value::Null) => Some(None),
+                Some(serde_json::Value::String(text)) => Some(Some(text.clone())),
+                Some(_) => {
+                    return Err(CoreError::InvalidCommand(
+                        "Invalid startTimecode value (expected string or null)".to_string(),
+                    ));
+                }
+                None => None,
+            };
 
             // Commit all mutations after validation
             if let Some(name) = next_name {
@@ -747,6 +776,9 @@ impl ProjectState {
             if let Some(settings) = next_hdr_settings {
                 sequence.hdr_settings = settings;
             }
+            if let Some(start_timecode) = next_start_timecode {
+                sequence.start_timecode = start_timecode;
+            }
         }
         Ok(())
     }
@@ -1049,6 +1081,64 @@ impl ProjectState {
             }
         }
 
+        // Surround pan update; present only when the edit set it, and Null clears it
+        if let Some(surround_pan_value) = op.payload.get("surroundPan") {
+            track.surround_pan = serde_json::from_value(surround_pan_value.clone())
+                .map_err(|e| CoreError::InvalidCommand(format!("Invalid surroundPan: {}", e)))?;
+        }
+
+        Ok(())
+    }
+
+    fn apply_audio_routing(&mut self, op: &Operation) -> CoreResult<()> {
+        // Audio bus ops log the sequence's full routing; replay swaps it in and
+        // keeps the effect registry in step with the bus effect chains.
+        let seq_id = op.payload["sequenceId"]
+            .as_str()
+            .ok_or_else(|| CoreError::InvalidCommand("Missing sequenceId".to_string()))?;
+        let audio_buses: Vec<AudioBus> = serde_json::from_value(op.payload["audioBuses"].clone())
+            .map_err(|e| {
+            CoreError::InvalidCommand(format!("Invalid audioBuses payload: {e}"))
+        })?;
+        let mut effects = Vec::new();
+        for entry in op.payload["effects"].as_array().into_iter().flatten() {
+            let effect: Effect = serde_json::from_value(entry.clone())
+                .map_err(|e| CoreError::InvalidCommand(format!("Invalid effect data: {e}")))?;
+            effects.push(effect);
+        }
+
+        let sequence = self
+            .sequences
+            .get_mut(seq_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(seq_id.to_string()))?;
+        let kept_effect_ids: HashSet<&str> = audio_buses
+            .iter()
+            .flat_map(|bus| bus.effects.iter().map(String::as_str))
+            .collect();
+        let dropped_effect_ids: Vec<String> = sequence
+            .audio_buses
+            .iter()
+            .flat_map(|bus| bus.effects.iter())
+            .filter(|effect_id| !kept_effect_ids.contains(effect_id.as_str()))
+            .cloned()
+            .collect();
+        for (track_id, output_bus_id) in op.payload["trackOutputBuses"]
+            .as_object()
+            .into_iter()
+            .flatten()
+        {
+            if let Some(track) = sequence.get_track_mut(track_id) {
+                track.output_bus_id = output_bus_id.as_str().map(str::to_string);
+            }
+        }
+        sequence.audio_buses = audio_buses;
+
+        for effect_id in dropped_effect_ids {
+            self.effects.remove(&effect_id);
+        }
+        for effect in effects {
+            self.effects.insert(effect.id.clone(), effect);
+        }
         Ok(())
     }
 
@@ -1212,6 +1302,52 @@ impl ProjectState {
         Ok(())
     }
 
+    /// Replays a slip, slide or roll edit: every touched clip's realized
+    /// source range and placement, applied as one operation.
+    fn apply_clip_spans(&mut self, op: &Operation) -> CoreResult<()> {
+        let seq_id = op.payload["sequenceId"]
+            .as_str()
+            .ok_or_else(|| CoreError::InvalidCommand("Missing sequenceId".to_string()))?;
+        let spans = op.payload["clips"]
+            .as_array()
+            .ok_or_else(|| CoreError::InvalidCommand("Missing clips".to_string()))?;
+
+        if let Some(sequence) = self.sequences.get_mut(seq_id) {
+            let mut touched_tracks = HashSet::new();
+            for span in spans {
+                let Some(clip_id) = span["clipId"].as_str() else {
+                    continue;
+                };
+                for track in &mut sequence.tracks {
+                    if let Some(clip) = track.get_clip_mut(clip_id) {
+                        if let Some(source_in) = span["sourceIn"].as_f64() {
+                            clip.range.source_in_sec = source_in;
+                        }
+                        if let Some(source_out) = span["sourceOut"].as_f64() {
+                            clip.range.source_out_sec = source_out;
+                        }
+                        if let Some(timeline_in) = span["timelineIn"].as_f64() {
+                            clip.place.timeline_in_sec = timeline_in;
+                        }
+                        if let Some(duration) = span["duration"].as_f64() {
+                            clip.place.duration_sec = duration;
+                        }
+                        touched_tracks.insert(track.id.clone());
+                        break;
+                    }
+                }
+            }
+
+            for track in &mut sequence.tracks {
+                if touched_tracks.contains(&track.id) {
+                    Self::sort_track_clips(track);
+                    Self::warn_replayed_track_overlap(track);
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn apply_clip_split(&mut self, op: &Operation) -> CoreResult<()> {
         // Split creates a new clip and modifies the original
         // The payload should contain both the modified original and the new clip
@@ -2167,6 +2303,9 @@ impl ProjectState {
         if let Some(author) = op.payload["author"].as_str() {
             self.meta.author = Some(author.to_string());
         }
+        if let Some(copyright) = op.payload["copyright"].as_str() {
+            self.meta.copyright = Some(copyright.to_string());
+        }
         Ok(())
     }
 
@@ -2395,6 +2534,66 @@ impl ProjectState {
         Ok(())
     }
 
+    fn apply_clip_snapshots(&mut self, op: &Operation) -> CoreResult<()> {
+        // Multicam and sync ops log full snapshots of every clip they wrote (and
+        // any inner sequence they created); replay removes, then upserts them.
+        let seq_id = op.payload["sequenceId"]
+            .as_str()
+            .ok_or_else(|| CoreError::InvalidCommand("Missing sequenceId".to_string()))?;
+        let removed_clip_ids: HashSet<&str> = op.payload["removedClipIds"]
+            .as_array()
+            .map(|ids| ids.iter().filter_map(|id| id.as_str()).collect())
+            .unwrap_or_default();
+        let mut clips = Vec::new();
+        for entry in op.payload["clips"].as_array().into_iter().flatten() {
+            let track_id = entry["trackId"].as_str().ok_or_else(|| {
+                CoreError::InvalidCommand("Clip entry missing trackId".to_string())
+            })?;
+            let clip: Clip = serde_json::from_value(entry["clip"].clone())
+                .map_err(|e| CoreError::InvalidCommand(format!("Invalid clip data: {}", e)))?;
+            clips.push((track_id.to_string(), clip));
+        }
+        let inner_sequence = if op.payload["innerSequence"].is_null() {
+            None
+        } else {
+            Some(
+                serde_json::from_value::<Sequence>(op.payload["innerSequence"].clone()).map_err(
+                    |e| CoreError::InvalidCommand(format!("Invalid innerSequence payload: {e}")),
+                )?,
+            )
+        };
+
+        let sequence = self
+            .sequences
+            .get_mut(seq_id)
+            .ok_or_else(|| CoreError::SequenceNotFound(seq_id.to_string()))?;
+        for track in &mut sequence.tracks {
+            track
+                .clips
+                .retain(|clip| !removed_clip_ids.contains(clip.id.as_str()));
+        }
+        for (track_id, clip) in clips {
+            let track = sequence
+                .get_track_mut(&track_id)
+                .ok_or_else(|| CoreError::TrackNotFound(track_id.clone()))?;
+            match track
+                .clips
+                .iter_mut()
+                .find(|existing| existing.id == clip.id)
+            {
+                Some(existing) => *existing = clip,
+                None => track.add_clip(clip),
+            }
+            Self::sort_track_clips(track);
+            Self::warn_replayed_track_overlap(track);
+        }
+        if let Some(inner_sequence) = inner_sequence {
+            self.sequences
+                .insert(inner_sequence.id.clone(), inner_sequence);
+        }
+        Ok(())
+    }
+
     fn apply_clip_group(&mut self, op: &Operation) -> CoreResult<()> {
         // Backward compatibility: earlier builds persisted compound clip creation
         // under `clip_group`. Detect that payload shape and replay it accordingly.
@@ -56,6 +56,156 @@ pub struct MeasuredStreams {
     pub has_audio: bool,
 }
 
+/// Which signal a flash transition changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FlashKind {
+    /// A change in screen luminance
+    Luminance,
+    /// A change to or from saturated red
+    Red,
+}
+
+/// One half of a flash: a change in the picture large enough to count toward
+/// the photosensitivity guidelines.
+///
+/// A flash is a pair of opposing transitions, so the measurement records each
+/// transition with its direction and leaves the pairing and the per-second
+/// count to the rule.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlashTransition {
+    /// Time of the frame that completed the transition, in seconds
+    pub time_sec: f64,
+    /// Signal that changed
+    pub kind: FlashKind,
+    /// Whether the picture got brighter (or redder)
+    pub rising: bool,
+    /// Fraction of the screen that changed, from 0 to 1
+    pub screen_fraction: f64,
+}
+
+/// A run of consecutive frames whose levels leave the nominal video range.
+///
+/// Values are 8-bit code values (nominal range 16–235 for luma, 16–240 for
+/// chroma) and are the extremes across the whole run, so a rule applying a
+/// wider tolerance judges the run by its worst frame.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelExcursion {
+    /// Start of the first frame in the run, in seconds
+    pub start_sec: f64,
+    /// End of the last frame in the run, in seconds
+    pub end_sec: f64,
+    /// Lowest luma value in the run
+    pub luma_min: f64,
+    /// Highest luma value in the run
+    pub luma_max: f64,
+    /// Lowest Cb or Cr value in the run
+    pub chroma_min: f64,
+    /// Highest Cb or Cr value in the run
+    pub chroma_max: f64,
+    /// Largest share of one frame's pixels outside the nominal range, from 0 to 1
+    pub out_of_range_fraction: f64,
+}
+
+/// Where one stream's packets start and end in the measured file.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamSpan {
+    /// Presentation time of the earliest packet, in seconds
+    pub start_sec: f64,
+    /// Presentation time at which the last packet ends, in seconds
+    pub end_sec: f64,
+}
+
+impl StreamSpan {
+    /// Returns how long the stream runs, in seconds.
+    pub fn duration_sec(&self) -> f64 {
+        self.end_sec - self.start_sec
+    }
+}
+
+/// When the audio and video streams start and end, read from packet
+/// timestamps rather than the container header.
+///
+/// Container durations are the maximum across streams, so a file whose audio
+/// runs on past the picture reports one length for both. Only the packets say
+/// where each stream actually begins and ends.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamTiming {
+    /// Span of the first video stream, or `None` when it carries no packets
+    pub video: Option<StreamSpan>,
+    /// Span of the first audio stream, or `None` when it carries no packets
+    pub audio: Option<StreamSpan>,
+}
+
+/// How a frame interval broke the video stream's cadence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FrameTimingIssueKind {
+    /// Frames are missing: the interval spans more than one frame
+    Dropped,
+    /// A frame repeats a timestamp, or lands well inside the previous frame
+    Duplicated,
+}
+
+/// A run of frames whose timestamps break the nominal cadence.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameTimingIssue {
+    /// Timestamp of the last frame before the break, in seconds
+    pub start_sec: f64,
+    /// Timestamp of the first frame after the break, in seconds
+    pub end_sec: f64,
+    /// Whether frames went missing or were repeated
+    pub kind: FrameTimingIssueKind,
+    /// Number of frames dropped or duplicated across the run
+    pub frames: u32,
+}
+
+/// Frame cadence of the measured file's video stream, read from packet
+/// timestamps.
+///
+/// The nominal interval is the median gap between consecutive frames, so a
+/// handful of broken intervals cannot shift the cadence they are judged
+/// against. Intervals close to it but off by more than timestamp rounding are
+/// counted as irregular, which is what a variable-frame-rate stream looks
+/// like; intervals spanning whole extra frames, or collapsing onto the
+/// previous frame, are recorded as dropped or duplicated frames instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameTiming {
+    /// Number of frames with a usable timestamp
+    pub frame_count: u64,
+    /// Median interval between consecutive frames, in seconds
+    pub nominal_interval_sec: f64,
+    /// Shortest non-zero interval between consecutive frames, in seconds
+    pub min_interval_sec: f64,
+    /// Longest interval between consecutive frames, in seconds
+    pub max_interval_sec: f64,
+    /// Intervals off the nominal cadence that neither drop nor repeat a frame
+    pub irregular_intervals: u64,
+    /// Total frames missing from the cadence
+    pub dropped_frames: u64,
+    /// Total frames repeating the previous timestamp
+    pub duplicated_frames: u64,
+    /// Breaks in the cadence, in time order
+    ///
+    /// Capped by the measurement pass; the totals above keep counting past
+    /// the cap.
+    pub issues: Vec<FrameTimingIssue>,
+}
+
+impl FrameTiming {
+    /// Returns the frame rate the nominal interval implies.
+    pub fn measured_fps(&self) -> Option<f64> {
+        (self.nominal_interval_sec.is_finite() && self.nominal_interval_sec > 0.0)
+            .then(|| 1.0 / self.nominal_interval_sec)
+    }
+}
+
 /// Measurements captured from a rendered version of the sequence.
 ///
 /// Produced by the render measurement pass. Every field is optional or empty so
@@ -95,6 +245,28 @@ pub struct RenderMeasurements {
     /// skip rather than judge while this is `None`.
     #[serde(default)]
     pub streams: Option<MeasuredStreams>,
+    /// Luminance and red-flash transitions, in time order
+    ///
+    /// `None` means flashing was not measured, which a rule must not read as
+    /// "the picture does not flash".
+    #[serde(default)]
+    pub flash_transitions: Option<Vec<FlashTransition>>,
+    /// Runs of frames with luma or chroma outside the nominal video range
+    ///
+    /// `None` means levels were not measured.
+    #[serde(default)]
+    pub level_excursions: Option<Vec<LevelExcursion>>,
+    /// Where the audio and video streams start and end
+    ///
+    /// `None` means packet timing was not measured.
+    #[serde(default)]
+    pub stream_timing: Option<StreamTiming>,
+    /// Frame cadence of the video stream
+    ///
+    /// `None` means the cadence was not measured, including when the file has
+    /// too few frames to have one.
+    #[serde(default)]
+    pub frame_timing: Option<FrameTiming>,
 }
 
 impl RenderMeasurements {
@@ -11,10 +11,10 @@ use tokio::sync::RwLock;
 
 use super::context::{QCContext, RenderMeasurements};
 use super::rules::{
-    AspectRatioRule, AudioClippingRule, AudioLoudnessRule, AudioPeakRule, BlackFrameRule,
-    CaptionSafeAreaRule, CheckCategory, CutRhythmRule, DurationRule, FrozenProgramRule,
-    LicenseRule, MissingVideoStreamRule, QCRule, RenderDurationRule, RenderResolutionRule,
-    RuleConfig,
+    AspectRatioRule, AudioClippingRule, AudioLoudnessRule, AudioPeakRule, AvSyncRule,
+    BlackFrameRule, BroadcastLegalRule, CaptionSafeAreaRule, CheckCategory, CutRhythmRule,
+    DurationRule, FrameTimingRule, FrozenProgramRule, LicenseRule, MissingVideoStreamRule,
+    PhotosensitiveFlashRule, QCRule, RenderDurationRule, RenderResolutionRule, RuleConfig,
 };
 use super::structural::{
     CaptionOutOfBoundsRule, CaptionOverlapRule, CaptionReadingRateRule, ClipOrphanRule,
@@ -407,8 +407,12 @@ impl QCEngine {
         self.register_rule(Arc::new(RenderDurationRule::new()));
         self.register_rule(Arc::new(MissingVideoStreamRule::new()));
         self.register_rule(Arc::new(RenderResolutionRule::new()));
+        self.register_rule(Arc::new(AvSyncRule::new()));
+        self.register_rule(Arc::new(FrameTimingRule::new()));
         self.register_rule(Arc::new(BlackFrameRule::new()));
         self.register_rule(Arc::new(FrozenProgramRule::new()));
+        self.register_rule(Arc::new(PhotosensitiveFlashRule::new()));
+        self.register_rule(Arc::new(BroadcastLegalRule::new()));
         self.register_rule(Arc::new(AudioPeakRule::new()));
         self.register_rule(Arc::new(AudioClippingRule::new()));
         self.register_rule(Arc::new(AudioLoudnessRule::new()));
@@ -856,7 +860,11 @@ mod tests {
         // taken and the finding never made.
         assert!(names.contains(&"MissingVideoStreamRule"));
         assert!(names.contains(&"RenderResolutionRule"));
+        assert!(names.contains(&"AvSyncRule"));
+        assert!(names.contains(&"FrameTimingRule"));
         assert!(names.contains(&"FrozenProgramRule"));
+        assert!(names.contains(&"PhotosensitiveFlashRule"));
+        assert!(names.contains(&"BroadcastLegalRule"));
         assert!(names.contains(&"AudioClippingRule"));
         assert!(names.contains(&"CaptionSafeAreaRule"));
         assert!(names.contains(&"CutRhythmRule"));
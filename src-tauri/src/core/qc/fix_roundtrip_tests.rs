@@ -14,7 +14,7 @@
 use crate::core::assets::{Asset, AudioInfo, VideoInfo};
 use crate::core::captions::CaptionPosition;
 use crate::core::project::ProjectState;
-use crate::core::qc::context::RenderMeasurements;
+use crate::core::qc::context::{LevelExcursion, RenderMeasurements};
 use crate::core::qc::engine::QCEngine;
 use crate::core::qc::violation::QCViolation;
 use crate::core::timeline::{Clip, Sequence, SequenceFormat, Track};
@@ -26,6 +26,7 @@ use crate::ipc::CommandPayload;
 /// unexecutable fix is caught by the parse, and a fix that quietly stopped
 /// being emitted is caught by the coverage assertion.
 const EXPECTED_FIX_COMMAND_TYPES: &[&str] = &[
+    "AddEffect",
     "CloseGap",
     "RemoveClip",
     "SetMasterVolume",
@@ -79,6 +80,7 @@ fn fixture_asset(id: &str) -> Asset {
         channels: 2,
         codec: "aac".to_string(),
         bitrate: None,
+        channel_layout: None,
     });
     asset
 }
@@ -97,6 +99,7 @@ fn video_clip(asset_id: &str, timeline_in_sec: f64, duration_sec: f64) -> Clip {
 /// * a caption pinned to the very bottom of the canvas — `UpdateCaption`
 /// * black at the head of a clip whose source has room — `TrimClip`
 /// * a clipped, over-loud mix — `SetMasterVolume`
+/// * super-whites under a clip — `AddEffect`
 fn project_with_every_fixable_finding() -> (Sequence, ProjectState) {
     const ASSET_ID: &str = "asset_fixture";
 
@@ -146,6 +149,17 @@ fn measurements_for(sequence: &Sequence) -> RenderMeasurements {
         // than a boost the peak would forbid.
         integrated_lufs: Some(-8.0),
         file_duration_sec: Some(sequence.duration()),
+        // Super-whites under the second clip: the levels rule suggests a
+        // legalizer on it.
+        level_excursions: Some(vec![LevelExcursion {
+            start_sec: 8.0,
+            end_sec: 9.0,
+            luma_min: 16.0,
+            luma_max: 254.0,
+            chroma_min: 16.0,
+            chroma_max: 240.0,
+            out_of_range_fraction: 0.2,
+        }]),
         ..Default::default()
     }
 }
@@ -7,14 +7,28 @@
 //! shared with every rule through the QC context.
 //!
 //! Every parser in this module is a pure function over FFmpeg stderr text so it
-//! can be tested against captured output without invoking FFmpeg.
+//! can be tested against captured output without invoking FFmpeg. Flashing,
+//! video levels and packet timing are the exceptions: they need every frame or
+//! packet, so they run as passes of their own and stream into
+//! `FlashAnalyzer`, `LevelTracker` and `PacketTimingTracker`, which are
+//! equally pure.
 
+use std::future::Future;
 use std::path::Path;
+use std::process::Stdio;
 use std::time::Duration;
 
-use super::context::{MeasuredStreams, MeasuredVideoStream, RenderMeasurements};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::process::Child;
+
+use super::context::{
+    FlashKind, FlashTransition, FrameTiming, FrameTimingIssue, FrameTimingIssueKind,
+    LevelExcursion, MeasuredStreams, MeasuredVideoStream, RenderMeasurements, StreamSpan,
+    StreamTiming,
+};
 use crate::core::analysis::audio::parse_silence_regions;
 use crate::core::ffmpeg::FFmpegRunner;
+use crate::core::process::configure_tokio_command;
 use crate::core::{CoreError, CoreResult};
 
 // =============================================================================
@@ -246,6 +260,39 @@ pub async fn measure_rendered_file_detailed(
         }
     }
 
+    let fps = media
+        .video
+        .as_ref()
+        .map(|video| video.fps)
+        .filter(|fps| fps.is_finite() && *fps > 0.0)
+        .unwrap_or(FALLBACK_FLASH_FPS);
+
+    if has_video {
+        match measure_flash_transitions(runner, file, fps, opts.timeout).await {
+            Ok(transitions) => measurements.flash_transitions = Some(transitions),
+            Err(error) => notes.push(format!(
+                "Flash analysis failed; photosensitivity checks were not run: {error}"
+            )),
+        }
+        match measure_level_excursions(runner, file, fps, opts.timeout).await {
+            Ok(excursions) => measurements.level_excursions = Some(excursions),
+            Err(error) => notes.push(format!(
+                "Level analysis failed; broadcast-legal checks were not run: {error}"
+            )),
+        }
+    }
+
+    match measure_packet_timing(runner, file, opts.timeout).await {
+        Ok(tracker) => {
+            let (stream_timing, frame_timing) = tracker.finish(1.0 / fps);
+            measurements.stream_timing = Some(stream_timing);
+            measurements.frame_timing = frame_timing;
+        }
+        Err(error) => notes.push(format!(
+            "Packet timing analysis failed; sync and frame-timing checks were not run: {error}"
+        )),
+    }
+
     Ok(MeasurementReport {
         measurements,
         duration_sec,
@@ -296,6 +343,738 @@ fn build_filter_graph(
     (chains.join(";"), maps)
 }
 
+// =============================================================================
+// Flash analysis
+// =============================================================================
+
+/// Grid the picture is averaged down to for flash analysis. Each cell is
+/// 1/576 of the screen, fine enough to grade the quarter-screen threshold.
+const FLASH_GRID_WIDTH: usize = 32;
+const FLASH_GRID_HEIGHT: usize = 18;
+
+/// Peak luminance of the reference display the guidelines assume, in cd/m².
+const FLASH_DISPLAY_PEAK_CD_M2: f64 = 200.0;
+
+/// Luminance swing that makes a transition, in cd/m² (ITU-R BT.1702).
+const FLASH_MIN_LUMINANCE_CHANGE: f64 = 20.0;
+
+/// A luminance swing only counts while its darker end is below this, in cd/m².
+const FLASH_MAX_DARK_LUMINANCE: f64 = 160.0;
+
+/// Share of the linear RGB sum above which a colour counts as saturated red.
+const SATURATED_RED_RATIO: f64 = 0.8;
+
+/// Swing that makes a red transition, on the `(R - G - B) × 320` scale.
+const FLASH_MIN_RED_CHANGE: f64 = 20.0;
+
+/// Movement against a run smaller than this is noise, not a reversal.
+const FLASH_REVERSAL_TOLERANCE: f64 = 2.0;
+
+/// Transitions covering less of the screen than this are not recorded.
+pub const MIN_RECORDED_FLASH_FRACTION: f64 = 0.1;
+
+/// Frame rate the picture is sampled at when the probe reports none.
+const FALLBACK_FLASH_FPS: f64 = 30.0;
+
+/// Streams the picture through FFmpeg, averaged down to the flash grid, and
+/// records the transitions in it.
+async fn measure_flash_transitions(
+    runner: &FFmpegRunner,
+    file: &Path,
+    fps: f64,
+    timeout: Duration,
+) -> CoreResult<Vec<FlashTransition>> {
+    let mut cmd = tokio::process::Command::new(&runner.info().ffmpeg_path);
+    configure_tokio_command(&mut cmd);
+    cmd.args([
+        "-hide_banner",
+        "-nostats",
+        "-nostdin",
+        "-loglevel",
+        "error",
+        "-i",
+    ])
+    .arg(file)
+    .args(["-map", "0:v:0", "-vf"])
+    .arg(format!(
+        "fps={fps},scale={FLASH_GRID_WIDTH}:{FLASH_GRID_HEIGHT}:flags=area,format=rgb24"
+    ))
+    .args(["-f", "rawvideo", "-"])
+    .stdin(Stdio::null())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::null());
+
+    let mut child = spawn_analysis(&mut cmd)?;
+    let mut stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| CoreError::AnalysisFailed("FFmpeg stdout was not captured".to_string()))?;
+
+    let read = async {
+        let mut analyzer = FlashAnalyzer::new(fps);
+        let mut frame = vec![0_u8; FLASH_GRID_WIDTH * FLASH_GRID_HEIGHT * 3];
+        loop {
+            match stdout.read_exact(&mut frame).await {
+                Ok(_) => analyzer.push_frame(&frame),
+                Err(error) if error.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                Err(error) => return Err(error),
+            }
+        }
+        Ok(analyzer.finish())
+    };
+
+    finish_analysis(child, timeout, "Flash analysis", read).await
+}
+
+/// Starts an analysis FFmpeg process that dies with its handle.
+fn spawn_analysis(cmd: &mut tokio::process::Command) -> CoreResult<Child> {
+    cmd.kill_on_drop(true)
+        .spawn()
+        .map_err(|error| CoreError::AnalysisFailed(format!("Failed to start FFmpeg: {error}")))
+}
+
+/// Drives `read` over a running analysis process and waits for it to exit,
+/// killing it if the pair overruns `timeout`.
+async fn finish_analysis<T>(
+    mut child: Child,
+    timeout: Duration,
+    label: &str,
+    read: impl Future<Output = std::io::Result<T>>,
+) -> CoreResult<T> {
+    let run = async {
+        let value = read.await?;
+        let status = child.wait().await?;
+        Ok::<_, std::io::Error>((value, status))
+    };
+
+    match tokio::time::timeout(timeout, run).await {
+        Ok(Ok((value, status))) if status.success() => Ok(value),
+        Ok(Ok((_, status))) => Err(CoreError::AnalysisFailed(format!(
+            "{label}: FFmpeg exited with {status}"
+        ))),
+        Ok(Err(error)) => Err(CoreError::AnalysisFailed(format!(
+            "{label}: failed to read FFmpeg output: {error}"
+        ))),
+        Err(_) => {
+            let _ = child.kill().await;
+            Err(CoreError::Timeout(format!(
+                "{label} timed out after {}s",
+                timeout.as_secs()
+            )))
+        }
+    }
+}
+
+/// One grid cell's current run of brightening or darkening.
+///
+/// A transition is a swing between a turning point and the furthest value
+/// reached since, so a fade spread over several frames counts once rather than
+/// not at all, and a run reports at most one transition.
+#[derive(Debug, Clone, Copy)]
+struct FlashCell {
+    anchor: f64,
+    extreme: f64,
+    rising: Option<bool>,
+    reported: bool,
+}
+
+impl FlashCell {
+    fn new(value: f64) -> Self {
+        Self {
+            anchor: value,
+            extreme: value,
+            rising: None,
+            reported: false,
+        }
+    }
+
+    /// Feeds the cell's next value, returning the direction of a transition
+    /// this value completed.
+    fn update(&mut self, value: f64, min_change: f64, max_dark: f64) -> Option<bool> {
+        let continues = match self.rising {
+            Some(true) => value >= self.extreme,
+            Some(false) => value <= self.extreme,
+            None => false,
+        };
+
+        if continues {
+            self.extreme = value;
+        } else if (value - self.extreme).abs() > FLASH_REVERSAL_TOLERANCE {
+            // A reversal starts a new run from the turning point.
+            self.anchor = self.extreme;
+            self.extreme = value;
+            self.rising = Some(value > self.anchor);
+            self.reported = false;
+        } else {
+            return None;
+        }
+
+        if self.reported
+            || (self.extreme - self.anchor).abs() < min_change
+            || self.extreme.min(self.anchor) >= max_dark
+        {
+            return None;
+        }
+        self.reported = true;
+        self.rising
+    }
+}
+
+/// Turns a stream of `rgb24` frames on the flash grid into the transitions
+/// photosensitivity rules pair into flashes.
+///
+/// Luminance follows ITU-R BT.1702 on a 200 cd/m² reference display; red
+/// follows the saturated-red definition those guidelines share, where a
+/// colour is saturated red when red is at least 80% of its linear RGB sum.
+#[derive(Debug)]
+struct FlashAnalyzer {
+    fps: f64,
+    frame_index: u64,
+    linear: [f64; 256],
+    luminance: Vec<FlashCell>,
+    red: Vec<FlashCell>,
+    transitions: Vec<FlashTransition>,
+}
+
+impl FlashAnalyzer {
+    /// Creates an analyzer for frames sampled at `fps`.
+    fn new(fps: f64) -> Self {
+        let mut linear = [0.0; 256];
+        for (code, value) in linear.iter_mut().enumerate() {
+            let encoded = code as f64 / 255.0;
+            *value = if encoded <= 0.04045 {
+                encoded / 12.92
+            } else {
+                ((encoded + 0.055) / 1.055).powf(2.4)
+            };
+        }
+
+        Self {
+            fps,
+            frame_index: 0,
+            linear,
+            luminance: Vec::new(),
+            red: Vec::new(),
+            transitions: Vec::new(),
+        }
+    }
+
+    /// Feeds one frame of packed `rgb24` pixels, one pixel per grid cell.
+    fn push_frame(&mut self, rgb: &[u8]) {
+        let first = self.luminance.is_empty();
+        let mut luminance_moves = [0_usize; 2];
+        let mut red_moves = [0_usize; 2];
+
+        for (index, pixel) in rgb.chunks_exact(3).enumerate() {
+            let r = self.linear[pixel[0] as usize];
+            let g = self.linear[pixel[1] as usize];
+            let b = self.linear[pixel[2] as usize];
+            let luminance = FLASH_DISPLAY_PEAK_CD_M2 * (0.2126 * r + 0.7152 * g + 0.0722 * b);
+            let sum = r + g + b;
+            let red = if sum > 0.0 && r / sum >= SATURATED_RED_RATIO {
+                (r - g - b).max(0.0) * 320.0
+            } else {
+                0.0
+            };
+
+            if first {
+                self.luminance.push(FlashCell::new(luminance));
+                self.red.push(FlashCell::new(red));
+                continue;
+            }
+
+            let Some((luminance_cell, red_cell)) =
+                self.luminance.get_mut(index).zip(self.red.get_mut(index))
+            else {
+                continue;
+            };
+            if let Some(rising) = luminance_cell.update(
+                luminance,
+                FLASH_MIN_LUMINANCE_CHANGE,
+                FLASH_MAX_DARK_LUMINANCE,
+            ) {
+                luminance_moves[usize::from(rising)] += 1;
+            }
+            if let Some(rising) = red_cell.update(red, FLASH_MIN_RED_CHANGE, f64::INFINITY) {
+                red_moves[usize::from(rising)] += 1;
+            }
+        }
+
+        let cells = self.luminance.len().max(1) as f64;
+        let time_sec = self.frame_index as f64 / self.fps;
+        self.frame_index += 1;
+
+        for (kind, moves) in [
+            (FlashKind::Luminance, luminance_moves),
+            (FlashKind::Red, red_moves),
+        ] {
+            for rising in [false, true] {
+                let screen_fraction = moves[usize::from(rising)] as f64 / cells;
+                if screen_fraction >= MIN_RECORDED_FLASH_FRACTION {
+                    self.transitions.push(FlashTransition {
+                        time_sec,
+                        kind,
+                        rising,
+                        screen_fraction,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Returns the recorded transitions in frame order.
+    fn finish(self) -> Vec<FlashTransition> {
+        self.transitions
+    }
+}
+
+// =============================================================================
+// Video levels
+// =============================================================================
+
+/// Nominal 8-bit luma range; frames outside it start a level excursion.
+const NOMINAL_LUMA_RANGE: (f64, f64) = (16.0, 235.0);
+
+/// Nominal 8-bit chroma range; frames outside it start a level excursion.
+const NOMINAL_CHROMA_RANGE: (f64, f64) = (16.0, 240.0);
+
+/// Key prefix `signalstats` writes its per-frame values under.
+const SIGNALSTATS_KEY_PREFIX: &str = "lavfi.signalstats.";
+
+/// Runs `signalstats` over every frame and records the runs of frames whose
+/// levels leave the nominal range.
+///
+/// Runs are always split against the nominal range, whatever limits the
+/// broadcast-legal rule is configured with; the rule re-grades them. A wider
+/// preset such as EBU R103 therefore sees every frame it would flag, inside
+/// runs bounded by the nominal limits.
+///
+/// The picture is converted to 8-bit 4:2:0 first so the values are on the
+/// scale the limits are written in whatever the render's depth.
+async fn measure_level_excursions(
+    runner: &FFmpegRunner,
+    file: &Path,
+    fps: f64,
+    timeout: Duration,
+) -> CoreResult<Vec<LevelExcursion>> {
+    let mut cmd = tokio::process::Command::new(&runner.info().ffmpeg_path);
+    configure_tokio_command(&mut cmd);
+    cmd.args([
+        "-hide_banner",
+        "-nostats",
+        "-nostdin",
+        "-loglevel",
+        "info",
+        "-i",
+    ])
+    .arg(file)
+    .args([
+        "-map",
+        "0:v:0",
+        "-vf",
+        "format=yuv420p,signalstats=stat=brng,metadata=mode=print",
+        "-f",
+        "null",
+        "-",
+    ])
+    .stdin(Stdio::null())
+    .stdout(Stdio::null())
+    .stderr(Stdio::piped());
+
+    let mut child = spawn_analysis(&mut cmd)?;
+    let stderr = child
+        .stderr
+        .take()
+        .ok_or_else(|| CoreError::AnalysisFailed("FFmpeg stderr was not captured".to_string()))?;
+
+    // Streamed rather than captured: the log carries a block per frame, far
+    // past what a retained stderr buffer holds for a full-length render.
+    let read = async {
+        let mut tracker = LevelTracker::default();
+        let mut lines = BufReader::new(stderr).split(b'\n');
+        while let Some(line) = lines.next_segment().await? {
+            tracker.push_line(&String::from_utf8_lossy(&line));
+        }
+        Ok(tracker)
+    };
+
+    let tracker = finish_analysis(child, timeout, "Level analysis", read).await?;
+    if tracker.frames == 0 {
+        return Err(CoreError::AnalysisFailed(
+            "signalstats reported no frames, so levels cannot be trusted".to_string(),
+        ));
+    }
+    Ok(tracker.finish(1.0 / fps))
+}
+
+/// Per-frame `signalstats` values, as 8-bit code values.
+#[derive(Debug, Clone, Copy)]
+struct FrameLevels {
+    time_sec: f64,
+    luma_min: f64,
+    luma_max: f64,
+    chroma_min: f64,
+    chroma_max: f64,
+    out_of_range_fraction: f64,
+}
+
+impl FrameLevels {
+    fn at(time_sec: f64) -> Self {
+        Self {
+            time_sec,
+            luma_min: NOMINAL_LUMA_RANGE.0,
+            luma_max: NOMINAL_LUMA_RANGE.1,
+            chroma_min: NOMINAL_CHROMA_RANGE.0,
+            chroma_max: NOMINAL_CHROMA_RANGE.1,
+            out_of_range_fraction: 0.0,
+        }
+    }
+
+    fn is_nominal(&self) -> bool {
+        self.luma_min >= NOMINAL_LUMA_RANGE.0
+            && self.luma_max <= NOMINAL_LUMA_RANGE.1
+            && self.chroma_min >= NOMINAL_CHROMA_RANGE.0
+            && self.chroma_max <= NOMINAL_CHROMA_RANGE.1
+    }
+}
+
+/// Folds `metadata=mode=print` log lines into runs of out-of-range frames.
+///
+/// Each frame opens with a `frame:N pts:P pts_time:T` line followed by one
+/// `key=value` line per statistic. A frame ends where the next begins, so the
+/// last one is closed with the nominal frame duration in [`Self::finish`].
+#[derive(Debug, Default)]
+struct LevelTracker {
+    frames: u64,
+    current: Option<FrameLevels>,
+    open: Option<LevelExcursion>,
+    excursions: Vec<LevelExcursion>,
+}
+
+impl LevelTracker {
+    fn push_line(&mut self, line: &str) {
+        let body = strip_log_prefix(line).trim();
+
+        if body.starts_with("frame:") {
+            if let Some(time_sec) = extract_marker_value(body, "pts_time") {
+                self.close_frame(time_sec);
+                self.current = Some(FrameLevels::at(time_sec));
+                self.frames += 1;
+            }
+            return;
+        }
+
+        let Some(current) = self.current.as_mut() else {
+            return;
+        };
+        let Some((key, value)) = body
+            .strip_prefix(SIGNALSTATS_KEY_PREFIX)
+            .and_then(|stat| stat.split_once('='))
+        else {
+            return;
+        };
+        let Some(value) = parse_leading_f64(value).filter(|value| value.is_finite()) else {
+            return;
+        };
+
+        match key {
+            "YMIN" => current.luma_min = value,
+            "YMAX" => current.luma_max = value,
+            "UMIN" | "VMIN" => current.chroma_min = current.chroma_min.min(value),
+            "UMAX" | "VMAX" => current.chroma_max = current.chroma_max.max(value),
+            "BRNG" => current.out_of_range_fraction = value.clamp(0.0, 1.0),
+            _ => {}
+        }
+    }
+
+    /// Ends the frame in progress at `end_sec` and extends or closes the run.
+    fn close_frame(&mut self, end_sec: f64) {
+        let Some(frame) = self.current.take() else {
+            return;
+        };
+
+        if frame.is_nominal() {
+            self.excursions.extend(self.open.take());
+            return;
+        }
+
+        let end_sec = end_sec.max(frame.time_sec);
+        match self.open.as_mut() {
+            Some(run) => {
+                run.end_sec = end_sec;
+                run.luma_min = run.luma_min.min(frame.luma_min);
+                run.luma_max = run.luma_max.max(frame.luma_max);
+                run.chroma_min = run.chroma_min.min(frame.chroma_min);
+                run.chroma_max = run.chroma_max.max(frame.chroma_max);
+                run.out_of_range_fraction =
+                    run.out_of_range_fraction.max(frame.out_of_range_fraction);
+            }
+            None => {
+                self.open = Some(LevelExcursion {
+                    start_sec: frame.time_sec,
+                    end_sec,
+                    luma_min: frame.luma_min,
+                    luma_max: frame.luma_max,
+                    chroma_min: frame.chroma_min,
+                    chroma_max: frame.chroma_max,
+                    out_of_range_fraction: frame.out_of_range_fraction,
+                })
+            }
+        }
+    }
+
+    /// Closes the last frame and returns the runs in time order.
+    fn finish(mut self, frame_duration_sec: f64) -> Vec<LevelExcursion> {
+        if let Some(time_sec) = self.current.as_ref().map(|frame| frame.time_sec) {
+            self.close_frame(time_sec + frame_duration_sec);
+        }
+        self.excursions.extend(self.open.take());
+        self.excursions
+    }
+}
+
+// =============================================================================
+// Packet timing
+// =============================================================================
+
+/// Slack allowed between a frame interval and the nominal one before it
+/// counts as irregular, in seconds.
+///
+/// Millisecond timebases (Matroska, WebM) round a 29.97 fps cadence to
+/// alternating 33 and 34 ms intervals; that is rounding, not a variable rate.
+const FRAME_TIMESTAMP_JITTER_SEC: f64 = 0.0011;
+
+/// Slack allowed as a share of the nominal interval, for slow cadences where
+/// a fraction of a frame is wider than the absolute slack.
+const FRAME_INTERVAL_JITTER_FRACTION: f64 = 0.01;
+
+/// An interval at least this many nominal intervals long has lost a frame.
+const DROPPED_FRAME_INTERVAL_RATIO: f64 = 1.5;
+
+/// An interval at most this share of the nominal interval repeats a frame.
+const DUPLICATED_FRAME_INTERVAL_RATIO: f64 = 0.5;
+
+/// Cadence breaks recorded individually; the totals keep counting past it.
+const MAX_RECORDED_FRAME_ISSUES: usize = 500;
+
+/// Reads every packet's timestamps with ffprobe and folds them into stream
+/// spans and the video cadence.
+///
+/// Packets rather than decoded frames: the timestamps are all the rules need,
+/// and reading them costs a demux instead of a decode.
+async fn measure_packet_timing(
+    runner: &FFmpegRunner,
+    file: &Path,
+    timeout: Duration,
+) -> CoreResult<PacketTimingTracker> {
+    let mut cmd = tokio::process::Command::new(&runner.info().ffprobe_path);
+    configure_tokio_command(&mut cmd);
+    cmd.args([
+        "-v",
+        "error",
+        "-show_entries",
+        "packet=codec_type,pts_time,duration_time",
+        "-of",
+        "compact=p=0",
+    ])
+    .arg(file)
+    .stdin(Stdio::null())
+    .stdout(Stdio::piped())
+    .stderr(Stdio::null());
+
+    let mut child = spawn_analysis(&mut cmd)?;
+    let stdout = child
+        .stdout
+        .take()
+        .ok_or_else(|| CoreError::AnalysisFailed("FFprobe stdout was not captured".to_string()))?;
+
+    // One line per packet, so a long render is streamed like the level log.
+    let read = async {
+        let mut tracker = PacketTimingTracker::default();
+        let mut lines = BufReader::new(stdout).split(b'\n');
+        while let Some(line) = lines.next_segment().await? {
+            tracker.push_line(&String::from_utf8_lossy(&line));
+        }
+        Ok(tracker)
+    };
+
+    let tracker = finish_analysis(child, timeout, "Packet timing analysis", read).await?;
+    if tracker.is_empty() {
+        return Err(CoreError::AnalysisFailed(
+            "ffprobe reported no timed packets, so stream timing cannot be trusted".to_string(),
+        ));
+    }
+    Ok(tracker)
+}
+
+/// Folds ffprobe `compact` packet lines into per-stream spans and the list
+/// of video presentation timestamps.
+///
+/// Packets arrive in decode order, which B-frames take out of presentation
+/// order, so video timestamps are kept and sorted in [`Self::finish`]. Audio
+/// only needs its extent.
+#[derive(Debug, Default)]
+struct PacketTimingTracker {
+    video_pts: Vec<f64>,
+    video_end_sec: Option<f64>,
+    audio: Option<StreamSpan>,
+}
+
+impl PacketTimingTracker {
+    /// Feeds one `codec_type=…|pts_time=…|duration_time=…` line.
+    fn push_line(&mut self, line: &str) {
+        let mut codec_type = None;
+        let mut pts = None;
+        let mut duration = None;
+        for field in line.trim().split('|') {
+            match field.split_once('=') {
+                Some(("codec_type", value)) => codec_type = Some(value),
+                Some(("pts_time", value)) => {
+                    pts = value.parse::<f64>().ok().filter(|pts| pts.is_finite())
+                }
+                Some(("duration_time", value)) => {
+                    duration = value
+                        .parse::<f64>()
+                        .ok()
+                        .filter(|duration| duration.is_finite() && *duration >= 0.0)
+                }
+                _ => {}
+            }
+        }
+
+        // Packets without a presentation time (`N/A`) cannot be placed.
+        let Some(pts) = pts else {
+            return;
+        };
+        match codec_type {
+            Some("video") => {
+                self.video_pts.push(pts);
+                if let Some(duration) = duration {
+                    let end = pts + duration;
+                    self.video_end_sec =
+                        Some(self.video_end_sec.map_or(end, |current| current.max(end)));
+                }
+            }
+            Some("audio") => {
+                let end = pts + duration.unwrap_or(0.0);
+                let span = self.audio.get_or_insert(StreamSpan {
+                    start_sec: pts,
+                    end_sec: end,
+                });
+                span.start_sec = span.start_sec.min(pts);
+                span.end_sec = span.end_sec.max(end);
+            }
+            _ => {}
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.video_pts.is_empty() && self.audio.is_none()
+    }
+
+    /// Returns the stream spans and the video cadence.
+    ///
+    /// The last frame is held for the nominal interval when its packet
+    /// reported no duration, falling back to `fallback_interval_sec` when the
+    /// stream has too few frames to have a cadence.
+    fn finish(mut self, fallback_interval_sec: f64) -> (StreamTiming, Option<FrameTiming>) {
+        self.video_pts.sort_by(f64::total_cmp);
+        let frame_timing = analyze_frame_timing(&self.video_pts);
+
+        let video =
+            self.video_pts
+                .first()
+                .zip(self.video_pts.last())
+                .map(|(&start_sec, &last_sec)| {
+                    let interval = frame_timing
+                        .as_ref()
+                        .map_or(fallback_interval_sec, |timing| timing.nominal_interval_sec);
+                    StreamSpan {
+                        start_sec,
+                        end_sec: self
+                            .video_end_sec
+                            .unwrap_or(last_sec + interval)
+                            .max(last_sec),
+                    }
+                });
+
+        (
+            StreamTiming {
+                video,
+                audio: self.audio,
+            },
+            frame_timing,
+        )
+    }
+}
+
+/// Grades the intervals between sorted presentation timestamps against their
+/// median, or returns `None` when there is no interval to take one from.
+fn analyze_frame_timing(pts: &[f64]) -> Option<FrameTiming> {
+    let mut intervals: Vec<f64> = pts
+        .windows(2)
+        .map(|pair| pair[1] - pair[0])
+        .filter(|interval| *interval > 0.0)
+        .collect();
+    if intervals.is_empty() {
+        return None;
+    }
+    intervals.sort_by(f64::total_cmp);
+    let nominal = intervals[intervals.len() / 2];
+    let jitter = FRAME_TIMESTAMP_JITTER_SEC.max(nominal * FRAME_INTERVAL_JITTER_FRACTION);
+
+    let mut timing = FrameTiming {
+        frame_count: pts.len() as u64,
+        nominal_interval_sec: nominal,
+        min_interval_sec: intervals[0],
+        max_interval_sec: intervals[intervals.len() - 1],
+        irregular_intervals: 0,
+        dropped_frames: 0,
+        duplicated_frames: 0,
+        issues: Vec::new(),
+    };
+
+    for pair in pts.windows(2) {
+        let (start_sec, end_sec) = (pair[0], pair[1]);
+        let interval = end_sec - start_sec;
+        let (kind, frames) = if interval <= nominal * DUPLICATED_FRAME_INTERVAL_RATIO {
+            timing.duplicated_frames += 1;
+            (FrameTimingIssueKind::Duplicated, 1)
+        } else if interval >= nominal * DROPPED_FRAME_INTERVAL_RATIO {
+            let missing = ((interval / nominal).round() as u32)
+                .saturating_sub(1)
+                .max(1);
+            timing.dropped_frames += u64::from(missing);
+            (FrameTimingIssueKind::Dropped, missing)
+        } else {
+            if (interval - nominal).abs() > jitter {
+                timing.irregular_intervals += 1;
+            }
+            continue;
+        };
+
+        let issue = FrameTimingIssue {
+            start_sec,
+            end_sec,
+            kind,
+            frames,
+        };
+        // Back-to-back breaks of the same kind are one run.
+        if let Some(last) = timing
+            .issues
+            .last_mut()
+            .filter(|last| last.kind == kind && last.end_sec >= start_sec)
+        {
+            last.end_sec = end_sec;
+            last.frames = last.frames.saturating_add(frames);
+        } else if timing.issues.len() < MAX_RECORDED_FRAME_ISSUES {
+            timing.issues.push(issue);
+        }
+    }
+
+    Some(timing)
+}
+
 // =============================================================================
 // Parsers (pure, testable without FFmpeg)
 // =============================================================================
@@ -792,4 +1571,333 @@ Output #0, null, to 'pipe:':";
         assert!(graph.contains("d=0.010[v]"));
         assert!(graph.contains("silencedetect=n=-90.0dB:d=600.000"));
     }
+
+    // ========================================================================
+    // Flash analysis
+    // ========================================================================
+
+    const GRID_CELLS: usize = FLASH_GRID_WIDTH * FLASH_GRID_HEIGHT;
+
+    /// A grid frame whose first `lit` cells are `on` and the rest `off`.
+    fn grid_frame(lit: usize, on: [u8; 3], off: [u8; 3]) -> Vec<u8> {
+        (0..GRID_CELLS)
+            .flat_map(|cell| if cell < lit { on } else { off })
+            .collect()
+    }
+
+    #[test]
+    fn test_flash_analyzer_should_record_full_screen_luminance_flashes() {
+        let mut analyzer = FlashAnalyzer::new(10.0);
+        for frame in 0..4 {
+            let lit = if frame % 2 == 0 { 0 } else { GRID_CELLS };
+            analyzer.push_frame(&grid_frame(lit, [255, 255, 255], [0, 0, 0]));
+        }
+
+        let transitions = analyzer.finish();
+
+        let luminance: Vec<_> = transitions
+            .iter()
+            .filter(|transition| transition.kind == FlashKind::Luminance)
+            .collect();
+        assert_eq!(luminance.len(), 3);
+        assert!(luminance[0].rising);
+        assert!(!luminance[1].rising);
+        assert!((luminance[1].time_sec - 0.2).abs() < 1e-9);
+        assert!((luminance[0].screen_fraction - 1.0).abs() < 1e-9);
+        assert!(transitions
+            .iter()
+            .all(|transition| transition.kind == FlashKind::Luminance));
+    }
+
+    #[test]
+    fn test_flash_analyzer_should_measure_the_flashing_area() {
+        let mut analyzer = FlashAnalyzer::new(25.0);
+        analyzer.push_frame(&grid_frame(0, [255, 255, 255], [0, 0, 0]));
+        analyzer.push_frame(&grid_frame(GRID_CELLS / 2, [255, 255, 255], [0, 0, 0]));
+        // A further 5% of the screen brightening is below the recorded area.
+        analyzer.push_frame(&grid_frame(
+            GRID_CELLS / 2 + GRID_CELLS / 20,
+            [255, 255, 255],
+            [0, 0, 0],
+        ));
+
+        let transitions = analyzer.finish();
+
+        assert_eq!(transitions.len(), 1);
+        assert!((transitions[0].screen_fraction - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_flash_analyzer_should_ignore_swings_between_bright_values() {
+        let mut analyzer = FlashAnalyzer::new(25.0);
+        for frame in 0..6 {
+            let level = if frame % 2 == 0 { 240 } else { 255 };
+            analyzer.push_frame(&grid_frame(GRID_CELLS, [level; 3], [0; 3]));
+        }
+
+        assert!(analyzer.finish().is_empty());
+    }
+
+    #[test]
+    fn test_flash_analyzer_should_count_a_gradual_fade_once() {
+        let mut analyzer = FlashAnalyzer::new(25.0);
+        for level in (0..=250).step_by(25) {
+            analyzer.push_frame(&grid_frame(GRID_CELLS, [level as u8; 3], [0; 3]));
+        }
+
+        let transitions = analyzer.finish();
+
+        assert_eq!(transitions.len(), 1);
+        assert!(transitions[0].rising);
+    }
+
+    #[test]
+    fn test_flash_analyzer_should_record_saturated_red_transitions() {
+        let mut analyzer = FlashAnalyzer::new(25.0);
+        analyzer.push_frame(&grid_frame(0, [255, 0, 0], [40, 0, 0]));
+        analyzer.push_frame(&grid_frame(GRID_CELLS, [255, 0, 0], [40, 0, 0]));
+
+        let transitions = analyzer.finish();
+
+        assert!(transitions
+            .iter()
+            .any(|transition| transition.kind == FlashKind::Red && transition.rising));
+    }
+
+    #[test]
+    fn test_flash_analyzer_should_not_treat_unsaturated_colour_as_red() {
+        let mut analyzer = FlashAnalyzer::new(25.0);
+        analyzer.push_frame(&grid_frame(0, [255, 160, 160], [0, 0, 0]));
+        analyzer.push_frame(&grid_frame(GRID_CELLS, [255, 160, 160], [0, 0, 0]));
+
+        let transitions = analyzer.finish();
+
+        assert!(transitions
+            .iter()
+            .all(|transition| transition.kind == FlashKind::Luminance));
+    }
+
+    // ========================================================================
+    // Video levels
+    // ========================================================================
+
+    /// One frame of real `signalstats` + `metadata=mode=print` output.
+    fn signalstats_frame(index: u32, pts_time: f64, stats: &[(&str, f64)]) -> String {
+        let mut block = format!(
+            "[Parsed_metadata_2 @ 0x55d0c8a3c240] frame:{index}    pts:{}    pts_time:{pts_time}\n",
+            index * 512
+        );
+        for key in [
+            "YMIN", "YLOW", "YAVG", "YHIGH", "YMAX", "UMIN", "UMAX", "VMIN", "VMAX",
+        ] {
+            let value = stats
+                .iter()
+                .find(|(stat, _)| *stat == key)
+                .map(|(_, value)| *value)
+                .unwrap_or(match key {
+                    "YMIN" | "UMIN" | "VMIN" => 16.0,
+                    "YMAX" => 235.0,
+                    "UMAX" | "VMAX" => 240.0,
+                    _ => 128.0,
+                });
+            block.push_str(&format!(
+                "[Parsed_metadata_2 @ 0x55d0c8a3c240] lavfi.signalstats.{key}={value}\n"
+            ));
+        }
+        let brng = stats
+            .iter()
+            .find(|(stat, _)| *stat == "BRNG")
+            .map_or(0.0, |(_, value)| *value);
+        block.push_str(&format!(
+            "[Parsed_metadata_2 @ 0x55d0c8a3c240] lavfi.signalstats.BRNG={brng}\n"
+        ));
+        block
+    }
+
+    fn track_levels(log: &str, frame_duration_sec: f64) -> Vec<LevelExcursion> {
+        let mut tracker = LevelTracker::default();
+        for line in log.lines() {
+            tracker.push_line(line);
+        }
+        tracker.finish(frame_duration_sec)
+    }
+
+    #[test]
+    fn test_level_tracker_should_merge_consecutive_out_of_range_frames() {
+        let log = [
+            signalstats_frame(0, 0.0, &[]),
+            signalstats_frame(1, 0.04, &[("YMAX", 250.0), ("BRNG", 0.02)]),
+            signalstats_frame(2, 0.08, &[("VMIN", 8.0), ("BRNG", 0.005)]),
+            signalstats_frame(3, 0.12, &[]),
+        ]
+        .concat();
+
+        let excursions = track_levels(&log, 0.04);
+
+        assert_eq!(excursions.len(), 1);
+        let run = excursions[0];
+        assert!((run.start_sec - 0.04).abs() < 1e-9);
+        assert!((run.end_sec - 0.12).abs() < 1e-9);
+        assert_eq!(run.luma_max, 250.0);
+        assert_eq!(run.luma_min, 16.0);
+        assert_eq!(run.chroma_min, 8.0);
+        assert_eq!(run.chroma_max, 240.0);
+        assert_eq!(run.out_of_range_fraction, 0.02);
+    }
+
+    #[test]
+    fn test_level_tracker_should_close_a_run_at_the_end_of_the_file() {
+        let log = [
+            signalstats_frame(0, 0.0, &[("YMIN", 4.0)]),
+            signalstats_frame(1, 0.5, &[]),
+            signalstats_frame(2, 1.0, &[("UMAX", 250.0)]),
+        ]
+        .concat();
+
+        let excursions = track_levels(&log, 0.5);
+
+        assert_eq!(excursions.len(), 2);
+        assert!((excursions[0].end_sec - 0.5).abs() < 1e-9);
+        assert_eq!(excursions[0].luma_min, 4.0);
+        assert!((excursions[1].start_sec - 1.0).abs() < 1e-9);
+        assert!((excursions[1].end_sec - 1.5).abs() < 1e-9);
+        assert_eq!(excursions[1].chroma_max, 250.0);
+    }
+
+    #[test]
+    fn test_level_tracker_should_report_nothing_for_a_legal_picture() {
+        let log = [
+            signalstats_frame(0, 0.0, &[]),
+            signalstats_frame(1, 0.04, &[("YMIN", 20.0), ("YMAX", 230.0)]),
+        ]
+        .concat();
+
+        assert!(track_levels(&log, 0.04).is_empty());
+    }
+
+    // ========================================================================
+    // Packet timing
+    // ========================================================================
+
+    /// Real `ffprobe -show_entries packet=codec_type,pts_time,duration_time
+    /// -of compact=p=0` output, interleaved as an MP4 muxer writes it.
+    const PACKET_LOG: &str = "\
+codec_type=video|pts_time=0.000000|duration_time=0.033333
+codec_type=audio|pts_time=-0.021333|duration_time=0.021333
+codec_type=audio|pts_time=0.000000|duration_time=0.021333
+codec_type=video|pts_time=0.066667|duration_time=0.033333
+codec_type=video|pts_time=0.033333|duration_time=0.033333
+codec_type=audio|pts_time=0.021333|duration_time=0.021333
+codec_type=video|pts_time=N/A|duration_time=0.033333
+codec_type=audio|pts_time=0.042667|duration_time=0.021333";
+
+    /// Evenly spaced timestamps at `fps`, skipping the listed frame indices.
+    fn cadence(fps: f64, frames: u32, skip: &[u32]) -> Vec<f64> {
+        (0..frames)
+            .filter(|frame| !skip.contains(frame))
+            .map(|frame| f64::from(frame) / fps)
+            .collect()
+    }
+
+    #[test]
+    fn test_packet_timing_tracker_should_read_stream_spans() {
+        let mut tracker = PacketTimingTracker::default();
+        for line in PACKET_LOG.lines() {
+            tracker.push_line(line);
+        }
+
+        let (timing, frames) = tracker.finish(1.0 / 30.0);
+
+        let video = timing.video.expect("video span");
+        assert_eq!(video.start_sec, 0.0);
+        assert!((video.end_sec - 0.1).abs() < 1e-6);
+        let audio = timing.audio.expect("audio span");
+        assert!((audio.start_sec + 0.021333).abs() < 1e-9);
+        assert!((audio.end_sec - 0.064).abs() < 1e-9);
+
+        let frames = frames.expect("cadence");
+        assert_eq!(frames.frame_count, 3);
+        assert!((frames.nominal_interval_sec - 1.0 / 30.0).abs() < 1e-5);
+        assert!(frames.issues.is_empty());
+    }
+
+    #[test]
+    fn test_packet_timing_tracker_should_report_no_cadence_for_a_single_frame() {
+        let mut tracker = PacketTimingTracker::default();
+        tracker.push_line("codec_type=video|pts_time=0.000000|duration_time=N/A");
+
+        let (timing, frames) = tracker.finish(0.04);
+
+        assert!(frames.is_none());
+        let video = timing.video.expect("video span");
+        assert!((video.end_sec - 0.04).abs() < 1e-9);
+        assert!(timing.audio.is_none());
+    }
+
+    #[test]
+    fn test_analyze_frame_timing_should_accept_millisecond_rounding() {
+        // 29.97 fps in a 1/1000 timebase: 33 and 34 ms intervals.
+        let pts: Vec<f64> = (0..300)
+            .map(|frame| (f64::from(frame) * 1001.0 / 30.0).round() / 1000.0)
+            .collect();
+
+        let timing = analyze_frame_timing(&pts).expect("cadence");
+
+        assert_eq!(timing.irregular_intervals, 0);
+        assert_eq!(timing.dropped_frames, 0);
+        assert_eq!(timing.duplicated_frames, 0);
+    }
+
+    #[test]
+    fn test_analyze_frame_timing_should_merge_dropped_frames_into_a_run() {
+        let pts = cadence(25.0, 100, &[40, 41, 42, 70]);
+
+        let timing = analyze_frame_timing(&pts).expect("cadence");
+
+        assert_eq!(timing.dropped_frames, 4);
+        assert_eq!(timing.issues.len(), 2);
+        let run = timing.issues[0];
+        assert_eq!(run.kind, FrameTimingIssueKind::Dropped);
+        assert_eq!(run.frames, 3);
+        assert!((run.start_sec - 39.0 / 25.0).abs() < 1e-9);
+        assert!((run.end_sec - 43.0 / 25.0).abs() < 1e-9);
+        assert_eq!(timing.issues[1].frames, 1);
+        assert!((timing.max_interval_sec - 4.0 / 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_analyze_frame_timing_should_count_repeated_timestamps() {
+        let mut pts = cadence(30.0, 60, &[]);
+        pts.insert(20, pts[20]);
+        pts.insert(21, pts[21]);
+
+        let timing = analyze_frame_timing(&pts).expect("cadence");
+
+        assert_eq!(timing.duplicated_frames, 2);
+        assert_eq!(timing.issues.len(), 1);
+        assert_eq!(timing.issues[0].kind, FrameTimingIssueKind::Duplicated);
+        assert_eq!(timing.issues[0].frames, 2);
+        assert_eq!(timing.dropped_frames, 0);
+    }
+
+    #[test]
+    fn test_analyze_frame_timing_should_count_irregular_intervals_on_a_variable_rate() {
+        // A screen recording alternating between 30 and 24 fps pacing.
+        let mut pts = vec![0.0];
+        for frame in 1..120 {
+            let interval = if frame % 3 == 0 {
+                1.0 / 24.0
+            } else {
+                1.0 / 30.0
+            };
+            pts.push(pts[frame - 1] + interval);
+        }
+
+        let timing = analyze_frame_timing(&pts).expect("cadence");
+
+        assert!((timing.nominal_interval_sec - 1.0 / 30.0).abs() < 1e-9);
+        assert_eq!(timing.irregular_intervals, 39);
+        assert_eq!(timing.dropped_frames, 0);
+        assert!(timing.issues.is_empty());
+    }
 }
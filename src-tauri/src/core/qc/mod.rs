@@ -6,6 +6,7 @@
 pub mod context;
 pub mod engine;
 pub mod measure;
+pub mod profile;
 pub mod rules;
 pub mod structural;
 pub mod violation;
@@ -15,18 +16,26 @@ pub mod violation;
 mod fix_roundtrip_tests;
 
 // Re-export main types
-pub use context::{MeasuredStreams, MeasuredVideoStream, QCContext, RenderMeasurements};
+pub use context::{
+    FlashKind, FlashTransition, FrameTiming, FrameTimingIssue, FrameTimingIssueKind,
+    LevelExcursion, MeasuredStreams, MeasuredVideoStream, QCContext, RenderMeasurements,
+    StreamSpan, StreamTiming,
+};
 pub use engine::{
     QCEngine, QCEngineConfig, QCReport, QCSeverityFilter, RuleFailure, RuleOutcome, RuleStatus,
 };
 pub use measure::{
     measure_rendered_file, measure_rendered_file_detailed, MeasureOptions, MeasurementReport,
 };
+pub use profile::{
+    builtin_profile, builtin_profile_names, resolve_profile, ProfileCheck, ProfileFormat, QCProfile,
+};
 pub use rules::{
-    AspectRatioRule, AudioClippingRule, AudioLoudnessRule, AudioPeakRule, BlackFrameRule,
-    CaptionSafeAreaRule, CheckCategory, CutRhythmRule, DurationRule, FrozenProgramRule,
-    LicenseRule, MissingVideoStreamRule, QCRule, RenderDurationRule, RenderResolutionRule,
-    RuleConfig,
+    AspectRatioRule, AudioClippingRule, AudioLoudnessRule, AudioPeakRule, AvSyncRule,
+    BlackFrameRule, BroadcastLegalRule, CaptionSafeAreaRule, CheckCategory, CutRhythmRule,
+    DurationRule, FrameTimingRule, FrozenProgramRule, LicenseRule, MissingVideoStreamRule,
+    PhotosensitiveFlashRule, QCRule, RenderDurationRule, RenderResolutionRule, RuleConfig,
+    RuleParam, RuleParamKind,
 };
 pub use structural::{
     crossref_black_ranges_with_gaps, CaptionOutOfBoundsRule, CaptionOverlapRule,
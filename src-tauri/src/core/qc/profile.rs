@@ -0,0 +1,563 @@
+//! QC Delivery Profiles
+//!
+//! A delivery profile is a named set of check settings for one destination —
+//! broadcast, premium streaming, YouTube, vertical social — written as a TOML
+//! or JSON file. Each entry turns a check on or off (opt-in checks included),
+//! overrides its severity, and sets its [`RuleConfig`] thresholds.
+//!
+//! Profiles are looked up by name in the project's `.openreelio/qc-profiles`
+//! directory, then in the user's `openreelio/qc-profiles` config directory,
+//! then among the built-ins shipped with the app. The first file found wins
+//! outright; profiles are not merged, so what the file says is what runs.
+//!
+//! A profile is validated against the engine before it is applied: an unknown
+//! check ID, a parameter the rule does not read, or a value of the wrong type
+//! is an error. Left unchecked, each of those would quietly leave the check at
+//! its default, and the run would claim a delivery spec it never enforced.
+//!
+//! ```toml
+//! description = "Broadcast delivery (EBU R128 loudness, R103 video levels)"
+//!
+//! [checks."audio.loudness"]
+//! severity = "error"
+//! params = { target_lufs = -23.0, tolerance_lu = 0.5 }
+//!
+//! [checks."asset.license"]
+//! enabled = true
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::engine::{QCEngine, QCEngineConfig};
+use super::rules::RuleParam;
+use super::violation::Severity;
+use crate::core::{CoreError, CoreResult};
+
+/// Directory name profiles are read from, under the project and user config dirs
+pub const PROFILE_DIR_NAME: &str = "qc-profiles";
+
+/// Profiles shipped with the app, as the files a user would write
+const BUILTIN_PROFILES: &[(&str, &str)] = &[
+    ("broadcast", include_str!("profiles/broadcast.toml")),
+    ("streaming", include_str!("profiles/streaming.toml")),
+    ("youtube", include_str!("profiles/youtube.toml")),
+    (
+        "social_vertical",
+        include_str!("profiles/social_vertical.toml"),
+    ),
+];
+
+/// File format of a profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileFormat {
+    /// `.toml`
+    Toml,
+    /// `.json`
+    Json,
+}
+
+impl ProfileFormat {
+    /// Extensions searched for a profile name, in order of preference
+    const EXTENSIONS: [(&'static str, ProfileFormat); 2] =
+        [("toml", ProfileFormat::Toml), ("json", ProfileFormat::Json)];
+
+    /// Format for a file path, judged by its extension
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        Self::EXTENSIONS
+            .iter()
+            .find(|(candidate, _)| *candidate == extension)
+            .map(|(_, format)| *format)
+    }
+}
+
+/// Settings a profile applies to one check
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileCheck {
+    /// Turns the check on (opt-in checks included) or off; unset keeps the default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Severity the check reports its findings at
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<Severity>,
+    /// Threshold overrides, keyed by the parameter names the rule reads
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, serde_json::Value>,
+}
+
+/// A named delivery profile
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct QCProfile {
+    /// Profile name, taken from the file name rather than the file contents
+    #[serde(skip)]
+    pub name: String,
+    /// Where the profile was read from; `None` for a built-in
+    #[serde(skip)]
+    pub source: Option<PathBuf>,
+    /// One-line summary of the destination
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Per-check settings, keyed by check ID
+    #[serde(default)]
+    pub checks: BTreeMap<String, ProfileCheck>,
+}
+
+impl QCProfile {
+    /// Parses a profile from file contents
+    pub fn parse(
+        name: &str,
+        contents: &str,
+        format: ProfileFormat,
+        source: Option<PathBuf>,
+    ) -> CoreResult<Self> {
+        let parsed = match format {
+            ProfileFormat::Toml => toml::from_str::<QCProfile>(contents).map_err(|e| {
+                // TOML errors render a multi-line source excerpt; the message
+                // alone names the line and the problem.
+                e.message().to_string()
+                    + &e.span()
+                        .map(|span| format!(" (at line {})", line_of(contents, span.start)))
+                        .unwrap_or_default()
+            }),
+            ProfileFormat::Json => {
+                serde_json::from_str::<QCProfile>(contents).map_err(|e| e.to_string())
+            }
+        };
+
+        let mut profile = parsed.map_err(|reason| {
+            CoreError::ValidationError(format!(
+                "QC profile '{}'{} could not be parsed: {}",
+                name,
+                describe_source(source.as_deref()),
+                reason
+            ))
+        })?;
+        profile.name = name.to_string();
+        profile.source = source;
+        Ok(profile)
+    }
+
+    /// Reads a profile file, naming it after the file stem
+    pub fn load(path: &Path) -> CoreResult<Self> {
+        let format = ProfileFormat::from_path(path).ok_or_else(|| {
+            CoreError::ValidationError(format!(
+                "QC profile '{}' must be a .toml or .json file",
+                path.display()
+            ))
+        })?;
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or_default()
+            .to_string();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            CoreError::ValidationError(format!(
+                "QC profile '{}' could not be read: {}",
+                path.display(),
+                e
+            ))
+        })?;
+
+        Self::parse(&name, &contents, format, Some(path.to_path_buf()))
+    }
+
+    /// Returns every problem that would stop the profile applying as written
+    pub fn problems(&self, engine: &QCEngine) -> Vec<String> {
+        let mut problems = Vec::new();
+
+        for (check_id, check) in &self.checks {
+            let Some(rule) = engine.get_rule_by_check_id(check_id) else {
+                problems.push(format!("checks.\"{check_id}\": unknown check ID"));
+                continue;
+            };
+
+            for (key, value) in &check.params {
+                match rule.params().iter().find(|param| param.name == key) {
+                    None => problems.push(format!(
+                        "checks.\"{check_id}\".params.{key}: {check_id} has no parameter \
+                         '{key}' (accepted: {})",
+                        describe_params(rule.params())
+                    )),
+                    Some(param) if !param.accepts(value) => problems.push(format!(
+                        "checks.\"{check_id}\".params.{key}: expected {}, found {value}",
+                        param.kind
+                    )),
+                    Some(_) => {}
+                }
+            }
+        }
+
+        problems
+    }
+
+    /// Fails with every problem found when the profile does not fit the engine
+    pub fn validate(&self, engine: &QCEngine) -> CoreResult<()> {
+        let problems = self.problems(engine);
+        if problems.is_empty() {
+            return Ok(());
+        }
+
+        Err(CoreError::ValidationError(format!(
+            "QC profile '{}'{} is invalid: {}",
+            self.name,
+            describe_source(self.source.as_deref()),
+            problems.join("; ")
+        )))
+    }
+
+    /// Validates the profile and writes its settings into `config`
+    ///
+    /// Parameters are merged into each rule's existing config, so a caller
+    /// that applies explicit overrides afterwards still has the last word.
+    pub fn apply(&self, engine: &QCEngine, config: &mut QCEngineConfig) -> CoreResult<()> {
+        self.validate(engine)?;
+
+        for (check_id, check) in &self.checks {
+            let Some(rule) = engine.get_rule_by_check_id(check_id) else {
+                continue;
+            };
+
+            match check.enabled {
+                Some(true) => config.enable_rule(rule.name()),
+                Some(false) => config.disable_rule(rule.name()),
+                None => {}
+            }
+
+            let mut rule_config = config.get_rule_config(rule.name());
+            if check.severity.is_some() {
+                rule_config.severity_override = check.severity;
+            }
+            for (key, value) in &check.params {
+                rule_config.params.insert(key.clone(), value.clone());
+            }
+            config.set_rule_config(rule.name(), rule_config);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the built-in profile with this name
+pub fn builtin_profile(name: &str) -> Option<QCProfile> {
+    BUILTIN_PROFILES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .and_then(|(name, contents)| {
+            QCProfile::parse(name, contents, ProfileFormat::Toml, None).ok()
+        })
+}
+
+/// Returns the names of the built-in profiles
+pub fn builtin_profile_names() -> Vec<&'static str> {
+    BUILTIN_PROFILES.iter().map(|(name, _)| *name).collect()
+}
+
+/// Directories searched for profile files, most specific first
+pub fn profile_search_dirs(project_dir: Option<&Path>) -> Vec<PathBuf> {
+    let mut search_dirs = Vec::new();
+    if let Some(project_dir) = project_dir {
+        search_dirs.push(project_dir.join(".openreelio").join(PROFILE_DIR_NAME));
+    }
+    if let Some(config_dir) = dirs::config_dir() {
+        search_dirs.push(config_dir.join("openreelio").join(PROFILE_DIR_NAME));
+    }
+    search_dirs
+}
+
+/// Resolves a profile by name, or by path when given a `.toml`/`.json` file
+///
+/// Names are searched in the project directory, then the user config
+/// directory, then the built-ins.
+pub fn resolve_profile(name_or_path: &str, project_dir: Option<&Path>) -> CoreResult<QCProfile> {
+    resolve_profile_in(name_or_path, &profile_search_dirs(project_dir))
+}
+
+/// Resolves a profile against an explicit list of search directories
+pub fn resolve_profile_in(name_or_path: &str, search_dirs: &[PathBuf]) -> CoreResult<QCProfile> {
+    let requested = name_or_path.trim();
+    let as_path = Path::new(requested);
+    if ProfileFormat::from_path(as_path).is_some() {
+        return QCProfile::load(as_path);
+    }
+
+    if !is_valid_profile_name(requested) {
+        return Err(CoreError::ValidationError(format!(
+            "Invalid QC profile name '{requested}': use letters, digits, '_' and '-', \
+             or pass a path to a .toml or .json file"
+        )));
+    }
+
+    for dir in search_dirs {
+        for (extension, _) in ProfileFormat::EXTENSIONS {
+            let candidate = dir.join(format!("{requested}.{extension}"));
+            if candidate.is_file() {
+                return QCProfile::load(&candidate);
+            }
+        }
+    }
+
+    builtin_profile(requested).ok_or_else(|| {
+        CoreError::NotFound(format!(
+            "QC profile '{}' (available: {})",
+            requested,
+            available_profile_names(search_dirs).join(", ")
+        ))
+    })
+}
+
+/// Names of every profile reachable from `search_dirs`, built-ins included
+pub fn available_profile_names(search_dirs: &[PathBuf]) -> Vec<String> {
+    let mut names: Vec<String> = builtin_profile_names()
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+
+    for dir in search_dirs {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if ProfileFormat::from_path(&path).is_none() {
+                continue;
+            }
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                if is_valid_profile_name(stem) {
+                    names.push(stem.to_string());
+                }
+            }
+        }
+    }
+
+    names.sort();
+    names.dedup();
+    names
+}
+
+/// Profile names double as file names, so they are kept to a safe alphabet.
+fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+}
+
+fn describe_source(source: Option<&Path>) -> String {
+    source
+        .map(|path| format!(" ({})", path.display()))
+        .unwrap_or_default()
+}
+
+fn describe_params(params: &[RuleParam]) -> String {
+    if params.is_empty() {
+        return "none".to_string();
+    }
+    params
+        .iter()
+        .map(|param| param.name)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// 1-based line number of a byte offset
+fn line_of(contents: &str, offset: usize) -> usize {
+    contents
+        .get(..offset)
+        .map(|prefix| prefix.matches('\n').count() + 1)
+        .unwrap_or(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn engine() -> QCEngine {
+        QCEngine::new()
+    }
+
+    #[test]
+    fn test_builtin_profiles_parse_and_validate() {
+        let engine = engine();
+        for name in builtin_profile_names() {
+            let profile = builtin_profile(name)
+                .unwrap_or_else(|| panic!("built-in profile '{name}' must parse"));
+            assert_eq!(profile.name, name);
+            assert!(profile.description.is_some(), "{name} needs a description");
+            profile
+                .validate(&engine)
+                .unwrap_or_else(|e| panic!("built-in profile '{name}' is invalid: {e}"));
+        }
+    }
+
+    #[test]
+    fn test_apply_sets_thresholds_severity_and_enablement() {
+        let engine = engine();
+        let profile = QCProfile::parse(
+            "spec",
+            r#"
+                [checks."audio.loudness"]
+                severity = "error"
+                params = { target_lufs = -23, tolerance_lu = 0.5 }
+
+                [checks."asset.license"]
+                enabled = true
+
+                [checks."timeline.gap"]
+                enabled = false
+            "#,
+            ProfileFormat::Toml,
+            None,
+        )
+        .unwrap();
+
+        let mut config = QCEngineConfig::default();
+        config.disable_rule("LicenseRule");
+        profile.apply(&engine, &mut config).unwrap();
+
+        let loudness = config.get_rule_config("AudioLoudnessRule");
+        assert_eq!(loudness.severity_override, Some(Severity::Error));
+        assert_eq!(loudness.get_param::<f64>("target_lufs"), Some(-23.0));
+        assert_eq!(loudness.get_param::<f64>("tolerance_lu"), Some(0.5));
+        assert!(config.is_rule_enabled("LicenseRule"));
+        assert!(!config.is_rule_enabled("TimelineGapRule"));
+    }
+
+    #[test]
+    fn test_json_profiles_are_equivalent_to_toml() {
+        let toml = QCProfile::parse(
+            "spec",
+            "[checks.\"audio.peak\"]\nparams = { peak_db = -2.0 }\n",
+            ProfileFormat::Toml,
+            None,
+        )
+        .unwrap();
+        let json = QCProfile::parse(
+            "spec",
+            r#"{ "checks": { "audio.peak": { "params": { "peak_db": -2.0 } } } }"#,
+            ProfileFormat::Json,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(toml, json);
+    }
+
+    #[test]
+    fn test_validation_reports_every_problem_by_location() {
+        let profile = QCProfile::parse(
+            "broken",
+            r#"
+                [checks."audio.loudnes"]
+                enabled = true
+
+                [checks."audio.loudness"]
+                params = { target = -23.0, tolerance_lu = "0.5" }
+            "#,
+            ProfileFormat::Toml,
+            Some(PathBuf::from("broken.toml")),
+        )
+        .unwrap();
+
+        let message = profile.validate(&engine()).unwrap_err().to_string();
+        assert!(
+            message.contains("QC profile 'broken' (broken.toml)"),
+            "{message}"
+        );
+        assert!(
+            message.contains("checks.\"audio.loudnes\": unknown check ID"),
+            "{message}"
+        );
+        assert!(
+            message.contains("params.target: audio.loudness has no parameter 'target'"),
+            "{message}"
+        );
+        assert!(message.contains("target_lufs, tolerance_lu"), "{message}");
+        assert!(
+            message.contains("params.tolerance_lu: expected a number, found \"0.5\""),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors_name_the_field_and_line() {
+        let error = QCProfile::parse(
+            "typo",
+            "description = \"x\"\n\n[checks.\"audio.peak\"]\nseverity = \"fatal\"\n",
+            ProfileFormat::Toml,
+            None,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            error.contains("QC profile 'typo' could not be parsed"),
+            "{error}"
+        );
+        assert!(error.contains("fatal"), "{error}");
+        assert!(error.contains("line 4"), "{error}");
+
+        let error = QCProfile::parse(
+            "typo",
+            "[checks.\"audio.peak\"]\nenable = true\n",
+            ProfileFormat::Toml,
+            None,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("enable"), "{error}");
+    }
+
+    #[test]
+    fn test_resolution_prefers_project_files_over_builtins() {
+        let project = tempfile::tempdir().unwrap();
+        let user = tempfile::tempdir().unwrap();
+        let dirs = vec![project.path().to_path_buf(), user.path().to_path_buf()];
+
+        std::fs::write(user.path().join("youtube.toml"), "description = \"user\"\n").unwrap();
+        assert_eq!(
+            resolve_profile_in("youtube", &dirs)
+                .unwrap()
+                .description
+                .as_deref(),
+            Some("user")
+        );
+
+        std::fs::write(
+            project.path().join("youtube.json"),
+            r#"{ "description": "project" }"#,
+        )
+        .unwrap();
+        let resolved = resolve_profile_in("youtube", &dirs).unwrap();
+        assert_eq!(resolved.description.as_deref(), Some("project"));
+        assert_eq!(resolved.source, Some(project.path().join("youtube.json")));
+
+        let builtin = resolve_profile_in("broadcast", &dirs).unwrap();
+        assert!(builtin.source.is_none());
+    }
+
+    #[test]
+    fn test_resolution_loads_explicit_paths_and_lists_names_when_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("festival.toml");
+        std::fs::write(
+            &path,
+            "[checks.\"audio.peak\"]\nparams = { peak_db = -3.0 }\n",
+        )
+        .unwrap();
+
+        let by_path = resolve_profile_in(path.to_str().unwrap(), &[]).unwrap();
+        assert_eq!(by_path.name, "festival");
+
+        let error = resolve_profile_in("netflix", &[dir.path().to_path_buf()])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("'netflix'"), "{error}");
+        assert!(error.contains("broadcast"), "{error}");
+        assert!(error.contains("festival"), "{error}");
+
+        assert!(resolve_profile_in("../secrets", &[]).is_err());
+    }
+}
@@ -7,15 +7,17 @@ use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use super::context::QCContext;
+use super::context::{FlashKind, FlashTransition, FrameTimingIssueKind, QCContext};
+use super::measure::MIN_RECORDED_FLASH_FRACTION;
 use super::violation::{merged_span_duration_sec, QCViolation, Severity, ViolationFix};
 use crate::core::captions::{
     CaptionPosition, CaptionStyle, CustomPosition, TextAlignment, VerticalPosition,
     CAPTION_SIDE_MARGIN_PERCENT, CAPTION_WRAP_BOX_WIDTH_PERCENT,
 };
+use crate::core::effects::EffectType;
 use crate::core::project::ProjectState;
 use crate::core::timeline::{Clip, Sequence, Track};
-use crate::core::CoreResult;
+use crate::core::{CoreError, CoreResult};
 
 /// Configuration for QC rules
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +64,74 @@ impl RuleConfig {
     }
 }
 
+/// Value type a rule parameter accepts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleParamKind {
+    /// A finite number
+    Number,
+    /// `true` or `false`
+    Bool,
+    /// A string, such as a preset name
+    Text,
+}
+
+impl std::fmt::Display for RuleParamKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RuleParamKind::Number => write!(f, "a number"),
+            RuleParamKind::Bool => write!(f, "true or false"),
+            RuleParamKind::Text => write!(f, "a string"),
+        }
+    }
+}
+
+/// A tunable parameter a rule reads from its [`RuleConfig`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleParam {
+    /// Key in [`RuleConfig::params`]
+    pub name: &'static str,
+    /// Value type the rule reads the key as
+    pub kind: RuleParamKind,
+}
+
+impl RuleParam {
+    /// A numeric parameter
+    pub const fn number(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: RuleParamKind::Number,
+        }
+    }
+
+    /// A boolean parameter
+    pub const fn bool(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: RuleParamKind::Bool,
+        }
+    }
+
+    /// A string parameter
+    pub const fn text(name: &'static str) -> Self {
+        Self {
+            name,
+            kind: RuleParamKind::Text,
+        }
+    }
+
+    /// Checks whether `value` is something the rule can read
+    ///
+    /// [`RuleConfig::get_param`] drops values of the wrong type, which would
+    /// leave a configured threshold silently at its default.
+    pub fn accepts(&self, value: &serde_json::Value) -> bool {
+        match self.kind {
+            RuleParamKind::Number => value.as_f64().is_some_and(f64::is_finite),
+            RuleParamKind::Bool => value.is_boolean(),
+            RuleParamKind::Text => value.is_string(),
+        }
+    }
+}
+
 /// What a rule inspects.
 ///
 /// Structural rules read the timeline alone; rendered rules need measurements
@@ -110,6 +180,14 @@ pub trait QCRule: Send + Sync {
     /// Returns the default severity for violations from this rule
     fn default_severity(&self) -> Severity;
 
+    /// Returns the parameters this rule reads from its [`RuleConfig`]
+    ///
+    /// Delivery profiles are validated against this list, so a misspelt
+    /// threshold is reported rather than quietly left at its default.
+    fn params(&self) -> &'static [RuleParam] {
+        &[]
+    }
+
     /// Checks the sequence for violations
     async fn check(
         &self,
@@ -269,6 +347,11 @@ impl QCRule for BlackFrameRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("min_duration")];
+        PARAMS
+    }
+
     fn skip_reason(&self, context: &QCContext) -> Option<String> {
         let Some(measurements) = context.measurements.as_ref() else {
             return Some("no rendered measurements available".to_string());
@@ -422,6 +505,11 @@ impl QCRule for AudioPeakRule {
         Severity::Error
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("peak_db"), RuleParam::number("warn_db")];
+        PARAMS
+    }
+
     fn skip_reason(&self, context: &QCContext) -> Option<String> {
         if context.measurements.is_none() {
             return Some("no rendered measurements available".to_string());
@@ -581,6 +669,14 @@ impl QCRule for AudioLoudnessRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[
+            RuleParam::number("target_lufs"),
+            RuleParam::number("tolerance_lu"),
+        ];
+        PARAMS
+    }
+
     fn skip_reason(&self, context: &QCContext) -> Option<String> {
         let Some(measurements) = context.measurements.as_ref() else {
             return Some("no rendered measurements available".to_string());
@@ -780,6 +876,11 @@ impl QCRule for RenderDurationRule {
         Severity::Error
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("tolerance_sec")];
+        PARAMS
+    }
+
     fn skip_reason(&self, context: &QCContext) -> Option<String> {
         let Some(measurements) = context.measurements.as_ref() else {
             return Some("no rendered measurements available".to_string());
@@ -1054,6 +1155,14 @@ impl QCRule for RenderResolutionRule {
         Severity::Error
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[
+            RuleParam::number("aspect_tolerance"),
+            RuleParam::number("fps_tolerance"),
+        ];
+        PARAMS
+    }
+
     fn skip_reason(&self, context: &QCContext) -> Option<String> {
         let Some(measurements) = context.measurements.as_ref() else {
             return Some("no rendered measurements available".to_string());
@@ -1194,6 +1303,378 @@ impl QCRule for RenderResolutionRule {
     }
 }
 
+// ============================================================================
+// AvSyncRule - Checks the rendered audio and picture line up
+// ============================================================================
+
+/// Rounds seconds to the millisecond for metrics.
+fn round_ms(seconds: f64) -> f64 {
+    (seconds * 1000.0).round() / 1000.0
+}
+
+/// Rule that compares where the rendered audio and video streams start and
+/// how long each runs
+///
+/// The container reports one duration for the whole file, so a render whose
+/// audio starts late or runs on past the picture measures the right length
+/// and passes the duration check. The packet timestamps tell the streams
+/// apart: a start offset puts every line of dialogue out of sync from the
+/// first frame, and a duration mismatch means one stream was stretched or
+/// cut against the other, so the drift grows until it reaches the end.
+/// Both are [`Severity::Error`]; neither can be fixed from the timeline, only
+/// by rendering again.
+#[derive(Debug, Default)]
+pub struct AvSyncRule;
+
+impl AvSyncRule {
+    /// Creates a new AvSyncRule
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Largest offset or drift tolerated, in seconds
+    ///
+    /// EBU R37 puts the limit of perceptible sync at 40ms of audio lead; the
+    /// lag limit is wider, and a single tolerance takes the tighter of the two.
+    const DEFAULT_MAX_DRIFT_SEC: f64 = 0.04;
+
+    /// Returns the offset tolerated for this run, in seconds.
+    ///
+    /// An explicitly configured `max_drift_sec` is honoured exactly. Without
+    /// one, the default never falls below one frame: the picture can only end
+    /// on a frame boundary, so a correct render at 24 fps already ends up to
+    /// 42ms off the audio.
+    fn max_drift_sec(config: &RuleConfig, context: &QCContext) -> f64 {
+        if let Some(configured) = config.get_param::<f64>("max_drift_sec") {
+            if configured.is_finite() {
+                return configured.abs();
+            }
+        }
+        Self::DEFAULT_MAX_DRIFT_SEC.max(context.frame_duration_sec())
+    }
+}
+
+#[async_trait]
+impl QCRule for AvSyncRule {
+    fn name(&self) -> &str {
+        "AvSyncRule"
+    }
+
+    fn check_id(&self) -> &str {
+        "render.av_sync"
+    }
+
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Rendered
+    }
+
+    fn description(&self) -> &str {
+        "Checks that the rendered audio and video streams start together and run equally long"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("max_drift_sec")];
+        PARAMS
+    }
+
+    fn skip_reason(&self, context: &QCContext) -> Option<String> {
+        let Some(measurements) = context.measurements.as_ref() else {
+            return Some("no rendered measurements available".to_string());
+        };
+        let Some(timing) = measurements.stream_timing.as_ref() else {
+            return Some("stream timing was not measured".to_string());
+        };
+        if timing.video.is_none() || timing.audio.is_none() {
+            return Some("the measured file does not carry both audio and video".to_string());
+        }
+        None
+    }
+
+    async fn check(
+        &self,
+        _sequence: &Sequence,
+        _state: &ProjectState,
+        config: &RuleConfig,
+        context: &QCContext,
+    ) -> CoreResult<Vec<QCViolation>> {
+        let Some((video, audio)) = context
+            .measurements
+            .as_ref()
+            .and_then(|measurements| measurements.stream_timing.as_ref())
+            .and_then(|timing| timing.video.zip(timing.audio))
+        else {
+            // The engine reports this rule as skipped (see `skip_reason`).
+            return Ok(Vec::new());
+        };
+
+        let max_drift_sec = Self::max_drift_sec(config, context);
+        let severity = config.severity_override.unwrap_or(self.default_severity());
+        let mut violations = Vec::new();
+
+        let offset = audio.start_sec - video.start_sec;
+        if offset.is_finite() && offset.abs() > max_drift_sec {
+            violations.push(
+                QCViolation::new(
+                    self.name(),
+                    severity,
+                    format!(
+                        "Audio starts {:.3}s {} the picture",
+                        offset.abs(),
+                        if offset > 0.0 { "after" } else { "before" }
+                    ),
+                )
+                .with_location(
+                    audio.start_sec.min(video.start_sec).max(0.0),
+                    audio.start_sec.max(video.start_sec).max(0.0),
+                )
+                .with_details(
+                    "The streams are offset from the first frame, so every sound lands away from \
+                     the picture it belongs to. Re-render the sequence and verify again."
+                        .to_string(),
+                )
+                .with_metric("videoStartSec", round_ms(video.start_sec))
+                .with_metric("audioStartSec", round_ms(audio.start_sec))
+                .with_metric("offsetSec", round_ms(offset))
+                .with_metric("maxDriftSec", round_ms(max_drift_sec)),
+            );
+        }
+
+        let drift = audio.duration_sec() - video.duration_sec();
+        if drift.is_finite() && drift.abs() > max_drift_sec {
+            violations.push(
+                QCViolation::new(
+                    self.name(),
+                    severity,
+                    format!(
+                        "Audio runs {:.3}s {} than the picture ({:.3}s vs {:.3}s)",
+                        drift.abs(),
+                        if drift > 0.0 { "longer" } else { "shorter" },
+                        audio.duration_sec(),
+                        video.duration_sec()
+                    ),
+                )
+                .with_location(
+                    audio.end_sec.min(video.end_sec).max(0.0),
+                    audio.end_sec.max(video.end_sec).max(0.0),
+                )
+                .with_details(
+                    "One stream was stretched or cut against the other, so sync drifts further \
+                     the longer the program runs. Re-render the sequence and verify again."
+                        .to_string(),
+                )
+                .with_metric("videoDurationSec", round_ms(video.duration_sec()))
+                .with_metric("audioDurationSec", round_ms(audio.duration_sec()))
+                .with_metric("driftSec", round_ms(drift))
+                .with_metric("maxDriftSec", round_ms(max_drift_sec)),
+            );
+        }
+
+        Ok(violations)
+    }
+}
+
+// ============================================================================
+// FrameTimingRule - Checks the rendered picture keeps a constant cadence
+// ============================================================================
+
+/// Rule that reports dropped and duplicated frames and variable frame rate
+///
+/// The measurement pass grades every video packet timestamp against the
+/// stream's median interval. A render that stalled writes a gap where frames
+/// should be and the picture stutters there; one that repeated frames holds
+/// the picture still for a beat. Both are [`Severity::Error`] and are
+/// reported run by run with the clips under them. Intervals that wander off
+/// the cadence without dropping a frame make the file variable frame rate,
+/// which many delivery specs reject and editors mis-conform; that is a single
+/// [`Severity::Warning`] for the file.
+///
+/// `allow_vfr` accepts a variable rate. A variable-rate stream has no cadence
+/// to drop frames from, so its gaps are not reported either; a constant-rate
+/// stream still is.
+#[derive(Debug, Default)]
+pub struct FrameTimingRule;
+
+impl FrameTimingRule {
+    /// Creates a new FrameTimingRule
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Dropped or duplicated frames tolerated before any are reported
+    const DEFAULT_MAX_FRAMES: f64 = 0.0;
+
+    /// Reads a frame allowance, treating anything unusable as the default.
+    fn allowance(config: &RuleConfig, key: &str) -> u64 {
+        config
+            .get_param::<f64>(key)
+            .filter(|frames| frames.is_finite())
+            .unwrap_or(Self::DEFAULT_MAX_FRAMES)
+            .max(0.0) as u64
+    }
+}
+
+#[async_trait]
+impl QCRule for FrameTimingRule {
+    fn name(&self) -> &str {
+        "FrameTimingRule"
+    }
+
+    fn check_id(&self) -> &str {
+        "render.frame_timing"
+    }
+
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Rendered
+    }
+
+    fn description(&self) -> &str {
+        "Reports dropped or duplicated frames and variable frame rate in the rendered picture"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[
+            RuleParam::number("max_dropped_frames"),
+            RuleParam::number("max_duplicated_frames"),
+            RuleParam::bool("allow_vfr"),
+        ];
+        PARAMS
+    }
+
+    fn skip_reason(&self, context: &QCContext) -> Option<String> {
+        let Some(measurements) = context.measurements.as_ref() else {
+            return Some("no rendered measurements available".to_string());
+        };
+        if measurements.has_video_stream() == Some(false) {
+            return Some("the measured file has no video stream".to_string());
+        }
+        if measurements.frame_timing.is_none() {
+            return Some("frame timing was not measured".to_string());
+        }
+        None
+    }
+
+    async fn check(
+        &self,
+        sequence: &Sequence,
+        _state: &ProjectState,
+        config: &RuleConfig,
+        context: &QCContext,
+    ) -> CoreResult<Vec<QCViolation>> {
+        let Some(timing) = context
+            .measurements
+            .as_ref()
+            .and_then(|measurements| measurements.frame_timing.as_ref())
+        else {
+            // The engine reports this rule as skipped (see `skip_reason`).
+            return Ok(Vec::new());
+        };
+
+        let allow_vfr = config.get_param::<bool>("allow_vfr").unwrap_or(false);
+        let is_vfr = timing.irregular_intervals > 0;
+        let nominal_ms = timing.nominal_interval_sec * 1000.0;
+        let mut violations = Vec::new();
+
+        if is_vfr {
+            if allow_vfr {
+                return Ok(violations);
+            }
+            violations.push(
+                QCViolation::new(
+                    self.name(),
+                    config.severity_override.unwrap_or(Severity::Warning),
+                    format!(
+                        "Video is variable frame rate: {} of {} frame intervals leave the {:.1}ms \
+                         cadence",
+                        timing.irregular_intervals,
+                        timing.frame_count.saturating_sub(1),
+                        nominal_ms
+                    ),
+                )
+                .with_location(0.0, sequence.output_duration())
+                .with_details(format!(
+                    "Frame intervals range from {:.1}ms to {:.1}ms. Broadcast and many \
+                     editorial deliveries require a constant frame rate; render with a fixed \
+                     output rate.",
+                    timing.min_interval_sec * 1000.0,
+                    timing.max_interval_sec * 1000.0
+                ))
+                .with_metric("irregularIntervals", timing.irregular_intervals)
+                .with_metric("nominalIntervalSec", round_ms(timing.nominal_interval_sec))
+                .with_metric("minIntervalSec", round_ms(timing.min_interval_sec))
+                .with_metric("maxIntervalSec", round_ms(timing.max_interval_sec)),
+            );
+        }
+
+        let severity = config.severity_override.unwrap_or(self.default_severity());
+        for (kind, total, allowance) in [
+            (
+                FrameTimingIssueKind::Dropped,
+                timing.dropped_frames,
+                Self::allowance(config, "max_dropped_frames"),
+            ),
+            (
+                FrameTimingIssueKind::Duplicated,
+                timing.duplicated_frames,
+                Self::allowance(config, "max_duplicated_frames"),
+            ),
+        ] {
+            if total <= allowance {
+                continue;
+            }
+            let label = match kind {
+                FrameTimingIssueKind::Dropped => "dropped",
+                FrameTimingIssueKind::Duplicated => "duplicated",
+            };
+
+            for issue in timing.issues.iter().filter(|issue| issue.kind == kind) {
+                let clips = video_clips_in_range(sequence, issue.start_sec, issue.end_sec);
+                let details = match kind {
+                    FrameTimingIssueKind::Dropped => format!(
+                        "Frame timestamps jump {:.1}ms across a {:.1}ms cadence, so the picture \
+                         stutters where frames are missing. Re-render the sequence and verify \
+                         again.",
+                        (issue.end_sec - issue.start_sec) * 1000.0,
+                        nominal_ms
+                    ),
+                    FrameTimingIssueKind::Duplicated => format!(
+                        "Frames repeat a timestamp on a {:.1}ms cadence, so the picture holds \
+                         for a beat and sync slips against the audio. Re-render the sequence \
+                         and verify again.",
+                        nominal_ms
+                    ),
+                };
+                violations.push(
+                    QCViolation::new(
+                        self.name(),
+                        severity,
+                        format!(
+                            "{} {label} frame(s) at {:.2}s",
+                            issue.frames, issue.start_sec
+                        ),
+                    )
+                    .with_location(issue.start_sec, issue.end_sec.max(issue.start_sec))
+                    .with_entities(clips.iter().map(|(_, clip)| clip.id.clone()).collect())
+                    .with_details(details)
+                    .with_metric("frames", issue.frames)
+                    .with_metric("kind", label)
+                    .with_metric("totalFrames", total)
+                    .with_metric("nominalIntervalSec", round_ms(timing.nominal_interval_sec)),
+                );
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
 // ============================================================================
 // FrozenProgramRule - Checks the rendered picture actually moves
 // ============================================================================
@@ -1249,6 +1730,11 @@ impl QCRule for FrozenProgramRule {
         Severity::Error
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("frozen_fraction")];
+        PARAMS
+    }
+
     fn skip_reason(&self, context: &QCContext) -> Option<String> {
         let Some(measurements) = context.measurements.as_ref() else {
             return Some("no rendered measurements available".to_string());
@@ -1339,37 +1825,576 @@ impl QCRule for FrozenProgramRule {
 }
 
 // ============================================================================
-// AudioClippingRule - Checks the mix for flat-topped samples
+// PhotosensitiveFlashRule - Checks the rendered picture for hazardous flashing
 // ============================================================================
 
-/// Rule that reports the flatness the audio statistics pass measured
+/// Rule that reports flashing fast and large enough to trigger photosensitive
+/// seizures
 ///
-/// `astats` counts runs of samples pinned at the signal's extreme level, which
-/// is what a clipped or hard-limited master looks like from the outside. The
-/// peak rule reads how loud the program got; nothing else read whether the
-/// waveform survived getting there, so a render clipped flat measured clean and
-/// passed.
+/// The measurement pass records every frame where a meaningful share of the
+/// screen brightens or darkens (or moves to or from saturated red) past the
+/// ITU-R BT.1702 thresholds. A flash is a pair of opposing transitions; the
+/// guideline broadcasters enforce (Ofcom, BT.1702) rejects more than three
+/// flashes in any one second over a quarter or more of the screen, and treats
+/// saturated-red flashing the same way at any brightness.
 ///
-/// Findings stay at [`Severity::Warning`]: a deliberately limited master is
-/// flat by design, and only a listener can tell that apart from damage.
-/// `audio.peak` keeps the objectively broken half — a signal over the ceiling.
+/// Guideline failures are [`Severity::Error`]. Flashing at a hazardous rate
+/// over a smaller area is reported as [`Severity::Warning`]: it passes the
+/// guideline but sits close enough to it that a different display or crop can
+/// tip it over.
 #[derive(Debug, Default)]
-pub struct AudioClippingRule;
+pub struct PhotosensitiveFlashRule;
 
-impl AudioClippingRule {
-    /// Creates a new AudioClippingRule
+impl PhotosensitiveFlashRule {
+    /// Creates a new PhotosensitiveFlashRule
     pub fn new() -> Self {
         Self
     }
 
-    /// Flatness above which the runs stop looking like ordinary programme peaks
-    ///
-    /// `astats` reports flatness in dB over the ratio of flat runs to samples
-    /// at the extremes, so a healthy mix sits at or near zero.
-    const DEFAULT_FLAT_FACTOR: f64 = 10.0;
+    /// Most flashes allowed in any one second
+    const DEFAULT_MAX_FLASHES_PER_SEC: f64 = 3.0;
+
+    /// Share of the screen at which flashing fails the guideline
+    const DEFAULT_FLASH_AREA: f64 = 0.25;
 }
 
-#[async_trait]
+/// A stretch of the program flashing faster than the limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct FlashingRange {
+    start_sec: f64,
+    end_sec: f64,
+    /// Highest flash count found in any one-second window of the stretch
+    flashes_per_sec: f64,
+    /// Largest screen share of a transition in the stretch
+    screen_fraction: f64,
+}
+
+/// Finds the stretches where transitions of `kind` covering at least
+/// `min_fraction` of the screen flash more than `max_flashes` times a second.
+fn find_flashing_ranges(
+    transitions: &[FlashTransition],
+    kind: FlashKind,
+    min_fraction: f64,
+    max_flashes: f64,
+) -> Vec<FlashingRange> {
+    // Consecutive transitions the same way are one transition spread over
+    // several frames; only alternating ones pair up into flashes.
+    let mut alternating: Vec<FlashTransition> = Vec::new();
+    for transition in transitions
+        .iter()
+        .filter(|transition| transition.kind == kind && transition.screen_fraction >= min_fraction)
+    {
+        match alternating.last_mut() {
+            Some(last) if last.rising == transition.rising => {
+                last.screen_fraction = last.screen_fraction.max(transition.screen_fraction);
+            }
+            _ => alternating.push(*transition),
+        }
+    }
+
+    // Frame times are quantized, so a transition exactly one second after the
+    // first belongs to the next window; the tolerance absorbs rounding.
+    let mut ranges: Vec<FlashingRange> = Vec::new();
+    let mut window_end = 0;
+    for (first, transition) in alternating.iter().enumerate() {
+        window_end = window_end.max(first);
+        while window_end + 1 < alternating.len()
+            && alternating[window_end + 1].time_sec - transition.time_sec < 1.0 - 1e-6
+        {
+            window_end += 1;
+        }
+
+        let window = &alternating[first..=window_end];
+        let flashes = window.len() as f64 / 2.0;
+        if flashes <= max_flashes {
+            continue;
+        }
+
+        let screen_fraction = window
+            .iter()
+            .map(|transition| transition.screen_fraction)
+            .fold(0.0_f64, f64::max);
+        let end_sec = window[window.len() - 1].time_sec;
+        match ranges.last_mut() {
+            Some(range) if transition.time_sec <= range.end_sec => {
+                range.end_sec = range.end_sec.max(end_sec);
+                range.flashes_per_sec = range.flashes_per_sec.max(flashes);
+                range.screen_fraction = range.screen_fraction.max(screen_fraction);
+            }
+            _ => ranges.push(FlashingRange {
+                start_sec: transition.time_sec,
+                end_sec,
+                flashes_per_sec: flashes,
+                screen_fraction,
+            }),
+        }
+    }
+
+    ranges
+}
+
+#[async_trait]
+impl QCRule for PhotosensitiveFlashRule {
+    fn name(&self) -> &str {
+        "PhotosensitiveFlashRule"
+    }
+
+    fn check_id(&self) -> &str {
+        "render.flashing"
+    }
+
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Rendered
+    }
+
+    fn description(&self) -> &str {
+        "Reports luminance and red flashing that breaks photosensitive epilepsy guidelines"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[
+            RuleParam::number("max_flashes_per_sec"),
+            RuleParam::number("flash_area"),
+        ];
+        PARAMS
+    }
+
+    fn skip_reason(&self, context: &QCContext) -> Option<String> {
+        let Some(measurements) = context.measurements.as_ref() else {
+            return Some("no rendered measurements available".to_string());
+        };
+        if measurements.has_video_stream() == Some(false) {
+            return Some("the measured file has no video stream".to_string());
+        }
+        if measurements.flash_transitions.is_none() {
+            return Some("flashing was not measured".to_string());
+        }
+        None
+    }
+
+    async fn check(
+        &self,
+        _sequence: &Sequence,
+        _state: &ProjectState,
+        config: &RuleConfig,
+        context: &QCContext,
+    ) -> CoreResult<Vec<QCViolation>> {
+        let Some(transitions) = context
+            .measurements
+            .as_ref()
+            .and_then(|measurements| measurements.flash_transitions.as_ref())
+        else {
+            // The engine reports this rule as skipped (see `skip_reason`).
+            return Ok(Vec::new());
+        };
+
+        let max_flashes = config
+            .get_param::<f64>("max_flashes_per_sec")
+            .unwrap_or(Self::DEFAULT_MAX_FLASHES_PER_SEC)
+            .abs();
+        let flash_area = config
+            .get_param::<f64>("flash_area")
+            .unwrap_or(Self::DEFAULT_FLASH_AREA)
+            .clamp(MIN_RECORDED_FLASH_FRACTION, 1.0);
+
+        let mut violations = Vec::new();
+        for kind in [FlashKind::Luminance, FlashKind::Red] {
+            let failing = find_flashing_ranges(transitions, kind, flash_area, max_flashes);
+            let near =
+                find_flashing_ranges(transitions, kind, MIN_RECORDED_FLASH_FRACTION, max_flashes)
+                    .into_iter()
+                    .filter(|range| {
+                        !failing.iter().any(|failed| {
+                            range.start_sec <= failed.end_sec && failed.start_sec <= range.end_sec
+                        })
+                    });
+
+            let graded = failing
+                .iter()
+                .map(|range| (*range, Severity::Error))
+                .chain(near.map(|range| (range, Severity::Warning)));
+            for (range, guideline_severity) in graded {
+                let (label, kind_name) = match kind {
+                    FlashKind::Luminance => ("Flashing", "luminance"),
+                    FlashKind::Red => ("Saturated-red flashing", "red"),
+                };
+                let details = if guideline_severity == Severity::Error {
+                    format!(
+                        "Broadcast photosensitivity guidelines (Ofcom, ITU-R BT.1702) reject more \
+                         than {max_flashes} flashes in any one second over {:.0}% or more of the \
+                         screen. Slow the flashing, lower its contrast, or shrink it.",
+                        flash_area * 100.0
+                    )
+                } else {
+                    format!(
+                        "The flashing area is under the {:.0}% guideline limit, so this passes, \
+                         but a crop or a larger display can push it over.",
+                        flash_area * 100.0
+                    )
+                };
+
+                violations.push(
+                    QCViolation::new(
+                        self.name(),
+                        config.severity_override.unwrap_or(guideline_severity),
+                        format!(
+                            "{label} at {} flashes per second over {:.0}% of the screen \
+                             (limit: {max_flashes} per second)",
+                            range.flashes_per_sec,
+                            range.screen_fraction * 100.0
+                        ),
+                    )
+                    .with_location(range.start_sec, range.end_sec)
+                    .with_details(details)
+                    .with_metric("flashKind", kind_name)
+                    .with_metric("flashesPerSecond", range.flashes_per_sec)
+                    .with_metric(
+                        "screenFraction",
+                        (range.screen_fraction * 1000.0).round() / 1000.0,
+                    ),
+                );
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+// ============================================================================
+// BroadcastLegalRule - Checks the rendered picture stays inside legal levels
+// ============================================================================
+
+/// Legal-level limits a delivery is checked against, as 8-bit code values
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LegalLevels {
+    luma_min: f64,
+    luma_max: f64,
+    chroma_min: f64,
+    chroma_max: f64,
+    /// Share of a frame's pixels that may sit outside the nominal range
+    min_area: f64,
+}
+
+impl LegalLevels {
+    /// Nominal studio range (ITU-R BT.709): no excursion at all
+    const NOMINAL: Self = Self {
+        luma_min: 16.0,
+        luma_max: 235.0,
+        chroma_min: 16.0,
+        chroma_max: 240.0,
+        min_area: 0.0,
+    };
+
+    /// EBU R103: −5% to 105% of the nominal range, ignoring excursions over
+    /// less than 1% of the picture
+    const EBU_R103: Self = Self {
+        luma_min: 5.0,
+        luma_max: 246.0,
+        chroma_min: 5.0,
+        chroma_max: 251.0,
+        min_area: 0.01,
+    };
+
+    fn preset(name: &str) -> Option<Self> {
+        match name.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "nominal" => Some(Self::NOMINAL),
+            "ebu_r103" | "r103" => Some(Self::EBU_R103),
+            _ => None,
+        }
+    }
+}
+
+/// Rule that reports frames whose luma or chroma leave broadcast-legal levels
+///
+/// The measurement pass runs `signalstats` over every frame and records the
+/// runs that leave the nominal range. Broadcasters reject super-whites,
+/// crushed sub-blacks and out-of-range chroma outright, so findings are
+/// [`Severity::Error`]. The `levels` parameter picks the limits (`nominal`
+/// 16–235/16–240, or `ebu_r103` tolerances) and each limit can be overridden.
+///
+/// The measurement does not know the configured limits, so runs are always
+/// found and bounded against the nominal range and the chosen limits only
+/// re-grade them. Every frame outside the wider R103 limits is also outside
+/// nominal, so nothing is missed, but an R103 finding spans the whole nominal
+/// run around it and its `outOfRangeFraction` is `signalstats`' share outside
+/// nominal, which FFmpeg does not let a caller change.
+///
+/// Each violation suggests a broadcast legalizer on every video clip under the
+/// range. It clamps rather than regrades, so it carries moderate confidence: a
+/// grade that brings highlights down keeps detail the clamp flattens. The
+/// commands run as one plan, so a range over many clips is still one undo.
+///
+/// An adjustment layer is not offered. It needs a free span on a video track
+/// above every clip in the range, usually a new track, and the legalizer goes
+/// on a clip that only gets its ID when the layer is created. A suggested fix
+/// is a flat list of commands, each checked on its own before it runs, so it
+/// cannot name that ID.
+#[derive(Debug, Default)]
+pub struct BroadcastLegalRule;
+
+impl BroadcastLegalRule {
+    /// Creates a new BroadcastLegalRule
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Preset applied when the config names none
+    const DEFAULT_LEVELS: &'static str = "nominal";
+
+    /// Resolves the preset and any per-limit overrides from the config.
+    fn limits(config: &RuleConfig) -> CoreResult<(String, LegalLevels)> {
+        let name = config
+            .get_param::<String>("levels")
+            .unwrap_or_else(|| Self::DEFAULT_LEVELS.to_string());
+        let mut limits = LegalLevels::preset(&name).ok_or_else(|| {
+            CoreError::ValidationError(format!(
+                "Unknown levels preset '{name}'; expected 'nominal' or 'ebu_r103'"
+            ))
+        })?;
+
+        for (key, limit) in [
+            ("luma_min", &mut limits.luma_min),
+            ("luma_max", &mut limits.luma_max),
+            ("chroma_min", &mut limits.chroma_min),
+            ("chroma_max", &mut limits.chroma_max),
+        ] {
+            if let Some(value) = config
+                .get_param::<f64>(key)
+                .filter(|value| value.is_finite())
+            {
+                *limit = value.clamp(0.0, 255.0);
+            }
+        }
+        if let Some(area) = config
+            .get_param::<f64>("min_area")
+            .filter(|area| area.is_finite())
+        {
+            limits.min_area = area.clamp(0.0, 1.0);
+        }
+
+        Ok((name, limits))
+    }
+
+    /// Builds the fix that adds a legalizer to every clip under the range
+    /// that does not already carry one.
+    fn legalize_clips(
+        sequence: &Sequence,
+        state: &ProjectState,
+        clips: &[(&Track, &Clip)],
+        limits: &LegalLevels,
+    ) -> Option<ViolationFix> {
+        let commands: Vec<serde_json::Value> = clips
+            .iter()
+            .filter(|(_, clip)| {
+                !clip.effects.iter().any(|effect_id| {
+                    state.get_effect(effect_id).is_some_and(|effect| {
+                        effect.enabled && effect.effect_type == EffectType::BroadcastLegalizer
+                    })
+                })
+            })
+            .map(|(track, clip)| {
+                serde_json::json!({
+                    "type": "AddEffect",
+                    "sequenceId": sequence.id,
+                    "trackId": track.id,
+                    "clipId": clip.id,
+                    "effectType": "broadcast_legalizer",
+                    "params": {
+                        "luma_min": limits.luma_min,
+                        "luma_max": limits.luma_max,
+                        "chroma_min": limits.chroma_min,
+                        "chroma_max": limits.chroma_max
+                    }
+                })
+            })
+            .collect();
+
+        if commands.is_empty() {
+            return None;
+        }
+
+        Some(
+            ViolationFix::new(
+                format!(
+                    "Add a broadcast legalizer to {} clip(s) under this range",
+                    commands.len()
+                ),
+                commands,
+            )
+            .with_confidence(0.7),
+        )
+    }
+}
+
+#[async_trait]
+impl QCRule for BroadcastLegalRule {
+    fn name(&self) -> &str {
+        "BroadcastLegalRule"
+    }
+
+    fn check_id(&self) -> &str {
+        "render.broadcast_legal"
+    }
+
+    fn category(&self) -> CheckCategory {
+        CheckCategory::Rendered
+    }
+
+    fn description(&self) -> &str {
+        "Reports frames with luma or chroma outside broadcast-legal levels"
+    }
+
+    fn default_severity(&self) -> Severity {
+        Severity::Error
+    }
+
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[
+            RuleParam::text("levels"),
+            RuleParam::number("luma_min"),
+            RuleParam::number("luma_max"),
+            RuleParam::number("chroma_min"),
+            RuleParam::number("chroma_max"),
+            RuleParam::number("min_area"),
+        ];
+        PARAMS
+    }
+
+    fn skip_reason(&self, context: &QCContext) -> Option<String> {
+        let Some(measurements) = context.measurements.as_ref() else {
+            return Some("no rendered measurements available".to_string());
+        };
+        if measurements.has_video_stream() == Some(false) {
+            return Some("the measured file has no video stream".to_string());
+        }
+        if measurements.level_excursions.is_none() {
+            return Some("video levels were not measured".to_string());
+        }
+        None
+    }
+
+    async fn check(
+        &self,
+        sequence: &Sequence,
+        state: &ProjectState,
+        config: &RuleConfig,
+        context: &QCContext,
+    ) -> CoreResult<Vec<QCViolation>> {
+        let Some(excursions) = context
+            .measurements
+            .as_ref()
+            .and_then(|measurements| measurements.level_excursions.as_ref())
+        else {
+            // The engine reports this rule as skipped (see `skip_reason`).
+            return Ok(Vec::new());
+        };
+
+        let (levels_name, limits) = Self::limits(config)?;
+        let severity = config.severity_override.unwrap_or(self.default_severity());
+
+        let mut violations = Vec::new();
+        for excursion in excursions {
+            let mut problems = Vec::new();
+            if excursion.luma_max > limits.luma_max {
+                problems.push("super-white luma");
+            }
+            if excursion.luma_min < limits.luma_min {
+                problems.push("sub-black luma");
+            }
+            if excursion.chroma_min < limits.chroma_min || excursion.chroma_max > limits.chroma_max
+            {
+                problems.push("out-of-range chroma");
+            }
+            if problems.is_empty() || excursion.out_of_range_fraction < limits.min_area {
+                continue;
+            }
+
+            let clips = video_clips_in_range(sequence, excursion.start_sec, excursion.end_sec);
+            let mut violation = QCViolation::new(
+                self.name(),
+                severity,
+                format!(
+                    "Illegal video levels ({}) for {:.2}s at {:.2}s",
+                    problems.join(", "),
+                    excursion.end_sec - excursion.start_sec,
+                    excursion.start_sec
+                ),
+            )
+            .with_location(excursion.start_sec, excursion.end_sec)
+            .with_entities(clips.iter().map(|(_, clip)| clip.id.clone()).collect())
+            .with_details(format!(
+                "Measured luma {:.0}–{:.0} and chroma {:.0}–{:.0} against {levels_name} limits of \
+                 luma {:.0}–{:.0} and chroma {:.0}–{:.0}. Broadcasters reject deliveries outside \
+                 these levels; legalize the clips or grade the picture back into range.",
+                excursion.luma_min,
+                excursion.luma_max,
+                excursion.chroma_min,
+                excursion.chroma_max,
+                limits.luma_min,
+                limits.luma_max,
+                limits.chroma_min,
+                limits.chroma_max,
+            ))
+            .with_metric("lumaMin", excursion.luma_min)
+            .with_metric("lumaMax", excursion.luma_max)
+            .with_metric("chromaMin", excursion.chroma_min)
+            .with_metric("chromaMax", excursion.chroma_max)
+            .with_metric(
+                "outOfRangeFraction",
+                (excursion.out_of_range_fraction * 10_000.0).round() / 10_000.0,
+            )
+            .with_metric("levels", levels_name.clone());
+
+            if let Some(fix) = Self::legalize_clips(sequence, state, &clips, &limits) {
+                violation = violation.with_fix(fix);
+            }
+            violations.push(violation);
+        }
+
+        Ok(violations)
+    }
+
+    fn supports_auto_fix(&self) -> bool {
+        true
+    }
+}
+
+// ============================================================================
+// AudioClippingRule - Checks the mix for flat-topped samples
+// ============================================================================
+
+/// Rule that reports the flatness the audio statistics pass measured
+///
+/// `astats` counts runs of samples pinned at the signal's extreme level, which
+/// is what a clipped or hard-limited master looks like from the outside. The
+/// peak rule reads how loud the program got; nothing else read whether the
+/// waveform survived getting there, so a render clipped flat measured clean and
+/// passed.
+///
+/// Findings stay at [`Severity::Warning`]: a deliberately limited master is
+/// flat by design, and only a listener can tell that apart from damage.
+/// `audio.peak` keeps the objectively broken half — a signal over the ceiling.
+#[derive(Debug, Default)]
+pub struct AudioClippingRule;
+
+impl AudioClippingRule {
+    /// Creates a new AudioClippingRule
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Flatness above which the runs stop looking like ordinary programme peaks
+    ///
+    /// `astats` reports flatness in dB over the ratio of flat runs to samples
+    /// at the extremes, so a healthy mix sits at or near zero.
+    const DEFAULT_FLAT_FACTOR: f64 = 10.0;
+}
+
+#[async_trait]
 impl QCRule for AudioClippingRule {
     fn name(&self) -> &str {
         "AudioClippingRule"
@@ -1391,6 +2416,11 @@ impl QCRule for AudioClippingRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("flat_factor")];
+        PARAMS
+    }
+
     fn skip_reason(&self, context: &QCContext) -> Option<String> {
         let Some(measurements) = context.measurements.as_ref() else {
             return Some("no rendered measurements available".to_string());
@@ -1698,6 +2728,11 @@ impl QCRule for CaptionSafeAreaRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("margin_percent")];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -1955,6 +2990,14 @@ impl QCRule for CutRhythmRule {
         Severity::Info
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[
+            RuleParam::number("min_cut_sec"),
+            RuleParam::number("max_cut_sec"),
+        ];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -2047,6 +3090,11 @@ impl QCRule for LicenseRule {
         Severity::Critical
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::bool("check_commercial")];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -2176,6 +3224,11 @@ impl QCRule for AspectRatioRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("tolerance")];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -2275,7 +3328,12 @@ impl QCRule for DurationRule {
         Severity::Error
     }
 
-    async fn check(
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("min_sec"), RuleParam::number("max_sec")];
+        PARAMS
+    }
+
+    async fn check(
         &self,
         sequence: &Sequence,
         _state: &ProjectState,
@@ -2345,7 +3403,10 @@ impl QCRule for DurationRule {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::qc::context::{MeasuredStreams, MeasuredVideoStream, RenderMeasurements};
+    use crate::core::qc::context::{
+        FlashKind, FlashTransition, FrameTiming, FrameTimingIssue, LevelExcursion, MeasuredStreams,
+        MeasuredVideoStream, RenderMeasurements, StreamSpan, StreamTiming,
+    };
     use crate::core::timeline::{SequenceFormat, Track};
 
     // ========================================================================
@@ -2461,6 +3522,23 @@ mod tests {
         assert_eq!(parsed.get_param::<f64>("threshold"), Some(10.0));
     }
 
+    #[test]
+    fn test_rule_param_accepts_only_values_the_rule_can_read() {
+        use serde_json::json;
+
+        let number = RuleParam::number("threshold");
+        assert!(number.accepts(&json!(-14)));
+        assert!(number.accepts(&json!(0.5)));
+        assert!(!number.accepts(&json!("-14")));
+        assert!(!number.accepts(&json!(true)));
+
+        assert!(RuleParam::bool("check_commercial").accepts(&json!(false)));
+        assert!(!RuleParam::bool("check_commercial").accepts(&json!(0)));
+
+        assert!(RuleParam::text("levels").accepts(&json!("ebu_r103")));
+        assert!(!RuleParam::text("levels").accepts(&json!(103)));
+    }
+
     // ========================================================================
     // BlackFrameRule Tests
     // ========================================================================
@@ -3752,6 +4830,592 @@ mod tests {
         assert!(violations.is_empty());
     }
 
+    // ========================================================================
+    // PhotosensitiveFlashRule Tests
+    // ========================================================================
+
+    #[test]
+    fn test_photosensitive_flash_rule_properties() {
+        let rule = PhotosensitiveFlashRule::new();
+        assert_eq!(rule.check_id(), "render.flashing");
+        assert_eq!(rule.category(), CheckCategory::Rendered);
+        assert_eq!(rule.default_severity(), Severity::Error);
+    }
+
+    /// Alternating transitions `per_sec` times a second from `start_sec` for
+    /// `duration_sec`, each covering `screen_fraction` of the screen.
+    fn flicker(
+        kind: FlashKind,
+        start_sec: f64,
+        duration_sec: f64,
+        per_sec: f64,
+        screen_fraction: f64,
+    ) -> Vec<FlashTransition> {
+        let count = (duration_sec * per_sec).round() as usize;
+        (0..count)
+            .map(|index| FlashTransition {
+                time_sec: start_sec + index as f64 / per_sec,
+                kind,
+                rising: index % 2 == 0,
+                screen_fraction,
+            })
+            .collect()
+    }
+
+    fn measurements_with_flash_transitions(
+        transitions: Vec<FlashTransition>,
+    ) -> RenderMeasurements {
+        RenderMeasurements {
+            flash_transitions: Some(transitions),
+            streams: Some(MeasuredStreams {
+                video: Some(stream_1080p()),
+                has_audio: true,
+            }),
+            ..Default::default()
+        }
+    }
+
+    async fn check_flashing(transitions: Vec<FlashTransition>) -> Vec<QCViolation> {
+        let sequence = sequence_with_video_clip(0.0, 30.0);
+        let state = state_with_video_asset("asset_001", Some(30.0));
+        let context = QCContext::from_sequence(&sequence)
+            .with_measurements(measurements_with_flash_transitions(transitions));
+
+        PhotosensitiveFlashRule::new()
+            .check(&sequence, &state, &RuleConfig::default(), &context)
+            .await
+            .expect("rule runs")
+    }
+
+    /// Feature: Photosensitive epilepsy check
+    /// Scenario: should error on full-screen strobing over three flashes a second
+    #[tokio::test]
+    async fn test_photosensitive_flash_rule_should_error_on_a_full_screen_strobe() {
+        // Ten transitions a second is five flashes a second, for two seconds.
+        let violations = check_flashing(flicker(FlashKind::Luminance, 10.0, 2.0, 10.0, 1.0)).await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert_eq!(
+            violations[0].message,
+            "Flashing at 5 flashes per second over 100% of the screen (limit: 3 per second)"
+        );
+        assert_eq!(
+            violations[0].details.as_deref(),
+            Some(
+                "Broadcast photosensitivity guidelines (Ofcom, ITU-R BT.1702) reject more than 3 \
+                 flashes in any one second over 25% or more of the screen. Slow the flashing, \
+                 lower its contrast, or shrink it."
+            )
+        );
+        assert_eq!(violations[0].metrics["flashKind"], "luminance");
+        assert_eq!(violations[0].metrics["flashesPerSecond"], 5.0);
+        let location = violations[0].location.as_ref().expect("located");
+        assert!((location.start_sec - 10.0).abs() < 1e-9);
+        assert!((location.end_sec - 11.9).abs() < 1e-9);
+    }
+
+    /// Feature: Photosensitive epilepsy check
+    /// Scenario: should pass flashing at the three-a-second limit
+    #[tokio::test]
+    async fn test_photosensitive_flash_rule_should_pass_flashing_at_the_limit() {
+        let violations = check_flashing(flicker(FlashKind::Luminance, 0.0, 5.0, 6.0, 1.0)).await;
+
+        assert!(violations.is_empty(), "got: {violations:?}");
+    }
+
+    /// Feature: Photosensitive epilepsy check
+    /// Scenario: should error on saturated-red flashing
+    #[tokio::test]
+    async fn test_photosensitive_flash_rule_should_error_on_red_flashing() {
+        let violations = check_flashing(flicker(FlashKind::Red, 3.0, 1.0, 8.0, 0.4)).await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert_eq!(violations[0].metrics["flashKind"], "red");
+        assert_eq!(violations[0].metrics["screenFraction"], 0.4);
+    }
+
+    /// Feature: Photosensitive epilepsy check
+    /// Scenario: should only warn when the flashing area is under a quarter
+    #[tokio::test]
+    async fn test_photosensitive_flash_rule_should_warn_below_the_area_threshold() {
+        let violations = check_flashing(flicker(FlashKind::Luminance, 0.0, 2.0, 10.0, 0.15)).await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert_eq!(
+            violations[0].message,
+            "Flashing at 5 flashes per second over 15% of the screen (limit: 3 per second)"
+        );
+        assert_eq!(
+            violations[0].details.as_deref(),
+            Some(
+                "The flashing area is under the 25% guideline limit, so this passes, but a crop \
+                 or a larger display can push it over."
+            )
+        );
+    }
+
+    /// Feature: Photosensitive epilepsy check
+    /// Scenario: should count a transition spread over several frames once
+    #[tokio::test]
+    async fn test_photosensitive_flash_rule_should_collapse_same_direction_transitions() {
+        // Eight rising transitions in a second are one slow brightening.
+        let transitions = (0..8)
+            .map(|index| FlashTransition {
+                time_sec: index as f64 * 0.1,
+                kind: FlashKind::Luminance,
+                rising: true,
+                screen_fraction: 0.5,
+            })
+            .collect();
+
+        let violations = check_flashing(transitions).await;
+
+        assert!(violations.is_empty(), "got: {violations:?}");
+    }
+
+    #[test]
+    fn test_photosensitive_flash_rule_should_skip_when_flashing_was_not_measured() {
+        let sequence = sequence_with_video_clip(0.0, 30.0);
+        let context = QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements {
+            streams: Some(MeasuredStreams {
+                video: Some(stream_1080p()),
+                has_audio: true,
+            }),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            PhotosensitiveFlashRule::new().skip_reason(&context),
+            Some("flashing was not measured".to_string())
+        );
+    }
+
+    // ========================================================================
+    // BroadcastLegalRule Tests
+    // ========================================================================
+
+    #[test]
+    fn test_broadcast_legal_rule_properties() {
+        let rule = BroadcastLegalRule::new();
+        assert_eq!(rule.check_id(), "render.broadcast_legal");
+        assert_eq!(rule.category(), CheckCategory::Rendered);
+        assert_eq!(rule.default_severity(), Severity::Error);
+        assert!(rule.supports_auto_fix());
+    }
+
+    fn excursion(luma: (f64, f64), chroma: (f64, f64), area: f64) -> LevelExcursion {
+        LevelExcursion {
+            start_sec: 4.0,
+            end_sec: 6.5,
+            luma_min: luma.0,
+            luma_max: luma.1,
+            chroma_min: chroma.0,
+            chroma_max: chroma.1,
+            out_of_range_fraction: area,
+        }
+    }
+
+    async fn check_levels(
+        excursions: Vec<LevelExcursion>,
+        config: &RuleConfig,
+    ) -> Vec<QCViolation> {
+        let sequence = sequence_with_video_clip(0.0, 30.0);
+        let state = state_with_video_asset("asset_001", Some(30.0));
+        let context = QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements {
+            level_excursions: Some(excursions),
+            streams: Some(MeasuredStreams {
+                video: Some(stream_1080p()),
+                has_audio: true,
+            }),
+            ..Default::default()
+        });
+
+        BroadcastLegalRule::new()
+            .check(&sequence, &state, config, &context)
+            .await
+            .expect("rule runs")
+    }
+
+    /// Feature: Broadcast-legal levels
+    /// Scenario: should error on super-whites and offer a legalizer
+    #[tokio::test]
+    async fn test_broadcast_legal_rule_should_error_on_super_whites() {
+        let violations = check_levels(
+            vec![excursion((16.0, 254.0), (16.0, 240.0), 0.08)],
+            &RuleConfig::default(),
+        )
+        .await;
+
+        assert_eq!(violations.len(), 1);
+        let violation = &violations[0];
+        assert_eq!(violation.severity, Severity::Error);
+        assert!(violation.message.contains("super-white luma"));
+        assert_eq!(violation.metrics["lumaMax"], 254.0);
+        assert_eq!(violation.metrics["levels"], "nominal");
+        let location = violation.location.as_ref().expect("located");
+        assert_eq!((location.start_sec, location.end_sec), (4.0, 6.5));
+
+        let fix = violation.suggested_fix.as_ref().expect("legalizer fix");
+        assert_eq!(fix.commands.len(), 1);
+        assert_eq!(fix.commands[0]["type"], "AddEffect");
+        assert_eq!(fix.commands[0]["effectType"], "broadcast_legalizer");
+        assert_eq!(fix.commands[0]["params"]["luma_max"], 235.0);
+    }
+
+    /// Feature: Broadcast-legal levels
+    /// Scenario: should tolerate small excursions under EBU R103
+    #[tokio::test]
+    async fn test_broadcast_legal_rule_should_apply_ebu_r103_tolerances() {
+        let mut config = RuleConfig::default();
+        config.set_param("levels", "ebu_r103");
+
+        let violations = check_levels(
+            vec![
+                // Inside the widened range.
+                excursion((10.0, 240.0), (12.0, 244.0), 0.2),
+                // Outside it, but over less than 1% of the picture.
+                excursion((0.0, 255.0), (16.0, 240.0), 0.004),
+                // Outside it over a real share of the picture.
+                excursion((16.0, 235.0), (2.0, 240.0), 0.05),
+            ],
+            &config,
+        )
+        .await;
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("out-of-range chroma"));
+        assert_eq!(violations[0].metrics["levels"], "ebu_r103");
+        assert_eq!(
+            violations[0].suggested_fix.as_ref().expect("fix").commands[0]["params"]["luma_max"],
+            246.0
+        );
+    }
+
+    /// Feature: Broadcast-legal levels
+    /// Scenario: should honour explicit limit overrides
+    #[tokio::test]
+    async fn test_broadcast_legal_rule_should_honour_limit_overrides() {
+        let mut config = RuleConfig::default();
+        config.set_param("luma_max", 255.0);
+
+        let violations =
+            check_levels(vec![excursion((16.0, 250.0), (16.0, 240.0), 0.1)], &config).await;
+
+        assert!(violations.is_empty(), "got: {violations:?}");
+    }
+
+    /// Feature: Broadcast-legal levels
+    /// Scenario: should reject an unknown levels preset
+    #[tokio::test]
+    async fn test_broadcast_legal_rule_should_reject_an_unknown_preset() {
+        let sequence = sequence_with_video_clip(0.0, 30.0);
+        let state = state_with_video_asset("asset_001", Some(30.0));
+        let context = QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements {
+            level_excursions: Some(Vec::new()),
+            ..Default::default()
+        });
+        let mut config = RuleConfig::default();
+        config.set_param("levels", "pal-plus");
+
+        let error = BroadcastLegalRule::new()
+            .check(&sequence, &state, &config, &context)
+            .await
+            .expect_err("unknown preset");
+
+        assert!(error.to_string().contains("pal-plus"));
+    }
+
+    /// Feature: Broadcast-legal levels
+    /// Scenario: should not suggest a second legalizer on a clip that has one
+    #[tokio::test]
+    async fn test_broadcast_legal_rule_should_not_stack_legalizers() {
+        use crate::core::effects::Effect;
+
+        let mut sequence = sequence_with_video_clip(0.0, 30.0);
+        let mut state = state_with_video_asset("asset_001", Some(30.0));
+        let legalizer = Effect::new(EffectType::BroadcastLegalizer);
+        sequence.tracks[0].clips[0]
+            .effects
+            .push(legalizer.id.clone());
+        state.effects.insert(legalizer.id.clone(), legalizer);
+        let context = QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements {
+            level_excursions: Some(vec![excursion((16.0, 254.0), (16.0, 240.0), 0.08)]),
+            ..Default::default()
+        });
+
+        let violations = BroadcastLegalRule::new()
+            .check(&sequence, &state, &RuleConfig::default(), &context)
+            .await
+            .expect("rule runs");
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].suggested_fix.is_none());
+        assert!(!violations[0].auto_fixable);
+    }
+
+    // ========================================================================
+    // AvSyncRule Tests
+    // ========================================================================
+
+    #[test]
+    fn test_av_sync_rule_properties() {
+        let rule = AvSyncRule::new();
+        assert_eq!(rule.check_id(), "render.av_sync");
+        assert_eq!(rule.category(), CheckCategory::Rendered);
+        assert_eq!(rule.default_severity(), Severity::Error);
+        assert!(!rule.supports_auto_fix());
+    }
+
+    fn span(start_sec: f64, end_sec: f64) -> Option<StreamSpan> {
+        Some(StreamSpan { start_sec, end_sec })
+    }
+
+    async fn check_sync(timing: StreamTiming, config: &RuleConfig) -> Vec<QCViolation> {
+        let sequence = sequence_with_video_clip(0.0, 30.0);
+        let state = state_with_video_asset("asset_001", Some(30.0));
+        let context = QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements {
+            stream_timing: Some(timing),
+            ..Default::default()
+        });
+
+        AvSyncRule::new()
+            .check(&sequence, &state, config, &context)
+            .await
+            .expect("rule runs")
+    }
+
+    /// Feature: A/V sync
+    /// Scenario: should pass streams aligned within a frame and AAC priming
+    #[tokio::test]
+    async fn test_av_sync_rule_should_pass_aligned_streams() {
+        let violations = check_sync(
+            StreamTiming {
+                video: span(0.0, 30.0),
+                audio: span(-0.021, 30.005),
+            },
+            &RuleConfig::default(),
+        )
+        .await;
+
+        assert!(violations.is_empty(), "got: {violations:?}");
+    }
+
+    /// Feature: A/V sync
+    /// Scenario: should error when the audio starts late
+    #[tokio::test]
+    async fn test_av_sync_rule_should_error_on_a_start_offset() {
+        let violations = check_sync(
+            StreamTiming {
+                video: span(0.0, 30.0),
+                audio: span(0.25, 30.25),
+            },
+            &RuleConfig::default(),
+        )
+        .await;
+
+        assert_eq!(violations.len(), 1);
+        let violation = &violations[0];
+        assert_eq!(violation.severity, Severity::Error);
+        assert!(violation.message.contains("0.250s after the picture"));
+        assert_eq!(violation.metrics["offsetSec"], 0.25);
+        let location = violation.location.as_ref().expect("located");
+        assert_eq!((location.start_sec, location.end_sec), (0.0, 0.25));
+    }
+
+    /// Feature: A/V sync
+    /// Scenario: should error when one stream runs longer than the other
+    #[tokio::test]
+    async fn test_av_sync_rule_should_error_on_duration_drift() {
+        let violations = check_sync(
+            StreamTiming {
+                video: span(0.0, 30.0),
+                audio: span(0.0, 29.5),
+            },
+            &RuleConfig::default(),
+        )
+        .await;
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("0.500s shorter"));
+        assert_eq!(violations[0].metrics["driftSec"], -0.5);
+        assert_eq!(violations[0].metrics["maxDriftSec"], 0.04);
+    }
+
+    /// Feature: A/V sync
+    /// Scenario: should honour an explicit drift threshold
+    #[tokio::test]
+    async fn test_av_sync_rule_should_honour_an_explicit_threshold() {
+        let timing = StreamTiming {
+            video: span(0.0, 30.0),
+            audio: span(0.02, 30.02),
+        };
+        let mut config = RuleConfig::default();
+        config.set_param("max_drift_sec", 0.01);
+
+        let violations = check_sync(timing, &config).await;
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("after the picture"));
+    }
+
+    /// Feature: A/V sync
+    /// Scenario: should skip a file that does not carry both streams
+    #[test]
+    fn test_av_sync_rule_should_skip_without_both_streams() {
+        let sequence = sequence_with_video_clip(0.0, 30.0);
+        let rule = AvSyncRule::new();
+
+        let unmeasured =
+            QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements::default());
+        assert_eq!(
+            rule.skip_reason(&unmeasured).as_deref(),
+            Some("stream timing was not measured")
+        );
+
+        let silent = QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements {
+            stream_timing: Some(StreamTiming {
+                video: span(0.0, 30.0),
+                audio: None,
+            }),
+            ..Default::default()
+        });
+        assert!(rule
+            .skip_reason(&silent)
+            .expect("skipped")
+            .contains("both audio and video"));
+    }
+
+    // ========================================================================
+    // FrameTimingRule Tests
+    // ========================================================================
+
+    #[test]
+    fn test_frame_timing_rule_properties() {
+        let rule = FrameTimingRule::new();
+        assert_eq!(rule.check_id(), "render.frame_timing");
+        assert_eq!(rule.category(), CheckCategory::Rendered);
+        assert_eq!(rule.default_severity(), Severity::Error);
+    }
+
+    fn frame_timing(
+        irregular_intervals: u64,
+        dropped_frames: u64,
+        issues: Vec<FrameTimingIssue>,
+    ) -> FrameTiming {
+        FrameTiming {
+            frame_count: 750,
+            nominal_interval_sec: 0.04,
+            min_interval_sec: 0.04,
+            max_interval_sec: 0.16,
+            irregular_intervals,
+            dropped_frames,
+            duplicated_frames: 0,
+            issues,
+        }
+    }
+
+    fn dropped(start_sec: f64, frames: u32) -> FrameTimingIssue {
+        FrameTimingIssue {
+            start_sec,
+            end_sec: start_sec + 0.04 * f64::from(frames + 1),
+            kind: FrameTimingIssueKind::Dropped,
+            frames,
+        }
+    }
+
+    async fn check_frames(timing: FrameTiming, config: &RuleConfig) -> Vec<QCViolation> {
+        let sequence = sequence_with_video_clip(0.0, 30.0);
+        let state = state_with_video_asset("asset_001", Some(30.0));
+        let context = QCContext::from_sequence(&sequence).with_measurements(RenderMeasurements {
+            frame_timing: Some(timing),
+            streams: Some(MeasuredStreams {
+                video: Some(stream_1080p()),
+                has_audio: true,
+            }),
+            ..Default::default()
+        });
+
+        FrameTimingRule::new()
+            .check(&sequence, &state, config, &context)
+            .await
+            .expect("rule runs")
+    }
+
+    /// Feature: Frame timing
+    /// Scenario: should pass a constant-rate stream with no breaks
+    #[tokio::test]
+    async fn test_frame_timing_rule_should_pass_a_constant_cadence() {
+        let violations = check_frames(frame_timing(0, 0, Vec::new()), &RuleConfig::default()).await;
+
+        assert!(violations.is_empty(), "got: {violations:?}");
+    }
+
+    /// Feature: Frame timing
+    /// Scenario: should error on each run of dropped frames with the clips under it
+    #[tokio::test]
+    async fn test_frame_timing_rule_should_error_on_dropped_frames() {
+        let violations = check_frames(
+            frame_timing(0, 4, vec![dropped(12.0, 3), dropped(20.0, 1)]),
+            &RuleConfig::default(),
+        )
+        .await;
+
+        assert_eq!(violations.len(), 2);
+        let violation = &violations[0];
+        assert_eq!(violation.severity, Severity::Error);
+        assert_eq!(violation.message, "3 dropped frame(s) at 12.00s");
+        assert_eq!(violation.metrics["totalFrames"], 4);
+        assert_eq!(violation.affected_entities.len(), 1);
+        let location = violation.location.as_ref().expect("located");
+        assert!((location.end_sec - 12.16).abs() < 1e-9);
+    }
+
+    /// Feature: Frame timing
+    /// Scenario: should tolerate dropped frames up to the configured allowance
+    #[tokio::test]
+    async fn test_frame_timing_rule_should_honour_the_dropped_frame_allowance() {
+        let mut config = RuleConfig::default();
+        config.set_param("max_dropped_frames", 4.0);
+
+        let violations = check_frames(
+            frame_timing(0, 4, vec![dropped(12.0, 3), dropped(20.0, 1)]),
+            &config,
+        )
+        .await;
+
+        assert!(violations.is_empty(), "got: {violations:?}");
+    }
+
+    /// Feature: Frame timing
+    /// Scenario: should warn once on a variable frame rate
+    #[tokio::test]
+    async fn test_frame_timing_rule_should_warn_on_a_variable_frame_rate() {
+        let violations =
+            check_frames(frame_timing(120, 0, Vec::new()), &RuleConfig::default()).await;
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Warning);
+        assert!(violations[0].message.contains("variable frame rate"));
+        assert_eq!(violations[0].metrics["irregularIntervals"], 120);
+    }
+
+    /// Feature: Frame timing
+    /// Scenario: should accept a variable rate, gaps included, when allowed
+    #[tokio::test]
+    async fn test_frame_timing_rule_should_accept_a_variable_rate_when_allowed() {
+        let mut config = RuleConfig::default();
+        config.set_param("allow_vfr", true);
+
+        let variable = check_frames(frame_timing(120, 3, vec![dropped(12.0, 3)]), &config).await;
+        assert!(variable.is_empty(), "got: {variable:?}");
+
+        let constant = check_frames(frame_timing(0, 3, vec![dropped(12.0, 3)]), &config).await;
+        assert_eq!(constant.len(), 1);
+    }
+
     // ========================================================================
     // AudioClippingRule Tests
     // ========================================================================
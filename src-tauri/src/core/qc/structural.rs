@@ -13,7 +13,7 @@ use async_trait::async_trait;
 
 use super::context::QCContext;
 use super::engine::QCReport;
-use super::rules::{QCRule, RuleConfig};
+use super::rules::{QCRule, RuleConfig, RuleParam};
 use super::violation::{merged_span_duration_sec, QCViolation, Severity, ViolationFix};
 use crate::core::captions::{CaptionPosition, CaptionStyle, VerticalPosition};
 use crate::core::commands::find_gaps;
@@ -405,6 +405,11 @@ impl QCRule for TimelineGapRule {
         Severity::Error
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("min_gap_sec")];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -508,6 +513,11 @@ impl QCRule for ClipOrphanRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("min_frames")];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -734,6 +744,11 @@ impl QCRule for SilentClipRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[RuleParam::number("silent_volume_db")];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -1011,6 +1026,14 @@ impl QCRule for CaptionReadingRateRule {
         Severity::Warning
     }
 
+    fn params(&self) -> &'static [RuleParam] {
+        const PARAMS: &[RuleParam] = &[
+            RuleParam::number("warn_cps"),
+            RuleParam::number("severe_cps"),
+        ];
+        PARAMS
+    }
+
     async fn check(
         &self,
         sequence: &Sequence,
@@ -1822,6 +1845,7 @@ mod tests {
                 channels: 2,
                 codec: "aac".to_string(),
                 bitrate: None,
+                channel_layout: None,
             });
         }
         asset
@@ -0,0 +1,487 @@
+//! Animated GIF and WebP exports.
+//!
+//! An animated image renders a range of the timeline through the same filter
+//! graph as a video export, then trims, resamples and scales it down to a
+//! short looping clip. GIFs go through FFmpeg's two-pass palette pipeline:
+//! `palettegen` reads every frame of the range to build one palette of up to
+//! `max_colors` entries, and `paletteuse` maps the frames onto it with the
+//! chosen dither. WebP is encoded with `libwebp`, lossy or lossless.
+//!
+//! With `max_file_size`, the export becomes a search: the file is encoded,
+//! measured and, when over budget, encoded again at a lower frame rate and
+//! width until it fits. File size grows roughly with frame rate times area,
+//! so each step shares the cut between the two.
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::timeline::Sequence;
+
+use super::{ExportError, ExportSettings};
+
+/// Frame rate used when the caller does not choose one (capped by the sequence)
+pub const DEFAULT_ANIMATED_IMAGE_FPS: f64 = 15.0;
+
+/// Width used when the caller does not choose one (capped by the sequence)
+pub const DEFAULT_ANIMATED_IMAGE_WIDTH: u32 = 480;
+
+/// Highest frame rate; GIF frame delays are whole centiseconds, and browsers
+/// slow anything faster than 50 fps down
+pub const MAX_ANIMATED_IMAGE_FPS: f64 = 50.0;
+
+/// Lowest frame rate a size-target search goes down to
+pub const MIN_ANIMATED_IMAGE_FPS: f64 = 5.0;
+
+/// Narrowest width a size-target search goes down to
+pub const MIN_ANIMATED_IMAGE_WIDTH: u32 = 96;
+
+/// Most encodes a size-target search runs before giving up
+pub const MAX_SIZE_TARGET_ATTEMPTS: u32 = 6;
+
+/// Share of the byte budget a retry aims for, so an estimate that is a little
+/// off still lands under it
+const SIZE_TARGET_MARGIN: f64 = 0.9;
+
+/// Largest share of the last attempt's size a retry aims for, so every retry
+/// is a real step down even when the file is barely over budget
+const MAX_SIZE_TARGET_STEP: f64 = 0.9;
+
+fn default_max_colors() -> u16 {
+    256
+}
+
+fn default_webp_quality() -> u8 {
+    75
+}
+
+/// Format of an animated-image export
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AnimatedImageFormat {
+    /// GIF with a palette built from the exported range
+    Gif,
+    /// Animated WebP, lossy or lossless
+    Webp,
+}
+
+impl AnimatedImageFormat {
+    /// File extension for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+        }
+    }
+
+    /// Pixel format the picture is composited in.
+    ///
+    /// The GIF palette is built from full-resolution chroma; WebP stores 4:2:0
+    /// anyway.
+    pub fn working_pixel_format(&self) -> &'static str {
+        match self {
+            Self::Gif => "yuv444p",
+            Self::Webp => "yuv420p",
+        }
+    }
+}
+
+/// Dither `paletteuse` applies when mapping GIF frames onto the palette
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum GifDither {
+    /// No dithering; flat areas stay flat, gradients band
+    None,
+    /// Ordered 8x8 Bayer pattern; stable between frames, so it compresses well
+    Bayer,
+    /// Heckbert error diffusion
+    Heckbert,
+    /// Floyd-Steinberg error diffusion
+    FloydSteinberg,
+    /// Sierra-2 error diffusion
+    Sierra2,
+    /// Sierra-2 "lite" error diffusion, FFmpeg's default
+    #[default]
+    #[serde(rename = "sierra2_4a")]
+    Sierra2Lite,
+}
+
+impl GifDither {
+    /// `paletteuse` name of this dither
+    pub fn ffmpeg_name(&self) -> &'static str {
+        match self {
+            Self::None => "none",
+            Self::Bayer => "bayer",
+            Self::Heckbert => "heckbert",
+            Self::FloydSteinberg => "floyd_steinberg",
+            Self::Sierra2 => "sierra2",
+            Self::Sierra2Lite => "sierra2_4a",
+        }
+    }
+}
+
+/// Settings for exporting a range of a sequence as an animated image.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimatedImageExportSettings {
+    /// Output file; its extension must match `format`
+    pub output_path: PathBuf,
+    /// Image format
+    pub format: AnimatedImageFormat,
+    /// Frame rate (None = the sequence's, at most 15)
+    pub fps: Option<f64>,
+    /// Width in pixels; the height follows the sequence's aspect ratio
+    /// (None = the sequence's, at most 480)
+    pub width: Option<u32>,
+    /// Palette size of a GIF, 2-256
+    #[serde(default = "default_max_colors")]
+    pub max_colors: u16,
+    /// Dither of a GIF
+    #[serde(default)]
+    pub dither: GifDither,
+    /// Quality of a lossy WebP, 0-100
+    #[serde(default = "default_webp_quality")]
+    pub quality: u8,
+    /// Encode a WebP losslessly
+    #[serde(default)]
+    pub lossless: bool,
+    /// Times the animation plays (0 = forever)
+    #[serde(default)]
+    pub play_count: u32,
+    /// Byte budget; frame rate and width are lowered until the file fits
+    pub max_file_size: Option<u64>,
+    /// Start time in seconds (for partial export)
+    pub start_time: Option<f64>,
+    /// End time in seconds (for partial export)
+    pub end_time: Option<f64>,
+}
+
+impl AnimatedImageExportSettings {
+    /// Settings with the defaults for `format`
+    pub fn new(output_path: PathBuf, format: AnimatedImageFormat) -> Self {
+        Self {
+            output_path,
+            format,
+            fps: None,
+            width: None,
+            max_colors: default_max_colors(),
+            dither: GifDither::default(),
+            quality: default_webp_quality(),
+            lossless: false,
+            play_count: 0,
+            max_file_size: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    /// Validate animated-image settings
+    pub fn validate(&self) -> Result<(), ExportError> {
+        let extension = self
+            .output_path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        if extension.as_deref() != Some(self.format.extension()) {
+            return Err(ExportError::InvalidSettings(format!(
+                "{:?} output must end in .{}",
+                self.format,
+                self.format.extension()
+            )));
+        }
+
+        if let Some(fps) = self.fps {
+            if !(fps.is_finite() && fps > 0.0 && fps <= MAX_ANIMATED_IMAGE_FPS) {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Frame rate must be above 0 and at most {}",
+                    MAX_ANIMATED_IMAGE_FPS
+                )));
+            }
+        }
+
+        if let Some(width) = self.width {
+            if width < 16 {
+                return Err(ExportError::InvalidSettings(
+                    "Width must be at least 16 pixels".to_string(),
+                ));
+            }
+        }
+
+        if !(2..=256).contains(&self.max_colors) {
+            return Err(ExportError::InvalidSettings(
+                "GIF palette must have between 2 and 256 colors".to_string(),
+            ));
+        }
+
+        if self.quality > 100 {
+            return Err(ExportError::InvalidSettings(
+                "WebP quality must be between 0 and 100".to_string(),
+            ));
+        }
+
+        if self.max_file_size == Some(0) {
+            return Err(ExportError::InvalidSettings(
+                "Byte budget must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Frame rate and width of the first encode
+    pub fn initial_candidate(&self, sequence: &Sequence) -> AnimationCandidate {
+        let fps = self
+            .fps
+            .unwrap_or_else(|| sequence.format.fps.as_f64().min(DEFAULT_ANIMATED_IMAGE_FPS));
+        let width = self.width.unwrap_or_else(|| {
+            sequence
+                .format
+                .canvas
+                .width
+                .min(DEFAULT_ANIMATED_IMAGE_WIDTH)
+        });
+        AnimationCandidate {
+            fps,
+            width: even_width(width),
+        }
+    }
+
+    /// Filter chain turning the composited timeline into the animation.
+    ///
+    /// The range is trimmed inside the graph rather than with output `-ss`/`-t`,
+    /// so the GIF palette is built from the exported frames only.
+    pub fn animation_filter(
+        &self,
+        candidate: &AnimationCandidate,
+        start_sec: f64,
+        end_sec: f64,
+    ) -> String {
+        let mut filter = format!(
+            "trim=start={}:end={},setpts=PTS-STARTPTS,fps={},scale={}:-2:flags=lanczos",
+            start_sec, end_sec, candidate.fps, candidate.width
+        );
+        if self.format == AnimatedImageFormat::Gif {
+            filter.push_str(&format!(
+                ",split[agifa][agifb];[agifa]palettegen=max_colors={}[agifp];\
+                 [agifb][agifp]paletteuse=dither={}:diff_mode=rectangle",
+                self.max_colors,
+                self.dither.ffmpeg_name()
+            ));
+        }
+        filter
+    }
+
+    /// Encoder and muxer arguments for the animation.
+    pub fn encoding_args(&self) -> Vec<String> {
+        match self.format {
+            AnimatedImageFormat::Gif => vec![
+                "-c:v".to_string(),
+                "gif".to_string(),
+                // The GIF muxer counts repeats after the first play, and -1
+                // means none.
+                "-loop".to_string(),
+                match self.play_count {
+                    0 => "0".to_string(),
+                    1 => "-1".to_string(),
+                    plays => (plays - 1).to_string(),
+                },
+                "-f".to_string(),
+                "gif".to_string(),
+            ],
+            AnimatedImageFormat::Webp => vec![
+                "-c:v".to_string(),
+                "libwebp".to_string(),
+                "-lossless".to_string(),
+                if self.lossless { "1" } else { "0" }.to_string(),
+                "-quality".to_string(),
+                self.quality.to_string(),
+                "-loop".to_string(),
+                self.play_count.to_string(),
+                "-f".to_string(),
+                "webp".to_string(),
+            ],
+        }
+    }
+
+    /// Settings the filter graph and render plan are built from.
+    pub fn to_export_settings(&self) -> ExportSettings {
+        ExportSettings {
+            output_path: self.output_path.clone(),
+            video_bitrate: None,
+            audio_bitrate: None,
+            fps: None,
+            crf: None,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            ..ExportSettings::default()
+        }
+    }
+}
+
+/// Frame rate and width of one encode
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimationCandidate {
+    pub fps: f64,
+    pub width: u32,
+}
+
+/// Rounds a width down to an even number, as 4:2:0 WebP needs.
+fn even_width(width: u32) -> u32 {
+    (width & !1).max(2)
+}
+
+/// The next encode of a size-target search, or `None` when frame rate and
+/// width are both at their floor.
+///
+/// Aims at the budget (less a margin) by assuming size scales with frame rate
+/// times width squared, and takes the square root of the cut from the frame
+/// rate and the rest from the width; whatever the frame rate cannot give once
+/// it reaches [`MIN_ANIMATED_IMAGE_FPS`] comes out of the width.
+pub fn next_size_candidate(
+    current: &AnimationCandidate,
+    measured_size: u64,
+    budget: u64,
+) -> Option<AnimationCandidate> {
+    let ratio = (budget as f64 * SIZE_TARGET_MARGIN / measured_size.max(1) as f64)
+        .min(MAX_SIZE_TARGET_STEP);
+
+    let fps = (current.fps * ratio.sqrt())
+        .max(MIN_ANIMATED_IMAGE_FPS)
+        .min(current.fps);
+    let fps = (fps * 100.0).floor() / 100.0;
+    let area_ratio = ratio / (fps / current.fps);
+    let width = (f64::from(current.width) * area_ratio.sqrt().min(1.0)).floor() as u32;
+    let width = even_width(width.max(MIN_ANIMATED_IMAGE_WIDTH)).min(current.width);
+
+    if fps >= current.fps && width >= current.width {
+        return None;
+    }
+    Some(AnimationCandidate { fps, width })
+}
+
+/// Outcome of an animated-image export
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnimatedImageExportResult {
+    pub output_path: PathBuf,
+    pub file_size: u64,
+    /// Frame rate and width of the written file
+    pub fps: f64,
+    pub width: u32,
+    /// Encodes run; more than one when a byte budget forced retries
+    pub attempts: u32,
+    pub duration_sec: f64,
+    /// Encoding time summed over every attempt
+    pub encoding_time_sec: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gif() -> AnimatedImageExportSettings {
+        AnimatedImageExportSettings::new("/tmp/loop.gif".into(), AnimatedImageFormat::Gif)
+    }
+
+    #[test]
+    fn validates_extension_palette_and_budget() {
+        assert!(gif().validate().is_ok());
+
+        let mut wrong_extension = gif();
+        wrong_extension.output_path = "/tmp/loop.webp".into();
+        assert!(wrong_extension.validate().is_err());
+
+        let mut palette = gif();
+        palette.max_colors = 1;
+        assert!(palette.validate().is_err());
+
+        let mut budget = gif();
+        budget.max_file_size = Some(0);
+        assert!(budget.validate().is_err());
+
+        let mut fps = gif();
+        fps.fps = Some(60.0);
+        assert!(fps.validate().is_err());
+    }
+
+    #[test]
+    fn gif_filter_builds_palette_from_the_trimmed_range() {
+        let mut settings = gif();
+        settings.max_colors = 64;
+        settings.dither = GifDither::Bayer;
+        let filter = settings.animation_filter(
+            &AnimationCandidate {
+                fps: 12.0,
+                width: 320,
+            },
+            2.0,
+            5.5,
+        );
+
+        assert!(filter.starts_with("trim=start=2:end=5.5,setpts=PTS-STARTPTS,fps=12,scale=320:-2"));
+        assert!(filter.contains("palettegen=max_colors=64"), "{filter}");
+        assert!(filter.contains("paletteuse=dither=bayer"), "{filter}");
+    }
+
+    #[test]
+    fn loop_arguments_follow_each_muxer() {
+        let loop_arg = |settings: &AnimatedImageExportSettings| {
+            let args = settings.encoding_args();
+            let index = args.iter().position(|arg| arg == "-loop").unwrap();
+            args[index + 1].clone()
+        };
+
+        let mut settings = gif();
+        assert_eq!(loop_arg(&settings), "0");
+        settings.play_count = 1;
+        assert_eq!(loop_arg(&settings), "-1");
+        settings.play_count = 3;
+        assert_eq!(loop_arg(&settings), "2");
+
+        let mut webp =
+            AnimatedImageExportSettings::new("/tmp/loop.webp".into(), AnimatedImageFormat::Webp);
+        webp.play_count = 3;
+        webp.lossless = true;
+        assert_eq!(loop_arg(&webp), "3");
+        assert!(webp.encoding_args().join(" ").contains("-lossless 1"));
+    }
+
+    #[test]
+    fn size_search_shrinks_until_both_floors() {
+        let start = AnimationCandidate {
+            fps: 15.0,
+            width: 480,
+        };
+        // Four times over budget: aim for a quarter (less the margin), split
+        // between frame rate and area.
+        let next = next_size_candidate(&start, 4_000_000, 1_000_000).unwrap();
+        assert!(
+            next.fps < 15.0 && next.fps > MIN_ANIMATED_IMAGE_FPS,
+            "{next:?}"
+        );
+        assert!(next.width < 480 && next.width.is_multiple_of(2), "{next:?}");
+        let estimate = next.fps / start.fps * (f64::from(next.width) / 480.0).powi(2);
+        assert!(estimate <= 0.25 * SIZE_TARGET_MARGIN, "{estimate}");
+
+        // Barely over budget still takes a real step.
+        let nudge = next_size_candidate(&start, 1_000_001, 1_000_000).unwrap();
+        assert!(nudge.fps < start.fps);
+
+        // Once the frame rate is at its floor, the width takes the whole cut.
+        let slow = AnimationCandidate {
+            fps: MIN_ANIMATED_IMAGE_FPS,
+            width: 400,
+        };
+        let narrower = next_size_candidate(&slow, 2_000_000, 1_000_000).unwrap();
+        assert_eq!(narrower.fps, MIN_ANIMATED_IMAGE_FPS);
+        assert!(narrower.width < 400);
+
+        let floor = AnimationCandidate {
+            fps: MIN_ANIMATED_IMAGE_FPS,
+            width: MIN_ANIMATED_IMAGE_WIDTH,
+        };
+        assert_eq!(next_size_candidate(&floor, 2_000_000, 1_000_000), None);
+    }
+}
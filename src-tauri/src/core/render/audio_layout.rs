@@ -0,0 +1,221 @@
+//! Channel layouts for the exported mix.
+//!
+//! A plain stereo export leaves every clip in the layout it arrives in and lets
+//! the mixer settle on one, which is how exports have always sounded. A 5.1 or
+//! 7.1 export, or a stereo export with a non-standard downmix, converts every
+//! clip to the output layout before it is mixed, so how a source is folded up
+//! or down is decided here rather than by whatever layout FFmpeg negotiates.
+//!
+//! A track with a surround pan is folded to mono and spread over the output
+//! speakers by distance from the pan position: every speaker closer than
+//! [`PAN_RADIUS`] takes a share that shrinks with distance, and the shares are
+//! scaled to constant power so moving the pan never changes the loudness.
+//! Stereo has no rear speakers, so there the front-back position folds away and
+//! the pan behaves like a balance control.
+
+use crate::core::timeline::SurroundPan;
+
+use super::{AudioChannelLayout, AudioDownmix};
+
+/// Distance from the pan position at which a speaker stops receiving it.
+///
+/// Wide enough that the nearest speaker is always in range, narrow enough that
+/// a track panned hard front never leaks into the rear pair.
+const PAN_RADIUS: f64 = 2.0;
+
+/// LFE fold-down level for [`AudioDownmix::WithLfe`] (-3 dB).
+const LFE_MIX_LEVEL: f64 = std::f64::consts::FRAC_1_SQRT_2;
+
+/// Speaker positions on the pan plane (x left to right, y rear to front),
+/// named as FFmpeg names the channels. The LFE has no position.
+fn speaker_positions(layout: AudioChannelLayout) -> &'static [(&'static str, f64, f64)] {
+    match layout {
+        AudioChannelLayout::Stereo => &[("FL", -1.0, 1.0), ("FR", 1.0, 1.0)],
+        AudioChannelLayout::Surround51 => &[
+            ("FL", -1.0, 1.0),
+            ("FR", 1.0, 1.0),
+            ("FC", 0.0, 1.0),
+            ("BL", -1.0, -1.0),
+            ("BR", 1.0, -1.0),
+        ],
+        AudioChannelLayout::Surround71 => &[
+            ("FL", -1.0, 1.0),
+            ("FR", 1.0, 1.0),
+            ("FC", 0.0, 1.0),
+            ("SL", -1.0, 0.0),
+            ("SR", 1.0, 0.0),
+            ("BL", -1.0, -1.0),
+            ("BR", 1.0, -1.0),
+        ],
+    }
+}
+
+/// Returns the gain of every speaker a panned track reaches, LFE included,
+/// leaving out the speakers it does not reach.
+pub(super) fn surround_pan_gains(
+    pan: SurroundPan,
+    layout: AudioChannelLayout,
+) -> Vec<(&'static str, f64)> {
+    let pan = pan.clamped();
+    let x = f64::from(pan.x);
+    let y = if layout.is_surround() {
+        f64::from(pan.y)
+    } else {
+        1.0
+    };
+
+    let speakers = speaker_positions(layout);
+    let weights: Vec<f64> = speakers
+        .iter()
+        .map(|&(_, sx, sy)| (PAN_RADIUS - (x - sx).hypot(y - sy)).max(0.0))
+        .collect();
+    let power = weights.iter().map(|w| w * w).sum::<f64>().sqrt();
+
+    let mut gains: Vec<(&'static str, f64)> = speakers
+        .iter()
+        .zip(&weights)
+        .filter(|(_, &weight)| weight > 0.0)
+        .map(|(&(name, _, _), &weight)| (name, weight / power))
+        .collect();
+    if layout.is_surround() && pan.lfe > 0.0 {
+        gains.push(("LFE", f64::from(pan.lfe)));
+    }
+    gains
+}
+
+/// Filter chain placing a track's audio at `pan` in the output layout.
+pub(super) fn surround_pan_filter(pan: SurroundPan, layout: AudioChannelLayout) -> String {
+    let routes: Vec<String> = surround_pan_gains(pan, layout)
+        .into_iter()
+        .map(|(channel, gain)| format!("{}={:.6}*c0", channel, gain))
+        .collect();
+    format!(
+        "aformat=channel_layouts=mono,pan={}|{}",
+        layout.ffmpeg_name(),
+        routes.join("|")
+    )
+}
+
+/// Filter chain converting a clip to the output layout, or `None` for a
+/// standard stereo export, which keeps the mixer's own negotiation.
+///
+/// Sources wider than the output fold down as `downmix` says; narrower ones
+/// keep their channels where they are (stereo lands on the front pair).
+pub(super) fn channel_conversion_filter(
+    layout: AudioChannelLayout,
+    downmix: AudioDownmix,
+) -> Option<String> {
+    if layout == AudioChannelLayout::Stereo && downmix == AudioDownmix::Standard {
+        return None;
+    }
+
+    let options = match downmix {
+        AudioDownmix::Standard => String::new(),
+        AudioDownmix::WithLfe => format!("=lfe_mix_level={:.6}", LFE_MIX_LEVEL),
+        AudioDownmix::DolbySurround => "=matrix_encoding=dolby".to_string(),
+        AudioDownmix::ProLogicIi => "=matrix_encoding=dplii".to_string(),
+    };
+    Some(format!(
+        "aresample{},aformat=channel_layouts={}",
+        options,
+        layout.ffmpeg_name()
+    ))
+}
+
+/// Filter picking a clip's source channels, or `None` when the clip plays
+/// the source as it is.
+pub(super) fn channel_map_filter(channel_map: &[u8]) -> Option<String> {
+    let layout = match channel_map.len() {
+        1 => "mono",
+        2 => "stereo",
+        6 => "5.1",
+        8 => "7.1",
+        _ => return None,
+    };
+    let routes: Vec<String> = channel_map
+        .iter()
+        .enumerate()
+        .map(|(output, source)| format!("c{}=c{}", output, source))
+        .collect();
+    Some(format!("pan={}|{}", layout, routes.join("|")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gain(gains: &[(&str, f64)], channel: &str) -> f64 {
+        gains
+            .iter()
+            .find(|(name, _)| *name == channel)
+            .map_or(0.0, |(_, gain)| *gain)
+    }
+
+    fn pan(x: f32, y: f32, lfe: f32) -> SurroundPan {
+        SurroundPan { x, y, lfe }
+    }
+
+    #[test]
+    fn panning_keeps_constant_power_and_follows_the_position() {
+        for layout in [
+            AudioChannelLayout::Stereo,
+            AudioChannelLayout::Surround51,
+            AudioChannelLayout::Surround71,
+        ] {
+            for (x, y) in [(0.0, 1.0), (-1.0, -1.0), (0.3, -0.2), (1.0, 0.0)] {
+                let power: f64 = surround_pan_gains(pan(x, y, 0.0), layout)
+                    .iter()
+                    .map(|(_, g)| g * g)
+                    .sum();
+                assert!((power - 1.0).abs() < 1e-9, "{layout:?} at ({x}, {y})");
+            }
+        }
+
+        let front = surround_pan_gains(pan(0.0, 1.0, 0.0), AudioChannelLayout::Surround51);
+        assert!(gain(&front, "FC") > gain(&front, "FL"));
+        assert_eq!(gain(&front, "BL"), 0.0);
+
+        let rear_left = surround_pan_gains(pan(-1.0, -1.0, 0.0), AudioChannelLayout::Surround71);
+        assert!(gain(&rear_left, "BL") > gain(&rear_left, "SL"));
+        assert_eq!(gain(&rear_left, "FR"), 0.0);
+    }
+
+    #[test]
+    fn stereo_ignores_depth_and_lfe() {
+        let rear = surround_pan_gains(pan(-0.5, -1.0, 1.0), AudioChannelLayout::Stereo);
+        let front = surround_pan_gains(pan(-0.5, 1.0, 0.0), AudioChannelLayout::Stereo);
+        assert_eq!(rear, front);
+        assert!(gain(&rear, "FL") > gain(&rear, "FR"));
+        assert_eq!(gain(&rear, "LFE"), 0.0);
+    }
+
+    #[test]
+    fn builds_pan_conversion_and_channel_map_filters() {
+        assert_eq!(
+            surround_pan_filter(pan(0.0, 1.0, 0.5), AudioChannelLayout::Surround51),
+            "aformat=channel_layouts=mono,pan=5.1|FL=0.408248*c0|FR=0.408248*c0|FC=0.816497*c0|LFE=0.500000*c0"
+        );
+
+        assert_eq!(
+            channel_conversion_filter(AudioChannelLayout::Stereo, AudioDownmix::Standard),
+            None
+        );
+        assert_eq!(
+            channel_conversion_filter(AudioChannelLayout::Stereo, AudioDownmix::ProLogicIi)
+                .as_deref(),
+            Some("aresample=matrix_encoding=dplii,aformat=channel_layouts=stereo")
+        );
+        assert_eq!(
+            channel_conversion_filter(AudioChannelLayout::Surround71, AudioDownmix::Standard)
+                .as_deref(),
+            Some("aresample,aformat=channel_layouts=7.1")
+        );
+
+        assert_eq!(channel_map_filter(&[1]).as_deref(), Some("pan=mono|c0=c1"));
+        assert_eq!(
+            channel_map_filter(&[2, 3]).as_deref(),
+            Some("pan=stereo|c0=c2|c1=c3")
+        );
+        assert_eq!(channel_map_filter(&[]), None);
+    }
+}
@@ -23,7 +23,7 @@ use crate::core::assets::Asset;
 use crate::core::effects::Effect;
 use crate::core::fs::validate_path_id_component;
 use crate::core::render::{build_render_plan, ExportSettings, RenderGraph};
-use crate::core::timeline::{Clip, Sequence, Track};
+use crate::core::timeline::{AudioBus, Clip, Sequence, Track};
 use crate::core::types::SequenceId;
 
 // =============================================================================
@@ -143,6 +143,7 @@ fn fingerprint_clip(clip: &Clip, hasher: &mut impl Hasher) {
     hash_f64(clip.audio.fade_out_sec, hasher);
     format!("{:?}", clip.audio.fade_in_type).hash(hasher);
     format!("{:?}", clip.audio.fade_out_type).hash(hasher);
+    clip.audio.channel_map.hash(hasher);
 
     // Audio keyframes
     match serde_json::to_string(&clip.audio.volume_keyframes) {
@@ -161,9 +162,10 @@ fn fingerprint_clip(clip: &Clip, hasher: &mut impl Hasher) {
         format!("{}", position).hash(hasher);
     }
 
-    // Adjustment layer / compound
+    // Adjustment layer / compound / multicam angle
     clip.is_adjustment_layer.hash(hasher);
     clip.compound_sequence_id.hash(hasher);
+    clip.multicam_angle.hash(hasher);
 }
 
 /// Computes a fingerprint for an effect's render-affecting properties.
@@ -219,6 +221,25 @@ fn fingerprint_track_meta(track: &Track, hasher: &mut impl Hasher) {
     track.muted.hash(hasher);
     track.visible.hash(hasher);
     hash_f64(f64::from(track.volume), hasher);
+    track.output_bus_id.hash(hasher);
+    format!("{:?}", track.surround_pan).hash(hasher);
+}
+
+/// Computes a fingerprint for an audio bus and the effects inserted on it.
+fn fingerprint_audio_bus(
+    bus: &AudioBus,
+    effects: &HashMap<String, Effect>,
+    hasher: &mut impl Hasher,
+) {
+    bus.id.hash(hasher);
+    hash_f64(f64::from(bus.volume_db), hasher);
+    bus.muted.hash(hasher);
+    bus.output_bus_id.hash(hasher);
+    for effect_id in &bus.effects {
+        if let Some(effect) = effects.get(effect_id) {
+            fingerprint_effect(effect, hasher);
+        }
+    }
 }
 
 /// Helper: hash an f64 by converting to bits (avoids NaN issues).
@@ -262,6 +283,9 @@ pub fn compute_segment_fingerprint(
     sequence.format.audio_sample_rate.hash(&mut hasher);
     sequence.format.audio_channels.hash(&mut hasher);
     hash_f64(f64::from(sequence.master_volume_db), &mut hasher);
+    for bus in &sequence.audio_buses {
+        fingerprint_audio_bus(bus, effects, &mut hasher);
+    }
 
     // Segment time range
     hash_f64(start_sec, &mut hasher);
@@ -1160,6 +1184,7 @@ mod tests {
             link_group_id: None,
             group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
         }
     }
@@ -1178,6 +1203,8 @@ mod tests {
             sync_lock: false,
             volume: 1.0,
             caption_language: None,
+            output_bus_id: None,
+            surround_pan: None,
         }
     }
 
@@ -1197,7 +1224,9 @@ mod tests {
             tracks,
             markers: vec![],
             master_volume_db: 0.0,
+            audio_buses: vec![],
             hdr_settings: Default::default(),
+            start_timecode: None,
             created_at: "2026-01-01T00:00:00Z".to_string(),
             modified_at: "2026-01-01T00:00:00Z".to_string(),
         }
@@ -1425,6 +1454,30 @@ mod tests {
         assert_ne!(fp1, fp2);
     }
 
+    #[test]
+    fn should_change_fingerprint_when_audio_bus_routing_changes() {
+        // Given a track routed to a bus
+        let clip = make_test_clip("c1", "a1", 0.0, 10.0);
+        let mut track = make_test_track("t1", TrackKind::Audio, vec![clip]);
+        let bus = AudioBus::new("Dialog");
+        track.output_bus_id = Some(bus.id.clone());
+        let mut seq1 = make_test_sequence("seq1", vec![track]);
+        seq1.audio_buses.push(bus);
+        let effects = HashMap::new();
+
+        let fp1 = compute_segment_fingerprint(&seq1, &effects, 0.0, 5.0);
+
+        // When the bus gain changes, or the track goes straight to the master
+        let mut seq2 = seq1.clone();
+        seq2.audio_buses[0].volume_db = -3.0;
+        let mut seq3 = seq1.clone();
+        seq3.tracks[0].output_bus_id = None;
+
+        // Then fingerprints should differ
+        assert_ne!(fp1, compute_segment_fingerprint(&seq2, &effects, 0.0, 5.0));
+        assert_ne!(fp1, compute_segment_fingerprint(&seq3, &effects, 0.0, 5.0));
+    }
+
     #[test]
     fn should_mark_segments_stale_when_fingerprints_change() {
         // Given a manifest with one cached segment
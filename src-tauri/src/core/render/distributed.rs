@@ -0,0 +1,719 @@
+//! Distributed segment rendering.
+//!
+//! A final export normally runs as one FFmpeg process, which leaves most
+//! cores idle on a long timeline. Distributed mode cuts the export range on a
+//! fixed grid of timeline segments, encodes their pictures in parallel — in
+//! local FFmpeg processes and, optionally, on `render worker` processes
+//! reached over a line-delimited JSON socket protocol — and joins the pieces
+//! with the smart-render stitcher ([`super::smart`]). The mix is rendered
+//! once for the whole range and encoded during the stitch, so no audio frame
+//! is ever split at a segment boundary.
+//!
+//! A segment renders the picture only ([`picture_only_sequence`]). With no
+//! sound to keep in step, the range export opens every clip that starts
+//! before the segment at the segment's start and leaves clips outside it
+//! unopened, so each segment decodes its own span.
+//!
+//! Encoded segments can be kept in the sequence's render cache, keyed by
+//! export profile and by a fingerprint of the segment's render plan, so a
+//! re-export after a local edit only re-encodes the segments the edit touched
+//! and stream-copies the rest.
+
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::sync::{mpsc::Sender, oneshot, watch};
+
+use crate::core::assets::Asset;
+use crate::core::effects::Effect;
+use crate::core::timeline::Sequence;
+use crate::core::SequenceId;
+
+use super::cache::compute_segment_fingerprint;
+use super::{
+    ContainerFormat, ExportEngine, ExportError, ExportProgress, ExportResult, ExportSettings,
+    RenderPlan, VideoCodec,
+};
+
+/// Default length of one distributed segment in seconds
+pub const DEFAULT_DISTRIBUTED_SEGMENT_DURATION_SEC: f64 = 10.0;
+
+/// Shortest segment accepted; shorter ones spend more time starting FFmpeg
+/// than encoding
+pub const MIN_DISTRIBUTED_SEGMENT_DURATION_SEC: f64 = 2.0;
+
+/// Most local FFmpeg processes one export may run at once
+pub const MAX_LOCAL_RENDER_WORKERS: usize = 32;
+
+/// Settings for a distributed final render
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributedRenderSettings {
+    /// Local FFmpeg processes encoding segments at once
+    pub local_workers: usize,
+    /// Addresses (`host:port`) of `render worker` processes sharing the work
+    #[serde(default)]
+    pub remote_workers: Vec<String>,
+    /// Project the remote workers load the sequence from; they must see the
+    /// same filesystem as this process, serve this project, and write only
+    /// inside its render cache
+    #[serde(default)]
+    pub project_path: Option<PathBuf>,
+    /// Length of one segment in seconds
+    pub segment_duration_sec: f64,
+    /// Directory keeping encoded segments between exports (`None` = discard
+    /// them after the stitch)
+    #[serde(default)]
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl DistributedRenderSettings {
+    /// Settings for `local_workers` local processes and default segments
+    pub fn new(local_workers: usize) -> Self {
+        Self {
+            local_workers,
+            remote_workers: Vec::new(),
+            project_path: None,
+            segment_duration_sec: DEFAULT_DISTRIBUTED_SEGMENT_DURATION_SEC,
+            cache_dir: None,
+        }
+    }
+
+    /// One local worker per available core
+    pub fn default_local_workers() -> usize {
+        std::thread::available_parallelism()
+            .map(|count| count.get())
+            .unwrap_or(1)
+            .min(MAX_LOCAL_RENDER_WORKERS)
+    }
+
+    /// Validate distributed settings
+    pub fn validate(&self) -> Result<(), ExportError> {
+        if self.local_workers > MAX_LOCAL_RENDER_WORKERS {
+            return Err(ExportError::InvalidSettings(format!(
+                "At most {} local render workers are supported",
+                MAX_LOCAL_RENDER_WORKERS
+            )));
+        }
+        if self.local_workers == 0 && self.remote_workers.is_empty() {
+            return Err(ExportError::InvalidSettings(
+                "A distributed render needs at least one local or remote worker".to_string(),
+            ));
+        }
+        if !self.remote_workers.is_empty()
+            && (self.project_path.is_none() || self.cache_dir.is_none())
+        {
+            // Workers write straight into the shared segment cache and refuse
+            // outputs anywhere else, so a throwaway directory will not do.
+            return Err(ExportError::InvalidSettings(
+                "Remote render workers need the project path and a segment cache directory"
+                    .to_string(),
+            ));
+        }
+        if !(self.segment_duration_sec.is_finite()
+            && self.segment_duration_sec >= MIN_DISTRIBUTED_SEGMENT_DURATION_SEC)
+        {
+            return Err(ExportError::InvalidSettings(format!(
+                "Segment duration must be at least {} seconds",
+                MIN_DISTRIBUTED_SEGMENT_DURATION_SEC
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Checks that an export can be cut into segments and stitched back.
+///
+/// MXF masters carry one PCM track per channel and a timecode track that the
+/// concat demuxer does not rebuild, so they render in one piece.
+pub fn check_distributable(settings: &ExportSettings) -> Result<(), ExportError> {
+    if settings.video_codec == VideoCodec::Copy {
+        return Err(ExportError::InvalidSettings(
+            "Stream copy cannot be rendered in segments".to_string(),
+        ));
+    }
+    if matches!(
+        super::export::container_from_output_path(&settings.output_path)?,
+        ContainerFormat::Mxf
+    ) {
+        return Err(ExportError::InvalidSettings(
+            "MXF exports cannot be rendered in segments".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// One piece of the export range, on the timeline
+#[derive(Clone, Debug, PartialEq)]
+pub struct DistributedSegment {
+    /// Position on the segment grid, counted from timeline zero
+    pub index: u32,
+    /// Start time in seconds
+    pub start_sec: f64,
+    /// End time in seconds
+    pub end_sec: f64,
+}
+
+impl DistributedSegment {
+    /// Duration of this segment in seconds
+    pub fn duration_sec(&self) -> f64 {
+        self.end_sec - self.start_sec
+    }
+}
+
+/// Cuts `[start_sec, end_sec)` on a grid of `segment_duration_sec` anchored
+/// at timeline zero, with every boundary on a frame of `fps`.
+///
+/// Anchoring the grid rather than the range keeps a segment's boundaries,
+/// and so its cache entry, the same across exports of different ranges.
+pub fn plan_distributed_segments(
+    start_sec: f64,
+    end_sec: f64,
+    segment_duration_sec: f64,
+    fps: f64,
+) -> Vec<DistributedSegment> {
+    if !(end_sec > start_sec && segment_duration_sec > 0.0 && fps > 0.0) {
+        return Vec::new();
+    }
+
+    let snap = |time: f64| (time * fps).round() / fps;
+    let first = (start_sec / segment_duration_sec).floor() as u32;
+    let mut segments = Vec::new();
+    let mut index = first;
+    loop {
+        let grid_start = snap(index as f64 * segment_duration_sec);
+        if grid_start >= end_sec {
+            break;
+        }
+        let grid_end = snap((index + 1) as f64 * segment_duration_sec);
+        let segment_start = grid_start.max(start_sec);
+        let segment_end = grid_end.min(end_sec);
+        // A sliver under one frame would encode nothing.
+        if (segment_end - segment_start) * fps >= 0.5 {
+            segments.push(DistributedSegment {
+                index,
+                start_sec: segment_start,
+                end_sec: segment_end,
+            });
+        }
+        index += 1;
+    }
+    segments
+}
+
+/// Key of the cache directory for segments encoded with `settings`.
+///
+/// Everything that changes the encoded stream is part of the key; the output
+/// path and range are not, since each segment carries its own.
+pub fn export_profile_key(settings: &ExportSettings) -> String {
+    let mut profile = settings.clone();
+    profile.output_path = PathBuf::new();
+    profile.start_time = None;
+    profile.end_time = None;
+    profile.metadata = None;
+    profile.loudness = None;
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    serde_json::to_string(&profile)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    super::export::output_extension(&settings.output_path).hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Identity of a segment's picture: its render plan, which covers the layers
+/// and assets in range, combined with the timeline fingerprint the preview
+/// cache uses, which covers clip and effect parameters
+pub fn segment_fingerprint(
+    plan_hash: &str,
+    sequence: &Sequence,
+    effects: &HashMap<String, Effect>,
+    segment: &DistributedSegment,
+) -> u64 {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    plan_hash.hash(&mut hasher);
+    compute_segment_fingerprint(sequence, effects, segment.start_sec, segment.end_sec)
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// File name of an encoded segment in the cache directory
+pub fn segment_file_name(index: u32, fingerprint: u64, extension: &str) -> String {
+    format!("segment_{:05}_{:016x}.{}", index, fingerprint, extension)
+}
+
+/// Grid index encoded in a segment file name, for pruning superseded copies
+fn segment_file_index(name: &str) -> Option<u32> {
+    let rest = name.strip_prefix("segment_")?;
+    let (index, rest) = rest.split_once('_')?;
+    let (fingerprint, _extension) = rest.split_once('.')?;
+    (fingerprint.len() == 16 && fingerprint.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| index.parse().ok())
+        .flatten()
+}
+
+/// Removes cached encodes of `segments` other than `keep`: they belong to a
+/// version of the timeline that no longer exists.
+pub fn prune_superseded_segments(
+    cache_dir: &Path,
+    segments: &[DistributedSegment],
+    keep: &[PathBuf],
+) {
+    let Ok(entries) = std::fs::read_dir(cache_dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Some(index) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(segment_file_index)
+        else {
+            continue;
+        };
+        if segments.iter().any(|segment| segment.index == index) && !keep.contains(&path) {
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+}
+
+/// `sequence` as a segment renders it: picture only, since the stitch takes
+/// the sound from the mix rendered for the whole range.
+///
+/// Clips are muted rather than removed, so the timeline keeps its length.
+pub fn picture_only_sequence(sequence: &Sequence) -> Sequence {
+    let mut picture = sequence.clone();
+    for clip in picture
+        .tracks
+        .iter_mut()
+        .flat_map(|track| track.clips.iter_mut())
+    {
+        clip.audio.muted = true;
+    }
+    picture
+}
+
+/// Outcome of a distributed render
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DistributedExportResult {
+    /// The finished export
+    #[serde(flatten)]
+    pub export: ExportResult,
+    /// Segments the range was cut into
+    pub segment_count: usize,
+    /// Segments copied from the cache instead of encoded
+    pub cached_segments: usize,
+    /// Segments encoded by remote workers
+    pub remote_segments: usize,
+}
+
+// =============================================================================
+// Worker pool
+// =============================================================================
+
+/// A segment waiting for a worker
+pub(super) struct SegmentJob {
+    pub(super) duration_sec: f64,
+    /// Export settings narrowed to the segment, writing to a partial file
+    pub(super) settings: ExportSettings,
+    pub(super) plan: RenderPlan,
+    /// Where the segment is kept once complete
+    pub(super) file: PathBuf,
+}
+
+/// State the workers of one distributed render share
+pub(super) struct SegmentContext {
+    pub(super) sequence: Sequence,
+    /// What the segments render: [`picture_only_sequence`] of `sequence`
+    pub(super) picture: Sequence,
+    pub(super) assets: HashMap<String, Asset>,
+    pub(super) effects: HashMap<String, Effect>,
+    pub(super) queue: Mutex<VecDeque<SegmentJob>>,
+    /// Flips to `true` when the render is cancelled or a segment fails
+    pub(super) cancel: watch::Receiver<bool>,
+    pub(super) progress_tx: Option<Sender<ExportProgress>>,
+    /// Timeline milliseconds encoded or found in the cache so far
+    pub(super) done_ms: AtomicU64,
+    pub(super) total_sec: f64,
+    pub(super) remote_segments: AtomicUsize,
+}
+
+/// Share of the progress bar the segments fill; the stitch takes the rest
+const SEGMENT_PROGRESS_SHARE: f64 = 95.0;
+
+impl SegmentContext {
+    pub(super) fn has_pending_jobs(&self) -> bool {
+        self.queue
+            .lock()
+            .map(|queue| !queue.is_empty())
+            .unwrap_or(false)
+    }
+
+    fn next_job(&self) -> Option<SegmentJob> {
+        self.queue.lock().ok()?.pop_front()
+    }
+
+    fn return_job(&self, job: SegmentJob) {
+        if let Ok(mut queue) = self.queue.lock() {
+            queue.push_front(job);
+        }
+    }
+
+    fn is_cancelled(&self) -> bool {
+        *self.cancel.borrow()
+    }
+
+    /// A cancel receiver for one FFmpeg process, fired with the shared cancel
+    pub(super) fn segment_cancel(&self) -> oneshot::Receiver<()> {
+        super::export::process_cancel_receiver(&self.cancel)
+    }
+
+    /// Moves a finished segment into place and reports progress
+    async fn finish_job(&self, job: &SegmentJob) -> Result<(), ExportError> {
+        tokio::fs::rename(&job.settings.output_path, &job.file).await?;
+        let done_ms = self
+            .done_ms
+            .fetch_add((job.duration_sec * 1000.0) as u64, Ordering::Relaxed)
+            + (job.duration_sec * 1000.0) as u64;
+
+        if let Some(tx) = &self.progress_tx {
+            let fraction = if self.total_sec > 0.0 {
+                (done_ms as f64 / 1000.0 / self.total_sec).min(1.0)
+            } else {
+                1.0
+            };
+            let _ = tx
+                .send(ExportProgress {
+                    frame: 0,
+                    total_frames: 0,
+                    percent: (fraction * SEGMENT_PROGRESS_SHARE) as f32,
+                    fps: 0.0,
+                    eta_seconds: 0,
+                    message: format!(
+                        "Rendered {:.0}s of {:.0}s",
+                        done_ms as f64 / 1000.0,
+                        self.total_sec
+                    ),
+                })
+                .await;
+        }
+        Ok(())
+    }
+
+    /// Encodes segments in this process until none are left
+    pub(super) async fn run_local_slot(&self, engine: ExportEngine) -> Result<(), ExportError> {
+        while let Some(job) = self.next_job() {
+            if self.is_cancelled() {
+                return Err(ExportError::Cancelled);
+            }
+            let result = engine
+                .export_sequence_with_effects_for_plan(
+                    &self.picture,
+                    &self.assets,
+                    &self.effects,
+                    &job.settings,
+                    &job.plan,
+                    None,
+                    Some(self.segment_cancel()),
+                )
+                .await;
+            if let Err(error) = result {
+                let _ = tokio::fs::remove_file(&job.settings.output_path).await;
+                return Err(error);
+            }
+            self.finish_job(&job).await?;
+        }
+        Ok(())
+    }
+
+    /// Sends segments to the worker at `address` until none are left.
+    ///
+    /// A worker that fails is retired: its segment goes back to the queue for
+    /// another worker and its address is returned.
+    pub(super) async fn run_remote_slot(
+        &self,
+        address: String,
+        project_path: PathBuf,
+    ) -> Result<Option<String>, ExportError> {
+        while let Some(job) = self.next_job() {
+            let request = WorkerSegmentRequest {
+                project_path: project_path.clone(),
+                sequence_id: self.sequence.id.clone(),
+                settings: job.settings.clone(),
+            };
+            let mut cancel = self.cancel.clone();
+            let result = tokio::select! {
+                result = render_segment_on_worker(&address, &request) => result,
+                _ = cancel.wait_for(|cancelled| *cancelled) => return Err(ExportError::Cancelled),
+            };
+            match result {
+                Ok(_) => {
+                    self.finish_job(&job).await?;
+                    self.remote_segments.fetch_add(1, Ordering::Relaxed);
+                }
+                Err(error) => {
+                    tracing::warn!("Retiring render worker {address}: {error}");
+                    let _ = tokio::fs::remove_file(&job.settings.output_path).await;
+                    self.return_job(job);
+                    return Ok(Some(address));
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+// =============================================================================
+// Worker protocol
+// =============================================================================
+
+/// One segment sent to a `render worker`: render `settings` (whose range is
+/// the segment) of a sequence in a project both sides can read
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerSegmentRequest {
+    pub project_path: PathBuf,
+    pub sequence_id: SequenceId,
+    pub settings: ExportSettings,
+}
+
+/// A worker's answer to a [`WorkerSegmentRequest`]
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "camelCase")]
+pub enum WorkerSegmentResponse {
+    Completed { result: ExportResult },
+    Failed { message: String },
+}
+
+/// Sends one segment to the worker at `address` and waits for it.
+///
+/// The exchange is one JSON line each way per connection. Dropping the
+/// returned future closes the connection, which the worker takes as a cancel.
+pub async fn render_segment_on_worker(
+    address: &str,
+    request: &WorkerSegmentRequest,
+) -> Result<ExportResult, ExportError> {
+    let stream = tokio::net::TcpStream::connect(address).await?;
+    let (reader, mut writer) = stream.into_split();
+
+    write_json_line(&mut writer, request).await?;
+    let response: WorkerSegmentResponse = read_json_line(&mut BufReader::new(reader))
+        .await?
+        .ok_or_else(|| {
+            ExportError::FFmpegFailed(format!("Render worker {address} closed the connection"))
+        })?;
+    match response {
+        WorkerSegmentResponse::Completed { result } => Ok(result),
+        WorkerSegmentResponse::Failed { message } => Err(ExportError::FFmpegFailed(format!(
+            "Render worker {address}: {message}"
+        ))),
+    }
+}
+
+/// Writes `value` as one line of JSON
+pub async fn write_json_line<W: AsyncWriteExt + Unpin, T: Serialize>(
+    writer: &mut W,
+    value: &T,
+) -> Result<(), ExportError> {
+    let mut line = serde_json::to_vec(value)
+        .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+    line.push(b'\n');
+    writer.write_all(&line).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Reads one line of JSON; `None` when the peer closed the connection first
+pub async fn read_json_line<R: AsyncBufReadExt + Unpin, T: for<'de> Deserialize<'de>>(
+    reader: &mut R,
+) -> Result<Option<T>, ExportError> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).await? == 0 {
+        return Ok(None);
+    }
+    serde_json::from_str(&line)
+        .map(Some)
+        .map_err(|error| ExportError::InvalidSettings(format!("Malformed worker message: {error}")))
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::render::ExportPreset;
+
+    fn starts(segments: &[DistributedSegment]) -> Vec<(u32, f64, f64)> {
+        segments
+            .iter()
+            .map(|segment| (segment.index, segment.start_sec, segment.end_sec))
+            .collect()
+    }
+
+    #[test]
+    fn segments_follow_a_grid_anchored_at_timeline_zero() {
+        let segments = plan_distributed_segments(5.0, 27.0, 10.0, 25.0);
+
+        assert_eq!(
+            starts(&segments),
+            vec![(0, 5.0, 10.0), (1, 10.0, 20.0), (2, 20.0, 27.0)]
+        );
+    }
+
+    #[test]
+    fn segment_boundaries_land_on_frames() {
+        let fps = 30000.0 / 1001.0;
+        let segments = plan_distributed_segments(0.0, 25.0, 10.0, fps);
+
+        assert_eq!(segments.len(), 3);
+        for pair in segments.windows(2) {
+            assert_eq!(pair[0].end_sec, pair[1].start_sec);
+            let frames = pair[1].start_sec * fps;
+            assert!(
+                (frames - frames.round()).abs() < 1e-6,
+                "{}",
+                pair[1].start_sec
+            );
+        }
+        assert_eq!(segments[2].end_sec, 25.0, "the range end is kept as given");
+    }
+
+    #[test]
+    fn an_empty_range_has_no_segments() {
+        assert!(plan_distributed_segments(4.0, 4.0, 10.0, 25.0).is_empty());
+    }
+
+    #[test]
+    fn profile_key_ignores_output_path_and_range_but_not_encoding() {
+        let settings = ExportSettings::from_preset(ExportPreset::Youtube1080p, "/a/out.mp4".into());
+        let mut moved = settings.clone();
+        moved.output_path = "/b/other.mp4".into();
+        moved.start_time = Some(3.0);
+        let mut higher_quality = settings.clone();
+        higher_quality.crf = Some(12);
+        let mut other_container = settings.clone();
+        other_container.output_path = "/a/out.mkv".into();
+
+        assert_eq!(export_profile_key(&settings), export_profile_key(&moved));
+        assert_ne!(
+            export_profile_key(&settings),
+            export_profile_key(&higher_quality)
+        );
+        assert_ne!(
+            export_profile_key(&settings),
+            export_profile_key(&other_container)
+        );
+    }
+
+    #[test]
+    fn superseded_segment_encodes_are_pruned() {
+        let dir = tempfile::tempdir().unwrap();
+        let current = dir.path().join(segment_file_name(1, 0xabc, "mp4"));
+        let stale = dir.path().join(segment_file_name(1, 0xdef, "mp4"));
+        let other_range = dir.path().join(segment_file_name(7, 0xdef, "mp4"));
+        for path in [&current, &stale, &other_range] {
+            std::fs::write(path, b"x").unwrap();
+        }
+        let segments = vec![DistributedSegment {
+            index: 1,
+            start_sec: 10.0,
+            end_sec: 20.0,
+        }];
+
+        prune_superseded_segments(dir.path(), &segments, std::slice::from_ref(&current));
+
+        assert!(current.exists());
+        assert!(!stale.exists());
+        assert!(other_range.exists(), "segments outside the range are kept");
+    }
+
+    #[test]
+    fn segments_render_the_picture_of_every_clip_without_its_sound() {
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut sequence = Sequence::new("Sequence", SequenceFormat::youtube_1080());
+        let mut video = Track::new_video("V1");
+        video.add_clip(Clip::new("a").with_source_range(0.0, 4.0).place_at(0.0));
+        let mut audio = Track::new_audio("A1");
+        audio.add_clip(Clip::new("b").with_source_range(0.0, 9.0).place_at(0.0));
+        sequence.tracks.extend([video, audio]);
+
+        let picture = picture_only_sequence(&sequence);
+
+        assert!(picture
+            .tracks
+            .iter()
+            .flat_map(|track| &track.clips)
+            .all(|clip| clip.audio.muted));
+        assert_eq!(picture.output_duration(), sequence.output_duration());
+        assert!(!sequence.tracks[0].clips[0].audio.muted);
+    }
+
+    #[test]
+    fn mxf_and_stream_copy_are_not_distributable() {
+        let mxf = ExportSettings::from_preset(ExportPreset::DnxhrMxf, "/out/master.mxf".into());
+        let mut copy = ExportSettings::from_preset(ExportPreset::Youtube1080p, "/o.mp4".into());
+        copy.video_codec = VideoCodec::Copy;
+
+        assert!(check_distributable(&mxf).is_err());
+        assert!(check_distributable(&copy).is_err());
+
+        let mut remote = DistributedRenderSettings::new(0);
+        remote.remote_workers.push("127.0.0.1:7600".to_string());
+        assert!(
+            remote.validate().is_err(),
+            "remote workers need the project"
+        );
+        remote.project_path = Some("/projects/demo".into());
+        assert!(remote.validate().is_err(), "remote workers need the cache");
+        remote.cache_dir = Some("/projects/demo/.openreelio/cache/renders/seq".into());
+        assert!(remote.validate().is_ok());
+    }
+
+    #[tokio::test]
+    async fn worker_protocol_round_trips_a_segment() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let worker = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (reader, mut writer) = stream.into_split();
+            let request: WorkerSegmentRequest = read_json_line(&mut BufReader::new(reader))
+                .await
+                .unwrap()
+                .unwrap();
+            let response = WorkerSegmentResponse::Completed {
+                result: ExportResult {
+                    output_path: request.settings.output_path.clone(),
+                    duration_sec: 10.0,
+                    file_size: 42,
+                    encoding_time_sec: 1.0,
+                    loudness: None,
+                },
+            };
+            write_json_line(&mut writer, &response).await.unwrap();
+            request.sequence_id
+        });
+
+        let request = WorkerSegmentRequest {
+            project_path: "/projects/film".into(),
+            sequence_id: "seq-1".to_string(),
+            settings: ExportSettings::from_preset(
+                ExportPreset::Youtube1080p,
+                "/cache/segment.mp4".into(),
+            ),
+        };
+        let result = render_segment_on_worker(&address, &request).await.unwrap();
+
+        assert_eq!(result.file_size, 42);
+        assert_eq!(result.output_path, PathBuf::from("/cache/segment.mp4"));
+        assert_eq!(worker.await.unwrap(), "seq-1");
+    }
+}
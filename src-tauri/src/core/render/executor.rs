@@ -22,6 +22,8 @@ pub struct FfmpegExecutionResult {
     pub output_path: PathBuf,
     pub file_size: u64,
     pub encoding_time_sec: f64,
+    /// FFmpeg's stderr, for filters that report through the log
+    pub stderr: String,
 }
 
 #[derive(Clone, Debug)]
@@ -170,9 +172,10 @@ pub async fn execute_ffmpeg_invocation(
         return Err(ExportError::FFmpegFailed(stderr_msg));
     }
 
-    if let Some(handle) = stderr_handle {
-        let _ = handle.await;
-    }
+    let stderr = match stderr_handle {
+        Some(handle) => handle.await.unwrap_or_default(),
+        None => String::new(),
+    };
 
     if let Some(tx) = progress_completion_tx {
         let _ = tx
@@ -195,6 +198,7 @@ pub async fn execute_ffmpeg_invocation(
         output_path: invocation.output_path,
         file_size,
         encoding_time_sec: start_time.elapsed().as_secs_f64(),
+        stderr,
     })
 }
 
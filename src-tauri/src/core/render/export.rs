@@ -25,19 +25,32 @@ use crate::core::{
     },
     ffmpeg::FFmpegRunner,
     fs::validate_local_input_path,
+    render::audio_layout::{channel_conversion_filter, channel_map_filter, surround_pan_filter},
+    render::distributed::{DistributedExportResult, DistributedRenderSettings},
     render::hdr::{build_tonemap_filter, HdrMetadata, TonemapMode, TonemapParams},
+    render::loudness::{LoudnessNormalization, LoudnessReport, LoudnormStats},
+    render::metadata::{
+        chapter_list_path, chapter_spans, chapter_warnings, ffmetadata, metadata_supported,
+        youtube_chapter_list, ChapterSpan, ExportMetadata,
+    },
     render::transform_layout::ClipTransformLayout,
     render::transition_stitch::{
         plan_sequence_transitions, ClipHandles, EngineAudioFades, TransitionPlan,
     },
     render::{
         build_ffmpeg_invocation_for_render_plan, build_ffmpeg_invocation_from_args,
-        execute_ffmpeg_invocation, execute_ffmpeg_output, RenderPlan,
+        execute_ffmpeg_invocation, execute_ffmpeg_output, AnimatedImageExportResult,
+        AnimatedImageExportSettings, ImageSequenceExportResult, ImageSequenceExportSettings,
+        ImageSequenceManifest, RenderPlan, StreamingPackageResult, StreamingPackageSettings,
+        StreamingRenditionOutput,
     },
+    render::{build_render_plan, RenderGraph},
     timeline::{
+        audio_bus_mix_order, resolve_multicam_angle, track_audio_reaches_master, AudioBus,
         BlendMode, Canvas, Clip, Sequence, SlowMotionInterpolation, TimelineClock, Track,
         TrackKind, Transform,
     },
+    SequenceId, TrackId,
 };
 
 pub(super) fn hdr_metadata_for_asset(asset: &Asset) -> HdrMetadata {
@@ -210,7 +223,7 @@ fn sequence_has_exportable_audio(
     sequence
         .tracks
         .iter()
-        .filter(|track| !track.muted)
+        .filter(|track| !track.muted && track_audio_reaches_master(sequence, track))
         .any(|track| {
             track.clips.iter().any(|clip| {
                 if !clip.enabled || clip.freeze_frame || clip.audio.muted {
@@ -253,6 +266,12 @@ pub const ENCODER_SPEED_VALUES: &[&str] = &[
     "placebo",
 ];
 
+/// SVT-AV1 `-preset` (0 slowest … 13 fastest) for each [`ENCODER_SPEED_VALUES`] step.
+const SVT_AV1_PRESETS: [u8; 10] = [12, 11, 10, 9, 8, 7, 5, 4, 2, 0];
+
+/// libaom `-cpu-used` (0 slowest … 8 fastest) for each [`ENCODER_SPEED_VALUES`] step.
+const AOM_AV1_CPU_USED: [u8; 10] = [8, 8, 7, 6, 6, 5, 4, 3, 1, 0];
+
 /// Returns true when `value` is a supported software encoder speed preset.
 ///
 /// Matching is case-insensitive and ignores surrounding whitespace so CLI and IPC
@@ -282,8 +301,12 @@ pub enum ExportPreset {
     Instagram,
     /// WebM (VP9, Opus)
     WebmVp9,
-    /// ProRes (macOS only)
+    /// WebM (AV1, Opus)
+    WebmAv1,
+    /// ProRes 422 HQ master (prores_ks, PCM) in MOV
     ProRes,
+    /// DNxHR HQX master (PCM) in MXF OP1a
+    DnxhrMxf,
     /// Custom settings
     Custom,
 }
@@ -306,7 +329,9 @@ impl ExportPreset {
             "twitter" => Ok(Self::Twitter),
             "instagram" => Ok(Self::Instagram),
             "webm" | "webm_vp9" | "webm_vp9_1080p" | "webm_vp9_720p" => Ok(Self::WebmVp9),
-            "prores" | "prores_422" => Ok(Self::ProRes),
+            "webm_av1" | "webm_av1_1080p" | "av1" => Ok(Self::WebmAv1),
+            "prores" | "prores_422" | "prores_422_hq" => Ok(Self::ProRes),
+            "dnxhr" | "dnxhr_hqx" | "dnxhr_mxf" | "mxf" => Ok(Self::DnxhrMxf),
             other => Err(ExportError::InvalidSettings(format!(
                 "Unknown export preset: {other}"
             ))),
@@ -322,8 +347,12 @@ pub enum VideoCodec {
     H265,
     #[serde(rename = "vp9")]
     Vp9,
+    #[serde(rename = "av1")]
+    Av1,
     #[serde(rename = "prores")]
     ProRes,
+    #[serde(rename = "dnxhr")]
+    Dnxhr,
     Copy,
 }
 
@@ -339,6 +368,66 @@ pub enum AudioCodec {
     Copy,
 }
 
+/// Channel layout of the exported audio
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioChannelLayout {
+    /// Two channels (default)
+    #[default]
+    Stereo,
+    /// 5.1: front left/right, centre, LFE, surround left/right
+    Surround51,
+    /// 7.1: 5.1 plus side left/right
+    Surround71,
+}
+
+impl AudioChannelLayout {
+    /// FFmpeg channel layout name
+    pub fn ffmpeg_name(&self) -> &'static str {
+        match self {
+            AudioChannelLayout::Stereo => "stereo",
+            AudioChannelLayout::Surround51 => "5.1",
+            AudioChannelLayout::Surround71 => "7.1",
+        }
+    }
+
+    /// Number of output channels
+    pub fn channel_count(&self) -> u8 {
+        match self {
+            AudioChannelLayout::Stereo => 2,
+            AudioChannelLayout::Surround51 => 6,
+            AudioChannelLayout::Surround71 => 8,
+        }
+    }
+
+    pub fn is_surround(&self) -> bool {
+        !matches!(self, AudioChannelLayout::Stereo)
+    }
+}
+
+/// How sources with more channels than the output are folded down
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum AudioDownmix {
+    /// ITU-R BS.775 fold-down: centre and surrounds at -3 dB, LFE dropped
+    #[default]
+    Standard,
+    /// As `Standard`, with the LFE folded in at -3 dB
+    WithLfe,
+    /// Dolby Surround (Lt/Rt) matrix-encoded stereo
+    DolbySurround,
+    /// Dolby Pro Logic II matrix-encoded stereo
+    #[serde(rename = "pro_logic_ii")]
+    ProLogicIi,
+}
+
+impl AudioDownmix {
+    /// Whether the downmix is a matrix encoding, which only stereo can carry
+    pub fn is_matrix_encoded(&self) -> bool {
+        matches!(self, AudioDownmix::DolbySurround | AudioDownmix::ProLogicIi)
+    }
+}
+
 /// HDR (High Dynamic Range) mode for export
 #[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
 #[serde(rename_all = "snake_case")]
@@ -364,8 +453,12 @@ pub enum ContainerFormat {
     Mp4,
     /// QuickTime container for ProRes/H.264/H.265 delivery and masters.
     Mov,
-    /// WebM container for VP9/Opus delivery.
+    /// WebM container for VP9/AV1 + Opus delivery.
     Webm,
+    /// Matroska container for H.264/H.265/VP9/AV1 with any audio codec.
+    Mkv,
+    /// MXF OP1a for DNxHR/ProRes masters with one PCM track per channel.
+    Mxf,
 }
 
 impl ContainerFormat {
@@ -374,6 +467,87 @@ impl ContainerFormat {
             Self::Mp4 => "mp4",
             Self::Mov => "mov",
             Self::Webm => "webm",
+            Self::Mkv => "mkv",
+            Self::Mxf => "mxf",
+        }
+    }
+}
+
+/// ProRes flavour written by `prores_ks`
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ProResProfile {
+    Proxy,
+    Lt,
+    Standard,
+    /// ProRes 422 HQ (default)
+    #[default]
+    Hq,
+    /// ProRes 4444
+    #[serde(rename = "4444")]
+    P4444,
+    /// ProRes 4444 XQ
+    #[serde(rename = "4444_xq")]
+    P4444Xq,
+}
+
+impl ProResProfile {
+    /// `-profile:v` value for `prores_ks`
+    pub fn ffmpeg_profile(&self) -> &'static str {
+        match self {
+            Self::Proxy => "0",
+            Self::Lt => "1",
+            Self::Standard => "2",
+            Self::Hq => "3",
+            Self::P4444 => "4",
+            Self::P4444Xq => "5",
+        }
+    }
+
+    pub fn pixel_format(&self) -> &'static str {
+        match self {
+            Self::P4444 | Self::P4444Xq => "yuv444p10le",
+            Self::Proxy | Self::Lt | Self::Standard | Self::Hq => "yuv422p10le",
+        }
+    }
+}
+
+/// DNxHR flavour written by FFmpeg's `dnxhd` encoder
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum DnxhrProfile {
+    /// Low bandwidth, 8-bit 4:2:2
+    Lb,
+    /// Standard quality, 8-bit 4:2:2
+    Sq,
+    /// High quality, 8-bit 4:2:2
+    Hq,
+    /// High quality, 10-bit 4:2:2 (default)
+    #[default]
+    Hqx,
+    /// 10-bit 4:4:4
+    #[serde(rename = "444")]
+    P444,
+}
+
+impl DnxhrProfile {
+    /// `-profile:v` value for `dnxhd`
+    pub fn ffmpeg_profile(&self) -> &'static str {
+        match self {
+            Self::Lb => "dnxhr_lb",
+            Self::Sq => "dnxhr_sq",
+            Self::Hq => "dnxhr_hq",
+            Self::Hqx => "dnxhr_hqx",
+            Self::P444 => "dnxhr_444",
+        }
+    }
+
+    /// The only pixel format `dnxhd` accepts for the profile
+    pub fn pixel_format(&self) -> &'static str {
+        match self {
+            Self::Lb | Self::Sq | Self::Hq => "yuv422p",
+            Self::Hqx => "yuv422p10le",
+            Self::P444 => "yuv444p10le",
         }
     }
 }
@@ -416,6 +590,14 @@ pub struct VideoExportRequest {
     pub max_cll: Option<u32>,
     pub max_fall: Option<u32>,
     pub bit_depth: Option<u8>,
+    #[serde(default)]
+    pub audio_channel_layout: AudioChannelLayout,
+    #[serde(default)]
+    pub audio_downmix: AudioDownmix,
+    #[serde(default)]
+    pub prores_profile: ProResProfile,
+    #[serde(default)]
+    pub dnxhr_profile: DnxhrProfile,
 }
 
 /// Export settings
@@ -478,6 +660,24 @@ pub struct ExportSettings {
     /// which carry their own tuning parameters.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub encoder_speed: Option<String>,
+    /// Output channel layout; 5.1 and 7.1 need AAC or PCM audio
+    #[serde(default)]
+    pub audio_channel_layout: AudioChannelLayout,
+    /// How sources wider than the output layout are folded down
+    #[serde(default)]
+    pub audio_downmix: AudioDownmix,
+    /// Profile used when `video_codec` is ProRes
+    #[serde(default)]
+    pub prores_profile: ProResProfile,
+    /// Profile used when `video_codec` is DNxHR
+    #[serde(default)]
+    pub dnxhr_profile: DnxhrProfile,
+    /// Tags, chapters and cover art to embed; `None` writes none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<ExportMetadata>,
+    /// Delivery loudness the mix is normalized to; `None` leaves it as mixed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loudness: Option<LoudnessNormalization>,
 }
 
 impl Default for ExportSettings {
@@ -504,6 +704,12 @@ impl Default for ExportSettings {
             hardware_accel: super::hardware::HardwareAccelMode::default(),
             resolved_encoder_name: None,
             encoder_speed: None,
+            audio_channel_layout: AudioChannelLayout::Stereo,
+            audio_downmix: AudioDownmix::Standard,
+            prores_profile: ProResProfile::Hq,
+            dnxhr_profile: DnxhrProfile::Hqx,
+            metadata: None,
+            loudness: None,
         }
     }
 }
@@ -522,7 +728,9 @@ impl ExportSettings {
 
     /// Build the `-preset` arguments for the resolved encoder, if any.
     ///
-    /// Only software x264/x265 accept the `ultrafast … placebo` preset ladder.
+    /// Software x264/x265 accept the `ultrafast … placebo` preset ladder as is.
+    /// The software AV1 encoders number their speeds instead, so the same ladder
+    /// is translated onto SVT-AV1's `-preset` and libaom's `-cpu-used`.
     /// Hardware encoders use their own preset namespace (handled by
     /// [`resolve_quality_args`](super::hardware::resolve_quality_args)) and VP9/ProRes
     /// have no equivalent, so this returns an empty vector for them rather than
@@ -531,14 +739,19 @@ impl ExportSettings {
         let Some(speed) = self.encoder_speed.as_deref() else {
             return Vec::new();
         };
-        if !matches!(encoder_name, "libx264" | "libx265") {
-            return Vec::new();
-        }
         let normalized = speed.trim().to_ascii_lowercase();
-        if !is_valid_encoder_speed(&normalized) {
+        let Some(step) = ENCODER_SPEED_VALUES
+            .iter()
+            .position(|value| *value == normalized)
+        else {
             return Vec::new();
+        };
+        match encoder_name {
+            "libx264" | "libx265" => vec!["-preset".to_string(), normalized],
+            "libsvtav1" => vec!["-preset".to_string(), SVT_AV1_PRESETS[step].to_string()],
+            "libaom-av1" => vec!["-cpu-used".to_string(), AOM_AV1_CPU_USED[step].to_string()],
+            _ => Vec::new(),
         }
-        vec!["-preset".to_string(), normalized]
     }
 
     /// Get the resolved audio encoder name for FFmpeg.
@@ -547,11 +760,32 @@ impl ExportSettings {
             AudioCodec::Aac => "aac",
             AudioCodec::Mp3 => "libmp3lame",
             AudioCodec::Opus => "libopus",
+            AudioCodec::Pcm if self.is_mxf_output() => "pcm_s24le",
             AudioCodec::Pcm => "pcm_s16le",
             AudioCodec::Copy => "copy",
         }
     }
 
+    /// Whether the output path names an MXF file, which carries 24-bit audio
+    /// as one mono PCM track per channel
+    pub fn is_mxf_output(&self) -> bool {
+        matches!(
+            container_from_output_path(&self.output_path),
+            Ok(ContainerFormat::Mxf)
+        )
+    }
+
+    /// `-profile:v` for the intermediate codecs, which select their flavour
+    /// by profile rather than by bitrate or CRF
+    pub fn video_profile_args(&self) -> Vec<String> {
+        let profile = match self.video_codec {
+            VideoCodec::ProRes => self.prores_profile.ffmpeg_profile(),
+            VideoCodec::Dnxhr => self.dnxhr_profile.ffmpeg_profile(),
+            _ => return Vec::new(),
+        };
+        vec!["-profile:v".to_string(), profile.to_string()]
+    }
+
     /// Create settings from a preset
     pub fn from_preset(preset: ExportPreset, output_path: PathBuf) -> Self {
         match preset {
@@ -577,6 +811,12 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::Mp4Draft => Self {
                 preset: ExportPreset::Mp4Draft,
@@ -600,6 +840,12 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::Mp4High => Self {
                 preset: ExportPreset::Mp4High,
@@ -623,6 +869,12 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::Youtube4k => Self {
                 preset: ExportPreset::Youtube4k,
@@ -646,6 +898,12 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::YoutubeShorts => Self {
                 preset: ExportPreset::YoutubeShorts,
@@ -669,6 +927,12 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::Twitter => Self {
                 preset: ExportPreset::Twitter,
@@ -692,6 +956,12 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::Instagram => Self {
                 preset: ExportPreset::Instagram,
@@ -715,6 +985,12 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::WebmVp9 => Self {
                 preset: ExportPreset::WebmVp9,
@@ -738,6 +1014,41 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
+            },
+            ExportPreset::WebmAv1 => Self {
+                preset: ExportPreset::WebmAv1,
+                output_path,
+                video_codec: VideoCodec::Av1,
+                audio_codec: AudioCodec::Opus,
+                width: Some(1920),
+                height: Some(1080),
+                video_bitrate: None,
+                audio_bitrate: Some("128k".to_string()),
+                fps: Some(30.0),
+                crf: Some(32),
+                two_pass: false,
+                start_time: None,
+                end_time: None,
+                hdr_mode: HdrMode::Sdr,
+                max_cll: None,
+                max_fall: None,
+                bit_depth: None,
+                tonemap_mode: None,
+                hardware_accel: super::hardware::HardwareAccelMode::default(),
+                resolved_encoder_name: None,
+                encoder_speed: Some("fast".to_string()),
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
             },
             ExportPreset::ProRes => Self {
                 preset: ExportPreset::ProRes,
@@ -761,6 +1072,17 @@ impl ExportSettings {
                 hardware_accel: super::hardware::HardwareAccelMode::default(),
                 resolved_encoder_name: None,
                 encoder_speed: None,
+                audio_channel_layout: AudioChannelLayout::Stereo,
+                audio_downmix: AudioDownmix::Standard,
+                prores_profile: ProResProfile::Hq,
+                dnxhr_profile: DnxhrProfile::Hqx,
+                metadata: None,
+                loudness: None,
+            },
+            ExportPreset::DnxhrMxf => Self {
+                preset: ExportPreset::DnxhrMxf,
+                video_codec: VideoCodec::Dnxhr,
+                ..Self::from_preset(ExportPreset::ProRes, output_path)
             },
             ExportPreset::Custom => Self {
                 preset: ExportPreset::Custom,
@@ -779,6 +1101,11 @@ impl ExportSettings {
     ) -> Result<Self, ExportError> {
         validate_video_export_request(request, &output_path)?;
 
+        let av1_defaults = match request.video_codec {
+            VideoCodec::Av1 => av1_quality_tier_defaults(&request.quality_tier),
+            _ => None,
+        };
+
         Ok(Self {
             preset: ExportPreset::Custom,
             output_path,
@@ -789,7 +1116,7 @@ impl ExportSettings {
             video_bitrate: request.video_bitrate.clone(),
             audio_bitrate: request.audio_bitrate.clone(),
             fps: request.fps,
-            crf: request.crf,
+            crf: request.crf.or(av1_defaults.map(|(crf, _)| crf)),
             two_pass: request.two_pass,
             start_time,
             end_time,
@@ -800,7 +1127,13 @@ impl ExportSettings {
             tonemap_mode: None,
             hardware_accel: super::hardware::HardwareAccelMode::default(),
             resolved_encoder_name: None,
-            encoder_speed: None,
+            encoder_speed: av1_defaults.map(|(_, speed)| speed.to_string()),
+            audio_channel_layout: request.audio_channel_layout,
+            audio_downmix: request.audio_downmix,
+            prores_profile: request.prores_profile,
+            dnxhr_profile: request.dnxhr_profile,
+            metadata: None,
+            loudness: None,
         })
     }
 
@@ -830,6 +1163,10 @@ impl ExportSettings {
             max_cll: None,
             max_fall: None,
             bit_depth: None,
+            audio_channel_layout: settings.audio_channel_layout,
+            audio_downmix: settings.audio_downmix,
+            prores_profile: settings.prores_profile,
+            dnxhr_profile: settings.dnxhr_profile,
         }
     }
 
@@ -869,6 +1206,12 @@ impl ExportSettings {
             hardware_accel: super::hardware::HardwareAccelMode::default(),
             resolved_encoder_name: None,
             encoder_speed: None,
+            audio_channel_layout: AudioChannelLayout::Stereo,
+            audio_downmix: AudioDownmix::Standard,
+            prores_profile: ProResProfile::Hq,
+            dnxhr_profile: DnxhrProfile::Hqx,
+            metadata: None,
+            loudness: None,
         }
     }
 
@@ -923,6 +1266,12 @@ impl ExportSettings {
             hardware_accel: super::hardware::HardwareAccelMode::default(),
             resolved_encoder_name: None,
             encoder_speed: Some("ultrafast".to_string()),
+            audio_channel_layout: AudioChannelLayout::Stereo,
+            audio_downmix: AudioDownmix::Standard,
+            prores_profile: ProResProfile::Hq,
+            dnxhr_profile: DnxhrProfile::Hqx,
+            metadata: None,
+            loudness: None,
         }
     }
 
@@ -1099,8 +1448,9 @@ impl ExportSettings {
 
 fn preset_default_container(preset: &ExportPreset) -> ContainerFormat {
     match preset {
-        ExportPreset::WebmVp9 => ContainerFormat::Webm,
+        ExportPreset::WebmVp9 | ExportPreset::WebmAv1 => ContainerFormat::Webm,
         ExportPreset::ProRes => ContainerFormat::Mov,
+        ExportPreset::DnxhrMxf => ContainerFormat::Mxf,
         ExportPreset::Youtube1080p
         | ExportPreset::Mp4Draft
         | ExportPreset::Mp4High
@@ -1118,15 +1468,32 @@ fn preset_default_quality_tier(preset: &ExportPreset) -> ExportQualityTier {
         ExportPreset::Mp4High | ExportPreset::Youtube4k | ExportPreset::WebmVp9 => {
             ExportQualityTier::High
         }
-        ExportPreset::ProRes => ExportQualityTier::Master,
-        ExportPreset::Youtube1080p | ExportPreset::YoutubeShorts | ExportPreset::Instagram => {
-            ExportQualityTier::Standard
-        }
+        ExportPreset::ProRes | ExportPreset::DnxhrMxf => ExportQualityTier::Master,
+        ExportPreset::Youtube1080p
+        | ExportPreset::YoutubeShorts
+        | ExportPreset::Instagram
+        | ExportPreset::WebmAv1 => ExportQualityTier::Standard,
         ExportPreset::Custom => ExportQualityTier::Custom,
     }
 }
 
-fn output_extension(path: &Path) -> Option<String> {
+/// CRF and encoder speed an AV1 export gets for a quality tier when the
+/// request leaves them unset, or `None` for `Custom`.
+///
+/// AV1 CRF runs 0–63, so these sit higher than the x264 values for the same
+/// tier. Standard encodes at SVT-AV1 preset 8, which keeps a 1080p export
+/// close to real time on a desktop CPU.
+fn av1_quality_tier_defaults(tier: &ExportQualityTier) -> Option<(u8, &'static str)> {
+    match tier {
+        ExportQualityTier::Draft => Some((40, "veryfast")),
+        ExportQualityTier::Standard => Some((32, "fast")),
+        ExportQualityTier::High => Some((27, "slow")),
+        ExportQualityTier::Master => Some((20, "slower")),
+        ExportQualityTier::Custom => None,
+    }
+}
+
+pub(super) fn output_extension(path: &Path) -> Option<String> {
     path.extension()
         .and_then(|ext| ext.to_str())
         .map(|ext| ext.trim_start_matches('.').to_ascii_lowercase())
@@ -1162,20 +1529,26 @@ fn bitrate_is_valid(value: &str) -> bool {
 fn crf_range_for_codec(codec: &VideoCodec) -> Option<std::ops::RangeInclusive<u8>> {
     match codec {
         VideoCodec::H264 | VideoCodec::H265 => Some(0..=51),
-        VideoCodec::Vp9 => Some(0..=63),
-        VideoCodec::ProRes | VideoCodec::Copy => None,
+        VideoCodec::Vp9 | VideoCodec::Av1 => Some(0..=63),
+        VideoCodec::ProRes | VideoCodec::Dnxhr | VideoCodec::Copy => None,
     }
 }
 
 fn container_supports_video_codec(container: &ContainerFormat, codec: &VideoCodec) -> bool {
     matches!(
         (container, codec),
-        (ContainerFormat::Mp4, VideoCodec::H264 | VideoCodec::H265)
+        (
+            ContainerFormat::Mp4,
+            VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Av1
+        ) | (
+            ContainerFormat::Mov,
+            VideoCodec::H264 | VideoCodec::H265 | VideoCodec::ProRes | VideoCodec::Dnxhr
+        ) | (ContainerFormat::Webm, VideoCodec::Vp9 | VideoCodec::Av1)
             | (
-                ContainerFormat::Mov,
-                VideoCodec::H264 | VideoCodec::H265 | VideoCodec::ProRes
+                ContainerFormat::Mkv,
+                VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Vp9 | VideoCodec::Av1
             )
-            | (ContainerFormat::Webm, VideoCodec::Vp9)
+            | (ContainerFormat::Mxf, VideoCodec::Dnxhr | VideoCodec::ProRes)
     )
 }
 
@@ -1188,6 +1561,11 @@ fn container_supports_audio_codec(container: &ContainerFormat, codec: &AudioCode
                 AudioCodec::Aac | AudioCodec::Mp3 | AudioCodec::Pcm
             )
             | (ContainerFormat::Webm, AudioCodec::Opus)
+            | (
+                ContainerFormat::Mkv,
+                AudioCodec::Aac | AudioCodec::Mp3 | AudioCodec::Opus | AudioCodec::Pcm
+            )
+            | (ContainerFormat::Mxf, AudioCodec::Pcm)
     )
 }
 
@@ -1197,6 +1575,8 @@ fn container_supports_extension(container: &ContainerFormat, extension: &str) ->
         (ContainerFormat::Mp4, "mp4" | "m4v")
             | (ContainerFormat::Mov, "mov")
             | (ContainerFormat::Webm, "webm")
+            | (ContainerFormat::Mkv, "mkv")
+            | (ContainerFormat::Mxf, "mxf")
     )
 }
 
@@ -1279,6 +1659,24 @@ pub fn validate_video_export_request(
         }
     }
 
+    if request.audio_channel_layout.is_surround()
+        && !matches!(request.audio_codec, AudioCodec::Aac | AudioCodec::Pcm)
+    {
+        return Err(ExportError::InvalidSettings(format!(
+            "{} audio export requires AAC or PCM audio. Current codec: {:?}",
+            request.audio_channel_layout.ffmpeg_name(),
+            request.audio_codec
+        )));
+    }
+
+    if request.audio_downmix.is_matrix_encoded() && request.audio_channel_layout.is_surround() {
+        return Err(ExportError::InvalidSettings(format!(
+            "{:?} downmix produces stereo and cannot be used for {} output",
+            request.audio_downmix,
+            request.audio_channel_layout.ffmpeg_name()
+        )));
+    }
+
     if request.two_pass {
         return Err(ExportError::InvalidSettings(
             "Two-pass export is not exposed until pass-one/pass-two execution is implemented"
@@ -1314,11 +1712,13 @@ pub fn validate_video_export_request(
     Ok(())
 }
 
-fn container_from_output_path(path: &Path) -> Result<ContainerFormat, ExportError> {
+pub(super) fn container_from_output_path(path: &Path) -> Result<ContainerFormat, ExportError> {
     match output_extension(path).as_deref() {
         Some("mp4") | Some("m4v") => Ok(ContainerFormat::Mp4),
         Some("mov") => Ok(ContainerFormat::Mov),
         Some("webm") => Ok(ContainerFormat::Webm),
+        Some("mkv") => Ok(ContainerFormat::Mkv),
+        Some("mxf") => Ok(ContainerFormat::Mxf),
         Some(ext) => Err(ExportError::InvalidSettings(format!(
             "Unsupported video output extension: .{ext}"
         ))),
@@ -1339,7 +1739,7 @@ fn validate_export_settings_options(settings: &ExportSettings) -> Vec<String> {
     };
 
     let request = VideoExportRequest {
-        container,
+        container: container.clone(),
         video_codec: settings.video_codec.clone(),
         audio_codec: settings.audio_codec.clone(),
         quality_tier: ExportQualityTier::Custom,
@@ -1354,6 +1754,10 @@ fn validate_export_settings_options(settings: &ExportSettings) -> Vec<String> {
         max_cll: settings.max_cll,
         max_fall: settings.max_fall,
         bit_depth: settings.bit_depth,
+        audio_channel_layout: settings.audio_channel_layout,
+        audio_downmix: settings.audio_downmix,
+        prores_profile: settings.prores_profile,
+        dnxhr_profile: settings.dnxhr_profile,
     };
 
     if let Err(error) = validate_video_export_request(&request, &settings.output_path) {
@@ -1364,6 +1768,18 @@ fn validate_export_settings_options(settings: &ExportSettings) -> Vec<String> {
         errors.push(error);
     }
 
+    if let Some(metadata) = &settings.metadata {
+        errors.extend(metadata.validate(&container));
+    }
+
+    if let Some(loudness) = &settings.loudness {
+        errors.extend(loudness.validate());
+        if settings.audio_codec == AudioCodec::Copy {
+            errors
+                .push("Loudness normalization needs the audio re-encoded, not copied".to_string());
+        }
+    }
+
     if let Some(ref speed) = settings.encoder_speed {
         if !is_valid_encoder_speed(speed) {
             errors.push(format!(
@@ -1407,6 +1823,9 @@ pub struct ExportResult {
     pub file_size: u64,
     /// Total encoding time in seconds
     pub encoding_time_sec: f64,
+    /// Measured loudness, when the export was normalized to a target
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loudness: Option<LoudnessReport>,
 }
 
 // =============================================================================
@@ -1439,6 +1858,8 @@ pub struct BatchRenderItem {
 pub enum RenderJobStatus {
     /// Waiting in queue
     Pending,
+    /// Held in the render queue until resumed
+    Paused,
     /// Currently encoding
     Rendering,
     /// Finished successfully
@@ -1808,6 +2229,26 @@ impl AudioExportSettings {
         Ok(())
     }
 
+    /// FFmpeg output options selecting the codec, bitrate and sample rate
+    fn encoding_args(&self) -> Vec<String> {
+        let mut args = vec!["-c:a".to_string(), self.format.codec().to_string()];
+
+        if let Some(ref bitrate) = self.bitrate {
+            args.push("-b:a".to_string());
+            args.push(bitrate.clone());
+        } else if let Some(default_br) = self.format.default_bitrate() {
+            args.push("-b:a".to_string());
+            args.push(default_br.to_string());
+        }
+
+        if let Some(sr) = self.sample_rate {
+            args.push("-ar".to_string());
+            args.push(sr.to_string());
+        }
+
+        args
+    }
+
     /// Convert to ExportSettings for reuse with the existing render pipeline
     pub fn to_export_settings(&self) -> ExportSettings {
         let bitrate = self
@@ -1846,6 +2287,12 @@ impl AudioExportSettings {
             hardware_accel: super::hardware::HardwareAccelMode::Cpu,
             resolved_encoder_name: None,
             encoder_speed: None,
+            audio_channel_layout: AudioChannelLayout::Stereo,
+            audio_downmix: AudioDownmix::Standard,
+            prores_profile: ProResProfile::Hq,
+            dnxhr_profile: DnxhrProfile::Hqx,
+            metadata: None,
+            loudness: None,
         }
     }
 }
@@ -1866,6 +2313,186 @@ pub struct AudioExportResult {
     pub encoding_time_sec: f64,
 }
 
+/// A group of tracks a stems export writes to its own file
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStem {
+    /// Stem name (e.g. "Dialog"), appended to the output file name
+    pub name: String,
+    /// Tracks mixed into the stem
+    pub track_ids: Vec<TrackId>,
+}
+
+/// Settings for rendering audio stems from a sequence.
+///
+/// Every stem, and the mixdown when requested, is written by one FFmpeg job
+/// from one filter graph: all files share the timeline origin, the padding
+/// and the export range, so they are the same length and line up sample for
+/// sample. A stem carries its tracks' clip and track processing and the master
+/// gain; submix bus effects and gain only shape the mixdown, but a track muted
+/// directly or through its bus is silent in its stem as well.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStemsExportSettings {
+    /// Format, range and encoding shared by every file; `output_path` names
+    /// the mixdown and is the base the stem file names are derived from
+    #[serde(flatten)]
+    pub audio: AudioExportSettings,
+    /// Stems to render, one file each
+    pub stems: Vec<AudioStem>,
+    /// Also write the full mixdown to `output_path`, for comparison
+    #[serde(default)]
+    pub include_mixdown: bool,
+}
+
+impl AudioStemsExportSettings {
+    /// Validate stems export settings
+    pub fn validate(&self) -> Result<(), ExportError> {
+        self.audio.validate()?;
+
+        if self.stems.is_empty() {
+            return Err(ExportError::InvalidSettings(
+                "A stems export needs at least one stem".to_string(),
+            ));
+        }
+
+        let mut file_names = HashSet::new();
+        for stem in &self.stems {
+            if stem.track_ids.is_empty() {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Stem '{}' has no tracks",
+                    stem.name
+                )));
+            }
+            let file_name = stem_file_name_segment(&stem.name);
+            if file_name.is_empty() {
+                return Err(ExportError::InvalidSettings(
+                    "Stem names must contain a letter or digit".to_string(),
+                ));
+            }
+            if !file_names.insert(file_name.to_ascii_lowercase()) {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Stem '{}' would overwrite another stem's file",
+                    stem.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that every stem track exists and carries audio.
+    pub fn validate_tracks(&self, sequence: &Sequence) -> Result<(), ExportError> {
+        for stem in &self.stems {
+            for track_id in &stem.track_ids {
+                let track = sequence.get_track(track_id).ok_or_else(|| {
+                    ExportError::InvalidSettings(format!(
+                        "Stem '{}' references unknown track: {}",
+                        stem.name, track_id
+                    ))
+                })?;
+                if !matches!(track.kind, TrackKind::Video | TrackKind::Audio) {
+                    return Err(ExportError::InvalidSettings(format!(
+                        "Stem '{}' references track '{}', which carries no audio",
+                        stem.name, track.name
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// File a stem is written to: `<mixdown name>_<stem name>.<extension>`,
+    /// next to the mixdown.
+    pub fn stem_output_path(&self, stem: &AudioStem) -> PathBuf {
+        let base = self
+            .audio
+            .output_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_else(|| "audio".to_string());
+        let file_name = format!(
+            "{}_{}.{}",
+            base,
+            stem_file_name_segment(&stem.name),
+            self.audio.format.extension()
+        );
+        match self.audio.output_path.parent() {
+            Some(parent) => parent.join(file_name),
+            None => PathBuf::from(file_name),
+        }
+    }
+}
+
+/// Reduces a stem name to characters that are safe in a file name.
+fn stem_file_name_segment(name: &str) -> String {
+    let segment: String = name
+        .trim()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if segment.chars().any(|c| c.is_ascii_alphanumeric()) {
+        segment
+    } else {
+        String::new()
+    }
+}
+
+/// The sequence a streaming package draws its picture from.
+///
+/// Caption clips keep their place, so the package is as long as a plain
+/// export, but lose their text unless they are to be burned in: the package
+/// carries them as WebVTT renditions instead.
+fn streaming_picture_sequence(sequence: &Sequence, burn_in_captions: bool) -> Sequence {
+    let mut picture = sequence.clone();
+    if !burn_in_captions {
+        for track in picture
+            .tracks
+            .iter_mut()
+            .filter(|track| track.kind == TrackKind::Caption)
+        {
+            for clip in &mut track.clips {
+                clip.label = None;
+            }
+        }
+    }
+    picture
+}
+
+/// One file written by a stems export
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStemFile {
+    /// Stem name, or "Mixdown"
+    pub name: String,
+    /// Output file path
+    pub output_path: PathBuf,
+    /// File size in bytes
+    pub file_size: u64,
+}
+
+/// Result of a stems export
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStemsExportResult {
+    /// Stem files, in the order the stems were requested
+    pub stems: Vec<AudioStemFile>,
+    /// The mixdown, when requested
+    pub mixdown: Option<AudioStemFile>,
+    /// Duration of every file in seconds
+    pub duration_sec: f64,
+    /// Audio format used
+    pub format: AudioExportFormat,
+    /// Total encoding time in seconds
+    pub encoding_time_sec: f64,
+}
+
 // =============================================================================
 // Render Job Registry (Cancel Support)
 // =============================================================================
@@ -1981,6 +2608,95 @@ fn normalize_output_time_range(
     Ok((normalized_start, normalized_end))
 }
 
+/// A cancel receiver for one FFmpeg process of a job that runs several,
+/// fired once `cancel` turns true.
+pub(super) fn process_cancel_receiver(
+    cancel: &tokio::sync::watch::Receiver<bool>,
+) -> oneshot::Receiver<()> {
+    let (tx, rx) = oneshot::channel();
+    let mut cancel = cancel.clone();
+    tokio::spawn(async move {
+        let mut tx = tx;
+        let cancelled = tokio::select! {
+            () = tx.closed() => false,
+            result = cancel.wait_for(|cancelled| *cancelled) => result.is_ok(),
+        };
+        if cancelled {
+            let _ = tx.send(());
+        }
+    });
+    rx
+}
+
+/// Chapters `settings.metadata` embeds in the export, in seconds from the
+/// start of the exported range; empty when chapters are off
+pub fn export_chapter_spans(sequence: &Sequence, settings: &ExportSettings) -> Vec<ChapterSpan> {
+    if !settings
+        .metadata
+        .as_ref()
+        .is_some_and(|metadata| metadata.chapters)
+    {
+        return Vec::new();
+    }
+    let range_start = settings
+        .start_time
+        .unwrap_or(0.0)
+        .max(0.0)
+        .min(sequence.output_duration());
+    let duration = effective_export_duration(sequence, settings.start_time, settings.end_time);
+    chapter_spans(sequence, range_start, range_start + duration)
+}
+
+/// Writes the tags of `settings.metadata` and `chapters`, shifted by
+/// `offset_sec`, to a temporary `FFMETADATA1` file, returning the directory
+/// holding it (which must outlive the render) and its path.
+///
+/// Returns `None` when there is nothing to embed or the container cannot
+/// carry it.
+pub(super) async fn write_ffmetadata_file(
+    settings: &ExportSettings,
+    chapters: &[ChapterSpan],
+    offset_sec: f64,
+) -> Result<Option<(tempfile::TempDir, PathBuf)>, ExportError> {
+    let Some(metadata) = &settings.metadata else {
+        return Ok(None);
+    };
+    if metadata.tags().is_empty() && chapters.is_empty() {
+        return Ok(None);
+    }
+    if !container_from_output_path(&settings.output_path)
+        .is_ok_and(|container| metadata_supported(&container))
+    {
+        return Ok(None);
+    }
+
+    let temp_dir = tempfile::Builder::new()
+        .prefix("openreelio-metadata-")
+        .tempdir()
+        .map_err(ExportError::IoError)?;
+    let path = temp_dir.path().join("metadata.txt");
+    tokio::fs::write(&path, ffmetadata(metadata, chapters, offset_sec))
+        .await
+        .map_err(ExportError::IoError)?;
+    Ok(Some((temp_dir, path)))
+}
+
+/// Writes the YouTube chapter list beside a finished export
+pub(super) async fn write_chapter_list(
+    output_path: &Path,
+    chapters: &[ChapterSpan],
+) -> Result<(), ExportError> {
+    if chapters.is_empty() {
+        return Ok(());
+    }
+    tokio::fs::write(
+        chapter_list_path(output_path),
+        youtube_chapter_list(chapters),
+    )
+    .await
+    .map_err(ExportError::IoError)
+}
+
 /// Length of the file a render of `sequence` over this range writes.
 ///
 /// Derived from [`Sequence::output_duration`] so the reported duration, the
@@ -2151,7 +2867,7 @@ fn build_atempo_chain(speed: f64) -> Option<String> {
 }
 
 /// Format a speed value without unnecessary trailing zeros.
-fn format_speed_number(value: f64) -> String {
+pub(super) fn format_speed_number(value: f64) -> String {
     let mut s = format!("{:.6}", value);
     let trimmed_len = s.trim_end_matches('0').trim_end_matches('.').len();
     s.truncate(trimmed_len);
@@ -2560,14 +3276,21 @@ pub(super) fn build_audio_trim_filter(
         "transition handles are only defined for constant-speed clips"
     );
 
+    // Picking source channels comes first, so effects see only the channels
+    // the clip plays.
+    let channel_map = channel_map_filter(&clip.audio.channel_map)
+        .map(|filter| format!("{},", filter))
+        .unwrap_or_default();
+
     if clip.has_time_remap() {
         // Time remap: trim the full source range, then apply average speed via atempo
         let remap = clip.time_remap.as_ref().unwrap();
         let (source_start, source_end) = remap.source_range();
 
         let filter = format!(
-            "[{}:a]atrim=start={}:end={},asetpts=PTS-STARTPTS[{}]",
+            "[{}:a]{}atrim=start={}:end={},asetpts=PTS-STARTPTS[{}]",
             input_index,
+            channel_map,
             format_speed_number(source_start),
             format_speed_number(source_end),
             audio_trim_label
@@ -2614,8 +3337,8 @@ pub(super) fn build_audio_trim_filter(
     // Regular audio trim
     let (source_in, source_out) = handled_source_window(clip, handles);
     let filter = format!(
-        "[{}:a]atrim=start={}:end={},asetpts=PTS-STARTPTS[{}]",
-        input_index, source_in, source_out, audio_trim_label
+        "[{}:a]{}atrim=start={}:end={},asetpts=PTS-STARTPTS[{}]",
+        input_index, channel_map, source_in, source_out, audio_trim_label
     );
     filter_complex.push_str(&filter);
     filter_complex.push(';');
@@ -2823,6 +3546,7 @@ pub(super) fn apply_audio_mix_settings(
     current_label: &str,
     filter_complex: &mut String,
     handles: ClipHandles,
+    settings: &ExportSettings,
 ) -> String {
     let mut current_label = current_label.to_string();
 
@@ -2853,6 +3577,22 @@ pub(super) fn apply_audio_mix_settings(
         current_label = pan_label;
     }
 
+    let layout_filter = match track.surround_pan {
+        Some(surround_pan) => Some(surround_pan_filter(
+            surround_pan,
+            settings.audio_channel_layout,
+        )),
+        None => channel_conversion_filter(settings.audio_channel_layout, settings.audio_downmix),
+    };
+    if let Some(layout_filter) = layout_filter {
+        let layout_label = format!("alayout{}", input_index);
+        filter_complex.push_str(&format!(
+            "[{}]{}[{}];",
+            current_label, layout_filter, layout_label
+        ));
+        current_label = layout_label;
+    }
+
     let branch_start_sec = (clip.place.timeline_in_sec - handles.head_sec.max(0.0)).max(0.0);
     let delay_ms = (branch_start_sec * 1000.0).round() as u64;
     if delay_ms > 0 {
@@ -2945,8 +3685,13 @@ pub(super) fn output_video_pixel_format(settings: &ExportSettings) -> &'static s
     let use_10_bit = settings.is_hdr() || settings.bit_depth.unwrap_or(8) >= 10;
 
     match settings.video_codec {
-        VideoCodec::ProRes => "yuv422p10le",
-        VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Vp9 | VideoCodec::Copy => {
+        VideoCodec::ProRes => settings.prores_profile.pixel_format(),
+        VideoCodec::Dnxhr => settings.dnxhr_profile.pixel_format(),
+        VideoCodec::H264
+        | VideoCodec::H265
+        | VideoCodec::Vp9
+        | VideoCodec::Av1
+        | VideoCodec::Copy => {
             if use_10_bit {
                 "yuv420p10le"
             } else {
@@ -2999,13 +3744,26 @@ pub(super) fn append_video_stream_normalization(
         _ => String::new(),
     };
 
+    // Padding a source without alpha in its own format would letterbox it in
+    // opaque black, so an alpha composite converts before it pads.
+    let pad = if has_alpha(pixel_format) {
+        format!(
+            "format={},pad={}:{}:(ow-iw)/2:(oh-ih)/2:color={}",
+            pixel_format,
+            width,
+            height,
+            canvas_fill_color(pixel_format)
+        )
+    } else {
+        format!("pad={}:{}:(ow-iw)/2:(oh-ih)/2", width, height)
+    };
+
     filter_complex.push_str(&format!(
-        "[{}]scale={}:{}:force_original_aspect_ratio=decrease,pad={}:{}:(ow-iw)/2:(oh-ih)/2,setsar=1,fps={},format={}{}[{}];",
+        "[{}]scale={}:{}:force_original_aspect_ratio=decrease,{},setsar=1,fps={},format={}{}[{}];",
         input_label,
         width,
         height,
-        width,
-        height,
+        pad,
         format_speed_number(fps),
         pixel_format,
         pin,
@@ -3013,6 +3771,21 @@ pub(super) fn append_video_stream_normalization(
     ));
 }
 
+/// Whether the picture is composited with an alpha channel.
+fn has_alpha(pixel_format: &str) -> bool {
+    pixel_format.starts_with("yuva")
+}
+
+/// What the empty canvas is filled with: black, or transparent black when the
+/// picture carries alpha, so gaps and letterboxing stay see-through.
+fn canvas_fill_color(pixel_format: &str) -> &'static str {
+    if has_alpha(pixel_format) {
+        "black@0"
+    } else {
+        "black"
+    }
+}
+
 /// Frames of cloned tail `tpad` adds behind a pinned segment.
 ///
 /// Covers the `HANDLE_SLACK_FRAMES` the planner allows the two duration probes
@@ -3154,6 +3927,16 @@ pub(super) fn resolve_asset_source_duration(
         return *cached;
     }
 
+    // An image sequence's `uri` is its first frame; only the frame count and
+    // the user-set rate say how long it plays.
+    if asset.image_sequence.is_some() {
+        let resolved = asset
+            .duration_sec
+            .filter(|duration| duration.is_finite() && *duration > 0.0);
+        cache.insert(asset.id.clone(), resolved);
+        return resolved;
+    }
+
     let probed = match crate::core::assets::MetadataExtractor::extract(&asset.uri) {
         // The video stream's own length when the file has one, for the same
         // reason [`AssetAudioInfo::source_duration_sec`] carries it: a container
@@ -3636,6 +4419,10 @@ fn transform_composition_formats(pixel_format: &str) -> (&'static str, &'static
     match pixel_format {
         "yuv422p10le" => ("yuva422p10le", "yuv422p10"),
         "yuv420p10le" => ("yuva420p10le", "yuv420p10"),
+        "yuv444p" | "yuva444p" => ("yuva444p", "yuv444"),
+        // `overlay` has no fixed 10-bit 4:4:4 mode on every release it runs
+        // on; `auto` follows the canvas, which is already 10-bit.
+        "yuv444p10le" | "yuva444p10le" => ("yuva444p10le", "auto"),
         _ => ("yuva420p", "yuv420"),
     }
 }
@@ -3650,7 +4437,8 @@ pub(super) fn append_black_video_gap(
     pixel_format: &str,
 ) {
     filter_complex.push_str(&format!(
-        "color=c=black:s={}x{}:r={}:d={},format={}[{}];",
+        "color=c={}:s={}x{}:r={}:d={},format={}[{}];",
+        canvas_fill_color(pixel_format),
         width,
         height,
         format_speed_number(fps),
@@ -3770,13 +4558,86 @@ pub(super) fn append_master_audio_output(
     audio_streams: &[String],
     master_volume_db: f32,
     timeline_end_sec: f64,
+) -> Option<String> {
+    append_padded_audio_output(
+        filter_complex,
+        audio_streams,
+        master_volume_db,
+        timeline_end_sec,
+        "outa",
+    )
+}
+
+/// Pins the master mix to a surround output layout, returning the label to
+/// map. Stereo output keeps the label it is given.
+pub(super) fn append_output_channel_layout(
+    filter_complex: &mut String,
+    audio_label: String,
+    settings: &ExportSettings,
+) -> String {
+    if !settings.audio_channel_layout.is_surround() {
+        return audio_label;
+    }
+
+    filter_complex.push(';');
+    filter_complex.push_str(&format!(
+        "{}aformat=channel_layouts={}[outa_layout]",
+        audio_label,
+        settings.audio_channel_layout.ffmpeg_name()
+    ));
+    "[outa_layout]".to_string()
+}
+
+/// Normalizes the mix to `settings.loudness`.
+///
+/// The mix is trimmed to the export range first, so only the delivered audio
+/// is measured and corrected, and its timestamps are shifted back afterwards
+/// so the output `-ss` still lands on the range start.
+pub(super) fn append_loudness_normalization(
+    filter_complex: &mut String,
+    audio_label: String,
+    sample_rate: u32,
+    settings: &ExportSettings,
+) -> String {
+    let Some(loudness) = &settings.loudness else {
+        return audio_label;
+    };
+
+    let start = settings.start_time.unwrap_or(0.0).max(0.0);
+    let mut chain = String::new();
+    if start > 0.0 || settings.end_time.is_some() {
+        chain.push_str(&format!("atrim=start={}", format_speed_number(start)));
+        if let Some(end) = settings.end_time {
+            chain.push_str(&format!(":end={}", format_speed_number(end)));
+        }
+        chain.push_str(",asetpts=PTS-STARTPTS,");
+    }
+    chain.push_str(&loudness.filter(sample_rate));
+    if start > 0.0 {
+        chain.push_str(&format!(",asetpts=PTS+{}/TB", format_speed_number(start)));
+    }
+
+    filter_complex.push(';');
+    filter_complex.push_str(&format!("{}{}[outa_loudness]", audio_label, chain));
+    "[outa_loudness]".to_string()
+}
+
+/// Mixes `audio_streams`, pads the result to the timeline end and applies the
+/// master gain, labelling the result `[<label>]` (or `[<label>_base]` at unity
+/// gain).
+fn append_padded_audio_output(
+    filter_complex: &mut String,
+    audio_streams: &[String],
+    master_volume_db: f32,
+    timeline_end_sec: f64,
+    label: &str,
 ) -> Option<String> {
     if audio_streams.is_empty() {
         return None;
     }
 
-    const BASE_AUDIO_LABEL: &str = "[outa_base]";
-    const FINAL_AUDIO_LABEL: &str = "[outa]";
+    let base_audio_label = format!("[{}_base]", label);
+    let final_audio_label = format!("[{}]", label);
 
     // Audio that runs out before the picture does leaves the muxer with a
     // stream shorter than the video, and an export range that starts after the
@@ -3792,7 +4653,7 @@ pub(super) fn append_master_audio_output(
     if audio_streams.len() == 1 {
         filter_complex.push_str(&format!(
             "{}anull{}{}",
-            audio_streams[0], tail_padding, BASE_AUDIO_LABEL
+            audio_streams[0], tail_padding, base_audio_label
         ));
     } else {
         filter_complex.push_str(&audio_streams.join(""));
@@ -3800,24 +4661,187 @@ pub(super) fn append_master_audio_output(
             "amix=inputs={}:duration=longest:dropout_transition=0:normalize=0{}{}",
             audio_streams.len(),
             tail_padding,
-            BASE_AUDIO_LABEL
+            base_audio_label
         ));
     }
 
     let clamped_master_volume_db = master_volume_db.clamp(-60.0, 6.0);
     if clamped_master_volume_db.abs() < f32::EPSILON {
-        return Some(BASE_AUDIO_LABEL.to_string());
+        return Some(base_audio_label);
     }
 
     filter_complex.push(';');
     filter_complex.push_str(&format!(
         "{}volume={:.6}{}",
-        BASE_AUDIO_LABEL,
+        base_audio_label,
         volume_db_to_linear(clamped_master_volume_db),
-        FINAL_AUDIO_LABEL
+        final_audio_label
+    ));
+
+    Some(final_audio_label)
+}
+
+/// Mixes one stem of a stems export, returning the label carrying it.
+///
+/// A stem is padded to the timeline end and takes the master gain exactly as
+/// the mixdown does, so every file the export writes is the same length. A
+/// stem none of whose tracks play in this render is silence of that length.
+pub(super) fn append_stem_audio_output(
+    filter_complex: &mut String,
+    index: usize,
+    audio_streams: &[String],
+    sequence: &Sequence,
+    sample_rate: u32,
+    timeline_end_sec: f64,
+) -> String {
+    let label = format!("stem{}", index);
+    if let Some(output) = append_padded_audio_output(
+        filter_complex,
+        audio_streams,
+        sequence.master_volume_db,
+        timeline_end_sec,
+        &label,
+    ) {
+        return output;
+    }
+
+    let channel_layout = if sequence.format.audio_channels == 1 {
+        "mono"
+    } else {
+        "stereo"
+    };
+    filter_complex.push(';');
+    filter_complex.push_str(&format!(
+        "anullsrc=r={}:cl={},atrim=duration={}[{}]",
+        sample_rate,
+        channel_layout,
+        format_speed_number(timeline_end_sec.max(0.0)),
+        label
     ));
+    format!("[{}]", label)
+}
+
+/// Splits an audio stream so `count` outputs can each consume it, returning
+/// the labels to use. A stream with a single consumer is returned as is.
+pub(super) fn append_audio_stream_split(
+    filter_complex: &mut String,
+    stream: &str,
+    count: usize,
+) -> Vec<String> {
+    if count <= 1 {
+        return vec![stream.to_string()];
+    }
+
+    let inner = stream.trim_start_matches('[').trim_end_matches(']');
+    let labels: Vec<String> = (0..count)
+        .map(|index| format!("[{}s{}]", inner, index))
+        .collect();
+    filter_complex.push(';');
+    filter_complex.push_str(&format!("{}asplit={}{}", stream, count, labels.join("")));
+    labels
+}
+
+/// Mixes clip audio through the sequence's submix buses.
+///
+/// Each stream joins the bus its track routes to, and buses are mixed feeders
+/// first: a bus sums its inputs, runs its audio effects and applies its gain,
+/// and its output joins whatever it feeds. The streams that reach the master —
+/// from tracks routed straight to it and from top-level buses — are returned
+/// for [`append_master_audio_output`]. A sequence without buses gets its streams
+/// back untouched, so its graph is exactly what it was before buses existed.
+///
+/// Streams from tracks under a muted bus must already have been left out; see
+/// [`track_audio_reaches_master`].
+pub(super) fn append_audio_bus_mix(
+    filter_complex: &mut String,
+    sequence: &Sequence,
+    effects: &HashMap<String, Effect>,
+    track_streams: Vec<(&str, String)>,
+) -> Result<Vec<String>, ExportError> {
+    if sequence.audio_buses.is_empty() {
+        return Ok(track_streams.into_iter().map(|(_, label)| label).collect());
+    }
+
+    let mix_order = audio_bus_mix_order(sequence)
+        .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+
+    let mut bus_inputs: HashMap<&str, Vec<String>> = HashMap::new();
+    let mut master_inputs = Vec::new();
+    for (track_id, label) in track_streams {
+        match sequence
+            .get_track(track_id)
+            .and_then(|track| track.output_bus_id.as_deref())
+        {
+            Some(bus_id) => bus_inputs.entry(bus_id).or_default().push(label),
+            None => master_inputs.push(label),
+        }
+    }
+
+    for (index, bus) in mix_order.into_iter().enumerate() {
+        // A bus nothing plays into this render adds nothing to the mix.
+        let Some(inputs) = bus_inputs.remove(bus.id.as_str()) else {
+            continue;
+        };
+        let output = append_bus_output(filter_complex, bus, index, &inputs, effects);
+        match bus.output_bus_id.as_deref() {
+            Some(parent_id) => bus_inputs.entry(parent_id).or_default().push(output),
+            None => master_inputs.push(output),
+        }
+    }
+
+    Ok(master_inputs)
+}
+
+/// Sums a bus's inputs, runs its effect chain and applies its gain, returning
+/// the label carrying the result.
+fn append_bus_output(
+    filter_complex: &mut String,
+    bus: &AudioBus,
+    index: usize,
+    inputs: &[String],
+    effects: &HashMap<String, Effect>,
+) -> String {
+    let mix_label = format!("bus{}mix", index);
+    filter_complex.push(';');
+    if inputs.len() == 1 {
+        filter_complex.push_str(&format!("{}anull[{}]", inputs[0], mix_label));
+    } else {
+        filter_complex.push_str(&inputs.join(""));
+        filter_complex.push_str(&format!(
+            "amix=inputs={}:duration=longest:dropout_transition=0:normalize=0[{}]",
+            inputs.len(),
+            mix_label
+        ));
+    }
+    let mut current_label = mix_label;
 
-    Some(FINAL_AUDIO_LABEL.to_string())
+    let mut graph = FilterGraph::new();
+    for effect in bus.effects.iter().filter_map(|id| effects.get(id)) {
+        if effect.is_audio() {
+            graph.add_effect(effect.clone());
+        }
+    }
+    if graph.has_audio_effects() {
+        let effects_label = format!("bus{}fx", index);
+        filter_complex.push(';');
+        filter_complex.push_str(&graph.to_audio_filter_complex(&current_label, &effects_label));
+        current_label = effects_label;
+    }
+
+    let clamped_volume_db = bus.volume_db.clamp(-60.0, 6.0);
+    if clamped_volume_db.abs() >= f32::EPSILON {
+        let volume_label = format!("bus{}", index);
+        filter_complex.push(';');
+        filter_complex.push_str(&format!(
+            "[{}]volume={:.6}[{}]",
+            current_label,
+            volume_db_to_linear(clamped_volume_db),
+            volume_label
+        ));
+        current_label = volume_label;
+    }
+
+    format!("[{}]", current_label)
 }
 
 // =============================================================================
@@ -5476,6 +6500,12 @@ impl ExportEngine {
         // Probe each unique asset
         for asset_id in unique_asset_ids {
             if let Some(asset) = assets.get(&asset_id) {
+                // Stills carry no audio and no timing of their own
+                if asset.image_sequence.is_some() {
+                    audio_info_map.insert(asset_id, AssetAudioInfo::from_asset(asset));
+                    continue;
+                }
+
                 // Try to probe the media file
                 match self.ffmpeg.probe(Path::new(&asset.uri)).await {
                     Ok(media_info) => {
@@ -5528,28 +6558,39 @@ impl ExportEngine {
         supported
     }
 
-    /// Build FFmpeg arguments for simple single-clip export
+    /// Build FFmpeg arguments for simple single-clip export, reading the
+    /// asset opened by `input_args` (see [`Asset::ffmpeg_input_args`])
     fn build_simple_export_args(
         &self,
-        input_path: &Path,
+        input_args: Vec<String>,
         settings: &ExportSettings,
     ) -> Vec<String> {
         let video_codec = settings.video_encoder_name();
         let audio_codec = settings.audio_encoder_name();
 
-        let mut args = vec![
-            "-i".to_string(),
-            input_path.to_string_lossy().to_string(),
+        let mut args = super::hardware::hardware_device_args(&video_codec);
+        args.extend(input_args);
+        args.extend([
             "-c:v".to_string(),
             video_codec.clone(),
             "-c:a".to_string(),
             audio_codec.to_string(),
-        ];
+        ]);
 
-        // Resolution
-        if let (Some(w), Some(h)) = (settings.width, settings.height) {
+        // Resolution, then the upload for encoders that read from GPU memory
+        let video_filters: Vec<String> = settings
+            .width
+            .zip(settings.height)
+            .map(|(w, h)| format!("scale={}:{}", w, h))
+            .into_iter()
+            .chain(super::hardware::hardware_upload_filter(
+                &video_codec,
+                output_video_pixel_format(settings),
+            ))
+            .collect();
+        if !video_filters.is_empty() {
             args.push("-vf".to_string());
-            args.push(format!("scale={}:{}", w, h));
+            args.push(video_filters.join(","));
         }
 
         // Video bitrate
@@ -5568,7 +6609,7 @@ impl ExportEngine {
         if let Some(crf) = settings.crf {
             if matches!(
                 settings.video_codec,
-                VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Vp9
+                VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Vp9 | VideoCodec::Av1
             ) {
                 args.extend(super::hardware::resolve_quality_args(&video_codec, crf));
             }
@@ -5577,6 +6618,15 @@ impl ExportEngine {
         // Encoder speed/compression trade-off (software x264/x265 only)
         args.extend(settings.encoder_speed_args(&video_codec));
 
+        // Intermediate codecs select their flavour by profile, and each
+        // profile accepts a single pixel format
+        let profile_args = settings.video_profile_args();
+        if !profile_args.is_empty() {
+            args.extend(profile_args);
+            args.push("-pix_fmt".to_string());
+            args.push(output_video_pixel_format(settings).to_string());
+        }
+
         // HDR metadata
         args.extend(settings.hdr_args());
 
@@ -5819,6 +6869,8 @@ impl ExportEngine {
                 settings,
                 render_plan: None,
                 ass_text_overlay_path,
+                working_pixel_format: None,
+                ffmetadata_path: None,
             },
         )
     }
@@ -5845,6 +6897,141 @@ impl ExportEngine {
         )
     }
 
+    #[cfg(test)]
+    fn build_audio_stems_filter_args_with_audio_info(
+        &self,
+        sequence: &Sequence,
+        assets: &std::collections::HashMap<String, Asset>,
+        audio_info: &std::collections::HashMap<String, AssetAudioInfo>,
+        settings: &AudioStemsExportSettings,
+    ) -> Result<Vec<String>, ExportError> {
+        let stem_paths: Vec<PathBuf> = settings
+            .stems
+            .iter()
+            .map(|stem| settings.stem_output_path(stem))
+            .collect();
+        let stem_outputs: Vec<super::ffmpeg_plan::AudioStemOutput<'_>> = settings
+            .stems
+            .iter()
+            .zip(&stem_paths)
+            .map(|(stem, path)| super::ffmpeg_plan::AudioStemOutput {
+                track_ids: &stem.track_ids,
+                output_path: path,
+            })
+            .collect();
+        super::ffmpeg_plan::build_audio_stems_ffmpeg_args(
+            super::ffmpeg_plan::AudioOnlyFfmpegBuildContext {
+                engine: self,
+                sequence,
+                assets,
+                effects: &std::collections::HashMap::new(),
+                audio_info,
+                settings: &settings.audio.to_export_settings(),
+                render_plan: None,
+            },
+            &stem_outputs,
+            settings.include_mixdown,
+            sequence.format.audio_sample_rate,
+            &settings.audio.encoding_args(),
+        )
+    }
+
+    #[cfg(test)]
+    fn build_streaming_package_filter_args_with_audio_info(
+        &self,
+        sequence: &Sequence,
+        assets: &std::collections::HashMap<String, Asset>,
+        audio_info: &std::collections::HashMap<String, AssetAudioInfo>,
+        settings: &StreamingPackageSettings,
+    ) -> Result<Vec<String>, ExportError> {
+        let rungs: Vec<super::ffmpeg_plan::LadderRung> = settings
+            .renditions
+            .iter()
+            .map(|rendition| {
+                let (width, height) = settings.rendition_size(sequence, rendition);
+                super::ffmpeg_plan::LadderRung {
+                    width,
+                    height,
+                    bitrate_bps: super::streaming::parse_bitrate_bps(&rendition.video_bitrate)
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+        super::ffmpeg_plan::build_streaming_package_ffmpeg_args(
+            super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                engine: self,
+                sequence: &streaming_picture_sequence(sequence, settings.burn_in_captions),
+                assets,
+                effects: &std::collections::HashMap::new(),
+                audio_info,
+                settings: &settings.to_export_settings(sequence),
+                render_plan: None,
+                ass_text_overlay_path: None,
+                working_pixel_format: None,
+                ffmetadata_path: None,
+            },
+            &rungs,
+            settings.segment_duration_sec,
+        )
+    }
+
+    #[cfg(test)]
+    fn build_image_sequence_filter_args_with_audio_info(
+        &self,
+        sequence: &Sequence,
+        assets: &std::collections::HashMap<String, Asset>,
+        audio_info: &std::collections::HashMap<String, AssetAudioInfo>,
+        settings: &ImageSequenceExportSettings,
+    ) -> Result<Vec<String>, ExportError> {
+        super::ffmpeg_plan::build_image_sequence_ffmpeg_args(
+            super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                engine: self,
+                sequence,
+                assets,
+                effects: &std::collections::HashMap::new(),
+                audio_info,
+                settings: &settings.to_export_settings(),
+                render_plan: None,
+                ass_text_overlay_path: None,
+                working_pixel_format: Some(settings.working_pixel_format()),
+                ffmetadata_path: None,
+            },
+            settings.encoding_args(),
+            settings.first_number(sequence),
+        )
+    }
+
+    #[cfg(test)]
+    fn build_animated_image_filter_args_with_audio_info(
+        &self,
+        sequence: &Sequence,
+        assets: &std::collections::HashMap<String, Asset>,
+        audio_info: &std::collections::HashMap<String, AssetAudioInfo>,
+        settings: &AnimatedImageExportSettings,
+        candidate: &super::AnimationCandidate,
+    ) -> Result<Vec<String>, ExportError> {
+        let start = settings.start_time.unwrap_or(0.0);
+        let end = settings
+            .end_time
+            .unwrap_or_else(|| sequence.output_duration());
+        super::ffmpeg_plan::build_animated_image_ffmpeg_args(
+            super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                engine: self,
+                sequence,
+                assets,
+                effects: &std::collections::HashMap::new(),
+                audio_info,
+                settings: &settings.to_export_settings(),
+                render_plan: None,
+                ass_text_overlay_path: None,
+                working_pixel_format: Some(settings.format.working_pixel_format()),
+                ffmetadata_path: None,
+            },
+            &settings.animation_filter(candidate, start, end),
+            settings.encoding_args(),
+        )
+    }
+
     /// Export a sequence to a video file
     ///
     /// Supports real-time progress reporting via the progress channel.
@@ -5949,34 +7136,53 @@ impl ExportEngine {
         // This prevents FFmpeg from failing when clips don't have audio
         let audio_info = self.probe_assets_for_audio(sequence, assets).await;
 
-        let mut ass_text_overlay_dir: Option<tempfile::TempDir> = None;
-        let mut ass_text_overlay_path: Option<PathBuf> = None;
-        if let Some(ass_script) = build_ass_text_overlay_script(sequence, effects)? {
-            if self.ffmpeg_supports_filter("subtitles").await {
-                let temp_dir = tempfile::Builder::new()
-                    .prefix("openreelio-text-overlays-")
-                    .tempdir()
-                    .map_err(ExportError::IoError)?;
-                let ass_path = temp_dir.path().join("text-overlays.ass");
-                // The `subtitles` filter takes this path as a quoted option value, and
-                // FFmpeg's filtergraph grammar cannot carry a literal `'` through to the
-                // filter. The temp directory inherits the system temp root, which on
-                // Windows sits under the user profile (`C:\Users\Ben's PC\...`), so this
-                // is reachable without anything malformed. Fail loudly with a fixable
-                // instruction rather than rendering a video with every caption missing.
-                crate::core::fs::validate_filter_safe_path(&ass_path, "Text overlay path")
-                    .map_err(ExportError::InvalidSettings)?;
-                tokio::fs::write(&ass_path, ass_script)
-                    .await
-                    .map_err(ExportError::IoError)?;
-                ass_text_overlay_path = Some(ass_path);
-                ass_text_overlay_dir = Some(temp_dir);
-            } else {
-                tracing::warn!(
-                    "FFmpeg subtitles filter is unavailable; falling back to drawtext overlays"
-                );
+        // A loudness target is measured on the final mix first, and the encode
+        // then applies it as one linear gain. Both processes share the cancel.
+        let loudness_cancel = settings
+            .loudness
+            .as_ref()
+            .is_some_and(|loudness| loudness.measured.is_none())
+            .then(|| tokio::sync::watch::channel(false));
+        let mut cancel_rx = cancel_rx;
+        let measured_settings = match &loudness_cancel {
+            Some((cancel_all_tx, cancel_all_rx)) => {
+                if let Some(cancel_rx) = cancel_rx.take() {
+                    let cancel_all_tx = cancel_all_tx.clone();
+                    tokio::spawn(async move {
+                        if cancel_rx.await.is_ok() {
+                            let _ = cancel_all_tx.send(true);
+                        }
+                    });
+                }
+                let measured = self
+                    .measure_sequence_loudness(
+                        sequence,
+                        assets,
+                        effects,
+                        &audio_info,
+                        settings,
+                        progress_tx.as_ref(),
+                        process_cancel_receiver(cancel_all_rx),
+                    )
+                    .await?;
+                cancel_rx = Some(process_cancel_receiver(cancel_all_rx));
+                Some(measured)
             }
-        }
+            None => None,
+        };
+        let settings = measured_settings.as_ref().unwrap_or(settings);
+
+        let ass_text_overlay = self.write_ass_text_overlay(sequence, effects).await?;
+        let ass_text_overlay_path = ass_text_overlay.as_ref().map(|(_, path)| path.clone());
+        // FFmpeg shifts mapped chapters back by the output `-ss` itself, so
+        // they are written in timeline time.
+        let chapters = export_chapter_spans(sequence, settings);
+        let ffmetadata = write_ffmetadata_file(
+            settings,
+            &chapters,
+            settings.start_time.unwrap_or(0.0).max(0.0),
+        )
+        .await?;
 
         let mut args = super::ffmpeg_plan::build_sequence_ffmpeg_args(
             super::ffmpeg_plan::SequenceFfmpegBuildContext {
@@ -5988,10 +7194,13 @@ impl ExportEngine {
                 settings,
                 render_plan,
                 ass_text_overlay_path: ass_text_overlay_path.as_deref(),
+                working_pixel_format: None,
+                ffmetadata_path: ffmetadata.as_ref().map(|(_, path)| path.as_path()),
             },
         )?;
 
-        let _keep_ass_text_overlay_dir_alive = ass_text_overlay_dir;
+        let _keep_ass_text_overlay_dir_alive = ass_text_overlay;
+        let _keep_ffmetadata_dir_alive = ffmetadata;
 
         // Calculate total duration from enabled clips only so progress/ETA
         // are accurate when trailing clips are disabled.
@@ -6019,51 +7228,218 @@ impl ExportEngine {
             "Export complete!",
         )
         .await?;
+        write_chapter_list(&execution.output_path, &chapters).await?;
+        let loudness = settings
+            .loudness
+            .as_ref()
+            .and_then(|loudness| LoudnessReport::new(loudness, &execution.stderr));
 
         Ok(ExportResult {
             output_path: execution.output_path,
             duration_sec: total_duration,
             file_size: execution.file_size,
             encoding_time_sec: execution.encoding_time_sec,
+            loudness,
         })
     }
 
-    /// Export a single asset (simple transcode)
-    ///
-    /// Supports real-time progress reporting via the progress channel.
-    pub async fn export_asset(
+    /// Measures the mix `settings` exports with a first `loudnorm` pass and
+    /// returns the settings with the measurement filled in.
+    #[allow(clippy::too_many_arguments)]
+    async fn measure_sequence_loudness(
         &self,
-        asset: &Asset,
+        sequence: &Sequence,
+        assets: &HashMap<String, Asset>,
+        effects: &HashMap<String, Effect>,
+        audio_info: &HashMap<String, AssetAudioInfo>,
         settings: &ExportSettings,
-        progress_tx: Option<Sender<ExportProgress>>,
-    ) -> Result<ExportResult, ExportError> {
-        let input_path = Path::new(&asset.uri);
-        let mut args = self.build_simple_export_args(input_path, settings);
+        progress_tx: Option<&Sender<ExportProgress>>,
+        cancel_rx: oneshot::Receiver<()>,
+    ) -> Result<ExportSettings, ExportError> {
+        let Some(loudness) = settings.loudness.clone() else {
+            return Ok(settings.clone());
+        };
+        if !sequence_has_exportable_audio(sequence, assets, audio_info) {
+            return Err(ExportError::InvalidSettings(
+                "Loudness normalization needs audio in the sequence".to_string(),
+            ));
+        }
+        if let Some(tx) = progress_tx {
+            let _ = tx
+                .send(ExportProgress {
+                    frame: 0,
+                    total_frames: 0,
+                    percent: 0.0,
+                    fps: 0.0,
+                    eta_seconds: 0,
+                    message: "Measuring loudness...".to_string(),
+                })
+                .await;
+        }
 
-        // Calculate total frames
-        let duration = asset.duration_sec.unwrap_or(0.0);
-        let fps = settings.fps.unwrap_or(30.0);
-        let total_frames = (duration * fps) as u64;
+        let temp_dir = tempfile::Builder::new()
+            .prefix("openreelio-loudness-")
+            .tempdir()
+            .map_err(ExportError::IoError)?;
+        let mut measure_settings = settings.clone();
+        measure_settings.output_path = temp_dir.path().join("loudness.null");
+        let mut args = super::ffmpeg_plan::build_audio_only_ffmpeg_args(
+            super::ffmpeg_plan::AudioOnlyFfmpegBuildContext {
+                engine: self,
+                sequence,
+                assets,
+                effects,
+                audio_info,
+                settings: &measure_settings,
+                render_plan: None,
+            },
+        )?;
+        insert_output_option_args(&mut args, ["-f".to_string(), "null".to_string()])?;
 
-        insert_output_option_args(&mut args, ["-progress".to_string(), "pipe:1".to_string()])?;
-        let invocation = build_ffmpeg_invocation_from_args(args, total_frames, None)
+        let measured = self.run_loudness_measurement(args, cancel_rx).await?;
+        let mut measured_settings = settings.clone();
+        measured_settings.loudness = Some(
+            loudness
+                .with_measurement(measured)
+                .map_err(ExportError::InvalidSettings)?,
+        );
+        Ok(measured_settings)
+    }
+
+    /// Measures an already mixed audio file the way
+    /// [`measure_sequence_loudness`](Self::measure_sequence_loudness) measures
+    /// a sequence.
+    async fn measure_file_loudness(
+        &self,
+        audio_path: &Path,
+        loudness: LoudnessNormalization,
+        sample_rate: u32,
+        cancel_rx: oneshot::Receiver<()>,
+    ) -> Result<LoudnessNormalization, ExportError> {
+        let null_output = audio_path.with_extension("loudness.null");
+        let args = vec![
+            "-hide_banner".to_string(),
+            "-i".to_string(),
+            audio_path.to_string_lossy().to_string(),
+            "-af".to_string(),
+            loudness.filter(sample_rate),
+            "-f".to_string(),
+            "null".to_string(),
+            "-y".to_string(),
+            null_output.to_string_lossy().to_string(),
+        ];
+        let measured = self.run_loudness_measurement(args, cancel_rx).await?;
+        loudness
+            .with_measurement(measured)
+            .map_err(ExportError::InvalidSettings)
+    }
+
+    /// Runs a first-pass `loudnorm` job and reads its statistics.
+    async fn run_loudness_measurement(
+        &self,
+        args: Vec<String>,
+        cancel_rx: oneshot::Receiver<()>,
+    ) -> Result<LoudnormStats, ExportError> {
+        let invocation = build_ffmpeg_invocation_from_args(args, 0, None)
             .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
         let execution = execute_ffmpeg_invocation(
             self.ffmpeg.info().ffmpeg_path.as_path(),
             invocation,
-            duration,
-            progress_tx,
+            0.0,
             None,
-            "Starting export...",
-            "Export complete!",
+            Some(cancel_rx),
+            "Measuring loudness...",
+            "Loudness measured",
         )
-        .await?;
-
-        Ok(ExportResult {
-            output_path: execution.output_path,
-            duration_sec: duration,
+        .await
+        .map_err(|error| match error {
+            ExportError::FFmpegFailed(message) => {
+                ExportError::FFmpegFailed(format!("Loudness measurement failed: {}", message))
+            }
+            other => other,
+        })?;
+        LoudnormStats::parse(&execution.stderr).ok_or_else(|| {
+            ExportError::FFmpegFailed("FFmpeg did not report the mix loudness".to_string())
+        })
+    }
+
+    /// Writes the sequence's text and caption overlays to a temporary ASS
+    /// script for the `subtitles` filter, returning the directory holding it
+    /// (which must outlive the render) and its path.
+    ///
+    /// Returns `None` when there is nothing to draw, or when this FFmpeg lacks
+    /// the filter and the overlays fall back to `drawtext`.
+    async fn write_ass_text_overlay(
+        &self,
+        sequence: &Sequence,
+        effects: &std::collections::HashMap<String, Effect>,
+    ) -> Result<Option<(tempfile::TempDir, PathBuf)>, ExportError> {
+        let Some(ass_script) = build_ass_text_overlay_script(sequence, effects)? else {
+            return Ok(None);
+        };
+        if !self.ffmpeg_supports_filter("subtitles").await {
+            tracing::warn!(
+                "FFmpeg subtitles filter is unavailable; falling back to drawtext overlays"
+            );
+            return Ok(None);
+        }
+
+        let temp_dir = tempfile::Builder::new()
+            .prefix("openreelio-text-overlays-")
+            .tempdir()
+            .map_err(ExportError::IoError)?;
+        let ass_path = temp_dir.path().join("text-overlays.ass");
+        // The `subtitles` filter takes this path as a quoted option value, and
+        // FFmpeg's filtergraph grammar cannot carry a literal `'` through to the
+        // filter. The temp directory inherits the system temp root, which on
+        // Windows sits under the user profile (`C:\Users\Ben's PC\...`), so this
+        // is reachable without anything malformed. Fail loudly with a fixable
+        // instruction rather than rendering a video with every caption missing.
+        crate::core::fs::validate_filter_safe_path(&ass_path, "Text overlay path")
+            .map_err(ExportError::InvalidSettings)?;
+        tokio::fs::write(&ass_path, ass_script)
+            .await
+            .map_err(ExportError::IoError)?;
+        Ok(Some((temp_dir, ass_path)))
+    }
+
+    /// Export a single asset (simple transcode)
+    ///
+    /// Supports real-time progress reporting via the progress channel.
+    pub async fn export_asset(
+        &self,
+        asset: &Asset,
+        settings: &ExportSettings,
+        progress_tx: Option<Sender<ExportProgress>>,
+    ) -> Result<ExportResult, ExportError> {
+        let input_path = Path::new(&asset.uri);
+        let mut args = self.build_simple_export_args(asset.ffmpeg_input_args(input_path), settings);
+
+        // Calculate total frames
+        let duration = asset.duration_sec.unwrap_or(0.0);
+        let fps = settings.fps.unwrap_or(30.0);
+        let total_frames = (duration * fps) as u64;
+
+        insert_output_option_args(&mut args, ["-progress".to_string(), "pipe:1".to_string()])?;
+        let invocation = build_ffmpeg_invocation_from_args(args, total_frames, None)
+            .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+        let execution = execute_ffmpeg_invocation(
+            self.ffmpeg.info().ffmpeg_path.as_path(),
+            invocation,
+            duration,
+            progress_tx,
+            None,
+            "Starting export...",
+            "Export complete!",
+        )
+        .await?;
+
+        Ok(ExportResult {
+            output_path: execution.output_path,
+            duration_sec: duration,
             file_size: execution.file_size,
             encoding_time_sec: execution.encoding_time_sec,
+            loudness: None,
         })
     }
 
@@ -6118,7 +7494,6 @@ impl ExportEngine {
         let quality = settings.quality.unwrap_or(2);
         let time_str = format!("{:.3}", source_time);
         let output_str = settings.output_path.to_string_lossy().to_string();
-        let input_str = asset_path.to_string_lossy().to_string();
 
         let mut args = vec![
             "-hide_banner".to_string(),
@@ -6127,11 +7502,9 @@ impl ExportEngine {
             "-nostdin".to_string(),
             "-ss".to_string(),
             time_str,
-            "-i".to_string(),
-            input_str,
-            "-frames:v".to_string(),
-            "1".to_string(),
         ];
+        args.extend(asset.ffmpeg_input_args(&asset_path));
+        args.extend(["-frames:v".to_string(), "1".to_string()]);
 
         // Downscale-only filter: sources narrower than the limit stay native.
         // The quotes protect the comma from the filtergraph separator.
@@ -6357,25 +7730,8 @@ impl ExportEngine {
             }
         }
 
-        let mut output_options = vec![
-            "-vn".to_string(),
-            "-c:a".to_string(),
-            normalized_settings.format.codec().to_string(),
-        ];
-
-        if let Some(ref bitrate) = normalized_settings.bitrate {
-            output_options.push("-b:a".to_string());
-            output_options.push(bitrate.clone());
-        } else if let Some(default_br) = normalized_settings.format.default_bitrate() {
-            output_options.push("-b:a".to_string());
-            output_options.push(default_br.to_string());
-        }
-
-        if let Some(sr) = normalized_settings.sample_rate {
-            output_options.push("-ar".to_string());
-            output_options.push(sr.to_string());
-        }
-
+        let mut output_options = vec!["-vn".to_string()];
+        output_options.extend(normalized_settings.encoding_args());
         output_options.push("-progress".to_string());
         output_options.push("pipe:1".to_string());
         insert_output_option_args(&mut args, output_options)?;
@@ -6427,124 +7783,1056 @@ impl ExportEngine {
         })
     }
 
-    /// Find the topmost visible video clip at a given time position.
-    ///
-    /// Iterates video tracks from top to bottom (highest index first) and
-    /// returns the first enabled clip that covers the requested time.
+    /// Export audio stems from a sequence.
     ///
-    /// Text clips and adjustment layers are skipped because they have no
-    /// file-backed source. `None` therefore means [`ExportEngine::export_frame`]
-    /// cannot serve the requested time and a composited render is required.
-    pub fn find_topmost_clip_at_time<'a>(
+    /// Renders every stem in `settings.stems` to its own file, plus the
+    /// mixdown when `settings.include_mixdown` is set, in a single FFmpeg job.
+    /// See [`AudioStemsExportSettings`] for how the files line up.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn export_audio_stems(
         &self,
-        sequence: &'a Sequence,
-        assets: &'a HashMap<String, Asset>,
-        time_sec: f64,
-    ) -> Option<(&'a Clip, &'a Asset)> {
-        // Lower track indices render above higher indices in preview/export.
-        for track in &sequence.tracks {
-            if !track.is_video() || !track_included_in_export(track) {
-                continue;
+        sequence: &Sequence,
+        assets: &HashMap<String, Asset>,
+        effects: &HashMap<String, Effect>,
+        settings: &AudioStemsExportSettings,
+        render_plan: Option<&RenderPlan>,
+        progress_tx: Option<Sender<ExportProgress>>,
+        cancel_rx: Option<oneshot::Receiver<()>>,
+    ) -> Result<AudioStemsExportResult, ExportError> {
+        if let Some(plan) = render_plan {
+            if !plan.validation.is_valid {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Render plan validation failed: {}",
+                    plan.validation.errors.join("; ")
+                )));
             }
+        }
 
-            for clip in &track.clips {
-                if !clip.enabled {
-                    continue;
-                }
-
-                // Skip text clips — they have no file-backed asset
-                if is_text_clip(clip) {
-                    continue;
-                }
+        settings.validate()?;
+        settings.validate_tracks(sequence)?;
 
-                // Skip adjustment layers
-                if clip.is_adjustment_layer() {
-                    continue;
-                }
+        let (normalized_start_time, normalized_end_time) = normalize_output_time_range(
+            sequence,
+            settings.audio.start_time,
+            settings.audio.end_time,
+        )?;
+        let mut normalized_settings = settings.audio.clone();
+        normalized_settings.start_time = normalized_start_time;
+        normalized_settings.end_time = normalized_end_time;
+        let export_settings = normalized_settings.to_export_settings();
 
-                if clip.place.contains(time_sec) {
-                    if let Some(asset) = assets.get(&clip.asset_id) {
-                        return Some((clip, asset));
-                    }
-                }
-            }
+        let stem_paths: Vec<PathBuf> = settings
+            .stems
+            .iter()
+            .map(|stem| settings.stem_output_path(stem))
+            .collect();
+        if settings.include_mixdown && stem_paths.contains(&normalized_settings.output_path) {
+            return Err(ExportError::InvalidSettings(
+                "A stem file would overwrite the mixdown".to_string(),
+            ));
         }
+        let stem_outputs: Vec<super::ffmpeg_plan::AudioStemOutput<'_>> = settings
+            .stems
+            .iter()
+            .zip(&stem_paths)
+            .map(|(stem, path)| super::ffmpeg_plan::AudioStemOutput {
+                track_ids: &stem.track_ids,
+                output_path: path,
+            })
+            .collect();
 
-        None
-    }
-}
-
-// =============================================================================
-// Progress Parsing
-// =============================================================================
+        let audio_info = self.probe_assets_for_audio(sequence, assets).await;
+        let mut args = super::ffmpeg_plan::build_audio_stems_ffmpeg_args(
+            super::ffmpeg_plan::AudioOnlyFfmpegBuildContext {
+                engine: self,
+                sequence,
+                assets,
+                effects,
+                audio_info: &audio_info,
+                settings: &export_settings,
+                render_plan,
+            },
+            &stem_outputs,
+            settings.include_mixdown,
+            normalized_settings
+                .sample_rate
+                .unwrap_or(sequence.format.audio_sample_rate),
+            &normalized_settings.encoding_args(),
+        )?;
+        args.splice(0..0, ["-progress".to_string(), "pipe:1".to_string()]);
 
-/// Parsed FFmpeg progress line data
-#[derive(Debug, Clone, Default)]
-pub struct FFmpegProgressData {
-    /// Current frame number
-    pub frame: u64,
-    /// Current FPS
-    pub fps: f32,
-    /// Current time in seconds
-    pub time_sec: f64,
-    /// Bitrate in kbps
-    pub bitrate_kbps: Option<f32>,
-    /// Speed multiplier (e.g., 2.5x)
-    pub speed: Option<f32>,
-}
+        if let Some(parent) = normalized_settings.output_path.parent() {
+            if !parent.as_os_str().is_empty() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+        }
 
-/// Parse FFmpeg progress output line
-///
-/// FFmpeg progress output format (when using -progress pipe:1):
-/// ```text
-/// frame=100
-/// fps=30.0
-/// out_time_ms=3333333
-/// bitrate=1234.5kbits/s
-/// speed=2.5x
-/// progress=continue
-/// ```
-pub fn parse_ffmpeg_progress_line(line: &str, data: &mut FFmpegProgressData) -> bool {
-    let line = line.trim();
+        let duration = effective_export_duration(
+            sequence,
+            normalized_settings.start_time,
+            normalized_settings.end_time,
+        );
+        let total_frames = (duration * sequence.format.fps.as_f64()).ceil() as u64;
+        let invocation = if let Some(plan) = render_plan {
+            build_ffmpeg_invocation_for_render_plan(plan, args)
+        } else {
+            build_ffmpeg_invocation_from_args(args, total_frames, None)
+        }
+        .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
 
-    if let Some(value) = line.strip_prefix("frame=") {
-        data.frame = value.trim().parse().unwrap_or(data.frame);
-        return true;
-    }
+        let execution = execute_ffmpeg_invocation(
+            self.ffmpeg.info().ffmpeg_path.as_path(),
+            invocation,
+            duration,
+            progress_tx,
+            cancel_rx,
+            "Starting stems export...",
+            "Stems export complete!",
+        )
+        .await;
+        let execution = match execution {
+            Ok(execution) => execution,
+            Err(ExportError::Cancelled) => {
+                // The runner only removes the last output; a cancelled job
+                // leaves no half-written stem behind either.
+                for path in stem_paths
+                    .iter()
+                    .chain(std::iter::once(&normalized_settings.output_path))
+                {
+                    let _ = tokio::fs::remove_file(path).await;
+                }
+                return Err(ExportError::Cancelled);
+            }
+            Err(ExportError::FFmpegFailed(message)) => {
+                return Err(ExportError::FFmpegFailed(format!(
+                    "Stems export failed: {}",
+                    message
+                )));
+            }
+            Err(other) => return Err(other),
+        };
 
-    if let Some(value) = line.strip_prefix("fps=") {
-        data.fps = value.trim().parse().unwrap_or(data.fps);
-        return true;
-    }
+        let written = |name: &str, path: &Path| AudioStemFile {
+            name: name.to_string(),
+            output_path: path.to_path_buf(),
+            file_size: std::fs::metadata(path)
+                .map(|metadata| metadata.len())
+                .unwrap_or(0),
+        };
 
-    if let Some(value) = line.strip_prefix("out_time_ms=") {
-        // out_time_ms is in microseconds despite the name
-        let microseconds: u64 = value.trim().parse().unwrap_or(0);
-        data.time_sec = microseconds as f64 / 1_000_000.0;
-        return true;
+        Ok(AudioStemsExportResult {
+            stems: settings
+                .stems
+                .iter()
+                .zip(&stem_paths)
+                .map(|(stem, path)| written(&stem.name, path))
+                .collect(),
+            mixdown: settings
+                .include_mixdown
+                .then(|| written("Mixdown", &normalized_settings.output_path)),
+            duration_sec: duration,
+            format: settings.audio.format.clone(),
+            encoding_time_sec: execution.encoding_time_sec,
+        })
     }
 
-    if let Some(value) = line.strip_prefix("bitrate=") {
-        // Format: "1234.5kbits/s" or "N/A"
-        if let Some(num_str) = value.strip_suffix("kbits/s") {
-            data.bitrate_kbps = num_str.trim().parse().ok();
+    /// Package a sequence for adaptive streaming.
+    ///
+    /// Renders every rung of `settings.renditions` in a single FFmpeg job as
+    /// CMAF segments with a DASH manifest and HLS playlists, then adds the
+    /// sequence's caption tracks as WebVTT renditions when
+    /// `settings.include_captions` is set. See [`StreamingPackageSettings`]
+    /// for the layout of the package.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn export_streaming_package(
+        &self,
+        sequence: &Sequence,
+        assets: &HashMap<String, Asset>,
+        effects: &HashMap<String, Effect>,
+        settings: &StreamingPackageSettings,
+        render_plan: Option<&RenderPlan>,
+        progress_tx: Option<Sender<ExportProgress>>,
+        cancel_rx: Option<oneshot::Receiver<()>>,
+    ) -> Result<StreamingPackageResult, ExportError> {
+        if let Some(plan) = render_plan {
+            if !plan.validation.is_valid {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Render plan validation failed: {}",
+                    plan.validation.errors.join("; ")
+                )));
+            }
         }
-        return true;
-    }
 
-    if let Some(value) = line.strip_prefix("speed=") {
-        // Format: "2.5x" or "N/A"
-        if let Some(num_str) = value.strip_suffix('x') {
-            data.speed = num_str.trim().parse().ok();
-        }
-        return true;
-    }
+        settings.validate()?;
 
-    // Return true for "progress=" lines to indicate a progress block boundary
-    line.starts_with("progress=")
-}
+        let (normalized_start_time, normalized_end_time) =
+            normalize_output_time_range(sequence, settings.start_time, settings.end_time)?;
+        let mut normalized_settings = settings.clone();
+        normalized_settings.start_time = normalized_start_time;
+        normalized_settings.end_time = normalized_end_time;
+        let export_settings = normalized_settings.to_export_settings(sequence);
 
-/// Calculate export progress from parsed data
+        let picture = streaming_picture_sequence(sequence, settings.burn_in_captions);
+        let rungs: Vec<super::ffmpeg_plan::LadderRung> = settings
+            .renditions
+            .iter()
+            .map(|rendition| {
+                let (width, height) = settings.rendition_size(sequence, rendition);
+                super::ffmpeg_plan::LadderRung {
+                    width,
+                    height,
+                    bitrate_bps: super::streaming::parse_bitrate_bps(&rendition.video_bitrate)
+                        .unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        let audio_info = self.probe_assets_for_audio(&picture, assets).await;
+        let ass_text_overlay = self.write_ass_text_overlay(&picture, effects).await?;
+        let mut args = super::ffmpeg_plan::build_streaming_package_ffmpeg_args(
+            super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                engine: self,
+                sequence: &picture,
+                assets,
+                effects,
+                audio_info: &audio_info,
+                settings: &export_settings,
+                render_plan,
+                ass_text_overlay_path: ass_text_overlay.as_ref().map(|(_, path)| path.as_path()),
+                working_pixel_format: None,
+                ffmetadata_path: None,
+            },
+            &rungs,
+            settings.segment_duration_sec,
+        )?;
+        args.splice(0..0, ["-progress".to_string(), "pipe:1".to_string()]);
+
+        tokio::fs::create_dir_all(&settings.output_dir).await?;
+
+        let duration =
+            effective_export_duration(sequence, normalized_start_time, normalized_end_time);
+        let fps = export_settings
+            .fps
+            .unwrap_or_else(|| sequence.format.fps.as_f64());
+        let total_frames = (duration * fps).ceil() as u64;
+        let invocation = if let Some(plan) = render_plan {
+            build_ffmpeg_invocation_for_render_plan(plan, args)
+        } else {
+            build_ffmpeg_invocation_from_args(args, total_frames, None)
+        }
+        .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+
+        let execution = execute_ffmpeg_invocation(
+            self.ffmpeg.info().ffmpeg_path.as_path(),
+            invocation,
+            duration,
+            progress_tx,
+            cancel_rx,
+            "Starting streaming package...",
+            "Streaming package complete!",
+        )
+        .await;
+        drop(ass_text_overlay);
+        let execution = match execution {
+            Ok(execution) => execution,
+            Err(ExportError::Cancelled) => {
+                // The runner only removes the manifest; a cancelled package
+                // leaves none of its segments or playlists behind either.
+                for path in super::streaming::package_files(&settings.output_dir) {
+                    let _ = tokio::fs::remove_file(path).await;
+                }
+                return Err(ExportError::Cancelled);
+            }
+            Err(ExportError::FFmpegFailed(message)) => {
+                return Err(ExportError::FFmpegFailed(format!(
+                    "Streaming package failed: {}",
+                    message
+                )));
+            }
+            Err(other) => return Err(other),
+        };
+
+        let captions = if settings.include_captions {
+            let start = normalized_start_time.unwrap_or(0.0);
+            let renditions =
+                super::streaming::collect_caption_renditions(sequence, start, start + duration);
+            super::streaming::write_caption_renditions(&settings.output_dir, &renditions, duration)
+                .await?
+        } else {
+            Vec::new()
+        };
+
+        let total_size = super::streaming::package_files(&settings.output_dir)
+            .iter()
+            .filter_map(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+
+        Ok(StreamingPackageResult {
+            output_dir: settings.output_dir.clone(),
+            dash_manifest_path: settings.dash_manifest_path(),
+            hls_master_playlist_path: settings.hls_master_playlist_path(),
+            renditions: rungs
+                .iter()
+                .map(|rung| StreamingRenditionOutput {
+                    width: rung.width,
+                    height: rung.height,
+                    bandwidth: rung.bitrate_bps,
+                })
+                .collect(),
+            captions,
+            duration_sec: duration,
+            total_size,
+            encoding_time_sec: execution.encoding_time_sec,
+        })
+    }
+
+    /// Export a range of a sequence as numbered stills.
+    ///
+    /// Renders the range through the same filter graph as a video export, one
+    /// file per frame, then writes the sidecar manifest mapping every file to
+    /// its timeline frame and timecode. See [`ImageSequenceExportSettings`] for
+    /// naming and numbering.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn export_image_sequence(
+        &self,
+        sequence: &Sequence,
+        assets: &HashMap<String, Asset>,
+        effects: &HashMap<String, Effect>,
+        settings: &ImageSequenceExportSettings,
+        render_plan: Option<&RenderPlan>,
+        progress_tx: Option<Sender<ExportProgress>>,
+        cancel_rx: Option<oneshot::Receiver<()>>,
+    ) -> Result<ImageSequenceExportResult, ExportError> {
+        if let Some(plan) = render_plan {
+            if !plan.validation.is_valid {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Render plan validation failed: {}",
+                    plan.validation.errors.join("; ")
+                )));
+            }
+        }
+
+        settings.validate()?;
+
+        let (normalized_start_time, normalized_end_time) =
+            normalize_output_time_range(sequence, settings.start_time, settings.end_time)?;
+        let mut normalized_settings = settings.clone();
+        normalized_settings.start_time = normalized_start_time;
+        normalized_settings.end_time = normalized_end_time;
+        let export_settings = normalized_settings.to_export_settings();
+        let first_timeline_frame = normalized_settings.first_timeline_frame(sequence);
+        let first_number = normalized_settings.first_number(sequence);
+
+        let audio_info = self.probe_assets_for_audio(sequence, assets).await;
+        let ass_text_overlay = self.write_ass_text_overlay(sequence, effects).await?;
+        let mut args = super::ffmpeg_plan::build_image_sequence_ffmpeg_args(
+            super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                engine: self,
+                sequence,
+                assets,
+                effects,
+                audio_info: &audio_info,
+                settings: &export_settings,
+                render_plan,
+                ass_text_overlay_path: ass_text_overlay.as_ref().map(|(_, path)| path.as_path()),
+                working_pixel_format: Some(settings.working_pixel_format()),
+                ffmetadata_path: None,
+            },
+            settings.encoding_args(),
+            first_number,
+        )?;
+        args.splice(0..0, ["-progress".to_string(), "pipe:1".to_string()]);
+
+        tokio::fs::create_dir_all(&settings.output_dir).await?;
+
+        let duration =
+            effective_export_duration(sequence, normalized_start_time, normalized_end_time);
+        let total_frames = (duration * sequence.format.fps.as_f64()).ceil() as u64;
+        let invocation = if let Some(plan) = render_plan {
+            build_ffmpeg_invocation_for_render_plan(plan, args)
+        } else {
+            build_ffmpeg_invocation_from_args(args, total_frames, None)
+        }
+        .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+
+        let execution = execute_ffmpeg_invocation(
+            self.ffmpeg.info().ffmpeg_path.as_path(),
+            invocation,
+            duration,
+            progress_tx,
+            cancel_rx,
+            "Starting image sequence...",
+            "Image sequence complete!",
+        )
+        .await;
+        drop(ass_text_overlay);
+        let execution = match execution {
+            Ok(execution) => execution,
+            Err(ExportError::Cancelled) => {
+                // The runner only knows the output pattern; remove the frames
+                // written before the cancel.
+                for path in super::image_sequence::written_frame_paths(settings, first_number) {
+                    let _ = tokio::fs::remove_file(path).await;
+                }
+                return Err(ExportError::Cancelled);
+            }
+            Err(ExportError::FFmpegFailed(message)) => {
+                return Err(ExportError::FFmpegFailed(format!(
+                    "Image sequence export failed: {}",
+                    message
+                )));
+            }
+            Err(other) => return Err(other),
+        };
+
+        let frames = super::image_sequence::written_frame_paths(settings, first_number);
+        if frames.is_empty() {
+            return Err(ExportError::FFmpegFailed(
+                "Image sequence export wrote no frames".to_string(),
+            ));
+        }
+        let total_size = frames
+            .iter()
+            .filter_map(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+
+        let manifest = ImageSequenceManifest::new(
+            sequence,
+            &normalized_settings,
+            first_timeline_frame,
+            frames.len() as u64,
+        );
+        let manifest_json = serde_json::to_vec_pretty(&manifest).map_err(std::io::Error::from)?;
+        tokio::fs::write(settings.manifest_path(), manifest_json).await?;
+
+        Ok(ImageSequenceExportResult {
+            output_dir: settings.output_dir.clone(),
+            manifest_path: settings.manifest_path(),
+            first_number,
+            frame_count: frames.len() as u64,
+            total_size,
+            duration_sec: duration,
+            encoding_time_sec: execution.encoding_time_sec,
+        })
+    }
+
+    /// Export a range of a sequence as a looping GIF or WebP.
+    ///
+    /// Renders the range through the same filter graph as a video export and
+    /// hands it to the animation filter of [`AnimatedImageExportSettings`].
+    /// With a byte budget, an oversized file is encoded again at the next
+    /// [`next_size_candidate`](super::animated_image::next_size_candidate)
+    /// until it fits; when the search runs out, the file is removed and the
+    /// export fails with the smallest size reached.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn export_animated_image(
+        &self,
+        sequence: &Sequence,
+        assets: &HashMap<String, Asset>,
+        effects: &HashMap<String, Effect>,
+        settings: &AnimatedImageExportSettings,
+        render_plan: Option<&RenderPlan>,
+        progress_tx: Option<Sender<ExportProgress>>,
+        cancel_rx: Option<oneshot::Receiver<()>>,
+    ) -> Result<AnimatedImageExportResult, ExportError> {
+        use super::animated_image::{next_size_candidate, MAX_SIZE_TARGET_ATTEMPTS};
+
+        if let Some(plan) = render_plan {
+            if !plan.validation.is_valid {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Render plan validation failed: {}",
+                    plan.validation.errors.join("; ")
+                )));
+            }
+        }
+
+        settings.validate()?;
+
+        let (normalized_start_time, normalized_end_time) =
+            normalize_output_time_range(sequence, settings.start_time, settings.end_time)?;
+        let mut normalized_settings = settings.clone();
+        normalized_settings.start_time = normalized_start_time;
+        normalized_settings.end_time = normalized_end_time;
+        let export_settings = normalized_settings.to_export_settings();
+        let duration =
+            effective_export_duration(sequence, normalized_start_time, normalized_end_time);
+        let range_start = normalized_start_time.unwrap_or(0.0);
+
+        let audio_info = self.probe_assets_for_audio(sequence, assets).await;
+        let ass_text_overlay = self.write_ass_text_overlay(sequence, effects).await?;
+        if let Some(parent) = settings.output_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        // A budget search runs several processes; the cancel reaches each.
+        let (cancel_all_tx, cancel_all_rx) = tokio::sync::watch::channel(false);
+        let cancel_forwarder = cancel_rx.map(|cancel_rx| {
+            tokio::spawn(async move {
+                if cancel_rx.await.is_ok() {
+                    let _ = cancel_all_tx.send(true);
+                }
+            })
+        });
+
+        let format_name = match settings.format {
+            super::AnimatedImageFormat::Gif => "GIF",
+            super::AnimatedImageFormat::Webp => "WebP",
+        };
+        let mut candidate = settings.initial_candidate(sequence);
+        let mut attempts = 0;
+        let mut encoding_time_sec = 0.0;
+        let outcome = loop {
+            attempts += 1;
+            let built = super::ffmpeg_plan::build_animated_image_ffmpeg_args(
+                super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                    engine: self,
+                    sequence,
+                    assets,
+                    effects,
+                    audio_info: &audio_info,
+                    settings: &export_settings,
+                    render_plan,
+                    ass_text_overlay_path: ass_text_overlay
+                        .as_ref()
+                        .map(|(_, path)| path.as_path()),
+                    working_pixel_format: Some(settings.format.working_pixel_format()),
+                    ffmetadata_path: None,
+                },
+                &settings.animation_filter(&candidate, range_start, range_start + duration),
+                settings.encoding_args(),
+            );
+            let mut args = match built {
+                Ok(args) => args,
+                Err(error) => break Err(error),
+            };
+            args.splice(0..0, ["-progress".to_string(), "pipe:1".to_string()]);
+
+            let total_frames = (duration * candidate.fps).ceil() as u64;
+            let invocation = match build_ffmpeg_invocation_from_args(
+                args,
+                total_frames,
+                render_plan.map(|plan| plan.plan_hash.clone()),
+            ) {
+                Ok(invocation) => invocation,
+                Err(error) => break Err(ExportError::InvalidSettings(error.to_string())),
+            };
+
+            let execution = execute_ffmpeg_invocation(
+                self.ffmpeg.info().ffmpeg_path.as_path(),
+                invocation,
+                duration,
+                progress_tx.clone(),
+                Some(process_cancel_receiver(&cancel_all_rx)),
+                format!(
+                    "Encoding {} at {}px, {} fps...",
+                    format_name, candidate.width, candidate.fps
+                ),
+                format!("{} complete!", format_name),
+            )
+            .await;
+            let execution = match execution {
+                Ok(execution) => execution,
+                Err(ExportError::FFmpegFailed(message)) => {
+                    break Err(ExportError::FFmpegFailed(format!(
+                        "{} export failed: {}",
+                        format_name, message
+                    )));
+                }
+                Err(other) => break Err(other),
+            };
+            encoding_time_sec += execution.encoding_time_sec;
+
+            let over_budget = settings
+                .max_file_size
+                .filter(|budget| execution.file_size > *budget);
+            let Some(budget) = over_budget else {
+                break Ok(AnimatedImageExportResult {
+                    output_path: execution.output_path,
+                    file_size: execution.file_size,
+                    fps: candidate.fps,
+                    width: candidate.width,
+                    attempts,
+                    duration_sec: duration,
+                    encoding_time_sec,
+                });
+            };
+            match next_size_candidate(&candidate, execution.file_size, budget) {
+                Some(next) if attempts < MAX_SIZE_TARGET_ATTEMPTS => candidate = next,
+                _ => {
+                    let _ = tokio::fs::remove_file(&execution.output_path).await;
+                    break Err(ExportError::InvalidSettings(format!(
+                        "Could not fit the {} under {} bytes; the smallest attempt was {} bytes \
+                         at {}px, {} fps. Shorten the range or raise the budget.",
+                        format_name, budget, execution.file_size, candidate.width, candidate.fps
+                    )));
+                }
+            }
+        };
+
+        drop(ass_text_overlay);
+        if let Some(forwarder) = cancel_forwarder {
+            forwarder.abort();
+        }
+        outcome
+    }
+
+    /// Render a sequence in segments spread over several FFmpeg processes.
+    ///
+    /// Cuts the export range on the grid described in
+    /// [`super::distributed`], encodes the segments that are not already in
+    /// `distributed.cache_dir` on the local and remote workers, renders the
+    /// mix once, and stitches everything into `settings.output_path`. The
+    /// first failing segment cancels the rest.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn export_sequence_distributed(
+        &self,
+        sequence: &Sequence,
+        assets: &HashMap<String, Asset>,
+        effects: &HashMap<String, Effect>,
+        render_graph: &RenderGraph,
+        settings: &ExportSettings,
+        distributed: &DistributedRenderSettings,
+        progress_tx: Option<Sender<ExportProgress>>,
+        cancel_rx: Option<oneshot::Receiver<()>>,
+    ) -> Result<DistributedExportResult, ExportError> {
+        use super::distributed::{
+            export_profile_key, picture_only_sequence, plan_distributed_segments,
+            prune_superseded_segments, segment_file_name, segment_fingerprint,
+        };
+        use super::distributed::{SegmentContext, SegmentJob};
+        use super::smart::{
+            build_stitch_args, concat_list, stitch_files, SegmentAction, SmartRenderPlan,
+            SmartRenderSegment,
+        };
+        use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+        use std::sync::{Arc, Mutex};
+
+        distributed.validate()?;
+        super::distributed::check_distributable(settings)?;
+        let started = std::time::Instant::now();
+
+        let (start_time, end_time) =
+            normalize_output_time_range(sequence, settings.start_time, settings.end_time)?;
+        let range_start = start_time.unwrap_or(0.0);
+        let duration = effective_export_duration(sequence, start_time, end_time);
+        let fps = settings.fps.unwrap_or_else(|| sequence.format.fps.as_f64());
+        let segments = plan_distributed_segments(
+            range_start,
+            range_start + duration,
+            distributed.segment_duration_sec,
+            fps,
+        );
+        if segments.is_empty() {
+            return Err(ExportError::InvalidSettings(
+                "Sequence has no exportable duration".to_string(),
+            ));
+        }
+
+        let work_dir = tempfile::Builder::new()
+            .prefix("openreelio-distributed-")
+            .tempdir()?;
+        let segment_dir = match &distributed.cache_dir {
+            Some(cache_dir) => cache_dir.join(format!("final_{}", export_profile_key(settings))),
+            None => work_dir.path().join("segments"),
+        };
+        tokio::fs::create_dir_all(&segment_dir).await?;
+        let extension = output_extension(&settings.output_path).unwrap_or_else(|| "mp4".into());
+
+        // Decide what to encode and what to copy. Jobs render to a partial name
+        // and are renamed once complete, so an interrupted encode is never taken
+        // for a cached one.
+        let mut stitch_segments = Vec::with_capacity(segments.len());
+        let mut encoded = HashMap::new();
+        let mut jobs = std::collections::VecDeque::new();
+        for segment in &segments {
+            let mut segment_settings = settings.clone();
+            segment_settings.start_time = Some(segment.start_sec);
+            segment_settings.end_time = Some(segment.end_sec);
+            segment_settings.metadata = None;
+            segment_settings.loudness = None;
+            let plan = build_render_plan(render_graph, assets, effects, &segment_settings);
+            if !plan.validation.is_valid {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Render plan validation failed for segment {}: {}",
+                    segment.index,
+                    plan.validation.errors.join("; ")
+                )));
+            }
+            let fingerprint = segment_fingerprint(&plan.plan_hash, sequence, effects, segment);
+            let file = segment_dir.join(segment_file_name(segment.index, fingerprint, &extension));
+            let action = if file.exists() {
+                SegmentAction::CopyFromCache { cache_file: file }
+            } else {
+                segment_settings.output_path = file.with_extension(format!("partial.{extension}"));
+                encoded.insert(segment.index, file.clone());
+                jobs.push_back(SegmentJob {
+                    duration_sec: segment.duration_sec(),
+                    settings: segment_settings,
+                    plan,
+                    file,
+                });
+                SegmentAction::ReEncode
+            };
+            stitch_segments.push(SmartRenderSegment {
+                index: segment.index,
+                start_sec: segment.start_sec,
+                end_sec: segment.end_sec,
+                action,
+            });
+        }
+        let stitch_plan = SmartRenderPlan {
+            segments: stitch_segments,
+            total_duration_sec: duration,
+        };
+        let files = stitch_files(&stitch_plan, &encoded).ok_or_else(|| {
+            ExportError::InvalidSettings("A segment to encode has no output file".to_string())
+        })?;
+        let cached_segments = stitch_plan.copy_count();
+        let pending_sec: f64 = jobs.iter().map(|job| job.duration_sec).sum();
+
+        // Share the cancel with every process this render starts.
+        let (cancel_all_tx, cancel_all_rx) = tokio::sync::watch::channel(false);
+        let cancel_forwarder = cancel_rx.map(|cancel_rx| {
+            let cancel_all_tx = cancel_all_tx.clone();
+            tokio::spawn(async move {
+                if cancel_rx.await.is_ok() {
+                    let _ = cancel_all_tx.send(true);
+                }
+            })
+        });
+
+        let context = Arc::new(SegmentContext {
+            sequence: sequence.clone(),
+            picture: picture_only_sequence(sequence),
+            assets: assets.clone(),
+            effects: effects.clone(),
+            queue: Mutex::new(jobs),
+            cancel: cancel_all_rx,
+            progress_tx: progress_tx.clone(),
+            done_ms: AtomicU64::new(((duration - pending_sec).max(0.0) * 1000.0) as u64),
+            total_sec: duration,
+            remote_segments: AtomicUsize::new(0),
+        });
+
+        // The mix renders alongside the pictures.
+        let audio_info = self.probe_assets_for_audio(sequence, assets).await;
+        let audio_task = if sequence_has_exportable_audio(sequence, assets, &audio_info) {
+            let audio_settings = AudioExportSettings {
+                format: AudioExportFormat::Wav,
+                output_path: work_dir.path().join("mix.wav"),
+                bitrate: None,
+                sample_rate: None,
+                start_time,
+                end_time,
+            };
+            let engine = ExportEngine::new(self.ffmpeg.clone());
+            let context = Arc::clone(&context);
+            Some(tokio::spawn(async move {
+                engine
+                    .export_audio_only(
+                        &context.sequence,
+                        &context.assets,
+                        &context.effects,
+                        &audio_settings,
+                        None,
+                        Some(context.segment_cancel()),
+                    )
+                    .await
+                    .map(|result| result.output_path)
+            }))
+        } else {
+            None
+        };
+
+        let mut remote_workers = distributed.remote_workers.clone();
+        let mut failure = None;
+        while context.has_pending_jobs() && failure.is_none() {
+            let mut slots = tokio::task::JoinSet::new();
+            for _ in 0..distributed.local_workers {
+                let engine = ExportEngine::new(self.ffmpeg.clone());
+                let context = Arc::clone(&context);
+                slots.spawn(async move { context.run_local_slot(engine).await.map(|()| None) });
+            }
+            for address in &remote_workers {
+                let context = Arc::clone(&context);
+                let address = address.clone();
+                let project_path = distributed.project_path.clone().unwrap_or_default();
+                slots.spawn(async move { context.run_remote_slot(address, project_path).await });
+            }
+            if slots.is_empty() {
+                let _ = cancel_all_tx.send(true);
+                failure = Some(ExportError::FFmpegFailed(
+                    "Every render worker failed before the segments were done".to_string(),
+                ));
+                break;
+            }
+
+            while let Some(joined) = slots.join_next().await {
+                match joined {
+                    Ok(Ok(Some(retired))) => remote_workers.retain(|address| *address != retired),
+                    Ok(Ok(None)) => {}
+                    Ok(Err(error)) => {
+                        if failure.is_none() {
+                            let _ = cancel_all_tx.send(true);
+                            failure = Some(error);
+                        }
+                    }
+                    Err(join_error) => {
+                        if failure.is_none() {
+                            let _ = cancel_all_tx.send(true);
+                            failure = Some(ExportError::FFmpegFailed(format!(
+                                "Segment worker panicked: {join_error}"
+                            )));
+                        }
+                    }
+                }
+            }
+        }
+
+        let audio_path = match audio_task {
+            Some(task) => match task.await {
+                Ok(Ok(path)) => Some(path),
+                Ok(Err(error)) => {
+                    failure.get_or_insert(error);
+                    None
+                }
+                Err(join_error) => {
+                    failure.get_or_insert(ExportError::FFmpegFailed(format!(
+                        "Mix render panicked: {join_error}"
+                    )));
+                    None
+                }
+            },
+            None => None,
+        };
+        if let Some(error) = failure {
+            if let Some(forwarder) = cancel_forwarder {
+                forwarder.abort();
+            }
+            return Err(error);
+        }
+        if distributed.cache_dir.is_some() {
+            prune_superseded_segments(&segment_dir, &segments, &files);
+        }
+
+        if let Some(parent) = settings.output_path.parent() {
+            if !parent.as_os_str().is_empty() {
+                tokio::fs::create_dir_all(parent).await?;
+            }
+        }
+        let list_path = work_dir.path().join("segments.txt");
+        tokio::fs::write(&list_path, concat_list(&files)).await?;
+        let mut stitch_settings = settings.clone();
+        stitch_settings.start_time = start_time;
+        stitch_settings.end_time = end_time;
+        // The stitch reads the range from zero, so chapters go in unshifted.
+        let chapters = export_chapter_spans(sequence, &stitch_settings);
+        let ffmetadata = write_ffmetadata_file(settings, &chapters, 0.0).await?;
+        // The mix covers just the range, so it is measured and normalized whole.
+        let sample_rate = sequence.format.audio_sample_rate;
+        let loudness = match (&settings.loudness, &audio_path) {
+            (Some(loudness), Some(audio_path)) => Some(
+                self.measure_file_loudness(
+                    audio_path,
+                    loudness.clone(),
+                    sample_rate,
+                    context.segment_cancel(),
+                )
+                .await?,
+            ),
+            (Some(_), None) => {
+                return Err(ExportError::InvalidSettings(
+                    "Loudness normalization needs audio in the sequence".to_string(),
+                ))
+            }
+            (None, _) => None,
+        };
+        let audio_filter = loudness
+            .as_ref()
+            .map(|loudness| loudness.filter(sample_rate));
+        let mut args = build_stitch_args(
+            &list_path,
+            audio_path.as_deref(),
+            audio_filter.as_deref(),
+            ffmetadata.as_ref().map(|(_, path)| path.as_path()),
+            settings,
+            super::ffmpeg_plan::output_start_timecode(sequence, &stitch_settings),
+        );
+        args.splice(0..0, ["-progress".to_string(), "pipe:1".to_string()]);
+        let invocation =
+            build_ffmpeg_invocation_from_args(args, (duration * fps).ceil() as u64, None)
+                .map_err(|error| ExportError::InvalidSettings(error.to_string()))?;
+        let execution = execute_ffmpeg_invocation(
+            self.ffmpeg.info().ffmpeg_path.as_path(),
+            invocation,
+            duration,
+            None,
+            Some(context.segment_cancel()),
+            "Stitching segments...",
+            "Export complete!",
+        )
+        .await;
+        if let Some(forwarder) = cancel_forwarder {
+            forwarder.abort();
+        }
+        let execution = execution.map_err(|error| match error {
+            ExportError::FFmpegFailed(message) => {
+                ExportError::FFmpegFailed(format!("Stitching segments failed: {}", message))
+            }
+            other => other,
+        })?;
+        write_chapter_list(&execution.output_path, &chapters).await?;
+        let loudness = loudness
+            .as_ref()
+            .and_then(|loudness| LoudnessReport::new(loudness, &execution.stderr));
+
+        if let Some(tx) = &progress_tx {
+            let _ = tx
+                .send(ExportProgress {
+                    frame: (duration * fps).ceil() as u64,
+                    total_frames: (duration * fps).ceil() as u64,
+                    percent: 100.0,
+                    fps: 0.0,
+                    eta_seconds: 0,
+                    message: "Export complete!".to_string(),
+                })
+                .await;
+        }
+
+        Ok(DistributedExportResult {
+            export: ExportResult {
+                output_path: execution.output_path,
+                duration_sec: duration,
+                file_size: execution.file_size,
+                encoding_time_sec: started.elapsed().as_secs_f64(),
+                loudness,
+            },
+            segment_count: segments.len(),
+            cached_segments,
+            remote_segments: context.remote_segments.load(Ordering::Relaxed),
+        })
+    }
+
+    /// Find the topmost visible video clip at a given time position.
+    ///
+    /// Iterates video tracks from top to bottom (highest index first) and
+    /// returns the first enabled clip that covers the requested time.
+    ///
+    /// Text clips and adjustment layers are skipped because they have no
+    /// file-backed source. `None` therefore means [`ExportEngine::export_frame`]
+    /// cannot serve the requested time and a composited render is required.
+    pub fn find_topmost_clip_at_time<'a>(
+        &self,
+        sequence: &'a Sequence,
+        assets: &'a HashMap<String, Asset>,
+        time_sec: f64,
+    ) -> Option<(&'a Clip, &'a Asset)> {
+        // Lower track indices render above higher indices in preview/export.
+        for track in &sequence.tracks {
+            if !track.is_video() || !track_included_in_export(track) {
+                continue;
+            }
+
+            for clip in &track.clips {
+                if !clip.enabled {
+                    continue;
+                }
+
+                // Skip text clips — they have no file-backed asset
+                if is_text_clip(clip) {
+                    continue;
+                }
+
+                // Skip adjustment layers
+                if clip.is_adjustment_layer() {
+                    continue;
+                }
+
+                if clip.place.contains(time_sec) {
+                    if let Some(asset) = assets.get(&clip.asset_id) {
+                        return Some((clip, asset));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+}
+
+// =============================================================================
+// Progress Parsing
+// =============================================================================
+
+/// Parsed FFmpeg progress line data
+#[derive(Debug, Clone, Default)]
+pub struct FFmpegProgressData {
+    /// Current frame number
+    pub frame: u64,
+    /// Current FPS
+    pub fps: f32,
+    /// Current time in seconds
+    pub time_sec: f64,
+    /// Bitrate in kbps
+    pub bitrate_kbps: Option<f32>,
+    /// Speed multiplier (e.g., 2.5x)
+    pub speed: Option<f32>,
+}
+
+/// Parse FFmpeg progress output line
+///
+/// FFmpeg progress output format (when using -progress pipe:1):
+/// ```text
+/// frame=100
+/// fps=30.0
+/// out_time_ms=3333333
+/// bitrate=1234.5kbits/s
+/// speed=2.5x
+/// progress=continue
+/// ```
+pub fn parse_ffmpeg_progress_line(line: &str, data: &mut FFmpegProgressData) -> bool {
+    let line = line.trim();
+
+    if let Some(value) = line.strip_prefix("frame=") {
+        data.frame = value.trim().parse().unwrap_or(data.frame);
+        return true;
+    }
+
+    if let Some(value) = line.strip_prefix("fps=") {
+        data.fps = value.trim().parse().unwrap_or(data.fps);
+        return true;
+    }
+
+    if let Some(value) = line.strip_prefix("out_time_ms=") {
+        // out_time_ms is in microseconds despite the name
+        let microseconds: u64 = value.trim().parse().unwrap_or(0);
+        data.time_sec = microseconds as f64 / 1_000_000.0;
+        return true;
+    }
+
+    if let Some(value) = line.strip_prefix("bitrate=") {
+        // Format: "1234.5kbits/s" or "N/A"
+        if let Some(num_str) = value.strip_suffix("kbits/s") {
+            data.bitrate_kbps = num_str.trim().parse().ok();
+        }
+        return true;
+    }
+
+    if let Some(value) = line.strip_prefix("speed=") {
+        // Format: "2.5x" or "N/A"
+        if let Some(num_str) = value.strip_suffix('x') {
+            data.speed = num_str.trim().parse().ok();
+        }
+        return true;
+    }
+
+    // Return true for "progress=" lines to indicate a progress block boundary
+    line.starts_with("progress=")
+}
+
+/// Calculate export progress from parsed data
 pub fn calculate_export_progress(
     data: &FFmpegProgressData,
     total_duration_sec: f64,
@@ -6917,6 +9205,49 @@ fn validate_caption_track_qc(
     }
 }
 
+/// Returns the sequence as export sees it, with multicam clips cut to their angles.
+///
+/// Export composites media clips, not nested sequences, so every multicam clip
+/// is replaced by its active angle's clips (see [`resolve_multicam_angle`])
+/// before validation, the render graph and the filtergraph look at it. A
+/// multicam clip that cannot be resolved is left in place, where validation
+/// reports it.
+pub fn resolve_multicam_clips(
+    sequence: &Sequence,
+    sequences: &HashMap<SequenceId, Sequence>,
+) -> Sequence {
+    let mut resolved = sequence.clone();
+    for track in &mut resolved.tracks {
+        if !track.clips.iter().any(Clip::is_multicam) {
+            continue;
+        }
+
+        let mut clips = Vec::with_capacity(track.clips.len());
+        for clip in track.clips.drain(..) {
+            let angle_clips = clip
+                .compound_sequence_id
+                .as_ref()
+                .filter(|_| clip.is_multicam())
+                .and_then(|inner_id| sequences.get(inner_id))
+                .map(|inner| resolve_multicam_angle(&clip, inner));
+            match angle_clips {
+                None => clips.push(clip),
+                Some(Ok(angle_clips)) => clips.extend(angle_clips),
+                Some(Err(error)) => {
+                    tracing::warn!(
+                        "Multicam clip '{}' not resolved for export: {}",
+                        clip.id,
+                        error
+                    );
+                    clips.push(clip);
+                }
+            }
+        }
+        track.clips = clips;
+    }
+    resolved
+}
+
 /// Validate export settings before starting export.
 ///
 /// This can spawn FFprobe (see [`validate_export_settings_with_dimensions`]), so
@@ -6986,6 +9317,9 @@ pub fn validate_export_settings_with_dimensions(
     for error in validate_export_settings_options(settings) {
         validation.add_error(error);
     }
+    for warning in chapter_warnings(&export_chapter_spans(sequence, settings)) {
+        validation.add_warning(warning);
+    }
 
     // Check for empty sequence after applying clip-enabled state.
     let total_clips: usize = sequence
@@ -7117,6 +9451,14 @@ pub fn validate_export_settings_with_dimensions(
                 continue;
             }
 
+            if clip.is_multicam() {
+                validation.add_error(format!(
+                    "Multicam clip '{}' on track '{}' could not be resolved to its active angle",
+                    clip.id, track.name
+                ));
+                continue;
+            }
+
             let Some(asset) = assets.get(&clip.asset_id) else {
                 validation.add_error(format!(
                     "Asset '{}' not found for clip '{}'",
@@ -7230,6 +9572,8 @@ pub fn build_complex_filter_args_with_audio_info(
         settings,
         render_plan: None,
         ass_text_overlay_path: None,
+        working_pixel_format: None,
+        ffmetadata_path: None,
     })
 }
 /// Detect gaps in the timeline between clips
@@ -8639,6 +10983,54 @@ mod tests {
         assert!(validation.errors.iter().any(|e| e.contains("not found")));
     }
 
+    #[test]
+    fn test_resolve_multicam_clips_replaces_multicam_with_active_angle() {
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut inner = Sequence::new("Interview", SequenceFormat::youtube_1080());
+        let mut cam_a = Track::new_video("Cam A");
+        cam_a.add_clip(Clip::new("cam_a").with_source_range(0.0, 20.0));
+        let mut cam_b = Track::new_video("Cam B");
+        cam_b.add_clip(Clip::new("cam_b").with_source_range(0.0, 20.0));
+        let cam_b_id = cam_b.id.clone();
+        inner.add_track(cam_a);
+        inner.add_track(cam_b);
+
+        let mut sequence = Sequence::new("Main", SequenceFormat::youtube_1080());
+        let mut track = Track::new_video("Video 1");
+        let multicam = Clip::multicam(&inner.id, 20.0, &cam_b_id).place_at(5.0);
+        let multicam_id = multicam.id.clone();
+        track.add_clip(multicam);
+        sequence.add_track(track);
+
+        let mut sequences = HashMap::new();
+        sequences.insert(inner.id.clone(), inner.clone());
+        sequences.insert(sequence.id.clone(), sequence.clone());
+
+        let resolved = resolve_multicam_clips(&sequence, &sequences);
+
+        let clips = &resolved.tracks[0].clips;
+        assert_eq!(clips.len(), 1);
+        assert_eq!(clips[0].asset_id, "cam_b");
+        assert!((clips[0].place.timeline_in_sec - 5.0).abs() < 1e-9);
+
+        // Without its inner sequence the clip stays and validation reports it
+        sequences.remove(&inner.id);
+        let unresolved = resolve_multicam_clips(&sequence, &sequences);
+        assert_eq!(unresolved.tracks[0].clips[0].id, multicam_id);
+        let validation = validate_export_settings(
+            &unresolved,
+            &HashMap::new(),
+            &HashMap::new(),
+            &ExportSettings::default(),
+        );
+        assert!(!validation.is_valid);
+        assert!(validation
+            .errors
+            .iter()
+            .any(|e| e.contains("could not be resolved to its active angle")));
+    }
+
     #[test]
     fn test_validation_rejects_asset_marked_missing_offline() {
         use crate::core::assets::VideoInfo;
@@ -10041,6 +12433,7 @@ mod tests {
             ("mp4-draft", ExportPreset::Mp4Draft),
             ("mp4-high", ExportPreset::Mp4High),
             ("webm-vp9-720p", ExportPreset::WebmVp9),
+            ("webm-av1-1080p", ExportPreset::WebmAv1),
         ];
 
         for (alias, expected) in aliases {
@@ -10081,15 +12474,117 @@ mod tests {
             max_cll: None,
             max_fall: None,
             bit_depth: None,
+            audio_channel_layout: AudioChannelLayout::Stereo,
+            audio_downmix: AudioDownmix::Standard,
+            prores_profile: ProResProfile::Hq,
+            dnxhr_profile: DnxhrProfile::Hqx,
+        };
+
+        let error =
+            validate_video_export_request(&request, Path::new("/tmp/master.webm")).unwrap_err();
+
+        assert!(
+            error.to_string().contains("does not support video codec"),
+            "unexpected error: {error}"
+        );
+    }
+
+    /// Feature: Structured video export validation
+    /// Scenario: should accept AV1 in MP4, WebM and MKV but not in MOV
+    #[test]
+    fn video_export_request_should_place_av1_in_web_containers() {
+        let request = ExportSettings::request_from_preset(ExportPreset::WebmAv1);
+        assert_eq!(request.video_codec, VideoCodec::Av1);
+        assert_eq!(request.container, ContainerFormat::Webm);
+        validate_video_export_request(&request, Path::new("/tmp/web.webm"))
+            .expect("AV1/Opus is a WebM delivery");
+
+        for (container, audio_codec, path) in [
+            (ContainerFormat::Mp4, AudioCodec::Aac, "/tmp/web.mp4"),
+            (ContainerFormat::Mkv, AudioCodec::Opus, "/tmp/web.mkv"),
+        ] {
+            let request = VideoExportRequest {
+                container,
+                audio_codec,
+                ..request.clone()
+            };
+            validate_video_export_request(&request, Path::new(path))
+                .unwrap_or_else(|error| panic!("{path} should accept AV1: {error}"));
+        }
+
+        let mov = VideoExportRequest {
+            container: ContainerFormat::Mov,
+            audio_codec: AudioCodec::Aac,
+            ..request.clone()
+        };
+        assert!(validate_video_export_request(&mov, Path::new("/tmp/web.mov")).is_err());
+
+        let out_of_range = VideoExportRequest {
+            crf: Some(64),
+            ..request
+        };
+        assert!(validate_video_export_request(&out_of_range, Path::new("/tmp/web.webm")).is_err());
+    }
+
+    /// Feature: Structured video export requests
+    /// Scenario: should fill AV1 CRF and speed from the quality tier unless the caller set them
+    #[test]
+    fn video_export_request_should_map_av1_quality_tiers() {
+        let request = VideoExportRequest {
+            crf: None,
+            quality_tier: ExportQualityTier::High,
+            ..ExportSettings::request_from_preset(ExportPreset::WebmAv1)
+        };
+
+        let settings =
+            ExportSettings::from_video_request(&request, PathBuf::from("web.webm"), None, None)
+                .unwrap();
+        assert_eq!(settings.crf, Some(27));
+        assert_eq!(settings.encoder_speed.as_deref(), Some("slow"));
+
+        let explicit = VideoExportRequest {
+            crf: Some(45),
+            quality_tier: ExportQualityTier::Draft,
+            ..request.clone()
+        };
+        let settings =
+            ExportSettings::from_video_request(&explicit, PathBuf::from("web.webm"), None, None)
+                .unwrap();
+        assert_eq!(settings.crf, Some(45));
+        assert_eq!(settings.encoder_speed.as_deref(), Some("veryfast"));
+
+        let custom = VideoExportRequest {
+            quality_tier: ExportQualityTier::Custom,
+            ..request
         };
+        let settings =
+            ExportSettings::from_video_request(&custom, PathBuf::from("web.webm"), None, None)
+                .unwrap();
+        assert_eq!(settings.crf, None);
+        assert_eq!(settings.encoder_speed, None);
+    }
 
-        let error =
-            validate_video_export_request(&request, Path::new("/tmp/master.webm")).unwrap_err();
+    /// Feature: AV1 hardware encoding
+    /// Scenario: should open the VA-API device and upload frames before encoding
+    #[test]
+    fn simple_export_args_should_upload_frames_for_vaapi() {
+        let engine = test_export_engine();
+        let settings = ExportSettings {
+            resolved_encoder_name: Some("av1_vaapi".to_string()),
+            crf: Some(32),
+            ..ExportSettings::from_preset(ExportPreset::WebmAv1, PathBuf::from("web.webm"))
+        };
 
-        assert!(
-            error.to_string().contains("does not support video codec"),
-            "unexpected error: {error}"
+        let args = engine.build_simple_export_args(
+            vec!["-i".to_string(), "/tmp/input.mp4".to_string()],
+            &settings,
         );
+
+        assert_eq!(args[0], "-vaapi_device");
+        let vf = args.iter().position(|arg| arg == "-vf").unwrap();
+        assert_eq!(args[vf + 1], "scale=1920:1080,format=nv12,hwupload");
+        let qp = args.iter().position(|arg| arg == "-qp").unwrap();
+        assert_eq!(args[qp + 1], "128");
     }
 
     /// Feature: Structured video export validation
@@ -10184,7 +12679,10 @@ mod tests {
         let settings =
             ExportSettings::from_preset(ExportPreset::WebmVp9, PathBuf::from("output.webm"));
 
-        let args = engine.build_simple_export_args(Path::new("/tmp/input.webm"), &settings);
+        let args = engine.build_simple_export_args(
+            vec!["-i".to_string(), "/tmp/input.webm".to_string()],
+            &settings,
+        );
 
         assert!(
             args.windows(2)
@@ -10366,7 +12864,10 @@ mod tests {
             None,
         );
 
-        let args = engine.build_simple_export_args(Path::new("/tmp/input.mp4"), &settings);
+        let args = engine.build_simple_export_args(
+            vec!["-i".to_string(), "/tmp/input.mp4".to_string()],
+            &settings,
+        );
 
         assert_eq!(
             preset_arg_value(&args),
@@ -10382,7 +12883,10 @@ mod tests {
         let engine = test_export_engine();
         let settings = ExportSettings::default();
 
-        let args = engine.build_simple_export_args(Path::new("/tmp/input.mp4"), &settings);
+        let args = engine.build_simple_export_args(
+            vec!["-i".to_string(), "/tmp/input.mp4".to_string()],
+            &settings,
+        );
 
         assert_eq!(preset_arg_value(&args), None, "unexpected args: {args:?}");
     }
@@ -10410,6 +12914,26 @@ mod tests {
         assert!(settings.encoder_speed_args("prores_ks").is_empty());
     }
 
+    /// Feature: Encoder speed argument emission
+    /// Scenario: should translate the speed ladder onto the AV1 encoders' numeric speeds
+    #[test]
+    fn encoder_speed_args_should_translate_the_ladder_for_av1() {
+        let settings = ExportSettings {
+            encoder_speed: Some("fast".to_string()),
+            ..ExportSettings::default()
+        };
+
+        assert_eq!(
+            settings.encoder_speed_args("libsvtav1"),
+            vec!["-preset".to_string(), "8".to_string()]
+        );
+        assert_eq!(
+            settings.encoder_speed_args("libaom-av1"),
+            vec!["-cpu-used".to_string(), "6".to_string()]
+        );
+        assert!(settings.encoder_speed_args("av1_nvenc").is_empty());
+    }
+
     /// Feature: Encoder speed argument emission
     /// Scenario: should drop an invalid encoder speed rather than hand FFmpeg a bad flag
     #[test]
@@ -10493,6 +13017,80 @@ mod tests {
         );
     }
 
+    /// Feature: Ranged export
+    /// Scenario: should open only the clips the range reaches, each at the range's start
+    #[test]
+    fn ranged_export_args_should_decode_only_the_range() {
+        use crate::core::assets::VideoInfo;
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut sequence = Sequence::new("Test", SequenceFormat::youtube_1080());
+        let mut video_track = Track::new_video("Video 1");
+        video_track.add_clip(
+            Clip::new("before_asset")
+                .with_source_range(0.0, 10.0)
+                .place_at(0.0),
+        );
+        video_track.add_clip(
+            Clip::new("spanning_asset")
+                .with_source_range(5.0, 25.0)
+                .place_at(10.0),
+        );
+        sequence.add_track(video_track);
+
+        let mut assets = std::collections::HashMap::new();
+        let mut audio_info_map = std::collections::HashMap::new();
+        for id in ["before_asset", "spanning_asset"] {
+            let path = create_temp_media_file(&format!("{id}.mp4"));
+            let mut asset = Asset::new_video(&format!("{id}.mp4"), &path, VideoInfo::default())
+                .with_duration(30.0)
+                .with_file_size(3_000_000);
+            asset.id = id.to_string();
+            assets.insert(id.to_string(), asset);
+            audio_info_map.insert(
+                id.to_string(),
+                AssetAudioInfo {
+                    has_audio: false,
+                    ..AssetAudioInfo::default()
+                },
+            );
+        }
+
+        let args = build_complex_filter_args_with_audio_info(
+            &sequence,
+            &assets,
+            &std::collections::HashMap::new(),
+            &audio_info_map,
+            &ExportSettings {
+                start_time: Some(15.0),
+                end_time: Some(20.0),
+                ..ExportSettings::default()
+            },
+        )
+        .expect("ranged settings should build export args");
+        let inputs: Vec<&String> = args
+            .windows(2)
+            .filter(|pair| pair[0] == "-i")
+            .map(|pair| &pair[1])
+            .collect();
+        let args_str = args.join(" ");
+
+        assert_eq!(
+            inputs.len(),
+            1,
+            "only the spanning clip is opened: {args:?}"
+        );
+        assert!(inputs[0].contains("spanning_asset"));
+        assert!(
+            args_str.contains(&format!("-ss 10 -i {}", inputs[0])),
+            "the spanning clip opens at the range start, 5s into its source: {args_str}"
+        );
+        assert!(
+            args_str.contains("tpad=start_duration=5[trim0]"),
+            "the skipped head is padded back: {args_str}"
+        );
+    }
+
     /// Feature: Encoder speed serialization
     /// Scenario: should stay absent from JSON when unset so stored settings are unchanged
     #[test]
@@ -10739,9 +13337,11 @@ mod tests {
                 channels: 2,
                 codec: "aac".to_string(),
                 bitrate: Some(192_000),
+                channel_layout: None,
             }),
             format: "mp4".to_string(),
             size_bytes: 10_000_000,
+            source: Default::default(),
         };
 
         let audio_info = AssetAudioInfo::from_media_info(&media_info);
@@ -10769,6 +13369,7 @@ mod tests {
             audio: None, // No audio stream
             format: "mp4".to_string(),
             size_bytes: 10_000_000,
+            source: Default::default(),
         };
 
         let audio_info = AssetAudioInfo::from_media_info(&media_info);
@@ -10930,16 +13531,213 @@ mod tests {
         audio_asset.id = "audio_asset".to_string();
 
         let mut assets = std::collections::HashMap::new();
-        assets.insert(audio_asset.id.clone(), audio_asset);
+        assets.insert(audio_asset.id.clone(), audio_asset);
+
+        let mut audio_info_map = std::collections::HashMap::new();
+        audio_info_map.insert(
+            "audio_asset".to_string(),
+            AssetAudioInfo {
+                has_audio: true,
+                ..AssetAudioInfo::default()
+            },
+        );
+
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+        let settings = AudioExportSettings {
+            format: AudioExportFormat::Mp3,
+            output_path: PathBuf::from("/tmp/audio-only.mp3"),
+            bitrate: None,
+            sample_rate: None,
+            start_time: None,
+            end_time: None,
+        }
+        .to_export_settings();
+
+        let args = engine
+            .build_audio_only_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &std::collections::HashMap::new(),
+                &audio_info_map,
+                &settings,
+            )
+            .expect("audio-only export args should build");
+
+        let args_str = args.join(" ");
+        assert!(args_str.contains("-map [outa_base]") || args_str.contains("-map [outa]"));
+        assert!(!args_str.contains("Sequence has no visual clips to export"));
+    }
+
+    #[test]
+    fn test_audio_only_filter_builder_skips_visual_assets_without_audio() {
+        use crate::core::assets::{AudioInfo, VideoInfo};
+        use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut sequence = Sequence::new("Audio Mix", SequenceFormat::youtube_1080());
+
+        let mut video_track = Track::new_video("Video 1");
+        video_track.add_clip(
+            Clip::new("broken_video")
+                .with_source_range(0.0, 5.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(video_track);
+
+        let mut audio_track = Track::new_audio("Audio 1");
+        audio_track.add_clip(
+            Clip::new("voiceover")
+                .with_source_range(0.0, 5.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(audio_track);
+
+        let mut broken_video = Asset::new_video(
+            "broken_visual.mp4",
+            "/missing/broken_visual.mp4",
+            VideoInfo::default(),
+        )
+        .with_duration(5.0)
+        .with_file_size(5_000_000);
+        broken_video.id = "broken_video".to_string();
+        broken_video.audio = None;
+
+        let voiceover_path = create_temp_media_file("audio_only_voiceover.wav");
+        let mut voiceover = Asset::new_audio(
+            "audio_only_voiceover.wav",
+            &voiceover_path,
+            AudioInfo::default(),
+        )
+        .with_duration(5.0)
+        .with_file_size(1_000_000);
+        voiceover.id = "voiceover".to_string();
+
+        let mut assets = std::collections::HashMap::new();
+        assets.insert(broken_video.id.clone(), broken_video);
+        assets.insert(voiceover.id.clone(), voiceover);
+
+        let mut audio_info_map = std::collections::HashMap::new();
+        audio_info_map.insert(
+            "broken_video".to_string(),
+            AssetAudioInfo {
+                has_audio: false,
+                ..AssetAudioInfo::default()
+            },
+        );
+        audio_info_map.insert(
+            "voiceover".to_string(),
+            AssetAudioInfo {
+                has_audio: true,
+                ..AssetAudioInfo::default()
+            },
+        );
+
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+        let settings = AudioExportSettings {
+            format: AudioExportFormat::Wav,
+            output_path: PathBuf::from("/tmp/audio-only.wav"),
+            bitrate: None,
+            sample_rate: None,
+            start_time: None,
+            end_time: None,
+        }
+        .to_export_settings();
+
+        let args = engine
+            .build_audio_only_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &std::collections::HashMap::new(),
+                &audio_info_map,
+                &settings,
+            )
+            .expect("visual-only broken assets should not block audio-only export");
+
+        let args_str = args.join(" ");
+        assert!(args_str.contains(&voiceover_path));
+        assert!(!args_str.contains("/missing/broken_visual.mp4"));
+    }
+
+    /// Feature: audio-only export padding
+    /// Scenario: the timeline outlives the last clip that carries audio
+    #[test]
+    fn test_audio_only_filter_builder_pads_to_the_sequence_end() {
+        use crate::core::assets::{AudioInfo, VideoInfo};
+        use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut sequence = Sequence::new("Audio Tail", SequenceFormat::youtube_1080());
+
+        let mut audio_track = Track::new_audio("Audio 1");
+        audio_track.add_clip(
+            Clip::new("voiceover")
+                .with_source_range(0.0, 4.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(audio_track);
+
+        // Silent picture holds the timeline open to 10s. An export range inside
+        // it is valid, so the master audio has to reach that far.
+        let mut video_track = Track::new_video("Video 1");
+        video_track.add_clip(
+            Clip::new("silent_video")
+                .with_source_range(0.0, 6.0)
+                .place_at(4.0),
+        );
+        sequence.add_track(video_track);
+
+        let voiceover_path = create_temp_media_file("audio_tail_voiceover.wav");
+        let mut voiceover = Asset::new_audio(
+            "audio_tail_voiceover.wav",
+            &voiceover_path,
+            AudioInfo::default(),
+        )
+        .with_duration(4.0)
+        .with_file_size(1_000_000);
+        voiceover.id = "voiceover".to_string();
+
+        let silent_video_path = create_temp_media_file("audio_tail_silent.mp4");
+        let mut silent_video = Asset::new_video(
+            "audio_tail_silent.mp4",
+            &silent_video_path,
+            VideoInfo::default(),
+        )
+        .with_duration(6.0)
+        .with_file_size(5_000_000);
+        silent_video.id = "silent_video".to_string();
+        silent_video.audio = None;
+
+        let mut assets = std::collections::HashMap::new();
+        assets.insert(voiceover.id.clone(), voiceover);
+        assets.insert(silent_video.id.clone(), silent_video);
 
         let mut audio_info_map = std::collections::HashMap::new();
         audio_info_map.insert(
-            "audio_asset".to_string(),
+            "voiceover".to_string(),
             AssetAudioInfo {
                 has_audio: true,
                 ..AssetAudioInfo::default()
             },
         );
+        audio_info_map.insert(
+            "silent_video".to_string(),
+            AssetAudioInfo {
+                has_audio: false,
+                ..AssetAudioInfo::default()
+            },
+        );
 
         let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
             ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
@@ -10949,8 +13747,8 @@ mod tests {
             source: crate::core::ffmpeg::FFmpegSource::System,
         }));
         let settings = AudioExportSettings {
-            format: AudioExportFormat::Mp3,
-            output_path: PathBuf::from("/tmp/audio-only.mp3"),
+            format: AudioExportFormat::Wav,
+            output_path: PathBuf::from("/tmp/audio-tail.wav"),
             bitrate: None,
             sample_rate: None,
             start_time: None,
@@ -10969,66 +13767,38 @@ mod tests {
             .expect("audio-only export args should build");
 
         let args_str = args.join(" ");
-        assert!(args_str.contains("-map [outa_base]") || args_str.contains("-map [outa]"));
-        assert!(!args_str.contains("Sequence has no visual clips to export"));
+        assert!(
+            args_str.contains("apad=whole_dur=10"),
+            "audio must be padded to the sequence end, got: {args_str}"
+        );
     }
 
-    #[test]
-    fn test_audio_only_filter_builder_skips_visual_assets_without_audio() {
-        use crate::core::assets::{AudioInfo, VideoInfo};
+    /// Builds an audio-only export of a 4s voiceover at 0s plus `tail_track`,
+    /// returning the joined arguments so the padding target can be read off.
+    fn audio_only_args_with_tail(tail_track: Track, tail_asset: Option<Asset>) -> String {
+        use crate::core::assets::AudioInfo;
         use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
-        use crate::core::timeline::{Clip, SequenceFormat, Track};
-
-        let mut sequence = Sequence::new("Audio Mix", SequenceFormat::youtube_1080());
-
-        let mut video_track = Track::new_video("Video 1");
-        video_track.add_clip(
-            Clip::new("broken_video")
-                .with_source_range(0.0, 5.0)
-                .place_at(0.0),
-        );
-        sequence.add_track(video_track);
+        use crate::core::timeline::{Clip, SequenceFormat};
 
+        let mut sequence = Sequence::new("Audio Tail", SequenceFormat::youtube_1080());
         let mut audio_track = Track::new_audio("Audio 1");
         audio_track.add_clip(
             Clip::new("voiceover")
-                .with_source_range(0.0, 5.0)
+                .with_source_range(0.0, 4.0)
                 .place_at(0.0),
         );
         sequence.add_track(audio_track);
+        sequence.add_track(tail_track);
 
-        let mut broken_video = Asset::new_video(
-            "broken_visual.mp4",
-            "/missing/broken_visual.mp4",
-            VideoInfo::default(),
-        )
-        .with_duration(5.0)
-        .with_file_size(5_000_000);
-        broken_video.id = "broken_video".to_string();
-        broken_video.audio = None;
-
-        let voiceover_path = create_temp_media_file("audio_only_voiceover.wav");
-        let mut voiceover = Asset::new_audio(
-            "audio_only_voiceover.wav",
-            &voiceover_path,
-            AudioInfo::default(),
-        )
-        .with_duration(5.0)
-        .with_file_size(1_000_000);
+        let voiceover_path = create_temp_media_file("audio_tail_case.wav");
+        let mut voiceover =
+            Asset::new_audio("audio_tail_case.wav", &voiceover_path, AudioInfo::default())
+                .with_duration(4.0)
+                .with_file_size(1_000_000);
         voiceover.id = "voiceover".to_string();
 
         let mut assets = std::collections::HashMap::new();
-        assets.insert(broken_video.id.clone(), broken_video);
-        assets.insert(voiceover.id.clone(), voiceover);
-
         let mut audio_info_map = std::collections::HashMap::new();
-        audio_info_map.insert(
-            "broken_video".to_string(),
-            AssetAudioInfo {
-                has_audio: false,
-                ..AssetAudioInfo::default()
-            },
-        );
         audio_info_map.insert(
             "voiceover".to_string(),
             AssetAudioInfo {
@@ -11036,6 +13806,17 @@ mod tests {
                 ..AssetAudioInfo::default()
             },
         );
+        if let Some(asset) = tail_asset {
+            audio_info_map.insert(
+                asset.id.clone(),
+                AssetAudioInfo {
+                    has_audio: true,
+                    ..AssetAudioInfo::default()
+                },
+            );
+            assets.insert(asset.id.clone(), asset);
+        }
+        assets.insert(voiceover.id.clone(), voiceover);
 
         let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
             ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
@@ -11046,7 +13827,7 @@ mod tests {
         }));
         let settings = AudioExportSettings {
             format: AudioExportFormat::Wav,
-            output_path: PathBuf::from("/tmp/audio-only.wav"),
+            output_path: PathBuf::from("/tmp/audio-tail-case.wav"),
             bitrate: None,
             sample_rate: None,
             start_time: None,
@@ -11054,7 +13835,7 @@ mod tests {
         }
         .to_export_settings();
 
-        let args = engine
+        engine
             .build_audio_only_filter_args_with_audio_info(
                 &sequence,
                 &assets,
@@ -11062,81 +13843,341 @@ mod tests {
                 &audio_info_map,
                 &settings,
             )
-            .expect("visual-only broken assets should not block audio-only export");
-
-        let args_str = args.join(" ");
-        assert!(args_str.contains(&voiceover_path));
-        assert!(!args_str.contains("/missing/broken_visual.mp4"));
+            .expect("audio-only export args should build")
+            .join(" ")
     }
 
-    /// Feature: audio-only export padding
-    /// Scenario: the timeline outlives the last clip that carries audio
+    /// Feature: audio stems export
+    /// Scenario: one job writes every stem and the mixdown at the same length
     #[test]
-    fn test_audio_only_filter_builder_pads_to_the_sequence_end() {
-        use crate::core::assets::{AudioInfo, VideoInfo};
+    fn test_audio_stems_args_split_shared_streams_and_pad_every_output() {
+        use crate::core::assets::AudioInfo;
         use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
-        use crate::core::timeline::{Clip, SequenceFormat, Track};
-
-        let mut sequence = Sequence::new("Audio Tail", SequenceFormat::youtube_1080());
+        use crate::core::timeline::{Clip, SequenceFormat};
 
-        let mut audio_track = Track::new_audio("Audio 1");
-        audio_track.add_clip(
-            Clip::new("voiceover")
+        let mut sequence = Sequence::new("Stems", SequenceFormat::youtube_1080());
+        let mut dx = Track::new_audio("DX");
+        dx.add_clip(
+            Clip::new("dialog")
                 .with_source_range(0.0, 4.0)
                 .place_at(0.0),
         );
-        sequence.add_track(audio_track);
+        let mut mx = Track::new_audio("MX");
+        mx.add_clip(Clip::new("music").with_source_range(0.0, 6.0).place_at(2.0));
+        let mut fx = Track::new_audio("FX");
+        let mut muted = Clip::new("music").with_source_range(0.0, 2.0).place_at(0.0);
+        muted.audio.muted = true;
+        fx.add_clip(muted);
+        let (dx_id, mx_id, fx_id) = (dx.id.clone(), mx.id.clone(), fx.id.clone());
+        sequence.add_track(dx);
+        sequence.add_track(mx);
+        sequence.add_track(fx);
 
-        // Silent picture holds the timeline open to 10s. An export range inside
-        // it is valid, so the master audio has to reach that far.
-        let mut video_track = Track::new_video("Video 1");
+        let mut assets = std::collections::HashMap::new();
+        let mut audio_info = std::collections::HashMap::new();
+        for id in ["dialog", "music"] {
+            let path = create_temp_media_file(&format!("stems_case_{id}.wav"));
+            let mut asset = Asset::new_audio(id, &path, AudioInfo::default())
+                .with_duration(10.0)
+                .with_file_size(1_000_000);
+            asset.id = id.to_string();
+            assets.insert(asset.id.clone(), asset);
+            audio_info.insert(
+                id.to_string(),
+                AssetAudioInfo {
+                    has_audio: true,
+                    ..AssetAudioInfo::default()
+                },
+            );
+        }
+
+        let stem = |name: &str, track_ids: Vec<String>| AudioStem {
+            name: name.to_string(),
+            track_ids,
+        };
+        let settings = AudioStemsExportSettings {
+            audio: AudioExportSettings {
+                format: AudioExportFormat::Wav,
+                output_path: PathBuf::from("/tmp/stems/Film.wav"),
+                bitrate: None,
+                sample_rate: None,
+                start_time: Some(1.0),
+                end_time: None,
+            },
+            stems: vec![
+                stem("Dialog", vec![dx_id.clone()]),
+                stem("Music", vec![mx_id.clone()]),
+                stem("DX + MX", vec![dx_id, mx_id]),
+                stem("Effects", vec![fx_id]),
+            ],
+            include_mixdown: true,
+        };
+        settings.validate().unwrap();
+        settings.validate_tracks(&sequence).unwrap();
+
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+        let args = engine
+            .build_audio_stems_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &audio_info,
+                &settings,
+            )
+            .expect("stems export args should build");
+        let args_str = args.join(" ");
+
+        // The muted clip is never decoded: two inputs feed five outputs.
+        assert_eq!(args.iter().filter(|arg| *arg == "-i").count(), 2);
+        assert_eq!(args_str.matches("asplit=3").count(), 2, "{args_str}");
+        // The mixdown and the three audible stems are padded to the timeline end.
+        assert_eq!(
+            args_str.matches("apad=whole_dur=8").count(),
+            4,
+            "{args_str}"
+        );
+        assert!(
+            args_str.contains("anullsrc=r=48000:cl=stereo,atrim=duration=8[stem3]"),
+            "{args_str}"
+        );
+
+        let outputs: Vec<&str> = args
+            .iter()
+            .filter(|arg| arg.starts_with("/tmp/stems/"))
+            .map(String::as_str)
+            .collect();
+        assert_eq!(
+            outputs,
+            vec![
+                "/tmp/stems/Film.wav",
+                "/tmp/stems/Film_Dialog.wav",
+                "/tmp/stems/Film_Music.wav",
+                "/tmp/stems/Film_DX___MX.wav",
+                "/tmp/stems/Film_Effects.wav",
+            ]
+        );
+        // Every file gets the same range and encoding.
+        assert_eq!(args.iter().filter(|arg| *arg == "-map").count(), 5);
+        assert_eq!(args_str.matches("-ss 1 -vn -c:a pcm_s16le -y").count(), 5);
+    }
+
+    #[test]
+    fn test_audio_stems_settings_reject_clashing_or_empty_stems() {
+        let stem = |name: &str, track_ids: &[&str]| AudioStem {
+            name: name.to_string(),
+            track_ids: track_ids.iter().map(|id| id.to_string()).collect(),
+        };
+        let settings = |stems| AudioStemsExportSettings {
+            audio: AudioExportSettings {
+                format: AudioExportFormat::Flac,
+                output_path: PathBuf::from("/tmp/Film.flac"),
+                bitrate: None,
+                sample_rate: None,
+                start_time: None,
+                end_time: None,
+            },
+            stems,
+            include_mixdown: false,
+        };
+
+        assert!(settings(vec![]).validate().is_err());
+        assert!(settings(vec![stem("Dialog", &[])]).validate().is_err());
+        assert!(settings(vec![stem("???", &["t1"])]).validate().is_err());
+        assert!(
+            settings(vec![stem("Music", &["t1"]), stem("music", &["t2"])])
+                .validate()
+                .is_err()
+        );
+
+        let valid = settings(vec![stem("Dialog", &["t1"])]);
+        assert!(valid.validate().is_ok());
+        assert_eq!(
+            valid.stem_output_path(&valid.stems[0]),
+            PathBuf::from("/tmp/Film_Dialog.flac")
+        );
+        let sequence = Sequence::new(
+            "Stems",
+            crate::core::timeline::SequenceFormat::youtube_1080(),
+        );
+        assert!(valid.validate_tracks(&sequence).is_err());
+    }
+
+    #[test]
+    fn test_streaming_package_args_split_one_picture_into_the_ladder() {
+        use crate::core::assets::VideoInfo;
+        use crate::core::ffmpeg::FFmpegInfo;
+        use crate::core::render::StreamingRendition;
+
+        let mut sequence = Sequence::new(
+            "Ladder",
+            crate::core::timeline::SequenceFormat::youtube_1080(),
+        );
+        let mut video_track = Track::new_video("V1");
         video_track.add_clip(
-            Clip::new("silent_video")
-                .with_source_range(0.0, 6.0)
-                .place_at(4.0),
+            Clip::new("video_asset")
+                .with_source_range(0.0, 4.0)
+                .place_at(0.0),
         );
         sequence.add_track(video_track);
+        let mut caption_track = Track::new_caption("English");
+        let mut caption = Clip::new("caption_asset")
+            .with_source_range(0.0, 2.0)
+            .place_at(1.0);
+        caption.label = Some("Burned".to_string());
+        caption_track.add_clip(caption);
+        sequence.add_track(caption_track);
 
-        let voiceover_path = create_temp_media_file("audio_tail_voiceover.wav");
-        let mut voiceover = Asset::new_audio(
-            "audio_tail_voiceover.wav",
-            &voiceover_path,
-            AudioInfo::default(),
+        let video_path = create_temp_media_file("streaming_ladder_source.mp4");
+        let mut video_asset = Asset::new_video(
+            "streaming_ladder_source.mp4",
+            &video_path,
+            VideoInfo::default(),
         )
         .with_duration(4.0)
-        .with_file_size(1_000_000);
-        voiceover.id = "voiceover".to_string();
+        .with_file_size(4_000_000);
+        video_asset.id = "video_asset".to_string();
+        let mut assets = HashMap::new();
+        assets.insert(video_asset.id.clone(), video_asset);
+        let mut audio_info = HashMap::new();
+        audio_info.insert(
+            "video_asset".to_string(),
+            AssetAudioInfo {
+                has_audio: true,
+                ..AssetAudioInfo::default()
+            },
+        );
+
+        let rendition = |height: u32, bitrate: &str| StreamingRendition {
+            height,
+            video_bitrate: bitrate.to_string(),
+        };
+        let mut settings = StreamingPackageSettings {
+            output_dir: PathBuf::from("/tmp/package"),
+            renditions: vec![
+                rendition(1080, "6M"),
+                rendition(720, "3M"),
+                rendition(360, "800k"),
+            ],
+            video_codec: VideoCodec::H264,
+            audio_bitrate: Some("128k".to_string()),
+            fps: None,
+            segment_duration_sec: 4.0,
+            encoder_speed: None,
+            start_time: None,
+            end_time: None,
+            include_captions: true,
+            burn_in_captions: false,
+        };
+        settings.validate().unwrap();
+
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+        let args = engine
+            .build_streaming_package_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &audio_info,
+                &settings,
+            )
+            .expect("streaming package args should build");
+        let args_str = args.join(" ");
+
+        // One decode feeds every rung.
+        assert_eq!(args.iter().filter(|arg| *arg == "-i").count(), 1);
+        assert!(
+            args_str.contains("split=3[ladder0][ladder1][ladder2]"),
+            "{args_str}"
+        );
+        assert!(args_str.contains("[ladder1]scale=1280:720,setsar=1[ladderv1]"));
+        assert!(args_str.contains("[ladder2]scale=640:360,setsar=1[ladderv2]"));
+        // Three video maps and the shared audio.
+        assert_eq!(args.iter().filter(|arg| *arg == "-map").count(), 4);
+        assert!(args_str.contains("-b:v:0 6000000 -maxrate:v:0 6000000 -bufsize:v:0 12000000"));
+        assert!(args_str.contains("-b:v:2 800000"));
+        assert!(args_str.contains("-force_key_frames expr:gte(t,n_forced*4) -sc_threshold 0"));
+        assert!(args_str.contains("-c:a aac -b:a 128k"));
+        assert!(args_str.contains("-f dash -seg_duration 4"));
+        assert!(args_str.contains("-adaptation_sets id=0,streams=v id=1,streams=a"));
+        assert!(args_str.contains("-hls_playlist 1"));
+        assert_eq!(args.last().unwrap(), "/tmp/package/manifest.mpd");
+        // The captions travel as WebVTT, not in the picture.
+        assert!(!args_str.contains("Burned"), "{args_str}");
+
+        settings.burn_in_captions = true;
+        let burned = engine
+            .build_streaming_package_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &audio_info,
+                &settings,
+            )
+            .expect("streaming package args should build")
+            .join(" ");
+        assert!(burned.contains("Burned"), "{burned}");
+    }
+
+    #[test]
+    fn test_image_sequence_args_number_frames_from_the_timeline_with_alpha() {
+        use crate::core::assets::VideoInfo;
+        use crate::core::ffmpeg::FFmpegInfo;
+        use crate::core::render::ImageSequenceFormat;
 
-        let silent_video_path = create_temp_media_file("audio_tail_silent.mp4");
-        let mut silent_video = Asset::new_video(
-            "audio_tail_silent.mp4",
-            &silent_video_path,
+        let mut sequence = Sequence::new(
+            "Plates",
+            crate::core::timeline::SequenceFormat::youtube_1080(),
+        );
+        let mut video_track = Track::new_video("V1");
+        video_track.add_clip(
+            Clip::new("video_asset")
+                .with_source_range(0.0, 4.0)
+                .place_at(1.0),
+        );
+        sequence.add_track(video_track);
+
+        let video_path = create_temp_media_file("image_sequence_source.mp4");
+        let mut video_asset = Asset::new_video(
+            "image_sequence_source.mp4",
+            &video_path,
             VideoInfo::default(),
         )
-        .with_duration(6.0)
-        .with_file_size(5_000_000);
-        silent_video.id = "silent_video".to_string();
-        silent_video.audio = None;
-
-        let mut assets = std::collections::HashMap::new();
-        assets.insert(voiceover.id.clone(), voiceover);
-        assets.insert(silent_video.id.clone(), silent_video);
-
-        let mut audio_info_map = std::collections::HashMap::new();
-        audio_info_map.insert(
-            "voiceover".to_string(),
+        .with_duration(4.0)
+        .with_file_size(4_000_000);
+        video_asset.id = "video_asset".to_string();
+        let mut assets = HashMap::new();
+        assets.insert(video_asset.id.clone(), video_asset);
+        let mut audio_info = HashMap::new();
+        audio_info.insert(
+            "video_asset".to_string(),
             AssetAudioInfo {
                 has_audio: true,
                 ..AssetAudioInfo::default()
             },
         );
-        audio_info_map.insert(
-            "silent_video".to_string(),
-            AssetAudioInfo {
-                has_audio: false,
-                ..AssetAudioInfo::default()
-            },
-        );
+
+        let mut settings = ImageSequenceExportSettings {
+            output_dir: PathBuf::from("/tmp/plates"),
+            base_name: "sh010".to_string(),
+            format: ImageSequenceFormat::Exr,
+            bit_depth: None,
+            alpha: true,
+            frame_padding: 4,
+            start_number: None,
+            width: None,
+            height: None,
+            start_time: Some(2.0),
+            end_time: Some(3.0),
+        };
+        settings.validate().unwrap();
 
         let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
             ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
@@ -11145,77 +14186,90 @@ mod tests {
             is_bundled: false,
             source: crate::core::ffmpeg::FFmpegSource::System,
         }));
-        let settings = AudioExportSettings {
-            format: AudioExportFormat::Wav,
-            output_path: PathBuf::from("/tmp/audio-tail.wav"),
-            bitrate: None,
-            sample_rate: None,
-            start_time: None,
-            end_time: None,
-        }
-        .to_export_settings();
-
         let args = engine
-            .build_audio_only_filter_args_with_audio_info(
+            .build_image_sequence_filter_args_with_audio_info(
                 &sequence,
                 &assets,
-                &std::collections::HashMap::new(),
-                &audio_info_map,
+                &audio_info,
                 &settings,
             )
-            .expect("audio-only export args should build");
-
+            .expect("image sequence args should build");
         let args_str = args.join(" ");
-        assert!(
-            args_str.contains("apad=whole_dur=10"),
-            "audio must be padded to the sequence end, got: {args_str}"
-        );
+
+        // The empty first second stays transparent instead of black.
+        assert!(args_str.contains("color=c=black@0"), "{args_str}");
+        assert!(args_str.contains("format=yuva444p10le"), "{args_str}");
+        assert!(args_str.contains("anullsink"), "{args_str}");
+        assert_eq!(args.iter().filter(|arg| *arg == "-map").count(), 1);
+        assert!(args_str.contains("-an -c:v exr -pix_fmt gbrapf32le -format half"));
+        assert!(args_str.contains("-ss 2 -t 1"));
+        assert!(args_str.contains("-f image2 -start_number 60 -y"));
+        assert_eq!(args.last().unwrap(), "/tmp/plates/sh010.%04d.exr");
+
+        settings.alpha = false;
+        settings.start_number = Some(1001);
+        let opaque = engine
+            .build_image_sequence_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &audio_info,
+                &settings,
+            )
+            .expect("image sequence args should build")
+            .join(" ");
+        assert!(!opaque.contains("black@0"), "{opaque}");
+        assert!(opaque.contains("format=yuv444p10le"), "{opaque}");
+        assert!(opaque.contains("-start_number 1001"));
     }
 
-    /// Builds an audio-only export of a 4s voiceover at 0s plus `tail_track`,
-    /// returning the joined arguments so the padding target can be read off.
-    fn audio_only_args_with_tail(tail_track: Track, tail_asset: Option<Asset>) -> String {
-        use crate::core::assets::AudioInfo;
-        use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
-        use crate::core::timeline::{Clip, SequenceFormat};
+    #[test]
+    fn test_animated_gif_args_build_the_palette_inside_the_trimmed_range() {
+        use crate::core::assets::VideoInfo;
+        use crate::core::ffmpeg::FFmpegInfo;
+        use crate::core::render::{AnimatedImageFormat, AnimationCandidate, GifDither};
 
-        let mut sequence = Sequence::new("Audio Tail", SequenceFormat::youtube_1080());
-        let mut audio_track = Track::new_audio("Audio 1");
-        audio_track.add_clip(
-            Clip::new("voiceover")
-                .with_source_range(0.0, 4.0)
-                .place_at(0.0),
+        let mut sequence = Sequence::new(
+            "Loop",
+            crate::core::timeline::SequenceFormat::youtube_1080(),
         );
-        sequence.add_track(audio_track);
-        sequence.add_track(tail_track);
-
-        let voiceover_path = create_temp_media_file("audio_tail_case.wav");
-        let mut voiceover =
-            Asset::new_audio("audio_tail_case.wav", &voiceover_path, AudioInfo::default())
-                .with_duration(4.0)
-                .with_file_size(1_000_000);
-        voiceover.id = "voiceover".to_string();
+        let mut video_track = Track::new_video("V1");
+        video_track.add_clip(Clip::new("video_asset").with_source_range(0.0, 6.0));
+        sequence.add_track(video_track);
 
-        let mut assets = std::collections::HashMap::new();
-        let mut audio_info_map = std::collections::HashMap::new();
-        audio_info_map.insert(
-            "voiceover".to_string(),
+        let video_path = create_temp_media_file("animated_source.mp4");
+        let mut video_asset =
+            Asset::new_video("animated_source.mp4", &video_path, VideoInfo::default())
+                .with_duration(6.0)
+                .with_file_size(6_000_000);
+        video_asset.id = "video_asset".to_string();
+        let mut assets = HashMap::new();
+        assets.insert(video_asset.id.clone(), video_asset);
+        let mut audio_info = HashMap::new();
+        audio_info.insert(
+            "video_asset".to_string(),
             AssetAudioInfo {
                 has_audio: true,
                 ..AssetAudioInfo::default()
             },
         );
-        if let Some(asset) = tail_asset {
-            audio_info_map.insert(
-                asset.id.clone(),
-                AssetAudioInfo {
-                    has_audio: true,
-                    ..AssetAudioInfo::default()
-                },
-            );
-            assets.insert(asset.id.clone(), asset);
-        }
-        assets.insert(voiceover.id.clone(), voiceover);
+
+        let mut settings = AnimatedImageExportSettings::new(
+            PathBuf::from("/tmp/loop.gif"),
+            AnimatedImageFormat::Gif,
+        );
+        settings.dither = GifDither::FloydSteinberg;
+        settings.max_colors = 128;
+        settings.start_time = Some(1.0);
+        settings.end_time = Some(4.0);
+        settings.validate().unwrap();
+        let candidate = settings.initial_candidate(&sequence);
+        assert_eq!(
+            candidate,
+            AnimationCandidate {
+                fps: 15.0,
+                width: 480
+            }
+        );
 
         let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
             ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
@@ -11224,26 +14278,93 @@ mod tests {
             is_bundled: false,
             source: crate::core::ffmpeg::FFmpegSource::System,
         }));
-        let settings = AudioExportSettings {
-            format: AudioExportFormat::Wav,
-            output_path: PathBuf::from("/tmp/audio-tail-case.wav"),
-            bitrate: None,
-            sample_rate: None,
-            start_time: None,
-            end_time: None,
-        }
-        .to_export_settings();
-
-        engine
-            .build_audio_only_filter_args_with_audio_info(
+        let args = engine
+            .build_animated_image_filter_args_with_audio_info(
                 &sequence,
                 &assets,
-                &std::collections::HashMap::new(),
-                &audio_info_map,
+                &audio_info,
                 &settings,
+                &candidate,
             )
-            .expect("audio-only export args should build")
-            .join(" ")
+            .expect("animated image args should build");
+        let args_str = args.join(" ");
+
+        assert!(args_str.contains("format=yuv444p"), "{args_str}");
+        assert!(
+            args_str.contains(
+                "trim=start=1:end=4,setpts=PTS-STARTPTS,fps=15,scale=480:-2:flags=lanczos,split"
+            ),
+            "{args_str}"
+        );
+        assert!(args_str.contains("palettegen=max_colors=128"), "{args_str}");
+        assert!(
+            args_str.contains("paletteuse=dither=floyd_steinberg"),
+            "{args_str}"
+        );
+        assert!(args_str.contains("anullsink"), "{args_str}");
+        // The range is cut in the graph, so the output side has no seek.
+        assert!(!args.iter().any(|arg| arg == "-ss" || arg == "-t"));
+        assert!(args_str.contains("-map [animout] -an -c:v gif -loop 0 -f gif -y"));
+        assert_eq!(args.last().unwrap(), "/tmp/loop.gif");
+    }
+
+    #[test]
+    fn test_image_sequence_asset_is_read_through_image2_at_its_rate() {
+        use crate::core::assets::{ImageSequenceInfo, VideoInfo};
+
+        let mut sequence = Sequence::new(
+            "Comp",
+            crate::core::timeline::SequenceFormat::youtube_1080(),
+        );
+        let mut video_track = Track::new_video("V1");
+        video_track.add_clip(Clip::new("plate_asset").with_source_range(0.0, 2.0));
+        sequence.add_track(video_track);
+
+        let first_frame = create_temp_media_file("shot_1001.exr");
+        let mut plate = Asset::new_image_sequence(
+            "shot_%04d.exr",
+            &first_frame,
+            VideoInfo {
+                fps: crate::core::Ratio::new(25, 1),
+                ..VideoInfo::default()
+            },
+            ImageSequenceInfo {
+                pattern: "shot_%04d.exr".to_string(),
+                start_number: 1001,
+                frame_count: 50,
+            },
+        );
+        plate.id = "plate_asset".to_string();
+        let mut assets = HashMap::new();
+        assets.insert(plate.id.clone(), plate.clone());
+        let mut audio_info = HashMap::new();
+        audio_info.insert(plate.id.clone(), AssetAudioInfo::from_asset(&plate));
+
+        let args = build_complex_filter_args_with_audio_info(
+            &sequence,
+            &assets,
+            &HashMap::new(),
+            &audio_info,
+            &ExportSettings::default(),
+        )
+        .expect("sequence args should build");
+        let args_str = args.join(" ");
+
+        let pattern = Path::new(&first_frame)
+            .with_file_name("shot_%04d.exr")
+            .to_string_lossy()
+            .to_string();
+        assert!(
+            args_str.contains(&format!(
+                "-f image2 -framerate 25/1 -start_number 1001 -i {pattern}"
+            )),
+            "{args_str}"
+        );
+        assert!(
+            !args_str.contains(&format!("-i {first_frame}")),
+            "{args_str}"
+        );
+        assert!(!args_str.contains(":a]atrim"), "{args_str}");
     }
 
     /// Feature: audio-only export padding
@@ -11323,45 +14444,264 @@ mod tests {
         let args_str = audio_only_args_with_tail(tail_track, Some(muted_asset));
 
         assert!(
-            args_str.contains("apad=whole_dur=10"),
-            "a muted clip contributes silence for its span, got: {args_str}"
+            args_str.contains("apad=whole_dur=10"),
+            "a muted clip contributes silence for its span, got: {args_str}"
+        );
+    }
+
+    /// Feature: audio-only export padding
+    /// Scenario: both master-audio branches, and targets that cannot be padded
+    #[test]
+    fn test_master_audio_output_pads_every_branch_and_rejects_invalid_targets() {
+        let mut single = String::from("[0:a]anull[a0]");
+        let label = append_master_audio_output(&mut single, &["[a0]".to_string()], 0.0, 12.5)
+            .expect("a single stream produces a master output");
+        assert_eq!(label, "[outa_base]");
+        assert!(single.contains("apad=whole_dur=12.5"), "{single}");
+
+        let mut mixed = String::from("[0:a]anull[a0]");
+        append_master_audio_output(
+            &mut mixed,
+            &["[a0]".to_string(), "[a1]".to_string()],
+            0.0,
+            12.5,
+        )
+        .expect("a mix produces a master output");
+        assert!(mixed.contains("amix=inputs=2"), "{mixed}");
+        assert!(mixed.contains("apad=whole_dur=12.5"), "{mixed}");
+
+        // A target that is zero, negative, or not a number is not a duration
+        // FFmpeg can pad to, so no padding is emitted at all.
+        for target in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            let mut graph = String::from("[0:a]anull[a0]");
+            append_master_audio_output(&mut graph, &["[a0]".to_string()], 0.0, target)
+                .expect("a single stream produces a master output");
+            assert!(!graph.contains("apad"), "target {target}: {graph}");
+        }
+
+        assert!(
+            append_master_audio_output(&mut String::new(), &[], 0.0, 5.0).is_none(),
+            "no audio streams means no master audio output"
+        );
+    }
+
+    /// Feature: Surround audio export
+    /// Scenario: clips pick their channels, tracks pan into 5.1, the mix is pinned to 5.1
+    #[test]
+    fn test_surround_export_maps_channels_pans_tracks_and_pins_the_layout() {
+        use crate::core::assets::AudioInfo;
+        use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
+        use crate::core::timeline::{Clip, SequenceFormat, SurroundPan};
+
+        let mut sequence = Sequence::new("Surround", SequenceFormat::youtube_1080());
+        let mut dx = Track::new_audio("DX");
+        let mut boom = Clip::new("camera")
+            .with_source_range(0.0, 4.0)
+            .place_at(0.0);
+        boom.audio.channel_map = vec![1];
+        dx.add_clip(boom);
+        let mut fx = Track::new_audio("FX");
+        fx.surround_pan = Some(SurroundPan {
+            x: 0.0,
+            y: -1.0,
+            lfe: 0.0,
+        });
+        fx.add_clip(
+            Clip::new("camera")
+                .with_source_range(0.0, 4.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(dx);
+        sequence.add_track(fx);
+
+        let path = create_temp_media_file("surround_camera.mov");
+        let mut asset = Asset::new_audio(
+            "camera",
+            &path,
+            AudioInfo {
+                channels: 4,
+                ..AudioInfo::default()
+            },
+        )
+        .with_duration(10.0)
+        .with_file_size(1_000_000);
+        asset.id = "camera".to_string();
+        let assets = HashMap::from([(asset.id.clone(), asset)]);
+        let audio_info = HashMap::from([(
+            "camera".to_string(),
+            AssetAudioInfo {
+                has_audio: true,
+                ..AssetAudioInfo::default()
+            },
+        )]);
+
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+        let mut settings = AudioExportSettings {
+            format: AudioExportFormat::Wav,
+            output_path: PathBuf::from("/tmp/surround.wav"),
+            bitrate: None,
+            sample_rate: None,
+            start_time: None,
+            end_time: None,
+        }
+        .to_export_settings();
+        settings.audio_channel_layout = AudioChannelLayout::Surround51;
+
+        let args = engine
+            .build_audio_only_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &HashMap::new(),
+                &audio_info,
+                &settings,
+            )
+            .expect("surround export args should build");
+        let args_str = args.join(" ");
+
+        assert!(
+            args_str.contains("[0:a]pan=mono|c0=c1,atrim=start=0:end=4"),
+            "{args_str}"
+        );
+        assert!(
+            args_str.contains("[a0]aresample,aformat=channel_layouts=5.1[alayout0]"),
+            "{args_str}"
+        );
+        assert!(
+            args_str.contains(
+                "[a1]aformat=channel_layouts=mono,pan=5.1|BL=0.707107*c0|BR=0.707107*c0[alayout1]"
+            ),
+            "{args_str}"
         );
+        assert!(
+            args_str.contains("aformat=channel_layouts=5.1[outa_layout]"),
+            "{args_str}"
+        );
+        assert!(args_str.contains("-map [outa_layout]"), "{args_str}");
+
+        // A plain stereo export leaves clips in the layout they arrive in.
+        settings.audio_channel_layout = AudioChannelLayout::Stereo;
+        sequence.tracks[1].surround_pan = None;
+        let args_str = engine
+            .build_audio_only_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &HashMap::new(),
+                &audio_info,
+                &settings,
+            )
+            .unwrap()
+            .join(" ");
+        assert!(!args_str.contains("alayout"), "{args_str}");
+        assert!(!args_str.contains("outa_layout"), "{args_str}");
     }
 
-    /// Feature: audio-only export padding
-    /// Scenario: both master-audio branches, and targets that cannot be padded
+    /// Feature: Surround audio export
+    /// Scenario: surround output needs AAC or PCM, matrix downmixes need stereo
     #[test]
-    fn test_master_audio_output_pads_every_branch_and_rejects_invalid_targets() {
-        let mut single = String::from("[0:a]anull[a0]");
-        let label = append_master_audio_output(&mut single, &["[a0]".to_string()], 0.0, 12.5)
-            .expect("a single stream produces a master output");
-        assert_eq!(label, "[outa_base]");
-        assert!(single.contains("apad=whole_dur=12.5"), "{single}");
+    fn video_export_request_should_validate_audio_layout() {
+        let surround_mp3 = VideoExportRequest {
+            audio_codec: AudioCodec::Mp3,
+            audio_channel_layout: AudioChannelLayout::Surround51,
+            ..ExportSettings::request_from_preset(ExportPreset::Youtube1080p)
+        };
+        let error =
+            validate_video_export_request(&surround_mp3, Path::new("/tmp/mix.mp4")).unwrap_err();
+        assert!(error.to_string().contains("requires AAC or PCM"), "{error}");
 
-        let mut mixed = String::from("[0:a]anull[a0]");
-        append_master_audio_output(
-            &mut mixed,
-            &["[a0]".to_string(), "[a1]".to_string()],
-            0.0,
-            12.5,
-        )
-        .expect("a mix produces a master output");
-        assert!(mixed.contains("amix=inputs=2"), "{mixed}");
-        assert!(mixed.contains("apad=whole_dur=12.5"), "{mixed}");
+        let matrix_71 = VideoExportRequest {
+            audio_channel_layout: AudioChannelLayout::Surround71,
+            audio_downmix: AudioDownmix::ProLogicIi,
+            ..ExportSettings::request_from_preset(ExportPreset::Youtube1080p)
+        };
+        assert!(validate_video_export_request(&matrix_71, Path::new("/tmp/mix.mp4")).is_err());
 
-        // A target that is zero, negative, or not a number is not a duration
-        // FFmpeg can pad to, so no padding is emitted at all.
-        for target in [0.0, -1.0, f64::NAN, f64::INFINITY] {
-            let mut graph = String::from("[0:a]anull[a0]");
-            append_master_audio_output(&mut graph, &["[a0]".to_string()], 0.0, target)
-                .expect("a single stream produces a master output");
-            assert!(!graph.contains("apad"), "target {target}: {graph}");
+        let surround_aac = VideoExportRequest {
+            audio_channel_layout: AudioChannelLayout::Surround71,
+            audio_downmix: AudioDownmix::WithLfe,
+            ..ExportSettings::request_from_preset(ExportPreset::Youtube1080p)
+        };
+        let settings = ExportSettings::from_video_request(
+            &surround_aac,
+            PathBuf::from("/tmp/mix.mp4"),
+            None,
+            None,
+        )
+        .expect("7.1 AAC request should be valid");
+        assert_eq!(
+            settings.audio_channel_layout,
+            AudioChannelLayout::Surround71
+        );
+        assert_eq!(settings.audio_downmix, AudioDownmix::WithLfe);
+    }
+
+    /// Feature: audio buses
+    /// Scenario: streams are summed per bus, processed, and fed up the hierarchy
+    #[test]
+    fn test_audio_bus_mix_routes_streams_through_the_bus_hierarchy() {
+        use crate::core::timeline::{AudioBus, SequenceFormat};
+
+        let mut sequence = Sequence::new("Buses", SequenceFormat::youtube_1080());
+        let mut stems = AudioBus::new("Stems");
+        stems.volume_db = -6.0;
+        let mut dialog = AudioBus::new("Dialog").with_output(&stems.id);
+        let compressor = Effect::new(EffectType::Compressor);
+        dialog.effects = vec![compressor.id.clone()];
+        let music = AudioBus::new("Music");
+        let mut dx = Track::new_audio("DX");
+        dx.output_bus_id = Some(dialog.id.clone());
+        let mut boom = Track::new_audio("Boom");
+        boom.output_bus_id = Some(dialog.id.clone());
+        let mut mx = Track::new_audio("MX");
+        mx.output_bus_id = Some(music.id.clone());
+        let fx = Track::new_audio("FX");
+        let track_ids: Vec<String> = [&dx, &boom, &mx, &fx]
+            .iter()
+            .map(|track| track.id.clone())
+            .collect();
+        sequence.audio_buses = vec![stems, dialog, music];
+        for track in [dx, boom, mx, fx] {
+            sequence.add_track(track);
         }
+        let effects = HashMap::from([(compressor.id.clone(), compressor)]);
 
-        assert!(
-            append_master_audio_output(&mut String::new(), &[], 0.0, 5.0).is_none(),
-            "no audio streams means no master audio output"
-        );
+        let mut graph = String::from("[0:a]anull[a0]");
+        let streams = vec![
+            (track_ids[0].as_str(), "[a0]".to_string()),
+            (track_ids[1].as_str(), "[a1]".to_string()),
+            (track_ids[3].as_str(), "[a3]".to_string()),
+        ];
+        let master = append_audio_bus_mix(&mut graph, &sequence, &effects, streams).unwrap();
+
+        // Dialog mixes first, compresses, then feeds Stems, which is turned down.
+        assert!(graph.contains(
+            ";[a0][a1]amix=inputs=2:duration=longest:dropout_transition=0:normalize=0[bus0mix]"
+        ));
+        assert!(graph.contains("[bus0mix]acompressor="), "{graph}");
+        assert!(graph.contains("[bus0fx];[bus0fx]anull[bus1mix];[bus1mix]volume=0.501187[bus1]"));
+        // Music has nothing playing into it and emits nothing.
+        assert!(!graph.contains("bus2"), "{graph}");
+        assert_eq!(master, vec!["[a3]".to_string(), "[bus1]".to_string()]);
+
+        let mut untouched = String::new();
+        let plain = Sequence::new("Plain", SequenceFormat::youtube_1080());
+        let master = append_audio_bus_mix(
+            &mut untouched,
+            &plain,
+            &effects,
+            vec![("any", "[a0]".to_string())],
+        )
+        .unwrap();
+        assert!(untouched.is_empty());
+        assert_eq!(master, vec!["[a0]".to_string()]);
+
+        sequence.audio_buses[0].output_bus_id = Some(sequence.audio_buses[1].id.clone());
+        assert!(append_audio_bus_mix(&mut String::new(), &sequence, &effects, Vec::new()).is_err());
     }
 
     #[test]
@@ -15112,6 +18452,386 @@ mod tests {
             ..Default::default()
         };
         assert_eq!(output_video_pixel_format(&prores), "yuv422p10le");
+
+        let prores_4444 = ExportSettings {
+            prores_profile: ProResProfile::P4444,
+            ..prores
+        };
+        assert_eq!(output_video_pixel_format(&prores_4444), "yuv444p10le");
+
+        let dnxhr_hq = ExportSettings {
+            video_codec: VideoCodec::Dnxhr,
+            dnxhr_profile: DnxhrProfile::Hq,
+            ..Default::default()
+        };
+        assert_eq!(output_video_pixel_format(&dnxhr_hq), "yuv422p");
+    }
+
+    #[test]
+    fn test_mastering_presets_pick_intermediate_profiles_and_containers() {
+        let prores = ExportSettings::from_preset(ExportPreset::ProRes, PathBuf::from("/tmp/m.mov"));
+        assert_eq!(prores.video_profile_args(), vec!["-profile:v", "3"]);
+        assert_eq!(prores.audio_encoder_name(), "pcm_s16le");
+
+        let dnxhr =
+            ExportSettings::from_preset(ExportPreset::DnxhrMxf, PathBuf::from("/tmp/m.mxf"));
+        assert_eq!(dnxhr.video_codec, VideoCodec::Dnxhr);
+        assert_eq!(dnxhr.video_profile_args(), vec!["-profile:v", "dnxhr_hqx"]);
+        assert_eq!(dnxhr.audio_encoder_name(), "pcm_s24le");
+        assert!(validate_export_settings_options(&dnxhr).is_empty());
+        assert_eq!(
+            ExportSettings::request_from_preset(ExportPreset::DnxhrMxf).container,
+            ContainerFormat::Mxf
+        );
+        assert!(ExportSettings::default().video_profile_args().is_empty());
+    }
+
+    #[test]
+    fn test_mxf_accepts_only_intermediate_video_with_pcm_audio() {
+        let request = ExportSettings::request_from_preset(ExportPreset::DnxhrMxf);
+        let mxf = Path::new("/tmp/master.mxf");
+        assert!(validate_video_export_request(&request, mxf).is_ok());
+
+        let prores_mxf = VideoExportRequest {
+            video_codec: VideoCodec::ProRes,
+            ..request.clone()
+        };
+        assert!(validate_video_export_request(&prores_mxf, mxf).is_ok());
+
+        let dnxhr_mov = VideoExportRequest {
+            container: ContainerFormat::Mov,
+            ..request.clone()
+        };
+        assert!(validate_video_export_request(&dnxhr_mov, Path::new("/tmp/master.mov")).is_ok());
+
+        let h264_mxf = VideoExportRequest {
+            video_codec: VideoCodec::H264,
+            ..request.clone()
+        };
+        assert!(validate_video_export_request(&h264_mxf, mxf).is_err());
+
+        let aac_mxf = VideoExportRequest {
+            audio_codec: AudioCodec::Aac,
+            ..request.clone()
+        };
+        assert!(validate_video_export_request(&aac_mxf, mxf).is_err());
+
+        let crf_dnxhr = VideoExportRequest {
+            crf: Some(20),
+            ..request
+        };
+        assert!(validate_video_export_request(&crf_dnxhr, mxf).is_err());
+    }
+
+    /// Feature: Mastering export
+    /// Scenario: an MXF master carries the profile, start timecode and one PCM track per channel
+    #[test]
+    fn test_mxf_master_splits_audio_tracks_and_writes_start_timecode() {
+        use crate::core::assets::VideoInfo;
+        use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut sequence = Sequence::new("Master", SequenceFormat::youtube_1080());
+        sequence.format.fps = crate::core::Ratio::new(25, 1);
+        sequence.start_timecode = Some("10:00:00:00".to_string());
+        let mut video_track = Track::new_video("V1");
+        video_track.add_clip(
+            Clip::new("camera")
+                .with_source_range(0.0, 20.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(video_track);
+        let mut audio_track = Track::new_audio("A1");
+        audio_track.add_clip(
+            Clip::new("camera")
+                .with_source_range(0.0, 20.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(audio_track);
+
+        let path = create_temp_media_file("mxf_master_camera.mov");
+        let mut asset = Asset::new_video("camera", &path, VideoInfo::default())
+            .with_duration(20.0)
+            .with_file_size(1_000_000);
+        asset.id = "camera".to_string();
+        let assets = HashMap::from([(asset.id.clone(), asset)]);
+        let audio_info = HashMap::from([(
+            "camera".to_string(),
+            AssetAudioInfo {
+                has_audio: true,
+                ..AssetAudioInfo::default()
+            },
+        )]);
+
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+        let mut settings =
+            ExportSettings::from_preset(ExportPreset::DnxhrMxf, PathBuf::from("/tmp/master.mxf"));
+        settings.start_time = Some(10.0);
+
+        let args = engine
+            .build_complex_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &HashMap::new(),
+                &audio_info,
+                &settings,
+            )
+            .expect("MXF master args should build");
+        let args_str = args.join(" ");
+
+        assert!(args_str.contains("-c:v dnxhd"), "{args_str}");
+        assert!(args_str.contains("-profile:v dnxhr_hqx"), "{args_str}");
+        assert!(args_str.contains("yuv422p10le"), "{args_str}");
+        assert!(args_str.contains("-c:a pcm_s24le"), "{args_str}");
+        assert!(
+            args_str.contains(
+                "aformat=sample_rates=48000:channel_layouts=stereo,channelsplit=channel_layout=stereo[mxfa0][mxfa1]"
+            ),
+            "{args_str}"
+        );
+        assert!(args_str.contains("-map [mxfa0] -map [mxfa1]"), "{args_str}");
+        assert!(args_str.contains("-timecode 10:00:10:00"), "{args_str}");
+
+        // An MP4 delivery has no timecode track and keeps one interleaved stream.
+        let delivery = ExportSettings {
+            output_path: PathBuf::from("/tmp/delivery.mp4"),
+            ..ExportSettings::default()
+        };
+        let args_str = engine
+            .build_complex_filter_args_with_audio_info(
+                &sequence,
+                &assets,
+                &HashMap::new(),
+                &audio_info,
+                &delivery,
+            )
+            .unwrap()
+            .join(" ");
+        assert!(!args_str.contains("-timecode"), "{args_str}");
+        assert!(!args_str.contains("channelsplit"), "{args_str}");
+    }
+
+    #[test]
+    fn test_export_metadata_maps_chapters_tags_and_cover() {
+        use crate::core::assets::VideoInfo;
+        use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut sequence = Sequence::new("Chapters", SequenceFormat::youtube_1080());
+        let mut video_track = Track::new_video("V1");
+        video_track.add_clip(
+            Clip::new("camera")
+                .with_source_range(0.0, 20.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(video_track);
+
+        let path = create_temp_media_file("metadata_camera.mp4");
+        let mut asset = Asset::new_video("camera", &path, VideoInfo::default())
+            .with_duration(20.0)
+            .with_file_size(1_000_000);
+        asset.id = "camera".to_string();
+        let assets = HashMap::from([(asset.id.clone(), asset)]);
+        let audio_info = HashMap::from([("camera".to_string(), AssetAudioInfo::default())]);
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+        let ffmetadata_path = PathBuf::from("/tmp/metadata.txt");
+        let build = |settings: &ExportSettings| {
+            super::super::ffmpeg_plan::build_sequence_ffmpeg_args(
+                super::super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                    engine: &engine,
+                    sequence: &sequence,
+                    assets: &assets,
+                    effects: &HashMap::new(),
+                    audio_info: &audio_info,
+                    settings,
+                    render_plan: None,
+                    ass_text_overlay_path: None,
+                    working_pixel_format: None,
+                    ffmetadata_path: Some(&ffmetadata_path),
+                },
+            )
+            .unwrap()
+            .join(" ")
+        };
+
+        let mp4 = ExportSettings {
+            output_path: PathBuf::from("/tmp/film.mp4"),
+            metadata: Some(ExportMetadata::default().with_cover_image("/tmp/cover.jpg")),
+            ..ExportSettings::default()
+        };
+        let args_str = build(&mp4);
+        assert!(
+            args_str
+                .contains("-f ffmetadata -i /tmp/metadata.txt -i /tmp/cover.jpg -filter_complex"),
+            "{args_str}"
+        );
+        assert!(
+            args_str.contains(
+                "-map_metadata 1 -map_chapters 1 -map 2:v:0 -c:v:1 copy -disposition:v:1 attached_pic"
+            ),
+            "{args_str}"
+        );
+
+        // Matroska attaches the cover instead of muxing it as a stream
+        let mkv = ExportSettings {
+            output_path: PathBuf::from("/tmp/film.mkv"),
+            ..mp4.clone()
+        };
+        let args_str = build(&mkv);
+        assert!(
+            !args_str.contains("/tmp/cover.jpg -filter_complex"),
+            "{args_str}"
+        );
+        assert!(
+            args_str.contains("-attach /tmp/cover.jpg -metadata:s:t:0 mimetype=image/jpeg"),
+            "{args_str}"
+        );
+
+        // Without metadata, nothing is added even when a file is passed
+        let plain = ExportSettings {
+            metadata: None,
+            ..mp4
+        };
+        let args_str = build(&plain);
+        assert!(!args_str.contains("ffmetadata"), "{args_str}");
+        assert!(!args_str.contains("-map_chapters"), "{args_str}");
+    }
+
+    #[test]
+    fn test_export_loudness_normalizes_the_export_range() {
+        use crate::core::assets::VideoInfo;
+        use crate::core::ffmpeg::{FFmpegInfo, FFmpegRunner};
+        use crate::core::render::loudness::LoudnessStandard;
+        use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+        let mut sequence = Sequence::new("Loudness", SequenceFormat::youtube_1080());
+        let mut video_track = Track::new_video("V1");
+        video_track.add_clip(
+            Clip::new("camera")
+                .with_source_range(0.0, 20.0)
+                .place_at(0.0),
+        );
+        sequence.add_track(video_track);
+
+        let path = create_temp_media_file("loudness_camera.mp4");
+        let mut asset = Asset::new_video("camera", &path, VideoInfo::default())
+            .with_duration(20.0)
+            .with_file_size(1_000_000);
+        asset.id = "camera".to_string();
+        let assets = HashMap::from([(asset.id.clone(), asset)]);
+        let audio_info = HashMap::from([(
+            "camera".to_string(),
+            AssetAudioInfo {
+                has_audio: true,
+                ..AssetAudioInfo::default()
+            },
+        )]);
+        let engine = ExportEngine::new(FFmpegRunner::new(FFmpegInfo {
+            ffmpeg_path: PathBuf::from("/usr/bin/ffmpeg"),
+            ffprobe_path: PathBuf::from("/usr/bin/ffprobe"),
+            version: "test".to_string(),
+            is_bundled: false,
+            source: crate::core::ffmpeg::FFmpegSource::System,
+        }));
+
+        let target = LoudnessNormalization::for_standard(LoudnessStandard::EbuR128);
+        let measured = target
+            .clone()
+            .with_measurement(LoudnormStats {
+                input_i: -30.5,
+                input_tp: -9.2,
+                input_lra: 6.1,
+                input_thresh: -41.0,
+                output_i: -23.1,
+                output_tp: -1.8,
+                output_lra: 5.9,
+                output_thresh: -33.4,
+                normalization_type: "dynamic".to_string(),
+                target_offset: 0.1,
+            })
+            .unwrap();
+        let settings = ExportSettings {
+            output_path: PathBuf::from("/tmp/broadcast.mp4"),
+            start_time: Some(5.0),
+            end_time: Some(15.0),
+            loudness: Some(measured),
+            ..ExportSettings::default()
+        };
+
+        // The encode applies the measured target to the range, then shifts
+        // the audio back so the output seek still lands on it
+        let args_str = super::super::ffmpeg_plan::build_sequence_ffmpeg_args(
+            super::super::ffmpeg_plan::SequenceFfmpegBuildContext {
+                engine: &engine,
+                sequence: &sequence,
+                assets: &assets,
+                effects: &HashMap::new(),
+                audio_info: &audio_info,
+                settings: &settings,
+                render_plan: None,
+                ass_text_overlay_path: None,
+                working_pixel_format: None,
+                ffmetadata_path: None,
+            },
+        )
+        .unwrap()
+        .join(" ");
+        assert!(
+            args_str.contains(
+                "atrim=start=5:end=15,asetpts=PTS-STARTPTS,loudnorm=I=-23:TP=-1:LRA=20:\
+                 measured_I=-30.5:measured_TP=-9.2:measured_LRA=6.1:measured_thresh=-41:\
+                 offset=0.1:linear=true:print_format=json,aresample=48000,\
+                 asetpts=PTS+5/TB[outa_loudness]"
+            ),
+            "{args_str}"
+        );
+        assert!(args_str.contains("-map [outa_loudness]"), "{args_str}");
+
+        // The measurement pass runs the same mix through the unmeasured filter
+        let measure_settings = ExportSettings {
+            output_path: PathBuf::from("/tmp/loudness.null"),
+            loudness: Some(target),
+            ..settings.clone()
+        };
+        let args_str = super::super::ffmpeg_plan::build_audio_only_ffmpeg_args(
+            super::super::ffmpeg_plan::AudioOnlyFfmpegBuildContext {
+                engine: &engine,
+                sequence: &sequence,
+                assets: &assets,
+                effects: &HashMap::new(),
+                audio_info: &audio_info,
+                settings: &measure_settings,
+                render_plan: None,
+            },
+        )
+        .unwrap()
+        .join(" ");
+        assert!(
+            args_str.contains("loudnorm=I=-23:TP=-1:LRA=20:print_format=json,aresample=48000"),
+            "{args_str}"
+        );
+        assert!(!args_str.contains("linear=true"), "{args_str}");
+
+        // Copied audio cannot be normalized
+        let copied = ExportSettings {
+            audio_codec: AudioCodec::Copy,
+            ..settings
+        };
+        assert!(validate_export_settings_options(&copied)
+            .iter()
+            .any(|error| error.contains("re-encoded")));
     }
 
     #[test]
@@ -15316,6 +19036,7 @@ mod tests {
             ExportPreset::Twitter,
             ExportPreset::Instagram,
             ExportPreset::WebmVp9,
+            ExportPreset::WebmAv1,
             ExportPreset::ProRes,
             ExportPreset::Custom,
         ];
@@ -8,28 +8,32 @@
 use std::{collections::HashMap, path::Path};
 
 use crate::core::{
-    assets::Asset,
+    assets::{Asset, AssetKind},
     effects::Effect,
     fs::validate_local_input_path,
-    timeline::{Sequence, TrackKind},
+    interchange::models::Timecode,
+    timeline::{track_audio_reaches_master, Clip, ClipRange, Sequence, Track, TrackKind},
+    TrackId,
 };
 
 use super::{
     export::{
-        append_ass_text_overlay, append_black_video_gap, append_drawtext_text_overlays,
-        append_master_audio_output, append_output_time_range_args, append_timeline_video_output,
-        append_video_stream_normalization, append_video_transform_composition,
-        apply_audio_mix_settings, asset_has_playable_audio, build_audio_trim_filter,
-        build_video_trim_filter, clip_audio_is_suppressed_by_companion,
+        append_ass_text_overlay, append_audio_bus_mix, append_audio_stream_split,
+        append_black_video_gap, append_drawtext_text_overlays, append_loudness_normalization,
+        append_master_audio_output, append_output_channel_layout, append_output_time_range_args,
+        append_stem_audio_output, append_timeline_video_output, append_video_stream_normalization,
+        append_video_transform_composition, apply_audio_mix_settings, asset_has_playable_audio,
+        build_audio_trim_filter, build_video_trim_filter, clip_audio_is_suppressed_by_companion,
         clip_needs_transform_composition, collect_audio_companion_keys,
-        collect_drawtext_text_overlays, collect_enabled_clips_sorted, effective_source_dimensions,
-        generated_text_visual_end_sec, hdr_metadata_for_asset, is_text_clip,
-        output_video_dimensions, output_video_fps, output_video_pixel_format,
-        resolve_asset_source_dimensions, resolve_asset_source_duration, resolve_trim_source_kind,
-        seed_source_dimension_cache, seed_source_duration_cache, unmeasurable_effect_message,
-        AssetAudioInfo, ExportEngine, ExportError, ExportSettings, SourceFrameCountCache,
-        VideoCodec, VideoTimelineSegment, TIMELINE_EPSILON_SEC,
+        collect_drawtext_text_overlays, collect_enabled_clips_sorted, container_from_output_path,
+        effective_source_dimensions, format_speed_number, generated_text_visual_end_sec,
+        hdr_metadata_for_asset, is_text_clip, output_video_dimensions, output_video_fps,
+        output_video_pixel_format, resolve_asset_source_dimensions, resolve_asset_source_duration,
+        resolve_trim_source_kind, seed_source_dimension_cache, seed_source_duration_cache,
+        unmeasurable_effect_message, AssetAudioInfo, ExportEngine, ExportError, ExportSettings,
+        SourceFrameCountCache, VideoCodec, VideoTimelineSegment, TIMELINE_EPSILON_SEC,
     },
+    metadata::{append_metadata_inputs, append_metadata_output_args},
     transform_layout::compute_clip_transform_layout,
     transition_stitch::{
         clip_stream_frames, plan_sequence_transitions, stitch_transition_groups, ClipHandles,
@@ -46,6 +50,11 @@ pub(super) struct SequenceFfmpegBuildContext<'a> {
     pub settings: &'a ExportSettings,
     pub render_plan: Option<&'a RenderPlan>,
     pub ass_text_overlay_path: Option<&'a Path>,
+    /// Pixel format to composite in instead of the one the video codec
+    /// implies; an alpha-carrying format keeps the empty canvas transparent
+    pub working_pixel_format: Option<&'static str>,
+    /// `FFMETADATA1` file with the tags and chapters of `settings.metadata`
+    pub ffmetadata_path: Option<&'a Path>,
 }
 
 pub(super) struct AudioOnlyFfmpegBuildContext<'a> {
@@ -58,12 +67,440 @@ pub(super) struct AudioOnlyFfmpegBuildContext<'a> {
     pub render_plan: Option<&'a RenderPlan>,
 }
 
+/// The sequence's composited picture and mixed sound, ready for outputs to be
+/// attached.
+struct SequenceFilterGraph {
+    /// Hardware device and input arguments, in order
+    args: Vec<String>,
+    filter_complex: String,
+    video_label: String,
+    audio_label: Option<String>,
+    video_encoder: String,
+}
+
 pub(super) fn build_sequence_ffmpeg_args(
     ctx: SequenceFfmpegBuildContext<'_>,
 ) -> Result<Vec<String>, ExportError> {
+    let SequenceFilterGraph {
+        mut args,
+        mut filter_complex,
+        video_label,
+        audio_label: final_audio_label,
+        video_encoder,
+    } = build_sequence_filter_graph(&ctx)?;
+
+    let output_pixel_format = output_video_pixel_format(ctx.settings);
+    let final_video_label =
+        match super::hardware::hardware_upload_filter(&video_encoder, output_pixel_format) {
+            Some(upload) => {
+                filter_complex.push_str(&format!(";{}{}[outv_hw]", video_label, upload));
+                "[outv_hw]".to_string()
+            }
+            None => video_label,
+        };
+
+    let audio_labels = match final_audio_label {
+        Some(label) if ctx.settings.is_mxf_output() => {
+            split_mxf_audio_tracks(&mut filter_complex, &label, ctx.settings)
+        }
+        Some(label) => vec![label],
+        None => Vec::new(),
+    };
+
+    let container = container_from_output_path(&ctx.settings.output_path).ok();
+    let metadata_inputs = container
+        .as_ref()
+        .map(|container| {
+            append_metadata_inputs(
+                &mut args,
+                ctx.settings.metadata.as_ref(),
+                ctx.ffmetadata_path,
+                container,
+            )
+        })
+        .unwrap_or_default();
+
+    args.push("-filter_complex".to_string());
+    args.push(filter_complex);
+    args.push("-map".to_string());
+    args.push(final_video_label);
+
+    for audio_label in &audio_labels {
+        args.push("-map".to_string());
+        args.push(audio_label.clone());
+    }
+
+    args.push("-c:v".to_string());
+    args.push(video_encoder.clone());
+
+    if !audio_labels.is_empty() {
+        args.push("-c:a".to_string());
+        args.push(ctx.settings.audio_encoder_name().to_string());
+    }
+
+    if let Some(ref bitrate) = ctx.settings.video_bitrate {
+        args.push("-b:v".to_string());
+        args.push(bitrate.clone());
+    }
+
+    if let Some(ref bitrate) = ctx.settings.audio_bitrate {
+        if !audio_labels.is_empty() {
+            args.push("-b:a".to_string());
+            args.push(bitrate.clone());
+        }
+    }
+
+    if let Some(crf) = ctx.settings.crf {
+        if matches!(
+            ctx.settings.video_codec,
+            VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Vp9 | VideoCodec::Av1
+        ) {
+            args.extend(super::hardware::resolve_quality_args(&video_encoder, crf));
+        }
+    }
+
+    args.extend(ctx.settings.encoder_speed_args(&video_encoder));
+    args.extend(ctx.settings.video_profile_args());
+
+    args.extend(ctx.settings.hdr_args());
+    if let Some(container) = &container {
+        append_metadata_output_args(
+            &mut args,
+            ctx.settings.metadata.as_ref(),
+            metadata_inputs,
+            container,
+            1,
+        );
+    }
+    if let Some(timecode) = output_start_timecode(ctx.sequence, ctx.settings) {
+        args.push("-timecode".to_string());
+        args.push(timecode);
+    }
+    append_output_time_range_args(&mut args, ctx.settings.start_time, ctx.settings.end_time);
+    args.push("-y".to_string());
+    args.push(ctx.settings.output_path.to_string_lossy().to_string());
+
+    Ok(args)
+}
+
+/// Splits the mixed audio into one mono stream per channel at 48 kHz, which
+/// is how MXF OP1a masters carry sound, and returns the stream labels in
+/// channel order.
+fn split_mxf_audio_tracks(
+    filter_complex: &mut String,
+    audio_label: &str,
+    settings: &ExportSettings,
+) -> Vec<String> {
+    let layout = settings.audio_channel_layout.ffmpeg_name();
+    let labels: Vec<String> = (0..settings.audio_channel_layout.channel_count())
+        .map(|channel| format!("[mxfa{}]", channel))
+        .collect();
+
+    filter_complex.push_str(&format!(
+        ";{}aformat=sample_rates=48000:channel_layouts={},channelsplit=channel_layout={}{}",
+        audio_label,
+        layout,
+        layout,
+        labels.concat()
+    ));
+    labels
+}
+
+/// Timecode of the first exported frame for containers with a timecode
+/// track: the sequence start timecode, advanced past any range start.
+pub(super) fn output_start_timecode(
+    sequence: &Sequence,
+    settings: &ExportSettings,
+) -> Option<String> {
+    if !matches!(
+        settings.output_path.extension().and_then(|ext| ext.to_str()),
+        Some(ext) if ext.eq_ignore_ascii_case("mov") || ext.eq_ignore_ascii_case("mxf")
+    ) {
+        return None;
+    }
+
+    let start = Timecode::parse(sequence.start_timecode.as_deref()?)?;
+    let fps = &sequence.format.fps;
+    let offset_sec = settings.start_time.unwrap_or(0.0);
+    if !(offset_sec.is_finite() && offset_sec > 0.0) {
+        return Some(start.to_string());
+    }
+
+    if start.drop_frame {
+        return Some(Timecode::from_seconds(start.to_seconds(fps) + offset_sec, fps).to_string());
+    }
+
+    // Non-drop timecode counts every frame at the nominal rate, including at
+    // 29.97 where `from_seconds` would switch to drop-frame.
+    let nominal_fps = (fps.as_f64().round() as u64).max(1);
+    let start_frame = ((start.hours as u64 * 60 + start.minutes as u64) * 60
+        + start.seconds as u64)
+        * nominal_fps
+        + start.frames as u64;
+    let frame = start_frame + (offset_sec * fps.as_f64()).round() as u64;
+    let total_seconds = frame / nominal_fps;
+    Some(
+        Timecode {
+            hours: (total_seconds / 3600) as u32,
+            minutes: (total_seconds / 60 % 60) as u32,
+            seconds: (total_seconds % 60) as u32,
+            frames: (frame % nominal_fps) as u32,
+            drop_frame: false,
+        }
+        .to_string(),
+    )
+}
+
+/// One video rendition of a streaming package.
+pub(super) struct LadderRung {
+    pub width: u32,
+    pub height: u32,
+    pub bitrate_bps: u64,
+}
+
+/// Builds one FFmpeg job packaging the sequence as a streaming ladder, written
+/// by the DASH muxer to `ctx.settings.output_path` with HLS playlists beside it.
+///
+/// The composited picture is split once per rung and scaled, so the timeline
+/// is decoded and composited a single time; the mixed audio is encoded once
+/// and shared by every rung. Keyframes are forced every `segment_duration_sec`
+/// and scene-cut keyframes turned off, so all rungs segment identically.
+pub(super) fn build_streaming_package_ffmpeg_args(
+    ctx: SequenceFfmpegBuildContext<'_>,
+    rungs: &[LadderRung],
+    segment_duration_sec: f64,
+) -> Result<Vec<String>, ExportError> {
+    let SequenceFilterGraph {
+        mut args,
+        mut filter_complex,
+        video_label,
+        audio_label,
+        video_encoder,
+    } = build_sequence_filter_graph(&ctx)?;
+
+    let split_labels: Vec<String> = (0..rungs.len())
+        .map(|index| format!("[ladder{}]", index))
+        .collect();
+    filter_complex.push_str(&format!(
+        ";{}split={}{}",
+        video_label,
+        rungs.len(),
+        split_labels.concat()
+    ));
+    for (index, (rung, label)) in rungs.iter().zip(&split_labels).enumerate() {
+        filter_complex.push_str(&format!(
+            ";{}scale={}:{},setsar=1[ladderv{}]",
+            label, rung.width, rung.height, index
+        ));
+    }
+
+    args.push("-filter_complex".to_string());
+    args.push(filter_complex);
+    for index in 0..rungs.len() {
+        args.push("-map".to_string());
+        args.push(format!("[ladderv{}]", index));
+    }
+    if let Some(audio_label) = audio_label.as_deref() {
+        args.push("-map".to_string());
+        args.push(audio_label.to_string());
+    }
+
+    args.push("-c:v".to_string());
+    args.push(video_encoder.clone());
+    if ctx.settings.video_codec == VideoCodec::H265 {
+        // Apple players only accept HEVC in fMP4 under the `hvc1` sample entry.
+        args.push("-tag:v".to_string());
+        args.push("hvc1".to_string());
+    }
+    for (index, rung) in rungs.iter().enumerate() {
+        args.extend([
+            format!("-b:v:{}", index),
+            rung.bitrate_bps.to_string(),
+            format!("-maxrate:v:{}", index),
+            rung.bitrate_bps.to_string(),
+            format!("-bufsize:v:{}", index),
+            (rung.bitrate_bps * 2).to_string(),
+        ]);
+    }
+    args.push("-force_key_frames".to_string());
+    args.push(format!("expr:gte(t,n_forced*{})", segment_duration_sec));
+    match video_encoder.as_str() {
+        "libx264" => args.extend(["-sc_threshold".to_string(), "0".to_string()]),
+        "libx265" => args.extend(["-x265-params".to_string(), "scenecut=0".to_string()]),
+        _ => {}
+    }
+    args.extend(ctx.settings.encoder_speed_args(&video_encoder));
+
+    if audio_label.is_some() {
+        args.push("-c:a".to_string());
+        args.push(ctx.settings.audio_encoder_name().to_string());
+        if let Some(ref bitrate) = ctx.settings.audio_bitrate {
+            args.push("-b:a".to_string());
+            args.push(bitrate.clone());
+        }
+    }
+
+    append_output_time_range_args(&mut args, ctx.settings.start_time, ctx.settings.end_time);
+
+    let adaptation_sets = if audio_label.is_some() {
+        "id=0,streams=v id=1,streams=a"
+    } else {
+        "id=0,streams=v"
+    };
+    args.extend([
+        "-f".to_string(),
+        "dash".to_string(),
+        "-seg_duration".to_string(),
+        segment_duration_sec.to_string(),
+        "-use_template".to_string(),
+        "1".to_string(),
+        "-use_timeline".to_string(),
+        "1".to_string(),
+        "-adaptation_sets".to_string(),
+        adaptation_sets.to_string(),
+        "-init_seg_name".to_string(),
+        "init-$RepresentationID$.m4s".to_string(),
+        "-media_seg_name".to_string(),
+        "chunk-$RepresentationID$-$Number%05d$.m4s".to_string(),
+        "-hls_playlist".to_string(),
+        "1".to_string(),
+        "-y".to_string(),
+        ctx.settings.output_path.to_string_lossy().to_string(),
+    ]);
+
+    Ok(args)
+}
+
+/// Builds one FFmpeg job writing the sequence as numbered stills through the
+/// `image2` muxer.
+///
+/// The mixed audio has no output of its own and is drained into a null sink.
+/// `encoding_args` selects the still encoder and its pixel format; `ctx` should
+/// set a 4:4:4 `working_pixel_format` so the conversion to RGB does not
+/// upsample chroma.
+pub(super) fn build_image_sequence_ffmpeg_args(
+    ctx: SequenceFfmpegBuildContext<'_>,
+    encoding_args: Vec<String>,
+    start_number: u64,
+) -> Result<Vec<String>, ExportError> {
+    let SequenceFilterGraph {
+        mut args,
+        mut filter_complex,
+        video_label,
+        audio_label,
+        video_encoder: _,
+    } = build_sequence_filter_graph(&ctx)?;
+
+    if let Some(audio_label) = audio_label.as_deref() {
+        filter_complex.push_str(&format!(";{}anullsink", audio_label));
+    }
+
+    args.push("-filter_complex".to_string());
+    args.push(filter_complex);
+    args.push("-map".to_string());
+    args.push(video_label);
+    args.push("-an".to_string());
+    args.extend(encoding_args);
+
+    append_output_time_range_args(&mut args, ctx.settings.start_time, ctx.settings.end_time);
+    args.extend([
+        "-f".to_string(),
+        "image2".to_string(),
+        "-start_number".to_string(),
+        start_number.to_string(),
+        "-y".to_string(),
+        ctx.settings.output_path.to_string_lossy().to_string(),
+    ]);
+
+    Ok(args)
+}
+
+/// Builds one FFmpeg job writing the sequence as an animated GIF or WebP.
+///
+/// `animation_filter` is appended to the composited picture and must trim it
+/// to the range itself; no output `-ss`/`-t` is added. The mixed audio is
+/// drained into a null sink.
+pub(super) fn build_animated_image_ffmpeg_args(
+    ctx: SequenceFfmpegBuildContext<'_>,
+    animation_filter: &str,
+    encoding_args: Vec<String>,
+) -> Result<Vec<String>, ExportError> {
+    let SequenceFilterGraph {
+        mut args,
+        mut filter_complex,
+        video_label,
+        audio_label,
+        video_encoder: _,
+    } = build_sequence_filter_graph(&ctx)?;
+
+    filter_complex.push_str(&format!(";{}{}[animout]", video_label, animation_filter));
+    if let Some(audio_label) = audio_label.as_deref() {
+        filter_complex.push_str(&format!(";{}anullsink", audio_label));
+    }
+
+    args.push("-filter_complex".to_string());
+    args.push(filter_complex);
+    args.push("-map".to_string());
+    args.push("[animout]".to_string());
+    args.push("-an".to_string());
+    args.extend(encoding_args);
+    args.push("-y".to_string());
+    args.push(ctx.settings.output_path.to_string_lossy().to_string());
+
+    Ok(args)
+}
+
+/// How a clip that starts before the render window is opened at its start.
+struct InputSeek {
+    /// Source position the input is opened at (`-ss` before `-i`)
+    source_sec: f64,
+    /// Timeline seconds of the clip before the window
+    skipped_sec: f64,
+    /// The clip with its source range rebased onto the seeked input
+    clip: Clip,
+}
+
+/// Where to open `clip`'s input so decoding starts at `window_start`, or
+/// `None` when it starts inside the window or cannot be cut at the head.
+///
+/// Only a forward, constant-speed clip of a video file maps a window start to
+/// one source position: a still has nothing to seek into, an image sequence
+/// is addressed by frame number, and a transition, reverse, freeze or remap
+/// decides its own source window.
+fn input_seek(
+    clip: &Clip,
+    asset: &Asset,
+    handles: ClipHandles,
+    window_start: f64,
+) -> Option<InputSeek> {
+    let seekable = asset.kind == AssetKind::Video
+        && asset.image_sequence.is_none()
+        && handles.is_none()
+        && !clip.freeze_frame
+        && !clip.has_time_remap()
+        && !clip.reverse;
+    let skipped_sec = window_start - clip.place.timeline_in_sec;
+    if !seekable || skipped_sec <= TIMELINE_EPSILON_SEC || skipped_sec >= clip.place.duration_sec {
+        return None;
+    }
+
+    let source_sec = clip.range.source_in_sec + skipped_sec * clip.safe_speed();
+    let mut seeked = clip.clone();
+    seeked.range = ClipRange::new(0.0, clip.range.source_out_sec - source_sec);
+    Some(InputSeek {
+        source_sec,
+        skipped_sec,
+        clip: seeked,
+    })
+}
+
+fn build_sequence_filter_graph(
+    ctx: &SequenceFfmpegBuildContext<'_>,
+) -> Result<SequenceFilterGraph, ExportError> {
     validate_optional_plan_contract(ctx.render_plan, ctx.sequence, ctx.settings)?;
 
-    let mut args = Vec::new();
+    let video_encoder = ctx.settings.video_encoder_name();
+    let mut args = super::hardware::hardware_device_args(&video_encoder);
     let mut input_index = 0;
     let mut filter_complex = String::new();
     let mut video_segments = Vec::new();
@@ -93,7 +530,9 @@ pub(super) fn build_sequence_ffmpeg_args(
 
     let (output_width, output_height) = output_video_dimensions(ctx.sequence, ctx.settings);
     let output_fps = output_video_fps(ctx.sequence, ctx.settings);
-    let output_pixel_format = output_video_pixel_format(ctx.settings);
+    let output_pixel_format = ctx
+        .working_pixel_format
+        .unwrap_or_else(|| output_video_pixel_format(ctx.settings));
 
     // The export already probed every unique asset to find out whether it has
     // audio, and that probe reports the picture size too. Seeding the cache with
@@ -116,6 +555,13 @@ pub(super) fn build_sequence_ffmpeg_args(
     // timeline that reuses one GIF to a single probe.
     let mut source_frame_counts = SourceFrameCountCache::new();
 
+    // Only `[window_start, window_end)` reaches the output; the rest is cut by
+    // the output-side range. Clips wholly outside it are not decoded at all,
+    // and one that starts before it is opened at the window's start.
+    let window_start = ctx.settings.start_time.unwrap_or(0.0).max(0.0);
+    let window_end = ctx.settings.end_time.unwrap_or(f64::INFINITY);
+    let mut picture_outside_window = false;
+
     let mut adjustment_layer_effects = Vec::new();
     for (clip, _track) in &all_clips {
         if clip.is_adjustment_layer() && !clip.effects.is_empty() {
@@ -160,23 +606,42 @@ pub(super) fn build_sequence_ffmpeg_args(
                     track,
                     asset,
                     &audio_companion_keys,
-                );
+                )
+                && track_audio_reaches_master(ctx.sequence, track);
 
-        let contributes_visual_output = matches!(track.kind, TrackKind::Video) && track.visible;
+        // A clip in a transition renders a little more than its slot: the extra
+        // comes out of unused source media, never out of the timeline. The
+        // effect chain needs to know too — its stream now starts before the
+        // clip's in point, so anything anchored in seconds has to move with it.
+        let handles = transition_plan.handles(&clip.id);
+
+        // A blend reaches across the cut into its neighbour, so a clip in a
+        // transition is kept whatever the window.
+        let in_window = transition_plan.touches(&clip.id)
+            || (clip.place.timeline_out_sec() > window_start + TIMELINE_EPSILON_SEC
+                && clip.place.timeline_in_sec < window_end - TIMELINE_EPSILON_SEC);
+        let is_visible_picture = matches!(track.kind, TrackKind::Video) && track.visible;
+        picture_outside_window |= is_visible_picture && !in_window;
+        let contributes_visual_output = is_visible_picture && in_window;
         if !contributes_visual_output && !clip_has_audio {
             // No stream to emit. The clip still occupies the timeline, and
             // `timeline_end_sec` already accounts for it.
             continue;
         }
 
-        args.push("-i".to_string());
-        args.push(validated_path.to_string_lossy().to_string());
-
-        // A clip in a transition renders a little more than its slot: the extra
-        // comes out of unused source media, never out of the timeline. The
-        // effect chain needs to know too — its stream now starts before the
-        // clip's in point, so anything anchored in seconds has to move with it.
-        let handles = transition_plan.handles(&clip.id);
+        // Seeking moves the sound with the picture, so only a clip whose input
+        // feeds no audio branch is opened late.
+        let emits_audio = clip_has_audio && !clip.freeze_frame && !clip.audio.muted;
+        let seek = if contributes_visual_output && !emits_audio {
+            input_seek(clip, asset, handles, window_start)
+        } else {
+            None
+        };
+        if let Some(seek) = &seek {
+            args.push("-ss".to_string());
+            args.push(format_speed_number(seek.source_sec));
+        }
+        args.extend(asset.ffmpeg_input_args(&validated_path));
 
         let clip_filter_graph = ctx.engine.build_clip_filter_graph(
             clip,
@@ -213,7 +678,7 @@ pub(super) fn build_sequence_ffmpeg_args(
 
         match track.kind {
             TrackKind::Video => {
-                if track.visible {
+                if contributes_visual_output {
                     let trim_label = format!("trim{}", input_index);
                     let video_out_label = format!("v{}", input_index);
                     let normalized_video_label = format!("vnorm{}", input_index);
@@ -224,14 +689,37 @@ pub(super) fn build_sequence_ffmpeg_args(
                         video_out_label.clone()
                     };
 
-                    build_video_trim_filter(
-                        clip,
-                        input_index,
-                        &trim_label,
-                        &mut filter_complex,
-                        handles,
-                        resolve_trim_source_kind(asset, &mut source_frame_counts),
-                    );
+                    let trim_source = resolve_trim_source_kind(asset, &mut source_frame_counts);
+                    match &seek {
+                        Some(seek) => {
+                            // The skipped head comes back as padding, so the
+                            // branch keeps its length and every effect keeps
+                            // its timing; the output range discards it.
+                            let seeked_label = format!("seek{}", input_index);
+                            build_video_trim_filter(
+                                &seek.clip,
+                                input_index,
+                                &seeked_label,
+                                &mut filter_complex,
+                                handles,
+                                trim_source,
+                            );
+                            filter_complex.push_str(&format!(
+                                "[{}]tpad=start_duration={}[{}];",
+                                seeked_label,
+                                format_speed_number(seek.skipped_sec),
+                                trim_label
+                            ));
+                        }
+                        None => build_video_trim_filter(
+                            clip,
+                            input_index,
+                            &trim_label,
+                            &mut filter_complex,
+                            handles,
+                            trim_source,
+                        ),
+                    }
 
                     if clip_filter_graph.has_video_effects() {
                         let effects_filter = clip_filter_graph
@@ -351,9 +839,10 @@ pub(super) fn build_sequence_ffmpeg_args(
                         &audio_out_label,
                         &mut filter_complex,
                         handles,
+                        ctx.settings,
                     );
 
-                    audio_streams.push(format!("[{}]", mixed_audio_label));
+                    audio_streams.push((track.id.as_str(), format!("[{}]", mixed_audio_label)));
                 }
             }
             TrackKind::Audio => {
@@ -389,9 +878,10 @@ pub(super) fn build_sequence_ffmpeg_args(
                         &audio_out_label,
                         &mut filter_complex,
                         handles,
+                        ctx.settings,
                     );
 
-                    audio_streams.push(format!("[{}]", mixed_audio_label));
+                    audio_streams.push((track.id.as_str(), format!("[{}]", mixed_audio_label)));
                 }
             }
             _ => {}
@@ -427,6 +917,26 @@ pub(super) fn build_sequence_ffmpeg_args(
         }
     }
 
+    // A window over a stretch with no picture still needs a stream for the
+    // stitch to pad out to `timeline_end_sec`.
+    if picture_outside_window && video_segments.is_empty() {
+        let blank_label = "vwindowbase0";
+        append_black_video_gap(
+            &mut filter_complex,
+            blank_label,
+            timeline_end_sec,
+            output_width,
+            output_height,
+            output_fps,
+            output_pixel_format,
+        );
+        video_segments.push(VideoTimelineSegment::new(
+            format!("[{}]", blank_label),
+            0.0,
+            timeline_end_sec,
+        ));
+    }
+
     if video_segments.is_empty() {
         return Err(ExportError::InvalidSettings(
             "Sequence has no visual clips to export".to_string(),
@@ -474,84 +984,216 @@ pub(super) fn build_sequence_ffmpeg_args(
         filter_complex.push_str(&format!("[{}]null[outv]", adj_video_label));
     }
 
-    let final_video_label = if let Some(ass_path) = ctx.ass_text_overlay_path {
+    let video_label = if let Some(ass_path) = ctx.ass_text_overlay_path {
         append_ass_text_overlay(&mut filter_complex, "[outv]", ass_path)
     } else {
         append_drawtext_text_overlays(&mut filter_complex, "[outv]", &drawtext_text_overlays)
     };
 
+    let master_audio_streams = append_audio_bus_mix(
+        &mut filter_complex,
+        ctx.sequence,
+        ctx.effects,
+        audio_streams,
+    )?;
+    let audio_label = append_master_audio_output(
+        &mut filter_complex,
+        &master_audio_streams,
+        ctx.sequence.master_volume_db,
+        timeline_end_sec,
+    )
+    .map(|label| append_output_channel_layout(&mut filter_complex, label, ctx.settings))
+    .map(|label| {
+        append_loudness_normalization(
+            &mut filter_complex,
+            label,
+            ctx.sequence.format.audio_sample_rate,
+            ctx.settings,
+        )
+    });
+
+    Ok(SequenceFilterGraph {
+        args,
+        filter_complex,
+        video_label,
+        audio_label,
+        video_encoder,
+    })
+}
+
+pub(super) fn build_audio_only_ffmpeg_args(
+    ctx: AudioOnlyFfmpegBuildContext<'_>,
+) -> Result<Vec<String>, ExportError> {
+    validate_optional_plan_contract(ctx.render_plan, ctx.sequence, ctx.settings)?;
+
+    let mut args = Vec::new();
+    let mut filter_complex = String::new();
+    // Same single source of truth as the video path: silence is padded out to
+    // it, so the file is exactly `Sequence::output_duration()` long.
+    let timeline_end_sec = ctx.sequence.output_duration();
+    let audio_streams =
+        append_audio_only_clip_streams(&ctx, &mut args, &mut filter_complex, |_| true)?;
+
+    let master_audio_streams = append_audio_bus_mix(
+        &mut filter_complex,
+        ctx.sequence,
+        ctx.effects,
+        audio_streams,
+    )?;
     let final_audio_label = append_master_audio_output(
         &mut filter_complex,
-        &audio_streams,
+        &master_audio_streams,
         ctx.sequence.master_volume_db,
         timeline_end_sec,
+    )
+    .ok_or_else(|| ExportError::InvalidSettings("No audio tracks found in sequence".to_string()))?;
+    let final_audio_label =
+        append_output_channel_layout(&mut filter_complex, final_audio_label, ctx.settings);
+    let final_audio_label = append_loudness_normalization(
+        &mut filter_complex,
+        final_audio_label,
+        ctx.sequence.format.audio_sample_rate,
+        ctx.settings,
     );
 
     args.push("-filter_complex".to_string());
     args.push(filter_complex);
     args.push("-map".to_string());
-    args.push(final_video_label);
+    args.push(final_audio_label);
 
-    if let Some(final_audio_label) = final_audio_label.as_deref() {
-        args.push("-map".to_string());
-        args.push(final_audio_label.to_string());
-    }
+    append_output_time_range_args(&mut args, ctx.settings.start_time, ctx.settings.end_time);
+    args.push("-y".to_string());
+    args.push(ctx.settings.output_path.to_string_lossy().to_string());
 
-    let video_encoder = ctx.settings.video_encoder_name();
-    args.push("-c:v".to_string());
-    args.push(video_encoder.clone());
+    Ok(args)
+}
 
-    if final_audio_label.is_some() {
-        args.push("-c:a".to_string());
-        args.push(ctx.settings.audio_encoder_name().to_string());
-    }
+/// One stem file of a stems export.
+pub(super) struct AudioStemOutput<'a> {
+    pub track_ids: &'a [TrackId],
+    pub output_path: &'a Path,
+}
 
-    if let Some(ref bitrate) = ctx.settings.video_bitrate {
-        args.push("-b:v".to_string());
-        args.push(bitrate.clone());
-    }
+/// Builds one FFmpeg job writing every stem, and optionally the mixdown to
+/// `ctx.settings.output_path`, from a single filter graph.
+///
+/// Each clip stream is decoded once and split between the outputs that use
+/// it; `output_options` (codec, bitrate, sample rate) are repeated for every
+/// file, as is the export range.
+pub(super) fn build_audio_stems_ffmpeg_args(
+    ctx: AudioOnlyFfmpegBuildContext<'_>,
+    stems: &[AudioStemOutput<'_>],
+    include_mixdown: bool,
+    sample_rate: u32,
+    output_options: &[String],
+) -> Result<Vec<String>, ExportError> {
+    validate_optional_plan_contract(ctx.render_plan, ctx.sequence, ctx.settings)?;
 
-    if let Some(ref bitrate) = ctx.settings.audio_bitrate {
-        if final_audio_label.is_some() {
-            args.push("-b:a".to_string());
-            args.push(bitrate.clone());
-        }
+    let stems_using = |track_id: &str| {
+        stems
+            .iter()
+            .filter(|stem| stem.track_ids.iter().any(|id| id == track_id))
+            .count()
+    };
+
+    let mut args = Vec::new();
+    let mut filter_complex = String::new();
+    let timeline_end_sec = ctx.sequence.output_duration();
+    let audio_streams =
+        append_audio_only_clip_streams(&ctx, &mut args, &mut filter_complex, |track| {
+            include_mixdown || stems_using(&track.id) > 0
+        })?;
+
+    if audio_streams.is_empty() {
+        return Err(ExportError::InvalidSettings(
+            "No audio found on the selected stem tracks".to_string(),
+        ));
     }
 
-    if let Some(crf) = ctx.settings.crf {
-        if matches!(
-            ctx.settings.video_codec,
-            VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Vp9
-        ) {
-            args.extend(super::hardware::resolve_quality_args(&video_encoder, crf));
+    let mut mixdown_streams = Vec::new();
+    let mut stem_streams = vec![Vec::new(); stems.len()];
+    for (track_id, stream) in audio_streams {
+        let consumers = usize::from(include_mixdown) + stems_using(track_id);
+        let mut labels =
+            append_audio_stream_split(&mut filter_complex, &stream, consumers).into_iter();
+        if include_mixdown {
+            mixdown_streams.extend(labels.next().map(|label| (track_id, label)));
+        }
+        for (stem, streams) in stems.iter().zip(stem_streams.iter_mut()) {
+            if stem.track_ids.iter().any(|id| id == track_id) {
+                streams.extend(labels.next());
+            }
         }
     }
 
-    args.extend(ctx.settings.encoder_speed_args(&video_encoder));
+    let mut outputs = Vec::new();
+    if include_mixdown {
+        let master_audio_streams = append_audio_bus_mix(
+            &mut filter_complex,
+            ctx.sequence,
+            ctx.effects,
+            mixdown_streams,
+        )?;
+        // Every track under the buses may be muted away, which still leaves
+        // the mixdown a file as long as the stems.
+        let label = append_master_audio_output(
+            &mut filter_complex,
+            &master_audio_streams,
+            ctx.sequence.master_volume_db,
+            timeline_end_sec,
+        )
+        .unwrap_or_else(|| {
+            append_stem_audio_output(
+                &mut filter_complex,
+                stems.len(),
+                &[],
+                ctx.sequence,
+                sample_rate,
+                timeline_end_sec,
+            )
+        });
+        outputs.push((label, ctx.settings.output_path.as_path()));
+    }
+    for (index, (stem, streams)) in stems.iter().zip(stem_streams.iter()).enumerate() {
+        let label = append_stem_audio_output(
+            &mut filter_complex,
+            index,
+            streams,
+            ctx.sequence,
+            sample_rate,
+            timeline_end_sec,
+        );
+        outputs.push((label, stem.output_path));
+    }
 
-    args.extend(ctx.settings.hdr_args());
-    append_output_time_range_args(&mut args, ctx.settings.start_time, ctx.settings.end_time);
-    args.push("-y".to_string());
-    args.push(ctx.settings.output_path.to_string_lossy().to_string());
+    args.push("-filter_complex".to_string());
+    args.push(filter_complex);
+    for (label, output_path) in outputs {
+        args.push("-map".to_string());
+        args.push(label);
+        append_output_time_range_args(&mut args, ctx.settings.start_time, ctx.settings.end_time);
+        args.push("-vn".to_string());
+        args.extend(output_options.iter().cloned());
+        args.push("-y".to_string());
+        args.push(output_path.to_string_lossy().to_string());
+    }
 
     Ok(args)
 }
 
-pub(super) fn build_audio_only_ffmpeg_args(
-    ctx: AudioOnlyFfmpegBuildContext<'_>,
-) -> Result<Vec<String>, ExportError> {
-    validate_optional_plan_contract(ctx.render_plan, ctx.sequence, ctx.settings)?;
-
-    let mut args = Vec::new();
+/// Adds an input and a processed stream for every audible clip on the tracks
+/// `include_track` accepts, returning each stream with its track.
+///
+/// Clips that carry no audio — muted, frozen, text, or a silent source — are
+/// skipped but still occupy the timeline; callers pad to its end.
+fn append_audio_only_clip_streams<'a>(
+    ctx: &AudioOnlyFfmpegBuildContext<'a>,
+    args: &mut Vec<String>,
+    filter_complex: &mut String,
+    include_track: impl Fn(&Track) -> bool,
+) -> Result<Vec<(&'a str, String)>, ExportError> {
     let mut input_index = 0;
-    let mut filter_complex = String::new();
     let mut audio_streams = Vec::new();
-    // Same single source of truth as the video path: silence is padded out to
-    // it, so the file is exactly `Sequence::output_duration()` long. Clips that
-    // carry no audio — muted, frozen, text, or a silent source — are skipped
-    // below but still occupy the timeline, and an export range inside their
-    // span has to receive packets.
-    let timeline_end_sec = ctx.sequence.output_duration();
     let audio_companion_keys =
         collect_audio_companion_keys(ctx.sequence, ctx.assets, ctx.audio_info);
     let all_clips = collect_enabled_clips_sorted(ctx.sequence);
@@ -577,7 +1219,7 @@ pub(super) fn build_audio_only_ffmpeg_args(
     );
 
     for (clip, track) in &all_clips {
-        if !matches!(track.kind, TrackKind::Video | TrackKind::Audio) {
+        if !matches!(track.kind, TrackKind::Video | TrackKind::Audio) || !include_track(track) {
             continue;
         }
 
@@ -597,7 +1239,8 @@ pub(super) fn build_audio_only_ffmpeg_args(
                     track,
                     asset,
                     &audio_companion_keys,
-                );
+                )
+                && track_audio_reaches_master(ctx.sequence, track);
 
         if !clip_has_audio {
             continue;
@@ -606,8 +1249,7 @@ pub(super) fn build_audio_only_ffmpeg_args(
         let validated_path = validate_local_input_path(&asset.uri, "Asset file")
             .map_err(ExportError::InvalidSettings)?;
 
-        args.push("-i".to_string());
-        args.push(validated_path.to_string_lossy().to_string());
+        args.extend(asset.ffmpeg_input_args(&validated_path));
 
         let handles = transition_plan.handles(&clip.id);
         let clip_filter_graph =
@@ -619,7 +1261,7 @@ pub(super) fn build_audio_only_ffmpeg_args(
             clip,
             input_index,
             &audio_trim_label,
-            &mut filter_complex,
+            filter_complex,
             handles,
             transition_plan.audio_fades(&clip.id),
         );
@@ -641,11 +1283,12 @@ pub(super) fn build_audio_only_ffmpeg_args(
             track,
             input_index,
             &audio_out_label,
-            &mut filter_complex,
+            filter_complex,
             handles,
+            ctx.settings,
         );
 
-        audio_streams.push(format!("[{}]", mixed_audio_label));
+        audio_streams.push((track.id.as_str(), format!("[{}]", mixed_audio_label)));
         input_index += 1;
     }
 
@@ -653,24 +1296,7 @@ pub(super) fn build_audio_only_ffmpeg_args(
         filter_complex.pop();
     }
 
-    let final_audio_label = append_master_audio_output(
-        &mut filter_complex,
-        &audio_streams,
-        ctx.sequence.master_volume_db,
-        timeline_end_sec,
-    )
-    .ok_or_else(|| ExportError::InvalidSettings("No audio tracks found in sequence".to_string()))?;
-
-    args.push("-filter_complex".to_string());
-    args.push(filter_complex);
-    args.push("-map".to_string());
-    args.push(final_audio_label);
-
-    append_output_time_range_args(&mut args, ctx.settings.start_time, ctx.settings.end_time);
-    args.push("-y".to_string());
-    args.push(ctx.settings.output_path.to_string_lossy().to_string());
-
-    Ok(args)
+    Ok(audio_streams)
 }
 
 fn validate_optional_plan_contract(
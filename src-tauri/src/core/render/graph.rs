@@ -12,6 +12,7 @@ use crate::core::{
     captions::{CAPTION_CUSTOM_DEFAULT_Y_PERCENT, CAPTION_DEFAULT_VERTICAL_MARGIN_PERCENT},
     commands::{get_text_data, is_text_clip},
     project::ProjectState,
+    render::resolve_multicam_clips,
     text::{TextAlignment, TextClipData},
     timeline::{AudioSettings, BlendMode, SequenceFormat, TimelineClock, TrackKind, Transform},
     AssetId, ClipId, CoreError, CoreResult, EffectId, Frame, SequenceId, TimeSec, TrackId,
@@ -411,6 +412,8 @@ pub fn build_render_graph(state: &ProjectState, sequence_id: &str) -> CoreResult
         .sequences
         .get(sequence_id)
         .ok_or_else(|| CoreError::SequenceNotFound(sequence_id.to_string()))?;
+    // Multicam clips render as the angle they show, exactly as export sees them.
+    let sequence = &resolve_multicam_clips(sequence, &state.sequences);
 
     let mut visual_layers = Vec::new();
     let mut audio_layers = Vec::new();
@@ -1,7 +1,8 @@
 //! Hardware Encoder Detection & Resolution
 //!
-//! Detects available GPU video encoders (NVENC, QSV, AMF, VideoToolbox)
-//! and resolves the appropriate FFmpeg encoder name based on user preference.
+//! Detects available GPU video encoders (NVENC, QSV, AMF, VideoToolbox,
+//! VA-API) and resolves the appropriate FFmpeg encoder name based on user
+//! preference.
 
 use std::path::Path;
 use std::process::Command;
@@ -28,6 +29,8 @@ pub enum HardwareAccelMode {
     Amf,
     /// Apple VideoToolbox (macOS only)
     VideoToolbox,
+    /// VA-API (Linux); only used for AV1
+    Vaapi,
 }
 
 /// Information about a detected hardware encoder
@@ -42,6 +45,10 @@ pub struct HardwareEncoderInfo {
     pub h264_encoder: String,
     /// FFmpeg encoder name for H.265/HEVC (e.g., "hevc_nvenc")
     pub h265_encoder: String,
+    /// FFmpeg encoder name for AV1 (e.g., "av1_nvenc"); empty when the GPU
+    /// or driver cannot encode AV1
+    #[serde(default)]
+    pub av1_encoder: String,
 }
 
 /// Result of probing available hardware encoders
@@ -52,17 +59,26 @@ pub struct AvailableEncoders {
     pub hardware: Vec<HardwareEncoderInfo>,
     /// Whether any hardware encoder is available
     pub has_hardware: bool,
+    /// Software AV1 encoder this FFmpeg build has: `libsvtav1` when present,
+    /// otherwise `libaom-av1`
+    #[serde(default)]
+    pub software_av1_encoder: Option<String>,
 }
 
 /// Known hardware encoder definitions (FFmpeg encoder name → backend info)
-const HARDWARE_ENCODERS: &[(&str, &str, &str, &str, &str)] = &[
-    // (probe_encoder, backend_id, display_name, h264_encoder, h265_encoder)
+///
+/// VA-API is listed for AV1 only. Its H.264/H.265 encoders work, but this
+/// backend is probed on `av1_vaapi` so that a Linux machine with no other GPU
+/// encoder keeps encoding H.264 in software, as it always has.
+const HARDWARE_ENCODERS: &[(&str, &str, &str, &str, &str, &str)] = &[
+    // (probe_encoder, backend_id, display_name, h264_encoder, h265_encoder, av1_encoder)
     (
         "h264_nvenc",
         "nvenc",
         "NVIDIA NVENC",
         "h264_nvenc",
         "hevc_nvenc",
+        "av1_nvenc",
     ),
     (
         "h264_qsv",
@@ -70,17 +86,29 @@ const HARDWARE_ENCODERS: &[(&str, &str, &str, &str, &str)] = &[
         "Intel Quick Sync Video",
         "h264_qsv",
         "hevc_qsv",
+        "av1_qsv",
+    ),
+    (
+        "h264_amf", "amf", "AMD AMF", "h264_amf", "hevc_amf", "av1_amf",
     ),
-    ("h264_amf", "amf", "AMD AMF", "h264_amf", "hevc_amf"),
     (
         "h264_videotoolbox",
         "videotoolbox",
         "Apple VideoToolbox",
         "h264_videotoolbox",
         "hevc_videotoolbox",
+        "",
     ),
+    ("av1_vaapi", "vaapi", "VA-API", "", "", "av1_vaapi"),
 ];
 
+/// Software AV1 encoders in order of preference: SVT-AV1 is several times
+/// faster than libaom at comparable quality.
+const SOFTWARE_AV1_ENCODERS: &[&str] = &["libsvtav1", "libaom-av1"];
+
+/// VA-API render node opened for hardware encoding.
+const VAAPI_DEVICE: &str = "/dev/dri/renderD128";
+
 /// Detect available hardware encoders by probing FFmpeg
 ///
 /// Runs `ffmpeg -encoders` and parses the output to find GPU-accelerated encoders.
@@ -92,40 +120,42 @@ pub fn detect_available_encoders(ffmpeg_path: &Path) -> AvailableEncoders {
             return AvailableEncoders {
                 hardware: Vec::new(),
                 has_hardware: false,
+                software_av1_encoder: None,
             };
         }
     };
 
+    parse_encoder_output(&encoder_list)
+}
+
+/// Parse `ffmpeg -encoders` output into detected encoders
+fn parse_encoder_output(encoder_list: &str) -> AvailableEncoders {
     let mut hardware = Vec::new();
 
-    for &(probe_encoder, _backend_id, display_name, h264_enc, h265_enc) in HARDWARE_ENCODERS {
+    for &(probe_encoder, _backend_id, display_name, h264_enc, h265_enc, av1_enc) in
+        HARDWARE_ENCODERS
+    {
         if encoder_list.contains(probe_encoder) {
             let backend = match _backend_id {
                 "nvenc" => HardwareAccelMode::Nvenc,
                 "qsv" => HardwareAccelMode::Qsv,
                 "amf" => HardwareAccelMode::Amf,
                 "videotoolbox" => HardwareAccelMode::VideoToolbox,
+                "vaapi" => HardwareAccelMode::Vaapi,
                 _ => continue,
             };
 
-            // Verify H.265 encoder independently — older GPUs or driver versions
-            // may support H.264 hardware encoding but not H.265.
-            let verified_h265 = if encoder_list.contains(h265_enc) {
-                h265_enc.to_string()
-            } else {
-                tracing::info!(
-                    "{} H.265 encoder ({}) not found, will fall back to software for HEVC",
-                    display_name,
-                    h265_enc
-                );
-                String::new()
-            };
+            // Verify H.265 and AV1 encoders independently — older GPUs or driver
+            // versions may support H.264 hardware encoding but not the newer codecs.
+            let verified_h265 = verify_encoder(encoder_list, display_name, "HEVC", h265_enc);
+            let verified_av1 = verify_encoder(encoder_list, display_name, "AV1", av1_enc);
 
             hardware.push(HardwareEncoderInfo {
                 backend,
                 display_name: display_name.to_string(),
                 h264_encoder: h264_enc.to_string(),
                 h265_encoder: verified_h265,
+                av1_encoder: verified_av1,
             });
         }
     }
@@ -134,9 +164,32 @@ pub fn detect_available_encoders(ffmpeg_path: &Path) -> AvailableEncoders {
     AvailableEncoders {
         hardware,
         has_hardware,
+        software_av1_encoder: SOFTWARE_AV1_ENCODERS
+            .iter()
+            .find(|name| encoder_list.contains(*name))
+            .map(|name| name.to_string()),
     }
 }
 
+/// Returns `encoder` when the encoder list has it, or an empty name so the
+/// codec falls back to software.
+fn verify_encoder(encoder_list: &str, display_name: &str, codec: &str, encoder: &str) -> String {
+    if encoder.is_empty() {
+        return String::new();
+    }
+    if encoder_list.contains(encoder) {
+        return encoder.to_string();
+    }
+    tracing::info!(
+        "{} {} encoder ({}) not found, will fall back to software for {}",
+        display_name,
+        codec,
+        encoder,
+        codec
+    );
+    String::new()
+}
+
 /// Query FFmpeg for the list of supported encoders
 fn query_ffmpeg_encoders(ffmpeg_path: &Path) -> Result<String, String> {
     let mut command = Command::new(ffmpeg_path);
@@ -171,25 +224,48 @@ pub fn resolve_video_encoder(
     match codec {
         VideoCodec::Vp9 => return "libvpx-vp9".to_string(),
         VideoCodec::ProRes => return "prores_ks".to_string(),
+        VideoCodec::Dnxhr => return "dnxhd".to_string(),
         VideoCodec::Copy => return "copy".to_string(),
-        VideoCodec::H264 | VideoCodec::H265 => {}
+        VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Av1 => {}
     }
 
+    let software = || match codec {
+        VideoCodec::Av1 => available
+            .software_av1_encoder
+            .clone()
+            .unwrap_or_else(|| software_encoder_name(codec)),
+        _ => software_encoder_name(codec),
+    };
+
     // CPU mode always uses software encoder
     if *hw_mode == HardwareAccelMode::Cpu {
-        return software_encoder_name(codec);
+        return software();
     }
 
+    let encoder_for = |info: &HardwareEncoderInfo| -> String {
+        match codec {
+            VideoCodec::H264 => info.h264_encoder.clone(),
+            VideoCodec::H265 => info.h265_encoder.clone(),
+            VideoCodec::Av1 => info.av1_encoder.clone(),
+            _ => unreachable!(),
+        }
+    };
+
     // Find matching hardware encoder
     let target_backend = match hw_mode {
         HardwareAccelMode::Auto => {
-            // Auto: pick first available hardware encoder
-            available.hardware.first()
+            // Auto: pick the first hardware encoder that can encode this codec
+            available
+                .hardware
+                .iter()
+                .find(|info| !encoder_for(info).is_empty())
+                .or_else(|| available.hardware.first())
         }
         HardwareAccelMode::Nvenc
         | HardwareAccelMode::Qsv
         | HardwareAccelMode::Amf
-        | HardwareAccelMode::VideoToolbox => available
+        | HardwareAccelMode::VideoToolbox
+        | HardwareAccelMode::Vaapi => available
             .hardware
             .iter()
             .find(|info| info.backend == *hw_mode),
@@ -198,16 +274,12 @@ pub fn resolve_video_encoder(
 
     match target_backend {
         Some(info) => {
-            let encoder = match codec {
-                VideoCodec::H264 => &info.h264_encoder,
-                VideoCodec::H265 => &info.h265_encoder,
-                _ => unreachable!(),
-            };
+            let encoder = encoder_for(info);
             // Fall back to software if the specific codec encoder was not verified
             if encoder.is_empty() {
-                return software_encoder_name(codec);
+                return software();
             }
-            encoder.clone()
+            encoder
         }
         None => {
             // No matching hardware encoder available — fallback to software
@@ -217,7 +289,7 @@ pub fn resolve_video_encoder(
                     hw_mode
                 );
             }
-            software_encoder_name(codec)
+            software()
         }
     }
 }
@@ -228,7 +300,9 @@ pub fn software_encoder_name(codec: &VideoCodec) -> String {
         VideoCodec::H264 => "libx264".to_string(),
         VideoCodec::H265 => "libx265".to_string(),
         VideoCodec::Vp9 => "libvpx-vp9".to_string(),
+        VideoCodec::Av1 => "libsvtav1".to_string(),
         VideoCodec::ProRes => "prores_ks".to_string(),
+        VideoCodec::Dnxhr => "dnxhd".to_string(),
         VideoCodec::Copy => "copy".to_string(),
     }
 }
@@ -241,7 +315,27 @@ pub fn software_encoder_name(codec: &VideoCodec) -> String {
 /// - QSV: `-global_quality <value>`
 /// - AMF: `-quality <value> -rc cqp -qp_i <crf> -qp_p <crf>`
 /// - VideoToolbox: `-q:v <scaled_value>`
+/// - VA-API (AV1): `-rc_mode CQP -qp <quantizer_index>`
+///
+/// AV1 CRF runs 0–63. The hardware AV1 encoders take the 0–51 scale their
+/// H.264/H.265 siblings use, so the value is rescaled onto it; VA-API takes
+/// AV1's 0–255 quantizer index instead.
 pub fn resolve_quality_args(encoder_name: &str, crf: u8) -> Vec<String> {
+    if encoder_name.contains("vaapi") {
+        let qindex = (u16::from(crf) * 4).min(255);
+        return vec![
+            "-rc_mode".to_string(),
+            "CQP".to_string(),
+            "-qp".to_string(),
+            qindex.to_string(),
+        ];
+    }
+    let crf = if encoder_name.starts_with("av1_") {
+        ((u16::from(crf.min(63)) * 51 + 31) / 63) as u8
+    } else {
+        crf
+    };
+
     if encoder_name.contains("nvenc") {
         vec![
             "-cq".to_string(),
@@ -280,6 +374,33 @@ pub fn is_hardware_encoder(encoder_name: &str) -> bool {
         || encoder_name.contains("qsv")
         || encoder_name.contains("amf")
         || encoder_name.contains("videotoolbox")
+        || encoder_name.contains("vaapi")
+}
+
+/// Global arguments an encoder needs before FFmpeg opens its inputs.
+///
+/// VA-API encoders encode from a device FFmpeg has to open up front; the same
+/// device is handed to the filter graph for the upload.
+pub fn hardware_device_args(encoder_name: &str) -> Vec<String> {
+    if encoder_name.contains("vaapi") {
+        vec!["-vaapi_device".to_string(), VAAPI_DEVICE.to_string()]
+    } else {
+        Vec::new()
+    }
+}
+
+/// Filter moving frames into GPU memory for encoders that only read from
+/// there, or `None` for encoders that take frames from system memory.
+pub fn hardware_upload_filter(encoder_name: &str, pixel_format: &str) -> Option<String> {
+    if !encoder_name.contains("vaapi") {
+        return None;
+    }
+    let upload_format = if pixel_format.contains("10") {
+        "p010"
+    } else {
+        "nv12"
+    };
+    Some(format!("format={},hwupload", upload_format))
 }
 
 // =============================================================================
@@ -514,8 +635,10 @@ mod tests {
                 display_name: "NVIDIA NVENC".to_string(),
                 h264_encoder: "h264_nvenc".to_string(),
                 h265_encoder: "hevc_nvenc".to_string(),
+                av1_encoder: String::new(),
             }],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
         // When: resolving encoder for H264 with CPU mode
@@ -534,8 +657,10 @@ mod tests {
                 display_name: "NVIDIA NVENC".to_string(),
                 h264_encoder: "h264_nvenc".to_string(),
                 h265_encoder: "hevc_nvenc".to_string(),
+                av1_encoder: String::new(),
             }],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
         // When: resolving H264 and H265 with NVENC mode
@@ -553,6 +678,7 @@ mod tests {
         let available = AvailableEncoders {
             hardware: Vec::new(),
             has_hardware: false,
+            software_av1_encoder: None,
         };
 
         // When: requesting NVENC mode with nothing available
@@ -573,15 +699,18 @@ mod tests {
                     display_name: "Intel Quick Sync Video".to_string(),
                     h264_encoder: "h264_qsv".to_string(),
                     h265_encoder: "hevc_qsv".to_string(),
+                    av1_encoder: String::new(),
                 },
                 HardwareEncoderInfo {
                     backend: HardwareAccelMode::Nvenc,
                     display_name: "NVIDIA NVENC".to_string(),
                     h264_encoder: "h264_nvenc".to_string(),
                     h265_encoder: "hevc_nvenc".to_string(),
+                    av1_encoder: String::new(),
                 },
             ],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
         // When: auto mode selected
@@ -601,22 +730,72 @@ mod tests {
                 display_name: "NVIDIA NVENC".to_string(),
                 h264_encoder: "h264_nvenc".to_string(),
                 h265_encoder: "hevc_nvenc".to_string(),
+                av1_encoder: String::new(),
             }],
             has_hardware: true,
+            software_av1_encoder: None,
         };
 
-        // When: resolving VP9, ProRes, Copy with NVENC mode
+        // When: resolving VP9, ProRes, DNxHR, Copy with NVENC mode
         let vp9 = resolve_video_encoder(&VideoCodec::Vp9, &HardwareAccelMode::Nvenc, &available);
         let prores =
             resolve_video_encoder(&VideoCodec::ProRes, &HardwareAccelMode::Nvenc, &available);
+        let dnxhr =
+            resolve_video_encoder(&VideoCodec::Dnxhr, &HardwareAccelMode::Nvenc, &available);
         let copy = resolve_video_encoder(&VideoCodec::Copy, &HardwareAccelMode::Nvenc, &available);
 
         // Then: software encoders returned (no GPU variants exist)
         assert_eq!(vp9, "libvpx-vp9");
         assert_eq!(prores, "prores_ks");
+        assert_eq!(dnxhr, "dnxhd");
         assert_eq!(copy, "copy");
     }
 
+    #[test]
+    fn should_resolve_av1_to_a_gpu_that_can_encode_it() {
+        // Given: QSV without AV1 listed ahead of NVENC with AV1, and libaom only
+        let available = AvailableEncoders {
+            hardware: vec![
+                HardwareEncoderInfo {
+                    backend: HardwareAccelMode::Qsv,
+                    display_name: "Intel Quick Sync Video".to_string(),
+                    h264_encoder: "h264_qsv".to_string(),
+                    h265_encoder: "hevc_qsv".to_string(),
+                    av1_encoder: String::new(),
+                },
+                HardwareEncoderInfo {
+                    backend: HardwareAccelMode::Nvenc,
+                    display_name: "NVIDIA NVENC".to_string(),
+                    h264_encoder: "h264_nvenc".to_string(),
+                    h265_encoder: "hevc_nvenc".to_string(),
+                    av1_encoder: "av1_nvenc".to_string(),
+                },
+            ],
+            has_hardware: true,
+            software_av1_encoder: Some("libaom-av1".to_string()),
+        };
+
+        // When/Then: auto skips the GPU without AV1, H.264 keeps the first GPU
+        assert_eq!(
+            resolve_video_encoder(&VideoCodec::Av1, &HardwareAccelMode::Auto, &available),
+            "av1_nvenc"
+        );
+        assert_eq!(
+            resolve_video_encoder(&VideoCodec::H264, &HardwareAccelMode::Auto, &available),
+            "h264_qsv"
+        );
+
+        // And: software AV1 uses the encoder the build has
+        assert_eq!(
+            resolve_video_encoder(&VideoCodec::Av1, &HardwareAccelMode::Qsv, &available),
+            "libaom-av1"
+        );
+        assert_eq!(
+            resolve_video_encoder(&VideoCodec::Av1, &HardwareAccelMode::Cpu, &available),
+            "libaom-av1"
+        );
+    }
+
     // -------------------------------------------------------------------------
     // BDD: Feature: Quality Argument Resolution
     // -------------------------------------------------------------------------
@@ -661,6 +840,33 @@ mod tests {
         assert!(args.contains(&"28".to_string()));
     }
 
+    #[test]
+    fn should_rescale_av1_crf_for_hardware_encoders() {
+        // AV1 CRF 63 is the bottom of the 0-51 scale the GPU encoders take
+        let args = resolve_quality_args("av1_nvenc", 63);
+        assert_eq!(args[1], "51");
+        assert_eq!(resolve_quality_args("av1_qsv", 32)[1], "26");
+        assert_eq!(
+            resolve_quality_args("av1_vaapi", 32),
+            vec!["-rc_mode", "CQP", "-qp", "128"]
+        );
+        assert_eq!(resolve_quality_args("libsvtav1", 32), vec!["-crf", "32"]);
+    }
+
+    #[test]
+    fn should_upload_frames_only_for_vaapi_encoders() {
+        assert_eq!(
+            hardware_upload_filter("av1_vaapi", "yuv420p10le").as_deref(),
+            Some("format=p010,hwupload")
+        );
+        assert_eq!(hardware_upload_filter("av1_nvenc", "yuv420p"), None);
+        assert_eq!(
+            hardware_device_args("av1_vaapi"),
+            vec!["-vaapi_device", "/dev/dri/renderD128"]
+        );
+        assert!(hardware_device_args("libsvtav1").is_empty());
+    }
+
     // -------------------------------------------------------------------------
     // BDD: Feature: Hardware Encoder Detection
     // -------------------------------------------------------------------------
@@ -675,12 +881,34 @@ mod tests {
         assert!(has_nvenc);
     }
 
+    #[test]
+    fn should_detect_av1_encoders_from_ffmpeg_encoder_list() {
+        // Given: an encoder list with NVENC lacking AV1, VA-API AV1 and both software AV1 encoders
+        let encoder_output = " V....D libaom-av1           libaom AV1 (codec av1)\n V....D libsvtav1            SVT-AV1 (codec av1)\n V....D h264_nvenc           NVIDIA NVENC H.264 encoder (codec h264)\n V....D hevc_nvenc           NVIDIA NVENC hevc encoder (codec hevc)\n V....D av1_vaapi            AV1 (VAAPI) (codec av1)\n";
+
+        // When: parsing
+        let available = parse_encoder_output(encoder_output);
+
+        // Then: NVENC has no AV1, VA-API is AV1-only, SVT-AV1 is preferred
+        assert_eq!(available.hardware.len(), 2);
+        assert_eq!(available.hardware[0].av1_encoder, "");
+        assert_eq!(available.hardware[1].backend, HardwareAccelMode::Vaapi);
+        assert_eq!(available.hardware[1].h264_encoder, "");
+        assert_eq!(available.hardware[1].av1_encoder, "av1_vaapi");
+        assert_eq!(available.software_av1_encoder.as_deref(), Some("libsvtav1"));
+        assert_eq!(
+            resolve_video_encoder(&VideoCodec::H264, &HardwareAccelMode::Vaapi, &available),
+            "libx264"
+        );
+    }
+
     #[test]
     fn should_return_empty_when_no_gpu_encoders_found() {
         // Given: encoder list with only software encoders
         let available = AvailableEncoders {
             hardware: Vec::new(),
             has_hardware: false,
+            software_av1_encoder: None,
         };
 
         // Then: no hardware encoders detected
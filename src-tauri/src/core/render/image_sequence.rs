@@ -0,0 +1,493 @@
+//! Numbered image-sequence exports.
+//!
+//! An image sequence renders a range of the timeline through the same filter
+//! graph as a video export, one still per frame, for hand-off to compositing
+//! and grading tools. Files are named `<base>.<frame>.<ext>` with the frame
+//! number zero-padded, and numbered after the timeline frames they hold unless
+//! the caller picks a different first number.
+//!
+//! Next to the frames goes `<base>.manifest.json`, which maps every file to its
+//! timeline frame, position and timecode, so a shot can be conformed back
+//! without re-deriving the numbering.
+//!
+//! The picture is composited in 4:4:4, at 10 bits whenever the output is
+//! deeper than 8, and converted to the file's RGB format at the end. With
+//! `alpha`, the canvas is transparent wherever the timeline has no picture.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::interchange::models::{is_drop_frame_rate, Timecode};
+use crate::core::timeline::Sequence;
+use crate::core::Ratio;
+
+use super::{ExportError, ExportSettings};
+
+fn default_frame_padding() -> u8 {
+    4
+}
+
+/// Still-image format of an image-sequence export
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageSequenceFormat {
+    /// PNG, 8 or 16 bits
+    Png,
+    /// TIFF, 8 or 16 bits
+    Tiff,
+    /// OpenEXR, 16-bit half or 32-bit float
+    Exr,
+    /// DPX, 8, 10, 12 or 16 bits
+    Dpx,
+}
+
+impl ImageSequenceFormat {
+    /// File extension for this format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Tiff => "tif",
+            Self::Exr => "exr",
+            Self::Dpx => "dpx",
+        }
+    }
+
+    /// FFmpeg encoder for this format
+    pub fn encoder_name(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Tiff => "tiff",
+            Self::Exr => "exr",
+            Self::Dpx => "dpx",
+        }
+    }
+
+    /// Bits per channel the format can be written at
+    pub fn supported_bit_depths(&self) -> &'static [u8] {
+        match self {
+            Self::Png | Self::Tiff => &[8, 16],
+            Self::Exr => &[16, 32],
+            Self::Dpx => &[8, 10, 12, 16],
+        }
+    }
+
+    /// Bit depth used when the caller does not choose one
+    pub fn default_bit_depth(&self) -> u8 {
+        match self {
+            Self::Png => 8,
+            Self::Tiff | Self::Exr => 16,
+            Self::Dpx => 10,
+        }
+    }
+
+    /// FFmpeg pixel format for a bit depth and alpha, or `None` when the
+    /// encoder cannot write that combination.
+    pub fn pixel_format(&self, bit_depth: u8, alpha: bool) -> Option<&'static str> {
+        let format = match (self, bit_depth, alpha) {
+            (Self::Png | Self::Tiff | Self::Dpx, 8, false) => "rgb24",
+            (Self::Png | Self::Tiff | Self::Dpx, 8, true) => "rgba",
+            (Self::Png, 16, false) => "rgb48be",
+            (Self::Png, 16, true) => "rgba64be",
+            (Self::Tiff | Self::Dpx, 16, false) => "rgb48le",
+            (Self::Tiff | Self::Dpx, 16, true) => "rgba64le",
+            (Self::Dpx, 10, false) => "gbrp10le",
+            (Self::Dpx, 12, false) => "gbrp12le",
+            (Self::Exr, 16 | 32, false) => "gbrpf32le",
+            (Self::Exr, 16 | 32, true) => "gbrapf32le",
+            _ => return None,
+        };
+        Some(format)
+    }
+}
+
+/// Settings for exporting a range of a sequence as numbered images.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSequenceExportSettings {
+    /// Directory the frames and the manifest are written to
+    pub output_dir: PathBuf,
+    /// File name before the frame number, e.g. "sh010_plate"
+    pub base_name: String,
+    /// Image format
+    pub format: ImageSequenceFormat,
+    /// Bits per channel (None = the format's default)
+    pub bit_depth: Option<u8>,
+    /// Write an alpha channel, transparent wherever the timeline has no picture
+    #[serde(default)]
+    pub alpha: bool,
+    /// Digits in the frame number, zero-padded
+    #[serde(default = "default_frame_padding")]
+    pub frame_padding: u8,
+    /// Number of the first file (None = the timeline frame it holds)
+    pub start_number: Option<u64>,
+    /// Output width (None = same as sequence)
+    pub width: Option<u32>,
+    /// Output height (None = same as sequence)
+    pub height: Option<u32>,
+    /// Start time in seconds (for partial export)
+    pub start_time: Option<f64>,
+    /// End time in seconds (for partial export)
+    pub end_time: Option<f64>,
+}
+
+impl ImageSequenceExportSettings {
+    /// Validate image-sequence settings
+    pub fn validate(&self) -> Result<(), ExportError> {
+        if self.base_name.is_empty()
+            || !self
+                .base_name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        {
+            return Err(ExportError::InvalidSettings(
+                "Base name may only contain letters, digits, '-', '_' and '.'".to_string(),
+            ));
+        }
+
+        if !(1..=10).contains(&self.frame_padding) {
+            return Err(ExportError::InvalidSettings(
+                "Frame padding must be between 1 and 10 digits".to_string(),
+            ));
+        }
+
+        let bit_depth = self.bit_depth();
+        if !self.format.supported_bit_depths().contains(&bit_depth) {
+            return Err(ExportError::InvalidSettings(format!(
+                "{:?} cannot be written at {} bits; supported: {:?}",
+                self.format,
+                bit_depth,
+                self.format.supported_bit_depths()
+            )));
+        }
+        if self.format.pixel_format(bit_depth, self.alpha).is_none() {
+            return Err(ExportError::InvalidSettings(format!(
+                "{:?} at {} bits cannot carry an alpha channel",
+                self.format, bit_depth
+            )));
+        }
+
+        if self.width == Some(0) || self.height == Some(0) {
+            return Err(ExportError::InvalidSettings(
+                "Output size must be greater than zero".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Bits per channel the frames are written at
+    pub fn bit_depth(&self) -> u8 {
+        self.bit_depth
+            .unwrap_or_else(|| self.format.default_bit_depth())
+    }
+
+    /// FFmpeg pixel format the frames are written in
+    pub fn pixel_format(&self) -> Option<&'static str> {
+        self.format.pixel_format(self.bit_depth(), self.alpha)
+    }
+
+    /// Pixel format the picture is composited in before conversion.
+    pub fn working_pixel_format(&self) -> &'static str {
+        match (self.bit_depth() > 8, self.alpha) {
+            (false, false) => "yuv444p",
+            (false, true) => "yuva444p",
+            (true, false) => "yuv444p10le",
+            (true, true) => "yuva444p10le",
+        }
+    }
+
+    /// Timeline frame at the start of the range
+    pub fn first_timeline_frame(&self, sequence: &Sequence) -> u64 {
+        (self.start_time.unwrap_or(0.0).max(0.0) * sequence.format.fps.as_f64()).round() as u64
+    }
+
+    /// Number of the first file
+    pub fn first_number(&self, sequence: &Sequence) -> u64 {
+        self.start_number
+            .unwrap_or_else(|| self.first_timeline_frame(sequence))
+    }
+
+    /// File name of a frame
+    pub fn frame_file_name(&self, number: u64) -> String {
+        format!(
+            "{}.{:0width$}.{}",
+            self.base_name,
+            number,
+            self.format.extension(),
+            width = usize::from(self.frame_padding)
+        )
+    }
+
+    /// `image2` output pattern the frames are written through
+    pub fn output_pattern(&self) -> PathBuf {
+        self.output_dir.join(format!(
+            "{}.%0{}d.{}",
+            self.base_name,
+            self.frame_padding,
+            self.format.extension()
+        ))
+    }
+
+    /// Path of the sidecar manifest
+    pub fn manifest_path(&self) -> PathBuf {
+        self.output_dir
+            .join(format!("{}.manifest.json", self.base_name))
+    }
+
+    /// Encoder arguments for the frames.
+    pub fn encoding_args(&self) -> Vec<String> {
+        let mut args = vec![
+            "-c:v".to_string(),
+            self.format.encoder_name().to_string(),
+            "-pix_fmt".to_string(),
+            self.pixel_format().unwrap_or("rgb24").to_string(),
+        ];
+        if self.format == ImageSequenceFormat::Exr {
+            args.push("-format".to_string());
+            args.push(
+                if self.bit_depth() == 16 {
+                    "half"
+                } else {
+                    "float"
+                }
+                .to_string(),
+            );
+        }
+        args
+    }
+
+    /// Settings the filter graph is built from.
+    pub fn to_export_settings(&self) -> ExportSettings {
+        ExportSettings {
+            output_path: self.output_pattern(),
+            width: self.width,
+            height: self.height,
+            video_bitrate: None,
+            audio_bitrate: None,
+            fps: None,
+            crf: None,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            ..ExportSettings::default()
+        }
+    }
+}
+
+/// One file of an image sequence
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSequenceFrame {
+    /// Number in the file name
+    pub number: u64,
+    /// File name, relative to the manifest
+    pub file: String,
+    /// Timeline frame the file holds
+    pub timeline_frame: u64,
+    /// Timeline position of the frame in seconds
+    pub timeline_sec: f64,
+    /// Timeline timecode of the frame
+    pub timecode: String,
+}
+
+/// Sidecar manifest written next to an image sequence
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSequenceManifest {
+    pub sequence_id: String,
+    pub sequence_name: String,
+    /// Timeline frame rate
+    pub fps: Ratio,
+    /// Whether the timecodes are drop-frame
+    pub drop_frame: bool,
+    pub format: ImageSequenceFormat,
+    pub bit_depth: u8,
+    pub alpha: bool,
+    /// FFmpeg pixel format of the files
+    pub pixel_format: String,
+    /// `printf`-style file pattern, e.g. "sh010.%04d.exr"
+    pub file_pattern: String,
+    pub frames: Vec<ImageSequenceFrame>,
+}
+
+impl ImageSequenceManifest {
+    /// Builds the manifest for `frame_count` files whose first holds timeline
+    /// frame `first_timeline_frame`.
+    pub fn new(
+        sequence: &Sequence,
+        settings: &ImageSequenceExportSettings,
+        first_timeline_frame: u64,
+        frame_count: u64,
+    ) -> Self {
+        let fps = sequence.format.fps.clone();
+        let first_number = settings.start_number.unwrap_or(first_timeline_frame);
+        let frames = (0..frame_count)
+            .map(|index| {
+                let timeline_frame = first_timeline_frame + index;
+                let timeline_sec = timeline_frame as f64 / fps.as_f64();
+                ImageSequenceFrame {
+                    number: first_number + index,
+                    file: settings.frame_file_name(first_number + index),
+                    timeline_frame,
+                    timeline_sec,
+                    timecode: Timecode::from_seconds(timeline_sec, &fps).to_string(),
+                }
+            })
+            .collect();
+
+        Self {
+            sequence_id: sequence.id.clone(),
+            sequence_name: sequence.name.clone(),
+            drop_frame: is_drop_frame_rate(&fps),
+            fps,
+            format: settings.format,
+            bit_depth: settings.bit_depth(),
+            alpha: settings.alpha,
+            pixel_format: settings.pixel_format().unwrap_or_default().to_string(),
+            file_pattern: settings
+                .output_pattern()
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            frames,
+        }
+    }
+}
+
+/// Result of an image-sequence export
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSequenceExportResult {
+    /// Directory holding the frames
+    pub output_dir: PathBuf,
+    /// Sidecar manifest
+    pub manifest_path: PathBuf,
+    /// Number of the first file
+    pub first_number: u64,
+    /// Number of files written
+    pub frame_count: u64,
+    /// Combined size of the frames in bytes
+    pub total_size: u64,
+    /// Duration in seconds
+    pub duration_sec: f64,
+    /// Total encoding time in seconds
+    pub encoding_time_sec: f64,
+}
+
+/// Paths of the frames from `first_number` on that exist in the output
+/// directory, for sizing or removing them.
+pub(super) fn written_frame_paths(
+    settings: &ImageSequenceExportSettings,
+    first_number: u64,
+) -> Vec<PathBuf> {
+    (first_number..)
+        .map(|number| settings.output_dir.join(settings.frame_file_name(number)))
+        .take_while(|path| Path::is_file(path))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::timeline::SequenceFormat;
+
+    fn settings(format: ImageSequenceFormat) -> ImageSequenceExportSettings {
+        ImageSequenceExportSettings {
+            output_dir: PathBuf::from("/tmp/plates"),
+            base_name: "sh010_plate".to_string(),
+            format,
+            bit_depth: None,
+            alpha: false,
+            frame_padding: 4,
+            start_number: None,
+            width: None,
+            height: None,
+            start_time: None,
+            end_time: None,
+        }
+    }
+
+    #[test]
+    fn picks_pixel_formats_per_format_depth_and_alpha() {
+        let mut exr = settings(ImageSequenceFormat::Exr);
+        exr.alpha = true;
+        assert_eq!(exr.pixel_format(), Some("gbrapf32le"));
+        assert_eq!(exr.working_pixel_format(), "yuva444p10le");
+        assert_eq!(
+            exr.encoding_args(),
+            vec!["-c:v", "exr", "-pix_fmt", "gbrapf32le", "-format", "half"]
+        );
+
+        let dpx = settings(ImageSequenceFormat::Dpx);
+        assert_eq!(dpx.pixel_format(), Some("gbrp10le"));
+
+        let mut png = settings(ImageSequenceFormat::Png);
+        png.bit_depth = Some(16);
+        assert_eq!(png.pixel_format(), Some("rgb48be"));
+        png.bit_depth = Some(8);
+        assert_eq!(png.working_pixel_format(), "yuv444p");
+    }
+
+    #[test]
+    fn rejects_unsupported_depths_alpha_and_names() {
+        assert!(settings(ImageSequenceFormat::Tiff).validate().is_ok());
+
+        let mut dpx_alpha = settings(ImageSequenceFormat::Dpx);
+        dpx_alpha.alpha = true;
+        assert!(dpx_alpha.validate().is_err());
+        dpx_alpha.bit_depth = Some(16);
+        assert!(dpx_alpha.validate().is_ok());
+
+        let mut png_10 = settings(ImageSequenceFormat::Png);
+        png_10.bit_depth = Some(10);
+        assert!(png_10.validate().is_err());
+
+        let mut bad_name = settings(ImageSequenceFormat::Png);
+        bad_name.base_name = "shot%d".to_string();
+        assert!(bad_name.validate().is_err());
+
+        let mut no_padding = settings(ImageSequenceFormat::Png);
+        no_padding.frame_padding = 0;
+        assert!(no_padding.validate().is_err());
+    }
+
+    #[test]
+    fn names_frames_with_padding() {
+        let mut exr = settings(ImageSequenceFormat::Exr);
+        exr.frame_padding = 6;
+        assert_eq!(exr.frame_file_name(1001), "sh010_plate.001001.exr");
+        assert_eq!(
+            exr.output_pattern(),
+            PathBuf::from("/tmp/plates/sh010_plate.%06d.exr")
+        );
+        assert_eq!(
+            exr.manifest_path(),
+            PathBuf::from("/tmp/plates/sh010_plate.manifest.json")
+        );
+    }
+
+    #[test]
+    fn maps_every_file_to_its_timeline_frame_and_timecode() {
+        let mut sequence = Sequence::new("Reel 1", SequenceFormat::new(1920, 1080, 24, 1, 48000));
+        sequence.id = "seq-1".to_string();
+        let mut tiff = settings(ImageSequenceFormat::Tiff);
+        tiff.start_number = Some(1001);
+
+        let manifest = ImageSequenceManifest::new(&sequence, &tiff, 47, 3);
+
+        assert_eq!(manifest.file_pattern, "sh010_plate.%04d.tif");
+        assert!(!manifest.drop_frame);
+        assert_eq!(manifest.frames.len(), 3);
+        let first = &manifest.frames[0];
+        assert_eq!(first.number, 1001);
+        assert_eq!(first.file, "sh010_plate.1001.tif");
+        assert_eq!(first.timeline_frame, 47);
+        assert_eq!(first.timecode, "00:00:01:23");
+        assert_eq!(manifest.frames[1].timecode, "00:00:02:00");
+
+        let numbered_by_timeline =
+            ImageSequenceManifest::new(&sequence, &settings(ImageSequenceFormat::Png), 47, 1);
+        assert_eq!(numbered_by_timeline.frames[0].file, "sh010_plate.0047.png");
+    }
+}
@@ -0,0 +1,362 @@
+//! Loudness-targeted delivery normalization.
+//!
+//! An export can be asked to land on a delivery loudness spec: an integrated
+//! loudness target with a true-peak ceiling. The final mix is measured with a
+//! first `loudnorm` pass, and the measured values drive a second, linear
+//! `loudnorm` pass inside the export itself, so the whole program is moved by
+//! one gain rather than pumped by the dynamic limiter. `loudnorm` falls back
+//! to dynamic mode on its own when the ceiling cannot be met linearly; the
+//! [`LoudnessReport`] records which of the two it used.
+
+use serde::{Deserialize, Serialize};
+
+/// Quietest integrated loudness `loudnorm` can measure and correct
+const MIN_MEASURABLE_LUFS: f64 = -70.0;
+
+/// Sample rate `loudnorm` works at internally
+const LOUDNORM_SAMPLE_RATE: u32 = 192_000;
+
+/// Delivery loudness specs with their integrated target and true-peak ceiling
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoudnessStandard {
+    /// Streaming platforms: -14 LUFS, -1 dBTP
+    Streaming,
+    /// EBU R128 broadcast: -23 LUFS, -1 dBTP
+    EbuR128,
+    /// ATSC A/85 broadcast: -24 LKFS, -2 dBTP
+    AtscA85,
+}
+
+impl LoudnessStandard {
+    /// Integrated loudness target in LUFS
+    pub fn integrated_lufs(&self) -> f64 {
+        match self {
+            Self::Streaming => -14.0,
+            Self::EbuR128 => -23.0,
+            Self::AtscA85 => -24.0,
+        }
+    }
+
+    /// True-peak ceiling in dBTP
+    pub fn true_peak_dbtp(&self) -> f64 {
+        match self {
+            Self::Streaming | Self::EbuR128 => -1.0,
+            Self::AtscA85 => -2.0,
+        }
+    }
+
+    /// Loudness range `loudnorm` aims for; broadcast specs allow more
+    /// dynamics than streaming
+    pub fn loudness_range_lu(&self) -> f64 {
+        match self {
+            Self::Streaming => 11.0,
+            Self::EbuR128 | Self::AtscA85 => 20.0,
+        }
+    }
+
+    /// Parses the names used by the CLI and settings files
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "streaming" => Some(Self::Streaming),
+            "ebu_r128" | "r128" => Some(Self::EbuR128),
+            "atsc_a85" | "a85" => Some(Self::AtscA85),
+            _ => None,
+        }
+    }
+}
+
+/// Loudness target an export is normalized to
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoudnessNormalization {
+    /// Spec the target was taken from, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard: Option<LoudnessStandard>,
+    /// Integrated loudness target in LUFS
+    pub integrated_lufs: f64,
+    /// True-peak ceiling in dBTP
+    pub true_peak_dbtp: f64,
+    /// Loudness range target in LU
+    pub loudness_range_lu: f64,
+    /// First-pass measurement of the mix, filled in by the export before the
+    /// encode; without it the encode normalizes in a single dynamic pass
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub measured: Option<LoudnormStats>,
+}
+
+impl LoudnessNormalization {
+    /// Target of a delivery spec
+    pub fn for_standard(standard: LoudnessStandard) -> Self {
+        Self {
+            standard: Some(standard),
+            integrated_lufs: standard.integrated_lufs(),
+            true_peak_dbtp: standard.true_peak_dbtp(),
+            loudness_range_lu: standard.loudness_range_lu(),
+            measured: None,
+        }
+    }
+
+    /// Replaces the true-peak ceiling
+    pub fn with_true_peak(mut self, true_peak_dbtp: f64) -> Self {
+        self.true_peak_dbtp = true_peak_dbtp;
+        self
+    }
+
+    /// Replaces the integrated loudness target
+    pub fn with_integrated_lufs(mut self, integrated_lufs: f64) -> Self {
+        self.integrated_lufs = integrated_lufs;
+        self
+    }
+
+    /// Problems with the target, within the ranges `loudnorm` accepts
+    pub fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if !(-70.0..=-5.0).contains(&self.integrated_lufs) {
+            errors.push(format!(
+                "Loudness target must be between -70 and -5 LUFS, got {}",
+                self.integrated_lufs
+            ));
+        }
+        if !(-9.0..=0.0).contains(&self.true_peak_dbtp) {
+            errors.push(format!(
+                "True-peak ceiling must be between -9 and 0 dBTP, got {}",
+                self.true_peak_dbtp
+            ));
+        }
+        if !(1.0..=50.0).contains(&self.loudness_range_lu) {
+            errors.push(format!(
+                "Loudness range target must be between 1 and 50 LU, got {}",
+                self.loudness_range_lu
+            ));
+        }
+        errors
+    }
+
+    /// `loudnorm` filter for the current pass, resampled back to
+    /// `sample_rate` from the 192 kHz the filter outputs.
+    ///
+    /// Before measurement this is the single-pass filter the measurement pass
+    /// runs; once measured it is the linear second pass. Both print their
+    /// statistics as JSON on stderr.
+    pub fn filter(&self, sample_rate: u32) -> String {
+        let mut filter = format!(
+            "loudnorm=I={}:TP={}:LRA={}",
+            self.integrated_lufs, self.true_peak_dbtp, self.loudness_range_lu
+        );
+        if let Some(measured) = &self.measured {
+            filter.push_str(&format!(
+                ":measured_I={}:measured_TP={}:measured_LRA={}:measured_thresh={}:offset={}:linear=true",
+                measured.input_i,
+                measured.input_tp,
+                measured.input_lra,
+                measured.input_thresh,
+                measured.target_offset
+            ));
+        }
+        filter.push_str(":print_format=json");
+        if sample_rate != LOUDNORM_SAMPLE_RATE {
+            filter.push_str(&format!(",aresample={}", sample_rate));
+        }
+        filter
+    }
+
+    /// Takes the first-pass statistics, refusing a mix too quiet to correct
+    pub fn with_measurement(mut self, measured: LoudnormStats) -> Result<Self, String> {
+        if !measured.input_i.is_finite() || measured.input_i < MIN_MEASURABLE_LUFS {
+            return Err(format!(
+                "The mix is too quiet to normalize (measured {} LUFS)",
+                measured.input_i
+            ));
+        }
+        self.measured = Some(measured);
+        Ok(self)
+    }
+}
+
+/// Statistics `loudnorm` prints with `print_format=json`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoudnormStats {
+    pub input_i: f64,
+    pub input_tp: f64,
+    pub input_lra: f64,
+    pub input_thresh: f64,
+    pub output_i: f64,
+    pub output_tp: f64,
+    pub output_lra: f64,
+    pub output_thresh: f64,
+    /// `linear` or `dynamic`
+    pub normalization_type: String,
+    pub target_offset: f64,
+}
+
+impl LoudnormStats {
+    /// Reads the last `loudnorm` JSON block from FFmpeg's stderr.
+    ///
+    /// ```text
+    /// [Parsed_loudnorm_0 @ 0x1]
+    /// {
+    ///     "input_i" : "-27.61",
+    ///     ...
+    /// }
+    /// ```
+    ///
+    /// Values are strings, and silence reads as `-inf`.
+    pub fn parse(stderr: &str) -> Option<Self> {
+        let tail = stderr
+            .rfind("Parsed_loudnorm")
+            .map_or(stderr, |index| &stderr[index..]);
+        let start = tail.find('{')?;
+        let end = start + tail[start..].find('}')?;
+        let fields: std::collections::HashMap<String, String> =
+            serde_json::from_str(&tail[start..=end]).ok()?;
+        let number = |key: &str| fields.get(key)?.trim().parse::<f64>().ok();
+
+        Some(Self {
+            input_i: number("input_i")?,
+            input_tp: number("input_tp")?,
+            input_lra: number("input_lra")?,
+            input_thresh: number("input_thresh")?,
+            output_i: number("output_i")?,
+            output_tp: number("output_tp")?,
+            output_lra: number("output_lra")?,
+            output_thresh: number("output_thresh")?,
+            normalization_type: fields.get("normalization_type")?.clone(),
+            target_offset: number("target_offset")?,
+        })
+    }
+}
+
+/// Loudness of an export before and after normalization
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoudnessReport {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub standard: Option<LoudnessStandard>,
+    pub target_lufs: f64,
+    pub true_peak_ceiling_dbtp: f64,
+    /// Integrated loudness of the mix before normalization
+    pub measured_lufs: f64,
+    /// True peak of the mix before normalization
+    pub measured_true_peak_dbtp: f64,
+    /// Loudness range of the mix before normalization
+    pub measured_lra_lu: f64,
+    /// Integrated loudness of the delivered audio, when FFmpeg reported it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_lufs: Option<f64>,
+    /// True peak of the delivered audio, when FFmpeg reported it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_true_peak_dbtp: Option<f64>,
+    /// Loudness range of the delivered audio, when FFmpeg reported it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_lra_lu: Option<f64>,
+    /// `linear` or `dynamic`, as the second pass reported it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalization_type: Option<String>,
+}
+
+impl LoudnessReport {
+    /// Report for a measured target and the stderr of the encode that
+    /// applied it; `None` when the target was never measured
+    pub fn new(normalization: &LoudnessNormalization, encode_stderr: &str) -> Option<Self> {
+        let measured = normalization.measured.as_ref()?;
+        let output = LoudnormStats::parse(encode_stderr);
+        Some(Self {
+            standard: normalization.standard,
+            target_lufs: normalization.integrated_lufs,
+            true_peak_ceiling_dbtp: normalization.true_peak_dbtp,
+            measured_lufs: measured.input_i,
+            measured_true_peak_dbtp: measured.input_tp,
+            measured_lra_lu: measured.input_lra,
+            output_lufs: output.as_ref().map(|stats| stats.output_i),
+            output_true_peak_dbtp: output.as_ref().map(|stats| stats.output_tp),
+            output_lra_lu: output.as_ref().map(|stats| stats.output_lra),
+            normalization_type: output.map(|stats| stats.normalization_type),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Real `loudnorm` JSON block, preceded by an earlier filter's log line
+    const LOUDNORM_STDERR: &str = "\
+[Parsed_anull_0 @ 0x1] {not json}
+[Parsed_loudnorm_1 @ 0x55d0c8a4e2c0]
+{
+\t\"input_i\" : \"-27.61\",
+\t\"input_tp\" : \"-4.47\",
+\t\"input_lra\" : \"18.06\",
+\t\"input_thresh\" : \"-39.20\",
+\t\"output_i\" : \"-16.58\",
+\t\"output_tp\" : \"-1.50\",
+\t\"output_lra\" : \"14.78\",
+\t\"output_thresh\" : \"-27.71\",
+\t\"normalization_type\" : \"dynamic\",
+\t\"target_offset\" : \"0.58\"
+}
+[out#0/null @ 0x2] video:0kB audio:1kB";
+
+    #[test]
+    fn standards_carry_their_delivery_targets() {
+        let ebu = LoudnessNormalization::for_standard(LoudnessStandard::EbuR128);
+        assert_eq!((ebu.integrated_lufs, ebu.true_peak_dbtp), (-23.0, -1.0));
+        let atsc = LoudnessNormalization::for_standard(LoudnessStandard::AtscA85);
+        assert_eq!((atsc.integrated_lufs, atsc.true_peak_dbtp), (-24.0, -2.0));
+        assert_eq!(
+            LoudnessStandard::parse("ebu-r128"),
+            Some(LoudnessStandard::EbuR128)
+        );
+        assert_eq!(LoudnessStandard::parse("loud"), None);
+
+        let out_of_range = LoudnessNormalization::for_standard(LoudnessStandard::Streaming)
+            .with_true_peak(1.0)
+            .with_integrated_lufs(-2.0);
+        assert_eq!(out_of_range.validate().len(), 2);
+    }
+
+    #[test]
+    fn parses_the_last_loudnorm_block_from_stderr() {
+        let stats = LoudnormStats::parse(LOUDNORM_STDERR).expect("stats");
+        assert_eq!(stats.input_i, -27.61);
+        assert_eq!(stats.input_thresh, -39.2);
+        assert_eq!(stats.target_offset, 0.58);
+        assert_eq!(stats.normalization_type, "dynamic");
+        assert!(LoudnormStats::parse("[out#0/null @ 0x2] video:0kB").is_none());
+
+        let silent = LOUDNORM_STDERR.replace("\"-27.61\"", "\"-inf\"");
+        let silent = LoudnormStats::parse(&silent).expect("silent stats");
+        assert!(
+            LoudnessNormalization::for_standard(LoudnessStandard::Streaming)
+                .with_measurement(silent)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn measured_target_builds_a_linear_second_pass() {
+        let target = LoudnessNormalization::for_standard(LoudnessStandard::Streaming);
+        assert_eq!(
+            target.filter(48000),
+            "loudnorm=I=-14:TP=-1:LRA=11:print_format=json,aresample=48000"
+        );
+
+        let measured = target
+            .with_measurement(LoudnormStats::parse(LOUDNORM_STDERR).expect("stats"))
+            .expect("measurable");
+        assert_eq!(
+            measured.filter(48000),
+            "loudnorm=I=-14:TP=-1:LRA=11:measured_I=-27.61:measured_TP=-4.47:\
+             measured_LRA=18.06:measured_thresh=-39.2:offset=0.58:linear=true:\
+             print_format=json,aresample=48000"
+        );
+
+        let report = LoudnessReport::new(&measured, LOUDNORM_STDERR).expect("report");
+        assert_eq!(report.standard, Some(LoudnessStandard::Streaming));
+        assert_eq!(report.measured_lufs, -27.61);
+        assert_eq!(report.output_lufs, Some(-16.58));
+        assert_eq!(report.normalization_type.as_deref(), Some("dynamic"));
+    }
+}
@@ -0,0 +1,536 @@
+//! Chapters, tags and cover art embedded in exports.
+//!
+//! Chapter markers on the sequence become chapters in the exported file, and
+//! the project's title, author, description and copyright become its tags.
+//! Both reach FFmpeg through an `FFMETADATA1` file added as an extra input and
+//! mapped with `-map_metadata`/`-map_chapters`; MP4 and MOV store the chapters
+//! as a QuickTime chapter track, Matroska and WebM as editions. A cover image
+//! is muxed as an attached picture in MP4/MOV and as an attachment in MKV.
+//!
+//! Next to the export, the same chapters are written as a plain-text list in
+//! the format YouTube reads from a video description.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{
+    project::ProjectMeta,
+    timeline::{MarkerType, Sequence},
+};
+
+use super::ContainerFormat;
+
+/// Title of the chapter that covers the start of an export when no chapter
+/// marker does
+pub const LEADING_CHAPTER_TITLE: &str = "Intro";
+
+/// Fewest chapters YouTube turns into a chapter list
+pub const MIN_YOUTUBE_CHAPTERS: usize = 3;
+
+/// Shortest chapter YouTube accepts, in seconds
+pub const MIN_YOUTUBE_CHAPTER_SEC: f64 = 10.0;
+
+/// Chapter markers closer together than this are the same chapter
+const CHAPTER_EPSILON_SEC: f64 = 0.001;
+
+fn default_true() -> bool {
+    true
+}
+
+/// Tags, chapters and cover art written into an export
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportMetadata {
+    /// Title tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Artist tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub artist: Option<String>,
+    /// Description tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Copyright tag
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub copyright: Option<String>,
+    /// PNG or JPEG image attached as cover art (MP4, MOV and MKV only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cover_image: Option<PathBuf>,
+    /// Write the sequence's chapter markers as chapters, and the chapter list
+    /// beside the export
+    #[serde(default = "default_true")]
+    pub chapters: bool,
+}
+
+impl Default for ExportMetadata {
+    fn default() -> Self {
+        Self {
+            title: None,
+            artist: None,
+            description: None,
+            copyright: None,
+            cover_image: None,
+            chapters: true,
+        }
+    }
+}
+
+impl ExportMetadata {
+    /// Tags taken from the project settings, with chapters on
+    pub fn from_project(meta: &ProjectMeta) -> Self {
+        let non_empty = |value: &str| {
+            let value = value.trim();
+            (!value.is_empty()).then(|| value.to_string())
+        };
+        Self {
+            title: non_empty(&meta.name),
+            artist: meta.author.as_deref().and_then(non_empty),
+            description: meta.description.as_deref().and_then(non_empty),
+            copyright: meta.copyright.as_deref().and_then(non_empty),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a cover image
+    pub fn with_cover_image(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cover_image = Some(path.into());
+        self
+    }
+
+    /// Global tags as FFmpeg metadata keys, in a fixed order
+    pub fn tags(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("title", &self.title),
+            ("artist", &self.artist),
+            ("description", &self.description),
+            ("copyright", &self.copyright),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| value.as_deref().map(|value| (key, value)))
+        .collect()
+    }
+
+    /// Errors that make this metadata impossible to write into `container`
+    pub fn validate(&self, container: &ContainerFormat) -> Vec<String> {
+        let mut errors = Vec::new();
+        let Some(cover) = &self.cover_image else {
+            return errors;
+        };
+
+        if !cover_supported(container) {
+            errors.push(format!(
+                "Cover images cannot be embedded in {} exports",
+                container.extension().to_uppercase()
+            ));
+        }
+        if cover_mime_type(cover).is_none() {
+            errors.push("Cover image must be a PNG or JPEG file".to_string());
+        }
+        if let Err(error) =
+            crate::core::fs::validate_local_input_path(&cover.to_string_lossy(), "Cover image")
+        {
+            errors.push(error);
+        }
+        errors
+    }
+}
+
+/// Whether `container` stores chapters and global tags
+pub fn metadata_supported(container: &ContainerFormat) -> bool {
+    !matches!(container, ContainerFormat::Mxf)
+}
+
+/// Whether `container` can carry a cover image
+pub fn cover_supported(container: &ContainerFormat) -> bool {
+    matches!(
+        container,
+        ContainerFormat::Mp4 | ContainerFormat::Mov | ContainerFormat::Mkv
+    )
+}
+
+fn cover_mime_type(path: &Path) -> Option<&'static str> {
+    match path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_ascii_lowercase())
+        .as_deref()
+    {
+        Some("png") => Some("image/png"),
+        Some("jpg") | Some("jpeg") => Some("image/jpeg"),
+        _ => None,
+    }
+}
+
+/// One chapter of an export, in seconds from the start of the file
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterSpan {
+    pub start_sec: f64,
+    pub end_sec: f64,
+    pub title: String,
+}
+
+impl ChapterSpan {
+    pub fn duration_sec(&self) -> f64 {
+        self.end_sec - self.start_sec
+    }
+}
+
+/// Chapters of an export covering `range_start..range_end` of the timeline.
+///
+/// Each chapter marker opens a chapter that runs to the next one or to the
+/// end of the range. The first chapter always starts at zero: it takes the
+/// title of the last marker before the range, or [`LEADING_CHAPTER_TITLE`]
+/// when there is none. Returns nothing when no chapter marker reaches into
+/// the range.
+pub fn chapter_spans(sequence: &Sequence, range_start: f64, range_end: f64) -> Vec<ChapterSpan> {
+    let mut markers: Vec<(f64, &str)> = sequence
+        .markers
+        .iter()
+        .filter(|marker| marker.marker_type == MarkerType::Chapter && marker.time_sec.is_finite())
+        .map(|marker| (marker.time_sec, marker.label.trim()))
+        .collect();
+    markers.sort_by(|a, b| a.0.total_cmp(&b.0));
+    markers.dedup_by(|later, earlier| later.0 - earlier.0 < CHAPTER_EPSILON_SEC);
+
+    let mut starts: Vec<(f64, String)> = Vec::new();
+    for (index, (time_sec, label)) in markers.iter().enumerate() {
+        if *time_sec >= range_end - CHAPTER_EPSILON_SEC {
+            break;
+        }
+        let title = if label.is_empty() {
+            format!("Chapter {}", index + 1)
+        } else {
+            label.to_string()
+        };
+        if *time_sec <= range_start + CHAPTER_EPSILON_SEC {
+            starts.clear();
+            starts.push((0.0, title));
+        } else {
+            if starts.is_empty() {
+                starts.push((0.0, LEADING_CHAPTER_TITLE.to_string()));
+            }
+            starts.push((time_sec - range_start, title));
+        }
+    }
+
+    let length = range_end - range_start;
+    let ends: Vec<f64> = starts
+        .iter()
+        .skip(1)
+        .map(|(start, _)| *start)
+        .chain(std::iter::once(length))
+        .collect();
+    starts
+        .into_iter()
+        .zip(ends)
+        .map(|((start_sec, title), end_sec)| ChapterSpan {
+            start_sec,
+            end_sec,
+            title,
+        })
+        .collect()
+}
+
+/// `FFMETADATA1` file carrying `metadata`'s tags and `chapters`, with every
+/// chapter written `offset_sec` later.
+///
+/// FFmpeg subtracts an output `-ss` from mapped chapters, so an export that
+/// cuts its range that way passes the range start here.
+pub fn ffmetadata(metadata: &ExportMetadata, chapters: &[ChapterSpan], offset_sec: f64) -> String {
+    let mut text = String::from(";FFMETADATA1\n");
+    for (key, value) in metadata.tags() {
+        text.push_str(&format!("{}={}\n", key, escape_ffmetadata(value)));
+    }
+    for chapter in chapters {
+        text.push_str(&format!(
+            "\n[CHAPTER]\nTIMEBASE=1/1000\nSTART={}\nEND={}\ntitle={}\n",
+            ((chapter.start_sec + offset_sec) * 1000.0).round() as i64,
+            ((chapter.end_sec + offset_sec) * 1000.0).round() as i64,
+            escape_ffmetadata(&chapter.title)
+        ));
+    }
+    text
+}
+
+fn escape_ffmetadata(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for ch in value.chars() {
+        if matches!(ch, '=' | ';' | '#' | '\\' | '\n') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Chapter list in the `0:00 Title` form YouTube reads from a description.
+/// Times carry an hour field on every line once the export reaches an hour.
+pub fn youtube_chapter_list(chapters: &[ChapterSpan]) -> String {
+    let with_hours = chapters
+        .last()
+        .is_some_and(|chapter| chapter.end_sec >= 3600.0);
+    chapters
+        .iter()
+        .map(|chapter| {
+            let total = chapter.start_sec.max(0.0).floor() as u64;
+            let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+            let time = if with_hours {
+                format!("{}:{:02}:{:02}", hours, minutes, seconds)
+            } else {
+                format!("{}:{:02}", total / 60, seconds)
+            };
+            format!("{} {}\n", time, chapter.title.replace('\n', " "))
+        })
+        .collect()
+}
+
+/// Where the chapter list of the export at `output_path` is written
+pub fn chapter_list_path(output_path: &Path) -> PathBuf {
+    output_path.with_extension("chapters.txt")
+}
+
+/// Reasons YouTube would ignore `chapters` as a chapter list
+pub fn chapter_warnings(chapters: &[ChapterSpan]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if chapters.is_empty() {
+        return warnings;
+    }
+    if chapters.len() < MIN_YOUTUBE_CHAPTERS {
+        warnings.push(format!(
+            "Export has {} chapter(s); YouTube needs at least {}",
+            chapters.len(),
+            MIN_YOUTUBE_CHAPTERS
+        ));
+    }
+    for chapter in chapters {
+        if chapter.duration_sec() < MIN_YOUTUBE_CHAPTER_SEC {
+            warnings.push(format!(
+                "Chapter '{}' is {:.1}s long; YouTube chapters must be at least {}s",
+                chapter.title,
+                chapter.duration_sec(),
+                MIN_YOUTUBE_CHAPTER_SEC
+            ));
+        }
+    }
+    warnings
+}
+
+/// Extra inputs an export with metadata reads, by input index
+#[derive(Clone, Copy, Debug, Default)]
+pub(super) struct MetadataInputs {
+    ffmetadata: Option<usize>,
+    cover: Option<usize>,
+}
+
+/// Appends the `FFMETADATA1` file and, where the container muxes it as a
+/// stream, the cover image as inputs after those already in `args`
+pub(super) fn append_metadata_inputs(
+    args: &mut Vec<String>,
+    metadata: Option<&ExportMetadata>,
+    ffmetadata_path: Option<&Path>,
+    container: &ContainerFormat,
+) -> MetadataInputs {
+    let mut inputs = MetadataInputs::default();
+    let Some(metadata) = metadata.filter(|_| metadata_supported(container)) else {
+        return inputs;
+    };
+    let mut next_index = args.iter().filter(|arg| *arg == "-i").count();
+
+    if let Some(path) = ffmetadata_path {
+        args.extend([
+            "-f".to_string(),
+            "ffmetadata".to_string(),
+            "-i".to_string(),
+            path.to_string_lossy().to_string(),
+        ]);
+        inputs.ffmetadata = Some(next_index);
+        next_index += 1;
+    }
+    if let Some(cover) = &metadata.cover_image {
+        if matches!(container, ContainerFormat::Mp4 | ContainerFormat::Mov) {
+            args.extend(["-i".to_string(), cover.to_string_lossy().to_string()]);
+            inputs.cover = Some(next_index);
+        }
+    }
+    inputs
+}
+
+/// Appends the output options that map the metadata inputs into the export.
+///
+/// `cover_stream` is the output video stream index the cover picture gets,
+/// which is the number of video streams mapped before it; this goes after the
+/// codec options so the cover's stream copy overrides them.
+pub(super) fn append_metadata_output_args(
+    args: &mut Vec<String>,
+    metadata: Option<&ExportMetadata>,
+    inputs: MetadataInputs,
+    container: &ContainerFormat,
+    cover_stream: usize,
+) {
+    let Some(metadata) = metadata.filter(|_| metadata_supported(container)) else {
+        return;
+    };
+    if let Some(index) = inputs.ffmetadata {
+        args.extend([
+            "-map_metadata".to_string(),
+            index.to_string(),
+            "-map_chapters".to_string(),
+            index.to_string(),
+        ]);
+    }
+    if let Some(index) = inputs.cover {
+        args.extend([
+            "-map".to_string(),
+            format!("{}:v:0", index),
+            format!("-c:v:{}", cover_stream),
+            "copy".to_string(),
+            format!("-disposition:v:{}", cover_stream),
+            "attached_pic".to_string(),
+        ]);
+    }
+    if *container == ContainerFormat::Mkv {
+        if let Some(cover) = &metadata.cover_image {
+            let mime_type = cover_mime_type(cover).unwrap_or("image/jpeg");
+            let extension = if mime_type == "image/png" {
+                "png"
+            } else {
+                "jpg"
+            };
+            args.extend([
+                "-attach".to_string(),
+                cover.to_string_lossy().to_string(),
+                "-metadata:s:t:0".to_string(),
+                format!("mimetype={}", mime_type),
+                "-metadata:s:t:0".to_string(),
+                format!("filename=cover.{}", extension),
+            ]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::timeline::{Marker, SequenceFormat};
+
+    fn chapter(time_sec: f64, label: &str) -> Marker {
+        let mut marker = Marker::new(time_sec, label);
+        marker.marker_type = MarkerType::Chapter;
+        marker
+    }
+
+    fn sequence_with_chapters(chapters: &[(f64, &str)]) -> Sequence {
+        let mut sequence = Sequence::new("Chapters", SequenceFormat::youtube_1080());
+        sequence.markers = chapters
+            .iter()
+            .map(|(time_sec, label)| chapter(*time_sec, label))
+            .collect();
+        sequence.markers.push(Marker::new(5.0, "Not a chapter"));
+        sequence
+    }
+
+    #[test]
+    fn chapter_markers_become_spans_inside_the_range() {
+        let sequence = sequence_with_chapters(&[(30.0, "Setup"), (0.0, "Welcome"), (90.0, "Demo")]);
+
+        let full = chapter_spans(&sequence, 0.0, 120.0);
+        let starts: Vec<(f64, f64, &str)> = full
+            .iter()
+            .map(|span| (span.start_sec, span.end_sec, span.title.as_str()))
+            .collect();
+        assert_eq!(
+            starts,
+            vec![
+                (0.0, 30.0, "Welcome"),
+                (30.0, 90.0, "Setup"),
+                (90.0, 120.0, "Demo")
+            ]
+        );
+
+        // A range starting mid-chapter opens with that chapter, shifted to zero
+        let trimmed = chapter_spans(&sequence, 45.0, 100.0);
+        assert_eq!(trimmed.len(), 2);
+        assert_eq!(trimmed[0].title, "Setup");
+        assert_eq!((trimmed[0].start_sec, trimmed[0].end_sec), (0.0, 45.0));
+        assert_eq!((trimmed[1].start_sec, trimmed[1].end_sec), (45.0, 55.0));
+
+        // Without a marker at the start, a lead-in chapter covers it
+        let late = sequence_with_chapters(&[(20.0, "Main")]);
+        let spans = chapter_spans(&late, 0.0, 60.0);
+        assert_eq!(spans[0].title, LEADING_CHAPTER_TITLE);
+        assert_eq!(spans[1].start_sec, 20.0);
+
+        assert!(chapter_spans(&late, 0.0, 15.0).is_empty());
+        assert!(chapter_spans(&sequence_with_chapters(&[]), 0.0, 60.0).is_empty());
+    }
+
+    #[test]
+    fn ffmetadata_escapes_values_and_offsets_chapters() {
+        let metadata = ExportMetadata {
+            title: Some("Part 1; the = sign".to_string()),
+            copyright: Some("(c) 2026 #1".to_string()),
+            ..Default::default()
+        };
+        let chapters = vec![
+            ChapterSpan {
+                start_sec: 0.0,
+                end_sec: 12.5,
+                title: "Intro".to_string(),
+            },
+            ChapterSpan {
+                start_sec: 12.5,
+                end_sec: 40.0,
+                title: "Line\nbreak".to_string(),
+            },
+        ];
+
+        let text = ffmetadata(&metadata, &chapters, 10.0);
+        assert!(text.starts_with(";FFMETADATA1\n"));
+        assert!(text.contains("title=Part 1\\; the \\= sign\n"));
+        assert!(text.contains("copyright=(c) 2026 \\#1\n"));
+        assert!(!text.contains("artist="));
+        assert!(text.contains("START=10000\nEND=22500\ntitle=Intro\n"));
+        assert!(text.contains("START=22500\nEND=50000\ntitle=Line\\\nbreak\n"));
+    }
+
+    #[test]
+    fn youtube_list_uses_hours_only_for_long_exports() {
+        let span = |start_sec: f64, end_sec: f64, title: &str| ChapterSpan {
+            start_sec,
+            end_sec,
+            title: title.to_string(),
+        };
+        let short = vec![span(0.0, 65.4, "Intro"), span(65.4, 700.0, "Main")];
+        assert_eq!(youtube_chapter_list(&short), "0:00 Intro\n1:05 Main\n");
+
+        let long = vec![span(0.0, 125.0, "Intro"), span(125.0, 3725.0, "Main")];
+        assert_eq!(youtube_chapter_list(&long), "0:00:00 Intro\n0:02:05 Main\n");
+
+        assert_eq!(
+            chapter_list_path(Path::new("/exports/film.mp4")),
+            PathBuf::from("/exports/film.chapters.txt")
+        );
+    }
+
+    #[test]
+    fn warns_about_lists_youtube_would_ignore() {
+        let sequence = sequence_with_chapters(&[(0.0, "A"), (4.0, "B")]);
+        let warnings = chapter_warnings(&chapter_spans(&sequence, 0.0, 60.0));
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("at least 3"));
+        assert!(warnings[1].contains("'A'"));
+    }
+
+    #[test]
+    fn cover_images_need_a_container_that_carries_them() {
+        let metadata = ExportMetadata::default().with_cover_image("/missing/cover.gif");
+        let errors = metadata.validate(&ContainerFormat::Webm);
+        assert!(errors.iter().any(|error| error.contains("WEBM")));
+        assert!(errors.iter().any(|error| error.contains("PNG or JPEG")));
+        assert!(ExportMetadata::default()
+            .validate(&ContainerFormat::Mxf)
+            .is_empty());
+    }
+}
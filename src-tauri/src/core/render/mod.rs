@@ -6,8 +6,17 @@
 //!
 //! - `export`: Video export engine and settings
 //! - `hdr`: HDR workflow support (color spaces, tonemapping, metadata)
+//! - `streaming`: HLS/DASH adaptive-streaming packages
+//! - `animated_image`: Looping GIF and WebP exports
+//! - `metadata`: Chapters, tags and cover art embedded in exports
+//! - `loudness`: Two-pass loudness normalization to a delivery spec
+//! - `queue`: Durable render queue stored with the project
+//! - `distributed`: Parallel segment rendering across local and remote workers
 
+pub mod animated_image;
+mod audio_layout;
 pub mod cache;
+pub mod distributed;
 pub mod executor;
 pub(crate) mod export;
 pub mod ffmpeg_graph;
@@ -15,8 +24,13 @@ mod ffmpeg_plan;
 pub mod graph;
 pub mod hardware;
 pub mod hdr;
+pub mod image_sequence;
+pub mod loudness;
+pub mod metadata;
 pub mod plan;
+pub mod queue;
 pub mod smart;
+pub mod streaming;
 mod transform_layout;
 pub(crate) mod transition_stitch;
 
@@ -52,11 +66,47 @@ pub use cache::{
     RenderCacheSegment, RenderCacheStatus, SegmentFingerprint,
 };
 
+// Distributed render re-exports
+pub use distributed::{
+    DistributedExportResult, DistributedRenderSettings, WorkerSegmentRequest,
+    WorkerSegmentResponse, DEFAULT_DISTRIBUTED_SEGMENT_DURATION_SEC, MAX_LOCAL_RENDER_WORKERS,
+};
+
+// Render queue re-exports
+pub use queue::{
+    RenderQueue, RenderQueueItem, RenderQueueLock, DEFAULT_RENDER_QUEUE_MAX_ATTEMPTS,
+    MAX_RENDER_QUEUE_ATTEMPTS, RENDER_QUEUE_FILE_NAME,
+};
+
 // Smart render re-exports
 pub use smart::{
     merge_reencode_ranges, plan_smart_render, SegmentAction, SmartRenderPlan, SmartRenderSegment,
 };
 
+// Streaming package re-exports
+pub use streaming::{
+    StreamingCaptionOutput, StreamingPackageResult, StreamingPackageSettings, StreamingRendition,
+    StreamingRenditionOutput, DASH_MANIFEST_NAME, HLS_MASTER_PLAYLIST_NAME,
+};
+
+// Animated image re-exports
+pub use animated_image::{
+    AnimatedImageExportResult, AnimatedImageExportSettings, AnimatedImageFormat,
+    AnimationCandidate, GifDither,
+};
+
+// Export metadata re-exports
+pub use metadata::{ChapterSpan, ExportMetadata};
+
+// Loudness normalization re-exports
+pub use loudness::{LoudnessNormalization, LoudnessReport, LoudnessStandard, LoudnormStats};
+
+// Image sequence re-exports
+pub use image_sequence::{
+    ImageSequenceExportResult, ImageSequenceExportSettings, ImageSequenceFormat,
+    ImageSequenceFrame, ImageSequenceManifest,
+};
+
 // HDR re-exports
 pub use hdr::{
     build_colorspace_conversion_filter, build_preview_tonemap_filter, build_tonemap_filter,
@@ -0,0 +1,581 @@
+//! Durable render queue.
+//!
+//! A batch render started with `batch_render` lives only in memory, so a crash
+//! loses every item that had not finished. The queue here is stored with the
+//! project at `{project}/.openreelio/render_queue.json` and rewritten after
+//! every state change, which lets a runner pick up where the last one died:
+//! [`RenderQueue::recover_interrupted`] returns items left `Rendering` to
+//! `Pending` before anything else runs.
+//!
+//! The file is the only coordination between processes. A runner reloads it
+//! between items and while encoding, so a pause or cancel written by another
+//! process takes effect without signalling the runner directly. Every edit is
+//! made under a lock on `render_queue.json.lock`, and a runner holds
+//! `render_queue.runner.lock` for its whole run, so a second runner fails fast
+//! instead of recovering — and rendering again — the item the first is on.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::export::{RenderJobStatus, VideoExportRequest};
+use crate::core::{annotations::store::PROJECT_META_DIR, CoreError, CoreResult, SequenceId};
+
+// =============================================================================
+// Constants
+// =============================================================================
+
+/// File name of the queue inside the project metadata directory
+pub const RENDER_QUEUE_FILE_NAME: &str = "render_queue.json";
+
+/// Lock held around every load-change-save of the queue file
+pub const RENDER_QUEUE_LOCK_FILE_NAME: &str = "render_queue.json.lock";
+
+/// Lock a runner holds for as long as it works the queue
+pub const RENDER_QUEUE_RUNNER_LOCK_FILE_NAME: &str = "render_queue.runner.lock";
+
+/// Attempts an item gets before a failure is final
+pub const DEFAULT_RENDER_QUEUE_MAX_ATTEMPTS: u32 = 3;
+
+/// Upper bound on `max_attempts`, so a typo cannot retry forever
+pub const MAX_RENDER_QUEUE_ATTEMPTS: u32 = 10;
+
+const RENDER_QUEUE_VERSION: u32 = 1;
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// One export waiting in, running from, or finished in the queue
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderQueueItem {
+    pub id: String,
+    pub sequence_id: SequenceId,
+    /// Export preset identifier (e.g., "youtube_1080p")
+    pub preset: String,
+    /// Output file path for this render
+    pub output_path: String,
+    /// Optional In point in seconds for range export
+    pub in_point: Option<f64>,
+    /// Optional Out point in seconds for range export
+    pub out_point: Option<f64>,
+    /// Optional structured export settings. When omitted, `preset` is used.
+    #[serde(default)]
+    pub settings: Option<VideoExportRequest>,
+    pub status: RenderJobStatus,
+    /// Renders started so far, including one interrupted by a crash
+    #[serde(default)]
+    pub attempts: u32,
+    pub max_attempts: u32,
+    /// Error from the most recent failed attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_error: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl RenderQueueItem {
+    /// Creates a pending item with the default retry budget
+    pub fn new(sequence_id: &str, preset: &str, output_path: &str) -> Self {
+        let now = chrono::Utc::now().to_rfc3339();
+        Self {
+            id: ulid::Ulid::new().to_string(),
+            sequence_id: sequence_id.to_string(),
+            preset: preset.to_string(),
+            output_path: output_path.to_string(),
+            in_point: None,
+            out_point: None,
+            settings: None,
+            status: RenderJobStatus::Pending,
+            attempts: 0,
+            max_attempts: DEFAULT_RENDER_QUEUE_MAX_ATTEMPTS,
+            last_error: None,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    /// Restricts the render to a timeline range
+    pub fn with_range(mut self, in_point: Option<f64>, out_point: Option<f64>) -> Self {
+        self.in_point = in_point;
+        self.out_point = out_point;
+        self
+    }
+
+    /// Sets how many attempts the item gets, clamped to `1..=MAX_RENDER_QUEUE_ATTEMPTS`
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.clamp(1, MAX_RENDER_QUEUE_ATTEMPTS);
+        self
+    }
+
+    /// Whether the item has reached a state the runner will not leave on its own
+    pub fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            RenderJobStatus::Completed | RenderJobStatus::Failed | RenderJobStatus::Cancelled
+        )
+    }
+
+    fn set_status(&mut self, status: RenderJobStatus) {
+        self.status = status;
+        self.updated_at = chrono::Utc::now().to_rfc3339();
+    }
+}
+
+/// Exclusive lock on a queue lock file, released on drop
+#[derive(Debug)]
+pub struct RenderQueueLock(std::fs::File);
+
+impl Drop for RenderQueueLock {
+    fn drop(&mut self) {
+        // Keep the handle alive for the lifetime of the guard. Locks are released on drop.
+        let _ = &self.0;
+    }
+}
+
+fn open_lock_file(project_dir: &Path, file_name: &str) -> CoreResult<std::fs::File> {
+    let meta_dir = project_dir.join(PROJECT_META_DIR);
+    std::fs::create_dir_all(&meta_dir)?;
+    Ok(std::fs::OpenOptions::new()
+        .create(true)
+        .read(true)
+        .write(true)
+        .truncate(false)
+        .open(meta_dir.join(file_name))?)
+}
+
+/// The project's render queue, in run order
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenderQueue {
+    pub version: u32,
+    /// When set, runners start no new items
+    #[serde(default)]
+    pub paused: bool,
+    pub items: Vec<RenderQueueItem>,
+}
+
+impl Default for RenderQueue {
+    fn default() -> Self {
+        Self {
+            version: RENDER_QUEUE_VERSION,
+            paused: false,
+            items: Vec::new(),
+        }
+    }
+}
+
+impl RenderQueue {
+    /// Location of the queue file for a project
+    pub fn path_for(project_dir: &Path) -> PathBuf {
+        project_dir
+            .join(PROJECT_META_DIR)
+            .join(RENDER_QUEUE_FILE_NAME)
+    }
+
+    /// Loads a project's queue; a project that never queued anything has an empty one
+    pub fn load(project_dir: &Path) -> CoreResult<Self> {
+        let path = Self::path_for(project_dir);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(&path).map_err(|e| {
+            CoreError::Internal(format!(
+                "Failed to read render queue {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        serde_json::from_str(&content).map_err(|e| {
+            CoreError::Internal(format!(
+                "Failed to parse render queue {}: {}",
+                path.display(),
+                e
+            ))
+        })
+    }
+
+    /// Writes the queue atomically, so a crash mid-save leaves the previous copy
+    pub fn save(&self, project_dir: &Path) -> CoreResult<()> {
+        crate::core::fs::atomic_write_json_pretty(&Self::path_for(project_dir), self)
+    }
+
+    /// Loads the queue, applies `change` and saves the result.
+    ///
+    /// The load and the save happen under the queue's edit lock, so an edit
+    /// from another process lands wholly before or wholly after this one
+    /// rather than being overwritten by it.
+    pub fn update<T>(
+        project_dir: &Path,
+        change: impl FnOnce(&mut Self) -> CoreResult<T>,
+    ) -> CoreResult<T> {
+        let file = open_lock_file(project_dir, RENDER_QUEUE_LOCK_FILE_NAME)?;
+        // Use UFCS to avoid accidentally picking up newer std methods and violating MSRV.
+        fs2::FileExt::lock_exclusive(&file)?;
+        let _lock = RenderQueueLock(file);
+
+        let mut queue = Self::load(project_dir)?;
+        let value = change(&mut queue)?;
+        queue.save(project_dir)?;
+        Ok(value)
+    }
+
+    /// Claims the project's queue for one runner.
+    ///
+    /// Fails at once, without waiting, when another runner holds it. The
+    /// claim lasts until the returned guard is dropped or the process dies.
+    pub fn lock_runner(project_dir: &Path) -> CoreResult<RenderQueueLock> {
+        let file = open_lock_file(project_dir, RENDER_QUEUE_RUNNER_LOCK_FILE_NAME)?;
+        // Use UFCS to avoid accidentally picking up newer std methods and violating MSRV.
+        fs2::FileExt::try_lock_exclusive(&file).map_err(|_| {
+            CoreError::ValidationError(
+                "Another runner is already working this project's render queue".to_string(),
+            )
+        })?;
+        Ok(RenderQueueLock(file))
+    }
+
+    pub fn get(&self, id: &str) -> Option<&RenderQueueItem> {
+        self.items.iter().find(|item| item.id == id)
+    }
+
+    fn get_mut(&mut self, id: &str) -> CoreResult<&mut RenderQueueItem> {
+        self.items
+            .iter_mut()
+            .find(|item| item.id == id)
+            .ok_or_else(|| CoreError::NotFound(format!("Render queue item not found: {id}")))
+    }
+
+    /// Appends an item to the end of the queue
+    pub fn push(&mut self, item: RenderQueueItem) {
+        self.items.push(item);
+    }
+
+    /// Removes an item that is not currently rendering
+    pub fn remove(&mut self, id: &str) -> CoreResult<RenderQueueItem> {
+        let index = self
+            .items
+            .iter()
+            .position(|item| item.id == id)
+            .ok_or_else(|| CoreError::NotFound(format!("Render queue item not found: {id}")))?;
+        if self.items[index].status == RenderJobStatus::Rendering {
+            return Err(CoreError::ValidationError(format!(
+                "Render queue item {id} is rendering; cancel it before removing it"
+            )));
+        }
+        Ok(self.items.remove(index))
+    }
+
+    /// Moves an item to `position`, clamped to the end of the queue
+    pub fn move_item(&mut self, id: &str, position: usize) -> CoreResult<()> {
+        let index = self
+            .items
+            .iter()
+            .position(|item| item.id == id)
+            .ok_or_else(|| CoreError::NotFound(format!("Render queue item not found: {id}")))?;
+        let item = self.items.remove(index);
+        let position = position.min(self.items.len());
+        self.items.insert(position, item);
+        Ok(())
+    }
+
+    /// Holds a pending item so runners skip it until it is resumed
+    pub fn pause_item(&mut self, id: &str) -> CoreResult<()> {
+        let item = self.get_mut(id)?;
+        match item.status {
+            RenderJobStatus::Pending => {
+                item.set_status(RenderJobStatus::Paused);
+                Ok(())
+            }
+            RenderJobStatus::Paused => Ok(()),
+            ref other => Err(CoreError::ValidationError(format!(
+                "Only pending items can be paused; {id} is {other:?}"
+            ))),
+        }
+    }
+
+    /// Returns a paused item to the pending set
+    pub fn resume_item(&mut self, id: &str) -> CoreResult<()> {
+        let item = self.get_mut(id)?;
+        match item.status {
+            RenderJobStatus::Paused => {
+                item.set_status(RenderJobStatus::Pending);
+                Ok(())
+            }
+            RenderJobStatus::Pending => Ok(()),
+            ref other => Err(CoreError::ValidationError(format!(
+                "Only paused items can be resumed; {id} is {other:?}"
+            ))),
+        }
+    }
+
+    /// Cancels an item that has not finished.
+    ///
+    /// A rendering item is only marked here; the runner sees the mark on its
+    /// next poll and stops the encode.
+    pub fn cancel(&mut self, id: &str) -> CoreResult<()> {
+        let item = self.get_mut(id)?;
+        if matches!(
+            item.status,
+            RenderJobStatus::Completed | RenderJobStatus::Failed
+        ) {
+            return Err(CoreError::ValidationError(format!(
+                "Render queue item {id} has already finished"
+            )));
+        }
+        item.set_status(RenderJobStatus::Cancelled);
+        Ok(())
+    }
+
+    /// Puts a failed or cancelled item back in line with a fresh retry budget
+    pub fn retry(&mut self, id: &str) -> CoreResult<()> {
+        let item = self.get_mut(id)?;
+        if !matches!(
+            item.status,
+            RenderJobStatus::Failed | RenderJobStatus::Cancelled
+        ) {
+            return Err(CoreError::ValidationError(format!(
+                "Only failed or cancelled items can be retried; {id} is {:?}",
+                item.status
+            )));
+        }
+        item.attempts = 0;
+        item.last_error = None;
+        item.set_status(RenderJobStatus::Pending);
+        Ok(())
+    }
+
+    /// Returns items a dead runner left `Rendering` to `Pending`, or to
+    /// `Failed` when the interrupted attempt was their last, and reports
+    /// their IDs.
+    pub fn recover_interrupted(&mut self) -> Vec<String> {
+        let mut recovered = Vec::new();
+        for item in &mut self.items {
+            if item.status != RenderJobStatus::Rendering {
+                continue;
+            }
+            if item.attempts >= item.max_attempts {
+                item.last_error = Some("Render was interrupted on its last attempt".to_string());
+                item.set_status(RenderJobStatus::Failed);
+            } else {
+                item.set_status(RenderJobStatus::Pending);
+            }
+            recovered.push(item.id.clone());
+        }
+        recovered
+    }
+
+    /// The item a runner should start next, or `None` when the queue is
+    /// paused or has nothing pending
+    pub fn next_pending(&self) -> Option<&RenderQueueItem> {
+        if self.paused {
+            return None;
+        }
+        self.items
+            .iter()
+            .find(|item| item.status == RenderJobStatus::Pending)
+    }
+
+    /// Records the start of an attempt
+    pub fn mark_rendering(&mut self, id: &str) -> CoreResult<()> {
+        let item = self.get_mut(id)?;
+        if item.status != RenderJobStatus::Pending {
+            return Err(CoreError::ValidationError(format!(
+                "Render queue item {id} is {:?}, not pending",
+                item.status
+            )));
+        }
+        item.attempts += 1;
+        item.set_status(RenderJobStatus::Rendering);
+        Ok(())
+    }
+
+    /// Records a finished attempt. An item cancelled during the attempt
+    /// stays cancelled, as in [`Self::mark_failed`].
+    pub fn mark_completed(&mut self, id: &str) -> CoreResult<RenderJobStatus> {
+        let item = self.get_mut(id)?;
+        if item.status == RenderJobStatus::Cancelled {
+            return Ok(RenderJobStatus::Cancelled);
+        }
+        item.last_error = None;
+        item.set_status(RenderJobStatus::Completed);
+        Ok(RenderJobStatus::Completed)
+    }
+
+    /// Records a failed attempt: the item goes back to `Pending` while it has
+    /// attempts left and becomes `Failed` once they run out. An item
+    /// cancelled during the attempt stays cancelled.
+    pub fn mark_failed(&mut self, id: &str, error: &str) -> CoreResult<RenderJobStatus> {
+        let item = self.get_mut(id)?;
+        item.last_error = Some(error.to_string());
+        let status = if item.status == RenderJobStatus::Cancelled {
+            RenderJobStatus::Cancelled
+        } else if item.attempts < item.max_attempts {
+            RenderJobStatus::Pending
+        } else {
+            RenderJobStatus::Failed
+        };
+        item.set_status(status.clone());
+        Ok(status)
+    }
+
+    /// Returns an item whose attempt was stopped by the runner itself (for
+    /// example on Ctrl-C) to `Pending` without spending an attempt
+    pub fn mark_interrupted(&mut self, id: &str) -> CoreResult<()> {
+        let item = self.get_mut(id)?;
+        if item.status == RenderJobStatus::Rendering {
+            item.attempts = item.attempts.saturating_sub(1);
+            item.set_status(RenderJobStatus::Pending);
+        }
+        Ok(())
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn queue_with(count: usize) -> RenderQueue {
+        let mut queue = RenderQueue::default();
+        for index in 0..count {
+            queue.push(RenderQueueItem::new(
+                "seq",
+                "mp4_h264_1080p",
+                &format!("/tmp/out_{index}.mp4"),
+            ));
+        }
+        queue
+    }
+
+    #[test]
+    fn retries_a_failed_item_until_its_attempts_run_out() {
+        let mut queue = queue_with(1);
+        let id = queue.items[0].id.clone();
+        queue.items[0] = queue.items[0].clone().with_max_attempts(2);
+
+        queue.mark_rendering(&id).unwrap();
+        assert_eq!(
+            queue.mark_failed(&id, "encoder crashed").unwrap(),
+            RenderJobStatus::Pending
+        );
+        queue.mark_rendering(&id).unwrap();
+        assert_eq!(
+            queue.mark_failed(&id, "encoder crashed").unwrap(),
+            RenderJobStatus::Failed
+        );
+        assert_eq!(queue.items[0].attempts, 2);
+        assert_eq!(
+            queue.items[0].last_error.as_deref(),
+            Some("encoder crashed")
+        );
+        assert!(queue.next_pending().is_none());
+
+        queue.retry(&id).unwrap();
+        assert_eq!(queue.items[0].attempts, 0);
+        assert_eq!(
+            queue.next_pending().map(|item| item.id.as_str()),
+            Some(id.as_str())
+        );
+    }
+
+    #[test]
+    fn recovers_items_a_crashed_runner_left_rendering() {
+        let dir = TempDir::new().unwrap();
+        let mut queue = queue_with(3);
+        let ids: Vec<String> = queue.items.iter().map(|item| item.id.clone()).collect();
+        queue.mark_rendering(&ids[0]).unwrap();
+        queue.mark_completed(&ids[0]).unwrap();
+        queue.mark_rendering(&ids[1]).unwrap();
+        queue.save(dir.path()).unwrap();
+
+        let mut reloaded = RenderQueue::load(dir.path()).unwrap();
+        assert_eq!(
+            serde_json::to_value(&reloaded).unwrap(),
+            serde_json::to_value(&queue).unwrap()
+        );
+        assert_eq!(reloaded.recover_interrupted(), vec![ids[1].clone()]);
+        assert_eq!(reloaded.items[1].status, RenderJobStatus::Pending);
+        assert_eq!(reloaded.items[1].attempts, 1);
+        assert_eq!(
+            reloaded.next_pending().map(|item| item.id.as_str()),
+            Some(ids[1].as_str())
+        );
+    }
+
+    #[test]
+    fn reorders_pauses_and_cancels_items() {
+        let mut queue = queue_with(3);
+        let ids: Vec<String> = queue.items.iter().map(|item| item.id.clone()).collect();
+
+        queue.move_item(&ids[2], 0).unwrap();
+        assert_eq!(queue.items[0].id, ids[2]);
+        queue.move_item(&ids[2], 99).unwrap();
+        assert_eq!(queue.items[2].id, ids[2]);
+
+        queue.pause_item(&ids[0]).unwrap();
+        assert_eq!(
+            queue.next_pending().map(|item| item.id.as_str()),
+            Some(ids[1].as_str())
+        );
+        queue.paused = true;
+        assert!(queue.next_pending().is_none());
+        queue.paused = false;
+        queue.resume_item(&ids[0]).unwrap();
+        assert_eq!(
+            queue.next_pending().map(|item| item.id.as_str()),
+            Some(ids[0].as_str())
+        );
+
+        queue.mark_rendering(&ids[0]).unwrap();
+        assert!(queue.remove(&ids[0]).is_err());
+        queue.cancel(&ids[0]).unwrap();
+        assert_eq!(
+            queue.mark_failed(&ids[0], "Export cancelled").unwrap(),
+            RenderJobStatus::Cancelled
+        );
+        assert_eq!(queue.remove(&ids[0]).unwrap().id, ids[0]);
+        assert!(queue.pause_item("missing").is_err());
+    }
+
+    #[test]
+    fn keeps_an_item_cancelled_during_its_final_encode_cancelled() {
+        let mut queue = queue_with(1);
+        let id = queue.items[0].id.clone();
+
+        queue.mark_rendering(&id).unwrap();
+        queue.cancel(&id).unwrap();
+        assert_eq!(
+            queue.mark_completed(&id).unwrap(),
+            RenderJobStatus::Cancelled
+        );
+        assert_eq!(queue.items[0].status, RenderJobStatus::Cancelled);
+    }
+
+    #[test]
+    fn lets_only_one_runner_hold_the_queue() {
+        let dir = TempDir::new().unwrap();
+
+        let first = RenderQueue::lock_runner(dir.path()).unwrap();
+        assert!(RenderQueue::lock_runner(dir.path()).is_err());
+        // Edits still go through while a runner works the queue.
+        RenderQueue::update(dir.path(), |queue| {
+            queue.push(RenderQueueItem::new(
+                "seq",
+                "mp4_h264_1080p",
+                "/tmp/out.mp4",
+            ));
+            Ok(())
+        })
+        .unwrap();
+
+        drop(first);
+        assert!(RenderQueue::lock_runner(dir.path()).is_ok());
+    }
+}
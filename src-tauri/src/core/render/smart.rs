@@ -3,11 +3,15 @@
 //! Analyzes cache manifests to determine which segments can be copied
 //! directly (stream-copy) versus which need re-encoding during export.
 //! Reduces export time by avoiding redundant encoding of unchanged segments.
+//! Once the re-encoded segments exist, the stitcher joins them with the
+//! copied ones in one stream-copy concat.
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 
 use super::cache::{CacheSegmentState, RenderCacheConfig, RenderCacheManifest, RenderCacheSegment};
+use super::metadata::{append_metadata_inputs, append_metadata_output_args};
+use super::{ContainerFormat, ExportSettings};
 use crate::core::effects::Effect;
 use crate::core::timeline::Sequence;
 
@@ -234,6 +238,126 @@ pub fn merge_reencode_ranges(plan: &SmartRenderPlan) -> Vec<(f64, f64)> {
     ranges
 }
 
+// =============================================================================
+// Stitching
+// =============================================================================
+
+/// The files `plan` joins, in timeline order: each copied segment's cache
+/// file, and for each re-encoded one the file `encoded` maps its index to.
+///
+/// Returns `None` when a re-encoded segment has no file.
+pub fn stitch_files(
+    plan: &SmartRenderPlan,
+    encoded: &HashMap<u32, PathBuf>,
+) -> Option<Vec<PathBuf>> {
+    plan.segments
+        .iter()
+        .map(|segment| match &segment.action {
+            SegmentAction::CopyFromCache { cache_file } => Some(cache_file.clone()),
+            SegmentAction::ReEncode => encoded.get(&segment.index).cloned(),
+        })
+        .collect()
+}
+
+/// Concat-demuxer list naming `files` in order
+pub fn concat_list(files: &[PathBuf]) -> String {
+    files
+        .iter()
+        .map(|file| {
+            // The list quotes with `'`; a quote inside the path closes the
+            // string, emits an escaped quote and reopens it.
+            format!("file '{}'\n", file.to_string_lossy().replace('\'', r"'\''"))
+        })
+        .collect()
+}
+
+/// FFmpeg arguments joining the segments in `list_path` into the export,
+/// copying their video and encoding the separately rendered mix at
+/// `audio_path`, if any, through `audio_filter` with the export's audio
+/// settings. The tags and chapters in `ffmetadata_path` and the cover of
+/// `settings.metadata` are added here, since segments carry neither.
+pub fn build_stitch_args(
+    list_path: &Path,
+    audio_path: Option<&Path>,
+    audio_filter: Option<&str>,
+    ffmetadata_path: Option<&Path>,
+    settings: &ExportSettings,
+    timecode: Option<String>,
+) -> Vec<String> {
+    let mut args = vec![
+        "-hide_banner".to_string(),
+        "-f".to_string(),
+        "concat".to_string(),
+        "-safe".to_string(),
+        "0".to_string(),
+        "-i".to_string(),
+        list_path.to_string_lossy().to_string(),
+    ];
+    if let Some(audio_path) = audio_path {
+        args.push("-i".to_string());
+        args.push(audio_path.to_string_lossy().to_string());
+    }
+    let container = super::export::container_from_output_path(&settings.output_path).ok();
+    let metadata_inputs = container
+        .as_ref()
+        .map(|container| {
+            append_metadata_inputs(
+                &mut args,
+                settings.metadata.as_ref(),
+                ffmetadata_path,
+                container,
+            )
+        })
+        .unwrap_or_default();
+
+    args.extend([
+        "-map".to_string(),
+        "0:v:0".to_string(),
+        "-c:v".to_string(),
+        "copy".to_string(),
+    ]);
+    if audio_path.is_some() {
+        args.extend([
+            "-map".to_string(),
+            "1:a:0".to_string(),
+            "-c:a".to_string(),
+            settings.audio_encoder_name().to_string(),
+        ]);
+        if let Some(audio_filter) = audio_filter {
+            args.push("-af".to_string());
+            args.push(audio_filter.to_string());
+        }
+        if let Some(bitrate) = &settings.audio_bitrate {
+            args.push("-b:a".to_string());
+            args.push(bitrate.clone());
+        }
+        args.push("-shortest".to_string());
+    }
+    if let Some(container) = &container {
+        append_metadata_output_args(
+            &mut args,
+            settings.metadata.as_ref(),
+            metadata_inputs,
+            container,
+            1,
+        );
+    }
+    if let Some(timecode) = timecode {
+        args.push("-timecode".to_string());
+        args.push(timecode);
+    }
+    if matches!(
+        super::export::container_from_output_path(&settings.output_path),
+        Ok(ContainerFormat::Mp4 | ContainerFormat::Mov)
+    ) {
+        args.push("-movflags".to_string());
+        args.push("+faststart".to_string());
+    }
+    args.push("-y".to_string());
+    args.push(settings.output_path.to_string_lossy().to_string());
+    args
+}
+
 // =============================================================================
 // Tests
 // =============================================================================
@@ -242,6 +366,7 @@ pub fn merge_reencode_ranges(plan: &SmartRenderPlan) -> Vec<(f64, f64)> {
 mod tests {
     use super::*;
     use crate::core::render::cache::{CacheSegmentState, RenderCacheConfig, RenderCacheManifest};
+    use crate::core::render::{AudioCodec, ExportPreset};
     use crate::core::timeline::{
         AudioSettings, BlendMode, Canvas, Clip, ClipPlace, ClipRange, Sequence, SequenceFormat,
         Track, TrackKind, Transform,
@@ -279,6 +404,7 @@ mod tests {
             link_group_id: None,
             group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
         }
     }
@@ -310,10 +436,14 @@ mod tests {
                 sync_lock: false,
                 volume: 1.0,
                 caption_language: None,
+                output_bus_id: None,
+                surround_pan: None,
             }],
             markers: vec![],
             master_volume_db: 0.0,
+            audio_buses: vec![],
             hdr_settings: Default::default(),
+            start_timecode: None,
             created_at: "2026-01-01T00:00:00Z".to_string(),
             modified_at: "2026-01-01T00:00:00Z".to_string(),
         }
@@ -510,7 +640,9 @@ mod tests {
             tracks: vec![],
             markers: vec![],
             master_volume_db: 0.0,
+            audio_buses: vec![],
             hdr_settings: Default::default(),
+            start_timecode: None,
             created_at: "2026-01-01T00:00:00Z".to_string(),
             modified_at: "2026-01-01T00:00:00Z".to_string(),
         };
@@ -528,4 +660,83 @@ mod tests {
         assert_eq!(plan.savings_ratio(), 0.0);
         assert!(!plan.has_savings());
     }
+
+    #[test]
+    fn stitch_copies_video_and_encodes_the_mix() {
+        let mut settings =
+            ExportSettings::from_preset(ExportPreset::Youtube1080p, "/out/final.mp4".into());
+        settings.audio_codec = AudioCodec::Aac;
+        settings.audio_bitrate = Some("192k".to_string());
+
+        let args = build_stitch_args(
+            Path::new("/tmp/list.txt"),
+            Some(Path::new("/tmp/mix.wav")),
+            None,
+            None,
+            &settings,
+            None,
+        );
+        let joined = args.join(" ");
+
+        assert!(joined.contains("-f concat -safe 0 -i /tmp/list.txt -i /tmp/mix.wav"));
+        assert!(joined.contains("-map 0:v:0 -c:v copy -map 1:a:0 -c:a aac -b:a 192k"));
+        assert_eq!(args.last().unwrap(), "/out/final.mp4");
+        assert_eq!(
+            concat_list(&[PathBuf::from("/c/it's.mp4")]),
+            "file '/c/it'\\''s.mp4'\n"
+        );
+    }
+
+    #[test]
+    fn stitch_filters_the_mix_before_encoding_it() {
+        let mut settings =
+            ExportSettings::from_preset(ExportPreset::Youtube1080p, "/out/final.mp4".into());
+        settings.audio_codec = AudioCodec::Aac;
+        settings.audio_bitrate = Some("192k".to_string());
+
+        let args = build_stitch_args(
+            Path::new("/tmp/list.txt"),
+            Some(Path::new("/tmp/mix.wav")),
+            Some("loudnorm=I=-14"),
+            None,
+            &settings,
+            None,
+        );
+
+        assert!(args
+            .join(" ")
+            .contains("-map 1:a:0 -c:a aac -af loudnorm=I=-14 -b:a 192k"));
+    }
+
+    #[test]
+    fn stitch_joins_copied_and_reencoded_segments_in_timeline_order() {
+        let segment = |index: u32, action: SegmentAction| SmartRenderSegment {
+            index,
+            start_sec: f64::from(index) * 10.0,
+            end_sec: f64::from(index + 1) * 10.0,
+            action,
+        };
+        let plan = SmartRenderPlan {
+            segments: vec![
+                segment(
+                    0,
+                    SegmentAction::CopyFromCache {
+                        cache_file: PathBuf::from("/cache/seg_0.mp4"),
+                    },
+                ),
+                segment(1, SegmentAction::ReEncode),
+            ],
+            total_duration_sec: 20.0,
+        };
+        let encoded = HashMap::from([(1, PathBuf::from("/work/seg_1.mp4"))]);
+
+        assert_eq!(
+            stitch_files(&plan, &encoded),
+            Some(vec![
+                PathBuf::from("/cache/seg_0.mp4"),
+                PathBuf::from("/work/seg_1.mp4")
+            ])
+        );
+        assert_eq!(stitch_files(&plan, &HashMap::new()), None);
+    }
 }
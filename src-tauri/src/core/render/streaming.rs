@@ -0,0 +1,686 @@
+//! Adaptive-streaming packages.
+//!
+//! A package is a bitrate/resolution ladder rendered from a single decode of
+//! the sequence: the composited picture is split once per rendition and each
+//! copy is scaled and encoded on its own, while the sound is encoded once and
+//! shared. FFmpeg's DASH muxer writes the renditions as fragmented MP4 (CMAF)
+//! segments, and from the same segments the DASH manifest and the HLS master
+//! and media playlists, so a player on either protocol streams the same files.
+//!
+//! Neither muxer carries WebVTT next to fMP4 video, so caption tracks are
+//! written afterwards as sidecar `.vtt` files and added to both manifests as
+//! text renditions.
+
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::core::captions::{export_vtt, Caption};
+use crate::core::timeline::{Sequence, TrackKind};
+
+use super::{AudioCodec, ExportError, ExportSettings, VideoCodec};
+
+/// DASH manifest written into the package directory.
+pub const DASH_MANIFEST_NAME: &str = "manifest.mpd";
+
+/// HLS master playlist written into the package directory.
+pub const HLS_MASTER_PLAYLIST_NAME: &str = "master.m3u8";
+
+/// HLS rendition group the caption playlists belong to.
+const HLS_SUBTITLE_GROUP: &str = "subs";
+
+fn default_segment_duration_sec() -> f64 {
+    6.0
+}
+
+fn default_include_captions() -> bool {
+    true
+}
+
+/// One rung of a streaming ladder
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingRendition {
+    /// Picture height in pixels; the width follows the sequence's aspect ratio
+    pub height: u32,
+    /// Target video bitrate (e.g. "5M", "800k")
+    pub video_bitrate: String,
+}
+
+/// Settings for packaging a sequence for adaptive streaming.
+///
+/// Everything lands in `output_dir`: the DASH manifest, the HLS master
+/// playlist, one HLS media playlist and one run of segments per rendition and
+/// for the audio, and a `.vtt` file with its playlist per caption track.
+/// Renditions are encoded with forced keyframes every `segment_duration_sec`,
+/// so every rung cuts its segments at the same instants and a player can
+/// switch between them at any segment boundary.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingPackageSettings {
+    /// Directory the package is written to
+    pub output_dir: PathBuf,
+    /// Ladder to render, one video rendition per entry
+    pub renditions: Vec<StreamingRendition>,
+    /// Codec of every video rendition: H.264, H.265 or AV1
+    pub video_codec: VideoCodec,
+    /// AAC bitrate of the shared audio rendition (e.g. "128k")
+    pub audio_bitrate: Option<String>,
+    /// Frame rate (None = same as sequence)
+    pub fps: Option<f64>,
+    /// Target segment length in seconds
+    #[serde(default = "default_segment_duration_sec")]
+    pub segment_duration_sec: f64,
+    /// Encoder speed preset, as for [`ExportSettings::encoder_speed`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoder_speed: Option<String>,
+    /// Start time in seconds (for partial export)
+    pub start_time: Option<f64>,
+    /// End time in seconds (for partial export)
+    pub end_time: Option<f64>,
+    /// Write every caption track as a WebVTT rendition
+    #[serde(default = "default_include_captions")]
+    pub include_captions: bool,
+    /// Also draw the captions into the picture, as a plain export does
+    #[serde(default)]
+    pub burn_in_captions: bool,
+}
+
+impl StreamingPackageSettings {
+    /// Validate packaging settings
+    pub fn validate(&self) -> Result<(), ExportError> {
+        if self.renditions.is_empty() {
+            return Err(ExportError::InvalidSettings(
+                "A streaming package needs at least one rendition".to_string(),
+            ));
+        }
+
+        let mut heights = std::collections::HashSet::new();
+        for rendition in &self.renditions {
+            if rendition.height < 2 {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Rendition height {} is too small",
+                    rendition.height
+                )));
+            }
+            if !heights.insert(even(rendition.height)) {
+                return Err(ExportError::InvalidSettings(format!(
+                    "More than one rendition is {} pixels tall",
+                    rendition.height
+                )));
+            }
+            if parse_bitrate_bps(&rendition.video_bitrate).is_none() {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Invalid video bitrate '{}' for the {}p rendition",
+                    rendition.video_bitrate, rendition.height
+                )));
+            }
+        }
+
+        if !matches!(
+            self.video_codec,
+            VideoCodec::H264 | VideoCodec::H265 | VideoCodec::Av1
+        ) {
+            return Err(ExportError::InvalidSettings(format!(
+                "{:?} cannot be packaged in CMAF segments; use H.264, H.265 or AV1",
+                self.video_codec
+            )));
+        }
+
+        if let Some(bitrate) = self.audio_bitrate.as_deref() {
+            if parse_bitrate_bps(bitrate).is_none() {
+                return Err(ExportError::InvalidSettings(format!(
+                    "Invalid audio bitrate '{}'",
+                    bitrate
+                )));
+            }
+        }
+
+        if !(1.0..=60.0).contains(&self.segment_duration_sec) {
+            return Err(ExportError::InvalidSettings(
+                "Segment duration must be between 1 and 60 seconds".to_string(),
+            ));
+        }
+
+        if let Some(fps) = self.fps {
+            if !fps.is_finite() || fps <= 0.0 {
+                return Err(ExportError::InvalidSettings(
+                    "Frame rate must be greater than zero".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Path of the DASH manifest
+    pub fn dash_manifest_path(&self) -> PathBuf {
+        self.output_dir.join(DASH_MANIFEST_NAME)
+    }
+
+    /// Path of the HLS master playlist
+    pub fn hls_master_playlist_path(&self) -> PathBuf {
+        self.output_dir.join(HLS_MASTER_PLAYLIST_NAME)
+    }
+
+    /// Picture size of a rendition of `sequence`, rounded to even numbers
+    /// for 4:2:0 encoding.
+    pub fn rendition_size(
+        &self,
+        sequence: &Sequence,
+        rendition: &StreamingRendition,
+    ) -> (u32, u32) {
+        let canvas = &sequence.format.canvas;
+        let height = even(rendition.height);
+        let width = if canvas.height > 0 {
+            (f64::from(canvas.width) * f64::from(height) / f64::from(canvas.height)).round() as u32
+        } else {
+            height
+        };
+        (even(width), height)
+    }
+
+    /// Settings the shared filter graph is built from: the picture is
+    /// composited at the tallest rendition's size and scaled down from there.
+    pub fn to_export_settings(&self, sequence: &Sequence) -> ExportSettings {
+        let (width, height) = self
+            .renditions
+            .iter()
+            .map(|rendition| self.rendition_size(sequence, rendition))
+            .max_by_key(|(_, height)| *height)
+            .unzip();
+
+        ExportSettings {
+            output_path: self.dash_manifest_path(),
+            video_codec: self.video_codec.clone(),
+            audio_codec: AudioCodec::Aac,
+            width,
+            height,
+            video_bitrate: None,
+            audio_bitrate: self.audio_bitrate.clone(),
+            fps: self.fps,
+            crf: None,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            encoder_speed: self.encoder_speed.clone(),
+            ..ExportSettings::default()
+        }
+    }
+}
+
+/// A video rendition written by a streaming package
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingRenditionOutput {
+    /// Picture width in pixels
+    pub width: u32,
+    /// Picture height in pixels
+    pub height: u32,
+    /// Target video bitrate in bits per second
+    pub bandwidth: u64,
+}
+
+/// A caption track written by a streaming package
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingCaptionOutput {
+    /// Caption track name
+    pub name: String,
+    /// BCP 47 language tag, "und" when the track has none
+    pub language: String,
+    /// WebVTT file
+    pub vtt_path: PathBuf,
+    /// HLS media playlist pointing at the WebVTT file
+    pub playlist_path: PathBuf,
+}
+
+/// Result of a streaming package export
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StreamingPackageResult {
+    /// Package directory
+    pub output_dir: PathBuf,
+    /// DASH manifest
+    pub dash_manifest_path: PathBuf,
+    /// HLS master playlist
+    pub hls_master_playlist_path: PathBuf,
+    /// Video renditions, in the order they were requested
+    pub renditions: Vec<StreamingRenditionOutput>,
+    /// Caption renditions, in track order
+    pub captions: Vec<StreamingCaptionOutput>,
+    /// Duration in seconds
+    pub duration_sec: f64,
+    /// Combined size of every file in the package, in bytes
+    pub total_size: u64,
+    /// Total encoding time in seconds
+    pub encoding_time_sec: f64,
+}
+
+fn even(value: u32) -> u32 {
+    (value / 2 * 2).max(2)
+}
+
+/// Parses an FFmpeg-style bitrate ("5M", "800k", "1.5M", "2500000") into bits
+/// per second.
+pub(super) fn parse_bitrate_bps(value: &str) -> Option<u64> {
+    let value = value.trim();
+    let (number, scale) = match value.char_indices().last()? {
+        (index, 'k' | 'K') => (&value[..index], 1_000.0),
+        (index, 'm' | 'M') => (&value[..index], 1_000_000.0),
+        _ => (value, 1.0),
+    };
+    let bps = number.parse::<f64>().ok()? * scale;
+    (bps.is_finite() && bps >= 1.0).then(|| bps.round() as u64)
+}
+
+/// Captions of one caption track, timed against the start of the package.
+pub(super) struct CaptionRendition {
+    pub name: String,
+    pub language: String,
+    pub captions: Vec<Caption>,
+}
+
+/// Collects the sequence's caption tracks for a package covering
+/// `start_sec..end_sec` of the timeline.
+///
+/// Every caption track that is not muted is included, hidden or not: hiding a
+/// caption track only keeps it out of the picture. Captions are clipped to the
+/// range and shifted so the package starts at zero; tracks left with no
+/// captions are dropped.
+pub(super) fn collect_caption_renditions(
+    sequence: &Sequence,
+    start_sec: f64,
+    end_sec: f64,
+) -> Vec<CaptionRendition> {
+    sequence
+        .tracks
+        .iter()
+        .filter(|track| track.kind == TrackKind::Caption && !track.muted)
+        .filter_map(|track| {
+            let mut captions: Vec<Caption> = track
+                .clips
+                .iter()
+                .filter(|clip| clip.enabled)
+                .filter_map(|clip| {
+                    let text = clip.label.as_deref()?.trim();
+                    let start = clip.place.timeline_in_sec.max(start_sec);
+                    let end = clip.place.timeline_out_sec().min(end_sec);
+                    (!text.is_empty() && end > start)
+                        .then(|| Caption::new(&clip.id, start - start_sec, end - start_sec, text))
+                })
+                .collect();
+            if captions.is_empty() {
+                return None;
+            }
+            captions.sort_by(|a, b| a.start_sec.total_cmp(&b.start_sec));
+            Some(CaptionRendition {
+                name: track.name.clone(),
+                language: track
+                    .caption_language
+                    .as_deref()
+                    .map(str::trim)
+                    .filter(|language| !language.is_empty())
+                    .unwrap_or("und")
+                    .to_string(),
+                captions,
+            })
+        })
+        .collect()
+}
+
+/// HLS media playlist serving a whole WebVTT file as one segment.
+pub(super) fn hls_subtitle_playlist(vtt_file_name: &str, duration_sec: f64) -> String {
+    format!(
+        "#EXTM3U\n#EXT-X-VERSION:3\n#EXT-X-TARGETDURATION:{}\n#EXT-X-MEDIA-SEQUENCE:0\n\
+         #EXT-X-PLAYLIST-TYPE:VOD\n#EXTINF:{:.3},\n{}\n#EXT-X-ENDLIST\n",
+        duration_sec.ceil().max(1.0) as u64,
+        duration_sec,
+        vtt_file_name
+    )
+}
+
+/// Adds subtitle renditions to an HLS master playlist and points every
+/// variant stream at them.
+///
+/// `captions` holds the rendition and its media playlist's file name.
+pub(super) fn add_hls_subtitle_renditions(
+    master: &str,
+    captions: &[(&CaptionRendition, String)],
+) -> String {
+    if captions.is_empty() {
+        return master.to_string();
+    }
+
+    // Quoted strings in a playlist cannot hold a double quote.
+    let quoted = |value: &str| value.replace(['"', '\n', '\r'], "'");
+    let media: Vec<String> = captions
+        .iter()
+        .map(|(rendition, uri)| {
+            format!(
+                "#EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID=\"{}\",NAME=\"{}\",LANGUAGE=\"{}\",\
+                 DEFAULT=NO,AUTOSELECT=YES,URI=\"{}\"",
+                HLS_SUBTITLE_GROUP,
+                quoted(&rendition.name),
+                quoted(&rendition.language),
+                uri
+            )
+        })
+        .collect();
+
+    let mut output = String::new();
+    let mut media_written = false;
+    for line in master.lines() {
+        if line.starts_with("#EXT-X-STREAM-INF:") {
+            if !media_written {
+                for entry in &media {
+                    output.push_str(entry);
+                    output.push('\n');
+                }
+                media_written = true;
+            }
+            output.push_str(&format!("{},SUBTITLES=\"{}\"\n", line, HLS_SUBTITLE_GROUP));
+        } else {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    if !media_written {
+        for entry in &media {
+            output.push_str(entry);
+            output.push('\n');
+        }
+    }
+    output
+}
+
+/// Adds a text adaptation set per caption rendition to a DASH manifest's
+/// last period.
+///
+/// `captions` holds the rendition and its WebVTT file's name.
+pub(super) fn add_dash_text_adaptation_sets(
+    manifest: &str,
+    captions: &[(&CaptionRendition, String)],
+) -> Result<String, ExportError> {
+    if captions.is_empty() {
+        return Ok(manifest.to_string());
+    }
+
+    let period_end = manifest.rfind("</Period>").ok_or_else(|| {
+        ExportError::FFmpegFailed("DASH manifest has no period to add captions to".to_string())
+    })?;
+
+    let mut sets = String::new();
+    for (index, (rendition, uri)) in captions.iter().enumerate() {
+        sets.push_str(&format!(
+            "\t\t<AdaptationSet contentType=\"text\" mimeType=\"text/vtt\" lang=\"{}\">\n\
+             \t\t\t<Label>{}</Label>\n\
+             \t\t\t<Role schemeIdUri=\"urn:mpeg:dash:role:2011\" value=\"subtitle\"/>\n\
+             \t\t\t<Representation id=\"caption{}\" bandwidth=\"256\">\n\
+             \t\t\t\t<BaseURL>{}</BaseURL>\n\
+             \t\t\t</Representation>\n\
+             \t\t</AdaptationSet>\n",
+            xml_escape(&rendition.language),
+            xml_escape(&rendition.name),
+            index,
+            xml_escape(uri)
+        ));
+    }
+
+    let line_start = manifest[..period_end].rfind('\n').map_or(0, |i| i + 1);
+    let insert_at = if manifest[line_start..period_end].trim().is_empty() {
+        line_start
+    } else {
+        period_end
+    };
+    let mut output = manifest.to_string();
+    output.insert_str(insert_at, &sets);
+    Ok(output)
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Writes every caption rendition into the package and adds them to both
+/// manifests, which FFmpeg must already have written.
+pub(super) async fn write_caption_renditions(
+    output_dir: &Path,
+    renditions: &[CaptionRendition],
+    duration_sec: f64,
+) -> Result<Vec<StreamingCaptionOutput>, ExportError> {
+    if renditions.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut outputs = Vec::new();
+    let mut playlists = Vec::new();
+    let mut vtt_files = Vec::new();
+    for (index, rendition) in renditions.iter().enumerate() {
+        let vtt_name = format!("captions_{}.vtt", index);
+        let playlist_name = format!("captions_{}.m3u8", index);
+        let vtt_path = output_dir.join(&vtt_name);
+        let playlist_path = output_dir.join(&playlist_name);
+
+        tokio::fs::write(&vtt_path, export_vtt(&rendition.captions)).await?;
+        tokio::fs::write(
+            &playlist_path,
+            hls_subtitle_playlist(&vtt_name, duration_sec),
+        )
+        .await?;
+
+        outputs.push(StreamingCaptionOutput {
+            name: rendition.name.clone(),
+            language: rendition.language.clone(),
+            vtt_path,
+            playlist_path,
+        });
+        playlists.push((rendition, playlist_name));
+        vtt_files.push((rendition, vtt_name));
+    }
+
+    let master_path = output_dir.join(HLS_MASTER_PLAYLIST_NAME);
+    let master = tokio::fs::read_to_string(&master_path).await?;
+    tokio::fs::write(
+        &master_path,
+        add_hls_subtitle_renditions(&master, &playlists),
+    )
+    .await?;
+
+    let manifest_path = output_dir.join(DASH_MANIFEST_NAME);
+    let manifest = tokio::fs::read_to_string(&manifest_path).await?;
+    tokio::fs::write(
+        &manifest_path,
+        add_dash_text_adaptation_sets(&manifest, &vtt_files)?,
+    )
+    .await?;
+
+    Ok(outputs)
+}
+
+/// Whether a file name is one a streaming package writes.
+fn is_package_file(name: &str) -> bool {
+    name == DASH_MANIFEST_NAME
+        || name == HLS_MASTER_PLAYLIST_NAME
+        || (name.starts_with("media_") && name.ends_with(".m3u8"))
+        || ((name.starts_with("init-") || name.starts_with("chunk-")) && name.ends_with(".m4s"))
+        || (name.starts_with("captions_") && (name.ends_with(".vtt") || name.ends_with(".m3u8")))
+}
+
+/// Files of a streaming package in `output_dir`, leaving anything else alone.
+pub(super) fn package_files(output_dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(output_dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.file_name().to_str().is_some_and(is_package_file))
+        .map(|entry| entry.path())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::timeline::{Clip, SequenceFormat, Track};
+
+    fn settings(heights: &[u32]) -> StreamingPackageSettings {
+        StreamingPackageSettings {
+            output_dir: PathBuf::from("/tmp/package"),
+            renditions: heights
+                .iter()
+                .map(|height| StreamingRendition {
+                    height: *height,
+                    video_bitrate: "2M".to_string(),
+                })
+                .collect(),
+            video_codec: VideoCodec::H264,
+            audio_bitrate: Some("128k".to_string()),
+            fps: None,
+            segment_duration_sec: 6.0,
+            encoder_speed: None,
+            start_time: None,
+            end_time: None,
+            include_captions: true,
+            burn_in_captions: false,
+        }
+    }
+
+    fn caption_clip(text: &str, start: f64, duration: f64) -> Clip {
+        let mut clip = Clip::new("caption")
+            .with_source_range(0.0, duration)
+            .place_at(start);
+        clip.label = Some(text.to_string());
+        clip
+    }
+
+    #[test]
+    fn parses_bitrates_and_rejects_bad_settings() {
+        assert_eq!(parse_bitrate_bps("5M"), Some(5_000_000));
+        assert_eq!(parse_bitrate_bps("1.5m"), Some(1_500_000));
+        assert_eq!(parse_bitrate_bps("800k"), Some(800_000));
+        assert_eq!(parse_bitrate_bps("2500000"), Some(2_500_000));
+        assert_eq!(parse_bitrate_bps("fast"), None);
+        assert_eq!(parse_bitrate_bps("0k"), None);
+
+        assert!(settings(&[1080, 720]).validate().is_ok());
+        assert!(settings(&[]).validate().is_err());
+        assert!(settings(&[720, 721]).validate().is_err());
+
+        let mut vp9 = settings(&[720]);
+        vp9.video_codec = VideoCodec::Vp9;
+        assert!(vp9.validate().is_err());
+
+        let mut bad_bitrate = settings(&[720]);
+        bad_bitrate.renditions[0].video_bitrate = "lots".to_string();
+        assert!(bad_bitrate.validate().is_err());
+    }
+
+    #[test]
+    fn sizes_renditions_from_the_sequence_aspect_ratio() {
+        let sequence = Sequence::new("Ladder", SequenceFormat::youtube_1080());
+        let package = settings(&[1080, 720, 361]);
+
+        let sizes: Vec<(u32, u32)> = package
+            .renditions
+            .iter()
+            .map(|rendition| package.rendition_size(&sequence, rendition))
+            .collect();
+        assert_eq!(sizes, vec![(1920, 1080), (1280, 720), (640, 360)]);
+
+        let export = package.to_export_settings(&sequence);
+        assert_eq!((export.width, export.height), (Some(1920), Some(1080)));
+        assert_eq!(
+            export.output_path,
+            PathBuf::from("/tmp/package/manifest.mpd")
+        );
+    }
+
+    #[test]
+    fn collects_caption_tracks_clipped_to_the_range() {
+        let mut sequence = Sequence::new("Captions", SequenceFormat::youtube_1080());
+        let mut english = Track::new_caption("English");
+        english.caption_language = Some("en".to_string());
+        english.add_clip(caption_clip("Before", 0.0, 2.0));
+        english.add_clip(caption_clip("Across", 3.0, 4.0));
+        english.add_clip(caption_clip("  ", 8.0, 1.0));
+        sequence.add_track(english);
+        let mut muted = Track::new_caption("Muted");
+        muted.muted = true;
+        muted.add_clip(caption_clip("Never", 5.0, 1.0));
+        sequence.add_track(muted);
+        let mut hidden = Track::new_caption("Hidden");
+        hidden.visible = false;
+        hidden.add_clip(caption_clip("Still here", 5.0, 1.0));
+        sequence.add_track(hidden);
+
+        let renditions = collect_caption_renditions(&sequence, 4.0, 10.0);
+
+        assert_eq!(renditions.len(), 2);
+        assert_eq!(renditions[0].language, "en");
+        assert_eq!(renditions[0].captions.len(), 1);
+        assert_eq!(renditions[0].captions[0].text, "Across");
+        assert_eq!(renditions[0].captions[0].start_sec, 0.0);
+        assert_eq!(renditions[0].captions[0].end_sec, 3.0);
+        assert_eq!(renditions[1].name, "Hidden");
+        assert_eq!(renditions[1].language, "und");
+    }
+
+    #[test]
+    fn adds_subtitle_renditions_to_both_manifests() {
+        let rendition = CaptionRendition {
+            name: "English \"SDH\"".to_string(),
+            language: "en".to_string(),
+            captions: vec![Caption::new("c", 0.0, 1.0, "Hi")],
+        };
+
+        let master = "#EXTM3U\n#EXT-X-VERSION:7\n\n\
+                      #EXT-X-MEDIA:TYPE=AUDIO,GROUP-ID=\"group_A1\",NAME=\"audio_0\",DEFAULT=YES,URI=\"media_2.m3u8\"\n\
+                      #EXT-X-STREAM-INF:BANDWIDTH=5000000,RESOLUTION=1920x1080,AUDIO=\"group_A1\"\n\
+                      media_0.m3u8\n\
+                      #EXT-X-STREAM-INF:BANDWIDTH=2000000,RESOLUTION=1280x720,AUDIO=\"group_A1\"\n\
+                      media_1.m3u8\n";
+        let patched =
+            add_hls_subtitle_renditions(master, &[(&rendition, "captions_0.m3u8".to_string())]);
+        assert!(patched.contains(
+            "#EXT-X-MEDIA:TYPE=SUBTITLES,GROUP-ID=\"subs\",NAME=\"English 'SDH'\",LANGUAGE=\"en\",\
+             DEFAULT=NO,AUTOSELECT=YES,URI=\"captions_0.m3u8\"\n#EXT-X-STREAM-INF"
+        ));
+        assert_eq!(patched.matches(",SUBTITLES=\"subs\"\n").count(), 2);
+
+        let manifest =
+            "<MPD>\n\t<Period id=\"0\">\n\t\t<AdaptationSet id=\"0\"/>\n\t</Period>\n</MPD>\n";
+        let patched =
+            add_dash_text_adaptation_sets(manifest, &[(&rendition, "captions_0.vtt".to_string())])
+                .unwrap();
+        assert!(patched.contains("<Label>English &quot;SDH&quot;</Label>"));
+        assert!(patched
+            .contains("<AdaptationSet contentType=\"text\" mimeType=\"text/vtt\" lang=\"en\">"));
+        assert!(patched.ends_with("\t\t</AdaptationSet>\n\t</Period>\n</MPD>\n"));
+        assert!(add_dash_text_adaptation_sets("<MPD/>", &[(&rendition, String::new())]).is_err());
+
+        assert!(hls_subtitle_playlist("captions_0.vtt", 12.5)
+            .contains("#EXT-X-TARGETDURATION:13\n#EXT-X-MEDIA-SEQUENCE:0\n#EXT-X-PLAYLIST-TYPE:VOD\n#EXTINF:12.500,\ncaptions_0.vtt\n"));
+    }
+
+    #[test]
+    fn recognises_only_package_files() {
+        for name in [
+            "manifest.mpd",
+            "master.m3u8",
+            "media_0.m3u8",
+            "init-0.m4s",
+            "chunk-1-00003.m4s",
+            "captions_0.vtt",
+            "captions_0.m3u8",
+        ] {
+            assert!(is_package_file(name), "{name}");
+        }
+        for name in ["notes.txt", "media_0.mp4", "poster.jpg", "captions.srt"] {
+            assert!(!is_package_file(name), "{name}");
+        }
+    }
+}
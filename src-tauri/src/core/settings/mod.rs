@@ -119,12 +119,12 @@ impl AppSettings {
 
         self.export.default_format = normalize_enum(
             &self.export.default_format,
-            &["mp4", "webm", "mov", "gif"],
+            &["mp4", "webm", "mov", "mkv", "mxf", "gif"],
             default_export_format(),
         );
         self.export.default_video_codec = normalize_enum(
             &self.export.default_video_codec,
-            &["h264", "h265", "vp9", "prores"],
+            &["h264", "h265", "vp9", "av1", "prores", "dnxhr"],
             default_video_codec(),
         );
         self.export.default_audio_codec = normalize_enum(
@@ -345,11 +345,11 @@ fn default_preview_quality() -> String {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct ExportSettings {
-    /// Default export format: "mp4", "webm", "mov", "gif"
+    /// Default export format: "mp4", "webm", "mov", "mkv", "mxf", "gif"
     #[serde(default = "default_export_format")]
     pub default_format: String,
 
-    /// Default video codec: "h264", "h265", "vp9", "prores"
+    /// Default video codec: "h264", "h265", "vp9", "av1", "prores", "dnxhr"
     #[serde(default = "default_video_codec")]
     pub default_video_codec: String,
 
@@ -0,0 +1,183 @@
+//! Audio bus routing.
+//!
+//! Every audio-carrying track feeds either the master or one submix bus, and
+//! every bus feeds either the master or another bus. The routing therefore
+//! forms a forest rooted at the master, which is what lets the export mix each
+//! bus exactly once: a bus is mixed after everything that feeds it.
+
+use std::collections::HashSet;
+
+use crate::core::{CoreError, CoreResult};
+
+use super::{AudioBus, Sequence, Track};
+
+/// Checks that the sequence's audio routing is a forest rooted at the master.
+///
+/// Fails when two buses share an ID, when a track or bus routes to a bus that
+/// does not exist, or when buses feed each other in a loop.
+pub fn validate_audio_routing(sequence: &Sequence) -> CoreResult<()> {
+    let mut ids = HashSet::new();
+    for bus in &sequence.audio_buses {
+        if !ids.insert(bus.id.as_str()) {
+            return Err(CoreError::ValidationError(format!(
+                "Sequence '{}' has more than one audio bus with ID '{}'",
+                sequence.id, bus.id
+            )));
+        }
+    }
+
+    for track in &sequence.tracks {
+        if let Some(bus_id) = track.output_bus_id.as_deref() {
+            if !ids.contains(bus_id) {
+                return Err(CoreError::ValidationError(format!(
+                    "Track '{}' routes to audio bus '{}', which does not exist",
+                    track.id, bus_id
+                )));
+            }
+        }
+    }
+
+    for bus in &sequence.audio_buses {
+        bus_depth(sequence, bus)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the buses in the order they have to be mixed: every bus comes after
+/// all the buses that feed it.
+///
+/// Buses at the same depth keep their order in the sequence, so the order (and
+/// the filter graph built from it) is stable across renders.
+pub fn audio_bus_mix_order(sequence: &Sequence) -> CoreResult<Vec<&AudioBus>> {
+    validate_audio_routing(sequence)?;
+
+    let mut ordered = sequence
+        .audio_buses
+        .iter()
+        .map(|bus| bus_depth(sequence, bus).map(|depth| (depth, bus)))
+        .collect::<CoreResult<Vec<_>>>()?;
+    ordered.sort_by(|(left, _), (right, _)| right.cmp(left));
+
+    Ok(ordered.into_iter().map(|(_, bus)| bus).collect())
+}
+
+/// Returns whether routing `bus_id` into `output_bus_id` would close a loop.
+pub fn would_create_bus_cycle(sequence: &Sequence, bus_id: &str, output_bus_id: &str) -> bool {
+    let mut current = Some(output_bus_id);
+    for _ in 0..=sequence.audio_buses.len() {
+        match current {
+            Some(id) if id == bus_id => return true,
+            Some(id) => {
+                current = sequence
+                    .get_audio_bus(id)
+                    .and_then(|bus| bus.output_bus_id.as_deref())
+            }
+            None => return false,
+        }
+    }
+    true
+}
+
+/// Returns whether a track's audio reaches the master.
+///
+/// Muting a bus silences everything routed through it, so a track under a muted
+/// bus, or under a bus that feeds a muted one, is not heard. Broken routing is
+/// treated as audible; export validation reports it.
+pub fn track_audio_reaches_master(sequence: &Sequence, track: &Track) -> bool {
+    let mut current = track.output_bus_id.as_deref();
+    for _ in 0..=sequence.audio_buses.len() {
+        let Some(bus) = current.and_then(|id| sequence.get_audio_bus(id)) else {
+            return true;
+        };
+        if bus.muted {
+            return false;
+        }
+        current = bus.output_bus_id.as_deref();
+    }
+    true
+}
+
+/// Counts the buses between `bus` and the master, failing on a dangling output
+/// or a loop.
+fn bus_depth(sequence: &Sequence, bus: &AudioBus) -> CoreResult<usize> {
+    let mut depth = 0;
+    let mut current = bus;
+    while let Some(output_id) = current.output_bus_id.as_deref() {
+        current = sequence.get_audio_bus(output_id).ok_or_else(|| {
+            CoreError::ValidationError(format!(
+                "Audio bus '{}' routes to audio bus '{}', which does not exist",
+                current.id, output_id
+            ))
+        })?;
+        depth += 1;
+        if depth > sequence.audio_buses.len() {
+            return Err(CoreError::ValidationError(format!(
+                "Audio bus '{}' feeds itself through a routing loop",
+                bus.id
+            )));
+        }
+    }
+    Ok(depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::timeline::SequenceFormat;
+
+    fn routed_sequence() -> Sequence {
+        let mut sequence = Sequence::new("Mix", SequenceFormat::youtube_1080());
+        let stems = AudioBus::new("Stems");
+        let dialog = AudioBus::new("Dialog").with_output(&stems.id);
+        let music = AudioBus::new("Music");
+        sequence.audio_buses = vec![stems, dialog, music];
+        let mut dx = Track::new_audio("DX");
+        dx.output_bus_id = Some(sequence.audio_buses[1].id.clone());
+        sequence.add_track(dx);
+        sequence.add_track(Track::new_audio("FX"));
+        sequence
+    }
+
+    #[test]
+    fn orders_feeding_buses_before_the_buses_they_feed() {
+        let sequence = routed_sequence();
+        let names: Vec<&str> = audio_bus_mix_order(&sequence)
+            .unwrap()
+            .into_iter()
+            .map(|bus| bus.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Dialog", "Stems", "Music"]);
+    }
+
+    #[test]
+    fn rejects_loops_and_dangling_routes() {
+        let mut sequence = routed_sequence();
+        let stems = sequence.audio_buses[0].id.clone();
+        let dialog = sequence.audio_buses[1].id.clone();
+        assert!(would_create_bus_cycle(&sequence, &stems, &dialog));
+        assert!(!would_create_bus_cycle(
+            &sequence,
+            &sequence.audio_buses[2].id,
+            &dialog
+        ));
+
+        sequence.audio_buses[0].output_bus_id = Some(dialog);
+        assert!(validate_audio_routing(&sequence).is_err());
+        assert!(audio_bus_mix_order(&sequence).is_err());
+
+        let mut sequence = routed_sequence();
+        sequence.tracks[1].output_bus_id = Some("missing".to_string());
+        assert!(validate_audio_routing(&sequence).is_err());
+    }
+
+    #[test]
+    fn muting_a_bus_silences_every_track_beneath_it() {
+        let mut sequence = routed_sequence();
+        assert!(track_audio_reaches_master(&sequence, &sequence.tracks[0]));
+
+        sequence.audio_buses[0].muted = true;
+        assert!(!track_audio_reaches_master(&sequence, &sequence.tracks[0]));
+        assert!(track_audio_reaches_master(&sequence, &sequence.tracks[1]));
+    }
+}
@@ -2,8 +2,12 @@
 //!
 //! Handles timeline-related structures including sequences, tracks, and clips.
 
+mod audio_bus;
 mod clock;
 mod models;
+mod multicam;
 
+pub use audio_bus::*;
 pub use clock::*;
 pub use models::*;
+pub use multicam::*;
@@ -7,7 +7,9 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use tracing::warn;
 
-use crate::core::{AssetId, ClipId, Color, EffectId, Point2D, Ratio, SequenceId, TimeSec, TrackId};
+use crate::core::{
+    AssetId, BusId, ClipId, Color, EffectId, Point2D, Ratio, SequenceId, TimeSec, TrackId,
+};
 
 // =============================================================================
 // Sequence Format
@@ -227,6 +229,54 @@ impl Marker {
     }
 }
 
+// =============================================================================
+// Audio Bus
+// =============================================================================
+
+/// Audio submix bus
+///
+/// Tracks and other buses route into a bus. The bus mixes its inputs, runs its
+/// effect chain, applies its gain and feeds the result to its own output: another
+/// bus, or the master when `output_bus_id` is `None`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioBus {
+    pub id: BusId,
+    pub name: String,
+    /// Bus gain in dB (-60.0 to +6.0, 0.0 = unity gain)
+    #[serde(default)]
+    pub volume_db: f32,
+    /// Muting a bus silences everything routed through it
+    #[serde(default)]
+    pub muted: bool,
+    /// Audio effects applied to the bus mix, in order
+    #[serde(default)]
+    pub effects: Vec<EffectId>,
+    /// Bus this one feeds; `None` feeds the master
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_bus_id: Option<BusId>,
+}
+
+impl AudioBus {
+    /// Creates a unity-gain bus feeding the master
+    pub fn new(name: &str) -> Self {
+        Self {
+            id: ulid::Ulid::new().to_string(),
+            name: name.to_string(),
+            volume_db: 0.0,
+            muted: false,
+            effects: vec![],
+            output_bus_id: None,
+        }
+    }
+
+    /// Routes this bus into another bus
+    pub fn with_output(mut self, bus_id: &str) -> Self {
+        self.output_bus_id = Some(bus_id.to_string());
+        self
+    }
+}
+
 // =============================================================================
 // Sequence
 // =============================================================================
@@ -245,8 +295,15 @@ pub struct Sequence {
     /// Master output volume in dB (-60.0 to +6.0, 0.0 = unity gain)
     #[serde(default)]
     pub master_volume_db: f32,
+    /// Submix buses that tracks can route their audio through
+    #[serde(default)]
+    pub audio_buses: Vec<AudioBus>,
     #[serde(default)]
     pub hdr_settings: SequenceHdrSettings,
+    /// Timecode of the first frame (e.g. "01:00:00:00"), written to the
+    /// timecode track of mastering exports
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_timecode: Option<String>,
     pub created_at: String,
     pub modified_at: String,
 }
@@ -262,7 +319,9 @@ impl Sequence {
             tracks: vec![],
             markers: vec![],
             master_volume_db: 0.0,
+            audio_buses: vec![],
             hdr_settings: SequenceHdrSettings::default(),
+            start_timecode: None,
             created_at: now.clone(),
             modified_at: now,
         }
@@ -295,6 +354,16 @@ impl Sequence {
         self.tracks.iter_mut().find(|t| t.id == track_id)
     }
 
+    /// Gets an audio bus by ID
+    pub fn get_audio_bus(&self, bus_id: &str) -> Option<&AudioBus> {
+        self.audio_buses.iter().find(|b| b.id == bus_id)
+    }
+
+    /// Gets a mutable audio bus by ID
+    pub fn get_audio_bus_mut(&mut self, bus_id: &str) -> Option<&mut AudioBus> {
+        self.audio_buses.iter_mut().find(|b| b.id == bus_id)
+    }
+
     /// Adds a marker to the sequence
     pub fn add_marker(&mut self, marker: Marker) {
         self.markers.push(marker);
@@ -515,6 +584,12 @@ pub struct Track {
     /// BCP-47-ish language code for caption tracks.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub caption_language: Option<String>,
+    /// Bus this track's audio feeds; `None` feeds the master
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_bus_id: Option<BusId>,
+    /// Position in the surround field; `None` keeps the clips' own channels
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub surround_pan: Option<SurroundPan>,
 }
 
 impl Track {
@@ -533,6 +608,8 @@ impl Track {
             sync_lock: false,
             volume: 1.0,
             caption_language: None,
+            output_bus_id: None,
+            surround_pan: None,
         }
     }
 
@@ -802,6 +879,11 @@ pub struct AudioSettings {
     /// Editorial audio tags groundwork for future buses/routing.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub audio_tags: Vec<String>,
+    /// Source channels to play, 0-based, in output order. One channel plays
+    /// as mono, two as stereo, six as 5.1 and eight as 7.1; empty plays the
+    /// source as it is.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channel_map: Vec<u8>,
 }
 
 impl Default for AudioSettings {
@@ -817,6 +899,7 @@ impl Default for AudioSettings {
             volume_keyframes: Vec::new(),
             audio_role: None,
             audio_tags: Vec::new(),
+            channel_map: Vec::new(),
         }
     }
 }
@@ -837,6 +920,41 @@ impl AudioSettings {
     }
 }
 
+/// Channel counts a clip channel map may produce: mono, stereo, 5.1 and 7.1.
+pub const CHANNEL_MAP_LENGTHS: [usize; 4] = [1, 2, 6, 8];
+
+/// Where a track sits in the surround field.
+///
+/// The track's audio is folded to mono and spread over the output speakers by
+/// distance from this point, so it only has an effect once it is set.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SurroundPan {
+    /// Left (-1.0) to right (1.0)
+    pub x: f32,
+    /// Rear (-1.0) to front (1.0)
+    pub y: f32,
+    /// Send to the LFE channel (0.0 none to 1.0 full level)
+    #[serde(default)]
+    pub lfe: f32,
+}
+
+impl SurroundPan {
+    /// Clamps every coordinate into range.
+    pub fn clamped(self) -> Self {
+        Self {
+            x: self.x.clamp(-1.0, 1.0),
+            y: self.y.clamp(-1.0, 1.0),
+            lfe: self.lfe.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Whether every coordinate is a finite number.
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.lfe.is_finite()
+    }
+}
+
 // =============================================================================
 // Time Remapping (Variable Speed Keyframes)
 // =============================================================================
@@ -1350,6 +1468,10 @@ pub struct Clip {
     /// The clip's duration matches the inner sequence duration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub compound_sequence_id: Option<SequenceId>,
+    /// Multicam clip: the inner video track (angle) currently on screen.
+    /// Only set on compound clips whose inner video tracks are synced angles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multicam_angle: Option<TrackId>,
     /// Whether this clip is an adjustment layer.
     /// Adjustment layers are transparent clips whose effects apply to all clips below them.
     #[serde(default)]
@@ -1398,6 +1520,7 @@ impl Clip {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
             group_id: None,
         }
@@ -1449,6 +1572,7 @@ impl Clip {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: false,
             group_id: None,
         }
@@ -1568,11 +1692,24 @@ impl Clip {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: Some(inner_sequence_id.to_string()),
+            multicam_angle: None,
             is_adjustment_layer: false,
             group_id: None,
         }
     }
 
+    /// Returns true if this clip is a multicam clip (compound clip with an active angle).
+    pub fn is_multicam(&self) -> bool {
+        self.compound_sequence_id.is_some() && self.multicam_angle.is_some()
+    }
+
+    /// Creates a multicam clip over a nested sequence of synced angles.
+    pub fn multicam(inner_sequence_id: &str, duration: TimeSec, angle_track_id: &str) -> Self {
+        let mut clip = Self::compound(inner_sequence_id, duration);
+        clip.multicam_angle = Some(angle_track_id.to_string());
+        clip
+    }
+
     /// Returns true if this clip is an adjustment layer.
     pub fn is_adjustment_layer(&self) -> bool {
         self.is_adjustment_layer
@@ -1604,6 +1741,7 @@ impl Clip {
             enabled: true,
             link_group_id: None,
             compound_sequence_id: None,
+            multicam_angle: None,
             is_adjustment_layer: true,
             group_id: None,
         }
@@ -3008,6 +3146,24 @@ mod tests {
         assert_eq!(clip.asset_id, expected_asset_id);
     }
 
+    #[test]
+    fn test_multicam_clip_is_compound_with_active_angle() {
+        let clip = Clip::multicam("seq_angles", 12.0, "track_cam_b").place_at(4.0);
+
+        assert!(clip.is_compound());
+        assert!(clip.is_multicam());
+        assert_eq!(clip.multicam_angle.as_deref(), Some("track_cam_b"));
+        assert!(!Clip::compound("seq_angles", 12.0).is_multicam());
+
+        let json = serde_json::to_value(&clip).unwrap();
+        assert_eq!(json["multicamAngle"], "track_cam_b");
+        let parsed: Clip = serde_json::from_value(json).unwrap();
+        assert_eq!(parsed, clip);
+
+        let plain = serde_json::to_value(Clip::new("asset_regular")).unwrap();
+        assert!(plain.get("multicamAngle").is_none());
+    }
+
     #[test]
     fn test_adjustment_layer_absent_in_legacy_json() {
         // Backward compatibility: JSON without is_adjustment_layer deserializes as false
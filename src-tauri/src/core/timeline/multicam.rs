@@ -0,0 +1,217 @@
+//! Multicam angle resolution.
+//!
+//! A multicam clip is a compound clip whose inner sequence holds one video
+//! track per camera angle, all synced to the same inner clock. Only the active
+//! angle is on screen, so anything that needs plain media (export, flattening)
+//! asks this module for the active angle's clips cut to the multicam clip's
+//! window and placed on the parent timeline.
+
+use crate::core::{CoreError, CoreResult, TimeSec};
+
+use super::{BlendMode, Clip, Sequence, Track, TrackKind, Transform};
+
+const ANGLE_EPSILON_SEC: TimeSec = 1e-6;
+
+/// Returns the camera angles of a multicam inner sequence, top to bottom.
+pub fn multicam_angles(inner: &Sequence) -> impl Iterator<Item = &Track> {
+    inner
+        .tracks
+        .iter()
+        .filter(|track| track.kind == TrackKind::Video)
+}
+
+/// Returns whether a clip plays its source at normal speed, forwards.
+pub fn plays_at_normal_speed(clip: &Clip) -> bool {
+    (clip.speed as f64 - 1.0).abs() <= ANGLE_EPSILON_SEC
+        && !clip.reverse
+        && !clip.freeze_frame
+        && !clip.has_time_remap()
+}
+
+/// Resolves the active angle of a multicam clip into parent-timeline clips.
+///
+/// The angle's clips are trimmed to the part of the inner sequence the
+/// multicam clip shows and placed where that part plays. The multicam clip's
+/// effects run after each angle clip's own, opacity multiplies, and its blend
+/// mode, transform and link/group membership apply wherever the angle clip
+/// leaves them at their defaults. Resolved clips get IDs derived from both the
+/// multicam clip and the angle clip, so resolving twice yields the same clips.
+pub fn resolve_multicam_angle(clip: &Clip, inner: &Sequence) -> CoreResult<Vec<Clip>> {
+    let angle_id = clip
+        .multicam_angle
+        .as_deref()
+        .filter(|_| clip.is_compound())
+        .ok_or_else(|| {
+            CoreError::ValidationError(format!("Clip '{}' is not a multicam clip", clip.id))
+        })?;
+    let angle = multicam_angles(inner)
+        .find(|track| track.id == angle_id)
+        .ok_or_else(|| {
+            CoreError::ValidationError(format!(
+                "Multicam clip '{}' shows angle '{}', which is not a video track of sequence '{}'",
+                clip.id, angle_id, inner.id
+            ))
+        })?;
+    if !plays_at_normal_speed(clip) {
+        return Err(CoreError::ValidationError(format!(
+            "Multicam clip '{}' is retimed; only normal-speed multicam clips resolve to their angles",
+            clip.id
+        )));
+    }
+
+    let window_in = clip.range.source_in_sec;
+    let window_out = window_in + clip.duration();
+    let offset = clip.place.timeline_in_sec - window_in;
+
+    let mut resolved = Vec::new();
+    for angle_clip in &angle.clips {
+        let start = angle_clip.place.timeline_in_sec.max(window_in);
+        let end = angle_clip.timeline_end().min(window_out);
+        if end - start <= ANGLE_EPSILON_SEC {
+            continue;
+        }
+
+        let head = start - angle_clip.place.timeline_in_sec;
+        let tail = angle_clip.timeline_end() - end;
+        let trimmed = head > ANGLE_EPSILON_SEC || tail > ANGLE_EPSILON_SEC;
+        if trimmed && angle_clip.has_time_remap() {
+            return Err(CoreError::ValidationError(format!(
+                "Angle clip '{}' has a time remap and cannot be cut at the edge of multicam clip '{}'",
+                angle_clip.id, clip.id
+            )));
+        }
+
+        let mut piece = angle_clip.clone();
+        piece.id = format!("{}.{}", clip.id, angle_clip.id);
+        if trimmed && !angle_clip.freeze_frame {
+            let speed = angle_clip.safe_speed();
+            if angle_clip.reverse {
+                piece.range.source_out_sec -= head * speed;
+                piece.range.source_in_sec += tail * speed;
+            } else {
+                piece.range.source_in_sec += head * speed;
+                piece.range.source_out_sec -= tail * speed;
+            }
+        }
+        piece.place.timeline_in_sec = start + offset;
+        piece.place.duration_sec = end - start;
+
+        piece.enabled = angle_clip.enabled && clip.enabled;
+        piece.opacity = angle_clip.opacity * clip.opacity;
+        piece.effects.extend(clip.effects.iter().cloned());
+        if piece.blend_mode == BlendMode::Normal {
+            piece.blend_mode = clip.blend_mode.clone();
+        }
+        if piece.transform == Transform::default() && piece.motion_keyframes.is_empty() {
+            piece.transform = clip.transform.clone();
+            piece.motion_keyframes = clip.motion_keyframes.clone();
+        }
+        if piece.link_group_id.is_none() {
+            piece.link_group_id = clip.link_group_id.clone();
+        }
+        if piece.group_id.is_none() {
+            piece.group_id = clip.group_id.clone();
+        }
+        resolved.push(piece);
+    }
+
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::timeline::SequenceFormat;
+
+    fn angle_sequence() -> Sequence {
+        let mut inner = Sequence::new("Interview", SequenceFormat::youtube_1080());
+        let mut cam_a = Track::new_video("Cam A");
+        cam_a.add_clip(Clip::new("cam_a").with_source_range(10.0, 40.0));
+        let mut cam_b = Track::new_video("Cam B");
+        cam_b.add_clip(
+            Clip::new("cam_b")
+                .with_source_range(0.0, 12.0)
+                .place_at(2.0),
+        );
+        cam_b.add_clip(
+            Clip::new("cam_b")
+                .with_source_range(20.0, 35.0)
+                .place_at(15.0),
+        );
+        inner.add_track(cam_a);
+        inner.add_track(cam_b);
+        inner.add_track(Track::new_audio("Lav"));
+        inner
+    }
+
+    #[test]
+    fn lists_only_video_tracks_as_angles() {
+        let inner = angle_sequence();
+        let names: Vec<&str> = multicam_angles(&inner)
+            .map(|track| track.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Cam A", "Cam B"]);
+    }
+
+    #[test]
+    fn resolves_active_angle_trimmed_to_the_clip_window() {
+        let inner = angle_sequence();
+        let cam_b = inner.tracks[1].id.clone();
+        let mut multicam = Clip::multicam(&inner.id, 30.0, &cam_b).place_at(100.0);
+        // Show inner 5s..20s only.
+        multicam.range.source_in_sec = 5.0;
+        multicam.range.source_out_sec = 20.0;
+        multicam.place.duration_sec = 15.0;
+
+        let resolved = resolve_multicam_angle(&multicam, &inner).unwrap();
+
+        assert_eq!(resolved.len(), 2);
+        assert_eq!(resolved[0].asset_id, "cam_b");
+        assert!((resolved[0].place.timeline_in_sec - 100.0).abs() < 1e-9);
+        assert!((resolved[0].place.duration_sec - 9.0).abs() < 1e-9);
+        assert!((resolved[0].range.source_in_sec - 3.0).abs() < 1e-9);
+        assert!((resolved[0].range.source_out_sec - 12.0).abs() < 1e-9);
+        assert!((resolved[1].place.timeline_in_sec - 110.0).abs() < 1e-9);
+        assert!((resolved[1].place.duration_sec - 5.0).abs() < 1e-9);
+        assert!((resolved[1].range.source_in_sec - 20.0).abs() < 1e-9);
+        assert!((resolved[1].range.source_out_sec - 25.0).abs() < 1e-9);
+        assert_eq!(
+            resolved[0].id,
+            format!("{}.{}", multicam.id, inner.tracks[1].clips[0].id)
+        );
+    }
+
+    #[test]
+    fn stacks_multicam_presentation_onto_angle_clips() {
+        let inner = angle_sequence();
+        let cam_a = inner.tracks[0].id.clone();
+        let mut multicam = Clip::multicam(&inner.id, 30.0, &cam_a);
+        multicam.opacity = 0.5;
+        multicam.effects = vec!["grade".to_string()];
+        multicam.transform.scale.x = 1.2;
+        multicam.link_group_id = Some("interview".to_string());
+
+        let resolved = resolve_multicam_angle(&multicam, &inner).unwrap();
+
+        assert_eq!(resolved.len(), 1);
+        assert!((resolved[0].opacity - 0.5).abs() < f32::EPSILON);
+        assert_eq!(resolved[0].effects, vec!["grade".to_string()]);
+        assert_eq!(resolved[0].transform, multicam.transform);
+        assert_eq!(resolved[0].link_group_id.as_deref(), Some("interview"));
+    }
+
+    #[test]
+    fn rejects_missing_angle_and_retimed_multicam_clip() {
+        let inner = angle_sequence();
+        let lav = inner.tracks[2].id.clone();
+        let on_audio = Clip::multicam(&inner.id, 30.0, &lav);
+        assert!(resolve_multicam_angle(&on_audio, &inner).is_err());
+
+        let mut retimed = Clip::multicam(&inner.id, 30.0, &inner.tracks[0].id);
+        retimed.speed = 2.0;
+        assert!(resolve_multicam_angle(&retimed, &inner).is_err());
+
+        let compound = Clip::compound(&inner.id, 30.0);
+        assert!(resolve_multicam_angle(&compound, &inner).is_err());
+    }
+}
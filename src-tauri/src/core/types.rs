@@ -44,6 +44,9 @@ pub type BinId = String;
 /// Mask unique identifier (ULID)
 pub type MaskId = String;
 
+/// Audio bus unique identifier (ULID)
+pub type BusId = String;
+
 // =============================================================================
 // Time Types
 // =============================================================================
@@ -1,14 +1,18 @@
 //! Workspace Directory Scanner
 //!
 //! Recursively scans the project directory for media files,
-//! respecting `.openreelignore` rules.
+//! respecting `.openreelignore` rules. Numbered stills such as render passes
+//! (`shot_1001.exr`, `shot_1002.exr`, ...) are collapsed into one video entry.
 
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 use walkdir::WalkDir;
 
-use crate::core::assets::{media_kind_from_extension, AssetKind, MetadataExtractor};
+use crate::core::assets::{
+    media_kind_from_extension, AssetKind, ImageSequenceInfo, MetadataExtractor,
+};
 
 use super::ignore::IgnoreRules;
 
@@ -25,6 +29,10 @@ pub struct DiscoveredFile {
     pub file_size: u64,
     /// Last modification time
     pub modified_at: SystemTime,
+    /// Set when the entry stands for a numbered still sequence. The paths,
+    /// size and time then describe its first frame, the whole run and its
+    /// newest frame respectively.
+    pub image_sequence: Option<ImageSequenceInfo>,
 }
 
 /// Recursive directory scanner for workspace media files
@@ -34,6 +42,163 @@ pub struct WorkspaceScanner {
     max_depth: usize,
 }
 
+/// Still formats that arrive as numbered frame sequences
+const IMAGE_SEQUENCE_EXTENSIONS: &[&str] = &["exr", "dpx", "tif", "tiff", "png"];
+
+/// Fewest trailing digits that mark a still as a frame of a sequence.
+/// Shorter numbers (`take_2.png`) are far more often versions than frames.
+const MIN_FRAME_NUMBER_DIGITS: usize = 3;
+
+/// Most digits read as a frame number
+const MAX_FRAME_NUMBER_DIGITS: usize = 9;
+
+/// A still named like one frame of a sequence
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FrameName {
+    /// `printf`-style file name shared by every frame of the sequence
+    pattern: String,
+    number: u64,
+}
+
+/// Splits `shot_0042.exr` into the pattern `shot_%04d.exr` and frame 42.
+fn parse_frame_name(file_name: &str) -> Option<FrameName> {
+    let (stem, ext) = file_name.rsplit_once('.')?;
+    if !IMAGE_SEQUENCE_EXTENSIONS
+        .iter()
+        .any(|candidate| ext.eq_ignore_ascii_case(candidate))
+    {
+        return None;
+    }
+
+    let prefix = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+    let digits = stem.len() - prefix.len();
+    if !(MIN_FRAME_NUMBER_DIGITS..=MAX_FRAME_NUMBER_DIGITS).contains(&digits)
+        || prefix.contains('%')
+        || file_name.starts_with('.')
+    {
+        return None;
+    }
+
+    Some(FrameName {
+        pattern: format!("{prefix}%0{digits}d.{ext}"),
+        number: stem[prefix.len()..].parse().ok()?,
+    })
+}
+
+/// Splits frame numbers, sorted ascending, into runs without gaps
+fn contiguous_runs<T>(frames: Vec<(u64, T)>) -> Vec<Vec<(u64, T)>> {
+    let mut runs: Vec<Vec<(u64, T)>> = Vec::new();
+    for frame in frames {
+        match runs.last_mut() {
+            Some(run) if run.last().is_some_and(|(last, _)| last + 1 == frame.0) => run.push(frame),
+            _ => runs.push(vec![frame]),
+        }
+    }
+    runs
+}
+
+/// The gap-free run of numbered siblings `frame` belongs to, itself
+/// included. Gaps end a sequence, as they do for FFmpeg's `image2` demuxer.
+fn sequence_run(frame: &Path) -> Option<(FrameName, Vec<(u64, PathBuf)>)> {
+    let name = parse_frame_name(frame.file_name()?.to_str()?)?;
+    let parent = frame.parent()?;
+
+    let mut frames: Vec<(u64, PathBuf)> = std::fs::read_dir(parent)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_type().is_ok_and(|kind| kind.is_file()))
+        .filter_map(|entry| {
+            let sibling = parse_frame_name(entry.file_name().to_str()?)?;
+            (sibling.pattern == name.pattern).then(|| (sibling.number, entry.path()))
+        })
+        .collect();
+    frames.sort_by_key(|(number, _)| *number);
+
+    let run = contiguous_runs(frames)
+        .into_iter()
+        .find(|run| run.iter().any(|(number, _)| *number == name.number))?;
+    Some((name, run))
+}
+
+/// The numbered sequence `frame` belongs to, if it has at least one
+/// neighbour on disk: the path of its first frame and its extent.
+pub fn detect_image_sequence(frame: &Path) -> Option<(PathBuf, ImageSequenceInfo)> {
+    let (name, run) = sequence_run(frame)?;
+    if run.len() < 2 {
+        return None;
+    }
+
+    let (start_number, first_frame) = run.first()?.clone();
+    Some((
+        first_frame,
+        ImageSequenceInfo {
+            pattern: name.pattern,
+            start_number,
+            frame_count: run.len() as u64,
+        },
+    ))
+}
+
+/// Replaces every run of two or more numbered stills with one video entry
+/// for the whole run. Stills that are not part of a run are left alone.
+fn collapse_image_sequences(files: Vec<DiscoveredFile>) -> Vec<DiscoveredFile> {
+    let mut collapsed = Vec::with_capacity(files.len());
+    let mut groups: BTreeMap<(PathBuf, String), Vec<(u64, DiscoveredFile)>> = BTreeMap::new();
+
+    for file in files {
+        let frame = match file.kind {
+            AssetKind::Image => file
+                .absolute_path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(parse_frame_name),
+            _ => None,
+        };
+        match (frame, file.absolute_path.parent()) {
+            (Some(frame), Some(parent)) => groups
+                .entry((parent.to_path_buf(), frame.pattern))
+                .or_default()
+                .push((frame.number, file)),
+            _ => collapsed.push(file),
+        }
+    }
+
+    for ((_, pattern), mut frames) in groups {
+        frames.sort_by_key(|(number, _)| *number);
+        for run in contiguous_runs(frames) {
+            if run.len() < 2 {
+                collapsed.extend(run.into_iter().map(|(_, file)| file));
+                continue;
+            }
+
+            let frame_count = run.len() as u64;
+            let file_size = run.iter().map(|(_, file)| file.file_size).sum();
+            let modified_at = run
+                .iter()
+                .map(|(_, file)| file.modified_at)
+                .max()
+                .unwrap_or(SystemTime::UNIX_EPOCH);
+            let Some((start_number, head)) = run.into_iter().next() else {
+                continue;
+            };
+
+            collapsed.push(DiscoveredFile {
+                kind: AssetKind::Video,
+                file_size,
+                modified_at,
+                image_sequence: Some(ImageSequenceInfo {
+                    pattern: pattern.clone(),
+                    start_number,
+                    frame_count,
+                }),
+                ..head
+            });
+        }
+    }
+
+    collapsed
+}
+
 fn media_kind_for_path(path: &Path, ext: &str) -> Option<AssetKind> {
     if ext.eq_ignore_ascii_case("ogg") {
         if let Ok(metadata) = MetadataExtractor::extract(path) {
@@ -106,6 +271,7 @@ impl WorkspaceScanner {
         }
 
         // Sort by relative path for deterministic results
+        let mut files = collapse_image_sequences(files);
         files.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
         files
     }
@@ -122,7 +288,27 @@ impl WorkspaceScanner {
             return None;
         }
 
-        self.process_entry(&absolute)
+        let discovered = self.process_entry(&absolute)?;
+        if discovered.kind != AssetKind::Image {
+            return Some(discovered);
+        }
+
+        // Any frame of a sequence stands for the whole sequence
+        let Some((frame, run)) = sequence_run(&absolute) else {
+            return Some(discovered);
+        };
+        let frames = run
+            .iter()
+            .filter_map(|(_, path)| self.process_entry(path))
+            .collect();
+        collapse_image_sequences(frames)
+            .into_iter()
+            .find(|entry| match &entry.image_sequence {
+                Some(sequence) => (sequence.start_number
+                    ..sequence.start_number + sequence.frame_count)
+                    .contains(&frame.number),
+                None => entry.absolute_path == absolute,
+            })
     }
 
     /// Check if a directory entry should be skipped entirely
@@ -167,6 +353,7 @@ impl WorkspaceScanner {
             kind,
             file_size,
             modified_at,
+            image_sequence: None,
         })
     }
 }
@@ -416,6 +603,70 @@ mod tests {
         assert_eq!(media_kind_from_extension("rs"), None);
     }
 
+    #[test]
+    fn test_scan_collapses_numbered_frames_into_one_sequence() {
+        let dir = tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("renders")).unwrap();
+        for frame in 1001..=1004 {
+            std::fs::write(dir.path().join(format!("renders/shot_{frame}.exr")), "exr").unwrap();
+        }
+        // After a gap, and too short a number to be a frame
+        std::fs::write(dir.path().join("renders/shot_1006.exr"), "exr").unwrap();
+        std::fs::write(dir.path().join("renders/take_02.png"), "png").unwrap();
+        std::fs::write(dir.path().join("renders/take_03.png"), "png").unwrap();
+
+        let scanner = WorkspaceScanner::new(dir.path().to_path_buf());
+        let files = scanner.scan();
+
+        let paths: Vec<&str> = files.iter().map(|f| f.relative_path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                "renders/shot_1001.exr",
+                "renders/shot_1006.exr",
+                "renders/take_02.png",
+                "renders/take_03.png"
+            ]
+        );
+
+        let sequence = &files[0];
+        assert_eq!(sequence.kind, AssetKind::Video);
+        assert_eq!(sequence.file_size, 12);
+        assert_eq!(
+            sequence.image_sequence,
+            Some(ImageSequenceInfo {
+                pattern: "shot_%04d.exr".to_string(),
+                start_number: 1001,
+                frame_count: 4,
+            })
+        );
+        assert_eq!(files[1].kind, AssetKind::Image);
+        assert!(files[1].image_sequence.is_none());
+    }
+
+    #[test]
+    fn test_scan_path_resolves_any_frame_to_its_sequence() {
+        let dir = tempdir().unwrap();
+        for frame in 1..=3 {
+            std::fs::write(dir.path().join(format!("pass.{frame:03}.dpx")), "dpx").unwrap();
+        }
+
+        let scanner = WorkspaceScanner::new(dir.path().to_path_buf());
+        let file = scanner.scan_path(Path::new("pass.002.dpx")).unwrap();
+
+        assert_eq!(file.relative_path, "pass.001.dpx");
+        assert_eq!(file.kind, AssetKind::Video);
+        assert_eq!(
+            file.image_sequence.map(|sequence| sequence.pattern),
+            Some("pass.%03d.dpx".to_string())
+        );
+        assert_eq!(
+            detect_image_sequence(&dir.path().join("pass.003.dpx"))
+                .map(|(first_frame, sequence)| (first_frame, sequence.frame_count)),
+            Some((dir.path().join("pass.001.dpx"), 3))
+        );
+    }
+
     #[test]
     fn test_media_kind_for_path_falls_back_to_extension_when_probe_fails() {
         let dir = tempdir().unwrap();
@@ -8,13 +8,13 @@ use std::sync::Arc;
 
 use tokio::sync::mpsc;
 
-use crate::core::assets::{Asset, AssetKind, MetadataExtractor};
+use crate::core::assets::{Asset, AssetKind, MetadataExtractor, DEFAULT_IMAGE_SEQUENCE_FPS};
 use crate::core::project::ProjectState;
-use crate::core::CoreResult;
+use crate::core::{CoreResult, Ratio};
 
 use super::ignore::IgnoreRules;
 use super::index::{AssetIndex, IndexEntry};
-use super::scanner::WorkspaceScanner;
+use super::scanner::{detect_image_sequence, WorkspaceScanner};
 use super::watcher::{WorkspaceEvent, WorkspaceWatcher, WORKSPACE_EVENT_CHANNEL_CAPACITY};
 
 /// Result of a workspace scan operation
@@ -73,6 +73,23 @@ fn build_workspace_asset(entry: &IndexEntry, absolute_path: &std::path::Path) ->
         .unwrap_or(&entry.relative_path);
     let extracted_metadata = MetadataExtractor::extract(absolute_path).ok();
 
+    // The scanner indexes a numbered still sequence as a video under its
+    // first frame. Probing that frame gives the picture, not the timing.
+    if matches!(entry.kind, AssetKind::Video) {
+        if let Some((_, sequence)) = detect_image_sequence(absolute_path)
+            .filter(|(first_frame, _)| first_frame == absolute_path)
+        {
+            let mut video = extracted_metadata
+                .as_ref()
+                .and_then(|metadata| metadata.video.clone())
+                .unwrap_or_default();
+            video.fps = Ratio::new(DEFAULT_IMAGE_SEQUENCE_FPS, 1);
+            let name = sequence.pattern.clone();
+            return Asset::new_image_sequence(&name, &uri, video, sequence)
+                .with_file_size(entry.file_size);
+        }
+    }
+
     let resolved_file_size = extracted_metadata
         .as_ref()
         .map(|metadata| metadata.file_size)
@@ -117,6 +134,7 @@ fn build_workspace_asset(entry: &IndexEntry, absolute_path: &std::path::Path) ->
         if let Some(audio) = metadata.audio.clone() {
             asset.audio = Some(audio);
         }
+        asset = asset.with_source(metadata.source.clone());
     }
 
     asset.with_file_size(resolved_file_size)
@@ -157,6 +175,9 @@ fn refresh_existing_workspace_asset_metadata(
     if asset.audio.is_none() {
         asset.audio = refreshed.audio;
     }
+    if asset.source.is_none() {
+        asset.source = refreshed.source;
+    }
 }
 
 impl WorkspaceService {
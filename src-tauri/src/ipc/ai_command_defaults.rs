@@ -13,6 +13,13 @@ pub(crate) fn command_needs_sequence_id(command_type: &str) -> bool {
             | "DeleteClip"
             | "RemoveClip"
             | "TrimClip"
+            | "SlipClip"
+            | "SlideClip"
+            | "RollEdit"
+            | "CreateMulticamClip"
+            | "SwitchMulticamAngle"
+            | "FlattenMulticamClip"
+            | "SyncClips"
             | "MoveClip"
             | "SetClipTransform"
             | "SetClipMute"
@@ -51,6 +58,12 @@ pub(crate) fn command_needs_sequence_id(command_type: &str) -> bool {
             | "deleteMarker"
             | "ReorderTracks"
             | "reorderTracks"
+            | "CreateAudioBus"
+            | "RemoveAudioBus"
+            | "UpdateAudioBus"
+            | "SetTrackOutputBus"
+            | "AddAudioBusEffect"
+            | "RemoveAudioBusEffect"
     )
 }
 
@@ -82,6 +95,10 @@ pub(crate) fn command_needs_track_id(command_type: &str) -> bool {
             | "DeleteClip"
             | "RemoveClip"
             | "TrimClip"
+            | "SlipClip"
+            | "SlideClip"
+            | "SwitchMulticamAngle"
+            | "FlattenMulticamClip"
             | "MoveClip"
             | "UpdateCaption"
             | "CreateCaption"
@@ -132,6 +149,14 @@ mod tests {
     #[test]
     fn command_needs_track_id_marks_clip_targeted_commands() {
         assert!(command_needs_track_id("TrimClip"));
+        assert!(command_needs_track_id("SlipClip"));
+        assert!(!command_needs_track_id("RollEdit"));
+        assert!(command_needs_track_id("SwitchMulticamAngle"));
+        assert!(!command_needs_track_id("CreateMulticamClip"));
+        assert!(!command_needs_track_id("SyncClips"));
+        assert!(command_needs_sequence_id("SyncClips"));
+        assert!(!command_needs_track_id("SetTrackOutputBus"));
+        assert!(command_needs_sequence_id("SetTrackOutputBus"));
         assert!(command_needs_track_id("UpdateCaption"));
         assert!(!command_needs_track_id("InsertEdit"));
     }
@@ -783,6 +783,10 @@ pub async fn apply_edit_script(
         ));
     }
 
+    use crate::core::commands::{
+        AddAudioBusEffectCommand, CreateAudioBusCommand, RemoveAudioBusCommand,
+        RemoveAudioBusEffectCommand, SetTrackOutputBusCommand, UpdateAudioBusCommand,
+    };
     use crate::core::commands::{
         AddAudioKeyframeCommand, AddEffectCommand, AddMarkerCommand, AddMaskCommand,
         AddTextClipCommand, AddTrackCommand, ApplyAudioDuckingCommand, ClearTimeRemapCommand,
@@ -798,15 +802,19 @@ pub async fn apply_edit_script(
         SetAudioKeyframeValueCommand, SetCaptionTrackLanguageCommand, SetClipAudioCommand,
         SetClipBlendModeCommand, SetClipEnabledCommand, SetClipMotionKeyframesCommand,
         SetClipMuteCommand, SetClipOpacityCommand, SetClipSlowMotionInterpolationCommand,
-        SetClipSpeedCommand, SetClipTransformCommand, SetMasterVolumeCommand, SetTimeRemapCommand,
-        SetTrackBlendModeCommand, SetTrackVolumeCommand, SplitClipCommand, ToggleTrackLockCommand,
-        ToggleTrackMuteCommand, ToggleTrackVisibilityCommand, TrimClipCommand, UngroupClipsCommand,
-        UnlinkClipsCommand, UnnestCompoundClipCommand, UpdateAssetCommand, UpdateEffectCommand,
-        UpdateMaskCommand, UpdateSequenceHdrSettingsCommand, UpdateTextCommand,
+        SetClipSpeedCommand, SetClipTransformCommand, SetMasterVolumeCommand,
+        SetSequenceStartTimecodeCommand, SetTimeRemapCommand, SetTrackBlendModeCommand,
+        SetTrackSurroundPanCommand, SetTrackVolumeCommand, SplitClipCommand,
+        ToggleTrackLockCommand, ToggleTrackMuteCommand, ToggleTrackVisibilityCommand,
+        TrimClipCommand, UngroupClipsCommand, UnlinkClipsCommand, UnnestCompoundClipCommand,
+        UpdateAssetCommand, UpdateEffectCommand, UpdateMaskCommand,
+        UpdateSequenceHdrSettingsCommand, UpdateTextCommand,
     };
     use crate::core::commands::{
-        CreateAdjustmentLayerCommand, CreateCompoundClipCommand, PasteAttributesCommand,
-        PasteEffectsCommand, RemoveAttributesCommand,
+        CreateAdjustmentLayerCommand, CreateCompoundClipCommand, CreateMulticamClipCommand,
+        FlattenMulticamClipCommand, PasteAttributesCommand, PasteEffectsCommand,
+        RemoveAttributesCommand, RollEditCommand, SlideClipCommand, SlipClipCommand,
+        SwitchMulticamAngleCommand,
     };
 
     let mut guard = state.project.lock().await;
@@ -1044,6 +1052,25 @@ pub async fn apply_edit_script(
                     p.new_timeline_in,
                 ))
             }
+            CommandPayload::SlipClip(p) => Box::new(SlipClipCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.clip_id,
+                p.delta_sec,
+            )),
+            CommandPayload::SlideClip(p) => Box::new(SlideClipCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.clip_id,
+                p.delta_sec,
+            )),
+            CommandPayload::RollEdit(p) => Box::new(RollEditCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.outgoing_clip_id,
+                &p.incoming_clip_id,
+                p.delta_sec,
+            )),
             CommandPayload::SplitClip(p) => {
                 if let Err(e) = validate_time_sec("splitTime", p.split_time) {
                     errors.push(format!("Command validation failed (SplitClip): {e}"));
@@ -1160,16 +1187,22 @@ pub async fn apply_edit_script(
                 &p.clip_id,
                 p.muted,
             )),
-            CommandPayload::SetClipAudio(p) => Box::new(SetClipAudioCommand::new(
-                &p.sequence_id,
-                &p.track_id,
-                &p.clip_id,
-                p.volume_db,
-                p.pan,
-                p.muted,
-                p.fade_in_sec,
-                p.fade_out_sec,
-            )),
+            CommandPayload::SetClipAudio(p) => {
+                let mut cmd = SetClipAudioCommand::new(
+                    &p.sequence_id,
+                    &p.track_id,
+                    &p.clip_id,
+                    p.volume_db,
+                    p.pan,
+                    p.muted,
+                    p.fade_in_sec,
+                    p.fade_out_sec,
+                );
+                if let Some(channel_map) = p.channel_map {
+                    cmd = cmd.with_channel_map(channel_map);
+                }
+                Box::new(cmd)
+            }
             CommandPayload::AddAudioKeyframe(p) => Box::new(AddAudioKeyframeCommand::new(
                 &p.sequence_id,
                 &p.track_id,
@@ -1218,9 +1251,61 @@ pub async fn apply_edit_script(
             CommandPayload::SetMasterVolume(p) => {
                 Box::new(SetMasterVolumeCommand::new(&p.sequence_id, p.volume_db))
             }
+            CommandPayload::CreateAudioBus(p) => {
+                let mut cmd = CreateAudioBusCommand::new(&p.sequence_id, &p.name);
+                if let Some(output_bus_id) = p.output_bus_id.as_deref() {
+                    cmd = cmd.with_output(output_bus_id);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::RemoveAudioBus(p) => {
+                Box::new(RemoveAudioBusCommand::new(&p.sequence_id, &p.bus_id))
+            }
+            CommandPayload::UpdateAudioBus(p) => {
+                let mut cmd = UpdateAudioBusCommand::new(&p.sequence_id, &p.bus_id);
+                if let Some(name) = p.name.as_deref() {
+                    cmd = cmd.with_name(name);
+                }
+                if let Some(volume_db) = p.volume_db {
+                    cmd = cmd.with_volume_db(volume_db);
+                }
+                if let Some(muted) = p.muted {
+                    cmd = cmd.with_muted(muted);
+                }
+                if p.route_to_master {
+                    cmd = cmd.with_output(None);
+                } else if let Some(output_bus_id) = p.output_bus_id {
+                    cmd = cmd.with_output(Some(output_bus_id));
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::SetTrackOutputBus(p) => Box::new(SetTrackOutputBusCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                p.bus_id.as_deref(),
+            )),
+            CommandPayload::AddAudioBusEffect(p) => {
+                let mut cmd =
+                    AddAudioBusEffectCommand::new(&p.sequence_id, &p.bus_id, p.effect_type);
+                for (key, value) in p.params {
+                    cmd = cmd.with_param(key, value);
+                }
+                if let Some(position) = p.position {
+                    cmd = cmd.at_position(position);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::RemoveAudioBusEffect(p) => Box::new(RemoveAudioBusEffectCommand::new(
+                &p.sequence_id,
+                &p.bus_id,
+                &p.effect_id,
+            )),
             CommandPayload::UpdateSequenceHdrSettings(p) => Box::new(
                 UpdateSequenceHdrSettingsCommand::new(&p.sequence_id, p.settings),
             ),
+            CommandPayload::SetSequenceStartTimecode(p) => Box::new(
+                SetSequenceStartTimecodeCommand::new(&p.sequence_id, p.start_timecode),
+            ),
             CommandPayload::SetTrackBlendMode(p) => Box::new(SetTrackBlendModeCommand::new(
                 &p.sequence_id,
                 &p.track_id,
@@ -1280,12 +1365,24 @@ pub async fn apply_edit_script(
                 if let Some(missing) = p.missing {
                     cmd = cmd.with_missing(missing);
                 }
+                if let Some(source) = p.source {
+                    cmd = cmd.with_source(source.map(|source| *source));
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::CreateSequence(p) => {
+                let mut cmd = CreateSequenceCommand::new(
+                    &p.name,
+                    &p.format.unwrap_or_else(|| "1080p".to_string()),
+                );
+                if let Some(fps) = p.fps {
+                    cmd = cmd.with_fps(fps);
+                }
+                if let Some(add_default_tracks) = p.add_default_tracks {
+                    cmd = cmd.with_default_tracks(add_default_tracks);
+                }
                 Box::new(cmd)
             }
-            CommandPayload::CreateSequence(p) => Box::new(CreateSequenceCommand::new(
-                &p.name,
-                &p.format.unwrap_or_else(|| "1080p".to_string()),
-            )),
             CommandPayload::CreateTrack(p) => {
                 let mut track_cmd = AddTrackCommand::new(&p.sequence_id, &p.name, p.kind);
                 if let Some(position) = p.position {
@@ -1309,6 +1406,11 @@ pub async fn apply_edit_script(
                 &p.track_id,
                 p.volume,
             )),
+            CommandPayload::SetTrackSurroundPan(p) => Box::new(SetTrackSurroundPanCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                p.surround_pan,
+            )),
             CommandPayload::ToggleTrackMute(p) => Box::new(ToggleTrackMuteCommand::new(
                 &p.sequence_id,
                 &p.track_id,
@@ -1533,6 +1635,47 @@ pub async fn apply_edit_script(
                 &p.track_id,
                 &p.clip_id,
             )),
+            CommandPayload::CreateMulticamClip(p) => {
+                let mut cmd =
+                    CreateMulticamClipCommand::new(&p.sequence_id, &p.track_id, p.clip_ids);
+                if let Some(name) = p.name {
+                    cmd = cmd.with_name(&name);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::SwitchMulticamAngle(p) => {
+                let mut cmd = SwitchMulticamAngleCommand::new(
+                    &p.sequence_id,
+                    &p.track_id,
+                    &p.clip_id,
+                    &p.angle_track_id,
+                );
+                if let Some(at_sec) = p.at_sec {
+                    cmd = cmd.at(at_sec);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::FlattenMulticamClip(p) => Box::new(FlattenMulticamClipCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.clip_id,
+            )),
+            CommandPayload::SyncClips(p) => Box::new(
+                SyncClipsCommand::new(
+                    &p.sequence_id,
+                    &p.reference_track_id,
+                    &p.reference_clip_id,
+                    p.clips
+                        .into_iter()
+                        .map(|clip| SyncClipOffset {
+                            track_id: clip.track_id,
+                            clip_id: clip.clip_id,
+                            offset_sec: clip.offset_sec,
+                        })
+                        .collect(),
+                )
+                .with_link(p.link),
+            ),
             CommandPayload::CreateAdjustmentLayer(p) => {
                 let mut cmd = CreateAdjustmentLayerCommand::new(
                     &p.sequence_id,
@@ -1893,6 +2036,35 @@ pub async fn validate_edit_script(
                     ));
                 }
             }
+            // Slip/Slide/Roll: require the clip(s) and a signed deltaSec
+            "SlipClip" | "SlideClip" | "RollEdit" => {
+                let clip_keys: &[&str] = if cmd.command_type == "RollEdit" {
+                    &["outgoingClipId", "incomingClipId"]
+                } else {
+                    &["clipId"]
+                };
+                for key in clip_keys {
+                    if cmd.params.get(*key).is_none() {
+                        issues.push(format!(
+                            "{} command {} missing {}",
+                            cmd.command_type, i, key
+                        ));
+                    }
+                }
+                match cmd.params.get("deltaSec") {
+                    None => issues.push(format!(
+                        "{} command {} missing deltaSec",
+                        cmd.command_type, i
+                    )),
+                    Some(v) => match v.as_f64() {
+                        Some(t) if t.is_finite() => {}
+                        _ => issues.push(format!(
+                            "{} command {} invalid deltaSec (must be a finite number)",
+                            cmd.command_type, i
+                        )),
+                    },
+                }
+            }
             // ExtractEdit: require inPoint and outPoint
             "ExtractEdit" => {
                 match cmd.params.get("inPoint") {
@@ -2059,6 +2231,39 @@ pub async fn validate_edit_script(
                     issues.push(format!("SetMasterVolume command {} missing volumeDb", i));
                 }
             }
+            // Audio bus commands
+            "CreateAudioBus"
+            | "createAudioBus"
+            | "RemoveAudioBus"
+            | "removeAudioBus"
+            | "UpdateAudioBus"
+            | "updateAudioBus"
+            | "SetTrackOutputBus"
+            | "setTrackOutputBus"
+            | "AddAudioBusEffect"
+            | "addAudioBusEffect"
+            | "RemoveAudioBusEffect"
+            | "removeAudioBusEffect" => {
+                let required: &[&str] = match cmd.command_type.as_str() {
+                    "CreateAudioBus" | "createAudioBus" => &["sequenceId", "name"],
+                    "SetTrackOutputBus" | "setTrackOutputBus" => &["sequenceId", "trackId"],
+                    "AddAudioBusEffect" | "addAudioBusEffect" => {
+                        &["sequenceId", "busId", "effectType"]
+                    }
+                    "RemoveAudioBusEffect" | "removeAudioBusEffect" => {
+                        &["sequenceId", "busId", "effectId"]
+                    }
+                    _ => &["sequenceId", "busId"],
+                };
+                for key in required {
+                    if cmd.params.get(*key).is_none() {
+                        issues.push(format!(
+                            "{} command {} missing {}",
+                            cmd.command_type, i, key
+                        ));
+                    }
+                }
+            }
             // Clip enable/disable command
             "SetClipEnabled" | "setClipEnabled" => {
                 if cmd.params.get("trackId").is_none() {
@@ -2181,6 +2386,75 @@ pub async fn validate_edit_script(
                     issues.push(format!("UnnestCompoundClip command {} missing clipId", i));
                 }
             }
+            "CreateMulticamClip" | "createMulticamClip" => {
+                for key in ["sequenceId", "trackId"] {
+                    if cmd.params.get(key).is_none() {
+                        issues.push(format!("CreateMulticamClip command {} missing {}", i, key));
+                    }
+                }
+                let angle_clip_count = cmd
+                    .params
+                    .get("clipIds")
+                    .and_then(|v| v.as_array())
+                    .map_or(0, |a| a.len());
+                if angle_clip_count < 2 {
+                    issues.push(format!(
+                        "CreateMulticamClip command {} needs a clipIds array with at least 2 angle clips",
+                        i
+                    ));
+                }
+            }
+            "SwitchMulticamAngle" | "switchMulticamAngle" => {
+                for key in ["sequenceId", "trackId", "clipId", "angleTrackId"] {
+                    if cmd.params.get(key).is_none() {
+                        issues.push(format!("SwitchMulticamAngle command {} missing {}", i, key));
+                    }
+                }
+                if let Some(v) = cmd.params.get("atSec").filter(|v| !v.is_null()) {
+                    if !v.as_f64().is_some_and(|t| t.is_finite() && t >= 0.0) {
+                        issues.push(format!(
+                            "SwitchMulticamAngle command {} invalid atSec (must be a non-negative number)",
+                            i
+                        ));
+                    }
+                }
+            }
+            "FlattenMulticamClip" | "flattenMulticamClip" => {
+                for key in ["sequenceId", "trackId", "clipId"] {
+                    if cmd.params.get(key).is_none() {
+                        issues.push(format!("FlattenMulticamClip command {} missing {}", i, key));
+                    }
+                }
+            }
+            "SyncClips" | "syncClips" => {
+                for key in ["sequenceId", "referenceTrackId", "referenceClipId"] {
+                    if cmd.params.get(key).is_none() {
+                        issues.push(format!("SyncClips command {} missing {}", i, key));
+                    }
+                }
+                match cmd.params.get("clips").and_then(|v| v.as_array()) {
+                    Some(clips) if !clips.is_empty() => {
+                        for (j, entry) in clips.iter().enumerate() {
+                            let has_ids = entry.get("trackId").is_some_and(|v| v.is_string())
+                                && entry.get("clipId").is_some_and(|v| v.is_string());
+                            let offset_ok = entry
+                                .get("offsetSec")
+                                .and_then(|v| v.as_f64())
+                                .is_some_and(f64::is_finite);
+                            if !has_ids || !offset_ok {
+                                issues.push(format!(
+                                    "SyncClips command {} clips[{}] needs trackId, clipId and a finite offsetSec",
+                                    i, j
+                                ));
+                            }
+                        }
+                    }
+                    _ => issues.push(format!(
+                        "SyncClips command {} requires a non-empty clips array",
+                        i
+                    )),
+                }
+            }
             "CreateAdjustmentLayer" | "createAdjustmentLayer" => {
                 if cmd.params.get("sequenceId").is_none() {
                     issues.push(format!(
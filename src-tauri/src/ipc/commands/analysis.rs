@@ -27,6 +27,9 @@ use std::time::Duration;
 
 use tauri::State;
 
+use crate::core::analysis::audio_sync::{
+    compute_sync_offsets, AudioSyncParams, AudioSyncResult, AudioSyncSource,
+};
 use crate::core::analysis::clip_analysis::{
     analyze_timeline_clip_bundle, inspect_timeline_range_bundles,
     load_clip_analysis_bundle_optional, map_timeline_times_for_clip, ClipAnalysisBundle,
@@ -52,18 +55,21 @@ use crate::core::analysis::{
     SemanticTemporalEditPlan, SemanticTemporalEditPlanOptions,
 };
 use crate::core::analysis::{AnalysisBundle, AnalysisJobRunner, AnalysisOptions, VideoMetadata};
-use crate::core::commands::AddEffectCommand;
+use crate::core::commands::{AddEffectCommand, SyncClipOffset, SyncClipsCommand};
 #[cfg(feature = "ai-providers")]
 use crate::core::credentials::{CredentialType, CredentialVault};
 use crate::core::effects::{curve_points_to_json, CurvePoint, EffectType, ParamValue};
-use crate::core::ffmpeg::{FFmpegRunner, MediaInfo, SharedFFmpegState};
-use crate::core::fs::export_allowed_roots;
+use crate::core::ffmpeg::{FFmpegRunner, MediaInfo, SharedFFmpegState, WaveformData};
+use crate::core::fs::{export_allowed_roots, validate_path_id_component};
+use crate::core::interchange::models::Timecode;
 use crate::core::jobs::{Job, JobStatus, JobType, Priority};
 use crate::core::project::ProjectState;
 #[cfg(feature = "ai-providers")]
 use crate::core::settings::{ProviderType, SettingsManager};
+use crate::core::{CoreError, Ratio};
 #[cfg(feature = "ai-providers")]
 use crate::ipc::commands::system::get_app_data_dir;
+use crate::ipc::commands::timeline::CommandResultDto;
 use crate::AppState;
 
 #[cfg(feature = "ai-providers")]
@@ -1406,6 +1412,296 @@ fn build_curves_params(correction: &ColorCorrection) -> HashMap<String, ParamVal
     params
 }
 
+// =============================================================================
+// Audio Sync Commands
+// =============================================================================
+
+/// Envelope rate used for audio sync; matches the default waveform cache rate.
+const AUDIO_SYNC_SAMPLES_PER_SECOND: u32 = 100;
+
+/// A clip to sync against the reference clip.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioSyncClipRef {
+    pub track_id: String,
+    pub clip_id: String,
+}
+
+/// Arguments for syncing clips to a reference clip by their audio.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncClipsByAudioArgs {
+    pub sequence_id: String,
+    pub reference_track_id: String,
+    pub reference_clip_id: String,
+    pub clips: Vec<AudioSyncClipRef>,
+    /// Analysis parameters (defaults when omitted)
+    #[serde(default)]
+    pub params: Option<AudioSyncParams>,
+    /// Link every synced clip with the reference clip
+    #[serde(default)]
+    pub link: bool,
+    /// Start timecode ("HH:MM:SS:FF") per asset ID, used when audio is inconclusive
+    #[serde(default)]
+    pub start_timecodes: HashMap<String, String>,
+}
+
+/// Result of syncing clips by audio.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, specta::Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncClipsByAudioResult {
+    /// Per-asset offsets and confidence
+    pub analysis: AudioSyncResult,
+    /// The placement edit, or `None` when no target clip could be synced
+    pub command: Option<CommandResultDto>,
+}
+
+/// An asset taking part in an audio sync, as resolved from project state.
+struct AudioSyncAssetContext {
+    asset_id: String,
+    media_path: PathBuf,
+    has_audio: bool,
+    start_timecode_sec: Option<f64>,
+}
+
+/// Syncs clips to a reference clip by cross-correlating their audio.
+///
+/// Envelopes come from the waveform cache, generated with FFmpeg when an asset
+/// has none at the sync rate. Assets whose audio does not match confidently
+/// fall back to the start timecodes passed in. Synced clips are then placed
+/// (and optionally linked) with a single undoable `SyncClips` command;
+/// unsynced ones stay where they are and are reported in the analysis.
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state, ffmpeg_state))]
+pub async fn sync_clips_by_audio(
+    args: SyncClipsByAudioArgs,
+    state: State<'_, AppState>,
+    ffmpeg_state: State<'_, SharedFFmpegState>,
+) -> Result<SyncClipsByAudioResult, String> {
+    let params = args.params.clone().unwrap_or_default();
+
+    // 1. Resolve the assets behind the reference and target clips
+    let (assets, clip_assets, project_path) = {
+        let guard = state.project.lock().await;
+        let project = guard
+            .as_ref()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+
+        let mut assets: Vec<AudioSyncAssetContext> = Vec::new();
+        let mut clip_assets = Vec::with_capacity(args.clips.len() + 1);
+        let refs = std::iter::once((&args.reference_track_id, &args.reference_clip_id)).chain(
+            args.clips
+                .iter()
+                .map(|clip| (&clip.track_id, &clip.clip_id)),
+        );
+        for (track_id, clip_id) in refs {
+            let asset_id =
+                resolve_sync_clip_asset(&project.state, &args.sequence_id, track_id, clip_id)?;
+            if !assets.iter().any(|asset| asset.asset_id == asset_id) {
+                assets.push(audio_sync_asset_context(
+                    &project.state,
+                    &project.path,
+                    &asset_id,
+                    &args.start_timecodes,
+                )?);
+            }
+            clip_assets.push(asset_id);
+        }
+
+        (assets, clip_assets, project.path.clone())
+    };
+
+    // 2. Load or extract each asset's audio envelope
+    let cache_dir = project_path
+        .join(".openreelio")
+        .join("cache")
+        .join("waveforms");
+    tokio::fs::create_dir_all(&cache_dir)
+        .await
+        .map_err(|e| format!("Failed to create waveform cache directory: {}", e))?;
+
+    let mut sources = Vec::with_capacity(assets.len());
+    for asset in assets {
+        let envelope = if asset.has_audio {
+            Some(load_sync_envelope(&asset, &cache_dir, &ffmpeg_state).await?)
+        } else {
+            None
+        };
+        sources.push(AudioSyncSource {
+            asset_id: asset.asset_id,
+            envelope,
+            start_timecode_sec: asset.start_timecode_sec,
+        });
+    }
+
+    // 3. Compute offsets
+    let analysis = compute_sync_offsets(&sources, &params).map_err(|e| e.to_ipc_error())?;
+
+    let offsets: Vec<SyncClipOffset> = args
+        .clips
+        .iter()
+        .zip(clip_assets.iter().skip(1))
+        .filter_map(|(clip, asset_id)| {
+            analysis.offset_for(asset_id).map(|offset| SyncClipOffset {
+                track_id: clip.track_id.clone(),
+                clip_id: clip.clip_id.clone(),
+                offset_sec: offset.offset_sec,
+            })
+        })
+        .collect();
+    if offsets.is_empty() {
+        return Ok(SyncClipsByAudioResult {
+            analysis,
+            command: None,
+        });
+    }
+
+    // 4. Place the synced clips (atomic, undoable)
+    let command = SyncClipsCommand::new(
+        &args.sequence_id,
+        &args.reference_track_id,
+        &args.reference_clip_id,
+        offsets,
+    )
+    .with_link(args.link);
+
+    let result = {
+        let mut guard = state.project.lock().await;
+        let project = guard
+            .as_mut()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+        project
+            .executor
+            .execute(Box::new(command), &mut project.state)
+            .map_err(|e| e.to_ipc_error())?
+    };
+
+    tracing::info!(
+        synced = analysis.offsets.len(),
+        unsynced = analysis.unsynced.len(),
+        "Audio sync applied"
+    );
+
+    Ok(SyncClipsByAudioResult {
+        analysis,
+        command: Some(CommandResultDto {
+            op_id: result.op_id,
+            created_ids: result.created_ids,
+            deleted_ids: result.deleted_ids,
+        }),
+    })
+}
+
+/// Returns the asset ID of a clip on a given track.
+fn resolve_sync_clip_asset(
+    state: &ProjectState,
+    sequence_id: &str,
+    track_id: &str,
+    clip_id: &str,
+) -> Result<String, String> {
+    let sequence = state
+        .sequences
+        .get(sequence_id)
+        .ok_or_else(|| CoreError::SequenceNotFound(sequence_id.to_string()).to_ipc_error())?;
+    let track = sequence
+        .get_track(track_id)
+        .ok_or_else(|| CoreError::TrackNotFound(track_id.to_string()).to_ipc_error())?;
+    let clip = track
+        .get_clip(clip_id)
+        .ok_or_else(|| CoreError::ClipNotFound(clip_id.to_string()).to_ipc_error())?;
+    Ok(clip.asset_id.clone())
+}
+
+/// Collects what audio sync needs to know about an asset.
+fn audio_sync_asset_context(
+    state: &ProjectState,
+    project_path: &std::path::Path,
+    asset_id: &str,
+    start_timecodes: &HashMap<String, String>,
+) -> Result<AudioSyncAssetContext, String> {
+    validate_path_id_component(asset_id, "assetId")?;
+    let asset = state
+        .assets
+        .get(asset_id)
+        .ok_or_else(|| CoreError::AssetNotFound(asset_id.to_string()).to_ipc_error())?;
+
+    let start_timecode_sec = start_timecodes
+        .get(asset_id)
+        .map(|text| {
+            let fps = asset
+                .video
+                .as_ref()
+                .map_or_else(|| Ratio::new(30, 1), |video| video.fps.clone());
+            start_timecode_seconds(text, &fps)
+        })
+        .transpose()?;
+
+    Ok(AudioSyncAssetContext {
+        asset_id: asset_id.to_string(),
+        media_path: resolve_asset_media_path(project_path, &asset.uri),
+        has_audio: asset.audio.is_some(),
+        start_timecode_sec,
+    })
+}
+
+/// Parses a start timecode into seconds at the asset's frame rate.
+fn start_timecode_seconds(text: &str, fps: &Ratio) -> Result<f64, String> {
+    Timecode::parse(text)
+        .map(|timecode| timecode.to_seconds(fps))
+        .ok_or_else(|| format!("Invalid start timecode '{}'", text))
+}
+
+/// Reads an asset's envelope from the waveform cache, extracting it if the
+/// cache is missing or was generated at another rate.
+///
+/// A cached waveform at another rate is left alone (the timeline draws it);
+/// the sync-rate envelope is extracted to a scratch file instead.
+async fn load_sync_envelope(
+    asset: &AudioSyncAssetContext,
+    cache_dir: &std::path::Path,
+    ffmpeg_state: &SharedFFmpegState,
+) -> Result<WaveformData, String> {
+    let cache_path = cache_dir.join(format!("{}.json", asset.asset_id));
+    if let Ok(json) = tokio::fs::read_to_string(&cache_path).await {
+        if let Ok(waveform) = serde_json::from_str::<WaveformData>(&json) {
+            if waveform.samples_per_second == AUDIO_SYNC_SAMPLES_PER_SECOND {
+                return Ok(waveform);
+            }
+        }
+    }
+
+    let scratch = cache_path.exists();
+    let output_path = if scratch {
+        cache_dir.join(format!(
+            "{}.sync_{}.json",
+            asset.asset_id,
+            ulid::Ulid::new()
+        ))
+    } else {
+        cache_path
+    };
+
+    let ffmpeg_guard = ffmpeg_state.read().await;
+    let runner = ffmpeg_guard
+        .runner()
+        .ok_or_else(|| "FFmpeg runner not available".to_string())?;
+    let waveform = runner
+        .generate_waveform_json(
+            &asset.media_path,
+            &output_path,
+            AUDIO_SYNC_SAMPLES_PER_SECOND,
+        )
+        .await
+        .map_err(|e| format!("Failed to extract audio for {}: {}", asset.asset_id, e))?;
+
+    if scratch {
+        let _ = tokio::fs::remove_file(&output_path).await;
+    }
+
+    Ok(waveform)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1460,6 +1756,7 @@ mod tests {
                 channels: 2,
                 codec: "aac".to_string(),
                 bitrate: Some(192_000),
+                channel_layout: None,
             }),
             format: "mov,mp4,m4a,3gp,3g2,mj2".to_string(),
             size_bytes: 123,
@@ -1480,4 +1777,11 @@ mod tests {
             PathBuf::from("/project/media/clip.mp4")
         );
     }
+
+    #[test]
+    fn start_timecode_seconds_uses_asset_frame_rate() {
+        let seconds = start_timecode_seconds("01:00:00:12", &Ratio::new(24, 1)).unwrap();
+        assert!((seconds - 3600.5).abs() < 1e-9);
+        assert!(start_timecode_seconds("not a timecode", &Ratio::new(24, 1)).is_err());
+    }
 }
@@ -9,9 +9,9 @@ use specta::Type;
 use tauri::State;
 
 use crate::core::{
-    assets::{Asset, AssetKind, AudioInfo, ProxyStatus, VideoInfo},
+    assets::{Asset, AssetKind, AudioInfo, ImageSequenceInfo, ProxyStatus, VideoInfo},
     commands::{ImportAssetCommand, RemoveAssetCommand, UpdateAssetCommand},
-    ffmpeg::{FFmpegProgress, SharedFFmpegState},
+    ffmpeg::{FFmpegProgress, ImageSequenceInput, SharedFFmpegState},
     fs::validate_path_id_component,
     jobs::{Job, JobType, Priority},
     CoreError, Ratio,
@@ -85,6 +85,7 @@ fn build_audio_info(audio_stream: &crate::core::ffmpeg::AudioStreamInfo) -> Audi
         channels: audio_stream.channels,
         codec: audio_stream.codec.clone(),
         bitrate: audio_stream.bitrate,
+        channel_layout: audio_stream.channel_layout.clone(),
     }
 }
 
@@ -142,7 +143,8 @@ fn build_import_command(
 
         command = command
             .with_duration(info.duration_sec)
-            .with_file_size(info.size_bytes);
+            .with_file_size(info.size_bytes)
+            .with_source(info.source.clone());
 
         if matches!(command.asset.kind, AssetKind::Video) {
             if let Some(video_stream) = info.video.as_ref() {
@@ -174,6 +176,86 @@ fn build_video_info(video_stream: &crate::core::ffmpeg::VideoStreamInfo) -> Vide
     }
 }
 
+/// Queues a proxy generation job and marks the asset's proxy as pending.
+///
+/// Returns the job ID, or `None` when the worker pool refused the job.
+async fn queue_proxy_job(
+    state: &AppState,
+    asset_id: &str,
+    name: &str,
+    payload: serde_json::Value,
+) -> Option<String> {
+    let proxy_job = Job::new(JobType::ProxyGeneration, payload).with_priority(Priority::Normal);
+
+    let submitted_job_id = proxy_job.id.clone();
+
+    // Submit to worker pool (holds job_pool lock briefly)
+    let submit_result = {
+        let pool = state.job_pool.lock().await;
+        pool.submit(proxy_job)
+    };
+
+    match submit_result {
+        Ok(_) => {
+            tracing::info!(
+                "Queued proxy generation job for asset {} ({})",
+                asset_id,
+                name
+            );
+
+            // Update asset status (re-acquire project lock briefly)
+            {
+                let mut guard = state.project.lock().await;
+                if let Some(project) = guard.as_mut() {
+                    let cmd =
+                        UpdateAssetCommand::new(asset_id).with_proxy_status(ProxyStatus::Pending);
+                    if let Err(e) = project
+                        .executor
+                        .execute_without_history(Box::new(cmd), &mut project.state)
+                    {
+                        tracing::warn!(
+                            "Failed to persist asset {} proxy status update: {}",
+                            asset_id,
+                            e
+                        );
+                    }
+                }
+            }
+
+            Some(submitted_job_id)
+        }
+        Err(e) => {
+            tracing::warn!("Failed to queue proxy job for asset {}: {}", asset_id, e);
+            None
+        }
+    }
+}
+
+/// Proxy job payload for an image-sequence asset whose first frame is `first_frame`
+fn image_sequence_proxy_payload(
+    asset_id: &str,
+    first_frame: &str,
+    sequence: &ImageSequenceInfo,
+    fps: &Ratio,
+) -> serde_json::Value {
+    serde_json::json!({
+        "assetId": asset_id,
+        "inputPath": first_frame,
+        "imageSequence": sequence,
+        "frameRate": fps,
+    })
+}
+
+fn validate_image_sequence_fps(fps_num: i32, fps_den: i32) -> Result<Ratio, String> {
+    if fps_num <= 0 || fps_den <= 0 {
+        return Err(format!(
+            "Image sequence frame rate must be positive: {}/{}",
+            fps_num, fps_den
+        ));
+    }
+    Ok(Ratio::new(fps_num, fps_den))
+}
+
 // =============================================================================
 // DTOs
 // =============================================================================
@@ -206,6 +288,20 @@ pub struct AssetRelinkResult {
     pub relative_path: Option<String>,
 }
 
+/// Result of changing an image sequence's frame rate.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageSequenceFrameRateResult {
+    /// Updated asset ID.
+    pub asset_id: String,
+    /// Operation ID for undo/redo tracking.
+    pub op_id: String,
+    /// New duration of the sequence in seconds.
+    pub duration_sec: f64,
+    /// Background job ID re-encoding the proxy at the new rate (if queued).
+    pub job_id: Option<String>,
+}
+
 // =============================================================================
 // Commands
 // =============================================================================
@@ -307,57 +403,16 @@ pub async fn import_asset(
 
     // Phase 2: Queue proxy job if needed (separate lock scope)
     let job_id = if needs_proxy {
-        let proxy_job = Job::new(
-            JobType::ProxyGeneration,
+        queue_proxy_job(
+            &state,
+            &asset_id,
+            &name,
             serde_json::json!({
                 "assetId": asset_id,
                 "inputPath": proxy_input_path,
             }),
         )
-        .with_priority(Priority::Normal);
-
-        let submitted_job_id = proxy_job.id.clone();
-
-        // Submit to worker pool (holds job_pool lock briefly)
-        let submit_result = {
-            let pool = state.job_pool.lock().await;
-            pool.submit(proxy_job)
-        };
-
-        match submit_result {
-            Ok(_) => {
-                tracing::info!(
-                    "Queued proxy generation job for asset {} ({})",
-                    asset_id,
-                    name
-                );
-
-                // Phase 3: Update asset status (re-acquire project lock briefly)
-                {
-                    let mut guard = state.project.lock().await;
-                    if let Some(project) = guard.as_mut() {
-                        let cmd = UpdateAssetCommand::new(&asset_id)
-                            .with_proxy_status(ProxyStatus::Pending);
-                        if let Err(e) = project
-                            .executor
-                            .execute_without_history(Box::new(cmd), &mut project.state)
-                        {
-                            tracing::warn!(
-                                "Failed to persist asset {} proxy status update: {}",
-                                asset_id,
-                                e
-                            );
-                        }
-                    }
-                }
-
-                Some(submitted_job_id)
-            }
-            Err(e) => {
-                tracing::warn!("Failed to queue proxy job for asset {}: {}", asset_id, e);
-                None
-            }
-        }
+        .await
     } else {
         None
     };
@@ -454,6 +509,7 @@ mod tests {
                 channels: 2,
                 codec: "aac".to_string(),
                 bitrate: Some(192_000),
+                channel_layout: None,
             }),
         );
 
@@ -479,6 +535,7 @@ mod tests {
                 channels: 2,
                 codec: "opus".to_string(),
                 bitrate: Some(192_000),
+                channel_layout: None,
             }),
         );
 
@@ -495,6 +552,7 @@ mod tests {
                 channels: 1,
                 codec: "pcm_s16le".to_string(),
                 bitrate: None,
+                channel_layout: None,
             }),
         );
 
@@ -581,6 +639,7 @@ pub async fn relink_asset(
             .with_file_size(replacement.file_size)
             .with_video(replacement.video.clone())
             .with_audio(replacement.audio.clone())
+            .with_source(replacement.source.clone())
             .with_relative_path(relative_path.clone())
             .with_workspace_managed(relative_path.is_some())
             .with_missing(false)
@@ -610,6 +669,201 @@ pub async fn relink_asset(
     })
 }
 
+/// Imports a numbered still sequence (e.g. `shot_1001.exr`, `shot_1002.exr`, ...)
+/// as a single video asset playing at `fpsNum/fpsDen`.
+///
+/// `uri` may name any frame of the sequence. Always queues proxy generation,
+/// since the preview cannot play stills as video.
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state, ffmpeg_state), fields(uri = %uri))]
+pub async fn import_image_sequence(
+    uri: String,
+    fps_num: i32,
+    fps_den: i32,
+    state: State<'_, AppState>,
+    ffmpeg_state: State<'_, SharedFFmpegState>,
+) -> Result<AssetImportResult, String> {
+    use crate::core::fs::validate_local_input_path;
+    use crate::core::workspace::scanner::detect_image_sequence;
+
+    let fps = validate_image_sequence_fps(fps_num, fps_den)?;
+    let project_root = {
+        let guard = state.project.lock().await;
+        let project = guard
+            .as_ref()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+        project.path.clone()
+    };
+
+    let (resolved_uri, _) = resolve_asset_uri(&project_root, &uri);
+    let frame_path = validate_local_input_path(&resolved_uri, "Asset path")?;
+    let (first_frame, sequence) = detect_image_sequence(&frame_path).ok_or_else(|| {
+        format!(
+            "Not part of a numbered image sequence: {}",
+            frame_path.display()
+        )
+    })?;
+    let first_frame_uri = first_frame.to_string_lossy().to_string();
+    let (_, relative_path) = resolve_asset_uri(&project_root, &first_frame_uri);
+    let name = sequence.pattern.clone();
+
+    let input = ImageSequenceInput {
+        pattern_path: sequence.pattern_path(&first_frame),
+        start_number: sequence.start_number,
+        frame_count: sequence.frame_count,
+        fps: fps.clone(),
+    };
+    let media_info = {
+        let ffmpeg_guard = ffmpeg_state.read().await;
+        if let Some(runner) = ffmpeg_guard.runner() {
+            match runner.probe_image_sequence(&input).await {
+                Ok(media_info) => Some(media_info),
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to extract metadata for image sequence {}: {}. Using defaults.",
+                        name,
+                        e
+                    );
+                    None
+                }
+            }
+        } else {
+            tracing::warn!(
+                "FFmpeg not available for metadata extraction of {}. Using defaults.",
+                name
+            );
+            None
+        }
+    };
+
+    let mut video = media_info
+        .as_ref()
+        .and_then(|info| info.video.as_ref())
+        .map(build_video_info)
+        .unwrap_or_default();
+    video.fps = fps.clone();
+    let mut asset = Asset::new_image_sequence(&name, &first_frame_uri, video, sequence.clone());
+    if let Some(info) = media_info.as_ref() {
+        asset = asset.with_file_size(info.size_bytes);
+    }
+
+    let mut command = ImportAssetCommand::from_asset(asset);
+    if let Some(ref rel_path) = relative_path {
+        command = command.with_project_root(project_root.clone());
+        command.asset.relative_path = Some(rel_path.clone());
+        command.asset.workspace_managed = true;
+    }
+
+    let asset_id = command.asset_id().to_string();
+
+    let op_id = {
+        let mut guard = state.project.lock().await;
+        let project = guard
+            .as_mut()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+
+        project
+            .executor
+            .execute(Box::new(command), &mut project.state)
+            .map_err(|e| e.to_ipc_error())?
+            .op_id
+    };
+
+    state.allow_asset_protocol_file(&first_frame);
+
+    let job_id = queue_proxy_job(
+        &state,
+        &asset_id,
+        &name,
+        image_sequence_proxy_payload(&asset_id, &first_frame_uri, &sequence, &fps),
+    )
+    .await;
+
+    Ok(AssetImportResult {
+        asset_id,
+        name,
+        op_id,
+        job_id,
+    })
+}
+
+/// Changes the rate an image-sequence asset plays at.
+///
+/// Stills carry no timing, so the rate is the user's to pick. The asset's
+/// duration follows the new rate and its proxy is re-encoded.
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state), fields(asset_id = %asset_id))]
+pub async fn set_image_sequence_frame_rate(
+    asset_id: String,
+    fps_num: i32,
+    fps_den: i32,
+    state: State<'_, AppState>,
+) -> Result<ImageSequenceFrameRateResult, String> {
+    validate_path_id_component(&asset_id, "assetId")?;
+    let fps = validate_image_sequence_fps(fps_num, fps_den)?;
+
+    let (op_id, duration_sec, name, first_frame_uri, sequence) = {
+        let mut guard = state.project.lock().await;
+        let project = guard
+            .as_mut()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+        let asset = project
+            .state
+            .assets
+            .get(&asset_id)
+            .ok_or_else(|| CoreError::AssetNotFound(asset_id.clone()).to_ipc_error())?;
+        let sequence = asset
+            .image_sequence
+            .clone()
+            .ok_or_else(|| format!("Asset is not an image sequence: {}", asset_id))?;
+
+        let mut video = asset.video.clone().unwrap_or_default();
+        video.fps = fps.clone();
+        let duration_sec = sequence.duration_sec(&fps);
+        let name = asset.name.clone();
+        let first_frame_uri = asset.uri.clone();
+
+        let command = UpdateAssetCommand::new(&asset_id)
+            .with_video(Some(video))
+            .with_duration_sec(Some(duration_sec));
+        let result = project
+            .executor
+            .execute(Box::new(command), &mut project.state)
+            .map_err(|e| e.to_ipc_error())?;
+
+        (result.op_id, duration_sec, name, first_frame_uri, sequence)
+    };
+
+    let job_id = match crate::core::fs::validate_local_input_path(&first_frame_uri, "Asset path") {
+        Ok(_) => {
+            queue_proxy_job(
+                &state,
+                &asset_id,
+                &name,
+                image_sequence_proxy_payload(&asset_id, &first_frame_uri, &sequence, &fps),
+            )
+            .await
+        }
+        Err(e) => {
+            tracing::warn!(
+                "Skipping proxy refresh for image sequence {}: {}",
+                asset_id,
+                e
+            );
+            None
+        }
+    };
+
+    Ok(ImageSequenceFrameRateResult {
+        asset_id,
+        op_id,
+        duration_sec,
+        job_id,
+    })
+}
+
 /// Gets all assets in the project
 #[tauri::command]
 #[specta::specta]
@@ -637,7 +891,7 @@ pub async fn generate_asset_thumbnail(
     validate_path_id_component(&asset_id, "assetId")?;
 
     // Get asset info from state
-    let (asset_path, asset_kind, project_path) = {
+    let (asset_path, asset_kind, image_sequence, project_path) = {
         let guard = state.project.lock().await;
 
         let project = guard
@@ -652,8 +906,14 @@ pub async fn generate_asset_thumbnail(
 
         let path = Path::new(&asset.uri);
         let kind = asset_kind_from_path(path);
+        let image_sequence = asset.image_sequence_input(path);
 
-        (asset.uri.clone(), kind, project.path.clone())
+        (
+            asset.uri.clone(),
+            kind,
+            image_sequence,
+            project.path.clone(),
+        )
     };
 
     // Get FFmpeg runner
@@ -667,9 +927,18 @@ pub async fn generate_asset_thumbnail(
     let asset_path = Path::new(&asset_path);
 
     // Generate thumbnail
-    let result = thumbnail_service
-        .generate_for_asset(&asset_id, asset_path, &asset_kind)
-        .await;
+    let result = match &image_sequence {
+        Some(sequence) => {
+            thumbnail_service
+                .generate_for_image_sequence(&asset_id, sequence)
+                .await
+        }
+        None => {
+            thumbnail_service
+                .generate_for_asset(&asset_id, asset_path, &asset_kind)
+                .await
+        }
+    };
 
     match result {
         Ok(thumb_path) => {
@@ -723,7 +992,7 @@ pub async fn generate_proxy_for_asset(
     validate_path_id_component(&asset_id, "assetId")?;
 
     // Get asset info from state
-    let (asset_path, project_path) = {
+    let (asset_path, image_sequence, project_path) = {
         let guard = state.project.lock().await;
 
         let project = guard
@@ -736,7 +1005,8 @@ pub async fn generate_proxy_for_asset(
             .get(&asset_id)
             .ok_or_else(|| format!("Asset not found: {}", asset_id))?;
 
-        (asset.uri.clone(), project.path.clone())
+        let image_sequence = asset.image_sequence_input(std::path::Path::new(&asset.uri));
+        (asset.uri.clone(), image_sequence, project.path.clone())
     };
 
     // Get FFmpeg runner
@@ -788,10 +1058,19 @@ pub async fn generate_proxy_for_asset(
     });
 
     // Generate proxy
-    match ffmpeg
-        .generate_proxy(input_path, &proxy_path, Some(progress_tx))
-        .await
-    {
+    let result = match &image_sequence {
+        Some(sequence) => {
+            ffmpeg
+                .generate_image_sequence_proxy(sequence, &proxy_path, Some(progress_tx))
+                .await
+        }
+        None => {
+            ffmpeg
+                .generate_proxy(input_path, &proxy_path, Some(progress_tx))
+                .await
+        }
+    };
+    match result {
         Ok(()) => {
             // Return raw file path; frontend converts to asset protocol via convertFileSrc().
             let proxy_url = proxy_path.to_string_lossy().to_string();
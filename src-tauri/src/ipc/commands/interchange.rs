@@ -1,6 +1,7 @@
 //! Interchange export commands
 //!
-//! Tauri IPC commands for exporting sequences to EDL, FCPXML and OTIO.
+//! Tauri IPC commands for exporting sequences to EDL, FCPXML, OTIO and
+//! Final Cut Pro 7 XML (XMEML).
 //!
 //! Import is deliberately absent: an OTIO file proposes a whole timeline, and
 //! that proposal runs through the plan machinery so it is atomic and undoable.
@@ -11,7 +12,7 @@ use tauri::State;
 
 use crate::core::{
     fs::{export_allowed_roots, validate_scoped_output_path, write_bytes_atomic_no_symlink},
-    interchange::{edl, models::InterchangeExportResult, otio, xml},
+    interchange::{edl, models::InterchangeExportResult, otio, xmeml, xml},
     CoreError,
 };
 use crate::AppState;
@@ -251,3 +252,84 @@ pub async fn export_otio(
 
     Ok(result)
 }
+
+/// Exports a sequence to Final Cut Pro 7 XML (`xmeml`).
+///
+/// Premiere Pro and DaVinci Resolve exchange this legacy format more reliably
+/// than FCPXML. Tracks, cuts, speed, opacity and volume keyframes and markers
+/// survive; whatever does not is named in the result's `unsupported` list.
+#[tauri::command]
+#[specta::specta]
+#[tracing::instrument(skip(state), fields(sequence_id = %sequence_id, output_path = %output_path))]
+pub async fn export_xmeml(
+    sequence_id: String,
+    output_path: String,
+    state: State<'_, AppState>,
+) -> Result<InterchangeExportResult, String> {
+    tracing::info!("Exporting sequence to XMEML format");
+
+    // Get sequence, assets and effects from project state
+    let (sequence, assets, effects, project_path) = {
+        let guard = state.project.lock().await;
+        let project = guard
+            .as_ref()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+
+        let sequence = project
+            .state
+            .sequences
+            .get(&sequence_id)
+            .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?
+            .clone();
+
+        let assets = project
+            .state
+            .assets
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        let effects = project
+            .state
+            .effects
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        (sequence, assets, effects, project.path.clone())
+    };
+
+    // Validate output path
+    let approved_dirs = state.approved_export_dirs_snapshot().await;
+    let roots = export_allowed_roots(&project_path, &approved_dirs);
+    let root_refs: Vec<&std::path::Path> = roots.iter().map(|p| p.as_path()).collect();
+    let validated_path =
+        validate_scoped_output_path(&output_path, "XMEML output path", &root_refs)?;
+
+    // Generate XMEML content
+    let duration_sec = sequence.duration();
+    let export = xmeml::export_xmeml(&sequence, &assets, &effects)?;
+
+    let write_path = validated_path.clone();
+    let bytes = export.xml.clone().into_bytes();
+    tokio::task::spawn_blocking(move || {
+        write_bytes_atomic_no_symlink(&write_path, &bytes, "XMEML output path")
+    })
+    .await
+    .map_err(|e| format!("XMEML write task failed: {e}"))?
+    .map_err(|e| format!("Failed to write XMEML file: {e}"))?;
+
+    let result =
+        xmeml::build_export_result(&validated_path.to_string_lossy(), &export, duration_sec);
+
+    tracing::info!(
+        "XMEML export complete: {} clips, {} tracks, {:.1}s duration, {} warning(s), {} unsupported",
+        export.clip_count,
+        export.track_count,
+        duration_sec,
+        export.warnings.len(),
+        export.unsupported.len()
+    );
+
+    Ok(result)
+}
@@ -366,19 +366,21 @@ pub async fn start_render(
     use tauri::Emitter;
 
     // Get sequence/assets/effects + project path from project state
-    let (sequence, assets, effects, render_graph, project_path) = {
+    let (sequence, assets, effects, render_graph, project_path, project_meta) = {
         let guard = state.project.lock().await;
 
         let project = guard
             .as_ref()
             .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
 
-        let sequence = project
-            .state
-            .sequences
-            .get(&sequence_id)
-            .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?
-            .clone();
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
 
         let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
             .map_err(|e| e.to_ipc_error())?;
@@ -403,6 +405,7 @@ pub async fn start_render(
             effects,
             render_graph,
             project.path.clone(),
+            project.state.meta.clone(),
         )
     };
 
@@ -436,6 +439,9 @@ pub async fn start_render(
         None,
         None,
     )?;
+    settings.metadata = Some(crate::core::render::ExportMetadata::from_project(
+        &project_meta,
+    ));
 
     resolve_export_hardware_preferences(&app_handle, &ffmpeg.info().ffmpeg_path, &mut settings)
         .await?;
@@ -640,12 +646,14 @@ pub async fn render_range(
             .as_ref()
             .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
 
-        let sequence = project
-            .state
-            .sequences
-            .get(&sequence_id)
-            .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?
-            .clone();
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
 
         let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
             .map_err(|e| e.to_ipc_error())?;
@@ -884,12 +892,14 @@ pub async fn batch_render(
             .as_ref()
             .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
 
-        let sequence = project
-            .state
-            .sequences
-            .get(&sequence_id)
-            .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?
-            .clone();
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
 
         let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
             .map_err(|e| e.to_ipc_error())?;
@@ -1225,19 +1235,881 @@ pub async fn export_frame(
         _ => return Err(format!("Unsupported image format: {}", format)),
     };
 
-    // Get sequence, assets, and project path from project state (single lock)
-    let (sequence, assets, project_path) = {
+    // Get sequence, assets, and project path from project state (single lock)
+    let (sequence, assets, project_path) = {
+        let guard = state.project.lock().await;
+        let project = guard
+            .as_ref()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
+
+        let assets: std::collections::HashMap<String, crate::core::assets::Asset> = project
+            .state
+            .assets
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        let project_path = project.path.clone();
+
+        (sequence, assets, project_path)
+    };
+    let approved_dirs = state.approved_export_dirs_snapshot().await;
+    let roots = export_allowed_roots(&project_path, &approved_dirs);
+    let root_refs: Vec<&std::path::Path> = roots.iter().map(|p| p.as_path()).collect();
+    let validated_output_path =
+        validate_scoped_output_path(&output_path, "Output path", &root_refs)?;
+
+    // Get FFmpeg runner
+    let ffmpeg_guard = ffmpeg_state.read().await;
+    let ffmpeg = ffmpeg_guard.runner().ok_or_else(|| {
+        "FFmpeg not initialized. Please install FFmpeg and restart the application.".to_string()
+    })?;
+
+    let engine = ExportEngine::new(ffmpeg.clone());
+    let settings = FrameExportSettings {
+        time_sec,
+        format: image_format,
+        output_path: validated_output_path,
+        quality,
+        // The GUI still exports stills at the source's native resolution.
+        max_width: None,
+    };
+
+    let result = engine
+        .export_frame(&sequence, &assets, &project_path, &settings)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(FrameExportResultDto {
+        output_path: result.output_path.to_string_lossy().to_string(),
+        file_size: result.file_size,
+        format: result.format.extension().to_string(),
+        width: result.width,
+        height: result.height,
+    })
+}
+
+// =============================================================================
+// Export Audio Only Command
+// =============================================================================
+
+/// Exports audio only from a sequence (no video).
+///
+/// Renders all audio tracks mixed down to a single audio file.
+/// Supports WAV, MP3, M4A, FLAC, and OGG output formats. Reports progress via
+/// Tauri events using the same `render-progress` event pattern.
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip(state, ffmpeg_state, app_handle), fields(sequence_id = %sequence_id, format = %format))]
+pub async fn export_audio_only(
+    sequence_id: String,
+    format: String,
+    output_path: String,
+    bitrate: Option<String>,
+    sample_rate: Option<u32>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    state: State<'_, AppState>,
+    ffmpeg_state: State<'_, crate::core::ffmpeg::SharedFFmpegState>,
+    app_handle: tauri::AppHandle,
+) -> Result<RenderStartResult, String> {
+    use crate::core::render::{AudioExportSettings, ExportEngine, ExportProgress};
+    use tauri::Emitter;
+
+    // Parse format
+    let audio_format = match format.to_lowercase().as_str() {
+        "wav" => AudioExportFormat::Wav,
+        "mp3" => AudioExportFormat::Mp3,
+        "m4a" => AudioExportFormat::M4a,
+        "flac" => AudioExportFormat::Flac,
+        "ogg" => AudioExportFormat::Ogg,
+        _ => return Err(format!("Unsupported audio format: {}", format)),
+    };
+
+    // Get sequence/assets/effects + project path
+    let (sequence, assets, effects, render_graph, project_path) = {
+        let guard = state.project.lock().await;
+        let project = guard
+            .as_ref()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
+
+        let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
+            .map_err(|e| e.to_ipc_error())?;
+
+        let assets: std::collections::HashMap<String, crate::core::assets::Asset> = project
+            .state
+            .assets
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        let effects: std::collections::HashMap<String, crate::core::effects::Effect> = project
+            .state
+            .effects
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        (
+            sequence,
+            assets,
+            effects,
+            render_graph,
+            project.path.clone(),
+        )
+    };
+
+    // Validate output path
+    let approved_dirs = state.approved_export_dirs_snapshot().await;
+    let roots = export_allowed_roots(&project_path, &approved_dirs);
+    let root_refs: Vec<&std::path::Path> = roots.iter().map(|p| p.as_path()).collect();
+    let validated_output_path =
+        validate_scoped_output_path(&output_path, "Output path", &root_refs)?;
+
+    // Get FFmpeg runner
+    let ffmpeg_guard = ffmpeg_state.read().await;
+    let ffmpeg = ffmpeg_guard.runner().ok_or_else(|| {
+        "FFmpeg not initialized. Please install FFmpeg and restart the application.".to_string()
+    })?;
+
+    let engine = ExportEngine::new(ffmpeg.clone());
+    let audio_settings = AudioExportSettings {
+        format: audio_format,
+        output_path: validated_output_path,
+        bitrate,
+        sample_rate,
+        start_time,
+        end_time,
+    };
+
+    let audio_export_settings = audio_settings.to_export_settings();
+    let render_plan = crate::core::render::build_render_plan(
+        &render_graph,
+        &assets,
+        &effects,
+        &audio_export_settings,
+    );
+    if !render_plan.validation.is_valid {
+        return Err(format!(
+            "Render plan validation failed: {}",
+            render_plan.validation.errors.join("; ")
+        ));
+    }
+    for warning in &render_plan.validation.warnings {
+        tracing::warn!("Render plan warning: {}", warning);
+    }
+    let plan_hash = render_plan.plan_hash.clone();
+
+    let job_id = ulid::Ulid::new().to_string();
+    let job_id_for_return = job_id.clone();
+
+    // Register cancel token
+    let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel::<()>();
+    register_render_job(&job_id, cancel_tx).await;
+
+    emit_render_lifecycle(
+        &app_handle,
+        RenderLifecycleEvent {
+            job_id: job_id.clone(),
+            sequence_id: Some(sequence_id.clone()),
+            kind: RenderLifecycleKind::AudioExport,
+            state: RenderLifecycleState::Queued,
+            progress: Some(0.0),
+            message: Some("Audio export queued".to_string()),
+            output_path: Some(audio_settings.output_path.to_string_lossy().to_string()),
+            plan_hash: Some(plan_hash.clone()),
+        },
+    );
+
+    // Progress channel
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<ExportProgress>(100);
+    let app_handle_progress = app_handle.clone();
+    let job_id_progress = job_id.clone();
+    let sequence_id_progress = sequence_id.clone();
+
+    tokio::spawn(async move {
+        while let Some(progress) = progress_rx.recv().await {
+            emit_render_progress_events(
+                &app_handle_progress,
+                &job_id_progress,
+                &sequence_id_progress,
+                RenderLifecycleKind::AudioExport,
+                &progress,
+            );
+        }
+    });
+
+    let app_handle_task = app_handle.clone();
+    let job_id_task = job_id.clone();
+    let sequence_id_task = sequence_id.clone();
+    let output_path_task = audio_settings.output_path.clone();
+    let plan_hash_task = plan_hash.clone();
+    let render_plan_task = render_plan.clone();
+
+    tokio::spawn(async move {
+        match engine
+            .export_audio_only_for_plan(
+                &sequence,
+                &assets,
+                &effects,
+                &audio_settings,
+                &render_plan_task,
+                Some(progress_tx),
+                Some(cancel_rx),
+            )
+            .await
+        {
+            Ok(result) => {
+                unregister_render_job(&job_id_task).await;
+                tracing::info!(
+                    "Audio export completed: {} ({:.1}s, {} bytes)",
+                    result.output_path.display(),
+                    result.encoding_time_sec,
+                    result.file_size
+                );
+
+                let _ = app_handle_task.emit(
+                    "render-complete",
+                    serde_json::json!({
+                        "jobId": job_id_task.clone(),
+                        "outputPath": result.output_path.to_string_lossy().to_string(),
+                        "durationSec": result.duration_sec,
+                        "fileSize": result.file_size,
+                        "encodingTimeSec": result.encoding_time_sec,
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id: job_id_task,
+                        sequence_id: Some(sequence_id_task),
+                        kind: RenderLifecycleKind::AudioExport,
+                        state: RenderLifecycleState::Completed,
+                        progress: Some(100.0),
+                        message: Some("Audio export completed".to_string()),
+                        output_path: Some(result.output_path.to_string_lossy().to_string()),
+                        plan_hash: Some(plan_hash_task.clone()),
+                    },
+                );
+            }
+            Err(e) => {
+                unregister_render_job(&job_id_task).await;
+                tracing::error!("Audio export failed: {}", e);
+                let lifecycle_state = lifecycle_state_for_export_error(&e);
+                let error_message = e.to_string();
+
+                let _ = app_handle_task.emit(
+                    "render-error",
+                    serde_json::json!({
+                        "jobId": job_id_task.clone(),
+                        "error": error_message.clone(),
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id: job_id_task,
+                        sequence_id: Some(sequence_id_task),
+                        kind: RenderLifecycleKind::AudioExport,
+                        state: lifecycle_state,
+                        progress: None,
+                        message: Some(error_message),
+                        output_path: Some(output_path_task.to_string_lossy().to_string()),
+                        plan_hash: Some(plan_hash_task.clone()),
+                    },
+                );
+            }
+        }
+    });
+
+    Ok(RenderStartResult {
+        job_id: job_id_for_return,
+        output_path,
+        status: "started".to_string(),
+    })
+}
+
+// =============================================================================
+// Export Audio Stems Command
+// =============================================================================
+
+/// Exports audio stems from a sequence.
+///
+/// Renders each stem (a group of tracks) to its own file next to `output_path`,
+/// named `<output name>_<stem name>`, and the mixdown to `output_path` when
+/// `include_mixdown` is set. All files come from one FFmpeg job and share the
+/// same length. Reports progress like `export_audio_only`.
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip(state, ffmpeg_state, app_handle, stems), fields(sequence_id = %sequence_id, format = %format, stem_count = stems.len()))]
+pub async fn export_audio_stems(
+    sequence_id: String,
+    format: String,
+    output_path: String,
+    stems: Vec<crate::core::render::AudioStem>,
+    include_mixdown: bool,
+    bitrate: Option<String>,
+    sample_rate: Option<u32>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    state: State<'_, AppState>,
+    ffmpeg_state: State<'_, crate::core::ffmpeg::SharedFFmpegState>,
+    app_handle: tauri::AppHandle,
+) -> Result<RenderStartResult, String> {
+    use crate::core::render::{
+        AudioExportSettings, AudioStemsExportSettings, ExportEngine, ExportProgress,
+    };
+    use tauri::Emitter;
+
+    let audio_format = match format.to_lowercase().as_str() {
+        "wav" => AudioExportFormat::Wav,
+        "mp3" => AudioExportFormat::Mp3,
+        "m4a" => AudioExportFormat::M4a,
+        "flac" => AudioExportFormat::Flac,
+        "ogg" => AudioExportFormat::Ogg,
+        _ => return Err(format!("Unsupported audio format: {}", format)),
+    };
+
+    let (sequence, assets, effects, render_graph, project_path) = {
+        let guard = state.project.lock().await;
+        let project = guard
+            .as_ref()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
+
+        let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
+            .map_err(|e| e.to_ipc_error())?;
+
+        let assets: std::collections::HashMap<String, crate::core::assets::Asset> = project
+            .state
+            .assets
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        let effects: std::collections::HashMap<String, crate::core::effects::Effect> = project
+            .state
+            .effects
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        (
+            sequence,
+            assets,
+            effects,
+            render_graph,
+            project.path.clone(),
+        )
+    };
+
+    let approved_dirs = state.approved_export_dirs_snapshot().await;
+    let roots = export_allowed_roots(&project_path, &approved_dirs);
+    let root_refs: Vec<&std::path::Path> = roots.iter().map(|p| p.as_path()).collect();
+    let validated_output_path =
+        validate_scoped_output_path(&output_path, "Output path", &root_refs)?;
+
+    let stems_settings = AudioStemsExportSettings {
+        audio: AudioExportSettings {
+            format: audio_format,
+            output_path: validated_output_path,
+            bitrate,
+            sample_rate,
+            start_time,
+            end_time,
+        },
+        stems,
+        include_mixdown,
+    };
+    stems_settings.validate().map_err(|e| e.to_string())?;
+    stems_settings
+        .validate_tracks(&sequence)
+        .map_err(|e| e.to_string())?;
+    for stem in &stems_settings.stems {
+        let stem_path = stems_settings.stem_output_path(stem);
+        validate_scoped_output_path(&stem_path.to_string_lossy(), "Stem output path", &root_refs)?;
+    }
+
+    let ffmpeg_guard = ffmpeg_state.read().await;
+    let ffmpeg = ffmpeg_guard.runner().ok_or_else(|| {
+        "FFmpeg not initialized. Please install FFmpeg and restart the application.".to_string()
+    })?;
+    let engine = ExportEngine::new(ffmpeg.clone());
+
+    let render_plan = crate::core::render::build_render_plan(
+        &render_graph,
+        &assets,
+        &effects,
+        &stems_settings.audio.to_export_settings(),
+    );
+    if !render_plan.validation.is_valid {
+        return Err(format!(
+            "Render plan validation failed: {}",
+            render_plan.validation.errors.join("; ")
+        ));
+    }
+    for warning in &render_plan.validation.warnings {
+        tracing::warn!("Render plan warning: {}", warning);
+    }
+    let plan_hash = render_plan.plan_hash.clone();
+
+    let job_id = ulid::Ulid::new().to_string();
+    let job_id_for_return = job_id.clone();
+
+    let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel::<()>();
+    register_render_job(&job_id, cancel_tx).await;
+
+    emit_render_lifecycle(
+        &app_handle,
+        RenderLifecycleEvent {
+            job_id: job_id.clone(),
+            sequence_id: Some(sequence_id.clone()),
+            kind: RenderLifecycleKind::AudioExport,
+            state: RenderLifecycleState::Queued,
+            progress: Some(0.0),
+            message: Some("Stems export queued".to_string()),
+            output_path: Some(output_path.clone()),
+            plan_hash: Some(plan_hash.clone()),
+        },
+    );
+
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<ExportProgress>(100);
+    let app_handle_progress = app_handle.clone();
+    let job_id_progress = job_id.clone();
+    let sequence_id_progress = sequence_id.clone();
+
+    tokio::spawn(async move {
+        while let Some(progress) = progress_rx.recv().await {
+            emit_render_progress_events(
+                &app_handle_progress,
+                &job_id_progress,
+                &sequence_id_progress,
+                RenderLifecycleKind::AudioExport,
+                &progress,
+            );
+        }
+    });
+
+    let app_handle_task = app_handle.clone();
+    let output_path_task = output_path.clone();
+
+    tokio::spawn(async move {
+        match engine
+            .export_audio_stems(
+                &sequence,
+                &assets,
+                &effects,
+                &stems_settings,
+                Some(&render_plan),
+                Some(progress_tx),
+                Some(cancel_rx),
+            )
+            .await
+        {
+            Ok(result) => {
+                unregister_render_job(&job_id).await;
+                tracing::info!(
+                    "Stems export completed: {} stem(s) ({:.1}s)",
+                    result.stems.len(),
+                    result.encoding_time_sec
+                );
+
+                let files: Vec<_> = result.mixdown.iter().chain(&result.stems).collect();
+                let _ = app_handle_task.emit(
+                    "render-complete",
+                    serde_json::json!({
+                        "jobId": job_id.clone(),
+                        "outputPath": output_path_task.clone(),
+                        "durationSec": result.duration_sec,
+                        "fileSize": files.iter().map(|file| file.file_size).sum::<u64>(),
+                        "encodingTimeSec": result.encoding_time_sec,
+                        "files": files,
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::AudioExport,
+                        state: RenderLifecycleState::Completed,
+                        progress: Some(100.0),
+                        message: Some("Stems export completed".to_string()),
+                        output_path: Some(output_path_task),
+                        plan_hash: Some(plan_hash),
+                    },
+                );
+            }
+            Err(e) => {
+                unregister_render_job(&job_id).await;
+                tracing::error!("Stems export failed: {}", e);
+                let lifecycle_state = lifecycle_state_for_export_error(&e);
+                let error_message = e.to_string();
+
+                let _ = app_handle_task.emit(
+                    "render-error",
+                    serde_json::json!({
+                        "jobId": job_id.clone(),
+                        "error": error_message.clone(),
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::AudioExport,
+                        state: lifecycle_state,
+                        progress: None,
+                        message: Some(error_message),
+                        output_path: Some(output_path_task),
+                        plan_hash: Some(plan_hash),
+                    },
+                );
+            }
+        }
+    });
+
+    Ok(RenderStartResult {
+        job_id: job_id_for_return,
+        output_path,
+        status: "started".to_string(),
+    })
+}
+
+// =============================================================================
+// Export Streaming Package Command
+// =============================================================================
+
+/// Packages a sequence for adaptive streaming.
+///
+/// Renders the ladder in `renditions` from one decode of the sequence into
+/// `output_dir` as CMAF segments with a DASH `manifest.mpd` and an HLS
+/// `master.m3u8`, adding the caption tracks as WebVTT renditions when
+/// `include_captions` is set. `video_codec` defaults to H.264 and
+/// `segment_duration_sec` to 6 seconds. Reports progress like `start_render`.
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip(state, ffmpeg_state, app_handle, renditions), fields(sequence_id = %sequence_id, rendition_count = renditions.len()))]
+pub async fn export_streaming_package(
+    sequence_id: String,
+    output_dir: String,
+    renditions: Vec<crate::core::render::StreamingRendition>,
+    video_codec: Option<crate::core::render::VideoCodec>,
+    audio_bitrate: Option<String>,
+    segment_duration_sec: Option<f64>,
+    include_captions: bool,
+    burn_in_captions: bool,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    state: State<'_, AppState>,
+    ffmpeg_state: State<'_, crate::core::ffmpeg::SharedFFmpegState>,
+    app_handle: tauri::AppHandle,
+) -> Result<RenderStartResult, String> {
+    use crate::core::render::{ExportEngine, ExportProgress, StreamingPackageSettings, VideoCodec};
+    use tauri::Emitter;
+
+    let (sequence, assets, effects, render_graph, project_path) = {
+        let guard = state.project.lock().await;
+        let project = guard
+            .as_ref()
+            .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
+
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
+
+        let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
+            .map_err(|e| e.to_ipc_error())?;
+
+        let assets: std::collections::HashMap<String, crate::core::assets::Asset> = project
+            .state
+            .assets
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        let effects: std::collections::HashMap<String, crate::core::effects::Effect> = project
+            .state
+            .effects
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+
+        (
+            sequence,
+            assets,
+            effects,
+            render_graph,
+            project.path.clone(),
+        )
+    };
+
+    let approved_dirs = state.approved_export_dirs_snapshot().await;
+    let roots = export_allowed_roots(&project_path, &approved_dirs);
+    let root_refs: Vec<&std::path::Path> = roots.iter().map(|p| p.as_path()).collect();
+    // Scoping the manifest rather than the directory puts the directory itself
+    // through the parent checks, symlinks included, and creates it.
+    let manifest_path = std::path::Path::new(&output_dir)
+        .join(crate::core::render::DASH_MANIFEST_NAME)
+        .to_string_lossy()
+        .to_string();
+    let validated_manifest_path =
+        validate_scoped_output_path(&manifest_path, "Output directory", &root_refs)?;
+    let validated_output_dir = validated_manifest_path
+        .parent()
+        .map(std::path::Path::to_path_buf)
+        .ok_or_else(|| format!("Invalid output directory: {}", output_dir))?;
+
+    let package_settings = StreamingPackageSettings {
+        output_dir: validated_output_dir,
+        renditions,
+        video_codec: video_codec.unwrap_or(VideoCodec::H264),
+        audio_bitrate,
+        fps: None,
+        segment_duration_sec: segment_duration_sec.unwrap_or(6.0),
+        encoder_speed: None,
+        start_time,
+        end_time,
+        include_captions,
+        burn_in_captions,
+    };
+    package_settings.validate().map_err(|e| e.to_string())?;
+
+    let ffmpeg_guard = ffmpeg_state.read().await;
+    let ffmpeg = ffmpeg_guard.runner().ok_or_else(|| {
+        "FFmpeg not initialized. Please install FFmpeg and restart the application.".to_string()
+    })?;
+    let engine = ExportEngine::new(ffmpeg.clone());
+
+    let render_plan = crate::core::render::build_render_plan(
+        &render_graph,
+        &assets,
+        &effects,
+        &package_settings.to_export_settings(&sequence),
+    );
+    if !render_plan.validation.is_valid {
+        return Err(format!(
+            "Render plan validation failed: {}",
+            render_plan.validation.errors.join("; ")
+        ));
+    }
+    for warning in &render_plan.validation.warnings {
+        tracing::warn!("Render plan warning: {}", warning);
+    }
+    let plan_hash = render_plan.plan_hash.clone();
+
+    let job_id = ulid::Ulid::new().to_string();
+    let job_id_for_return = job_id.clone();
+
+    let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel::<()>();
+    register_render_job(&job_id, cancel_tx).await;
+
+    emit_render_lifecycle(
+        &app_handle,
+        RenderLifecycleEvent {
+            job_id: job_id.clone(),
+            sequence_id: Some(sequence_id.clone()),
+            kind: RenderLifecycleKind::Export,
+            state: RenderLifecycleState::Queued,
+            progress: Some(0.0),
+            message: Some("Streaming package queued".to_string()),
+            output_path: Some(output_dir.clone()),
+            plan_hash: Some(plan_hash.clone()),
+        },
+    );
+
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<ExportProgress>(100);
+    let app_handle_progress = app_handle.clone();
+    let job_id_progress = job_id.clone();
+    let sequence_id_progress = sequence_id.clone();
+
+    tokio::spawn(async move {
+        while let Some(progress) = progress_rx.recv().await {
+            emit_render_progress_events(
+                &app_handle_progress,
+                &job_id_progress,
+                &sequence_id_progress,
+                RenderLifecycleKind::Export,
+                &progress,
+            );
+        }
+    });
+
+    let app_handle_task = app_handle.clone();
+    let output_dir_task = output_dir.clone();
+
+    tokio::spawn(async move {
+        match engine
+            .export_streaming_package(
+                &sequence,
+                &assets,
+                &effects,
+                &package_settings,
+                Some(&render_plan),
+                Some(progress_tx),
+                Some(cancel_rx),
+            )
+            .await
+        {
+            Ok(result) => {
+                unregister_render_job(&job_id).await;
+                tracing::info!(
+                    "Streaming package completed: {} rendition(s), {} caption track(s) ({:.1}s)",
+                    result.renditions.len(),
+                    result.captions.len(),
+                    result.encoding_time_sec
+                );
+
+                let _ = app_handle_task.emit(
+                    "render-complete",
+                    serde_json::json!({
+                        "jobId": job_id.clone(),
+                        "outputPath": output_dir_task.clone(),
+                        "durationSec": result.duration_sec,
+                        "fileSize": result.total_size,
+                        "encodingTimeSec": result.encoding_time_sec,
+                        "dashManifestPath": result.dash_manifest_path,
+                        "hlsMasterPlaylistPath": result.hls_master_playlist_path,
+                        "renditions": result.renditions,
+                        "captions": result.captions,
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::Export,
+                        state: RenderLifecycleState::Completed,
+                        progress: Some(100.0),
+                        message: Some("Streaming package completed".to_string()),
+                        output_path: Some(output_dir_task),
+                        plan_hash: Some(plan_hash),
+                    },
+                );
+            }
+            Err(e) => {
+                unregister_render_job(&job_id).await;
+                tracing::error!("Streaming package failed: {}", e);
+                let lifecycle_state = lifecycle_state_for_export_error(&e);
+                let error_message = e.to_string();
+
+                let _ = app_handle_task.emit(
+                    "render-error",
+                    serde_json::json!({
+                        "jobId": job_id.clone(),
+                        "error": error_message.clone(),
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::Export,
+                        state: lifecycle_state,
+                        progress: None,
+                        message: Some(error_message),
+                        output_path: Some(output_dir_task),
+                        plan_hash: Some(plan_hash),
+                    },
+                );
+            }
+        }
+    });
+
+    Ok(RenderStartResult {
+        job_id: job_id_for_return,
+        output_path: output_dir,
+        status: "started".to_string(),
+    })
+}
+
+// =============================================================================
+// Export Image Sequence Command
+// =============================================================================
+
+/// Exports a range of a sequence as numbered stills for VFX hand-off.
+///
+/// Writes `<base_name>.<frame>.<ext>` into `output_dir`, numbered after the
+/// timeline frames unless `start_number` is given, with `frame_padding` digits
+/// (default 4), plus a `<base_name>.manifest.json` mapping every file to its
+/// timeline timecode. `bit_depth` defaults per format: 8 for PNG, 16 for TIFF
+/// and EXR (half float), 10 for DPX. Reports progress like `start_render`.
+#[tauri::command]
+#[specta::specta]
+#[allow(clippy::too_many_arguments)]
+#[tracing::instrument(skip(state, ffmpeg_state, app_handle), fields(sequence_id = %sequence_id))]
+pub async fn export_image_sequence(
+    sequence_id: String,
+    output_dir: String,
+    base_name: String,
+    format: crate::core::render::ImageSequenceFormat,
+    bit_depth: Option<u8>,
+    alpha: bool,
+    frame_padding: Option<u8>,
+    start_number: Option<u64>,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    state: State<'_, AppState>,
+    ffmpeg_state: State<'_, crate::core::ffmpeg::SharedFFmpegState>,
+    app_handle: tauri::AppHandle,
+) -> Result<RenderStartResult, String> {
+    use crate::core::render::{ExportEngine, ExportProgress, ImageSequenceExportSettings};
+    use tauri::Emitter;
+
+    let (sequence, assets, effects, render_graph, project_path) = {
         let guard = state.project.lock().await;
         let project = guard
             .as_ref()
             .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
 
-        let sequence = project
-            .state
-            .sequences
-            .get(&sequence_id)
-            .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?
-            .clone();
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
+
+        let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
+            .map_err(|e| e.to_ipc_error())?;
 
         let assets: std::collections::HashMap<String, crate::core::assets::Asset> = project
             .state
@@ -1246,97 +2118,253 @@ pub async fn export_frame(
             .map(|(k, v)| (k.clone(), v.clone()))
             .collect();
 
-        let project_path = project.path.clone();
+        let effects: std::collections::HashMap<String, crate::core::effects::Effect> = project
+            .state
+            .effects
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
 
-        (sequence, assets, project_path)
+        (
+            sequence,
+            assets,
+            effects,
+            render_graph,
+            project.path.clone(),
+        )
+    };
+
+    let mut sequence_settings = ImageSequenceExportSettings {
+        output_dir: std::path::PathBuf::from(&output_dir),
+        base_name,
+        format,
+        bit_depth,
+        alpha,
+        frame_padding: frame_padding.unwrap_or(4),
+        start_number,
+        width: None,
+        height: None,
+        start_time,
+        end_time,
     };
+    sequence_settings.validate().map_err(|e| e.to_string())?;
+
     let approved_dirs = state.approved_export_dirs_snapshot().await;
     let roots = export_allowed_roots(&project_path, &approved_dirs);
     let root_refs: Vec<&std::path::Path> = roots.iter().map(|p| p.as_path()).collect();
-    let validated_output_path =
-        validate_scoped_output_path(&output_path, "Output path", &root_refs)?;
+    // Scoping the manifest rather than the directory puts the directory itself
+    // through the parent checks, symlinks included, and creates it.
+    let manifest_path = sequence_settings
+        .manifest_path()
+        .to_string_lossy()
+        .to_string();
+    let validated_manifest_path =
+        validate_scoped_output_path(&manifest_path, "Output directory", &root_refs)?;
+    sequence_settings.output_dir = validated_manifest_path
+        .parent()
+        .map(std::path::Path::to_path_buf)
+        .ok_or_else(|| format!("Invalid output directory: {}", output_dir))?;
 
-    // Get FFmpeg runner
     let ffmpeg_guard = ffmpeg_state.read().await;
     let ffmpeg = ffmpeg_guard.runner().ok_or_else(|| {
         "FFmpeg not initialized. Please install FFmpeg and restart the application.".to_string()
     })?;
-
     let engine = ExportEngine::new(ffmpeg.clone());
-    let settings = FrameExportSettings {
-        time_sec,
-        format: image_format,
-        output_path: validated_output_path,
-        quality,
-        // The GUI still exports stills at the source's native resolution.
-        max_width: None,
-    };
 
-    let result = engine
-        .export_frame(&sequence, &assets, &project_path, &settings)
-        .await
-        .map_err(|e| e.to_string())?;
+    let render_plan = crate::core::render::build_render_plan(
+        &render_graph,
+        &assets,
+        &effects,
+        &sequence_settings.to_export_settings(),
+    );
+    if !render_plan.validation.is_valid {
+        return Err(format!(
+            "Render plan validation failed: {}",
+            render_plan.validation.errors.join("; ")
+        ));
+    }
+    for warning in &render_plan.validation.warnings {
+        tracing::warn!("Render plan warning: {}", warning);
+    }
+    let plan_hash = render_plan.plan_hash.clone();
 
-    Ok(FrameExportResultDto {
-        output_path: result.output_path.to_string_lossy().to_string(),
-        file_size: result.file_size,
-        format: result.format.extension().to_string(),
-        width: result.width,
-        height: result.height,
+    let job_id = ulid::Ulid::new().to_string();
+    let job_id_for_return = job_id.clone();
+
+    let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel::<()>();
+    register_render_job(&job_id, cancel_tx).await;
+
+    emit_render_lifecycle(
+        &app_handle,
+        RenderLifecycleEvent {
+            job_id: job_id.clone(),
+            sequence_id: Some(sequence_id.clone()),
+            kind: RenderLifecycleKind::Export,
+            state: RenderLifecycleState::Queued,
+            progress: Some(0.0),
+            message: Some("Image sequence queued".to_string()),
+            output_path: Some(output_dir.clone()),
+            plan_hash: Some(plan_hash.clone()),
+        },
+    );
+
+    let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<ExportProgress>(100);
+    let app_handle_progress = app_handle.clone();
+    let job_id_progress = job_id.clone();
+    let sequence_id_progress = sequence_id.clone();
+
+    tokio::spawn(async move {
+        while let Some(progress) = progress_rx.recv().await {
+            emit_render_progress_events(
+                &app_handle_progress,
+                &job_id_progress,
+                &sequence_id_progress,
+                RenderLifecycleKind::Export,
+                &progress,
+            );
+        }
+    });
+
+    let app_handle_task = app_handle.clone();
+    let output_dir_task = output_dir.clone();
+
+    tokio::spawn(async move {
+        match engine
+            .export_image_sequence(
+                &sequence,
+                &assets,
+                &effects,
+                &sequence_settings,
+                Some(&render_plan),
+                Some(progress_tx),
+                Some(cancel_rx),
+            )
+            .await
+        {
+            Ok(result) => {
+                unregister_render_job(&job_id).await;
+                tracing::info!(
+                    "Image sequence completed: {} frame(s) from {} ({:.1}s)",
+                    result.frame_count,
+                    result.first_number,
+                    result.encoding_time_sec
+                );
+
+                let _ = app_handle_task.emit(
+                    "render-complete",
+                    serde_json::json!({
+                        "jobId": job_id.clone(),
+                        "outputPath": output_dir_task.clone(),
+                        "durationSec": result.duration_sec,
+                        "fileSize": result.total_size,
+                        "encodingTimeSec": result.encoding_time_sec,
+                        "manifestPath": result.manifest_path,
+                        "firstNumber": result.first_number,
+                        "frameCount": result.frame_count,
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::Export,
+                        state: RenderLifecycleState::Completed,
+                        progress: Some(100.0),
+                        message: Some("Image sequence completed".to_string()),
+                        output_path: Some(output_dir_task),
+                        plan_hash: Some(plan_hash),
+                    },
+                );
+            }
+            Err(e) => {
+                unregister_render_job(&job_id).await;
+                tracing::error!("Image sequence failed: {}", e);
+                let lifecycle_state = lifecycle_state_for_export_error(&e);
+                let error_message = e.to_string();
+
+                let _ = app_handle_task.emit(
+                    "render-error",
+                    serde_json::json!({
+                        "jobId": job_id.clone(),
+                        "error": error_message.clone(),
+                    }),
+                );
+                emit_render_lifecycle(
+                    &app_handle_task,
+                    RenderLifecycleEvent {
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::Export,
+                        state: lifecycle_state,
+                        progress: None,
+                        message: Some(error_message),
+                        output_path: Some(output_dir_task),
+                        plan_hash: Some(plan_hash),
+                    },
+                );
+            }
+        }
+    });
+
+    Ok(RenderStartResult {
+        job_id: job_id_for_return,
+        output_path: output_dir,
+        status: "started".to_string(),
     })
 }
 
 // =============================================================================
-// Export Audio Only Command
+// Export Animated Image Command
 // =============================================================================
 
-/// Exports audio only from a sequence (no video).
+/// Exports a range of a sequence as a looping GIF or animated WebP.
 ///
-/// Renders all audio tracks mixed down to a single audio file.
-/// Supports WAV, MP3, M4A, FLAC, and OGG output formats. Reports progress via
-/// Tauri events using the same `render-progress` event pattern.
+/// `fps` and `width` default to the sequence's, capped at 15 fps and 480 px;
+/// the height follows the aspect ratio. GIFs use a palette of `max_colors`
+/// (default 256) built from the range and the chosen `dither`; WebP uses
+/// `quality` (default 75) or `lossless`. `play_count` 0 loops forever. With
+/// `max_file_size` (bytes), frame rate and width are lowered over up to six
+/// encodes until the file fits. Reports progress like `start_render`.
 #[tauri::command]
 #[specta::specta]
 #[allow(clippy::too_many_arguments)]
-#[tracing::instrument(skip(state, ffmpeg_state, app_handle), fields(sequence_id = %sequence_id, format = %format))]
-pub async fn export_audio_only(
+#[tracing::instrument(skip(state, ffmpeg_state, app_handle), fields(sequence_id = %sequence_id))]
+pub async fn export_animated_image(
     sequence_id: String,
-    format: String,
     output_path: String,
-    bitrate: Option<String>,
-    sample_rate: Option<u32>,
+    format: crate::core::render::AnimatedImageFormat,
+    fps: Option<f64>,
+    width: Option<u32>,
+    max_colors: Option<u16>,
+    dither: Option<crate::core::render::GifDither>,
+    quality: Option<u8>,
+    lossless: bool,
+    play_count: Option<u32>,
+    max_file_size: Option<u64>,
     start_time: Option<f64>,
     end_time: Option<f64>,
     state: State<'_, AppState>,
     ffmpeg_state: State<'_, crate::core::ffmpeg::SharedFFmpegState>,
     app_handle: tauri::AppHandle,
 ) -> Result<RenderStartResult, String> {
-    use crate::core::render::{AudioExportSettings, ExportEngine, ExportProgress};
+    use crate::core::render::{AnimatedImageExportSettings, ExportEngine, ExportProgress};
     use tauri::Emitter;
 
-    // Parse format
-    let audio_format = match format.to_lowercase().as_str() {
-        "wav" => AudioExportFormat::Wav,
-        "mp3" => AudioExportFormat::Mp3,
-        "m4a" => AudioExportFormat::M4a,
-        "flac" => AudioExportFormat::Flac,
-        "ogg" => AudioExportFormat::Ogg,
-        _ => return Err(format!("Unsupported audio format: {}", format)),
-    };
-
-    // Get sequence/assets/effects + project path
     let (sequence, assets, effects, render_graph, project_path) = {
         let guard = state.project.lock().await;
         let project = guard
             .as_ref()
             .ok_or_else(|| CoreError::NoProjectOpen.to_ipc_error())?;
 
-        let sequence = project
-            .state
-            .sequences
-            .get(&sequence_id)
-            .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?
-            .clone();
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&sequence_id)
+                .ok_or_else(|| format!("Sequence not found: {}", sequence_id))?,
+            &project.state.sequences,
+        );
 
         let render_graph = crate::core::render::build_render_graph(&project.state, &sequence_id)
             .map_err(|e| e.to_ipc_error())?;
@@ -1364,35 +2392,43 @@ pub async fn export_audio_only(
         )
     };
 
-    // Validate output path
+    let mut animation_settings =
+        AnimatedImageExportSettings::new(std::path::PathBuf::from(&output_path), format);
+    animation_settings.fps = fps;
+    animation_settings.width = width;
+    if let Some(max_colors) = max_colors {
+        animation_settings.max_colors = max_colors;
+    }
+    if let Some(dither) = dither {
+        animation_settings.dither = dither;
+    }
+    if let Some(quality) = quality {
+        animation_settings.quality = quality;
+    }
+    animation_settings.lossless = lossless;
+    animation_settings.play_count = play_count.unwrap_or(0);
+    animation_settings.max_file_size = max_file_size;
+    animation_settings.start_time = start_time;
+    animation_settings.end_time = end_time;
+    animation_settings.validate().map_err(|e| e.to_string())?;
+
     let approved_dirs = state.approved_export_dirs_snapshot().await;
     let roots = export_allowed_roots(&project_path, &approved_dirs);
     let root_refs: Vec<&std::path::Path> = roots.iter().map(|p| p.as_path()).collect();
-    let validated_output_path =
+    animation_settings.output_path =
         validate_scoped_output_path(&output_path, "Output path", &root_refs)?;
 
-    // Get FFmpeg runner
     let ffmpeg_guard = ffmpeg_state.read().await;
     let ffmpeg = ffmpeg_guard.runner().ok_or_else(|| {
         "FFmpeg not initialized. Please install FFmpeg and restart the application.".to_string()
     })?;
-
     let engine = ExportEngine::new(ffmpeg.clone());
-    let audio_settings = AudioExportSettings {
-        format: audio_format,
-        output_path: validated_output_path,
-        bitrate,
-        sample_rate,
-        start_time,
-        end_time,
-    };
 
-    let audio_export_settings = audio_settings.to_export_settings();
     let render_plan = crate::core::render::build_render_plan(
         &render_graph,
         &assets,
         &effects,
-        &audio_export_settings,
+        &animation_settings.to_export_settings(),
     );
     if !render_plan.validation.is_valid {
         return Err(format!(
@@ -1408,7 +2444,6 @@ pub async fn export_audio_only(
     let job_id = ulid::Ulid::new().to_string();
     let job_id_for_return = job_id.clone();
 
-    // Register cancel token
     let (cancel_tx, cancel_rx) = tokio::sync::oneshot::channel::<()>();
     register_render_job(&job_id, cancel_tx).await;
 
@@ -1417,16 +2452,15 @@ pub async fn export_audio_only(
         RenderLifecycleEvent {
             job_id: job_id.clone(),
             sequence_id: Some(sequence_id.clone()),
-            kind: RenderLifecycleKind::AudioExport,
+            kind: RenderLifecycleKind::Export,
             state: RenderLifecycleState::Queued,
             progress: Some(0.0),
-            message: Some("Audio export queued".to_string()),
-            output_path: Some(audio_settings.output_path.to_string_lossy().to_string()),
+            message: Some("Animated image queued".to_string()),
+            output_path: Some(output_path.clone()),
             plan_hash: Some(plan_hash.clone()),
         },
     );
 
-    // Progress channel
     let (progress_tx, mut progress_rx) = tokio::sync::mpsc::channel::<ExportProgress>(100);
     let app_handle_progress = app_handle.clone();
     let job_id_progress = job_id.clone();
@@ -1438,89 +2472,89 @@ pub async fn export_audio_only(
                 &app_handle_progress,
                 &job_id_progress,
                 &sequence_id_progress,
-                RenderLifecycleKind::AudioExport,
+                RenderLifecycleKind::Export,
                 &progress,
             );
         }
     });
 
     let app_handle_task = app_handle.clone();
-    let job_id_task = job_id.clone();
-    let sequence_id_task = sequence_id.clone();
-    let output_path_task = audio_settings.output_path.clone();
-    let plan_hash_task = plan_hash.clone();
-    let render_plan_task = render_plan.clone();
+    let output_path_task = output_path.clone();
 
     tokio::spawn(async move {
         match engine
-            .export_audio_only_for_plan(
+            .export_animated_image(
                 &sequence,
                 &assets,
                 &effects,
-                &audio_settings,
-                &render_plan_task,
+                &animation_settings,
+                Some(&render_plan),
                 Some(progress_tx),
                 Some(cancel_rx),
             )
             .await
         {
             Ok(result) => {
-                unregister_render_job(&job_id_task).await;
+                unregister_render_job(&job_id).await;
                 tracing::info!(
-                    "Audio export completed: {} ({:.1}s, {} bytes)",
-                    result.output_path.display(),
-                    result.encoding_time_sec,
-                    result.file_size
+                    "Animated image completed: {} bytes at {}px, {} fps after {} attempt(s)",
+                    result.file_size,
+                    result.width,
+                    result.fps,
+                    result.attempts
                 );
 
                 let _ = app_handle_task.emit(
                     "render-complete",
                     serde_json::json!({
-                        "jobId": job_id_task.clone(),
-                        "outputPath": result.output_path.to_string_lossy().to_string(),
+                        "jobId": job_id.clone(),
+                        "outputPath": output_path_task.clone(),
                         "durationSec": result.duration_sec,
                         "fileSize": result.file_size,
                         "encodingTimeSec": result.encoding_time_sec,
+                        "fps": result.fps,
+                        "width": result.width,
+                        "attempts": result.attempts,
                     }),
                 );
                 emit_render_lifecycle(
                     &app_handle_task,
                     RenderLifecycleEvent {
-                        job_id: job_id_task,
-                        sequence_id: Some(sequence_id_task),
-                        kind: RenderLifecycleKind::AudioExport,
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::Export,
                         state: RenderLifecycleState::Completed,
                         progress: Some(100.0),
-                        message: Some("Audio export completed".to_string()),
-                        output_path: Some(result.output_path.to_string_lossy().to_string()),
-                        plan_hash: Some(plan_hash_task.clone()),
+                        message: Some("Animated image completed".to_string()),
+                        output_path: Some(output_path_task),
+                        plan_hash: Some(plan_hash),
                     },
                 );
             }
             Err(e) => {
-                unregister_render_job(&job_id_task).await;
-                tracing::error!("Audio export failed: {}", e);
+                unregister_render_job(&job_id).await;
+                tracing::error!("Animated image failed: {}", e);
                 let lifecycle_state = lifecycle_state_for_export_error(&e);
                 let error_message = e.to_string();
 
                 let _ = app_handle_task.emit(
                     "render-error",
                     serde_json::json!({
-                        "jobId": job_id_task.clone(),
+                        "jobId": job_id.clone(),
                         "error": error_message.clone(),
                     }),
                 );
                 emit_render_lifecycle(
                     &app_handle_task,
                     RenderLifecycleEvent {
-                        job_id: job_id_task,
-                        sequence_id: Some(sequence_id_task),
-                        kind: RenderLifecycleKind::AudioExport,
+                        job_id,
+                        sequence_id: Some(sequence_id),
+                        kind: RenderLifecycleKind::Export,
                         state: lifecycle_state,
                         progress: None,
                         message: Some(error_message),
-                        output_path: Some(output_path_task.to_string_lossy().to_string()),
-                        plan_hash: Some(plan_hash_task.clone()),
+                        output_path: Some(output_path_task),
+                        plan_hash: Some(plan_hash),
                     },
                 );
             }
@@ -2485,12 +3519,14 @@ pub async fn render_preview_cache(
             .ok_or_else(|| "No active sequence".to_string())?
             .clone();
 
-        let sequence = project
-            .state
-            .sequences
-            .get(&seq_id)
-            .ok_or_else(|| format!("Sequence not found: {seq_id}"))?
-            .clone();
+        let sequence = crate::core::render::resolve_multicam_clips(
+            project
+                .state
+                .sequences
+                .get(&seq_id)
+                .ok_or_else(|| format!("Sequence not found: {seq_id}"))?,
+            &project.state.sequences,
+        );
 
         let render_graph = crate::core::render::build_render_graph(&project.state, &seq_id)
             .map_err(|error| format!("Failed to build render graph: {error}"))?;
@@ -2721,7 +3757,10 @@ pub async fn render_preview_cache(
                 match guard.as_ref() {
                     Some(project) => {
                         let seq = match project.state.sequences.get(&job_seq_id) {
-                            Some(s) => s.clone(),
+                            Some(s) => crate::core::render::resolve_multicam_clips(
+                                s,
+                                &project.state.sequences,
+                            ),
                             None => {
                                 let message =
                                     format!("Sequence {} removed during cache render", job_seq_id);
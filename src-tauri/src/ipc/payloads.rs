@@ -1,12 +1,15 @@
-use crate::core::assets::{AudioInfo, LicenseInfo, ProxyStatus, VideoInfo};
+use crate::core::assets::{AudioInfo, LicenseInfo, ProxyStatus, SourceMetadata, VideoInfo};
 use crate::core::effects::{EffectType, Keyframe, ParamValue};
 use crate::core::masks::{MaskBlendMode, MaskKeyframe, MaskShape};
 use crate::core::project::ProjectState;
 use crate::core::text::TextClipData;
 use crate::core::timeline::{
-    BlendMode, MarkerType, SequenceHdrSettings, Track, TrackKind, Transform, TransformKeyframe,
+    BlendMode, MarkerType, SequenceHdrSettings, SurroundPan, Track, TrackKind, Transform,
+    TransformKeyframe,
+};
+use crate::core::{
+    AssetId, BusId, ClipId, Color, EffectId, MaskId, Ratio, SequenceId, TimeSec, TrackId,
 };
-use crate::core::{AssetId, ClipId, Color, EffectId, MaskId, SequenceId, TimeSec, TrackId};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -244,6 +247,39 @@ pub struct TrimClipPayload {
     pub new_timeline_in: Option<TimeSec>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SlipClipPayload {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    /// Source offset in seconds; positive plays later media.
+    pub delta_sec: TimeSec,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SlideClipPayload {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    /// Timeline offset in seconds; positive slides right.
+    pub delta_sec: TimeSec,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RollEditPayload {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    /// Clip ending at the edit point.
+    pub outgoing_clip_id: ClipId,
+    /// Clip starting at the edit point.
+    pub incoming_clip_id: ClipId,
+    /// Edit point offset in seconds; positive rolls right.
+    pub delta_sec: TimeSec,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct SetClipTransformPayload {
@@ -408,6 +444,8 @@ pub struct SetClipAudioPayload {
     pub fade_out_sec: Option<TimeSec>,
     pub audio_role: Option<String>,
     pub audio_tags: Option<Vec<String>>,
+    /// Source channels to play, 0-based; an empty list plays the source as it is.
+    pub channel_map: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
@@ -515,6 +553,7 @@ pub struct UpdateAssetPayload {
     pub relative_path: Option<Option<String>>,
     pub workspace_managed: Option<bool>,
     pub missing: Option<bool>,
+    pub source: Option<Option<Box<SourceMetadata>>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
@@ -522,6 +561,12 @@ pub struct UpdateAssetPayload {
 pub struct CreateSequencePayload {
     pub name: String,
     pub format: Option<String>,
+    /// Frame rate overriding the format preset's.
+    #[serde(default)]
+    pub fps: Option<Ratio>,
+    /// Whether to add the default video and audio tracks (defaults to `true`).
+    #[serde(default)]
+    pub add_default_tracks: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
@@ -531,6 +576,73 @@ pub struct SetMasterVolumePayload {
     pub volume_db: f32,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateAudioBusPayload {
+    pub sequence_id: SequenceId,
+    pub name: String,
+    /// Bus this one feeds (None = the master)
+    #[serde(default)]
+    pub output_bus_id: Option<BusId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RemoveAudioBusPayload {
+    pub sequence_id: SequenceId,
+    pub bus_id: BusId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateAudioBusPayload {
+    pub sequence_id: SequenceId,
+    pub bus_id: BusId,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub volume_db: Option<f32>,
+    #[serde(default)]
+    pub muted: Option<bool>,
+    /// Bus to feed instead of the current output
+    #[serde(default)]
+    pub output_bus_id: Option<BusId>,
+    /// Feed the master instead of the current output (wins over `outputBusId`)
+    #[serde(default)]
+    pub route_to_master: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SetTrackOutputBusPayload {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    /// Bus the track feeds (None = the master)
+    #[serde(default)]
+    pub bus_id: Option<BusId>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AddAudioBusEffectPayload {
+    pub sequence_id: SequenceId,
+    pub bus_id: BusId,
+    pub effect_type: EffectType,
+    #[serde(default, alias = "parameters")]
+    pub params: HashMap<String, ParamValue>,
+    /// Optional position in the bus's effect chain (None = append at end)
+    #[serde(default)]
+    pub position: Option<usize>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RemoveAudioBusEffectPayload {
+    pub sequence_id: SequenceId,
+    pub bus_id: BusId,
+    pub effect_id: EffectId,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct UpdateSequenceHdrSettingsPayload {
@@ -538,6 +650,14 @@ pub struct UpdateSequenceHdrSettingsPayload {
     pub settings: SequenceHdrSettings,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SetSequenceStartTimecodePayload {
+    pub sequence_id: SequenceId,
+    /// `HH:MM:SS:FF` (`;` for drop-frame); `null` clears it
+    pub start_timecode: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreateTrackPayload {
@@ -587,6 +707,16 @@ pub struct SetTrackVolumePayload {
     pub volume: f32,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SetTrackSurroundPanPayload {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    /// Position in the surround field; omitted or null clears it.
+    #[serde(default)]
+    pub surround_pan: Option<SurroundPan>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct ToggleTrackMutePayload {
@@ -1167,6 +1297,59 @@ pub struct UnnestCompoundClipPayload {
     pub clip_id: ClipId,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateMulticamClipPayload {
+    pub sequence_id: SequenceId,
+    /// Video track that receives the multicam clip
+    pub track_id: TrackId,
+    /// Synced angle clips; each video track they sit on becomes one angle
+    pub clip_ids: Vec<ClipId>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SwitchMulticamAnglePayload {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    /// Inner video track of the angle to show
+    pub angle_track_id: TrackId,
+    /// Timeline time of the cut; omit to switch the whole clip
+    #[serde(default)]
+    pub at_sec: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FlattenMulticamClipPayload {
+    pub sequence_id: SequenceId,
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SyncClipOffsetPayload {
+    pub track_id: TrackId,
+    pub clip_id: ClipId,
+    /// Reference-media time at which this clip's media starts
+    pub offset_sec: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct SyncClipsPayload {
+    pub sequence_id: SequenceId,
+    pub reference_track_id: TrackId,
+    pub reference_clip_id: ClipId,
+    pub clips: Vec<SyncClipOffsetPayload>,
+    /// Link the synced clips to the reference (defaults to `false`)
+    #[serde(default)]
+    pub link: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, specta::Type)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreateAdjustmentLayerPayload {
@@ -1230,6 +1413,15 @@ pub enum CommandPayload {
     #[serde(alias = "trimClip", alias = "TrimClip")]
     TrimClip(TrimClipPayload),
 
+    #[serde(alias = "slipClip", alias = "SlipClip")]
+    SlipClip(SlipClipPayload),
+
+    #[serde(alias = "slideClip", alias = "SlideClip")]
+    SlideClip(SlideClipPayload),
+
+    #[serde(alias = "rollEdit", alias = "RollEdit")]
+    RollEdit(RollEditPayload),
+
     #[serde(alias = "splitClip", alias = "SplitClip")]
     SplitClip(SplitClipPayload),
 
@@ -1334,12 +1526,33 @@ pub enum CommandPayload {
     #[serde(alias = "setMasterVolume", alias = "SetMasterVolume")]
     SetMasterVolume(SetMasterVolumePayload),
 
+    #[serde(alias = "createAudioBus", alias = "CreateAudioBus")]
+    CreateAudioBus(CreateAudioBusPayload),
+
+    #[serde(alias = "removeAudioBus", alias = "RemoveAudioBus")]
+    RemoveAudioBus(RemoveAudioBusPayload),
+
+    #[serde(alias = "updateAudioBus", alias = "UpdateAudioBus")]
+    UpdateAudioBus(UpdateAudioBusPayload),
+
+    #[serde(alias = "setTrackOutputBus", alias = "SetTrackOutputBus")]
+    SetTrackOutputBus(SetTrackOutputBusPayload),
+
+    #[serde(alias = "addAudioBusEffect", alias = "AddAudioBusEffect")]
+    AddAudioBusEffect(AddAudioBusEffectPayload),
+
+    #[serde(alias = "removeAudioBusEffect", alias = "RemoveAudioBusEffect")]
+    RemoveAudioBusEffect(RemoveAudioBusEffectPayload),
+
     #[serde(
         alias = "updateSequenceHdrSettings",
         alias = "UpdateSequenceHdrSettings"
     )]
     UpdateSequenceHdrSettings(UpdateSequenceHdrSettingsPayload),
 
+    #[serde(alias = "setSequenceStartTimecode", alias = "SetSequenceStartTimecode")]
+    SetSequenceStartTimecode(SetSequenceStartTimecodePayload),
+
     #[serde(
         alias = "createTrack",
         alias = "CreateTrack",
@@ -1368,6 +1581,9 @@ pub enum CommandPayload {
     #[serde(alias = "setTrackVolume", alias = "SetTrackVolume")]
     SetTrackVolume(SetTrackVolumePayload),
 
+    #[serde(alias = "setTrackSurroundPan", alias = "SetTrackSurroundPan")]
+    SetTrackSurroundPan(SetTrackSurroundPanPayload),
+
     #[serde(alias = "toggleTrackMute", alias = "ToggleTrackMute")]
     ToggleTrackMute(ToggleTrackMutePayload),
 
@@ -1474,6 +1690,18 @@ pub enum CommandPayload {
     #[serde(alias = "unnestCompoundClip", alias = "UnnestCompoundClip")]
     UnnestCompoundClip(UnnestCompoundClipPayload),
 
+    #[serde(alias = "createMulticamClip", alias = "CreateMulticamClip")]
+    CreateMulticamClip(CreateMulticamClipPayload),
+
+    #[serde(alias = "switchMulticamAngle", alias = "SwitchMulticamAngle")]
+    SwitchMulticamAngle(SwitchMulticamAnglePayload),
+
+    #[serde(alias = "flattenMulticamClip", alias = "FlattenMulticamClip")]
+    FlattenMulticamClip(FlattenMulticamClipPayload),
+
+    #[serde(alias = "syncClips", alias = "SyncClips")]
+    SyncClips(SyncClipsPayload),
+
     #[serde(alias = "createAdjustmentLayer", alias = "CreateAdjustmentLayer")]
     CreateAdjustmentLayer(CreateAdjustmentLayerPayload),
 
@@ -1501,6 +1729,9 @@ impl CommandPayload {
         "RemoveClip",
         "MoveClip",
         "TrimClip",
+        "SlipClip",
+        "SlideClip",
+        "RollEdit",
         "SplitClip",
         "SetClipTransform",
         "SetClipMotionKeyframes",
@@ -1532,13 +1763,21 @@ impl CommandPayload {
         "UpdateAsset",
         "CreateSequence",
         "SetMasterVolume",
+        "CreateAudioBus",
+        "RemoveAudioBus",
+        "UpdateAudioBus",
+        "SetTrackOutputBus",
+        "AddAudioBusEffect",
+        "RemoveAudioBusEffect",
         "UpdateSequenceHdrSettings",
+        "SetSequenceStartTimecode",
         "CreateTrack",
         "RemoveTrack",
         "RenameTrack",
         "SetCaptionTrackLanguage",
         "ReorderTracks",
         "SetTrackVolume",
+        "SetTrackSurroundPan",
         "ToggleTrackMute",
         "ToggleTrackLock",
         "ToggleTrackVisibility",
@@ -1564,6 +1803,10 @@ impl CommandPayload {
         "ApplyAudioDucking",
         "CreateCompoundClip",
         "UnnestCompoundClip",
+        "CreateMulticamClip",
+        "SwitchMulticamAngle",
+        "FlattenMulticamClip",
+        "SyncClips",
         "CreateAdjustmentLayer",
         "PasteEffects",
         "PasteAttributes",
@@ -1709,17 +1952,25 @@ impl CommandPayload {
             SetClipAudioCommand, SetClipBlendModeCommand, SetClipEnabledCommand,
             SetClipMotionKeyframesCommand, SetClipMuteCommand, SetClipOpacityCommand,
             SetClipSlowMotionInterpolationCommand, SetClipSpeedCommand, SetClipTransformCommand,
-            SetMasterVolumeCommand, SetTimeRemapCommand, SetTrackBlendModeCommand,
-            SetTrackVolumeCommand, SplitClipCommand, ToggleTrackLockCommand,
-            ToggleTrackMuteCommand, ToggleTrackVisibilityCommand, TrimClipCommand,
-            UngroupClipsCommand, UnlinkClipsCommand, UnnestCompoundClipCommand, UpdateAssetCommand,
-            UpdateEffectCommand, UpdateMaskCommand, UpdateSequenceHdrSettingsCommand,
-            UpdateTextCommand,
+            SetMasterVolumeCommand, SetSequenceStartTimecodeCommand, SetTimeRemapCommand,
+            SetTrackBlendModeCommand, SetTrackVolumeCommand, SplitClipCommand,
+            ToggleTrackLockCommand, ToggleTrackMuteCommand, ToggleTrackVisibilityCommand,
+            TrimClipCommand, UngroupClipsCommand, UnlinkClipsCommand, UnnestCompoundClipCommand,
+            UpdateAssetCommand, UpdateEffectCommand, UpdateMaskCommand,
+            UpdateSequenceHdrSettingsCommand, UpdateTextCommand,
         };
 
         use crate::core::commands::{
-            CreateAdjustmentLayerCommand, CreateCompoundClipCommand, PasteAttributesCommand,
-            PasteEffectsCommand, RemoveAttributesCommand,
+            AddAudioBusEffectCommand, CreateAudioBusCommand, RemoveAudioBusCommand,
+            RemoveAudioBusEffectCommand, SetTrackOutputBusCommand, SetTrackSurroundPanCommand,
+            UpdateAudioBusCommand,
+        };
+
+        use crate::core::commands::{
+            CreateAdjustmentLayerCommand, CreateCompoundClipCommand, CreateMulticamClipCommand,
+            FlattenMulticamClipCommand, PasteAttributesCommand, PasteEffectsCommand,
+            RemoveAttributesCommand, RollEditCommand, SlideClipCommand, SlipClipCommand,
+            SwitchMulticamAngleCommand, SyncClipOffset, SyncClipsCommand,
         };
 
         match self {
@@ -1811,6 +2062,25 @@ impl CommandPayload {
                 p.new_source_out,
                 p.new_timeline_in,
             )),
+            CommandPayload::SlipClip(p) => Box::new(SlipClipCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.clip_id,
+                p.delta_sec,
+            )),
+            CommandPayload::SlideClip(p) => Box::new(SlideClipCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.clip_id,
+                p.delta_sec,
+            )),
+            CommandPayload::RollEdit(p) => Box::new(RollEditCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.outgoing_clip_id,
+                &p.incoming_clip_id,
+                p.delta_sec,
+            )),
             CommandPayload::SplitClip(p) => Box::new(SplitClipCommand::new(
                 &p.sequence_id,
                 &p.track_id,
@@ -1938,6 +2208,9 @@ impl CommandPayload {
                 if let Some(audio_tags) = p.audio_tags {
                     cmd = cmd.with_audio_tags(audio_tags);
                 }
+                if let Some(channel_map) = p.channel_map {
+                    cmd = cmd.with_channel_map(channel_map);
+                }
                 Box::new(cmd)
             }
             CommandPayload::AddAudioKeyframe(p) => Box::new(AddAudioKeyframeCommand::new(
@@ -1995,6 +2268,11 @@ impl CommandPayload {
                 &p.track_id,
                 p.volume,
             )),
+            CommandPayload::SetTrackSurroundPan(p) => Box::new(SetTrackSurroundPanCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                p.surround_pan,
+            )),
             CommandPayload::SetClipBlendMode(p) => Box::new(SetClipBlendModeCommand::new(
                 &p.sequence_id,
                 &p.track_id,
@@ -2047,18 +2325,82 @@ impl CommandPayload {
                 if let Some(missing) = p.missing {
                     cmd = cmd.with_missing(missing);
                 }
+                if let Some(source) = p.source {
+                    cmd = cmd.with_source(source.map(|source| *source));
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::CreateSequence(p) => {
+                let mut cmd = CreateSequenceCommand::new(
+                    &p.name,
+                    &p.format.unwrap_or_else(|| "1080p".to_string()),
+                );
+                if let Some(fps) = p.fps {
+                    cmd = cmd.with_fps(fps);
+                }
+                if let Some(add_default_tracks) = p.add_default_tracks {
+                    cmd = cmd.with_default_tracks(add_default_tracks);
+                }
                 Box::new(cmd)
             }
-            CommandPayload::CreateSequence(p) => Box::new(CreateSequenceCommand::new(
-                &p.name,
-                &p.format.unwrap_or_else(|| "1080p".to_string()),
-            )),
             CommandPayload::SetMasterVolume(p) => {
                 Box::new(SetMasterVolumeCommand::new(&p.sequence_id, p.volume_db))
             }
+            CommandPayload::CreateAudioBus(p) => {
+                let mut cmd = CreateAudioBusCommand::new(&p.sequence_id, &p.name);
+                if let Some(output_bus_id) = p.output_bus_id.as_deref() {
+                    cmd = cmd.with_output(output_bus_id);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::RemoveAudioBus(p) => {
+                Box::new(RemoveAudioBusCommand::new(&p.sequence_id, &p.bus_id))
+            }
+            CommandPayload::UpdateAudioBus(p) => {
+                let mut cmd = UpdateAudioBusCommand::new(&p.sequence_id, &p.bus_id);
+                if let Some(name) = p.name.as_deref() {
+                    cmd = cmd.with_name(name);
+                }
+                if let Some(volume_db) = p.volume_db {
+                    cmd = cmd.with_volume_db(volume_db);
+                }
+                if let Some(muted) = p.muted {
+                    cmd = cmd.with_muted(muted);
+                }
+                if p.route_to_master {
+                    cmd = cmd.with_output(None);
+                } else if let Some(output_bus_id) = p.output_bus_id {
+                    cmd = cmd.with_output(Some(output_bus_id));
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::SetTrackOutputBus(p) => Box::new(SetTrackOutputBusCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                p.bus_id.as_deref(),
+            )),
+            CommandPayload::AddAudioBusEffect(p) => {
+                let mut cmd =
+                    AddAudioBusEffectCommand::new(&p.sequence_id, &p.bus_id, p.effect_type);
+                for (key, value) in p.params {
+                    cmd = cmd.with_param(key, value);
+                }
+                if let Some(position) = p.position {
+                    cmd = cmd.at_position(position);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::RemoveAudioBusEffect(p) => Box::new(RemoveAudioBusEffectCommand::new(
+                &p.sequence_id,
+                &p.bus_id,
+                &p.effect_id,
+            )),
             CommandPayload::UpdateSequenceHdrSettings(p) => Box::new(
                 UpdateSequenceHdrSettingsCommand::new(&p.sequence_id, p.settings),
             ),
+            CommandPayload::SetSequenceStartTimecode(p) => Box::new(
+                SetSequenceStartTimecodeCommand::new(&p.sequence_id, p.start_timecode),
+            ),
             CommandPayload::CreateTrack(p) => {
                 let mut cmd = AddTrackCommand::new(&p.sequence_id, &p.name, p.kind);
                 if let Some(position) = p.position {
@@ -2305,6 +2647,47 @@ impl CommandPayload {
                 &p.track_id,
                 &p.clip_id,
             )),
+            CommandPayload::CreateMulticamClip(p) => {
+                let mut cmd =
+                    CreateMulticamClipCommand::new(&p.sequence_id, &p.track_id, p.clip_ids);
+                if let Some(name) = p.name {
+                    cmd = cmd.with_name(&name);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::SwitchMulticamAngle(p) => {
+                let mut cmd = SwitchMulticamAngleCommand::new(
+                    &p.sequence_id,
+                    &p.track_id,
+                    &p.clip_id,
+                    &p.angle_track_id,
+                );
+                if let Some(at_sec) = p.at_sec {
+                    cmd = cmd.at(at_sec);
+                }
+                Box::new(cmd)
+            }
+            CommandPayload::FlattenMulticamClip(p) => Box::new(FlattenMulticamClipCommand::new(
+                &p.sequence_id,
+                &p.track_id,
+                &p.clip_id,
+            )),
+            CommandPayload::SyncClips(p) => Box::new(
+                SyncClipsCommand::new(
+                    &p.sequence_id,
+                    &p.reference_track_id,
+                    &p.reference_clip_id,
+                    p.clips
+                        .into_iter()
+                        .map(|clip| SyncClipOffset {
+                            track_id: clip.track_id,
+                            clip_id: clip.clip_id,
+                            offset_sec: clip.offset_sec,
+                        })
+                        .collect(),
+                )
+                .with_link(p.link),
+            ),
             CommandPayload::CreateAdjustmentLayer(p) => {
                 let mut cmd = CreateAdjustmentLayerCommand::new(
                     &p.sequence_id,
@@ -2976,6 +3359,39 @@ mod tests {
         assert!(parsed.is_err());
     }
 
+    #[test]
+    fn parse_set_track_surround_pan_payload_is_supported() {
+        let payload = serde_json::json!({
+            "sequenceId": "seq_001",
+            "trackId": "track_001",
+            "surroundPan": { "x": -0.5, "y": -1.0 },
+        });
+
+        let parsed = CommandPayload::parse("SetTrackSurroundPan".to_string(), payload);
+        assert!(
+            matches!(parsed, Ok(CommandPayload::SetTrackSurroundPan(_))),
+            "expected SetTrackSurroundPan to parse, got: {parsed:?}"
+        );
+
+        if let Ok(CommandPayload::SetTrackSurroundPan(p)) = parsed {
+            assert_eq!(
+                p.surround_pan,
+                Some(SurroundPan {
+                    x: -0.5,
+                    y: -1.0,
+                    lfe: 0.0
+                })
+            );
+        }
+
+        let clear = serde_json::json!({ "sequenceId": "seq_001", "trackId": "track_001" });
+        let parsed = CommandPayload::parse("setTrackSurroundPan".to_string(), clear);
+        assert!(
+            matches!(parsed, Ok(CommandPayload::SetTrackSurroundPan(ref p)) if p.surround_pan.is_none()),
+            "expected a clearing SetTrackSurroundPan, got: {parsed:?}"
+        );
+    }
+
     #[test]
     fn parse_set_track_volume_payload_is_supported() {
         let payload = serde_json::json!({
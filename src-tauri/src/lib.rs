@@ -116,10 +116,17 @@ impl ActiveProject {
             crate::core::project::OpKind::ClipRemove => "RemoveClip",
             crate::core::project::OpKind::ClipMove => "MoveClip",
             crate::core::project::OpKind::ClipTrim => "TrimClip",
+            crate::core::project::OpKind::ClipSlip => "SlipClip",
+            crate::core::project::OpKind::ClipSlide => "SlideClip",
+            crate::core::project::OpKind::ClipRoll => "RollEdit",
             crate::core::project::OpKind::ClipSplit => "SplitClip",
             crate::core::project::OpKind::ClipUpdate => "SetClipAudio",
             crate::core::project::OpKind::CompoundClipCreate => "CreateCompoundClip",
             crate::core::project::OpKind::CompoundClipUnnest => "UnnestCompoundClip",
+            crate::core::project::OpKind::MulticamClipCreate => "CreateMulticamClip",
+            crate::core::project::OpKind::MulticamAngleSwitch => "SwitchMulticamAngle",
+            crate::core::project::OpKind::MulticamClipFlatten => "FlattenMulticamClip",
+            crate::core::project::OpKind::ClipSync => "SyncClips",
             crate::core::project::OpKind::ClipGroup => "GroupClips",
             crate::core::project::OpKind::ClipUngroup => "UngroupClips",
             crate::core::project::OpKind::ClipLink => "LinkClips",
@@ -128,6 +135,12 @@ impl ActiveProject {
             crate::core::project::OpKind::TrackRemove => "RemoveTrack",
             crate::core::project::OpKind::TrackReorder => "ReorderTracks",
             crate::core::project::OpKind::TrackUpdate => "RenameTrack",
+            crate::core::project::OpKind::AudioBusCreate => "CreateAudioBus",
+            crate::core::project::OpKind::AudioBusRemove => "RemoveAudioBus",
+            crate::core::project::OpKind::AudioBusUpdate => "UpdateAudioBus",
+            crate::core::project::OpKind::AudioBusRoute => "SetTrackOutputBus",
+            crate::core::project::OpKind::AudioBusEffectAdd => "AddAudioBusEffect",
+            crate::core::project::OpKind::AudioBusEffectRemove => "RemoveAudioBusEffect",
             crate::core::project::OpKind::EffectAdd => "AddEffect",
             crate::core::project::OpKind::EffectRemove => "RemoveEffect",
             crate::core::project::OpKind::EffectUpdate => "UpdateEffect",
@@ -1606,6 +1619,8 @@ mod tauri_app {
                 // Asset commands
                 $crate::ipc::import_asset,
                 $crate::ipc::relink_asset,
+                $crate::ipc::import_image_sequence,
+                $crate::ipc::set_image_sequence_frame_rate,
                 $crate::ipc::get_assets,
                 $crate::ipc::remove_asset,
                 $crate::ipc::generate_asset_thumbnail,
@@ -1648,6 +1663,10 @@ mod tauri_app {
                 $crate::ipc::cancel_render,
                 $crate::ipc::export_frame,
                 $crate::ipc::export_audio_only,
+                $crate::ipc::export_audio_stems,
+                $crate::ipc::export_streaming_package,
+                $crate::ipc::export_image_sequence,
+                $crate::ipc::export_animated_image,
                 $crate::ipc::get_available_encoders,
                 $crate::ipc::detect_gpu_devices,
                 $crate::ipc::get_available_decoders,
@@ -1665,6 +1684,7 @@ mod tauri_app {
                 $crate::ipc::export_edl,
                 $crate::ipc::export_fcpxml,
                 $crate::ipc::export_otio,
+                $crate::ipc::export_xmeml,
                 // Export destination picker (native save dialog + allow-list)
                 $crate::ipc::pick_export_destination,
                 // AI commands
@@ -1814,6 +1834,7 @@ mod tauri_app {
                 $crate::ipc::apply_editing_style,
                 // Color match (S38-002)
                 $crate::ipc::auto_color_match,
+                $crate::ipc::sync_clips_by_audio,
                 // Settings
                 $crate::ipc::get_settings,
                 $crate::ipc::set_settings,
@@ -2137,6 +2158,8 @@ mod tauri_app {
             // Asset commands
             ipc::import_asset,
             ipc::relink_asset,
+            ipc::import_image_sequence,
+            ipc::set_image_sequence_frame_rate,
             ipc::get_assets,
             ipc::remove_asset,
             ipc::generate_asset_thumbnail,
@@ -2179,6 +2202,10 @@ mod tauri_app {
             ipc::cancel_render,
             ipc::export_frame,
             ipc::export_audio_only,
+            ipc::export_audio_stems,
+            ipc::export_streaming_package,
+            ipc::export_image_sequence,
+            ipc::export_animated_image,
             ipc::get_available_encoders,
             ipc::detect_gpu_devices,
             ipc::get_available_decoders,
@@ -2196,6 +2223,7 @@ mod tauri_app {
             ipc::export_edl,
             ipc::export_fcpxml,
             ipc::export_otio,
+            ipc::export_xmeml,
             // Export destination picker (native save dialog + allow-list)
             ipc::pick_export_destination,
             // AI commands
@@ -2346,6 +2374,7 @@ mod tauri_app {
             ipc::apply_editing_style,
             // Color match (S38-002)
             ipc::auto_color_match,
+            ipc::sync_clips_by_audio,
             // Settings
             ipc::get_settings,
             ipc::set_settings,
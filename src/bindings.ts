@@ -231,6 +231,33 @@ async relinkAsset(assetId: string, uri: string) : Promise<Result<AssetRelinkResu
     return { status: "error", error: e  as any };
 }
 },
+/**
+ * Imports a numbered still sequence (e.g. `shot_1001.exr`, `shot_1002.exr`, ...)
+ * as a single video asset playing at `fpsNum/fpsDen`.
+ * 
+ * `uri` may name any frame of the sequence. Always queues proxy generation,
+ * since the preview cannot play stills as video.
+ */
+async importImageSequence(uri: string, fpsNum: number, fpsDen: number) : Promise<Result<AssetImportResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("import_image_sequence", { uri, fpsNum, fpsDen }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
+/**
+ * Changes the rate an image-sequence asset plays at.
+ * 
+ * Stills carry no timing, so the rate is the user's to pick. The asset's
+ * duration follows the new rate and its proxy is re-encoded.
+ */
+async setImageSequenceFrameRate(assetId: string, fpsNum: number, fpsDen: number) : Promise<Result<ImageSequenceFrameRateResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("set_image_sequence_frame_rate", { assetId, fpsNum, fpsDen }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
 /**
  * Gets all assets in the project
  */
@@ -620,6 +647,70 @@ async exportAudioOnly(sequenceId: string, format: string, outputPath: string, bi
     return { status: "error", error: e  as any };
 }
 },
+/**
+ * Exports audio stems from a sequence.
+ * 
+ * Renders each stem (a group of tracks) to its own file next to `output_path`,
+ * named `<output name>_<stem name>`, and the mixdown to `output_path` when
+ * `include_mixdown` is set. All files come from one FFmpeg job and share the
+ * same length. Reports progress like `export_audio_only`.
+ */
+async exportAudioStems(sequenceId: string, format: string, outputPath: string, stems: AudioStem[], includeMixdown: boolean, bitrate: string | null, sampleRate: number | null, startTime: number | null, endTime: number | null) : Promise<Result<RenderStartResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_audio_stems", { sequenceId, format, outputPath, stems, includeMixdown, bitrate, sampleRate, startTime, endTime }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
+/**
+ * Packages a sequence for adaptive streaming.
+ * 
+ * Renders the ladder in `renditions` from one decode of the sequence into
+ * `output_dir` as CMAF segments with a DASH `manifest.mpd` and an HLS
+ * `master.m3u8`, adding the caption tracks as WebVTT renditions when
+ * `include_captions` is set. `video_codec` defaults to H.264 and
+ * `segment_duration_sec` to 6 seconds. Reports progress like `start_render`.
+ */
+async exportStreamingPackage(sequenceId: string, outputDir: string, renditions: StreamingRendition[], videoCodec: VideoCodec | null, audioBitrate: string | null, segmentDurationSec: number | null, includeCaptions: boolean, burnInCaptions: boolean, startTime: number | null, endTime: number | null) : Promise<Result<RenderStartResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_streaming_package", { sequenceId, outputDir, renditions, videoCodec, audioBitrate, segmentDurationSec, includeCaptions, burnInCaptions, startTime, endTime }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
+/**
+ * Exports a range of a sequence as numbered stills for VFX hand-off.
+ * 
+ * Writes `<base_name>.<frame>.<ext>` into `output_dir`, numbered after the
+ * timeline frames unless `start_number` is given, with `frame_padding` digits
+ * (default 4), plus a `<base_name>.manifest.json` mapping every file to its
+ * timeline timecode. `bit_depth` defaults per format: 8 for PNG, 16 for TIFF
+ * and EXR (half float), 10 for DPX. Reports progress like `start_render`.
+ */
+async exportImageSequence(sequenceId: string, outputDir: string, baseName: string, format: ImageSequenceFormat, bitDepth: number | null, alpha: boolean, framePadding: number | null, startNumber: number | null, startTime: number | null, endTime: number | null) : Promise<Result<RenderStartResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_image_sequence", { sequenceId, outputDir, baseName, format, bitDepth, alpha, framePadding, startNumber, startTime, endTime }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
+/**
+ * Exports a range of a sequence as a looping GIF or animated WebP.
+ * 
+ * `fps` and `width` default to the sequence's, capped at 15 fps and 480 px;
+ * the height follows the aspect ratio. GIFs use a palette of `max_colors`
+ * (default 256) built from the range and the chosen `dither`; WebP uses
+ * `quality` (default 75) or `lossless`. `play_count` 0 loops forever. With
+ * `max_file_size` (bytes), frame rate and width are lowered over up to six
+ * encodes until the file fits. Reports progress like `start_render`.
+ */
+async exportAnimatedImage(sequenceId: string, outputPath: string, format: AnimatedImageFormat, fps: number | null, width: number | null, maxColors: number | null, dither: GifDither | null, quality: number | null, lossless: boolean, playCount: number | null, maxFileSize: number | null, startTime: number | null, endTime: number | null) : Promise<Result<RenderStartResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_animated_image", { sequenceId, outputPath, format, fps, width, maxColors, dither, quality, lossless, playCount, maxFileSize, startTime, endTime }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
 /**
  * Detect available hardware video encoders (NVENC, QSV, AMF, VideoToolbox).
  * 
@@ -792,6 +883,20 @@ async exportOtio(sequenceId: string, outputPath: string) : Promise<Result<Interc
     return { status: "error", error: e  as any };
 }
 },
+/**
+ * Exports a sequence to Final Cut Pro 7 XML (`xmeml`).
+ * 
+ * Premiere Pro and DaVinci Resolve exchange this legacy format more reliably
+ * than FCPXML. Tracks, cuts, speed, opacity and volume keyframes and markers
+ * survive; whatever does not is named in the result's `unsupported` list.
+ */
+async exportXmeml(sequenceId: string, outputPath: string) : Promise<Result<InterchangeExportResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_xmeml", { sequenceId, outputPath }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
 /**
  * Opens a native save dialog for an export destination.
  * 
@@ -2231,6 +2336,22 @@ async autoColorMatch(referenceClipId: string, targetClipId: string, sequenceId:
     return { status: "error", error: e  as any };
 }
 },
+/**
+ * Syncs clips to a reference clip by cross-correlating their audio.
+ * 
+ * Envelopes come from the waveform cache, generated with FFmpeg when an asset
+ * has none at the sync rate. Assets whose audio does not match confidently
+ * fall back to the start timecodes passed in. Synced clips are then placed
+ * (and optionally linked) with a single undoable `SyncClips` command;
+ * unsynced ones stay where they are and are reported in the analysis.
+ */
+async syncClipsByAudio(args: SyncClipsByAudioArgs) : Promise<Result<SyncClipsByAudioResult, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("sync_clips_by_audio", { args }) };
+} catch (e) {
+    return { status: "error", error: e  as any };
+}
+},
 /**
  * Gets application settings
  */
@@ -3089,6 +3210,11 @@ needsConfirmation: boolean | null;
  */
 intent: AIIntentDto | null }
 export type AISettingsDto = { assistantRuntime?: AssistantRuntimeDto; codexModel?: string; codexReasoningEffort?: CodexReasoningEffortDto; claudeModel?: string; claudeEffort?: string; claudeAuthMode?: string; codexPreferSystem?: boolean; claudePreferSystem?: boolean; primaryProvider: ProviderTypeDto; primaryModel: string; visionProvider: ProviderTypeDto | null; visionModel: string | null; openaiApiKey: string | null; anthropicApiKey: string | null; googleApiKey: string | null; ollamaUrl: string | null; temperature: number; maxTokens: number; frameExtractionRate: number; monthlyBudgetCents: number | null; perRequestLimitCents: number; currentMonthUsageCents: number; currentUsageMonth: number | null; autoAnalyzeOnImport: boolean; autoCaptionOnImport: boolean; proposalReviewMode: ProposalReviewModeDto; cacheDurationHours: number; localOnlyMode: boolean; seedanceApiKey?: string | null; videoGenProvider?: string | null; videoGenDefaultQuality?: string; videoGenBudgetCents?: number | null; videoGenPerRequestLimitCents?: number }
+export type AddAudioBusEffectPayload = { sequenceId: string; busId: string; effectType: EffectType; params?: { [key in string]: ParamValue }; 
+/**
+ * Optional position in the bus's effect chain (None = append at end)
+ */
+position?: number | null }
 export type AddAudioKeyframePayload = { sequenceId: string; trackId: string; clipId: string; timeOffset: number; valueDb: number; interpolation?: KeyframeInterpolation }
 /**
  * Payload for adding an effect to a clip.
@@ -3607,6 +3733,18 @@ response: AnalysisResponse }
 /**
  * Best-effort cleanup result returned to the frontend on app close.
  */
+/**
+ * Format of an animated-image export
+ */
+export type AnimatedImageFormat = 
+/**
+ * GIF with a palette built from the exported range
+ */
+"gif" | 
+/**
+ * Animated WebP, lossy or lossless
+ */
+"webp"
 export type AppCleanupResult = { projectSaved: boolean; workersShutdown: boolean; error: string | null }
 /**
  * DTO for app settings (mirrors Rust AppSettings)
@@ -3726,7 +3864,15 @@ missing?: boolean;
  * `None` on every asset that loaded cleanly, which is every asset written by
  * this application.
  */
-quarantinedUri?: string | null }
+quarantinedUri?: string | null; 
+/**
+ * Source timecode, reel and camera metadata probed from the file
+ */
+source?: SourceMetadata | null;
+/**
+ * Set when the asset is a numbered still sequence rather than one file
+ */
+imageSequence?: ImageSequenceInfo | null }
 /**
  * Complete annotation data for an asset
  */
@@ -3835,6 +3981,19 @@ duration: number | null;
  * Associated tags
  */
 tags: string[] }
+/**
+ * Where one asset starts relative to the reference asset.
+ */
+export type AssetSyncOffset = { assetId: string; 
+/**
+ * Reference-asset time at which this asset's first sample plays.
+ * Negative when this asset started recording before the reference.
+ */
+offsetSec: number; 
+/**
+ * 0.0 (no evidence) to 1.0 (unambiguous match)
+ */
+confidence: number; method: SyncMethod }
 export type AssistantRuntimeDto = "api" | "codex" | "claude_code"
 /**
  * Flags indicating which attributes to paste from the clipboard.
@@ -3864,10 +4023,70 @@ speed?: boolean;
  * Whether to paste audio settings (volume, pan, fades)
  */
 audioSettings?: boolean }
+/**
+ * Audio submix bus
+ * 
+ * Tracks and other buses route into a bus. The bus mixes its inputs, runs its
+ * effect chain, applies its gain and feeds the result to its own output: another
+ * bus, or the master when `output_bus_id` is `None`.
+ */
+export type AudioBus = { id: string; name: string; 
+/**
+ * Bus gain in dB (-60.0 to +6.0, 0.0 = unity gain)
+ */
+volumeDb?: number; 
+/**
+ * Muting a bus silences everything routed through it
+ */
+muted?: boolean; 
+/**
+ * Audio effects applied to the bus mix, in order
+ */
+effects?: string[]; 
+/**
+ * Bus this one feeds; `None` feeds the master
+ */
+outputBusId?: string | null }
+/**
+ * Channel layout of the exported audio
+ */
+export type AudioChannelLayout = 
+/**
+ * Two channels (default)
+ */
+"stereo" | 
+/**
+ * 5.1: front left/right, centre, LFE, surround left/right
+ */
+"surround51" | 
+/**
+ * 7.1: 5.1 plus side left/right
+ */
+"surround71"
 /**
  * Audio codec selection
  */
 export type AudioCodec = "aac" | "mp3" | "opus" | "pcm" | "copy"
+/**
+ * How sources with more channels than the output are folded down
+ */
+export type AudioDownmix = 
+/**
+ * ITU-R BS.775 fold-down: centre and surrounds at -3 dB, LFE dropped
+ */
+"standard" | 
+/**
+ * As `Standard`, with the LFE folded in at -3 dB
+ */
+"with_lfe" | 
+/**
+ * Dolby Surround (Lt/Rt) matrix-encoded stereo
+ */
+"dolby_surround" | 
+/**
+ * Dolby Pro Logic II matrix-encoded stereo
+ */
+"pro_logic_ii"
 /**
  * Parameters for audio ducking.
  */
@@ -3920,7 +4139,12 @@ codec: string;
 /**
  * Bitrate in bps (optional)
  */
-bitrate?: number | null }
+bitrate?: number | null; 
+/**
+ * FFmpeg channel layout name (e.g., "stereo", "5.1(side)"); `None` when
+ * the file does not label its channels, as many multitrack camera files do
+ */
+channelLayout?: string | null }
 /**
  * A single volume automation keyframe on an audio clip.
  * 
@@ -4023,7 +4247,25 @@ audioRole?: string | null;
 /**
  * Editorial audio tags groundwork for future buses/routing.
  */
-audioTags: string[] }
+audioTags: string[]; 
+/**
+ * Source channels to play, 0-based, in output order. One channel plays
+ * as mono, two as stereo, six as 5.1 and eight as 7.1; empty plays the
+ * source as it is.
+ */
+channelMap: number[] }
+/**
+ * A group of tracks a stems export writes to its own file
+ */
+export type AudioStem = { 
+/**
+ * Stem name (e.g. "Dialog"), appended to the output file name
+ */
+name: string; 
+/**
+ * Tracks mixed into the stem
+ */
+trackIds: string[] }
 /**
  * Audio stream information.
  */
@@ -4044,6 +4286,35 @@ codec: string;
  * Bitrate in bits/s (if available)
  */
 bitrate: number | null }
+/**
+ * A clip to sync against the reference clip.
+ */
+export type AudioSyncClipRef = { trackId: string; clipId: string }
+/**
+ * Parameters for audio sync analysis.
+ */
+export type AudioSyncParams = { 
+/**
+ * Largest start offset to search, in seconds. `None` searches every
+ * offset that leaves `min_overlap_sec` of shared audio.
+ */
+maxOffsetSec: number | null; 
+/**
+ * Shortest stretch of audio two sources must share to be compared. Default: 5.0
+ */
+minOverlapSec: number; 
+/**
+ * Audio matches below this confidence fall back to timecode. Default: 0.25
+ */
+minConfidence: number }
+/**
+ * Result of syncing a set of assets to the first one.
+ */
+export type AudioSyncResult = { referenceAssetId: string; 
+/**
+ * One entry per synced asset, the reference included (offset 0)
+ */
+offsets: AssetSyncOffset[]; unsynced: UnsyncedAsset[] }
 export type AutoSaveSettingsDto = { enabled: boolean; intervalSeconds: number; backupCount: number }
 /**
  * Result of probing available hardware decoders
@@ -4068,7 +4339,12 @@ hardware: HardwareEncoderInfo[];
 /**
  * Whether any hardware encoder is available
  */
-hasHardware: boolean }
+hasHardware: boolean; 
+/**
+ * Software AV1 encoder this FFmpeg build has: `libsvtav1` when present,
+ * otherwise `libaom-av1`
+ */
+softwareAv1Encoder?: string | null }
 /**
  * A single item in a batch render request (IPC DTO).
  */
@@ -4579,6 +4855,11 @@ linkGroupId?: string | null;
  * The clip's duration matches the inner sequence duration.
  */
 compoundSequenceId?: string | null; 
+/**
+ * Multicam clip: the inner video track (angle) currently on screen.
+ * Only set on compound clips whose inner video tracks are synced angles.
+ */
+multicamAngle?: string | null; 
 /**
  * Whether this clip is an adjustment layer.
  * Adjustment layers are transparent clips whose effects apply to all clips below them.
@@ -4745,7 +5026,7 @@ temperatureShift: number }
  * RGBA color in straight alpha byte space.
  */
 export type ColorRgba = { r: number; g: number; b: number; a: number }
-export type CommandPayload = { commandType: "insertClip"; payload: InsertClipPayload } | { commandType: "insertMedia"; payload: InsertMediaPayload } | { commandType: "insertEdit"; payload: InsertEditPayload } | { commandType: "overwriteEdit"; payload: OverwriteEditPayload } | { commandType: "rippleDelete"; payload: RippleDeletePayload } | { commandType: "lift"; payload: LiftPayload } | { commandType: "extractEdit"; payload: ExtractEditPayload } | { commandType: "closeGap"; payload: CloseGapPayload } | { commandType: "closeAllGaps"; payload: CloseAllGapsPayload } | { commandType: "removeClip"; payload: RemoveClipPayload } | { commandType: "moveClip"; payload: MoveClipPayload } | { commandType: "trimClip"; payload: TrimClipPayload } | { commandType: "splitClip"; payload: SplitClipPayload } | { commandType: "setClipTransform"; payload: SetClipTransformPayload } | { commandType: "setClipMotionKeyframes"; payload: SetClipMotionKeyframesPayload } | { commandType: "setClipOpacity"; payload: SetClipOpacityPayload } | { commandType: "setClipSpeed"; payload: SetClipSpeedPayload } | { commandType: "setClipSlowMotionInterpolation"; payload: SetClipSlowMotionInterpolationPayload } | { commandType: "reverseClip"; payload: ReverseClipPayload } | { commandType: "setClipEnabled"; payload: SetClipEnabledPayload } | { commandType: "linkClips"; payload: LinkClipsPayload } | { commandType: "unlinkClips"; payload: UnlinkClipsPayload } | { commandType: "groupClips"; payload: GroupClipsPayload } | { commandType: "ungroupClips"; payload: UngroupClipsPayload } | { commandType: "detachAudio"; payload: DetachAudioPayload } | { commandType: "createFreezeFrame"; payload: CreateFreezeFramePayload } | { commandType: "setTimeRemap"; payload: SetTimeRemapPayload } | { commandType: "clearTimeRemap"; payload: ClearTimeRemapPayload } | { commandType: "setClipMute"; payload: SetClipMutePayload } | { commandType: "setClipAudio"; payload: SetClipAudioPayload } | { commandType: "addAudioKeyframe"; payload: AddAudioKeyframePayload } | { commandType: "removeAudioKeyframe"; payload: RemoveAudioKeyframePayload } | { commandType: "moveAudioKeyframe"; payload: MoveAudioKeyframePayload } | { commandType: "setAudioKeyframeValue"; payload: SetAudioKeyframeValuePayload } | { commandType: "setAudioFadeIn"; payload: SetAudioFadeInPayload } | { commandType: "setAudioFadeOut"; payload: SetAudioFadeOutPayload } | { commandType: "setTrackBlendMode"; payload: SetTrackBlendModePayload } | { commandType: "setClipBlendMode"; payload: SetClipBlendModePayload } | { commandType: "importAsset"; payload: ImportAssetPayload } | { commandType: "removeAsset"; payload: RemoveAssetPayload } | { commandType: "updateAsset"; payload: UpdateAssetPayload } | { commandType: "createSequence"; payload: CreateSequencePayload } | { commandType: "setMasterVolume"; payload: SetMasterVolumePayload } | { commandType: "updateSequenceHdrSettings"; payload: UpdateSequenceHdrSettingsPayload } | { commandType: "createTrack"; payload: CreateTrackPayload } | { commandType: "removeTrack"; payload: RemoveTrackPayload } | { commandType: "renameTrack"; payload: RenameTrackPayload } | { commandType: "setCaptionTrackLanguage"; payload: SetCaptionTrackLanguagePayload } | { commandType: "reorderTracks"; payload: ReorderTracksPayload } | { commandType: "setTrackVolume"; payload: SetTrackVolumePayload } | { commandType: "toggleTrackMute"; payload: ToggleTrackMutePayload } | { commandType: "toggleTrackLock"; payload: ToggleTrackLockPayload } | { commandType: "toggleTrackVisibility"; payload: ToggleTrackVisibilityPayload } | { commandType: "addMarker"; payload: AddMarkerPayload } | { commandType: "removeMarker"; payload: RemoveMarkerPayload } | { commandType: "createCaption"; payload: CreateCaptionPayload } | { commandType: "importGeneratedCaptions"; payload: ImportGeneratedCaptionsPayload } | { commandType: "deleteCaption"; payload: DeleteCaptionPayload } | { commandType: "updateCaption"; payload: UpdateCaptionPayload } | { commandType: "addEffect"; payload: AddEffectPayload } | { commandType: "removeEffect"; payload: RemoveEffectPayload } | { commandType: "updateEffect"; payload: UpdateEffectPayload } | { commandType: "addMask"; payload: AddMaskPayload } | { commandType: "updateMask"; payload: UpdateMaskPayload } | { commandType: "removeMask"; payload: RemoveMaskPayload } | { commandType: "addTextClip"; payload: AddTextClipPayload } | { commandType: "updateTextClip"; payload: UpdateTextClipPayload } | { commandType: "removeTextClip"; payload: RemoveTextClipPayload } | { commandType: "createFolder"; payload: CreateFolderPayload } | { commandType: "renameFile"; payload: RenameFilePayload } | { commandType: "moveFile"; payload: MoveFilePayload } | { commandType: "deleteFile"; payload: DeleteFilePayload } | { commandType: "applyAudioDucking"; payload: ApplyAudioDuckingPayload } | { commandType: "createCompoundClip"; payload: CreateCompoundClipPayload } | { commandType: "unnestCompoundClip"; payload: UnnestCompoundClipPayload } | { commandType: "createAdjustmentLayer"; payload: CreateAdjustmentLayerPayload } | { commandType: "pasteEffects"; payload: PasteEffectsPayload } | { commandType: "pasteAttributes"; payload: PasteAttributesPayload } | { commandType: "removeAttributes"; payload: RemoveAttributesPayload }
+export type CommandPayload = { commandType: "insertClip"; payload: InsertClipPayload } | { commandType: "insertMedia"; payload: InsertMediaPayload } | { commandType: "insertEdit"; payload: InsertEditPayload } | { commandType: "overwriteEdit"; payload: OverwriteEditPayload } | { commandType: "rippleDelete"; payload: RippleDeletePayload } | { commandType: "lift"; payload: LiftPayload } | { commandType: "extractEdit"; payload: ExtractEditPayload } | { commandType: "closeGap"; payload: CloseGapPayload } | { commandType: "closeAllGaps"; payload: CloseAllGapsPayload } | { commandType: "removeClip"; payload: RemoveClipPayload } | { commandType: "moveClip"; payload: MoveClipPayload } | { commandType: "trimClip"; payload: TrimClipPayload } | { commandType: "slipClip"; payload: SlipClipPayload } | { commandType: "slideClip"; payload: SlideClipPayload } | { commandType: "rollEdit"; payload: RollEditPayload } | { commandType: "splitClip"; payload: SplitClipPayload } | { commandType: "setClipTransform"; payload: SetClipTransformPayload } | { commandType: "setClipMotionKeyframes"; payload: SetClipMotionKeyframesPayload } | { commandType: "setClipOpacity"; payload: SetClipOpacityPayload } | { commandType: "setClipSpeed"; payload: SetClipSpeedPayload } | { commandType: "setClipSlowMotionInterpolation"; payload: SetClipSlowMotionInterpolationPayload } | { commandType: "reverseClip"; payload: ReverseClipPayload } | { commandType: "setClipEnabled"; payload: SetClipEnabledPayload } | { commandType: "linkClips"; payload: LinkClipsPayload } | { commandType: "unlinkClips"; payload: UnlinkClipsPayload } | { commandType: "groupClips"; payload: GroupClipsPayload } | { commandType: "ungroupClips"; payload: UngroupClipsPayload } | { commandType: "detachAudio"; payload: DetachAudioPayload } | { commandType: "createFreezeFrame"; payload: CreateFreezeFramePayload } | { commandType: "setTimeRemap"; payload: SetTimeRemapPayload } | { commandType: "clearTimeRemap"; payload: ClearTimeRemapPayload } | { commandType: "setClipMute"; payload: SetClipMutePayload } | { commandType: "setClipAudio"; payload: SetClipAudioPayload } | { commandType: "addAudioKeyframe"; payload: AddAudioKeyframePayload } | { commandType: "removeAudioKeyframe"; payload: RemoveAudioKeyframePayload } | { commandType: "moveAudioKeyframe"; payload: MoveAudioKeyframePayload } | { commandType: "setAudioKeyframeValue"; payload: SetAudioKeyframeValuePayload } | { commandType: "setAudioFadeIn"; payload: SetAudioFadeInPayload } | { commandType: "setAudioFadeOut"; payload: SetAudioFadeOutPayload } | { commandType: "setTrackBlendMode"; payload: SetTrackBlendModePayload } | { commandType: "setClipBlendMode"; payload: SetClipBlendModePayload } | { commandType: "importAsset"; payload: ImportAssetPayload } | { commandType: "removeAsset"; payload: RemoveAssetPayload } | { commandType: "updateAsset"; payload: UpdateAssetPayload } | { commandType: "createSequence"; payload: CreateSequencePayload } | { commandType: "setMasterVolume"; payload: SetMasterVolumePayload } | { commandType: "createAudioBus"; payload: CreateAudioBusPayload } | { commandType: "removeAudioBus"; payload: RemoveAudioBusPayload } | { commandType: "updateAudioBus"; payload: UpdateAudioBusPayload } | { commandType: "setTrackOutputBus"; payload: SetTrackOutputBusPayload } | { commandType: "addAudioBusEffect"; payload: AddAudioBusEffectPayload } | { commandType: "removeAudioBusEffect"; payload: RemoveAudioBusEffectPayload } | { commandType: "updateSequenceHdrSettings"; payload: UpdateSequenceHdrSettingsPayload } | { commandType: "setSequenceStartTimecode"; payload: SetSequenceStartTimecodePayload } | { commandType: "createTrack"; payload: CreateTrackPayload } | { commandType: "removeTrack"; payload: RemoveTrackPayload } | { commandType: "renameTrack"; payload: RenameTrackPayload } | { commandType: "setCaptionTrackLanguage"; payload: SetCaptionTrackLanguagePayload } | { commandType: "reorderTracks"; payload: ReorderTracksPayload } | { commandType: "setTrackVolume"; payload: SetTrackVolumePayload } | { commandType: "setTrackSurroundPan"; payload: SetTrackSurroundPanPayload } | { commandType: "toggleTrackMute"; payload: ToggleTrackMutePayload } | { commandType: "toggleTrackLock"; payload: ToggleTrackLockPayload } | { commandType: "toggleTrackVisibility"; payload: ToggleTrackVisibilityPayload } | { commandType: "addMarker"; payload: AddMarkerPayload } | { commandType: "removeMarker"; payload: RemoveMarkerPayload } | { commandType: "createCaption"; payload: CreateCaptionPayload } | { commandType: "importGeneratedCaptions"; payload: ImportGeneratedCaptionsPayload } | { commandType: "deleteCaption"; payload: DeleteCaptionPayload } | { commandType: "updateCaption"; payload: UpdateCaptionPayload } | { commandType: "addEffect"; payload: AddEffectPayload } | { commandType: "removeEffect"; payload: RemoveEffectPayload } | { commandType: "updateEffect"; payload: UpdateEffectPayload } | { commandType: "addMask"; payload: AddMaskPayload } | { commandType: "updateMask"; payload: UpdateMaskPayload } | { commandType: "removeMask"; payload: RemoveMaskPayload } | { commandType: "addTextClip"; payload: AddTextClipPayload } | { commandType: "updateTextClip"; payload: UpdateTextClipPayload } | { commandType: "removeTextClip"; payload: RemoveTextClipPayload } | { commandType: "createFolder"; payload: CreateFolderPayload } | { commandType: "renameFile"; payload: RenameFilePayload } | { commandType: "moveFile"; payload: MoveFilePayload } | { commandType: "deleteFile"; payload: DeleteFilePayload } | { commandType: "applyAudioDucking"; payload: ApplyAudioDuckingPayload } | { commandType: "createCompoundClip"; payload: CreateCompoundClipPayload } | { commandType: "unnestCompoundClip"; payload: UnnestCompoundClipPayload } | { commandType: "createMulticamClip"; payload: CreateMulticamClipPayload } | { commandType: "switchMulticamAngle"; payload: SwitchMulticamAnglePayload } | { commandType: "flattenMulticamClip"; payload: FlattenMulticamClipPayload } | { commandType: "syncClips"; payload: SyncClipsPayload } | { commandType: "createAdjustmentLayer"; payload: CreateAdjustmentLayerPayload } | { commandType: "pasteEffects"; payload: PasteEffectsPayload } | { commandType: "pasteAttributes"; payload: PasteAttributesPayload } | { commandType: "removeAttributes"; payload: RemoveAttributesPayload }
 /**
  * Result of executing an edit command.
  */
@@ -4927,9 +5208,17 @@ export type ContainerFormat =
  */
 "mov" |
 /**
- * WebM container for VP9/Opus delivery.
+ * WebM container for VP9/AV1 + Opus delivery.
+ */
+"webm" | 
+/**
+ * Matroska container for H.264/H.265/VP9/AV1 with any audio codec.
  */
-"webm"
+"mkv" | 
+/**
+ * MXF OP1a for DNxHR/ProRes masters with one PCM track per channel.
+ */
+"mxf"
 /**
  * A classified time segment of video content
  */
@@ -5015,6 +5304,11 @@ export type CreateAdjustmentLayerPayload = { sequenceId: string; trackId: string
  * Input payload for creating an agent session kernel row.
  */
 export type CreateAgentSessionInputDto = { projectId: string; sequenceId: string | null; title: string | null; runtimeKind: string | null; agentProfileId: string | null; sessionMode: string | null; parentSessionId: string | null; branchFromSessionId: string | null; rootSessionId: string | null; modelProvider: string | null; modelId: string | null; id: string | null }
+export type CreateAudioBusPayload = { sequenceId: string; name: string; 
+/**
+ * Bus this one feeds (None = the master)
+ */
+outputBusId?: string | null }
 export type CreateCaptionPayload = { sequenceId: string; trackId: string; text: string; startSec: number; endSec: number; style: JsonValue | null; position: JsonValue | null; 
 /**
  * Curated caption pack id, resolved into `style` + `position`.
@@ -5032,11 +5326,28 @@ export type CreateDelegationRecordInput = { id: string | null; parentSessionId:
 export type CreateExternalAgentApprovalTokenInput = { sessionId: string; runId: string | null; planId: string | null; projectId: string; runtimeId: string; scopes: string[]; ttlMs: number | null }
 export type CreateFolderPayload = { relativePath: string }
 export type CreateFreezeFramePayload = { sequenceId: string; trackId: string; clipId: string; playheadSec: number; durationSec?: number }
+export type CreateMulticamClipPayload = { sequenceId: string; 
+/**
+ * Video track that receives the multicam clip
+ */
+trackId: string; 
+/**
+ * Synced angle clips; each video track they sit on becomes one angle
+ */
+clipIds: string[]; name: string | null }
 /**
  * Input payload for creating a resume checkpoint.
  */
 export type CreateResumeCheckpointInput = { id: string | null; sessionId: string; runId: string | null; checkpointKind: string; status: string | null; resumeCursorJson: string; sessionStateJson: string; pendingWorkJson: string | null; createdAt: number | null }
-export type CreateSequencePayload = { name: string; format: string | null }
+export type CreateSequencePayload = { name: string; format: string | null; 
+/**
+ * Frame rate overriding the format preset's.
+ */
+fps?: Ratio | null; 
+/**
+ * Whether to add the default video and audio tracks (defaults to `true`).
+ */
+addDefaultTracks?: boolean | null }
 export type CreateTrackPayload = { sequenceId: string; kind: TrackKind; name: string; position: number | null }
 /**
  * Status of credentials for each provider
@@ -5123,6 +5434,30 @@ regionType: RegionType;
 label: string }
 export type DetectedTerminalProfile = { id: string; label: string; commandLine: string; source: string; isDefault: boolean }
 export type DiarizationImportSummary = { assetId: string; transcriptSegmentCount: number; speakerCount: number; speakerTurnCount: number }
+/**
+ * DNxHR flavour written by FFmpeg's `dnxhd` encoder
+ */
+export type DnxhrProfile = 
+/**
+ * Low bandwidth, 8-bit 4:2:2
+ */
+"lb" | 
+/**
+ * Standard quality, 8-bit 4:2:2
+ */
+"sq" | 
+/**
+ * High quality, 8-bit 4:2:2
+ */
+"hq" | 
+/**
+ * High quality, 10-bit 4:2:2 (default)
+ */
+"hqx" | 
+/**
+ * 10-bit 4:4:4
+ */
+"444"
 /**
  * Response for download_generated_video
  */
@@ -5276,7 +5611,7 @@ export type EffectCapabilityDto = { effectType: string; preview: string; export:
 /**
  * Predefined effect types
  */
-export type EffectType = "brightness" | "contrast" | "saturation" | "hue" | "color_balance" | "color_wheels" | "gamma" | "levels" | "curves" | "temperature_tint" | "lut" | "crop" | "flip" | "mirror" | "rotate" | "gaussian_blur" | "box_blur" | "motion_blur" | "radial_blur" | "sharpen" | "unsharp_mask" | "vignette" | "glow" | "film_grain" | "chromatic_aberration" | "noise" | "pixelate" | "posterize" | "cross_dissolve" | "fade" | "wipe" | "slide" | "zoom" | "volume" | "gain" | "eq_band" | "compressor" | "limiter" | "noise_reduction" | "reverb" | "delay" | "text_overlay" | "subtitle" | "chroma_key" | "luma_key" | "blend_mode" | "opacity" | "hsl_qualifier" | "loudness_normalize" | "stabilize" | "background_removal" | "auto_reframe" | "face_blur" | "object_tracking" | { custom: string }
+export type EffectType = "brightness" | "contrast" | "saturation" | "hue" | "color_balance" | "color_wheels" | "gamma" | "levels" | "curves" | "temperature_tint" | "lut" | "broadcast_legalizer" | "crop" | "flip" | "mirror" | "rotate" | "gaussian_blur" | "box_blur" | "motion_blur" | "radial_blur" | "sharpen" | "unsharp_mask" | "vignette" | "glow" | "film_grain" | "chromatic_aberration" | "noise" | "pixelate" | "posterize" | "cross_dissolve" | "fade" | "wipe" | "slide" | "zoom" | "volume" | "gain" | "eq_band" | "compressor" | "limiter" | "noise_reduction" | "reverb" | "delay" | "text_overlay" | "subtitle" | "chroma_key" | "luma_key" | "blend_mode" | "opacity" | "hsl_qualifier" | "loudness_normalize" | "stabilize" | "background_removal" | "auto_reframe" | "face_blur" | "object_tracking" | { custom: string }
 /**
  * Ellipse mask shape
  */
@@ -5513,6 +5848,7 @@ missing?: boolean;
  * Child entries (for directories)
  */
 children: FileTreeEntryDto[] }
+export type FlattenMulticamClipPayload = { sequenceId: string; trackId: string; clipId: string }
 /**
  * Visual composition analysis for a single shot's keyframe
  */
@@ -5665,6 +6001,34 @@ export type GetExternalAgentSessionLinkInput = { conversationSessionId: string;
 /**
  * GPU acceleration status
  */
+/**
+ * Dither `paletteuse` applies when mapping GIF frames onto the palette
+ */
+export type GifDither = 
+/**
+ * No dithering; flat areas stay flat, gradients band
+ */
+"none" | 
+/**
+ * Ordered 8x8 Bayer pattern; stable between frames, so it compresses well
+ */
+"bayer" | 
+/**
+ * Heckbert error diffusion
+ */
+"heckbert" | 
+/**
+ * Floyd-Steinberg error diffusion
+ */
+"floyd_steinberg" | 
+/**
+ * Sierra-2 error diffusion
+ */
+"sierra2" | 
+/**
+ * Sierra-2 "lite" error diffusion, FFmpeg's default
+ */
+"sierra2_4a"
 export type GpuAccelerationStatus = { 
 /**
  * Whether GPU acceleration is enabled in settings
@@ -5770,7 +6134,11 @@ export type HardwareAccelMode =
 /**
  * Apple VideoToolbox (macOS only)
  */
-"video_toolbox"
+"video_toolbox" | 
+/**
+ * VA-API (Linux); only used for AV1
+ */
+"vaapi"
 /**
  * Hardware decoder backend for video decoding acceleration
  */
@@ -5842,7 +6210,12 @@ h264Encoder: string;
 /**
  * FFmpeg encoder name for H.265/HEVC (e.g., "hevc_nvenc")
  */
-h265Encoder: string }
+h265Encoder: string; 
+/**
+ * FFmpeg encoder name for AV1 (e.g., "av1_nvenc"); empty when the GPU
+ * or driver cannot encode AV1
+ */
+av1Encoder?: string }
 /**
  * HDR (High Dynamic Range) mode for export
  */
@@ -5881,6 +6254,66 @@ undoCount: number;
  * Number of operations in redo stack
  */
 redoCount: number }
+/**
+ * Still-image format of an image-sequence export
+ */
+export type ImageSequenceFormat =
+/**
+ * PNG, 8 or 16 bits
+ */
+"png" |
+/**
+ * TIFF, 8 or 16 bits
+ */
+"tiff" |
+/**
+ * OpenEXR, 16-bit half or 32-bit float
+ */
+"exr" |
+/**
+ * DPX, 8, 10, 12 or 16 bits
+ */
+"dpx"
+/**
+ * Result of changing an image sequence's frame rate.
+ */
+export type ImageSequenceFrameRateResult = {
+/**
+ * Updated asset ID.
+ */
+assetId: string;
+/**
+ * Operation ID for undo/redo tracking.
+ */
+opId: string;
+/**
+ * New duration of the sequence in seconds.
+ */
+durationSec: number;
+/**
+ * Background job ID re-encoding the proxy at the new rate (if queued).
+ */
+jobId: string | null }
+/**
+ * A numbered run of stills played back as one video asset.
+ * 
+ * The asset's `uri` is the first frame, so path validation, scoping and
+ * relinking treat the sequence like any file; `pattern` names its siblings.
+ * The frame rate lives on the asset's [`VideoInfo`].
+ */
+export type ImageSequenceInfo = {
+/**
+ * `printf`-style file name of the frames (e.g. "shot_%04d.exr")
+ */
+pattern: string;
+/**
+ * Number of the first frame
+ */
+startNumber: number;
+/**
+ * Number of consecutive frames
+ */
+frameCount: number }
 export type ImportAssetPayload = { name: string; uri: string }
 export type ImportGeneratedCaptionsPayload = { sequenceId: string; trackId: string; segments: GeneratedCaptionSegmentPayload[]; style: JsonValue | null; position: JsonValue | null; 
 /**
@@ -6000,7 +6433,11 @@ export type InterchangeFormat =
 /**
  * OpenTimelineIO cut interchange
  */
-"otio"
+"otio" | 
+/**
+ * Final Cut Pro 7 XML (`xmeml`), as Premiere Pro and DaVinci Resolve read it
+ */
+"xmeml"
 /**
  * Job completed event payload.
  */
@@ -6334,7 +6771,11 @@ format: string;
 /**
  * File size in bytes
  */
-sizeBytes: number }
+sizeBytes: number; 
+/**
+ * Source timecode, reel and camera tags
+ */
+source?: SourceMetadata }
 /**
  * Result of memory cleanup operation.
  */
@@ -6705,6 +7146,22 @@ startSec: number;
  * End time in seconds
  */
 endSec: number }
+/**
+ * ProRes flavour written by `prores_ks`
+ */
+export type ProResProfile = "proxy" | "lt" | "standard" | 
+/**
+ * ProRes 422 HQ (default)
+ */
+"hq" | 
+/**
+ * ProRes 4444
+ */
+"4444" | 
+/**
+ * ProRes 4444 XQ
+ */
+"4444_xq"
 /**
  * Project information returned when creating or opening a project.
  */
@@ -7017,6 +7474,8 @@ effectIds?: string[];
  * Which attributes to reset to defaults
  */
 resetTransform?: boolean; resetOpacity?: boolean; resetBlendMode?: boolean; resetSpeed?: boolean; resetAudio?: boolean }
+export type RemoveAudioBusEffectPayload = { sequenceId: string; busId: string; effectId: string }
+export type RemoveAudioBusPayload = { sequenceId: string; busId: string }
 export type RemoveAudioKeyframePayload = { sequenceId: string; trackId: string; clipId: string; keyframeIndex: number }
 export type RemoveClipPayload = { sequenceId: string; trackId: string; clipId: string }
 /**
@@ -7342,6 +7801,19 @@ copyright: string;
  * NSFW risk level ("none", "possible", "likely")
  */
 nsfw: string }
+export type RollEditPayload = { sequenceId: string; trackId: string; 
+/**
+ * Clip ending at the edit point.
+ */
+outgoingClipId: string; 
+/**
+ * Clip starting at the edit point.
+ */
+incomingClipId: string; 
+/**
+ * Edit point offset in seconds; positive rolls right.
+ */
+deltaSec: number }
 /**
  * Report of rollback operations after a plan failure.
  */
@@ -7569,7 +8041,16 @@ tracks: Track[]; markers: Marker[];
 /**
  * Master output volume in dB (-60.0 to +6.0, 0.0 = unity gain)
  */
-masterVolumeDb?: number; hdrSettings?: SequenceHdrSettings; createdAt: string; modifiedAt: string }
+masterVolumeDb?: number; 
+/**
+ * Submix buses that tracks can route their audio through
+ */
+audioBuses?: AudioBus[]; hdrSettings?: SequenceHdrSettings; 
+/**
+ * Timecode of the first frame (e.g. "01:00:00:00"), written to the
+ * timecode track of mastering exports
+ */
+startTimecode?: string | null; createdAt: string; modifiedAt: string }
 /**
  * Sequence format specification
  */
@@ -7610,7 +8091,11 @@ export type SetAudioFadeInPayload = { sequenceId: string; trackId: string; clipI
 export type SetAudioFadeOutPayload = { sequenceId: string; trackId: string; clipId: string; duration: number; fadeType?: FadeType }
 export type SetAudioKeyframeValuePayload = { sequenceId: string; trackId: string; clipId: string; keyframeIndex: number; valueDb: number; interpolation: KeyframeInterpolation | null }
 export type SetCaptionTrackLanguagePayload = { sequenceId: string; trackId: string; language: string }
-export type SetClipAudioPayload = { sequenceId: string; trackId: string; clipId: string; volumeDb: number | null; pan: number | null; muted: boolean | null; fadeInSec: number | null; fadeOutSec: number | null; audioRole: string | null; audioTags: string[] | null }
+export type SetClipAudioPayload = { sequenceId: string; trackId: string; clipId: string; volumeDb: number | null; pan: number | null; muted: boolean | null; fadeInSec: number | null; fadeOutSec: number | null; audioRole: string | null; audioTags: string[] | null; 
+/**
+ * Source channels to play, 0-based; an empty list plays the source as it is.
+ */
+channelMap: number[] | null }
 export type SetClipBlendModePayload = { sequenceId: string; trackId: string; clipId: string; blendMode: BlendMode }
 export type SetClipEnabledPayload = { sequenceId: string; trackId: string; clipId: string; enabled: boolean }
 export type SetClipMotionKeyframesPayload = { sequenceId: string; trackId: string; clipId: string; keyframes: TransformKeyframe[] }
@@ -7644,6 +8129,11 @@ isPlaying: boolean | null;
  * Timeline duration in seconds (if known).
  */
 durationSec: number | null }
+export type SetSequenceStartTimecodePayload = { sequenceId: string; 
+/**
+ * `HH:MM:SS:FF` (`;` for drop-frame); `null` clears it
+ */
+startTimecode: string | null }
 /**
  * Input payload for loading an asset into the source monitor.
  */
@@ -7662,6 +8152,16 @@ export type SetSourcePointPayload = {
 timeSec: number }
 export type SetTimeRemapPayload = { sequenceId: string; trackId: string; clipId: string; timeRemap: TimeRemapCurve }
 export type SetTrackBlendModePayload = { sequenceId: string; trackId: string; blendMode: BlendMode }
+export type SetTrackOutputBusPayload = { sequenceId: string; trackId: string; 
+/**
+ * Bus the track feeds (None = the master)
+ */
+busId?: string | null }
+export type SetTrackSurroundPanPayload = { sequenceId: string; trackId: string; 
+/**
+ * Position in the surround field; omitted or null clears it.
+ */
+surroundPan?: SurroundPan | null }
 export type SetTrackVolumePayload = { sequenceId: string; trackId: string; 
 /**
  * Linear track volume, where 1.0 is unity and 2.0 is +6 dB.
@@ -7782,6 +8282,16 @@ startSec: number;
  * End time in seconds
  */
 endSec: number }
+export type SlideClipPayload = { sequenceId: string; trackId: string; clipId: string; 
+/**
+ * Timeline offset in seconds; positive slides right.
+ */
+deltaSec: number }
+export type SlipClipPayload = { sequenceId: string; trackId: string; clipId: string; 
+/**
+ * Source offset in seconds; positive plays later media.
+ */
+deltaSec: number }
 /**
  * Slow-motion interpolation mode used when a clip plays below real time.
  */
@@ -7826,6 +8336,34 @@ analysisData: string;
  * Computed crop dimensions
  */
 cropWidth: number; cropHeight: number }
+/**
+ * Recording metadata carried by the source file: where its timecode starts
+ * and which reel, camera and lens produced it.
+ * 
+ * Interchange exports read this so EDL, FCPXML and OTIO point at the
+ * timecode the footage was actually shot with instead of zero.
+ */
+export type SourceMetadata = { 
+/**
+ * Start timecode as recorded in the file (e.g., "01:00:00:00")
+ */
+startTimecode?: string | null; 
+/**
+ * Start timecode in seconds from midnight
+ */
+startSec?: number | null; 
+/**
+ * Reel or tape name
+ */
+reelName?: string | null; 
+/**
+ * Camera make and model
+ */
+camera?: string | null; 
+/**
+ * Lens model
+ */
+lens?: string | null }
 /**
  * Response DTO for source monitor state.
  */
@@ -8241,6 +8779,18 @@ description: string;
  * JSON Schema describing tool input parameters.
  */
 parameters: JsonValue }
+/**
+ * One rung of a streaming ladder
+ */
+export type StreamingRendition = { 
+/**
+ * Picture height in pixels; the width follows the sequence's aspect ratio
+ */
+height: number; 
+/**
+ * Target video bitrate (e.g. "5M", "800k")
+ */
+videoBitrate: string }
 /**
  * Result of applying a reference editing style to source footage
  */
@@ -8293,6 +8843,84 @@ export type SubmitVideoGenerationRequest = { prompt: string; mode?: string; qual
  * Response for submit_video_generation
  */
 export type SubmitVideoGenerationResponse = { jobId: string; providerJobId: string; estimatedCostCents: number }
+/**
+ * Where a track sits in the surround field.
+ * 
+ * The track's audio is folded to mono and spread over the output speakers by
+ * distance from this point, so it only has an effect once it is set.
+ */
+export type SurroundPan = { 
+/**
+ * Left (-1.0) to right (1.0)
+ */
+x: number; 
+/**
+ * Rear (-1.0) to front (1.0)
+ */
+y: number; 
+/**
+ * Send to the LFE channel (0.0 none to 1.0 full level)
+ */
+lfe?: number }
+export type SwitchMulticamAnglePayload = { sequenceId: string; trackId: string; clipId: string; 
+/**
+ * Inner video track of the angle to show
+ */
+angleTrackId: string; 
+/**
+ * Timeline time of the cut; omit to switch the whole clip
+ */
+atSec?: number | null }
+export type SyncClipOffsetPayload = { trackId: string; clipId: string; 
+/**
+ * Reference-media time at which this clip's media starts
+ */
+offsetSec: number }
+/**
+ * Arguments for syncing clips to a reference clip by their audio.
+ */
+export type SyncClipsByAudioArgs = { sequenceId: string; referenceTrackId: string; referenceClipId: string; clips: AudioSyncClipRef[]; 
+/**
+ * Analysis parameters (defaults when omitted)
+ */
+params?: AudioSyncParams | null; 
+/**
+ * Link every synced clip with the reference clip
+ */
+link?: boolean; 
+/**
+ * Start timecode ("HH:MM:SS:FF") per asset ID, used when audio is inconclusive
+ */
+startTimecodes?: { [key in string]: string } }
+/**
+ * Result of syncing clips by audio.
+ */
+export type SyncClipsByAudioResult = { 
+/**
+ * Per-asset offsets and confidence
+ */
+analysis: AudioSyncResult; 
+/**
+ * The placement edit, or `None` when no target clip could be synced
+ */
+command: CommandResultDto | null }
+export type SyncClipsPayload = { sequenceId: string; referenceTrackId: string; referenceClipId: string; clips: SyncClipOffsetPayload[]; 
+/**
+ * Link the synced clips to the reference (defaults to `false`)
+ */
+link?: boolean }
+/**
+ * How an offset was found.
+ */
+export type SyncMethod = 
+/**
+ * Cross-correlation of the audio envelopes
+ */
+"audio" | 
+/**
+ * Difference of the start timecodes
+ */
+"timecode"
 /**
  * A detected synchronization point between audio and visual events
  */
@@ -8769,7 +9397,15 @@ volume: number;
 /**
  * BCP-47-ish language code for caption tracks.
  */
-captionLanguage?: string | null }
+captionLanguage?: string | null; 
+/**
+ * Bus this track's audio feeds; `None` feeds the master
+ */
+outputBusId?: string | null; 
+/**
+ * Position in the surround field; `None` keeps the clips' own channels
+ */
+surroundPan?: SurroundPan | null }
 /**
  * Track event payload.
  */
@@ -9269,11 +9905,24 @@ export type UnlinkClipsPayload = { sequenceId: string; clipRefs: ClipRef[] }
  */
 export type UnnestCompoundClipArgs = { sequenceId: string; trackId: string; clipId: string }
 export type UnnestCompoundClipPayload = { sequenceId: string; trackId: string; clipId: string }
+/**
+ * An asset that could not be placed relative to the reference.
+ */
+export type UnsyncedAsset = { assetId: string; reason: string }
 /**
  * Input payload for updating an agent run phase and syncing session state.
  */
 export type UpdateAgentRunPhaseInput = { runId: string; phase: string; traceId: string | null; toolCallsUsed: number | null; plannedStepCount: number | null; completedStepCount: number | null; outputMessageId: string | null; rollbackReportJson: string | null; errorCode: string | null; errorMessage: string | null; currentPlanId: string | null; pendingApprovalId: string | null; activeCheckpointId: string | null; permissionStateVersion: number | null; compactionVersion: number | null; resumeCursorVersion: number | null; lastCompactedAt: number | null; lastResumedAt: number | null; endedAt: number | null }
-export type UpdateAssetPayload = { assetId: string; name: string | null; tags: string[] | null; license: LicenseInfo | null; thumbnailUrl: string | null; proxyStatus: ProxyStatus | null; proxyUrl: string | null; uri: string | null; durationSec: number | null; fileSize: number | null; video: VideoInfo | null; audio: AudioInfo | null; relativePath: string | null; workspaceManaged: boolean | null; missing: boolean | null }
+export type UpdateAssetPayload = { assetId: string; name: string | null; tags: string[] | null; license: LicenseInfo | null; thumbnailUrl: string | null; proxyStatus: ProxyStatus | null; proxyUrl: string | null; uri: string | null; durationSec: number | null; fileSize: number | null; video: VideoInfo | null; audio: AudioInfo | null; relativePath: string | null; workspaceManaged: boolean | null; missing: boolean | null; source: SourceMetadata | null }
+export type UpdateAudioBusPayload = { sequenceId: string; busId: string; name?: string | null; volumeDb?: number | null; muted?: boolean | null; 
+/**
+ * Bus to feed instead of the current output
+ */
+outputBusId?: string | null; 
+/**
+ * Feed the master instead of the current output (wins over `outputBusId`)
+ */
+routeToMaster?: boolean }
 export type UpdateCaptionPayload = { sequenceId: string; trackId: string; captionId: string; text: string | null; startSec: number | null; endSec: number | null; style: JsonValue | null; position: JsonValue | null; 
 /**
  * Curated caption pack id, resolved into `style` only.
@@ -9415,11 +10064,11 @@ warnings: string[] }
 /**
  * Video codec selection
  */
-export type VideoCodec = "h264" | "h265" | "vp9" | "prores" | "copy"
+export type VideoCodec = "h264" | "h265" | "vp9" | "av1" | "prores" | "dnxhr" | "copy"
 /**
  * Structured video export request used by UI and agent-driven export paths.
  */
-export type VideoExportRequest = { container: ContainerFormat; videoCodec: VideoCodec; audioCodec: AudioCodec; qualityTier: ExportQualityTier; width: number | null; height: number | null; fps: number | null; videoBitrate: string | null; audioBitrate: string | null; crf: number | null; twoPass?: boolean; hdrMode?: HdrMode; maxCll: number | null; maxFall: number | null; bitDepth: number | null }
+export type VideoExportRequest = { container: ContainerFormat; videoCodec: VideoCodec; audioCodec: AudioCodec; qualityTier: ExportQualityTier; width: number | null; height: number | null; fps: number | null; videoBitrate: string | null; audioBitrate: string | null; crf: number | null; twoPass?: boolean; hdrMode?: HdrMode; maxCll: number | null; maxFall: number | null; bitDepth: number | null; audioChannelLayout?: AudioChannelLayout; audioDownmix?: AudioDownmix; proresProfile?: ProResProfile; dnxhrProfile?: DnxhrProfile }
 /**
  * Video-specific metadata
  */
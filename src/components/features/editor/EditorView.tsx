@@ -1546,6 +1546,7 @@ export function EditorView({ sequence, appVersion = '0.1.0' }: EditorViewProps):
       if (isTextClip(clip.assetId)) return undefined;
 
       const asset = assets.get(clip.assetId);
+      const fps = asset?.video?.fps ?? sequence.format.fps;
       return {
         id: clip.id,
         sequenceId: sequence.id,
@@ -1581,6 +1582,14 @@ export function EditorView({ sequence, appVersion = '0.1.0' }: EditorViewProps):
         slowMotionInterpolation: clip.slowMotionInterpolation,
         hasTimeRemap: hasActiveTimeRemap(clip),
         audio: clip.audio,
+        sourceTimecode:
+          asset?.source?.startSec !== undefined && fps.den > 0
+            ? {
+                startSec: asset.source.startSec,
+                fps: fps.num / fps.den,
+                reelName: asset.source.reelName,
+              }
+            : undefined,
       };
     }
     return undefined;
@@ -73,6 +73,10 @@ export const EFFECT_CATEGORIES: CategoryWithIcon[] = [
       { type: 'levels', label: EFFECT_TYPE_LABELS.levels ?? 'Levels' },
       { type: 'curves', label: EFFECT_TYPE_LABELS.curves ?? 'Curves' },
       { type: 'lut', label: EFFECT_TYPE_LABELS.lut ?? 'LUT' },
+      {
+        type: 'broadcast_legalizer',
+        label: EFFECT_TYPE_LABELS.broadcast_legalizer ?? 'Broadcast Legalizer',
+      },
     ],
   },
   {
@@ -5,6 +5,7 @@ import { getExportPreset, getPresetExtension } from './constants';
 describe('export constants', () => {
   it('derives preset extensions from the preset container', () => {
     expect(getPresetExtension('webm_vp9')).toBe('webm');
+    expect(getPresetExtension('webm_av1')).toBe('webm');
     expect(getPresetExtension('prores')).toBe('mov');
   });
 
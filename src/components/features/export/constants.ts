@@ -203,6 +203,29 @@ export const EXPORT_PRESETS: ExportPreset[] = [
       bitDepth: 8,
     },
   },
+  {
+    id: 'webm_av1',
+    name: 'WebM AV1',
+    description: 'AV1/Opus, smallest web delivery',
+    icon: 'globe',
+    settings: {
+      container: 'webm',
+      videoCodec: 'av1',
+      audioCodec: 'opus',
+      qualityTier: 'standard',
+      width: 1920,
+      height: 1080,
+      fps: 30,
+      videoBitrate: null,
+      audioBitrate: '128k',
+      crf: 32,
+      twoPass: false,
+      hdrMode: 'sdr',
+      maxCll: null,
+      maxFall: null,
+      bitDepth: 8,
+    },
+  },
   {
     id: 'prores',
     name: 'MOV Master',
@@ -270,6 +293,12 @@ export const TIMELINE_EXPORT_FORMATS: TimelineFormatOption[] = [
     description: 'Editable timeline for FCP/Resolve',
     icon: 'film',
   },
+  {
+    id: 'xmeml',
+    name: 'Premiere XML',
+    description: 'Final Cut Pro 7 XML for Premiere/Resolve',
+    icon: 'film',
+  },
   {
     id: 'edl',
     name: 'EDL',
@@ -291,6 +320,7 @@ export const AUDIO_FORMAT_EXTENSIONS: Record<AudioExportFormat, string> = {
 export const TIMELINE_FORMAT_EXTENSIONS: Record<TimelineExportFormat, string> = {
   edl: 'edl',
   fcpxml: 'fcpxml',
+  xmeml: 'xml',
 };
 
 /**
@@ -38,7 +38,7 @@ export interface ExportPreset extends SelectableExportOption {
 export type AudioExportFormat = 'wav' | 'mp3' | 'm4a' | 'flac' | 'ogg';
 
 /** Editable timeline export format IDs */
-export type TimelineExportFormat = 'edl' | 'fcpxml';
+export type TimelineExportFormat = 'edl' | 'fcpxml' | 'xmeml';
 
 /** Audio export option configuration */
 export interface AudioFormatOption extends SelectableExportOption {
@@ -91,6 +91,12 @@ export interface SelectedClip {
   hasTimeRemap?: boolean;
   /** Clip-level audio settings */
   audio?: AudioSettings;
+  /** Recorded source timecode of the clip's media, when the file carries one */
+  sourceTimecode?: {
+    startSec: number;
+    fps: number;
+    reelName?: string;
+  };
 }
 
 /** Asset selection data */
@@ -23,7 +23,7 @@ import {
   XCircle,
   Zap,
 } from 'lucide-react';
-import { formatDuration, formatFileSize } from '@/utils/formatters';
+import { formatDuration, formatFileSize, formatSourceTimecode } from '@/utils/formatters';
 import { BUNDLED_TEXT_FONT_FAMILIES } from '@/utils/textFonts';
 import {
   captionColorToHex,
@@ -1065,6 +1065,35 @@ export function ClipInspectorPanel({
           value={formatDuration(selectedClip.range.sourceOutSec)}
           testId="clip-out-point"
         />
+        {selectedClip.sourceTimecode && (
+          <>
+            <PropertyRow
+              label="Source TC In"
+              value={formatSourceTimecode(
+                selectedClip.range.sourceInSec,
+                selectedClip.sourceTimecode.startSec,
+                selectedClip.sourceTimecode.fps,
+              )}
+              testId="clip-source-tc-in"
+            />
+            <PropertyRow
+              label="Source TC Out"
+              value={formatSourceTimecode(
+                selectedClip.range.sourceOutSec,
+                selectedClip.sourceTimecode.startSec,
+                selectedClip.sourceTimecode.fps,
+              )}
+              testId="clip-source-tc-out"
+            />
+          </>
+        )}
+        {selectedClip.sourceTimecode?.reelName && (
+          <PropertyRow
+            label="Reel"
+            value={selectedClip.sourceTimecode.reelName}
+            testId="clip-reel"
+          />
+        )}
         <PropertyRow
           label="Timeline Position"
           value={formatDuration(selectedClip.place.timelineInSec)}
@@ -497,10 +497,19 @@ export const TimelinePreviewPlayer = memo(function TimelinePreviewPlayer({
               continue;
             }
 
-            const nestedSequence = sequences.get(clip.compoundSequenceId);
-            if (!nestedSequence) {
+            const compoundSequence = sequences.get(clip.compoundSequenceId);
+            if (!compoundSequence) {
               continue;
             }
+            // A multicam clip shows only its active angle.
+            const nestedSequence = clip.multicamAngle
+              ? {
+                  ...compoundSequence,
+                  tracks: compoundSequence.tracks.filter(
+                    (nestedTrack) => nestedTrack.id === clip.multicamAngle,
+                  ),
+                }
+              : compoundSequence;
 
             const nestedCanvas = targetCtx.canvas.ownerDocument.createElement('canvas');
             nestedCanvas.width = targetCtx.canvas.width;
@@ -309,6 +309,44 @@ describe('useExportDialog', () => {
     expect(invoke).not.toHaveBeenCalledWith('start_render', expect.anything());
   });
 
+  it('should route editable timeline exports to Premiere XML with an .xml extension', async () => {
+    vi.mocked(invoke).mockResolvedValue({
+      format: 'xmeml',
+      outputPath: '/tmp/sequence.xml',
+      eventCount: 2,
+      trackCount: 2,
+      durationSec: 10,
+    });
+
+    const { result } = renderHook(() =>
+      useExportDialog({
+        isOpen: true,
+        sequenceId: 'sequence-1',
+        sequenceName: 'Sequence',
+        initialExportKind: 'timeline',
+      }),
+    );
+
+    act(() => {
+      result.current.setOutputPath('/tmp/sequence.fcpxml');
+      result.current.setSelectedTimelineFormat('xmeml');
+    });
+
+    await waitFor(() => {
+      expect(result.current.outputPath).toBe('/tmp/sequence.xml');
+    });
+
+    await act(async () => {
+      await result.current.handleExport();
+    });
+
+    expect(invoke).toHaveBeenCalledWith('export_xmeml', {
+      sequenceId: 'sequence-1',
+      outputPath: '/tmp/sequence.xml',
+    });
+    expect(invoke).not.toHaveBeenCalledWith('start_render', expect.anything());
+  });
+
   it('should ignore invalid render ranges when exporting editable timelines', async () => {
     vi.mocked(invoke).mockResolvedValue({
       format: 'fcpxml',
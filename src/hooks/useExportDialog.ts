@@ -334,7 +334,9 @@ export function useExportDialog({
         const res =
           selectedTimelineFormat === 'edl'
             ? await commands.exportEdl(sequenceId, outputPath)
-            : await commands.exportFcpxml(sequenceId, outputPath);
+            : selectedTimelineFormat === 'xmeml'
+              ? await commands.exportXmeml(sequenceId, outputPath)
+              : await commands.exportFcpxml(sequenceId, outputPath);
 
         if (res.status === 'error') {
           setStatus({ type: 'failed', error: String(res.error) });
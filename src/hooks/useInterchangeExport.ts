@@ -63,6 +63,13 @@ const FORMAT_CONFIG: Record<
     supported: false,
     label: 'OTIO',
   },
+  // Premiere XML is offered by the export dialog's editable-timeline list.
+  xmeml: {
+    extension: 'xml',
+    filterName: 'Final Cut Pro 7 XML',
+    supported: false,
+    label: 'XMEML',
+  },
 };
 
 function sanitizeFilename(name: string): string {
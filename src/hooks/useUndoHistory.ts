@@ -24,6 +24,9 @@ const COMMAND_LABELS: Record<string, string> = {
   DeleteClip: 'Delete Clip',
   MoveClip: 'Move Clip',
   TrimClip: 'Trim Clip',
+  SlipClip: 'Slip Clip',
+  SlideClip: 'Slide Clip',
+  RollEdit: 'Roll Edit',
   SplitClip: 'Split Clip',
   SetClipSpeed: 'Set Clip Speed',
   SetClipSlowMotionInterpolation: 'Slow Motion Interpolation',
@@ -47,6 +50,7 @@ const COMMAND_LABELS: Record<string, string> = {
   RenameTrack: 'Rename Track',
   ReorderTracks: 'Reorder Tracks',
   SetTrackVolume: 'Set Track Volume',
+  SetTrackSurroundPan: 'Set Track Surround Pan',
   SetCaptionTrackLanguage: 'Caption Track Language',
   ToggleTrackMute: 'Toggle Track Mute',
   ToggleTrackLock: 'Toggle Track Lock',
@@ -65,9 +69,19 @@ const COMMAND_LABELS: Record<string, string> = {
   SetAudioFadeIn: 'Audio Fade In',
   SetAudioFadeOut: 'Audio Fade Out',
   SetMasterVolume: 'Master Volume',
+  CreateAudioBus: 'Create Audio Bus',
+  RemoveAudioBus: 'Remove Audio Bus',
+  UpdateAudioBus: 'Update Audio Bus',
+  SetTrackOutputBus: 'Route Track',
+  AddAudioBusEffect: 'Add Bus Effect',
+  RemoveAudioBusEffect: 'Remove Bus Effect',
   ApplyAudioDucking: 'Audio Ducking',
   CreateCompoundClip: 'Create Compound Clip',
   UnnestCompoundClip: 'Unnest Compound Clip',
+  CreateMulticamClip: 'Create Multicam Clip',
+  SwitchMulticamAngle: 'Switch Angle',
+  FlattenMulticamClip: 'Flatten Multicam Clip',
+  SyncClips: 'Sync Clips',
   CreateAdjustmentLayer: 'Adjustment Layer',
   GroupClips: 'Group Clips',
   UngroupClips: 'Ungroup Clips',
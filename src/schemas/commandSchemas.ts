@@ -87,6 +87,7 @@ export const EffectType = z.enum([
   'curves',
   'temperature_tint',
   'lut',
+  'broadcast_legalizer',
   // Transform effects
   'crop',
   'flip',
@@ -75,8 +75,8 @@ export interface PlaybackSettings {
 
 /** Export settings */
 export interface ExportSettings {
-  defaultFormat: 'mp4' | 'webm' | 'mov' | 'gif';
-  defaultVideoCodec: 'h264' | 'h265' | 'vp9' | 'prores';
+  defaultFormat: 'mp4' | 'webm' | 'mov' | 'mkv' | 'gif';
+  defaultVideoCodec: 'h264' | 'h265' | 'vp9' | 'av1' | 'prores';
   defaultAudioCodec: 'aac' | 'mp3' | 'opus';
   defaultExportLocation: string | null;
   openFolderAfterExport: boolean;
@@ -31,6 +31,7 @@ const ALL_STRING_EFFECT_TYPES: EffectType[] = [
   'curves',
   'temperature_tint',
   'lut',
+  'broadcast_legalizer',
   // Transform
   'crop',
   'flip',
@@ -215,6 +215,8 @@ export interface AudioInfo {
   channels: number;
   codec: string;
   bitrate?: number;
+  /** FFmpeg channel layout name (e.g. "5.1(side)"); absent when unlabelled */
+  channelLayout?: string | null;
 }
 
 /**
@@ -232,6 +234,17 @@ export interface WaveformData {
   channels: number;
 }
 
+/** Timecode, reel and camera the source file was recorded with */
+export interface SourceMetadata {
+  /** Start timecode as recorded in the file (e.g. "01:00:00:00") */
+  startTimecode?: string;
+  /** Start timecode in seconds from midnight */
+  startSec?: number;
+  reelName?: string;
+  camera?: string;
+  lens?: string;
+}
+
 export interface LicenseInfo {
   source: 'user' | 'stockProvider' | 'generated' | 'plugin';
   provider?: string;
@@ -275,6 +288,8 @@ export interface Asset {
    * rejected value survives for the user to see and relink from.
    */
   quarantinedUri?: string;
+  /** Source timecode, reel and camera metadata probed from the file */
+  source?: SourceMetadata;
 }
 
 /** Check if an asset requires proxy generation based on video dimensions */
@@ -374,6 +389,20 @@ export interface SequenceHdrSettings {
   bitDepth: 8 | 10 | 12;
 }
 
+/** Audio submix bus; tracks and other buses route into it */
+export interface AudioBus {
+  id: string;
+  name: string;
+  /** Bus gain in dB (-60 to +6, 0 = unity) */
+  volumeDb?: number;
+  /** Muting a bus silences everything routed through it */
+  muted?: boolean;
+  /** Audio effect IDs applied to the bus mix, in order */
+  effects?: string[];
+  /** Bus this one feeds; absent feeds the master */
+  outputBusId?: string | null;
+}
+
 export interface Sequence {
   id: SequenceId;
   name: string;
@@ -382,6 +411,8 @@ export interface Sequence {
   markers: Marker[];
   /** Master output volume in dB (-60 to +6, 0 = unity) */
   masterVolumeDb?: number;
+  /** Submix buses that tracks can route their audio through */
+  audioBuses?: AudioBus[];
   hdrSettings?: SequenceHdrSettings;
 }
 
@@ -425,6 +456,20 @@ export interface Track {
   volume: number;
   /** Language code for caption tracks */
   captionLanguage?: string;
+  /** Bus this track's audio feeds; absent feeds the master */
+  outputBusId?: string | null;
+  /** Position in the surround field; absent keeps the clips' own channels */
+  surroundPan?: SurroundPan | null;
+}
+
+/** Track position in the surround field */
+export interface SurroundPan {
+  /** Left (-1.0) to right (1.0) */
+  x: number;
+  /** Rear (-1.0) to front (1.0) */
+  y: number;
+  /** Send to the LFE channel (0.0 - 1.0) */
+  lfe?: number;
 }
 
 export interface ClipRange {
@@ -496,6 +541,8 @@ export interface AudioSettings {
   audioRole?: AudioRole;
   /** Editorial tags groundwork for future buses/routing */
   audioTags?: string[];
+  /** Source channels to play, 0-based (1, 2, 6 or 8 entries); empty plays the source as is */
+  channelMap?: number[];
 }
 
 /** Interpolation type for time remap keyframes */
@@ -566,6 +613,8 @@ export interface Clip {
   linkGroupId?: string;
   /** Compound clip: references a nested sequence. When set, this clip acts as a container. */
   compoundSequenceId?: string;
+  /** Multicam clip: the inner video track (angle) currently on screen. */
+  multicamAngle?: string;
   /** Whether this clip is an adjustment layer. Effects on adjustment layers apply to all clips below. */
   isAdjustmentLayer?: boolean;
   /** Group ID for clip grouping. Clips sharing the same groupId are selected/moved together. */
@@ -944,6 +993,9 @@ export type CommandType =
   | 'SetClipBlendMode'
   | 'SplitClip'
   | 'TrimClip'
+  | 'SlipClip'
+  | 'SlideClip'
+  | 'RollEdit'
   | 'MoveClip'
   | 'DeleteClip'
   | 'ApplyEffect'
@@ -960,6 +1012,7 @@ export type CommandType =
   | 'DeleteTrack'
   | 'SetTrackBlendMode'
   | 'SetTrackVolume'
+  | 'SetTrackSurroundPan'
   | 'SetCaptionTrackLanguage'
   | 'ToggleTrackMute'
   | 'ToggleTrackLock'
@@ -997,6 +1050,13 @@ export type CommandType =
   | 'SetAudioFadeOut'
   // Master volume (S26)
   | 'SetMasterVolume'
+  // Audio buses
+  | 'CreateAudioBus'
+  | 'RemoveAudioBus'
+  | 'UpdateAudioBus'
+  | 'SetTrackOutputBus'
+  | 'AddAudioBusEffect'
+  | 'RemoveAudioBusEffect'
   // Clip enable/disable (S27)
   | 'SetClipEnabled'
   // Audio-video link/unlink/detach (S29)
@@ -1008,6 +1068,11 @@ export type CommandType =
   // Compound clip commands (S30)
   | 'CreateCompoundClip'
   | 'UnnestCompoundClip'
+  // Multicam clip commands
+  | 'CreateMulticamClip'
+  | 'SwitchMulticamAngle'
+  | 'FlattenMulticamClip'
+  | 'SyncClips'
   // Adjustment layer (S30)
   | 'CreateAdjustmentLayer'
   // Clip group/ungroup (S30)
@@ -1266,6 +1331,7 @@ export type ExportPreset =
   | 'twitter'
   | 'instagram'
   | 'webm_vp9'
+  | 'webm_av1'
   | 'prores';
 
 /** Export progress event data */
@@ -1327,6 +1393,7 @@ export type EffectType =
   | 'curves'
   | 'temperature_tint' // White balance (temperature + tint)
   | 'lut'
+  | 'broadcast_legalizer' // Clamps luma/chroma into broadcast-legal levels
   // Transform effects
   | 'crop'
   | 'flip'
@@ -1504,6 +1571,7 @@ export function getEffectCategory(effectType: EffectType): EffectCategory {
     case 'curves':
     case 'temperature_tint':
     case 'lut':
+    case 'broadcast_legalizer':
       return 'color';
 
     case 'chroma_key':
@@ -1611,6 +1679,7 @@ export const EFFECT_TYPE_LABELS: Partial<Record<string, string>> = {
   curves: 'Curves',
   temperature_tint: 'Temperature / Tint',
   lut: 'LUT',
+  broadcast_legalizer: 'Broadcast Legalizer',
   crop: 'Crop',
   flip: 'Flip',
   mirror: 'Mirror',
@@ -629,6 +629,41 @@ export const VIDEO_EFFECT_PARAM_DEFS: Record<string, ParamDef[]> = {
     },
   ],
 
+  broadcast_legalizer: [
+    {
+      name: 'luma_min',
+      label: 'Luma Min',
+      default: { type: 'float', value: 16 },
+      min: 0,
+      max: 255,
+      step: 1,
+    },
+    {
+      name: 'luma_max',
+      label: 'Luma Max',
+      default: { type: 'float', value: 235 },
+      min: 0,
+      max: 255,
+      step: 1,
+    },
+    {
+      name: 'chroma_min',
+      label: 'Chroma Min',
+      default: { type: 'float', value: 16 },
+      min: 0,
+      max: 255,
+      step: 1,
+    },
+    {
+      name: 'chroma_max',
+      label: 'Chroma Max',
+      default: { type: 'float', value: 240 },
+      min: 0,
+      max: 255,
+      step: 1,
+    },
+  ],
+
   unsharp_mask: [
     {
       name: 'amount',
@@ -8,6 +8,7 @@ import { describe, it, expect } from 'vitest';
 import {
   formatDuration,
   formatTimecode,
+  formatSourceTimecode,
   formatFileSize,
   formatRelativeTime,
   formatShuttleSpeed,
@@ -64,6 +65,20 @@ describe('formatTimecode', () => {
   });
 });
 
+describe('formatSourceTimecode', () => {
+  it('offsets a source position by the recorded start timecode', () => {
+    expect(formatSourceTimecode(2, 3600, 24)).toBe('01:00:02:00');
+    expect(formatSourceTimecode(0.5, 3600, 24)).toBe('01:00:00:12');
+  });
+
+  it('labels NTSC frames at the nominal rate', () => {
+    // Non-drop 01:00:00:00 at 29.97 is frame 108000, which lands at 3603.6s.
+    const startSec = (108000 * 1001) / 30000;
+    expect(formatSourceTimecode(0, startSec, 30000 / 1001)).toBe('01:00:00:00');
+    expect(formatSourceTimecode(1001 / 30000, startSec, 30000 / 1001)).toBe('01:00:00:01');
+  });
+});
+
 describe('parseTimecode', () => {
   it('parses HH:MM:SS:FF timecode to seconds at 30fps', () => {
     expect(parseTimecode('00:00:00:00', 30)).toBe(0);
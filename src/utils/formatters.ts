@@ -58,6 +58,26 @@ export function formatTimecode(seconds: number, fps: number): string {
   ].join(':');
 }
 
+/**
+ * Format a position inside a source file as the timecode it was recorded with
+ *
+ * Frames are counted at the file's true rate and labelled at the nominal
+ * rate, the way non-drop timecode runs on 23.976 and 29.97 footage.
+ *
+ * @param sourceSec - Position in the file, in seconds from its first frame
+ * @param startSec - The file's recorded start timecode, in seconds
+ * @param fps - The file's frame rate
+ * @returns Formatted timecode string
+ *
+ * @example
+ * formatSourceTimecode(2, 3600, 24) // "01:00:02:00"
+ */
+export function formatSourceTimecode(sourceSec: number, startSec: number, fps: number): string {
+  const nominalFps = Math.max(1, Math.round(fps));
+  const frames = Math.round((startSec + Math.max(0, sourceSec)) * fps);
+  return formatTimecode(frames / nominalFps, nominalFps);
+}
+
 /**
  * Parse SMPTE timecode (HH:MM:SS:FF) to seconds
  *